@@ -8,6 +8,14 @@ const MAX_TIMEOUT_FOR_TEST = 300_000;
 
 test.describe('Wasm example', () => {
   test('Wait for success', async ({ page }, testInfo) => {
+    // The screenshots taken by the example through `bevy_ci_testing` are downloads in the browser.
+    let downloads = [];
+    if (process.env.SCREENSHOT_DIR !== undefined) {
+      page.on('download', download => {
+        downloads.push(download.saveAs(`${process.env.SCREENSHOT_DIR}/${testInfo.project.name}/${download.suggestedFilename()}`));
+      });
+    }
+
     let start = new Date().getTime();
 
     let found = false;
@@ -25,6 +33,7 @@ test.describe('Wasm example', () => {
       }
     }
 
+    await Promise.all(downloads);
     expect(found).toBe(true);
   });
 
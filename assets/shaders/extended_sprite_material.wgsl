@@ -0,0 +1,39 @@
+#import bevy_sprite::{
+    mesh2d_vertex_output::VertexOutput,
+    sprite_functions::sample_final_color,
+}
+
+struct Dissolve {
+    progress: f32,
+    edge_color: vec4<f32>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> dissolve: Dissolve;
+
+// A cheap value noise, good enough to break up the dissolve edge.
+fn noise(p: vec2<f32>) -> f32 {
+    let i = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+    let a = fract(sin(dot(i, vec2(12.9898, 78.233))) * 43758.5453);
+    let b = fract(sin(dot(i + vec2(1.0, 0.0), vec2(12.9898, 78.233))) * 43758.5453);
+    let c = fract(sin(dot(i + vec2(0.0, 1.0), vec2(12.9898, 78.233))) * 43758.5453);
+    let d = fract(sin(dot(i + vec2(1.0, 1.0), vec2(12.9898, 78.233))) * 43758.5453);
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
+
+@fragment
+fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+    // Sample the sprite the same way the default `SpriteMaterial` shader does, so flipping,
+    // atlases, slicing and tinting keep working.
+    var color = sample_final_color(mesh.uv, mesh.instance_index);
+
+    let value = noise(mesh.uv * 16.0);
+    if value < dissolve.progress {
+        discard;
+    }
+    // Glow along the edge of the dissolved area.
+    let edge = 1.0 - smoothstep(0.0, 0.05, value - dissolve.progress);
+    color = vec4(mix(color.rgb, dissolve.edge_color.rgb, edge * dissolve.edge_color.a), color.a);
+    return color;
+}
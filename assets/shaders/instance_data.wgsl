@@ -0,0 +1,48 @@
+#import bevy_pbr::{
+    mesh_functions,
+    mesh_view_bindings::globals,
+    view_transformations::position_world_to_clip
+}
+
+// Must match the layout of `CubeInstance` in the example.
+struct CubeInstance {
+    color: vec4<f32>,
+    phase: f32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<storage, read> instances: array<CubeInstance>;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_position: vec4<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+
+    // The `InstanceDataPlugin` stores the index of each instance's data in its tag.
+    let instance = instances[mesh_functions::get_tag(vertex.instance_index)];
+
+    var world_from_local = mesh_functions::get_world_from_local(vertex.instance_index);
+    out.world_position = mesh_functions::mesh_position_local_to_world(world_from_local, vec4(vertex.position, 1.0));
+    // Bob each cube up and down, out of step with the others.
+    out.world_position.y += 0.2 * sin(globals.time * 2.0 + instance.phase);
+    out.clip_position = position_world_to_clip(out.world_position.xyz);
+
+    out.color = instance.color;
+    return out;
+}
+
+@fragment
+fn fragment(
+    mesh: VertexOutput,
+) -> @location(0) vec4<f32> {
+    return mesh.color;
+}
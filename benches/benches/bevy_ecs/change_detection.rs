@@ -17,7 +17,8 @@ criterion_group!(
     all_changed_detection,
     few_changed_detection,
     none_changed_detection,
-    multiple_archetype_none_changed_detection
+    multiple_archetype_none_changed_detection,
+    mutable_deref
 );
 
 macro_rules! modify {
@@ -36,6 +37,12 @@ struct Table(f32);
 #[component(storage = "SparseSet")]
 struct Sparse(f32);
 #[derive(Component, Default)]
+#[component(storage = "Table", track_archetype_changes)]
+struct TrackedTable(f32);
+#[derive(Component, Default)]
+#[component(storage = "SparseSet", track_archetype_changes)]
+struct TrackedSparse(f32);
+#[derive(Component, Default)]
 #[component(storage = "Table")]
 struct Data<const X: u16>(f32);
 
@@ -57,6 +64,20 @@ impl BenchModify for Sparse {
     }
 }
 
+impl BenchModify for TrackedTable {
+    fn bench_modify(&mut self) -> f32 {
+        self.0 += 1f32;
+        black_box(self.0)
+    }
+}
+
+impl BenchModify for TrackedSparse {
+    fn bench_modify(&mut self) -> f32 {
+        self.0 += 1f32;
+        black_box(self.0)
+    }
+}
+
 const ENTITIES_TO_BENCH_COUNT: &[u32] = &[5000, 50000];
 
 type BenchGroup<'a> = criterion::BenchmarkGroup<'a, criterion::measurement::WallTime>;
@@ -377,3 +398,42 @@ fn multiple_archetype_none_changed_detection(criterion: &mut Criterion) {
         }
     }
 }
+
+// Measures the cost of marking components as changed, which also records the latest change tick
+// of their table column or archetype for `ArchetypeChanged` when they track archetype changes.
+fn mutable_deref_generic<T: Component<Mutability = Mutable> + Default + BenchModify>(
+    group: &mut BenchGroup,
+    entity_count: u32,
+) {
+    group.bench_function(
+        format!("{}_entities_{}", entity_count, core::any::type_name::<T>()),
+        |bencher| {
+            let mut world = setup::<T>(entity_count);
+            let mut query = world.query::<&mut T>();
+            bencher.iter(|| {
+                world.increment_change_tick();
+                for mut component in query.iter_mut(&mut world) {
+                    black_box(component.bench_modify());
+                }
+            });
+        },
+    );
+}
+
+fn mutable_deref(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("mutable_deref");
+    group.warm_up_time(core::time::Duration::from_millis(500));
+    group.measurement_time(core::time::Duration::from_secs(4));
+    for &entity_count in ENTITIES_TO_BENCH_COUNT {
+        generic_bench(
+            &mut group,
+            vec![
+                Box::new(mutable_deref_generic::<Table>),
+                Box::new(mutable_deref_generic::<Sparse>),
+                Box::new(mutable_deref_generic::<TrackedTable>),
+                Box::new(mutable_deref_generic::<TrackedSparse>),
+            ],
+            entity_count,
+        );
+    }
+}
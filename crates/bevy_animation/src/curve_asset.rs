@@ -0,0 +1,136 @@
+//! Splines stored as assets, for paths, camera rails and easing curves authored as data.
+
+use std::io;
+
+use bevy_asset::{io::Reader, Asset, AssetLoader, LoadContext};
+use bevy_math::{
+    cubic_splines::{CubicCurve, CubicGenerator, Spline, SplineError},
+    curve::{Curve, Interval},
+    Vec3,
+};
+use bevy_reflect::{Reflect, TypePath};
+use ron::de::SpannedError;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// A [`Spline`] loaded as an asset, along with the [`CubicCurve`] it evaluates to.
+///
+/// Curve assets are loaded from `.curve.ron` files containing a serialized [`Spline<Vec3>`]:
+///
+/// ```ron
+/// (
+///     kind: CatmullRom,
+///     control_points: [(0.0, 0.0, 0.0), (2.0, 1.0, 0.0), (4.0, 0.0, 2.0)],
+///     cyclic: false,
+/// )
+/// ```
+///
+/// The asset is a [`Curve<Vec3>`] whose domain goes from 0 to the number of segments of the curve.
+/// 2D paths can leave the `z` coordinate at zero. To tweak a path visually, draw its
+/// [`spline`](CurveAsset::spline) with the `Gizmos::spline` method of `bevy_gizmos`.
+#[derive(Asset, Reflect, Clone, Debug)]
+#[reflect(Clone, Debug)]
+pub struct CurveAsset {
+    spline: Spline<Vec3>,
+    curve: CubicCurve<Vec3>,
+}
+
+impl CurveAsset {
+    /// Creates a curve asset from `spline`, failing if it doesn't have enough control points.
+    pub fn new(spline: Spline<Vec3>) -> Result<Self, SplineError> {
+        let curve = spline.to_curve()?;
+        Ok(Self { spline, curve })
+    }
+
+    /// The spline defining this curve.
+    pub fn spline(&self) -> &Spline<Vec3> {
+        &self.spline
+    }
+
+    /// The cubic curve evaluated from the spline.
+    pub fn curve(&self) -> &CubicCurve<Vec3> {
+        &self.curve
+    }
+}
+
+impl Curve<Vec3> for CurveAsset {
+    #[inline]
+    fn domain(&self) -> Interval {
+        self.curve.domain()
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> Vec3 {
+        self.curve.sample_unchecked(t)
+    }
+}
+
+/// Loads [`CurveAsset`]s from `.curve.ron` files.
+#[derive(Default, TypePath)]
+pub struct CurveAssetLoader;
+
+/// Errors that can occur when loading [`CurveAsset`]s.
+#[derive(Error, Debug)]
+pub enum CurveAssetLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An error occurred in RON deserialization, and the location of the error is supplied.
+    #[error(transparent)]
+    SpannedRon(#[from] SpannedError),
+    /// The deserialized spline couldn't be evaluated.
+    #[error(transparent)]
+    Spline(#[from] SplineError),
+}
+
+impl AssetLoader for CurveAssetLoader {
+    type Asset = CurveAsset;
+
+    type Settings = ();
+
+    type Error = CurveAssetLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        let spline = Spline::<Vec3>::deserialize(&mut deserializer)
+            .map_err(|err| deserializer.span_error(err))?;
+        Ok(CurveAsset::new(spline)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["curve.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{
+        cubic_splines::{Spline, SplineKind},
+        curve::Curve,
+        Vec3,
+    };
+
+    use super::CurveAsset;
+
+    #[test]
+    fn curve_asset_from_ron() {
+        let spline: Spline<Vec3> = ron::from_str(
+            "(kind: Linear, control_points: [(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 4.0, 0.0)], cyclic: true)",
+        )
+        .unwrap();
+        assert_eq!(spline.kind, SplineKind::Linear);
+
+        let curve = CurveAsset::new(spline).unwrap();
+        assert_eq!(curve.domain().end(), 3.0);
+        assert_eq!(curve.sample(0.5), Some(Vec3::new(1.0, 0.0, 0.0)));
+        assert_eq!(curve.sample(3.0), Some(Vec3::ZERO));
+    }
+}
@@ -11,10 +11,12 @@ extern crate alloc;
 
 pub mod animatable;
 pub mod animation_curves;
+pub mod curve_asset;
 pub mod gltf_curves;
 pub mod graph;
 #[cfg(feature = "bevy_mesh")]
 mod morph;
+pub mod state_machine;
 pub mod transition;
 
 mod animation_event;
@@ -57,14 +59,16 @@ use uuid::Uuid;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        animatable::*, animation_curves::*, graph::*, transition::*, AnimationClip,
-        AnimationPlayer, AnimationPlugin, VariableCurve,
+        animatable::*, animation_curves::*, curve_asset::CurveAsset, graph::*, state_machine::*,
+        transition::*, AnimationClip, AnimationPlayer, AnimationPlugin, VariableCurve,
     };
 }
 
 use crate::{
     animation_curves::AnimationCurve,
+    curve_asset::{CurveAsset, CurveAssetLoader},
     graph::{AnimationGraph, AnimationGraphAssetLoader, AnimationNodeIndex},
+    state_machine::{advance_state_machines, AnimationStateMachine, AnimationStateMachineLoader},
     transition::{advance_transitions, expire_completed_transitions},
 };
 use alloc::sync::Arc;
@@ -1284,14 +1288,21 @@ impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<AnimationClip>()
             .init_asset::<AnimationGraph>()
+            .init_asset::<CurveAsset>()
+            .init_asset::<AnimationStateMachine>()
             .init_asset_loader::<AnimationGraphAssetLoader>()
+            .init_asset_loader::<CurveAssetLoader>()
+            .init_asset_loader::<AnimationStateMachineLoader>()
             .register_asset_reflect::<AnimationClip>()
             .register_asset_reflect::<AnimationGraph>()
+            .register_asset_reflect::<CurveAsset>()
+            .register_asset_reflect::<AnimationStateMachine>()
             .init_resource::<ThreadedAnimationGraphs>()
             .add_systems(
                 PostUpdate,
                 (
                     graph::thread_animation_graphs.before(AssetEventSystems),
+                    advance_state_machines,
                     advance_transitions,
                     advance_animations,
                     // TODO: `animate_targets` can animate anything, so
@@ -0,0 +1,682 @@
+//! Animation state machines, which play the states of an [`AnimationGraph`] and crossfade between
+//! them according to parameters set by the application.
+//!
+//! [`AnimationGraph`]: crate::graph::AnimationGraph
+
+use std::io;
+
+use bevy_asset::{io::Reader, Asset, AssetLoader, Assets, Handle, LoadContext};
+use bevy_ecs::{
+    component::Component,
+    reflect::ReflectComponent,
+    system::{Query, Res},
+};
+use bevy_math::Vec2;
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath};
+use bevy_time::Time;
+use ron::de::SpannedError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{graph::AnimationNodeIndex, ActiveAnimation, AnimationPlayer};
+
+/// A set of named states, each playing nodes of an [`AnimationGraph`], and the transitions between
+/// them.
+///
+/// State machines are played by an [`AnimationStateMachinePlayer`] on the entity of the
+/// [`AnimationPlayer`] and [`AnimationGraphHandle`] playing the graph. The first state is the
+/// initial state.
+///
+/// State machines are loaded from `.animstates.ron` files by the [`AnimationStateMachineLoader`]:
+///
+/// ```ron
+/// (
+///     states: [
+///         (
+///             name: "locomotion",
+///             motion: BlendSpace1d(parameter: "speed", points: [(0.0, 1), (2.0, 2), (6.0, 3)]),
+///         ),
+///         (name: "jump", motion: Node(4), repeat: false),
+///     ],
+///     transitions: [
+///         (from: Some("locomotion"), to: "jump", crossfade: 0.1, condition: Trigger("jump")),
+///         (from: Some("jump"), to: "locomotion", crossfade: 0.2, condition: Finished),
+///     ],
+/// )
+/// ```
+///
+/// [`AnimationGraph`]: crate::graph::AnimationGraph
+/// [`AnimationGraphHandle`]: crate::graph::AnimationGraphHandle
+#[derive(Asset, Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Clone, Debug, Default)]
+pub struct AnimationStateMachine {
+    /// The states of the machine.
+    pub states: Vec<AnimationState>,
+    /// The transitions between the states, evaluated in order. At most one transition is taken per
+    /// frame.
+    #[serde(default)]
+    pub transitions: Vec<AnimationStateTransition>,
+}
+
+/// A state of an [`AnimationStateMachine`].
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct AnimationState {
+    /// The name of the state, referenced by the transitions.
+    pub name: String,
+    /// The graph nodes played in this state.
+    pub motion: AnimationStateMotion,
+    /// The playback speed of the nodes of this state.
+    ///
+    /// Defaults to 1.0.
+    #[serde(default = "default_speed")]
+    pub speed: f32,
+    /// Whether the nodes of this state repeat forever, or play once from the start every time the
+    /// state is entered.
+    ///
+    /// Defaults to true.
+    #[serde(default = "default_repeat")]
+    pub repeat: bool,
+}
+
+fn default_speed() -> f32 {
+    1.0
+}
+
+fn default_repeat() -> bool {
+    true
+}
+
+/// The graph nodes played by an [`AnimationState`], and how they are weighted.
+///
+/// The nodes can be clip nodes, or blend and add nodes whose children are then blended as usual.
+/// All the nodes of a blend space keep playing while the state is active, even with a weight of
+/// zero, so that they stay in phase.
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub enum AnimationStateMotion {
+    /// Plays a single node.
+    Node(AnimationNodeIndex),
+    /// Blends the nodes at positions along an axis, according to a float parameter.
+    ///
+    /// The two nodes surrounding the parameter are linearly blended. Outside of the positions, the
+    /// nearest node plays alone.
+    BlendSpace1d {
+        /// The name of the float parameter.
+        parameter: String,
+        /// The positions of the nodes on the axis.
+        points: Vec<(f32, AnimationNodeIndex)>,
+    },
+    /// Blends the nodes at positions on a plane, according to a [`Vec2`] parameter, for example
+    /// the velocity of a character in its local space.
+    ///
+    /// The weights are computed with gradient band interpolation: a node plays alone when the
+    /// parameter is at its position, and fades out as the parameter moves towards the other
+    /// positions.
+    BlendSpace2d {
+        /// The name of the [`Vec2`] parameter.
+        parameter: String,
+        /// The positions of the nodes on the plane.
+        points: Vec<(Vec2, AnimationNodeIndex)>,
+    },
+}
+
+/// A transition between two states of an [`AnimationStateMachine`].
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct AnimationStateTransition {
+    /// The name of the state the transition leaves, or `None` to leave any state other than
+    /// [`to`](Self::to).
+    #[serde(default)]
+    pub from: Option<String>,
+    /// The name of the state the transition enters.
+    pub to: String,
+    /// How long the previous state fades out for, in seconds.
+    #[serde(default)]
+    pub crossfade: f32,
+    /// The condition under which the transition is taken.
+    pub condition: AnimationStateCondition,
+}
+
+/// The condition of an [`AnimationStateTransition`], based on the parameters of the
+/// [`AnimationStateMachinePlayer`].
+///
+/// Missing float parameters are zero, and missing bool parameters are false.
+#[derive(Reflect, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[reflect(Clone, Debug, PartialEq)]
+pub enum AnimationStateCondition {
+    /// The trigger is set. Taking the transition resets the trigger.
+    Trigger(String),
+    /// The bool parameter has the value.
+    Bool(String, bool),
+    /// The float parameter is greater than the value.
+    Greater(String, f32),
+    /// The float parameter is less than the value.
+    Less(String, f32),
+    /// All the nodes of the state have finished playing, which never happens for repeating
+    /// states.
+    Finished,
+}
+
+impl AnimationStateMachine {
+    /// Returns the index in [`states`](Self::states) of the state named `name`.
+    pub fn state_index(&self, name: &str) -> Option<usize> {
+        self.states.iter().position(|state| state.name == name)
+    }
+
+    /// Returns the name of a state referenced by a transition that doesn't exist, if any.
+    pub fn find_unknown_state(&self) -> Option<&str> {
+        self.transitions
+            .iter()
+            .flat_map(|transition| transition.from.iter().chain([&transition.to]))
+            .find(|name| self.state_index(name).is_none())
+            .map(String::as_str)
+    }
+}
+
+impl AnimationStateMotion {
+    /// Calls `f` with each node of the motion and its weight, given the `parameters` of the state
+    /// machine.
+    ///
+    /// The weights of blend spaces add up to 1.
+    pub fn for_each_weight(
+        &self,
+        parameters: &AnimationStateMachinePlayer,
+        mut f: impl FnMut(AnimationNodeIndex, f32),
+    ) {
+        match self {
+            AnimationStateMotion::Node(node) => f(*node, 1.0),
+            AnimationStateMotion::BlendSpace1d { parameter, points } => {
+                let weights = blend_space_1d_weights(points, parameters.float(parameter));
+                for (&(_, node), weight) in points.iter().zip(weights) {
+                    f(node, weight);
+                }
+            }
+            AnimationStateMotion::BlendSpace2d { parameter, points } => {
+                let weights = blend_space_2d_weights(points, parameters.vec2(parameter));
+                for (&(_, node), weight) in points.iter().zip(weights) {
+                    f(node, weight);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the weight of each point of a 1D blend space for the parameter `value`.
+fn blend_space_1d_weights(points: &[(f32, AnimationNodeIndex)], value: f32) -> Vec<f32> {
+    let mut weights = vec![0.0; points.len()];
+    // The nearest positions below and above the value.
+    let mut below: Option<usize> = None;
+    let mut above: Option<usize> = None;
+    for (index, &(position, _)) in points.iter().enumerate() {
+        if position <= value && below.is_none_or(|below| points[below].0 < position) {
+            below = Some(index);
+        }
+        if position >= value && above.is_none_or(|above| points[above].0 > position) {
+            above = Some(index);
+        }
+    }
+    match (below, above) {
+        (Some(below), Some(above)) if points[below].0 < points[above].0 => {
+            let t = (value - points[below].0) / (points[above].0 - points[below].0);
+            weights[below] = 1.0 - t;
+            weights[above] = t;
+        }
+        (Some(index), _) | (None, Some(index)) => weights[index] = 1.0,
+        (None, None) => {}
+    }
+    weights
+}
+
+/// Returns the weight of each point of a 2D blend space for the parameter `value`, with gradient
+/// band interpolation.
+fn blend_space_2d_weights(points: &[(Vec2, AnimationNodeIndex)], value: Vec2) -> Vec<f32> {
+    let mut weights: Vec<f32> = points
+        .iter()
+        .enumerate()
+        .map(|(index, &(position, _))| {
+            points
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, &(other_position, _))| {
+                    let band = other_position - position;
+                    let length_squared = band.length_squared();
+                    if length_squared == 0.0 {
+                        return 1.0;
+                    }
+                    (1.0 - (value - position).dot(band) / length_squared).clamp(0.0, 1.0)
+                })
+                .fold(1.0, f32::min)
+        })
+        .collect();
+    let total: f32 = weights.iter().sum();
+    if total > 0.0 {
+        for weight in &mut weights {
+            *weight /= total;
+        }
+    }
+    weights
+}
+
+/// Plays an [`AnimationStateMachine`] on the [`AnimationPlayer`] of the same entity.
+///
+/// The application controls the state machine by setting its parameters, which drive the blend
+/// spaces and the conditions of the transitions. [`play`](Self::play) enters a state directly.
+///
+/// The player manages the weights of the nodes played by the state machine, so these nodes
+/// shouldn't also be played through the [`AnimationPlayer`] or an
+/// [`AnimationTransitions`](crate::transition::AnimationTransitions) component.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default, Clone, Debug)]
+pub struct AnimationStateMachinePlayer {
+    /// The state machine to play.
+    pub state_machine: Handle<AnimationStateMachine>,
+    floats: HashMap<String, f32>,
+    vectors: HashMap<String, Vec2>,
+    bools: HashMap<String, bool>,
+    triggers: HashSet<String>,
+    /// The index of the current state, or `None` before the state machine is loaded.
+    current: Option<usize>,
+    /// A state requested with `play`, and its crossfade.
+    requested: Option<(String, f32)>,
+    /// The states fading out, from the oldest to the most recent.
+    fading: Vec<FadingAnimationState>,
+    /// The weight of the current state in the last update.
+    current_weight: f32,
+    /// The nodes currently played by the state machine.
+    nodes: HashSet<AnimationNodeIndex>,
+}
+
+/// A state that is being faded out as part of a transition.
+#[derive(Clone, Copy, Debug, Reflect)]
+#[reflect(Clone, Debug)]
+struct FadingAnimationState {
+    state: usize,
+    /// The current weight. Starts at the weight the state had when it was left, and goes to 0.0
+    /// during the fade-out.
+    weight: f32,
+    /// How much to decrease `weight` per second.
+    weight_decline_per_sec: f32,
+}
+
+impl AnimationStateMachinePlayer {
+    /// Creates a player of `state_machine`, starting in its first state.
+    pub fn new(state_machine: Handle<AnimationStateMachine>) -> Self {
+        Self {
+            state_machine,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the value of a float parameter, or zero if it isn't set.
+    pub fn float(&self, name: &str) -> f32 {
+        self.floats.get(name).copied().unwrap_or_default()
+    }
+
+    /// Sets the value of a float parameter.
+    pub fn set_float(&mut self, name: impl Into<String>, value: f32) -> &mut Self {
+        self.floats.insert(name.into(), value);
+        self
+    }
+
+    /// Returns the value of a [`Vec2`] parameter, or zero if it isn't set.
+    pub fn vec2(&self, name: &str) -> Vec2 {
+        self.vectors.get(name).copied().unwrap_or_default()
+    }
+
+    /// Sets the value of a [`Vec2`] parameter.
+    pub fn set_vec2(&mut self, name: impl Into<String>, value: Vec2) -> &mut Self {
+        self.vectors.insert(name.into(), value);
+        self
+    }
+
+    /// Returns the value of a bool parameter, or false if it isn't set.
+    pub fn bool(&self, name: &str) -> bool {
+        self.bools.get(name).copied().unwrap_or_default()
+    }
+
+    /// Sets the value of a bool parameter.
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) -> &mut Self {
+        self.bools.insert(name.into(), value);
+        self
+    }
+
+    /// Sets a trigger, taking the first transition with this trigger from the current state.
+    ///
+    /// Triggers are reset when the state machine is next updated, whether they caused a
+    /// transition or not.
+    pub fn set_trigger(&mut self, name: impl Into<String>) -> &mut Self {
+        self.triggers.insert(name.into());
+        self
+    }
+
+    /// Enters the state named `state` when the state machine is next updated, fading out the
+    /// current state for `crossfade` seconds.
+    pub fn play(&mut self, state: impl Into<String>, crossfade: f32) -> &mut Self {
+        self.requested = Some((state.into(), crossfade));
+        self
+    }
+
+    /// Returns the index of the current state in [`AnimationStateMachine::states`], or `None` if
+    /// the state machine isn't loaded yet.
+    pub fn current_state(&self) -> Option<usize> {
+        self.current
+    }
+
+    fn is_met(
+        &self,
+        condition: &AnimationStateCondition,
+        state: &AnimationState,
+        player: &AnimationPlayer,
+    ) -> bool {
+        match condition {
+            AnimationStateCondition::Trigger(name) => self.triggers.contains(name),
+            AnimationStateCondition::Bool(name, value) => self.bool(name) == *value,
+            AnimationStateCondition::Greater(name, value) => self.float(name) > *value,
+            AnimationStateCondition::Less(name, value) => self.float(name) < *value,
+            AnimationStateCondition::Finished => {
+                let mut finished = true;
+                state.motion.for_each_weight(self, |node, _| {
+                    finished &= player
+                        .animation(node)
+                        .is_none_or(ActiveAnimation::is_finished);
+                });
+                finished
+            }
+        }
+    }
+
+    /// Enters the state at index `state`, fading out the current state for `crossfade` seconds.
+    fn enter(&mut self, state: usize, crossfade: f32) {
+        if let Some(previous) = self.current.replace(state)
+            && previous != state
+        {
+            self.fading.retain(|fading| fading.state != state);
+            if crossfade > 0.0 {
+                self.fading.push(FadingAnimationState {
+                    state: previous,
+                    weight: self.current_weight,
+                    weight_decline_per_sec: 1.0 / crossfade,
+                });
+            }
+        }
+    }
+}
+
+/// A system that takes the transitions of the [`AnimationStateMachinePlayer`]s, and updates the
+/// animations of their [`AnimationPlayer`]s.
+pub fn advance_state_machines(
+    mut query: Query<(&mut AnimationStateMachinePlayer, &mut AnimationPlayer)>,
+    state_machines: Res<Assets<AnimationStateMachine>>,
+    time: Res<Time>,
+) {
+    for (mut machine_player, mut player) in query.iter_mut() {
+        let Some(state_machine) = state_machines.get(&machine_player.state_machine) else {
+            continue;
+        };
+        let machine_player = &mut *machine_player;
+        if state_machine.states.is_empty() {
+            continue;
+        }
+
+        // Take a transition.
+        let previous = machine_player.current;
+        let current = previous
+            .unwrap_or_default()
+            .min(state_machine.states.len() - 1);
+        machine_player.current = Some(current);
+        if let Some((name, crossfade)) = machine_player.requested.take() {
+            if let Some(state) = state_machine.state_index(&name) {
+                machine_player.enter(state, crossfade);
+            }
+        } else {
+            let current_state = &state_machine.states[current];
+            let transition = state_machine.transitions.iter().find(|transition| {
+                let leaves_current = match &transition.from {
+                    Some(from) => *from == current_state.name,
+                    None => transition.to != current_state.name,
+                };
+                leaves_current
+                    && machine_player.is_met(&transition.condition, current_state, &player)
+            });
+            if let Some(transition) = transition
+                && let Some(state) = state_machine.state_index(&transition.to)
+            {
+                if let AnimationStateCondition::Trigger(name) = &transition.condition {
+                    machine_player.triggers.remove(name);
+                }
+                machine_player.enter(state, transition.crossfade);
+            }
+        }
+        machine_player.triggers.clear();
+        let current = machine_player.current.unwrap();
+        let entered = previous != Some(current);
+
+        // Fade out the previous states, using the same "greedy layer" system as
+        // `advance_transitions`: the most recent fading state gets as much weight as it wants, and
+        // the current state receives whatever's left.
+        let delta = time.delta_secs();
+        for fading in &mut machine_player.fading {
+            fading.weight = (fading.weight - fading.weight_decline_per_sec * delta).max(0.0);
+        }
+        machine_player.fading.retain(|fading| fading.weight > 0.0);
+
+        let mut weights: HashMap<AnimationNodeIndex, (f32, usize)> = HashMap::default();
+        let mut remaining_weight = 1.0;
+        let layers = machine_player
+            .fading
+            .iter()
+            .rev()
+            .map(|fading| (fading.state, fading.weight))
+            .chain([(current, 1.0)]);
+        for (state, weight) in layers {
+            let state_weight = weight * remaining_weight;
+            remaining_weight -= state_weight;
+            if state == current {
+                machine_player.current_weight = state_weight;
+            }
+            state_machine.states[state]
+                .motion
+                .for_each_weight(machine_player, |node, weight| {
+                    // Nodes shared between states play with the settings of the most recent
+                    // one.
+                    let entry = weights.entry(node).or_default();
+                    *entry = (entry.0 + weight * state_weight, state);
+                });
+        }
+
+        // Stop the nodes which aren't played anymore.
+        for node in machine_player.nodes.iter() {
+            if !weights.contains_key(node) {
+                player.stop(*node);
+            }
+        }
+        machine_player.nodes.clear();
+
+        for (node, (weight, state_index)) in weights {
+            let state = &state_machine.states[state_index];
+            // Non-repeating states play from the start every time they are entered.
+            let animation = if entered && !state.repeat && state_index == current {
+                player.start(node)
+            } else {
+                player.play(node)
+            };
+            animation.set_weight(weight).set_speed(state.speed);
+            if state.repeat {
+                animation.repeat();
+            }
+            machine_player.nodes.insert(node);
+        }
+    }
+}
+
+/// Loads [`AnimationStateMachine`]s from `.animstates.ron` files.
+#[derive(Default, TypePath)]
+pub struct AnimationStateMachineLoader;
+
+/// Errors that can occur when loading [`AnimationStateMachine`]s.
+#[derive(Error, Debug)]
+pub enum AnimationStateMachineLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An error occurred in RON deserialization, and the location of the error is supplied.
+    #[error(transparent)]
+    SpannedRon(#[from] SpannedError),
+    /// A transition references a state that doesn't exist.
+    #[error("A transition references the unknown state `{0}`")]
+    UnknownState(String),
+}
+
+impl AssetLoader for AnimationStateMachineLoader {
+    type Asset = AnimationStateMachine;
+
+    type Settings = ();
+
+    type Error = AnimationStateMachineLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        let state_machine = AnimationStateMachine::deserialize(&mut deserializer)
+            .map_err(|err| deserializer.span_error(err))?;
+        if let Some(name) = state_machine.find_unknown_state() {
+            return Err(AnimationStateMachineLoadError::UnknownState(name.into()));
+        }
+        Ok(state_machine)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["animstates.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use core::time::Duration;
+
+    use super::*;
+
+    fn node(index: u32) -> AnimationNodeIndex {
+        AnimationNodeIndex::new(index as usize)
+    }
+
+    #[test]
+    fn blend_space_weights() {
+        let points = [(0.0, node(1)), (2.0, node(2)), (6.0, node(3))];
+        assert_eq!(blend_space_1d_weights(&points, 1.0), [0.5, 0.5, 0.0]);
+        assert_eq!(blend_space_1d_weights(&points, 4.0), [0.0, 0.5, 0.5]);
+        assert_eq!(blend_space_1d_weights(&points, 2.0), [0.0, 1.0, 0.0]);
+        assert_eq!(blend_space_1d_weights(&points, -1.0), [1.0, 0.0, 0.0]);
+        assert_eq!(blend_space_1d_weights(&points, 10.0), [0.0, 0.0, 1.0]);
+
+        let points = [
+            (Vec2::ZERO, node(1)),
+            (Vec2::X, node(2)),
+            (Vec2::NEG_X, node(3)),
+            (Vec2::Y, node(4)),
+            (Vec2::NEG_Y, node(5)),
+        ];
+        assert_eq!(
+            blend_space_2d_weights(&points, Vec2::ZERO),
+            [1.0, 0.0, 0.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            blend_space_2d_weights(&points, Vec2::new(0.5, 0.0)),
+            [0.5, 0.5, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn state_machine_from_ron() {
+        let state_machine: AnimationStateMachine = ron::from_str(
+            r#"(
+                states: [(name: "idle", motion: Node(1)), (name: "jump", motion: Node(2), repeat: false)],
+                transitions: [(to: "jump", crossfade: 0.5, condition: Trigger("jump"))],
+            )"#,
+        )
+        .unwrap();
+        assert_eq!(state_machine.state_index("jump"), Some(1));
+        assert_eq!(state_machine.states[0].speed, 1.0);
+        assert!(state_machine.states[0].repeat);
+        assert!(!state_machine.states[1].repeat);
+        assert_eq!(state_machine.transitions[0].from, None);
+        assert_eq!(state_machine.find_unknown_state(), None);
+
+        let mut broken = state_machine.clone();
+        broken.transitions[0].to = "fall".into();
+        assert_eq!(broken.find_unknown_state(), Some("fall"));
+    }
+
+    #[test]
+    fn transitions_crossfade_states() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let mut state_machines = Assets::<AnimationStateMachine>::default();
+        let state_machine = state_machines.add(AnimationStateMachine {
+            states: vec![
+                AnimationState {
+                    name: "idle".into(),
+                    motion: AnimationStateMotion::Node(node(1)),
+                    speed: 1.0,
+                    repeat: true,
+                },
+                AnimationState {
+                    name: "jump".into(),
+                    motion: AnimationStateMotion::Node(node(2)),
+                    speed: 1.0,
+                    repeat: false,
+                },
+            ],
+            transitions: vec![AnimationStateTransition {
+                from: Some("idle".into()),
+                to: "jump".into(),
+                crossfade: 0.5,
+                condition: AnimationStateCondition::Trigger("jump".into()),
+            }],
+        });
+        world.insert_resource(state_machines);
+        let entity = world
+            .spawn((
+                AnimationPlayer::default(),
+                AnimationStateMachinePlayer::new(state_machine),
+            ))
+            .id();
+
+        let advance = |world: &mut World, secs: f32| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(secs));
+            world.run_system_once(advance_state_machines).unwrap();
+            let player = world.get::<AnimationPlayer>(entity).unwrap();
+            [node(1), node(2)].map(|index| player.animation(index).map(ActiveAnimation::weight))
+        };
+
+        assert_eq!(advance(&mut world, 0.1), [Some(1.0), None]);
+
+        world
+            .get_mut::<AnimationStateMachinePlayer>(entity)
+            .unwrap()
+            .set_trigger("jump");
+        assert_eq!(advance(&mut world, 0.25), [Some(0.5), Some(0.5)]);
+        assert_eq!(
+            world
+                .get::<AnimationStateMachinePlayer>(entity)
+                .unwrap()
+                .current_state(),
+            Some(1)
+        );
+
+        assert_eq!(advance(&mut world, 0.5), [None, Some(1.0)]);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp, SubApps,
+    DisabledPlugins, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState,
+    SubApp, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -122,6 +123,7 @@ impl Default for App {
         app.init_resource::<AppFunctionRegistry>();
 
         app.add_plugins(MainSchedulePlugin);
+        app.init_resource::<DisabledPlugins>();
         app.add_systems(
             crate::Last,
             bevy_ecs::system::despawn_unused_registered_systems,
@@ -260,6 +262,7 @@ impl App {
     pub fn finish(&mut self) {
         #[cfg(feature = "trace")]
         let _finish_span = info_span!("plugin finish").entered();
+        self.main().check_plugin_dependencies();
         // plugins installed to main should see all sub-apps
         // do hokey pokey with a boxed zst plugin (doesn't allocate)
         let mut hokeypokey: Box<dyn Plugin> = Box::new(HokeyPokey);
@@ -536,6 +539,7 @@ impl App {
     pub(crate) fn add_boxed_plugin(
         &mut self,
         plugin: Box<dyn Plugin>,
+        type_name: &'static str,
     ) -> Result<&mut Self, AppError> {
         debug!("added plugin: {}", plugin.name());
         if plugin.is_unique() && self.main_mut().plugin_names.contains(plugin.name()) {
@@ -567,6 +571,7 @@ impl App {
         self.main_mut()
             .plugin_names
             .insert(plugin.name().to_string());
+        self.main_mut().plugin_type_names.insert(type_name);
         self.main_mut().plugin_build_depth -= 1;
 
         #[cfg(feature = "std")]
@@ -1635,7 +1640,9 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{
+        plugin_enabled, App, AppExit, DisabledPlugins, Plugin, PluginDependencies, SubApp, Update,
+    };
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1738,6 +1745,115 @@ mod tests {
         App::new().add_plugins(PluginRun);
     }
 
+    struct DependentPlugin;
+    impl Plugin for DependentPlugin {
+        fn build(&self, _app: &mut App) {}
+
+        fn dependencies(&self, dependencies: &mut PluginDependencies) {
+            dependencies
+                .requires::<PluginA>()
+                .conflicts_with::<PluginB>();
+        }
+    }
+
+    #[test]
+    fn plugin_dependencies_can_be_added_in_any_order() {
+        let mut app = App::new();
+        app.add_plugins((DependentPlugin, PluginA));
+        app.finish();
+    }
+
+    #[test]
+    fn plugin_dependencies_on_renamed_and_non_unique_plugins() {
+        struct RenamedPlugin;
+        impl Plugin for RenamedPlugin {
+            fn build(&self, _app: &mut App) {}
+
+            fn name(&self) -> &str {
+                "Renamed"
+            }
+        }
+
+        struct NonUniquePlugin;
+        impl Plugin for NonUniquePlugin {
+            fn build(&self, _app: &mut App) {}
+
+            fn is_unique(&self) -> bool {
+                false
+            }
+        }
+
+        struct RequiringPlugin;
+        impl Plugin for RequiringPlugin {
+            fn build(&self, _app: &mut App) {}
+
+            fn dependencies(&self, dependencies: &mut PluginDependencies) {
+                dependencies
+                    .requires::<RenamedPlugin>()
+                    .requires::<NonUniquePlugin>()
+                    .requires_name("Renamed");
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins((
+            RequiringPlugin,
+            RenamedPlugin,
+            NonUniquePlugin,
+            NonUniquePlugin,
+        ));
+        app.finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "which has not been added to the app")]
+    fn plugin_dependencies_missing_required() {
+        let mut app = App::new();
+        app.add_plugins(DependentPlugin);
+        app.finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "which has also been added to the app")]
+    fn plugin_dependencies_conflict() {
+        let mut app = App::new();
+        app.add_plugins((PluginA, PluginB, DependentPlugin));
+        app.finish();
+    }
+
+    #[test]
+    fn disabled_plugin_systems_do_not_run() {
+        #[derive(Resource, Default)]
+        struct Runs(u32);
+
+        struct CountingPlugin;
+        impl Plugin for CountingPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<Runs>().add_systems(
+                    Update,
+                    (|mut runs: ResMut<Runs>| runs.0 += 1).run_if(plugin_enabled::<Self>()),
+                );
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(CountingPlugin);
+        app.update();
+        assert_eq!(app.world().resource::<Runs>().0, 1);
+
+        app.world_mut()
+            .resource_mut::<DisabledPlugins>()
+            .disable::<CountingPlugin>();
+        app.update();
+        assert_eq!(app.world().resource::<Runs>().0, 1);
+
+        app.world_mut()
+            .resource_mut::<DisabledPlugins>()
+            .toggle::<CountingPlugin>();
+        app.update();
+        assert_eq!(app.world().resource::<Runs>().0, 2);
+    }
+
     #[derive(ScheduleLabel, Hash, Clone, PartialEq, Eq, Debug)]
     struct EnterMainMenu;
 
@@ -0,0 +1,102 @@
+use alloc::string::{String, ToString};
+use bevy_ecs::{resource::Resource, system::Res};
+use bevy_platform::collections::HashSet;
+
+use crate::Plugin;
+
+/// The plugins whose systems are currently disabled.
+///
+/// Plugins are identified by their type name, as given by [`core::any::type_name`]. This is also
+/// the default [`name()`](Plugin::name) of a plugin, but a plugin overriding [`Plugin::name`] is
+/// still identified by its type name by [`disable`](Self::disable), [`plugin_enabled`] and the
+/// other generic methods, as they have no plugin instance to call it on.
+///
+/// Disabling a plugin does not remove anything it added to the app, it only stops systems that
+/// use the [`plugin_enabled`] run condition from running. This lets plugins such as debug
+/// overlays be turned off and on again at runtime, for example from a settings menu.
+///
+/// This resource is added by [`App::new`](crate::App::new).
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_app::{plugin_enabled, DisabledPlugins};
+/// # use bevy_ecs::prelude::*;
+/// pub struct DebugOverlayPlugin;
+///
+/// impl Plugin for DebugOverlayPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.add_systems(Update, draw_overlay.run_if(plugin_enabled::<Self>()));
+///     }
+/// }
+///
+/// fn draw_overlay() {}
+///
+/// fn toggle_overlay(mut disabled: ResMut<DisabledPlugins>) {
+///     disabled.toggle::<DebugOverlayPlugin>();
+/// }
+/// # bevy_ecs::system::assert_is_system(toggle_overlay);
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct DisabledPlugins {
+    names: HashSet<String>,
+}
+
+impl DisabledPlugins {
+    /// Disables the systems of the plugin `P`.
+    pub fn disable<P: Plugin>(&mut self) {
+        self.disable_by_name(core::any::type_name::<P>());
+    }
+
+    /// Re-enables the systems of the plugin `P`.
+    pub fn enable<P: Plugin>(&mut self) {
+        self.enable_by_name(core::any::type_name::<P>());
+    }
+
+    /// Disables the systems of the plugin `P` if they are enabled, and re-enables them otherwise.
+    pub fn toggle<P: Plugin>(&mut self) {
+        self.set_enabled::<P>(!self.is_enabled::<P>());
+    }
+
+    /// Enables or disables the systems of the plugin `P`.
+    pub fn set_enabled<P: Plugin>(&mut self, enabled: bool) {
+        if enabled {
+            self.enable::<P>();
+        } else {
+            self.disable::<P>();
+        }
+    }
+
+    /// Returns `true` if the plugin `P` is not disabled.
+    pub fn is_enabled<P: Plugin>(&self) -> bool {
+        self.is_enabled_by_name(core::any::type_name::<P>())
+    }
+
+    /// Disables the systems of the plugin with the given type name.
+    pub fn disable_by_name(&mut self, name: &str) {
+        self.names.insert(name.to_string());
+    }
+
+    /// Re-enables the systems of the plugin with the given type name.
+    pub fn enable_by_name(&mut self, name: &str) {
+        self.names.remove(name);
+    }
+
+    /// Returns `true` if the plugin with the given type name is not disabled.
+    pub fn is_enabled_by_name(&self, name: &str) -> bool {
+        !self.names.contains(name)
+    }
+
+    /// Returns an iterator over the names of all disabled plugins.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+}
+
+/// A run condition that is `true` unless the plugin `P` has been disabled in [`DisabledPlugins`].
+///
+/// If the [`DisabledPlugins`] resource does not exist, all plugins are considered enabled.
+pub fn plugin_enabled<P: Plugin>() -> impl FnMut(Option<Res<DisabledPlugins>>) -> bool + Clone {
+    |disabled: Option<Res<DisabledPlugins>>| {
+        disabled.is_none_or(|disabled| disabled.is_enabled::<P>())
+    }
+}
@@ -0,0 +1,318 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::future::Future;
+
+use bevy_ecs::{
+    bundle::Bundle,
+    component::Component,
+    entity::Entity,
+    event::Event,
+    system::{Commands, EntityCommands, Query},
+    world::{EntityWorldMut, World},
+};
+use bevy_tasks::{futures::check_ready, AsyncComputeTaskPool, Task};
+
+/// Work to apply to the owning entity once a task spawned through [`EntityTaskCommandsExt`]
+/// has completed.
+type TaskCompletion = Box<dyn FnOnce(EntityWorldMut) + Send>;
+
+/// The async tasks that are currently running on behalf of an entity.
+///
+/// This component is added by the methods of [`EntityTaskCommandsExt`] and
+/// [`SpawnTaskCommandsExt`], and is polled every frame in [`PreUpdate`](crate::PreUpdate) by
+/// the [`TaskPoolPlugin`](crate::TaskPoolPlugin). The results of completed tasks are delivered
+/// back to the entity, and the component is removed once no tasks remain.
+///
+/// Tasks are tied to the lifetime of their entity: despawning the entity (or removing this
+/// component) cancels all of its unfinished tasks.
+#[derive(Component, Default)]
+pub struct EntityTasks {
+    tasks: Vec<Task<TaskCompletion>>,
+}
+
+impl EntityTasks {
+    /// Returns the number of tasks that have not been delivered yet.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if there are no tasks left to deliver.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
+
+/// Extension trait for [`EntityCommands`] to run async tasks whose results are delivered back to
+/// the entity.
+///
+/// The futures are spawned on the [`AsyncComputeTaskPool`], so they can perform long-running
+/// work such as pathfinding or procedural generation without blocking the frame.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_app::EntityTaskCommandsExt;
+/// #[derive(Component)]
+/// struct PathResult(Vec<u32>);
+///
+/// #[derive(Component)]
+/// struct Agent;
+///
+/// fn request_paths(mut commands: Commands, agents: Query<Entity, Added<Agent>>) {
+///     for agent in &agents {
+///         commands
+///             .entity(agent)
+///             .insert_when_complete(async move { PathResult(vec![1, 2, 3]) });
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(request_paths);
+/// ```
+pub trait EntityTaskCommandsExt {
+    /// Spawns `future` and inserts its output into the entity once it completes.
+    ///
+    /// The output is only inserted if the entity still exists at that point.
+    fn insert_when_complete<B: Bundle>(
+        &mut self,
+        future: impl Future<Output = B> + Send + 'static,
+    ) -> &mut Self;
+
+    /// Spawns `future` and triggers its output as an [`Event`] once it completes.
+    ///
+    /// The event is only triggered if the entity still exists at that point.
+    fn trigger_when_complete<E>(
+        &mut self,
+        future: impl Future<Output = E> + Send + 'static,
+    ) -> &mut Self
+    where
+        E: Event + Send,
+        for<'a> E::Trigger<'a>: Default;
+
+    /// Spawns `future` and calls `on_complete` with its output and the entity once it completes.
+    ///
+    /// `on_complete` is only called if the entity still exists at that point: despawning the
+    /// entity cancels the task, and drops `on_complete` without calling it.
+    fn run_when_complete<T: Send + 'static>(
+        &mut self,
+        future: impl Future<Output = T> + Send + 'static,
+        on_complete: impl FnOnce(T, EntityWorldMut) + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl EntityTaskCommandsExt for EntityCommands<'_> {
+    fn insert_when_complete<B: Bundle>(
+        &mut self,
+        future: impl Future<Output = B> + Send + 'static,
+    ) -> &mut Self {
+        self.run_when_complete(future, |bundle, mut entity| {
+            entity.insert(bundle);
+        })
+    }
+
+    fn trigger_when_complete<E>(
+        &mut self,
+        future: impl Future<Output = E> + Send + 'static,
+    ) -> &mut Self
+    where
+        E: Event + Send,
+        for<'a> E::Trigger<'a>: Default,
+    {
+        self.run_when_complete(future, |event, entity| {
+            entity.into_world_mut().trigger(event);
+        })
+    }
+
+    fn run_when_complete<T: Send + 'static>(
+        &mut self,
+        future: impl Future<Output = T> + Send + 'static,
+        on_complete: impl FnOnce(T, EntityWorldMut) + Send + 'static,
+    ) -> &mut Self {
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            let output = future.await;
+            Box::new(move |entity: EntityWorldMut| on_complete(output, entity)) as TaskCompletion
+        });
+
+        self.queue(move |mut entity: EntityWorldMut| {
+            if let Some(mut tasks) = entity.get_mut::<EntityTasks>() {
+                tasks.tasks.push(task);
+            } else {
+                entity.insert(EntityTasks { tasks: vec![task] });
+            }
+        })
+    }
+}
+
+/// Extension trait for [`Commands`] to spawn entities that represent an async task.
+pub trait SpawnTaskCommandsExt {
+    /// Spawns a new entity running `future`, and inserts its output into the entity once it
+    /// completes.
+    ///
+    /// Despawning the returned entity before the task completes cancels the task.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_app::SpawnTaskCommandsExt;
+    /// #[derive(Component)]
+    /// struct GeneratedChunk(Vec<u8>);
+    ///
+    /// fn generate(mut commands: Commands) {
+    ///     commands.spawn_task(async move { GeneratedChunk(vec![0; 64]) });
+    /// }
+    /// # bevy_ecs::system::assert_is_system(generate);
+    /// ```
+    fn spawn_task<B: Bundle>(
+        &mut self,
+        future: impl Future<Output = B> + Send + 'static,
+    ) -> EntityCommands<'_>;
+}
+
+impl SpawnTaskCommandsExt for Commands<'_, '_> {
+    fn spawn_task<B: Bundle>(
+        &mut self,
+        future: impl Future<Output = B> + Send + 'static,
+    ) -> EntityCommands<'_> {
+        let mut entity = self.spawn_empty();
+        entity.insert_when_complete(future);
+        entity
+    }
+}
+
+/// Polls all [`EntityTasks`] and delivers the results of completed tasks to their entities.
+pub fn poll_entity_tasks(mut query: Query<(Entity, &mut EntityTasks)>, mut commands: Commands) {
+    for (entity, mut entity_tasks) in &mut query {
+        entity_tasks
+            .tasks
+            .retain_mut(|task| match check_ready(task) {
+                Some(on_complete) => {
+                    // The entity may be despawned before the command is applied, in which case the
+                    // result is dropped without calling the completion.
+                    commands.queue(move |world: &mut World| {
+                        if let Ok(entity) = world.get_entity_mut(entity) {
+                            on_complete(entity);
+                        }
+                    });
+                    false
+                }
+                None => true,
+            });
+
+        if entity_tasks.is_empty() {
+            // Completion callbacks may have spawned new tasks on this entity, so only remove the
+            // component if it is still empty once they have run.
+            commands
+                .entity(entity)
+                .queue_silenced(|mut entity: EntityWorldMut| {
+                    if entity
+                        .get::<EntityTasks>()
+                        .is_some_and(EntityTasks::is_empty)
+                    {
+                        entity.remove::<EntityTasks>();
+                    }
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_ecs::{prelude::*, system::SystemState};
+    use bevy_tasks::futures_lite::future::yield_now;
+
+    use crate::{
+        poll_entity_tasks, App, EntityTaskCommandsExt, EntityTasks, SpawnTaskCommandsExt,
+        TaskPoolPlugin,
+    };
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Output(u32);
+
+    #[derive(Event)]
+    struct Done;
+
+    #[derive(Resource, Default)]
+    struct DoneCount(u32);
+
+    fn update_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+        for _ in 0..1000 {
+            app.update();
+            if done(app) {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("task did not complete in time");
+    }
+
+    #[test]
+    fn spawn_task_inserts_output() {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default());
+
+        let entity = app
+            .world_mut()
+            .commands()
+            .spawn_task(async {
+                yield_now().await;
+                Output(42)
+            })
+            .id();
+        app.world_mut().flush();
+
+        update_until(&mut app, |app| app.world().get::<Output>(entity).is_some());
+
+        assert_eq!(app.world().get::<Output>(entity), Some(&Output(42)));
+        app.update();
+        assert!(app.world().get::<EntityTasks>(entity).is_none());
+    }
+
+    #[test]
+    fn trigger_when_complete() {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default())
+            .init_resource::<DoneCount>()
+            .add_observer(|_: On<Done>, mut count: ResMut<DoneCount>| count.0 += 1);
+
+        let entity = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .entity(entity)
+            .trigger_when_complete(async { Done });
+        app.world_mut().flush();
+
+        update_until(&mut app, |app| app.world().resource::<DoneCount>().0 == 1);
+    }
+
+    #[test]
+    fn run_when_complete_skips_despawned_entity() {
+        let mut app = App::new();
+        app.add_plugins(TaskPoolPlugin::default())
+            .init_resource::<DoneCount>();
+        let world = app.world_mut();
+
+        let entity = world.spawn_empty().id();
+        world
+            .commands()
+            .entity(entity)
+            .run_when_complete(async {}, |_, entity| {
+                entity.into_world_mut().resource_mut::<DoneCount>().0 += 1;
+            });
+        world.flush();
+
+        let mut state = SystemState::<(Query<(Entity, &mut EntityTasks)>, Commands)>::new(world);
+        for _ in 0..1000 {
+            let (query, commands) = state.get_mut(world).unwrap();
+            poll_entity_tasks(query, commands);
+            if world
+                .get::<EntityTasks>(entity)
+                .is_some_and(EntityTasks::is_empty)
+            {
+                // The completion is queued: despawn the entity before it is delivered.
+                world.despawn(entity);
+                state.apply(world);
+                assert_eq!(world.resource::<DoneCount>().0, 0);
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        panic!("task did not complete in time");
+    }
+}
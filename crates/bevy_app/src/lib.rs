@@ -25,6 +25,9 @@ extern crate alloc;
 extern crate self as bevy_app;
 
 mod app;
+mod disabled_plugins;
+#[cfg(feature = "std")]
+mod entity_tasks;
 mod hierarchy;
 mod main_schedule;
 mod panic_handler;
@@ -41,6 +44,9 @@ mod terminal_ctrl_c_handler;
 pub mod hotpatch;
 
 pub use app::*;
+pub use disabled_plugins::*;
+#[cfg(feature = "std")]
+pub use entity_tasks::*;
 pub use hierarchy::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
@@ -1,4 +1,5 @@
 use crate::App;
+use alloc::{string::String, vec::Vec};
 use core::any::Any;
 use downcast_rs::{impl_downcast, Downcast};
 
@@ -54,6 +55,18 @@ use downcast_rs::{impl_downcast, Downcast};
 /// }
 /// # fn damp_flickering() {}
 /// ```
+///
+/// ## Dependencies
+///
+/// A plugin can declare which other plugins it requires or conflicts with by overriding
+/// [`Plugin::dependencies`]. This is checked once all plugins have been added, so the order in
+/// which plugins are added does not matter.
+///
+/// ## Disabling a plugin at runtime
+///
+/// Systems added by a plugin can be made to stop running while the plugin is disabled in the
+/// [`DisabledPlugins`](crate::DisabledPlugins) resource, by using the
+/// [`plugin_enabled`](crate::plugin_enabled) run condition.
 pub trait Plugin: Downcast + Any + Send + Sync {
     /// Configures the [`App`] to which this plugin is added.
     fn build(&self, app: &mut App);
@@ -89,10 +102,89 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// Declares the plugins this plugin requires or conflicts with.
+    ///
+    /// The declarations are checked right before [`Plugin::finish`] is called, once all plugins
+    /// have been added. If a required plugin is missing, or a conflicting plugin has been added,
+    /// the app panics with an error naming both plugins.
+    ///
+    /// ```
+    /// # use bevy_app::*;
+    /// # struct PhysicsPlugin;
+    /// # impl Plugin for PhysicsPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// # struct OtherPhysicsPlugin;
+    /// # impl Plugin for OtherPhysicsPlugin {
+    /// #     fn build(&self, app: &mut App) {}
+    /// # }
+    /// pub struct CharacterControllerPlugin;
+    ///
+    /// impl Plugin for CharacterControllerPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         // ...
+    ///     }
+    ///
+    ///     fn dependencies(&self, dependencies: &mut PluginDependencies) {
+    ///         dependencies
+    ///             .requires::<PhysicsPlugin>()
+    ///             .conflicts_with::<OtherPhysicsPlugin>();
+    ///     }
+    /// }
+    /// ```
+    fn dependencies(&self, _dependencies: &mut PluginDependencies) {
+        // no dependencies
+    }
 }
 
 impl_downcast!(Plugin);
 
+/// The plugins a [`Plugin`] requires or conflicts with, see [`Plugin::dependencies`].
+///
+/// Plugins are identified by their [`name()`](Plugin::name), which defaults to their type name.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PluginDependencies {
+    required: Vec<String>,
+    conflicts: Vec<String>,
+}
+
+impl PluginDependencies {
+    /// Declares that the plugin `T` has to be added to the app as well.
+    pub fn requires<T: Plugin>(&mut self) -> &mut Self {
+        self.requires_name(core::any::type_name::<T>())
+    }
+
+    /// Declares that a plugin with the given [`name()`](Plugin::name) has to be added to the app
+    /// as well.
+    pub fn requires_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.required.push(name.into());
+        self
+    }
+
+    /// Declares that the plugin `T` must not be added to the app.
+    pub fn conflicts_with<T: Plugin>(&mut self) -> &mut Self {
+        self.conflicts_with_name(core::any::type_name::<T>())
+    }
+
+    /// Declares that a plugin with the given [`name()`](Plugin::name) must not be added to the
+    /// app.
+    pub fn conflicts_with_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.conflicts.push(name.into());
+        self
+    }
+
+    /// Returns the names of the required plugins.
+    pub fn required(&self) -> &[String] {
+        &self.required
+    }
+
+    /// Returns the names of the conflicting plugins.
+    pub fn conflicts(&self) -> &[String] {
+        &self.conflicts
+    }
+}
+
 impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     fn build(&self, app: &mut App) {
         self(app);
@@ -145,7 +237,7 @@ mod sealed {
         #[track_caller]
         fn add_to_app(self, app: &mut App) {
             if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
+                app.add_boxed_plugin(Box::new(self), core::any::type_name::<P>())
             {
                 panic!(
                     "Error adding plugin {plugin_name}: : plugin was already added in application"
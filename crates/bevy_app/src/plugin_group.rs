@@ -265,6 +265,7 @@ pub trait PluginGroup: Sized {
 
 struct PluginEntry {
     plugin: Box<dyn Plugin>,
+    type_name: &'static str,
     enabled: bool,
 }
 
@@ -321,6 +322,7 @@ impl PluginGroupBuilder {
             TypeId::of::<T>(),
             PluginEntry {
                 plugin: Box::new(plugin),
+                type_name: core::any::type_name::<T>(),
                 enabled: true,
             },
             added_at_index,
@@ -571,7 +573,7 @@ impl PluginGroupBuilder {
             {
                 debug!("added plugin: {}", entry.plugin.name());
                 if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                    app.add_boxed_plugin(entry.plugin)
+                    app.add_boxed_plugin(entry.plugin, entry.type_name)
                 {
                     panic!(
                         "Error adding plugin {} in group {}: plugin was already added in application",
@@ -68,6 +68,23 @@ impl ScheduleRunnerPlugin {
             },
         }
     }
+
+    /// Runs the schedule at a fixed rate of `ticks_per_second`, see [`RunMode::Loop`].
+    ///
+    /// This is typically used by dedicated servers, which should neither busy-loop nor drift
+    /// from their tick rate. If an update takes longer than a tick, the next update starts
+    /// immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks_per_second` is not strictly positive and finite.
+    pub fn run_at_tick_rate(ticks_per_second: f64) -> Self {
+        assert!(
+            ticks_per_second.is_finite() && ticks_per_second > 0.0,
+            "the tick rate must be strictly positive and finite, got {ticks_per_second}"
+        );
+        Self::run_loop(Duration::from_secs_f64(1.0 / ticks_per_second))
+    }
 }
 
 impl Plugin for ScheduleRunnerPlugin {
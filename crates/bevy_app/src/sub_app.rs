@@ -1,4 +1,6 @@
-use crate::{App, AppLabel, First, InternedAppLabel, Plugin, Plugins, PluginsState};
+use crate::{
+    App, AppLabel, First, InternedAppLabel, Plugin, PluginDependencies, Plugins, PluginsState,
+};
 use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
     message::{message_update_system, MessageRegistry},
@@ -71,6 +73,9 @@ pub struct SubApp {
     /// The names of plugins that have been added to this app. (used to track duplicates and
     /// already-registered plugins)
     pub(crate) plugin_names: HashSet<String>,
+    /// The type names of plugins that have been added to this app, used to check the
+    /// [dependencies](Plugin::dependencies) of plugins.
+    pub(crate) plugin_type_names: HashSet<&'static str>,
     /// Panics if an update is attempted while plugins are building.
     pub(crate) plugin_build_depth: usize,
     pub(crate) plugins_state: PluginsState,
@@ -103,6 +108,7 @@ impl Default for SubApp {
             world,
             plugin_registry: Vec::default(),
             plugin_names: HashSet::default(),
+            plugin_type_names: HashSet::default(),
             plugin_build_depth: 0,
             plugins_state: PluginsState::Adding,
             update_schedule: None,
@@ -451,8 +457,42 @@ impl SubApp {
         }
     }
 
+    /// Returns `true` if a plugin with the given type name or [`name()`](Plugin::name) has been
+    /// added.
+    fn has_plugin_named(&self, name: &str) -> bool {
+        self.plugin_type_names.contains(name) || self.plugin_names.contains(name)
+    }
+
+    /// Panics if any added plugin is missing a plugin it [requires](Plugin::dependencies), or if
+    /// a plugin it conflicts with has been added.
+    pub(crate) fn check_plugin_dependencies(&self) {
+        for plugin in &self.plugin_registry {
+            let mut dependencies = PluginDependencies::default();
+            plugin.dependencies(&mut dependencies);
+
+            for required in dependencies.required() {
+                if !self.has_plugin_named(required) {
+                    panic!(
+                        "Plugin {} requires plugin {required}, which has not been added to the app",
+                        plugin.name()
+                    );
+                }
+            }
+
+            for conflict in dependencies.conflicts() {
+                if self.has_plugin_named(conflict) {
+                    panic!(
+                        "Plugin {} conflicts with plugin {conflict}, which has also been added to the app",
+                        plugin.name()
+                    );
+                }
+            }
+        }
+    }
+
     /// Runs [`Plugin::finish`] for each plugin.
     pub fn finish(&mut self) {
+        self.check_plugin_dependencies();
         // do hokey pokey with a boxed zst plugin (doesn't allocate)
         let mut hokeypokey: Box<dyn Plugin> = Box::new(crate::HokeyPokey);
         for i in 0..self.plugin_registry.len() {
@@ -36,6 +36,9 @@ impl Plugin for TaskPoolPlugin {
 
         #[cfg(not(all(target_arch = "wasm32", feature = "web")))]
         _app.add_systems(Last, tick_global_task_pools);
+
+        #[cfg(feature = "std")]
+        _app.add_systems(crate::PreUpdate, crate::poll_entity_tasks);
     }
 }
 
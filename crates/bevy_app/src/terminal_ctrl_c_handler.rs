@@ -17,6 +17,9 @@ static ON_EXIT_HANDLERS: Mutex<Vec<Box<dyn Fn() + Send>>> = Mutex::new(Vec::new(
 
 /// Gracefully handles `Ctrl+C` by emitting a [`AppExit`] event. This plugin is part of the `DefaultPlugins`.
 ///
+/// On Unix, termination requests (`SIGTERM` and `SIGHUP`) are handled the same way, so that
+/// servers stopped by a process manager or container runtime shut down gracefully as well.
+///
 /// ```no_run
 /// # use bevy_app::{App, NoopPluginGroup as MinimalPlugins, PluginGroup, TerminalCtrlCHandlerPlugin};
 /// fn main() {
@@ -16,6 +16,8 @@ pub mod embedded;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod file;
 pub mod memory;
+#[cfg(target_arch = "wasm32")]
+pub mod opfs;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -0,0 +1,397 @@
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetWriter, AssetWriterError, PathStream,
+    Reader, VecReader, Writer,
+};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::String, sync::Arc, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures_io::AsyncWrite;
+use futures_lite::Stream;
+use js_sys::{AsyncIterator, IteratorNext, Promise, Uint8Array};
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{
+    DomException, File, FileSystemDirectoryHandle, FileSystemFileHandle,
+    FileSystemGetDirectoryOptions, FileSystemGetFileOptions, FileSystemRemoveOptions,
+    FileSystemWritableFileStream, StorageManager, Window, WorkerGlobalScope,
+};
+
+/// Converts a JavaScript error to an [`io::Error`], keeping the kind of the errors of the file
+/// system.
+fn js_error(error: JsValue) -> io::Error {
+    let kind = match error
+        .dyn_ref::<DomException>()
+        .map(DomException::name)
+        .as_deref()
+    {
+        Some("NotFoundError") => io::ErrorKind::NotFound,
+        Some("TypeMismatchError") => io::ErrorKind::InvalidInput,
+        Some("InvalidModificationError") => io::ErrorKind::DirectoryNotEmpty,
+        _ => io::ErrorKind::Other,
+    };
+    io::Error::new(kind, format!("{error:?}"))
+}
+
+async fn resolve(promise: Promise) -> io::Result<JsValue> {
+    JsFuture::from(promise).await.map_err(js_error)
+}
+
+/// Returns the storage of the window or of the worker running the app.
+fn storage() -> io::Result<StorageManager> {
+    let global = js_sys::global();
+    if let Some(window) = global.dyn_ref::<Window>() {
+        Ok(window.navigator().storage())
+    } else if let Some(worker) = global.dyn_ref::<WorkerGlobalScope>() {
+        Ok(worker.navigator().storage())
+    } else {
+        Err(io::Error::other("Unsupported JavaScript global context"))
+    }
+}
+
+/// Returns the names of the directories and file of `path`, ignoring the current directories.
+fn names(path: &Path) -> impl Iterator<Item = &str> {
+    path.components().filter_map(|component| match component {
+        Component::Normal(name) => name.to_str(),
+        _ => None,
+    })
+}
+
+/// A directory of the origin private file system, in which the paths are resolved.
+#[derive(Clone)]
+struct OpfsRoot {
+    root: PathBuf,
+}
+
+impl OpfsRoot {
+    /// Returns the directory at `path`, creating it and its parents if `create` is true.
+    async fn directory(&self, path: &Path, create: bool) -> io::Result<FileSystemDirectoryHandle> {
+        let mut directory: FileSystemDirectoryHandle =
+            resolve(storage()?.get_directory()).await?.unchecked_into();
+        let options = FileSystemGetDirectoryOptions::new();
+        options.set_create(create);
+        let path = self.root.join(path);
+        for name in names(&path) {
+            directory = resolve(directory.get_directory_handle_with_options(name, &options))
+                .await?
+                .unchecked_into();
+        }
+        Ok(directory)
+    }
+
+    /// Returns the directory containing the entry at `path`, and the name of the entry.
+    async fn parent<'a>(
+        &self,
+        path: &'a Path,
+        create: bool,
+    ) -> io::Result<(FileSystemDirectoryHandle, &'a str)> {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no file name"))?;
+        let parent = path.parent().unwrap_or(Path::new(""));
+        Ok((self.directory(parent, create).await?, name))
+    }
+
+    async fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let (directory, name) = self.parent(path, false).await?;
+        let handle: FileSystemFileHandle = resolve(directory.get_file_handle(name))
+            .await?
+            .unchecked_into();
+        let file: File = resolve(handle.get_file()).await?.unchecked_into();
+        let buffer = resolve(file.array_buffer()).await?;
+        Ok(Uint8Array::new(&buffer).to_vec())
+    }
+
+    /// Replaces the content of the file at `path`, creating it and its parents if needed.
+    async fn write(&self, path: &Path, bytes: &[u8]) -> io::Result<()> {
+        let (directory, name) = self.parent(path, true).await?;
+        let options = FileSystemGetFileOptions::new();
+        options.set_create(true);
+        let handle: FileSystemFileHandle =
+            resolve(directory.get_file_handle_with_options(name, &options))
+                .await?
+                .unchecked_into();
+        let stream: FileSystemWritableFileStream =
+            resolve(handle.create_writable()).await?.unchecked_into();
+        resolve(stream.write_with_u8_array(bytes).map_err(js_error)?).await?;
+        // The file is only replaced once the stream is closed.
+        resolve(stream.close()).await?;
+        Ok(())
+    }
+
+    /// Removes the entry at `path`. A directory that is not empty is only removed if
+    /// `recursive` is true.
+    async fn remove(&self, path: &Path, recursive: bool) -> io::Result<()> {
+        let (directory, name) = self.parent(path, false).await?;
+        let options = FileSystemRemoveOptions::new();
+        options.set_recursive(recursive);
+        resolve(directory.remove_entry_with_options(name, &options)).await?;
+        Ok(())
+    }
+
+    /// Returns the names of the entries of the directory at `path`.
+    async fn entries(&self, path: &Path) -> io::Result<Vec<String>> {
+        let keys: AsyncIterator = self.directory(path, false).await?.keys();
+        let mut names = Vec::new();
+        loop {
+            let next: IteratorNext = resolve(keys.next().map_err(js_error)?)
+                .await?
+                .unchecked_into();
+            if next.done() {
+                return Ok(names);
+            }
+            if let Some(name) = next.value().as_string() {
+                names.push(name);
+            }
+        }
+    }
+}
+
+fn reader_error(path: &Path, error: io::Error) -> AssetReaderError {
+    if error.kind() == io::ErrorKind::NotFound {
+        AssetReaderError::NotFound(path.to_owned())
+    } else {
+        AssetReaderError::Io(Arc::new(error))
+    }
+}
+
+/// Reader implementation for loading assets from the [origin private file system] of the
+/// browser, which persists the files of the web page between visits.
+///
+/// [origin private file system]: https://developer.mozilla.org/en-US/docs/Web/API/File_System_API/Origin_private_file_system
+pub struct OpfsAssetReader {
+    root: OpfsRoot,
+}
+
+impl OpfsAssetReader {
+    /// Creates a new `OpfsAssetReader`, reading the files of the directory at `path` in the
+    /// origin private file system.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            root: OpfsRoot {
+                root: path.as_ref().to_owned(),
+            },
+        }
+    }
+}
+
+impl AssetReader for OpfsAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.root
+            .read(path)
+            .await
+            .map(VecReader::new)
+            .map_err(|error| reader_error(path, error))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let meta_path = get_meta_path(path);
+        self.root
+            .read(&meta_path)
+            .await
+            .map(VecReader::new)
+            .map_err(|error| reader_error(&meta_path, error))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let names = self
+            .root
+            .entries(path)
+            .await
+            .map_err(|error| reader_error(path, error))?;
+        let paths: Vec<PathBuf> = names
+            .into_iter()
+            // Meta files are not assets, and hidden files are not listed, like in the file source.
+            .filter(|name| !name.starts_with('.'))
+            .map(|name| path.join(name))
+            .filter(|path| {
+                !path
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("meta"))
+            })
+            .collect();
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(paths));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        match self.root.directory(path, false).await {
+            Ok(_) => Ok(true),
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    io::ErrorKind::NotFound | io::ErrorKind::InvalidInput
+                ) =>
+            {
+                Ok(false)
+            }
+            Err(error) => Err(AssetReaderError::Io(Arc::new(error))),
+        }
+    }
+}
+
+/// A writer buffering the bytes of a file, which are written to the origin private file system
+/// when flushed or closed.
+struct OpfsWriter {
+    root: OpfsRoot,
+    path: PathBuf,
+    /// All the bytes of the file, including the ones that have been flushed already.
+    bytes: Vec<u8>,
+    /// Whether the file must be written on the next flush.
+    dirty: bool,
+    /// Receives the result of the write in progress. The browser APIs cannot be called from
+    /// another thread, so the file is written by a task spawned on the current one.
+    flushing: Option<Pin<Box<async_channel::Receiver<io::Result<()>>>>>,
+}
+
+impl AsyncWrite for OpfsWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        this.bytes.extend_from_slice(buf);
+        this.dirty = true;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(flushing) = &mut this.flushing {
+                let result = ready!(flushing.as_mut().poll_next(cx));
+                this.flushing = None;
+                if let Err(error) =
+                    result.unwrap_or_else(|| Err(io::Error::other("the write was cancelled")))
+                {
+                    return Poll::Ready(Err(error));
+                }
+            } else if this.dirty {
+                this.dirty = false;
+                let (sender, receiver) = async_channel::bounded(1);
+                let root = this.root.clone();
+                let path = this.path.clone();
+                let bytes = this.bytes.clone();
+                spawn_local(async move {
+                    let _ = sender.send(root.write(&path, &bytes).await).await;
+                });
+                this.flushing = Some(Box::pin(receiver));
+            } else {
+                return Poll::Ready(Ok(()));
+            }
+        }
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.poll_flush(cx)
+    }
+}
+
+/// Writer implementation for saving assets to the [origin private file system] of the browser,
+/// which persists the files of the web page between visits.
+///
+/// The files are written once their [`Writer`] is flushed or closed.
+///
+/// [origin private file system]: https://developer.mozilla.org/en-US/docs/Web/API/File_System_API/Origin_private_file_system
+pub struct OpfsAssetWriter {
+    root: OpfsRoot,
+}
+
+impl OpfsAssetWriter {
+    /// Creates a new `OpfsAssetWriter`, writing the files to the directory at `path` in the
+    /// origin private file system.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            root: OpfsRoot {
+                root: path.as_ref().to_owned(),
+            },
+        }
+    }
+
+    fn writer(&self, path: PathBuf) -> Box<Writer> {
+        Box::new(OpfsWriter {
+            root: self.root.clone(),
+            path,
+            bytes: Vec::new(),
+            // Empty files are written too.
+            dirty: true,
+            flushing: None,
+        })
+    }
+
+    async fn rename_file(&self, old_path: &Path, new_path: &Path) -> io::Result<()> {
+        let bytes = self.root.read(old_path).await?;
+        self.root.write(new_path, &bytes).await?;
+        self.root.remove(old_path, false).await
+    }
+}
+
+impl AssetWriter for OpfsAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(self.writer(path.to_owned()))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(self.writer(get_meta_path(path)))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        Ok(self.root.remove(path, false).await?)
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        Ok(self.root.remove(&get_meta_path(path), false).await?)
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        Ok(self.rename_file(old_path, new_path).await?)
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        Ok(self
+            .rename_file(&get_meta_path(old_path), &get_meta_path(new_path))
+            .await?)
+    }
+
+    async fn create_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.root.directory(path, true).await?;
+        Ok(())
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        Ok(self.root.remove(path, true).await?)
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        Ok(self.root.remove(path, false).await?)
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        for name in self.root.entries(path).await? {
+            self.root.remove(&path.join(name), true).await?;
+        }
+        Ok(())
+    }
+}
@@ -180,6 +180,8 @@ mod id;
 mod loader;
 mod loader_builders;
 mod path;
+mod paths;
+mod progress;
 mod reflect;
 mod render_asset;
 mod server;
@@ -196,6 +198,8 @@ pub use id::*;
 pub use loader::*;
 pub use loader_builders::NestedLoadBuilder;
 pub use path::*;
+pub use paths::*;
+pub use progress::*;
 pub use reflect::*;
 pub use render_asset::*;
 pub use server::*;
@@ -417,9 +421,14 @@ impl Plugin for AssetPlugin {
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
             .add_message::<UntypedAssetLoadFailedEvent>()
+            .add_message::<AssetLoadMilestone>()
+            .init_resource::<AssetLoadProgress>()
             .configure_sets(
                 PreUpdate,
-                AssetTrackingSystems.after(handle_internal_asset_events),
+                (
+                    AssetTrackingSystems.after(handle_internal_asset_events),
+                    AssetLoadProgressSystems.after(handle_internal_asset_events),
+                ),
             )
             // `handle_internal_asset_events` requires the use of `&mut World`,
             // and as a result has ambiguous system ordering with all other systems in `PreUpdate`.
@@ -435,6 +444,10 @@ impl Plugin for AssetPlugin {
                 )
                     .chain(),
             )
+            .add_systems(
+                PreUpdate,
+                write_asset_load_milestones.in_set(AssetLoadProgressSystems),
+            )
             .register_diagnostic(Diagnostic::new(AssetServer::STARTED_LOAD_COUNT));
     }
 }
@@ -2662,6 +2675,65 @@ mod tests {
         assert_eq!(get_started_load_count(app.world()), 2);
     }
 
+    #[test]
+    fn labeled_assets_are_listed_after_load() {
+        let (mut app, dir) = create_app();
+        dir.insert_asset(Path::new("test.txt"), &[]);
+
+        #[derive(TypePath)]
+        struct LabeledLoader;
+
+        impl AssetLoader for LabeledLoader {
+            type Asset = TestAsset;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load(
+                &self,
+                _reader: &mut dyn Reader,
+                _settings: &Self::Settings,
+                load_context: &mut LoadContext<'_>,
+            ) -> Result<Self::Asset, Self::Error> {
+                load_context.add_labeled_asset("B", TestAsset);
+                load_context.add_labeled_asset("A", TestAsset);
+                Ok(TestAsset)
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["txt"]
+            }
+        }
+
+        app.init_asset::<TestAsset>()
+            .register_asset_loader(LabeledLoader);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<TestAsset> = asset_server.load("test.txt");
+        assert!(asset_server.get_labeled_assets(&handle).is_empty());
+
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<Assets<TestAsset>>()
+                .get(&handle)
+                .map(|_| ())
+        });
+
+        // The labeled assets are listed even though no handle kept them alive.
+        let labeled_assets = asset_server.get_labeled_assets(&handle);
+        let labels: Vec<&str> = labeled_assets
+            .iter()
+            .map(|labeled_asset| &*labeled_asset.label)
+            .collect();
+        assert_eq!(labels, ["A", "B"]);
+        assert_eq!(labeled_assets[0].type_id, TypeId::of::<TestAsset>());
+
+        let subasset: Handle<TestAsset> = asset_server.load_with_label("test.txt", "A");
+        assert_eq!(
+            asset_server.get_path(&subasset),
+            Some(AssetPath::from("test.txt#A"))
+        );
+    }
+
     /// A loader that immediately returns a [`TestAsset`].
     #[derive(TypePath)]
     struct TrivialLoader;
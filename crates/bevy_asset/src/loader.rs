@@ -3,8 +3,9 @@ use crate::{
     loader_builders::NestedLoadBuilder,
     meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfo, ProcessedInfoMinimal, Settings},
     path::AssetPath,
+    server::InternalAssetEvent,
     Asset, AssetIndex, AssetLoadError, AssetServer, AssetServerMode, Assets, ErasedAssetIndex,
-    Handle, UntypedAssetId, UntypedHandle,
+    Handle, LoadProgress, UntypedAssetId, UntypedHandle,
 };
 use alloc::{boxed::Box, string::ToString, vec::Vec};
 use atomicow::CowArc;
@@ -17,6 +18,7 @@ use core::{
     convert::Infallible,
 };
 use downcast_rs::{impl_downcast, Downcast};
+use futures_lite::StreamExt;
 use ron::error::SpannedError;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -574,6 +576,23 @@ impl<'a> LoadContext<'a> {
         &self.asset_path
     }
 
+    /// Reports how much of the asset has been read, to show it in the
+    /// [`AssetLoadProgress`](crate::AssetLoadProgress) resource.
+    ///
+    /// Loaders reading large files in chunks can call this as they go. `total_bytes` is the size
+    /// of the asset, if it is known. The progress is applied the next time the [`AssetServer`]
+    /// handles its events.
+    pub fn report_progress(&self, bytes_loaded: u64, total_bytes: Option<u64>) {
+        self.asset_server
+            .send_asset_event(InternalAssetEvent::Progress {
+                path: self.asset_path.clone(),
+                progress: LoadProgress {
+                    bytes_loaded,
+                    total_bytes,
+                },
+            });
+    }
+
     /// Reads the asset at the given path and returns its bytes
     pub async fn read_asset_bytes<'b, 'c>(
         &'b mut self,
@@ -616,6 +635,33 @@ impl<'a> LoadContext<'a> {
         Ok(bytes)
     }
 
+    /// Returns the paths of the assets and folders directly inside the folder at the given path,
+    /// sorted by path.
+    ///
+    /// Unlike the assets read or loaded from the folder, the folder itself isn't a dependency of
+    /// this asset, so adding or removing files in the folder doesn't reload it.
+    pub async fn read_directory<'b>(
+        &self,
+        path: impl Into<AssetPath<'b>>,
+    ) -> Result<Vec<AssetPath<'static>>, ReadAssetBytesError> {
+        let path = path.into();
+        let source = self.asset_server.get_source(path.source())?;
+        let asset_reader = match self.asset_server.mode() {
+            AssetServerMode::Unprocessed => source.reader(),
+            AssetServerMode::Processed => source.processed_reader()?,
+        };
+        let mut children: Vec<PathBuf> = asset_reader
+            .read_directory(path.path())
+            .await?
+            .collect()
+            .await;
+        children.sort();
+        Ok(children
+            .into_iter()
+            .map(|child| AssetPath::from_path_buf(child).with_source(path.source().clone_owned()))
+            .collect())
+    }
+
     /// Returns a handle to an asset of type `A` with the label `label`. This [`LoadContext`] must produce an asset of the
     /// given type and the given label or the dependencies of this asset will never be considered "fully loaded". However you
     /// can call this method before _or_ after adding the labeled asset.
@@ -0,0 +1,273 @@
+use crate::{
+    io::{AssetSourceBuilder, AssetSourceId},
+    AssetApp,
+};
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    string::{String, ToString},
+};
+use bevy_app::{App, Plugin};
+use bevy_ecs::resource::Resource;
+use bevy_platform::dirs;
+use std::path::{Path, PathBuf};
+
+/// A directory where an app stores the files it writes, with its own [`AssetSource`](crate::io::AssetSource)
+/// registered by the [`PathsPlugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UserDirectory {
+    /// The saved games, in the `saves://` asset source.
+    Saves,
+    /// The configuration and settings files, in the `config://` asset source. On Linux, macOS and
+    /// iOS, this is the same directory where the `bevy_settings` crate writes the settings.
+    Config,
+    /// The cached files, which the app can rebuild and the user or the system may delete, in the
+    /// `cache://` asset source.
+    Cache,
+    /// The log files, in the `logs://` asset source.
+    Logs,
+}
+
+impl UserDirectory {
+    /// All the user directories.
+    pub const ALL: [UserDirectory; 4] = [
+        UserDirectory::Saves,
+        UserDirectory::Config,
+        UserDirectory::Cache,
+        UserDirectory::Logs,
+    ];
+
+    /// Returns the name of the [`AssetSource`](crate::io::AssetSource) of this directory.
+    pub fn source_name(&self) -> &'static str {
+        match self {
+            UserDirectory::Saves => "saves",
+            UserDirectory::Config => "config",
+            UserDirectory::Cache => "cache",
+            UserDirectory::Logs => "logs",
+        }
+    }
+
+    /// Returns the id of the [`AssetSource`](crate::io::AssetSource) of this directory.
+    pub fn source_id(&self) -> AssetSourceId<'static> {
+        AssetSourceId::from(self.source_name())
+    }
+}
+
+/// The platform-appropriate directories where an app stores the files it writes, inserted by the
+/// [`PathsPlugin`].
+///
+/// With `app_name = "com.example.mygame"`, the directories are:
+///
+/// | Directory | Linux                                  | macOS                                             | Windows                                          |
+/// |-----------|----------------------------------------|---------------------------------------------------|--------------------------------------------------|
+/// | `saves`   | `~/.local/share/{app_name}/saves`      | `~/Library/Application Support/{app_name}/saves`  | `%AppData%\{app_name}\saves`                     |
+/// | `config`  | `~/.config/{app_name}`                 | `~/Library/Preferences/{app_name}`                | `%LocalAppData%\{app_name}\config`               |
+/// | `cache`   | `~/.cache/{app_name}`                  | `~/Library/Caches/{app_name}`                     | `%LocalAppData%\{app_name}\cache`                |
+/// | `logs`    | `~/.local/state/{app_name}/logs`       | `~/Library/Logs/{app_name}`                       | `%LocalAppData%\{app_name}\logs`                 |
+///
+/// On Linux, the `XDG_DATA_HOME`, `XDG_CONFIG_HOME`, `XDG_CACHE_HOME` and `XDG_STATE_HOME`
+/// environment variables are respected. On iOS, the directories are the same as on macOS, in the
+/// sandbox of the app. On Android, they are in the internal storage of the app, like
+/// `/data/user/0/{package}/files/{app_name}/config` and `/data/user/0/{package}/cache/{app_name}`.
+///
+/// A directory whose base directory is shared with another one, like the `config`, `cache` and
+/// `logs` directories on Windows, gets its own subdirectory, so no directory contains another.
+///
+/// On the web, the directories are `None`, and their asset sources store the files in the
+/// [origin private file system] of the browser, under `{app_name}/{directory}`. On other
+/// platforms, the directories are `None`, and their asset sources store the files in memory.
+///
+/// [origin private file system]: https://developer.mozilla.org/en-US/docs/Web/API/File_System_API/Origin_private_file_system
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Paths {
+    /// The directory of the saved games.
+    pub saves: Option<PathBuf>,
+    /// The directory of the configuration and settings files.
+    pub config: Option<PathBuf>,
+    /// The directory of the cached files.
+    pub cache: Option<PathBuf>,
+    /// The directory of the log files.
+    pub logs: Option<PathBuf>,
+}
+
+impl Paths {
+    /// Returns the directories of the app named `app_name` on this platform.
+    pub fn new(app_name: &str) -> Self {
+        let data_dir = dirs::data_dir();
+        let preferences_dir = dirs::preferences_dir();
+        let cache_dir = dirs::cache_dir();
+        let log_dir = dirs::log_dir();
+
+        // Gives the directory its own subdirectory if its base directory is also the base of
+        // another directory, like `%LocalAppData%` on Windows or `files` on Android.
+        let app_dir = |base: &Option<PathBuf>, others: [&Option<PathBuf>; 3], subdirectory| {
+            base.as_ref().map(|base| {
+                let dir = base.join(app_name);
+                if others.iter().any(|&other| other.as_ref() == Some(base)) {
+                    dir.join(subdirectory)
+                } else {
+                    dir
+                }
+            })
+        };
+
+        Self {
+            saves: data_dir
+                .as_ref()
+                .map(|dir| dir.join(app_name).join("saves")),
+            config: app_dir(
+                &preferences_dir,
+                [&data_dir, &cache_dir, &log_dir],
+                "config",
+            ),
+            cache: app_dir(&cache_dir, [&data_dir, &preferences_dir, &log_dir], "cache"),
+            logs: log_dir.map(|dir| {
+                let dir = dir.join(app_name);
+                // The macOS and iOS directory is already dedicated to logs.
+                if cfg!(any(target_os = "macos", target_os = "ios")) {
+                    dir
+                } else {
+                    dir.join("logs")
+                }
+            }),
+        }
+    }
+
+    /// Returns the path of the given `directory`, if it exists on this platform.
+    pub fn get(&self, directory: UserDirectory) -> Option<&Path> {
+        match directory {
+            UserDirectory::Saves => self.saves.as_deref(),
+            UserDirectory::Config => self.config.as_deref(),
+            UserDirectory::Cache => self.cache.as_deref(),
+            UserDirectory::Logs => self.logs.as_deref(),
+        }
+    }
+}
+
+/// Plugin inserting the [`Paths`] resource, and registering an [`AssetSource`](crate::io::AssetSource)
+/// for each [`UserDirectory`], to load and save files like `saves://slot1.ron` with the
+/// [`AssetServer`](crate::AssetServer) or the asset writers instead of hardcoding a `./saves`
+/// directory.
+///
+/// You are required to provide a unique application name, to not share the directories of other
+/// apps, like the reverse domain name used by the `bevy_settings` crate.
+///
+/// Like any asset source, this plugin must be added before the [`AssetPlugin`](crate::AssetPlugin),
+/// which is typically added as part of `DefaultPlugins`.
+///
+/// On the web, the files are stored in the origin private file system of the browser. On other
+/// platforms without these directories, the files are stored in memory and are lost when the app
+/// exits.
+pub struct PathsPlugin {
+    /// The unique name of the application.
+    pub app_name: String,
+}
+
+impl PathsPlugin {
+    /// Construct a new `PathsPlugin` for the given application name.
+    pub fn new(app_name: &str) -> Self {
+        Self {
+            app_name: app_name.to_string(),
+        }
+    }
+}
+
+impl Plugin for PathsPlugin {
+    fn build(&self, app: &mut App) {
+        let paths = Paths::new(&self.app_name);
+        for directory in UserDirectory::ALL {
+            app.register_asset_source(
+                directory.source_id(),
+                directory_source(&self.app_name, directory, paths.get(directory)),
+            );
+        }
+        app.insert_resource(paths);
+    }
+}
+
+/// Returns a source reading and writing the files in `path`, or in memory if there is no path.
+#[cfg(not(target_arch = "wasm32"))]
+fn directory_source(
+    _app_name: &str,
+    directory: UserDirectory,
+    path: Option<&Path>,
+) -> AssetSourceBuilder {
+    use crate::io::{
+        file::{FileAssetReader, FileAssetWriter},
+        memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+    };
+
+    if let Some(path) = path {
+        let reader_path = path.to_owned();
+        let writer_path = path.to_owned();
+        return AssetSourceBuilder::new(move || Box::new(FileAssetReader::new(&reader_path)))
+            .with_writer(move |create_root| {
+                Some(Box::new(FileAssetWriter::new(&writer_path, create_root)))
+            });
+    }
+
+    tracing::warn!(
+        "No {} directory on this platform, its files will be stored in memory",
+        directory.source_name()
+    );
+    let reader_dir = Dir::default();
+    let writer_dir = reader_dir.clone();
+    AssetSourceBuilder::new(move || {
+        Box::new(MemoryAssetReader {
+            root: reader_dir.clone(),
+        })
+    })
+    .with_writer(move |_| {
+        Some(Box::new(MemoryAssetWriter {
+            root: writer_dir.clone(),
+        }))
+    })
+}
+
+/// Returns a source reading and writing the files in the `{app_name}/{directory}` directory of
+/// the origin private file system of the browser.
+#[cfg(target_arch = "wasm32")]
+fn directory_source(
+    app_name: &str,
+    directory: UserDirectory,
+    _path: Option<&Path>,
+) -> AssetSourceBuilder {
+    use crate::io::opfs::{OpfsAssetReader, OpfsAssetWriter};
+
+    let root = Path::new(app_name).join(directory.source_name());
+    let writer_root = root.clone();
+    AssetSourceBuilder::new(move || Box::new(OpfsAssetReader::new(&root)))
+        .with_writer(move |_| Some(Box::new(OpfsAssetWriter::new(&writer_root))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_are_specific_to_the_app() {
+        let paths = Paths::new("com.example.mygame");
+        for directory in UserDirectory::ALL {
+            if let Some(path) = paths.get(directory) {
+                assert!(path.iter().any(|part| part == "com.example.mygame"));
+            }
+        }
+        // No directory contains another, so the files of one are never listed in another.
+        for directory in UserDirectory::ALL {
+            let Some(path) = paths.get(directory) else {
+                continue;
+            };
+            for other in UserDirectory::ALL
+                .into_iter()
+                .filter(|&other| other != directory)
+            {
+                if let Some(other_path) = paths.get(other) {
+                    assert!(
+                        !path.starts_with(other_path),
+                        "{path:?} is in {other_path:?}"
+                    );
+                }
+            }
+        }
+    }
+}
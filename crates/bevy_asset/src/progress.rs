@@ -0,0 +1,243 @@
+use alloc::{vec, vec::Vec};
+use bevy_ecs::{
+    message::{Message, MessageWriter},
+    resource::Resource,
+    schedule::SystemSet,
+    system::ResMut,
+};
+use bevy_platform::collections::HashMap;
+
+use crate::UntypedAssetId;
+
+/// How much of an asset has been read, as reported by its [`AssetLoader`](crate::AssetLoader)
+/// with [`LoadContext::report_progress`](crate::LoadContext::report_progress).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// The number of bytes loaded so far.
+    pub bytes_loaded: u64,
+    /// The total number of bytes to load, if the loader knows it.
+    pub total_bytes: Option<u64>,
+}
+
+impl LoadProgress {
+    /// Returns the fraction of the asset that has been loaded, between 0.0 and 1.0, if the total
+    /// size is known.
+    pub fn fraction(&self) -> Option<f32> {
+        self.total_bytes.map(|total_bytes| {
+            if total_bytes == 0 {
+                1.0
+            } else {
+                (self.bytes_loaded as f64 / total_bytes as f64).min(1.0) as f32
+            }
+        })
+    }
+}
+
+/// The progress of the assets being loaded by loaders that report it, which is useful to display
+/// progress bars on loading screens.
+///
+/// An asset is tracked from the first time its loader calls
+/// [`LoadContext::report_progress`](crate::LoadContext::report_progress) until it has finished
+/// loading or failed. Afterwards, use [`AssetServer::load_state`](crate::AssetServer::load_state)
+/// to check it.
+///
+/// When the progress of an asset passes one of [`AssetLoadProgress::milestones`], an
+/// [`AssetLoadMilestone`] message is written in the [`AssetLoadProgressSystems`] set.
+#[derive(Resource, Debug)]
+pub struct AssetLoadProgress {
+    /// The fractions at which [`AssetLoadMilestone`]s are written, in increasing order.
+    ///
+    /// Defaults to 25%, 50%, 75% and 100%. A milestone of 1.0 is always reached once the asset has
+    /// loaded, even if its loader never reported its total size.
+    pub milestones: Vec<f32>,
+    assets: HashMap<UntypedAssetId, TrackedLoad>,
+}
+
+#[derive(Debug)]
+struct TrackedLoad {
+    progress: LoadProgress,
+    /// The number of milestones that have already been reached.
+    milestones_reached: usize,
+    finished: bool,
+}
+
+impl Default for AssetLoadProgress {
+    fn default() -> Self {
+        Self {
+            milestones: vec![0.25, 0.5, 0.75, 1.0],
+            assets: HashMap::default(),
+        }
+    }
+}
+
+impl AssetLoadProgress {
+    /// Returns the progress of the asset with the given `id`, if it is being tracked.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<LoadProgress> {
+        self.assets.get(&id.into()).map(|tracked| tracked.progress)
+    }
+
+    /// Returns the fraction of the asset with the given `id` that has been loaded, if it is being
+    /// tracked and its total size is known.
+    pub fn fraction(&self, id: impl Into<UntypedAssetId>) -> Option<f32> {
+        self.get(id)?.fraction()
+    }
+
+    /// Iterates over the tracked assets and their progress.
+    pub fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, LoadProgress)> + '_ {
+        self.assets
+            .iter()
+            .map(|(id, tracked)| (*id, tracked.progress))
+    }
+
+    /// Returns the number of bytes loaded by all the tracked assets.
+    pub fn bytes_loaded(&self) -> u64 {
+        self.iter().map(|(_, progress)| progress.bytes_loaded).sum()
+    }
+
+    /// Returns the total number of bytes of all the tracked assets, if all their loaders reported
+    /// it.
+    pub fn total_bytes(&self) -> Option<u64> {
+        self.iter().map(|(_, progress)| progress.total_bytes).sum()
+    }
+
+    /// Returns the number of tracked assets.
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns true if no asset is being tracked.
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    pub(crate) fn update(&mut self, id: UntypedAssetId, progress: LoadProgress) {
+        let tracked = self.assets.entry(id).or_insert(TrackedLoad {
+            progress,
+            milestones_reached: 0,
+            finished: false,
+        });
+        tracked.progress = progress;
+    }
+
+    pub(crate) fn finish(&mut self, id: UntypedAssetId) {
+        if let Some(tracked) = self.assets.get_mut(&id) {
+            if let Some(total_bytes) = tracked.progress.total_bytes {
+                tracked.progress.bytes_loaded = total_bytes;
+            }
+            tracked.finished = true;
+        }
+    }
+
+    pub(crate) fn remove(&mut self, id: UntypedAssetId) {
+        self.assets.remove(&id);
+    }
+}
+
+/// A [`Message`] written when the progress of an asset tracked by [`AssetLoadProgress`] passes
+/// one of [`AssetLoadProgress::milestones`].
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct AssetLoadMilestone {
+    /// The asset that reached the milestone.
+    pub id: UntypedAssetId,
+    /// The milestone that was reached, as a fraction between 0.0 and 1.0.
+    pub milestone: f32,
+}
+
+/// A system set where [`AssetLoadMilestone`]s are written, after the progress reported by loaders
+/// has been applied to [`AssetLoadProgress`].
+#[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub struct AssetLoadProgressSystems;
+
+/// Writes the [`AssetLoadMilestone`]s reached since the last run, and stops tracking the assets
+/// that have finished loading.
+pub fn write_asset_load_milestones(
+    mut load_progress: ResMut<AssetLoadProgress>,
+    mut milestones: MessageWriter<AssetLoadMilestone>,
+) {
+    if load_progress.is_empty() {
+        return;
+    }
+
+    let AssetLoadProgress {
+        milestones: thresholds,
+        assets,
+    } = &mut *load_progress;
+
+    assets.retain(|&id, tracked| {
+        let fraction = if tracked.finished {
+            1.0
+        } else {
+            tracked.progress.fraction().unwrap_or(0.0)
+        };
+        while let Some(&milestone) = thresholds.get(tracked.milestones_reached)
+            && milestone <= fraction
+        {
+            milestones.write(AssetLoadMilestone { id, milestone });
+            tracked.milestones_reached += 1;
+        }
+        !tracked.finished
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use bevy_ecs::{message::Messages, system::RunSystemOnce, world::World};
+    use uuid::Uuid;
+
+    use super::{write_asset_load_milestones, AssetLoadMilestone, AssetLoadProgress, LoadProgress};
+    use crate::{AssetId, UntypedAssetId};
+
+    fn run_milestones(world: &mut World) -> Vec<f32> {
+        world.run_system_once(write_asset_load_milestones).unwrap();
+        world
+            .resource_mut::<Messages<AssetLoadMilestone>>()
+            .drain()
+            .map(|milestone| milestone.milestone)
+            .collect()
+    }
+
+    #[test]
+    fn milestones_are_written_once() {
+        let mut world = World::new();
+        world.init_resource::<AssetLoadProgress>();
+        world.init_resource::<Messages<AssetLoadMilestone>>();
+        let id: UntypedAssetId = AssetId::<()>::Uuid {
+            uuid: Uuid::from_u128(123),
+        }
+        .untyped();
+
+        world.resource_mut::<AssetLoadProgress>().update(
+            id,
+            LoadProgress {
+                bytes_loaded: 60,
+                total_bytes: Some(100),
+            },
+        );
+        assert_eq!(run_milestones(&mut world), vec![0.25, 0.5]);
+        assert_eq!(run_milestones(&mut world), Vec::<f32>::new());
+        assert_eq!(
+            world.resource::<AssetLoadProgress>().fraction(id),
+            Some(0.6)
+        );
+
+        world.resource_mut::<AssetLoadProgress>().finish(id);
+        assert_eq!(run_milestones(&mut world), vec![0.75, 1.0]);
+        assert!(world.resource::<AssetLoadProgress>().is_empty());
+    }
+
+    #[test]
+    fn load_progress_fraction() {
+        let progress = LoadProgress {
+            bytes_loaded: 25,
+            total_bytes: Some(100),
+        };
+        assert_eq!(progress.fraction(), Some(0.25));
+        assert_eq!(LoadProgress::default().fraction(), None);
+        let empty = LoadProgress {
+            bytes_loaded: 0,
+            total_bytes: Some(0),
+        };
+        assert_eq!(empty.fraction(), Some(1.0));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
     Asset, AssetHandleProvider, AssetIndex, AssetLoadError, AssetPath, DependencyLoadState,
-    ErasedAssetIndex, ErasedLoadedAsset, Handle, InternalAssetEvent, LoadState,
+    ErasedAssetIndex, ErasedLoadedAsset, Handle, InternalAssetEvent, LabeledAssetInfo, LoadState,
     RecursiveDependencyLoadState, StrongHandle, UntypedHandle,
 };
 use alloc::{
@@ -42,6 +42,8 @@ pub(crate) struct AssetInfo {
     ///
     /// [`LoadedAsset`]: crate::loader::LoadedAsset
     loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
+    /// The labeled assets produced by the loader of this asset, sorted by label.
+    pub(crate) labeled_assets: Vec<LabeledAssetInfo>,
     /// The number of handle drops to skip for this asset.
     /// See usage (and comments) in `get_or_create_path_handle` for context.
     handle_drops_to_skip: usize,
@@ -62,6 +64,7 @@ impl AssetInfo {
             loading_rec_dependencies: HashSet::default(),
             failed_rec_dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
+            labeled_assets: Vec::new(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
             handle_drops_to_skip: 0,
@@ -401,6 +404,21 @@ impl AssetInfos {
         world: &mut World,
         sender: &Sender<InternalAssetEvent>,
     ) {
+        // Keep track of the labels, since the labeled assets may not stay alive.
+        let mut labeled_assets: Vec<_> = loaded_asset
+            .label_to_asset_index
+            .iter()
+            .map(|(label, &index)| {
+                let labeled_asset = &loaded_asset.labeled_assets[index];
+                LabeledAssetInfo {
+                    label: label.clone(),
+                    type_id: labeled_asset.handle.type_id(),
+                    type_name: labeled_asset.asset.asset_type_name(),
+                }
+            })
+            .collect();
+        labeled_assets.sort_by_key(|asset| asset.label.clone());
+
         // Process all the labeled assets first so that they don't get skipped due to the "parent"
         // not having its handle alive.
         for asset in loaded_asset.labeled_assets {
@@ -527,6 +545,7 @@ impl AssetInfos {
             info.load_state = LoadState::Loaded;
             info.dep_load_state = dep_load_state;
             info.rec_dep_load_state = rec_dep_load_state.clone();
+            info.labeled_assets = labeled_assets;
             if watching_for_changes {
                 info.loader_dependencies = loaded_asset.loader_dependencies;
             }
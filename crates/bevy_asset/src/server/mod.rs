@@ -15,9 +15,9 @@ use crate::{
     },
     path::AssetPath,
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetIndex, AssetLoadFailedEvent,
-    AssetMetaCheck, Assets, DeserializeMetaError, ErasedAssetIndex, ErasedLoadedAsset, Handle,
-    LoadedUntypedAsset, UnapprovedPathMode, UntypedAssetId, UntypedAssetLoadFailedEvent,
-    UntypedHandle, VisitAssetDependencies,
+    AssetLoadProgress, AssetMetaCheck, Assets, DeserializeMetaError, ErasedAssetIndex,
+    ErasedLoadedAsset, Handle, LoadProgress, LoadedUntypedAsset, UnapprovedPathMode,
+    UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle, VisitAssetDependencies,
 };
 use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
 use alloc::{
@@ -365,6 +365,20 @@ impl AssetServer {
         self.load_builder().load(path.into())
     }
 
+    /// Begins loading the asset labeled `label` produced by the loader of the asset at `path`.
+    ///
+    /// This is the same as calling [`load`](Self::load) with the path and the label joined by `#`.
+    /// The labels of a loaded asset can be listed with
+    /// [`get_labeled_assets`](Self::get_labeled_assets).
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_label<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        label: impl Into<CowArc<'a, str>>,
+    ) -> Handle<A> {
+        self.load(path.into().with_label(label))
+    }
+
     /// Returns a [`LoadBuilder`] that can be used to start more complex loads. See [`LoadBuilder`]
     /// for details.
     #[must_use = "the load doesn't start until LoadBuilder has been consumed"]
@@ -1219,7 +1233,7 @@ impl AssetServer {
             .detach();
     }
 
-    fn send_asset_event(&self, event: InternalAssetEvent) {
+    pub(crate) fn send_asset_event(&self, event: InternalAssetEvent) {
         self.data.asset_event_sender.send(event).unwrap();
     }
 
@@ -1500,6 +1514,23 @@ impl AssetServer {
         Some(info.path.as_ref()?.clone())
     }
 
+    /// Returns the labeled assets produced by the loader of the asset `id`, sorted by label.
+    ///
+    /// This is empty until the asset is loaded, and lists the labeled assets even if their handles
+    /// were dropped. Each of them can be loaded with [`load_with_label`](Self::load_with_label),
+    /// or through reflection by looking up its [`type_id`](LabeledAssetInfo::type_id) in the
+    /// type registry to get its `ReflectAsset`.
+    pub fn get_labeled_assets(&self, id: impl Into<UntypedAssetId>) -> Vec<LabeledAssetInfo> {
+        let Ok(index) = id.into().try_into() else {
+            // Always say we don't have Uuid assets.
+            return Vec::new();
+        };
+        self.read_infos()
+            .get(index)
+            .map(|info| info.labeled_assets.clone())
+            .unwrap_or_default()
+    }
+
     /// Returns the [`AssetServerMode`] this server is currently in.
     pub fn mode(&self) -> AssetServerMode {
         self.data.mode
@@ -2070,6 +2101,11 @@ pub fn handle_internal_asset_events(world: &mut World) {
                     index,
                     loaded_asset,
                 } => {
+                    if let Some(mut load_progress) = world.get_resource_mut::<AssetLoadProgress>()
+                        && load_progress.get(index).is_some()
+                    {
+                        load_progress.finish(index.into());
+                    }
                     infos.process_asset_load(
                         index,
                         loaded_asset,
@@ -2090,6 +2126,11 @@ pub fn handle_internal_asset_events(world: &mut World) {
                     }
                 }
                 InternalAssetEvent::Failed { index, path, error } => {
+                    if let Some(mut load_progress) = world.get_resource_mut::<AssetLoadProgress>()
+                        && load_progress.get(index).is_some()
+                    {
+                        load_progress.remove(index.into());
+                    }
                     infos.process_asset_fail(index, error.clone());
 
                     // Send untyped failure event
@@ -2106,6 +2147,13 @@ pub fn handle_internal_asset_events(world: &mut World) {
                         .expect("Asset failed event sender should exist");
                     sender(world, index.index, path, error);
                 }
+                InternalAssetEvent::Progress { path, progress } => {
+                    if let Some(mut load_progress) = world.get_resource_mut::<AssetLoadProgress>() {
+                        for index in infos.get_path_indices(&path) {
+                            load_progress.update(index.into(), progress);
+                        }
+                    }
+                }
             }
         }
 
@@ -2247,6 +2295,22 @@ pub(crate) enum InternalAssetEvent {
         path: AssetPath<'static>,
         error: AssetLoadError,
     },
+    Progress {
+        path: AssetPath<'static>,
+        progress: LoadProgress,
+    },
+}
+
+/// A labeled asset produced by the loader of an asset, as listed by
+/// [`AssetServer::get_labeled_assets`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabeledAssetInfo {
+    /// The label of the asset, which is appended to the path of the loaded asset after a `#`.
+    pub label: CowArc<'static, str>,
+    /// The [`TypeId`] of the asset.
+    pub type_id: TypeId,
+    /// The name of the type of the asset.
+    pub type_name: &'static str,
 }
 
 /// The load state of an asset.
@@ -1,4 +1,4 @@
-use crate::{AudioSource, Decodable, Volume};
+use crate::{AudioSource, Decodable, SpatialVelocity, Volume};
 use bevy_asset::{Asset, Handle};
 use bevy_ecs::prelude::*;
 use bevy_math::Vec3;
@@ -166,9 +166,11 @@ impl PlaybackSettings {
 /// Settings for the listener for spatial audio sources.
 ///
 /// This is accompanied by [`Transform`] and [`GlobalTransform`](bevy_transform::prelude::GlobalTransform).
-/// Only one entity with a [`SpatialListener`] should be present at any given time.
+/// Spatial sounds are heard by the first listener, unless they select another one with
+/// [`OnSpatialListener`](crate::OnSpatialListener), for example to have a listener per player in
+/// split-screen games.
 #[derive(Component, Clone, Debug, Reflect)]
-#[require(Transform)]
+#[require(Transform, SpatialVelocity)]
 #[reflect(Clone, Default, Component, Debug)]
 pub struct SpatialListener {
     /// Left ear position relative to the [`GlobalTransform`](bevy_transform::prelude::GlobalTransform).
@@ -1,12 +1,19 @@
 use crate::{
-    AudioPlayer, Decodable, DefaultSpatialScale, GlobalVolume, PlaybackMode, PlaybackSettings,
-    SpatialAudioSink, SpatialListener,
+    device::{device_name, AudioOutputDevice},
+    AudioBus, AudioBusSink, AudioPlayer, Decodable, DefaultSpatialScale, GlobalVolume, OnAudioBus,
+    OnSpatialListener, PlaybackMode, PlaybackSettings, SpatialAudioSink, SpatialListener,
+    SpatialVelocity,
 };
+use alloc::{string::String, sync::Arc};
 use bevy_asset::{Asset, Assets};
-use bevy_ecs::{prelude::*, system::SystemParam};
+use bevy_ecs::{entity::EntityHashMap, prelude::*, system::SystemParam};
 use bevy_math::Vec3;
 use bevy_transform::prelude::GlobalTransform;
-use rodio::{DeviceSinkBuilder, MixerDeviceSink, Player, Source, SpatialPlayer};
+use core::sync::atomic::{AtomicBool, Ordering};
+use rodio::{
+    cpal::{self, traits::HostTrait},
+    DeviceSinkBuilder, MixerDeviceSink, Player, Source, SpatialPlayer,
+};
 use tracing::warn;
 
 use crate::{AudioSink, AudioSinkPlayback};
@@ -15,20 +22,60 @@ use crate::{AudioSink, AudioSinkPlayback};
 #[derive(Resource)]
 pub(crate) struct AudioOutput {
     stream: Option<MixerDeviceSink>,
+    /// The name of the device of the stream.
+    pub(crate) device_name: Option<String>,
+    /// Set by the audio thread when the stream fails, for example when its device is unplugged.
+    pub(crate) stream_error: Arc<AtomicBool>,
 }
 
-impl Default for AudioOutput {
-    fn default() -> Self {
-        let stream = DeviceSinkBuilder::open_default_sink()
-            .inspect_err(|_err| {
-                warn!("No audio device found.");
+impl AudioOutput {
+    /// Opens a stream on the `selected` device, or on the default device if the selected device
+    /// isn't available.
+    pub(crate) fn open(selected: &AudioOutputDevice) -> Self {
+        let host = cpal::default_host();
+        let device = match selected {
+            AudioOutputDevice::Named(name) => host
+                .output_devices()
+                .ok()
+                .and_then(|mut devices| {
+                    devices.find(|device| device_name(device).as_ref() == Some(name))
+                })
+                .or_else(|| {
+                    warn!("Audio device {name:?} not found, using the default device.");
+                    host.default_output_device()
+                }),
+            AudioOutputDevice::Default => host.default_output_device(),
+        };
+        let stream_error = Arc::new(AtomicBool::new(false));
+        let Some(device) = device else {
+            warn!("No audio device found.");
+            return Self {
+                stream: None,
+                device_name: None,
+                stream_error,
+            };
+        };
+        let device_name = device_name(&device);
+        let error_flag = stream_error.clone();
+        let stream = DeviceSinkBuilder::from_device(device)
+            .and_then(|builder| {
+                builder
+                    .with_error_callback(move |_err| error_flag.store(true, Ordering::Relaxed))
+                    .open_sink()
+            })
+            .inspect_err(|err| {
+                warn!("Could not open the audio device {device_name:?}: {err}");
             })
             .map(|mut s| {
                 s.log_on_drop(false);
                 s
             })
             .ok();
-        Self { stream }
+        Self {
+            device_name: stream.as_ref().and(device_name),
+            stream,
+            stream_error,
+        }
     }
 }
 
@@ -42,20 +89,43 @@ pub struct PlaybackRemoveMarker;
 
 #[derive(SystemParam)]
 pub(crate) struct EarPositions<'w, 's> {
-    pub(crate) query: Query<'w, 's, (Entity, &'static GlobalTransform, &'static SpatialListener)>,
+    pub(crate) query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static GlobalTransform,
+            &'static SpatialListener,
+            Option<&'static OnAudioBus>,
+        ),
+    >,
 }
 
 impl<'w, 's> EarPositions<'w, 's> {
-    /// Gets a set of transformed ear positions.
+    /// Gets the listener hearing the sounds [on](OnSpatialListener) `listener`, or the first
+    /// listener if `listener` is `None` or isn't a listener.
+    pub(crate) fn listener(
+        &self,
+        listener: Option<Entity>,
+    ) -> Option<(
+        Entity,
+        &GlobalTransform,
+        &SpatialListener,
+        Option<&OnAudioBus>,
+    )> {
+        listener
+            .and_then(|listener| self.query.get(listener).ok())
+            .or_else(|| self.query.iter().next())
+    }
+
+    /// Gets a set of transformed ear positions of the listener hearing the sounds on `listener`.
     ///
-    /// If there are no listeners, use the default values. If a user has added multiple
-    /// listeners for whatever reason, we will return the first value.
-    pub(crate) fn get(&self) -> (Vec3, Vec3) {
+    /// If there are no listeners, use the default values. If `listener` is `None` and a user has
+    /// added multiple listeners, we will return the first value.
+    pub(crate) fn get(&self, listener: Option<Entity>) -> (Vec3, Vec3) {
         let (left_ear, right_ear) = self
-            .query
-            .iter()
-            .next()
-            .map(|(_, transform, settings)| {
+            .listener(listener)
+            .map(|(_, transform, settings, _)| {
                 (
                     transform.transform_point(settings.left_ear_offset),
                     transform.transform_point(settings.right_ear_offset),
@@ -91,9 +161,12 @@ pub(crate) fn play_queued_audio_system<Source: Asset + Decodable>(
             &AudioPlayer<Source>,
             &PlaybackSettings,
             Option<&GlobalTransform>,
+            Option<&OnAudioBus>,
+            Option<&OnSpatialListener>,
         ),
         (Without<AudioSink>, Without<SpatialAudioSink>),
     >,
+    buses: Query<&AudioBusSink>,
     ear_positions: EarPositions,
     default_spatial_scale: Res<DefaultSpatialScale>,
     mut commands: Commands,
@@ -104,21 +177,40 @@ pub(crate) fn play_queued_audio_system<Source: Asset + Decodable>(
         // audio output unavailable; cannot play sound
         return;
     };
-    let mixer = stream.mixer();
 
-    for (entity, source_handle, settings, maybe_emitter_transform) in &query_nonplaying {
+    for (entity, source_handle, settings, maybe_emitter_transform, bus, listener) in
+        &query_nonplaying
+    {
         let Some(audio_source) = audio_sources.get(&source_handle.0) else {
             continue;
         };
+        let listener = listener.map(|listener| listener.0);
+        // Spatial sounds without a bus are played on the bus of their listener.
+        let bus = bus.or_else(|| {
+            settings
+                .spatial
+                .then(|| ear_positions.listener(listener))
+                .flatten()
+                .and_then(|(_, _, _, bus)| bus)
+        });
+        let mixer = match bus {
+            Some(bus) => match buses.get(bus.0) {
+                Ok(bus) => &bus.mixer,
+                // the bus has not been created yet
+                Err(_) => continue,
+            },
+            None => stream.mixer(),
+        };
         // audio data is available (has loaded), begin playback and insert sink component
         if settings.spatial {
-            let (left_ear, right_ear) = ear_positions.get();
+            let (left_ear, right_ear) = ear_positions.get(listener);
 
-            // We can only use one `SpatialListener`. If there are more than that, then
-            // the user may have made a mistake.
-            if ear_positions.multiple_listeners() {
+            // Without an `OnSpatialListener`, we can only use one `SpatialListener`. If there are
+            // more than that, then the user may have made a mistake.
+            if listener.is_none() && ear_positions.multiple_listeners() {
                 warn!(
-                    "Multiple SpatialListeners found. Using {}.",
+                    "Multiple SpatialListeners found. Using {}. Use OnSpatialListener to select \
+                    the listener of a sound.",
                     ear_positions.query.iter().next().unwrap().0
                 );
             }
@@ -196,6 +288,8 @@ pub(crate) fn play_queued_audio_system<Source: Asset + Decodable>(
                 sink.pause();
             }
 
+            let sink = (sink, SpatialVelocity::default());
+
             match settings.mode {
                 PlaybackMode::Loop | PlaybackMode::Once => commands.entity(entity).insert(sink),
                 PlaybackMode::Despawn => commands
@@ -332,6 +426,64 @@ pub(crate) fn cleanup_finished_audio<T: Decodable + Asset>(
     }
 }
 
+/// Creates the mixers of the new [`AudioBus`]es, once the buses they send their output to have been
+/// created.
+pub(crate) fn create_audio_buses(
+    audio_output: Res<AudioOutput>,
+    new_buses: Query<(Entity, &AudioBus, Option<&OnAudioBus>), Without<AudioBusSink>>,
+    buses: Query<&AudioBusSink>,
+    mut commands: Commands,
+) {
+    let Some(stream) = audio_output.stream.as_ref() else {
+        return;
+    };
+
+    // Buses sending their output to other new buses are created in later passes.
+    let mut created = EntityHashMap::<AudioBusSink>::default();
+    loop {
+        let created_count = created.len();
+        for (entity, bus, output) in &new_buses {
+            if created.contains_key(&entity) {
+                continue;
+            }
+            let output_mixer = match output {
+                Some(output) => match created.get(&output.0).or(buses.get(output.0).ok()) {
+                    Some(output) => &output.mixer,
+                    None => continue,
+                },
+                None => stream.mixer(),
+            };
+            let sink = AudioBusSink::connect_new(output_mixer, bus);
+            created.insert(entity, sink);
+        }
+        if created.len() == created_count {
+            break;
+        }
+    }
+
+    for (entity, sink) in created {
+        commands.entity(entity).try_insert(sink);
+    }
+}
+
+/// Applies the changes of the [`AudioBus`]es to their mixers, and removes the mixers of the removed
+/// buses.
+pub(crate) fn update_audio_buses(
+    buses: Query<(&AudioBus, &AudioBusSink), Changed<AudioBus>>,
+    mut removed: RemovedComponents<AudioBus>,
+    mut commands: Commands,
+) {
+    for (bus, sink) in &buses {
+        sink.update(bus);
+    }
+
+    for entity in removed.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<AudioBusSink>();
+        }
+    }
+}
+
 /// Run Condition to only play audio if the audio output is available
 pub(crate) fn audio_output_available(audio_output: Res<AudioOutput>) -> bool {
     audio_output.stream.is_some()
@@ -353,9 +505,14 @@ pub(crate) fn update_emitter_positions(
     }
 }
 
-/// Updates spatial audio sink ear positions when spatial listeners change.
+/// Updates spatial audio sink ear positions when spatial listeners, or the listeners of the sinks,
+/// change.
 pub(crate) fn update_listener_positions(
-    mut emitters: Query<(&SpatialAudioSink, &PlaybackSettings)>,
+    mut emitters: Query<(
+        &SpatialAudioSink,
+        &PlaybackSettings,
+        Option<Ref<OnSpatialListener>>,
+    )>,
     changed_listener: Query<
         (),
         (
@@ -370,14 +527,15 @@ pub(crate) fn update_listener_positions(
     ear_positions: EarPositions,
     default_spatial_scale: Res<DefaultSpatialScale>,
 ) {
-    if !default_spatial_scale.is_changed() && changed_listener.is_empty() {
-        return;
-    }
+    let update_all = default_spatial_scale.is_changed() || !changed_listener.is_empty();
 
-    let (left_ear, right_ear) = ear_positions.get();
+    for (sink, settings, listener) in emitters.iter_mut() {
+        if !update_all && !listener.as_ref().is_some_and(DetectChanges::is_changed) {
+            continue;
+        }
 
-    for (sink, settings) in emitters.iter_mut() {
         let scale = settings.spatial_scale.unwrap_or(default_spatial_scale.0).0;
+        let (left_ear, right_ear) = ear_positions.get(listener.map(|listener| listener.0));
 
         sink.set_ears_position(left_ear * scale, right_ear * scale);
     }
@@ -0,0 +1,559 @@
+use crate::Volume;
+use alloc::{sync::Arc, vec, vec::Vec};
+use bevy_ecs::prelude::*;
+use bevy_math::ops;
+use bevy_reflect::prelude::*;
+use core::{
+    f32::consts::TAU,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+    time::Duration,
+};
+use rodio::{
+    mixer::{Mixer, MixerSource},
+    ChannelCount, Sample, SampleRate, Source,
+};
+use std::sync::{Mutex, PoisonError};
+
+/// The number of channels the sounds of an [`AudioBus`] are mixed in.
+const BUS_CHANNELS: u16 = 2;
+
+/// The sample rate the sounds of an [`AudioBus`] are mixed at.
+const BUS_SAMPLE_RATE: u32 = 48_000;
+
+/// A mixing bus, such as the music, sound effects or voice bus of a game.
+///
+/// The sounds played [on the bus](OnAudioBus) are mixed together, go through the
+/// [`effects`](AudioBus::effects) in order, and are scaled by the [`volume`](AudioBus::volume) of
+/// the bus, before being sent to the audio output, or to another bus if the bus itself is
+/// [`OnAudioBus`]. Unlike the volume of a [`PlaybackSettings`](crate::PlaybackSettings), changes
+/// to the volume and effects of a bus apply to the sounds already playing on it.
+///
+/// The bus is created when the bus it sends its output to has been created; the sounds played on
+/// the bus wait for it to be created before starting. Changing the [`OnAudioBus`] of a bus after it
+/// has been created has no effect.
+///
+/// ```
+/// # use bevy_asset::AssetServer;
+/// # use bevy_audio::{AudioBus, AudioEffect, AudioPlayer, OnAudioBus, Volume};
+/// # use bevy_ecs::prelude::*;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let music = commands
+///         .spawn((Name::new("Music"), AudioBus::default().with_volume(Volume::Linear(0.5))))
+///         .id();
+///     let sfx = commands
+///         .spawn((
+///             Name::new("SFX"),
+///             AudioBus::default().with_effect(AudioEffect::compressor(Volume::Decibels(-6.0), 4.0)),
+///         ))
+///         .id();
+///
+///     commands.spawn((
+///         AudioPlayer::new(asset_server.load("music.ogg")),
+///         OnAudioBus(music),
+///     ));
+///     commands.spawn((
+///         AudioPlayer::new(asset_server.load("explosion.ogg")),
+///         OnAudioBus(sfx),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Clone, Debug, Default, PartialEq)]
+pub struct AudioBus {
+    /// The volume of the bus, applied after the effects.
+    pub volume: Volume,
+    /// Whether the bus is muted.
+    pub muted: bool,
+    /// The effects applied to the mix of the bus, in order.
+    pub effects: Vec<AudioEffect>,
+}
+
+impl AudioBus {
+    /// Helper to set the volume of the bus.
+    pub fn with_volume(mut self, volume: Volume) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Helper to add an effect at the end of the effects of the bus.
+    pub fn with_effect(mut self, effect: AudioEffect) -> Self {
+        self.effects.push(effect);
+        self
+    }
+}
+
+/// Plays the sound of this entity on an [`AudioBus`], or sends the output of this [`AudioBus`] to
+/// another bus.
+///
+/// Sounds and buses without this component are played directly on the audio output.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+#[relationship(relationship_target = AudioBusInputs)]
+pub struct OnAudioBus(pub Entity);
+
+/// The sounds and buses played on an [`AudioBus`].
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[relationship_target(relationship = OnAudioBus)]
+pub struct AudioBusInputs(Vec<Entity>);
+
+/// An effect applied to the mix of an [`AudioBus`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub enum AudioEffect {
+    /// Attenuates the frequencies above the cutoff frequency, for example to muffle the sounds
+    /// heard under water or behind a wall.
+    LowPass {
+        /// The cutoff frequency, in hertz.
+        cutoff_frequency: f32,
+    },
+    /// Adds the echoes of a space to the sounds.
+    ///
+    /// This is a simple feedback delay rather than a simulation of the space.
+    Reverb {
+        /// The delay between the sound and its first echo.
+        delay: Duration,
+        /// How much of each echo is echoed again, from `0.0` to below `1.0`.
+        feedback: f32,
+        /// How loud the echoes are mixed with the sounds, from `0.0` to `1.0`.
+        mix: f32,
+    },
+    /// Reduces the volume of the loud parts of the mix, to keep many sounds playing at once from
+    /// getting too loud.
+    Compressor {
+        /// The level above which the mix is compressed.
+        threshold: Volume,
+        /// How much the level above the threshold is reduced, `4.0` dividing its decibels by 4.
+        ///
+        /// Ratios below `1.0` don't compress.
+        ratio: f32,
+        /// How fast the compressor reacts to the mix getting louder.
+        attack: Duration,
+        /// How fast the compressor recovers from the mix getting quieter.
+        release: Duration,
+    },
+}
+
+impl AudioEffect {
+    /// Creates an [`AudioEffect::LowPass`] with the given cutoff frequency, in hertz.
+    pub const fn low_pass(cutoff_frequency: f32) -> Self {
+        Self::LowPass { cutoff_frequency }
+    }
+
+    /// Creates an [`AudioEffect::Reverb`].
+    pub const fn reverb(delay: Duration, feedback: f32, mix: f32) -> Self {
+        Self::Reverb {
+            delay,
+            feedback,
+            mix,
+        }
+    }
+
+    /// Creates an [`AudioEffect::Compressor`] with an attack of 10 milliseconds and a release of
+    /// 100 milliseconds.
+    pub const fn compressor(threshold: Volume, ratio: f32) -> Self {
+        Self::Compressor {
+            threshold,
+            ratio,
+            attack: Duration::from_millis(10),
+            release: Duration::from_millis(100),
+        }
+    }
+}
+
+/// The mixer of an [`AudioBus`], inserted when the bus is created.
+#[derive(Component)]
+pub(crate) struct AudioBusSink {
+    pub(crate) mixer: Mixer,
+    controls: Arc<AudioBusControls>,
+}
+
+impl AudioBusSink {
+    /// Creates the mixer of `bus` and plays it on the `output` mixer.
+    pub(crate) fn connect_new(output: &Mixer, bus: &AudioBus) -> Self {
+        let (mixer, input) = rodio::mixer::mixer(
+            ChannelCount::new(BUS_CHANNELS).unwrap(),
+            SampleRate::new(BUS_SAMPLE_RATE).unwrap(),
+        );
+        let controls = Arc::new(AudioBusControls {
+            bus: Mutex::new(bus.clone()),
+            generation: AtomicU32::new(0),
+            stopped: AtomicBool::new(false),
+        });
+        output.add(AudioBusSource::new(input, controls.clone()));
+        Self { mixer, controls }
+    }
+
+    /// Applies the changes of `bus` to the sounds playing on it.
+    pub(crate) fn update(&self, bus: &AudioBus) {
+        *self
+            .controls
+            .bus
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = bus.clone();
+        self.controls.generation.fetch_add(1, Ordering::Release);
+    }
+}
+
+impl Drop for AudioBusSink {
+    fn drop(&mut self) {
+        self.controls.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+/// The settings of an [`AudioBus`], shared with the audio thread.
+struct AudioBusControls {
+    bus: Mutex<AudioBus>,
+    /// Incremented when `bus` changes, so that the audio thread only locks it then.
+    generation: AtomicU32,
+    /// Set when the bus is removed, to stop playing it.
+    stopped: AtomicBool,
+}
+
+/// The [`Source`] mixing the sounds of an [`AudioBus`] and applying its effects and volume.
+struct AudioBusSource {
+    input: MixerSource,
+    controls: Arc<AudioBusControls>,
+    generation: u32,
+    volume: f32,
+    effects: EffectChain,
+}
+
+impl AudioBusSource {
+    fn new(input: MixerSource, controls: Arc<AudioBusControls>) -> Self {
+        let mut source = Self {
+            input,
+            controls,
+            generation: 0,
+            volume: 1.0,
+            effects: EffectChain::new(BUS_CHANNELS, BUS_SAMPLE_RATE),
+        };
+        source.apply_controls();
+        source
+    }
+
+    fn apply_controls(&mut self) {
+        let bus = self
+            .controls
+            .bus
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        self.volume = if bus.muted {
+            0.0
+        } else {
+            bus.volume.to_linear()
+        };
+        self.effects.set_effects(&bus.effects);
+    }
+}
+
+impl Iterator for AudioBusSource {
+    type Item = Sample;
+
+    fn next(&mut self) -> Option<Sample> {
+        if self.controls.stopped.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let generation = self.controls.generation.load(Ordering::Acquire);
+        if generation != self.generation {
+            self.generation = generation;
+            self.apply_controls();
+        }
+
+        // The bus keeps playing silence while no sound plays on it, so that it isn't removed from
+        // its output.
+        let sample = self.input.next().unwrap_or(0.0);
+        Some(self.effects.process(sample) * self.volume)
+    }
+}
+
+impl Source for AudioBusSource {
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> ChannelCount {
+        ChannelCount::new(BUS_CHANNELS).unwrap()
+    }
+
+    fn sample_rate(&self) -> SampleRate {
+        SampleRate::new(BUS_SAMPLE_RATE).unwrap()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// The state of the [`AudioEffect`]s of a bus, processing interleaved samples.
+struct EffectChain {
+    channels: u16,
+    sample_rate: u32,
+    /// The channel of the next sample.
+    channel: u16,
+    effects: Vec<EffectState>,
+}
+
+impl EffectChain {
+    fn new(channels: u16, sample_rate: u32) -> Self {
+        Self {
+            channels,
+            sample_rate,
+            channel: 0,
+            effects: Vec::new(),
+        }
+    }
+
+    /// Updates the effects, keeping the state of those of the same kind at the same place so that
+    /// tweaking an effect doesn't cut its tail.
+    fn set_effects(&mut self, effects: &[AudioEffect]) {
+        self.effects.truncate(effects.len());
+        for (index, effect) in effects.iter().enumerate() {
+            if let Some(state) = self.effects.get_mut(index) {
+                state.configure(effect, self.channels, self.sample_rate);
+            } else {
+                let mut state = EffectState::new(effect, self.channels);
+                state.configure(effect, self.channels, self.sample_rate);
+                self.effects.push(state);
+            }
+        }
+    }
+
+    fn process(&mut self, mut sample: f32) -> f32 {
+        for effect in &mut self.effects {
+            sample = effect.process(sample, self.channel as usize);
+        }
+        self.channel = (self.channel + 1) % self.channels;
+        sample
+    }
+}
+
+enum EffectState {
+    LowPass {
+        coefficient: f32,
+        /// The last output of each channel.
+        previous: Vec<f32>,
+    },
+    Reverb {
+        /// The interleaved samples of the last `delay` of each channel, with their echoes.
+        buffer: Vec<f32>,
+        position: usize,
+        feedback: f32,
+        mix: f32,
+    },
+    Compressor {
+        threshold: f32,
+        ratio: f32,
+        attack: f32,
+        release: f32,
+        /// The level of the mix, following the absolute value of the samples of all channels.
+        envelope: f32,
+    },
+}
+
+impl EffectState {
+    fn new(effect: &AudioEffect, channels: u16) -> Self {
+        match effect {
+            AudioEffect::LowPass { .. } => Self::LowPass {
+                coefficient: 1.0,
+                previous: vec![0.0; channels as usize],
+            },
+            AudioEffect::Reverb { .. } => Self::Reverb {
+                buffer: Vec::new(),
+                position: 0,
+                feedback: 0.0,
+                mix: 0.0,
+            },
+            AudioEffect::Compressor { .. } => Self::Compressor {
+                threshold: 1.0,
+                ratio: 1.0,
+                attack: 0.0,
+                release: 0.0,
+                envelope: 0.0,
+            },
+        }
+    }
+
+    /// Applies the settings of `effect`, replacing the state if it is of another kind.
+    fn configure(&mut self, effect: &AudioEffect, channels: u16, sample_rate: u32) {
+        match (&mut *self, effect) {
+            (Self::LowPass { coefficient, .. }, AudioEffect::LowPass { cutoff_frequency }) => {
+                *coefficient =
+                    1.0 - ops::exp(-TAU * cutoff_frequency.max(0.0) / sample_rate as f32);
+            }
+            (
+                Self::Reverb {
+                    buffer,
+                    position,
+                    feedback: current_feedback,
+                    mix: current_mix,
+                },
+                AudioEffect::Reverb {
+                    delay,
+                    feedback,
+                    mix,
+                },
+            ) => {
+                let delay_frames = (delay.as_secs_f32() * sample_rate as f32) as usize;
+                buffer.resize(delay_frames.max(1) * channels as usize, 0.0);
+                *position %= buffer.len();
+                *current_feedback = feedback.clamp(0.0, 0.99);
+                *current_mix = mix.clamp(0.0, 1.0);
+            }
+            (
+                Self::Compressor {
+                    threshold: current_threshold,
+                    ratio: current_ratio,
+                    attack: current_attack,
+                    release: current_release,
+                    ..
+                },
+                AudioEffect::Compressor {
+                    threshold,
+                    ratio,
+                    attack,
+                    release,
+                },
+            ) => {
+                // The envelope follows the samples of all channels.
+                let samples_per_second = sample_rate as f32 * channels as f32;
+                let smoothing = |time: &Duration| {
+                    let samples = time.as_secs_f32() * samples_per_second;
+                    if samples > 0.0 {
+                        ops::exp(-1.0 / samples)
+                    } else {
+                        0.0
+                    }
+                };
+                *current_threshold = threshold.to_linear();
+                *current_ratio = ratio.max(1.0);
+                *current_attack = smoothing(attack);
+                *current_release = smoothing(release);
+            }
+            _ => {
+                *self = Self::new(effect, channels);
+                self.configure(effect, channels, sample_rate);
+            }
+        }
+    }
+
+    fn process(&mut self, sample: f32, channel: usize) -> f32 {
+        match self {
+            Self::LowPass {
+                coefficient,
+                previous,
+            } => {
+                let output = &mut previous[channel];
+                *output += *coefficient * (sample - *output);
+                *output
+            }
+            Self::Reverb {
+                buffer,
+                position,
+                feedback,
+                mix,
+            } => {
+                let echo = buffer[*position];
+                buffer[*position] = sample + echo * *feedback;
+                *position = (*position + 1) % buffer.len();
+                sample + echo * *mix
+            }
+            Self::Compressor {
+                threshold,
+                ratio,
+                attack,
+                release,
+                envelope,
+            } => {
+                let level = ops::abs(sample);
+                let smoothing = if level > *envelope { *attack } else { *release };
+                *envelope = level + smoothing * (*envelope - level);
+                if *envelope > *threshold {
+                    sample * ops::powf(*threshold / *envelope, 1.0 - 1.0 / *ratio)
+                } else {
+                    sample
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AudioEffect, EffectChain};
+    use crate::Volume;
+    use bevy_math::ops;
+    use core::time::Duration;
+
+    #[test]
+    fn low_pass_removes_high_frequencies() {
+        let mut chain = EffectChain::new(1, 48_000);
+        chain.set_effects(&[AudioEffect::low_pass(200.0)]);
+
+        // A constant signal goes through.
+        let mut output = 0.0;
+        for _ in 0..48_000 {
+            output = chain.process(1.0);
+        }
+        assert!(ops::abs(output - 1.0) < 0.01);
+
+        // A signal alternating every sample is the highest frequency there is.
+        let mut peak: f32 = 0.0;
+        for i in 0..48_000 {
+            let sample = if i % 2 == 0 { 1.0 } else { -1.0 };
+            let output = chain.process(sample);
+            if i > 47_000 {
+                peak = peak.max(ops::abs(output));
+            }
+        }
+        assert!(peak < 0.05);
+    }
+
+    #[test]
+    fn reverb_echoes_after_the_delay() {
+        // Two channels with a delay of 2 frames.
+        let mut chain = EffectChain::new(2, 1_000);
+        chain.set_effects(&[AudioEffect::reverb(Duration::from_millis(2), 0.5, 1.0)]);
+
+        let input = [1.0, 0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let output = input.map(|sample| chain.process(sample));
+        assert_eq!(output, [1.0, 0.5, 0.0, 0.0, 1.0, 0.5, 0.0, 0.0]);
+        assert_eq!(chain.process(0.0), 0.5);
+    }
+
+    #[test]
+    fn compressor_reduces_loud_levels() {
+        let mut chain = EffectChain::new(1, 48_000);
+        chain.set_effects(&[AudioEffect::compressor(Volume::Linear(0.25), 4.0)]);
+
+        let mut quiet = 0.0;
+        for _ in 0..48_000 {
+            quiet = chain.process(0.2);
+        }
+        assert!(ops::abs(quiet - 0.2) < 1e-6);
+
+        let mut loud = 0.0;
+        for _ in 0..48_000 {
+            loud = chain.process(1.0);
+        }
+        // 12 decibels above the threshold are reduced to 3.
+        let expected = 0.25 * ops::powf(4.0, 0.25);
+        assert!(ops::abs(loud - expected) < 0.01);
+    }
+
+    #[test]
+    fn tweaking_effects_keeps_their_state() {
+        let mut chain = EffectChain::new(1, 1_000);
+        chain.set_effects(&[AudioEffect::reverb(Duration::from_millis(2), 0.0, 1.0)]);
+        chain.process(1.0);
+
+        // The echo of the sample played before the change is still heard.
+        chain.set_effects(&[AudioEffect::reverb(Duration::from_millis(2), 0.0, 0.5)]);
+        chain.process(0.0);
+        assert_eq!(chain.process(0.0), 0.5);
+
+        chain.set_effects(&[AudioEffect::low_pass(100.0)]);
+        assert!(chain.process(1.0) < 1.0);
+    }
+}
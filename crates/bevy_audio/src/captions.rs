@@ -0,0 +1,303 @@
+use alloc::{string::String, vec::Vec};
+use std::io;
+
+use bevy_asset::{io::Reader, Asset, AssetLoader, Assets, Handle, LoadContext};
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use ron::de::SpannedError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{AudioSink, AudioSinkPlayback, SpatialAudioSink};
+
+/// A line of timed text of a [`CaptionTrack`].
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Clone, Debug, PartialEq)]
+pub struct Caption {
+    /// The time at which the caption is shown, in seconds from the start of the sound.
+    pub start: f32,
+    /// The time at which the caption is hidden, in seconds from the start of the sound.
+    pub end: f32,
+    /// The text of the caption.
+    pub text: String,
+    /// The name of the speaker, shown before the text.
+    #[serde(default)]
+    pub speaker: Option<String>,
+    /// The priority of the caption. When more captions are active than can be shown, the
+    /// captions with a lower priority are hidden first, and ducked behind the more important
+    /// ones, such as the dialogue of the main characters over the chatter of the crowds.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// The captions of a sound, such as the subtitles of a dialogue or the description of a sound
+/// effect.
+///
+/// Add [`AudioCaptions`] next to an [`AudioPlayer`](crate::AudioPlayer) to show the captions of
+/// the sound while it plays.
+///
+/// Caption tracks are loaded from `.captions.ron` files by the [`CaptionTrackLoader`]:
+///
+/// ```ron
+/// (
+///     captions: [
+///         (start: 0.0, end: 2.5, text: "Where is everybody?", speaker: Some("Alice")),
+///         (start: 1.0, end: 2.0, text: "[Door creaks]", priority: -1),
+///     ],
+/// )
+/// ```
+#[derive(Asset, Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+#[reflect(Clone, Debug, Default, PartialEq)]
+pub struct CaptionTrack {
+    captions: Vec<Caption>,
+}
+
+impl CaptionTrack {
+    /// Creates a track of `captions`.
+    pub fn new(captions: impl IntoIterator<Item = Caption>) -> Self {
+        let mut track = Self {
+            captions: captions.into_iter().collect(),
+        };
+        track.sort();
+        track
+    }
+
+    /// Returns the captions, ordered by start time.
+    pub fn captions(&self) -> &[Caption] {
+        &self.captions
+    }
+
+    /// Returns the indices and captions active at `time`, in seconds from the start of the sound.
+    pub fn active_at(&self, time: f32) -> impl Iterator<Item = (usize, &Caption)> {
+        self.captions
+            .iter()
+            .enumerate()
+            .take_while(move |(_, caption)| caption.start <= time)
+            .filter(move |(_, caption)| caption.end > time)
+    }
+
+    fn sort(&mut self) {
+        self.captions.sort_by(|a, b| a.start.total_cmp(&b.start));
+    }
+}
+
+/// Shows the captions of a [`CaptionTrack`] while the sound of this entity plays.
+///
+/// The captions follow the playback position of the [`AudioSink`] or [`SpatialAudioSink`] of the
+/// entity, so they pause and change speed with the sound.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Clone, Debug, Default, PartialEq)]
+pub struct AudioCaptions(pub Handle<CaptionTrack>);
+
+/// A caption shown for a sound, in [`ActiveCaptions`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub struct ActiveCaption {
+    /// The entity playing the sound.
+    pub entity: Entity,
+    /// The index of the caption in its [`CaptionTrack`].
+    pub index: usize,
+    /// The caption.
+    pub caption: Caption,
+}
+
+/// The captions of the sounds playing, for instance to show them with a custom UI.
+///
+/// A [`CaptionStarted`] or [`CaptionEnded`] message is written when a caption starts or ends.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Resource, Clone, Debug, Default, PartialEq)]
+pub struct ActiveCaptions {
+    captions: Vec<ActiveCaption>,
+}
+
+impl ActiveCaptions {
+    /// Iterates over the active captions, from the highest to the lowest
+    /// [priority](Caption::priority), and in the order they started for the same priority.
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveCaption> {
+        self.captions.iter()
+    }
+
+    /// Returns the number of active captions.
+    pub fn len(&self) -> usize {
+        self.captions.len()
+    }
+
+    /// Returns `true` if there are no active captions.
+    pub fn is_empty(&self) -> bool {
+        self.captions.is_empty()
+    }
+
+    fn contains(&self, entity: Entity, index: usize) -> bool {
+        self.captions
+            .iter()
+            .any(|active| active.entity == entity && active.index == index)
+    }
+}
+
+/// A [`Message`] written when a caption of a sound starts.
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct CaptionStarted(pub ActiveCaption);
+
+/// A [`Message`] written when a caption of a sound ends, or the sound stops.
+#[derive(Message, Clone, Debug, PartialEq)]
+pub struct CaptionEnded(pub ActiveCaption);
+
+/// Updates the [`ActiveCaptions`] from the playback position of the sounds with
+/// [`AudioCaptions`].
+pub(crate) fn update_captions(
+    emitters: Query<(
+        Entity,
+        &AudioCaptions,
+        Option<&AudioSink>,
+        Option<&SpatialAudioSink>,
+    )>,
+    tracks: Res<Assets<CaptionTrack>>,
+    mut active_captions: ResMut<ActiveCaptions>,
+    mut started: MessageWriter<CaptionStarted>,
+    mut ended: MessageWriter<CaptionEnded>,
+    mut playing: Local<Vec<(Entity, usize)>>,
+) {
+    playing.clear();
+    let mut new_captions = Vec::new();
+    for (entity, captions, sink, spatial_sink) in &emitters {
+        let (position, empty) = match (sink, spatial_sink) {
+            (Some(sink), _) => (sink.position(), sink.empty()),
+            (None, Some(sink)) => (sink.position(), sink.empty()),
+            (None, None) => continue,
+        };
+        let Some(track) = tracks.get(&captions.0).filter(|_| !empty) else {
+            continue;
+        };
+        let time = position.as_secs_f32();
+        for (index, caption) in track.active_at(time) {
+            playing.push((entity, index));
+            if !active_captions.contains(entity, index) {
+                new_captions.push(ActiveCaption {
+                    entity,
+                    index,
+                    caption: caption.clone(),
+                });
+            }
+        }
+    }
+
+    if new_captions.is_empty()
+        && active_captions
+            .captions
+            .iter()
+            .all(|active| playing.contains(&(active.entity, active.index)))
+    {
+        return;
+    }
+
+    let active_captions = &mut active_captions.captions;
+    active_captions.retain(|active| {
+        let keep = playing.contains(&(active.entity, active.index));
+        if !keep {
+            ended.write(CaptionEnded(active.clone()));
+        }
+        keep
+    });
+    for caption in new_captions {
+        started.write(CaptionStarted(caption.clone()));
+        active_captions.push(caption);
+    }
+    // The sort is stable, so captions of the same priority stay in the order they started.
+    active_captions.sort_by_key(|active| core::cmp::Reverse(active.caption.priority));
+}
+
+/// Loads [`CaptionTrack`]s from `.captions.ron` files.
+#[derive(Default, TypePath)]
+pub struct CaptionTrackLoader;
+
+/// Errors that can occur when loading [`CaptionTrack`]s.
+#[derive(Error, Debug)]
+pub enum CaptionTrackLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An error occurred in RON deserialization, and the location of the error is supplied.
+    #[error(transparent)]
+    SpannedRon(#[from] SpannedError),
+}
+
+impl AssetLoader for CaptionTrackLoader {
+    type Asset = CaptionTrack;
+
+    type Settings = ();
+
+    type Error = CaptionTrackLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        let mut track = CaptionTrack::deserialize(&mut deserializer)
+            .map_err(|err| deserializer.span_error(err))?;
+        track.sort();
+        Ok(track)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["captions.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Caption, CaptionTrack};
+    use alloc::{string::String, vec::Vec};
+
+    fn caption(start: f32, end: f32, text: &str) -> Caption {
+        Caption {
+            start,
+            end,
+            text: text.into(),
+            speaker: None,
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn captions_are_active_between_their_start_and_end() {
+        let track = CaptionTrack::new([
+            caption(2.0, 4.0, "second"),
+            caption(0.0, 1.0, "first"),
+            caption(3.0, 5.0, "third"),
+        ]);
+        let active = |time| {
+            track
+                .active_at(time)
+                .map(|(_, caption)| caption.text.clone())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(active(0.0), ["first"]);
+        assert_eq!(active(1.5), Vec::<String>::new());
+        assert_eq!(active(3.5), ["second", "third"]);
+        assert_eq!(active(4.0), ["third"]);
+        assert_eq!(active(5.0), Vec::<String>::new());
+    }
+
+    #[test]
+    fn caption_tracks_are_deserialized() {
+        let track: CaptionTrack = ron::from_str(
+            r#"(
+                captions: [
+                    (start: 1.0, end: 2.0, text: "[Door creaks]", priority: -1),
+                    (start: 0.0, end: 2.5, text: "Where is everybody?", speaker: Some("Alice")),
+                ],
+            )"#,
+        )
+        .unwrap();
+        // Deserializing doesn't sort the captions, the loader does.
+        assert_eq!(track.captions()[1].speaker.as_deref(), Some("Alice"));
+        assert_eq!(track.captions()[0].priority, -1);
+    }
+}
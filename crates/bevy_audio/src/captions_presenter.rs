@@ -0,0 +1,97 @@
+use alloc::format;
+
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_text::{TextColor, TextFont};
+use bevy_ui::{px, widget::Text, BackgroundColor, Node, UiRect};
+
+use crate::ActiveCaptions;
+
+/// A UI node showing the [`ActiveCaptions`], one line per caption, for instance as subtitles at
+/// the bottom of the screen.
+///
+/// At most [`max_visible`](Self::max_visible) captions are shown, choosing the captions with the
+/// highest [priority](crate::Caption::priority).
+///
+/// ```
+/// # use bevy_audio::CaptionPresenter;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{percent, px, AlignItems, FlexDirection, Node, PositionType};
+/// fn spawn_subtitles(mut commands: Commands) {
+///     commands.spawn((
+///         CaptionPresenter::default(),
+///         Node {
+///             position_type: PositionType::Absolute,
+///             bottom: px(40),
+///             width: percent(100),
+///             flex_direction: FlexDirection::Column,
+///             align_items: AlignItems::Center,
+///             ..Default::default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Clone, Debug, Default)]
+#[require(Node)]
+pub struct CaptionPresenter {
+    /// The maximum number of captions shown at the same time.
+    pub max_visible: usize,
+    /// Whether to show the name of the [speaker](crate::Caption::speaker) before the text.
+    pub show_speakers: bool,
+    /// The font of the captions.
+    pub font: TextFont,
+    /// The color of the text of the captions.
+    pub color: Color,
+    /// The color of the background of the captions, which keeps them readable over bright
+    /// scenes.
+    pub background: Color,
+}
+
+impl Default for CaptionPresenter {
+    fn default() -> Self {
+        Self {
+            max_visible: 3,
+            show_speakers: true,
+            font: TextFont::from_font_size(24.),
+            color: Color::WHITE,
+            background: Color::BLACK.with_alpha(0.75),
+        }
+    }
+}
+
+/// Updates the lines of the [`CaptionPresenter`]s when the [`ActiveCaptions`] change.
+pub(crate) fn present_captions(
+    mut commands: Commands,
+    presenters: Query<(Entity, Ref<CaptionPresenter>)>,
+    active_captions: Res<ActiveCaptions>,
+) {
+    for (entity, presenter) in &presenters {
+        if !presenter.is_changed() && !active_captions.is_changed() {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .despawn_related::<Children>()
+            .with_children(|parent| {
+                for active in active_captions.iter().take(presenter.max_visible) {
+                    let caption = &active.caption;
+                    let text = match caption.speaker.as_ref().filter(|_| presenter.show_speakers) {
+                        Some(speaker) => format!("{speaker}: {}", caption.text),
+                        None => caption.text.clone(),
+                    };
+                    parent.spawn((
+                        Text::new(text),
+                        presenter.font.clone(),
+                        TextColor(presenter.color),
+                        BackgroundColor(presenter.background),
+                        Node {
+                            padding: UiRect::axes(px(8), px(4)),
+                            ..Default::default()
+                        },
+                    ));
+                }
+            });
+    }
+}
@@ -0,0 +1,207 @@
+use alloc::{string::String, vec::Vec};
+use core::sync::atomic::Ordering;
+use core::time::Duration;
+
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use bevy_time::{Real, Time, Timer, TimerMode};
+use rodio::cpal::{
+    self,
+    traits::{DeviceTrait, HostTrait},
+};
+use tracing::warn;
+
+use crate::{
+    audio_output::AudioOutput, AudioBusSink, AudioSink, AudioSinkPlayback, SpatialAudioSink,
+};
+
+/// How often the output devices are enumerated, to detect the devices being plugged or unplugged and
+/// the default device changing.
+const DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The audio output device to play the sounds on.
+///
+/// Change this resource at runtime to switch to another device, for example from the audio
+/// settings of a game listing the [`AudioOutputDevices`]. The sounds playing when the device
+/// changes restart from the beginning on the new device.
+///
+/// When this is [`AudioOutputDevice::Default`], the sounds follow the default device of the
+/// system, for example when headphones are plugged or unplugged. When the selected device isn't
+/// available, the default device is used until the selected device becomes available again.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Clone, Debug, Default, PartialEq)]
+pub enum AudioOutputDevice {
+    /// The default output device of the system.
+    #[default]
+    Default,
+    /// The output device with this name, in [`AudioOutputDevices::devices`].
+    Named(String),
+}
+
+/// The output devices available on the system, enumerated regularly.
+///
+/// An [`AudioOutputDevicesChanged`] message is written when the devices change.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource, Clone, Debug, Default, PartialEq)]
+pub struct AudioOutputDevices {
+    /// The names of the output devices.
+    pub devices: Vec<String>,
+    /// The name of the default output device, if any.
+    pub default_device: Option<String>,
+    /// The name of the output device the sounds are played on, if any.
+    pub current_device: Option<String>,
+}
+
+/// A [`Message`] written when the [`AudioOutputDevices`] change.
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub struct AudioOutputDevicesChanged;
+
+/// A [`Message`] written when the sounds switch to another output device, because the
+/// [`AudioOutputDevice`] changed, or the device the sounds were played on was disconnected.
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub struct AudioOutputDeviceChanged {
+    /// The name of the new output device, or `None` if no device is available.
+    pub device: Option<String>,
+}
+
+/// The timer of the enumeration of the output devices.
+#[derive(Resource)]
+pub(crate) struct AudioDevicePoll(Timer);
+
+impl Default for AudioDevicePoll {
+    fn default() -> Self {
+        let mut timer = Timer::new(DEVICE_POLL_INTERVAL, TimerMode::Repeating);
+        // Enumerate the devices in the first frame.
+        timer.set_elapsed(DEVICE_POLL_INTERVAL);
+        Self(timer)
+    }
+}
+
+/// Returns the name of an audio device.
+pub(crate) fn device_name(device: &cpal::Device) -> Option<String> {
+    #[allow(
+        deprecated,
+        reason = "Device names are still the most portable way to identify devices"
+    )]
+    device.name().ok()
+}
+
+/// Enumerates the output devices of the default host.
+fn enumerate_devices() -> (Vec<String>, Option<String>) {
+    let host = cpal::default_host();
+    let devices = host
+        .output_devices()
+        .inspect_err(|err| warn!("Could not enumerate the audio output devices: {err}"))
+        .map(|devices| devices.filter_map(|device| device_name(&device)).collect())
+        .unwrap_or_default();
+    let default_device = host
+        .default_output_device()
+        .and_then(|device| device_name(&device));
+    (devices, default_device)
+}
+
+/// Returns whether the audio output should be reopened to play on the `selected` device.
+fn should_reopen(selected: &AudioOutputDevice, devices: &AudioOutputDevices) -> bool {
+    let current = devices.current_device.as_ref();
+    match selected {
+        AudioOutputDevice::Named(name) if devices.devices.contains(name) => current != Some(name),
+        _ => devices.default_device.is_some() && devices.default_device.as_ref() != current,
+    }
+}
+
+/// Follows the [`AudioOutputDevice`], and reopens the audio output when its device changes or is
+/// disconnected.
+pub(crate) fn update_audio_output(
+    mut audio_output: ResMut<AudioOutput>,
+    selected: Res<AudioOutputDevice>,
+    mut devices: ResMut<AudioOutputDevices>,
+    mut poll: ResMut<AudioDevicePoll>,
+    time: Res<Time<Real>>,
+    sinks: Query<(Entity, &AudioSink)>,
+    spatial_sinks: Query<(Entity, &SpatialAudioSink)>,
+    buses: Query<Entity, With<AudioBusSink>>,
+    mut devices_changed: MessageWriter<AudioOutputDevicesChanged>,
+    mut device_changed: MessageWriter<AudioOutputDeviceChanged>,
+    mut commands: Commands,
+) {
+    let failed = audio_output.stream_error.swap(false, Ordering::Relaxed);
+    if failed {
+        warn!("The audio output device failed or was disconnected.");
+    }
+    let polled = poll.0.tick(time.delta()).just_finished();
+    if polled || failed {
+        let (names, default_device) = enumerate_devices();
+        if names != devices.devices || default_device != devices.default_device {
+            devices.devices = names;
+            devices.default_device = default_device;
+            devices_changed.write(AudioOutputDevicesChanged);
+        }
+    }
+    if devices.current_device != audio_output.device_name {
+        devices.current_device = audio_output.device_name.clone();
+    }
+
+    let reopen = failed
+        || (selected.is_changed() && !selected.is_added())
+        || (polled && should_reopen(&selected, &devices));
+    if !reopen {
+        return;
+    }
+
+    *audio_output = AudioOutput::open(&selected);
+    devices.current_device = audio_output.device_name.clone();
+    device_changed.write(AudioOutputDeviceChanged {
+        device: audio_output.device_name.clone(),
+    });
+
+    // Restart the sounds and buses on the new device. The sounds which finished playing are
+    // left alone, so that they don't play again.
+    for (entity, sink) in &sinks {
+        if !sink.empty() {
+            commands.entity(entity).remove::<AudioSink>();
+        }
+    }
+    for (entity, sink) in &spatial_sinks {
+        if !sink.empty() {
+            commands.entity(entity).remove::<SpatialAudioSink>();
+        }
+    }
+    for entity in &buses {
+        commands.entity(entity).remove::<AudioBusSink>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{should_reopen, AudioOutputDevice, AudioOutputDevices};
+
+    #[test]
+    fn outputs_follow_the_selected_device() {
+        let mut devices = AudioOutputDevices {
+            devices: vec!["Speakers".into(), "Headphones".into()],
+            default_device: Some("Speakers".into()),
+            current_device: Some("Speakers".into()),
+        };
+        let headphones = AudioOutputDevice::Named("Headphones".into());
+        assert!(!should_reopen(&AudioOutputDevice::Default, &devices));
+        assert!(should_reopen(&headphones, &devices));
+
+        // The default device changes.
+        devices.default_device = Some("Headphones".into());
+        assert!(should_reopen(&AudioOutputDevice::Default, &devices));
+
+        // The selected device is unplugged, and the default device is used instead.
+        devices.current_device = Some("Headphones".into());
+        devices.devices.pop();
+        devices.default_device = Some("Speakers".into());
+        assert!(should_reopen(&headphones, &devices));
+        devices.current_device = Some("Speakers".into());
+        assert!(!should_reopen(&headphones, &devices));
+
+        // No device is available.
+        devices.devices.clear();
+        devices.default_device = None;
+        devices.current_device = None;
+        assert!(!should_reopen(&AudioOutputDevice::Default, &devices));
+    }
+}
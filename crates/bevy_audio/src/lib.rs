@@ -32,8 +32,14 @@ extern crate alloc;
 mod audio;
 mod audio_output;
 mod audio_source;
+mod bus;
+mod captions;
+#[cfg(feature = "bevy_ui")]
+mod captions_presenter;
+mod device;
 mod pitch;
 mod sinks;
+mod spatial;
 mod volume;
 
 /// The audio prelude.
@@ -42,14 +48,21 @@ mod volume;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        AudioPlayer, AudioSink, AudioSinkPlayback, AudioSource, Decodable, GlobalVolume, Pitch,
+        AudioBus, AudioCaptions, AudioEffect, AudioPlayer, AudioSink, AudioSinkPlayback,
+        AudioSource, Decodable, GlobalVolume, OnAudioBus, OnSpatialListener, Pitch,
         PlaybackSettings, SpatialAudioSink, SpatialListener,
     };
 }
 
 pub use audio::*;
 pub use audio_source::*;
+pub use bus::*;
+pub use captions::*;
+#[cfg(feature = "bevy_ui")]
+pub use captions_presenter::*;
+pub use device::*;
 pub use pitch::*;
+pub use spatial::*;
 pub use volume::*;
 
 pub use rodio::{cpal::Sample as CpalSample, source::Source, ChannelCount, Sample, SampleRate};
@@ -61,6 +74,9 @@ use bevy_ecs::prelude::*;
 use bevy_transform::TransformSystems;
 
 use audio_output::*;
+use captions::update_captions;
+use device::{update_audio_output, AudioDevicePoll};
+use spatial::{update_doppler_factors, update_spatial_velocities};
 
 /// Set for the audio playback systems, so they can share a run condition
 #[derive(SystemSet, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
@@ -76,12 +92,24 @@ pub struct AudioPlugin {
     /// The scale factor applied to the positions of audio sources and listeners for
     /// spatial audio.
     pub default_spatial_scale: SpatialScale,
+    /// The doppler effect applied to spatial audio.
+    pub doppler_effect: DopplerEffect,
+    /// The output device to play the audio on, which can be changed at runtime with the
+    /// [`AudioOutputDevice`] resource.
+    pub output_device: AudioOutputDevice,
 }
 
 impl Plugin for AudioPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(self.global_volume)
             .insert_resource(DefaultSpatialScale(self.default_spatial_scale))
+            .insert_resource(self.doppler_effect)
+            .insert_resource(self.output_device.clone())
+            .insert_resource(AudioOutput::open(&self.output_device))
+            .init_resource::<AudioOutputDevices>()
+            .init_resource::<AudioDevicePoll>()
+            .add_message::<AudioOutputDevicesChanged>()
+            .add_message::<AudioOutputDeviceChanged>()
             .configure_sets(
                 PostUpdate,
                 AudioPlaybackSystems
@@ -90,9 +118,29 @@ impl Plugin for AudioPlugin {
             )
             .add_systems(
                 PostUpdate,
-                (update_emitter_positions, update_listener_positions).in_set(AudioPlaybackSystems),
+                (
+                    update_emitter_positions,
+                    update_listener_positions,
+                    (update_spatial_velocities, update_doppler_factors).chain(),
+                    (update_audio_buses, create_audio_buses).chain(),
+                )
+                    .in_set(AudioPlaybackSystems),
             )
-            .init_resource::<AudioOutput>();
+            .add_systems(PostUpdate, update_audio_output.before(AudioPlaybackSystems))
+            .init_asset::<CaptionTrack>()
+            .init_asset_loader::<CaptionTrackLoader>()
+            .init_resource::<ActiveCaptions>()
+            .add_message::<CaptionStarted>()
+            .add_message::<CaptionEnded>()
+            .add_systems(PostUpdate, update_captions);
+
+        #[cfg(feature = "bevy_ui")]
+        app.add_systems(
+            PostUpdate,
+            captions_presenter::present_captions
+                .after(update_captions)
+                .before(bevy_ui::UiSystems::Prepare),
+        );
 
         #[cfg(any(feature = "mp3", feature = "flac", feature = "wav", feature = "vorbis"))]
         {
@@ -112,7 +160,10 @@ impl AddAudioSource for App {
     {
         self.init_asset::<T>().add_systems(
             PostUpdate,
-            (play_queued_audio_system::<T>, cleanup_finished_audio::<T>)
+            (
+                play_queued_audio_system::<T>.after(create_audio_buses),
+                cleanup_finished_audio::<T>,
+            )
                 .in_set(AudioPlaybackSystems),
         );
         self
@@ -255,6 +255,10 @@ pub struct SpatialAudioSink {
     /// user's intended volume setting, even if the underlying sink's volume is
     /// 0.
     pub(crate) managed_volume: Option<Volume>,
+
+    /// The factor of the [`DopplerEffect`](crate::DopplerEffect) by which the speed of the
+    /// underlying sink is multiplied.
+    doppler_factor: f32,
 }
 
 impl SpatialAudioSink {
@@ -263,8 +267,21 @@ impl SpatialAudioSink {
         Self {
             sink,
             managed_volume: None,
+            doppler_factor: 1.0,
         }
     }
+
+    /// Gets the factor of the [`DopplerEffect`](crate::DopplerEffect) applied to the speed of
+    /// the sound, on top of its [`speed`](AudioSinkPlayback::speed).
+    pub fn doppler_factor(&self) -> f32 {
+        self.doppler_factor
+    }
+
+    pub(crate) fn set_doppler_factor(&mut self, factor: f32) {
+        let speed = self.speed();
+        self.doppler_factor = factor;
+        self.sink.set_speed(speed * factor);
+    }
 }
 
 impl AudioSinkPlayback for SpatialAudioSink {
@@ -282,11 +299,11 @@ impl AudioSinkPlayback for SpatialAudioSink {
     }
 
     fn speed(&self) -> f32 {
-        self.sink.speed()
+        self.sink.speed() / self.doppler_factor
     }
 
     fn set_speed(&self, speed: f32) {
-        self.sink.set_speed(speed);
+        self.sink.set_speed(speed * self.doppler_factor);
     }
 
     fn play(&self) {
@@ -0,0 +1,255 @@
+use alloc::vec::Vec;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec3;
+use bevy_reflect::prelude::*;
+use bevy_time::Time;
+use bevy_transform::prelude::GlobalTransform;
+
+use crate::{audio_output::EarPositions, SpatialAudioSink};
+
+/// Selects the [`SpatialListener`](crate::SpatialListener) hearing the spatial sound of this
+/// entity, for example the listener of the player whose part of a split screen shows the entity.
+///
+/// Spatial sounds without this component are heard by the first listener.
+///
+/// If the listener is [`OnAudioBus`](crate::OnAudioBus), the sounds it hears are played on that
+/// bus, unless they are on a bus themselves. This allows playing the sounds heard by each listener
+/// on a different bus, to set their volume and effects separately.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+#[relationship(relationship_target = SpatialListenerEmitters)]
+pub struct OnSpatialListener(pub Entity);
+
+/// The spatial sounds [heard by](OnSpatialListener) a [`SpatialListener`](crate::SpatialListener).
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[relationship_target(relationship = OnSpatialListener)]
+pub struct SpatialListenerEmitters(Vec<Entity>);
+
+/// The velocity of a [`SpatialListener`](crate::SpatialListener) or a spatial sound, used for the
+/// [`DopplerEffect`].
+///
+/// The velocity is derived every frame from the changes of the [`GlobalTransform`]. This component
+/// is added to the listeners, and to the spatial sounds when they start playing.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq, Clone)]
+pub struct SpatialVelocity {
+    /// The velocity, in world units per second.
+    pub velocity: Vec3,
+    previous_translation: Option<Vec3>,
+}
+
+impl SpatialVelocity {
+    /// Resets the velocity to zero, for example after teleporting the entity, so that the jump
+    /// isn't heard as a sudden pitch change.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Settings of the doppler effect, which shifts the pitch of the spatial sounds moving towards or
+/// away from their [`SpatialListener`](crate::SpatialListener), according to their
+/// [`SpatialVelocity`].
+///
+/// The speed of the sounds is multiplied by the doppler factor, on top of the speed set with
+/// [`AudioSinkPlayback::set_speed`](crate::AudioSinkPlayback::set_speed).
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Debug, Default, PartialEq, Clone)]
+pub struct DopplerEffect {
+    /// The speed of sound, in world units per second.
+    ///
+    /// Defaults to 343, the speed of sound in air in meters per second.
+    pub speed_of_sound: f32,
+    /// The factor applied to the velocities, to exaggerate or reduce the effect. Zero disables it.
+    ///
+    /// Defaults to 1.0.
+    pub scale: f32,
+}
+
+impl Default for DopplerEffect {
+    fn default() -> Self {
+        Self {
+            speed_of_sound: 343.0,
+            scale: 1.0,
+        }
+    }
+}
+
+impl DopplerEffect {
+    /// A doppler effect disabled.
+    pub const DISABLED: Self = Self {
+        speed_of_sound: 343.0,
+        scale: 0.0,
+    };
+
+    /// The minimum and maximum doppler factor, which keep sounds moving near the speed of sound
+    /// audible.
+    const FACTOR_RANGE: (f32, f32) = (0.5, 2.0);
+
+    /// Returns the factor by which the speed of a sound emitted at `emitter` with `emitter_velocity`
+    /// is multiplied when heard at `listener` with `listener_velocity`.
+    pub fn factor(
+        &self,
+        emitter: Vec3,
+        emitter_velocity: Vec3,
+        listener: Vec3,
+        listener_velocity: Vec3,
+    ) -> f32 {
+        let Some(direction) = (listener - emitter).try_normalize() else {
+            return 1.0;
+        };
+        if self.scale == 0.0 || self.speed_of_sound <= 0.0 {
+            return 1.0;
+        }
+        // The speeds at which the listener moves towards the emitter, and the emitter towards the
+        // listener.
+        let listener_speed = -listener_velocity.dot(direction) * self.scale;
+        let emitter_speed = emitter_velocity.dot(direction) * self.scale;
+        let factor = (self.speed_of_sound + listener_speed)
+            / (self.speed_of_sound - emitter_speed).max(f32::EPSILON);
+        factor.clamp(Self::FACTOR_RANGE.0, Self::FACTOR_RANGE.1)
+    }
+}
+
+/// Derives the [`SpatialVelocity`]s from the changes of the [`GlobalTransform`]s.
+pub(crate) fn update_spatial_velocities(
+    mut query: Query<(&GlobalTransform, &mut SpatialVelocity)>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+    for (transform, mut velocity) in &mut query {
+        let translation = transform.translation();
+        let new_velocity = velocity
+            .previous_translation
+            .map(|previous| (translation - previous) / delta)
+            .unwrap_or_default();
+        // Avoid triggering change detection for static entities.
+        if velocity.velocity != new_velocity || velocity.previous_translation != Some(translation) {
+            velocity.velocity = new_velocity;
+            velocity.previous_translation = Some(translation);
+        }
+    }
+}
+
+/// Applies the [`DopplerEffect`] to the speed of the spatial sounds.
+pub(crate) fn update_doppler_factors(
+    mut emitters: Query<(
+        &mut SpatialAudioSink,
+        &GlobalTransform,
+        &SpatialVelocity,
+        Option<&OnSpatialListener>,
+    )>,
+    listeners: Query<Option<&SpatialVelocity>>,
+    ear_positions: EarPositions,
+    doppler_effect: Res<DopplerEffect>,
+) {
+    for (mut sink, transform, velocity, listener) in &mut emitters {
+        let Some((listener, listener_transform, ..)) =
+            ear_positions.listener(listener.map(|listener| listener.0))
+        else {
+            continue;
+        };
+        let listener_velocity = listeners
+            .get(listener)
+            .ok()
+            .flatten()
+            .map(|velocity| velocity.velocity)
+            .unwrap_or_default();
+        let factor = doppler_effect.factor(
+            transform.translation(),
+            velocity.velocity,
+            listener_transform.translation(),
+            listener_velocity,
+        );
+        if sink.doppler_factor() != factor {
+            sink.set_doppler_factor(factor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{update_spatial_velocities, DopplerEffect, SpatialVelocity};
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_math::Vec3;
+    use bevy_time::Time;
+    use bevy_transform::prelude::GlobalTransform;
+    use core::time::Duration;
+
+    #[test]
+    fn doppler_factors() {
+        let doppler = DopplerEffect {
+            speed_of_sound: 100.0,
+            scale: 1.0,
+        };
+        let emitter = Vec3::ZERO;
+        let listener = Vec3::new(10.0, 0.0, 0.0);
+
+        // Static, or moving sideways.
+        assert_eq!(
+            doppler.factor(emitter, Vec3::ZERO, listener, Vec3::ZERO),
+            1.0
+        );
+        assert_eq!(
+            doppler.factor(emitter, Vec3::Y * 50.0, listener, Vec3::ZERO),
+            1.0
+        );
+
+        // The emitter approaches the listener, then moves away.
+        assert_eq!(
+            doppler.factor(emitter, Vec3::X * 20.0, listener, Vec3::ZERO),
+            1.25
+        );
+        assert_eq!(
+            doppler.factor(emitter, Vec3::X * -25.0, listener, Vec3::ZERO),
+            0.8
+        );
+
+        // The listener approaches the emitter.
+        assert_eq!(
+            doppler.factor(emitter, Vec3::ZERO, listener, Vec3::X * -50.0),
+            1.5
+        );
+
+        // Moving at the speed of sound is clamped.
+        assert_eq!(
+            doppler.factor(emitter, Vec3::X * 100.0, listener, Vec3::ZERO),
+            2.0
+        );
+
+        assert_eq!(
+            DopplerEffect::DISABLED.factor(emitter, Vec3::X * 20.0, listener, Vec3::ZERO),
+            1.0
+        );
+    }
+
+    #[test]
+    fn velocities_are_derived_from_transforms() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let entity = world
+            .spawn((GlobalTransform::default(), SpatialVelocity::default()))
+            .id();
+
+        let advance = |world: &mut World, translation: Vec3| {
+            world
+                .entity_mut(entity)
+                .insert(GlobalTransform::from_translation(translation));
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(500));
+            world.run_system_once(update_spatial_velocities).unwrap();
+            world.get::<SpatialVelocity>(entity).unwrap().velocity
+        };
+
+        assert_eq!(advance(&mut world, Vec3::ZERO), Vec3::ZERO);
+        assert_eq!(advance(&mut world, Vec3::X), Vec3::X * 2.0);
+        assert_eq!(advance(&mut world, Vec3::X), Vec3::ZERO);
+
+        world.get_mut::<SpatialVelocity>(entity).unwrap().reset();
+        assert_eq!(advance(&mut world, Vec3::Y * 100.0), Vec3::ZERO);
+    }
+}
@@ -4,6 +4,8 @@ mod clear_color;
 mod components;
 pub mod primitives;
 mod projection;
+mod projection_transition;
+mod view_model;
 pub mod visibility;
 
 use bevy_ecs::schedule::SystemSet;
@@ -11,6 +13,8 @@ pub use camera::*;
 pub use clear_color::*;
 pub use components::*;
 pub use projection::*;
+pub use projection_transition::*;
+pub use view_model::*;
 
 use bevy_app::{App, Plugin};
 
@@ -23,6 +27,8 @@ impl Plugin for CameraPlugin {
             CameraProjectionPlugin,
             visibility::VisibilityPlugin,
             visibility::VisibilityRangePlugin,
+            ProjectionTransitionPlugin,
+            ViewModelPlugin,
         ));
     }
 }
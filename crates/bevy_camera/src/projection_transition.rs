@@ -0,0 +1,380 @@
+use crate::{
+    CameraProjection, CameraUpdateSystems, OrthographicProjection, PerspectiveProjection,
+    Projection, SubCameraView,
+};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::prelude::*;
+use bevy_math::{
+    curve::{Curve, EaseFunction},
+    Mat4, Vec3A,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::Time;
+
+/// A plugin animating the [`ProjectionTransition`]s of the cameras.
+pub struct ProjectionTransitionPlugin;
+
+impl Plugin for ProjectionTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            update_projection_transitions.before(CameraUpdateSystems),
+        );
+    }
+}
+
+/// A [`CameraProjection`] blending a [`PerspectiveProjection`] and an [`OrthographicProjection`],
+/// for the cameras switching between them, such as those of strategy games switching between a
+/// free view and a map view.
+///
+/// The projection matrices are interpolated, after scaling the perspective one so both agree at
+/// the [`focus_distance`](Self::focus_distance): the objects at that distance keep their size on
+/// the screen if the orthographic projection covers the same area there, the nearer ones shrink
+/// and the farther ones grow as the projection becomes orthographic. The depth is remapped so it
+/// stays between `0.0` at the far plane and `1.0` at the near plane of the
+/// [`perspective`](Self::perspective) projection, and the frustum used for culling and for the
+/// shadow cascades is computed from the blended matrix.
+///
+/// At both ends of the blend, the projection is exactly the perspective or the orthographic one.
+/// In between, the clip plane of the perspective projection is ignored.
+///
+/// Add it with [`Projection::custom`], and animate it with a [`ProjectionTransition`].
+#[derive(Debug, Clone, Reflect)]
+#[reflect(Default, Debug, Clone)]
+pub struct BlendedProjection {
+    /// The projection when [`blend`](Self::blend) is `0.0`.
+    pub perspective: PerspectiveProjection,
+    /// The projection when [`blend`](Self::blend) is `1.0`.
+    pub orthographic: OrthographicProjection,
+    /// The distance from the camera at which both projections agree.
+    ///
+    /// Defaults to `10.0`.
+    pub focus_distance: f32,
+    /// How orthographic the projection is, from `0.0` for perspective to `1.0` for orthographic.
+    pub blend: f32,
+}
+
+impl Default for BlendedProjection {
+    fn default() -> Self {
+        Self {
+            perspective: PerspectiveProjection::default(),
+            orthographic: OrthographicProjection::default_3d(),
+            focus_distance: 10.0,
+            blend: 0.0,
+        }
+    }
+}
+
+impl BlendedProjection {
+    /// Returns the [`BlendedProjection`] of a [`Projection`], if any.
+    pub fn get(projection: &Projection) -> Option<&Self> {
+        match projection {
+            Projection::Custom(custom) => custom.get(),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`BlendedProjection`] of a [`Projection`] mutably, if any.
+    pub fn get_mut(projection: &mut Projection) -> Option<&mut Self> {
+        match projection {
+            Projection::Custom(custom) => custom.get_mut(),
+            _ => None,
+        }
+    }
+
+    fn blend_matrices(&self, perspective: Mat4, orthographic: Mat4) -> Mat4 {
+        let t = self.blend;
+        if t <= 0.0 {
+            return perspective;
+        }
+        if t >= 1.0 {
+            return orthographic;
+        }
+
+        // Scales the perspective matrix so its `w` is `1.0` at the focus distance, like the
+        // orthographic one. This doesn't change the projection itself.
+        let mut matrix =
+            perspective * ((1.0 - t) / self.focus_distance.max(f32::EPSILON)) + orthographic * t;
+
+        // The interpolated depth isn't monotonic, so it is replaced by one going from `1.0` at the
+        // near plane to `0.0` at the far plane, for the interpolated `w`.
+        let near = self.perspective.near;
+        let far = self.far();
+        let w_near = matrix.z_axis.w * -near + matrix.w_axis.w;
+        let scale = w_near / (far - near);
+        matrix.x_axis.z = 0.0;
+        matrix.y_axis.z = 0.0;
+        matrix.z_axis.z = scale;
+        matrix.w_axis.z = scale * far;
+        matrix
+    }
+}
+
+impl CameraProjection for BlendedProjection {
+    fn get_clip_from_view(&self) -> Mat4 {
+        self.blend_matrices(
+            self.perspective.get_clip_from_view(),
+            self.orthographic.get_clip_from_view(),
+        )
+    }
+
+    fn get_clip_from_view_for_sub(&self, sub_view: &SubCameraView) -> Mat4 {
+        self.blend_matrices(
+            self.perspective.get_clip_from_view_for_sub(sub_view),
+            self.orthographic.get_clip_from_view_for_sub(sub_view),
+        )
+    }
+
+    fn update(&mut self, width: f32, height: f32) {
+        self.perspective.update(width, height);
+        self.orthographic.update(width, height);
+    }
+
+    fn far(&self) -> f32 {
+        self.perspective.far.max(self.orthographic.far)
+    }
+
+    fn get_frustum_corners(&self, z_near: f32, z_far: f32) -> [Vec3A; 8] {
+        let matrix = self.get_clip_from_view();
+        // Solves the `x` and `y` projecting to the edges of the view at the depth `z`.
+        let corner = |x: f32, y: f32, z: f32| {
+            let w = matrix.z_axis.w * z + matrix.w_axis.w;
+            Vec3A::new(
+                (x * w - matrix.z_axis.x * z - matrix.w_axis.x) / matrix.x_axis.x,
+                (y * w - matrix.z_axis.y * z - matrix.w_axis.y) / matrix.y_axis.y,
+                z,
+            )
+        };
+        // NOTE: These vertices are in the specific order required by [`calculate_cascade`].
+        [
+            corner(1.0, -1.0, z_near),  // bottom right
+            corner(1.0, 1.0, z_near),   // top right
+            corner(-1.0, 1.0, z_near),  // top left
+            corner(-1.0, -1.0, z_near), // bottom left
+            corner(1.0, -1.0, z_far),   // bottom right
+            corner(1.0, 1.0, z_far),    // top right
+            corner(-1.0, 1.0, z_far),   // top left
+            corner(-1.0, -1.0, z_far),  // bottom left
+        ]
+    }
+}
+
+/// Animates the [`BlendedProjection::blend`] of the [`Projection`] of a camera toward a target,
+/// with an easing curve.
+///
+/// If the [`Projection`] of the camera isn't a [`BlendedProjection`] when the transition starts,
+/// it is replaced by one, keeping the perspective or orthographic projection the camera had.
+///
+/// ```
+/// # use bevy_camera::{ProjectionTransition};
+/// # use bevy_ecs::prelude::*;
+/// # fn system(mut commands: Commands, camera: Entity) {
+/// commands
+///     .entity(camera)
+///     .insert(ProjectionTransition::to_orthographic(0.6));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+pub struct ProjectionTransition {
+    /// The [`BlendedProjection::blend`] at the end of the transition.
+    pub target: f32,
+    /// The duration of the transition, in seconds.
+    pub duration: f32,
+    /// The easing of the transition.
+    ///
+    /// Defaults to [`EaseFunction::CubicInOut`].
+    pub ease: EaseFunction,
+    /// The time since the start of the transition, in seconds.
+    pub elapsed: f32,
+    /// The blend at the start of the transition, set when it starts.
+    ///
+    /// Changing the target of a started transition doesn't restart it; insert a new transition
+    /// instead.
+    #[reflect(ignore)]
+    start: Option<f32>,
+}
+
+impl Default for ProjectionTransition {
+    fn default() -> Self {
+        Self {
+            target: 1.0,
+            duration: 0.5,
+            ease: EaseFunction::CubicInOut,
+            elapsed: 0.0,
+            start: None,
+        }
+    }
+}
+
+impl ProjectionTransition {
+    /// Transitions to the orthographic projection in `duration` seconds.
+    pub fn to_orthographic(duration: f32) -> Self {
+        Self {
+            target: 1.0,
+            duration,
+            ..Default::default()
+        }
+    }
+
+    /// Transitions to the perspective projection in `duration` seconds.
+    pub fn to_perspective(duration: f32) -> Self {
+        Self {
+            target: 0.0,
+            duration,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the progress of the transition, from `0.0` to `1.0` once finished.
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns whether the transition is finished.
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
+
+/// Advances the [`ProjectionTransition`]s, and updates the [`BlendedProjection`] of their camera.
+pub fn update_projection_transitions(
+    time: Res<Time>,
+    mut cameras: Query<(&mut ProjectionTransition, &mut Projection)>,
+) {
+    for (mut transition, mut projection) in &mut cameras {
+        if transition.is_finished() && transition.start.is_some() {
+            continue;
+        }
+
+        let blended = match BlendedProjection::get(&projection) {
+            Some(blended) => blended.clone(),
+            None => {
+                let mut blended = BlendedProjection::default();
+                match &*projection {
+                    Projection::Perspective(perspective) => {
+                        blended.perspective = perspective.clone();
+                    }
+                    Projection::Orthographic(orthographic) => {
+                        blended.orthographic = orthographic.clone();
+                        blended.blend = 1.0;
+                    }
+                    // Custom projections can't be blended.
+                    Projection::Custom(_) => continue,
+                }
+                blended
+            }
+        };
+
+        let start = *transition.start.get_or_insert(blended.blend);
+        transition.elapsed += time.delta_secs();
+        let eased = transition.ease.sample_clamped(transition.progress());
+        let blend = start + (transition.target - start) * eased;
+
+        match BlendedProjection::get_mut(&mut projection) {
+            Some(current) => current.blend = blend,
+            None => {
+                *projection = Projection::custom(BlendedProjection { blend, ..blended });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::{ops, Vec3, Vec4};
+    use core::time::Duration;
+
+    /// A blended projection whose orthographic projection covers the same area as the perspective
+    /// one at the focus distance.
+    fn matching_projection(blend: f32) -> BlendedProjection {
+        let mut projection = BlendedProjection {
+            blend,
+            ..Default::default()
+        };
+        projection.update(1600.0, 900.0);
+        let height = 2.0 * projection.focus_distance * ops::tan(projection.perspective.fov / 2.0);
+        projection.orthographic.scale = height / 900.0;
+        projection.update(1600.0, 900.0);
+        projection
+    }
+
+    fn project(matrix: Mat4, point: Vec3) -> Vec3 {
+        let clip = matrix * Vec4::from((point, 1.0));
+        clip.truncate() / clip.w
+    }
+
+    #[test]
+    fn focus_plane_keeps_its_size() {
+        let point = Vec3::new(2.0, 1.5, -10.0);
+        let expected = project(matching_projection(0.0).get_clip_from_view(), point);
+        for blend in [0.25, 0.5, 0.75, 1.0] {
+            let projected = project(matching_projection(blend).get_clip_from_view(), point);
+            assert!(projected.truncate().abs_diff_eq(expected.truncate(), 1e-4));
+        }
+    }
+
+    #[test]
+    fn depth_stays_in_range_and_ordered() {
+        let projection = matching_projection(0.5);
+        let matrix = projection.get_clip_from_view();
+        let mut last = f32::INFINITY;
+        for distance in [0.2, 1.0, 10.0, 100.0, 999.0] {
+            let depth = project(matrix, Vec3::new(0.0, 0.0, -distance)).z;
+            assert!((0.0..=1.0).contains(&depth));
+            assert!(depth < last);
+            last = depth;
+        }
+
+        // The frustum corners are at the edges of the view.
+        for corner in projection.get_frustum_corners(-1.0, -50.0) {
+            let projected = project(matrix, corner.into());
+            assert!((projected.x.abs() - 1.0).abs() < 1e-4);
+            assert!((projected.y.abs() - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn transition_to_orthographic() {
+        let mut app = App::new();
+        app.add_plugins(ProjectionTransitionPlugin)
+            .init_resource::<Time>();
+        let camera = app
+            .world_mut()
+            .spawn((
+                Projection::default(),
+                ProjectionTransition::to_orthographic(1.0),
+            ))
+            .id();
+
+        let blend = |app: &App| {
+            BlendedProjection::get(app.world().get::<Projection>(camera).unwrap())
+                .unwrap()
+                .blend
+        };
+        app.update();
+        assert_eq!(blend(&app), 0.0);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        app.update();
+        assert!((blend(&app) - 0.5).abs() < 1e-4);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(1));
+        app.update();
+        assert_eq!(blend(&app), 1.0);
+        assert!(app
+            .world()
+            .get::<ProjectionTransition>(camera)
+            .unwrap()
+            .is_finished());
+    }
+}
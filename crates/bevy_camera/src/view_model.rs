@@ -0,0 +1,222 @@
+use crate::{
+    visibility::{Layer, RenderLayers},
+    Camera, Camera3d, Camera3dDepthLoadOp, CameraUpdateSystems, ClearColorConfig, Hdr,
+    PerspectiveProjection, Projection, RenderTarget, Viewport,
+};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::prelude::*;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// The [`RenderLayers`] layer of the view model of a [`ViewModelCamera`] by default.
+pub const VIEW_MODEL_RENDER_LAYER: Layer = 1;
+
+/// A plugin keeping [`ViewModelCamera`]s in sync with the camera they are composited over.
+pub struct ViewModelPlugin;
+
+impl Plugin for ViewModelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            update_view_model_cameras.before(CameraUpdateSystems),
+        );
+    }
+}
+
+/// A camera rendering the *view model* of a first-person 3D camera, such as the weapon and the
+/// hands of the player, over the *world model* rendered by the camera it is a child of.
+///
+/// The view model is rendered with its own field of view, which is usually fixed as the models are
+/// authored for it, while the world model camera's can be changed by the player. It is rendered
+/// after the world model with the depth buffer cleared, so it never clips into the walls the player
+/// stands against.
+///
+/// The view model is made of the entities on the [`RenderLayers`] of this camera, by default
+/// [`VIEW_MODEL_RENDER_LAYER`], which are usually children of the player too. The lights must be
+/// on both the layers of the world model and of the view model to light both, and the view model
+/// should have `NotShadowCaster` so it doesn't cast its shadows on the world.
+///
+/// The order, activity, target, viewport and [`Hdr`] of this camera follow those of the parent
+/// camera, and the clear color is set to [`ClearColorConfig::None`].
+///
+/// ```
+/// # use bevy_camera::{Camera3d, ViewModelCamera};
+/// # use bevy_ecs::prelude::*;
+/// # fn system(mut commands: Commands) {
+/// commands.spawn((
+///     Camera3d::default(),
+///     children![ViewModelCamera::default()],
+/// ));
+/// # }
+/// ```
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+#[require(Camera3d, RenderLayers = RenderLayers::layer(VIEW_MODEL_RENDER_LAYER))]
+pub struct ViewModelCamera {
+    /// The vertical field of view of the view model, in radians.
+    ///
+    /// Defaults to 70 degrees.
+    pub fov: f32,
+    /// The distance from the camera under which the view model is clipped.
+    ///
+    /// Defaults to `0.01`, as the view model is usually very close to the camera.
+    pub near: f32,
+    /// The distance from the camera beyond which the view model is culled.
+    ///
+    /// Defaults to `10.0`.
+    pub far: f32,
+}
+
+impl Default for ViewModelCamera {
+    fn default() -> Self {
+        Self {
+            fov: 70.0_f32.to_radians(),
+            near: 0.01,
+            far: 10.0,
+        }
+    }
+}
+
+/// Copies the settings of the parent camera of the [`ViewModelCamera`]s, and updates their
+/// [`Projection`].
+pub fn update_view_model_cameras(
+    mut commands: Commands,
+    mut view_models: Query<(
+        Entity,
+        &ViewModelCamera,
+        &ChildOf,
+        &mut Camera,
+        &mut Camera3d,
+        &mut Projection,
+        &mut RenderTarget,
+        Has<Hdr>,
+    )>,
+    world_models: Query<(&Camera, &RenderTarget, Has<Hdr>), Without<ViewModelCamera>>,
+) {
+    for (
+        entity,
+        view_model,
+        child_of,
+        mut camera,
+        mut camera_3d,
+        mut projection,
+        mut target,
+        hdr,
+    ) in &mut view_models
+    {
+        let Ok((world_camera, world_target, world_hdr)) = world_models.get(child_of.parent())
+        else {
+            continue;
+        };
+
+        if camera.order != world_camera.order + 1 {
+            camera.order = world_camera.order + 1;
+        }
+        if camera.is_active != world_camera.is_active {
+            camera.is_active = world_camera.is_active;
+        }
+        if !same_viewport(camera.viewport.as_ref(), world_camera.viewport.as_ref()) {
+            camera.viewport = world_camera.viewport.clone();
+        }
+        if !matches!(camera.clear_color, ClearColorConfig::None) {
+            camera.clear_color = ClearColorConfig::None;
+        }
+        if !matches!(camera_3d.depth_load_op, Camera3dDepthLoadOp::Clear(_)) {
+            camera_3d.depth_load_op = Camera3dDepthLoadOp::Clear(0.0);
+        }
+        // Only the normalized targets can be compared.
+        if target.normalize(None) != world_target.normalize(None) {
+            *target = world_target.clone();
+        }
+        if hdr != world_hdr {
+            if world_hdr {
+                commands.entity(entity).insert(Hdr);
+            } else {
+                commands.entity(entity).remove::<Hdr>();
+            }
+        }
+
+        let up_to_date = matches!(
+            &*projection,
+            Projection::Perspective(perspective)
+                if perspective.fov == view_model.fov
+                    && perspective.near == view_model.near
+                    && perspective.far == view_model.far
+        );
+        if !up_to_date {
+            if let Projection::Perspective(perspective) = &mut *projection {
+                // The aspect ratio is kept, as it is computed from the viewport.
+                perspective.fov = view_model.fov;
+                perspective.near = view_model.near;
+                perspective.far = view_model.far;
+            } else {
+                *projection = Projection::Perspective(PerspectiveProjection {
+                    fov: view_model.fov,
+                    near: view_model.near,
+                    far: view_model.far,
+                    ..PerspectiveProjection::default()
+                });
+            }
+        }
+    }
+}
+
+fn same_viewport(a: Option<&Viewport>, b: Option<&Viewport>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            a.physical_position == b.physical_position
+                && a.physical_size == b.physical_size
+                && a.depth == b.depth
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_model_follows_world_model_camera() {
+        let mut app = App::new();
+        app.add_plugins(ViewModelPlugin);
+
+        let world_model = app
+            .world_mut()
+            .spawn((
+                Camera3d::default(),
+                Camera {
+                    order: 2,
+                    is_active: false,
+                    ..Default::default()
+                },
+                Hdr,
+            ))
+            .id();
+        let view_model = app
+            .world_mut()
+            .spawn((ViewModelCamera::default(), ChildOf(world_model)))
+            .id();
+        app.update();
+
+        let entity = app.world().entity(view_model);
+        let camera = entity.get::<Camera>().unwrap();
+        assert_eq!(camera.order, 3);
+        assert!(!camera.is_active);
+        assert!(matches!(camera.clear_color, ClearColorConfig::None));
+        assert!(entity.contains::<Hdr>());
+        assert_eq!(
+            entity.get::<RenderLayers>(),
+            Some(&RenderLayers::layer(VIEW_MODEL_RENDER_LAYER))
+        );
+        let Some(Projection::Perspective(projection)) = entity.get::<Projection>() else {
+            panic!("The view model projection should be perspective");
+        };
+        assert_eq!(projection.fov, 70.0_f32.to_radians());
+        assert_eq!(projection.near, 0.01);
+
+        app.world_mut().entity_mut(world_model).remove::<Hdr>();
+        app.update();
+        assert!(!app.world().entity(view_model).contains::<Hdr>());
+    }
+}
@@ -35,6 +35,10 @@ use core::any::TypeId;
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::lifecycle::HookContext;
 use bevy_ecs::world::DeferredWorld;
+#[cfg(feature = "morph")]
+use bevy_math::Vec3;
+#[cfg(feature = "morph")]
+use bevy_mesh::morph::{MeshMorphWeights, MorphWeights};
 use bevy_mesh::skinning::{
     entity_aabb_from_skinned_mesh_bounds, SkinnedMesh, SkinnedMeshInverseBindposes,
 };
@@ -324,12 +328,30 @@ pub struct NoFrustumCulling;
 /// `DynamicSkinnedMeshBounds` depends on data from `Mesh::skinned_mesh_bounds`
 /// and `SkinnedMesh`. The resulting `Aabb` will reliably enclose meshes where
 /// vertex positions are only affected by skinning. But the `Aabb` may be larger
-/// than is optimal, and doesn't account for morph targets, vertex shaders, and
-/// anything else that modifies vertex positions.
+/// than is optimal, and doesn't account for vertex shaders or anything else
+/// that modifies vertex positions. Morph targets are accounted for by also
+/// adding a `DynamicMorphedMeshBounds`.
 #[derive(Debug, Component, Default, Reflect)]
 #[reflect(Component, Default, Debug)]
 pub struct DynamicSkinnedMeshBounds;
 
+/// Use this component to enable dynamic morph target bounds. The [`Aabb`]
+/// component of the mesh will be automatically grown each frame by how far the
+/// current [`MeshMorphWeights`] can move its vertices.
+///
+/// `DynamicMorphedMeshBounds` depends on data from `Mesh::morph_target_bounds`,
+/// see `Mesh::generate_morph_target_bounds`. The resulting `Aabb` is
+/// conservative: it assumes every vertex may be moved as far as the furthest
+/// displaced vertex of each morph target.
+///
+/// This can be combined with [`DynamicSkinnedMeshBounds`], in which case the
+/// skinned `Aabb` is grown in all directions by the largest displacement. This
+/// assumes the joints don't scale the mesh up.
+#[cfg(feature = "morph")]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default, Debug)]
+pub struct DynamicMorphedMeshBounds;
+
 /// Collection of entities visible from the current view.
 ///
 /// This component contains all entities which are visible from the currently
@@ -523,7 +545,12 @@ impl Plugin for VisibilityPlugin {
             .add_systems(
                 PostUpdate,
                 (
-                    (calculate_bounds, update_skinned_mesh_bounds)
+                    (
+                        calculate_bounds,
+                        update_skinned_mesh_bounds,
+                        #[cfg(feature = "morph")]
+                        update_morphed_mesh_bounds,
+                    )
                         .chain()
                         .in_set(CalculateBounds),
                     (visibility_propagate_system, reset_view_visibility)
@@ -621,6 +648,73 @@ fn update_skinned_mesh_bounds(
         });
 }
 
+// Grow the `Aabb` component of all morphed mesh entities with a
+// `DynamicMorphedMeshBounds` component by their current morph displacements.
+#[cfg(feature = "morph")]
+fn update_morphed_mesh_bounds(
+    mesh_assets: Res<Assets<Mesh>>,
+    mut mesh_entities: Query<
+        (
+            &mut Aabb,
+            &Mesh3d,
+            &MeshMorphWeights,
+            Has<DynamicSkinnedMeshBounds>,
+        ),
+        With<DynamicMorphedMeshBounds>,
+    >,
+    morph_weights: Query<&MorphWeights>,
+) {
+    mesh_entities
+        .par_iter_mut()
+        .for_each(|(mut aabb, mesh, mesh_morph_weights, skinned)| {
+            let Some(mesh_asset) = mesh_assets.get(mesh) else {
+                return;
+            };
+            let Some(morph_target_bounds) = mesh_asset.morph_target_bounds() else {
+                return;
+            };
+            let weights = match mesh_morph_weights {
+                MeshMorphWeights::Value { weights } => weights.as_slice(),
+                MeshMorphWeights::Reference(entity) => match morph_weights.get(*entity) {
+                    Ok(morph_weights) => morph_weights.weights(),
+                    Err(_) => return,
+                },
+            };
+            let (min, max) = morph_target_bounds.displacement_bounds(weights);
+
+            // Skinned bounds are recalculated every frame, others start from the
+            // bounds of the mesh without morph targets. Skinned bounds that
+            // weren't updated this frame have already been grown.
+            let base = if skinned {
+                if !aabb.is_changed() {
+                    return;
+                }
+                *aabb
+            } else {
+                let Some(base) = morph_target_bounds
+                    .base_aabb
+                    .map(Aabb::from)
+                    .or_else(|| mesh_asset.get_aabb())
+                else {
+                    return;
+                };
+                base
+            };
+            let morphed_aabb = if skinned {
+                let radius = min.abs().max(max.abs()).length();
+                Aabb::from_min_max(
+                    Vec3::from(base.min()) - radius,
+                    Vec3::from(base.max()) + radius,
+                )
+            } else {
+                Aabb::from_min_max(Vec3::from(base.min()) + min, Vec3::from(base.max()) + max)
+            };
+            if *aabb != morphed_aabb {
+                *aabb = morphed_aabb;
+            }
+        });
+}
+
 /// Updates [`Frustum`].
 ///
 /// This system is used in [`CameraProjectionPlugin`](crate::CameraProjectionPlugin).
@@ -6,8 +6,9 @@ use core::{
     ops::Range,
 };
 
-use bevy_app::{App, Plugin, PostUpdate};
+use bevy_app::{App, HierarchyPropagatePlugin, Plugin, PostUpdate, PropagateSet};
 use bevy_ecs::{
+    change_detection::DetectChangesMut as _,
     component::Component,
     entity::{Entity, EntityHashMap},
     query::{Or, With, Without},
@@ -26,16 +27,27 @@ use crate::{camera::Camera, primitives::Aabb, visibility::NoCpuCulling, ShadowLo
 
 /// A plugin that enables [`VisibilityRange`]s, which allow entities to be
 /// hidden or shown based on distance to the camera.
+///
+/// This also propagates [`Propagate<VisibilityRange>`](bevy_app::Propagate)
+/// down the hierarchy, and keeps the ranges of [`VisibilityRangeProxy`]s in
+/// sync with their groups.
 pub struct VisibilityRangePlugin;
 
 impl Plugin for VisibilityRangePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<VisibleEntityRanges>().add_systems(
-            PostUpdate,
-            check_visibility_ranges
-                .in_set(VisibilitySystems::CheckVisibility)
-                .before(check_visibility_cpu_culling),
-        );
+        app.init_resource::<VisibleEntityRanges>()
+            .add_plugins(HierarchyPropagatePlugin::<VisibilityRange>::new(PostUpdate))
+            .add_systems(
+                PostUpdate,
+                (
+                    update_visibility_range_proxies
+                        .after(PropagateSet::<VisibilityRange>::default()),
+                    check_visibility_ranges
+                        .in_set(VisibilitySystems::CheckVisibility)
+                        .before(check_visibility_cpu_culling),
+                )
+                    .chain(),
+            );
     }
 }
 
@@ -75,8 +87,17 @@ impl Plugin for VisibilityRangePlugin {
 /// that the `end_margin` of a higher LOD is always identical to the
 /// `start_margin` of the next lower LOD; this is important for the crossfade
 /// effect to function properly.
-#[derive(Component, Clone, PartialEq, Default, Reflect)]
-#[reflect(Component, PartialEq, Hash, Clone)]
+///
+/// Rather than adding a [`VisibilityRange`] to every mesh of a LOD, you can put
+/// a [`Propagate<VisibilityRange>`](bevy_app::Propagate) on the root of the LOD
+/// and it will be copied to all of its descendants. A [`VisibilityRangeProxy`]
+/// can then stand in for the whole group once it has faded out.
+///
+/// Meshes with an opaque or alpha masked material fade using a dithering
+/// pattern, while meshes with an alpha blended material fade by scaling their
+/// alpha instead.
+#[derive(Component, Clone, PartialEq, Debug, Default, Reflect)]
+#[reflect(Component, PartialEq, Hash, Debug, Clone)]
 pub struct VisibilityRange {
     /// The range of distances, in world units, between which this entity will
     /// smoothly fade into view as the camera zooms out.
@@ -170,6 +191,76 @@ impl VisibilityRange {
     }
 }
 
+/// Makes this entity the *HLOD proxy* of a group of entities: a single,
+/// usually cheaper, stand-in that is shown exactly when the group is hidden.
+///
+/// The [`VisibilityRange`] of this entity is kept up to date so that it starts
+/// fading in over the `end_margin` of the group's [`VisibilityRange`], and
+/// fades out over this component's `end_margin`.
+///
+/// The `group` is usually an entity with a
+/// [`Propagate<VisibilityRange>`](bevy_app::Propagate), so that all of its
+/// descendants fade out together. The proxy shouldn't be one of these
+/// descendants, or the propagated range would fight with its own.
+///
+/// ```
+/// # use bevy_app::Propagate;
+/// # use bevy_camera::visibility::{VisibilityRange, VisibilityRangeProxy};
+/// # use bevy_ecs::prelude::*;
+/// # let mut world = World::new();
+/// // The detailed buildings of a city block are shown closer than 100 units...
+/// let block = world
+///     .spawn(Propagate(VisibilityRange {
+///         start_margin: 0.0..0.0,
+///         end_margin: 100.0..110.0,
+///         use_aabb: false,
+///     }))
+///     .id();
+/// // ...and replaced by a single merged mesh, up to 500 units.
+/// world.spawn(VisibilityRangeProxy::new(block, 500.0..520.0));
+/// ```
+#[derive(Component, Clone, PartialEq, Debug, Reflect)]
+#[reflect(Component, PartialEq, Debug, Clone)]
+#[require(VisibilityRange)]
+pub struct VisibilityRangeProxy {
+    /// The entity whose [`VisibilityRange`] this proxy takes over from.
+    pub group: Entity,
+
+    /// The range of distances, in world units, between which the proxy itself
+    /// fades out of view as the camera zooms out.
+    pub end_margin: Range<f32>,
+}
+
+impl VisibilityRangeProxy {
+    /// Creates a proxy for `group` that fades out over `end_margin`.
+    #[inline]
+    pub fn new(group: Entity, end_margin: Range<f32>) -> Self {
+        Self { group, end_margin }
+    }
+}
+
+/// Updates the [`VisibilityRange`] of each [`VisibilityRangeProxy`] to start
+/// where the [`VisibilityRange`] of its group ends.
+pub fn update_visibility_range_proxies(
+    proxies: Query<(Entity, &VisibilityRangeProxy)>,
+    mut ranges: Query<&mut VisibilityRange>,
+) {
+    for (entity, proxy) in &proxies {
+        let Ok(group_range) = ranges.get(proxy.group) else {
+            continue;
+        };
+        let range = VisibilityRange {
+            start_margin: group_range.end_margin.clone(),
+            end_margin: proxy.end_margin.clone(),
+            // Crossfades need both sides to measure the distance the same way.
+            use_aabb: group_range.use_aabb,
+        };
+        if let Ok(mut proxy_range) = ranges.get_mut(entity) {
+            proxy_range.set_if_neq(range);
+        }
+    }
+}
+
 /// Stores which entities are in within the [`VisibilityRange`]s of views.
 ///
 /// This doesn't store the results of frustum or occlusion culling; use
@@ -282,3 +373,41 @@ pub fn check_visibility_ranges(
 
     visible_entity_ranges.entities.extend(par_local.drain());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::Propagate;
+    use bevy_ecs::{hierarchy::ChildOf, world::World};
+
+    #[test]
+    fn proxy_starts_where_propagated_group_ends() {
+        let mut app = App::new();
+        app.add_plugins(VisibilityRangePlugin);
+
+        let group_range = VisibilityRange {
+            start_margin: 0.0..0.0,
+            end_margin: 20.0..25.0,
+            use_aabb: false,
+        };
+        let group = app.world_mut().spawn(Propagate(group_range.clone())).id();
+        let child = app.world_mut().spawn(ChildOf(group)).id();
+        let proxy = app
+            .world_mut()
+            .spawn(VisibilityRangeProxy::new(group, 70.0..75.0))
+            .id();
+
+        app.update();
+
+        let world: &World = app.world();
+        assert_eq!(world.get::<VisibilityRange>(child), Some(&group_range));
+        assert_eq!(
+            world.get::<VisibilityRange>(proxy),
+            Some(&VisibilityRange {
+                start_margin: 20.0..25.0,
+                end_margin: 70.0..75.0,
+                use_aabb: false,
+            })
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Helpers for orthographic 2D cameras: pixel-perfect zoom, level bounds, smooth following and
+//! screen shake.
+//!
+//! To use these helpers, add [`Camera2dPlugin`] to your app, and insert the components you need
+//! into your [`Camera2d`](bevy_camera::Camera2d) entity:
+//!
+//! - [`PixelPerfectZoom`] zooms the camera by whole multiples of a reference resolution, so that
+//!   each pixel of the art covers the same number of screen pixels.
+//! - [`CameraBounds2d`] keeps the view inside the level.
+//! - [`CameraFollow2d`] follows a target once it leaves a deadzone.
+//! - [`CameraShake2d`] shakes the camera by an amount that depends on its trauma.
+//!
+//! The camera must not have a parent: its [`GlobalTransform`] is updated directly, after
+//! transform propagation, so that it follows the current position of its target without a frame
+//! of delay.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::{
+    visibility::VisibilitySystems, Camera, CameraUpdateSystems, Projection, ScalingMode,
+};
+use bevy_ecs::prelude::*;
+use bevy_math::{ops, Quat, Rect, StableInterpolate, UVec2, Vec2, Vec3Swizzles};
+use bevy_time::Time;
+use bevy_transform::{
+    components::{GlobalTransform, Transform},
+    TransformSystems,
+};
+
+use crate::noise::noise;
+
+/// A plugin that updates the cameras with a [`PixelPerfectZoom`], [`CameraBounds2d`],
+/// [`CameraFollow2d`] or [`CameraShake2d`].
+pub struct Camera2dPlugin;
+
+impl Plugin for Camera2dPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                update_pixel_perfect_zoom
+                    .in_set(Camera2dSystems)
+                    .before(CameraUpdateSystems),
+                update_cameras_2d
+                    .in_set(Camera2dSystems)
+                    .after(CameraUpdateSystems)
+                    .after(TransformSystems::Propagate)
+                    .before(VisibilitySystems::UpdateFrusta),
+            ),
+        );
+    }
+}
+
+/// The system set in which the 2D camera helpers are updated, in [`PostUpdate`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Camera2dSystems;
+
+/// Zooms an orthographic camera by the largest whole factor that fits `resolution` in the
+/// viewport, so that pixel art stays crisp.
+///
+/// One world unit is one pixel of the art: at a zoom of 3, it covers 3×3 pixels of the screen.
+/// This overrides the [`OrthographicProjection::scaling_mode`](bevy_camera::OrthographicProjection::scaling_mode)
+/// and [`OrthographicProjection::scale`](bevy_camera::OrthographicProjection::scale) of the
+/// camera.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Camera2dPose)]
+pub struct PixelPerfectZoom {
+    /// The smallest area of the world that should be visible, in pixels of the art.
+    pub resolution: UVec2,
+    /// Whether to round the position of the camera to whole pixels of the art, so that the art
+    /// does not shimmer while the camera moves.
+    pub snap: bool,
+    zoom: u32,
+}
+
+impl PixelPerfectZoom {
+    /// Fits at least `resolution` pixels of the art in the viewport, snapping the camera to them.
+    pub fn new(resolution: UVec2) -> Self {
+        Self {
+            resolution,
+            snap: true,
+            zoom: 1,
+        }
+    }
+
+    /// Returns the number of screen pixels covered by each pixel of the art, along each axis.
+    pub fn zoom(&self) -> u32 {
+        self.zoom
+    }
+}
+
+/// Keeps the view of an orthographic camera inside a rectangle of the world, such as the level.
+///
+/// Along the axes where the view is larger than the rectangle, the camera is centered on it.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Camera2dPose)]
+pub struct CameraBounds2d(pub Rect);
+
+/// Moves the camera smoothly towards a target entity once it leaves the deadzone.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Camera2dPose)]
+pub struct CameraFollow2d {
+    /// The entity to follow.
+    pub target: Entity,
+    /// Where to keep the target relative to the center of the view, in world units.
+    pub offset: Vec2,
+    /// Half the size of the area around the center of the view in which the target can move
+    /// without moving the camera.
+    pub deadzone: Vec2,
+    /// How quickly the camera catches up with the target, see
+    /// [`StableInterpolate::smooth_nudge`]. Infinity disables the smoothing.
+    pub decay_rate: f32,
+}
+
+impl CameraFollow2d {
+    /// Follows `target` without a deadzone.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec2::ZERO,
+            deadzone: Vec2::ZERO,
+            decay_rate: 10.0,
+        }
+    }
+
+    /// Returns the camera position that brings the target back to the edge of the deadzone.
+    fn goal(&self, position: Vec2, target: Vec2) -> Vec2 {
+        let delta = target + self.offset - position;
+        position + (delta.abs() - self.deadzone).max(Vec2::ZERO) * delta.signum()
+    }
+}
+
+/// Shakes a 2D camera by an amount that depends on its trauma.
+///
+/// Add trauma for impacts and explosions with [`CameraShake2d::add_trauma`]: it decays over
+/// time, and the shake is proportional to its square, so that small and large impacts feel
+/// different.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Camera2dPose)]
+pub struct CameraShake2d {
+    /// The current trauma, between zero and one.
+    pub trauma: f32,
+    /// How much trauma is lost per second.
+    pub decay: f32,
+    /// The largest displacement of the camera along each axis, in world units.
+    pub max_offset: f32,
+    /// The largest rotation of the camera, in radians.
+    ///
+    /// This defaults to zero, as a rotated view can show past the [`CameraBounds2d`].
+    pub max_angle: f32,
+    /// How fast the camera shakes.
+    pub frequency: f32,
+    time: f32,
+}
+
+impl Default for CameraShake2d {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_offset: 16.0,
+            max_angle: 0.0,
+            frequency: 15.0,
+            time: 0.0,
+        }
+    }
+}
+
+impl CameraShake2d {
+    /// Adds `amount` of trauma, up to one.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+}
+
+/// The pose of the camera before the shake and the pixel snapping are applied.
+#[derive(Component, Debug, Clone, Copy, Default)]
+struct Camera2dPose {
+    position: Vec2,
+    rotation: Quat,
+    /// The transform written last frame, to detect when the camera is moved by something else.
+    written: Option<Transform>,
+}
+
+/// Returns the position closest to `position` that keeps `area`, relative to the camera, inside
+/// `bounds`.
+fn clamp_to_bounds(bounds: Rect, area: Rect, position: Vec2) -> Vec2 {
+    let min = bounds.min - area.min;
+    let max = bounds.max - area.max;
+    let clamp = |position: f32, min: f32, max: f32| {
+        if min > max {
+            (min + max) / 2.0
+        } else {
+            position.clamp(min, max)
+        }
+    };
+    Vec2::new(
+        clamp(position.x, min.x, max.x),
+        clamp(position.y, min.y, max.y),
+    )
+}
+
+fn update_pixel_perfect_zoom(
+    mut cameras: Query<(&mut PixelPerfectZoom, &Camera, &mut Projection)>,
+) {
+    for (mut pixel_perfect, camera, mut projection) in &mut cameras {
+        let (Some(viewport), Some(scale_factor)) = (
+            camera.physical_viewport_size(),
+            camera.target_scaling_factor(),
+        ) else {
+            continue;
+        };
+        let Projection::Orthographic(orthographic) = projection.bypass_change_detection() else {
+            continue;
+        };
+
+        let fit = viewport.as_vec2() / pixel_perfect.resolution.max(UVec2::ONE).as_vec2();
+        let zoom = ops::floor(fit.min_element()).max(1.0);
+        // The projection is sized in logical pixels.
+        let scale = scale_factor / zoom;
+        if orthographic.scale != scale
+            || !matches!(orthographic.scaling_mode, ScalingMode::WindowSize)
+        {
+            orthographic.scale = scale;
+            orthographic.scaling_mode = ScalingMode::WindowSize;
+            projection.set_changed();
+        }
+        if pixel_perfect.zoom != zoom as u32 {
+            pixel_perfect.zoom = zoom as u32;
+        }
+    }
+}
+
+fn update_cameras_2d(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &mut Camera2dPose,
+        &mut Transform,
+        &mut GlobalTransform,
+        Option<&Projection>,
+        Option<&CameraFollow2d>,
+        Option<&CameraBounds2d>,
+        Option<&mut CameraShake2d>,
+        Option<&PixelPerfectZoom>,
+    )>,
+    targets: Query<&GlobalTransform, Without<Camera2dPose>>,
+) {
+    let delta = time.delta_secs();
+
+    for (
+        mut pose,
+        mut transform,
+        mut global_transform,
+        projection,
+        follow,
+        bounds,
+        shake,
+        pixel_perfect,
+    ) in &mut cameras
+    {
+        if pose.written != Some(*transform) {
+            pose.position = transform.translation.xy();
+            pose.rotation = transform.rotation;
+        }
+
+        if let Some(follow) = follow
+            && let Ok(target) = targets.get(follow.target)
+        {
+            let goal = follow.goal(pose.position, target.translation().xy());
+            pose.position.smooth_nudge(&goal, follow.decay_rate, delta);
+        }
+
+        let area = match projection {
+            Some(Projection::Orthographic(orthographic)) => Some(orthographic.area),
+            _ => None,
+        };
+        let clamp = |position: Vec2| match (bounds, area) {
+            (Some(CameraBounds2d(bounds)), Some(area)) => clamp_to_bounds(*bounds, area, position),
+            _ => position,
+        };
+        pose.position = clamp(pose.position);
+
+        let mut position = pose.position;
+        let mut rotation = pose.rotation;
+        if let Some(mut shake) = shake {
+            shake.time += delta;
+            let t = shake.time * shake.frequency;
+            let amount = shake.trauma * shake.trauma;
+            position += Vec2::new(noise(0, t), noise(1, t)) * shake.max_offset * amount;
+            rotation *= Quat::from_rotation_z(noise(2, t) * shake.max_angle * amount);
+            shake.trauma = (shake.trauma - shake.decay * delta).max(0.0);
+            position = clamp(position);
+        }
+        if let Some(pixel_perfect) = pixel_perfect
+            && pixel_perfect.snap
+        {
+            position = Vec2::new(ops::round(position.x), ops::round(position.y));
+        }
+
+        let pose_transform = Transform {
+            translation: position.extend(transform.translation.z),
+            rotation,
+            scale: transform.scale,
+        };
+        if *transform != pose_transform {
+            *transform = pose_transform;
+            *global_transform = pose_transform.into();
+        }
+        pose.written = Some(pose_transform);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::entity::Entity;
+    use bevy_math::{Rect, Vec2};
+
+    use super::{clamp_to_bounds, CameraFollow2d};
+
+    #[test]
+    fn bounds() {
+        let bounds = Rect::new(0.0, 0.0, 100.0, 50.0);
+        let area = Rect::new(-20.0, -40.0, 20.0, 40.0);
+
+        // The view is narrower than the level, but taller.
+        assert_eq!(
+            clamp_to_bounds(bounds, area, Vec2::new(-10.0, 0.0)),
+            Vec2::new(20.0, 25.0)
+        );
+        assert_eq!(
+            clamp_to_bounds(bounds, area, Vec2::new(50.0, 0.0)),
+            Vec2::new(50.0, 25.0)
+        );
+        assert_eq!(
+            clamp_to_bounds(bounds, area, Vec2::new(95.0, 0.0)),
+            Vec2::new(80.0, 25.0)
+        );
+    }
+
+    #[test]
+    fn deadzone() {
+        let follow = CameraFollow2d {
+            deadzone: Vec2::new(10.0, 5.0),
+            ..CameraFollow2d::new(Entity::PLACEHOLDER)
+        };
+
+        assert_eq!(follow.goal(Vec2::ZERO, Vec2::new(8.0, -4.0)), Vec2::ZERO);
+        assert_eq!(
+            follow.goal(Vec2::ZERO, Vec2::new(15.0, -8.0)),
+            Vec2::new(5.0, -3.0)
+        );
+    }
+}
@@ -0,0 +1,654 @@
+//! A framework of composable camera behaviors, blended between virtual cameras.
+//!
+//! Instead of moving the camera directly, spawn [`VirtualCamera`]s: entities describing where a
+//! camera could be, built from behaviors such as [`Follow`], [`Orbit`], [`LookAt`],
+//! [`ShoulderOffset`] and [`AvoidObstacles`]. The camera with a [`CameraRig`] then takes the pose
+//! of the enabled virtual camera with the highest [`VirtualCamera::priority`], and smoothly
+//! blends to the next one when the priorities change, like when a cutscene starts.
+//! A [`CameraShake`] on the rig shakes the camera, whichever virtual camera is live.
+//!
+//! To use the rig, add [`CameraRigPlugin`] to your app:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_camera::Camera3d;
+//! # use bevy_math::Vec3;
+//! # use bevy_transform::components::Transform;
+//! # use bevy_camera_controller::camera_rig::*;
+//! fn setup(mut commands: Commands) {
+//!     let player = commands.spawn(Transform::default()).id();
+//!
+//!     commands.spawn((Camera3d::default(), CameraRig::default()));
+//!     // The gameplay camera, following the player from behind its shoulder.
+//!     commands.spawn((
+//!         VirtualCamera::default(),
+//!         Follow::new(player, Vec3::new(0.0, 2.0, 6.0)),
+//!         LookAt::new(player),
+//!         ShoulderOffset(Vec3::new(0.6, 0.0, 0.0)),
+//!         AvoidObstacles::default(),
+//!     ));
+//! }
+//! # bevy_ecs::system::assert_is_system(setup);
+//! ```
+//!
+//! The rig and the virtual cameras must not have a parent: their [`GlobalTransform`] is updated
+//! directly, after transform propagation, so that they follow the current position of their
+//! targets without a frame of delay.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::visibility::VisibilitySystems;
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    mouse::{AccumulatedMouseMotion, MouseButton},
+    ButtonInput,
+};
+use bevy_math::{
+    curve::{Curve, EaseFunction},
+    EulerRot, Quat, Ray3d, StableInterpolate, Vec2, Vec3,
+};
+use bevy_picking::mesh_picking::ray_cast::{MeshRayCast, MeshRayCastSettings};
+use bevy_time::Time;
+use bevy_transform::{
+    components::{GlobalTransform, Transform},
+    TransformSystems,
+};
+use core::f32::consts::FRAC_PI_2;
+
+use crate::{noise::noise, orbit::OrbitPose};
+
+/// A plugin that moves the cameras with a [`CameraRig`] to the pose of their live
+/// [`VirtualCamera`].
+pub struct CameraRigPlugin;
+
+impl Plugin for CameraRigPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            PostUpdate,
+            CameraRigSystems
+                .after(TransformSystems::Propagate)
+                .before(VisibilitySystems::UpdateFrusta),
+        )
+        .add_systems(
+            PostUpdate,
+            (update_virtual_cameras, avoid_obstacles, update_camera_rigs)
+                .chain()
+                .in_set(CameraRigSystems),
+        );
+    }
+}
+
+/// The system set in which the [`CameraRig`]s and the [`VirtualCamera`]s are updated, in
+/// [`PostUpdate`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CameraRigSystems;
+
+/// Moves this camera to the pose of the live [`VirtualCamera`].
+///
+/// The live virtual camera is the enabled one with the highest priority. When it changes, the
+/// camera blends from its current pose to the new virtual camera over
+/// [`CameraRig::blend_duration`].
+#[derive(Component, Debug, Clone)]
+#[require(Transform)]
+pub struct CameraRig {
+    /// How long blending to a new live virtual camera takes, in seconds.
+    ///
+    /// Zero cuts to the new virtual camera directly.
+    pub blend_duration: f32,
+    /// The easing of the blends.
+    pub blend_curve: EaseFunction,
+    live: Option<Entity>,
+    /// The pose blended from, and the time elapsed since the blend started.
+    blend: Option<(Transform, f32)>,
+    /// The pose of the rig, before the shake is applied.
+    pose: Option<Transform>,
+}
+
+impl Default for CameraRig {
+    fn default() -> Self {
+        Self {
+            blend_duration: 1.0,
+            blend_curve: EaseFunction::SmootherStep,
+            live: None,
+            blend: None,
+            pose: None,
+        }
+    }
+}
+
+impl CameraRig {
+    /// Returns the [`VirtualCamera`] the rig follows.
+    pub fn live(&self) -> Option<Entity> {
+        self.live
+    }
+
+    /// Returns `true` if the rig is blending to the live [`VirtualCamera`].
+    pub fn is_blending(&self) -> bool {
+        self.blend.is_some()
+    }
+}
+
+/// A pose the [`CameraRig`]s can take, computed by the behaviors on this entity.
+///
+/// The pose is written to the [`Transform`] of the entity, which can also be set manually for
+/// fixed cameras.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Transform)]
+pub struct VirtualCamera {
+    /// The rigs follow the enabled virtual camera with the highest priority.
+    pub priority: i32,
+    /// Whether the rigs can follow this virtual camera.
+    pub enabled: bool,
+    /// The point the camera is looking at or orbiting around, used to avoid obstacles.
+    pivot: Option<Vec3>,
+}
+
+impl Default for VirtualCamera {
+    fn default() -> Self {
+        Self::with_priority(0)
+    }
+}
+
+impl VirtualCamera {
+    /// Creates an enabled virtual camera with the given priority.
+    pub fn with_priority(priority: i32) -> Self {
+        Self {
+            priority,
+            enabled: true,
+            pivot: None,
+        }
+    }
+}
+
+/// Moves the [`VirtualCamera`] towards an offset from a target entity.
+///
+/// This is ignored if the virtual camera also has an [`Orbit`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Follow {
+    /// The entity to follow.
+    pub target: Entity,
+    /// Where to stay relative to the target.
+    pub offset: Vec3,
+    /// Whether the offset is rotated along with the target, to stay behind it.
+    pub rotate_offset: bool,
+    /// How quickly the camera catches up with the target, see
+    /// [`StableInterpolate::smooth_nudge`]. Infinity disables the smoothing.
+    pub decay_rate: f32,
+}
+
+impl Follow {
+    /// Follows `target` at a fixed `offset` in world space.
+    pub fn new(target: Entity, offset: Vec3) -> Self {
+        Self {
+            target,
+            offset,
+            rotate_offset: false,
+            decay_rate: 8.0,
+        }
+    }
+}
+
+/// Places the [`VirtualCamera`] on a sphere around a target entity, looking at it.
+///
+/// The orbit is controlled with the mouse, or by setting [`Orbit::yaw`] and [`Orbit::pitch`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Orbit {
+    /// The entity to orbit around.
+    pub target: Entity,
+    /// The offset from the target to the center of the orbit.
+    pub offset: Vec3,
+    /// The distance from the center of the orbit.
+    pub distance: f32,
+    /// The rotation around the Y axis, in radians.
+    pub yaw: f32,
+    /// The rotation around the X axis, in radians. Negative values look down on the target.
+    pub pitch: f32,
+    /// The lowest pitch.
+    pub min_pitch: f32,
+    /// The highest pitch.
+    pub max_pitch: f32,
+    /// The yaw and pitch caused by one pixel of mouse motion, in radians.
+    ///
+    /// Set this to zero to control the orbit manually.
+    pub sensitivity: Vec2,
+    /// The mouse button to hold to orbit, or `None` to always orbit with the mouse.
+    pub button: Option<MouseButton>,
+}
+
+impl Orbit {
+    /// Orbits around `target` at `distance`, looking slightly down on it, while the right mouse
+    /// button is held.
+    pub fn new(target: Entity, distance: f32) -> Self {
+        let pitch_limit = FRAC_PI_2 - 0.01;
+        Self {
+            target,
+            offset: Vec3::ZERO,
+            distance,
+            yaw: 0.0,
+            pitch: -0.3,
+            min_pitch: -pitch_limit,
+            max_pitch: pitch_limit,
+            sensitivity: Vec2::new(-0.004, -0.003),
+            button: Some(MouseButton::Right),
+        }
+    }
+}
+
+/// Turns the [`VirtualCamera`] towards a target entity.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct LookAt {
+    /// The entity to look at.
+    pub target: Entity,
+    /// The offset from the target to the point to look at.
+    pub offset: Vec3,
+    /// How quickly the camera turns towards the target, see
+    /// [`StableInterpolate::smooth_nudge`]. Infinity disables the smoothing.
+    pub decay_rate: f32,
+}
+
+impl LookAt {
+    /// Looks at `target` directly.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            offset: Vec3::ZERO,
+            decay_rate: f32::INFINITY,
+        }
+    }
+}
+
+/// Moves the [`VirtualCamera`] by an offset in its own space, after it is oriented, such as
+/// over the shoulder of a character.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct ShoulderOffset(pub Vec3);
+
+/// Pulls the [`VirtualCamera`] towards the point it looks at or orbits around when a mesh is in
+/// the way, so that the target stays visible.
+///
+/// Obstacles are found by ray casting against the meshes, see [`MeshRayCast`]. Add
+/// [`IgnoredByCamera`] to meshes that should not push the camera, such as the player.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AvoidObstacles {
+    /// The distance kept between the camera and the obstacles.
+    pub margin: f32,
+    /// The closest the camera gets to the point it looks at.
+    pub min_distance: f32,
+    /// How quickly the camera moves back once the obstacle is gone, see
+    /// [`StableInterpolate::smooth_nudge`].
+    pub recovery_rate: f32,
+    /// The current distance of the camera from the pivot, while it is pulled in.
+    distance: Option<f32>,
+}
+
+impl Default for AvoidObstacles {
+    fn default() -> Self {
+        Self {
+            margin: 0.2,
+            min_distance: 0.5,
+            recovery_rate: 4.0,
+            distance: None,
+        }
+    }
+}
+
+/// Marks meshes [`AvoidObstacles`] ignores.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct IgnoredByCamera;
+
+/// Shakes the [`CameraRig`] by an amount that depends on its trauma.
+///
+/// Add trauma for impacts and explosions with [`CameraShake::add_trauma`]: it decays over time,
+/// and the shake is proportional to its square, so that small and large impacts feel different.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CameraShake {
+    /// The current trauma, between zero and one.
+    pub trauma: f32,
+    /// How much trauma is lost per second.
+    pub decay: f32,
+    /// The largest rotation of the camera around each axis, in radians.
+    pub max_angle: f32,
+    /// The largest displacement of the camera along each axis.
+    pub max_offset: f32,
+    /// How fast the camera shakes.
+    pub frequency: f32,
+    time: f32,
+}
+
+impl Default for CameraShake {
+    fn default() -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_angle: 0.1,
+            max_offset: 0.2,
+            frequency: 15.0,
+            time: 0.0,
+        }
+    }
+}
+
+impl CameraShake {
+    /// Adds `amount` of trauma, up to one.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// Returns the rotation and translation caused by the shake.
+    fn offset(&self) -> (Quat, Vec3) {
+        let amount = self.trauma * self.trauma;
+        let noise = |seed| noise(seed, self.time * self.frequency) * amount;
+        let rotation = Quat::from_euler(
+            EulerRot::YXZ,
+            noise(0) * self.max_angle,
+            noise(1) * self.max_angle,
+            noise(2) * self.max_angle,
+        );
+        let translation = Vec3::new(noise(3), noise(4), noise(5)) * self.max_offset;
+        (rotation, translation)
+    }
+}
+
+/// Computes the poses of the [`VirtualCamera`]s from their behaviors.
+fn update_virtual_cameras(
+    time: Res<Time>,
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    mut cameras: Query<
+        (
+            &mut VirtualCamera,
+            &mut Transform,
+            &mut GlobalTransform,
+            Option<&Follow>,
+            Option<&mut Orbit>,
+            Option<&LookAt>,
+            Option<&ShoulderOffset>,
+        ),
+        Without<CameraRig>,
+    >,
+    targets: Query<&GlobalTransform, Without<VirtualCamera>>,
+) {
+    let delta = time.delta_secs();
+    let mouse_delta = mouse_motion.map_or(Vec2::ZERO, |motion| motion.delta);
+
+    for (mut camera, mut transform, mut global_transform, follow, orbit, look_at, shoulder) in
+        &mut cameras
+    {
+        let mut pose = *transform;
+        let mut pivot = None;
+
+        if let Some(mut orbit) = orbit {
+            let orbiting = orbit.button.is_none_or(|button| {
+                mouse_buttons
+                    .as_ref()
+                    .is_some_and(|buttons| buttons.pressed(button))
+            });
+            let mut orbit_pose = OrbitPose {
+                focus: Vec3::ZERO,
+                yaw: orbit.yaw,
+                pitch: orbit.pitch,
+                distance: orbit.distance,
+            };
+            if orbiting && mouse_delta != Vec2::ZERO {
+                let rotation = mouse_delta * orbit.sensitivity;
+                orbit_pose.orbit(rotation.x, rotation.y, orbit.min_pitch..orbit.max_pitch);
+                orbit.yaw = orbit_pose.yaw;
+                orbit.pitch = orbit_pose.pitch;
+            }
+            if let Ok(target) = targets.get(orbit.target) {
+                orbit_pose.focus = target.translation() + orbit.offset;
+                let orbit_transform = orbit_pose.transform();
+                pose.rotation = orbit_transform.rotation;
+                pose.translation = orbit_transform.translation;
+                pivot = Some(orbit_pose.focus);
+            }
+        } else if let Some(follow) = follow
+            && let Ok(target) = targets.get(follow.target)
+        {
+            let offset = if follow.rotate_offset {
+                target.rotation() * follow.offset
+            } else {
+                follow.offset
+            };
+            let goal = target.translation() + offset;
+            pose.translation
+                .smooth_nudge(&goal, follow.decay_rate, delta);
+            pivot = Some(target.translation());
+        }
+
+        if let Some(look_at) = look_at
+            && let Ok(target) = targets.get(look_at.target)
+        {
+            let point = target.translation() + look_at.offset;
+            if point != pose.translation {
+                let goal = pose.looking_at(point, Vec3::Y).rotation;
+                pose.rotation.smooth_nudge(&goal, look_at.decay_rate, delta);
+            }
+            pivot = Some(point);
+        }
+
+        if let Some(ShoulderOffset(offset)) = shoulder {
+            pose.translation += pose.rotation * *offset;
+        }
+
+        camera.pivot = pivot;
+        if *transform != pose {
+            *transform = pose;
+            *global_transform = pose.into();
+        }
+    }
+}
+
+/// Pulls the [`VirtualCamera`]s with [`AvoidObstacles`] in front of the meshes hiding their
+/// pivot.
+fn avoid_obstacles(
+    time: Res<Time>,
+    mut cameras: Query<(
+        &VirtualCamera,
+        &mut AvoidObstacles,
+        &mut Transform,
+        &mut GlobalTransform,
+    )>,
+    ignored: Query<(), With<IgnoredByCamera>>,
+    mut ray_cast: MeshRayCast,
+) {
+    let filter = |entity| !ignored.contains(entity);
+    let settings = MeshRayCastSettings::default().with_filter(&filter);
+
+    for (camera, mut avoid, mut transform, mut global_transform) in &mut cameras {
+        let Some(pivot) = camera.pivot else {
+            avoid.distance = None;
+            continue;
+        };
+        let Ok((direction, full_distance)) =
+            bevy_math::Dir3::new_and_length(transform.translation - pivot)
+        else {
+            continue;
+        };
+
+        let allowed = ray_cast
+            .cast_ray(Ray3d::new(pivot, direction), &settings)
+            .iter()
+            .map(|(_, hit)| hit.distance)
+            .find(|&distance| distance < full_distance + avoid.margin)
+            .map_or(full_distance, |distance| {
+                (distance - avoid.margin).max(avoid.min_distance)
+            });
+
+        // Move in immediately, but back out smoothly.
+        let mut distance = avoid.distance.unwrap_or(full_distance).min(full_distance);
+        if allowed < distance {
+            distance = allowed;
+        } else {
+            distance.smooth_nudge(&allowed, avoid.recovery_rate, time.delta_secs());
+        }
+        avoid.distance = (distance < full_distance - 1e-4).then_some(distance);
+
+        if avoid.distance.is_some() {
+            transform.translation = pivot + direction * distance;
+            *global_transform = (*transform).into();
+        }
+    }
+}
+
+/// Moves the [`CameraRig`]s to their live [`VirtualCamera`], blending between them.
+fn update_camera_rigs(
+    time: Res<Time>,
+    mut rigs: Query<(
+        &mut CameraRig,
+        &mut Transform,
+        &mut GlobalTransform,
+        Option<&mut CameraShake>,
+    )>,
+    cameras: Query<(Entity, &VirtualCamera, &Transform), Without<CameraRig>>,
+) {
+    let delta = time.delta_secs();
+
+    for (mut rig, mut transform, mut global_transform, shake) in &mut rigs {
+        let live = cameras
+            .iter()
+            .filter(|(_, camera, _)| camera.enabled)
+            .max_by_key(|&(entity, camera, _)| (camera.priority, Some(entity) == rig.live))
+            .map(|(entity, _, pose)| (entity, *pose));
+
+        let Some((entity, target)) = live else {
+            rig.live = None;
+            rig.blend = None;
+            continue;
+        };
+        if rig.live != Some(entity) {
+            rig.blend = rig
+                .pose
+                .filter(|_| rig.live.is_some() && rig.blend_duration > 0.0)
+                .map(|pose| (pose, 0.0));
+            rig.live = Some(entity);
+        }
+
+        let mut pose = target;
+        if let Some((from, elapsed)) = rig.blend {
+            let elapsed = elapsed + delta;
+            if elapsed >= rig.blend_duration {
+                rig.blend = None;
+            } else {
+                let t = rig.blend_curve.sample_clamped(elapsed / rig.blend_duration);
+                pose.translation = from.translation.lerp(target.translation, t);
+                pose.rotation = from.rotation.slerp(target.rotation, t);
+                pose.scale = from.scale.lerp(target.scale, t);
+                rig.blend = Some((from, elapsed));
+            }
+        }
+        rig.pose = Some(pose);
+
+        if let Some(mut shake) = shake {
+            shake.time += delta;
+            let (rotation, translation) = shake.offset();
+            pose.rotation *= rotation;
+            pose.translation += pose.rotation * translation;
+            shake.trauma = (shake.trauma - shake.decay * delta).max(0.0);
+        }
+
+        if *transform != pose {
+            *transform = pose;
+            *global_transform = pose.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_ecs::{prelude::*, system::RunSystemOnce};
+    use bevy_math::Vec3;
+    use bevy_time::Time;
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use super::{
+        update_camera_rigs, update_virtual_cameras, CameraRig, Follow, LookAt, Orbit, VirtualCamera,
+    };
+
+    fn step(world: &mut World, seconds: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(seconds));
+        world.run_system_once(update_virtual_cameras).unwrap();
+        world.run_system_once(update_camera_rigs).unwrap();
+    }
+
+    fn translation(world: &World, entity: Entity) -> Vec3 {
+        world.get::<Transform>(entity).unwrap().translation
+    }
+
+    #[test]
+    fn behaviors() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let target = world.spawn(GlobalTransform::from_xyz(1.0, 0.0, 0.0)).id();
+        let follow = world
+            .spawn((
+                VirtualCamera::default(),
+                {
+                    let mut follow = Follow::new(target, Vec3::new(0.0, 2.0, 5.0));
+                    follow.decay_rate = f32::INFINITY;
+                    follow
+                },
+                LookAt::new(target),
+            ))
+            .id();
+        let orbit = world
+            .spawn((
+                VirtualCamera::with_priority(-1),
+                Orbit {
+                    pitch: 0.0,
+                    ..Orbit::new(target, 3.0)
+                },
+            ))
+            .id();
+        step(&mut world, 0.1);
+
+        assert_eq!(translation(&world, follow), Vec3::new(1.0, 2.0, 5.0));
+        let forward = world.get::<Transform>(follow).unwrap().forward();
+        assert!(forward.dot(Vec3::new(0.0, -2.0, -5.0).normalize()) > 0.999);
+        assert!(translation(&world, orbit).distance(Vec3::new(1.0, 0.0, 3.0)) < 1e-5);
+    }
+
+    #[test]
+    fn blend_between_priorities() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let rig = world
+            .spawn(CameraRig {
+                blend_duration: 1.0,
+                ..Default::default()
+            })
+            .id();
+        let low = world
+            .spawn((
+                VirtualCamera::with_priority(0),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ))
+            .id();
+        let high = world
+            .spawn((
+                VirtualCamera::with_priority(1),
+                Transform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+        world.get_mut::<VirtualCamera>(high).unwrap().enabled = false;
+
+        // The first live camera is cut to.
+        step(&mut world, 0.1);
+        assert_eq!(world.get::<CameraRig>(rig).unwrap().live(), Some(low));
+        assert_eq!(translation(&world, rig), Vec3::ZERO);
+
+        world.get_mut::<VirtualCamera>(high).unwrap().enabled = true;
+        step(&mut world, 0.5);
+        let rig_state = world.get::<CameraRig>(rig).unwrap();
+        assert_eq!(rig_state.live(), Some(high));
+        assert!(rig_state.is_blending());
+        let x = translation(&world, rig).x;
+        assert!(x > 0.0 && x < 10.0, "{x}");
+
+        step(&mut world, 0.6);
+        assert!(!world.get::<CameraRig>(rig).unwrap().is_blending());
+        assert_eq!(translation(&world, rig), Vec3::new(10.0, 0.0, 0.0));
+    }
+}
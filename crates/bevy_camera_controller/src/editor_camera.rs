@@ -0,0 +1,486 @@
+//! A 3D camera controller for editors and tools, orbiting around a focus point.
+//!
+//! The camera orbits around, pans and zooms toward its focus point, like in most 3D editors,
+//! and can switch to flying around the scene while a mouse button is held:
+//!
+//! - Orbit with the middle mouse button, or the left one with `Alt` held.
+//! - Pan with the middle mouse button while `Shift` is held.
+//! - Zoom with the scroll wheel, toward the point under the cursor.
+//! - Fly with `WASD`, `Q` and `E` while the right mouse button is held, looking around with the
+//!   mouse.
+//!
+//! The movements are smoothed and keep some inertia after the mouse is released, which can both
+//! be disabled for precise control.
+//!
+//! To use this controller, add [`EditorCameraPlugin`] to your app,
+//! and attach the [`EditorCamera`] component to your camera entity.
+//! The required [`EditorCameraState`] component will be added automatically.
+//!
+//! To configure the settings of this controller, modify the fields of the [`EditorCamera`]
+//! component. The camera must not have a parent.
+
+use bevy_app::{App, Plugin, RunFixedMainLoop, RunFixedMainLoopSystems};
+use bevy_camera::{Camera, Projection};
+use bevy_ecs::prelude::*;
+use bevy_input::keyboard::KeyCode;
+use bevy_input::mouse::{
+    AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButton, MouseScrollPixelsPerLine,
+};
+use bevy_input::ButtonInput;
+use bevy_log::info;
+use bevy_math::{ops, EulerRot, Ray3d, StableInterpolate, Vec2, Vec3};
+use bevy_time::{Real, Time};
+use bevy_transform::components::{GlobalTransform, Transform};
+use bevy_window::{PrimaryWindow, Window};
+
+use core::{f32::consts::*, fmt};
+
+use crate::orbit::OrbitPose;
+
+/// An editor-style camera controller plugin.
+///
+/// Use the [`EditorCamera`] struct to add and customize the controller for a camera entity.
+/// The camera's dynamic state is managed by the [`EditorCameraState`] struct.
+pub struct EditorCameraPlugin;
+
+impl Plugin for EditorCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            RunFixedMainLoop,
+            run_editor_camera_controller.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+        );
+    }
+}
+
+/// Scales mouse motion into yaw/pitch movement, like the free camera.
+const RADIANS_PER_DOT: f32 = 1.0 / 180.0;
+
+/// Stores the settings for the [`EditorCamera`] controller.
+///
+/// This component defines static configuration for camera controls,
+/// including sensitivities, smoothing and input bindings.
+///
+/// Add this component to a [`Camera`] entity to enable `EditorCamera` controls.
+/// The associated dynamic state is automatically handled by [`EditorCameraState`],
+/// which is added to the entity as a required component.
+///
+/// To activate the controller, add the [`EditorCameraPlugin`] to your [`App`].
+#[derive(Component, Clone)]
+#[require(EditorCameraState)]
+pub struct EditorCamera {
+    /// The point the camera orbits around when it is first controlled.
+    ///
+    /// The camera is turned toward it, keeping its distance. Use
+    /// [`EditorCameraState::focus_on`] to change it later.
+    pub initial_focus: Vec3,
+    /// [`MouseButton`] to orbit around the focus point.
+    pub button_orbit: MouseButton,
+    /// [`MouseButton`] to orbit around the focus point while [`key_orbit_modifier`] is held, for
+    /// mice and touchpads without a middle button.
+    ///
+    /// [`key_orbit_modifier`]: EditorCamera::key_orbit_modifier
+    pub button_orbit_alternate: MouseButton,
+    /// Modifier [`KeyCode`] for [`button_orbit_alternate`](EditorCamera::button_orbit_alternate).
+    pub key_orbit_modifier: KeyCode,
+    /// Modifier [`KeyCode`] to pan instead of orbiting while the orbit buttons are held.
+    pub key_pan_modifier: KeyCode,
+    /// [`MouseButton`] to fly around the scene while held.
+    pub button_fly: MouseButton,
+    /// [`KeyCode`] for forward flight.
+    pub key_forward: KeyCode,
+    /// [`KeyCode`] for backward flight.
+    pub key_back: KeyCode,
+    /// [`KeyCode`] for left flight.
+    pub key_left: KeyCode,
+    /// [`KeyCode`] for right flight.
+    pub key_right: KeyCode,
+    /// [`KeyCode`] for up flight.
+    pub key_up: KeyCode,
+    /// [`KeyCode`] for down flight.
+    pub key_down: KeyCode,
+    /// [`KeyCode`] to use [`fly_run_speed`](EditorCamera::fly_run_speed) instead of
+    /// [`fly_speed`](EditorCamera::fly_speed).
+    pub key_run: KeyCode,
+    /// Multiplier for the rotation speed when orbiting and flying.
+    pub orbit_sensitivity: f32,
+    /// Multiplier for the panning speed.
+    ///
+    /// At `1.0`, the point at the depth of the focus point follows the cursor.
+    pub pan_sensitivity: f32,
+    /// How much one line of scroll zooms, exponentially: the distance to the focus point is
+    /// multiplied by `e^(-zoom_sensitivity)` per line of scroll.
+    pub zoom_sensitivity: f32,
+    /// Whether zooming moves toward the point under the cursor rather than toward the focus point.
+    pub zoom_to_cursor: bool,
+    /// The minimum distance to the focus point.
+    pub min_distance: f32,
+    /// The maximum distance to the focus point.
+    pub max_distance: f32,
+    /// The maximum pitch above or below the horizon, in radians.
+    pub pitch_limit: f32,
+    /// Base flying speed, in units per second.
+    pub fly_speed: f32,
+    /// Flying speed while [`key_run`](EditorCamera::key_run) is held, in units per second.
+    pub fly_run_speed: f32,
+    /// How quickly the camera catches up with the input, as the decay rate of
+    /// [`StableInterpolate::smooth_nudge`].
+    ///
+    /// Zero disables smoothing.
+    pub smoothing: f32,
+    /// How quickly the inertia left by orbiting and panning decays, as the decay rate of
+    /// [`StableInterpolate::smooth_nudge`].
+    ///
+    /// Zero disables inertia.
+    pub damping: f32,
+}
+
+impl Default for EditorCamera {
+    fn default() -> Self {
+        Self {
+            initial_focus: Vec3::ZERO,
+            button_orbit: MouseButton::Middle,
+            button_orbit_alternate: MouseButton::Left,
+            key_orbit_modifier: KeyCode::AltLeft,
+            key_pan_modifier: KeyCode::ShiftLeft,
+            button_fly: MouseButton::Right,
+            key_forward: KeyCode::KeyW,
+            key_back: KeyCode::KeyS,
+            key_left: KeyCode::KeyA,
+            key_right: KeyCode::KeyD,
+            key_up: KeyCode::KeyE,
+            key_down: KeyCode::KeyQ,
+            key_run: KeyCode::ShiftLeft,
+            orbit_sensitivity: 0.5,
+            pan_sensitivity: 1.0,
+            // Approximation of ln(1.1)
+            zoom_sensitivity: 0.0953102,
+            zoom_to_cursor: true,
+            min_distance: 0.05,
+            max_distance: 10_000.0,
+            pitch_limit: FRAC_PI_2 - 0.01,
+            fly_speed: 5.0,
+            fly_run_speed: 15.0,
+            smoothing: 30.0,
+            damping: 8.0,
+        }
+    }
+}
+
+impl fmt::Display for EditorCamera {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "
+Editor camera Controls:
+    {:?} or {:?} + {:?}\t- Orbit around the focus point
+    {:?} + {:?}\t- Pan
+    Scroll\t- Zoom
+    {:?}\t- Hold to fly and look around
+    {:?} & {:?}\t- Fly forward & backwards
+    {:?} & {:?}\t- Fly sideways left & right
+    {:?} & {:?}\t- Fly up & down
+    {:?}\t- Fly faster while held",
+            self.button_orbit,
+            self.key_orbit_modifier,
+            self.button_orbit_alternate,
+            self.key_pan_modifier,
+            self.button_orbit,
+            self.button_fly,
+            self.key_forward,
+            self.key_back,
+            self.key_left,
+            self.key_right,
+            self.key_up,
+            self.key_down,
+            self.key_run,
+        )
+    }
+}
+
+/// Tracks the runtime state of an [`EditorCamera`] controller.
+///
+/// The pose of the camera is described by the point it orbits around, its yaw and pitch around
+/// that point, and its distance to it. The input moves the goal pose, which the camera catches up
+/// with according to [`EditorCamera::smoothing`].
+///
+/// It is automatically added to any entity that has an [`EditorCamera`] component,
+/// and is updated by the [`EditorCameraPlugin`] systems in response to user input.
+#[derive(Component)]
+pub struct EditorCameraState {
+    /// Enables [`EditorCamera`] controls when `true`.
+    pub enabled: bool,
+    /// Internal flag indicating if this controller has been initialized by the
+    /// [`EditorCameraPlugin`].
+    initialized: bool,
+    /// The current pose of the camera.
+    current: OrbitPose,
+    /// The pose the camera is moving to.
+    goal: OrbitPose,
+    /// The yaw and pitch velocity left by orbiting, in radians per second.
+    angular_velocity: Vec2,
+    /// The velocity of the focus point left by panning and flying, in units per second.
+    velocity: Vec3,
+}
+
+impl Default for EditorCameraState {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            initialized: false,
+            current: OrbitPose::default(),
+            goal: OrbitPose::default(),
+            angular_velocity: Vec2::ZERO,
+            velocity: Vec3::ZERO,
+        }
+    }
+}
+
+impl EditorCameraState {
+    /// The point the camera orbits around, once it caught up with the input.
+    pub fn focus(&self) -> Vec3 {
+        self.goal.focus
+    }
+
+    /// The distance of the camera to its focus point, once it caught up with the input.
+    pub fn distance(&self) -> f32 {
+        self.goal.distance
+    }
+
+    /// Moves the camera to orbit around `focus` at `distance`, keeping its orientation, like
+    /// editors do to frame the selection.
+    ///
+    /// The camera moves there smoothly, according to [`EditorCamera::smoothing`].
+    pub fn focus_on(&mut self, focus: Vec3, distance: f32) {
+        self.goal.focus = focus;
+        self.goal.distance = distance;
+        self.angular_velocity = Vec2::ZERO;
+        self.velocity = Vec3::ZERO;
+    }
+}
+
+/// Updates the camera's position and orientation based on user input.
+///
+/// - [`EditorCamera`] contains static configuration such as key bindings and sensitivities.
+/// - [`EditorCameraState`] stores the dynamic runtime state, including the pose and the inertia.
+///
+/// This system is typically added via the [`EditorCameraPlugin`].
+pub fn run_editor_camera_controller(
+    time: Res<Time<Real>>,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    accumulated_mouse_motion: Res<AccumulatedMouseMotion>,
+    accumulated_mouse_scroll: Res<AccumulatedMouseScroll>,
+    mouse_scroll_conversion: Res<MouseScrollPixelsPerLine>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(
+        &Camera,
+        &mut Projection,
+        &mut Transform,
+        &mut EditorCameraState,
+        &EditorCamera,
+    )>,
+) {
+    let dt = time.delta_secs();
+    let cursor = primary_window
+        .single()
+        .ok()
+        .and_then(Window::cursor_position);
+
+    for (camera, mut projection, mut transform, mut state, config) in &mut query {
+        let state = &mut *state;
+
+        if !state.initialized {
+            let offset = transform.translation - config.initial_focus;
+            let distance = offset.length().max(config.min_distance);
+            let rotation = Transform::from_translation(offset)
+                .looking_at(Vec3::ZERO, Vec3::Y)
+                .rotation;
+            let (yaw, pitch, _roll) = rotation.to_euler(EulerRot::YXZ);
+            state.current = OrbitPose {
+                focus: config.initial_focus,
+                yaw,
+                pitch: pitch.clamp(-config.pitch_limit, config.pitch_limit),
+                distance,
+            };
+            state.goal = state.current;
+            state.initialized = true;
+            info!("{}", *config);
+        }
+
+        if !state.enabled {
+            continue;
+        }
+
+        let delta = accumulated_mouse_motion.delta * RADIANS_PER_DOT * config.orbit_sensitivity;
+        let orbiting = mouse_button_input.pressed(config.button_orbit)
+            || (mouse_button_input.pressed(config.button_orbit_alternate)
+                && key_input.pressed(config.key_orbit_modifier));
+        let panning = orbiting && key_input.pressed(config.key_pan_modifier);
+        let flying = mouse_button_input.pressed(config.button_fly);
+        let right = state.goal.rotation() * Vec3::X;
+        let up = state.goal.rotation() * Vec3::Y;
+        let forward = state.goal.rotation() * Vec3::NEG_Z;
+
+        let pitch_range = -config.pitch_limit..config.pitch_limit;
+        let inertia = config.damping > 0.0;
+        // Whether the focus point was moved by the input this frame.
+        let mut moving = false;
+        if flying {
+            state.goal.look(-delta.x, -delta.y, pitch_range.clone());
+            state.angular_velocity = Vec2::ZERO;
+
+            let mut axis_input = Vec3::ZERO;
+            for (key, axis) in [
+                (config.key_forward, forward),
+                (config.key_back, -forward),
+                (config.key_right, right),
+                (config.key_left, -right),
+                (config.key_up, Vec3::Y),
+                (config.key_down, Vec3::NEG_Y),
+            ] {
+                if key_input.pressed(key) {
+                    axis_input += axis;
+                }
+            }
+            if axis_input != Vec3::ZERO {
+                let speed = if key_input.pressed(config.key_run) {
+                    config.fly_run_speed
+                } else {
+                    config.fly_speed
+                };
+                state.velocity = axis_input.normalize() * speed;
+                state.goal.focus += state.velocity * dt;
+                moving = true;
+            }
+        } else if panning {
+            let units_per_pixel =
+                units_per_pixel(camera, &projection, state.goal.distance) * config.pan_sensitivity;
+            let pan = (-accumulated_mouse_motion.delta.x * right
+                + accumulated_mouse_motion.delta.y * up)
+                * units_per_pixel;
+            state.goal.focus += pan;
+            state.velocity = if dt > 0.0 { pan / dt } else { Vec3::ZERO };
+            state.angular_velocity = Vec2::ZERO;
+            moving = true;
+        } else if orbiting {
+            state.goal.orbit(-delta.x, -delta.y, pitch_range.clone());
+            state.angular_velocity = if dt > 0.0 { -delta / dt } else { Vec2::ZERO };
+            state.velocity = Vec3::ZERO;
+        }
+
+        // Keep the inertia once the input stops.
+        if !inertia {
+            state.angular_velocity = Vec2::ZERO;
+            state.velocity = Vec3::ZERO;
+        } else {
+            if !orbiting || panning || flying {
+                let angular_velocity = state.angular_velocity * dt;
+                state
+                    .goal
+                    .orbit(angular_velocity.x, angular_velocity.y, pitch_range);
+                state
+                    .angular_velocity
+                    .smooth_nudge(&Vec2::ZERO, config.damping, dt);
+            }
+            if !moving {
+                state.goal.focus += state.velocity * dt;
+                state.velocity.smooth_nudge(&Vec3::ZERO, config.damping, dt);
+            }
+        }
+
+        let scroll = accumulated_mouse_scroll
+            .to_lines(&mouse_scroll_conversion)
+            .delta
+            .y;
+        if scroll != 0.0 {
+            let distance = (state.goal.distance * ops::exp(-config.zoom_sensitivity * scroll))
+                .clamp(config.min_distance, config.max_distance);
+            let factor = distance / state.goal.distance;
+            let ray = cursor
+                .filter(|_| config.zoom_to_cursor)
+                .and_then(|cursor| cursor_ray(camera, &state.goal, cursor));
+            let point = match ray {
+                Some(ray) => point_at_focus_depth(ray, &state.goal).unwrap_or(state.goal.focus),
+                None => state.goal.focus,
+            };
+            state.goal.zoom_around(point, factor);
+            if let Projection::Orthographic(orthographic) = &mut *projection {
+                orthographic.scale *= factor;
+            }
+        }
+
+        if config.smoothing > 0.0 {
+            state
+                .current
+                .smooth_nudge(&state.goal, config.smoothing, dt);
+        } else {
+            state.current = state.goal;
+        }
+
+        let pose = state.current.transform();
+        transform.rotation = pose.rotation;
+        transform.translation = pose.translation;
+    }
+}
+
+/// The world units covered by one logical pixel at `distance` from the camera.
+fn units_per_pixel(camera: &Camera, projection: &Projection, distance: f32) -> f32 {
+    let Some(viewport_size) = camera.logical_viewport_size() else {
+        return 0.0;
+    };
+    match projection {
+        Projection::Perspective(perspective) => {
+            2.0 * distance * ops::tan(perspective.fov / 2.0) / viewport_size.y
+        }
+        Projection::Orthographic(orthographic) => orthographic.area.height() / viewport_size.y,
+        // There is no way to know the scale of custom projections.
+        Projection::Custom(_) => distance / viewport_size.y,
+    }
+}
+
+/// The ray going through the `cursor` from the camera at `pose`.
+fn cursor_ray(camera: &Camera, pose: &OrbitPose, cursor: Vec2) -> Option<Ray3d> {
+    let viewport_min = camera.logical_viewport_rect()?.min;
+    let camera_transform = GlobalTransform::from(pose.transform());
+    camera
+        .viewport_to_world(&camera_transform, cursor - viewport_min)
+        .ok()
+}
+
+/// The point of the `ray` at the depth of the focus point of the camera at `pose`.
+fn point_at_focus_depth(ray: Ray3d, pose: &OrbitPose) -> Option<Vec3> {
+    let forward = pose.rotation() * Vec3::NEG_Z;
+    let alignment = ray.direction.dot(forward);
+    if alignment <= f32::EPSILON {
+        return None;
+    }
+    Some(ray.origin + *ray.direction * (pose.distance / alignment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Dir3;
+
+    #[test]
+    fn zoom_to_cursor_keeps_point_under_cursor() {
+        let mut pose = OrbitPose {
+            focus: Vec3::ZERO,
+            yaw: 0.0,
+            pitch: 0.0,
+            distance: 10.0,
+        };
+        let ray = Ray3d::new(
+            pose.translation(),
+            Dir3::new(Vec3::new(1.0, 0.5, -4.0)).unwrap(),
+        );
+        let point = point_at_focus_depth(ray, &pose).unwrap();
+        assert!((point.z - pose.focus.z).abs() < 1e-5);
+
+        pose.zoom_around(point, 0.5);
+        assert_eq!(pose.distance, 5.0);
+        // The point is still in the same direction from the camera.
+        let direction = (point - pose.translation()).normalize();
+        assert!(direction.abs_diff_eq(*ray.direction, 1e-5));
+    }
+}
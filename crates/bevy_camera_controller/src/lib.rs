@@ -36,8 +36,30 @@
 //! Each camera controller is stored in its own module,
 //! and gated behind a feature flag of the same name.
 
+#[cfg(feature = "camera_2d")]
+pub mod camera_2d;
+
+#[cfg(feature = "camera_rig")]
+pub mod camera_rig;
+
+#[cfg(feature = "editor_camera")]
+pub mod editor_camera;
+
 #[cfg(feature = "free_camera")]
 pub mod free_camera;
 
+#[cfg(feature = "orbit_camera")]
+pub mod orbit_camera;
+
 #[cfg(feature = "pan_camera")]
 pub mod pan_camera;
+
+#[cfg(any(feature = "camera_2d", feature = "camera_rig"))]
+mod noise;
+
+#[cfg(any(
+    feature = "camera_rig",
+    feature = "editor_camera",
+    feature = "orbit_camera"
+))]
+mod orbit;
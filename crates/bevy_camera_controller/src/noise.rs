@@ -0,0 +1,19 @@
+use bevy_math::ops;
+
+/// Smooth value noise between -1 and 1, a different curve for each `seed`.
+pub(crate) fn noise(seed: u32, t: f32) -> f32 {
+    let hash = |i: u32| {
+        let mut x = i.wrapping_mul(0x9E37_79B9) ^ seed.wrapping_mul(0x85EB_CA6B);
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7FEB_352D);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x846C_A68B);
+        x ^= x >> 16;
+        x as f32 / u32::MAX as f32 * 2.0 - 1.0
+    };
+    let floor = ops::floor(t);
+    let fraction = t - floor;
+    let i = floor as i32 as u32;
+    let smooth = fraction * fraction * (3.0 - 2.0 * fraction);
+    hash(i) + (hash(i.wrapping_add(1)) - hash(i)) * smooth
+}
@@ -0,0 +1,118 @@
+#[cfg(any(feature = "editor_camera", feature = "orbit_camera"))]
+use bevy_math::StableInterpolate;
+use bevy_math::{EulerRot, Quat, Vec3};
+use bevy_transform::components::Transform;
+
+#[cfg(any(feature = "camera_rig", feature = "editor_camera"))]
+use core::ops::Range;
+
+/// The pose of a camera orbiting around a focus point, shared by the orbiting controllers.
+///
+/// The camera is turned by `yaw` around the Y axis and by `pitch` around its X axis, and placed
+/// at `distance` behind the focus point, looking at it. Negative pitches look down on the focus
+/// point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct OrbitPose {
+    pub(crate) focus: Vec3,
+    pub(crate) yaw: f32,
+    pub(crate) pitch: f32,
+    pub(crate) distance: f32,
+}
+
+impl OrbitPose {
+    pub(crate) fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0)
+    }
+
+    pub(crate) fn translation(&self) -> Vec3 {
+        self.focus + self.rotation() * Vec3::new(0.0, 0.0, self.distance)
+    }
+
+    pub(crate) fn transform(&self) -> Transform {
+        Transform::from_translation(self.translation()).with_rotation(self.rotation())
+    }
+
+    /// Rotates the camera around the focus point, keeping the pitch within `pitch_range`.
+    #[cfg(any(feature = "camera_rig", feature = "editor_camera"))]
+    pub(crate) fn orbit(&mut self, yaw: f32, pitch: f32, pitch_range: Range<f32>) {
+        self.yaw += yaw;
+        self.pitch = (self.pitch + pitch).clamp(pitch_range.start, pitch_range.end);
+    }
+
+    /// Rotates the camera in place, moving the focus point around it.
+    #[cfg(feature = "editor_camera")]
+    pub(crate) fn look(&mut self, yaw: f32, pitch: f32, pitch_range: Range<f32>) {
+        let translation = self.translation();
+        self.orbit(yaw, pitch, pitch_range);
+        self.focus = translation - self.rotation() * Vec3::new(0.0, 0.0, self.distance);
+    }
+
+    /// Scales the distance to the focus point by `factor` around `point`, so that `point` stays at
+    /// the same place on screen.
+    #[cfg(feature = "editor_camera")]
+    pub(crate) fn zoom_around(&mut self, point: Vec3, factor: f32) {
+        self.focus = point + (self.focus - point) * factor;
+        self.distance *= factor;
+    }
+
+    /// Moves the pose towards `goal`, see [`StableInterpolate::smooth_nudge`].
+    #[cfg(any(feature = "editor_camera", feature = "orbit_camera"))]
+    pub(crate) fn smooth_nudge(&mut self, goal: &Self, decay_rate: f32, delta: f32) {
+        self.focus.smooth_nudge(&goal.focus, decay_rate, delta);
+        self.yaw.smooth_nudge(&goal.yaw, decay_rate, delta);
+        self.pitch.smooth_nudge(&goal.pitch, decay_rate, delta);
+        self.distance
+            .smooth_nudge(&goal.distance, decay_rate, delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::*;
+
+    #[test]
+    #[cfg(feature = "editor_camera")]
+    fn orbit_around_focus() {
+        let mut pose = OrbitPose {
+            focus: Vec3::new(1.0, 0.0, 0.0),
+            yaw: 0.0,
+            pitch: 0.0,
+            distance: 5.0,
+        };
+        assert!(pose
+            .translation()
+            .abs_diff_eq(Vec3::new(1.0, 0.0, 5.0), 1e-5));
+
+        pose.orbit(FRAC_PI_2, 0.0, -FRAC_PI_2..FRAC_PI_2);
+        assert!(pose
+            .translation()
+            .abs_diff_eq(Vec3::new(6.0, 0.0, 0.0), 1e-5));
+
+        // Looking around keeps the camera in place.
+        let translation = pose.translation();
+        pose.look(0.3, 0.2, -FRAC_PI_2..FRAC_PI_2);
+        assert!(pose.translation().abs_diff_eq(translation, 1e-5));
+        assert_eq!(pose.distance, 5.0);
+
+        pose.orbit(0.0, PI, -1.0..1.0);
+        assert_eq!(pose.pitch, 1.0);
+    }
+
+    #[test]
+    fn negative_pitch_looks_down() {
+        let pose = OrbitPose {
+            focus: Vec3::new(0.0, 1.0, 0.0),
+            yaw: 0.0,
+            pitch: -FRAC_PI_4,
+            distance: 2.0,
+        };
+        let transform = pose.transform();
+        assert!(transform.translation.y > 1.0);
+        assert!(transform.translation.z > 0.0);
+        // The camera looks at the focus point.
+        assert!(transform
+            .forward()
+            .abs_diff_eq((pose.focus - transform.translation).normalize(), 1e-5));
+    }
+}
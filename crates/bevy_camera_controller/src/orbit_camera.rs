@@ -0,0 +1,329 @@
+//! A 3D camera controller orbiting around a target entity, like third-person and model viewer
+//! cameras.
+//!
+//! - Orbit by moving the mouse while the left mouse button is held.
+//! - Zoom with the scroll wheel.
+//!
+//! The camera follows its target as it moves, and the movements are smoothed and keep some inertia
+//! after the mouse button is released, which can both be disabled for precise control.
+//!
+//! To use this controller, add [`OrbitCameraPlugin`] to your app,
+//! and attach the [`OrbitCamera`] component to your camera entity.
+//! The required [`OrbitCameraState`] component will be added automatically.
+//!
+//! To configure the settings of this controller, or to move the camera from code, modify the
+//! fields of the [`OrbitCamera`] component. The camera must not have a parent.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{
+    AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButton, MouseScrollPixelsPerLine,
+};
+use bevy_input::ButtonInput;
+use bevy_math::{ops, StableInterpolate, Vec2, Vec3};
+use bevy_time::{Real, Time};
+use bevy_transform::{components::Transform, helper::TransformHelper, TransformSystems};
+
+use core::{f32::consts::*, ops::Range};
+
+use crate::orbit::OrbitPose;
+
+/// An orbiting camera controller plugin.
+///
+/// Use the [`OrbitCamera`] struct to add and customize the controller for a camera entity.
+/// The camera's dynamic state is managed by the [`OrbitCameraState`] struct.
+pub struct OrbitCameraPlugin;
+
+impl Plugin for OrbitCameraPlugin {
+    fn build(&self, app: &mut App) {
+        // The target is followed once it was moved, but before its transform is propagated to
+        // the camera.
+        app.add_systems(
+            PostUpdate,
+            (update_orbit_camera_targets, run_orbit_camera_controller)
+                .chain()
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// Scales mouse motion into yaw/pitch movement, like the free camera.
+const RADIANS_PER_DOT: f32 = 1.0 / 180.0;
+
+/// Stores the settings and the goal pose of the [`OrbitCamera`] controller.
+///
+/// The camera is placed at [`distance`](OrbitCamera::distance) from its target, turned by
+/// [`yaw`](OrbitCamera::yaw) and [`pitch`](OrbitCamera::pitch), and looks at it. These fields are
+/// updated from user input, and can also be set directly to move the camera from code.
+///
+/// Add this component to a [`Camera`] entity to enable `OrbitCamera` controls.
+/// The associated dynamic state is automatically handled by [`OrbitCameraState`],
+/// which is added to the entity as a required component.
+///
+/// To activate the controller, add the [`OrbitCameraPlugin`] to your [`App`].
+#[derive(Component, Clone)]
+#[require(OrbitCameraState)]
+pub struct OrbitCamera {
+    /// Enables this [`OrbitCamera`] when `true`.
+    ///
+    /// The camera keeps following its target while disabled, but ignores user input.
+    pub enabled: bool,
+    /// The entity to orbit around, or `None` to orbit around [`offset`](OrbitCamera::offset).
+    pub target: Option<Entity>,
+    /// The point the camera looks at, relative to the [`target`](OrbitCamera::target)'s
+    /// translation, in world space.
+    ///
+    /// This can for example be used to look at the head of a character rather than at its feet.
+    pub offset: Vec3,
+    /// The distance from the camera to the point it looks at.
+    pub distance: f32,
+    /// The rotation of the camera around the vertical axis, in radians.
+    ///
+    /// At zero, the camera is behind the target along the positive Z axis.
+    pub yaw: f32,
+    /// The rotation of the camera above or below the horizon, in radians.
+    ///
+    /// Positive values look down on the target from above.
+    pub pitch: f32,
+    /// The range the [`distance`](OrbitCamera::distance) is clamped to.
+    pub distance_range: Range<f32>,
+    /// The range the [`yaw`](OrbitCamera::yaw) is clamped to, or `None` to orbit all around the
+    /// target.
+    pub yaw_range: Option<Range<f32>>,
+    /// The range the [`pitch`](OrbitCamera::pitch) is clamped to.
+    ///
+    /// Limiting pitch short of 90° up or down stops some unexpected rotation.
+    pub pitch_range: Range<f32>,
+    /// [`MouseButton`] to hold to orbit, or `None` to orbit on any mouse motion.
+    pub button_orbit: Option<MouseButton>,
+    /// Multiplier for the rotation speed when orbiting.
+    pub orbit_sensitivity: f32,
+    /// How much one line of scroll zooms, exponentially: the distance to the target is
+    /// multiplied by `e^(-zoom_sensitivity)` per line of scroll.
+    ///
+    /// Zero disables zooming.
+    pub zoom_sensitivity: f32,
+    /// How quickly the camera catches up with its goal pose and its moving target, as the decay
+    /// rate of [`StableInterpolate::smooth_nudge`].
+    ///
+    /// Zero disables smoothing.
+    pub smoothing: f32,
+    /// How quickly the inertia left by orbiting decays, as the decay rate of
+    /// [`StableInterpolate::smooth_nudge`].
+    ///
+    /// Zero disables inertia.
+    pub damping: f32,
+}
+
+impl Default for OrbitCamera {
+    fn default() -> Self {
+        let pitch_limit = FRAC_PI_2 - 0.01;
+        Self {
+            enabled: true,
+            target: None,
+            offset: Vec3::ZERO,
+            distance: 10.0,
+            yaw: 0.0,
+            pitch: FRAC_PI_8,
+            distance_range: 1.0..100.0,
+            yaw_range: None,
+            pitch_range: -pitch_limit..pitch_limit,
+            button_orbit: Some(MouseButton::Left),
+            orbit_sensitivity: 0.5,
+            // Approximation of ln(1.1)
+            zoom_sensitivity: 0.0953102,
+            smoothing: 20.0,
+            damping: 8.0,
+        }
+    }
+}
+
+impl OrbitCamera {
+    /// Creates an [`OrbitCamera`] orbiting around `target` at `distance`.
+    pub fn new(target: Entity, distance: f32) -> Self {
+        Self {
+            target: Some(target),
+            distance,
+            ..Default::default()
+        }
+    }
+
+    /// Turns the camera around its target by `yaw` and `pitch` radians, within the angular
+    /// limits.
+    pub fn orbit(&mut self, yaw: f32, pitch: f32) {
+        self.yaw += yaw;
+        if let Some(yaw_range) = &self.yaw_range {
+            self.yaw = self.yaw.clamp(yaw_range.start, yaw_range.end);
+        }
+        self.pitch = (self.pitch + pitch).clamp(self.pitch_range.start, self.pitch_range.end);
+    }
+
+    /// Multiplies the distance to the target by `factor`, within the distance limits.
+    pub fn zoom(&mut self, factor: f32) {
+        self.distance =
+            (self.distance * factor).clamp(self.distance_range.start, self.distance_range.end);
+    }
+
+    /// Returns the goal pose of the camera, looking at `focus`.
+    fn pose(&self, focus: Vec3) -> OrbitPose {
+        OrbitPose {
+            focus,
+            yaw: self.yaw,
+            // The camera looks down on the target at positive pitches.
+            pitch: -self.pitch,
+            distance: self.distance,
+        }
+    }
+}
+
+/// Tracks the runtime state of an [`OrbitCamera`] controller.
+///
+/// It is automatically added to any entity that has an [`OrbitCamera`] component,
+/// and is updated by the [`OrbitCameraPlugin`] systems in response to user input.
+#[derive(Component, Default)]
+pub struct OrbitCameraState {
+    /// Internal flag indicating if this controller has been initialized by the
+    /// [`OrbitCameraPlugin`].
+    initialized: bool,
+    /// The point the camera should look at this frame.
+    goal_focus: Vec3,
+    /// The current pose of the camera, catching up with the goal pose.
+    current: OrbitPose,
+    /// The yaw and pitch velocity left by orbiting, in radians per second.
+    angular_velocity: Vec2,
+}
+
+impl OrbitCameraState {
+    /// The point the camera currently looks at.
+    pub fn focus(&self) -> Vec3 {
+        self.current.focus
+    }
+}
+
+/// Updates the point each [`OrbitCamera`] looks at from the current transform of its target.
+///
+/// This system is typically added via the [`OrbitCameraPlugin`].
+pub fn update_orbit_camera_targets(
+    transform_helper: TransformHelper,
+    mut query: Query<(&OrbitCamera, &mut OrbitCameraState)>,
+) {
+    for (config, mut state) in &mut query {
+        let target = config
+            .target
+            .and_then(|target| transform_helper.compute_global_transform(target).ok())
+            .map_or(Vec3::ZERO, |transform| transform.translation());
+        state.goal_focus = target + config.offset;
+    }
+}
+
+/// Updates the camera's position and orientation based on user input and its target.
+///
+/// - [`OrbitCamera`] contains the configuration such as limits and sensitivities, and the goal pose.
+/// - [`OrbitCameraState`] stores the dynamic runtime state, including the current pose and the
+///   inertia.
+///
+/// This system is typically added via the [`OrbitCameraPlugin`].
+pub fn run_orbit_camera_controller(
+    time: Res<Time<Real>>,
+    accumulated_mouse_motion: Res<AccumulatedMouseMotion>,
+    accumulated_mouse_scroll: Res<AccumulatedMouseScroll>,
+    mouse_scroll_conversion: Res<MouseScrollPixelsPerLine>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    mut query: Query<(&mut Transform, &mut OrbitCamera, &mut OrbitCameraState), With<Camera>>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut transform, mut config, mut state) in &mut query {
+        let state = &mut *state;
+
+        if config.enabled {
+            let orbiting = config
+                .button_orbit
+                .is_none_or(|button| mouse_button_input.pressed(button));
+            if orbiting {
+                let delta =
+                    accumulated_mouse_motion.delta * RADIANS_PER_DOT * config.orbit_sensitivity;
+                config.orbit(-delta.x, delta.y);
+                state.angular_velocity = if dt > 0.0 {
+                    Vec2::new(-delta.x, delta.y) / dt
+                } else {
+                    Vec2::ZERO
+                };
+            } else if config.damping > 0.0 {
+                // Keep the inertia once the button is released.
+                let angular_velocity = state.angular_velocity * dt;
+                config.orbit(angular_velocity.x, angular_velocity.y);
+                state
+                    .angular_velocity
+                    .smooth_nudge(&Vec2::ZERO, config.damping, dt);
+            }
+            if config.damping <= 0.0 {
+                state.angular_velocity = Vec2::ZERO;
+            }
+
+            let scroll = accumulated_mouse_scroll
+                .to_lines(&mouse_scroll_conversion)
+                .delta
+                .y;
+            if scroll != 0.0 {
+                let zoom_sensitivity = config.zoom_sensitivity;
+                config.zoom(ops::exp(-zoom_sensitivity * scroll));
+            }
+        } else {
+            state.angular_velocity = Vec2::ZERO;
+        }
+
+        let goal = config.pose(state.goal_focus);
+        if !state.initialized || config.smoothing <= 0.0 {
+            state.current = goal;
+            state.initialized = true;
+        } else {
+            state.current.smooth_nudge(&goal, config.smoothing, dt);
+        }
+
+        let pose = state.current.transform();
+        transform.rotation = pose.rotation;
+        transform.translation = pose.translation;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orbit_within_limits() {
+        let mut orbit_camera = OrbitCamera {
+            yaw_range: Some(-1.0..1.0),
+            pitch_range: 0.0..1.0,
+            distance_range: 2.0..20.0,
+            ..Default::default()
+        };
+        orbit_camera.orbit(3.0, -3.0);
+        assert_eq!(orbit_camera.yaw, 1.0);
+        assert_eq!(orbit_camera.pitch, 0.0);
+
+        orbit_camera.zoom(0.01);
+        assert_eq!(orbit_camera.distance, 2.0);
+        orbit_camera.zoom(100.0);
+        assert_eq!(orbit_camera.distance, 20.0);
+    }
+
+    #[test]
+    fn positive_pitch_looks_down() {
+        let orbit_camera = OrbitCamera {
+            pitch: FRAC_PI_4,
+            distance: 2.0,
+            ..Default::default()
+        };
+        let focus = Vec3::new(0.0, 1.0, 0.0);
+        let transform = orbit_camera.pose(focus).transform();
+        assert!(transform.translation.y > 1.0);
+        assert!(transform.translation.z > 0.0);
+        // The camera looks at the focus point.
+        assert!(transform
+            .forward()
+            .abs_diff_eq((focus - transform.translation).normalize(), 1e-5));
+    }
+}
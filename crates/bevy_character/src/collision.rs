@@ -0,0 +1,75 @@
+use bevy_ecs::{entity::Entity, resource::Resource};
+use bevy_math::{primitives::Capsule3d, Dir3, Quat, Vec3};
+
+/// The first contact found by a [`CollisionWorld::cast_capsule`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapeHit {
+    /// The entity that was hit, if the collision world tracks entities.
+    ///
+    /// Only entities with a [`GlobalTransform`](bevy_transform::components::GlobalTransform)
+    /// can act as moving platforms.
+    pub entity: Option<Entity>,
+    /// How far the shape traveled along the cast direction before touching the surface.
+    pub distance: f32,
+    /// The contact point.
+    pub point: Vec3,
+    /// The normal of the surface at the contact point, pointing away from the surface.
+    pub normal: Dir3,
+}
+
+/// The static and moving geometry characters collide with.
+///
+/// The character controller only needs to sweep capsules through the world, so it does not
+/// depend on a particular physics engine: implement this trait for a resource that wraps the
+/// query pipeline of the physics engine of your choice, or for a simple list of colliders, and
+/// add a [`CharacterControllerPlugin`](crate::CharacterControllerPlugin) for it.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{primitives::Capsule3d, Dir3, Quat, Vec3};
+/// # use bevy_character::{CollisionWorld, ShapeHit};
+/// /// An infinite floor at `y = 0`.
+/// #[derive(Resource)]
+/// struct Floor;
+///
+/// impl CollisionWorld for Floor {
+///     fn cast_capsule(
+///         &self,
+///         capsule: &Capsule3d,
+///         origin: Vec3,
+///         _rotation: Quat,
+///         direction: Dir3,
+///         max_distance: f32,
+///         _exclude: Entity,
+///     ) -> Option<ShapeHit> {
+///         // Assumes an upright capsule.
+///         let bottom = origin.y - capsule.half_length - capsule.radius;
+///         if direction.y >= 0.0 || bottom < 0.0 {
+///             return None;
+///         }
+///         let distance = bottom / -direction.y;
+///         (distance <= max_distance).then(|| ShapeHit {
+///             entity: None,
+///             distance,
+///             point: origin + direction * distance - Vec3::Y * (capsule.half_length + capsule.radius),
+///             normal: Dir3::Y,
+///         })
+///     }
+/// }
+/// ```
+pub trait CollisionWorld: Resource {
+    /// Sweeps `capsule`, centered at `origin` and rotated by `rotation`, along `direction` and
+    /// returns the first surface it touches within `max_distance`.
+    ///
+    /// The collider of the character itself, `exclude`, must be ignored. If the capsule already
+    /// overlaps a surface at `origin`, a hit with a `distance` of zero should be returned.
+    fn cast_capsule(
+        &self,
+        capsule: &Capsule3d,
+        origin: Vec3,
+        rotation: Quat,
+        direction: Dir3,
+        max_distance: f32,
+        exclude: Entity,
+    ) -> Option<ShapeHit>;
+}
@@ -0,0 +1,115 @@
+use core::f32::consts::FRAC_PI_4;
+
+use bevy_ecs::{component::Component, entity::Entity, reflect::ReflectComponent};
+use bevy_math::{ops, primitives::Capsule3d, Affine3A, Dir3, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::Transform;
+
+/// A character moved kinematically by the [`CharacterControllerPlugin`](crate::CharacterControllerPlugin).
+///
+/// Every fixed timestep, the character is moved by its [`CharacterMovement`], sliding along the
+/// surfaces it hits, climbing steps and walkable slopes, and following the platform it stands on.
+/// The result is written to the [`Transform`], whose translation is the center of the
+/// capsule, and to the [`CharacterState`].
+///
+/// The controller does not apply gravity or any other force by itself: it moves the character
+/// exactly as requested unless something is in the way.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Transform, CharacterMovement, CharacterState)]
+pub struct CharacterController {
+    /// The shape of the character, aligned with [`CharacterController::up`].
+    pub capsule: Capsule3d,
+    /// The direction the character stands along. Ground is below the character.
+    pub up: Dir3,
+    /// The steepest slope, in radians, the character can stand on and walk up.
+    ///
+    /// Steeper surfaces are treated as walls.
+    pub max_slope_angle: f32,
+    /// The highest obstacle the character can step onto while walking.
+    pub step_height: f32,
+    /// How far the character is snapped down to the ground when walking down slopes and steps,
+    /// to keep it from briefly leaving the ground.
+    pub snap_to_ground: f32,
+    /// The distance kept between the character and the surfaces around it, which avoids
+    /// getting stuck due to floating point precision.
+    pub skin_width: f32,
+    /// The number of times the character can slide along a surface in one step.
+    pub max_slides: u32,
+}
+
+impl Default for CharacterController {
+    fn default() -> Self {
+        Self {
+            capsule: Capsule3d::new(0.4, 1.0),
+            up: Dir3::Y,
+            max_slope_angle: FRAC_PI_4,
+            step_height: 0.3,
+            snap_to_ground: 0.2,
+            skin_width: 0.01,
+            max_slides: 4,
+        }
+    }
+}
+
+impl CharacterController {
+    /// Returns `true` if the character can stand on a surface with the given `normal`.
+    pub fn is_walkable(&self, normal: Dir3) -> bool {
+        normal.dot(*self.up) >= ops::cos(self.max_slope_angle) - 1e-4
+    }
+}
+
+/// The movement requested for a [`CharacterController`].
+///
+/// Set this every frame from player input, including gravity and jumps, before the
+/// [`CharacterControllerSystems::Move`](crate::CharacterControllerSystems::Move) systems run.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct CharacterMovement {
+    /// The velocity of the character relative to the platform it stands on, in units per
+    /// second.
+    pub velocity: Vec3,
+}
+
+/// The ground a [`CharacterController`] stands on.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq, Clone)]
+pub struct GroundContact {
+    /// The entity standing on, if the collision world reported one.
+    pub entity: Option<Entity>,
+    /// The normal of the ground.
+    pub normal: Dir3,
+    /// The contact point.
+    pub point: Vec3,
+    /// Whether the character can stand on the ground, see
+    /// [`CharacterController::max_slope_angle`].
+    pub walkable: bool,
+}
+
+/// The outcome of the last move of a [`CharacterController`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct CharacterState {
+    /// The surface right below the character, if any.
+    ///
+    /// The character is only grounded if this is walkable, steeper surfaces make it slide down.
+    pub ground: Option<GroundContact>,
+    /// The velocity the character actually moved with during the last step, including the
+    /// motion of the platform it stands on.
+    pub velocity: Vec3,
+    /// The velocity of the platform the character stands on.
+    ///
+    /// Add it to the [`CharacterMovement`] when the character jumps off the platform to keep its
+    /// momentum.
+    pub platform_velocity: Vec3,
+    /// The platform the character stood on and its transform during the last step.
+    #[reflect(ignore)]
+    pub(crate) platform: Option<(Entity, Affine3A)>,
+}
+
+impl CharacterState {
+    /// Returns `true` if the character stands on walkable ground.
+    pub fn is_grounded(&self) -> bool {
+        self.ground.is_some_and(|ground| ground.walkable)
+    }
+}
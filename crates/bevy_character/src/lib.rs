@@ -0,0 +1,274 @@
+//! A kinematic character controller for Bevy.
+//!
+//! Characters are moved by sweeping a capsule through the world and sliding along what it hits,
+//! instead of being simulated as rigid bodies. This gives the precise, responsive movement most
+//! games expect from the player character without having to adopt a full physics engine: the
+//! controller only needs a [`CollisionWorld`] to cast capsules against.
+//!
+//! Refer to [`CharacterControllerPlugin`] for usage information.
+
+mod collision;
+mod controller;
+mod movement;
+
+pub use collision::{CollisionWorld, ShapeHit};
+pub use controller::{CharacterController, CharacterMovement, CharacterState, GroundContact};
+pub use movement::move_characters;
+
+use core::marker::PhantomData;
+
+use bevy_app::{App, FixedUpdate, Plugin};
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+
+/// The character controller prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{
+        CharacterController, CharacterControllerPlugin, CharacterMovement, CharacterState,
+        CollisionWorld,
+    };
+}
+
+/// Moves the [`CharacterController`]s through the collision world `W`.
+///
+/// The characters are moved in [`FixedUpdate`], in the [`CharacterControllerSystems::Move`] set,
+/// using the [`CharacterMovement`] set by the systems before it:
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{primitives::Capsule3d, Dir3, Quat, Vec3};
+/// # use bevy_character::prelude::*;
+/// # use bevy_character::{CharacterControllerSystems, ShapeHit};
+/// # use bevy_time::Time;
+/// # #[derive(Resource, Default)]
+/// # struct MyCollisionWorld;
+/// # impl CollisionWorld for MyCollisionWorld {
+/// #     fn cast_capsule(&self, _: &Capsule3d, _: Vec3, _: Quat, _: Dir3, _: f32, _: Entity) -> Option<ShapeHit> {
+/// #         None
+/// #     }
+/// # }
+/// const GRAVITY: Vec3 = Vec3::new(0.0, -9.81, 0.0);
+///
+/// fn apply_gravity(
+///     time: Res<Time>,
+///     mut characters: Query<(&mut CharacterMovement, &CharacterState)>,
+/// ) {
+///     for (mut movement, state) in &mut characters {
+///         if state.is_grounded() {
+///             movement.velocity.y = 0.0;
+///         } else {
+///             movement.velocity += GRAVITY * time.delta_secs();
+///         }
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(CharacterControllerPlugin::<MyCollisionWorld>::default())
+///     .init_resource::<MyCollisionWorld>()
+///     .add_systems(
+///         FixedUpdate,
+///         apply_gravity.before(CharacterControllerSystems::Move),
+///     );
+/// ```
+///
+/// The collision world must be inserted separately, and kept up to date with the geometry of
+/// the scene.
+pub struct CharacterControllerPlugin<W: CollisionWorld> {
+    _marker: PhantomData<fn() -> W>,
+}
+
+impl<W: CollisionWorld> Default for CharacterControllerPlugin<W> {
+    fn default() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<W: CollisionWorld> Plugin for CharacterControllerPlugin<W> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CharacterController>()
+            .register_type::<CharacterMovement>()
+            .register_type::<CharacterState>()
+            .add_systems(
+                FixedUpdate,
+                move_characters::<W>.in_set(CharacterControllerSystems::Move),
+            );
+    }
+}
+
+/// The systems of the [`CharacterControllerPlugin`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CharacterControllerSystems {
+    /// Moves the characters, in [`FixedUpdate`].
+    Move,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_ecs::{prelude::*, system::RunSystemOnce};
+    use bevy_math::{primitives::Capsule3d, Dir3, Quat, Vec3};
+    use bevy_time::Time;
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use crate::{
+        move_characters, CharacterController, CharacterMovement, CharacterState, CollisionWorld,
+        ShapeHit,
+    };
+
+    /// Solid half-spaces, each made of the points `p` with `normal.dot(p) <= offset`.
+    #[derive(Resource, Default)]
+    struct HalfSpaces(Vec<(Option<Entity>, Dir3, f32)>);
+
+    impl CollisionWorld for HalfSpaces {
+        fn cast_capsule(
+            &self,
+            capsule: &Capsule3d,
+            origin: Vec3,
+            rotation: Quat,
+            direction: Dir3,
+            max_distance: f32,
+            _exclude: Entity,
+        ) -> Option<ShapeHit> {
+            let axis = rotation * Vec3::Y;
+            self.0
+                .iter()
+                .filter_map(|&(entity, normal, offset)| {
+                    // The point of the capsule closest to the half-space.
+                    let support = origin
+                        - axis * capsule.half_length * normal.dot(axis).signum()
+                        - *normal * capsule.radius;
+                    let gap = normal.dot(support) - offset;
+                    let approach = -normal.dot(*direction);
+                    let distance = if gap <= 0.0 {
+                        0.0
+                    } else if approach > 0.0 {
+                        gap / approach
+                    } else {
+                        return None;
+                    };
+                    (distance <= max_distance).then(|| ShapeHit {
+                        entity,
+                        distance,
+                        point: support + direction * distance,
+                        normal,
+                    })
+                })
+                .min_by(|a, b| a.distance.total_cmp(&b.distance))
+        }
+    }
+
+    const REST_HEIGHT: f32 = 0.9 + 0.01;
+
+    fn setup(
+        half_spaces: Vec<(Option<Entity>, Dir3, f32)>,
+        position: Vec3,
+        velocity: Vec3,
+    ) -> (World, Entity) {
+        let mut world = World::new();
+        world.insert_resource(HalfSpaces(half_spaces));
+        world.init_resource::<Time>();
+        let character = world
+            .spawn((
+                CharacterController::default(),
+                CharacterMovement { velocity },
+                Transform::from_translation(position),
+            ))
+            .id();
+        (world, character)
+    }
+
+    fn step(world: &mut World, steps: u32) {
+        for _ in 0..steps {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(1.0 / 60.0));
+            world
+                .run_system_once(move_characters::<HalfSpaces>)
+                .unwrap();
+        }
+    }
+
+    fn ground() -> (Option<Entity>, Dir3, f32) {
+        (None, Dir3::Y, 0.0)
+    }
+
+    #[test]
+    fn lands_on_ground() {
+        let (mut world, character) =
+            setup(vec![ground()], Vec3::new(0.0, 3.0, 0.0), Vec3::NEG_Y * 5.0);
+        step(&mut world, 60);
+
+        let translation = world.get::<Transform>(character).unwrap().translation;
+        assert!((translation.y - REST_HEIGHT).abs() < 1e-3, "{translation}");
+        assert!(world
+            .get::<CharacterState>(character)
+            .unwrap()
+            .is_grounded());
+    }
+
+    #[test]
+    fn slides_along_walls() {
+        // A wall at `x = 2`.
+        let wall = (None, Dir3::NEG_X, -2.0);
+        let (mut world, character) = setup(
+            vec![ground(), wall],
+            Vec3::new(0.0, REST_HEIGHT, 0.0),
+            Vec3::new(5.0, 0.0, 5.0),
+        );
+        step(&mut world, 60);
+
+        let translation = world.get::<Transform>(character).unwrap().translation;
+        assert!(
+            (translation.x - (2.0 - 0.4 - 0.01)).abs() < 1e-3,
+            "{translation}"
+        );
+        assert!((translation.z - 5.0).abs() < 0.1, "{translation}");
+        assert!((translation.y - REST_HEIGHT).abs() < 1e-3, "{translation}");
+    }
+
+    #[test]
+    fn walks_up_slopes() {
+        // A 30 degree slope starting at `x = 1`.
+        let normal = Dir3::new(Vec3::new(-0.5, 0.75_f32.sqrt(), 0.0)).unwrap();
+        let slope = (None, normal, normal.dot(Vec3::X));
+        let (mut world, character) = setup(
+            vec![ground(), slope],
+            Vec3::new(0.0, REST_HEIGHT, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+        );
+        step(&mut world, 180);
+
+        let translation = world.get::<Transform>(character).unwrap().translation;
+        assert!(translation.x > 3.0, "{translation}");
+        assert!(translation.y > REST_HEIGHT + 1.0, "{translation}");
+        assert!(world
+            .get::<CharacterState>(character)
+            .unwrap()
+            .is_grounded());
+    }
+
+    #[test]
+    fn follows_moving_platforms() {
+        let (mut world, character) =
+            setup(Vec::new(), Vec3::new(0.0, REST_HEIGHT, 0.0), Vec3::ZERO);
+        let platform = world.spawn(GlobalTransform::IDENTITY).id();
+        world.resource_mut::<HalfSpaces>().0 = vec![(Some(platform), Dir3::Y, 0.0)];
+        step(&mut world, 1);
+
+        *world.get_mut::<GlobalTransform>(platform).unwrap() =
+            GlobalTransform::from_translation(Vec3::new(1.0, 0.0, 0.0));
+        step(&mut world, 1);
+
+        let translation = world.get::<Transform>(character).unwrap().translation;
+        assert!((translation.x - 1.0).abs() < 1e-3, "{translation}");
+        let state = world.get::<CharacterState>(character).unwrap();
+        assert!(state.is_grounded());
+        assert!((state.platform_velocity.x - 60.0).abs() < 1e-2);
+    }
+}
@@ -0,0 +1,211 @@
+use bevy_ecs::{
+    entity::Entity,
+    system::{Query, Res},
+};
+use bevy_math::{Dir3, Quat, Vec3};
+use bevy_time::Time;
+use bevy_transform::components::{GlobalTransform, Transform};
+
+use crate::{
+    CharacterController, CharacterMovement, CharacterState, CollisionWorld, GroundContact, ShapeHit,
+};
+
+/// Motions shorter than this are ignored.
+const MIN_MOTION: f32 = 1e-5;
+
+/// Sweeps the capsule of one character through a [`CollisionWorld`].
+struct Sweep<'a, W> {
+    world: &'a W,
+    controller: &'a CharacterController,
+    rotation: Quat,
+    entity: Entity,
+}
+
+impl<W: CollisionWorld> Sweep<'_, W> {
+    fn cast(&self, origin: Vec3, direction: Dir3, distance: f32) -> Option<ShapeHit> {
+        self.world.cast_capsule(
+            &self.controller.capsule,
+            origin,
+            self.rotation,
+            direction,
+            distance,
+            self.entity,
+        )
+    }
+
+    /// Moves from `origin` along `motion` until something is hit, keeping the skin width from
+    /// it along its normal. Returns the new position and the hit.
+    fn advance(&self, origin: Vec3, motion: Vec3) -> (Vec3, Option<ShapeHit>) {
+        let Ok((direction, length)) = Dir3::new_and_length(motion) else {
+            return (origin, None);
+        };
+        let skin = self.controller.skin_width;
+        match self.cast(origin, direction, length + skin) {
+            Some(hit) => {
+                let travel = (hit.distance - skin).clamp(0.0, length);
+                // Backing off along the motion leaves a smaller gap when the surface is hit at an
+                // angle, so the rest of the skin width is made up along the normal.
+                let gap = (hit.distance - travel) * -direction.dot(*hit.normal);
+                let push = (skin - gap).max(0.0);
+                (origin + direction * travel + *hit.normal * push, Some(hit))
+            }
+            None => (origin + motion, None),
+        }
+    }
+
+    /// Moves from `origin` along `motion`, sliding along the surfaces that are hit.
+    fn slide(&self, mut origin: Vec3, motion: Vec3, grounded: bool) -> Vec3 {
+        let up = self.controller.up;
+        let mut remaining = motion;
+        for _ in 0..self.controller.max_slides {
+            if remaining.length_squared() < MIN_MOTION * MIN_MOTION {
+                break;
+            }
+            let (position, hit) = self.advance(origin, remaining);
+            remaining -= position - origin;
+            origin = position;
+            let Some(hit) = hit else {
+                break;
+            };
+
+            let walkable = self.controller.is_walkable(hit.normal);
+            if !walkable && grounded {
+                let horizontal = remaining.reject_from_normalized(*up);
+                if let Some(stepped) = self.step(origin, horizontal) {
+                    origin = stepped;
+                    remaining -= horizontal;
+                    continue;
+                }
+            }
+
+            remaining = remaining.reject_from_normalized(*hit.normal);
+            if !walkable {
+                // Walls and steep slopes must not be climbed by sliding along them.
+                let climb = remaining.dot(*up);
+                if climb > 0.0 {
+                    remaining -= *up * climb;
+                }
+            }
+        }
+        origin
+    }
+
+    /// Tries to step over an obstacle in the way of `horizontal`, returning the position on top
+    /// of it.
+    fn step(&self, origin: Vec3, horizontal: Vec3) -> Option<Vec3> {
+        let controller = self.controller;
+        if controller.step_height <= 0.0 || horizontal.length_squared() < MIN_MOTION * MIN_MOTION {
+            return None;
+        }
+        let up = controller.up;
+
+        let (raised, _) = self.advance(origin, *up * controller.step_height);
+        // The obstacle is only stepped over if it is lower than the step height.
+        let (moved, hit) = self.advance(raised, horizontal);
+        if hit.is_some_and(|hit| !controller.is_walkable(hit.normal))
+            || (moved - raised).length_squared() < MIN_MOTION * MIN_MOTION
+        {
+            return None;
+        }
+        let lift = (raised - origin).dot(*up);
+        let hit = self.cast(moved, -up, lift + controller.skin_width)?;
+        if !controller.is_walkable(hit.normal) {
+            return None;
+        }
+        Some(moved - *up * (hit.distance - controller.skin_width).max(0.0))
+    }
+
+    /// Looks for ground below `origin`, snapping down to it by up to `snap` if walkable.
+    fn ground(&self, origin: Vec3, snap: f32) -> (Vec3, Option<GroundContact>) {
+        let controller = self.controller;
+        let skin = controller.skin_width;
+        let Some(hit) = self.cast(origin, -controller.up, snap + 2.0 * skin) else {
+            return (origin, None);
+        };
+        let walkable = controller.is_walkable(hit.normal);
+        if !walkable && hit.distance > 2.0 * skin {
+            return (origin, None);
+        }
+        let contact = GroundContact {
+            entity: hit.entity,
+            normal: hit.normal,
+            point: hit.point,
+            walkable,
+        };
+        let drop = if walkable {
+            (hit.distance - skin).max(0.0)
+        } else {
+            0.0
+        };
+        (origin - *controller.up * drop, Some(contact))
+    }
+}
+
+/// Moves every [`CharacterController`] through the collision world `W`.
+pub fn move_characters<W: CollisionWorld>(
+    world: Res<W>,
+    time: Res<Time>,
+    mut characters: Query<(
+        Entity,
+        &CharacterController,
+        &CharacterMovement,
+        &mut CharacterState,
+        &mut Transform,
+    )>,
+    platforms: Query<&GlobalTransform>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (entity, controller, movement, mut state, mut transform) in &mut characters {
+        let sweep = Sweep {
+            world: &*world,
+            controller,
+            rotation: Quat::from_rotation_arc(Vec3::Y, *controller.up),
+            entity,
+        };
+        let start = transform.translation;
+        let was_grounded = state.is_grounded();
+
+        // Follow the platform the character stands on.
+        let mut position = start;
+        let mut platform_motion = Vec3::ZERO;
+        if let Some((platform, previous)) = state.platform
+            && let Ok(current) = platforms.get(platform)
+        {
+            let platform_delta = current.affine() * previous.inverse();
+            platform_motion = platform_delta.transform_point3(start) - start;
+            position = sweep.slide(position, platform_motion, true);
+
+            let (_, rotation, _) = platform_delta.to_scale_rotation_translation();
+            let up = *controller.up;
+            let twist = Vec3::new(rotation.x, rotation.y, rotation.z).project_onto_normalized(up);
+            let twist = Quat::from_xyzw(twist.x, twist.y, twist.z, rotation.w);
+            if twist.length_squared() > 1e-6 {
+                transform.rotate(twist.normalize());
+            }
+        }
+
+        position = sweep.slide(position, movement.velocity * delta, was_grounded);
+
+        let snap = if was_grounded && movement.velocity.dot(*controller.up) <= 0.0 {
+            controller.snap_to_ground
+        } else {
+            0.0
+        };
+        let (position, ground) = sweep.ground(position, snap);
+
+        state.ground = ground;
+        state.velocity = (position - start) / delta;
+        state.platform_velocity = platform_motion / delta;
+        state.platform = ground
+            .filter(|ground| ground.walkable)
+            .and_then(|ground| ground.entity)
+            .and_then(|platform| Some((platform, platforms.get(platform).ok()?.affine())));
+        if transform.translation != position {
+            transform.translation = position;
+        }
+    }
+}
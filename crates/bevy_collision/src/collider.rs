@@ -0,0 +1,113 @@
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_math::primitives::{Capsule3d, Cuboid, Sphere};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::Transform;
+
+/// The shape of an entity for collision detection, centered on its [`Transform`].
+///
+/// Colliders do not push each other: the [`CollisionPlugin`](crate::CollisionPlugin) only
+/// reports which colliders overlap, see [`Collisions`](crate::Collisions), and answers
+/// queries such as ray casts. This is enough for triggers, pickups or hit detection, without
+/// adding rigid-body dynamics to the game.
+///
+/// Colliders are scaled along with their entity. Spheres are scaled by the largest axis of the
+/// scale, and capsules, aligned with the Y axis, by the largest of the X and Z axes.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+#[require(Transform, CollisionLayers)]
+pub enum Collider {
+    /// A sphere.
+    Sphere(Sphere),
+    /// A box.
+    Cuboid(Cuboid),
+    /// A capsule, aligned with the Y axis.
+    Capsule(Capsule3d),
+}
+
+impl Collider {
+    /// Creates a sphere collider.
+    pub fn sphere(radius: f32) -> Self {
+        Self::Sphere(Sphere::new(radius))
+    }
+
+    /// Creates a box collider with the given full lengths along the X, Y and Z axes.
+    pub fn cuboid(x_length: f32, y_length: f32, z_length: f32) -> Self {
+        Self::Cuboid(Cuboid::new(x_length, y_length, z_length))
+    }
+
+    /// Creates a capsule collider, `length` being the height of its cylinder.
+    pub fn capsule(radius: f32, length: f32) -> Self {
+        Self::Capsule(Capsule3d::new(radius, length))
+    }
+}
+
+impl From<Sphere> for Collider {
+    fn from(sphere: Sphere) -> Self {
+        Self::Sphere(sphere)
+    }
+}
+
+impl From<Cuboid> for Collider {
+    fn from(cuboid: Cuboid) -> Self {
+        Self::Cuboid(cuboid)
+    }
+}
+
+impl From<Capsule3d> for Collider {
+    fn from(capsule: Capsule3d) -> Self {
+        Self::Capsule(capsule)
+    }
+}
+
+/// The layers a [`Collider`] belongs to, and the layers it collides with.
+///
+/// Two colliders only overlap if each of them belongs to a layer the other one collides with.
+/// By default, colliders belong to and collide with all the layers.
+///
+/// ```
+/// # use bevy_collision::CollisionLayers;
+/// const PLAYER: u32 = 1 << 0;
+/// const ENEMY: u32 = 1 << 1;
+/// const PICKUP: u32 = 1 << 2;
+///
+/// let player = CollisionLayers::new(PLAYER, ENEMY | PICKUP);
+/// let pickup = CollisionLayers::new(PICKUP, PLAYER);
+/// let enemy = CollisionLayers::new(ENEMY, PLAYER);
+/// assert!(player.interacts_with(&pickup));
+/// assert!(!pickup.interacts_with(&enemy));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Hash, Clone)]
+pub struct CollisionLayers {
+    /// The bitmask of the layers the collider belongs to.
+    pub memberships: u32,
+    /// The bitmask of the layers the collider collides with.
+    pub filters: u32,
+}
+
+impl CollisionLayers {
+    /// Belongs to and collides with all the layers.
+    pub const ALL: Self = Self::new(u32::MAX, u32::MAX);
+
+    /// Belongs to and collides with no layer.
+    pub const NONE: Self = Self::new(0, 0);
+
+    /// Creates layers from the bitmasks of the `memberships` and the `filters`.
+    pub const fn new(memberships: u32, filters: u32) -> Self {
+        Self {
+            memberships,
+            filters,
+        }
+    }
+
+    /// Returns `true` if colliders with these layers and the `other` layers can overlap.
+    pub const fn interacts_with(&self, other: &Self) -> bool {
+        self.memberships & other.filters != 0 && other.memberships & self.filters != 0
+    }
+}
+
+impl Default for CollisionLayers {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
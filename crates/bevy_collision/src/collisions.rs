@@ -0,0 +1,231 @@
+use bevy_ecs::{
+    entity::{Entities, Entity},
+    event::EntityEvent,
+    reflect::ReflectEvent,
+    resource::Resource,
+    system::{Commands, Query, ResMut},
+};
+use bevy_math::{bounding::Aabb3d, Dir3, Ray3d, Vec3};
+use bevy_platform::collections::HashSet;
+use bevy_reflect::Reflect;
+use bevy_transform::components::GlobalTransform;
+
+use crate::{shape::WorldShape, Collider, CollisionLayers};
+
+/// Triggered on both entities when two [`Collider`]s start overlapping.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_collision::{Collider, CollisionStart};
+/// #[derive(Component)]
+/// struct Coin;
+///
+/// fn spawn_coin(mut commands: Commands) {
+///     commands.spawn((Coin, Collider::sphere(0.5))).observe(
+///         |collision: On<CollisionStart>, mut commands: Commands| {
+///             // Picked up.
+///             commands.entity(collision.entity).despawn();
+///         },
+///     );
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_coin);
+/// ```
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Event, Debug, PartialEq, Clone)]
+pub struct CollisionStart {
+    /// The entity the event is triggered on.
+    pub entity: Entity,
+    /// The entity it started overlapping with.
+    pub other: Entity,
+}
+
+/// Triggered on both entities when two [`Collider`]s stop overlapping.
+///
+/// This is also triggered when one of them loses its [`Collider`] or is despawned, in which
+/// case it is only triggered on the remaining entity.
+#[derive(EntityEvent, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Event, Debug, PartialEq, Clone)]
+pub struct CollisionEnd {
+    /// The entity the event is triggered on.
+    pub entity: Entity,
+    /// The entity it stopped overlapping with.
+    pub other: Entity,
+}
+
+/// The first [`Collider`] hit by a ray, see [`Collisions::cast_ray`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    /// The entity that was hit.
+    pub entity: Entity,
+    /// The distance along the ray to the hit.
+    pub distance: f32,
+    /// The point that was hit.
+    pub point: Vec3,
+    /// The normal of the surface at the hit point.
+    pub normal: Dir3,
+}
+
+struct Entry {
+    entity: Entity,
+    shape: WorldShape,
+    layers: CollisionLayers,
+    aabb: Aabb3d,
+}
+
+/// The [`Collider`]s overlapping each other, and the spatial queries against all the colliders.
+///
+/// This is updated in [`PostUpdate`](bevy_app::PostUpdate), in
+/// [`CollisionSystems`](crate::CollisionSystems), after transform propagation. Systems running
+/// earlier in the frame see the colliders where they were at the end of the previous frame.
+#[derive(Resource, Default)]
+pub struct Collisions {
+    /// The colliders, sorted by the lower bound of their bounding box along the X axis.
+    entries: Vec<Entry>,
+    /// The overlapping pairs, the lowest entity first.
+    pairs: HashSet<(Entity, Entity)>,
+}
+
+impl Collisions {
+    /// Returns `true` if the colliders of `a` and `b` overlap.
+    pub fn contains(&self, a: Entity, b: Entity) -> bool {
+        self.pairs.contains(&ordered(a, b))
+    }
+
+    /// Returns all the pairs of overlapping colliders.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Entity)> + '_ {
+        self.pairs.iter().copied()
+    }
+
+    /// Returns the entities whose colliders overlap the collider of `entity`.
+    pub fn colliding_with(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.pairs.iter().filter_map(move |&(a, b)| {
+            if a == entity {
+                Some(b)
+            } else if b == entity {
+                Some(a)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the first collider in `layers` hit by `ray` within `max_distance`.
+    ///
+    /// Colliders containing the origin of the ray are hit at a distance of zero.
+    pub fn cast_ray(
+        &self,
+        ray: Ray3d,
+        max_distance: f32,
+        layers: CollisionLayers,
+    ) -> Option<RayHit> {
+        self.entries
+            .iter()
+            .filter(|entry| layers.interacts_with(&entry.layers))
+            .filter_map(|entry| {
+                let (distance, normal) = entry.shape.cast_ray(ray, max_distance)?;
+                Some(RayHit {
+                    entity: entry.entity,
+                    distance,
+                    point: ray.get_point(distance),
+                    normal,
+                })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// Returns the entities in `layers` whose colliders contain `point`.
+    pub fn point_overlaps(
+        &self,
+        point: Vec3,
+        layers: CollisionLayers,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        self.entries
+            .iter()
+            .filter(move |entry| {
+                layers.interacts_with(&entry.layers) && entry.shape.contains_point(point)
+            })
+            .map(|entry| entry.entity)
+    }
+}
+
+fn ordered(a: Entity, b: Entity) -> (Entity, Entity) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Finds the overlapping [`Collider`]s and triggers the [`CollisionStart`] and [`CollisionEnd`]
+/// events.
+pub(crate) fn update_collisions(
+    mut collisions: ResMut<Collisions>,
+    colliders: Query<(Entity, &Collider, &CollisionLayers, &GlobalTransform)>,
+    entities: &Entities,
+    mut commands: Commands,
+) {
+    let collisions = &mut *collisions;
+    collisions.entries.clear();
+    collisions.entries.extend(
+        colliders
+            .iter()
+            .map(|(entity, collider, layers, transform)| {
+                let shape = WorldShape::new(collider, transform);
+                Entry {
+                    entity,
+                    shape,
+                    layers: *layers,
+                    aabb: shape.aabb(),
+                }
+            }),
+    );
+    collisions
+        .entries
+        .sort_unstable_by(|a, b| a.aabb.min.x.total_cmp(&b.aabb.min.x));
+
+    // Sweep and prune along the X axis: only the colliders whose bounding boxes overlap along
+    // it are tested further.
+    let mut pairs = HashSet::with_capacity(collisions.pairs.len());
+    let entries = &collisions.entries;
+    for (i, a) in entries.iter().enumerate() {
+        for b in entries[i + 1..]
+            .iter()
+            .take_while(|b| b.aabb.min.x <= a.aabb.max.x)
+        {
+            if a.aabb.min.y <= b.aabb.max.y
+                && b.aabb.min.y <= a.aabb.max.y
+                && a.aabb.min.z <= b.aabb.max.z
+                && b.aabb.min.z <= a.aabb.max.z
+                && a.layers.interacts_with(&b.layers)
+                && a.shape.intersects(&b.shape)
+            {
+                pairs.insert(ordered(a.entity, b.entity));
+            }
+        }
+    }
+
+    // The pairs are sorted so the events are triggered in the same order on every run.
+    let mut started: Vec<_> = pairs.difference(&collisions.pairs).copied().collect();
+    started.sort_unstable();
+    let mut ended: Vec<_> = collisions.pairs.difference(&pairs).copied().collect();
+    ended.sort_unstable();
+
+    for (a, b) in started {
+        commands.trigger(CollisionStart {
+            entity: a,
+            other: b,
+        });
+        commands.trigger(CollisionStart {
+            entity: b,
+            other: a,
+        });
+    }
+    for (a, b) in ended {
+        for (entity, other) in [(a, b), (b, a)] {
+            if entities.contains_spawned(entity) {
+                commands.trigger(CollisionEnd { entity, other });
+            }
+        }
+    }
+    collisions.pairs = pairs;
+}
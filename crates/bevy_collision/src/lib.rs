@@ -0,0 +1,221 @@
+//! Lightweight collision detection for Bevy.
+//!
+//! Many games only need to know when things touch, to trigger a cutscene when the player walks
+//! into an area, pick up coins or detect the hits of a sword, without the cost and complexity of
+//! a physics engine. This crate detects the overlaps between [`Collider`]s, reports them with
+//! the [`CollisionStart`] and [`CollisionEnd`] events, and answers ray casts and point queries
+//! through the [`Collisions`] resource. Colliders never push each other.
+//!
+//! Refer to [`CollisionPlugin`] for usage information.
+
+mod collider;
+mod collisions;
+mod shape;
+
+pub use collider::{Collider, CollisionLayers};
+pub use collisions::{CollisionEnd, CollisionStart, Collisions, RayHit};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_transform::TransformSystems;
+
+/// The collision prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{
+        Collider, CollisionEnd, CollisionLayers, CollisionPlugin, CollisionStart, Collisions,
+    };
+}
+
+/// Detects the overlaps between [`Collider`]s.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{Dir3, Ray3d, Vec3};
+/// # use bevy_collision::prelude::*;
+/// fn shoot(collisions: Res<Collisions>) {
+///     let ray = Ray3d::new(Vec3::ZERO, Dir3::X);
+///     if let Some(hit) = collisions.cast_ray(ray, 100.0, CollisionLayers::ALL) {
+///         println!("Hit {} at {}", hit.entity, hit.point);
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(CollisionPlugin)
+///     .add_observer(|collision: On<CollisionStart>| {
+///         println!("{} touched {}", collision.entity, collision.other);
+///     })
+///     .add_systems(Update, shoot);
+/// ```
+///
+/// Overlaps are detected in [`PostUpdate`], after transform propagation, using the
+/// [`GlobalTransform`](bevy_transform::components::GlobalTransform) of the colliders.
+#[derive(Default)]
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Collider>()
+            .register_type::<CollisionLayers>()
+            .register_type::<CollisionStart>()
+            .register_type::<CollisionEnd>()
+            .init_resource::<Collisions>()
+            .add_systems(
+                PostUpdate,
+                collisions::update_collisions
+                    .in_set(CollisionSystems)
+                    .after(TransformSystems::Propagate),
+            );
+    }
+}
+
+/// The system set in which the [`Collisions`] are updated, in [`PostUpdate`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CollisionSystems;
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::prelude::*;
+    use bevy_math::{Dir3, Ray3d, Vec3};
+    use bevy_transform::components::GlobalTransform;
+
+    use crate::{
+        Collider, CollisionEnd, CollisionLayers, CollisionPlugin, CollisionStart, Collisions,
+    };
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<(&'static str, Entity, Entity)>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(CollisionPlugin)
+            .init_resource::<Log>()
+            .add_observer(|event: On<CollisionStart>, mut log: ResMut<Log>| {
+                log.0.push(("start", event.entity, event.other));
+            })
+            .add_observer(|event: On<CollisionEnd>, mut log: ResMut<Log>| {
+                log.0.push(("end", event.entity, event.other));
+            });
+        app
+    }
+
+    fn spawn(app: &mut App, collider: Collider, position: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((collider, GlobalTransform::from_translation(position)))
+            .id()
+    }
+
+    fn update(app: &mut App) -> Vec<(&'static str, Entity, Entity)> {
+        app.world_mut().run_schedule(PostUpdate);
+        core::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+    }
+
+    /// Sorts the `events`, as entities are not ordered by when they were spawned.
+    fn sorted(
+        mut events: Vec<(&'static str, Entity, Entity)>,
+    ) -> Vec<(&'static str, Entity, Entity)> {
+        events.sort();
+        events
+    }
+
+    #[test]
+    fn start_and_end_events() {
+        let mut app = app();
+        let a = spawn(&mut app, Collider::sphere(1.0), Vec3::ZERO);
+        let b = spawn(
+            &mut app,
+            Collider::cuboid(1.0, 1.0, 1.0),
+            Vec3::new(1.2, 0.0, 0.0),
+        );
+        let c = spawn(
+            &mut app,
+            Collider::capsule(0.5, 1.0),
+            Vec3::new(5.0, 0.0, 0.0),
+        );
+
+        assert_eq!(
+            sorted(update(&mut app)),
+            sorted(vec![("start", a, b), ("start", b, a)])
+        );
+        assert!(app.world().resource::<Collisions>().contains(b, a));
+        assert!(update(&mut app).is_empty());
+
+        // Moving the box over the capsule.
+        *app.world_mut().get_mut::<GlobalTransform>(b).unwrap() =
+            GlobalTransform::from_translation(Vec3::new(4.0, 0.9, 0.0));
+        assert_eq!(
+            sorted(update(&mut app)),
+            sorted(vec![
+                ("end", a, b),
+                ("end", b, a),
+                ("start", b, c),
+                ("start", c, b)
+            ])
+        );
+
+        app.world_mut().despawn(c);
+        assert_eq!(update(&mut app), [("end", b, c)]);
+        assert_eq!(app.world().resource::<Collisions>().iter().count(), 0);
+    }
+
+    #[test]
+    fn layers() {
+        let mut app = app();
+        let a = app
+            .world_mut()
+            .spawn((Collider::sphere(1.0), CollisionLayers::new(0b01, 0b10)))
+            .id();
+        let b = app
+            .world_mut()
+            .spawn((Collider::sphere(1.0), CollisionLayers::new(0b10, 0b01)))
+            .id();
+        app.world_mut()
+            .spawn((Collider::sphere(1.0), CollisionLayers::new(0b10, 0b10)));
+        update(&mut app);
+
+        let collisions = app.world().resource::<Collisions>();
+        assert_eq!(collisions.colliding_with(a).collect::<Vec<_>>(), [b]);
+        assert_eq!(collisions.colliding_with(b).collect::<Vec<_>>(), [a]);
+    }
+
+    #[test]
+    fn spatial_queries() {
+        let mut app = app();
+        let near = spawn(&mut app, Collider::sphere(1.0), Vec3::new(5.0, 0.0, 0.0));
+        let far = spawn(
+            &mut app,
+            Collider::cuboid(2.0, 2.0, 2.0),
+            Vec3::new(10.0, 0.0, 0.0),
+        );
+        app.world_mut().spawn((
+            Collider::sphere(1.0),
+            GlobalTransform::from_xyz(2.0, 0.0, 0.0),
+            CollisionLayers::NONE,
+        ));
+        update(&mut app);
+
+        let collisions = app.world().resource::<Collisions>();
+        let ray = Ray3d::new(Vec3::ZERO, Dir3::X);
+        let hit = collisions
+            .cast_ray(ray, 100.0, CollisionLayers::ALL)
+            .unwrap();
+        assert_eq!(hit.entity, near);
+        assert_eq!(hit.point, Vec3::new(4.0, 0.0, 0.0));
+        assert_eq!(hit.normal, Dir3::NEG_X);
+        assert!(collisions
+            .cast_ray(ray, 3.0, CollisionLayers::ALL)
+            .is_none());
+
+        let points = |point| {
+            collisions
+                .point_overlaps(point, CollisionLayers::ALL)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(points(Vec3::new(10.5, 0.9, -0.9)), [far]);
+        assert!(points(Vec3::new(7.0, 0.0, 0.0)).is_empty());
+    }
+}
@@ -0,0 +1,455 @@
+use bevy_math::{bounding::Aabb3d, Dir3, Mat3, Quat, Ray3d, Vec3};
+use bevy_transform::components::GlobalTransform;
+
+use crate::Collider;
+
+/// Lengths below this are treated as zero.
+const EPSILON: f32 = 1e-6;
+
+/// A [`Collider`] placed in the world by its [`GlobalTransform`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum WorldShape {
+    Sphere {
+        center: Vec3,
+        radius: f32,
+    },
+    Box {
+        center: Vec3,
+        rotation: Quat,
+        half_size: Vec3,
+    },
+    Capsule {
+        a: Vec3,
+        b: Vec3,
+        radius: f32,
+    },
+}
+
+impl WorldShape {
+    pub(crate) fn new(collider: &Collider, transform: &GlobalTransform) -> Self {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        let scale = scale.abs();
+        match *collider {
+            Collider::Sphere(sphere) => Self::Sphere {
+                center: translation,
+                radius: sphere.radius * scale.max_element(),
+            },
+            Collider::Cuboid(cuboid) => Self::Box {
+                center: translation,
+                rotation,
+                half_size: cuboid.half_size * scale,
+            },
+            Collider::Capsule(capsule) => {
+                let axis = rotation * Vec3::Y * capsule.half_length * scale.y;
+                Self::Capsule {
+                    a: translation - axis,
+                    b: translation + axis,
+                    radius: capsule.radius * scale.x.max(scale.z),
+                }
+            }
+        }
+    }
+
+    pub(crate) fn aabb(&self) -> Aabb3d {
+        match *self {
+            Self::Sphere { center, radius } => Aabb3d::new(center, Vec3::splat(radius)),
+            Self::Box {
+                center,
+                rotation,
+                half_size,
+            } => Aabb3d::new(center, Mat3::from_quat(rotation).abs() * half_size),
+            Self::Capsule { a, b, radius } => Aabb3d {
+                min: (a.min(b) - radius).into(),
+                max: (a.max(b) + radius).into(),
+            },
+        }
+    }
+
+    pub(crate) fn contains_point(&self, point: Vec3) -> bool {
+        match *self {
+            Self::Sphere { center, radius } => center.distance_squared(point) <= radius * radius,
+            Self::Box {
+                center,
+                rotation,
+                half_size,
+            } => box_distance_squared(center, rotation, half_size, point) == 0.0,
+            Self::Capsule { a, b, radius } => {
+                closest_on_segment(a, b, point).distance_squared(point) <= radius * radius
+            }
+        }
+    }
+
+    pub(crate) fn intersects(&self, other: &Self) -> bool {
+        use WorldShape::*;
+        match (*self, *other) {
+            (
+                Sphere {
+                    center: a,
+                    radius: radius_a,
+                },
+                Sphere {
+                    center: b,
+                    radius: radius_b,
+                },
+            ) => a.distance_squared(b) <= (radius_a + radius_b) * (radius_a + radius_b),
+            (Sphere { center, radius }, Capsule { a, b, radius: r })
+            | (Capsule { a, b, radius: r }, Sphere { center, radius }) => {
+                closest_on_segment(a, b, center).distance_squared(center)
+                    <= (radius + r) * (radius + r)
+            }
+            (
+                Capsule {
+                    a: a1,
+                    b: b1,
+                    radius: r1,
+                },
+                Capsule {
+                    a: a2,
+                    b: b2,
+                    radius: r2,
+                },
+            ) => segment_distance_squared(a1, b1, a2, b2) <= (r1 + r2) * (r1 + r2),
+            (
+                Sphere { center, radius },
+                Box {
+                    center: box_center,
+                    rotation,
+                    half_size,
+                },
+            )
+            | (
+                Box {
+                    center: box_center,
+                    rotation,
+                    half_size,
+                },
+                Sphere { center, radius },
+            ) => box_distance_squared(box_center, rotation, half_size, center) <= radius * radius,
+            (
+                Capsule { a, b, radius },
+                Box {
+                    center,
+                    rotation,
+                    half_size,
+                },
+            )
+            | (
+                Box {
+                    center,
+                    rotation,
+                    half_size,
+                },
+                Capsule { a, b, radius },
+            ) => segment_box_distance_squared(a, b, center, rotation, half_size) <= radius * radius,
+            (
+                Box {
+                    center: c1,
+                    rotation: r1,
+                    half_size: h1,
+                },
+                Box {
+                    center: c2,
+                    rotation: r2,
+                    half_size: h2,
+                },
+            ) => boxes_intersect(c1, r1, h1, c2, r2, h2),
+        }
+    }
+
+    /// Returns the distance along `ray` at which it enters the shape, and the normal of the
+    /// surface there.
+    ///
+    /// Rays starting inside the shape hit it at a distance of zero, with a normal opposite to
+    /// the ray.
+    pub(crate) fn cast_ray(&self, ray: Ray3d, max_distance: f32) -> Option<(f32, Dir3)> {
+        let (distance, normal) = match *self {
+            Self::Sphere { center, radius } => ray_sphere(ray, center, radius)
+                .map(|distance| (distance, ray.get_point(distance) - center))?,
+            Self::Box {
+                center,
+                rotation,
+                half_size,
+            } => {
+                let inverse = rotation.inverse();
+                let origin = inverse * (ray.origin - center);
+                let direction = inverse * *ray.direction;
+                ray_box(origin, direction, half_size)
+                    .map(|(distance, normal)| (distance, rotation * normal))?
+            }
+            Self::Capsule { a, b, radius } => ray_capsule(ray, a, b, radius).map(|distance| {
+                let point = ray.get_point(distance);
+                (distance, point - closest_on_segment(a, b, point))
+            })?,
+        };
+        if distance > max_distance {
+            return None;
+        }
+        let normal = if distance == 0.0 {
+            -ray.direction
+        } else {
+            Dir3::new(normal).unwrap_or(-ray.direction)
+        };
+        Some((distance, normal))
+    }
+}
+
+fn closest_on_segment(a: Vec3, b: Vec3, point: Vec3) -> Vec3 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared < EPSILON {
+        return a;
+    }
+    a + ab * ((point - a).dot(ab) / length_squared).clamp(0.0, 1.0)
+}
+
+/// Returns the squared distance between the segments `p1 q1` and `p2 q2`.
+fn segment_distance_squared(p1: Vec3, q1: Vec3, p2: Vec3, q2: Vec3) -> f32 {
+    let (d1, d2, r) = (q1 - p1, q2 - p2, p1 - p2);
+    let (a, e, f) = (d1.length_squared(), d2.length_squared(), d2.dot(r));
+    let (s, t) = if a <= EPSILON && e <= EPSILON {
+        (0.0, 0.0)
+    } else if a <= EPSILON {
+        (0.0, (f / e).clamp(0.0, 1.0))
+    } else {
+        let c = d1.dot(r);
+        if e <= EPSILON {
+            ((-c / a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = d1.dot(d2);
+            let denominator = a * e - b * b;
+            let s = if denominator != 0.0 {
+                ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / e;
+            if t < 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else if t > 1.0 {
+                (((b - c) / a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+    (p1 + d1 * s).distance_squared(p2 + d2 * t)
+}
+
+fn box_distance_squared(center: Vec3, rotation: Quat, half_size: Vec3, point: Vec3) -> f32 {
+    let local = rotation.inverse() * (point - center);
+    local.distance_squared(local.clamp(-half_size, half_size))
+}
+
+fn segment_box_distance_squared(
+    a: Vec3,
+    b: Vec3,
+    center: Vec3,
+    rotation: Quat,
+    half_size: Vec3,
+) -> f32 {
+    // The distance to a convex shape is convex along the segment, so its minimum is found with
+    // a golden-section search.
+    let distance = |t: f32| box_distance_squared(center, rotation, half_size, a.lerp(b, t));
+    const RATIO: f32 = 0.618_034;
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..32 {
+        let left = high - RATIO * (high - low);
+        let right = low + RATIO * (high - low);
+        if distance(left) < distance(right) {
+            high = right;
+        } else {
+            low = left;
+        }
+    }
+    distance((low + high) / 2.0)
+}
+
+/// Tests two oriented boxes for intersection with the separating axis theorem.
+fn boxes_intersect(c1: Vec3, r1: Quat, h1: Vec3, c2: Vec3, r2: Quat, h2: Vec3) -> bool {
+    let (a, b) = (Mat3::from_quat(r1), Mat3::from_quat(r2));
+    let axes_a = [a.x_axis, a.y_axis, a.z_axis];
+    let axes_b = [b.x_axis, b.y_axis, b.z_axis];
+    // The rotation of the second box in the frame of the first one.
+    let r = axes_a.map(|axis_a| axes_b.map(|axis_b| axis_a.dot(axis_b)));
+    // The epsilon avoids false negatives when two edges are parallel.
+    let abs_r = r.map(|row| row.map(|value| value.abs() + EPSILON));
+    let t = axes_a.map(|axis| (c2 - c1).dot(axis));
+    let (ha, hb) = (h1.to_array(), h2.to_array());
+
+    for i in 0..3 {
+        let rb = hb[0] * abs_r[i][0] + hb[1] * abs_r[i][1] + hb[2] * abs_r[i][2];
+        if t[i].abs() > ha[i] + rb {
+            return false;
+        }
+    }
+    for j in 0..3 {
+        let ra = ha[0] * abs_r[0][j] + ha[1] * abs_r[1][j] + ha[2] * abs_r[2][j];
+        let tj = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+        if tj.abs() > ra + hb[j] {
+            return false;
+        }
+    }
+    for i in 0..3 {
+        let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+        for j in 0..3 {
+            let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+            let ra = ha[i1] * abs_r[i2][j] + ha[i2] * abs_r[i1][j];
+            let rb = hb[j1] * abs_r[i][j2] + hb[j2] * abs_r[i][j1];
+            if (t[i2] * r[i1][j] - t[i1] * r[i2][j]).abs() > ra + rb {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn ray_sphere(ray: Ray3d, center: Vec3, radius: f32) -> Option<f32> {
+    let offset = ray.origin - center;
+    let b = offset.dot(*ray.direction);
+    let c = offset.length_squared() - radius * radius;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let discriminant = b * b - c;
+    (b <= 0.0 && discriminant >= 0.0).then(|| -b - discriminant.sqrt())
+}
+
+/// Casts a ray against an axis-aligned box centered on the origin, with the slab method.
+fn ray_box(origin: Vec3, direction: Vec3, half_size: Vec3) -> Option<(f32, Vec3)> {
+    if origin.abs().cmple(half_size).all() {
+        return Some((0.0, Vec3::ZERO));
+    }
+    let (mut near, mut far, mut normal) = (0.0_f32, f32::INFINITY, Vec3::ZERO);
+    for axis in 0..3 {
+        let (o, d, h) = (origin[axis], direction[axis], half_size[axis]);
+        if d.abs() < EPSILON {
+            if o.abs() > h {
+                return None;
+            }
+            continue;
+        }
+        let (t1, t2) = ((-h - o) / d, (h - o) / d);
+        let (entry, exit, side) = if t1 < t2 {
+            (t1, t2, -1.0)
+        } else {
+            (t2, t1, 1.0)
+        };
+        if entry > near {
+            near = entry;
+            normal = Vec3::ZERO;
+            normal[axis] = side;
+        }
+        far = far.min(exit);
+        if near > far {
+            return None;
+        }
+    }
+    Some((near, normal))
+}
+
+fn ray_capsule(ray: Ray3d, a: Vec3, b: Vec3, radius: f32) -> Option<f32> {
+    if closest_on_segment(a, b, ray.origin).distance_squared(ray.origin) <= radius * radius {
+        return Some(0.0);
+    }
+    let direction = *ray.direction;
+
+    // The cylinder between the two caps.
+    let (ba, oa) = (b - a, ray.origin - a);
+    let (baba, bard, baoa) = (ba.dot(ba), ba.dot(direction), ba.dot(oa));
+    let k = baba - bard * bard;
+    let mut body = None;
+    if k > EPSILON {
+        let half_b = baba * direction.dot(oa) - baoa * bard;
+        let c = baba * oa.dot(oa) - baoa * baoa - radius * radius * baba;
+        let discriminant = half_b * half_b - k * c;
+        if discriminant >= 0.0 {
+            let t = (-half_b - discriminant.sqrt()) / k;
+            let y = baoa + t * bard;
+            if t >= 0.0 && y > 0.0 && y < baba {
+                body = Some(t);
+            }
+        }
+    }
+
+    [body, ray_sphere(ray, a, radius), ray_sphere(ray, b, radius)]
+        .into_iter()
+        .flatten()
+        .min_by(f32::total_cmp)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_4;
+
+    use bevy_math::{Dir3, Quat, Ray3d, Vec3};
+
+    use super::WorldShape;
+
+    fn unit_box(center: Vec3, rotation: Quat) -> WorldShape {
+        WorldShape::Box {
+            center,
+            rotation,
+            half_size: Vec3::splat(0.5),
+        }
+    }
+
+    #[test]
+    fn box_intersections() {
+        let a = unit_box(Vec3::ZERO, Quat::IDENTITY);
+        assert!(a.intersects(&unit_box(Vec3::new(0.9, 0.0, 0.0), Quat::IDENTITY)));
+        assert!(!a.intersects(&unit_box(Vec3::new(1.1, 0.0, 0.0), Quat::IDENTITY)));
+
+        // Rotated by 45 degrees, the corner of the second box reaches `0.5 * sqrt(2)`.
+        let rotated = Quat::from_rotation_y(FRAC_PI_4);
+        assert!(a.intersects(&unit_box(Vec3::new(1.15, 0.0, 0.0), rotated)));
+        assert!(!a.intersects(&unit_box(Vec3::new(1.25, 0.0, 0.0), rotated)));
+        // Boxes rotated around several axes.
+        let tilted = Quat::from_rotation_x(FRAC_PI_4) * Quat::from_rotation_z(FRAC_PI_4);
+        assert!(
+            !unit_box(Vec3::ZERO, rotated).intersects(&unit_box(Vec3::new(1.3, 1.3, 0.0), tilted))
+        );
+
+        let capsule = WorldShape::Capsule {
+            a: Vec3::new(-2.0, 1.0, 0.0),
+            b: Vec3::new(2.0, 1.0, 0.0),
+            radius: 0.6,
+        };
+        assert!(a.intersects(&capsule));
+        assert!(capsule.intersects(&a));
+        let capsule = WorldShape::Capsule {
+            a: Vec3::new(-2.0, 1.2, 0.0),
+            b: Vec3::new(2.0, 1.2, 0.0),
+            radius: 0.6,
+        };
+        assert!(!a.intersects(&capsule));
+    }
+
+    #[test]
+    fn ray_casts() {
+        let ray = Ray3d::new(Vec3::new(-5.0, 0.0, 0.0), Dir3::X);
+        let sphere = WorldShape::Sphere {
+            center: Vec3::ZERO,
+            radius: 1.0,
+        };
+        assert_eq!(sphere.cast_ray(ray, 10.0), Some((4.0, Dir3::NEG_X)));
+        assert_eq!(sphere.cast_ray(ray, 3.0), None);
+
+        let rotated = unit_box(Vec3::ZERO, Quat::from_rotation_z(FRAC_PI_4));
+        let (distance, normal) = rotated.cast_ray(ray, 10.0).unwrap();
+        assert!((distance - (5.0 - 0.5 * 2.0_f32.sqrt())).abs() < 1e-4);
+        assert!(normal.x < 0.0 && normal.y.abs() > 0.5);
+
+        let capsule = WorldShape::Capsule {
+            a: Vec3::new(0.0, -1.0, 0.0),
+            b: Vec3::new(0.0, 1.0, 0.0),
+            radius: 0.5,
+        };
+        let (distance, normal) = capsule.cast_ray(ray, 10.0).unwrap();
+        assert!((distance - 4.5).abs() < 1e-4);
+        assert!(normal.dot(Vec3::NEG_X) > 0.999);
+        let down = Ray3d::new(Vec3::new(0.0, 5.0, 0.0), Dir3::NEG_Y);
+        assert!((capsule.cast_ray(down, 10.0).unwrap().0 - 3.5).abs() < 1e-4);
+        let inside = Ray3d::new(Vec3::ZERO, Dir3::NEG_Y);
+        assert_eq!(capsule.cast_ray(inside, 10.0), Some((0.0, Dir3::Y)));
+    }
+}
@@ -1,6 +1,8 @@
 //! Module for calculating distance between two colors in the same color space.
 
-use bevy_math::ops;
+use bevy_math::{ops, FloatPow};
+
+use crate::{Laba, Oklaba};
 
 /// Calculate the distance between this and another color as if they were coordinates
 /// in a Euclidean space. Alpha is not considered in the distance calculation.
@@ -13,3 +15,143 @@ pub trait EuclideanDistance: Sized {
     /// Distance squared from `self` to `other`.
     fn distance_squared(&self, other: &Self) -> f32;
 }
+
+/// Calculate the perceptual difference between two colors, also known as "delta E".
+///
+/// A difference below 1.0 is generally not noticeable, and a difference around 2.0 is only
+/// noticeable on close inspection. Unlike [`EuclideanDistance`], this can compare colors from
+/// different color spaces once they are converted to the same type, such as [`Color`](crate::Color).
+/// Alpha is not considered.
+pub trait DeltaE {
+    /// The CIEDE2000 difference from `self` to `other`, computed in [`Laba`] space, on the usual
+    /// scale where the lightness ranges from 0 to 100.
+    fn delta_e(&self, other: &Self) -> f32;
+
+    /// The difference from `self` to `other` in [`Oklaba`] space, scaled to match the
+    /// range of [`DeltaE::delta_e`].
+    ///
+    /// This is much cheaper than [`DeltaE::delta_e`], and nearly as perceptually uniform.
+    fn delta_e_ok(&self, other: &Self) -> f32;
+}
+
+impl<T> DeltaE for T
+where
+    T: Copy + Into<Laba> + Into<Oklaba>,
+{
+    fn delta_e(&self, other: &Self) -> f32 {
+        ciede2000((*self).into(), (*other).into())
+    }
+
+    fn delta_e_ok(&self, other: &Self) -> f32 {
+        let a: Oklaba = (*self).into();
+        let b: Oklaba = (*other).into();
+        100.0 * a.distance(&b)
+    }
+}
+
+/// The CIEDE2000 color difference formula, following
+/// <https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/ciede2000noteCRNA.pdf>.
+fn ciede2000(lab1: Laba, lab2: Laba) -> f32 {
+    const POW_25_7: f32 = 6_103_515_625.0;
+
+    let (l1, a1, b1) = (100.0 * lab1.lightness, 100.0 * lab1.a, 100.0 * lab1.b);
+    let (l2, a2, b2) = (100.0 * lab2.lightness, 100.0 * lab2.a, 100.0 * lab2.b);
+
+    let c_mean = (ops::hypot(a1, b1) + ops::hypot(a2, b2)) / 2.0;
+    let c_mean_7 = ops::powf(c_mean, 7.0);
+    let g = 0.5 * (1.0 - ops::sqrt(c_mean_7 / (c_mean_7 + POW_25_7)));
+    let a1 = (1.0 + g) * a1;
+    let a2 = (1.0 + g) * a2;
+
+    let c1 = ops::hypot(a1, b1);
+    let c2 = ops::hypot(a2, b2);
+    let hue = |a: f32, b: f32| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            ops::rem_euclid(ops::atan2(b, a).to_degrees(), 360.0)
+        }
+    };
+    let h1 = hue(a1, b1);
+    let h2 = hue(a2, b2);
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let hue_difference = h2 - h1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if ops::abs(hue_difference) <= 180.0 {
+        hue_difference
+    } else if hue_difference > 180.0 {
+        hue_difference - 360.0
+    } else {
+        hue_difference + 360.0
+    };
+    let delta_h = 2.0 * ops::sqrt(c1 * c2) * ops::sin((delta_h / 2.0).to_radians());
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if ops::abs(hue_difference) <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let cos_degrees = |degrees: f32| ops::cos(degrees.to_radians());
+    let t = 1.0 - 0.17 * cos_degrees(h_mean - 30.0)
+        + 0.24 * cos_degrees(2.0 * h_mean)
+        + 0.32 * cos_degrees(3.0 * h_mean + 6.0)
+        - 0.20 * cos_degrees(4.0 * h_mean - 63.0);
+    let delta_theta = 30.0 * ops::exp(-((h_mean - 275.0) / 25.0).squared());
+    let c_mean_7 = ops::powf(c_mean, 7.0);
+    let r_c = 2.0 * ops::sqrt(c_mean_7 / (c_mean_7 + POW_25_7));
+    let s_l = 1.0 + 0.015 * (l_mean - 50.0).squared() / ops::sqrt(20.0 + (l_mean - 50.0).squared());
+    let s_c = 1.0 + 0.045 * c_mean;
+    let s_h = 1.0 + 0.015 * c_mean * t;
+    let r_t = -ops::sin((2.0 * delta_theta).to_radians()) * r_c;
+
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_h / s_h;
+    ops::sqrt(l.squared() + c.squared() + h.squared() + r_t * c * h)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DeltaE;
+    use crate::{testing::assert_approx_eq, Color, Laba};
+
+    #[test]
+    fn ciede2000_reference_pairs() {
+        // Pairs from the test data of Sharma, Wu and Dalal.
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let lab1 = Laba::lab(l1 / 100.0, a1 / 100.0, b1 / 100.0);
+            let lab2 = Laba::lab(l2 / 100.0, a2 / 100.0, b2 / 100.0);
+            assert_approx_eq!(lab1.delta_e(&lab2), expected, 1e-3);
+            assert_approx_eq!(lab2.delta_e(&lab1), expected, 1e-3);
+        }
+    }
+
+    #[test]
+    fn delta_e_across_color_spaces() {
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        assert_approx_eq!(red.delta_e(&Color::from(Laba::from(red))), 0.0, 1e-2);
+        assert_approx_eq!(
+            red.delta_e_ok(&Color::oklch(0.627955, 0.257683, 29.2339)),
+            0.0,
+            1e-2
+        );
+        assert!(red.delta_e(&Color::srgb(0.0, 0.0, 1.0)) > 50.0);
+    }
+}
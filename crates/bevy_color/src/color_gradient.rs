@@ -1,9 +1,14 @@
-use crate::Mix;
+use crate::{
+    color_ops::lerp_hue_long, Color, Hsla, Hsva, Hue, Laba, Lcha, LinearRgba, Mix, Oklaba, Oklcha,
+    Srgba,
+};
 use alloc::vec::Vec;
 use bevy_math::curve::{
     cores::{EvenCore, EvenCoreError},
     Curve, Interval,
 };
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
 
 /// A curve whose samples are defined by a collection of colors.
 #[derive(Clone, Debug)]
@@ -72,10 +77,217 @@ where
     }
 }
 
+/// The color space a [`ColorGradient`] is interpolated in.
+///
+/// The same stops can give very different gradients depending on the color space: [`Srgba`]
+/// interpolation tends to go through muddy, dark colors, while [`Oklaba`] interpolation keeps a
+/// perceptually even lightness, and the hue-based spaces go around the color wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Hash, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GradientColorSpace {
+    /// Interpolates in [`Oklaba`] space.
+    #[default]
+    Oklaba,
+    /// Interpolates in [`Oklcha`] space, taking the shortest hue path.
+    Oklcha,
+    /// Interpolates in [`Oklcha`] space, taking the longest hue path.
+    OklchaLong,
+    /// Interpolates in [`Srgba`] space.
+    Srgba,
+    /// Interpolates in [`LinearRgba`] space.
+    LinearRgba,
+    /// Interpolates in [`Laba`] space.
+    Laba,
+    /// Interpolates in [`Lcha`] space, taking the shortest hue path.
+    Lcha,
+    /// Interpolates in [`Hsla`] space, taking the shortest hue path.
+    Hsla,
+    /// Interpolates in [`Hsla`] space, taking the longest hue path.
+    HslaLong,
+    /// Interpolates in [`Hsva`] space, taking the shortest hue path.
+    Hsva,
+    /// Interpolates in [`Hsva`] space, taking the longest hue path.
+    HsvaLong,
+}
+
+impl GradientColorSpace {
+    /// Mixes `a` and `b` in this color space, returning a [`Color`] of this color space.
+    pub fn mix(self, a: Color, b: Color, factor: f32) -> Color {
+        fn mix_in<T: Mix + From<Color> + Into<Color>>(a: Color, b: Color, factor: f32) -> Color {
+            T::from(a).mix(&T::from(b), factor).into()
+        }
+
+        fn mix_long<T: Mix + Hue + From<Color> + Into<Color>>(
+            a: Color,
+            b: Color,
+            factor: f32,
+        ) -> Color {
+            let (a, b) = (T::from(a), T::from(b));
+            a.mix(&b, factor)
+                .with_hue(lerp_hue_long(a.hue(), b.hue(), factor))
+                .into()
+        }
+
+        match self {
+            Self::Oklaba => mix_in::<Oklaba>(a, b, factor),
+            Self::Oklcha => mix_in::<Oklcha>(a, b, factor),
+            Self::OklchaLong => mix_long::<Oklcha>(a, b, factor),
+            Self::Srgba => mix_in::<Srgba>(a, b, factor),
+            Self::LinearRgba => mix_in::<LinearRgba>(a, b, factor),
+            Self::Laba => mix_in::<Laba>(a, b, factor),
+            Self::Lcha => mix_in::<Lcha>(a, b, factor),
+            Self::Hsla => mix_in::<Hsla>(a, b, factor),
+            Self::HslaLong => mix_long::<Hsla>(a, b, factor),
+            Self::Hsva => mix_in::<Hsva>(a, b, factor),
+            Self::HsvaLong => mix_long::<Hsva>(a, b, factor),
+        }
+    }
+}
+
+/// A color at a position of a [`ColorGradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Clone, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorStop {
+    /// The color at this stop.
+    pub color: Color,
+    /// The position of this stop, usually between 0.0 and 1.0.
+    pub position: f32,
+}
+
+impl ColorStop {
+    /// Creates a stop of `color` at `position`.
+    pub fn new(color: impl Into<Color>, position: f32) -> Self {
+        Self {
+            color: color.into(),
+            position,
+        }
+    }
+}
+
+/// A gradient through any number of [`ColorStop`]s, interpolated in a [`GradientColorSpace`].
+///
+/// Unlike [`ColorCurve`], the stops can be unevenly spaced, and colors of different color spaces
+/// can be mixed in the color space of choice. This can be serialized, for data-driven gradients
+/// in scenes and assets.
+///
+/// ```
+/// # use bevy_color::{palettes::basic::*, Color, ColorGradient, ColorStop, GradientColorSpace};
+/// let gradient = ColorGradient::new([
+///     ColorStop::new(RED, 0.0),
+///     ColorStop::new(YELLOW, 0.2),
+///     ColorStop::new(BLUE, 1.0),
+/// ])
+/// .with_color_space(GradientColorSpace::Oklcha);
+/// assert_eq!(gradient.sample(0.0), Color::from(RED));
+/// // Positions past the last stop take its color.
+/// assert_eq!(gradient.sample(2.0), gradient.sample(1.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorGradient {
+    /// The stops of the gradient, sorted by position.
+    pub stops: Vec<ColorStop>,
+    /// The color space the stops are interpolated in.
+    pub color_space: GradientColorSpace,
+}
+
+impl ColorGradient {
+    /// Creates a gradient through the given stops, interpolated in [`Oklaba`] space.
+    ///
+    /// The stops are sorted by position.
+    pub fn new(stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        let mut stops = stops.into_iter().collect::<Vec<_>>();
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        Self {
+            stops,
+            color_space: GradientColorSpace::default(),
+        }
+    }
+
+    /// Creates a gradient through the given colors, evenly spaced between 0.0 and 1.0.
+    pub fn evenly_spaced<C: Into<Color>>(colors: impl IntoIterator<Item = C>) -> Self {
+        let colors = colors.into_iter().map(Into::into).collect::<Vec<Color>>();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| ColorStop::new(color, i as f32 / last)),
+        )
+    }
+
+    /// Returns this gradient interpolated in `color_space`.
+    pub fn with_color_space(mut self, color_space: GradientColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Samples the color of the gradient at `position`.
+    ///
+    /// Positions before the first stop and after the last one take the color of that stop, and an
+    /// empty gradient is [`Color::NONE`]. The result is in the [`GradientColorSpace`] of the
+    /// gradient, unless `position` is exactly on a stop.
+    pub fn sample(&self, position: f32) -> Color {
+        let next = self.stops.partition_point(|stop| stop.position <= position);
+        match (
+            next.checked_sub(1).map(|i| &self.stops[i]),
+            self.stops.get(next),
+        ) {
+            (None, None) => Color::NONE,
+            (Some(stop), None) | (None, Some(stop)) => stop.color,
+            (Some(start), Some(_)) if start.position == position => start.color,
+            (Some(start), Some(end)) => {
+                let factor = (position - start.position) / (end.position - start.position);
+                self.color_space.mix(start.color, end.color, factor)
+            }
+        }
+    }
+}
+
+impl Curve<Color> for ColorGradient {
+    #[inline]
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    #[inline]
+    fn sample_clamped(&self, t: f32) -> Color {
+        self.sample(t.clamp(0.0, 1.0))
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> Color {
+        self.sample(t)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{palettes::basic, Srgba};
+    use crate::{palettes::basic, Color, Srgba};
     use bevy_math::curve::{Curve, CurveExt};
 
     #[test]
@@ -108,4 +320,44 @@ mod tests {
             assert_eq!(brighter_curve.sample(t), maybe_color);
         });
     }
+
+    #[test]
+    fn test_color_gradient() {
+        let gradient = ColorGradient::new([
+            ColorStop::new(basic::BLUE, 1.0),
+            ColorStop::new(basic::RED, 0.0),
+            ColorStop::new(basic::WHITE, 0.25),
+        ])
+        .with_color_space(GradientColorSpace::Srgba);
+
+        assert_eq!(gradient.stops[1].color, basic::WHITE.into());
+        assert_eq!(gradient.sample(-1.0), basic::RED.into());
+        assert_eq!(gradient.sample(0.25), basic::WHITE.into());
+        assert_eq!(
+            gradient.sample(0.125),
+            Srgba::new(1.0, 0.5, 0.5, 1.0).into()
+        );
+        assert_eq!(
+            gradient.sample(0.625),
+            Srgba::new(0.5, 0.5, 1.0, 1.0).into()
+        );
+        assert_eq!(gradient.sample(1.5), basic::BLUE.into());
+        assert_eq!(ColorGradient::default().sample(0.5), Color::NONE);
+    }
+
+    #[test]
+    fn test_long_hue_path() {
+        let red = Color::hsl(0.0, 1.0, 0.5);
+        let green = Color::hsl(120.0, 1.0, 0.5);
+        let mid = |color_space: GradientColorSpace| {
+            ColorGradient::evenly_spaced([red, green])
+                .with_color_space(color_space)
+                .sample(0.5)
+        };
+        assert_eq!(mid(GradientColorSpace::Hsla), Color::hsl(60.0, 1.0, 0.5));
+        assert_eq!(
+            mid(GradientColorSpace::HslaLong),
+            Color::hsl(240.0, 1.0, 0.5)
+        );
+    }
 }
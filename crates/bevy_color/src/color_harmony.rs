@@ -0,0 +1,109 @@
+use crate::Hue;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+/// A color scheme generated by rotating the hue of a base color, such as complementary or
+/// triadic colors.
+///
+/// The hues are rotated in the color space of the base color, so harmonies of an [`Oklcha`] color
+/// keep the same perceived lightness and chroma, while harmonies of a [`Color`] are rotated in
+/// [`Oklcha`] space.
+///
+/// ```
+/// # use bevy_color::{ColorHarmony, Hue, Oklcha};
+/// let base = Oklcha::lch(0.7, 0.15, 30.0);
+/// let [first, second, third] = ColorHarmony::Triadic.colors(base)[..] else {
+///     unreachable!();
+/// };
+/// assert_eq!(first, base);
+/// assert_eq!(second.hue(), 150.0);
+/// assert_eq!(third.hue(), 270.0);
+/// ```
+///
+/// [`Oklcha`]: crate::Oklcha
+/// [`Color`]: crate::Color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Hash, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ColorHarmony {
+    /// The base color and the opposite hue.
+    #[default]
+    Complementary,
+    /// The base color and the two hues next to its complement, 150° away.
+    SplitComplementary,
+    /// The base color and its two neighbors, 30° away.
+    Analogous,
+    /// Three hues evenly spaced around the color wheel.
+    Triadic,
+    /// Two pairs of complementary hues, 60° apart.
+    Tetradic,
+    /// Four hues evenly spaced around the color wheel.
+    Square,
+}
+
+impl ColorHarmony {
+    /// The rotations of the hue of the base color making up this harmony, in degrees, starting
+    /// with 0 for the base color itself.
+    pub const fn hue_offsets(self) -> &'static [f32] {
+        match self {
+            Self::Complementary => &[0.0, 180.0],
+            Self::SplitComplementary => &[0.0, 150.0, 210.0],
+            Self::Analogous => &[0.0, 330.0, 30.0],
+            Self::Triadic => &[0.0, 120.0, 240.0],
+            Self::Tetradic => &[0.0, 60.0, 180.0, 240.0],
+            Self::Square => &[0.0, 90.0, 180.0, 270.0],
+        }
+    }
+
+    /// Iterates over the colors of this harmony, starting with `base`.
+    pub fn iter<T: Hue + Copy>(self, base: T) -> impl Iterator<Item = T> {
+        self.hue_offsets().iter().map(move |&offset| {
+            if offset == 0.0 {
+                base
+            } else {
+                base.rotate_hue(offset)
+            }
+        })
+    }
+
+    /// Returns the colors of this harmony, starting with `base`.
+    #[cfg(feature = "alloc")]
+    pub fn colors<T: Hue + Copy>(self, base: T) -> alloc::vec::Vec<T> {
+        self.iter(base).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::ops;
+
+    use super::ColorHarmony;
+    use crate::{testing::assert_approx_eq, Color, Hsla, Hue};
+
+    #[test]
+    fn harmonies_rotate_the_hue() {
+        let base = Hsla::hsl(300.0, 0.5, 0.5);
+        let hues: [f32; 4] =
+            core::array::from_fn(|i| ColorHarmony::Square.iter(base).nth(i).unwrap().hue());
+        assert_eq!(hues, [300.0, 30.0, 120.0, 210.0]);
+        assert!(ColorHarmony::Square
+            .iter(base)
+            .all(|color| color.saturation == 0.5 && color.lightness == 0.5));
+
+        let color = Color::srgb(0.8, 0.3, 0.1);
+        let complement = ColorHarmony::Complementary.iter(color).last().unwrap();
+        assert_approx_eq!(
+            ops::rem_euclid(complement.hue() - color.hue(), 360.0),
+            180.0,
+            1e-2
+        );
+    }
+}
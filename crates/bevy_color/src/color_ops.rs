@@ -164,6 +164,20 @@ pub(crate) fn lerp_hue(a: f32, b: f32, t: f32) -> f32 {
     ops::rem_euclid(a + diff * t, 360.)
 }
 
+/// Like [`lerp_hue`], but takes the longest path around the color wheel.
+pub(crate) fn lerp_hue_long(a: f32, b: f32, t: f32) -> f32 {
+    let diff = ops::rem_euclid(b - a + 180.0, 360.) - 180.;
+    let diff = if diff > 0.0 {
+        diff - 360.
+    } else if diff < 0.0 {
+        diff + 360.
+    } else {
+        // The hues are the same, so there is no longer path.
+        0.
+    };
+    ops::rem_euclid(a + diff * t, 360.)
+}
+
 #[cfg(test)]
 mod tests {
     use core::fmt::Debug;
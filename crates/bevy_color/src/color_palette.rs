@@ -0,0 +1,128 @@
+use crate::{color_difference::DeltaE, Color, ColorGradient};
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::prelude::*;
+
+/// An ordered collection of named colors, such as the colors of a game's art direction.
+///
+/// Unlike the const colors of the [`palettes`](crate::palettes) module, this can be built at
+/// runtime and serialized, to load palettes from data files or scenes.
+///
+/// ```
+/// # use bevy_color::{palettes::css, Color, ColorPalette};
+/// let palette = ColorPalette::default()
+///     .with("sky", css::SKY_BLUE)
+///     .with("grass", css::FOREST_GREEN)
+///     .with("sand", css::TAN);
+/// assert_eq!(palette.get("grass"), Some(css::FOREST_GREEN.into()));
+/// // Snap an arbitrary color to the closest color of the palette.
+/// assert_eq!(palette.nearest(Color::srgb(0.1, 0.5, 0.2)).unwrap().name, "grass");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ColorPalette {
+    /// The colors of the palette, in order.
+    pub colors: Vec<PaletteColor>,
+}
+
+/// A named color of a [`ColorPalette`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Clone, PartialEq))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct PaletteColor {
+    /// The name of the color.
+    pub name: String,
+    /// The color.
+    pub color: Color,
+}
+
+impl ColorPalette {
+    /// Returns this palette with `color` added under `name`, replacing the color that had this
+    /// name if any.
+    pub fn with(mut self, name: impl Into<String>, color: impl Into<Color>) -> Self {
+        self.insert(name, color);
+        self
+    }
+
+    /// Adds `color` under `name`, replacing the color that had this name if any.
+    pub fn insert(&mut self, name: impl Into<String>, color: impl Into<Color>) {
+        let name = name.into();
+        let color = color.into();
+        match self.colors.iter_mut().find(|entry| entry.name == name) {
+            Some(entry) => entry.color = color,
+            None => self.colors.push(PaletteColor { name, color }),
+        }
+    }
+
+    /// Returns the color named `name`.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.colors
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(|entry| entry.color)
+    }
+
+    /// Returns the color of the palette that is perceptually closest to `color`, according to
+    /// [`DeltaE::delta_e_ok`].
+    pub fn nearest(&self, color: impl Into<Color>) -> Option<&PaletteColor> {
+        let color = color.into();
+        self.colors.iter().min_by(|a, b| {
+            a.color
+                .delta_e_ok(&color)
+                .total_cmp(&b.color.delta_e_ok(&color))
+        })
+    }
+
+    /// Iterates over the named colors of the palette, in order.
+    pub fn iter(&self) -> impl Iterator<Item = &PaletteColor> {
+        self.colors.iter()
+    }
+
+    /// Returns the number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns true if the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns a gradient through the colors of the palette, in order and evenly spaced.
+    pub fn gradient(&self) -> ColorGradient {
+        ColorGradient::evenly_spaced(self.colors.iter().map(|entry| entry.color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColorPalette;
+    use crate::palettes::basic;
+
+    #[test]
+    fn palette_names_are_unique() {
+        let mut palette = ColorPalette::default()
+            .with("primary", basic::RED)
+            .with("secondary", basic::BLUE);
+        palette.insert("primary", basic::LIME);
+
+        assert_eq!(palette.len(), 2);
+        assert_eq!(palette.get("primary"), Some(basic::LIME.into()));
+        assert_eq!(palette.colors[0].name, "primary");
+        assert_eq!(palette.get("tertiary"), None);
+        assert_eq!(palette.nearest(basic::NAVY).unwrap().name, "secondary");
+    }
+}
@@ -100,7 +100,10 @@ mod color;
 pub mod color_difference;
 #[cfg(feature = "alloc")]
 mod color_gradient;
+mod color_harmony;
 mod color_ops;
+#[cfg(feature = "alloc")]
+mod color_palette;
 mod color_range;
 mod hsla;
 mod hsva;
@@ -135,7 +138,10 @@ pub mod prelude {
 pub use color::*;
 #[cfg(feature = "alloc")]
 pub use color_gradient::*;
+pub use color_harmony::*;
 pub use color_ops::*;
+#[cfg(feature = "alloc")]
+pub use color_palette::*;
 pub use color_range::*;
 pub use hsla::*;
 pub use hsva::*;
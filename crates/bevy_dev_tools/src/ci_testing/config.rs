@@ -1,13 +1,14 @@
 use bevy_ecs::prelude::*;
-use bevy_math::{Quat, Vec3};
-use serde::Deserialize;
+use bevy_input::{keyboard::KeyCode, mouse::MouseButton};
+use bevy_math::{Quat, Vec2, Vec3};
+use serde::{Deserialize, Serialize};
 
 /// A configuration struct for automated CI testing.
 ///
 /// It gets used when the `bevy_ci_testing` feature is enabled to automatically
 /// exit a Bevy app when run through the CI. This is needed because otherwise
 /// Bevy apps would be stuck in the game loop and wouldn't allow the CI to progress.
-#[derive(Deserialize, Resource, PartialEq, Debug, Default, Clone)]
+#[derive(Serialize, Deserialize, Resource, PartialEq, Debug, Default, Clone)]
 pub struct CiTestingConfig {
     /// The setup for this test.
     #[serde(default)]
@@ -18,7 +19,7 @@ pub struct CiTestingConfig {
 }
 
 /// Setup for a test.
-#[derive(Deserialize, Default, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone)]
 pub struct CiTestingSetup {
     /// The amount of time in seconds between frame updates.
     ///
@@ -29,11 +30,11 @@ pub struct CiTestingSetup {
 }
 
 /// An event to send at a given frame, used for CI testing.
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct CiTestingEventOnFrame(pub u32, pub CiTestingEvent);
 
 /// An event to send, used for CI testing.
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum CiTestingEvent {
     /// Takes a screenshot of the entire screen, and saves the results to
     /// `screenshot-{current_frame}.png`.
@@ -61,6 +62,45 @@ pub enum CiTestingEvent {
     },
     /// Sends a [`CiTestingCustomEvent`] using the given [`String`].
     Custom(String),
+    /// Presses the key on the primary window, until a matching [`CiTestingEvent::KeyRelease`].
+    ///
+    /// Like the other synthetic inputs, the app sees it from the next frame.
+    KeyPress(KeyCode),
+    /// Releases a key pressed with [`CiTestingEvent::KeyPress`].
+    KeyRelease(KeyCode),
+    /// Presses the mouse button on the primary window, until a matching
+    /// [`CiTestingEvent::MouseButtonRelease`].
+    MouseButtonPress(MouseButton),
+    /// Releases a mouse button pressed with [`CiTestingEvent::MouseButtonPress`].
+    MouseButtonRelease(MouseButton),
+    /// Moves the cursor to the given logical position in the primary window, from its top left
+    /// corner.
+    MoveCursor(Vec2),
+    /// Despawns the scene spawned by the previous `SwitchScene`, if any, and spawns the scene at
+    /// the given asset path, either a serialized world (`.scn` or `.scn.ron`) or a labeled world
+    /// asset, like `models/FlightHelmet/FlightHelmet.gltf#Scene0`.
+    SwitchScene(String),
+    /// Checks that the smoothed value of the diagnostic at `path` is within the bounds, and exits
+    /// with [`AppExit::Error`] otherwise, or if the diagnostic has no value yet.
+    ///
+    /// For example, `AssertDiagnostic(path: "fps", min: Some(30.0))` checks that the app runs at
+    /// more than 30 frames per second, provided it has the `FrameTimeDiagnosticsPlugin`.
+    ///
+    /// [`AppExit::Error`]: bevy_app::AppExit::Error
+    AssertDiagnostic {
+        /// The path of the diagnostic.
+        path: String,
+        /// The minimum value, if any.
+        #[serde(default)]
+        min: Option<f64>,
+        /// The maximum value, if any.
+        #[serde(default)]
+        max: Option<f64>,
+    },
+    /// Stops the program with the given exit code, [`AppExit::Success`] if it is zero.
+    ///
+    /// [`AppExit::Success`]: bevy_app::AppExit::Success
+    AppExitWithCode(u8),
 }
 
 /// A custom event that can be configured from a configuration file for CI testing.
@@ -100,4 +140,67 @@ mod tests {
 
         assert_eq!(config, expected);
     }
+
+    #[test]
+    fn deserialize_scenario() {
+        const INPUT: &str = r#"
+(
+    events: [
+        (10, KeyPress(Space)),
+        (11, KeyRelease(Space)),
+        (20, MoveCursor((200.0, 100.0))),
+        (21, MouseButtonPress(Left)),
+        (30, SwitchScene("serialized_worlds/load_scene_example.scn.ron")),
+        (100, NamedScreenshot("loaded")),
+        (110, AssertDiagnostic(path: "fps", min: Some(30.0))),
+        (120, AppExitWithCode(2)),
+    ],
+)"#;
+
+        let config: CiTestingConfig = ron::from_str(INPUT).unwrap();
+
+        assert_eq!(
+            config.events,
+            vec![
+                CiTestingEventOnFrame(10, CiTestingEvent::KeyPress(KeyCode::Space)),
+                CiTestingEventOnFrame(11, CiTestingEvent::KeyRelease(KeyCode::Space)),
+                CiTestingEventOnFrame(20, CiTestingEvent::MoveCursor(Vec2::new(200.0, 100.0))),
+                CiTestingEventOnFrame(21, CiTestingEvent::MouseButtonPress(MouseButton::Left)),
+                CiTestingEventOnFrame(
+                    30,
+                    CiTestingEvent::SwitchScene(
+                        "serialized_worlds/load_scene_example.scn.ron".into()
+                    )
+                ),
+                CiTestingEventOnFrame(100, CiTestingEvent::NamedScreenshot("loaded".into())),
+                CiTestingEventOnFrame(
+                    110,
+                    CiTestingEvent::AssertDiagnostic {
+                        path: "fps".into(),
+                        min: Some(30.0),
+                        max: None,
+                    }
+                ),
+                CiTestingEventOnFrame(120, CiTestingEvent::AppExitWithCode(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn serialize_round_trip() {
+        let config = CiTestingConfig {
+            setup: CiTestingSetup {
+                fixed_frame_time: Some(0.016),
+            },
+            events: vec![
+                CiTestingEventOnFrame(10, CiTestingEvent::Screenshot),
+                CiTestingEventOnFrame(20, CiTestingEvent::ScreenshotAndExit),
+            ],
+        };
+
+        let serialized = ron::to_string(&config).unwrap();
+        let deserialized: CiTestingConfig = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, config);
+    }
 }
@@ -2,10 +2,24 @@ use crate::CameraMovement;
 
 use super::config::*;
 use bevy_app::AppExit;
+use bevy_asset::AssetServer;
 use bevy_camera::Camera;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, DiagnosticsStore};
 use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey},
+    mouse::{MouseButton, MouseButtonInput},
+    ButtonState,
+};
+use bevy_math::Vec2;
 use bevy_render::view::screenshot::{save_to_disk, Screenshot};
-use tracing::{debug, info};
+use bevy_window::{CursorMoved, PrimaryWindow, Window};
+use bevy_world_serialization::{DynamicWorldRoot, WorldAssetRoot};
+use tracing::{debug, error, info};
+
+/// Marks the root of the scene spawned by [`CiTestingEvent::SwitchScene`].
+#[derive(Component)]
+pub(crate) struct CiTestingScene;
 
 pub(crate) fn send_events(world: &mut World, mut current_frame: Local<u32>) {
     let mut config = world.resource_mut::<CiTestingConfig>();
@@ -80,8 +94,146 @@ pub(crate) fn send_events(world: &mut World, mut current_frame: Local<u32>) {
             CiTestingEvent::Custom(event_string) => {
                 world.write_message(CiTestingCustomEvent(event_string));
             }
+            CiTestingEvent::KeyPress(key_code) => {
+                send_key(world, key_code, ButtonState::Pressed);
+            }
+            CiTestingEvent::KeyRelease(key_code) => {
+                send_key(world, key_code, ButtonState::Released);
+            }
+            CiTestingEvent::MouseButtonPress(button) => {
+                send_mouse_button(world, button, ButtonState::Pressed);
+            }
+            CiTestingEvent::MouseButtonRelease(button) => {
+                send_mouse_button(world, button, ButtonState::Released);
+            }
+            CiTestingEvent::MoveCursor(position) => {
+                move_cursor(world, position);
+            }
+            CiTestingEvent::SwitchScene(path) => {
+                info!("Switched to scene {} at frame {}.", path, *current_frame);
+                switch_scene(world, path);
+            }
+            CiTestingEvent::AssertDiagnostic { path, min, max } => {
+                let value = world.get_resource::<DiagnosticsStore>().and_then(|store| {
+                    store
+                        .get(&DiagnosticPath::new(path.clone()))
+                        .and_then(Diagnostic::smoothed)
+                });
+                match value {
+                    Some(value)
+                        if min.is_none_or(|min| value >= min)
+                            && max.is_none_or(|max| value <= max) =>
+                    {
+                        info!(
+                            "Diagnostic {} is {} at frame {}, within the bounds.",
+                            path, value, *current_frame
+                        );
+                    }
+                    _ => {
+                        error!(
+                            "Diagnostic {} is {:?} at frame {}, expected between {:?} and {:?}. Test failed!",
+                            path, value, *current_frame, min, max
+                        );
+                        world.write_message(AppExit::error());
+                    }
+                }
+            }
+            CiTestingEvent::AppExitWithCode(code) => {
+                world.write_message(AppExit::from_code(code));
+                info!(
+                    "Exiting after {} frames with code {}.",
+                    *current_frame, code
+                );
+            }
         }
     }
 
     *current_frame += 1;
 }
+
+fn primary_window(world: &mut World) -> Entity {
+    world
+        .query_filtered::<Entity, With<PrimaryWindow>>()
+        .iter(world)
+        .next()
+        .unwrap_or(Entity::PLACEHOLDER)
+}
+
+fn send_key(world: &mut World, key_code: KeyCode, state: ButtonState) {
+    let window = primary_window(world);
+    world.write_message(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        text: None,
+        repeat: false,
+        window,
+    });
+}
+
+fn send_mouse_button(world: &mut World, button: MouseButton, state: ButtonState) {
+    let window = primary_window(world);
+    world.write_message(MouseButtonInput {
+        button,
+        state,
+        window,
+    });
+}
+
+fn move_cursor(world: &mut World, position: Vec2) {
+    let window = primary_window(world);
+    if let Some(mut primary_window) = world.get_mut::<Window>(window) {
+        primary_window.set_cursor_position(Some(position));
+    }
+    world.write_message(CursorMoved {
+        window,
+        position,
+        delta: None,
+    });
+}
+
+fn switch_scene(world: &mut World, path: String) {
+    let previous: Vec<Entity> = world
+        .query_filtered::<Entity, With<CiTestingScene>>()
+        .iter(world)
+        .collect();
+    for entity in previous {
+        world.despawn(entity);
+    }
+
+    let asset_server = world.resource::<AssetServer>().clone();
+    let file = path.split('#').next().unwrap_or(&path);
+    if file.ends_with(".scn") || file.ends_with(".scn.ron") {
+        world.spawn((DynamicWorldRoot(asset_server.load(path)), CiTestingScene));
+    } else {
+        world.spawn((WorldAssetRoot(asset_server.load(path)), CiTestingScene));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{message::Messages, system::RunSystemOnce};
+
+    #[test]
+    fn failed_assertion_exits_with_error() {
+        let mut world = World::new();
+        world.init_resource::<Messages<AppExit>>();
+        world.insert_resource(CiTestingConfig {
+            events: vec![CiTestingEventOnFrame(
+                0,
+                CiTestingEvent::AssertDiagnostic {
+                    path: "fps".into(),
+                    min: Some(30.0),
+                    max: None,
+                },
+            )],
+            ..Default::default()
+        });
+
+        world.run_system_once(send_events).unwrap();
+
+        let exits: Vec<_> = world.resource_mut::<Messages<AppExit>>().drain().collect();
+        assert_eq!(exits, vec![AppExit::error()]);
+    }
+}
@@ -27,6 +27,8 @@ pub mod schedule_data;
 
 pub mod states;
 
+pub mod stepping;
+
 pub use easy_screenshot::*;
 
 pub mod render_debug;
@@ -2,4 +2,5 @@
 //! visualization tools (for example).
 
 pub mod plugin;
+pub mod render_graph;
 pub mod serde;
@@ -0,0 +1,454 @@
+//! Tools for dumping the render graph of an app, along with the timings of its passes.
+//!
+//! The render graph is made of the schedules run every frame by the renderer: the root
+//! [`RenderGraph`] schedule and the schedules of the cameras (such as `Core2d` and `Core3d`). Each
+//! system of these schedules is a node of the graph, the ordering constraints between them are its
+//! edges, and the resources and components they access are the data flowing between the nodes.
+use core::fmt::Write as _;
+use std::{fs::File, io::Write, path::PathBuf};
+
+use bevy_app::{App, Plugin, Update};
+use bevy_core_pipeline::schedule::RootNonCameraView;
+use bevy_diagnostic::DiagnosticsStore;
+use bevy_ecs::{
+    error::{BevyError, ResultSeverityExt, Severity},
+    message::{Message, MessageReader, MessageWriter},
+    resource::Resource,
+    schedule::{InternedScheduleLabel, IntoScheduleConfigs, ScheduleLabel, Schedules},
+    system::{Commands, Res, ResMut},
+    world::World,
+};
+use bevy_input::{common_conditions::input_just_pressed, keyboard::KeyCode};
+use bevy_platform::collections::HashMap;
+use bevy_render::{
+    camera::ExtractedCamera, renderer::RenderGraph, ExtractSchedule, MainWorld, Render, RenderApp,
+    RenderSystems,
+};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::schedule_data::serde::{ExtractAppDataError, ScheduleData, ScheduleIndex};
+
+/// A plugin to dump the render graph to a file on demand, by writing a [`DumpRenderGraph`]
+/// message or by pressing [`RenderGraphDumpPlugin::trigger`].
+///
+/// The graph is written to [`RenderGraphDumpSettings::path`], as [RON](ron) to be loaded by other
+/// tools, or as a [Graphviz](https://graphviz.org) graph to be visualized directly. The timings of
+/// the passes are only available when the
+/// [`RenderDiagnosticsPlugin`](bevy_render::diagnostic::RenderDiagnosticsPlugin) is added.
+pub struct RenderGraphDumpPlugin {
+    /// The key dumping the render graph when pressed, if any.
+    pub trigger: Option<KeyCode>,
+}
+
+impl Default for RenderGraphDumpPlugin {
+    fn default() -> Self {
+        Self {
+            trigger: Some(KeyCode::F9),
+        }
+    }
+}
+
+impl Plugin for RenderGraphDumpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<DumpRenderGraph>()
+            .init_resource::<RenderGraphDumpSettings>()
+            .add_systems(Update, request_render_graph_dump);
+
+        if let Some(trigger) = self.trigger {
+            app.add_systems(
+                Update,
+                (|mut messages: MessageWriter<DumpRenderGraph>| {
+                    messages.write(DumpRenderGraph);
+                })
+                .run_if(input_just_pressed(trigger))
+                .before(request_render_graph_dump),
+            );
+        }
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .add_systems(ExtractSchedule, extract_render_graph_dump_request)
+            .add_systems(Render, dump_render_graph.in_set(RenderSystems::Cleanup));
+    }
+}
+
+/// Dumps the render graph at the end of the next frame.
+#[derive(Message, Clone, Copy, Debug, Default)]
+pub struct DumpRenderGraph;
+
+/// The format in which the render graph is dumped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RenderGraphDumpFormat {
+    /// A [`RenderGraphData`] serialized as [RON](ron).
+    #[default]
+    Ron,
+    /// A [Graphviz](https://graphviz.org) graph, see [`RenderGraphData::to_dot`].
+    Dot,
+}
+
+/// Where and how [`RenderGraphDumpPlugin`] dumps the render graph.
+#[derive(Resource, Clone, Debug)]
+pub struct RenderGraphDumpSettings {
+    /// The file the render graph is written to.
+    pub path: PathBuf,
+    /// The format of the file.
+    pub format: RenderGraphDumpFormat,
+}
+
+impl Default for RenderGraphDumpSettings {
+    fn default() -> Self {
+        Self {
+            path: "render_graph.ron".into(),
+            format: RenderGraphDumpFormat::Ron,
+        }
+    }
+}
+
+/// The data of the render graph of an app.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RenderGraphData {
+    /// The schedules of the render graph, starting with the root [`RenderGraph`] schedule.
+    pub schedules: Vec<ScheduleData>,
+    /// The timings of the passes, as recorded by the render diagnostics.
+    pub timings: Vec<PassTimingData>,
+}
+
+/// The timings of a pass or of a group of passes, smoothed over the last frames.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct PassTimingData {
+    /// The path of the span, such as `main_opaque_pass_3d`.
+    ///
+    /// Nested spans are separated by a `/`.
+    pub path: String,
+    /// The time it took the CPU to record the pass, in milliseconds.
+    pub elapsed_cpu_ms: Option<f64>,
+    /// The time it took the GPU to execute the pass, in milliseconds.
+    pub elapsed_gpu_ms: Option<f64>,
+}
+
+impl PassTimingData {
+    /// Collects the timings of the passes from the render diagnostics in `store`.
+    pub fn from_diagnostics(store: &DiagnosticsStore) -> Vec<Self> {
+        let mut timings = Vec::<Self>::new();
+        let mut path_to_index = HashMap::new();
+
+        for diagnostic in store.iter() {
+            let components = diagnostic.path().components().collect::<Vec<_>>();
+            let ["render", span @ .., field @ ("elapsed_cpu" | "elapsed_gpu")] =
+                components.as_slice()
+            else {
+                continue;
+            };
+            if span.is_empty() {
+                continue;
+            }
+
+            let path = span.join("/");
+            let index = *path_to_index.entry(path.clone()).or_insert_with(|| {
+                timings.push(Self {
+                    path,
+                    elapsed_cpu_ms: None,
+                    elapsed_gpu_ms: None,
+                });
+                timings.len() - 1
+            });
+
+            let value = diagnostic.smoothed();
+            if *field == "elapsed_cpu" {
+                timings[index].elapsed_cpu_ms = value;
+            } else {
+                timings[index].elapsed_gpu_ms = value;
+            }
+        }
+
+        timings.sort_by(|a, b| a.path.cmp(&b.path));
+        timings
+    }
+}
+
+impl RenderGraphData {
+    /// Creates the data from the schedules of the render `world` with the given `labels`.
+    ///
+    /// Labels of schedules missing from the world are skipped.
+    ///
+    /// Note: we assume all the schedules have been initialized through
+    /// [`Schedule::initialize`](bevy_ecs::schedule::Schedule::initialize), which is the case once
+    /// they have run.
+    pub fn from_render_world(
+        world: &World,
+        labels: &[InternedScheduleLabel],
+        timings: Vec<PassTimingData>,
+    ) -> Result<Self, ExtractAppDataError> {
+        let schedules = world.resource::<Schedules>();
+
+        Ok(Self {
+            schedules: labels
+                .iter()
+                .filter_map(|label| schedules.get(*label))
+                .map(|schedule| ScheduleData::from_schedule(schedule, world.components(), None))
+                .collect::<Result<_, ExtractAppDataError>>()?,
+            timings,
+        })
+    }
+
+    /// Writes the render graph as a [Graphviz](https://graphviz.org) graph.
+    ///
+    /// Each schedule is drawn as a cluster: its systems are boxes, its system sets are ellipses,
+    /// the ordering constraints are solid arrows and the set memberships are dashed arrows. The
+    /// timings of the passes are listed in a separate table.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph render_graph {{");
+        let _ = writeln!(dot, "    rankdir=LR;");
+        let _ = writeln!(dot, "    compound=true;");
+
+        for (schedule_index, schedule) in self.schedules.iter().enumerate() {
+            let node_id = |index: &ScheduleIndex| match index {
+                ScheduleIndex::System(index) => format!("s{schedule_index}_system{index}"),
+                ScheduleIndex::SystemSet(index) => format!("s{schedule_index}_set{index}"),
+            };
+
+            let _ = writeln!(dot, "    subgraph cluster_{schedule_index} {{");
+            let _ = writeln!(dot, "        label=\"{}\";", escape(&schedule.name));
+            for (index, system) in schedule.systems.iter().enumerate() {
+                if system.apply_deferred {
+                    continue;
+                }
+                let _ = writeln!(
+                    dot,
+                    "        {} [shape=box, label=\"{}\"];",
+                    node_id(&ScheduleIndex::System(index)),
+                    escape(short_name(&system.name)),
+                );
+            }
+            for (index, system_set) in schedule.system_sets.iter().enumerate() {
+                let _ = writeln!(
+                    dot,
+                    "        {} [shape=ellipse, label=\"{}\"];",
+                    node_id(&ScheduleIndex::SystemSet(index)),
+                    escape(short_name(&system_set.name)),
+                );
+            }
+            let _ = writeln!(dot, "    }}");
+
+            let is_apply_deferred = |index: &ScheduleIndex| matches!(index, ScheduleIndex::System(index) if schedule.systems[*index].apply_deferred);
+            for (before, after) in &schedule.dependency {
+                if is_apply_deferred(before) || is_apply_deferred(after) {
+                    continue;
+                }
+                let _ = writeln!(dot, "    {} -> {};", node_id(before), node_id(after));
+            }
+            for (parent, child) in &schedule.hierarchy {
+                if is_apply_deferred(child) {
+                    continue;
+                }
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {} [style=dashed, arrowhead=none];",
+                    node_id(&ScheduleIndex::SystemSet(parent.0)),
+                    node_id(child),
+                );
+            }
+        }
+
+        if !self.timings.is_empty() {
+            let _ = write!(
+                dot,
+                "    timings [shape=plaintext, label=\"Pass timings (ms)"
+            );
+            for timing in &self.timings {
+                let format_ms =
+                    |ms: Option<f64>| ms.map_or("-".to_string(), |ms| format!("{ms:.3}"));
+                let _ = write!(
+                    dot,
+                    "\\l{}: cpu {}, gpu {}",
+                    escape(&timing.path),
+                    format_ms(timing.elapsed_cpu_ms),
+                    format_ms(timing.elapsed_gpu_ms),
+                );
+            }
+            let _ = writeln!(dot, "\\l\"];");
+        }
+
+        let _ = writeln!(dot, "}}");
+        dot
+    }
+}
+
+/// Strips the module paths from a type name, keeping its generics.
+fn short_name(name: &str) -> &str {
+    let end = name.find('<').unwrap_or(name.len());
+    match name[..end].rfind("::") {
+        Some(start) => &name[start + 2..],
+        None => name,
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A request to dump the render graph, sent from the main world to the render world.
+#[derive(Resource)]
+struct RenderGraphDumpRequest {
+    settings: RenderGraphDumpSettings,
+    timings: Vec<PassTimingData>,
+}
+
+/// Turns the [`DumpRenderGraph`] messages into a [`RenderGraphDumpRequest`], collecting the
+/// timings of the passes, which only live in the main world.
+fn request_render_graph_dump(
+    mut messages: MessageReader<DumpRenderGraph>,
+    settings: Res<RenderGraphDumpSettings>,
+    diagnostics: Option<Res<DiagnosticsStore>>,
+    mut commands: Commands,
+) {
+    if messages.read().count() == 0 {
+        return;
+    }
+
+    commands.insert_resource(RenderGraphDumpRequest {
+        settings: settings.clone(),
+        timings: diagnostics
+            .map(|diagnostics| PassTimingData::from_diagnostics(&diagnostics))
+            .unwrap_or_default(),
+    });
+}
+
+fn extract_render_graph_dump_request(mut main_world: ResMut<MainWorld>, mut commands: Commands) {
+    if let Some(request) = main_world.remove_resource::<RenderGraphDumpRequest>() {
+        commands.insert_resource(request);
+    }
+}
+
+/// Returns the labels of the schedules of the render graph: the root [`RenderGraph`] schedule,
+/// followed by the schedules of the views rendered this frame.
+fn render_graph_schedule_labels(world: &mut World) -> Vec<InternedScheduleLabel> {
+    let mut labels = vec![RenderGraph.intern()];
+    let view_schedules = world
+        .query::<&ExtractedCamera>()
+        .iter(world)
+        .map(|camera| camera.schedule)
+        .chain(
+            world
+                .query::<&RootNonCameraView>()
+                .iter(world)
+                .map(|view| view.0),
+        )
+        .collect::<Vec<_>>();
+    for label in view_schedules {
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    labels
+}
+
+/// Writes the render graph to the file requested by the [`RenderGraphDumpRequest`], if any.
+fn dump_render_graph(world: &mut World) -> Result<(), BevyError> {
+    let Some(request) = world.remove_resource::<RenderGraphDumpRequest>() else {
+        return Ok(());
+    };
+
+    let labels = render_graph_schedule_labels(world);
+    let render_graph = RenderGraphData::from_render_world(world, &labels, request.timings)
+        .with_severity(Severity::Warning)?;
+
+    let serialized = match request.settings.format {
+        // Use \n unconditionally so that Windows formatting is predictable.
+        RenderGraphDumpFormat::Ron => {
+            ron::ser::to_string_pretty(&render_graph, PrettyConfig::default().new_line("\n"))?
+        }
+        RenderGraphDumpFormat::Dot => render_graph.to_dot(),
+    };
+    let mut file = File::create(&request.settings.path).with_severity(Severity::Warning)?;
+    file.write_all(serialized.as_bytes())
+        .with_severity(Severity::Warning)?;
+    bevy_log::info!("Dumped the render graph to {:?}", request.settings.path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+    use bevy_ecs::{
+        schedule::{IntoScheduleConfigs, Schedule, ScheduleLabel, Schedules},
+        world::World,
+    };
+    use bevy_platform::time::Instant;
+    use bevy_render::renderer::RenderGraph;
+
+    use super::{PassTimingData, RenderGraphData};
+
+    #[test]
+    fn collects_pass_timings() {
+        let mut store = DiagnosticsStore::default();
+        for (path, value) in [
+            ("render/main_pass/elapsed_cpu", 1.0),
+            ("render/main_pass/elapsed_gpu", 2.0),
+            ("render/shadows/point_light/elapsed_gpu", 3.0),
+            ("render/main_pass/vertices", 4.0),
+            ("fps", 60.0),
+        ] {
+            let mut diagnostic = Diagnostic::new(DiagnosticPath::new(path));
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: Instant::now(),
+                value,
+            });
+            store.add(diagnostic);
+        }
+
+        assert_eq!(
+            PassTimingData::from_diagnostics(&store),
+            [
+                PassTimingData {
+                    path: "main_pass".into(),
+                    elapsed_cpu_ms: Some(1.0),
+                    elapsed_gpu_ms: Some(2.0),
+                },
+                PassTimingData {
+                    path: "shadows/point_light".into(),
+                    elapsed_cpu_ms: None,
+                    elapsed_gpu_ms: Some(3.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn dumps_render_graph_schedules() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Missing;
+
+        fn prepare() {}
+        fn draw() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(RenderGraph);
+        schedule.add_systems((prepare, draw).chain());
+        schedule.initialize(&mut world).unwrap();
+        let mut schedules = Schedules::default();
+        schedules.insert(schedule);
+        world.insert_resource(schedules);
+
+        let render_graph = RenderGraphData::from_render_world(
+            &world,
+            &[RenderGraph.intern(), Missing.intern()],
+            Vec::new(),
+        )
+        .unwrap();
+
+        assert_eq!(render_graph.schedules.len(), 1);
+        assert_eq!(render_graph.schedules[0].name, "RenderGraph");
+
+        let dot = render_graph.to_dot();
+        assert!(dot.contains("label=\"RenderGraph\""));
+        assert!(dot.contains("label=\"prepare\""));
+        assert!(dot.contains("label=\"draw\""));
+        assert!(dot.contains("s0_system0 -> s0_system1;"));
+    }
+}
@@ -0,0 +1,372 @@
+//! Module containing the system stepping overlay.
+//!
+//! The [`SteppingPlugin`] lists the systems of the stepped schedules, marking the system under the
+//! stepping cursor, the systems with a breakpoint and the systems skipped in the last frame, and
+//! controls [`Stepping`] from the keyboard.
+
+use alloc::{format, string::String, vec::Vec};
+use bevy_app::{App, MainScheduleOrder, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::With,
+    reflect::ReflectResource,
+    resource::Resource,
+    schedule::{
+        common_conditions::not, InternedScheduleLabel, IntoScheduleConfigs, NodeId, ScheduleLabel,
+        Schedules, Stepping,
+    },
+    system::{Commands, Res, ResMut, Single},
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_picking::Pickable;
+use bevy_reflect::Reflect;
+use bevy_text::{TextColor, TextFont, TextSpan};
+use bevy_ui::{
+    widget::{Text, TextUiWriter},
+    BackgroundColor, GlobalZIndex, Node, PositionType, UiRect, Val,
+};
+use tracing::{info, warn};
+
+/// [`GlobalZIndex`] used to render the stepping overlay.
+///
+/// This is just under the [FPS overlay](crate::fps_overlay::FPS_OVERLAY_ZINDEX).
+pub const STEPPING_OVERLAY_ZINDEX: i32 = i32::MAX - 33;
+
+/// The schedule running the stepping overlay, after [`Update`].
+///
+/// The overlay must run in its own schedule to inspect the stepped schedules, since the schedule
+/// being run is removed from the [`Schedules`] resource.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, ScheduleLabel)]
+pub struct SteppingOverlaySchedule;
+
+/// A plugin adding the [`Stepping`] resource and an overlay to step through the systems of
+/// some schedules.
+///
+/// Stepping requires the `bevy_debug_stepping` feature. The overlay is hidden until stepping is
+/// enabled with the [`SteppingOverlayConfig::toggle_key`]. While stepping:
+/// - [`SteppingOverlayConfig::step_key`] runs the system under the cursor,
+/// - [`SteppingOverlayConfig::continue_key`] runs the systems until the end of the frame or the
+///   next breakpoint,
+/// - [`SteppingOverlayConfig::breakpoint_key`] toggles the breakpoint of the system under the
+///   cursor.
+///
+/// Breakpoints can also be set from code through the [`Stepping`] resource, on systems or on
+/// whole system sets with [`Stepping::set_breakpoint_set`].
+///
+/// The systems of Bevy itself are always run, when the names of the systems are known thanks to
+/// the `debug` feature.
+///
+/// ```no_run
+/// # use bevy_app::{App, FixedUpdate, Update};
+/// # use bevy_dev_tools::stepping::SteppingPlugin;
+/// App::new().add_plugins(
+///     SteppingPlugin::default()
+///         .add_schedule(Update)
+///         .add_schedule(FixedUpdate),
+/// );
+/// ```
+#[derive(Default)]
+pub struct SteppingPlugin {
+    /// The schedules to step through, in addition to those added to the [`Stepping`] resource.
+    pub schedules: Vec<InternedScheduleLabel>,
+    /// Starting configuration of the overlay, which can later be changed through the
+    /// [`SteppingOverlayConfig`] resource.
+    pub config: SteppingOverlayConfig,
+}
+
+impl SteppingPlugin {
+    /// Adds a schedule to step through.
+    pub fn add_schedule(mut self, label: impl ScheduleLabel) -> Self {
+        self.schedules.push(label.intern());
+        self
+    }
+}
+
+impl Plugin for SteppingPlugin {
+    fn build(&self, app: &mut App) {
+        if cfg!(not(feature = "bevy_debug_stepping")) {
+            warn!(
+                "SteppingPlugin requires the bevy_debug_stepping feature; \
+                the stepping overlay is disabled"
+            );
+            return;
+        }
+
+        app.init_schedule(SteppingOverlaySchedule);
+        app.world_mut()
+            .resource_mut::<MainScheduleOrder>()
+            .insert_after(Update, SteppingOverlaySchedule);
+
+        app.init_resource::<Stepping>();
+        let mut stepping = app.world_mut().resource_mut::<Stepping>();
+        for label in &self.schedules {
+            stepping.add_schedule(*label);
+        }
+
+        app.insert_resource(self.config.clone())
+            .init_resource::<SteppingOverlayState>()
+            .add_systems(
+                SteppingOverlaySchedule,
+                (
+                    build_overlay.run_if(not(overlay_built)),
+                    handle_input,
+                    update_overlay.run_if(overlay_built),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration of the stepping overlay.
+#[derive(Resource, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct SteppingOverlayConfig {
+    /// The key enabling and disabling stepping.
+    ///
+    /// Defaults to [`KeyCode::Backquote`].
+    pub toggle_key: KeyCode,
+    /// The key running the system under the cursor.
+    ///
+    /// Defaults to [`KeyCode::KeyS`].
+    pub step_key: KeyCode,
+    /// The key running the systems until the end of the frame or the next breakpoint.
+    ///
+    /// Defaults to [`KeyCode::Space`].
+    pub continue_key: KeyCode,
+    /// The key toggling the breakpoint of the system under the cursor.
+    ///
+    /// Defaults to [`KeyCode::KeyB`].
+    pub breakpoint_key: KeyCode,
+    /// Configuration of the text of the overlay.
+    pub text_config: TextFont,
+    /// The color of the systems that ran in the last frame.
+    pub text_color: Color,
+    /// The color of the systems skipped in the last frame.
+    pub skipped_color: Color,
+    /// The distance of the overlay from the left of the window.
+    pub left: Val,
+    /// The distance of the overlay from the top of the window.
+    pub top: Val,
+}
+
+impl Default for SteppingOverlayConfig {
+    fn default() -> Self {
+        Self {
+            toggle_key: KeyCode::Backquote,
+            step_key: KeyCode::KeyS,
+            continue_key: KeyCode::Space,
+            breakpoint_key: KeyCode::KeyB,
+            text_config: TextFont::from_font_size(14.),
+            text_color: Color::WHITE,
+            skipped_color: Color::srgb(0.5, 0.5, 0.5),
+            left: Val::Px(10.),
+            top: Val::Px(10.),
+        }
+    }
+}
+
+/// The text spans of a system in the overlay.
+struct SystemEntry {
+    schedule: InternedScheduleLabel,
+    node: NodeId,
+    /// Index of the span showing the cursor and the breakpoint.
+    mark: usize,
+    /// Index of the span showing the name of the system.
+    name: usize,
+}
+
+#[derive(Resource, Default)]
+struct SteppingOverlayState {
+    systems: Vec<SystemEntry>,
+}
+
+#[derive(Component)]
+struct SteppingOverlay;
+
+fn overlay_built(state: Res<SteppingOverlayState>) -> bool {
+    !state.systems.is_empty()
+}
+
+/// Builds the overlay from the [`Schedules`] resource.
+///
+/// This may run for a few frames before building the overlay, until all the stepped schedules
+/// have run once.
+fn build_overlay(
+    mut commands: Commands,
+    schedules: Res<Schedules>,
+    mut stepping: ResMut<Stepping>,
+    mut state: ResMut<SteppingOverlayState>,
+    config: Res<SteppingOverlayConfig>,
+) {
+    let Ok(schedule_order) = stepping.schedules() else {
+        return;
+    };
+
+    let mut spans = Vec::new();
+    let mut systems = Vec::new();
+    let mut always_run = Vec::new();
+    for label in schedule_order {
+        let Some(schedule) = schedules.get(*label) else {
+            continue;
+        };
+        let Ok(schedule_systems) = schedule.systems() else {
+            return;
+        };
+        spans.push(format!("{label:?}\n"));
+
+        for (key, system) in schedule_systems {
+            let name = format!("{}", system.name());
+            let node = NodeId::System(key);
+            // Don't step through the systems of Bevy itself.
+            if name.starts_with("bevy") {
+                always_run.push((*label, node));
+                continue;
+            }
+
+            // The root text is the span at index 0.
+            systems.push(SystemEntry {
+                schedule: *label,
+                node,
+                mark: spans.len() + 1,
+                name: spans.len() + 2,
+            });
+            spans.push(String::from("    "));
+            spans.push(format!("{name}\n"));
+        }
+    }
+
+    for (label, node) in always_run {
+        stepping.always_run_node(label, node);
+    }
+    if systems.is_empty() {
+        return;
+    }
+    state.systems = systems;
+
+    commands
+        .spawn((
+            Text::default(),
+            config.text_config.clone(),
+            TextColor(config.text_color),
+            SteppingOverlay,
+            Node {
+                position_type: PositionType::Absolute,
+                left: config.left,
+                top: config.top,
+                padding: UiRect::all(Val::Px(8.)),
+                display: bevy_ui::Display::None,
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            GlobalZIndex(STEPPING_OVERLAY_ZINDEX),
+            Pickable::IGNORE,
+        ))
+        .with_children(|parent| {
+            for span in spans {
+                parent.spawn((
+                    TextSpan(span),
+                    config.text_config.clone(),
+                    TextColor(config.text_color),
+                ));
+            }
+        });
+}
+
+fn handle_input(
+    keyboard_input: Option<Res<ButtonInput<KeyCode>>>,
+    mut stepping: ResMut<Stepping>,
+    config: Res<SteppingOverlayConfig>,
+) {
+    let Some(keyboard_input) = keyboard_input else {
+        return;
+    };
+
+    if keyboard_input.just_pressed(config.toggle_key) {
+        if stepping.is_enabled() {
+            stepping.disable();
+        } else {
+            stepping.enable();
+        }
+    }
+
+    if !stepping.is_enabled() {
+        return;
+    }
+
+    if keyboard_input.just_pressed(config.continue_key) {
+        stepping.continue_frame();
+    } else if keyboard_input.just_pressed(config.step_key) {
+        stepping.step_frame();
+    }
+
+    if keyboard_input.just_pressed(config.breakpoint_key)
+        && let Some((schedule, node)) = stepping.cursor()
+    {
+        if stepping
+            .breakpoints(schedule)
+            .any(|breakpoint| breakpoint == node)
+        {
+            info!("cleared breakpoint in {schedule:?}");
+            stepping.clear_breakpoint_node(schedule, node);
+        } else {
+            info!("set breakpoint in {schedule:?}");
+            stepping.set_breakpoint_node(schedule, node);
+        }
+    }
+}
+
+fn update_overlay(
+    state: Res<SteppingOverlayState>,
+    stepping: Res<Stepping>,
+    config: Res<SteppingOverlayConfig>,
+    overlay: Single<(Entity, &mut Node), With<SteppingOverlay>>,
+    mut writer: TextUiWriter,
+) {
+    let (overlay, mut node) = overlay.into_inner();
+    let display = if stepping.is_enabled() {
+        bevy_ui::Display::Flex
+    } else {
+        bevy_ui::Display::None
+    };
+    if node.display != display {
+        node.display = display;
+    }
+    if !stepping.is_enabled() {
+        return;
+    }
+
+    let cursor = stepping.cursor();
+    let mut breakpoints = Vec::new();
+    let mut skipped = Vec::new();
+    let mut schedule = None;
+    for entry in &state.systems {
+        // The systems are grouped by schedule, so the lists are only collected once per schedule.
+        if schedule != Some(entry.schedule) {
+            schedule = Some(entry.schedule);
+            breakpoints = stepping.breakpoints(entry.schedule).collect();
+            skipped = stepping.last_skipped_systems(entry.schedule).collect();
+        }
+
+        let at_cursor = cursor == Some((entry.schedule, entry.node));
+        let mark = match (at_cursor, breakpoints.contains(&entry.node)) {
+            (true, true) => "->* ",
+            (true, false) => "->  ",
+            (false, true) => "  * ",
+            (false, false) => "    ",
+        };
+        if *writer.text(overlay, entry.mark) != mark {
+            *writer.text(overlay, entry.mark) = String::from(mark);
+        }
+
+        let color = if skipped.contains(&entry.node) {
+            config.skipped_color
+        } else {
+            config.text_color
+        };
+        if writer.color(overlay, entry.name).0 != color {
+            writer.color(overlay, entry.name).0 = color;
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use bevy_app::prelude::*;
+use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPool};
+
+use crate::{
+    Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds "task pool" diagnostics to an App, specifically the number of active tasks and the load
+/// of the [`ComputeTaskPool`], [`AsyncComputeTaskPool`] and [`IoTaskPool`].
+///
+/// The load is the number of active tasks per pool thread. A load that stays above `1.0`
+/// indicates that tasks are waiting for a free thread, in which case long-running tasks may
+/// need to be moved to dedicated threads with [`TaskPool::spawn_dedicated`].
+///
+/// Pools that have not been initialized are not measured.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct TaskPoolDiagnosticsPlugin {
+    /// The total number of values to keep.
+    pub max_history_length: usize,
+}
+
+impl Default for TaskPoolDiagnosticsPlugin {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HISTORY_LENGTH)
+    }
+}
+
+impl TaskPoolDiagnosticsPlugin {
+    /// Creates a new `TaskPoolDiagnosticsPlugin` with the specified `max_history_length`.
+    pub fn new(max_history_length: usize) -> Self {
+        Self { max_history_length }
+    }
+}
+
+impl Plugin for TaskPoolDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for path in [
+            Self::COMPUTE_ACTIVE_TASKS,
+            Self::COMPUTE_LOAD,
+            Self::ASYNC_COMPUTE_ACTIVE_TASKS,
+            Self::ASYNC_COMPUTE_LOAD,
+            Self::IO_ACTIVE_TASKS,
+            Self::IO_LOAD,
+        ] {
+            app.register_diagnostic(
+                Diagnostic::new(path).with_max_history_length(self.max_history_length),
+            );
+        }
+
+        app.add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl TaskPoolDiagnosticsPlugin {
+    /// Number of active tasks on the [`ComputeTaskPool`].
+    pub const COMPUTE_ACTIVE_TASKS: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/compute/active_tasks");
+
+    /// Active tasks per thread of the [`ComputeTaskPool`].
+    pub const COMPUTE_LOAD: DiagnosticPath = DiagnosticPath::const_new("task_pool/compute/load");
+
+    /// Number of active tasks on the [`AsyncComputeTaskPool`].
+    pub const ASYNC_COMPUTE_ACTIVE_TASKS: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/async_compute/active_tasks");
+
+    /// Active tasks per thread of the [`AsyncComputeTaskPool`].
+    pub const ASYNC_COMPUTE_LOAD: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/async_compute/load");
+
+    /// Number of active tasks on the [`IoTaskPool`].
+    pub const IO_ACTIVE_TASKS: DiagnosticPath =
+        DiagnosticPath::const_new("task_pool/io/active_tasks");
+
+    /// Active tasks per thread of the [`IoTaskPool`].
+    pub const IO_LOAD: DiagnosticPath = DiagnosticPath::const_new("task_pool/io/load");
+
+    /// Updates the task pool measurements.
+    pub fn diagnostic_system(mut diagnostics: Diagnostics) {
+        let pools: [(Option<&TaskPool>, DiagnosticPath, DiagnosticPath); 3] = [
+            (
+                ComputeTaskPool::try_get().map(|pool| &**pool),
+                Self::COMPUTE_ACTIVE_TASKS,
+                Self::COMPUTE_LOAD,
+            ),
+            (
+                AsyncComputeTaskPool::try_get().map(|pool| &**pool),
+                Self::ASYNC_COMPUTE_ACTIVE_TASKS,
+                Self::ASYNC_COMPUTE_LOAD,
+            ),
+            (
+                IoTaskPool::try_get().map(|pool| &**pool),
+                Self::IO_ACTIVE_TASKS,
+                Self::IO_LOAD,
+            ),
+        ];
+
+        for (pool, active_tasks, load) in pools {
+            let Some(pool) = pool else {
+                continue;
+            };
+            let stats = pool.stats();
+            diagnostics.add_measurement(&active_tasks, || stats.active_tasks as f64);
+            diagnostics.add_measurement(&load, || stats.load());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    system::ScheduleSystem,
+    world::FromWorld,
+};
+use bevy_log::warn;
+use bevy_reflect::{PartialReflect, Reflect, TypePath};
+
+/// The schedule running the systems of the hot library for the schedule `0` of the app.
+///
+/// It is run by a system added to the schedule of the app, and replaced on every reload.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HotSchedule(pub InternedScheduleLabel);
+
+/// The systems and the state declared by the hot library, in its [`hot_systems!`] entry point.
+///
+/// [`hot_systems!`]: crate::hot_systems
+pub struct HotSystems {
+    pub(crate) schedules: Vec<(InternedScheduleLabel, Schedule)>,
+    pub(crate) preserved: Vec<PreservedState>,
+}
+
+impl HotSystems {
+    pub(crate) fn new(labels: &[InternedScheduleLabel]) -> Self {
+        Self {
+            schedules: labels
+                .iter()
+                .map(|label| (*label, Schedule::new(HotSchedule(*label))))
+                .collect(),
+            preserved: Vec::new(),
+        }
+    }
+
+    /// Adds systems to the `schedule`, which must be one of the
+    /// [`DylibReloadPlugin::schedules`](crate::DylibReloadPlugin::schedules).
+    ///
+    /// The systems are replaced by those of the next version of the library.
+    pub fn add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
+    ) -> &mut Self {
+        // The label isn't interned, as the interner would keep referring to the code of this
+        // version of the library.
+        let label: &dyn ScheduleLabel = &schedule;
+        match self
+            .schedules
+            .iter_mut()
+            .find(|(app_label, _)| **app_label == *label)
+        {
+            Some((_, hot_schedule)) => {
+                hot_schedule.add_systems(systems);
+            }
+            None => warn!(
+                "The hot library added systems to {label:?}, which isn't one of the schedules of the `DylibReloadPlugin`; they are ignored."
+            ),
+        }
+        self
+    }
+
+    /// Declares a resource defined by the library, which is carried over to the next version of
+    /// the library field by field, through reflection.
+    ///
+    /// The fields that don't exist anymore are dropped, and the new fields take their value from
+    /// [`FromWorld`]. If a field changed type, the whole resource is reset.
+    pub fn preserve_resource<R: Resource + Reflect + TypePath + FromWorld>(&mut self) -> &mut Self {
+        self.preserved.push(PreservedState {
+            type_path: R::type_path().to_string(),
+            capture: |world| {
+                let resource = world.remove_resource::<R>()?;
+                match resource.to_dynamic() {
+                    Ok(captured) => Some(Captured::Resource(captured)),
+                    Err(err) => {
+                        warn!(
+                            "The state of {} couldn't be preserved ({err}); it is reset.",
+                            R::type_path()
+                        );
+                        None
+                    }
+                }
+            },
+            restore: |world, captured| {
+                let mut resource = R::from_world(world);
+                if let Some(Captured::Resource(captured)) = captured
+                    && let Err(err) = resource.try_apply(&**captured)
+                {
+                    warn!(
+                        "The state of {} couldn't be preserved ({err}); it is reset.",
+                        R::type_path()
+                    );
+                    resource = R::from_world(world);
+                }
+                world.insert_resource(resource);
+            },
+        });
+        self
+    }
+
+    /// Declares a component defined by the library, which is carried over to the next version of
+    /// the library field by field, through reflection, like [`HotSystems::preserve_resource`].
+    pub fn preserve_component<C: Component + Reflect + TypePath + Default>(&mut self) -> &mut Self {
+        self.preserved.push(PreservedState {
+            type_path: C::type_path().to_string(),
+            capture: |world| {
+                let components: Vec<_> = world
+                    .query::<(Entity, &C)>()
+                    .iter(world)
+                    .map(|(entity, component)| (entity, component.to_dynamic().ok()))
+                    .collect();
+                for (entity, _) in &components {
+                    world.entity_mut(*entity).remove::<C>();
+                }
+                Some(Captured::Components(components))
+            },
+            restore: |world, captured| {
+                let Some(Captured::Components(components)) = captured else {
+                    return;
+                };
+                let mut failed = false;
+                for (entity, captured) in components {
+                    let mut component = C::default();
+                    if captured
+                        .as_deref()
+                        .is_none_or(|captured| component.try_apply(captured).is_err())
+                    {
+                        failed = true;
+                        component = C::default();
+                    }
+                    if let Ok(mut entity) = world.get_entity_mut(*entity) {
+                        entity.insert(component);
+                    }
+                }
+                if failed {
+                    warn!(
+                        "The state of some {} couldn't be preserved; they are reset.",
+                        C::type_path()
+                    );
+                }
+            },
+        });
+        self
+    }
+}
+
+/// The state of a type of the library, taken out of the world before a reload.
+pub(crate) enum Captured {
+    Resource(Box<dyn PartialReflect>),
+    /// The components of each entity, which are `None` if they couldn't be captured.
+    Components(Vec<(Entity, Option<Box<dyn PartialReflect>>)>),
+}
+
+/// How to take the state of a type of the library out of the world and to put it back in.
+pub(crate) struct PreservedState {
+    pub(crate) type_path: String,
+    pub(crate) capture: fn(&mut World) -> Option<Captured>,
+    pub(crate) restore: fn(&mut World, Option<&Captured>),
+}
@@ -0,0 +1,454 @@
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![expect(
+    unsafe_code,
+    reason = "Loading a dynamic library and calling into it are inherently unsafe."
+)]
+#![doc(
+    html_logo_url = "https://bevy.org/assets/icon.png",
+    html_favicon_url = "https://bevy.org/assets/icon.png"
+)]
+
+//! Reloads gameplay systems from a dynamic library while the app runs.
+//!
+//! The gameplay systems live in a library crate of the project built as a `dylib`, which exports
+//! them with [`hot_systems!`]. The [`DylibReloadPlugin`] loads the library, watches it, and when it
+//! is rebuilt, for example by `cargo watch -x "build -p my_gameplay"`, swaps the systems for the
+//! new ones without restarting the app. Only the library is rebuilt, which is much faster than
+//! relinking the whole app.
+//!
+//! ```ignore
+//! // In the `my_gameplay` crate, with `crate-type = ["dylib"]`.
+//! use bevy::{dylib_reload::HotSystems, prelude::*};
+//!
+//! #[derive(Resource, Reflect, Default)]
+//! struct Score {
+//!     points: u32,
+//! }
+//!
+//! fn score(mut score: ResMut<Score>, keys: Res<ButtonInput<KeyCode>>) {
+//!     if keys.just_pressed(KeyCode::Space) {
+//!         score.points += 10;
+//!     }
+//! }
+//!
+//! fn register(systems: &mut HotSystems) {
+//!     systems
+//!         .preserve_resource::<Score>()
+//!         .add_systems(Update, score);
+//! }
+//!
+//! bevy::dylib_reload::hot_systems!(register);
+//!
+//! // In the app.
+//! // SAFETY: `my_gameplay` is built with the same compiler and Bevy as the app.
+//! let reload = unsafe { DylibReloadPlugin::for_crate("my_gameplay") };
+//! App::new().add_plugins((DefaultPlugins, reload)).run();
+//! ```
+//!
+//! # Limitations
+//!
+//! Rust has no stable ABI, so the app and the library must be built by the same compiler, with the
+//! same Bevy, which must be linked dynamically in both with the `dynamic_linking` feature.
+//!
+//! The types defined in the library change with each version of it, so the resources and
+//! components it defines must be declared with [`HotSystems::preserve_resource`] and
+//! [`HotSystems::preserve_component`]: they are taken out of the world before a reload and
+//! rebuilt for the new version through reflection. Any other value of such a type left in the
+//! world, in an event or in a [`Local`](bevy_ecs::system::Local), is undefined behavior after a
+//! reload. The types of the app and of Bevy are safe to use.
+//!
+//! The previous versions of the library are never unloaded, as parts of the engine, like the
+//! interned labels and the type registry, may still refer to their code and data.
+//!
+//! Each version is loaded from a copy of the library in the temporary directory of the system,
+//! see [`env::temp_dir`], named `bevy-hot-{process id}-{version}-{library file name}`. The copies
+//! can't be removed while the app runs, so the copies left by the previous runs of the app are
+//! removed when the [`DylibReloadPlugin`] is added.
+
+mod hot_systems;
+
+pub use hot_systems::{HotSchedule, HotSystems};
+
+use core::mem;
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use bevy_app::{App, First, FixedUpdate, Last, Plugin, PostUpdate, PreUpdate, Update};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel, Schedules},
+};
+use bevy_log::{error, info};
+use hot_systems::Captured;
+
+/// The name of the function exported by [`hot_systems!`].
+const ENTRY_POINT: &[u8] = b"bevy_hot_systems";
+
+/// Exports the function registering the systems of a hot library with the [`DylibReloadPlugin`].
+///
+/// The function takes a `&mut` [`HotSystems`].
+#[macro_export]
+macro_rules! hot_systems {
+    ($register:path) => {
+        #[unsafe(no_mangle)]
+        pub fn bevy_hot_systems(systems: &mut $crate::HotSystems) {
+            $register(systems);
+        }
+    };
+}
+
+/// Loads the systems of a dynamic library exporting them with [`hot_systems!`], and reloads them
+/// when the library is rebuilt.
+///
+/// This is a development tool: ship the library linked statically.
+pub struct DylibReloadPlugin {
+    /// The path of the library.
+    path: PathBuf,
+    /// The schedules the library can add systems to.
+    ///
+    /// Defaults to [`First`], [`PreUpdate`], [`Update`], [`PostUpdate`], [`Last`] and
+    /// [`FixedUpdate`].
+    pub schedules: Vec<InternedScheduleLabel>,
+    /// How often the library is checked for changes.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub poll_interval: Duration,
+}
+
+impl DylibReloadPlugin {
+    /// Reloads the library at `path`.
+    ///
+    /// # Safety
+    ///
+    /// Every version of the library loaded from `path` runs its initialization code and its
+    /// systems in the app, so it must be built with the same compiler and Bevy as the app, and
+    /// follow the [limitations](crate#limitations) of the crate.
+    pub unsafe fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            schedules: vec![
+                First.intern(),
+                PreUpdate.intern(),
+                Update.intern(),
+                PostUpdate.intern(),
+                Last.intern(),
+                FixedUpdate.intern(),
+            ],
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+
+    /// Reloads the library of the crate `name` of the workspace, built in the same target folder
+    /// and with the same profile as the app.
+    ///
+    /// # Safety
+    ///
+    /// See [`DylibReloadPlugin::new`].
+    pub unsafe fn for_crate(name: &str) -> Self {
+        let mut folder = env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+        // Examples and tests are built in subfolders of the profile folder.
+        if folder.ends_with("examples") || folder.ends_with("deps") {
+            folder.pop();
+        }
+        let file = format!(
+            "{}{}{}",
+            env::consts::DLL_PREFIX,
+            name.replace('-', "_"),
+            env::consts::DLL_SUFFIX
+        );
+        // SAFETY: The caller upholds the safety contract of `new`.
+        unsafe { Self::new(folder.join(file)) }
+    }
+
+    /// Returns the path of the library.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Allows the library to add systems to the `schedule`.
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        let schedule = schedule.intern();
+        if !self.schedules.contains(&schedule) {
+            self.schedules.push(schedule);
+        }
+        self
+    }
+}
+
+impl Plugin for DylibReloadPlugin {
+    fn build(&self, app: &mut App) {
+        remove_previous_copies(&self.path);
+
+        app.add_message::<DylibReloaded>()
+            .insert_resource(HotLibrary {
+                path: self.path.clone(),
+                schedules: self.schedules.clone(),
+                poll_interval: self.poll_interval,
+                last_poll: None,
+                modified: None,
+                version: 0,
+                preserved: Vec::new(),
+            })
+            .add_systems(First, reload_hot_library);
+
+        for schedule in &self.schedules {
+            if *schedule == First.intern() {
+                app.add_systems(First, run_hot_schedule(*schedule).after(reload_hot_library));
+            } else {
+                app.add_systems(*schedule, run_hot_schedule(*schedule));
+            }
+        }
+    }
+}
+
+/// Sent after the hot library was loaded or reloaded.
+#[derive(Message, Clone, Debug)]
+pub struct DylibReloaded {
+    /// The number of times the library was loaded, starting from one.
+    pub version: u32,
+}
+
+#[derive(Resource)]
+struct HotLibrary {
+    path: PathBuf,
+    schedules: Vec<InternedScheduleLabel>,
+    poll_interval: Duration,
+    last_poll: Option<Instant>,
+    modified: Option<SystemTime>,
+    version: u32,
+    preserved: Vec<hot_systems::PreservedState>,
+}
+
+fn run_hot_schedule(label: InternedScheduleLabel) -> impl FnMut(&mut World) {
+    move |world: &mut World| {
+        // There is no schedule until the library is loaded.
+        let _ = world.try_run_schedule(HotSchedule(label));
+    }
+}
+
+fn reload_hot_library(world: &mut World) {
+    let modified = {
+        let mut library = world.resource_mut::<HotLibrary>();
+        if library
+            .last_poll
+            .is_some_and(|last_poll| last_poll.elapsed() < library.poll_interval)
+        {
+            return;
+        }
+        library.last_poll = Some(Instant::now());
+
+        let Ok(modified) = fs::metadata(&library.path).and_then(|metadata| metadata.modified())
+        else {
+            return;
+        };
+        // Wait for the linker to finish writing the library.
+        let settled = modified
+            .elapsed()
+            .is_ok_and(|elapsed| elapsed >= library.poll_interval);
+        if library.modified == Some(modified) || !settled {
+            return;
+        }
+        library.modified = Some(modified);
+        modified
+    };
+
+    world.resource_scope(|world, mut library: Mut<HotLibrary>| {
+        // SAFETY: The library is built with the same compiler and Bevy as the app, as promised
+        // when creating the `DylibReloadPlugin`.
+        match unsafe { load(&library.path, library.version + 1) } {
+            Ok(loaded) => {
+                let mut hot_systems = HotSystems::new(&library.schedules);
+                // SAFETY: `hot_systems!` exports the entry point with this signature. The library
+                // must be built with the same compiler and Bevy as the app for the types to match,
+                // as documented in the crate.
+                let entry_point = unsafe { loaded.get::<fn(&mut HotSystems)>(ENTRY_POINT) };
+                let entry_point = match entry_point {
+                    Ok(entry_point) => *entry_point,
+                    Err(err) => {
+                        error!(
+                            "{} has no `hot_systems!` entry point: {err}",
+                            library.path.display()
+                        );
+                        return;
+                    }
+                };
+                entry_point(&mut hot_systems);
+
+                swap(world, &mut library, hot_systems);
+                // The library is never unloaded, as parts of the engine may still refer to it.
+                mem::forget(loaded);
+                library.version += 1;
+                info!(
+                    "Loaded version {} of {}, built {:?} ago.",
+                    library.version,
+                    library.path.display(),
+                    modified.elapsed().unwrap_or_default()
+                );
+                let version = library.version;
+                world.write_message(DylibReloaded { version });
+            }
+            Err(err) => error!("Failed to load {}: {err}", library.path.display()),
+        }
+    });
+}
+
+/// Replaces the systems and the state of the previous version of the library with `hot_systems`.
+fn swap(world: &mut World, library: &mut HotLibrary, hot_systems: HotSystems) {
+    let captured: Vec<(String, Option<Captured>)> = library
+        .preserved
+        .drain(..)
+        .map(|preserved| (preserved.type_path, (preserved.capture)(world)))
+        .collect();
+
+    for preserved in &hot_systems.preserved {
+        let previous = captured
+            .iter()
+            .find(|(type_path, _)| *type_path == preserved.type_path)
+            .and_then(|(_, captured)| captured.as_ref());
+        (preserved.restore)(world, previous);
+    }
+
+    let mut schedules = world.resource_mut::<Schedules>();
+    for (_, schedule) in hot_systems.schedules {
+        schedules.insert(schedule);
+    }
+    library.preserved = hot_systems.preserved;
+}
+
+/// Loads a copy of the library at `path`, as the operating systems either lock the loaded
+/// libraries or don't load a library twice from the same path.
+///
+/// # Safety
+///
+/// Loading the library runs its initialization code, see [`DylibReloadPlugin::new`].
+unsafe fn load(path: &Path, version: u32) -> Result<libloading::Library, String> {
+    let mut file_name = OsString::from(format!("bevy-hot-{}-{version}-", std::process::id()));
+    file_name.push(path.file_name().unwrap_or_default());
+    let copy = env::temp_dir().join(file_name);
+    fs::copy(path, &copy).map_err(|err| format!("failed to copy to {}: {err}", copy.display()))?;
+
+    // SAFETY: The caller ensures the library can be loaded.
+    unsafe { libloading::Library::new(&copy) }.map_err(|err| err.to_string())
+}
+
+/// Removes the copies of the library at `path` made by [`load`] in the previous runs of the app.
+fn remove_previous_copies(path: &Path) {
+    let Some(library_name) = path.file_name().and_then(OsStr::to_str) else {
+        return;
+    };
+    let Ok(entries) = fs::read_dir(env::temp_dir()) else {
+        return;
+    };
+    let library_suffix = format!("-{library_name}");
+    let current_prefix = format!("bevy-hot-{}-", std::process::id());
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+        if file_name.starts_with("bevy-hot-")
+            && !file_name.starts_with(&current_prefix)
+            && file_name.ends_with(&library_suffix)
+        {
+            // The copies loaded by another instance of the app that is still running can't be
+            // removed on Windows, and are left for a later run.
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_ecs::prelude::*;
+    use bevy_reflect::Reflect;
+
+    use std::{env, fs, path::Path};
+
+    use super::{remove_previous_copies, swap, DylibReloadPlugin, HotLibrary, HotSystems};
+
+    #[derive(Resource, Reflect, Default)]
+    struct Score {
+        points: u32,
+        combo: u32,
+    }
+
+    /// A new version of `Score`, with a field removed and one added.
+    #[derive(Resource, Reflect)]
+    struct NewScore {
+        points: u32,
+        multiplier: f32,
+    }
+
+    impl Default for NewScore {
+        fn default() -> Self {
+            Self {
+                points: 0,
+                multiplier: 1.0,
+            }
+        }
+    }
+
+    #[test]
+    fn swaps_systems_and_preserves_state() {
+        let mut app = App::new();
+        // SAFETY: The library doesn't exist, so it is never loaded.
+        app.add_plugins(unsafe { DylibReloadPlugin::new("missing") });
+
+        let mut hot_systems = HotSystems::new(&app.world().resource::<HotLibrary>().schedules);
+        hot_systems
+            .preserve_resource::<Score>()
+            .add_systems(Update, |mut score: ResMut<Score>| {
+                score.points += 1;
+                score.combo += 1;
+            });
+        app.world_mut()
+            .resource_scope(|world, mut library: Mut<HotLibrary>| {
+                swap(world, &mut library, hot_systems);
+            });
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Score>().points, 2);
+
+        // Pretend the next version renamed the type, but kept the type path.
+        let mut hot_systems = HotSystems::new(&app.world().resource::<HotLibrary>().schedules);
+        hot_systems.preserve_resource::<NewScore>();
+        hot_systems.preserved[0].type_path = "bevy_dylib_reload::tests::Score".into();
+        app.world_mut()
+            .resource_scope(|world, mut library: Mut<HotLibrary>| {
+                swap(world, &mut library, hot_systems);
+            });
+        app.update();
+
+        assert!(!app.world().contains_resource::<Score>());
+        let score = app.world().resource::<NewScore>();
+        assert_eq!(score.points, 2);
+        assert_eq!(score.multiplier, 1.0);
+    }
+
+    #[test]
+    fn removes_copies_of_previous_runs() {
+        let library = Path::new("target").join("libremoves_previous_copies.so");
+        let previous = env::temp_dir().join("bevy-hot-0-1-libremoves_previous_copies.so");
+        let current = env::temp_dir().join(format!(
+            "bevy-hot-{}-1-libremoves_previous_copies.so",
+            std::process::id()
+        ));
+        fs::write(&previous, b"").unwrap();
+        fs::write(&current, b"").unwrap();
+
+        remove_previous_copies(&library);
+        assert!(!previous.exists());
+        assert!(current.exists());
+        fs::remove_file(current).unwrap();
+    }
+}
@@ -45,6 +45,8 @@ pub struct DeriveComponent {
     pub relationship_target: Option<RelationshipTarget>,
     /// Whether or not this component is immutable.
     pub immutable: bool,
+    /// Whether or not the storages of this component keep their most recent changed tick.
+    pub track_archetype_changes: bool,
     /// The clone behavior for this component.
     pub clone_behavior: Option<Expr>,
     /// The `map_entities` attribute information.
@@ -67,6 +69,7 @@ impl DeriveComponent {
             relationship: None,
             relationship_target: None,
             immutable: false,
+            track_archetype_changes: false,
             clone_behavior: None,
             map_entities: None,
             additional_requires: Vec::new(),
@@ -117,6 +120,9 @@ impl DeriveComponent {
                     } else if nested.path.is_ident(IMMUTABLE) {
                         attrs.immutable = true;
                         Ok(())
+                    } else if nested.path.is_ident(TRACK_ARCHETYPE_CHANGES) {
+                        attrs.track_archetype_changes = true;
+                        Ok(())
                     } else if nested.path.is_ident(CLONE_BEHAVIOR) {
                         attrs.clone_behavior = Some(nested.value()?.parse()?);
                         Ok(())
@@ -289,6 +295,12 @@ impl DeriveComponent {
             .then_some(quote! { #bevy_ecs::component::Immutable })
             .unwrap_or(quote! { #bevy_ecs::component::Mutable });
 
+        let track_archetype_changes = self.track_archetype_changes.then(|| {
+            quote! {
+                const TRACK_ARCHETYPE_CHANGES: bool = true;
+            }
+        });
+
         let clone_behavior = if relationship_target.is_some() || relationship.is_some() {
             quote!(
                 use #bevy_ecs::relationship::{
@@ -339,6 +351,7 @@ impl DeriveComponent {
             impl #impl_generics #bevy_ecs::component::Component for #struct_name #type_generics #where_clause {
                 const STORAGE_TYPE: #bevy_ecs::component::StorageType = #storage;
                 type Mutability = #mutable_type;
+                #track_archetype_changes
                 fn register_required_components(
                     _requiree: #bevy_ecs::component::ComponentId,
                     required_components: &mut #bevy_ecs::component::RequiredComponentsRegistrator,
@@ -508,6 +521,7 @@ const ON_REMOVE: &str = "on_remove";
 const ON_DESPAWN: &str = "on_despawn";
 
 const IMMUTABLE: &str = "immutable";
+const TRACK_ARCHETYPE_CHANGES: &str = "track_archetype_changes";
 const CLONE_BEHAVIOR: &str = "clone_behavior";
 
 /// All allowed attribute value expression kinds for component hooks.
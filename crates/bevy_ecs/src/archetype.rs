@@ -21,12 +21,13 @@
 
 use crate::{
     bundle::BundleId,
+    change_detection::{CheckChangeTicks, LastChangedTick, Tick},
     component::{ComponentId, Components, RequiredComponentConstructor, StorageType},
     entity::{Entity, EntityLocation},
     event::Event,
     observer::Observers,
     query::DebugCheckedUnwrap,
-    storage::{ImmutableSparseSet, SparseArray, SparseSet, TableId, TableRow},
+    storage::{ImmutableSparseSet, SparseArray, SparseSet, SparseSets, TableId, TableRow},
 };
 use alloc::{boxed::Box, vec::Vec};
 use bevy_platform::collections::{hash_map::Entry, HashMap};
@@ -353,6 +354,10 @@ pub(crate) struct ArchetypeSwapRemoveResult {
 /// [`Component`]: crate::component::Component
 struct ArchetypeComponentInfo {
     storage_type: StorageType,
+    /// The most recent changed tick of the component in this archetype, kept for components
+    /// stored in sparse sets that [track archetype changes](crate::component::Component::TRACK_ARCHETYPE_CHANGES).
+    /// Table columns keep their own.
+    last_changed: Option<LastChangedTick>,
 }
 
 bitflags::bitflags! {
@@ -413,6 +418,7 @@ impl Archetype {
                 component_id,
                 ArchetypeComponentInfo {
                     storage_type: StorageType::Table,
+                    last_changed: None,
                 },
             );
             // NOTE: the `table_components` are sorted AND they were inserted in the `Table` in the same
@@ -433,6 +439,9 @@ impl Archetype {
                 component_id,
                 ArchetypeComponentInfo {
                     storage_type: StorageType::SparseSet,
+                    last_changed: info
+                        .tracks_archetype_changes()
+                        .then(LastChangedTick::default),
                 },
             );
             component_index
@@ -663,6 +672,52 @@ impl Archetype {
             .map(|info| info.storage_type)
     }
 
+    /// Returns the most recent changed tick of the sparse set component `component_id` in this
+    /// archetype, or `None` if the archetype doesn't contain it or the component doesn't
+    /// [track archetype changes](crate::component::Component::TRACK_ARCHETYPE_CHANGES).
+    #[inline]
+    pub(crate) fn sparse_set_last_changed(
+        &self,
+        component_id: ComponentId,
+    ) -> Option<&LastChangedTick> {
+        self.components
+            .get(component_id)
+            .and_then(|info| info.last_changed.as_ref())
+    }
+
+    /// Records the changed ticks of the sparse set components of `entity`, after it was moved into
+    /// this archetype or some of these components were inserted at `change_tick`.
+    pub(crate) fn mark_sparse_set_changes(
+        &self,
+        entity: Entity,
+        sparse_sets: &SparseSets,
+        change_tick: Tick,
+    ) {
+        for (&component_id, info) in self.components.iter() {
+            let Some(last_changed) = &info.last_changed else {
+                continue;
+            };
+            if let Some(ticks) = sparse_sets
+                .get(component_id)
+                .and_then(|sparse_set| sparse_set.get_ticks(entity))
+            {
+                last_changed.mark(ticks.changed, change_tick);
+            }
+        }
+    }
+
+    /// Clamps the most recent changed ticks of the sparse set components, like the ticks of the
+    /// components themselves.
+    pub(crate) fn check_change_ticks(&mut self, check: CheckChangeTicks) {
+        for last_changed in self
+            .components
+            .values_mut()
+            .filter_map(|info| info.last_changed.as_mut())
+        {
+            last_changed.check_tick(check);
+        }
+    }
+
     /// Clears all entities from the archetype.
     pub(crate) fn clear_entities(&mut self) {
         self.entities.clear();
@@ -891,6 +946,13 @@ impl Archetypes {
         self.archetypes.iter()
     }
 
+    /// Clamps the most recent changed ticks kept by the archetypes.
+    pub(crate) fn check_change_ticks(&mut self, check: CheckChangeTicks) {
+        for archetype in &mut self.archetypes {
+            archetype.check_change_ticks(check);
+        }
+    }
+
     /// Gets the archetype id matching the given inputs or inserts a new one if it doesn't exist.
     ///
     /// Specifically, it returns a tuple where the first element
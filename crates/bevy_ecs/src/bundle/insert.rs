@@ -135,6 +135,7 @@ impl<'w> BundleInserter<'w> {
         archetype_after_insert: &ArchetypeAfterBundleInsert,
         world: &'a UnsafeWorldCell<'w>,
         archetype_move_type: &'a mut ArchetypeMoveType,
+        change_tick: Tick,
     ) -> (
         &'a Archetype,
         EntityLocation,
@@ -280,11 +281,13 @@ impl<'w> BundleInserter<'w> {
                 // - `result.table_row` was obtained from a valid archetype.
                 // - We will not drop any components.
                 // - Valid values will be written to new components by the caller (`Self::insert`).
+                // - `change_tick` is the current tick of the world.
                 let move_result = unsafe {
                     tables.move_row::<true>(
                         location.table_id,
                         new_archetype.table_id(),
                         result.table_row,
+                        change_tick,
                     )
                 };
 
@@ -381,6 +384,7 @@ impl<'w> BundleInserter<'w> {
                     archetype_after_insert,
                     &self.world,
                     &mut self.archetype_move_type,
+                    self.change_tick,
                 )
             };
 
@@ -402,6 +406,7 @@ impl<'w> BundleInserter<'w> {
                     caller,
                 );
             }
+            new_archetype.mark_sparse_set_changes(entity, sparse_sets, self.change_tick);
 
             (new_archetype, new_location)
         };
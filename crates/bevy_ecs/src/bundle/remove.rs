@@ -193,6 +193,7 @@ impl<'w> BundleRemover<'w> {
         // SAFETY: We still have the cell, so this is unique, it doesn't conflict with other references, and we drop it shortly.
         // We will not use it to invalidate the bundle_info or archetype pointers.
         let world = unsafe { self.world.world_mut() };
+        let change_tick = world.change_tick();
 
         let (needs_drop, pre_remove_result) = pre_remove(
             &mut world.storages.sparse_sets,
@@ -264,11 +265,13 @@ impl<'w> BundleRemover<'w> {
                 // - The caller ensures `location` is valid.
                 // - We will not drop any components.
                 // - No components were added.
+                // - `change_tick` is the current tick of the world.
                 unsafe {
                     world.storages.tables.move_row::<true>(
                         old_table_id,
                         new_table_id,
                         location.table_row,
+                        change_tick,
                     )
                 }
             } else {
@@ -279,11 +282,13 @@ impl<'w> BundleRemover<'w> {
                 // - The caller ensures `location` is valid.
                 // - Ownership of removed components was obtained in `pre_remove`.
                 // - No components were added.
+                // - `change_tick` is the current tick of the world.
                 unsafe {
                     world.storages.tables.move_row::<false>(
                         old_table_id,
                         new_table_id,
                         location.table_row,
+                        change_tick,
                     )
                 }
             };
@@ -328,6 +333,13 @@ impl<'w> BundleRemover<'w> {
             }
         };
 
+        // SAFETY: pointer valid for 'w, no mutable references into world.archetypes currently exist
+        unsafe { self.new_archetype.as_ref() }.mark_sparse_set_changes(
+            entity,
+            &world.storages.sparse_sets,
+            change_tick,
+        );
+
         // SAFETY: The entity is valid and has been moved to the new location already.
         unsafe {
             world
@@ -134,6 +134,7 @@ impl<'w> BundleSpawner<'w> {
                     caller,
                 );
             }
+            archetype.mark_sparse_set_changes(entity, sparse_sets, self.change_tick);
             // SAFETY: Entity was just spawned at this location
             unsafe {
                 entities.set_location(entity.index(), Some(location));
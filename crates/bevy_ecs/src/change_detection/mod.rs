@@ -159,6 +159,7 @@ mod tests {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
             changed_by: caller.as_mut(),
+            last_changed: None,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
         };
@@ -209,6 +210,7 @@ mod tests {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
             changed_by: caller.as_mut(),
+            last_changed: None,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
         };
@@ -242,6 +244,7 @@ mod tests {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
             changed_by: caller.as_mut(),
+            last_changed: None,
             last_run,
             this_run,
         };
@@ -330,6 +333,7 @@ mod tests {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
             changed_by: caller.as_mut(),
+            last_changed: None,
             last_run,
             this_run,
         };
@@ -366,6 +370,7 @@ mod tests {
             added: &mut component_ticks.added,
             changed: &mut component_ticks.changed,
             changed_by: caller.as_mut(),
+            last_changed: None,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
         };
@@ -1,5 +1,5 @@
 use crate::{
-    change_detection::{traits::*, ComponentTickCells, MaybeLocation, Tick},
+    change_detection::{traits::*, ComponentTickCells, LastChangedTick, MaybeLocation, Tick},
     component::Mutable,
     ptr::PtrMut,
     resource::Resource,
@@ -204,16 +204,20 @@ pub(crate) struct ComponentTicksMut<'w> {
     pub(crate) added: &'w mut Tick,
     pub(crate) changed: &'w mut Tick,
     pub(crate) changed_by: MaybeLocation<&'w mut &'static Location<'static>>,
+    pub(crate) last_changed: Option<&'w LastChangedTick>,
     pub(crate) last_run: Tick,
     pub(crate) this_run: Tick,
 }
 
 impl<'w> ComponentTicksMut<'w> {
+    /// `last_changed` is the most recent changed tick of the storage of the value, if it keeps one.
+    ///
     /// # Safety
     /// This should never alias the underlying ticks. All access must be unique.
     #[inline]
     pub(crate) unsafe fn from_tick_cells(
         cells: ComponentTickCells<'w>,
+        last_changed: Option<&'w LastChangedTick>,
         last_run: Tick,
         this_run: Tick,
     ) -> Self {
@@ -224,10 +228,33 @@ impl<'w> ComponentTicksMut<'w> {
             changed: unsafe { cells.changed.deref_mut() },
             // SAFETY: Caller ensures there is no alias to the cell.
             changed_by: unsafe { cells.changed_by.map(|changed_by| changed_by.deref_mut()) },
+            last_changed,
             last_run,
             this_run,
         }
     }
+
+    /// Sets the changed tick, and records the change in the storage of the value.
+    #[inline]
+    pub(crate) fn set_changed(&mut self, tick: Tick) {
+        *self.changed = tick;
+        if let Some(last_changed) = self.last_changed {
+            last_changed.mark(tick, self.this_run);
+        }
+    }
+
+    /// Returns a `ComponentTicksMut` with a smaller lifetime.
+    #[inline]
+    pub(crate) fn reborrow(&mut self) -> ComponentTicksMut<'_> {
+        ComponentTicksMut {
+            added: self.added,
+            changed: self.changed,
+            changed_by: self.changed_by.as_deref_mut(),
+            last_changed: self.last_changed,
+            last_run: self.last_run,
+            this_run: self.this_run,
+        }
+    }
 }
 
 impl<'w> From<ComponentTicksMut<'w>> for ComponentTicksRef<'w> {
@@ -252,6 +279,7 @@ pub struct ContiguousComponentTicksMut<'w> {
     pub(crate) added: &'w mut [Tick],
     pub(crate) changed: &'w mut [Tick],
     pub(crate) changed_by: MaybeLocation<&'w mut [&'static Location<'static>]>,
+    pub(crate) last_changed: Option<&'w LastChangedTick>,
     pub(crate) last_run: Tick,
     pub(crate) this_run: Tick,
 }
@@ -264,6 +292,7 @@ impl<'w> ContiguousComponentTicksMut<'w> {
         added: ThinSlicePtr<'w, UnsafeCell<Tick>>,
         changed: ThinSlicePtr<'w, UnsafeCell<Tick>>,
         changed_by: MaybeLocation<ThinSlicePtr<'w, UnsafeCell<&'static Location<'static>>>>,
+        last_changed: Option<&'w LastChangedTick>,
         len: usize,
         this_run: Tick,
         last_run: Tick,
@@ -277,6 +306,7 @@ impl<'w> ContiguousComponentTicksMut<'w> {
             changed: unsafe { changed.as_mut_slice_unchecked(len) },
             // SAFETY: see above.
             changed_by: changed_by.map(|v| unsafe { v.as_mut_slice_unchecked(len) }),
+            last_changed,
             last_run,
             this_run,
         }
@@ -311,6 +341,7 @@ impl<'w> ContiguousComponentTicksMut<'w> {
             added,
             changed,
             changed_by: caller,
+            last_changed: None,
             last_run,
             this_run,
         })
@@ -337,7 +368,12 @@ impl<'w> ContiguousComponentTicksMut<'w> {
     }
 
     /// Returns mutable changed ticks' slice.
+    ///
+    /// As any of them may be changed, the storage of components that
+    /// [track archetype changes](crate::component::Component::TRACK_ARCHETYPE_CHANGES) is marked
+    /// as changed, which the [`ArchetypeChanged`](crate::query::ArchetypeChanged) filter detects.
     pub fn changed_mut(&mut self) -> &mut [Tick] {
+        self.mark_storage_changed();
         self.changed
     }
 
@@ -423,6 +459,13 @@ impl<'w> ContiguousComponentTicksMut<'w> {
         for t in self.changed.iter_mut() {
             *t = this_run;
         }
+        self.mark_storage_changed();
+    }
+
+    fn mark_storage_changed(&self) {
+        if let Some(last_changed) = self.last_changed {
+            last_changed.mark(self.this_run, self.this_run);
+        }
     }
 
     /// Returns a `ContiguousComponentTicksMut` with a smaller lifetime.
@@ -431,6 +474,7 @@ impl<'w> ContiguousComponentTicksMut<'w> {
             added: self.added,
             changed: self.changed,
             changed_by: self.changed_by.as_deref_mut(),
+            last_changed: self.last_changed,
             last_run: self.last_run,
             this_run: self.this_run,
         }
@@ -948,6 +992,7 @@ impl<'w, T: ?Sized> Mut<'w, T> {
                 added,
                 changed: last_changed,
                 changed_by: caller,
+                last_changed: None,
                 last_run,
                 this_run,
             },
@@ -1024,9 +1069,11 @@ impl<'w, T> ContiguousMut<'w, T> {
     }
 
     /// Returns the mutable changed ticks' slice.
+    ///
+    /// See [`ContiguousComponentTicksMut::changed_mut`].
     #[inline]
     pub fn changed_ticks_slice_mut(&mut self) -> &mut [Tick] {
-        self.ticks.changed
+        self.ticks.changed_mut()
     }
 
     /// Returns the mutable changed by ticks' slice
@@ -1255,13 +1302,7 @@ impl<'w> MutUntyped<'w> {
     pub fn reborrow(&mut self) -> MutUntyped<'_> {
         MutUntyped {
             value: self.value.reborrow(),
-            ticks: ComponentTicksMut {
-                added: self.ticks.added,
-                changed: self.ticks.changed,
-                changed_by: self.ticks.changed_by.as_deref_mut(),
-                last_run: self.ticks.last_run,
-                this_run: self.ticks.this_run,
-            },
+            ticks: self.ticks.reborrow(),
         }
     }
 
@@ -1381,14 +1422,14 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
     #[inline]
     #[track_caller]
     fn set_changed(&mut self) {
-        *self.ticks.changed = self.ticks.this_run;
+        self.ticks.set_changed(self.ticks.this_run);
         self.ticks.changed_by.assign(MaybeLocation::caller());
     }
 
     #[inline]
     #[track_caller]
     fn set_added(&mut self) {
-        *self.ticks.changed = self.ticks.this_run;
+        self.ticks.set_changed(self.ticks.this_run);
         *self.ticks.added = self.ticks.this_run;
         self.ticks.changed_by.assign(MaybeLocation::caller());
     }
@@ -1396,7 +1437,7 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
     #[inline]
     #[track_caller]
     fn set_last_changed(&mut self, last_changed: Tick) {
-        *self.ticks.changed = last_changed;
+        self.ticks.set_changed(last_changed);
         self.ticks.changed_by.assign(MaybeLocation::caller());
     }
 
@@ -1404,7 +1445,7 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
     #[track_caller]
     fn set_last_added(&mut self, last_added: Tick) {
         *self.ticks.added = last_added;
-        *self.ticks.changed = last_added;
+        self.ticks.set_changed(last_added);
         self.ticks.changed_by.assign(MaybeLocation::caller());
     }
 
@@ -1,9 +1,10 @@
 use bevy_ecs_macros::Event;
+use bevy_platform::sync::atomic::{AtomicU32, Ordering};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use core::{cell::UnsafeCell, panic::Location};
 
-use crate::change_detection::{MaybeLocation, MAX_CHANGE_AGE};
+use crate::change_detection::{MaybeLocation, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE};
 
 /// A value that tracks when a system ran relative to other systems.
 /// This is used to power change detection.
@@ -120,6 +121,60 @@ impl CheckChangeTicks {
     }
 }
 
+/// The most recent changed [`Tick`] of a group of components, like the ones of a table column.
+///
+/// It is updated whenever one of these components is inserted, moved in or changed, so that the
+/// [`ArchetypeChanged`](crate::query::ArchetypeChanged) filter can skip the whole group without
+/// reading the tick of each component. Systems running in parallel may change components of the
+/// same group, which is why it is atomic.
+#[derive(Debug, Default)]
+pub(crate) struct LastChangedTick(AtomicU32);
+
+impl LastChangedTick {
+    /// Returns the most recent changed tick of the group.
+    #[inline]
+    pub(crate) fn get(&self) -> Tick {
+        Tick::new(self.0.load(Ordering::Relaxed))
+    }
+
+    /// Records that a component of the group was changed at `tick`, by a system running at `this_run`.
+    #[inline]
+    pub(crate) fn mark(&self, tick: Tick, this_run: Tick) {
+        // Systems running at the same time as this one may have recorded ticks a bit ahead of
+        // `this_run`, while the other ticks are behind it, at most by `MAX_CHANGE_AGE` plus the
+        // ticks elapsed since the last `check_tick`.
+        let position = |tick: u32| {
+            let ahead = tick.wrapping_sub(this_run.get());
+            if ahead < CHECK_TICK_THRESHOLD {
+                i64::from(ahead)
+            } else {
+                -i64::from(this_run.get().wrapping_sub(tick))
+            }
+        };
+        let new = position(tick.get());
+        let mut current = self.0.load(Ordering::Relaxed);
+        while position(current) < new {
+            match self.0.compare_exchange_weak(
+                current,
+                tick.get(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Wraps the tick if it exceeds [`Tick::MAX`], like [`Tick::check_tick`].
+    #[inline]
+    pub(crate) fn check_tick(&mut self, check: CheckChangeTicks) {
+        let mut tick = Tick::new(*self.0.get_mut());
+        tick.check_tick(check);
+        *self.0.get_mut() = tick.get();
+    }
+}
+
 /// Interior-mutable access to the [`Tick`]s of a single component or resource.
 #[derive(Copy, Clone, Debug)]
 pub struct ComponentTickCells<'a> {
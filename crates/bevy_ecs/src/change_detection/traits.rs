@@ -445,14 +445,14 @@ macro_rules! change_detection_mut_impl {
             #[inline]
             #[track_caller]
             fn set_changed(&mut self) {
-                *self.ticks.changed = self.ticks.this_run;
+                self.ticks.set_changed(self.ticks.this_run);
                 self.ticks.changed_by.assign(MaybeLocation::caller());
             }
 
             #[inline]
             #[track_caller]
             fn set_added(&mut self) {
-                *self.ticks.changed = self.ticks.this_run;
+                self.ticks.set_changed(self.ticks.this_run);
                 *self.ticks.added = self.ticks.this_run;
                 self.ticks.changed_by.assign(MaybeLocation::caller());
             }
@@ -460,7 +460,7 @@ macro_rules! change_detection_mut_impl {
             #[inline]
             #[track_caller]
             fn set_last_changed(&mut self, last_changed: Tick) {
-                *self.ticks.changed = last_changed;
+                self.ticks.set_changed(last_changed);
                 self.ticks.changed_by.assign(MaybeLocation::caller());
             }
 
@@ -468,7 +468,7 @@ macro_rules! change_detection_mut_impl {
             #[track_caller]
             fn set_last_added(&mut self, last_added: Tick) {
                 *self.ticks.added = last_added;
-                *self.ticks.changed = last_added;
+                self.ticks.set_changed(last_added);
                 self.ticks.changed_by.assign(MaybeLocation::caller());
             }
 
@@ -517,13 +517,7 @@ macro_rules! impl_methods {
             pub fn reborrow(&mut self) -> Mut<'_, $target> {
                 Mut {
                     value: self.value,
-                    ticks: ComponentTicksMut {
-                        added: self.ticks.added,
-                        changed: self.ticks.changed,
-                        changed_by: self.ticks.changed_by.as_deref_mut(),
-                        last_run: self.ticks.last_run,
-                        this_run: self.ticks.this_run,
-                    },
+                    ticks: self.ticks.reborrow(),
                 }
             }
 
@@ -57,6 +57,14 @@ impl ComponentInfo {
         self.descriptor.mutable
     }
 
+    /// Returns `true` if the storages of the current component keep their most recent changed tick.
+    ///
+    /// See [`Component::TRACK_ARCHETYPE_CHANGES`].
+    #[inline]
+    pub fn tracks_archetype_changes(&self) -> bool {
+        self.descriptor.track_archetype_changes
+    }
+
     /// Returns [`ComponentCloneBehavior`] of the current component.
     #[inline]
     pub fn clone_behavior(&self) -> &ComponentCloneBehavior {
@@ -227,6 +235,7 @@ pub struct ComponentDescriptor {
     // None if the underlying type doesn't need to be dropped
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
     mutable: bool,
+    track_archetype_changes: bool,
     clone_behavior: ComponentCloneBehavior,
     relationship_accessor: MaybeRelationshipAccessor,
 }
@@ -241,6 +250,7 @@ impl Debug for ComponentDescriptor {
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
             .field("mutable", &self.mutable)
+            .field("track_archetype_changes", &self.track_archetype_changes)
             .field("clone_behavior", &self.clone_behavior)
             .field("relationship_accessor", &self.relationship_accessor)
             .finish()
@@ -269,6 +279,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: T::Mutability::MUTABLE,
+            track_archetype_changes: T::TRACK_ARCHETYPE_CHANGES,
             clone_behavior: T::clone_behavior(),
             relationship_accessor: T::relationship_accessor().map(|v| v.initializer).into(),
         }
@@ -306,6 +317,7 @@ impl ComponentDescriptor {
             layout,
             drop,
             mutable,
+            track_archetype_changes: false,
             clone_behavior,
             relationship_accessor: relationship_accessor.into(),
         }
@@ -321,6 +333,7 @@ impl ComponentDescriptor {
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
             mutable: true,
+            track_archetype_changes: false,
             clone_behavior: ComponentCloneBehavior::Default,
             relationship_accessor: None.into(),
         }
@@ -351,6 +364,14 @@ impl ComponentDescriptor {
         self.mutable
     }
 
+    /// Returns whether the storages of this component keep their most recent changed tick.
+    ///
+    /// See [`Component::TRACK_ARCHETYPE_CHANGES`].
+    #[inline]
+    pub fn tracks_archetype_changes(&self) -> bool {
+        self.track_archetype_changes
+    }
+
     fn initialize(&mut self, id: ComponentId, components: &mut Components) {
         self.relationship_accessor.initialize(id, components);
     }
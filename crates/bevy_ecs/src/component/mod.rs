@@ -520,6 +520,16 @@ pub trait Component: Send + Sync + 'static {
     /// * For a component to be immutable, this type must be [`Immutable`].
     type Mutability: ComponentMutability;
 
+    /// Whether the table columns and archetypes storing this component keep the most recent tick
+    /// at which one of their components was changed.
+    ///
+    /// This lets the [`ArchetypeChanged`] filter skip unchanged tables and archetypes in `O(1)`,
+    /// at the cost of an atomic update of that tick on every mutable access. It is `false` by
+    /// default, and set with the `#[component(track_archetype_changes)]` attribute.
+    ///
+    /// [`ArchetypeChanged`]: crate::query::ArchetypeChanged
+    const TRACK_ARCHETYPE_CHANGES: bool = false;
+
     /// Gets the `on_add` [`ComponentHook`] for this [`Component`] if one is defined.
     fn on_add() -> Option<ComponentHook> {
         None
@@ -83,9 +83,12 @@ pub mod prelude {
         },
         name::{Name, NameOrEntity},
         observer::{Observer, ObserverSystemExt, On},
-        query::{Added, Allow, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
+        query::{
+            Added, Allow, AnyOf, ArchetypeChanged, Changed, Has, Or, QueryBuilder, QueryState,
+            With, Without,
+        },
         related,
-        relationship::RelationshipTarget,
+        relationship::{RelatedBy, Relation, RelationshipTarget},
         resource::Resource,
         schedule::{
             common_conditions::*, ApplyDeferred, IntoScheduleConfigs, IntoSystemSet, Schedule,
@@ -164,7 +167,7 @@ mod tests {
         entity::{Entity, EntityHashSet, EntityMapper, EntityNotSpawnedError},
         entity_disabling::DefaultQueryFilters,
         prelude::Or,
-        query::{Added, Changed, FilteredAccess, QueryFilter, With, Without},
+        query::{Added, ArchetypeChanged, Changed, FilteredAccess, QueryFilter, With, Without},
         resource::Resource,
         world::{error::EntityDespawnError, EntityMut, EntityRef, Mut, World},
     };
@@ -1247,6 +1250,116 @@ mod tests {
         assert_eq!(get_changed(&mut world), vec![e1]);
     }
 
+    #[test]
+    fn archetype_changed_query() {
+        #[derive(Component)]
+        #[component(track_archetype_changes)]
+        struct TrackedA(usize);
+
+        #[derive(Component)]
+        #[component(storage = "SparseSet", track_archetype_changes)]
+        struct TrackedSparse(usize);
+
+        let mut world = World::default();
+        let e1 = world.spawn(TrackedA(0)).id();
+        let e2 = world.spawn(TrackedA(0)).id();
+        let e3 = world.spawn((TrackedA(0), B(0))).id();
+        world.spawn(TrackedSparse(0));
+        let e5 = world.spawn((TrackedSparse(0), TrackedA(0))).id();
+        world.clear_trackers();
+
+        fn get_filtered<F: QueryFilter>(world: &mut World) -> EntityHashSet {
+            world
+                .query_filtered::<Entity, F>()
+                .iter(world)
+                .collect::<EntityHashSet>()
+        }
+
+        assert!(get_filtered::<ArchetypeChanged<TrackedA>>(&mut world).is_empty());
+
+        // All the entities of the changed table are retained, including `e5` which shares it.
+        world.get_mut::<TrackedA>(e2).unwrap().0 += 1;
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<TrackedA>>(&mut world),
+            [e1, e2, e5].into_iter().collect::<EntityHashSet>()
+        );
+        world.clear_trackers();
+
+        world.get_mut::<TrackedA>(e3).unwrap().0 += 1;
+        world.get_mut::<TrackedSparse>(e5).unwrap().0 += 1;
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<TrackedA>>(&mut world),
+            [e3].into_iter().collect::<EntityHashSet>()
+        );
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<TrackedSparse>>(&mut world),
+            [e5].into_iter().collect::<EntityHashSet>()
+        );
+        world.clear_trackers();
+
+        // Moving unchanged entities to another table doesn't mark it as changed.
+        world.entity_mut(e2).insert(B(0));
+        assert!(get_filtered::<ArchetypeChanged<TrackedA>>(&mut world).is_empty());
+        world.clear_trackers();
+
+        // Changed entities carry their changes to their new table, while their previous table
+        // stays marked as changed.
+        world.get_mut::<TrackedA>(e1).unwrap().0 += 1;
+        world.entity_mut(e1).insert(B(0));
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<TrackedA>>(&mut world),
+            [e1, e2, e3, e5].into_iter().collect::<EntityHashSet>()
+        );
+    }
+
+    #[test]
+    fn archetype_changed_query_untracked() {
+        let mut world = World::default();
+        let e1 = world.spawn(A(0)).id();
+        let e2 = world.spawn(A(0)).id();
+        let e3 = world.spawn((A(0), B(0))).id();
+        world.spawn(SparseStored(0));
+        let e5 = world.spawn((SparseStored(0), A(0))).id();
+        world.clear_trackers();
+
+        fn get_filtered<F: QueryFilter>(world: &mut World) -> EntityHashSet {
+            world
+                .query_filtered::<Entity, F>()
+                .iter(world)
+                .collect::<EntityHashSet>()
+        }
+
+        assert!(get_filtered::<ArchetypeChanged<A>>(&mut world).is_empty());
+
+        world.get_mut::<A>(e2).unwrap().0 += 1;
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<A>>(&mut world),
+            [e1, e2, e5].into_iter().collect::<EntityHashSet>()
+        );
+        world.clear_trackers();
+
+        world.get_mut::<A>(e3).unwrap().0 += 1;
+        world.get_mut::<SparseStored>(e5).unwrap().0 += 1;
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<A>>(&mut world),
+            [e3].into_iter().collect::<EntityHashSet>()
+        );
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<SparseStored>>(&mut world),
+            [e5].into_iter().collect::<EntityHashSet>()
+        );
+        world.clear_trackers();
+
+        // Without tracking, the ticks are scanned, so only the table the changed entity moved to
+        // is changed.
+        world.get_mut::<A>(e1).unwrap().0 += 1;
+        world.entity_mut(e1).insert(B(0));
+        assert_eq!(
+            get_filtered::<ArchetypeChanged<A>>(&mut world),
+            [e1, e3].into_iter().collect::<EntityHashSet>()
+        );
+    }
+
     #[test]
     fn resource() {
         use crate::resource::Resource;
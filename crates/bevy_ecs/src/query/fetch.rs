@@ -3,7 +3,8 @@ use crate::{
     bundle::Bundle,
     change_detection::{
         ComponentTicksMut, ComponentTicksRef, ContiguousComponentTicksMut,
-        ContiguousComponentTicksRef, ContiguousMut, ContiguousRef, MaybeLocation, Tick,
+        ContiguousComponentTicksRef, ContiguousMut, ContiguousRef, LastChangedTick, MaybeLocation,
+        Tick,
     },
     component::{Component, ComponentId, Components, Mutable, StorageType},
     entity::{Entities, Entity, EntityLocation},
@@ -2240,6 +2241,8 @@ pub struct WriteFetch<'w, T: Component> {
         // Can be `None` when the component has never been inserted
         Option<&'w ComponentSparseSet>,
     >,
+    // The most recent changed tick of the current table column or of the component in the current archetype
+    last_changed: Option<&'w LastChangedTick>,
     last_run: Tick,
     this_run: Tick,
 }
@@ -2283,6 +2286,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
                     unsafe { world.storages().sparse_sets.get(component_id) }
                 },
             ),
+            last_changed: None,
             last_run,
             this_run,
         }
@@ -2299,7 +2303,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
     unsafe fn set_archetype<'w>(
         fetch: &mut WriteFetch<'w, T>,
         component_id: &ComponentId,
-        _archetype: &'w Archetype,
+        archetype: &'w Archetype,
         table: &'w Table,
     ) {
         if Self::IS_DENSE {
@@ -2307,6 +2311,8 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
             unsafe {
                 Self::set_table(fetch, component_id, table);
             }
+        } else if T::TRACK_ARCHETYPE_CHANGES {
+            fetch.last_changed = archetype.sparse_set_last_changed(*component_id);
         }
     }
 
@@ -2331,6 +2337,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
         ));
         // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table
         unsafe { fetch.components.set_table(table_data) };
+        fetch.last_changed = column.last_changed();
     }
 
     fn update_component_access(&component_id: &ComponentId, access: &mut FilteredAccess) {
@@ -2400,6 +2407,7 @@ unsafe impl<'__w, T: Component<Mutability = Mutable>> QueryData for &'__w mut T
                         added: added.deref_mut(),
                         changed: changed.deref_mut(),
                         changed_by: caller.map(|caller| caller.deref_mut()),
+                        last_changed: fetch.last_changed,
                         this_run: fetch.this_run,
                         last_run: fetch.last_run,
                     },
@@ -2418,6 +2426,7 @@ unsafe impl<'__w, T: Component<Mutability = Mutable>> QueryData for &'__w mut T
                     value: component.assert_unique().deref_mut(),
                     ticks: ComponentTicksMut::from_tick_cells(
                         ticks,
+                        fetch.last_changed,
                         fetch.last_run,
                         fetch.this_run,
                     ),
@@ -2471,6 +2480,7 @@ impl<T: Component<Mutability = Mutable>> ContiguousQueryData for &mut T {
                             added_ticks,
                             changed_ticks,
                             callers,
+                            fetch.last_changed,
                             entities.len(),
                             fetch.this_run,
                             fetch.last_run,
@@ -18,7 +18,8 @@ use variadics_please::all_tuples;
 /// - **Component filters.**
 ///   [`With`] and [`Without`] filters can be applied to check if the queried entity does or does not contain a particular component.
 /// - **Change detection filters.**
-///   [`Added`] and [`Changed`] filters can be applied to detect component changes to an entity.
+///   [`Added`] and [`Changed`] filters can be applied to detect component changes to an entity,
+///   and [`ArchetypeChanged`] to detect them in the entity's table or archetype.
 /// - **Spawned filter.**
 ///   [`Spawned`] filter can be applied to check if the queried entity was spawned recently.
 /// - **`QueryFilter` tuples.**
@@ -1111,6 +1112,231 @@ unsafe impl<T: Component> QueryFilter for Changed<T> {
     }
 }
 
+/// A filter on a component that retains all the entities of a table or archetype if any of them
+/// had that component added or mutably dereferenced since the system last ran.
+///
+/// This is useful when the entities of an archetype are processed together, for example to
+/// rebuild a spatial index or a GPU buffer per archetype, or when most entities are static and
+/// the few changes don't need to be located precisely.
+///
+/// # Time complexity
+///
+/// For components marked with `#[component(track_archetype_changes)]`, each table column, and
+/// each archetype for the components stored in sparse sets, keeps the most recent tick at which
+/// one of its components was changed, updated whenever one of them is inserted, moved in or
+/// changed. The filter only compares that tick when iteration reaches a table or archetype, in
+/// `O(1)`. A table also stays changed when its changed entities are moved to another table.
+/// See [`Component::TRACK_ARCHETYPE_CHANGES`] for the cost of this tracking.
+///
+/// For other components, the change ticks of each table or archetype are scanned once when
+/// iteration reaches it, stopping at the first change.
+///
+/// Either way, the entities of unchanged tables are then rejected without reading their ticks,
+/// and the entities of changed tables are all retained, whether or not they were changed
+/// themselves. Use [`Changed<T>`] to only retain these.
+///
+/// Like [`Changed<T>`], this filter is not archetypal: unchanged tables and archetypes are not
+/// skipped as a whole, so iterating a query still visits each of their entities to reject it.
+/// Iteration is therefore `O(entities)` in the matched tables and archetypes, but only reads a
+/// flag per entity, which is much cheaper than the ticks read by [`Changed<T>`].
+///
+/// For components stored in [`Table`]s, the changes are grouped by table, which may be shared
+/// by several archetypes that only differ by their sparse set components.
+///
+/// # Examples
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::query::ArchetypeChanged;
+/// # use bevy_ecs::system::Query;
+/// #
+/// # #[derive(Component)]
+/// #[component(track_archetype_changes)]
+/// struct Transform;
+/// # #[derive(Component)]
+/// # struct Collider;
+///
+/// fn rebuild_colliders(query: Query<&Collider, ArchetypeChanged<Transform>>) {
+///     for collider in &query {
+///         // Only visits the colliders of archetypes where something moved.
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(rebuild_colliders);
+/// ```
+pub struct ArchetypeChanged<T>(PhantomData<T>);
+
+#[doc(hidden)]
+pub struct ArchetypeChangedFetch<'w, T: Component> {
+    // Only used for sparse set components that don't track archetype changes.
+    // Can be `None` when the component has never been inserted
+    sparse_set: Option<&'w ComponentSparseSet>,
+    matches: bool,
+    last_run: Tick,
+    this_run: Tick,
+    marker: PhantomData<T>,
+}
+
+impl<T: Component> Clone for ArchetypeChangedFetch<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            sparse_set: self.sparse_set,
+            matches: self.matches,
+            last_run: self.last_run,
+            this_run: self.this_run,
+            marker: PhantomData,
+        }
+    }
+}
+
+// SAFETY:
+// `set_table` and `set_archetype` read the most recent changed tick or the ticks of a single component.
+// This is sound because `update_component_access` add read access for that component and panics when appropriate.
+// `update_component_access` adds a `With` filter for a component.
+// This is sound because `matches_component_set` returns whether the set contains that component.
+unsafe impl<T: Component> WorldQuery for ArchetypeChanged<T> {
+    type Fetch<'w> = ArchetypeChangedFetch<'w, T>;
+    type State = ComponentId;
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        fetch
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w, 's>(
+        world: UnsafeWorldCell<'w>,
+        &id: &'s ComponentId,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        ArchetypeChangedFetch {
+            sparse_set: match T::STORAGE_TYPE {
+                StorageType::SparseSet if !T::TRACK_ARCHETYPE_CHANGES => {
+                    // SAFETY: The underlying type associated with `component_id` is `T`,
+                    // which we are allowed to access since we registered it in `update_component_access`.
+                    // Note that we do not actually access any components' ticks in this function, we just get a shared
+                    // reference to the sparse set, which is used to access the components' ticks in `Self::set_archetype`.
+                    unsafe { world.storages().sparse_sets.get(id) }
+                }
+                _ => None,
+            },
+            matches: false,
+            last_run,
+            this_run,
+            marker: PhantomData,
+        }
+    }
+
+    const IS_DENSE: bool = {
+        match T::STORAGE_TYPE {
+            StorageType::Table => true,
+            StorageType::SparseSet => false,
+        }
+    };
+
+    #[inline]
+    unsafe fn set_archetype<'w, 's>(
+        fetch: &mut Self::Fetch<'w>,
+        component_id: &'s ComponentId,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        if Self::IS_DENSE {
+            // SAFETY: `set_archetype`'s safety rules are a super set of the `set_table`'s ones.
+            unsafe {
+                Self::set_table(fetch, component_id, table);
+            }
+        } else if T::TRACK_ARCHETYPE_CHANGES {
+            // SAFETY: `archetype` contains the component, which tracks archetype changes.
+            let last_changed = unsafe {
+                archetype
+                    .sparse_set_last_changed(*component_id)
+                    .debug_checked_unwrap()
+            };
+            fetch.matches = last_changed
+                .get()
+                .is_newer_than(fetch.last_run, fetch.this_run);
+        } else {
+            // SAFETY: The sparse set exists, as `archetype` contains the component.
+            let sparse_set = unsafe { fetch.sparse_set.debug_checked_unwrap() };
+            fetch.matches = archetype.entities().iter().any(|archetype_entity| {
+                // SAFETY: The entities of `archetype` all have the component.
+                let tick = unsafe {
+                    sparse_set
+                        .get_changed_tick(archetype_entity.id())
+                        .debug_checked_unwrap()
+                };
+                // SAFETY: We have read access to the ticks of the component.
+                unsafe { tick.deref() }.is_newer_than(fetch.last_run, fetch.this_run)
+            });
+        }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w, 's>(
+        fetch: &mut Self::Fetch<'w>,
+        &component_id: &'s ComponentId,
+        table: &'w Table,
+    ) {
+        // SAFETY: set_table is only called when T::STORAGE_TYPE = StorageType::Table,
+        // for tables containing the component.
+        let column = unsafe { table.get_column(component_id).debug_checked_unwrap() };
+        fetch.matches = if T::TRACK_ARCHETYPE_CHANGES {
+            // SAFETY: The component tracks archetype changes.
+            let last_changed = unsafe { column.last_changed().debug_checked_unwrap() };
+            last_changed
+                .get()
+                .is_newer_than(fetch.last_run, fetch.this_run)
+        } else {
+            // SAFETY: The column contains one tick per entity of the table.
+            let table_ticks =
+                unsafe { column.get_changed_ticks_slice(table.entity_count() as usize) };
+            table_ticks.iter().any(|tick| {
+                // SAFETY: We have read access to the ticks of the component.
+                unsafe { tick.deref() }.is_newer_than(fetch.last_run, fetch.this_run)
+            })
+        };
+    }
+
+    #[inline]
+    fn update_component_access(&id: &ComponentId, access: &mut FilteredAccess) {
+        if access.access().has_write(id) {
+            panic!("ArchetypeChanged<{}> conflicts with a previous access in this query. Shared access cannot coincide with exclusive access.", DebugName::type_name::<T>());
+        }
+        access.add_read(id);
+    }
+
+    fn init_state(world: &mut World) -> ComponentId {
+        world.register_component::<T>()
+    }
+
+    fn get_state(components: &Components) -> Option<ComponentId> {
+        components.component_id::<T>()
+    }
+
+    fn matches_component_set(
+        &id: &ComponentId,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        set_contains_id(id)
+    }
+}
+
+// SAFETY: WorldQuery impl performs only read access on ticks
+unsafe impl<T: Component> QueryFilter for ArchetypeChanged<T> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    unsafe fn filter_fetch(
+        _state: &Self::State,
+        fetch: &mut Self::Fetch<'_>,
+        _entity: Entity,
+        _table_row: TableRow,
+    ) -> bool {
+        fetch.matches
+    }
+}
+
 /// A filter that only retains results the first time after the entity has been spawned.
 ///
 /// A common use for this filter is one-time initialization.
@@ -1,6 +1,7 @@
 //! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
 
 mod related_methods;
+mod relation;
 mod relationship_query;
 mod relationship_source_collection;
 
@@ -13,6 +14,7 @@ use alloc::format;
 
 use bevy_utils::prelude::DebugName;
 pub use related_methods::*;
+pub use relation::*;
 pub use relationship_query::*;
 pub use relationship_source_collection::*;
 
@@ -0,0 +1,156 @@
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+
+use crate::{component::Component, entity::Entity};
+
+/// A typed edge from this entity to a target entity, without having to define a pair of
+/// [`Relationship`](super::Relationship) and [`RelationshipTarget`](super::RelationshipTarget)
+/// components.
+///
+/// `T` is a marker type naming the kind of relation, such as `Targets` or `Likes`. An entity can
+/// have one [`Relation<T>`] for each kind of relation, and the target entity gets a
+/// [`RelatedBy<T>`] listing all the entities relating to it with that kind.
+///
+/// Like other relationships, this is kept in sync automatically: if the source entity is despawned
+/// or loses its [`Relation<T>`], it is removed from the [`RelatedBy<T>`] of the target, and if the
+/// target entity is despawned, the [`Relation<T>`]s pointing to it are removed. This avoids the
+/// dangling [`Entity`] fields of hand-rolled components.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::relationship::{Relation, RelatedBy, RelationshipTarget};
+/// /// Marks the entities an attacker targets.
+/// struct Targets;
+///
+/// fn attack(attackers: Query<&Relation<Targets>>, mut health: Query<&mut Health>) {
+///     for relation in &attackers {
+///         if let Ok(mut health) = health.get_mut(relation.target()) {
+///             health.0 -= 1;
+///         }
+///     }
+/// }
+///
+/// fn count_attackers(targets: Query<(Entity, &RelatedBy<Targets>)>) {
+///     for (entity, attackers) in &targets {
+///         println!("{entity} is targeted by {} attackers", attackers.len());
+///     }
+/// }
+/// # #[derive(Component)]
+/// # struct Health(u32);
+/// # bevy_ecs::system::assert_is_system(attack);
+/// # bevy_ecs::system::assert_is_system(count_attackers);
+/// ```
+///
+/// Relations can't point to their own entity, and don't despawn their sources with their target.
+/// Define a dedicated [`Relationship`](super::Relationship) for these, or to store more data on the edge.
+#[derive(Component)]
+#[relationship(relationship_target = RelatedBy<T>)]
+pub struct Relation<T: 'static> {
+    /// The entity this relation points to.
+    #[relationship]
+    #[entities]
+    target: Entity,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> Relation<T> {
+    /// Creates a relation pointing to `target`.
+    #[inline]
+    pub const fn new(target: Entity) -> Self {
+        Self {
+            target,
+            marker: PhantomData,
+        }
+    }
+
+    /// The entity this relation points to.
+    #[inline]
+    pub const fn target(&self) -> Entity {
+        self.target
+    }
+}
+
+impl<T: 'static> Clone for Relation<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for Relation<T> {}
+
+impl<T: 'static> PartialEq for Relation<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target
+    }
+}
+
+impl<T: 'static> Eq for Relation<T> {}
+
+impl<T: 'static> fmt::Debug for Relation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Relation").field(&self.target).finish()
+    }
+}
+
+/// The entities relating to this entity through a [`Relation<T>`].
+///
+/// This is inserted and updated automatically when [`Relation<T>`]s pointing to this entity are
+/// inserted or removed, and removed once none are left. Iterate the sources with
+/// [`RelationshipTarget::iter`](super::RelationshipTarget::iter).
+#[derive(Component)]
+#[relationship_target(relationship = Relation<T>)]
+pub struct RelatedBy<T: 'static> {
+    #[relationship]
+    sources: Vec<Entity>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: 'static> RelatedBy<T> {
+    /// The entities relating to this entity.
+    #[inline]
+    pub fn sources(&self) -> &[Entity] {
+        &self.sources
+    }
+}
+
+impl<T: 'static> fmt::Debug for RelatedBy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RelatedBy").field(&self.sources).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RelatedBy, Relation};
+    use crate::world::World;
+
+    struct Targets;
+    struct Likes;
+
+    #[test]
+    fn relations_are_cleaned_up_with_either_endpoint() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(Relation::<Targets>::new(target)).id();
+        let b = world
+            .spawn((Relation::<Targets>::new(target), Relation::<Likes>::new(a)))
+            .id();
+
+        let related_by = world.entity(target).get::<RelatedBy<Targets>>().unwrap();
+        assert_eq!(related_by.sources(), &[a, b]);
+        // Each kind of relation is tracked separately.
+        assert!(world.entity(target).get::<RelatedBy<Likes>>().is_none());
+        assert_eq!(
+            world.entity(a).get::<RelatedBy<Likes>>().unwrap().sources(),
+            &[b]
+        );
+
+        world.despawn(a);
+        let related_by = world.entity(target).get::<RelatedBy<Targets>>().unwrap();
+        assert_eq!(related_by.sources(), &[b]);
+        assert!(world.entity(b).get::<Relation<Likes>>().is_none());
+
+        world.despawn(target);
+        assert!(world.entity(b).get::<Relation<Targets>>().is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     entity::Entity,
-    query::{QueryData, QueryFilter},
+    query::{IterQueryData, QueryData, QueryFilter, QueryItem, QueryIter, ROQueryItem},
     relationship::{Relationship, RelationshipTarget},
     system::Query,
 };
@@ -143,6 +143,133 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
     }
 }
 
+impl<'w, 's, 'r, R: Relationship, D: QueryData, F: QueryFilter> Query<'w, 's, (&'r R, D), F> {
+    /// Iterates over the entities of this query paired with the targets of their `R`
+    /// [`Relationship`] in the `targets` query, yielding the items of both.
+    ///
+    /// Entities whose target doesn't match the `targets` query are skipped.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Nickname(&'static str);
+    /// fn print_parents(children: Query<(&ChildOf, &Nickname)>, parents: Query<&Nickname>) {
+    ///     for ((_, child), parent) in children.join_related(&parents) {
+    ///         println!("{} is a child of {}", child.0, parent.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(print_parents);
+    /// ```
+    pub fn join_related<'a, 'ts, OtherD: QueryData, OtherF: QueryFilter>(
+        &'a self,
+        targets: &'a Query<'_, 'ts, OtherD, OtherF>,
+    ) -> impl Iterator<
+        Item = (
+            ROQueryItem<'a, 's, (&'r R, D)>,
+            ROQueryItem<'a, 'ts, OtherD>,
+        ),
+    > {
+        self.iter().filter_map(move |source| {
+            let target = targets.get(source.0.get()).ok()?;
+            Some((source, target))
+        })
+    }
+
+    /// Returns a [`RelatedJoin`] over the entities of this query paired with the targets of
+    /// their `R` [`Relationship`] in the `targets` query, yielding mutable items of both.
+    ///
+    /// Several entities may share the same target, such as the children of a parent, so the items
+    /// of the targets are lent one at a time by [`RelatedJoin::fetch_next`] rather than by an
+    /// [`Iterator`]. The two queries can't overlap on the data they access mutably, which is
+    /// checked when the system is initialized like for any pair of queries.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Health(u32);
+    /// # #[derive(Component)]
+    /// # struct Shield(u32);
+    /// // Moves the damage taken by the children to the shield of their parent.
+    /// fn absorb_damage(
+    ///     mut children: Query<(&ChildOf, &mut Health)>,
+    ///     mut parents: Query<&mut Shield>,
+    /// ) {
+    ///     let mut join = children.join_related_mut(&mut parents);
+    ///     while let Some(((_, mut health), mut shield)) = join.fetch_next() {
+    ///         let absorbed = (100 - health.0).min(shield.0);
+    ///         health.0 += absorbed;
+    ///         shield.0 -= absorbed;
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(absorb_damage);
+    /// ```
+    pub fn join_related_mut<'a, 'ts, OtherD: QueryData, OtherF: QueryFilter>(
+        &'a mut self,
+        targets: &'a mut Query<'_, 'ts, OtherD, OtherF>,
+    ) -> RelatedJoin<'a, 's, 'r, 'ts, R, D, F, OtherD, OtherF>
+    where
+        D: IterQueryData,
+    {
+        RelatedJoin {
+            sources: self.iter_mut(),
+            targets: targets.reborrow(),
+        }
+    }
+}
+
+/// Pairs the items of a query with the items of the targets of their `R` [`Relationship`] in
+/// another query.
+///
+/// This `struct` is created by the [`Query::join_related_mut`] method.
+pub struct RelatedJoin<
+    'a,
+    's,
+    'r,
+    'ts,
+    R: Relationship,
+    D: IterQueryData,
+    F: QueryFilter,
+    OtherD: QueryData,
+    OtherF: QueryFilter,
+> {
+    sources: QueryIter<'a, 's, (&'r R, D), F>,
+    targets: Query<'a, 'ts, OtherD, OtherF>,
+}
+
+impl<
+        'a,
+        's,
+        'r,
+        'ts,
+        R: Relationship,
+        D: IterQueryData,
+        F: QueryFilter,
+        OtherD: QueryData,
+        OtherF: QueryFilter,
+    > RelatedJoin<'a, 's, 'r, 'ts, R, D, F, OtherD, OtherF>
+{
+    /// Returns the next item of the source query and the item of its target.
+    ///
+    /// The item of the target is borrowed until the next call, as another entity may share the
+    /// same target.
+    pub fn fetch_next(
+        &mut self,
+    ) -> Option<(QueryItem<'a, 's, (&'r R, D)>, QueryItem<'_, 'ts, OtherD>)> {
+        loop {
+            let source = self.sources.next()?;
+            let target = source.0.get();
+            // Checked first so that the item is only borrowed when it is returned.
+            if self.targets.contains(target) {
+                return self
+                    .targets
+                    .get_mut(target)
+                    .ok()
+                    .map(|target| (source, target));
+            }
+        }
+    }
+}
+
 /// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`].
 ///
 /// Traverses the hierarchy breadth-first.
@@ -270,3 +397,46 @@ where
         self.next
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::{ChildOf, Component, Query, World},
+        system::SystemState,
+    };
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    #[derive(Component)]
+    struct Shield(u32);
+
+    #[test]
+    fn join_related_pairs_sources_with_targets() {
+        let mut world = World::new();
+        let parent = world.spawn(Shield(30)).id();
+        let unshielded_parent = world.spawn_empty().id();
+        let children = [
+            world.spawn((ChildOf(parent), Health(80))).id(),
+            world.spawn((ChildOf(parent), Health(90))).id(),
+            world.spawn((ChildOf(unshielded_parent), Health(50))).id(),
+        ];
+
+        let mut state =
+            SystemState::<(Query<(&ChildOf, &mut Health)>, Query<&mut Shield>)>::new(&mut world);
+        let (mut sources, mut targets) = state.get_mut(&mut world).unwrap();
+        assert_eq!(sources.join_related(&targets).count(), 2);
+
+        let mut join = sources.join_related_mut(&mut targets);
+        while let Some(((_, mut health), mut shield)) = join.fetch_next() {
+            let absorbed = (100 - health.0).min(shield.0);
+            health.0 += absorbed;
+            shield.0 -= absorbed;
+        }
+
+        let health = children.map(|child| world.get::<Health>(child).unwrap().0);
+        // The first child took 20 points of the shield, the second the remaining 10.
+        assert_eq!(health, [100, 100, 50]);
+        assert_eq!(world.get::<Shield>(parent).unwrap().0, 0);
+    }
+}
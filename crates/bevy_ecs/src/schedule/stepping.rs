@@ -1,6 +1,9 @@
 use crate::{
     resource::Resource,
-    schedule::{InternedScheduleLabel, NodeId, Schedule, ScheduleLabel, SystemKey},
+    schedule::{
+        InternedScheduleLabel, InternedSystemSet, NodeId, Schedule, ScheduleLabel, SystemKey,
+        SystemSet,
+    },
     system::{IntoSystem, ResMut},
 };
 use alloc::vec::Vec;
@@ -64,10 +67,12 @@ struct Cursor {
     pub system: usize,
 }
 
-// Two methods of referring to Systems, via TypeId, or per-Schedule NodeId
+// Three methods of referring to Systems, via TypeId, per-Schedule NodeId, or
+// the SystemSet they belong to
 enum SystemIdentifier {
     Type(TypeId),
     Node(NodeId),
+    Set(InternedSystemSet),
 }
 
 /// Updates to [`Stepping::schedule_states`] that will be applied at the start
@@ -339,6 +344,74 @@ impl Stepping {
         self
     }
 
+    /// Add a breakpoint for every system in the system set
+    ///
+    /// Note: the systems are looked up the next time the [`Schedule`] runs,
+    /// so systems added to the set afterwards don't get a breakpoint.
+    pub fn set_breakpoint_set(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+    ) -> &mut Self {
+        self.updates.push(Update::SetBehavior(
+            schedule.intern(),
+            SystemIdentifier::Set(set.intern()),
+            SystemBehavior::Break,
+        ));
+        self
+    }
+
+    /// Clear any behavior set for the systems in the system set, including
+    /// their breakpoints
+    pub fn clear_breakpoint_set(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        set: impl SystemSet,
+    ) -> &mut Self {
+        self.updates.push(Update::ClearBehavior(
+            schedule.intern(),
+            SystemIdentifier::Set(set.intern()),
+        ));
+        self
+    }
+
+    /// Return the system instances with a breakpoint in the schedule
+    ///
+    /// NOTE: Breakpoints set by system type or system set are only listed once
+    /// the schedule has run with stepping enabled.
+    pub fn breakpoints(&self, schedule: impl ScheduleLabel) -> impl Iterator<Item = NodeId> + '_ {
+        self.schedule_states
+            .get(&schedule.intern())
+            .into_iter()
+            .flat_map(|state| {
+                state
+                    .behaviors
+                    .iter()
+                    .filter(|(_, behavior)| matches!(behavior, SystemBehavior::Break))
+                    .map(|(node_id, _)| *node_id)
+            })
+    }
+
+    /// Return the system instances of the schedule that were skipped the last
+    /// time it ran with stepping enabled, in the order they would have run
+    ///
+    /// This is empty while stepping is disabled.
+    pub fn last_skipped_systems(
+        &self,
+        schedule: impl ScheduleLabel,
+    ) -> impl Iterator<Item = NodeId> + '_ {
+        self.schedule_states
+            .get(&schedule.intern())
+            .into_iter()
+            .flat_map(|state| {
+                state
+                    .skipped
+                    .ones()
+                    .filter_map(|index| state.node_ids.get(index))
+                    .map(|key| NodeId::System(*key))
+            })
+    }
+
     /// Clear any behavior set for the system
     pub fn clear_system<Marker>(
         &mut self,
@@ -406,6 +479,9 @@ impl Stepping {
                 Update::SetAction(Action::RunAll) => {
                     self.action = Action::RunAll;
                     reset_cursor = true;
+                    for state in self.schedule_states.values_mut() {
+                        state.skipped.clear();
+                    }
                 }
                 Update::SetAction(action) => {
                     // This match block is really just to filter out invalid
@@ -569,6 +645,7 @@ impl Stepping {
             let (skip_list, _) = state.skipped_systems(schedule, 0, Action::Waiting);
             (skip_list, Some(cursor.system))
         };
+        state.skipped.clone_from(&skip_list);
 
         // update the stepping frame cursor based on if there are any systems
         // remaining to be run in the schedule
@@ -610,6 +687,14 @@ struct ScheduleState {
     /// [`ScheduleState::skipped_systems()`] is called
     behavior_updates: TypeIdHashMap<Option<SystemBehavior>>,
 
+    /// changes to the behavior of the systems in a system set that should be
+    /// applied the next time [`ScheduleState::skipped_systems()`] is called
+    set_behavior_updates: Vec<(InternedSystemSet, Option<SystemBehavior>)>,
+
+    /// the systems skipped the last time [`ScheduleState::skipped_systems()`]
+    /// was called
+    skipped: FixedBitSet,
+
     /// This field contains the first steppable system in the schedule.
     first: Option<usize>,
 }
@@ -628,6 +713,10 @@ impl ScheduleState {
             SystemIdentifier::Type(type_id) => {
                 self.behavior_updates.insert(type_id, Some(behavior));
             }
+            // Likewise, the systems in a set are only known by the `Schedule`.
+            SystemIdentifier::Set(set) => {
+                self.set_behavior_updates.push((set, Some(behavior)));
+            }
         }
     }
 
@@ -642,6 +731,9 @@ impl ScheduleState {
             SystemIdentifier::Type(type_id) => {
                 self.behavior_updates.insert(type_id, None);
             }
+            SystemIdentifier::Set(set) => {
+                self.set_behavior_updates.push((set, None));
+            }
         }
     }
 
@@ -649,6 +741,7 @@ impl ScheduleState {
     fn clear_behaviors(&mut self) {
         self.behaviors.clear();
         self.behavior_updates.clear();
+        self.set_behavior_updates.clear();
         self.first = None;
     }
 
@@ -674,6 +767,22 @@ impl ScheduleState {
         }
         self.behavior_updates.clear();
 
+        for (set, behavior) in self.set_behavior_updates.drain(..) {
+            let Ok(keys) = schedule.graph().systems_in_set(set) else {
+                warn!(
+                    "system set {set:?} not found in schedule {:?}",
+                    schedule.label()
+                );
+                continue;
+            };
+            for key in keys {
+                match behavior {
+                    None => self.behaviors.remove(&NodeId::System(*key)),
+                    Some(behavior) => self.behaviors.insert(NodeId::System(*key), behavior),
+                };
+            }
+        }
+
         #[cfg(test)]
         debug!("apply_updates(): {:?}", self.behaviors);
     }
@@ -695,7 +804,7 @@ impl ScheduleState {
         // Now that we have the schedule, apply any pending system behavior
         // updates.  The schedule is required to map from system `TypeId` to
         // `NodeId`.
-        if !self.behavior_updates.is_empty() {
+        if !self.behavior_updates.is_empty() || !self.set_behavior_updates.is_empty() {
             self.apply_behavior_updates(schedule);
         }
 
@@ -821,7 +930,10 @@ impl ScheduleState {
 #[expect(clippy::print_stdout, reason = "Allowed in tests.")]
 mod tests {
     use super::*;
-    use crate::{prelude::*, schedule::ScheduleLabel};
+    use crate::{
+        prelude::*,
+        schedule::{ScheduleLabel, SystemSet},
+    };
     use alloc::{format, vec};
     use slotmap::SlotMap;
     use std::println;
@@ -841,6 +953,9 @@ mod tests {
     #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
     struct TestScheduleD;
 
+    #[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSet;
+
     fn first_system() {}
     fn second_system() {}
     fn third_system() {}
@@ -1166,6 +1281,66 @@ mod tests {
         assert_schedule_runs!(&schedule, &mut stepping, third_system);
     }
 
+    #[test]
+    fn continue_breakpoint_set() {
+        let mut world = World::new();
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.add_systems(
+            (
+                first_system,
+                (second_system, third_system).chain().in_set(TestSet),
+            )
+                .chain(),
+        );
+        schedule.initialize(&mut world).unwrap();
+
+        let mut stepping = Stepping::new();
+        stepping
+            .add_schedule(TestSchedule)
+            .enable()
+            .set_breakpoint_set(TestSchedule, TestSet)
+            .continue_frame();
+
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+        assert_eq!(stepping.breakpoints(TestSchedule).count(), 2);
+        stepping.continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, second_system);
+        stepping.continue_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, third_system);
+
+        stepping
+            .clear_breakpoint_set(TestSchedule, TestSet)
+            .continue_frame();
+        assert_schedule_runs!(
+            &schedule,
+            &mut stepping,
+            first_system,
+            second_system,
+            third_system
+        );
+        assert_eq!(stepping.breakpoints(TestSchedule).count(), 0);
+    }
+
+    #[test]
+    fn last_skipped_systems() {
+        let (schedule, _world) = setup();
+
+        let mut stepping = Stepping::new();
+        stepping.add_schedule(TestSchedule).enable().step_frame();
+        assert_schedule_runs!(&schedule, &mut stepping, first_system);
+
+        let second_system = NodeId::System(schedule.executable().system_ids[1]);
+        assert_eq!(
+            stepping
+                .last_skipped_systems(TestSchedule)
+                .collect::<Vec<_>>(),
+            vec![second_system]
+        );
+
+        stepping.disable().next_frame();
+        assert_eq!(stepping.last_skipped_systems(TestSchedule).count(), 0);
+    }
+
     #[test]
     fn clear_breakpoint() {
         let (schedule, _world) = setup();
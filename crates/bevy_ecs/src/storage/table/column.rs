@@ -1,6 +1,6 @@
 use super::*;
 use crate::{
-    change_detection::MaybeLocation,
+    change_detection::{LastChangedTick, MaybeLocation},
     storage::{blob_array::BlobArray, thin_array_ptr::ThinArrayPtr},
 };
 use core::{mem::needs_drop, panic::Location};
@@ -27,6 +27,7 @@ pub struct Column {
     pub(super) added_ticks: ThinArrayPtr<UnsafeCell<Tick>>,
     pub(super) changed_ticks: ThinArrayPtr<UnsafeCell<Tick>>,
     pub(super) changed_by: MaybeLocation<ThinArrayPtr<UnsafeCell<&'static Location<'static>>>>,
+    pub(super) last_changed: Option<LastChangedTick>,
 }
 
 impl Column {
@@ -42,6 +43,9 @@ impl Column {
             added_ticks: ThinArrayPtr::with_capacity(capacity),
             changed_ticks: ThinArrayPtr::with_capacity(capacity),
             changed_by: MaybeLocation::new_with(|| ThinArrayPtr::with_capacity(capacity)),
+            last_changed: component_info
+                .tracks_archetype_changes()
+                .then(LastChangedTick::default),
         }
     }
 
@@ -180,6 +184,9 @@ impl Column {
         self.data.initialize_unchecked(row.index(), data);
         *self.added_ticks.get_unchecked_mut(row.index()).get_mut() = tick;
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = tick;
+        if let Some(last_changed) = &self.last_changed {
+            last_changed.mark(tick, tick);
+        }
         self.changed_by
             .as_mut()
             .map(|changed_by| changed_by.get_unchecked_mut(row.index()).get_mut())
@@ -202,6 +209,9 @@ impl Column {
     ) {
         self.data.replace_unchecked(row.index(), data);
         *self.changed_ticks.get_unchecked_mut(row.index()).get_mut() = change_tick;
+        if let Some(last_changed) = &self.last_changed {
+            last_changed.mark(change_tick, change_tick);
+        }
         self.changed_by
             .as_mut()
             .map(|changed_by| changed_by.get_unchecked_mut(row.index()).get_mut())
@@ -210,6 +220,7 @@ impl Column {
 
     /// Removes the element from `other` at `src_row` and inserts it
     /// into the current column to initialize the values at `dst_row`.
+    /// `change_tick` is the current tick of the world.
     /// Does not do any bounds checking.
     ///
     /// # Safety
@@ -225,6 +236,7 @@ impl Column {
         other_last_element_index: usize,
         src_row: TableRow,
         dst_row: TableRow,
+        change_tick: Tick,
     ) {
         debug_assert!(self.data.layout() == other.data.layout());
         // Init the data
@@ -239,9 +251,12 @@ impl Column {
         self.added_ticks
             .initialize_unchecked(dst_row.index(), added_tick);
         // Init changed_ticks
-        let changed_tick = other
+        let mut changed_tick = other
             .changed_ticks
             .swap_remove_unchecked(src_row.index(), other_last_element_index);
+        if let Some(last_changed) = &self.last_changed {
+            last_changed.mark(*changed_tick.get_mut(), change_tick);
+        }
         self.changed_ticks
             .initialize_unchecked(dst_row.index(), changed_tick);
         self.changed_by.as_mut().zip(other.changed_by.as_mut()).map(
@@ -259,6 +274,9 @@ impl Column {
     /// `len` is the actual length of this column
     #[inline]
     pub(crate) unsafe fn check_change_ticks(&mut self, len: usize, check: CheckChangeTicks) {
+        if let Some(last_changed) = &mut self.last_changed {
+            last_changed.check_tick(check);
+        }
         for i in 0..len {
             // SAFETY:
             // - `i` < `len`
@@ -337,6 +355,13 @@ impl Column {
         unsafe { self.added_ticks.as_slice(len) }
     }
 
+    /// Get the most recent changed [`Tick`] of the components in this [`Column`], if the component
+    /// [tracks archetype changes](crate::component::Component::TRACK_ARCHETYPE_CHANGES).
+    #[inline]
+    pub(crate) fn last_changed(&self) -> Option<&LastChangedTick> {
+        self.last_changed.as_ref()
+    }
+
     /// Get a slice to the changed [`ticks`](Tick) in this [`Column`].
     ///
     /// # Safety
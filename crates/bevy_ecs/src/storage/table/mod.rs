@@ -747,11 +747,13 @@ impl Tables {
     /// - If any components were added,
     ///   the returned row will be uninitialized in the corresponding columns
     ///   and must have valid values written to those columns immediately.
+    /// - `change_tick` must be the current tick of the world.
     pub(crate) unsafe fn move_row<const DROP: bool>(
         &mut self,
         old_table_id: TableId,
         new_table_id: TableId,
         row: TableRow,
+        change_tick: Tick,
     ) -> TableMoveResult<'_> {
         #[cfg(debug_assertions)]
         debug_assert!(old_table_id != new_table_id);
@@ -793,7 +795,13 @@ impl Tables {
                 //   or by a previous caller.
                 // - `dst_row` was just allocated and has not been written to.
                 unsafe {
-                    dst_column.initialize_from_unchecked(src_column, last_index, row, dst_row);
+                    dst_column.initialize_from_unchecked(
+                        src_column,
+                        last_index,
+                        row,
+                        dst_row,
+                        change_tick,
+                    );
                 }
             } else {
                 // SAFETY:
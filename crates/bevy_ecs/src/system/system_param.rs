@@ -708,7 +708,7 @@ unsafe impl<'a, T: Resource> SystemParam for Res<'a, T> {
         world: UnsafeWorldCell<'w>,
         change_tick: Tick,
     ) -> Result<Self::Item<'w, 's>, SystemParamValidationError> {
-        let (ptr, ticks) = world.get_resource_with_ticks(component_id).ok_or_else(|| {
+        let (ptr, ticks, _) = world.get_resource_with_ticks(component_id).ok_or_else(|| {
             SystemParamValidationError::invalid::<Self>("Resource does not exist")
         })?;
         Ok(Res {
@@ -774,6 +774,7 @@ unsafe impl<'a, T: Resource<Mutability = Mutable>> SystemParam for ResMut<'a, T>
                 added: value.ticks.added,
                 changed: value.ticks.changed,
                 changed_by: value.ticks.changed_by,
+                last_changed: value.ticks.last_changed,
                 last_run: system_meta.last_run,
                 this_run: change_tick,
             },
@@ -1406,7 +1407,12 @@ unsafe impl<'a, T: 'static> SystemParam for NonSendMut<'a, T> {
         })?;
         Ok(NonSendMut {
             value: ptr.assert_unique().deref_mut(),
-            ticks: ComponentTicksMut::from_tick_cells(ticks, system_meta.last_run, change_tick),
+            ticks: ComponentTicksMut::from_tick_cells(
+                ticks,
+                None,
+                system_meta.last_run,
+                change_tick,
+            ),
         })
     }
 }
@@ -164,7 +164,7 @@ impl<'w, 's> FilteredResources<'w, 's> {
         }
 
         // SAFETY: We have read access to this resource
-        let (value, ticks) = unsafe { self.world.get_resource_with_ticks(component_id) }
+        let (value, ticks, _) = unsafe { self.world.get_resource_with_ticks(component_id) }
             .ok_or(ResourceFetchError::DoesNotExist(component_id))?;
 
         Ok(Ref {
@@ -487,8 +487,9 @@ impl<'w, 's> FilteredResourcesMut<'w, 's> {
         }
 
         // SAFETY: We have read access to this resource
-        let (value, ticks) = unsafe { self.world.get_resource_with_ticks(component_id) }
-            .ok_or(ResourceFetchError::DoesNotExist(component_id))?;
+        let (value, ticks, last_changed) =
+            unsafe { self.world.get_resource_with_ticks(component_id) }
+                .ok_or(ResourceFetchError::DoesNotExist(component_id))?;
 
         // SAFETY: Resource is present, so its component info exists
         let mutable = unsafe { self.world.components().get_info_unchecked(component_id) }.mutable();
@@ -501,7 +502,12 @@ impl<'w, 's> FilteredResourcesMut<'w, 's> {
             value: unsafe { value.assert_unique() },
             // SAFETY: We have exclusive access to the underlying storage.
             ticks: unsafe {
-                ComponentTicksMut::from_tick_cells(ticks, self.last_run, self.this_run)
+                ComponentTicksMut::from_tick_cells(
+                    ticks,
+                    last_changed,
+                    self.last_run,
+                    self.this_run,
+                )
             },
         })
     }
@@ -2933,6 +2933,7 @@ impl World {
                 added: &mut ticks.added,
                 changed: &mut ticks.changed,
                 changed_by: guard.caller.as_mut(),
+                last_changed: None,
                 last_run: last_change_tick,
                 this_run: change_tick,
             },
@@ -3278,6 +3279,7 @@ impl World {
         tables.check_change_ticks(check);
         sparse_sets.check_change_ticks(check);
         non_sends.check_change_ticks(check);
+        self.archetypes.check_change_ticks(check);
         self.entities.check_change_ticks(check);
 
         if let Some(mut schedules) = self.get_resource_mut::<Schedules>() {
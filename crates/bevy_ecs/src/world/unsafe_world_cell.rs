@@ -5,8 +5,8 @@ use crate::{
     archetype::{Archetype, Archetypes},
     bundle::Bundles,
     change_detection::{
-        ComponentTickCells, ComponentTicks, ComponentTicksMut, ComponentTicksRef, MaybeLocation,
-        MutUntyped, Tick,
+        ComponentTickCells, ComponentTicks, ComponentTicksMut, ComponentTicksRef, LastChangedTick,
+        MaybeLocation, MutUntyped, Tick,
     },
     component::{ComponentId, Components, Mutable, StorageType},
     entity::{
@@ -18,7 +18,7 @@ use crate::{
     prelude::Component,
     query::{DebugCheckedUnwrap, QueryAccessError, ReleaseStateQueryData, SingleEntityQueryData},
     resource::{Resource, ResourceEntities},
-    storage::{ComponentSparseSet, Storages, Table},
+    storage::{Column, ComponentSparseSet, Storages, Table},
     world::RawCommandQueue,
 };
 use bevy_platform::sync::atomic::Ordering;
@@ -442,7 +442,7 @@ impl<'w> UnsafeWorldCell<'w> {
 
         // SAFETY: caller ensures `self` has permission to access the resource
         // caller also ensures that no mutable reference to the resource exists
-        let (ptr, ticks) = unsafe { self.get_resource_with_ticks(component_id)? };
+        let (ptr, ticks, _) = unsafe { self.get_resource_with_ticks(component_id)? };
 
         // SAFETY: `component_id` was obtained from the type ID of `R`
         let value = unsafe { ptr.deref::<R>() };
@@ -632,7 +632,7 @@ impl<'w> UnsafeWorldCell<'w> {
             // SAFETY: This function has exclusive access to the world so nothing aliases `ticks`.
             // - index is in-bounds because the column is initialized and non-empty
             // - no other reference to the ticks of the same row can exist at the same time
-            unsafe { ComponentTicksMut::from_tick_cells(ticks, self.last_change_tick(), change_tick) };
+            unsafe { ComponentTicksMut::from_tick_cells(ticks, None, self.last_change_tick(), change_tick) };
 
         Some(MutUntyped {
             // SAFETY: This function has exclusive access to the world so nothing aliases `ptr`.
@@ -650,7 +650,7 @@ impl<'w> UnsafeWorldCell<'w> {
     pub(crate) unsafe fn get_resource_with_ticks(
         self,
         component_id: ComponentId,
-    ) -> Option<(Ptr<'w>, ComponentTickCells<'w>)> {
+    ) -> Option<(Ptr<'w>, ComponentTickCells<'w>, Option<&'w LastChangedTick>)> {
         // SAFETY: We have permission to access the resource of `component_id`.
         let entity = unsafe { self.resource_entities() }.get(component_id)?;
         let storage_type = self.components().get_info(component_id)?.storage_type();
@@ -870,7 +870,7 @@ impl<'w> UnsafeEntityCell<'w> {
                 self.entity,
                 self.location,
             )
-            .map(|(value, cells)| Ref {
+            .map(|(value, cells, _)| Ref {
                 // SAFETY: returned component is of type T
                 value: value.deref::<T>(),
                 ticks: ComponentTicksRef::from_tick_cells(cells, last_change_tick, change_tick),
@@ -1011,10 +1011,15 @@ impl<'w> UnsafeEntityCell<'w> {
                 self.entity,
                 self.location,
             )
-            .map(|(value, cells)| Mut {
+            .map(|(value, cells, last_changed)| Mut {
                 // SAFETY: returned component is of type T
                 value: value.assert_unique().deref_mut::<T>(),
-                ticks: ComponentTicksMut::from_tick_cells(cells, last_change_tick, change_tick),
+                ticks: ComponentTicksMut::from_tick_cells(
+                    cells,
+                    last_changed,
+                    last_change_tick,
+                    change_tick,
+                ),
             })
         }
     }
@@ -1133,10 +1138,15 @@ impl<'w> UnsafeEntityCell<'w> {
                 self.entity,
                 self.location,
             )
-            .map(|(value, cells)| MutUntyped {
+            .map(|(value, cells, last_changed)| MutUntyped {
                 // SAFETY: world access validated by caller and ties world lifetime to `MutUntyped` lifetime
                 value: value.assert_unique(),
-                ticks: ComponentTicksMut::from_tick_cells(cells, self.last_run, self.this_run),
+                ticks: ComponentTicksMut::from_tick_cells(
+                    cells,
+                    last_changed,
+                    self.last_run,
+                    self.this_run,
+                ),
             })
             .ok_or(GetEntityMutByIdError::ComponentNotFound)
         }
@@ -1176,10 +1186,15 @@ impl<'w> UnsafeEntityCell<'w> {
                 self.entity,
                 self.location,
             )
-            .map(|(value, cells)| MutUntyped {
+            .map(|(value, cells, last_changed)| MutUntyped {
                 // SAFETY: world access validated by caller and ties world lifetime to `MutUntyped` lifetime
                 value: value.assert_unique(),
-                ticks: ComponentTicksMut::from_tick_cells(cells, self.last_run, self.this_run),
+                ticks: ComponentTicksMut::from_tick_cells(
+                    cells,
+                    last_changed,
+                    self.last_run,
+                    self.this_run,
+                ),
             })
             .ok_or(GetEntityMutByIdError::ComponentNotFound)
         }
@@ -1273,7 +1288,9 @@ unsafe fn get_component(
     }
 }
 
-/// Get an untyped pointer to a particular [`Component`] and its [`ComponentTicks`]
+/// Get an untyped pointer to a particular [`Component`] and its [`ComponentTicks`], along with
+/// the most recent changed tick of its storage if the component
+/// [tracks archetype changes](Component::TRACK_ARCHETYPE_CHANGES).
 ///
 /// # Safety
 /// - `location` must refer to an archetype that contains `entity`
@@ -1287,7 +1304,7 @@ unsafe fn get_component_and_ticks(
     storage_type: StorageType,
     entity: Entity,
     location: EntityLocation,
-) -> Option<(Ptr<'_>, ComponentTickCells<'_>)> {
+) -> Option<(Ptr<'_>, ComponentTickCells<'_>, Option<&'_ LastChangedTick>)> {
     match storage_type {
         StorageType::Table => {
             // SAFETY: caller upholds aliasing rules
@@ -1308,12 +1325,19 @@ unsafe fn get_component_and_ticks(
                             .get_changed_by(component_id, location.table_row)
                             .map(|changed_by| changed_by.debug_checked_unwrap()),
                     },
+                    table
+                        .get_column(component_id)
+                        .and_then(Column::last_changed),
                 )
             })
         }
         StorageType::SparseSet => {
             // SAFETY: caller upholds aliasing rules
-            unsafe { world.fetch_sparse_set(component_id) }?.get_with_ticks(entity)
+            let (value, cells) =
+                unsafe { world.fetch_sparse_set(component_id) }?.get_with_ticks(entity)?;
+            let last_changed =
+                world.archetypes()[location.archetype_id].sparse_set_last_changed(component_id);
+            Some((value, cells, last_changed))
         }
     }
 }
@@ -6,8 +6,8 @@ use bevy_ecs::message::MessageWriter;
 use bevy_ecs::prelude::Commands;
 use bevy_ecs::system::ResMut;
 use bevy_input::gamepad::{
-    GamepadConnection, GamepadConnectionEvent, RawGamepadAxisChangedEvent,
-    RawGamepadButtonChangedEvent, RawGamepadEvent,
+    GamepadConnection, GamepadConnectionEvent, GamepadGuid, GamepadHaptics,
+    RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent, RawGamepadEvent,
 };
 use gilrs::{ev::filter::axis_dpad_to_button, EventType, Filter};
 
@@ -20,7 +20,14 @@ pub fn gilrs_event_startup_system(
     gilrs.with(|gilrs| {
         for (id, gamepad) in gilrs.gamepads() {
             // Create entity and add to mapping
-            let entity = commands.spawn_empty().id();
+            let entity = commands
+                .spawn((
+                    GamepadGuid(gamepad.uuid()),
+                    GamepadHaptics {
+                        rumble: gamepad.is_ff_supported(),
+                    },
+                ))
+                .id();
             gamepads.id_to_entity.insert(id, entity);
             gamepads.entity_to_id.insert(entity, id);
             events.write(GamepadConnectionEvent {
@@ -56,6 +63,12 @@ pub fn gilrs_event_system(
                         gamepads.entity_to_id.insert(entity, gilrs_event.id);
                         entity
                     });
+                    commands.entity(entity).try_insert((
+                        GamepadGuid(pad.uuid()),
+                        GamepadHaptics {
+                            rumble: pad.is_ff_supported(),
+                        },
+                    ));
 
                     let event = GamepadConnectionEvent::new(
                         entity,
@@ -74,6 +87,7 @@ pub fn gilrs_event_system(
                         .get(&gilrs_event.id)
                         .copied()
                         .expect("mapping should exist from connection");
+                    commands.entity(gamepad).try_remove::<GamepadHaptics>();
                     let event =
                         GamepadConnectionEvent::new(gamepad, GamepadConnection::Disconnected);
                     events.write(event.clone().into());
@@ -1,7 +1,7 @@
 //! Handle user specified rumble request events.
 use crate::{Gilrs, GilrsGamepads};
 use bevy_ecs::prelude::{MessageReader, Res, ResMut, Resource};
-use bevy_input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy_input::gamepad::{GamepadRumble, GamepadRumbleIntensity, GamepadRumbleRequest};
 use bevy_platform::cell::SyncCell;
 use bevy_platform::collections::HashMap;
 use bevy_time::{Real, Time};
@@ -27,6 +27,55 @@ struct RunningRumble {
     effect: SyncCell<ff::Effect>,
 }
 
+/// The effect of a motor of a [`RunningEnvelopeRumble`].
+struct MotorEffect {
+    /// The gain of the effect, following the envelope of the motor
+    gain: f32,
+    effect: SyncCell<ff::Effect>,
+}
+
+/// A rumble effect following the envelopes of a [`GamepadRumble`].
+///
+/// Each motor has its own effect, whose gain is updated every frame.
+struct RunningEnvelopeRumble {
+    /// Duration from app startup when this effect started
+    start: Duration,
+    rumble: GamepadRumble,
+    strong_motor: Option<MotorEffect>,
+    weak_motor: Option<MotorEffect>,
+}
+
+impl RunningEnvelopeRumble {
+    fn deadline(&self) -> Duration {
+        self.start + self.rumble.duration
+    }
+
+    fn update(&mut self, current_time: Duration) -> Result<(), ff::Error> {
+        let elapsed = current_time.saturating_sub(self.start);
+        let GamepadRumble {
+            duration,
+            strong_motor_envelope,
+            weak_motor_envelope,
+            ..
+        } = self.rumble;
+        let motors = [
+            (&mut self.strong_motor, strong_motor_envelope),
+            (&mut self.weak_motor, weak_motor_envelope),
+        ];
+        for (motor, envelope) in motors {
+            let Some(motor) = motor else {
+                continue;
+            };
+            let gain = envelope.sample(elapsed, duration);
+            if gain != motor.gain {
+                motor.gain = gain;
+                motor.effect.get().set_gain(gain)?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Error, Debug)]
 enum RumbleError {
     #[error("gamepad not found")]
@@ -41,6 +90,8 @@ pub(crate) struct RunningRumbleEffects {
     /// If multiple rumbles are running at the same time, their resulting rumble
     /// will be the saturated sum of their strengths up until [`u16::MAX`]
     rumbles: HashMap<GamepadId, Vec<RunningRumble>>,
+    /// The rumbles following envelopes, which add up with the other rumbles
+    envelope_rumbles: HashMap<GamepadId, Vec<RunningEnvelopeRumble>>,
 }
 
 /// gilrs uses magnitudes from 0 to [`u16::MAX`], while ours go from `0.0` to `1.0` ([`f32`])
@@ -79,6 +130,31 @@ fn get_base_effects(
     effects
 }
 
+/// Creates the effect of a single motor of an envelope rumble, with its peak `intensity` and
+/// initial `gain`.
+fn motor_effect(
+    gilrs: &mut gilrs::Gilrs,
+    gamepad_id: GamepadId,
+    intensity: GamepadRumbleIntensity,
+    gain: f32,
+    duration: Duration,
+) -> Result<Option<MotorEffect>, ff::Error> {
+    let Some(base_effect) = get_base_effects(intensity, duration).pop() else {
+        return Ok(None);
+    };
+    let effect = ff::EffectBuilder::new()
+        .add_effect(base_effect)
+        .repeat(Repeat::For(duration.into()))
+        .gain(gain)
+        .gamepads(&[gamepad_id])
+        .finish(gilrs)?;
+    effect.play()?;
+    Ok(Some(MotorEffect {
+        gain,
+        effect: SyncCell::new(effect),
+    }))
+}
+
 fn handle_rumble_request(
     running_rumbles: &mut RunningRumbleEffects,
     gilrs: &mut gilrs::Gilrs,
@@ -97,6 +173,7 @@ fn handle_rumble_request(
         GamepadRumbleRequest::Stop { .. } => {
             // `ff::Effect` uses RAII, dropping = deactivating
             running_rumbles.rumbles.remove(&gamepad_id);
+            running_rumbles.envelope_rumbles.remove(&gamepad_id);
         }
         GamepadRumbleRequest::Add {
             duration,
@@ -120,6 +197,39 @@ fn handle_rumble_request(
                 effect: SyncCell::new(effect),
             });
         }
+        GamepadRumbleRequest::AddEnvelope { rumble, .. } => {
+            let GamepadRumble {
+                duration,
+                intensity,
+                strong_motor_envelope,
+                weak_motor_envelope,
+            } = rumble;
+            let strong_motor = motor_effect(
+                gilrs,
+                gamepad_id,
+                GamepadRumbleIntensity::strong_motor(intensity.strong_motor),
+                strong_motor_envelope.sample(Duration::ZERO, duration),
+                duration,
+            )?;
+            let weak_motor = motor_effect(
+                gilrs,
+                gamepad_id,
+                GamepadRumbleIntensity::weak_motor(intensity.weak_motor),
+                weak_motor_envelope.sample(Duration::ZERO, duration),
+                duration,
+            )?;
+
+            running_rumbles
+                .envelope_rumbles
+                .entry(gamepad_id)
+                .or_default()
+                .push(RunningEnvelopeRumble {
+                    start: current_time,
+                    rumble,
+                    strong_motor,
+                    weak_motor,
+                });
+        }
     }
 
     Ok(())
@@ -141,6 +251,19 @@ pub(crate) fn play_gilrs_rumble(
         running_rumbles
             .rumbles
             .retain(|_gamepad, rumbles| !rumbles.is_empty());
+        for rumbles in running_rumbles.envelope_rumbles.values_mut() {
+            rumbles.retain(|rumble| rumble.deadline() >= current_time);
+        }
+        running_rumbles
+            .envelope_rumbles
+            .retain(|_gamepad, rumbles| !rumbles.is_empty());
+
+        // Follow the envelopes of the running effects.
+        for rumble in running_rumbles.envelope_rumbles.values_mut().flatten() {
+            if let Err(err) = rumble.update(current_time) {
+                warn!("Tried to update the envelope of a rumble but an error occurred: {err}");
+            }
+        }
 
         // Add new effects.
         for rumble in requests.read().cloned() {
@@ -22,6 +22,8 @@
 // Required to make proc macros work in bevy itself.
 extern crate self as bevy_gizmos;
 
+extern crate alloc;
+
 pub mod aabb;
 pub mod arcs;
 pub mod arrows;
@@ -36,7 +38,9 @@ pub mod grid;
 pub mod primitives;
 pub mod retained;
 pub mod rounded_box;
+pub mod shared;
 mod simplex_stroke_font;
+pub mod splines;
 pub mod stroke_text;
 pub mod transform_gizmo;
 
@@ -67,6 +71,7 @@ pub mod prelude {
         global::gizmo,
         primitives::{dim2::GizmoPrimitive2d, dim3::GizmoPrimitive3d},
         retained::Gizmo,
+        shared::{GizmoWriter, SharedGizmos},
         AppGizmoBuilder, GizmoAsset,
     };
 
@@ -96,6 +101,7 @@ use bevy_utils::TypeIdIndexMap;
 use config::{DefaultGizmoConfigGroup, GizmoConfig, GizmoConfigGroup, GizmoConfigStore};
 use core::{any::TypeId, marker::PhantomData, mem};
 use gizmos::{GizmoStorage, Swap};
+use shared::{flush_shared_gizmos, SharedGizmos};
 
 #[cfg(feature = "bevy_mesh")]
 use crate::skinned_mesh_bounds::SkinnedMeshBoundsGizmoPlugin;
@@ -159,6 +165,7 @@ impl AppGizmoBuilder for App {
         self.init_resource::<GizmoStorage<Config, ()>>()
             .init_resource::<GizmoStorage<Config, Fixed>>()
             .init_resource::<GizmoStorage<Config, Swap<Fixed>>>()
+            .init_resource::<SharedGizmos<Config>>()
             .add_systems(
                 RunFixedMainLoop,
                 start_gizmo_context::<Config, Fixed>
@@ -175,6 +182,7 @@ impl AppGizmoBuilder for App {
                 Last,
                 (
                     propagate_gizmos::<Config, Fixed>.before(GizmoMeshSystems),
+                    flush_shared_gizmos::<Config>.before(GizmoMeshSystems),
                     update_gizmo_meshes::<Config>.in_set(GizmoMeshSystems),
                 ),
             );
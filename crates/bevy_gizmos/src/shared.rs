@@ -0,0 +1,250 @@
+//! Gizmos recorded outside of the systems, like from async tasks or other threads.
+//!
+//! Unlike [`Gizmos`](crate::gizmos::Gizmos), which are drawn for the frame they are recorded in,
+//! the gizmos of a [`GizmoWriter`] are only published when [`GizmoWriter::submit`] is called, and
+//! stay drawn until the next submission. A background task running slower than the frame rate,
+//! like a path finder or a physics step on its own thread, can thus draw a complete picture
+//! every time it finishes its work without flickering in the frames in between.
+
+use alloc::{collections::BTreeMap, sync::Arc};
+use core::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use bevy_ecs::{
+    resource::Resource,
+    system::{Res, ResMut},
+};
+
+use crate::{
+    config::{DefaultGizmoConfigGroup, GizmoConfigGroup},
+    gizmos::{GizmoBuffer, GizmoStorage},
+};
+
+/// A thread-safe entry point to draw gizmos of the `Config` group from outside of the systems.
+///
+/// Clone this resource into the tasks that need to draw, and create a [`GizmoWriter`] in each of
+/// them.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_gizmos::shared::SharedGizmos;
+/// # use bevy_math::prelude::*;
+/// # use bevy_color::palettes::basic::RED;
+/// fn start_path_finding(shared_gizmos: Res<SharedGizmos>) {
+///     let mut writer = shared_gizmos.writer();
+///     std::thread::spawn(move || {
+///         for step in 0..10 {
+///             writer.line(Vec3::ZERO, Vec3::X * step as f32, RED);
+///             // The lines drawn so far stay visible until the next step is submitted.
+///             writer.submit();
+///         }
+///     });
+/// }
+/// ```
+#[derive(Resource)]
+pub struct SharedGizmos<Config: GizmoConfigGroup = DefaultGizmoConfigGroup>(
+    Arc<Mutex<SharedGizmosState<Config>>>,
+);
+
+struct SharedGizmosState<Config: GizmoConfigGroup> {
+    /// The number of times the shared gizmos have been flushed, used to tag the submissions.
+    frame: u64,
+    next_writer: u64,
+    submissions: BTreeMap<u64, Submission<Config>>,
+}
+
+struct Submission<Config: GizmoConfigGroup> {
+    buffer: GizmoBuffer<Config, ()>,
+    frame: u64,
+    lifetime: Option<u32>,
+}
+
+impl<Config: GizmoConfigGroup> Default for SharedGizmos<Config> {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(SharedGizmosState {
+            frame: 0,
+            next_writer: 0,
+            submissions: BTreeMap::new(),
+        })))
+    }
+}
+
+impl<Config: GizmoConfigGroup> Clone for SharedGizmos<Config> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<Config: GizmoConfigGroup> SharedGizmos<Config> {
+    /// Creates a new writer, whose submissions replace each other but not those of the other
+    /// writers.
+    pub fn writer(&self) -> GizmoWriter<Config> {
+        let id = {
+            let mut state = self.lock();
+            state.next_writer += 1;
+            state.next_writer
+        };
+        GizmoWriter {
+            shared: self.clone(),
+            id,
+            lifetime: None,
+            buffer: GizmoBuffer::new(),
+        }
+    }
+
+    /// The current frame, as counted by the shared gizmos, which the submissions are tagged with.
+    pub fn frame(&self) -> u64 {
+        self.lock().frame
+    }
+
+    fn lock(&self) -> MutexGuard<'_, SharedGizmosState<Config>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Advances the frame, drops the expired submissions and appends the others to `storage`.
+    pub(crate) fn flush_into(&self, storage: &mut GizmoStorage<Config, ()>) {
+        let mut state = self.lock();
+        state.frame += 1;
+        let frame = state.frame;
+        state.submissions.retain(|_, submission| {
+            submission
+                .lifetime
+                .is_none_or(|lifetime| frame - submission.frame <= u64::from(lifetime))
+        });
+        for submission in state.submissions.values() {
+            let buffer = &submission.buffer;
+            storage.list_positions.extend(&buffer.list_positions);
+            storage.list_colors.extend(&buffer.list_colors);
+            storage.strip_positions.extend(&buffer.strip_positions);
+            storage.strip_colors.extend(&buffer.strip_colors);
+        }
+    }
+}
+
+/// Records gizmos from any thread, then publishes them at once with [`GizmoWriter::submit`].
+///
+/// The writer dereferences to a [`GizmoBuffer`], which has the same drawing methods as
+/// [`Gizmos`](crate::gizmos::Gizmos). Dropping the writer removes its last submission, unless it
+/// was given a lifetime with [`GizmoWriter::with_lifetime`], in which case the submission expires
+/// on its own.
+pub struct GizmoWriter<Config: GizmoConfigGroup = DefaultGizmoConfigGroup> {
+    shared: SharedGizmos<Config>,
+    id: u64,
+    lifetime: Option<u32>,
+    buffer: GizmoBuffer<Config, ()>,
+}
+
+impl<Config: GizmoConfigGroup> GizmoWriter<Config> {
+    /// Only draws each submission for `frames` frames, even if no new submission replaces it.
+    pub fn with_lifetime(mut self, frames: u32) -> Self {
+        self.lifetime = Some(frames);
+        self
+    }
+
+    /// Publishes the gizmos recorded since the last submission, replacing the previous ones of
+    /// this writer, and starts a new recording.
+    pub fn submit(&mut self) {
+        let buffer = core::mem::take(&mut self.buffer);
+        let mut state = self.shared.lock();
+        let frame = state.frame;
+        state.submissions.insert(
+            self.id,
+            Submission {
+                buffer,
+                frame,
+                lifetime: self.lifetime,
+            },
+        );
+    }
+
+    /// Stops drawing the last submission of this writer.
+    pub fn clear_submitted(&mut self) {
+        self.shared.lock().submissions.remove(&self.id);
+    }
+}
+
+impl<Config: GizmoConfigGroup> Deref for GizmoWriter<Config> {
+    type Target = GizmoBuffer<Config, ()>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<Config: GizmoConfigGroup> DerefMut for GizmoWriter<Config> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl<Config: GizmoConfigGroup> Drop for GizmoWriter<Config> {
+    fn drop(&mut self) {
+        if self.lifetime.is_none() {
+            self.clear_submitted();
+        }
+    }
+}
+
+/// Draws the current submissions of the [`SharedGizmos`] of the `Config` group.
+///
+/// This should be before [`GizmoMeshSystems`](crate::GizmoMeshSystems).
+pub fn flush_shared_gizmos<Config: GizmoConfigGroup>(
+    shared: Res<SharedGizmos<Config>>,
+    mut storage: ResMut<GizmoStorage<Config, ()>>,
+) {
+    shared.flush_into(&mut storage);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::palettes::basic::RED;
+    use bevy_math::Vec3;
+
+    use super::SharedGizmos;
+    use crate::{config::DefaultGizmoConfigGroup, gizmos::GizmoStorage};
+
+    fn flush(shared: &SharedGizmos) -> usize {
+        let mut storage = GizmoStorage::<DefaultGizmoConfigGroup, ()>::default();
+        shared.flush_into(&mut storage);
+        storage.list_positions.len()
+    }
+
+    #[test]
+    fn submissions_persist_until_replaced() {
+        let shared = SharedGizmos::default();
+        let mut writer = shared.writer();
+
+        writer.line(Vec3::ZERO, Vec3::X, RED);
+        assert_eq!(flush(&shared), 0);
+
+        writer.submit();
+        assert_eq!(flush(&shared), 2);
+        assert_eq!(flush(&shared), 2);
+
+        writer.line(Vec3::ZERO, Vec3::Y, RED);
+        writer.line(Vec3::ZERO, Vec3::Z, RED);
+        writer.submit();
+        assert_eq!(flush(&shared), 4);
+
+        drop(writer);
+        assert_eq!(flush(&shared), 0);
+    }
+
+    #[test]
+    fn submissions_expire_after_their_lifetime() {
+        let shared = SharedGizmos::default();
+        let mut writer = shared.writer().with_lifetime(2);
+        let mut other = shared.writer();
+
+        writer.line(Vec3::ZERO, Vec3::X, RED);
+        writer.submit();
+        other.line(Vec3::ZERO, Vec3::Y, RED);
+        other.submit();
+        drop(writer);
+
+        assert_eq!(flush(&shared), 4);
+        assert_eq!(flush(&shared), 4);
+        assert_eq!(flush(&shared), 2);
+    }
+}
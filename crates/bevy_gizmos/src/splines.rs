@@ -0,0 +1,193 @@
+//! Additional [`GizmoBuffer`] Functions -- Splines
+//!
+//! Includes the implementation of [`GizmoBuffer::spline`] and [`GizmoBuffer::spline_2d`],
+//! and assorted support items.
+
+use crate::{gizmos::GizmoBuffer, prelude::GizmoConfigGroup};
+use bevy_color::{Alpha, Color};
+use bevy_math::{
+    cubic_splines::{CubicGenerator, Spline, SplineKind},
+    Isometry3d, Vec2, Vec3,
+};
+
+/// A builder returned by [`GizmoBuffer::spline`] and [`GizmoBuffer::spline_2d`]
+pub struct SplineBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    spline: Spline<Vec3>,
+    color: Color,
+    resolution: usize,
+    control_points: bool,
+    point_size: Option<f32>,
+}
+
+impl<Config, Clear> SplineBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the number of lines used to draw each segment of the curve.
+    ///
+    /// The default is 32.
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Set whether the control points of the spline are drawn, with the lines between them.
+    ///
+    /// The default is `true`.
+    pub fn control_points(mut self, control_points: bool) -> Self {
+        self.control_points = control_points;
+        self
+    }
+
+    /// Set the half size of the crosses marking the control points.
+    ///
+    /// The default is 2% of the size of the box bounding the control points.
+    pub fn point_size(mut self, point_size: f32) -> Self {
+        self.point_size = Some(point_size);
+        self
+    }
+}
+
+impl<Config, Clear> Drop for SplineBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draws the spline, by drawing lines with the stored [`GizmoBuffer`]
+    fn drop(&mut self) {
+        if !self.gizmos.enabled {
+            return;
+        }
+
+        // Splines with too few control points have no curve, but their points are still drawn.
+        if let Ok(curve) = self.spline.to_curve() {
+            let subdivisions = curve.segments().len() * self.resolution.max(1);
+            self.gizmos
+                .linestrip(curve.iter_positions(subdivisions), self.color);
+        }
+
+        let points = &self.spline.control_points;
+        if !self.control_points || points.is_empty() {
+            return;
+        }
+        let handle_color = self.color.with_alpha(self.color.alpha() * 0.5);
+        match self.spline.kind {
+            // The control points of a Bezier segment are its tangents at its anchors, so they are
+            // drawn as handles attached to the anchors.
+            SplineKind::Bezier => {
+                for (i, &point) in points.iter().enumerate() {
+                    let anchor = match i % 3 {
+                        1 => points[i - 1],
+                        2 => points[(i + 1) % points.len()],
+                        _ => continue,
+                    };
+                    self.gizmos.line(anchor, point, handle_color);
+                }
+            }
+            _ => {
+                let closing_point = self.spline.cyclic.then(|| points[0]);
+                self.gizmos
+                    .linestrip(points.iter().copied().chain(closing_point), handle_color);
+            }
+        }
+
+        let point_size = self.point_size.unwrap_or_else(|| {
+            let (min, max) = points
+                .iter()
+                .fold((points[0], points[0]), |(min, max), &point| {
+                    (min.min(point), max.max(point))
+                });
+            0.02 * (max - min).length()
+        });
+        for &point in points {
+            self.gizmos
+                .cross(Isometry3d::from_translation(point), point_size, self.color);
+        }
+    }
+}
+
+impl<Config, Clear> GizmoBuffer<Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw a [`Spline`] in 3D, along with its control points.
+    ///
+    /// This is useful to visualize and tweak paths, such as camera rails.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let spline = Spline::new(
+    ///         SplineKind::CatmullRom,
+    ///         [Vec3::ZERO, Vec3::new(1., 2., 0.), Vec3::new(3., 2., 1.)],
+    ///     );
+    ///     gizmos.spline(&spline, GREEN).resolution(64);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn spline(
+        &mut self,
+        spline: &Spline<Vec3>,
+        color: impl Into<Color>,
+    ) -> SplineBuilder<'_, Config, Clear> {
+        SplineBuilder {
+            gizmos: self,
+            spline: spline.clone(),
+            color: color.into(),
+            resolution: 32,
+            control_points: true,
+            point_size: None,
+        }
+    }
+
+    /// Draw a [`Spline`] in 2D (on the xy plane), along with its control points.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     let spline = Spline::new(
+    ///         SplineKind::BSpline,
+    ///         [Vec2::ZERO, Vec2::new(100., 50.), Vec2::new(200., 0.), Vec2::new(300., 50.)],
+    ///     )
+    ///     .cyclic();
+    ///     gizmos.spline_2d(&spline, GREEN).control_points(false);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn spline_2d(
+        &mut self,
+        spline: &Spline<Vec2>,
+        color: impl Into<Color>,
+    ) -> SplineBuilder<'_, Config, Clear> {
+        let spline = Spline {
+            kind: spline.kind,
+            control_points: spline
+                .control_points
+                .iter()
+                .map(|point| point.extend(0.))
+                .collect(),
+            cyclic: spline.cyclic,
+        };
+        SplineBuilder {
+            gizmos: self,
+            spline,
+            color: color.into(),
+            resolution: 32,
+            control_points: true,
+            point_size: None,
+        }
+    }
+}
@@ -207,6 +207,11 @@ pub enum GltfSkinnedMeshBoundsPolicy {
     /// Skinned meshes are created with [`SkinnedMeshBounds`](bevy_mesh::skinning::SkinnedMeshBounds)
     /// and assigned a [`DynamicSkinnedMeshBounds`](bevy_camera::visibility::DynamicSkinnedMeshBounds)
     /// component. See `DynamicSkinnedMeshBounds` for details.
+    ///
+    /// Meshes with morph targets, skinned or not, are also created with
+    /// [`MorphTargetBounds`](bevy_mesh::morph::MorphTargetBounds) and assigned a
+    /// [`DynamicMorphedMeshBounds`](bevy_camera::visibility::DynamicMorphedMeshBounds)
+    /// component.
     #[default]
     Dynamic,
     /// Same as `BindPose`, but also assign a `NoFrustumCulling` component. That
@@ -11,7 +11,9 @@ use bevy_asset::{
 };
 use bevy_camera::{
     primitives::Aabb,
-    visibility::{DynamicSkinnedMeshBounds, NoFrustumCulling, Visibility},
+    visibility::{
+        DynamicMorphedMeshBounds, DynamicSkinnedMeshBounds, NoFrustumCulling, Visibility,
+    },
     Camera, Camera3d, OrthographicProjection, PerspectiveProjection, Projection, ScalingMode,
 };
 use bevy_color::{Color, LinearRgba};
@@ -875,6 +877,13 @@ impl GltfLoader {
                     warn!("Failed to generate skinned mesh bounds: {err}");
                 }
 
+                if (skinned_mesh_bounds_policy == GltfSkinnedMeshBoundsPolicy::Dynamic)
+                    && primitive.morph_targets().len() != 0
+                    && let Err(err) = mesh.generate_morph_target_bounds()
+                {
+                    warn!("Failed to generate morph target bounds: {err}");
+                }
+
                 let mesh_handle = load_context.add_labeled_asset(
                     primitive_label.to_string(),
                     mesh.compressed_mesh(
@@ -1706,6 +1715,9 @@ fn load_node(
                 if target_count != 0 {
                     max_morph_target_count = max_morph_target_count.max(target_count);
                     mesh_entity.insert(MeshMorphWeights::Reference(parent_entity));
+                    if skinned_mesh_bounds_policy == GltfSkinnedMeshBoundsPolicy::Dynamic {
+                        mesh_entity.insert(DynamicMorphedMeshBounds);
+                    }
                 }
 
                 let mut bounds_min = Vec3::from_slice(&bounds.min);
@@ -8,6 +8,7 @@ use super::{CompressedImageFormats, Image, TextureError};
 pub fn basis_buffer_to_image(
     buffer: &[u8],
     supported_compressed_formats: CompressedImageFormats,
+    transcode_targets: CompressedImageFormats,
     is_srgb: bool,
 ) -> Result<Image, TextureError> {
     let mut transcoder = Transcoder::new();
@@ -29,7 +30,14 @@ pub fn basis_buffer_to_image(
     // First deal with transcoding to the desired format
     // FIXME: Use external metadata to transcode to more appropriate formats for 1- or 2-component sources
     let (transcode_format, texture_format) =
-        get_transcoded_formats(supported_compressed_formats, is_srgb);
+        get_transcoded_formats(supported_compressed_formats & transcode_targets, is_srgb);
+    if !texture_format.is_compressed() {
+        warn_uncompressed_fallback(
+            texture_format,
+            supported_compressed_formats,
+            transcode_targets,
+        );
+    }
     let basis_texture_format = transcoder.basis_texture_format(buffer);
     if !basis_texture_format.can_transcode_to_format(transcode_format) {
         return Err(TextureError::UnsupportedTextureFormat(format!(
@@ -119,6 +127,20 @@ pub fn basis_buffer_to_image(
     Ok(image)
 }
 
+/// Reports a Basis Universal texture transcoded to an uncompressed format, which takes several
+/// times more memory than a compressed one.
+pub(crate) fn warn_uncompressed_fallback(
+    texture_format: TextureFormat,
+    supported_compressed_formats: CompressedImageFormats,
+    transcode_targets: CompressedImageFormats,
+) {
+    tracing::warn!(
+        "Transcoding a Basis Universal texture to the uncompressed {texture_format:?} format, \
+        which uses more memory: none of the transcode targets ({transcode_targets:?}) is \
+        supported by the GPU ({supported_compressed_formats:?})"
+    );
+}
+
 pub fn get_transcoded_formats(
     supported_compressed_formats: CompressedImageFormats,
     is_srgb: bool,
@@ -1,7 +1,10 @@
-use crate::{Image, TextureAccessError, TextureAtlasLayout, TextureFormatPixelInfo as _};
-use bevy_asset::RenderAssetUsages;
+use crate::{
+    texture_atlas_builder::copy_extruded_texture, Image, TextureAccessError, TextureAtlasLayout,
+    TextureAtlasSources, TextureFormatPixelInfo as _,
+};
+use bevy_asset::{AssetId, RenderAssetUsages};
 use bevy_math::{URect, UVec2};
-use guillotiere::{size2, Allocation, AtlasAllocator};
+use guillotiere::{size2, AtlasAllocator};
 use thiserror::Error;
 
 /// An error produced by [`DynamicTextureAtlasBuilder`] when trying to add a new
@@ -29,6 +32,7 @@ pub enum DynamicTextureAtlasBuilderError {
 pub struct DynamicTextureAtlasBuilder {
     atlas_allocator: AtlasAllocator,
     padding: u32,
+    extrusion: u32,
 }
 
 impl DynamicTextureAtlasBuilder {
@@ -47,9 +51,19 @@ impl DynamicTextureAtlasBuilder {
             // atlas.
             atlas_allocator: AtlasAllocator::new(to_size2(size - padding)),
             padding,
+            extrusion: 0,
         }
     }
 
+    /// Sets the number of pixels by which the edges of the added textures are extruded, to
+    /// prevent them from bleeding into each other when filtered.
+    ///
+    /// See [`TextureAtlasBuilder::extrusion`](crate::TextureAtlasBuilder::extrusion).
+    pub fn with_extrusion(mut self, extrusion: u32) -> Self {
+        self.extrusion = extrusion;
+        self
+    }
+
     /// Add a new texture to `atlas_layout`.
     ///
     /// It is the user's responsibility to pass in the correct [`TextureAtlasLayout`].
@@ -70,8 +84,12 @@ impl DynamicTextureAtlasBuilder {
         // Allocate enough space for the texture and the padding to the top and left (bottom and
         // right padding are taken care off since the allocator size omits it on creation).
         let allocation = self.atlas_allocator.allocate(size2(
-            (texture.width() + self.padding).try_into().unwrap(),
-            (texture.height() + self.padding).try_into().unwrap(),
+            (texture.width() + self.padding + 2 * self.extrusion)
+                .try_into()
+                .unwrap(),
+            (texture.height() + self.padding + 2 * self.extrusion)
+                .try_into()
+                .unwrap(),
         ));
         if let Some(mut allocation) = allocation {
             assert!(
@@ -84,22 +102,43 @@ impl DynamicTextureAtlasBuilder {
             rect.min.x += self.padding as i32;
             rect.min.y += self.padding as i32;
 
-            self.place_texture(atlas_texture, allocation, texture)?;
-            Ok(atlas_layout.add_texture(to_rect(allocation.rectangle)))
+            // The extruded pixels surround the texture rect.
+            let min = to_rect(allocation.rectangle).min + self.extrusion;
+            let rect = URect::from_corners(min, min + texture.size());
+            self.place_texture(atlas_texture, rect, texture)?;
+            Ok(atlas_layout.add_texture(rect))
         } else {
             Err(DynamicTextureAtlasBuilderError::FailedToAllocateSpace)
         }
     }
 
+    /// Add a new texture to `atlas_layout`, and record its index in `atlas_sources` under
+    /// `image_id`.
+    ///
+    /// This makes it possible to keep adding textures at runtime to an atlas built by
+    /// [`TextureAtlasBuilder::build_dynamic`](crate::TextureAtlasBuilder::build_dynamic), and to
+    /// look them up by their source image as usual. See [`add_texture`](Self::add_texture) for
+    /// the other arguments.
+    pub fn add_texture_with_id(
+        &mut self,
+        atlas_layout: &mut TextureAtlasLayout,
+        atlas_sources: &mut TextureAtlasSources,
+        image_id: AssetId<Image>,
+        texture: &Image,
+        atlas_texture: &mut Image,
+    ) -> Result<usize, DynamicTextureAtlasBuilderError> {
+        let index = self.add_texture(atlas_layout, texture, atlas_texture)?;
+        atlas_sources.texture_ids.insert(image_id, index);
+        Ok(index)
+    }
+
     fn place_texture(
         &mut self,
         atlas_texture: &mut Image,
-        allocation: Allocation,
+        rect: URect,
         texture: &Image,
     ) -> Result<(), DynamicTextureAtlasBuilderError> {
-        let rect = &allocation.rectangle;
-        let atlas_width = atlas_texture.width() as usize;
-        let rect_width = rect.width() as usize;
+        let atlas_width = atlas_texture.width();
         let format_size = atlas_texture.texture_descriptor.format.pixel_size()?;
 
         let Some(ref mut atlas_data) = atlas_texture.data else {
@@ -108,13 +147,14 @@ impl DynamicTextureAtlasBuilder {
         let Some(ref data) = texture.data else {
             return Err(DynamicTextureAtlasBuilderError::UninitializedSourceTexture);
         };
-        for (texture_y, bound_y) in (rect.min.y..rect.max.y).map(|i| i as usize).enumerate() {
-            let begin = (bound_y * atlas_width + rect.min.x as usize) * format_size;
-            let end = begin + rect_width * format_size;
-            let texture_begin = texture_y * rect_width * format_size;
-            let texture_end = texture_begin + rect_width * format_size;
-            atlas_data[begin..end].copy_from_slice(&data[texture_begin..texture_end]);
-        }
+        copy_extruded_texture(
+            atlas_data,
+            atlas_width,
+            data,
+            rect,
+            self.extrusion,
+            format_size,
+        );
         Ok(())
     }
 }
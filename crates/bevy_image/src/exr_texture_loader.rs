@@ -1,4 +1,4 @@
-use crate::{Image, TextureFormatPixelInfo};
+use crate::{Image, TextureAccessError, TextureFormatPixelInfo};
 use bevy_asset::{io::Reader, AssetLoader, LoadContext, RenderAssetUsages};
 use bevy_reflect::TypePath;
 use image::ImageDecoder;
@@ -17,6 +17,12 @@ pub struct ExrTextureLoader;
 pub struct ExrTextureLoaderSettings {
     /// Where the asset will be used - see the docs on [`RenderAssetUsages`] for details.
     pub asset_usage: RenderAssetUsages,
+    /// If set, the image is loaded as an equirectangular panorama and converted to a cubemap with
+    /// faces of this size, ready to be used as a skybox or environment map.
+    ///
+    /// See [`Image::equirectangular_to_cubemap`] for details.
+    #[serde(default)]
+    pub cubemap_face_size: Option<u32>,
 }
 
 /// Possible errors that can be produced by [`ExrTextureLoader`]
@@ -30,6 +36,9 @@ pub enum ExrTextureLoaderError {
     /// Failed to decode the texture.
     #[error(transparent)]
     ImageError(#[from] image::ImageError),
+    /// Failed to convert the texture to a cubemap.
+    #[error(transparent)]
+    Cubemap(#[from] TextureAccessError),
 }
 
 impl AssetLoader for ExrTextureLoader {
@@ -64,7 +73,7 @@ impl AssetLoader for ExrTextureLoader {
         let mut buf = vec![0u8; total_bytes];
         decoder.read_image(buf.as_mut_slice())?;
 
-        Ok(Image::new(
+        let image = Image::new(
             Extent3d {
                 width,
                 height,
@@ -74,7 +83,11 @@ impl AssetLoader for ExrTextureLoader {
             buf,
             format,
             settings.asset_usage,
-        ))
+        );
+        Ok(match settings.cubemap_face_size {
+            Some(face_size) => image.equirectangular_to_cubemap(face_size)?,
+            None => image,
+        })
     }
 
     fn extensions(&self) -> &[&str] {
@@ -1,4 +1,4 @@
-use crate::{Image, TextureFormatPixelInfo};
+use crate::{Image, TextureAccessError, TextureFormatPixelInfo};
 use bevy_asset::RenderAssetUsages;
 use bevy_asset::{io::Reader, AssetLoader, LoadContext};
 use bevy_reflect::TypePath;
@@ -16,6 +16,12 @@ pub struct HdrTextureLoader;
 pub struct HdrTextureLoaderSettings {
     /// Where the asset will be used - see the docs on [`RenderAssetUsages`] for details.
     pub asset_usage: RenderAssetUsages,
+    /// If set, the image is loaded as an equirectangular panorama and converted to a cubemap with
+    /// faces of this size, ready to be used as a skybox or environment map.
+    ///
+    /// See [`Image::equirectangular_to_cubemap`] for details.
+    #[serde(default)]
+    pub cubemap_face_size: Option<u32>,
 }
 
 /// Possible errors that can be produced by [`HdrTextureLoader`]
@@ -28,6 +34,9 @@ pub enum HdrTextureLoaderError {
     /// Failed to decode the texture.
     #[error("Could not extract image: {0}")]
     Image(#[from] image::ImageError),
+    /// Failed to convert the texture to a cubemap.
+    #[error("Could not convert to a cubemap: {0}")]
+    Cubemap(#[from] TextureAccessError),
 }
 
 impl AssetLoader for HdrTextureLoader {
@@ -64,7 +73,7 @@ impl AssetLoader for HdrTextureLoader {
             rgba_data.extend_from_slice(&alpha.to_le_bytes());
         }
 
-        Ok(Image::new(
+        let image = Image::new(
             Extent3d {
                 width: info.width,
                 height: info.height,
@@ -74,7 +83,11 @@ impl AssetLoader for HdrTextureLoader {
             rgba_data,
             format,
             settings.asset_usage,
-        ))
+        );
+        Ok(match settings.cubemap_face_size {
+            Some(face_size) => image.equirectangular_to_cubemap(face_size)?,
+            None => image,
+        })
     }
 
     fn extensions(&self) -> &[&str] {
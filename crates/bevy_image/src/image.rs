@@ -14,12 +14,14 @@ use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
+use alloc::sync::Arc;
 use bevy_asset::{uuid_handle, Asset, AssetApp, Assets, Handle, RenderAssetUsages};
 use bevy_color::{Color, ColorToComponents, Gray, LinearRgba, Srgba, Xyza};
 use bevy_ecs::resource::Resource;
 use bevy_math::{AspectRatio, UVec2, UVec3, Vec2};
 use core::hash::Hash;
 use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
 use thiserror::Error;
 use wgpu_types::{
     AddressMode, CompareFunction, Extent3d, Features, FilterMode, MipmapFilterMode,
@@ -236,7 +238,8 @@ impl Plugin for ImagePlugin {
         #[cfg(feature = "hdr")]
         app.init_asset_loader::<crate::HdrTextureLoader>();
 
-        app.init_asset::<Image>();
+        app.init_asset::<Image>()
+            .init_resource::<CompressedImageTranscodeTargets>();
         #[cfg(feature = "bevy_reflect")]
         app.register_asset_reflect::<Image>();
 
@@ -1343,7 +1346,8 @@ impl Image {
     /// Resizes the image to the new size, by removing information or appending 0 to the `data`.
     /// Does not properly scale the contents of the image.
     ///
-    /// If you need to keep pixel data intact, use [`Image::resize_in_place`].
+    /// If you need to keep pixel data intact, use [`Image::resize_in_place`]. To scale the
+    /// contents of the image, use [`Image::scaled`].
     pub fn resize(&mut self, size: Extent3d) {
         self.texture_descriptor.size = size;
         if let Some(ref mut data) = self.data
@@ -1585,6 +1589,29 @@ impl Image {
     /// Load a bytes buffer in a [`Image`], according to type `image_type`, using the `image`
     /// crate
     pub fn from_buffer(
+        buffer: &[u8],
+        image_type: ImageType,
+        supported_compressed_formats: CompressedImageFormats,
+        is_srgb: bool,
+        image_sampler: ImageSampler,
+        asset_usage: RenderAssetUsages,
+    ) -> Result<Image, TextureError> {
+        Self::from_buffer_with_transcode_targets(
+            buffer,
+            image_type,
+            supported_compressed_formats,
+            CompressedImageFormats::all(),
+            is_srgb,
+            image_sampler,
+            asset_usage,
+        )
+    }
+
+    /// Load a bytes buffer in a [`Image`], according to type `image_type`, only transcoding Basis
+    /// Universal textures to the compressed formats in `transcode_targets`.
+    ///
+    /// See [`CompressedImageTranscodeTargets`] for more details.
+    pub fn from_buffer_with_transcode_targets(
         buffer: &[u8],
         image_type: ImageType,
         #[cfg_attr(
@@ -1592,6 +1619,11 @@ impl Image {
             expect(unused_variables, reason = "only used with certain features")
         )]
         supported_compressed_formats: CompressedImageFormats,
+        #[cfg_attr(
+            not(any(feature = "basis-universal", feature = "ktx2")),
+            expect(unused_variables, reason = "only used with certain features")
+        )]
+        transcode_targets: CompressedImageFormats,
         is_srgb: bool,
         image_sampler: ImageSampler,
         asset_usage: RenderAssetUsages,
@@ -1606,15 +1638,21 @@ impl Image {
 
         let mut image = match format {
             #[cfg(feature = "basis-universal")]
-            ImageFormat::Basis => {
-                basis_buffer_to_image(buffer, supported_compressed_formats, is_srgb)?
-            }
+            ImageFormat::Basis => basis_buffer_to_image(
+                buffer,
+                supported_compressed_formats,
+                transcode_targets,
+                is_srgb,
+            )?,
             #[cfg(feature = "dds")]
             ImageFormat::Dds => dds_buffer_to_image(buffer, supported_compressed_formats, is_srgb)?,
             #[cfg(feature = "ktx2")]
-            ImageFormat::Ktx2 => {
-                ktx2_buffer_to_image(buffer, supported_compressed_formats, is_srgb)?
-            }
+            ImageFormat::Ktx2 => ktx2_buffer_to_image_with_transcode_targets(
+                buffer,
+                supported_compressed_formats,
+                transcode_targets,
+                is_srgb,
+            )?,
             #[expect(
                 clippy::allow_attributes,
                 reason = "`unreachable_patterns` may not always lint"
@@ -2466,9 +2504,62 @@ impl CompressedImageFormats {
 /// For defining which compressed image formats are supported. This will be initialized from available device features
 /// in `finish()` of the bevy `RenderPlugin`, but is left for the user to specify if not using the `RenderPlugin`, or
 /// the WGPU backend.
+///
+/// This can be queried to know which compressed formats the current GPU supports, for instance to
+/// pick which textures to load.
 #[derive(Resource)]
 pub struct CompressedImageFormatSupport(pub CompressedImageFormats);
 
+impl CompressedImageFormatSupport {
+    /// Returns `true` if the current GPU supports the given [`TextureFormat`].
+    ///
+    /// Always returns `true` for uncompressed formats.
+    pub fn supports(&self, format: TextureFormat) -> bool {
+        self.0.supports(format)
+    }
+}
+
+/// The compressed formats that Basis Universal textures, in `.basis` and `.ktx2` files, may be
+/// transcoded to when they are loaded.
+///
+/// The textures are transcoded to the best of these formats that the GPU supports, according to
+/// [`CompressedImageFormatSupport`], or to an uncompressed format if there is none, with a
+/// warning, as uncompressed textures take several times more memory. Restricting the targets
+/// makes the memory taken by the textures the same across platforms, for instance to
+/// [`CompressedImageFormats::ETC2`] on mobile, or to [`CompressedImageFormats::NONE`] to always
+/// transcode them to uncompressed formats. Textures which are already compressed are loaded as
+/// they are.
+///
+/// Defaults to all the formats. This can be changed at runtime, which affects the textures loaded
+/// afterwards: the textures already loaded need to be reloaded.
+#[derive(Resource, Clone, Debug)]
+pub struct CompressedImageTranscodeTargets(Arc<RwLock<CompressedImageFormats>>);
+
+impl Default for CompressedImageTranscodeTargets {
+    fn default() -> Self {
+        Self::new(CompressedImageFormats::all())
+    }
+}
+
+impl CompressedImageTranscodeTargets {
+    /// Creates a new [`CompressedImageTranscodeTargets`].
+    pub fn new(formats: CompressedImageFormats) -> Self {
+        Self(Arc::new(RwLock::new(formats)))
+    }
+
+    /// Returns the current transcode targets.
+    pub fn get(&self) -> CompressedImageFormats {
+        *self.0.read().unwrap()
+    }
+
+    /// Replaces the transcode targets.
+    ///
+    /// Doesn't apply to the textures already loaded, which need to be reloaded.
+    pub fn set(&self, formats: CompressedImageFormats) {
+        *self.0.write().unwrap() = formats;
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -2887,4 +2978,20 @@ mod test {
         assert_eq!(my_sampler_in_a_loader.min_filter, ImageFilterMode::Linear);
         assert_eq!(my_sampler_in_a_loader.anisotropy_clamp, 8);
     }
+
+    #[test]
+    fn transcode_targets_are_shared() {
+        let targets = CompressedImageTranscodeTargets::default();
+        assert_eq!(targets.get(), CompressedImageFormats::all());
+
+        // A loader holding a clone sees the targets changed at runtime.
+        let loader_targets = targets.clone();
+        targets.set(CompressedImageFormats::ETC2);
+        assert_eq!(loader_targets.get(), CompressedImageFormats::ETC2);
+
+        let support = CompressedImageFormatSupport(CompressedImageFormats::BC);
+        assert!(support.supports(TextureFormat::Bc7RgbaUnorm));
+        assert!(!support.supports(TextureFormat::Etc2Rgba8Unorm));
+        assert!(support.supports(TextureFormat::Rgba8Unorm));
+    }
 }
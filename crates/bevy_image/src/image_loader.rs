@@ -6,13 +6,14 @@ use bevy_asset::{io::Reader, AssetLoader, LoadContext, RenderAssetUsages};
 use bevy_reflect::TypePath;
 use thiserror::Error;
 
-use super::{CompressedImageFormats, ImageSampler};
+use super::{CompressedImageFormats, CompressedImageTranscodeTargets, ImageSampler};
 use serde::{Deserialize, Serialize};
 
 /// Loader for images that can be read by the `image` crate.
 #[derive(Clone, TypePath)]
 pub struct ImageLoader {
     supported_compressed_formats: CompressedImageFormats,
+    transcode_targets: CompressedImageTranscodeTargets,
 }
 
 impl ImageLoader {
@@ -81,8 +82,24 @@ impl ImageLoader {
     pub fn new(supported_compressed_formats: CompressedImageFormats) -> Self {
         Self {
             supported_compressed_formats,
+            transcode_targets: CompressedImageTranscodeTargets::default(),
         }
     }
+
+    /// Only transcodes Basis Universal textures to the formats of the given
+    /// [`CompressedImageTranscodeTargets`], which can be changed at runtime.
+    pub fn with_transcode_targets(
+        mut self,
+        transcode_targets: CompressedImageTranscodeTargets,
+    ) -> Self {
+        self.transcode_targets = transcode_targets;
+        self
+    }
+
+    /// The compressed formats supported by the GPU, which this loader loads textures in.
+    pub fn supported_compressed_formats(&self) -> CompressedImageFormats {
+        self.supported_compressed_formats
+    }
 }
 
 /// How to determine an image's format when loading.
@@ -225,15 +242,19 @@ impl AssetLoader for ImageLoader {
             }
         };
 
-        let mut image = Image::from_buffer(
-            &bytes,
-            image_type,
-            self.supported_compressed_formats,
-            settings.is_srgb,
-            settings.sampler.clone(),
-            settings.asset_usage,
-        )
-        .map_err(|err| FileTextureError {
+        // Reports the path of the image in the warnings of the transcoders.
+        let image = tracing::info_span!("load_image", path = %load_context.path()).in_scope(|| {
+            Image::from_buffer_with_transcode_targets(
+                &bytes,
+                image_type,
+                self.supported_compressed_formats,
+                self.transcode_targets.get(),
+                settings.is_srgb,
+                settings.sampler.clone(),
+                settings.asset_usage,
+            )
+        });
+        let mut image = image.map_err(|err| FileTextureError {
             error: err,
             path: format!("{}", load_context.path().path().display()),
         })?;
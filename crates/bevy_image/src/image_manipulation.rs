@@ -0,0 +1,561 @@
+use crate::{Image, ImageFilterMode, TextureAccessError, TextureFormatPixelInfo};
+use bevy_color::{Color, ColorToComponents, ColorToPacked, LinearRgba, Srgba};
+use bevy_math::{ops, URect, UVec2, UVec3, Vec3, Vec4};
+use core::f32::consts::{PI, TAU};
+use image::{imageops::FilterType, Rgba, Rgba32FImage};
+use wgpu_types::{
+    Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension,
+};
+
+impl Image {
+    /// Copies the pixels of `source` within `source_rect` to this image, with their top-left
+    /// corner at `destination`.
+    ///
+    /// The pixels are copied as they are, replacing the ones of this image, alpha included. If the
+    /// two images have different formats, the pixels are converted through [`Color`], which
+    /// supports the same formats as [`get_color_at`](Self::get_color_at) and
+    /// [`set_color_at`](Self::set_color_at). The region is clipped to the bounds of both images.
+    ///
+    /// Only the first layer of 2D textures is supported.
+    pub fn copy_region(
+        &mut self,
+        source: &Image,
+        source_rect: URect,
+        destination: UVec2,
+    ) -> Result<(), TextureAccessError> {
+        let Some((source_rect, destination)) =
+            self.clip_region(source, source_rect, destination)?
+        else {
+            return Ok(());
+        };
+
+        if source.texture_descriptor.format != self.texture_descriptor.format {
+            return self.for_each_region_pixel(source, source_rect, destination, |color, _| color);
+        }
+
+        // The formats match, so the rows are copied without decoding them.
+        let pixel_size = self.texture_descriptor.format.pixel_size()?;
+        let row_len = source_rect.width() as usize * pixel_size;
+        for y in 0..source_rect.height() {
+            let source_offset = source.pixel_data_offset(UVec3::new(
+                source_rect.min.x,
+                source_rect.min.y + y,
+                0,
+            ))?;
+            let destination_offset =
+                self.pixel_data_offset(UVec3::new(destination.x, destination.y + y, 0))?;
+            let Some(source_data) = source.data.as_ref() else {
+                return Err(TextureAccessError::Uninitialized);
+            };
+            let Some(data) = self.data.as_mut() else {
+                return Err(TextureAccessError::Uninitialized);
+            };
+            data[destination_offset..destination_offset + row_len]
+                .copy_from_slice(&source_data[source_offset..source_offset + row_len]);
+        }
+        Ok(())
+    }
+
+    /// Draws the pixels of `source` within `source_rect` over this image, with their top-left
+    /// corner at `destination`, blending them according to their alpha.
+    ///
+    /// The colors are blended in linear space. Both images must have a format supported by
+    /// [`get_color_at`](Self::get_color_at) and [`set_color_at`](Self::set_color_at). The region
+    /// is clipped to the bounds of both images.
+    ///
+    /// Only the first layer of 2D textures is supported. To replace the pixels instead, use
+    /// [`copy_region`](Self::copy_region).
+    pub fn blit(
+        &mut self,
+        source: &Image,
+        source_rect: URect,
+        destination: UVec2,
+    ) -> Result<(), TextureAccessError> {
+        let Some((source_rect, destination)) =
+            self.clip_region(source, source_rect, destination)?
+        else {
+            return Ok(());
+        };
+
+        self.for_each_region_pixel(source, source_rect, destination, |source, destination| {
+            let source = source.to_linear();
+            let destination = destination.to_linear();
+            let alpha = source.alpha + destination.alpha * (1.0 - source.alpha);
+            if alpha <= 0.0 {
+                return LinearRgba::NONE.into();
+            }
+            let blend = |source_channel: f32, destination_channel: f32| {
+                (source_channel * source.alpha
+                    + destination_channel * destination.alpha * (1.0 - source.alpha))
+                    / alpha
+            };
+            LinearRgba::new(
+                blend(source.red, destination.red),
+                blend(source.green, destination.green),
+                blend(source.blue, destination.blue),
+                alpha,
+            )
+            .into()
+        })
+    }
+
+    /// Returns a copy of this image scaled to `size`, filtering its pixels with `filter`.
+    ///
+    /// The pixels are filtered in linear space, and the copy keeps the format, sampler and usages
+    /// of this image, without its mipmaps. Only 2D textures with a single layer, in a format
+    /// supported by [`get_color_at`](Self::get_color_at) and
+    /// [`set_color_at`](Self::set_color_at), can be scaled.
+    ///
+    /// To change the size of the image without scaling its contents, use
+    /// [`resize_in_place`](Self::resize_in_place).
+    pub fn scaled(
+        &self,
+        size: UVec2,
+        filter: ImageFilterMode,
+    ) -> Result<Image, TextureAccessError> {
+        self.check_single_layer()?;
+        let buffer = image::imageops::resize(
+            &self.to_linear_buffer()?,
+            size.x,
+            size.y,
+            filter_type(filter),
+        );
+        self.encode_linear_buffer(&buffer)
+    }
+
+    /// Generates the full chain of mipmaps of this image on the CPU, filtering each level down
+    /// from the previous one with `filter`.
+    ///
+    /// The existing mipmaps, if any, are replaced. Only 2D textures with a single layer, in a
+    /// format supported by [`get_color_at`](Self::get_color_at) and
+    /// [`set_color_at`](Self::set_color_at), are supported.
+    pub fn generate_mipmaps(&mut self, filter: ImageFilterMode) -> Result<(), TextureAccessError> {
+        self.check_single_layer()?;
+        let size = self.size();
+        let base_len = self.texture_descriptor.format.pixel_size()? * (size.x * size.y) as usize;
+        let Some(data) = self.data.as_mut() else {
+            return Err(TextureAccessError::Uninitialized);
+        };
+        data.truncate(base_len);
+        self.texture_descriptor.mip_level_count = 1;
+
+        let mut buffer = self.to_linear_buffer()?;
+        let mut mip_data = Vec::new();
+        let mut mip_level_count = 1;
+        while buffer.width() > 1 || buffer.height() > 1 {
+            buffer = image::imageops::resize(
+                &buffer,
+                (buffer.width() / 2).max(1),
+                (buffer.height() / 2).max(1),
+                filter_type(filter),
+            );
+            let Some(level_data) = self.encode_linear_buffer(&buffer)?.data else {
+                return Err(TextureAccessError::Uninitialized);
+            };
+            mip_data.extend(level_data);
+            mip_level_count += 1;
+        }
+
+        if let Some(data) = self.data.as_mut() {
+            data.extend(mip_data);
+        }
+        self.texture_descriptor.mip_level_count = mip_level_count;
+        Ok(())
+    }
+
+    /// Returns a copy of this image converted to the sRGB or linear variant of its format, with
+    /// its pixels encoded accordingly, so that it looks the same when sampled.
+    ///
+    /// This is useful for textures which were loaded in the wrong color space, or which should be
+    /// processed in the other one. Only 8-bit RGBA and BGRA formats are supported, their mipmaps
+    /// and layers included. Images already in the requested color space are returned unchanged.
+    pub fn convert_color_space(&self, srgb: bool) -> Result<Image, TextureAccessError> {
+        let format = self.texture_descriptor.format;
+        let new_format = if srgb {
+            format.add_srgb_suffix()
+        } else {
+            format.remove_srgb_suffix()
+        };
+        if new_format == format {
+            return Ok(self.clone());
+        }
+        if !matches!(
+            format,
+            TextureFormat::Rgba8Unorm
+                | TextureFormat::Rgba8UnormSrgb
+                | TextureFormat::Bgra8Unorm
+                | TextureFormat::Bgra8UnormSrgb
+        ) {
+            return Err(TextureAccessError::UnsupportedTextureFormat(format));
+        }
+
+        let mut converted = self.clone();
+        let Some(data) = converted.data.as_mut() else {
+            return Err(TextureAccessError::Uninitialized);
+        };
+        for pixel in data.chunks_exact_mut(4) {
+            // The alpha channel is always linear.
+            for channel in &mut pixel[..3] {
+                let value = *channel as f32 / u8::MAX as f32;
+                let value = if srgb {
+                    Srgba::gamma_function_inverse(value)
+                } else {
+                    Srgba::gamma_function(value)
+                };
+                *channel = (value * u8::MAX as f32).round() as u8;
+            }
+        }
+        converted.texture_descriptor.format = new_format;
+        Ok(converted)
+    }
+
+    /// Converts this equirectangular panorama, such as an HDR sky loaded from a `.hdr` or `.exr`
+    /// file, to a cubemap with faces of `face_size` by `face_size` pixels.
+    ///
+    /// The center of the panorama faces forward (towards negative Z), and its top edge faces up.
+    /// The cubemap is bilinearly sampled from the panorama in an
+    /// [`Rgba16Float`](TextureFormat::Rgba16Float) format, and can be used as a skybox, or as the
+    /// environment map of a `GeneratedEnvironmentMapLight`, which prefilters it on the GPU into
+    /// the specular and diffuse maps used for image-based lighting. The latter requires
+    /// `face_size` to be a power of two.
+    ///
+    /// Only 2D textures with a single layer, in a format supported by
+    /// [`get_color_at`](Self::get_color_at), can be converted.
+    pub fn equirectangular_to_cubemap(&self, face_size: u32) -> Result<Image, TextureAccessError> {
+        self.check_single_layer()?;
+        let panorama = self.to_linear_buffer()?;
+        let format = TextureFormat::Rgba16Float;
+        let mut cubemap = Image::new(
+            Extent3d {
+                width: face_size,
+                height: face_size,
+                depth_or_array_layers: 6,
+            },
+            TextureDimension::D2,
+            vec![0; format.pixel_size()? * (face_size * face_size * 6) as usize],
+            format,
+            self.asset_usage,
+        );
+        for face in 0..6 {
+            for y in 0..face_size {
+                for x in 0..face_size {
+                    // The direction through the center of the texel, with the faces laid out in
+                    // the +X, -X, +Y, -Y, +Z, -Z order expected by cube textures.
+                    let u = 2.0 * (x as f32 + 0.5) / face_size as f32 - 1.0;
+                    let v = 2.0 * (y as f32 + 0.5) / face_size as f32 - 1.0;
+                    let direction = match face {
+                        0 => Vec3::new(1.0, -v, -u),
+                        1 => Vec3::new(-1.0, -v, u),
+                        2 => Vec3::new(u, 1.0, v),
+                        3 => Vec3::new(u, -1.0, -v),
+                        4 => Vec3::new(u, -v, 1.0),
+                        _ => Vec3::new(-u, -v, -1.0),
+                    };
+                    let color = sample_equirectangular(&panorama, direction);
+                    cubemap.set_color_at_3d(x, y, face, LinearRgba::from_vec4(color).into())?;
+                }
+            }
+        }
+        cubemap.texture_view_descriptor = Some(TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::Cube),
+            ..Default::default()
+        });
+        Ok(cubemap)
+    }
+
+    /// Clips a region copied from `source` to the bounds of both images.
+    ///
+    /// Returns `None` if nothing is left of the region.
+    fn clip_region(
+        &self,
+        source: &Image,
+        source_rect: URect,
+        destination: UVec2,
+    ) -> Result<Option<(URect, UVec2)>, TextureAccessError> {
+        if self.texture_descriptor.dimension != TextureDimension::D2
+            || source.texture_descriptor.dimension != TextureDimension::D2
+        {
+            return Err(TextureAccessError::WrongDimension);
+        }
+        let source_rect = source_rect.intersect(URect::from_corners(UVec2::ZERO, source.size()));
+        let size = source_rect
+            .size()
+            .min(self.size().saturating_sub(destination));
+        if source_rect.is_empty() || size.cmpeq(UVec2::ZERO).any() {
+            return Ok(None);
+        }
+        Ok(Some((
+            URect::from_corners(source_rect.min, source_rect.min + size),
+            destination,
+        )))
+    }
+
+    /// Replaces each pixel of the destination region with the result of `f`, called with the
+    /// color of the source pixel and of the destination pixel.
+    fn for_each_region_pixel(
+        &mut self,
+        source: &Image,
+        source_rect: URect,
+        destination: UVec2,
+        mut f: impl FnMut(Color, Color) -> Color,
+    ) -> Result<(), TextureAccessError> {
+        for y in 0..source_rect.height() {
+            for x in 0..source_rect.width() {
+                let source_color =
+                    source.get_color_at(source_rect.min.x + x, source_rect.min.y + y)?;
+                let (x, y) = (destination.x + x, destination.y + y);
+                let color = f(source_color, self.get_color_at(x, y)?);
+                self.set_color_at(x, y, color)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_single_layer(&self) -> Result<(), TextureAccessError> {
+        if self.texture_descriptor.dimension != TextureDimension::D2
+            || self.texture_descriptor.size.depth_or_array_layers != 1
+        {
+            return Err(TextureAccessError::WrongDimension);
+        }
+        Ok(())
+    }
+
+    /// Decodes the first level of this image to a buffer of linear colors.
+    fn to_linear_buffer(&self) -> Result<Rgba32FImage, TextureAccessError> {
+        let mut buffer = Rgba32FImage::new(self.width(), self.height());
+        for (x, y, pixel) in buffer.enumerate_pixels_mut() {
+            *pixel = Rgba(self.get_color_at(x, y)?.to_linear().to_f32_array());
+        }
+        Ok(buffer)
+    }
+
+    /// Creates a copy of this image with the size and the colors of `buffer`, encoded in the format
+    /// of this image.
+    fn encode_linear_buffer(&self, buffer: &Rgba32FImage) -> Result<Image, TextureAccessError> {
+        let format = self.texture_descriptor.format;
+        let mut image = Image::new(
+            Extent3d {
+                width: buffer.width(),
+                height: buffer.height(),
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![0; format.pixel_size()? * (buffer.width() * buffer.height()) as usize],
+            format,
+            self.asset_usage,
+        );
+        for (x, y, pixel) in buffer.enumerate_pixels() {
+            let color = LinearRgba::from_f32_array(pixel.0);
+            // `set_color_at` truncates 8-bit channels, which would darken the image a bit more
+            // each time it is processed, so they are rounded instead.
+            let bytes = match format {
+                TextureFormat::Rgba8UnormSrgb => Srgba::from(color).to_u8_array(),
+                TextureFormat::Bgra8UnormSrgb => bgra(Srgba::from(color).to_u8_array()),
+                TextureFormat::Rgba8Unorm => color.to_u8_array(),
+                TextureFormat::Bgra8Unorm => bgra(color.to_u8_array()),
+                _ => {
+                    image.set_color_at(x, y, color.into())?;
+                    continue;
+                }
+            };
+            image
+                .pixel_bytes_mut(UVec3::new(x, y, 0))?
+                .copy_from_slice(&bytes);
+        }
+        image.sampler = self.sampler.clone();
+        image.texture_descriptor.usage = self.texture_descriptor.usage;
+        image.texture_descriptor.view_formats = self.texture_descriptor.view_formats;
+        image.texture_view_descriptor = self.texture_view_descriptor.clone();
+        Ok(image)
+    }
+}
+
+/// Swaps the red and blue channels of an RGBA pixel.
+fn bgra([r, g, b, a]: [u8; 4]) -> [u8; 4] {
+    [b, g, r, a]
+}
+
+fn filter_type(filter: ImageFilterMode) -> FilterType {
+    match filter {
+        ImageFilterMode::Nearest => FilterType::Nearest,
+        ImageFilterMode::Linear => FilterType::Triangle,
+    }
+}
+
+/// Bilinearly samples an equirectangular `panorama` in the given cubemap `direction`.
+fn sample_equirectangular(panorama: &Rgba32FImage, direction: Vec3) -> Vec4 {
+    // Cube textures are sampled in a left-handed coordinate system, so Z is flipped back to get
+    // the direction in world space.
+    let direction = direction.normalize() * Vec3::new(1.0, 1.0, -1.0);
+    let u = 0.5 + ops::atan2(direction.x, -direction.z) / TAU;
+    let v = ops::acos(direction.y.clamp(-1.0, 1.0)) / PI;
+
+    let (width, height) = (panorama.width() as i32, panorama.height() as i32);
+    let x = u * width as f32 - 0.5;
+    let y = v * height as f32 - 0.5;
+    let (x0, y0) = (ops::floor(x), ops::floor(y));
+    let (fx, fy) = (x - x0, y - y0);
+    let texel = |x: i32, y: i32| {
+        // The panorama wraps around horizontally, and is clamped at the poles.
+        let x = x.rem_euclid(width) as u32;
+        let y = y.clamp(0, height - 1) as u32;
+        Vec4::from_array(panorama.get_pixel(x, y).0)
+    };
+    let (x0, y0) = (x0 as i32, y0 as i32);
+    let top = texel(x0, y0).lerp(texel(x0 + 1, y0), fx);
+    let bottom = texel(x0, y0 + 1).lerp(texel(x0 + 1, y0 + 1), fx);
+    top.lerp(bottom, fy)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::RenderAssetUsages;
+    use bevy_color::{Color, LinearRgba, Srgba};
+    use bevy_math::{URect, UVec2};
+    use wgpu_types::{Extent3d, TextureDimension, TextureFormat, TextureViewDimension};
+
+    use crate::{Image, ImageFilterMode};
+
+    fn make_filled_image(size: UVec2, pixel: &[u8], format: TextureFormat) -> Image {
+        Image::new_fill(
+            Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            pixel,
+            format,
+            RenderAssetUsages::all(),
+        )
+    }
+
+    #[test]
+    fn copy_region_clips_and_converts() {
+        let mut image =
+            make_filled_image(UVec2::splat(4), &[0, 0, 0, 255], TextureFormat::Rgba8Unorm);
+        let source = make_filled_image(
+            UVec2::splat(4),
+            &[0, 0, 255, 255],
+            TextureFormat::Bgra8Unorm,
+        );
+
+        image
+            .copy_region(&source, URect::new(0, 0, 4, 4), UVec2::new(2, 3))
+            .unwrap();
+        assert_eq!(
+            image.get_color_at(2, 3).unwrap(),
+            Color::linear_rgba(1.0, 0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            image.get_color_at(3, 3).unwrap(),
+            Color::linear_rgba(1.0, 0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            image.get_color_at(1, 3).unwrap(),
+            Color::linear_rgba(0.0, 0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            image.get_color_at(2, 2).unwrap(),
+            Color::linear_rgba(0.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn blit_blends_with_alpha() {
+        let mut image =
+            make_filled_image(UVec2::splat(2), &[0, 0, 0, 255], TextureFormat::Rgba8Unorm);
+        let source = make_filled_image(
+            UVec2::splat(1),
+            &[255, 255, 255, 51],
+            TextureFormat::Rgba8Unorm,
+        );
+
+        image
+            .blit(&source, URect::new(0, 0, 1, 1), UVec2::ZERO)
+            .unwrap();
+        let LinearRgba { red, alpha, .. } = image.get_color_at(0, 0).unwrap().to_linear();
+        assert!((red - 0.2).abs() < 0.01);
+        assert_eq!(alpha, 1.0);
+        assert_eq!(
+            image.get_color_at(1, 1).unwrap(),
+            Color::linear_rgba(0.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn scale_and_generate_mipmaps() {
+        let mut image = make_filled_image(
+            UVec2::new(4, 2),
+            &[255, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+        );
+        image.set_color_at(0, 0, Srgba::WHITE.into()).unwrap();
+
+        let scaled = image
+            .scaled(UVec2::new(8, 4), ImageFilterMode::Nearest)
+            .unwrap();
+        assert_eq!(scaled.size(), UVec2::new(8, 4));
+        assert_eq!(scaled.get_color_at(1, 1).unwrap(), Srgba::WHITE.into());
+        assert_eq!(
+            scaled.get_color_at(7, 3).unwrap(),
+            Color::srgba(1.0, 0.0, 0.0, 1.0)
+        );
+
+        image.generate_mipmaps(ImageFilterMode::Linear).unwrap();
+        assert_eq!(image.texture_descriptor.mip_level_count, 3);
+        // 4x2, 2x1 and 1x1 pixels.
+        assert_eq!(image.data.as_ref().unwrap().len(), (8 + 2 + 1) * 4);
+    }
+
+    #[test]
+    fn convert_color_space() {
+        let image = make_filled_image(
+            UVec2::splat(1),
+            &[128, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+        );
+
+        let linear = image.convert_color_space(false).unwrap();
+        assert_eq!(linear.texture_descriptor.format, TextureFormat::Rgba8Unorm);
+        let expected = LinearRgba::from(Srgba::rgb_u8(128, 0, 0)).red;
+        let LinearRgba { red, .. } = linear.get_color_at(0, 0).unwrap().to_linear();
+        assert!((red - expected).abs() < 0.01);
+
+        let srgb = linear.convert_color_space(true).unwrap();
+        assert_eq!(
+            srgb.texture_descriptor.format,
+            TextureFormat::Rgba8UnormSrgb
+        );
+        assert!(srgb.data.as_ref().unwrap()[0].abs_diff(128) <= 1);
+        assert_eq!(srgb.data.as_ref().unwrap()[3], 255);
+    }
+
+    #[test]
+    fn equirectangular_to_cubemap() {
+        // The top half of the panorama is the sky, and the bottom half the ground.
+        let mut panorama =
+            make_filled_image(UVec2::new(8, 4), &[0; 16], TextureFormat::Rgba32Float);
+        for x in 0..8 {
+            for y in 0..2 {
+                panorama.set_color_at(x, y, Color::WHITE).unwrap();
+            }
+        }
+
+        let cubemap = panorama.equirectangular_to_cubemap(4).unwrap();
+        assert_eq!(cubemap.texture_descriptor.size.depth_or_array_layers, 6);
+        assert_eq!(
+            cubemap.texture_view_descriptor.as_ref().unwrap().dimension,
+            Some(TextureViewDimension::Cube)
+        );
+        // The +Y face looks up at the sky, and the -Y face down at the ground.
+        assert_eq!(
+            cubemap.get_color_at_3d(1, 2, 2).unwrap(),
+            Color::linear_rgba(1.0, 1.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            cubemap.get_color_at_3d(1, 2, 3).unwrap(),
+            Color::linear_rgba(0.0, 0.0, 0.0, 0.0)
+        );
+    }
+}
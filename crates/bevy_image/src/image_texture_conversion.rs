@@ -153,6 +153,8 @@ impl Image {
     /// - `TextureFormat::Rg8Unorm`
     /// - `TextureFormat::Rgba8UnormSrgb`
     /// - `TextureFormat::Bgra8UnormSrgb`
+    /// - `TextureFormat::Rgba16Float`, converted to 32-bit floats
+    /// - `TextureFormat::Rgba32Float`
     ///
     /// To convert [`Image`] to a different format see: [`Image::convert`].
     pub fn try_into_dynamic(self) -> Result<DynamicImage, IntoDynamicImageError> {
@@ -183,6 +185,28 @@ impl Image {
                 })
                 .map(DynamicImage::ImageRgba8)
             }
+            // Float formats are used by HDR render targets, and are kept as floats so that colors
+            // outside of the [0, 1] range can be saved to EXR or HDR files.
+            TextureFormat::Rgba32Float => ImageBuffer::from_raw(
+                width,
+                height,
+                data.as_chunks::<4>()
+                    .0
+                    .iter()
+                    .map(|&bytes| f32::from_le_bytes(bytes))
+                    .collect(),
+            )
+            .map(DynamicImage::ImageRgba32F),
+            TextureFormat::Rgba16Float => ImageBuffer::from_raw(
+                width,
+                height,
+                data.as_chunks::<2>()
+                    .0
+                    .iter()
+                    .map(|&bytes| half::f16::from_le_bytes(bytes).to_f32())
+                    .collect(),
+            )
+            .map(DynamicImage::ImageRgba32F),
             // Throw and error if conversion isn't supported
             texture_format => return Err(IntoDynamicImageError::UnsupportedFormat(texture_format)),
         }
@@ -247,4 +271,32 @@ mod test {
         );
         assert_eq!(luma_a16.texture_descriptor.format, TextureFormat::Rg16Unorm);
     }
+
+    #[test]
+    fn float_conversion() {
+        // Colors outside of the [0, 1] range are kept by float formats.
+        let pixel = [4.0, 0.5, -1.0, 1.0];
+        let mut initial = DynamicImage::new_rgba32f(1, 1);
+        initial
+            .as_mut_rgba32f()
+            .unwrap()
+            .put_pixel(0, 0, Rgba(pixel));
+
+        let image = Image::from_dynamic(initial.clone(), false, RenderAssetUsages::RENDER_WORLD);
+        assert_eq!(image.texture_descriptor.format, TextureFormat::Rgba32Float);
+        assert_eq!(initial, image.try_into_dynamic().unwrap());
+
+        let half_data = pixel
+            .iter()
+            .flat_map(|&channel| half::f16::from_f32(channel).to_le_bytes())
+            .collect();
+        let half_image = Image::new(
+            Extent3d::default(),
+            TextureDimension::D2,
+            half_data,
+            TextureFormat::Rgba16Float,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        assert_eq!(initial, half_image.try_into_dynamic().unwrap());
+    }
 }
@@ -31,6 +31,39 @@ pub fn ktx2_buffer_to_image(
     buffer: &[u8],
     supported_compressed_formats: CompressedImageFormats,
     is_srgb: bool,
+) -> Result<Image, TextureError> {
+    ktx2_buffer_to_image_with_transcode_targets(
+        buffer,
+        supported_compressed_formats,
+        CompressedImageFormats::all(),
+        is_srgb,
+    )
+}
+
+/// Converts KTX2 bytes to a bevy [`Image`] using the given compressed format support, only
+/// transcoding Basis Universal data to the compressed formats in `transcode_targets`.
+///
+/// Basis Universal data is transcoded to an uncompressed format if none of the
+/// `transcode_targets` is supported. Textures which are already compressed are loaded as they
+/// are, regardless of the `transcode_targets`.
+///
+/// # Errors
+///
+/// Returns an error if the provided buffer contained invalid data, decompression fails, or transcoding
+/// of unsupported data formats fails.
+#[cfg(feature = "ktx2")]
+#[cfg_attr(
+    not(feature = "basis-universal"),
+    expect(
+        unused_variables,
+        reason = "only used with the basis-universal feature"
+    )
+)]
+pub fn ktx2_buffer_to_image_with_transcode_targets(
+    buffer: &[u8],
+    supported_compressed_formats: CompressedImageFormats,
+    transcode_targets: CompressedImageFormats,
+    is_srgb: bool,
 ) -> Result<Image, TextureError> {
     let ktx2 = ktx2::Reader::new(buffer)
         .map_err(|err| TextureError::InvalidData(format!("Failed to parse ktx2 file: {err:?}")))?;
@@ -170,8 +203,18 @@ pub fn ktx2_buffer_to_image(
                 }
                 #[cfg(feature = "basis-universal")]
                 TranscodeFormat::Uastc(data_format) => {
-                    let (transcode_block_format, texture_format) =
-                        get_transcoded_formats(supported_compressed_formats, data_format, is_srgb);
+                    let (transcode_block_format, texture_format) = get_transcoded_formats(
+                        supported_compressed_formats & transcode_targets,
+                        data_format,
+                        is_srgb,
+                    );
+                    if !texture_format.is_compressed() {
+                        crate::basis::warn_uncompressed_fallback(
+                            texture_format,
+                            supported_compressed_formats,
+                            transcode_targets,
+                        );
+                    }
                     let texture_format_info = texture_format;
                     let (block_width_pixels, block_height_pixels) = (
                         texture_format_info.block_dimensions().0,
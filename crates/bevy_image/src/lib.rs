@@ -37,6 +37,7 @@ mod exr_texture_loader;
 #[cfg(feature = "hdr")]
 mod hdr_texture_loader;
 mod image_loader;
+mod image_manipulation;
 #[cfg(feature = "ktx2")]
 mod ktx2;
 mod saver;
@@ -1,5 +1,5 @@
 use bevy_app::prelude::*;
-use bevy_asset::{Asset, AssetApp as _, AssetId, Assets, Handle};
+use bevy_asset::{Asset, AssetApp as _, AssetId, AssetPath, Assets, Handle};
 use bevy_ecs::template::FromTemplate;
 use bevy_math::{Rect, URect, UVec2};
 use bevy_platform::collections::HashMap;
@@ -29,10 +29,13 @@ impl Plugin for TextureAtlasPlugin {
 /// Generated by [`TextureAtlasBuilder`].
 ///
 /// [`TextureAtlasBuilder`]: crate::TextureAtlasBuilder
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct TextureAtlasSources {
     /// Maps from a specific image handle to the index in `textures` where they can be found.
     pub texture_ids: HashMap<AssetId<Image>, usize>,
+    /// Maps from the asset path of a source image to the index in `textures` where it can be
+    /// found.
+    pub texture_paths: HashMap<AssetPath<'static>, usize>,
 }
 
 impl TextureAtlasSources {
@@ -42,6 +45,11 @@ impl TextureAtlasSources {
         self.texture_ids.get(&id).cloned()
     }
 
+    /// Retrieves the texture *section* index of the source image loaded from `path`.
+    pub fn path_index<'a>(&self, path: impl Into<AssetPath<'a>>) -> Option<usize> {
+        self.texture_paths.get(&path.into().into_owned()).cloned()
+    }
+
     /// Creates a [`TextureAtlas`] handle for the given `texture` handle.
     pub fn handle(
         &self,
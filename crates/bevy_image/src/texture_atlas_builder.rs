@@ -1,15 +1,17 @@
-use bevy_asset::{AssetId, RenderAssetUsages};
+use bevy_asset::{AssetId, AssetPath, RenderAssetUsages};
 use bevy_math::{URect, UVec2};
-use bevy_platform::collections::HashMap;
 use rectangle_pack::{
-    contains_smallest_box, pack_rects, volume_heuristic, GroupedRectsToPlace, PackedLocation,
-    RectToInsert, TargetBin,
+    contains_smallest_box, pack_rects, volume_heuristic, GroupedRectsToPlace, RectToInsert,
+    RectanglePackOk, TargetBin,
 };
 use thiserror::Error;
 use tracing::{debug, error, warn};
 use wgpu_types::{Extent3d, TextureDimension, TextureFormat};
 
-use crate::{Image, TextureAccessError, TextureFormatPixelInfo};
+use crate::{
+    DynamicTextureAtlasBuilder, DynamicTextureAtlasBuilderError, Image, TextureAccessError,
+    TextureFormatPixelInfo,
+};
 use crate::{TextureAtlasLayout, TextureAtlasSources};
 
 /// Errors returned by [`TextureAtlasBuilder`].
@@ -32,13 +34,62 @@ pub enum TextureAtlasBuilderError {
     TextureAccess(#[from] TextureAccessError),
 }
 
+/// Copies the pixels of a texture to the `rect` of an atlas `atlas_width` pixels wide, and
+/// repeats its outermost pixels `extrusion` times around it.
+pub(crate) fn copy_extruded_texture(
+    atlas_data: &mut [u8],
+    atlas_width: u32,
+    data: &[u8],
+    rect: URect,
+    extrusion: u32,
+    format_size: usize,
+) {
+    let rect_width = rect.width() as usize;
+    let rect_height = rect.height() as usize;
+    let rect_x = rect.min.x as usize;
+    let rect_y = rect.min.y as usize;
+    let atlas_width = atlas_width as usize;
+    let extrusion = extrusion as usize;
+    if rect_width == 0 || rect_height == 0 {
+        return;
+    }
+    for bound_y in rect_y - extrusion..rect_y + rect_height + extrusion {
+        // The extruded rows repeat the top and bottom rows of the texture.
+        let texture_y = bound_y.saturating_sub(rect_y).min(rect_height - 1);
+        let begin = (bound_y * atlas_width + rect_x) * format_size;
+        let end = begin + rect_width * format_size;
+        let texture_begin = texture_y * rect_width * format_size;
+        let texture_end = texture_begin + rect_width * format_size;
+        atlas_data[begin..end].copy_from_slice(&data[texture_begin..texture_end]);
+        // The extruded columns repeat the leftmost and rightmost pixels of the row.
+        for column in 1..=extrusion {
+            atlas_data.copy_within(begin..begin + format_size, begin - column * format_size);
+            atlas_data.copy_within(end - format_size..end, end + (column - 1) * format_size);
+        }
+    }
+}
+
+/// A texture added to a [`TextureAtlasBuilder`], waiting to be packed.
+#[derive(Debug)]
+struct TextureToPlace<'a> {
+    image_id: Option<AssetId<Image>>,
+    path: Option<AssetPath<'static>>,
+    texture: &'a Image,
+}
+
+/// A page of a texture atlas built by [`TextureAtlasBuilder::build_pages`].
+pub type TextureAtlasPage = (TextureAtlasLayout, TextureAtlasSources, Image);
+
 #[derive(Debug)]
 #[must_use]
 /// A builder which is used to create a texture atlas from many individual
 /// sprites.
+///
+/// To keep adding textures to the atlas at runtime, once it has been built, use
+/// [`build_dynamic`](Self::build_dynamic).
 pub struct TextureAtlasBuilder<'a> {
-    /// Collection of texture's asset id (optional) and image data to be packed into an atlas
-    textures_to_place: Vec<(Option<AssetId<Image>>, &'a Image)>,
+    /// Collection of texture's asset id (optional), asset path (optional) and image data to be packed into an atlas
+    textures_to_place: Vec<TextureToPlace<'a>>,
     /// The initial atlas size in pixels.
     initial_size: UVec2,
     /// The absolute maximum size of the texture atlas in pixels.
@@ -49,6 +100,8 @@ pub struct TextureAtlasBuilder<'a> {
     auto_format_conversion: bool,
     /// The amount of padding in pixels to add along the right and bottom edges of the texture rects.
     padding: UVec2,
+    /// The number of pixels by which the edges of the textures are extruded.
+    extrusion: u32,
 }
 
 impl Default for TextureAtlasBuilder<'_> {
@@ -60,6 +113,7 @@ impl Default for TextureAtlasBuilder<'_> {
             format: TextureFormat::Rgba8UnormSrgb,
             auto_format_conversion: true,
             padding: UVec2::ZERO,
+            extrusion: 0,
         }
     }
 }
@@ -101,7 +155,29 @@ impl<'a> TextureAtlasBuilder<'a> {
         image_id: Option<AssetId<Image>>,
         texture: &'a Image,
     ) -> &mut Self {
-        self.textures_to_place.push((image_id, texture));
+        self.textures_to_place.push(TextureToPlace {
+            image_id,
+            path: None,
+            texture,
+        });
+        self
+    }
+
+    /// Adds a texture loaded from `path` to be copied to the texture atlas.
+    ///
+    /// The path can later be used with [`TextureAtlasSources::path_index`] to retrieve the index
+    /// of this texture, for instance to look up the sprites of a folder by their file names.
+    pub fn add_texture_with_path(
+        &mut self,
+        image_id: Option<AssetId<Image>>,
+        path: impl Into<AssetPath<'static>>,
+        texture: &'a Image,
+    ) -> &mut Self {
+        self.textures_to_place.push(TextureToPlace {
+            image_id,
+            path: Some(path.into()),
+            texture,
+        });
         self
     }
 
@@ -113,17 +189,26 @@ impl<'a> TextureAtlasBuilder<'a> {
         self
     }
 
+    /// Sets the number of pixels by which the edges of the textures are extruded in the texture
+    /// atlas.
+    ///
+    /// The outermost pixels of every texture are repeated this many times on each side, so that
+    /// linear filtering and mipmapping sample the edge of the texture rather than its neighbors,
+    /// which prevents them from bleeding into each other. The extruded pixels are not part of the
+    /// texture rects of the [`TextureAtlasLayout`], and come in addition to the
+    /// [`padding`](Self::padding).
+    pub fn extrusion(&mut self, extrusion: u32) -> &mut Self {
+        self.extrusion = extrusion;
+        self
+    }
+
     fn copy_texture_to_atlas(
         atlas_texture: &mut Image,
         texture: &Image,
-        packed_location: &PackedLocation,
-        padding: UVec2,
+        min: UVec2,
+        extrusion: u32,
     ) -> TextureAtlasBuilderResult<()> {
-        let rect_width = (packed_location.width() - padding.x) as usize;
-        let rect_height = (packed_location.height() - padding.y) as usize;
-        let rect_x = packed_location.x() as usize;
-        let rect_y = packed_location.y() as usize;
-        let atlas_width = atlas_texture.width() as usize;
+        let atlas_width = atlas_texture.width();
         let format_size = atlas_texture.texture_descriptor.format.pixel_size()?;
 
         let Some(ref mut atlas_data) = atlas_texture.data else {
@@ -132,13 +217,14 @@ impl<'a> TextureAtlasBuilder<'a> {
         let Some(ref data) = texture.data else {
             return Err(TextureAtlasBuilderError::UninitializedSourceTexture);
         };
-        for (texture_y, bound_y) in (rect_y..rect_y + rect_height).enumerate() {
-            let begin = (bound_y * atlas_width + rect_x) * format_size;
-            let end = begin + rect_width * format_size;
-            let texture_begin = texture_y * rect_width * format_size;
-            let texture_end = texture_begin + rect_width * format_size;
-            atlas_data[begin..end].copy_from_slice(&data[texture_begin..texture_end]);
-        }
+        copy_extruded_texture(
+            atlas_data,
+            atlas_width,
+            data,
+            URect::from_corners(min, min + texture.size()),
+            extrusion,
+            format_size,
+        );
         Ok(())
     }
 
@@ -146,21 +232,16 @@ impl<'a> TextureAtlasBuilder<'a> {
         &self,
         atlas_texture: &mut Image,
         texture: &Image,
-        packed_location: &PackedLocation,
+        min: UVec2,
     ) -> TextureAtlasBuilderResult<()> {
         if self.format == texture.texture_descriptor.format {
-            Self::copy_texture_to_atlas(atlas_texture, texture, packed_location, self.padding)?;
+            Self::copy_texture_to_atlas(atlas_texture, texture, min, self.extrusion)?;
         } else if let Some(converted_texture) = texture.convert(self.format) {
             debug!(
                 "Converting texture from '{:?}' to '{:?}'",
                 texture.texture_descriptor.format, self.format
             );
-            Self::copy_texture_to_atlas(
-                atlas_texture,
-                &converted_texture,
-                packed_location,
-                self.padding,
-            )?;
+            Self::copy_texture_to_atlas(atlas_texture, &converted_texture, min, self.extrusion)?;
         } else {
             error!(
                 "Error converting texture from '{:?}' to '{:?}', ignoring",
@@ -170,6 +251,118 @@ impl<'a> TextureAtlasBuilder<'a> {
         Ok(())
     }
 
+    /// The size of the rectangles the textures take in the atlas, including their extrusion and
+    /// padding.
+    fn rects_to_place(&self) -> GroupedRectsToPlace<usize> {
+        let mut rects_to_place = GroupedRectsToPlace::<usize>::new();
+        for (index, texture_to_place) in self.textures_to_place.iter().enumerate() {
+            let size = texture_to_place.texture.size() + self.padding + 2 * self.extrusion;
+            rects_to_place.push_rect(index, None, RectToInsert::new(size.x, size.y, 1));
+        }
+        rects_to_place
+    }
+
+    /// Packs the textures in a single page, starting with the initial size and doubling it until
+    /// they fit, up to the max size.
+    fn pack_single_page(
+        &self,
+        rects_to_place: &GroupedRectsToPlace<usize>,
+    ) -> Option<(UVec2, RectanglePackOk<usize, usize>)> {
+        let max_width = self.max_size.x;
+        let max_height = self.max_size.y;
+
+        let mut current_width = self.initial_size.x;
+        let mut current_height = self.initial_size.y;
+
+        loop {
+            if current_width > max_width || current_height > max_height {
+                return None;
+            }
+
+            let last_attempt = current_height == max_height && current_width == max_width;
+
+            let mut target_bins = alloc::collections::BTreeMap::new();
+            target_bins.insert(0, TargetBin::new(current_width, current_height, 1));
+            match pack_rects(
+                rects_to_place,
+                &mut target_bins,
+                &volume_heuristic,
+                &contains_smallest_box,
+            ) {
+                Ok(rect_placements) => {
+                    return Some((UVec2::new(current_width, current_height), rect_placements));
+                }
+                Err(rectangle_pack::RectanglePackError::NotEnoughBinSpace) => {
+                    current_height = (current_height * 2).clamp(0, max_height);
+                    current_width = (current_width * 2).clamp(0, max_width);
+                }
+            }
+
+            if last_attempt {
+                return None;
+            }
+        }
+    }
+
+    /// Creates a blank atlas texture of the given size.
+    fn new_atlas_texture(&self, size: UVec2) -> TextureAtlasBuilderResult<Image> {
+        Ok(Image::new(
+            Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            vec![0; self.format.pixel_size()? * (size.x * size.y) as usize],
+            self.format,
+            RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
+        ))
+    }
+
+    /// Creates the pages of the given sizes and copies the textures to them, where they have been
+    /// packed.
+    fn fill_pages(
+        &self,
+        rect_placements: &RectanglePackOk<usize, usize>,
+        page_sizes: &[UVec2],
+    ) -> TextureAtlasBuilderResult<Vec<TextureAtlasPage>> {
+        let mut pages = Vec::with_capacity(page_sizes.len());
+        for size in page_sizes {
+            pages.push((
+                TextureAtlasLayout::new_empty(*size),
+                TextureAtlasSources::default(),
+                self.new_atlas_texture(*size)?,
+            ));
+        }
+
+        // We iterate through the textures to place to respect the insertion order for the texture indices
+        for (index, texture_to_place) in self.textures_to_place.iter().enumerate() {
+            let texture = texture_to_place.texture;
+            let (page, packed_location) = rect_placements.packed_locations().get(&index).unwrap();
+            let (layout, sources, atlas_texture) = &mut pages[*page];
+
+            let min = UVec2::new(packed_location.x(), packed_location.y()) + self.extrusion;
+            let max = min + texture.size();
+            let texture_index = layout.add_texture(URect { min, max });
+            if let Some(image_id) = texture_to_place.image_id {
+                sources.texture_ids.insert(image_id, texture_index);
+            }
+            if let Some(path) = &texture_to_place.path {
+                sources.texture_paths.insert(path.clone(), texture_index);
+            }
+            if texture.texture_descriptor.format != self.format && !self.auto_format_conversion {
+                warn!(
+                    "Loading a texture of format '{:?}' in an atlas with format '{:?}'",
+                    texture.texture_descriptor.format, self.format
+                );
+                return Err(TextureAtlasBuilderError::WrongFormat);
+            }
+            self.copy_converted_texture(atlas_texture, texture, min)?;
+        }
+
+        Ok(pages)
+    }
+
     /// Consumes the builder, and returns the newly created texture atlas and
     /// the associated atlas layout.
     ///
@@ -199,108 +392,264 @@ impl<'a> TextureAtlasBuilder<'a> {
     /// # Errors
     ///
     /// If there is not enough space in the atlas texture, an error will
-    /// be returned. It is then recommended to make a larger sprite sheet.
+    /// be returned. It is then recommended to make a larger sprite sheet,
+    /// or to use [`build_pages`](Self::build_pages).
     pub fn build(
         &mut self,
     ) -> TextureAtlasBuilderResult<(TextureAtlasLayout, TextureAtlasSources, Image)> {
-        let max_width = self.max_size.x;
-        let max_height = self.max_size.y;
+        let rects_to_place = self.rects_to_place();
+        let (size, rect_placements) = self
+            .pack_single_page(&rects_to_place)
+            .ok_or(TextureAtlasBuilderError::NotEnoughSpace)?;
+        let mut pages = self.fill_pages(&rect_placements, &[size])?;
 
-        let mut current_width = self.initial_size.x;
-        let mut current_height = self.initial_size.y;
-        let mut rect_placements = None;
-        let mut atlas_texture = Image::default();
-        let mut rects_to_place = GroupedRectsToPlace::<usize>::new();
+        Ok(pages.pop().unwrap())
+    }
 
-        // Adds textures to rectangle group packer
-        for (index, (_, texture)) in self.textures_to_place.iter().enumerate() {
-            rects_to_place.push_rect(
-                index,
-                None,
-                RectToInsert::new(
-                    texture.width() + self.padding.x,
-                    texture.height() + self.padding.y,
-                    1,
-                ),
-            );
+    /// Consumes the builder, and returns as many texture atlas pages as needed to fit all the
+    /// textures within the max size.
+    ///
+    /// If the textures fit in a single page, this returns the same atlas as
+    /// [`build`](Self::build). Otherwise the textures are spread over several pages of the max
+    /// size. The indices of the [`TextureAtlasLayout`] and [`TextureAtlasSources`] of each page
+    /// refer to the textures of that page only, in insertion order, so a texture is found by
+    /// looking it up in the sources of each page in turn.
+    ///
+    /// # Errors
+    ///
+    /// If a texture, with its extrusion and padding, is larger than the max size, an error will
+    /// be returned.
+    pub fn build_pages(&mut self) -> TextureAtlasBuilderResult<Vec<TextureAtlasPage>> {
+        let rects_to_place = self.rects_to_place();
+        if let Some((size, rect_placements)) = self.pack_single_page(&rects_to_place) {
+            return self.fill_pages(&rect_placements, &[size]);
         }
 
-        while rect_placements.is_none() {
-            if current_width > max_width || current_height > max_height {
-                break;
-            }
-
-            let last_attempt = current_height == max_height && current_width == max_width;
+        let border = self.padding + 2 * self.extrusion;
+        if self.textures_to_place.iter().any(|texture_to_place| {
+            (texture_to_place.texture.size() + border)
+                .cmpgt(self.max_size)
+                .any()
+        }) {
+            return Err(TextureAtlasBuilderError::NotEnoughSpace);
+        }
 
+        // Every texture fits in a page of its own, so this eventually succeeds.
+        for page_count in 2..=self.textures_to_place.len() {
             let mut target_bins = alloc::collections::BTreeMap::new();
-            target_bins.insert(0, TargetBin::new(current_width, current_height, 1));
-            rect_placements = match pack_rects(
+            for page in 0..page_count {
+                target_bins.insert(page, TargetBin::new(self.max_size.x, self.max_size.y, 1));
+            }
+            if let Ok(rect_placements) = pack_rects(
                 &rects_to_place,
                 &mut target_bins,
                 &volume_heuristic,
                 &contains_smallest_box,
             ) {
-                Ok(rect_placements) => {
-                    atlas_texture = Image::new(
-                        Extent3d {
-                            width: current_width,
-                            height: current_height,
-                            depth_or_array_layers: 1,
-                        },
-                        TextureDimension::D2,
-                        vec![
-                            0;
-                            self.format.pixel_size()? * (current_width * current_height) as usize
-                        ],
-                        self.format,
-                        RenderAssetUsages::MAIN_WORLD | RenderAssetUsages::RENDER_WORLD,
-                    );
-                    Some(rect_placements)
-                }
-                Err(rectangle_pack::RectanglePackError::NotEnoughBinSpace) => {
-                    current_height = (current_height * 2).clamp(0, max_height);
-                    current_width = (current_width * 2).clamp(0, max_width);
-                    None
-                }
-            };
-
-            if last_attempt {
-                break;
+                let mut pages =
+                    self.fill_pages(&rect_placements, &vec![self.max_size; page_count])?;
+                // The packer may leave some of the pages empty.
+                pages.retain(|(layout, ..)| !layout.is_empty());
+                return Ok(pages);
             }
         }
 
-        let rect_placements = rect_placements.ok_or(TextureAtlasBuilderError::NotEnoughSpace)?;
+        Err(TextureAtlasBuilderError::NotEnoughSpace)
+    }
 
-        let mut texture_rects = Vec::with_capacity(rect_placements.packed_locations().len());
-        let mut texture_ids = <HashMap<_, _>>::default();
-        // We iterate through the textures to place to respect the insertion order for the texture indices
-        for (index, (image_id, texture)) in self.textures_to_place.iter().enumerate() {
-            let (_, packed_location) = rect_placements.packed_locations().get(&index).unwrap();
-
-            let min = UVec2::new(packed_location.x(), packed_location.y());
-            let max =
-                min + UVec2::new(packed_location.width(), packed_location.height()) - self.padding;
-            if let Some(image_id) = image_id {
-                texture_ids.insert(*image_id, index);
-            }
-            texture_rects.push(URect { min, max });
-            if texture.texture_descriptor.format != self.format && !self.auto_format_conversion {
+    /// Consumes the builder, and returns a texture atlas of the max size along with a
+    /// [`DynamicTextureAtlasBuilder`] to keep adding textures to it at runtime.
+    ///
+    /// The textures are placed in insertion order, in the same way as they will be by
+    /// [`DynamicTextureAtlasBuilder::add_texture_with_id`], which leaves room for the ones added
+    /// later. The atlas is not as tightly packed as the one returned by [`build`](Self::build),
+    /// and the padding is added on all sides of the textures, using the largest of its `x` and
+    /// `y` values.
+    ///
+    /// # Errors
+    ///
+    /// If there is not enough space in the atlas texture, or a texture can't be converted to
+    /// the atlas format, an error will be returned.
+    pub fn build_dynamic(
+        &mut self,
+    ) -> TextureAtlasBuilderResult<(
+        TextureAtlasLayout,
+        TextureAtlasSources,
+        Image,
+        DynamicTextureAtlasBuilder,
+    )> {
+        let mut atlas_layout = TextureAtlasLayout::new_empty(self.max_size);
+        let mut atlas_sources = TextureAtlasSources::default();
+        let mut atlas_texture = self.new_atlas_texture(self.max_size)?;
+        let mut builder =
+            DynamicTextureAtlasBuilder::new(self.max_size, self.padding.max_element())
+                .with_extrusion(self.extrusion);
+
+        for texture_to_place in &self.textures_to_place {
+            let texture = texture_to_place.texture;
+            let converted_texture;
+            let texture = if texture.texture_descriptor.format == self.format {
+                texture
+            } else if let Some(texture) = self
+                .auto_format_conversion
+                .then(|| texture.convert(self.format))
+                .flatten()
+            {
+                converted_texture = texture;
+                &converted_texture
+            } else {
                 warn!(
                     "Loading a texture of format '{:?}' in an atlas with format '{:?}'",
                     texture.texture_descriptor.format, self.format
                 );
                 return Err(TextureAtlasBuilderError::WrongFormat);
+            };
+
+            let texture_index = builder
+                .add_texture(&mut atlas_layout, texture, &mut atlas_texture)
+                .map_err(|error| match error {
+                    DynamicTextureAtlasBuilderError::FailedToAllocateSpace => {
+                        TextureAtlasBuilderError::NotEnoughSpace
+                    }
+                    DynamicTextureAtlasBuilderError::UninitializedAtlas => {
+                        TextureAtlasBuilderError::UninitializedAtlas
+                    }
+                    DynamicTextureAtlasBuilderError::UninitializedSourceTexture => {
+                        TextureAtlasBuilderError::UninitializedSourceTexture
+                    }
+                    DynamicTextureAtlasBuilderError::TextureAccess(error) => {
+                        TextureAtlasBuilderError::TextureAccess(error)
+                    }
+                })?;
+            if let Some(image_id) = texture_to_place.image_id {
+                atlas_sources.texture_ids.insert(image_id, texture_index);
+            }
+            if let Some(path) = &texture_to_place.path {
+                atlas_sources
+                    .texture_paths
+                    .insert(path.clone(), texture_index);
             }
-            self.copy_converted_texture(&mut atlas_texture, texture, packed_location)?;
         }
 
-        Ok((
-            TextureAtlasLayout {
-                size: atlas_texture.size(),
-                textures: texture_rects,
+        Ok((atlas_layout, atlas_sources, atlas_texture, builder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::{AssetPath, RenderAssetUsages};
+    use bevy_math::{URect, UVec2};
+
+    use crate::{Image, TextureAtlasBuilder, TextureAtlasBuilderError};
+
+    fn make_filled_image(size: UVec2, pixel_rgba_bytes: [u8; 4]) -> Image {
+        Image::new_fill(
+            wgpu_types::Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
             },
-            TextureAtlasSources { texture_ids },
-            atlas_texture,
-        ))
+            wgpu_types::TextureDimension::D2,
+            &pixel_rgba_bytes,
+            wgpu_types::TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::all(),
+        )
+    }
+
+    fn pixel(image: &Image, position: UVec2) -> [u8; 4] {
+        let byte_start = ((position.x + position.y * image.width()) * 4) as usize;
+        image.data.as_ref().unwrap()[byte_start..byte_start + 4]
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn extrusion_repeats_the_edge_pixels() {
+        let mut texture = make_filled_image(UVec2::new(2, 2), [0, 0, 0, 255]);
+        texture.data.as_mut().unwrap()[0..4].copy_from_slice(&[255, 0, 0, 255]);
+
+        let mut builder = TextureAtlasBuilder::default();
+        builder
+            .initial_size(UVec2::splat(8))
+            .extrusion(2)
+            .add_texture(None, &texture);
+        let (layout, _, atlas_texture) = builder.build().unwrap();
+
+        let rect = layout.textures[0];
+        assert_eq!(rect.size(), UVec2::new(2, 2));
+        assert_eq!(rect.min, UVec2::new(2, 2));
+        // The top left pixel is repeated above and to the left of the texture.
+        assert_eq!(pixel(&atlas_texture, UVec2::new(0, 0)), [255, 0, 0, 255]);
+        assert_eq!(pixel(&atlas_texture, UVec2::new(2, 0)), [255, 0, 0, 255]);
+        assert_eq!(pixel(&atlas_texture, UVec2::new(0, 2)), [255, 0, 0, 255]);
+        assert_eq!(pixel(&atlas_texture, UVec2::new(5, 5)), [0, 0, 0, 255]);
+        assert_eq!(pixel(&atlas_texture, UVec2::new(3, 0)), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn build_pages_when_over_max_size() {
+        let textures: Vec<_> = (0..6)
+            .map(|index| make_filled_image(UVec2::splat(8), [index, 0, 0, 255]))
+            .collect();
+
+        let mut builder = TextureAtlasBuilder::default();
+        builder
+            .initial_size(UVec2::splat(16))
+            .max_size(UVec2::splat(16));
+        for (index, texture) in textures.iter().enumerate() {
+            builder.add_texture_with_path(None, format!("sprites/{index}.png"), texture);
+        }
+        assert!(matches!(
+            builder.build(),
+            Err(TextureAtlasBuilderError::NotEnoughSpace)
+        ));
+
+        let pages = builder.build_pages().unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(
+            pages.iter().map(|(layout, ..)| layout.len()).sum::<usize>(),
+            6
+        );
+        for index in 0..6u8 {
+            let path = AssetPath::from(format!("sprites/{index}.png"));
+            let (layout, texture_index, atlas_texture) = pages
+                .iter()
+                .find_map(|(layout, sources, atlas_texture)| {
+                    Some((layout, sources.path_index(path.clone())?, atlas_texture))
+                })
+                .unwrap();
+            let rect = layout.textures[texture_index];
+            assert_eq!(pixel(atlas_texture, rect.min), [index, 0, 0, 255]);
+        }
+    }
+
+    #[test]
+    fn build_dynamic_and_add_textures() {
+        let texture = make_filled_image(UVec2::splat(4), [255, 0, 0, 255]);
+        let mut builder = TextureAtlasBuilder::default();
+        builder
+            .max_size(UVec2::splat(16))
+            .add_texture_with_path(None, "red.png", &texture);
+        let (mut layout, mut sources, mut atlas_texture, mut dynamic_builder) =
+            builder.build_dynamic().unwrap();
+        assert_eq!(layout.textures[0], URect::new(0, 0, 4, 4));
+        assert_eq!(sources.path_index("red.png"), Some(0));
+
+        let added = make_filled_image(UVec2::splat(4), [0, 255, 0, 255]);
+        let image_id = bevy_asset::AssetId::<Image>::default();
+        let index = dynamic_builder
+            .add_texture_with_id(
+                &mut layout,
+                &mut sources,
+                image_id,
+                &added,
+                &mut atlas_texture,
+            )
+            .unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(sources.texture_index(image_id), Some(1));
+        let rect = layout.textures[1];
+        assert!(rect.intersect(layout.textures[0]).is_empty());
+        assert_eq!(pixel(&atlas_texture, rect.min), [0, 255, 0, 255]);
     }
 }
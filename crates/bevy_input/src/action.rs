@@ -0,0 +1,553 @@
+//! Mapping of keyboard, mouse, gamepad and touch inputs to named actions.
+
+use alloc::vec::Vec;
+use core::{hash::Hash, marker::PhantomData};
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use bevy_platform::collections::HashMap;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+#[cfg(feature = "gamepad")]
+use crate::gamepad::{Gamepad, GamepadAxis, GamepadButton};
+#[cfg(feature = "keyboard")]
+use crate::keyboard::KeyCode;
+#[cfg(feature = "mouse")]
+use crate::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButton};
+#[cfg(feature = "touch")]
+use crate::touch::Touches;
+use crate::{ButtonInput, InputSystems};
+
+/// The absolute value from which an analog input, such as a gamepad stick, presses an action.
+pub const ACTION_PRESS_THRESHOLD: f32 = 0.5;
+
+/// A game action triggered by inputs, such as jumping or moving, usually defined as an enum.
+///
+/// This is implemented for all the types which can be used as the actions of an [`InputMap`].
+pub trait Action: Clone + Eq + Hash + Send + Sync + 'static {}
+
+impl<T: Clone + Eq + Hash + Send + Sync + 'static> Action for T {}
+
+/// An axis of the motion of the mouse or of its wheel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum MouseAxis {
+    /// The horizontal axis, positive to the right.
+    X,
+    /// The vertical axis, positive downwards for the motion and upwards for the wheel.
+    Y,
+}
+
+/// An input which can trigger an [`Action`].
+///
+/// Buttons have a value of 1 while pressed and 0 otherwise, while analog inputs have the value of
+/// their axis, or of their pressure for analog gamepad buttons.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputBinding {
+    /// A key of the keyboard, by its physical position.
+    #[cfg(feature = "keyboard")]
+    Key(KeyCode),
+    /// A mouse button.
+    #[cfg(feature = "mouse")]
+    MouseButton(MouseButton),
+    /// An axis of the motion of the mouse since the last frame, in pixels.
+    #[cfg(feature = "mouse")]
+    MouseMotion(MouseAxis),
+    /// An axis of the scrolling of the mouse wheel since the last frame.
+    #[cfg(feature = "mouse")]
+    MouseScroll(MouseAxis),
+    /// A button of any connected gamepad.
+    #[cfg(feature = "gamepad")]
+    GamepadButton(GamepadButton),
+    /// An axis of any connected gamepad, such as a stick. When several gamepads are connected, the
+    /// value furthest from zero is used.
+    #[cfg(feature = "gamepad")]
+    GamepadAxis(GamepadAxis),
+    /// A finger touching the screen.
+    #[cfg(feature = "touch")]
+    Touch,
+}
+
+#[cfg(feature = "keyboard")]
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        Self::Key(key)
+    }
+}
+
+#[cfg(feature = "mouse")]
+impl From<MouseButton> for InputBinding {
+    fn from(button: MouseButton) -> Self {
+        Self::MouseButton(button)
+    }
+}
+
+#[cfg(feature = "gamepad")]
+impl From<GamepadButton> for InputBinding {
+    fn from(button: GamepadButton) -> Self {
+        Self::GamepadButton(button)
+    }
+}
+
+#[cfg(feature = "gamepad")]
+impl From<GamepadAxis> for InputBinding {
+    fn from(axis: GamepadAxis) -> Self {
+        Self::GamepadAxis(axis)
+    }
+}
+
+/// An [`InputBinding`] of an [`Action`], with the factor applied to the value of the input.
+///
+/// A negative scale lets two buttons drive an axis, for example with the left arrow key bound
+/// with a scale of -1 and the right arrow key with a scale of 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ActionBinding {
+    /// The bound input.
+    pub input: InputBinding,
+    /// The factor applied to the value of the input.
+    #[cfg_attr(feature = "serialize", serde(default = "default_scale"))]
+    pub scale: f32,
+}
+
+#[cfg(feature = "serialize")]
+fn default_scale() -> f32 {
+    1.0
+}
+
+impl ActionBinding {
+    /// Binds `input` with a scale of 1.
+    pub fn new(input: impl Into<InputBinding>) -> Self {
+        Self {
+            input: input.into(),
+            scale: 1.0,
+        }
+    }
+
+    /// Returns this binding with its value multiplied by `scale`.
+    pub fn scaled(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+macro_rules! impl_from_input {
+    ($($(#[$meta:meta])* $input:ty),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            impl From<$input> for ActionBinding {
+                fn from(input: $input) -> Self {
+                    Self::new(input)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_input!(
+    InputBinding,
+    #[cfg(feature = "keyboard")]
+    KeyCode,
+    #[cfg(feature = "mouse")]
+    MouseButton,
+    #[cfg(feature = "gamepad")]
+    GamepadButton,
+    #[cfg(feature = "gamepad")]
+    GamepadAxis,
+);
+
+/// A resource mapping inputs to the actions of type `A`, which can be changed at runtime to let
+/// players rebind their controls.
+///
+/// With the `serialize` feature, input maps can be saved to and loaded from files, for example
+/// in RON. The state of the actions is tracked by the [`ActionState`] resource, updated by the
+/// [`InputMapPlugin`].
+///
+/// ```
+/// # use bevy_input::{
+/// #     action::{ActionBinding, InputBinding, InputMap, MouseAxis},
+/// #     keyboard::KeyCode,
+/// #     mouse::MouseButton,
+/// # };
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum PlayerAction {
+///     Jump,
+///     Move,
+///     Look,
+/// }
+///
+/// let mut input_map = InputMap::default()
+///     .with(PlayerAction::Jump, KeyCode::Space)
+///     .with(PlayerAction::Move, ActionBinding::new(KeyCode::KeyA).scaled(-1.0))
+///     .with(PlayerAction::Move, KeyCode::KeyD)
+///     .with(PlayerAction::Look, InputBinding::MouseMotion(MouseAxis::X));
+///
+/// // Rebind jumping to the left mouse button.
+/// input_map.clear(&PlayerAction::Jump);
+/// input_map.bind(PlayerAction::Jump, MouseButton::Left);
+/// assert_eq!(input_map.bindings(&PlayerAction::Jump).len(), 1);
+/// ```
+#[derive(Resource, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputMap<A: Action> {
+    bindings: HashMap<A, Vec<ActionBinding>>,
+}
+
+impl<A: Action> Default for InputMap<A> {
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::default(),
+        }
+    }
+}
+
+impl<A: Action> InputMap<A> {
+    /// Returns this input map with `binding` added to the bindings of `action`.
+    pub fn with(mut self, action: A, binding: impl Into<ActionBinding>) -> Self {
+        self.bind(action, binding);
+        self
+    }
+
+    /// Adds `binding` to the bindings of `action`.
+    pub fn bind(&mut self, action: A, binding: impl Into<ActionBinding>) -> &mut Self {
+        self.bindings
+            .entry(action)
+            .or_default()
+            .push(binding.into());
+        self
+    }
+
+    /// Removes the bindings of `action` to `input`.
+    pub fn unbind(&mut self, action: &A, input: impl Into<InputBinding>) {
+        let input = input.into();
+        if let Some(bindings) = self.bindings.get_mut(action) {
+            bindings.retain(|binding| binding.input != input);
+        }
+    }
+
+    /// Removes all the bindings of `action`.
+    pub fn clear(&mut self, action: &A) {
+        self.bindings.remove(action);
+    }
+
+    /// Returns the bindings of `action`.
+    pub fn bindings(&self, action: &A) -> &[ActionBinding] {
+        self.bindings.get(action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterates over the actions of this map, with their bindings.
+    pub fn iter(&self) -> impl Iterator<Item = (&A, &[ActionBinding])> {
+        self.bindings
+            .iter()
+            .map(|(action, bindings)| (action, bindings.as_slice()))
+    }
+}
+
+/// A resource with the state of the actions of type `A`, updated from their [`InputMap`] before
+/// [`Update`](bevy_app::Update).
+///
+/// Each action has a value, the sum of the values of its bindings, and is pressed while one of
+/// its buttons is pressed, or one of its analog inputs is at least [`ACTION_PRESS_THRESHOLD`]
+/// away from zero.
+#[derive(Resource, Debug, Clone)]
+pub struct ActionState<A: Action> {
+    buttons: ButtonInput<A>,
+    values: HashMap<A, f32>,
+}
+
+impl<A: Action> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            buttons: ButtonInput::default(),
+            values: HashMap::default(),
+        }
+    }
+}
+
+impl<A: Action> ActionState<A> {
+    /// Returns the value of `action`, or 0 if none of its inputs is active.
+    pub fn value(&self, action: &A) -> f32 {
+        self.values.get(action).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the values of two actions as the axes of a vector, for example to move a
+    /// character with a horizontal and a vertical action.
+    pub fn axis_pair(&self, x: &A, y: &A) -> Vec2 {
+        Vec2::new(self.value(x), self.value(y))
+    }
+
+    /// Returns `true` if `action` is pressed.
+    pub fn pressed(&self, action: &A) -> bool {
+        self.buttons.pressed(action.clone())
+    }
+
+    /// Returns `true` if `action` has been pressed during the current frame.
+    pub fn just_pressed(&self, action: &A) -> bool {
+        self.buttons.just_pressed(action.clone())
+    }
+
+    /// Returns `true` if `action` has been released during the current frame.
+    pub fn just_released(&self, action: &A) -> bool {
+        self.buttons.just_released(action.clone())
+    }
+
+    /// Returns the pressed state of the actions, as a [`ButtonInput`].
+    pub fn buttons(&self) -> &ButtonInput<A> {
+        &self.buttons
+    }
+}
+
+/// Adds the [`InputMap`] and [`ActionState`] resources of the actions of type `A`, and the
+/// [`update_action_state`] system updating the latter.
+///
+/// The input map is kept if it was already inserted, for example after loading it from the
+/// settings of the player.
+pub struct InputMapPlugin<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for InputMapPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Action> Plugin for InputMapPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputMap<A>>()
+            .init_resource::<ActionState<A>>()
+            .add_systems(PreUpdate, update_action_state::<A>.after(InputSystems));
+    }
+}
+
+/// The input resources read to evaluate the bindings of actions.
+struct InputSources<'a> {
+    #[cfg(feature = "keyboard")]
+    keys: Option<&'a ButtonInput<KeyCode>>,
+    #[cfg(feature = "mouse")]
+    mouse_buttons: Option<&'a ButtonInput<MouseButton>>,
+    #[cfg(feature = "mouse")]
+    mouse_motion: Option<&'a AccumulatedMouseMotion>,
+    #[cfg(feature = "mouse")]
+    mouse_scroll: Option<&'a AccumulatedMouseScroll>,
+    #[cfg(feature = "gamepad")]
+    gamepads: Vec<&'a Gamepad>,
+    #[cfg(feature = "touch")]
+    touches: Option<&'a Touches>,
+    #[cfg(not(any(
+        feature = "keyboard",
+        feature = "mouse",
+        feature = "gamepad",
+        feature = "touch"
+    )))]
+    _marker: PhantomData<&'a ()>,
+}
+
+impl InputSources<'_> {
+    /// Returns the current value of `input`.
+    fn value(&self, input: InputBinding) -> f32 {
+        #[cfg(any(feature = "keyboard", feature = "mouse", feature = "touch"))]
+        let button = |pressed: bool| if pressed { 1.0 } else { 0.0 };
+        match input {
+            #[cfg(feature = "keyboard")]
+            InputBinding::Key(key) => button(self.keys.is_some_and(|keys| keys.pressed(key))),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseButton(mouse_button) => button(
+                self.mouse_buttons
+                    .is_some_and(|buttons| buttons.pressed(mouse_button)),
+            ),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseMotion(axis) => self
+                .mouse_motion
+                .map_or(0.0, |motion| axis_value(motion.delta, axis)),
+            #[cfg(feature = "mouse")]
+            InputBinding::MouseScroll(axis) => self
+                .mouse_scroll
+                .map_or(0.0, |scroll| axis_value(scroll.delta, axis)),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadButton(gamepad_button) => self
+                .gamepads
+                .iter()
+                .filter_map(|gamepad| gamepad.get(gamepad_button))
+                .fold(0.0, f32::max),
+            #[cfg(feature = "gamepad")]
+            InputBinding::GamepadAxis(gamepad_axis) => self
+                .gamepads
+                .iter()
+                .filter_map(|gamepad| gamepad.get(gamepad_axis))
+                .fold(0.0, |value: f32, axis_value| {
+                    if axis_value.abs() > value.abs() {
+                        axis_value
+                    } else {
+                        value
+                    }
+                }),
+            #[cfg(feature = "touch")]
+            InputBinding::Touch => button(
+                self.touches
+                    .is_some_and(|touches| touches.iter().next().is_some()),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "mouse")]
+fn axis_value(delta: Vec2, axis: MouseAxis) -> f32 {
+    match axis {
+        MouseAxis::X => delta.x,
+        MouseAxis::Y => delta.y,
+    }
+}
+
+/// Updates the [`ActionState`] of the actions of type `A` from the current inputs and the
+/// bindings of their [`InputMap`].
+pub fn update_action_state<A: Action>(
+    input_map: Res<InputMap<A>>,
+    mut action_state: ResMut<ActionState<A>>,
+    #[cfg(feature = "keyboard")] keys: Option<Res<ButtonInput<KeyCode>>>,
+    #[cfg(feature = "mouse")] mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    #[cfg(feature = "mouse")] mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    #[cfg(feature = "mouse")] mouse_scroll: Option<Res<AccumulatedMouseScroll>>,
+    #[cfg(feature = "gamepad")] gamepads: Query<&Gamepad>,
+    #[cfg(feature = "touch")] touches: Option<Res<Touches>>,
+) {
+    let sources = InputSources {
+        #[cfg(feature = "keyboard")]
+        keys: keys.as_deref(),
+        #[cfg(feature = "mouse")]
+        mouse_buttons: mouse_buttons.as_deref(),
+        #[cfg(feature = "mouse")]
+        mouse_motion: mouse_motion.as_deref(),
+        #[cfg(feature = "mouse")]
+        mouse_scroll: mouse_scroll.as_deref(),
+        #[cfg(feature = "gamepad")]
+        gamepads: gamepads.iter().collect(),
+        #[cfg(feature = "touch")]
+        touches: touches.as_deref(),
+        #[cfg(not(any(
+            feature = "keyboard",
+            feature = "mouse",
+            feature = "gamepad",
+            feature = "touch"
+        )))]
+        _marker: PhantomData,
+    };
+
+    // Avoid marking the state as changed every frame while no action is used.
+    let unchanged_state = action_state.bypass_change_detection();
+    unchanged_state.buttons.clear();
+    unchanged_state.values.clear();
+    for (action, bindings) in input_map.iter() {
+        let mut value = 0.0;
+        let mut pressed = false;
+        for binding in bindings {
+            let input_value = sources.value(binding.input);
+            value += input_value * binding.scale;
+            pressed |= input_value.abs() >= ACTION_PRESS_THRESHOLD;
+        }
+        if value != 0.0 {
+            action_state.values.insert(action.clone(), value);
+        }
+        if pressed {
+            if !action_state.buttons.pressed(action.clone()) {
+                action_state.buttons.press(action.clone());
+            }
+        } else if action_state.buttons.pressed(action.clone()) {
+            action_state.buttons.release(action.clone());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "keyboard"))]
+mod tests {
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+
+    use super::{update_action_state, ActionBinding, ActionState, InputMap};
+    use crate::{keyboard::KeyCode, ButtonInput};
+
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    enum TestAction {
+        Jump,
+        Move,
+    }
+
+    #[test]
+    fn keys_drive_actions() {
+        let mut world = World::new();
+        world.insert_resource(
+            InputMap::default()
+                .with(TestAction::Jump, KeyCode::Space)
+                .with(
+                    TestAction::Move,
+                    ActionBinding::new(KeyCode::KeyA).scaled(-1.0),
+                )
+                .with(TestAction::Move, KeyCode::KeyD),
+        );
+        world.init_resource::<ActionState<TestAction>>();
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::KeyA);
+        world.insert_resource(keys);
+
+        world
+            .run_system_once(update_action_state::<TestAction>)
+            .unwrap();
+        let state = world.resource::<ActionState<TestAction>>();
+        assert_eq!(state.value(&TestAction::Move), -1.0);
+        assert!(state.just_pressed(&TestAction::Move));
+        assert!(!state.pressed(&TestAction::Jump));
+
+        // Both directions cancel out, but the action is still pressed.
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyD);
+        world
+            .run_system_once(update_action_state::<TestAction>)
+            .unwrap();
+        let state = world.resource::<ActionState<TestAction>>();
+        assert_eq!(state.value(&TestAction::Move), 0.0);
+        assert!(state.pressed(&TestAction::Move));
+        assert!(!state.just_pressed(&TestAction::Move));
+
+        // Rebinding takes effect on the next update.
+        let mut input_map = world.resource_mut::<InputMap<TestAction>>();
+        input_map.unbind(&TestAction::Move, KeyCode::KeyA);
+        input_map.bind(TestAction::Jump, KeyCode::KeyA);
+        world
+            .run_system_once(update_action_state::<TestAction>)
+            .unwrap();
+        let state = world.resource::<ActionState<TestAction>>();
+        assert_eq!(state.value(&TestAction::Move), 1.0);
+        assert!(state.just_pressed(&TestAction::Jump));
+    }
+}
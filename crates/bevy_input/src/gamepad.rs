@@ -1,22 +1,30 @@
 //! The gamepad input functionality.
 
-use core::{ops::RangeInclusive, time::Duration};
+use core::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    ops::RangeInclusive,
+    time::Duration,
+};
 
 use crate::{Axis, ButtonInput, ButtonState};
-use alloc::string::String;
-#[cfg(feature = "bevy_reflect")]
-use bevy_ecs::prelude::ReflectComponent;
+use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_ecs::{
-    change_detection::DetectChangesMut,
+    change_detection::{DetectChanges, DetectChangesMut, Ref},
     component::Component,
     entity::Entity,
     message::{Message, MessageReader, MessageWriter},
     name::Name,
-    system::{Commands, Query},
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
 };
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::{prelude::ReflectComponent, reflect::ReflectResource};
+use bevy_math::curve::{Curve, EaseFunction};
 use bevy_math::ops;
 use bevy_math::Vec2;
 use bevy_platform::collections::HashMap;
+use bevy_platform::sync::Arc;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 #[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
@@ -740,6 +748,11 @@ pub enum GamepadInput {
     derive(Reflect),
     reflect(Debug, Default, Component, Clone)
 )]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct GamepadSettings {
     /// The default button settings.
     pub default_button_settings: ButtonSettings,
@@ -811,6 +824,102 @@ impl GamepadSettings {
     }
 }
 
+/// The GUID of the model of a connected [`Gamepad`], inserted by the input backend when the
+/// gamepad connects.
+///
+/// The GUID follows the SDL convention, and is derived from the bus, vendor and product of the
+/// gamepad. Identical gamepads connected at the same time share the same GUID, which makes it suited
+/// to identify [`GamepadProfiles`], but not to tell gamepads apart.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Component, PartialEq, Hash, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadGuid(pub [u8; 16]);
+
+/// The [`GamepadSettings`] of gamepad models, identified by their [`GamepadGuid`].
+///
+/// The settings of the profile of a gamepad are applied when it connects, and when the profiles
+/// change, replacing the [`GamepadSettings`] of the gamepad. Gamepads without a profile keep their
+/// settings.
+///
+/// With the `serialize` feature, profiles can be saved and loaded, for example with the
+/// [calibration](GamepadCalibration) of the gamepads of a player.
+///
+/// Like the dead zones, the inversion and the response curves of the [`AxisSettings`] only affect
+/// the values of the [`GamepadAxisChangedEvent`]s: [`Gamepad::get`], [`Gamepad::left_stick`] and
+/// [`Gamepad::right_stick`] keep returning the raw values of the axes.
+///
+/// ```
+/// # use bevy_input::gamepad::{
+/// #     AxisSettings, GamepadAxis, GamepadAxisChangedEvent, GamepadGuid, GamepadProfiles,
+/// # };
+/// # use bevy_ecs::prelude::*;
+/// fn invert_look_axis(gamepads: Query<&GamepadGuid>, mut profiles: ResMut<GamepadProfiles>) {
+///     for guid in &gamepads {
+///         let mut axis_settings = AxisSettings::default();
+///         axis_settings.set_inverted(true);
+///         profiles
+///             .profiles
+///             .entry(*guid)
+///             .or_default()
+///             .axis_settings
+///             .insert(GamepadAxis::RightStickY, axis_settings);
+///     }
+/// }
+///
+/// fn look(mut axis_events: MessageReader<GamepadAxisChangedEvent>) {
+///     for event in axis_events.read() {
+///         if event.axis == GamepadAxis::RightStickY {
+///             // The value of the event is inverted by the profile.
+///             let _pitch = event.value;
+///         }
+///     }
+/// }
+/// ```
+#[derive(Resource, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, Resource, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct GamepadProfiles {
+    /// The settings of each gamepad model.
+    pub profiles: HashMap<GamepadGuid, GamepadSettings>,
+}
+
+impl GamepadProfiles {
+    /// Returns the settings of the gamepad model with the `guid`, if any.
+    pub fn get(&self, guid: GamepadGuid) -> Option<&GamepadSettings> {
+        self.profiles.get(&guid)
+    }
+
+    /// Sets the settings of the gamepad model with the `guid`, returning its previous settings.
+    pub fn insert(
+        &mut self,
+        guid: GamepadGuid,
+        settings: GamepadSettings,
+    ) -> Option<GamepadSettings> {
+        self.profiles.insert(guid, settings)
+    }
+
+    /// Removes the settings of the gamepad model with the `guid`, returning them.
+    pub fn remove(&mut self, guid: GamepadGuid) -> Option<GamepadSettings> {
+        self.profiles.remove(&guid)
+    }
+}
+
 /// Manages settings for gamepad buttons.
 ///
 /// It is used inside [`GamepadSettings`] to define the threshold for a [`GamepadButton`]
@@ -824,6 +933,11 @@ impl GamepadSettings {
     derive(Reflect),
     reflect(Debug, Default, Clone)
 )]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct ButtonSettings {
     press_threshold: f32,
     release_threshold: f32,
@@ -981,6 +1095,9 @@ impl ButtonSettings {
 /// Otherwise, values will be linearly rescaled to fit into the sensitivity range.
 /// For example, a value that is one fourth of the way from `deadzone_upperbound` to `livezone_upperbound` will be scaled to 0.25.
 ///
+/// The scaled value is then shaped by the [`response_curve`](Self::response_curve), and negated if
+/// the axis is [`inverted`](Self::inverted).
+///
 /// The valid range is `[-1.0, 1.0]`.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -988,6 +1105,11 @@ impl ButtonSettings {
     derive(Reflect),
     reflect(Debug, PartialEq, Default, Clone)
 )]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct AxisSettings {
     /// Values that are higher than `livezone_upperbound` will be rounded up to 1.0.
     livezone_upperbound: f32,
@@ -999,6 +1121,18 @@ pub struct AxisSettings {
     livezone_lowerbound: f32,
     /// `threshold` defines the minimum difference between old and new values to apply the changes.
     threshold: f32,
+    /// Whether the scaled values are negated, for example to invert the vertical look axis.
+    #[cfg_attr(feature = "serialize", serde(default))]
+    inverted: bool,
+    /// The curve mapping the magnitude of the scaled values, for example to make small movements
+    /// of a stick more precise.
+    #[cfg_attr(feature = "serialize", serde(default = "linear_response_curve"))]
+    response_curve: EaseFunction,
+}
+
+#[cfg(feature = "serialize")]
+fn linear_response_curve() -> EaseFunction {
+    EaseFunction::Linear
 }
 
 impl Default for AxisSettings {
@@ -1009,6 +1143,8 @@ impl Default for AxisSettings {
             deadzone_lowerbound: -0.05,
             livezone_lowerbound: -1.0,
             threshold: 0.01,
+            inverted: false,
+            response_curve: EaseFunction::Linear,
         }
     }
 }
@@ -1081,6 +1217,7 @@ impl AxisSettings {
                 deadzone_upperbound,
                 livezone_upperbound,
                 threshold,
+                ..Default::default()
             })
         }
     }
@@ -1265,6 +1402,46 @@ impl AxisSettings {
         self.threshold
     }
 
+    /// Returns whether the scaled values are negated.
+    pub fn inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Sets whether the scaled values are negated, for example to invert the vertical look axis.
+    ///
+    /// Only the values of the [`GamepadAxisChangedEvent`]s are negated, [`Gamepad::get`] returns
+    /// the raw value of the axis.
+    pub fn set_inverted(&mut self, inverted: bool) {
+        self.inverted = inverted;
+    }
+
+    /// Get the curve mapping the magnitude of the scaled values.
+    pub fn response_curve(&self) -> EaseFunction {
+        self.response_curve
+    }
+
+    /// Sets the curve mapping the magnitude of the scaled values.
+    ///
+    /// The curve is sampled between `0.0` and `1.0` with the distance of the value to the dead
+    /// zone, so that [`EaseFunction::QuadraticIn`] makes small movements more precise, while
+    /// [`EaseFunction::Linear`] keeps the values unchanged.
+    ///
+    /// Like the inversion, the curve only maps the values of the [`GamepadAxisChangedEvent`]s.
+    pub fn set_response_curve(&mut self, response_curve: EaseFunction) {
+        self.response_curve = response_curve;
+    }
+
+    /// Applies the [`response_curve`](Self::response_curve) and the
+    /// [inversion](Self::inverted) to a `scaled_value` in the `[-1.0, 1.0]` range.
+    pub fn apply_response(&self, scaled_value: f32) -> f32 {
+        let magnitude = self.response_curve.sample_clamped(ops::abs(scaled_value));
+        if (scaled_value < 0.0) != self.inverted {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
     /// Clamps the `raw_value` according to the `AxisSettings`.
     pub fn clamp(&self, raw_value: f32) -> f32 {
         if self.deadzone_lowerbound <= raw_value && raw_value <= self.deadzone_upperbound {
@@ -1338,6 +1515,149 @@ fn linear_remapping(value: f32, old: RangeInclusive<f32>, new: RangeInclusive<f3
     ((value - old.start()) / (old.end() - old.start())) * (new.end() - new.start()) + new.start()
 }
 
+/// The range and drift of an axis, captured by a [`GamepadCalibration`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default, Clone)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AxisCalibration {
+    /// The lowest raw value reached while capturing the range.
+    pub min: f32,
+    /// The highest raw value reached while capturing the range.
+    pub max: f32,
+    /// The largest distance to `0.0` of the raw values while capturing the drift.
+    pub drift: f32,
+}
+
+impl AxisCalibration {
+    /// Creates [`AxisSettings`] with a dead zone covering the drift, and live zones reaching the
+    /// range of the axis.
+    ///
+    /// The `margin` widens the dead zone and narrows the live zones, so that worn sticks still
+    /// reach the ends of the range. The live zones are left untouched on the sides of the range
+    /// which weren't captured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AxisSettingsError`] if the drift and margin cover the whole range of the axis.
+    pub fn to_axis_settings(&self, margin: f32) -> Result<AxisSettings, AxisSettingsError> {
+        let deadzone = self.drift + margin;
+        let livezone_upperbound = if self.max - margin > deadzone {
+            (self.max - margin).min(1.0)
+        } else {
+            1.0
+        };
+        let livezone_lowerbound = if self.min + margin < -deadzone {
+            (self.min + margin).max(-1.0)
+        } else {
+            -1.0
+        };
+        AxisSettings::new(
+            livezone_lowerbound,
+            -deadzone,
+            deadzone,
+            livezone_upperbound,
+            AxisSettings::default().threshold,
+        )
+    }
+}
+
+/// What a [`GamepadCalibration`] is capturing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Default, Clone)
+)]
+pub enum GamepadCalibrationStep {
+    /// The drift of the axes, while the sticks are left at rest.
+    #[default]
+    Drift,
+    /// The range of the axes, while the sticks are rotated along their edges.
+    Range,
+}
+
+/// Captures the range and drift of the axes of a [`Gamepad`], from their raw values.
+///
+/// Insert this component on a gamepad to start calibrating it, and prompt the player to leave the
+/// sticks at rest to capture their [drift](GamepadCalibrationStep::Drift). Then switch to the
+/// [`Range`](GamepadCalibrationStep::Range) step, and prompt the player to rotate the sticks and
+/// press the triggers. Finally, [`apply`](Self::apply) the calibration to the
+/// [`GamepadSettings`], or to the [`GamepadProfiles`] of the gamepad, and remove this component.
+#[derive(Component, Clone, Default, Debug)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, Component, Clone)
+)]
+pub struct GamepadCalibration {
+    /// What is being captured.
+    pub step: GamepadCalibrationStep,
+    axes: HashMap<GamepadAxis, AxisCalibration>,
+}
+
+impl GamepadCalibration {
+    /// Returns the calibration of the `axis`, if it moved since the calibration started.
+    pub fn axis(&self, axis: GamepadAxis) -> Option<&AxisCalibration> {
+        self.axes.get(&axis)
+    }
+
+    /// Returns an iterator over the axes which moved since the calibration started, and their
+    /// calibration.
+    pub fn axes(&self) -> impl Iterator<Item = (GamepadAxis, &AxisCalibration)> {
+        self.axes
+            .iter()
+            .map(|(axis, calibration)| (*axis, calibration))
+    }
+
+    /// Records a `raw_value` of the `axis` for the current [`step`](Self::step).
+    pub fn record(&mut self, axis: GamepadAxis, raw_value: f32) {
+        let calibration = self.axes.entry(axis).or_default();
+        match self.step {
+            GamepadCalibrationStep::Drift => {
+                calibration.drift = calibration.drift.max(ops::abs(raw_value));
+            }
+            GamepadCalibrationStep::Range => {
+                calibration.min = calibration.min.min(raw_value);
+                calibration.max = calibration.max.max(raw_value);
+            }
+        }
+    }
+
+    /// Replaces the [`AxisSettings`] of the calibrated axes in the `settings`, keeping their
+    /// [inversion](AxisSettings::inverted) and [response curve](AxisSettings::response_curve).
+    ///
+    /// See [`AxisCalibration::to_axis_settings`] for the `margin`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`AxisSettingsError`] if the drift and margin of an axis cover its whole range,
+    /// in which case the `settings` are left unchanged.
+    pub fn apply(
+        &self,
+        settings: &mut GamepadSettings,
+        margin: f32,
+    ) -> Result<(), AxisSettingsError> {
+        let mut axis_settings = Vec::with_capacity(self.axes.len());
+        for (axis, calibration) in self.axes() {
+            let previous = settings.get_axis_settings(axis);
+            let mut calibrated = calibration.to_axis_settings(margin)?;
+            calibrated.set_inverted(previous.inverted());
+            calibrated.set_response_curve(previous.response_curve());
+            axis_settings.push((axis, calibrated));
+        }
+        settings.axis_settings.extend(axis_settings);
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 /// Deadzone-aware axis position.
 enum ScaledAxisWithDeadZonePosition {
@@ -1416,6 +1736,11 @@ impl ScaledAxisPosition {
     derive(Reflect),
     reflect(Debug, Default, Clone)
 )]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
 pub struct ButtonAxisSettings {
     /// The high value at which to apply rounding.
     pub high: f32,
@@ -1548,9 +1873,34 @@ pub fn gamepad_connection_system(
     }
 }
 
-// Note that we don't expose `gilrs::Gamepad::uuid` due to
-// https://gitlab.com/gilrs-project/gilrs/-/issues/153.
-//
+/// Applies the [`GamepadProfiles`] to the [`GamepadSettings`] of the gamepads when they connect
+/// and when the profiles change.
+pub fn gamepad_profiles_system(
+    profiles: Res<GamepadProfiles>,
+    mut gamepads: Query<(Ref<Gamepad>, &GamepadGuid, &mut GamepadSettings)>,
+) {
+    for (gamepad, guid, mut settings) in &mut gamepads {
+        if !gamepad.is_added() && !profiles.is_changed() {
+            continue;
+        }
+        if let Some(profile) = profiles.get(*guid) {
+            *settings = profile.clone();
+        }
+    }
+}
+
+/// Records the raw values of the axes of the gamepads with a [`GamepadCalibration`].
+pub fn gamepad_calibration_system(
+    mut raw_axis_events: MessageReader<RawGamepadAxisChangedEvent>,
+    mut calibrations: Query<&mut GamepadCalibration>,
+) {
+    for event in raw_axis_events.read() {
+        if let Ok(mut calibration) = calibrations.get_mut(event.gamepad) {
+            calibration.record(event.axis, event.value);
+        }
+    }
+}
+
 /// The connection status of a gamepad.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
@@ -1613,15 +1963,17 @@ pub fn gamepad_event_processing_system(
                 let Ok((mut gamepad_axis, gamepad_settings)) = gamepads.get_mut(gamepad) else {
                     continue;
                 };
-                let Some(filtered_value) = gamepad_settings
-                    .get_axis_settings(axis)
-                    .filter(value, gamepad_axis.get(axis))
+                let axis_settings = gamepad_settings.get_axis_settings(axis);
+                let Some(filtered_value) = axis_settings.filter(value, gamepad_axis.get(axis))
                 else {
                     continue;
                 };
                 gamepad_axis.analog.set(axis, filtered_value.raw);
-                let send_event =
-                    GamepadAxisChangedEvent::new(gamepad, axis, filtered_value.scaled.to_f32());
+                let send_event = GamepadAxisChangedEvent::new(
+                    gamepad,
+                    axis,
+                    axis_settings.apply_response(filtered_value.scaled.to_f32()),
+                );
                 processed_axis_events.write(send_event);
                 processed_events.write(GamepadEvent::from(send_event));
             }
@@ -1749,6 +2101,192 @@ impl GamepadRumbleIntensity {
     }
 }
 
+/// The shape of the intensity of a gamepad motor over the duration of a [`GamepadRumble`].
+///
+/// The intensity ramps from [`attack_level`](Self::attack_level) to the full intensity of the
+/// rumble during the [`attack`](Self::attack), holds, then ramps down to
+/// [`fade_level`](Self::fade_level) during the [`fade`](Self::fade) at the end of the rumble.
+/// The levels are relative to the [`GamepadRumbleIntensity`] of the rumble, from `0.0` to `1.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone, Default)
+)]
+pub struct GamepadRumbleEnvelope {
+    /// How long the intensity ramps up at the start of the rumble.
+    pub attack: Duration,
+    /// The relative intensity at the start of the rumble.
+    pub attack_level: f32,
+    /// How long the intensity ramps down at the end of the rumble.
+    pub fade: Duration,
+    /// The relative intensity at the end of the rumble.
+    pub fade_level: f32,
+    /// The curve of the ramps of the attack and the fade.
+    pub easing: EaseFunction,
+}
+
+impl GamepadRumbleEnvelope {
+    /// An envelope keeping the full intensity for the whole rumble.
+    pub const CONSTANT: Self = Self {
+        attack: Duration::ZERO,
+        attack_level: 1.0,
+        fade: Duration::ZERO,
+        fade_level: 1.0,
+        easing: EaseFunction::Linear,
+    };
+
+    /// Creates an envelope ramping up from silence during `attack`, and back down to silence
+    /// during `fade`.
+    pub const fn new(attack: Duration, fade: Duration) -> Self {
+        Self {
+            attack,
+            attack_level: 0.0,
+            fade,
+            fade_level: 0.0,
+            easing: EaseFunction::Linear,
+        }
+    }
+
+    /// Returns this envelope with the ramps following the `easing` curve.
+    pub const fn with_easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the relative intensity `elapsed` after the start of a rumble lasting `duration`.
+    ///
+    /// When the attack and the fade overlap, the lowest of their intensities is used.
+    pub fn sample(&self, elapsed: Duration, duration: Duration) -> f32 {
+        if elapsed >= duration {
+            return 0.0;
+        }
+        let ramp = |from: f32, to: f32, elapsed: Duration, length: Duration| {
+            let t = elapsed.as_secs_f32() / length.as_secs_f32();
+            let t = self.easing.sample_clamped(t);
+            from + (to - from) * t
+        };
+        let attack = if elapsed < self.attack {
+            ramp(self.attack_level, 1.0, elapsed, self.attack)
+        } else {
+            1.0
+        };
+        let remaining = duration - elapsed;
+        let fade = if remaining < self.fade {
+            ramp(1.0, self.fade_level, self.fade - remaining, self.fade)
+        } else {
+            1.0
+        };
+        attack.min(fade).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for GamepadRumbleEnvelope {
+    fn default() -> Self {
+        Self::CONSTANT
+    }
+}
+
+/// A rumble with an [envelope](GamepadRumbleEnvelope) for each gamepad motor, played with
+/// [`GamepadRumbleRequest::AddEnvelope`].
+///
+/// ```
+/// # use bevy_input::gamepad::{GamepadRumble, GamepadRumbleEnvelope, GamepadRumbleIntensity};
+/// # use bevy_math::curve::EaseFunction;
+/// # use core::time::Duration;
+/// // An explosion: a sharp kick of the weak motor, and a long rumble of the strong motor.
+/// let explosion = GamepadRumble::new(Duration::from_secs(1), GamepadRumbleIntensity::MAX)
+///     .with_weak_motor_envelope(GamepadRumbleEnvelope::new(
+///         Duration::ZERO,
+///         Duration::from_millis(900),
+///     ))
+///     .with_strong_motor_envelope(
+///         GamepadRumbleEnvelope::new(Duration::from_millis(100), Duration::from_millis(600))
+///             .with_easing(EaseFunction::QuadraticOut),
+///     );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+pub struct GamepadRumble {
+    /// How long the gamepad should rumble.
+    pub duration: Duration,
+    /// The peak intensity of each motor.
+    pub intensity: GamepadRumbleIntensity,
+    /// The envelope of the strong, low-frequency, motor.
+    pub strong_motor_envelope: GamepadRumbleEnvelope,
+    /// The envelope of the weak, high-frequency, motor.
+    pub weak_motor_envelope: GamepadRumbleEnvelope,
+}
+
+impl GamepadRumble {
+    /// Creates a rumble at a constant `intensity` for `duration`.
+    pub const fn new(duration: Duration, intensity: GamepadRumbleIntensity) -> Self {
+        Self {
+            duration,
+            intensity,
+            strong_motor_envelope: GamepadRumbleEnvelope::CONSTANT,
+            weak_motor_envelope: GamepadRumbleEnvelope::CONSTANT,
+        }
+    }
+
+    /// Returns this rumble with the same `envelope` for both motors.
+    pub const fn with_envelope(self, envelope: GamepadRumbleEnvelope) -> Self {
+        self.with_strong_motor_envelope(envelope)
+            .with_weak_motor_envelope(envelope)
+    }
+
+    /// Returns this rumble with the `envelope` for the strong motor.
+    pub const fn with_strong_motor_envelope(mut self, envelope: GamepadRumbleEnvelope) -> Self {
+        self.strong_motor_envelope = envelope;
+        self
+    }
+
+    /// Returns this rumble with the `envelope` for the weak motor.
+    pub const fn with_weak_motor_envelope(mut self, envelope: GamepadRumbleEnvelope) -> Self {
+        self.weak_motor_envelope = envelope;
+        self
+    }
+
+    /// Returns the intensity of the motors `elapsed` after the start of the rumble.
+    pub fn intensity_at(&self, elapsed: Duration) -> GamepadRumbleIntensity {
+        GamepadRumbleIntensity {
+            strong_motor: self.intensity.strong_motor
+                * self.strong_motor_envelope.sample(elapsed, self.duration),
+            weak_motor: self.intensity.weak_motor
+                * self.weak_motor_envelope.sample(elapsed, self.duration),
+        }
+    }
+}
+
+/// The haptic capabilities of a connected [`Gamepad`], inserted by the input backend when the
+/// gamepad connects, and removed when it disconnects.
+///
+/// ```
+/// # use bevy_input::gamepad::{Gamepad, GamepadHaptics};
+/// # use bevy_ecs::prelude::*;
+/// fn rumbling_gamepads(gamepads: Query<(Entity, &GamepadHaptics), With<Gamepad>>) {
+///     for (entity, haptics) in &gamepads {
+///         if !haptics.rumble {
+///             println!("{entity} can't rumble");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Component, Default, PartialEq, Clone)
+)]
+pub struct GamepadHaptics {
+    /// Whether the gamepad supports force-feedback rumble, with [`GamepadRumbleRequest`]s.
+    pub rumble: bool,
+}
+
 /// An event that controls force-feedback rumbling of a [`Gamepad`] [`entity`](Entity).
 ///
 /// # Notes
@@ -1799,6 +2337,16 @@ pub enum GamepadRumbleRequest {
         /// The gamepad to rumble.
         gamepad: Entity,
     },
+    /// Add a rumble whose intensity follows the envelopes of a [`GamepadRumble`] to the given
+    /// gamepad.
+    ///
+    /// Simultaneous rumble effects add up like with [`GamepadRumbleRequest::Add`].
+    AddEnvelope {
+        /// The rumble to play.
+        rumble: GamepadRumble,
+        /// The gamepad to rumble.
+        gamepad: Entity,
+    },
     /// Stop all running rumbles on the given [`Entity`].
     Stop {
         /// The gamepad to stop rumble.
@@ -1810,7 +2358,346 @@ impl GamepadRumbleRequest {
     /// Get the [`Entity`] associated with this request.
     pub fn gamepad(&self) -> Entity {
         match self {
-            Self::Add { gamepad, .. } | Self::Stop { gamepad } => *gamepad,
+            Self::Add { gamepad, .. }
+            | Self::AddEnvelope { gamepad, .. }
+            | Self::Stop { gamepad } => *gamepad,
+        }
+    }
+}
+
+/// The color of the light bar or LEDs of a gamepad, set with
+/// [`GamepadOutputRequest::SetLight`].
+///
+/// The channels go from `0.0` to `1.0`, in the sRGB color space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
+pub struct GamepadLightColor {
+    /// The red channel.
+    pub red: f32,
+    /// The green channel.
+    pub green: f32,
+    /// The blue channel.
+    pub blue: f32,
+}
+
+impl GamepadLightColor {
+    /// The light turned off.
+    pub const OFF: Self = Self::rgb(0.0, 0.0, 0.0);
+
+    /// The light at full white.
+    pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+
+    /// Creates a color from its `red`, `green` and `blue` channels.
+    pub const fn rgb(red: f32, green: f32, blue: f32) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+/// One of the two analog triggers of a gamepad, the targets of a [`GamepadTriggerEffect`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Hash, PartialEq, Clone)
+)]
+pub enum GamepadTrigger {
+    /// The left trigger, [`GamepadButton::LeftTrigger2`].
+    Left,
+    /// The right trigger, [`GamepadButton::RightTrigger2`].
+    Right,
+}
+
+/// The force feedback of an adaptive trigger, set with
+/// [`GamepadOutputRequest::SetTriggerEffect`].
+///
+/// The positions go from `0.0`, when the trigger is released, to `1.0`, when it is fully
+/// pressed, and the strengths and amplitudes from `0.0` to `1.0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone, Default)
+)]
+pub enum GamepadTriggerEffect {
+    /// The trigger moves freely.
+    #[default]
+    Off,
+    /// The trigger resists being pressed past `start`, like a spring.
+    Resistance {
+        /// The position from which the trigger resists.
+        start: f32,
+        /// How hard the trigger resists.
+        strength: f32,
+    },
+    /// The trigger resists between `start` and `end`, then gives way, like the trigger of a gun.
+    Weapon {
+        /// The position from which the trigger resists.
+        start: f32,
+        /// The position at which the trigger gives way.
+        end: f32,
+        /// How hard the trigger resists.
+        strength: f32,
+    },
+    /// The trigger vibrates once pressed past `start`, like the engine of a vehicle.
+    Vibration {
+        /// The position from which the trigger vibrates.
+        start: f32,
+        /// How strongly the trigger vibrates.
+        amplitude: f32,
+        /// How fast the trigger vibrates, in hertz.
+        frequency: f32,
+    },
+}
+
+/// An output specific to some gamepads or platforms, sent with
+/// [`GamepadOutputRequest::Extension`].
+///
+/// Platform plugins define their own extensions, for features such as the player indicators or
+/// the touchpad lights of console controllers, and handle them in their
+/// [`GamepadOutputBackend`].
+///
+/// ```
+/// # use bevy_input::gamepad::GamepadOutputExtension;
+/// /// Lights up the player indicators of a controller.
+/// #[derive(Debug)]
+/// struct PlayerIndicators(u8);
+///
+/// impl GamepadOutputExtension for PlayerIndicators {}
+/// ```
+pub trait GamepadOutputExtension: Any + Debug + Send + Sync {}
+
+/// A request to change an output of a [`Gamepad`] other than its rumble, such as its light or the
+/// effects of its adaptive triggers.
+///
+/// The requests are sent to the [`GamepadOutputBackends`] in [`PostUpdate`](bevy_app::PostUpdate).
+/// Requests the [`GamepadOutputCapabilities`] of the gamepad don't support are ignored.
+///
+/// ```
+/// # use bevy_input::gamepad::{
+/// #     GamepadLightColor, GamepadOutputCapabilities, GamepadOutputRequest, GamepadTrigger,
+/// #     GamepadTriggerEffect,
+/// # };
+/// # use bevy_ecs::prelude::*;
+/// fn low_health_feedback(
+///     mut requests: MessageWriter<GamepadOutputRequest>,
+///     gamepads: Query<(Entity, &GamepadOutputCapabilities)>,
+/// ) {
+///     for (gamepad, capabilities) in &gamepads {
+///         if capabilities.light {
+///             requests.write(GamepadOutputRequest::SetLight {
+///                 gamepad,
+///                 color: GamepadLightColor::rgb(1.0, 0.0, 0.0),
+///             });
+///         }
+///         if capabilities.adaptive_triggers {
+///             requests.write(GamepadOutputRequest::SetTriggerEffect {
+///                 gamepad,
+///                 trigger: GamepadTrigger::Right,
+///                 effect: GamepadTriggerEffect::Resistance {
+///                     start: 0.2,
+///                     strength: 0.8,
+///                 },
+///             });
+///         }
+///     }
+/// }
+/// ```
+#[derive(Message, Clone, Debug)]
+pub enum GamepadOutputRequest {
+    /// Set the color of the light of the given gamepad.
+    SetLight {
+        /// The gamepad to light.
+        gamepad: Entity,
+        /// The color of the light.
+        color: GamepadLightColor,
+    },
+    /// Set the effect of an adaptive trigger of the given gamepad, until another effect is set.
+    SetTriggerEffect {
+        /// The gamepad of the trigger.
+        gamepad: Entity,
+        /// The trigger to change.
+        trigger: GamepadTrigger,
+        /// The effect of the trigger.
+        effect: GamepadTriggerEffect,
+    },
+    /// Send a [`GamepadOutputExtension`] to the given gamepad.
+    Extension {
+        /// The gamepad to send the output to.
+        gamepad: Entity,
+        /// The output.
+        extension: Arc<dyn GamepadOutputExtension>,
+    },
+}
+
+impl GamepadOutputRequest {
+    /// Creates a request sending the `extension` to the `gamepad`.
+    pub fn extension(gamepad: Entity, extension: impl GamepadOutputExtension) -> Self {
+        // `portable-atomic-util` `Arc` is not able to coerce an unsized type like
+        // `std::sync::Arc` can, so the extension is boxed first.
+        #[cfg(not(target_has_atomic = "ptr"))]
+        let extension = Arc::from(Box::new(extension) as Box<dyn GamepadOutputExtension>);
+        #[cfg(target_has_atomic = "ptr")]
+        let extension = Arc::new(extension);
+        Self::Extension { gamepad, extension }
+    }
+
+    /// Get the [`Entity`] associated with this request.
+    pub fn gamepad(&self) -> Entity {
+        match self {
+            Self::SetLight { gamepad, .. }
+            | Self::SetTriggerEffect { gamepad, .. }
+            | Self::Extension { gamepad, .. } => *gamepad,
+        }
+    }
+
+    /// Returns the extension of this request, if it is an [`Extension`](Self::Extension) of type
+    /// `T`.
+    pub fn downcast_extension<T: GamepadOutputExtension>(&self) -> Option<&T> {
+        match self {
+            Self::Extension { extension, .. } => {
+                (extension.as_ref() as &dyn Any).downcast_ref::<T>()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The outputs supported by a connected [`Gamepad`], other than its rumble which is described by
+/// its [`GamepadHaptics`].
+///
+/// The [`GamepadOutputBackend`]s insert this component when a gamepad connects, and enable the
+/// outputs they can drive for it. A gamepad without this component doesn't support any output.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Component, Default, PartialEq, Clone)
+)]
+pub struct GamepadOutputCapabilities {
+    /// Whether the color of the light of the gamepad can be set, with
+    /// [`GamepadOutputRequest::SetLight`].
+    pub light: bool,
+    /// Whether the triggers of the gamepad are adaptive, with
+    /// [`GamepadOutputRequest::SetTriggerEffect`].
+    pub adaptive_triggers: bool,
+    /// The types of the [`GamepadOutputExtension`]s supported by the gamepad.
+    pub extensions: Vec<TypeId>,
+}
+
+impl GamepadOutputCapabilities {
+    /// Returns these capabilities with support for the `T` extension.
+    pub fn with_extension<T: GamepadOutputExtension>(mut self) -> Self {
+        if !self.supports_extension::<T>() {
+            self.extensions.push(TypeId::of::<T>());
+        }
+        self
+    }
+
+    /// Returns `true` if the gamepad supports the `T` extension.
+    pub fn supports_extension<T: GamepadOutputExtension>(&self) -> bool {
+        self.extensions.contains(&TypeId::of::<T>())
+    }
+
+    /// Returns `true` if the gamepad supports the output changed by the `request`.
+    pub fn supports(&self, request: &GamepadOutputRequest) -> bool {
+        match request {
+            GamepadOutputRequest::SetLight { .. } => self.light,
+            GamepadOutputRequest::SetTriggerEffect { .. } => self.adaptive_triggers,
+            GamepadOutputRequest::Extension { extension, .. } => self
+                .extensions
+                .contains(&(extension.as_ref() as &dyn Any).type_id()),
+        }
+    }
+}
+
+/// Errors that can occur when a [`GamepadOutputBackend`] sends a [`GamepadOutputRequest`].
+#[derive(Error, Debug)]
+pub enum GamepadOutputError {
+    /// The gamepad isn't driven by this backend, so the request is sent to the next backend.
+    #[error("the gamepad is not driven by this backend")]
+    UnknownGamepad,
+    /// The backend failed to change the output.
+    #[error("failed to change the output of the gamepad: {0}")]
+    Backend(String),
+}
+
+/// A platform integration driving the outputs of gamepads, registered in the
+/// [`GamepadOutputBackends`].
+///
+/// ```
+/// # use bevy_input::gamepad::{
+/// #     GamepadOutputBackend, GamepadOutputBackends, GamepadOutputError, GamepadOutputRequest,
+/// # };
+/// # use bevy_app::App;
+/// struct ConsoleOutputs;
+///
+/// impl GamepadOutputBackend for ConsoleOutputs {
+///     fn send(&mut self, request: &GamepadOutputRequest) -> Result<(), GamepadOutputError> {
+///         match request {
+///             GamepadOutputRequest::SetLight { .. } => {
+///                 // Call into the platform SDK.
+///                 Ok(())
+///             }
+///             _ => Err(GamepadOutputError::UnknownGamepad),
+///         }
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.init_resource::<GamepadOutputBackends>();
+/// app.world_mut()
+///     .resource_mut::<GamepadOutputBackends>()
+///     .add(ConsoleOutputs);
+/// ```
+pub trait GamepadOutputBackend: Send + Sync + 'static {
+    /// Changes the output of the gamepad of the `request`.
+    ///
+    /// This is only called with requests the [`GamepadOutputCapabilities`] of the gamepad
+    /// support. Return [`GamepadOutputError::UnknownGamepad`] for the gamepads driven by other
+    /// backends.
+    fn send(&mut self, request: &GamepadOutputRequest) -> Result<(), GamepadOutputError>;
+}
+
+/// The [`GamepadOutputBackend`]s, which are sent the [`GamepadOutputRequest`]s in the order they
+/// were added, until one of them drives the gamepad.
+#[derive(Resource, Default)]
+pub struct GamepadOutputBackends(Vec<Box<dyn GamepadOutputBackend>>);
+
+impl GamepadOutputBackends {
+    /// Adds a `backend`, after the existing ones.
+    pub fn add(&mut self, backend: impl GamepadOutputBackend) {
+        self.0.push(Box::new(backend));
+    }
+}
+
+/// Sends the [`GamepadOutputRequest`]s supported by their gamepads to the
+/// [`GamepadOutputBackends`].
+pub fn gamepad_output_system(
+    mut requests: MessageReader<GamepadOutputRequest>,
+    mut backends: ResMut<GamepadOutputBackends>,
+    capabilities: Query<&GamepadOutputCapabilities>,
+) {
+    for request in requests.read() {
+        let gamepad = request.gamepad();
+        if !capabilities
+            .get(gamepad)
+            .is_ok_and(|capabilities| capabilities.supports(request))
+        {
+            continue;
+        }
+        for backend in &mut backends.0 {
+            match backend.send(request) {
+                Ok(()) => break,
+                Err(GamepadOutputError::UnknownGamepad) => continue,
+                Err(err) => {
+                    warn!("Tried to change an output of gamepad {gamepad} but an error occurred: {err}");
+                    break;
+                }
+            }
         }
     }
 }
@@ -1818,20 +2705,28 @@ impl GamepadRumbleRequest {
 #[cfg(test)]
 mod tests {
     use super::{
-        gamepad_connection_system, gamepad_event_processing_system, AxisSettings,
-        AxisSettingsError, ButtonAxisSettings, ButtonSettings, ButtonSettingsError, Gamepad,
-        GamepadAxis, GamepadAxisChangedEvent, GamepadButton, GamepadButtonChangedEvent,
-        GamepadButtonStateChangedEvent,
+        gamepad_connection_system, gamepad_event_processing_system, gamepad_profiles_system,
+        AxisCalibration, AxisSettings, AxisSettingsError, ButtonAxisSettings, ButtonSettings,
+        ButtonSettingsError, Gamepad, GamepadAxis, GamepadAxisChangedEvent, GamepadButton,
+        GamepadButtonChangedEvent, GamepadButtonStateChangedEvent, GamepadCalibration,
+        GamepadCalibrationStep,
         GamepadConnection::{Connected, Disconnected},
-        GamepadConnectionEvent, GamepadEvent, GamepadSettings, RawGamepadAxisChangedEvent,
-        RawGamepadButtonChangedEvent, RawGamepadEvent,
+        GamepadConnectionEvent, GamepadEvent, GamepadGuid, GamepadLightColor, GamepadOutputBackend,
+        GamepadOutputBackends, GamepadOutputCapabilities, GamepadOutputError,
+        GamepadOutputExtension, GamepadOutputRequest, GamepadProfiles, GamepadRumble,
+        GamepadRumbleEnvelope, GamepadRumbleIntensity, GamepadSettings, GamepadTrigger,
+        GamepadTriggerEffect, RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent,
+        RawGamepadEvent,
     };
     use crate::ButtonState;
-    use alloc::string::ToString;
-    use bevy_app::{App, PreUpdate};
+    use alloc::{string::ToString, vec::Vec};
+    use bevy_app::{App, PostUpdate, PreUpdate};
     use bevy_ecs::entity::Entity;
     use bevy_ecs::message::Messages;
     use bevy_ecs::schedule::IntoScheduleConfigs;
+    use bevy_math::curve::EaseFunction;
+    use bevy_platform::sync::{Arc, Mutex};
+    use core::time::Duration;
 
     fn test_button_axis_settings_filter(
         settings: ButtonAxisSettings,
@@ -2114,6 +3009,7 @@ mod tests {
                 deadzone_upperbound: 0.05,
                 livezone_upperbound: 0.95,
                 threshold: 0.001,
+                ..AxisSettings::default()
             })
         );
         assert_eq!(
@@ -2979,4 +3875,218 @@ mod tests {
             4
         );
     }
+
+    #[test]
+    fn rumble_envelopes_shape_each_motor() {
+        let rumble = GamepadRumble::new(Duration::from_secs(1), GamepadRumbleIntensity::MAX)
+            .with_strong_motor_envelope(GamepadRumbleEnvelope::new(
+                Duration::from_millis(500),
+                Duration::ZERO,
+            ))
+            .with_weak_motor_envelope(GamepadRumbleEnvelope {
+                fade_level: 0.5,
+                ..GamepadRumbleEnvelope::new(Duration::ZERO, Duration::from_millis(500))
+            });
+        let intensity_at = |millis| rumble.intensity_at(Duration::from_millis(millis));
+
+        assert_eq!(intensity_at(0).strong_motor, 0.0);
+        assert_eq!(intensity_at(0).weak_motor, 1.0);
+        assert!((intensity_at(250).strong_motor - 0.5).abs() < 1e-6);
+        assert_eq!(intensity_at(500), GamepadRumbleIntensity::MAX);
+        assert!((intensity_at(750).weak_motor - 0.75).abs() < 1e-6);
+        assert_eq!(intensity_at(750).strong_motor, 1.0);
+
+        // The rumble is over.
+        assert_eq!(
+            intensity_at(1000),
+            GamepadRumbleIntensity {
+                strong_motor: 0.0,
+                weak_motor: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn axis_response_curve_and_inversion() {
+        let mut settings = AxisSettings::default();
+        assert_eq!(settings.apply_response(0.5), 0.5);
+        assert_eq!(settings.apply_response(-0.5), -0.5);
+
+        settings.set_response_curve(EaseFunction::QuadraticIn);
+        assert_eq!(settings.apply_response(0.5), 0.25);
+        assert_eq!(settings.apply_response(-0.5), -0.25);
+
+        settings.set_inverted(true);
+        assert_eq!(settings.apply_response(0.5), -0.25);
+        assert_eq!(settings.apply_response(-1.0), 1.0);
+    }
+
+    #[test]
+    fn calibration_captures_drift_and_range() {
+        let mut calibration = GamepadCalibration::default();
+        for value in [0.02, -0.08, 0.05] {
+            calibration.record(GamepadAxis::LeftStickX, value);
+        }
+        calibration.step = GamepadCalibrationStep::Range;
+        for value in [0.5, 0.9, -0.85, 0.2] {
+            calibration.record(GamepadAxis::LeftStickX, value);
+        }
+        assert_eq!(
+            calibration.axis(GamepadAxis::LeftStickX),
+            Some(&AxisCalibration {
+                min: -0.85,
+                max: 0.9,
+                drift: 0.08,
+            })
+        );
+        assert!(calibration.axis(GamepadAxis::LeftStickY).is_none());
+
+        let mut settings = GamepadSettings::default();
+        settings
+            .axis_settings
+            .entry(GamepadAxis::LeftStickX)
+            .or_default()
+            .set_inverted(true);
+        calibration.apply(&mut settings, 0.02).unwrap();
+        let axis_settings = settings.get_axis_settings(GamepadAxis::LeftStickX);
+        assert!((axis_settings.deadzone_upperbound() - 0.1).abs() < 1e-6);
+        assert!((axis_settings.deadzone_lowerbound() + 0.1).abs() < 1e-6);
+        assert!((axis_settings.livezone_upperbound() - 0.88).abs() < 1e-6);
+        assert!((axis_settings.livezone_lowerbound() + 0.83).abs() < 1e-6);
+        assert!(axis_settings.inverted());
+
+        // A drift covering the whole range can't be calibrated.
+        let worn = AxisCalibration {
+            min: 0.0,
+            max: 0.0,
+            drift: 0.99,
+        };
+        assert!(worn.to_axis_settings(0.02).is_err());
+    }
+
+    #[test]
+    fn profiles_apply_to_connected_gamepads() {
+        let mut app = App::new();
+        app.add_message::<GamepadConnectionEvent>()
+            .init_resource::<GamepadProfiles>()
+            .add_systems(
+                PreUpdate,
+                (
+                    gamepad_connection_system,
+                    gamepad_profiles_system.after(gamepad_connection_system),
+                ),
+            );
+        let guid = GamepadGuid([1; 16]);
+        let mut profile = GamepadSettings::default();
+        profile
+            .axis_settings
+            .entry(GamepadAxis::RightStickY)
+            .or_default()
+            .set_inverted(true);
+        app.world_mut()
+            .resource_mut::<GamepadProfiles>()
+            .insert(guid, profile);
+
+        let gamepad = app.world_mut().spawn(guid).id();
+        let other_gamepad = app.world_mut().spawn(GamepadGuid([2; 16])).id();
+        for entity in [gamepad, other_gamepad] {
+            app.world_mut()
+                .resource_mut::<Messages<GamepadConnectionEvent>>()
+                .write(GamepadConnectionEvent::new(
+                    entity,
+                    Connected {
+                        name: "Test gamepad".to_string(),
+                        vendor_id: None,
+                        product_id: None,
+                    },
+                ));
+        }
+        app.update();
+
+        let inverted = |entity| {
+            app.world()
+                .get::<GamepadSettings>(entity)
+                .unwrap()
+                .get_axis_settings(GamepadAxis::RightStickY)
+                .inverted()
+        };
+        assert!(inverted(gamepad));
+        assert!(!inverted(other_gamepad));
+    }
+
+    #[derive(Debug)]
+    struct PlayerIndicators(u8);
+
+    impl GamepadOutputExtension for PlayerIndicators {}
+
+    /// A backend driving a single gamepad, recording the requests it is sent.
+    struct RecordingBackend {
+        gamepad: Entity,
+        sent: Arc<Mutex<Vec<GamepadOutputRequest>>>,
+    }
+
+    impl GamepadOutputBackend for RecordingBackend {
+        fn send(&mut self, request: &GamepadOutputRequest) -> Result<(), GamepadOutputError> {
+            if request.gamepad() != self.gamepad {
+                return Err(GamepadOutputError::UnknownGamepad);
+            }
+            self.sent.lock().unwrap().push(request.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn output_requests_are_sent_to_capable_gamepads() {
+        let mut app = App::new();
+        app.add_message::<GamepadOutputRequest>()
+            .init_resource::<GamepadOutputBackends>()
+            .add_systems(PostUpdate, super::gamepad_output_system);
+
+        let capabilities = GamepadOutputCapabilities {
+            light: true,
+            ..Default::default()
+        }
+        .with_extension::<PlayerIndicators>();
+        let first = app.world_mut().spawn(capabilities.clone()).id();
+        let second = app.world_mut().spawn(capabilities).id();
+        let sent = [(); 2].map(|_| Arc::new(Mutex::new(Vec::new())));
+        for (gamepad, sent) in [first, second].into_iter().zip(&sent) {
+            app.world_mut()
+                .resource_mut::<GamepadOutputBackends>()
+                .add(RecordingBackend {
+                    gamepad,
+                    sent: sent.clone(),
+                });
+        }
+
+        let mut requests = app
+            .world_mut()
+            .resource_mut::<Messages<GamepadOutputRequest>>();
+        requests.write(GamepadOutputRequest::SetLight {
+            gamepad: second,
+            color: GamepadLightColor::WHITE,
+        });
+        // The triggers of the gamepads aren't adaptive.
+        requests.write(GamepadOutputRequest::SetTriggerEffect {
+            gamepad: first,
+            trigger: GamepadTrigger::Left,
+            effect: GamepadTriggerEffect::Off,
+        });
+        requests.write(GamepadOutputRequest::extension(first, PlayerIndicators(1)));
+        app.update();
+
+        let first_sent = sent[0].lock().unwrap();
+        assert_eq!(first_sent.len(), 1);
+        assert_eq!(
+            first_sent[0]
+                .downcast_extension::<PlayerIndicators>()
+                .map(|indicators| indicators.0),
+            Some(1)
+        );
+        let second_sent = sent[1].lock().unwrap();
+        assert!(matches!(
+            second_sent[..],
+            [GamepadOutputRequest::SetLight { gamepad, .. }] if gamepad == second
+        ));
+    }
 }
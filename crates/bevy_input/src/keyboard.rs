@@ -97,6 +97,11 @@ use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 /// The event is consumed inside of the [`keyboard_input_system`] to update the
 /// [`ButtonInput<KeyCode>`](ButtonInput<KeyCode>) and
 /// [`ButtonInput<Key>`](ButtonInput<Key>) resources.
+///
+/// Gameplay should read the state of the keys from these resources, which ignore the
+/// [repeats](Self::repeat) of held keys. Text fields should read [`TextInputEvent`]s instead,
+/// which follow the keyboard layout, the key repeats and the input method, and [`KeyRepeat`]s for
+/// the editing keys such as backspace or the arrow keys.
 #[derive(Message, Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "bevy_reflect",
@@ -159,6 +164,88 @@ pub struct KeyboardInput {
 )]
 pub struct KeyboardFocusLost;
 
+/// A key held down and repeated by the operating system, for example to repeat the navigation in
+/// a menu, or the deletion in a text field.
+///
+/// This event is written for each [`KeyboardInput`] with [`repeat`](KeyboardInput::repeat) set,
+/// at the rate configured in the keyboard settings of the operating system.
+#[derive(Message, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Clone, Message)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct KeyRepeat {
+    /// The physical key code of the repeated key.
+    pub key_code: KeyCode,
+    /// The logical key of the repeated key.
+    pub logical_key: Key,
+    /// Window that received the input.
+    pub window: Entity,
+}
+
+impl KeyRepeat {
+    /// Returns the repeat of a [`KeyboardInput`], if it is a repeat.
+    pub fn from_keyboard_input(input: &KeyboardInput) -> Option<Self> {
+        (input.repeat && input.state == ButtonState::Pressed).then(|| Self {
+            key_code: input.key_code,
+            logical_key: input.logical_key.clone(),
+            window: input.window,
+        })
+    }
+}
+
+/// Text typed in a window, to insert in a text field.
+///
+/// The text follows the keyboard layout, and is repeated while a key is held. It is written from
+/// the [`text`](KeyboardInput::text) of the pressed keys, and from the text committed by an input
+/// method editor (IME).
+///
+/// The control characters produced by keys such as backspace, enter or escape are left out, so
+/// that the text can be inserted as is. Read the [`KeyboardInput`]s and [`KeyRepeat`]s of these
+/// keys to edit the text.
+#[derive(Message, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Hash, Clone, Message)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TextInputEvent {
+    /// The text to insert.
+    pub text: SmolStr,
+    /// Whether this text comes from a repeat of a held key.
+    pub repeat: bool,
+    /// Window that received the input.
+    pub window: Entity,
+}
+
+impl TextInputEvent {
+    /// Returns the text typed by a [`KeyboardInput`], if it produces text other than control
+    /// characters.
+    pub fn from_keyboard_input(input: &KeyboardInput) -> Option<Self> {
+        if input.state != ButtonState::Pressed {
+            return None;
+        }
+        let text = input.text.as_ref()?;
+        let text: SmolStr = text.chars().filter(|c| !c.is_control()).collect();
+        (!text.is_empty()).then_some(Self {
+            text,
+            repeat: input.repeat,
+            window: input.window,
+        })
+    }
+}
+
 /// Updates the [`ButtonInput<KeyCode>`] and [`ButtonInput<Key>`] resources with the latest [`KeyboardInput`] events.
 ///
 /// ## Differences
@@ -1557,3 +1644,47 @@ pub enum Key {
     /// General-purpose function key.
     F35,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Key, KeyCode, KeyRepeat, KeyboardInput, TextInputEvent};
+    use crate::ButtonState;
+    use bevy_ecs::entity::Entity;
+
+    fn keyboard_input(text: Option<&str>, state: ButtonState, repeat: bool) -> KeyboardInput {
+        KeyboardInput {
+            key_code: KeyCode::KeyA,
+            logical_key: Key::Character("a".into()),
+            state,
+            text: text.map(Into::into),
+            repeat,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    #[test]
+    fn text_input_skips_releases_and_control_characters() {
+        let text = |input: KeyboardInput| TextInputEvent::from_keyboard_input(&input);
+
+        let typed = text(keyboard_input(Some("a"), ButtonState::Pressed, true)).unwrap();
+        assert_eq!(typed.text, "a");
+        assert!(typed.repeat);
+
+        assert!(text(keyboard_input(Some("a"), ButtonState::Released, false)).is_none());
+        assert!(text(keyboard_input(None, ButtonState::Pressed, false)).is_none());
+        // Backspace produces a control character.
+        assert!(text(keyboard_input(Some("\u{8}"), ButtonState::Pressed, false)).is_none());
+    }
+
+    #[test]
+    fn key_repeats_only_come_from_repeated_presses() {
+        let repeat = |input: KeyboardInput| KeyRepeat::from_keyboard_input(&input);
+
+        assert_eq!(
+            repeat(keyboard_input(None, ButtonState::Pressed, true)).map(|repeat| repeat.key_code),
+            Some(KeyCode::KeyA)
+        );
+        assert!(repeat(keyboard_input(None, ButtonState::Pressed, false)).is_none());
+        assert!(repeat(keyboard_input(None, ButtonState::Released, true)).is_none());
+    }
+}
@@ -11,12 +11,15 @@
 //! # Supported input devices
 //!
 //! `bevy` currently supports keyboard, mouse, gamepad, and touch inputs.
+//!
+//! These inputs can be mapped to the actions of a game with an [`InputMap`](action::InputMap).
 
 #[cfg(feature = "std")]
 extern crate std;
 
 extern crate alloc;
 
+pub mod action;
 mod axis;
 mod button_input;
 /// Common run conditions
@@ -46,7 +49,10 @@ pub use button_input::*;
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Axis, ButtonInput};
+    pub use crate::{
+        action::{ActionBinding, ActionState, InputMap, InputMapPlugin},
+        Axis, ButtonInput,
+    };
 
     #[doc(hidden)]
     #[cfg(feature = "gamepad")]
@@ -74,7 +80,10 @@ use bevy_reflect::Reflect;
 use gestures::*;
 
 #[cfg(feature = "keyboard")]
-use keyboard::{keyboard_input_system, Key, KeyCode, KeyboardFocusLost, KeyboardInput};
+use keyboard::{
+    keyboard_input_system, Key, KeyCode, KeyRepeat, KeyboardFocusLost, KeyboardInput,
+    TextInputEvent,
+};
 
 #[cfg(feature = "mouse")]
 use mouse::{
@@ -88,9 +97,11 @@ use touch::{touch_screen_input_system, TouchInput, Touches};
 
 #[cfg(feature = "gamepad")]
 use gamepad::{
-    gamepad_connection_system, gamepad_event_processing_system, GamepadAxisChangedEvent,
+    gamepad_calibration_system, gamepad_connection_system, gamepad_event_processing_system,
+    gamepad_output_system, gamepad_profiles_system, GamepadAxisChangedEvent,
     GamepadButtonChangedEvent, GamepadButtonStateChangedEvent, GamepadConnectionEvent,
-    GamepadEvent, GamepadRumbleRequest, RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent,
+    GamepadEvent, GamepadOutputBackends, GamepadOutputRequest, GamepadProfiles,
+    GamepadRumbleRequest, RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent,
     RawGamepadEvent,
 };
 
@@ -112,6 +123,8 @@ impl Plugin for InputPlugin {
         #[cfg(feature = "keyboard")]
         app.add_message::<KeyboardInput>()
             .add_message::<KeyboardFocusLost>()
+            .add_message::<KeyRepeat>()
+            .add_message::<TextInputEvent>()
             .init_resource::<ButtonInput<KeyCode>>()
             .init_resource::<ButtonInput<Key>>()
             .add_systems(PreUpdate, keyboard_input_system.in_set(InputSystems));
@@ -150,14 +163,20 @@ impl Plugin for InputPlugin {
             .add_message::<RawGamepadAxisChangedEvent>()
             .add_message::<RawGamepadButtonChangedEvent>()
             .add_message::<GamepadRumbleRequest>()
+            .add_message::<GamepadOutputRequest>()
+            .init_resource::<GamepadProfiles>()
+            .init_resource::<GamepadOutputBackends>()
             .add_systems(
                 PreUpdate,
                 (
                     gamepad_connection_system,
-                    gamepad_event_processing_system.after(gamepad_connection_system),
+                    gamepad_profiles_system.after(gamepad_connection_system),
+                    gamepad_event_processing_system.after(gamepad_profiles_system),
+                    gamepad_calibration_system,
                 )
                     .in_set(InputSystems),
-            );
+            )
+            .add_systems(PostUpdate, gamepad_output_system);
 
         #[cfg(feature = "touch")]
         app.add_message::<TouchInput>()
@@ -1,4 +1,4 @@
-use bevy_app::{plugin_group, Plugin};
+use bevy_app::{plugin_group, Plugin, PluginGroup, PluginGroupBuilder};
 
 plugin_group! {
     /// This plugin group will add all the default plugins for a *Bevy* application:
@@ -11,6 +11,8 @@ plugin_group! {
         bevy_time:::TimePlugin,
         bevy_transform:::TransformPlugin,
         bevy_diagnostic:::DiagnosticsPlugin,
+        #[cfg(feature = "bevy_rand")]
+        bevy_rand:::RngPlugin,
         bevy_input:::InputPlugin,
         #[cfg(feature = "bevy_input_focus")]
         bevy_input_focus:::InputFocusPlugin,
@@ -188,3 +190,85 @@ plugin_group! {
     ///     Duration::from_secs_f64(1.0 / 60.0),
     /// ))).run();
 }
+
+/// This plugin group will add the plugins for a headless *Bevy* server application.
+///
+/// It contains the engine plugins that make sense without any window, rendering, audio or
+/// input, which is typically what a dedicated game server needs:
+/// * [`PanicHandlerPlugin`](crate::app::PanicHandlerPlugin)
+/// * [`LogPlugin`](crate::log::LogPlugin) - with feature `bevy_log`
+/// * [`TaskPoolPlugin`](crate::app::TaskPoolPlugin)
+/// * [`FrameCountPlugin`](crate::diagnostic::FrameCountPlugin)
+/// * [`TimePlugin`](crate::time::TimePlugin)
+/// * [`TransformPlugin`](crate::transform::TransformPlugin)
+/// * [`DiagnosticsPlugin`](crate::diagnostic::DiagnosticsPlugin)
+/// * [`RngPlugin`](crate::rand::RngPlugin) - with feature `bevy_rand`
+/// * [`ScheduleRunnerPlugin`](crate::app::ScheduleRunnerPlugin) - running at
+///   [`ServerPlugins::DEFAULT_TICK_RATE`]
+/// * [`TerminalCtrlCHandlerPlugin`](crate::app::TerminalCtrlCHandlerPlugin) - with feature `std`,
+///   to shut down gracefully on `Ctrl+C` and termination signals
+/// * [`AssetPlugin`](crate::asset::AssetPlugin) - with feature `bevy_asset`
+/// * [`WorldSerializationPlugin`](crate::world_serialization::WorldSerializationPlugin) - with
+///   feature `bevy_world_serialization`
+/// * [`ScenePlugin`](crate::scene::ScenePlugin) - with feature `bevy_scene`
+/// * [`StatesPlugin`](crate::state::app::StatesPlugin) - with feature `bevy_state`
+/// * [`CiTestingPlugin`](crate::dev_tools::ci_testing::CiTestingPlugin) - with feature
+///   `bevy_ci_testing`
+///
+/// Unlike [`DefaultPlugins`], this group never adds windowing or rendering plugins, even if the
+/// corresponding features are enabled, so a server and a client can be built from the same
+/// crate.
+///
+/// The tick rate can be configured by replacing the runner:
+/// ```rust, no_run
+/// # use bevy_app::{App, PluginGroup, ScheduleRunnerPlugin};
+/// # use bevy_internal::ServerPlugins;
+/// App::new()
+///     .add_plugins(ServerPlugins.set(ScheduleRunnerPlugin::run_at_tick_rate(30.0)))
+///     .run();
+/// ```
+///
+/// Assets are loaded from the same [`AssetPlugin::file_path`](crate::asset::AssetPlugin::file_path)
+/// as on the client, relative to the `BEVY_ASSET_ROOT` environment variable, the
+/// `CARGO_MANIFEST_DIR` environment variable or the directory of the executable. Set
+/// `BEVY_ASSET_ROOT` to deploy the server's assets elsewhere.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ServerPlugins;
+
+impl ServerPlugins {
+    /// The tick rate the [`ScheduleRunnerPlugin`](crate::app::ScheduleRunnerPlugin) of this group
+    /// runs at by default, in ticks per second.
+    pub const DEFAULT_TICK_RATE: f64 = 60.0;
+}
+
+impl PluginGroup for ServerPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let mut group = PluginGroupBuilder::start::<Self>().add(bevy_app::PanicHandlerPlugin);
+        #[cfg(feature = "bevy_log")]
+        group = group.add(bevy_log::LogPlugin::default());
+        group = group
+            .add(bevy_app::TaskPoolPlugin::default())
+            .add(bevy_diagnostic::FrameCountPlugin)
+            .add(bevy_time::TimePlugin)
+            .add(bevy_transform::TransformPlugin)
+            .add(bevy_diagnostic::DiagnosticsPlugin);
+        #[cfg(feature = "bevy_rand")]
+        group = group.add(bevy_rand::RngPlugin::default());
+        group = group.add(bevy_app::ScheduleRunnerPlugin::run_at_tick_rate(
+            Self::DEFAULT_TICK_RATE,
+        ));
+        #[cfg(all(feature = "std", any(all(unix, not(target_os = "horizon")), windows)))]
+        group = group.add(bevy_app::TerminalCtrlCHandlerPlugin);
+        #[cfg(feature = "bevy_asset")]
+        group = group.add(bevy_asset::AssetPlugin::default());
+        #[cfg(feature = "bevy_world_serialization")]
+        group = group.add(bevy_world_serialization::WorldSerializationPlugin);
+        #[cfg(feature = "bevy_scene")]
+        group = group.add(bevy_scene::ScenePlugin);
+        #[cfg(feature = "bevy_state")]
+        group = group.add(bevy_state::app::StatesPlugin);
+        #[cfg(feature = "bevy_ci_testing")]
+        group = group.add(bevy_dev_tools::ci_testing::CiTestingPlugin);
+        group
+    }
+}
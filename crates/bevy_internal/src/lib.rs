@@ -31,8 +31,12 @@ pub use bevy_audio as audio;
 pub use bevy_camera as camera;
 #[cfg(feature = "bevy_camera_controller")]
 pub use bevy_camera_controller as camera_controller;
+#[cfg(feature = "bevy_character")]
+pub use bevy_character as character;
 #[cfg(feature = "bevy_clipboard")]
 pub use bevy_clipboard as clipboard;
+#[cfg(feature = "bevy_collision")]
+pub use bevy_collision as collision;
 #[cfg(feature = "bevy_color")]
 pub use bevy_color as color;
 #[cfg(feature = "bevy_core_pipeline")]
@@ -40,6 +44,8 @@ pub use bevy_core_pipeline as core_pipeline;
 #[cfg(feature = "bevy_dev_tools")]
 pub use bevy_dev_tools as dev_tools;
 pub use bevy_diagnostic as diagnostic;
+#[cfg(feature = "dylib_reload")]
+pub use bevy_dylib_reload as dylib_reload;
 pub use bevy_ecs as ecs;
 #[cfg(feature = "bevy_feathers")]
 pub use bevy_feathers as feathers;
@@ -65,6 +71,12 @@ pub use bevy_material as material;
 pub use bevy_math as math;
 #[cfg(feature = "bevy_mesh")]
 pub use bevy_mesh as mesh;
+#[cfg(feature = "bevy_nav")]
+pub use bevy_nav as nav;
+#[cfg(feature = "bevy_net")]
+pub use bevy_net as net;
+#[cfg(feature = "bevy_particles")]
+pub use bevy_particles as particles;
 #[cfg(feature = "bevy_pbr")]
 pub use bevy_pbr as pbr;
 #[cfg(feature = "bevy_picking")]
@@ -73,6 +85,8 @@ pub use bevy_platform as platform;
 #[cfg(feature = "bevy_post_process")]
 pub use bevy_post_process as post_process;
 pub use bevy_ptr as ptr;
+#[cfg(feature = "bevy_rand")]
+pub use bevy_rand as rand;
 pub use bevy_reflect as reflect;
 #[cfg(feature = "bevy_remote")]
 pub use bevy_remote as remote;
@@ -96,6 +110,8 @@ pub use bevy_tasks as tasks;
 #[cfg(feature = "bevy_text")]
 pub use bevy_text as text;
 pub use bevy_time as time;
+#[cfg(feature = "bevy_timeline")]
+pub use bevy_timeline as timeline;
 pub use bevy_transform as transform;
 #[cfg(feature = "bevy_ui")]
 pub use bevy_ui as ui;
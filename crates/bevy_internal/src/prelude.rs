@@ -2,7 +2,7 @@
 pub use crate::{
     app::prelude::*, ecs::prelude::*, input::prelude::*, math::prelude::*, platform::prelude::*,
     reflect::prelude::*, time::prelude::*, transform::prelude::*, utils::prelude::*,
-    DefaultPlugins, MinimalPlugins,
+    DefaultPlugins, MinimalPlugins, ServerPlugins,
 };
 
 #[doc(hidden)]
@@ -103,6 +103,10 @@ pub use crate::gilrs::*;
 #[cfg(feature = "bevy_state")]
 pub use crate::state::prelude::*;
 
+#[doc(hidden)]
+#[cfg(feature = "bevy_rand")]
+pub use crate::rand::prelude::*;
+
 #[doc(hidden)]
 #[cfg(feature = "bevy_gltf")]
 pub use crate::gltf::prelude::*;
@@ -30,6 +30,12 @@ pub struct CascadeShadowConfig {
     pub overlap_proportion: f32,
     /// The (positive) distance to the near boundary of the first cascade.
     pub minimum_distance: f32,
+    /// The proportion of the last cascade over which shadows fade out, so they don't end abruptly
+    /// at the maximum shadow distance.
+    pub fade_proportion: f32,
+    /// Whether each cascade is snapped to shadow map texels, which prevents shadow edges from
+    /// shimmering as the camera moves.
+    pub texel_snapping: bool,
 }
 
 impl Default for CascadeShadowConfig {
@@ -38,20 +44,79 @@ impl Default for CascadeShadowConfig {
     }
 }
 
+/// How the view frustum is split into shadow cascades.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub enum CascadeSplitScheme {
+    /// The far bound of the first cascade is
+    /// [`first_cascade_far_bound`](CascadeShadowConfigBuilder::first_cascade_far_bound), and the
+    /// following cascades are exponentially spaced up to the maximum shadow distance.
+    #[default]
+    Exponential,
+    /// The cascades evenly split the distance between the minimum and maximum shadow distances.
+    ///
+    /// This gives far away cascades the same resolution as near ones, at the cost of the
+    /// resolution near the camera.
+    Uniform,
+    /// The cascade bounds blend between a logarithmic and a uniform split of the distance between
+    /// the minimum and maximum shadow distances, as described in
+    /// [Parallel-Split Shadow Maps](https://developer.nvidia.com/gpugems/gpugems3/part-ii-light-and-shadows/chapter-10-parallel-split-shadow-maps-programmable-gpus).
+    Practical {
+        /// The weight of the logarithmic split, from `0.0` (uniform) to `1.0` (logarithmic).
+        lambda: f32,
+    },
+}
+
 fn calculate_cascade_bounds(
+    split_scheme: CascadeSplitScheme,
     num_cascades: usize,
+    minimum_distance: f32,
     nearest_bound: f32,
     shadow_maximum_distance: f32,
 ) -> Vec<f32> {
     if num_cascades == 1 {
         return vec![shadow_maximum_distance];
     }
-    let base = ops::powf(
-        shadow_maximum_distance / nearest_bound,
-        1.0 / (num_cascades - 1) as f32,
-    );
-    (0..num_cascades)
-        .map(|i| nearest_bound * ops::powf(base, i as f32))
+    match split_scheme {
+        CascadeSplitScheme::Exponential => {
+            let base = ops::powf(
+                shadow_maximum_distance / nearest_bound,
+                1.0 / (num_cascades - 1) as f32,
+            );
+            (0..num_cascades)
+                .map(|i| nearest_bound * ops::powf(base, i as f32))
+                .collect()
+        }
+        CascadeSplitScheme::Uniform => {
+            practical_cascade_bounds(0.0, num_cascades, minimum_distance, shadow_maximum_distance)
+        }
+        CascadeSplitScheme::Practical { lambda } => practical_cascade_bounds(
+            lambda,
+            num_cascades,
+            minimum_distance,
+            shadow_maximum_distance,
+        ),
+    }
+}
+
+fn practical_cascade_bounds(
+    lambda: f32,
+    num_cascades: usize,
+    minimum_distance: f32,
+    shadow_maximum_distance: f32,
+) -> Vec<f32> {
+    (1..=num_cascades)
+        .map(|i| {
+            let t = i as f32 / num_cascades as f32;
+            let uniform = minimum_distance + (shadow_maximum_distance - minimum_distance) * t;
+            // The logarithmic split is undefined for a minimum distance of zero.
+            let logarithmic = if minimum_distance > 0.0 {
+                minimum_distance * ops::powf(shadow_maximum_distance / minimum_distance, t)
+            } else {
+                uniform
+            };
+            lambda * logarithmic + (1.0 - lambda) * uniform
+        })
         .collect()
 }
 
@@ -84,12 +149,22 @@ pub struct CascadeShadowConfigBuilder {
     pub maximum_distance: f32,
     /// Sets the far bound of the first cascade, relative to the view origin.
     /// In-between cascades will be exponentially spaced relative to the maximum shadow distance.
-    /// NOTE: This is ignored if there is only one cascade, the maximum distance takes precedence.
+    /// NOTE: This is ignored if there is only one cascade, the maximum distance takes precedence,
+    /// or if the `split_scheme` is not [`CascadeSplitScheme::Exponential`].
     pub first_cascade_far_bound: f32,
     /// Sets the overlap proportion between cascades.
     /// The overlap is used to make the transition from one cascade's shadow map to the next
     /// less abrupt by blending between both shadow maps.
     pub overlap_proportion: f32,
+    /// How the distance between `minimum_distance` and `maximum_distance` is split into cascades.
+    pub split_scheme: CascadeSplitScheme,
+    /// Sets the proportion of the last cascade over which shadows fade out towards
+    /// `maximum_distance`. `0.0` disables the fade.
+    pub fade_proportion: f32,
+    /// Whether cascades are snapped to shadow map texels.
+    /// Snapping keeps shadow edges from shimmering when the camera moves, but can be turned off
+    /// to compare against, or when the camera never moves.
+    pub texel_snapping: bool,
 }
 
 impl CascadeShadowConfigBuilder {
@@ -120,14 +195,30 @@ impl CascadeShadowConfigBuilder {
             "overlap_proportion must be in [0.0, 1.0) but was {}",
             self.overlap_proportion
         );
+        assert!(
+            (0.0..=1.0).contains(&self.fade_proportion),
+            "fade_proportion must be in [0.0, 1.0] but was {}",
+            self.fade_proportion
+        );
+        if let CascadeSplitScheme::Practical { lambda } = self.split_scheme {
+            assert!(
+                (0.0..=1.0).contains(&lambda),
+                "lambda must be in [0.0, 1.0] but was {}",
+                lambda
+            );
+        }
         CascadeShadowConfig {
             bounds: calculate_cascade_bounds(
+                self.split_scheme,
                 self.num_cascades,
+                self.minimum_distance,
                 self.first_cascade_far_bound,
                 self.maximum_distance,
             ),
             overlap_proportion: self.overlap_proportion,
             minimum_distance: self.minimum_distance,
+            fade_proportion: self.fade_proportion,
+            texel_snapping: self.texel_snapping,
         }
     }
 }
@@ -153,6 +244,9 @@ impl Default for CascadeShadowConfigBuilder {
             maximum_distance: 150.0,
             first_cascade_far_bound: 10.0,
             overlap_proportion: 0.2,
+            split_scheme: CascadeSplitScheme::Exponential,
+            fade_proportion: 0.0,
+            texel_snapping: true,
         }
     }
 }
@@ -191,6 +285,14 @@ pub struct Cascade {
     pub texel_size: f32,
 }
 
+/// Add this component to a camera to tint each directional light shadow cascade with a different
+/// color in its view, which shows where the cascade boundaries, overlaps and fade are.
+///
+/// This is useful when tuning a [`CascadeShadowConfig`].
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct ShadowCascadesDebug;
+
 /// Sets up [`Cascades`] for all shadow mapped [`DirectionalLight`]s.
 pub fn build_directional_light_cascades(
     directional_light_shadow_map: Res<DirectionalLightShadowMap>,
@@ -246,6 +348,7 @@ pub fn build_directional_light_cascades(
                         directional_light_shadow_map.size as f32,
                         world_from_light,
                         light_view_from_camera,
+                        cascades_config.texel_snapping,
                     )
                 })
                 .collect();
@@ -259,12 +362,16 @@ pub fn build_directional_light_cascades(
 /// The corner vertices should be specified in the following order:
 /// first the bottom right, top right, top left, bottom left for the near plane, then similar for the far plane.
 ///
+/// If `texel_snapping` is enabled, the cascade is moved in whole shadow map texels to keep
+/// shadows stable as the view moves.
+///
 /// See this [reference](https://developer.download.nvidia.com/SDK/10.5/opengl/src/cascaded_shadow_maps/doc/cascaded_shadow_maps.pdf) for more details.
 fn calculate_cascade(
     frustum_corners: [Vec3A; 8],
     cascade_texture_size: f32,
     world_from_light: Mat4,
     light_from_camera: Mat4,
+    texel_snapping: bool,
 ) -> Cascade {
     let mut min = Vec3A::splat(f32::MAX);
     let mut max = Vec3A::splat(f32::MIN);
@@ -291,9 +398,16 @@ fn calculate_cascade(
     let cascade_texel_size = cascade_diameter / cascade_texture_size;
     // NOTE: For shadow stability it is very important that the near_plane_center is at integer
     //       multiples of the texel size to be exactly representable in a floating point value.
+    let snap = |x: f32| {
+        if texel_snapping {
+            (x / cascade_texel_size).floor() * cascade_texel_size
+        } else {
+            x
+        }
+    };
     let near_plane_center = Vec3A::new(
-        (0.5 * (min.x + max.x) / cascade_texel_size).floor() * cascade_texel_size,
-        (0.5 * (min.y + max.y) / cascade_texel_size).floor() * cascade_texel_size,
+        snap(0.5 * (min.x + max.x)),
+        snap(0.5 * (min.y + max.y)),
         // NOTE: max.z is the near plane for right-handed y-up
         max.z,
     );
@@ -333,3 +447,46 @@ fn calculate_cascade(
         texel_size: cascade_texel_size,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_schemes() {
+        let bounds = |split_scheme| {
+            CascadeShadowConfigBuilder {
+                num_cascades: 4,
+                minimum_distance: 1.0,
+                maximum_distance: 81.0,
+                first_cascade_far_bound: 3.0,
+                split_scheme,
+                ..Default::default()
+            }
+            .build()
+            .bounds
+        };
+
+        let assert_bounds = |split_scheme, expected: [f32; 4]| {
+            let bounds = bounds(split_scheme);
+            assert_eq!(bounds.len(), expected.len());
+            for (bound, expected) in bounds.into_iter().zip(expected) {
+                assert!(
+                    (bound - expected).abs() < 1e-3,
+                    "{split_scheme:?}: expected {expected}, got {bound}"
+                );
+            }
+        };
+
+        assert_bounds(CascadeSplitScheme::Exponential, [3.0, 9.0, 27.0, 81.0]);
+        assert_bounds(CascadeSplitScheme::Uniform, [21.0, 41.0, 61.0, 81.0]);
+        assert_bounds(
+            CascadeSplitScheme::Practical { lambda: 1.0 },
+            [3.0, 9.0, 27.0, 81.0],
+        );
+        assert_bounds(
+            CascadeSplitScheme::Practical { lambda: 0.5 },
+            [12.0, 25.0, 44.0, 81.0],
+        );
+    }
+}
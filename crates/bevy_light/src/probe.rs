@@ -264,6 +264,10 @@ impl Default for Skybox {
 /// A generated environment map that is filtered at runtime.
 ///
 /// See `bevy_pbr::light_probe::generate` for detailed information.
+///
+/// Equirectangular HDR panoramas can be converted to a suitable cubemap when they are loaded, by
+/// setting the `cubemap_face_size` of the `HdrTextureLoaderSettings` or `ExrTextureLoaderSettings`
+/// to a power of two.
 #[derive(Clone, Component, Reflect, FromTemplate)]
 #[reflect(Component, Default, Clone)]
 pub struct GeneratedEnvironmentMapLight {
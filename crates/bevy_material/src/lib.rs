@@ -65,6 +65,9 @@ pub struct MaterialProperties {
     /// for meshes with equal depth, to avoid z-fighting.
     /// The bias is in depth-texture units so large values may be needed to overcome small depth differences.
     pub depth_bias: f32,
+    /// Add a bias to the view depth of the mesh when sorting it with other meshes of the same
+    /// sorted phase, without affecting the depth test.
+    pub sort_bias: f32,
     /// Whether the material would like to read from
     /// [`ViewTransmissionTexture`](https://docs.rs/bevy/latest/bevy/core_pipeline/core_3d/struct.ViewTransmissionTexture.html).
     ///
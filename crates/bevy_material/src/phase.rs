@@ -1,5 +1,5 @@
 /// Which phase a material renders in.
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
 pub enum RenderPhaseType {
     #[default]
     Opaque,
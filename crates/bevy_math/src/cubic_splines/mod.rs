@@ -909,6 +909,151 @@ pub struct InsufficientDataError {
     given: usize,
 }
 
+/// The kind of interpolation of a [`Spline`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Clone, Default, PartialEq, Hash)
+)]
+pub enum SplineKind {
+    /// Cubic Bezier segments, as in [`CubicBezier`].
+    ///
+    /// The control points are the anchors the curve passes through, separated by the two
+    /// intermediate control points of each segment: `anchor, control, control, anchor, control,
+    /// control, anchor`. This needs `3 * n + 1` points for `n` segments, or `3 * n` if cyclic, where
+    /// the last segment ends at the first anchor.
+    Bezier,
+    /// A Catmull-Rom spline, passing through every control point, as in
+    /// [`CubicCardinalSpline::new_catmull_rom`].
+    #[default]
+    CatmullRom,
+    /// A B-spline, approximating the control points very smoothly, as in [`CubicBSpline`].
+    BSpline,
+    /// Straight lines between the control points, as in [`LinearSpline`].
+    Linear,
+}
+
+/// A spline of any [`SplineKind`], defined by a list of control points.
+///
+/// Unlike the other splines of this module, this can be serialized, which makes it suitable to
+/// store paths, camera rails or easing curves in scenes and assets. It is converted to a
+/// [`CubicCurve`] with [`CubicGenerator::to_curve`] for sampling.
+///
+/// ```
+/// # use bevy_math::{*, prelude::*};
+/// let spline = Spline {
+///     kind: SplineKind::CatmullRom,
+///     control_points: vec![vec2(0.0, 0.0), vec2(1.0, 2.0), vec2(3.0, 2.0), vec2(4.0, 0.0)],
+///     cyclic: false,
+/// };
+/// let curve = spline.to_curve().unwrap();
+/// assert_eq!(curve.position(0.0), vec2(0.0, 0.0));
+/// assert_eq!(curve.segments().len(), 3);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, Clone))]
+pub struct Spline<P: VectorSpace> {
+    /// How the curve is interpolated from the control points.
+    pub kind: SplineKind,
+    /// The control points of the spline.
+    pub control_points: Vec<P>,
+    /// Whether the curve loops back from the last control point to the first one.
+    pub cyclic: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<P: VectorSpace> Spline<P> {
+    /// Creates a spline of the given kind through `control_points`, which isn't cyclic.
+    pub fn new(kind: SplineKind, control_points: impl IntoIterator<Item = P>) -> Self {
+        Self {
+            kind,
+            control_points: control_points.into_iter().collect(),
+            cyclic: false,
+        }
+    }
+
+    /// Returns this spline, looping back from its last control point to its first one.
+    pub fn cyclic(mut self) -> Self {
+        self.cyclic = true;
+        self
+    }
+}
+
+/// An error returned when building a [`CubicCurve`] from a [`Spline`].
+#[derive(Clone, Debug, Error)]
+pub enum SplineError {
+    /// There weren't enough control points.
+    #[error(transparent)]
+    InsufficientData(#[from] InsufficientDataError),
+    /// The number of control points of a [`SplineKind::Bezier`] spline didn't make up whole
+    /// segments.
+    #[error(
+        "A Bezier spline needs 3 * n + 1 control points, or 3 * n if cyclic, but was given {given}"
+    )]
+    InvalidBezierPoints {
+        /// The number of control points of the spline.
+        given: usize,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl<P: VectorSpace<Scalar = f32>> CubicGenerator<P> for Spline<P> {
+    type Error = SplineError;
+
+    fn to_curve(&self) -> Result<CubicCurve<P>, Self::Error> {
+        let points = &self.control_points;
+        let curve = match (self.kind, self.cyclic) {
+            (SplineKind::Bezier, cyclic) => {
+                let length = points.len();
+                let segment_count = if cyclic {
+                    length / 3
+                } else {
+                    length.saturating_sub(1) / 3
+                };
+                let expected = if cyclic {
+                    3 * segment_count
+                } else {
+                    3 * segment_count + 1
+                };
+                if segment_count == 0 || length != expected {
+                    return Err(SplineError::InvalidBezierPoints { given: length });
+                }
+                let segments = (0..segment_count).map(|i| {
+                    let start = 3 * i;
+                    [
+                        points[start],
+                        points[start + 1],
+                        points[start + 2],
+                        points[(start + 3) % length],
+                    ]
+                });
+                CubicBezier::new(segments)
+                    .to_curve()
+                    .map_err(|_| SplineError::InvalidBezierPoints { given: length })?
+            }
+            (SplineKind::CatmullRom, false) => {
+                CubicCardinalSpline::new_catmull_rom(points.iter().copied()).to_curve()?
+            }
+            (SplineKind::CatmullRom, true) => {
+                CubicCardinalSpline::new_catmull_rom(points.iter().copied()).to_curve_cyclic()?
+            }
+            (SplineKind::BSpline, false) => CubicBSpline::new(points.iter().copied()).to_curve()?,
+            (SplineKind::BSpline, true) => {
+                CubicBSpline::new(points.iter().copied()).to_curve_cyclic()?
+            }
+            (SplineKind::Linear, false) => LinearSpline::new(points.iter().copied()).to_curve()?,
+            (SplineKind::Linear, true) => {
+                LinearSpline::new(points.iter().copied()).to_curve_cyclic()?
+            }
+        };
+        Ok(curve)
+    }
+}
+
 /// Implement this on cubic splines that can generate a cubic curve from their spline parameters.
 #[cfg(feature = "alloc")]
 pub trait CubicGenerator<P: VectorSpace> {
@@ -1648,7 +1793,7 @@ mod tests {
     use crate::{
         cubic_splines::{
             CubicBSpline, CubicBezier, CubicGenerator, CubicNurbs, CubicSegment, RationalCurve,
-            RationalGenerator,
+            RationalGenerator, Spline, SplineError, SplineKind,
         },
         ops::{self, FloatPow},
     };
@@ -1830,4 +1975,37 @@ mod tests {
             );
         }
     }
+
+    /// Check that the control points of Bezier splines are split into segments sharing anchors.
+    #[test]
+    fn bezier_spline_segments() {
+        let points = [
+            vec2(0.0, 0.0),
+            vec2(1.0, 1.0),
+            vec2(2.0, 1.0),
+            vec2(3.0, 0.0),
+            vec2(4.0, -1.0),
+            vec2(5.0, -1.0),
+            vec2(6.0, 0.0),
+        ];
+        let curve = Spline::new(SplineKind::Bezier, points).to_curve().unwrap();
+        assert_eq!(curve.segments().len(), 2);
+        assert!(curve.position(1.0).distance(vec2(3.0, 0.0)) <= FLOAT_EQ);
+        assert!(curve.position(2.0).distance(vec2(6.0, 0.0)) <= FLOAT_EQ);
+
+        // A cyclic spline drops the last anchor, as it is the first one.
+        let cyclic = Spline::new(SplineKind::Bezier, points[..6].iter().copied()).cyclic();
+        let curve = cyclic.to_curve().unwrap();
+        assert_eq!(curve.segments().len(), 2);
+        assert!(curve.position(2.0).distance(vec2(0.0, 0.0)) <= FLOAT_EQ);
+
+        assert!(matches!(
+            Spline::new(SplineKind::Bezier, points[..5].iter().copied()).to_curve(),
+            Err(SplineError::InvalidBezierPoints { given: 5 })
+        ));
+        assert!(matches!(
+            Spline::new(SplineKind::CatmullRom, [Vec2::ZERO]).to_curve(),
+            Err(SplineError::InsufficientData(_))
+        ));
+    }
 }
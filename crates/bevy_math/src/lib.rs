@@ -97,7 +97,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::cubic_splines::{
         CubicBSpline, CubicBezier, CubicCardinalSpline, CubicCurve, CubicGenerator, CubicHermite,
-        CubicNurbs, CyclicCubicGenerator, RationalCurve, RationalGenerator,
+        CubicNurbs, CyclicCubicGenerator, RationalCurve, RationalGenerator, Spline, SplineKind,
     };
 }
 
@@ -0,0 +1,469 @@
+use crate::{Mesh, Mesh3d, MeshAccessError, VertexAttributeValues};
+use alloc::vec::Vec;
+use bevy_asset::{AssetEvent, Assets, Handle};
+use bevy_ecs::{
+    change_detection::{DetectChanges, Ref},
+    component::Component,
+    message::MessageReader,
+    reflect::ReflectComponent,
+    system::{Query, ResMut},
+};
+use bevy_math::{ops, Mat3, Quat, UVec3, Vec2, Vec3};
+use bevy_platform::{collections::HashSet, hash::FixedHasher};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Deforms a copy of a [`Mesh`] on the CPU, with a stack of [`Deformer`]s, without skinning it.
+///
+/// The deformed mesh is rebuilt from [`MeshDeformers::base`] when this component changes and when
+/// the base mesh is modified, in [`PostUpdate`](bevy_app::PostUpdate), and rendered by the
+/// [`Mesh3d`] of the entity. The positions of the vertices are deformed by the deformers in order,
+/// and their normals and tangents are transformed accordingly.
+///
+/// The base mesh must keep its data in the main world, with the
+/// [`RenderAssetUsages::MAIN_WORLD`](bevy_asset::RenderAssetUsages::MAIN_WORLD) usage.
+///
+/// ```
+/// # use bevy_asset::Assets;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::prelude::Cuboid;
+/// # use bevy_mesh::{Deformer, Mesh, MeshDeformers};
+/// fn spawn_pillar(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+///     let base = meshes.add(Cuboid::new(1.0, 4.0, 1.0));
+///     commands.spawn(
+///         MeshDeformers::new(base)
+///             .with(Deformer::Taper { factor: -0.2 })
+///             .with(Deformer::Twist { angle: 0.5 }),
+///     );
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Mesh3d)]
+pub struct MeshDeformers {
+    /// The mesh to deform.
+    ///
+    /// It is left untouched: the deformed mesh is a separate asset, held by the [`Mesh3d`].
+    pub base: Handle<Mesh>,
+    /// The deformers applied to the base mesh, in order.
+    pub deformers: Vec<Deformer>,
+}
+
+impl MeshDeformers {
+    /// Creates a stack of deformers for the `base` mesh, without any deformer.
+    pub fn new(base: Handle<Mesh>) -> Self {
+        Self {
+            base,
+            deformers: Vec::new(),
+        }
+    }
+
+    /// Adds a deformer to the end of the stack.
+    pub fn with(mut self, deformer: Deformer) -> Self {
+        self.deformers.push(deformer);
+        self
+    }
+
+    /// Returns where `point` is moved by the deformers.
+    pub fn deform(&self, point: Vec3) -> Vec3 {
+        self.deformers
+            .iter()
+            .fold(point, |point, deformer| deformer.deform(point))
+    }
+
+    /// Deforms the positions, normals and tangents of `mesh` in place.
+    ///
+    /// The normals are transformed by the inverse transpose of the local linear approximation of
+    /// the deformation, so that they stay perpendicular to the deformed surface.
+    pub fn deform_mesh(&self, mesh: &mut Mesh) -> Result<(), MeshAccessError> {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.try_attribute_mut_option(Mesh::ATTRIBUTE_POSITION)?
+        else {
+            return Ok(());
+        };
+        let mut jacobians = Vec::with_capacity(positions.len());
+        for position in positions.iter_mut() {
+            let point = Vec3::from(*position);
+            jacobians.push(self.jacobian(point));
+            *position = self.deform(point).to_array();
+        }
+
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.try_attribute_mut_option(Mesh::ATTRIBUTE_NORMAL)?
+        {
+            for (normal, jacobian) in normals.iter_mut().zip(&jacobians) {
+                let original = Vec3::from(*normal);
+                if jacobian.determinant().abs() > f32::EPSILON {
+                    *normal = (jacobian.inverse().transpose() * original)
+                        .try_normalize()
+                        .unwrap_or(original)
+                        .to_array();
+                }
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x4(tangents)) =
+            mesh.try_attribute_mut_option(Mesh::ATTRIBUTE_TANGENT)?
+        {
+            for (tangent, jacobian) in tangents.iter_mut().zip(&jacobians) {
+                let [x, y, z, handedness] = *tangent;
+                let original = Vec3::new(x, y, z);
+                let [x, y, z] = (*jacobian * original)
+                    .try_normalize()
+                    .unwrap_or(original)
+                    .to_array();
+                *tangent = [x, y, z, handedness];
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the partial derivatives of the deformation at `point`, by central differences.
+    fn jacobian(&self, point: Vec3) -> Mat3 {
+        const EPSILON: f32 = 1e-3;
+        let derivative = |axis: Vec3| {
+            (self.deform(point + axis * EPSILON) - self.deform(point - axis * EPSILON))
+                / (2.0 * EPSILON)
+        };
+        Mat3::from_cols(
+            derivative(Vec3::X),
+            derivative(Vec3::Y),
+            derivative(Vec3::Z),
+        )
+    }
+}
+
+/// A procedural deformation of the vertices of a mesh, in its local space, applied by
+/// [`MeshDeformers`].
+///
+/// The bend, twist and taper deformers act along the local Y axis, from the origin of the mesh, so
+/// that a mesh standing on its origin bends, twists or tapers towards its top.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+pub enum Deformer {
+    /// Bends the part of the mesh above the origin into a circular arc.
+    Bend {
+        /// The angle by which the mesh is bent at [`Deformer::Bend::length`], in radians.
+        angle: f32,
+        /// The height over which the mesh bends. Above it, the mesh continues straight, in the
+        /// bent direction.
+        length: f32,
+        /// The direction in the XZ plane towards which the mesh bends, as `(x, z)`.
+        direction: Vec2,
+    },
+    /// Rotates the mesh around the Y axis, by an angle proportional to the height.
+    Twist {
+        /// The rotation per unit of height, in radians.
+        angle: f32,
+    },
+    /// Scales the mesh in the XZ plane, by a factor proportional to the height.
+    Taper {
+        /// The change of the scale per unit of height: `-0.5` halves the width at a height of `1.0`.
+        factor: f32,
+    },
+    /// Moves the vertices with the control points of a [`Lattice`].
+    Lattice(Lattice),
+}
+
+impl Deformer {
+    /// Returns where `point` is moved by this deformer.
+    pub fn deform(&self, point: Vec3) -> Vec3 {
+        match self {
+            Deformer::Bend {
+                angle,
+                length,
+                direction,
+            } => bend(point, *angle, *length, *direction),
+            Deformer::Twist { angle } => Quat::from_rotation_y(angle * point.y) * point,
+            Deformer::Taper { factor } => {
+                let scale = (1.0 + factor * point.y).max(0.0);
+                Vec3::new(point.x * scale, point.y, point.z * scale)
+            }
+            Deformer::Lattice(lattice) => point + lattice.sample(point),
+        }
+    }
+}
+
+fn bend(point: Vec3, angle: f32, length: f32, direction: Vec2) -> Vec3 {
+    let Some(direction) = direction.try_normalize() else {
+        return point;
+    };
+    if angle.abs() <= f32::EPSILON || length <= 0.0 || point.y <= 0.0 {
+        return point;
+    }
+    let direction = Vec3::new(direction.x, 0.0, direction.y);
+    // The offset of the point towards the direction of the bend, around which the mesh is rolled
+    // onto a circle of radius `radius`.
+    let offset = point.dot(direction);
+    let rest = point - direction * offset - Vec3::Y * point.y;
+    let radius = length / angle;
+    let theta = angle * point.y.min(length) / length;
+    let (sin, cos) = (ops::sin(theta), ops::cos(theta));
+    let mut bent_offset = radius - (radius - offset) * cos;
+    let mut height = (radius - offset) * sin;
+    // Past the arc, the mesh goes on along its tangent.
+    let beyond = (point.y - length).max(0.0);
+    bent_offset += beyond * sin;
+    height += beyond * cos;
+    rest + direction * bent_offset + Vec3::Y * height
+}
+
+/// A box of control points moving the vertices inside of it, for free-form deformation.
+///
+/// Each vertex is moved by the trilinear interpolation of the offsets of the eight control points
+/// around it. The vertices outside of the box are moved like the closest point of its surface.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Debug, Clone, PartialEq)]
+pub struct Lattice {
+    /// The corner of the box with the smallest coordinates.
+    pub min: Vec3,
+    /// The corner of the box with the largest coordinates.
+    pub max: Vec3,
+    /// The number of control points along each axis, at least 2.
+    pub resolution: UVec3,
+    /// The offsets of the control points from their rest position, with X varying fastest, then Y,
+    /// then Z.
+    pub offsets: Vec<Vec3>,
+}
+
+impl Lattice {
+    /// Creates a lattice with `resolution` control points along each axis, at rest, evenly
+    /// distributed in the box from `min` to `max`.
+    pub fn new(min: Vec3, max: Vec3, resolution: UVec3) -> Self {
+        let resolution = resolution.max(UVec3::splat(2));
+        Self {
+            min,
+            max,
+            resolution,
+            offsets: alloc::vec![Vec3::ZERO; resolution.element_product() as usize],
+        }
+    }
+
+    /// Returns the offset of the control point at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`Lattice::resolution`].
+    pub fn offset(&self, index: UVec3) -> Vec3 {
+        self.offsets[self.linear_index(index)]
+    }
+
+    /// Returns a mutable reference to the offset of the control point at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than [`Lattice::resolution`].
+    pub fn offset_mut(&mut self, index: UVec3) -> &mut Vec3 {
+        let index = self.linear_index(index);
+        &mut self.offsets[index]
+    }
+
+    fn linear_index(&self, index: UVec3) -> usize {
+        assert!(
+            index.cmplt(self.resolution).all(),
+            "the control point {index} is outside of a lattice of resolution {}",
+            self.resolution
+        );
+        (index.x + self.resolution.x * (index.y + self.resolution.y * index.z)) as usize
+    }
+
+    /// Returns the offset of `point`, interpolated from the control points around it.
+    pub fn sample(&self, point: Vec3) -> Vec3 {
+        if self.resolution.cmplt(UVec3::splat(2)).any()
+            || self.offsets.len() < self.resolution.element_product() as usize
+        {
+            return Vec3::ZERO;
+        }
+        let size = (self.max - self.min).max(Vec3::splat(f32::EPSILON));
+        let cells = (self.resolution - 1).as_vec3();
+        let grid = ((point - self.min) / size).clamp(Vec3::ZERO, Vec3::ONE) * cells;
+        let cell = grid.floor().min(cells - 1.0);
+        let t = grid - cell;
+        let cell = cell.as_uvec3();
+
+        let mut offset = Vec3::ZERO;
+        for corner in 0..8u32 {
+            let corner = UVec3::new(corner & 1, (corner >> 1) & 1, corner >> 2);
+            let weights = Vec3::select(corner.cmpeq(UVec3::ZERO), 1.0 - t, t);
+            offset += self.offset(cell + corner) * weights.element_product();
+        }
+        offset
+    }
+}
+
+/// A system that rebuilds the deformed meshes of the entities with [`MeshDeformers`].
+///
+/// When the [`Mesh3d`] of the entity still renders the base mesh, or the default mesh, the
+/// deformed mesh is added as a new asset. Otherwise, the mesh it renders is replaced.
+pub fn apply_mesh_deformers(
+    mut mesh_asset_events: MessageReader<AssetEvent<Mesh>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut deformed: Query<(Ref<MeshDeformers>, &mut Mesh3d)>,
+) {
+    let mut changed_meshes: HashSet<_, FixedHasher> = HashSet::default();
+    for mesh_asset_event in mesh_asset_events.read() {
+        if let AssetEvent::Added { id }
+        | AssetEvent::Modified { id }
+        | AssetEvent::LoadedWithDependencies { id } = mesh_asset_event
+        {
+            changed_meshes.insert(*id);
+        }
+    }
+
+    for (deformers, mut mesh_3d) in &mut deformed {
+        if !deformers.is_changed() && !changed_meshes.contains(&deformers.base.id()) {
+            continue;
+        }
+        let Some(mut mesh) = meshes.get(&deformers.base).cloned() else {
+            continue;
+        };
+        if let Err(error) = deformers.deform_mesh(&mut mesh) {
+            tracing::warn!("Failed to deform mesh {:?}: {error}", deformers.base.id());
+            continue;
+        }
+
+        if mesh_3d.0 != Handle::default()
+            && mesh_3d.0 != deformers.base
+            && let Some(mut output) = meshes.get_mut(&mesh_3d.0)
+        {
+            *output = mesh;
+            continue;
+        }
+        mesh_3d.0 = meshes.add(mesh);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+
+    use bevy_asset::{AssetEvent, Assets, RenderAssetUsages};
+    use bevy_ecs::{message::Messages, prelude::*};
+    use bevy_math::{UVec3, Vec2, Vec3};
+
+    use crate::{
+        apply_mesh_deformers, Deformer, Lattice, Mesh, Mesh3d, MeshDeformers, PrimitiveTopology,
+        VertexAttributeValues,
+    };
+
+    fn assert_near(a: Vec3, b: Vec3) {
+        assert!(a.abs_diff_eq(b, 1e-4), "{a} != {b}");
+    }
+
+    #[test]
+    fn bend_twist_and_taper() {
+        let bend = Deformer::Bend {
+            angle: FRAC_PI_2,
+            length: 2.0,
+            direction: Vec2::X,
+        };
+        assert_near(
+            bend.deform(Vec3::new(0.0, -1.0, 0.5)),
+            Vec3::new(0.0, -1.0, 0.5),
+        );
+        // A quarter circle of radius 4 / π, then straight along X.
+        let radius = 4.0 / core::f32::consts::PI;
+        assert_near(
+            bend.deform(Vec3::new(0.0, 2.0, 0.0)),
+            Vec3::new(radius, radius, 0.0),
+        );
+        assert_near(
+            bend.deform(Vec3::new(0.0, 3.0, 0.0)),
+            Vec3::new(radius + 1.0, radius, 0.0),
+        );
+
+        let twist = Deformer::Twist { angle: FRAC_PI_2 };
+        assert_near(
+            twist.deform(Vec3::new(1.0, 1.0, 0.0)),
+            Vec3::new(0.0, 1.0, -1.0),
+        );
+
+        let taper = Deformer::Taper { factor: -0.5 };
+        assert_near(
+            taper.deform(Vec3::new(1.0, 1.0, 2.0)),
+            Vec3::new(0.5, 1.0, 1.0),
+        );
+        assert_near(
+            taper.deform(Vec3::new(1.0, 4.0, 2.0)),
+            Vec3::new(0.0, 4.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn lattice() {
+        let mut lattice = Lattice::new(Vec3::ZERO, Vec3::splat(2.0), UVec3::new(3, 2, 2));
+        *lattice.offset_mut(UVec3::new(2, 1, 0)) = Vec3::Y;
+        *lattice.offset_mut(UVec3::new(2, 1, 1)) = Vec3::Y;
+
+        assert_eq!(lattice.sample(Vec3::ZERO), Vec3::ZERO);
+        assert_near(lattice.sample(Vec3::new(2.0, 2.0, 1.0)), Vec3::Y);
+        assert_near(
+            lattice.sample(Vec3::new(1.5, 1.0, 1.0)),
+            Vec3::new(0.0, 0.25, 0.0),
+        );
+        // Outside of the box, the offset of the closest point of its surface.
+        assert_near(lattice.sample(Vec3::new(5.0, 5.0, 1.0)), Vec3::Y);
+    }
+
+    #[test]
+    fn deformed_mesh() {
+        let mut world = World::new();
+        world.init_resource::<Messages<AssetEvent<Mesh>>>();
+        let base = world.get_resource_or_init::<Assets<Mesh>>().add(
+            Mesh::new(PrimitiveTopology::PointList, RenderAssetUsages::all())
+                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vec![[1.0, 1.0, 0.0]])
+                .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[1.0, 0.0, 0.0]]),
+        );
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_mesh_deformers);
+
+        let entity = world
+            .spawn(MeshDeformers::new(base.clone()).with(Deformer::Twist { angle: FRAC_PI_2 }))
+            .id();
+        schedule.run(&mut world);
+
+        let output = world.get::<Mesh3d>(entity).unwrap().0.clone();
+        assert_ne!(output, base);
+        let meshes = world.resource::<Assets<Mesh>>();
+        let Some(VertexAttributeValues::Float32x3(positions)) = meshes
+            .get(&output)
+            .unwrap()
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("the deformed mesh has no positions");
+        };
+        assert_near(Vec3::from(positions[0]), Vec3::new(0.0, 1.0, -1.0));
+        let Some(VertexAttributeValues::Float32x3(normals)) = meshes
+            .get(&output)
+            .unwrap()
+            .attribute(Mesh::ATTRIBUTE_NORMAL)
+        else {
+            panic!("the deformed mesh has no normals");
+        };
+        assert!(Vec3::from(normals[0]).abs_diff_eq(Vec3::NEG_Z, 1e-2));
+        assert_eq!(
+            meshes
+                .get(&base)
+                .unwrap()
+                .attribute(Mesh::ATTRIBUTE_POSITION),
+            Some(&VertexAttributeValues::Float32x3(vec![[1.0, 1.0, 0.0]]))
+        );
+
+        // Changing the deformers replaces the deformed mesh, rather than adding another one.
+        world
+            .get_mut::<MeshDeformers>(entity)
+            .unwrap()
+            .deformers
+            .clear();
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Mesh3d>(entity).unwrap().0, output);
+        assert_eq!(
+            world
+                .resource::<Assets<Mesh>>()
+                .get(&output)
+                .unwrap()
+                .attribute(Mesh::ATTRIBUTE_POSITION),
+            Some(&VertexAttributeValues::Float32x3(vec![[1.0, 1.0, 0.0]]))
+        );
+    }
+}
@@ -5,6 +5,7 @@ extern crate core;
 
 mod components;
 mod conversions;
+mod deform;
 mod index;
 mod mesh;
 #[cfg(feature = "bevy_mikktspace")]
@@ -19,6 +20,7 @@ use bevy_asset::{AssetApp, AssetEventSystems};
 use bevy_ecs::schedule::IntoScheduleConfigs;
 use bitflags::bitflags;
 pub use components::*;
+pub use deform::*;
 pub use index::*;
 pub use mesh::*;
 #[cfg(feature = "bevy_mikktspace")]
@@ -65,9 +67,13 @@ impl Plugin for MeshPlugin {
         app.init_asset::<Mesh>()
             .init_asset::<skinning::SkinnedMeshInverseBindposes>()
             .register_asset_reflect::<Mesh>()
+            .register_type::<MeshDeformers>()
             .add_systems(
                 PostUpdate,
-                mark_3d_meshes_as_changed_if_their_assets_changed.after(AssetEventSystems),
+                (
+                    apply_mesh_deformers.before(AssetEventSystems),
+                    mark_3d_meshes_as_changed_if_their_assets_changed.after(AssetEventSystems),
+                ),
             );
     }
 }
@@ -10,7 +10,7 @@ use super::{
 };
 use crate::arr_f32_to_unorm8;
 #[cfg(feature = "morph")]
-use crate::morph::MorphAttributes;
+use crate::morph::{MorphAttributes, MorphTargetBounds};
 #[cfg(feature = "serialize")]
 use crate::SerializedMeshAttributeData;
 use alloc::collections::BTreeMap;
@@ -24,6 +24,7 @@ use bevy_platform::collections::{hash_map, HashMap};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bytemuck::cast_slice;
 use core::hash::{Hash, Hasher};
+use core::ops::Range;
 use core::ptr;
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
@@ -129,6 +130,53 @@ impl<T> MeshExtractableData<T> {
     }
 }
 
+// the vertices that changed since a mesh was last extracted to the render world, so that only
+// these are uploaded again
+#[derive(Debug, Clone, Default)]
+enum VertexChanges {
+    // any of the vertex, index or morph target data may have changed
+    #[default]
+    All,
+    // only the vertices in these sorted, disjoint ranges were written
+    Ranges(Vec<Range<u32>>),
+}
+
+// the changes are the edit history of a mesh rather than its data, so meshes with the same data
+// are equal whatever their changes
+impl PartialEq for VertexChanges {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl VertexChanges {
+    // the number of ranges above which they are merged into one, to bound the number of uploads
+    const MAX_RANGES: usize = 64;
+
+    fn insert(&mut self, range: Range<u32>) {
+        let VertexChanges::Ranges(ranges) = self else {
+            return;
+        };
+        if range.is_empty() {
+            return;
+        }
+        // merge the range with the ranges it overlaps or touches
+        let start = ranges.partition_point(|other| other.end < range.start);
+        let end = ranges.partition_point(|other| other.start <= range.end);
+        let merged = if start < end {
+            ranges[start].start.min(range.start)..ranges[end - 1].end.max(range.end)
+        } else {
+            range
+        };
+        ranges.splice(start..end, [merged]);
+        if ranges.len() > Self::MAX_RANGES {
+            let hull = ranges[0].start..ranges[ranges.len() - 1].end;
+            ranges.clear();
+            ranges.push(hull);
+        }
+    }
+}
+
 impl<T> From<Option<T>> for MeshExtractableData<T> {
     fn from(value: Option<T>) -> Self {
         match value {
@@ -268,6 +316,11 @@ pub struct Mesh {
     /// This will be set when calling [`Mesh::compressed_mesh`].
     pub final_uv_ranges: [Option<Aabb2d>; 2],
     skinned_mesh_bounds: Option<SkinnedMeshBounds>,
+    #[cfg(feature = "morph")]
+    morph_target_bounds: Option<MorphTargetBounds>,
+    /// The vertices written since the mesh was last extracted to the render world.
+    #[reflect(ignore, clone)]
+    vertex_changes: VertexChanges,
 }
 
 bitflags::bitflags! {
@@ -407,7 +460,10 @@ impl Mesh {
             attribute_compression: MeshAttributeCompressionFlags::empty(),
             final_aabb: None,
             skinned_mesh_bounds: None,
+            #[cfg(feature = "morph")]
+            morph_target_bounds: None,
             final_uv_ranges: [None; 2],
+            vertex_changes: VertexChanges::All,
         }
     }
 
@@ -450,6 +506,7 @@ impl Mesh {
         attribute: MeshVertexAttribute,
         values: impl Into<VertexAttributeValues>,
     ) -> Result<(), MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         let values = values.into();
         let values_format = VertexFormat::from(&values);
         if values_format != attribute.format {
@@ -514,6 +571,7 @@ impl Mesh {
         &mut self,
         attribute: impl Into<MeshVertexAttributeId>,
     ) -> Option<VertexAttributeValues> {
+        self.vertex_changes = VertexChanges::All;
         self.attributes
             .as_mut()
             .expect(MESH_EXTRACTED_ERROR)
@@ -528,6 +586,7 @@ impl Mesh {
         &mut self,
         attribute: impl Into<MeshVertexAttributeId>,
     ) -> Result<VertexAttributeValues, MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         Ok(self
             .attributes
             .as_mut()?
@@ -672,6 +731,7 @@ impl Mesh {
         &mut self,
         id: impl Into<MeshVertexAttributeId>,
     ) -> Result<Option<&mut VertexAttributeValues>, MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         Ok(self
             .attributes
             .as_mut()?
@@ -723,6 +783,7 @@ impl Mesh {
         impl Iterator<Item = (&MeshVertexAttribute, &mut VertexAttributeValues)>,
         MeshAccessError,
     > {
+        self.vertex_changes = VertexChanges::All;
         Ok(self
             .attributes
             .as_mut()?
@@ -730,6 +791,117 @@ impl Mesh {
             .map(|data| (&data.attribute, &mut data.values)))
     }
 
+    /// Overwrites the values of a vertex attribute from `first_vertex`, without changing the
+    /// number of vertices of the mesh.
+    ///
+    /// Unlike the other ways of modifying a mesh, this keeps track of the vertices that were
+    /// written, so that only these are uploaded to the GPU again when the mesh is next extracted,
+    /// rather than the whole vertex buffer. This makes it cheap to paint vertex colors or to
+    /// deform, dig or destroy parts of a large mesh every frame:
+    ///
+    /// ```
+    /// # use bevy_asset::{Assets, Handle};
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_mesh::Mesh;
+    /// # #[derive(Resource)]
+    /// # struct Terrain(Handle<Mesh>);
+    /// fn paint(terrain: Res<Terrain>, mut meshes: ResMut<Assets<Mesh>>) {
+    ///     if let Some(mut mesh) = meshes.get_mut(&terrain.0) {
+    ///         // Paints vertices 100 to 103 red.
+    ///         mesh.write_attribute_range(Mesh::ATTRIBUTE_COLOR, 100, vec![[1.0f32, 0.0, 0.0, 1.0]; 4]);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The partial upload only happens when the mesh keeps its data in the main world, with the
+    /// [`RenderAssetUsages::MAIN_WORLD`] usage, and when nothing else in the vertex, index or morph
+    /// target data of the mesh was changed, with the other methods of [`Mesh`], since it was last
+    /// extracted. Otherwise, the whole mesh is uploaded again, as usual.
+    ///
+    /// `Aabb` of entities with modified mesh are not updated automatically.
+    ///
+    /// # Panics
+    /// Panics when the mesh doesn't have the attribute, when the format of the values does not
+    /// match the attribute's format, or when the values go past the last vertex of the attribute.
+    /// Panics when the mesh data has already been extracted to `RenderWorld`. To handle
+    /// this as an error use [`Mesh::try_write_attribute_range`]
+    #[inline]
+    pub fn write_attribute_range(
+        &mut self,
+        id: impl Into<MeshVertexAttributeId>,
+        first_vertex: usize,
+        values: impl Into<VertexAttributeValues>,
+    ) {
+        if let Err(error) = self.try_write_attribute_range(id, first_vertex, values) {
+            panic!("Failed to write attribute: {error}");
+        }
+    }
+
+    /// Overwrites the values of a vertex attribute from `first_vertex`, without changing the
+    /// number of vertices of the mesh, and keeps track of the vertices that were written so that
+    /// only these are uploaded to the GPU again. See [`Mesh::write_attribute_range`].
+    ///
+    /// Returns an error if the mesh data has been extracted to `RenderWorld` or
+    /// if the attribute does not exist.
+    ///
+    /// # Panics
+    /// Panics when the format of the values does not match the attribute's format, or when the
+    /// values go past the last vertex of the attribute.
+    pub fn try_write_attribute_range(
+        &mut self,
+        id: impl Into<MeshVertexAttributeId>,
+        first_vertex: usize,
+        values: impl Into<VertexAttributeValues>,
+    ) -> Result<(), MeshAccessError> {
+        let values = values.into();
+        let data = self
+            .attributes
+            .as_mut()?
+            .get_mut(&id.into())
+            .ok_or(MeshAccessError::NotFound)?;
+        let values_format = VertexFormat::from(&values);
+        if values_format != data.attribute.format {
+            panic!(
+                "Failed to write attribute. Invalid attribute format for {}. Given format is {values_format:?} but expected {:?}",
+                data.attribute.name, data.attribute.format
+            );
+        }
+        let vertex_count = data.values.len();
+        let end_vertex = first_vertex + values.len();
+        if end_vertex > vertex_count {
+            panic!(
+                "Failed to write attribute. The vertices {first_vertex}..{end_vertex} of {} are out of bounds of its {vertex_count} vertices",
+                data.attribute.name
+            );
+        }
+
+        let size = data.attribute.format.size() as usize;
+        data.values.get_bytes_mut()[first_vertex * size..end_vertex * size]
+            .copy_from_slice(values.get_bytes());
+        self.vertex_changes
+            .insert(first_vertex as u32..end_vertex as u32);
+        Ok(())
+    }
+
+    /// Returns the ranges of vertices written with [`Mesh::write_attribute_range`] since the mesh
+    /// was last extracted to the render world, sorted and disjoint.
+    ///
+    /// Returns `None` if anything else in the vertex, index or morph target data of the mesh may
+    /// have changed, in which case the whole mesh must be uploaded again.
+    pub fn changed_vertex_ranges(&self) -> Option<&[Range<u32>]> {
+        match &self.vertex_changes {
+            VertexChanges::All => None,
+            VertexChanges::Ranges(ranges) => Some(ranges),
+        }
+    }
+
+    /// Forgets the vertices that changed, once the mesh is extracted to the render world.
+    /// This function is called internally in render world extraction, it is
+    /// unlikely to be useful outside of that context.
+    pub fn clear_changed_vertex_ranges(&mut self) {
+        self.vertex_changes = VertexChanges::Ranges(Vec::new());
+    }
+
     /// Sets the vertex indices of the mesh. They describe how triangles are constructed out of the
     /// vertex attributes and are therefore only useful for the [`PrimitiveTopology`] variants
     /// that use triangles.
@@ -739,6 +911,7 @@ impl Mesh {
     /// this as an error use [`Mesh::try_insert_indices`]
     #[inline]
     pub fn insert_indices(&mut self, indices: Indices) {
+        self.vertex_changes = VertexChanges::All;
         self.indices
             .replace(Some(indices))
             .expect(MESH_EXTRACTED_ERROR);
@@ -751,6 +924,7 @@ impl Mesh {
     /// Returns an error if the mesh data has been extracted to `RenderWorld`.
     #[inline]
     pub fn try_insert_indices(&mut self, indices: Indices) -> Result<(), MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         self.indices.replace(Some(indices))?;
         Ok(())
     }
@@ -822,6 +996,7 @@ impl Mesh {
     /// Returns an error if the mesh data has been extracted to `RenderWorld`.
     #[inline]
     pub fn try_indices_mut(&mut self) -> Result<&mut Indices, MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         self.indices.as_mut()
     }
 
@@ -830,6 +1005,7 @@ impl Mesh {
     /// Returns an error if the mesh data has been extracted to `RenderWorld`.
     #[inline]
     pub fn try_indices_mut_option(&mut self) -> Result<Option<&mut Indices>, MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         self.indices.as_mut_option()
     }
 
@@ -848,6 +1024,7 @@ impl Mesh {
     /// Returns an error if the mesh data has been extracted to `RenderWorld`.
     #[inline]
     pub fn try_remove_indices(&mut self) -> Result<Option<Indices>, MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         self.indices.replace(None)
     }
 
@@ -1295,21 +1472,38 @@ impl Mesh {
     ///
     /// # Panics
     /// Panics when the mesh data has already been extracted to `RenderWorld`.
-    pub fn write_packed_vertex_buffer_data(&self, mut slice: WriteOnly<'_, [u8]>) {
+    pub fn write_packed_vertex_buffer_data(&self, slice: WriteOnly<'_, [u8]>) {
+        self.write_packed_vertex_buffer_range(0..self.count_vertices(), slice);
+    }
+
+    /// Computes and writes the vertex data of the vertices in `vertices` into a mutable byte
+    /// slice, laid out as in [`Mesh::write_packed_vertex_buffer_data`] but starting at the first
+    /// vertex of the range. This is used to upload the vertices that changed, listed by
+    /// [`Mesh::changed_vertex_ranges`].
+    ///
+    /// The range is truncated to the vertices of the mesh.
+    ///
+    /// # Panics
+    /// Panics when the mesh data has already been extracted to `RenderWorld`.
+    pub fn write_packed_vertex_buffer_range(
+        &self,
+        vertices: Range<usize>,
+        mut slice: WriteOnly<'_, [u8]>,
+    ) {
         let mesh_attributes = self.attributes.as_ref().expect(MESH_EXTRACTED_ERROR);
 
         let vertex_size = self.get_vertex_size() as usize;
         let vertex_count = self.count_vertices();
+        let end = vertices.end.min(vertex_count);
+        let vertices = vertices.start.min(end)..end;
         // bundle into interleaved buffers
         let mut attribute_offset = 0;
         for attribute_data in mesh_attributes.values() {
             let attribute_size = attribute_data.attribute.format.size() as usize;
-            let attributes_bytes = attribute_data.values.get_bytes();
-            for (vertex_index, attribute_bytes) in attributes_bytes
-                .chunks_exact(attribute_size)
-                .take(vertex_count)
-                .enumerate()
-            {
+            let attributes_bytes = attribute_data.values.get_bytes()
+                [vertices.start * attribute_size..vertices.end * attribute_size]
+                .chunks_exact(attribute_size);
+            for (vertex_index, attribute_bytes) in attributes_bytes.enumerate() {
                 let offset = vertex_index * vertex_size + attribute_offset;
                 slice
                     .slice(offset..offset + attribute_size)
@@ -1339,6 +1533,7 @@ impl Mesh {
     ///
     /// Returns an error if the mesh data has been extracted to `RenderWorld`.
     pub fn try_duplicate_vertices(&mut self) -> Result<(), MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         fn duplicate<T: Copy>(values: &[T], indices: impl Iterator<Item = usize>) -> Vec<T> {
             indices.map(|i| values[i]).collect()
         }
@@ -1443,6 +1638,7 @@ impl Mesh {
     /// are duplicate vertices. If deduplication is needed with indices already set,
     /// consider calling [`Mesh::duplicate_vertices`] and then this function.
     pub fn merge_duplicate_vertices(&mut self) -> Result<(), MeshMergeDuplicateVerticesError> {
+        self.vertex_changes = VertexChanges::All;
         match self.try_indices() {
             Ok(_) => return Err(MeshMergeDuplicateVerticesError::IndicesAlreadySet),
             Err(err) => match err {
@@ -1558,6 +1754,7 @@ impl Mesh {
     /// Does nothing if no [`Indices`] are set.
     /// If this operation succeeded, an [`Ok`] result is returned.
     pub fn invert_winding(&mut self) -> Result<(), MeshWindingInvertError> {
+        self.vertex_changes = VertexChanges::All;
         fn invert<I>(
             indices: &mut [I],
             topology: PrimitiveTopology,
@@ -2714,6 +2911,7 @@ impl Mesh {
         &mut self,
         morph_targets: Vec<MorphAttributes>,
     ) -> Result<(), MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         self.morph_targets.replace(Some(morph_targets))?;
         Ok(())
     }
@@ -2791,6 +2989,7 @@ impl Mesh {
         &mut self,
         names: Vec<String>,
     ) -> Result<(), MeshAccessError> {
+        self.vertex_changes = VertexChanges::All;
         self.morph_target_names.replace(Some(names))?;
         Ok(())
     }
@@ -2842,6 +3041,44 @@ impl Mesh {
             .as_ref_option()?
             .map(core::ops::Deref::deref))
     }
+
+    /// Get this mesh's [`MorphTargetBounds`].
+    pub fn morph_target_bounds(&self) -> Option<&MorphTargetBounds> {
+        self.morph_target_bounds.as_ref()
+    }
+
+    /// Set this mesh's [`MorphTargetBounds`].
+    pub fn set_morph_target_bounds(&mut self, morph_target_bounds: Option<MorphTargetBounds>) {
+        self.morph_target_bounds = morph_target_bounds;
+    }
+
+    /// Generate [`MorphTargetBounds`] for this mesh from its positions and
+    /// morph targets.
+    ///
+    /// The bounds are cleared if the mesh has no morph targets.
+    ///
+    /// Returns an error if the mesh data has been extracted to `RenderWorld`.
+    pub fn generate_morph_target_bounds(&mut self) -> Result<(), MeshAccessError> {
+        let bounds = match self.morph_targets.as_ref_option()? {
+            Some(morph_targets) => Some(MorphTargetBounds {
+                base_aabb: self
+                    .try_attribute_option(Self::ATTRIBUTE_POSITION)?
+                    .and_then(Self::compute_aabb),
+                ..MorphTargetBounds::from_morph_targets(morph_targets, self.count_vertices())
+            }),
+            None => None,
+        };
+        self.morph_target_bounds = bounds;
+        Ok(())
+    }
+
+    /// Consumes the mesh and returns a mesh with generated [`MorphTargetBounds`].
+    ///
+    /// Returns an error if the mesh data has been extracted to `RenderWorld`.
+    pub fn with_generated_morph_target_bounds(mut self) -> Result<Self, MeshAccessError> {
+        self.generate_morph_target_bounds()?;
+        Ok(self)
+    }
 }
 
 /// An enum to define which UV attribute to use for a texture.
@@ -3052,6 +3289,41 @@ mod tests {
     use bevy_math::primitives::Triangle3d;
     use bevy_math::{Vec3, Vec3A};
     use bevy_transform::components::Transform;
+    use wgpu_types::WriteOnly;
+
+    #[cfg(feature = "morph")]
+    #[test]
+    fn morph_target_bounds() {
+        use crate::morph::MorphAttributes;
+
+        let displacement = |position: Vec3| MorphAttributes::new(position, Vec3::ZERO, Vec3::ZERO);
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0, 0.0, 0.0]; 2])
+        .with_morph_targets(vec![
+            // The first target moves a vertex up.
+            displacement(Vec3::new(0.0, 2.0, 0.0)),
+            displacement(Vec3::ZERO),
+            // The second target moves the vertices apart.
+            displacement(Vec3::new(-1.0, 0.0, 0.0)),
+            displacement(Vec3::new(1.0, 0.0, 0.0)),
+        ]);
+        mesh.generate_morph_target_bounds().unwrap();
+
+        let bounds = mesh.morph_target_bounds().unwrap();
+        assert_eq!(bounds.base_aabb, Some(Aabb3d::new(Vec3::ZERO, Vec3::ZERO)));
+        assert_eq!(
+            bounds.displacement_bounds(&[0.5, 2.0]),
+            (Vec3::new(-2.0, 0.0, 0.0), Vec3::new(2.0, 1.0, 0.0))
+        );
+        // Negative weights move vertices the other way.
+        assert_eq!(
+            bounds.displacement_bounds(&[-1.0, 0.0]),
+            (Vec3::new(0.0, -2.0, 0.0), Vec3::ZERO)
+        );
+    }
 
     #[test]
     #[should_panic]
@@ -3677,4 +3949,57 @@ mod tests {
             )
             .all(|(a, b)| approx::relative_eq!(a.to_f32(), b.to_f32())));
     }
+
+    #[test]
+    fn write_attribute_range() {
+        let mut mesh = Mesh::new(PrimitiveTopology::PointList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0f32; 3]; 8])
+            .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, vec![[0.0f32; 4]; 8]);
+        // A new mesh is uploaded whole.
+        assert_eq!(mesh.changed_vertex_ranges(), None);
+        mesh.write_attribute_range(Mesh::ATTRIBUTE_COLOR, 0, vec![[1.0f32; 4]]);
+        assert_eq!(mesh.changed_vertex_ranges(), None);
+
+        mesh.clear_changed_vertex_ranges();
+        assert_eq!(mesh.changed_vertex_ranges(), Some(&[][..]));
+        mesh.write_attribute_range(Mesh::ATTRIBUTE_COLOR, 5, vec![[0.5f32; 4]; 2]);
+        mesh.write_attribute_range(Mesh::ATTRIBUTE_POSITION, 1, vec![[2.0f32; 3]; 2]);
+        assert_eq!(mesh.changed_vertex_ranges(), Some(&[1..3, 5..7][..]));
+        // Touching ranges are merged.
+        mesh.write_attribute_range(Mesh::ATTRIBUTE_POSITION, 3, vec![[3.0f32; 3]; 2]);
+        let changed = mesh.changed_vertex_ranges().unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0], 1..7);
+
+        let Some(VertexAttributeValues::Float32x4(colors)) = mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+        else {
+            panic!("the mesh has no colors");
+        };
+        assert_eq!(colors[0], [1.0; 4]);
+        assert_eq!(colors[4], [0.0; 4]);
+        assert_eq!(colors[6], [0.5; 4]);
+
+        let packed = mesh.create_packed_vertex_buffer_data();
+        let mut range = vec![0; 2 * 28];
+        mesh.write_packed_vertex_buffer_range(3..5, WriteOnly::from_mut(&mut range));
+        assert_eq!(range, packed[3 * 28..5 * 28]);
+
+        // The changes don't take part in comparisons.
+        let mut uploaded = mesh.clone();
+        uploaded.clear_changed_vertex_ranges();
+        assert_eq!(uploaded, mesh);
+
+        // Any other change uploads the whole mesh again.
+        mesh.insert_indices(Indices::U16(vec![0, 1, 2]));
+        assert_eq!(mesh.changed_vertex_ranges(), None);
+        assert_ne!(uploaded, mesh);
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_attribute_range_out_of_bounds() {
+        let mut mesh = Mesh::new(PrimitiveTopology::PointList, RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vec![[0.0f32; 3]; 2]);
+        mesh.write_attribute_range(Mesh::ATTRIBUTE_POSITION, 1, vec![[0.0f32; 3]; 2]);
+    }
 }
@@ -1,7 +1,7 @@
 use super::Mesh;
 use bevy_asset::Handle;
 use bevy_ecs::prelude::*;
-use bevy_math::Vec3;
+use bevy_math::{bounding::Aabb3d, Vec3};
 use bevy_reflect::prelude::*;
 use bytemuck::{Pod, Zeroable};
 use encase::ShaderType;
@@ -129,6 +129,67 @@ pub enum MeshMorphWeights {
     Reference(#[entities] Entity),
 }
 
+/// The extents of the position displacements of each morph target of a mesh.
+///
+/// This is used to calculate bounds that enclose the mesh whatever the current
+/// [`MeshMorphWeights`] are, without going through all of its vertices every
+/// frame. See [`Mesh::generate_morph_target_bounds`].
+#[derive(Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default)]
+pub struct MorphTargetBounds {
+    /// The bounds of the mesh with no morph target applied, if known.
+    pub base_aabb: Option<Aabb3d>,
+    /// The smallest position displacement of each morph target, per axis.
+    pub min_displacements: Vec<Vec3>,
+    /// The largest position displacement of each morph target, per axis.
+    pub max_displacements: Vec<Vec3>,
+}
+
+impl MorphTargetBounds {
+    /// Creates the bounds of the given morph targets, with `vertex_count`
+    /// consecutive [`MorphAttributes`] per target, as stored in [`Mesh`].
+    pub fn from_morph_targets(morph_targets: &[MorphAttributes], vertex_count: usize) -> Self {
+        if vertex_count == 0 {
+            return Self::default();
+        }
+        let (min_displacements, max_displacements) = morph_targets
+            .chunks(vertex_count)
+            .map(|target| {
+                target
+                    .iter()
+                    .fold((Vec3::ZERO, Vec3::ZERO), |(min, max), attributes| {
+                        (min.min(attributes.position), max.max(attributes.position))
+                    })
+            })
+            .unzip();
+        Self {
+            base_aabb: None,
+            min_displacements,
+            max_displacements,
+        }
+    }
+
+    /// Returns the smallest and largest displacement any vertex can have with
+    /// the given morph target weights.
+    ///
+    /// Weights beyond the number of morph targets are ignored.
+    pub fn displacement_bounds(&self, weights: &[f32]) -> (Vec3, Vec3) {
+        self.min_displacements
+            .iter()
+            .zip(&self.max_displacements)
+            .zip(weights)
+            .fold(
+                (Vec3::ZERO, Vec3::ZERO),
+                |(min, max), ((&target_min, &target_max), &weight)| {
+                    // A negative weight flips the displacement.
+                    let a = target_min * weight;
+                    let b = target_max * weight;
+                    (min + a.min(b), max + a.max(b))
+                },
+            )
+    }
+}
+
 /// Attributes **differences** used for morph targets.
 #[derive(Copy, Clone, PartialEq, Debug, Reflect, ShaderType, Pod, Zeroable, Default)]
 #[reflect(Clone, Default)]
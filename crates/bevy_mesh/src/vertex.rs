@@ -8,7 +8,7 @@ use bevy_math::{
 #[cfg(feature = "serialize")]
 use bevy_platform::collections::HashMap;
 use bevy_platform::collections::HashSet;
-use bytemuck::{bytes_of, cast_slice};
+use bytemuck::{bytes_of, cast_slice, cast_slice_mut};
 use core::hash::{Hash, Hasher};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
@@ -521,6 +521,61 @@ impl VertexAttributeValues {
         }
     }
 
+    /// Flattens the [`VertexAttributeValues`] into a mutable sequence of bytes.
+    #[expect(
+        clippy::match_same_arms,
+        reason = "Although the `values` binding on some match arms may have matching types, each variant has different semantics; thus it's not guaranteed that they will use the same type forever."
+    )]
+    pub(crate) fn get_bytes_mut(&mut self) -> &mut [u8] {
+        match self {
+            VertexAttributeValues::Float32(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint32(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint32(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float32x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint32x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint32x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float32x3(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint32x3(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint32x3(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float32x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint32x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint32x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint16x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Snorm16x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint16x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm16x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint16x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Snorm16x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint16x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm16x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint8x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Snorm8x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint8x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm8x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint8x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Snorm8x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint8x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm8x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint8(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint8(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm8(values) => cast_slice_mut(values),
+            VertexAttributeValues::Snorm8(values) => cast_slice_mut(values),
+            VertexAttributeValues::Uint16(values) => cast_slice_mut(values),
+            VertexAttributeValues::Sint16(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm16(values) => cast_slice_mut(values),
+            VertexAttributeValues::Snorm16(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float16(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float16x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float16x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float64(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float64x2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float64x3(values) => cast_slice_mut(values),
+            VertexAttributeValues::Float64x4(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm10_10_10_2(values) => cast_slice_mut(values),
+            VertexAttributeValues::Unorm8x4Bgra(values) => cast_slice_mut(values),
+        }
+    }
+
     /// Create a new `VertexAttributeValues` with the values converted from f32 to f16. Return None if the values are not Float32, Float32x2 or Float32x4.
     pub(crate) fn create_f16_values(&self) -> Option<VertexAttributeValues> {
         match &self {
@@ -0,0 +1,102 @@
+use bevy_asset::{AssetEvent, Assets};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    lifecycle::RemovedComponents,
+    message::{Message, MessageReader, MessageWriter},
+    reflect::ReflectComponent,
+    resource::Resource,
+    system::{Query, Res, ResMut},
+    world::Ref,
+};
+use bevy_math::primitives::Triangle3d;
+use bevy_mesh::{Mesh, Mesh3d};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_tasks::{futures::check_ready, AsyncComputeTaskPool, Task};
+use bevy_transform::components::GlobalTransform;
+
+use crate::{NavMesh, NavMeshSettings};
+
+/// Marks a [`Mesh3d`] as part of the level geometry the [`NavMesh`] is baked from.
+///
+/// The navigation mesh is rebaked in the background whenever one of these entities is added,
+/// removed or moved, or its mesh changes. The positions of the mesh must be kept in the main
+/// world, see [`RenderAssetUsages`](bevy_asset::RenderAssetUsages).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Mesh3d)]
+pub struct NavAffector;
+
+/// Sent when a new [`NavMesh`] has been baked.
+#[derive(Message, Clone, Copy, Debug, Default)]
+pub struct NavMeshBaked;
+
+/// Tracks the bake of the [`NavMesh`] running in the background.
+#[derive(Resource, Default)]
+pub(crate) struct NavMeshBaker {
+    task: Option<Task<NavMesh>>,
+    /// Whether the level geometry changed since the last bake started.
+    dirty: bool,
+}
+
+/// Rebakes the [`NavMesh`] in the background when the [`NavAffector`]s or the
+/// [`NavMeshSettings`] change, and replaces it once baked.
+pub(crate) fn bake_nav_mesh(
+    mut baker: ResMut<NavMeshBaker>,
+    mut nav_mesh: ResMut<NavMesh>,
+    mut baked: MessageWriter<NavMeshBaked>,
+    settings: Res<NavMeshSettings>,
+    meshes: Res<Assets<Mesh>>,
+    mut mesh_events: MessageReader<AssetEvent<Mesh>>,
+    affectors: Query<(Ref<NavAffector>, Ref<Mesh3d>, Ref<GlobalTransform>)>,
+    mut removed: RemovedComponents<NavAffector>,
+) {
+    if let Some(task) = &mut baker.task
+        && let Some(baked_nav_mesh) = check_ready(task)
+    {
+        baker.task = None;
+        *nav_mesh = baked_nav_mesh;
+        baked.write(NavMeshBaked);
+    }
+
+    // All the messages are read, so that they are not seen again during the next frame.
+    let mut mesh_changed = false;
+    for event in mesh_events.read() {
+        mesh_changed |= affectors.iter().any(|(_, mesh, _)| {
+            event.is_loaded_with_dependencies(&mesh.0) || event.is_modified(&mesh.0)
+        });
+    }
+    let removed = removed.read().count() > 0;
+    baker.dirty |= settings.is_changed()
+        || mesh_changed
+        || removed
+        || affectors.iter().any(|(affector, mesh, transform)| {
+            affector.is_changed() || mesh.is_changed() || transform.is_changed()
+        });
+
+    // Changes made during a bake are picked up by the next one.
+    if !baker.dirty || baker.task.is_some() {
+        return;
+    }
+    baker.dirty = false;
+
+    // Meshes that are not loaded yet are added once they are.
+    let triangles: Vec<Triangle3d> = affectors
+        .iter()
+        .filter_map(|(_, mesh, transform)| {
+            Some((meshes.get(&mesh.0)?.triangles().ok()?, transform))
+        })
+        .flat_map(|(triangles, transform)| {
+            triangles.map(move |triangle| {
+                Triangle3d::new(
+                    transform.transform_point(triangle.vertices[0]),
+                    transform.transform_point(triangle.vertices[1]),
+                    transform.transform_point(triangle.vertices[2]),
+                )
+            })
+        })
+        .collect();
+    let settings = *settings;
+    baker.task =
+        Some(AsyncComputeTaskPool::get().spawn(async move { NavMesh::bake(triangles, &settings) }));
+}
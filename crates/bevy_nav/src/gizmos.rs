@@ -0,0 +1,112 @@
+//! A module adding debug visualization of the [`NavMesh`] and the [`NavPath`]s.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_color::{
+    palettes::basic::{AQUA, RED, YELLOW},
+    Color,
+};
+use bevy_ecs::{
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res},
+};
+use bevy_gizmos::{config::GizmoConfigGroup, gizmos::Gizmos, AppGizmoBuilder};
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+use crate::{NavMesh, NavPath, NavSystems};
+
+/// Lifts the lines slightly above the walkable surfaces so they are not hidden by them.
+const OFFSET: Vec3 = Vec3::new(0.0, 0.02, 0.0);
+
+/// The [`GizmoConfigGroup`] used to configure the visualization of the [`NavMesh`] and the
+/// [`NavPath`]s.
+#[derive(Clone, Reflect, GizmoConfigGroup)]
+#[reflect(Clone, Default)]
+pub struct NavMeshGizmoConfigGroup {
+    /// Draw the outline of the walkable surfaces if true.
+    ///
+    /// Defaults to `false`.
+    pub draw_nav_mesh: bool,
+    /// Draw all the [`NavPath`]s if true.
+    ///
+    /// Defaults to `false`.
+    pub draw_paths: bool,
+    /// [`Color`] of the outline of the walkable surfaces.
+    ///
+    /// Defaults to [`AQUA`].
+    pub nav_mesh_color: Color,
+    /// [`Color`] of the cells blocked by obstacles.
+    ///
+    /// Defaults to [`RED`].
+    pub blocked_color: Color,
+    /// [`Color`] of the paths.
+    ///
+    /// Defaults to [`YELLOW`].
+    pub path_color: Color,
+}
+
+impl Default for NavMeshGizmoConfigGroup {
+    fn default() -> Self {
+        Self {
+            draw_nav_mesh: false,
+            draw_paths: false,
+            nav_mesh_color: AQUA.into(),
+            blocked_color: RED.into(),
+            path_color: YELLOW.into(),
+        }
+    }
+}
+
+fn draw_nav_mesh(nav_mesh: Res<NavMesh>, mut gizmos: Gizmos<NavMeshGizmoConfigGroup>) {
+    if !gizmos.config_ext.draw_nav_mesh {
+        return;
+    }
+    let color = gizmos.config_ext.nav_mesh_color;
+    for (start, end) in nav_mesh.boundary_edges() {
+        gizmos.line(start + OFFSET, end + OFFSET, color);
+    }
+
+    let color = gizmos.config_ext.blocked_color;
+    let half = nav_mesh.cell_size() / 2.0;
+    for index in (0..nav_mesh.cells().len()).filter(|&index| nav_mesh.is_blocked(index)) {
+        let center = nav_mesh.cell_center(index) + OFFSET;
+        gizmos.line(
+            center + Vec3::new(-half, 0.0, -half),
+            center + Vec3::new(half, 0.0, half),
+            color,
+        );
+        gizmos.line(
+            center + Vec3::new(-half, 0.0, half),
+            center + Vec3::new(half, 0.0, -half),
+            color,
+        );
+    }
+}
+
+fn draw_paths(paths: Query<&NavPath>, mut gizmos: Gizmos<NavMeshGizmoConfigGroup>) {
+    if !gizmos.config_ext.draw_paths {
+        return;
+    }
+    let color = gizmos.config_ext.path_color;
+    for path in &paths {
+        gizmos.linestrip(path.points.iter().map(|&point| point + OFFSET), color);
+    }
+}
+
+/// A [`Plugin`] that provides visualization of the [`NavMesh`] and the [`NavPath`]s for
+/// debugging.
+///
+/// It is added by the [`NavPlugin`](crate::NavPlugin) when the `bevy_gizmos` feature is enabled.
+/// Nothing is drawn until [`NavMeshGizmoConfigGroup::draw_nav_mesh`] or
+/// [`NavMeshGizmoConfigGroup::draw_paths`] are set.
+pub struct NavMeshGizmoPlugin;
+
+impl Plugin for NavMeshGizmoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_gizmo_group::<NavMeshGizmoConfigGroup>()
+            .add_systems(
+                PostUpdate,
+                (draw_nav_mesh, draw_paths).after(NavSystems::Carve),
+            );
+    }
+}
@@ -0,0 +1,109 @@
+//! Navigation meshes and pathfinding for Bevy.
+//!
+//! The [`NavPlugin`] bakes a [`NavMesh`] of the surfaces agents can walk on from the meshes
+//! marked with [`NavAffector`], and finds paths across it for the entities with a
+//! [`PathRequest`]. [`NavObstacle`]s, such as doors or moving crates, are carved out of the
+//! navigation mesh without rebaking it.
+//!
+//! Refer to [`NavPlugin`] for usage information.
+
+#[cfg(feature = "bevy_gizmos")]
+pub mod gizmos;
+
+mod bake;
+mod navmesh;
+mod obstacle;
+mod path;
+
+pub use bake::{NavAffector, NavMeshBaked};
+pub use navmesh::{NavCell, NavMesh, NavMeshSettings};
+pub use obstacle::NavObstacle;
+pub use path::{NavPath, PathRequest};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_transform::TransformSystems;
+
+/// The navigation prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{
+        NavAffector, NavMesh, NavMeshSettings, NavObstacle, NavPath, NavPlugin, PathRequest,
+    };
+}
+
+/// Bakes the [`NavMesh`] and finds the paths across it.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::Assets;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{primitives::Plane3d, Vec2, Vec3};
+/// # use bevy_mesh::{Mesh, Mesh3d, Meshable};
+/// # use bevy_nav::prelude::*;
+/// fn setup(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
+///     // The level geometry.
+///     commands.spawn((
+///         Mesh3d(meshes.add(Plane3d::default().mesh().size(20.0, 20.0))),
+///         NavAffector,
+///     ));
+///     // An agent.
+///     commands.spawn(PathRequest {
+///         start: Vec3::new(-5.0, 0.0, -5.0),
+///         goal: Vec3::new(5.0, 0.0, 5.0),
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(setup);
+/// ```
+///
+/// The navigation mesh is baked in the background with the [`NavMeshSettings`], and rebaked
+/// whenever the [`NavAffector`]s change. Paths are then found for the [`PathRequest`]s, in the
+/// background as well, and inserted as [`NavPath`]s. Moving the agents along the paths is left
+/// to the app, so that it can use the character controller or the physics engine of its choice.
+#[derive(Default)]
+pub struct NavPlugin;
+
+impl Plugin for NavPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<NavAffector>()
+            .register_type::<NavObstacle>()
+            .register_type::<NavMeshSettings>()
+            .register_type::<PathRequest>()
+            .register_type::<NavPath>()
+            .init_resource::<NavMeshSettings>()
+            .init_resource::<NavMesh>()
+            .init_resource::<bake::NavMeshBaker>()
+            .add_message::<NavMeshBaked>()
+            .configure_sets(
+                PostUpdate,
+                (NavSystems::Bake, NavSystems::Carve, NavSystems::FindPaths)
+                    .chain()
+                    .after(TransformSystems::Propagate),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    bake::bake_nav_mesh.in_set(NavSystems::Bake),
+                    obstacle::carve_obstacles.in_set(NavSystems::Carve),
+                    path::find_paths.in_set(NavSystems::FindPaths),
+                ),
+            );
+
+        #[cfg(feature = "bevy_gizmos")]
+        app.add_plugins(gizmos::NavMeshGizmoPlugin);
+    }
+}
+
+/// The systems of the [`NavPlugin`], running in [`PostUpdate`] in this order.
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NavSystems {
+    /// Starts baking the [`NavMesh`] when the [`NavAffector`]s changed, and replaces it once
+    /// baked.
+    Bake,
+    /// Carves the [`NavObstacle`]s out of the [`NavMesh`].
+    Carve,
+    /// Starts finding the paths of the [`PathRequest`]s.
+    FindPaths,
+}
@@ -0,0 +1,797 @@
+use core::{cmp::Reverse, f32::consts::FRAC_PI_4};
+use std::collections::{BinaryHeap, VecDeque};
+
+use bevy_ecs::{reflect::ReflectResource, resource::Resource};
+use bevy_math::{
+    ops, primitives::Triangle3d, FloatOrd, Isometry3d, Mat3, Vec2, Vec3, Vec3Swizzles,
+};
+use bevy_platform::sync::Arc;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Marks a missing neighbor of a [`NavCell`].
+const NONE: u32 = u32::MAX;
+
+/// The offsets of the four neighbors of a cell, in the order of [`NavCell::neighbors`].
+const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// How far from the navigation mesh, in world units, the start and the goal of a path are
+/// looked up.
+const SEARCH_DISTANCE: f32 = 2.0;
+
+/// Controls how the [`NavMesh`] is baked from the level geometry.
+///
+/// The navigation mesh is rebaked whenever this resource changes.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Resource, Default, Debug, PartialEq, Clone)]
+pub struct NavMeshSettings {
+    /// The size of the cells the walkable surfaces are sampled with, in world units.
+    ///
+    /// Smaller cells follow the geometry more closely, at the cost of longer bakes and
+    /// pathfinding queries.
+    pub cell_size: f32,
+    /// The radius of the agents. Walkable surfaces closer than this to a wall or a ledge are
+    /// removed from the navigation mesh, and obstacles are expanded by it.
+    pub agent_radius: f32,
+    /// The height of the agents. Surfaces with less free space above them are not walkable.
+    pub agent_height: f32,
+    /// The steepest slope, in radians, the agents can walk on.
+    pub max_slope: f32,
+    /// The highest step the agents can climb up or down.
+    pub max_climb: f32,
+}
+
+impl Default for NavMeshSettings {
+    fn default() -> Self {
+        Self {
+            cell_size: 0.25,
+            agent_radius: 0.4,
+            agent_height: 1.8,
+            max_slope: FRAC_PI_4,
+            max_climb: 0.3,
+        }
+    }
+}
+
+/// A walkable cell of a [`NavMesh`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NavCell {
+    /// The column of the cell along the X axis.
+    pub x: u32,
+    /// The row of the cell along the Z axis.
+    pub z: u32,
+    /// The height of the walkable surface at the center of the cell.
+    pub height: f32,
+    /// The cells reachable from this one towards -X, +X, -Z and +Z, or [`NONE`].
+    neighbors: [u32; 4],
+}
+
+impl NavCell {
+    /// Returns the indices of the cells the agents can walk to from this one.
+    pub fn neighbors(&self) -> impl Iterator<Item = usize> + '_ {
+        self.neighbors
+            .iter()
+            .filter(|&&neighbor| neighbor != NONE)
+            .map(|&neighbor| neighbor as usize)
+    }
+}
+
+/// The surfaces agents can walk on, and the queries to find paths across them.
+///
+/// The walkable surfaces are sampled on a grid of [`NavMeshSettings::cell_size`] cells in the XZ
+/// plane, and agents move from one cell to the cells next to it. A grid cell can hold several
+/// walkable cells at different heights, such as the floors of a building, so the navigation
+/// mesh is not limited to heightfields.
+///
+/// The [`NavPlugin`](crate::NavPlugin) bakes this resource from the meshes marked with
+/// [`NavAffector`](crate::NavAffector), but it can also be baked from any triangles with
+/// [`NavMesh::bake`]. Cloning it is cheap, which allows running queries in the background.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct NavMesh {
+    origin: Vec2,
+    cell_size: f32,
+    width: u32,
+    depth: u32,
+    agent_radius: f32,
+    agent_height: f32,
+    /// The index of the first cell of each grid cell, cells being sorted by grid cell.
+    columns: Arc<[u32]>,
+    cells: Arc<[NavCell]>,
+    /// Whether each cell is covered by an obstacle.
+    blocked: Arc<[bool]>,
+}
+
+impl NavMesh {
+    /// Bakes a navigation mesh from world space `triangles`.
+    ///
+    /// The walkable triangles are the ones facing up, with a counterclockwise winding, that are
+    /// not steeper than [`NavMeshSettings::max_slope`]. All the other triangles, such as walls and
+    /// ceilings, are obstructions.
+    pub fn bake(
+        triangles: impl IntoIterator<Item = Triangle3d>,
+        settings: &NavMeshSettings,
+    ) -> Self {
+        let triangles: Vec<Triangle3d> = triangles.into_iter().collect();
+        let cell_size = settings.cell_size.max(1e-3);
+        let empty = Self {
+            cell_size,
+            agent_radius: settings.agent_radius,
+            agent_height: settings.agent_height,
+            ..Self::default()
+        };
+
+        let Some((min, max)) = triangles
+            .iter()
+            .flat_map(|triangle| triangle.vertices)
+            .map(Vec3::xz)
+            .fold(None, |bounds: Option<(Vec2, Vec2)>, point| {
+                Some(bounds.map_or((point, point), |(min, max)| {
+                    (min.min(point), max.max(point))
+                }))
+            })
+        else {
+            return empty;
+        };
+        let size = ((max - min) / cell_size).ceil().max(Vec2::ONE);
+        let (width, depth) = (size.x as u32, size.y as u32);
+        let grid = Grid {
+            origin: min,
+            cell_size,
+            width,
+            depth,
+        };
+
+        // Rasterize the triangles into walkable floors and obstructions for each grid cell.
+        let column_count = width as usize * depth as usize;
+        let mut floors = vec![Vec::<f32>::new(); column_count];
+        let mut obstructions = vec![Vec::<(f32, f32)>::new(); column_count];
+        let min_up = ops::cos(settings.max_slope) - 1e-4;
+        for triangle in &triangles {
+            let [a, b, c] = triangle.vertices;
+            let Some(normal) = (b - a).cross(c - a).try_normalize() else {
+                continue;
+            };
+            let projected = [a.xz(), b.xz(), c.xz()];
+            let (x_range, z_range) = grid.cells_overlapping(
+                projected[0].min(projected[1]).min(projected[2]),
+                projected[0].max(projected[1]).max(projected[2]),
+            );
+            let walkable = normal.y >= min_up;
+            let heights = (a.y.min(b.y).min(c.y), a.y.max(b.y).max(c.y));
+            for z in z_range {
+                for x in x_range.clone() {
+                    let center = grid.center(x, z);
+                    let column = grid.column(x, z);
+                    if walkable {
+                        floors[column].extend(height_at(&[a, b, c], center));
+                    } else if overlaps_cell(&projected, center, cell_size / 2.0) {
+                        obstructions[column].push(heights);
+                    }
+                }
+            }
+        }
+
+        // Keep the floors with enough free space above them.
+        let mut columns = Vec::with_capacity(column_count + 1);
+        let mut cells = Vec::new();
+        for z in 0..depth {
+            for x in 0..width {
+                let column = grid.column(x, z);
+                columns.push(cells.len() as u32);
+                let floors = &mut floors[column];
+                floors.sort_by(f32::total_cmp);
+                floors.dedup_by(|a, b| (*a - *b).abs() < 1e-3);
+                for (i, &height) in floors.iter().enumerate() {
+                    let clearance = floors
+                        .get(i + 1)
+                        .is_none_or(|&above| above - height >= settings.agent_height);
+                    let obstructed = obstructions[column].iter().any(|&(bottom, top)| {
+                        top > height + settings.max_climb && bottom < height + settings.agent_height
+                    });
+                    if clearance && !obstructed {
+                        cells.push(NavCell {
+                            x,
+                            z,
+                            height,
+                            neighbors: [NONE; 4],
+                        });
+                    }
+                }
+            }
+        }
+        columns.push(cells.len() as u32);
+        link_neighbors(&grid, &columns, &mut cells, settings.max_climb);
+
+        // Keep the agents away from walls and ledges.
+        if settings.agent_radius > 0.0 {
+            let distances = boundary_distances(&cells);
+            let keep =
+                |cell: usize| (distances[cell] as f32 + 0.5) * cell_size >= settings.agent_radius;
+            let mut remap = vec![NONE; cells.len()];
+            let mut eroded = Vec::with_capacity(cells.len());
+            let mut eroded_columns = Vec::with_capacity(columns.len());
+            for column in columns.windows(2) {
+                eroded_columns.push(eroded.len() as u32);
+                for cell in column[0] as usize..column[1] as usize {
+                    if keep(cell) {
+                        remap[cell] = eroded.len() as u32;
+                        eroded.push(cells[cell]);
+                    }
+                }
+            }
+            eroded_columns.push(eroded.len() as u32);
+            for cell in &mut eroded {
+                for neighbor in &mut cell.neighbors {
+                    if *neighbor != NONE {
+                        *neighbor = remap[*neighbor as usize];
+                    }
+                }
+            }
+            cells = eroded;
+            columns = eroded_columns;
+        }
+
+        Self {
+            origin: min,
+            width,
+            depth,
+            blocked: vec![false; cells.len()].into(),
+            columns: columns.into(),
+            cells: cells.into(),
+            ..empty
+        }
+    }
+
+    /// Returns the walkable cells.
+    pub fn cells(&self) -> &[NavCell] {
+        &self.cells
+    }
+
+    /// Returns the size of the cells in world units.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Returns the center of the walkable surface of the cell at `index`.
+    pub fn cell_center(&self, index: usize) -> Vec3 {
+        let cell = &self.cells[index];
+        let center = self.grid().center(cell.x, cell.z);
+        Vec3::new(center.x, cell.height, center.y)
+    }
+
+    /// Returns `true` if the cell at `index` is covered by an obstacle.
+    pub fn is_blocked(&self, index: usize) -> bool {
+        self.blocked[index]
+    }
+
+    /// Returns the index of the unblocked cell closest to `point`, looking up to two world units
+    /// away from it.
+    pub fn find_cell(&self, point: Vec3) -> Option<usize> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        let grid = self.grid();
+        let search = Vec2::splat(SEARCH_DISTANCE);
+        let (x_range, z_range) = grid.cells_overlapping(point.xz() - search, point.xz() + search);
+        z_range
+            .flat_map(|z| x_range.clone().map(move |x| grid.column(x, z)))
+            .flat_map(|column| self.columns[column] as usize..self.columns[column + 1] as usize)
+            .filter(|&cell| !self.blocked[cell])
+            .map(|cell| (cell, self.cell_center(cell).distance_squared(point)))
+            .filter(|&(_, distance)| distance <= SEARCH_DISTANCE * SEARCH_DISTANCE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(cell, _)| cell)
+    }
+
+    /// Finds the shortest path from `start` to `goal` that avoids the obstacles.
+    ///
+    /// The path starts and ends on the navigation mesh, at the points closest to `start` and
+    /// `goal`, and only has points where it turns around a corner. Returns `None` if `start` or
+    /// `goal` are not close to the navigation mesh, or if there is no path between them.
+    pub fn find_path(&self, start: Vec3, goal: Vec3) -> Option<Vec<Vec3>> {
+        let from = self.find_cell(start)?;
+        let to = self.find_cell(goal)?;
+        let cells = self.find_cells(from, to)?;
+        let path = self.straighten(self.snap(start, from), self.snap(goal, to), &cells);
+        Some(self.shortcut(path))
+    }
+
+    /// Blocks the cells covered by `obstacles`, given as boxes with their half extents, and
+    /// unblocks all the others.
+    ///
+    /// The obstacles are expanded by the agent radius the navigation mesh was baked with, and
+    /// only block the cells whose center is covered.
+    pub fn carve(&mut self, obstacles: impl IntoIterator<Item = (Isometry3d, Vec3)>) {
+        let grid = self.grid();
+        let mut blocked = vec![false; self.cells.len()];
+        for (isometry, half_extents) in obstacles {
+            let half_extents = half_extents + Vec3::new(self.agent_radius, 0.0, self.agent_radius);
+            let center = Vec3::from(isometry.translation);
+            let world_half_extents = Mat3::from_quat(isometry.rotation).abs() * half_extents;
+            let (bottom, top) = (
+                center.y - world_half_extents.y,
+                center.y + world_half_extents.y,
+            );
+            let (x_range, z_range) = grid.cells_overlapping(
+                center.xz() - world_half_extents.xz(),
+                center.xz() + world_half_extents.xz(),
+            );
+            for z in z_range {
+                for x in x_range.clone() {
+                    let column = grid.column(x, z);
+                    for cell in self.columns[column] as usize..self.columns[column + 1] as usize {
+                        let position = self.cell_center(cell);
+                        let local = Vec3::from(isometry.inverse_transform_point(position));
+                        if local.x.abs() <= half_extents.x
+                            && local.z.abs() <= half_extents.z
+                            && position.y < top
+                            && position.y + self.agent_height > bottom
+                        {
+                            blocked[cell] = true;
+                        }
+                    }
+                }
+            }
+        }
+        self.blocked = blocked.into();
+    }
+
+    /// Returns the edges of the cells along which the agents cannot walk any further.
+    pub fn boundary_edges(&self) -> impl Iterator<Item = (Vec3, Vec3)> + '_ {
+        let half = self.cell_size / 2.0;
+        (0..self.cells.len()).flat_map(move |index| {
+            let center = self.cell_center(index);
+            DIRECTIONS
+                .into_iter()
+                .zip(self.cells[index].neighbors)
+                .filter(|&(_, neighbor)| neighbor == NONE)
+                .map(move |((dx, dz), _)| {
+                    let (dx, dz) = (dx as f32 * half, dz as f32 * half);
+                    let middle = center + Vec3::new(dx, 0.0, dz);
+                    let along = Vec3::new(dz, 0.0, dx);
+                    (middle - along, middle + along)
+                })
+        })
+    }
+
+    fn grid(&self) -> Grid {
+        Grid {
+            origin: self.origin,
+            cell_size: self.cell_size,
+            width: self.width,
+            depth: self.depth,
+        }
+    }
+
+    /// Moves `point` onto the surface of `cell`.
+    fn snap(&self, point: Vec3, cell: usize) -> Vec3 {
+        let center = self.cell_center(cell);
+        let half = self.cell_size / 2.0;
+        Vec3::new(
+            point.x.clamp(center.x - half, center.x + half),
+            center.y,
+            point.z.clamp(center.z - half, center.z + half),
+        )
+    }
+
+    /// Finds the cells to walk through to go from the cell `from` to the cell `to`, with A*.
+    fn find_cells(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let goal = self.cell_center(to);
+        let mut costs = vec![f32::INFINITY; self.cells.len()];
+        let mut previous = vec![NONE; self.cells.len()];
+        let mut open = BinaryHeap::new();
+        costs[from] = 0.0;
+        open.push(Reverse((
+            FloatOrd(self.cell_center(from).distance(goal)),
+            from,
+        )));
+
+        while let Some(Reverse((FloatOrd(estimate), current))) = open.pop() {
+            let center = self.cell_center(current);
+            if current == to {
+                let mut cells = vec![to];
+                let mut cell = to;
+                while cell != from {
+                    cell = previous[cell] as usize;
+                    cells.push(cell);
+                }
+                cells.reverse();
+                return Some(cells);
+            }
+            // Skip the outdated entries of cells that were reached again with a lower cost.
+            if estimate > costs[current] + center.distance(goal) {
+                continue;
+            }
+            for neighbor in self.cells[current].neighbors() {
+                if self.blocked[neighbor] {
+                    continue;
+                }
+                let neighbor_center = self.cell_center(neighbor);
+                let cost = costs[current] + center.distance(neighbor_center);
+                if cost < costs[neighbor] {
+                    costs[neighbor] = cost;
+                    previous[neighbor] = current as u32;
+                    open.push(Reverse((
+                        FloatOrd(cost + neighbor_center.distance(goal)),
+                        neighbor,
+                    )));
+                }
+            }
+        }
+        None
+    }
+
+    /// Turns the cells of a path into the shortest line through them, with the funnel
+    /// algorithm.
+    fn straighten(&self, start: Vec3, goal: Vec3, cells: &[usize]) -> Vec<Vec3> {
+        // The edges crossed by the path, as (left, right) pairs seen from the start.
+        let mut portals = Vec::with_capacity(cells.len() + 1);
+        portals.push((start, start));
+        for pair in cells.windows(2) {
+            let (from, to) = (self.cell_center(pair[0]), self.cell_center(pair[1]));
+            let middle = (from + to) / 2.0;
+            let offset = Vec3::new(from.z - to.z, 0.0, to.x - from.x) / 2.0;
+            let (a, b) = (middle + offset, middle - offset);
+            if area(from, a, b) > 0.0 {
+                portals.push((a, b));
+            } else {
+                portals.push((b, a));
+            }
+        }
+        portals.push((goal, goal));
+
+        let mut path = vec![start];
+        let (mut apex, mut left, mut right) = (start, start, start);
+        let (mut apex_index, mut left_index, mut right_index) = (0, 0, 0);
+        let mut i = 1;
+        while i < portals.len() {
+            let (portal_left, portal_right) = portals[i];
+
+            if area(apex, right, portal_right) <= 0.0 {
+                if apex == right || area(apex, left, portal_right) > 0.0 {
+                    // Tighten the funnel.
+                    right = portal_right;
+                    right_index = i;
+                } else {
+                    // The right side crossed the left one, which is a corner of the path.
+                    path.push(left);
+                    apex = left;
+                    apex_index = left_index;
+                    (right, right_index) = (apex, apex_index);
+                    i = apex_index + 1;
+                    continue;
+                }
+            }
+
+            if area(apex, left, portal_left) >= 0.0 {
+                if apex == left || area(apex, right, portal_left) < 0.0 {
+                    left = portal_left;
+                    left_index = i;
+                } else {
+                    path.push(right);
+                    apex = right;
+                    apex_index = right_index;
+                    (left, left_index) = (apex, apex_index);
+                    i = apex_index + 1;
+                    continue;
+                }
+            }
+
+            i += 1;
+        }
+
+        if path.last() != Some(&goal) {
+            path.push(goal);
+        }
+        path
+    }
+
+    /// Removes the corners of `path` that can be cut by walking in a straight line.
+    ///
+    /// The funnel algorithm only finds the shortest path through the cells found by A*, which
+    /// moves between cells along the grid axes, so a shorter path may go through other cells.
+    fn shortcut(&self, path: Vec<Vec3>) -> Vec<Vec3> {
+        let mut shortened = vec![path[0]];
+        let mut current = 0;
+        while current + 1 < path.len() {
+            let next = (current + 2..path.len())
+                .rev()
+                .find(|&next| self.is_straight_walkable(path[current], path[next]))
+                .unwrap_or(current + 1);
+            shortened.push(path[next]);
+            current = next;
+        }
+        shortened
+    }
+
+    /// Returns `true` if an agent can walk in a straight line from `from` to `to`.
+    fn is_straight_walkable(&self, from: Vec3, to: Vec3) -> bool {
+        let grid = self.grid();
+        let Some(direction) = (to.xz() - from.xz()).try_normalize() else {
+            return true;
+        };
+        // The points of a path are on the edges of cells, so the cells the line starts and ends
+        // in are looked up slightly inside of it.
+        let nudge = direction * self.cell_size * 1e-3;
+        let start = (from.xz() + nudge - grid.origin) / grid.cell_size;
+        let end = (to.xz() - nudge - grid.origin) / grid.cell_size;
+        let (Some(mut cell), Some(goal)) = (self.cell_at(start, from.y), self.cell_at(end, to.y))
+        else {
+            return false;
+        };
+
+        // Walk along the cells crossed by the line.
+        let delta = end - start;
+        let boundary = |start: f32, delta: f32| {
+            if delta > 0.0 {
+                (start.floor() + 1.0 - start) / delta
+            } else if delta < 0.0 {
+                (start - start.floor()) / -delta
+            } else {
+                f32::INFINITY
+            }
+        };
+        let mut next = Vec2::new(boundary(start.x, delta.x), boundary(start.y, delta.y));
+        let step = delta.abs().recip();
+        while cell != goal {
+            let direction = if next.x < next.y {
+                if next.x > 1.0 {
+                    return false;
+                }
+                next.x += step.x;
+                if delta.x > 0.0 {
+                    1
+                } else {
+                    0
+                }
+            } else {
+                if next.y > 1.0 {
+                    return false;
+                }
+                next.y += step.y;
+                if delta.y > 0.0 {
+                    3
+                } else {
+                    2
+                }
+            };
+            let neighbor = self.cells[cell].neighbors[direction];
+            if neighbor == NONE || self.blocked[neighbor as usize] {
+                return false;
+            }
+            cell = neighbor as usize;
+        }
+        true
+    }
+
+    /// Returns the unblocked cell at `position`, in grid units, with the height closest to
+    /// `height`.
+    fn cell_at(&self, position: Vec2, height: f32) -> Option<usize> {
+        let position = position.floor();
+        if position.x < 0.0
+            || position.y < 0.0
+            || position.x >= self.width as f32
+            || position.y >= self.depth as f32
+        {
+            return None;
+        }
+        let column = self.grid().column(position.x as u32, position.y as u32);
+        (self.columns[column] as usize..self.columns[column + 1] as usize)
+            .min_by(|&a, &b| {
+                let distance = |cell: usize| (self.cells[cell].height - height).abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .filter(|&cell| !self.blocked[cell])
+    }
+}
+
+/// The grid the walkable surfaces are sampled on, in the XZ plane.
+#[derive(Clone, Copy)]
+struct Grid {
+    origin: Vec2,
+    cell_size: f32,
+    width: u32,
+    depth: u32,
+}
+
+impl Grid {
+    fn column(&self, x: u32, z: u32) -> usize {
+        z as usize * self.width as usize + x as usize
+    }
+
+    fn center(&self, x: u32, z: u32) -> Vec2 {
+        self.origin + (Vec2::new(x as f32, z as f32) + 0.5) * self.cell_size
+    }
+
+    /// Returns the ranges of grid cells overlapping the rectangle from `min` to `max`.
+    fn cells_overlapping(
+        &self,
+        min: Vec2,
+        max: Vec2,
+    ) -> (core::ops::Range<u32>, core::ops::Range<u32>) {
+        let cell = |point: Vec2| ((point - self.origin) / self.cell_size).floor();
+        let (min, max) = (cell(min), cell(max));
+        let range = |min: f32, max: f32, len: u32| {
+            let start = min.max(0.0) as u32;
+            let end = (max + 1.0).clamp(0.0, len as f32) as u32;
+            start..end.max(start)
+        };
+        (
+            range(min.x, max.x, self.width),
+            range(min.y, max.y, self.depth),
+        )
+    }
+}
+
+/// Connects the cells next to each other whose heights differ by at most `max_climb`.
+fn link_neighbors(grid: &Grid, columns: &[u32], cells: &mut [NavCell], max_climb: f32) {
+    for index in 0..cells.len() {
+        let cell = cells[index];
+        for (direction, (dx, dz)) in DIRECTIONS.into_iter().enumerate() {
+            let (Some(x), Some(z)) = (
+                cell.x.checked_add_signed(dx).filter(|&x| x < grid.width),
+                cell.z.checked_add_signed(dz).filter(|&z| z < grid.depth),
+            ) else {
+                continue;
+            };
+            let column = grid.column(x, z);
+            let neighbor = (columns[column]..columns[column + 1])
+                .map(|neighbor| {
+                    (
+                        neighbor,
+                        (cells[neighbor as usize].height - cell.height).abs(),
+                    )
+                })
+                .filter(|&(_, climb)| climb <= max_climb)
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(NONE, |(neighbor, _)| neighbor);
+            cells[index].neighbors[direction] = neighbor;
+        }
+    }
+}
+
+/// Returns how many cells away from a wall or a ledge each cell is.
+fn boundary_distances(cells: &[NavCell]) -> Vec<u32> {
+    let mut distances = vec![u32::MAX; cells.len()];
+    let mut queue = VecDeque::new();
+    for (index, cell) in cells.iter().enumerate() {
+        if cell.neighbors.contains(&NONE) {
+            distances[index] = 0;
+            queue.push_back(index);
+        }
+    }
+    while let Some(index) = queue.pop_front() {
+        for neighbor in cells[index].neighbors() {
+            if distances[neighbor] == u32::MAX {
+                distances[neighbor] = distances[index] + 1;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    distances
+}
+
+/// Returns the height of `triangle` above `point`, if `point` is within it in the XZ plane.
+fn height_at(triangle: &[Vec3; 3], point: Vec2) -> Option<f32> {
+    let [a, b, c] = *triangle;
+    let (ab, ac, ap) = (b.xz() - a.xz(), c.xz() - a.xz(), point - a.xz());
+    let determinant = ab.perp_dot(ac);
+    if determinant.abs() < 1e-8 {
+        return None;
+    }
+    let u = ap.perp_dot(ac) / determinant;
+    let v = ab.perp_dot(ap) / determinant;
+    const EPSILON: f32 = 1e-5;
+    (u >= -EPSILON && v >= -EPSILON && u + v <= 1.0 + EPSILON)
+        .then(|| a.y + u * (b.y - a.y) + v * (c.y - a.y))
+}
+
+/// Returns `true` if `triangle` overlaps the square of half size `half` around `center`.
+fn overlaps_cell(triangle: &[Vec2; 3], center: Vec2, half: f32) -> bool {
+    // The cell is within the bounds of the triangle, so only the edges can separate them.
+    (0..3).all(|i| {
+        let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+        let axis = (b - a).perp();
+        let (min, max) = triangle.iter().map(|vertex| axis.dot(*vertex)).fold(
+            (f32::INFINITY, f32::NEG_INFINITY),
+            |(min, max), projection| (min.min(projection), max.max(projection)),
+        );
+        let projection = axis.dot(center);
+        let extent = half * (axis.x.abs() + axis.y.abs());
+        projection + extent >= min && projection - extent <= max
+    })
+}
+
+/// Returns twice the signed area of the triangle `a`, `b`, `c` in the XZ plane.
+fn area(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+    let (ab, ac) = (b.xz() - a.xz(), c.xz() - a.xz());
+    ac.x * ab.y - ab.x * ac.y
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{primitives::Triangle3d, Isometry3d, Vec3};
+
+    use super::{NavMesh, NavMeshSettings};
+
+    /// A flat floor from `-size` to `size` in X and Z.
+    fn floor(size: f32, height: f32) -> [Triangle3d; 2] {
+        let corner = |x: f32, z: f32| Vec3::new(x * size, height, z * size);
+        [
+            Triangle3d::new(corner(-1.0, -1.0), corner(-1.0, 1.0), corner(1.0, 1.0)),
+            Triangle3d::new(corner(-1.0, -1.0), corner(1.0, 1.0), corner(1.0, -1.0)),
+        ]
+    }
+
+    #[test]
+    fn bake_floor() {
+        let settings = NavMeshSettings {
+            cell_size: 0.5,
+            agent_radius: 0.0,
+            ..Default::default()
+        };
+        let nav_mesh = NavMesh::bake(floor(2.0, 1.0), &settings);
+        assert_eq!(nav_mesh.cells().len(), 8 * 8);
+        assert!(nav_mesh.cells().iter().all(|cell| cell.height == 1.0));
+
+        // The agent radius keeps the agents away from the edges.
+        let settings = NavMeshSettings {
+            agent_radius: 0.5,
+            ..settings
+        };
+        let nav_mesh = NavMesh::bake(floor(2.0, 1.0), &settings);
+        assert_eq!(nav_mesh.cells().len(), 6 * 6);
+
+        // Ceilings and walls are not walkable.
+        let flipped = floor(2.0, 1.0).map(Triangle3d::reversed);
+        assert!(NavMesh::bake(flipped, &settings).cells().is_empty());
+    }
+
+    #[test]
+    fn straight_path() {
+        let settings = NavMeshSettings {
+            agent_radius: 0.0,
+            ..Default::default()
+        };
+        let nav_mesh = NavMesh::bake(floor(5.0, 0.0), &settings);
+        let path = nav_mesh
+            .find_path(Vec3::new(-4.0, 0.5, -4.0), Vec3::new(3.9, 0.0, 2.1))
+            .unwrap();
+        assert_eq!(path, [Vec3::new(-4.0, 0.0, -4.0), Vec3::new(3.9, 0.0, 2.1)]);
+
+        assert!(nav_mesh
+            .find_path(Vec3::ZERO, Vec3::new(20.0, 0.0, 0.0))
+            .is_none());
+    }
+
+    #[test]
+    fn path_around_obstacle() {
+        let mut nav_mesh = NavMesh::bake(floor(5.0, 0.0), &NavMeshSettings::default());
+        let (start, goal) = (Vec3::new(-3.0, 0.0, 0.0), Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(nav_mesh.find_path(start, goal).unwrap().len(), 2);
+
+        // A wall across the floor, with a gap at high Z.
+        let half_extents = Vec3::new(0.5, 1.0, 3.0);
+        nav_mesh.carve([(
+            Isometry3d::from_translation(Vec3::new(0.0, 0.0, -1.0)),
+            half_extents,
+        )]);
+        let path = nav_mesh.find_path(start, goal).unwrap();
+        assert!(path.len() > 2, "{path:?}");
+        assert!(path.iter().any(|point| point.z > 2.0), "{path:?}");
+        // The path keeps the agent radius away from the wall.
+        for point in &path {
+            assert!(
+                point.x.abs() >= 0.9 - 1e-3 || point.z >= 2.0 + 0.4 - 1e-3,
+                "{path:?}"
+            );
+        }
+
+        // Walls all the way across block the path.
+        let half_extents = Vec3::new(0.5, 1.0, 6.0);
+        nav_mesh.carve([(Isometry3d::IDENTITY, half_extents)]);
+        assert!(nav_mesh.find_path(start, goal).is_none());
+
+        nav_mesh.carve([]);
+        assert_eq!(nav_mesh.find_path(start, goal).unwrap().len(), 2);
+    }
+}
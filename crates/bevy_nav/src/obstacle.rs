@@ -0,0 +1,63 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    lifecycle::RemovedComponents,
+    message::MessageReader,
+    reflect::ReflectComponent,
+    system::{Query, ResMut},
+    world::Ref,
+};
+use bevy_math::{Isometry3d, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::{GlobalTransform, Transform};
+
+use crate::{NavMesh, NavMeshBaked};
+
+/// A box carved out of the [`NavMesh`], such as a door, a crate or a parked vehicle.
+///
+/// Unlike the [`NavAffector`](crate::NavAffector)s, obstacles do not require rebaking the
+/// navigation mesh when they move: they only block the cells they cover, expanded by
+/// [`NavMeshSettings::agent_radius`](crate::NavMeshSettings::agent_radius).
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Transform)]
+pub struct NavObstacle {
+    /// The half extents of the box, centered on the [`Transform`] of the entity and scaled by it.
+    pub half_extents: Vec3,
+}
+
+impl Default for NavObstacle {
+    fn default() -> Self {
+        Self {
+            half_extents: Vec3::splat(0.5),
+        }
+    }
+}
+
+/// Carves the [`NavObstacle`]s out of the [`NavMesh`] when they change, or when it is rebaked.
+pub(crate) fn carve_obstacles(
+    mut nav_mesh: ResMut<NavMesh>,
+    mut baked: MessageReader<NavMeshBaked>,
+    obstacles: Query<(Ref<NavObstacle>, Ref<GlobalTransform>)>,
+    mut removed: RemovedComponents<NavObstacle>,
+) {
+    // All the messages are read, so that they are not seen again during the next frame.
+    let baked = baked.read().count() > 0;
+    let removed = removed.read().count() > 0;
+    let changed = baked
+        || removed
+        || obstacles
+            .iter()
+            .any(|(obstacle, transform)| obstacle.is_changed() || transform.is_changed());
+    if !changed {
+        return;
+    }
+
+    nav_mesh.carve(obstacles.iter().map(|(obstacle, transform)| {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        (
+            Isometry3d::new(translation, rotation),
+            obstacle.half_extents * scale,
+        )
+    }));
+}
@@ -0,0 +1,99 @@
+use bevy_app::EntityTaskCommandsExt;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    reflect::ReflectComponent,
+    system::{Commands, Query, Res},
+    world::Ref,
+};
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+use crate::NavMesh;
+
+/// Requests a path across the [`NavMesh`] for this entity.
+///
+/// The path is found in the background on the
+/// [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool), and inserted as a [`NavPath`] a
+/// few frames later. It is found again whenever the request or the navigation mesh changes,
+/// for instance when an obstacle moves.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::Vec3;
+/// # use bevy_nav::{NavPath, PathRequest};
+/// # use bevy_transform::components::Transform;
+/// #[derive(Component)]
+/// struct Goal(Vec3);
+///
+/// fn request_paths(
+///     mut commands: Commands,
+///     agents: Query<(Entity, &Transform, &Goal), Changed<Goal>>,
+/// ) {
+///     for (agent, transform, goal) in &agents {
+///         commands.entity(agent).insert(PathRequest {
+///             start: transform.translation,
+///             goal: goal.0,
+///         });
+///     }
+/// }
+///
+/// fn follow_paths(agents: Query<(&Transform, &NavPath)>) {
+///     for (transform, path) in &agents {
+///         if let Some(next) = path.points.get(1) {
+///             let direction = (*next - transform.translation).normalize_or_zero();
+///             // Walk along `direction`.
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(request_paths);
+/// # bevy_ecs::system::assert_is_system(follow_paths);
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+pub struct PathRequest {
+    /// Where the path starts.
+    pub start: Vec3,
+    /// Where the path should end.
+    pub goal: Vec3,
+}
+
+/// The path found for the [`PathRequest`] of this entity, see [`NavMesh::find_path`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct NavPath {
+    /// The points of the path, from the start to the goal.
+    ///
+    /// This is empty if there was no path to the goal.
+    pub points: Vec<Vec3>,
+}
+
+/// Starts finding the paths of the [`PathRequest`]s that changed, or of all of them when the
+/// [`NavMesh`] changed.
+pub(crate) fn find_paths(
+    mut commands: Commands,
+    nav_mesh: Res<NavMesh>,
+    requests: Query<(Entity, Ref<PathRequest>)>,
+) {
+    let nav_mesh_changed = nav_mesh.is_changed();
+    for (entity, request) in &requests {
+        if !nav_mesh_changed && !request.is_changed() {
+            continue;
+        }
+        let nav_mesh = nav_mesh.clone();
+        let request = *request;
+        commands.entity(entity).run_when_complete(
+            async move { nav_mesh.find_path(request.start, request.goal) },
+            move |points, mut entity| {
+                // A path found for an outdated request is dropped, the new request is already
+                // being processed.
+                if entity.get::<PathRequest>() == Some(&request) {
+                    entity.insert(NavPath {
+                        points: points.unwrap_or_default(),
+                    });
+                }
+            },
+        );
+    }
+}
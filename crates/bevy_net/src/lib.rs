@@ -0,0 +1,311 @@
+//! Networking foundation for Bevy applications.
+//!
+//! This crate provides the building blocks shared by networked games:
+//!
+//! - the [`Transport`] trait, which moves bytes between peers, with a [`UdpTransport`] for
+//!   native platforms, a `WebSocketTransport` for web clients, and an in-process
+//!   [`MemoryTransport`] for tests and local play,
+//! - the [`Network`] resource, which tracks connections and sends messages on channels with a
+//!   configurable [`Reliability`],
+//! - a replication layer, which sends the components registered with
+//!   [`AppReplicationExt::replicate`] of [`Replicated`] entities to every connection.
+//!
+//! Refer to [`NetPlugin`] for usage information.
+
+extern crate alloc;
+
+mod memory;
+mod network;
+mod replication;
+mod transport;
+#[cfg(not(target_arch = "wasm32"))]
+mod udp;
+#[cfg(target_arch = "wasm32")]
+mod websocket;
+
+pub use memory::MemoryTransport;
+pub use network::{
+    receive_network_events, Connected, Disconnected, NetMessage, NetSystems, Network,
+};
+pub use replication::{
+    apply_replication, despawn_disconnected_entities, send_replication, AppReplicationExt, Remote,
+    Replicated, ReplicatedEntities, ReplicationRegistry,
+};
+pub use transport::{
+    ChannelId, ConnectionId, Reliability, Transport, TransportError, TransportEvent,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use udp::{UdpTransport, UdpTransportConfig};
+#[cfg(target_arch = "wasm32")]
+pub use websocket::WebSocketTransport;
+
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+
+/// The networking prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{
+        AppReplicationExt, ChannelId, Connected, ConnectionId, Disconnected, NetMessage, NetPlugin,
+        Network, Reliability, Remote, Replicated,
+    };
+}
+
+/// Adds networking to an [`App`].
+///
+/// The plugin polls the transport of the [`Network`] resource at the start of every update and
+/// writes the [`Connected`], [`Disconnected`] and [`NetMessage`] messages, then sends the
+/// replicated state at the end of the update. Nothing is sent or received until a transport is
+/// set:
+///
+/// ```no_run
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_net::{prelude::*, UdpTransport};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// fn start_server(mut network: ResMut<Network>) {
+///     network.set_transport(UdpTransport::bind("0.0.0.0:5000").unwrap());
+/// }
+///
+/// fn spawn_player(mut commands: Commands) {
+///     commands.spawn((Replicated, Health(100)));
+/// }
+///
+/// App::new()
+///     .add_plugins(NetPlugin)
+///     .replicate::<Health>()
+///     .add_systems(Startup, (start_server, spawn_player))
+///     .run();
+/// ```
+///
+/// Clients connect with [`UdpTransport::connect`] instead, and receive a copy of every
+/// [`Replicated`] entity of the server, marked with [`Remote`].
+///
+/// Browsers do not allow UDP sockets, so web clients connect to a WebSocket server with the
+/// `WebSocketTransport` instead. Bevy does not provide a WebSocket server: it has to be
+/// implemented as a [`Transport`] on the server.
+#[derive(Default)]
+pub struct NetPlugin;
+
+impl Plugin for NetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Network>()
+            .init_resource::<ReplicationRegistry>()
+            .init_resource::<ReplicatedEntities>()
+            .add_message::<Connected>()
+            .add_message::<Disconnected>()
+            .add_message::<NetMessage>()
+            .register_type::<Replicated>()
+            .register_type::<Remote>()
+            .add_systems(
+                PreUpdate,
+                (
+                    receive_network_events,
+                    apply_replication,
+                    despawn_disconnected_entities,
+                )
+                    .chain()
+                    .in_set(NetSystems::Receive),
+            )
+            .add_systems(PostUpdate, send_replication.in_set(NetSystems::Send));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, Update};
+    use bevy_ecs::{message::Messages, prelude::*};
+    use bevy_reflect::Reflect;
+
+    use crate::{
+        AppReplicationExt, ChannelId, ConnectionId, Disconnected, MemoryTransport, NetMessage,
+        NetPlugin, Network, Remote, Replicated,
+    };
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Target(#[entities] Entity);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Secret(u32);
+
+    #[derive(Resource, Default)]
+    struct Received(Vec<NetMessage>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(NetPlugin)
+            .replicate::<Health>()
+            .replicate::<Target>();
+        app
+    }
+
+    fn connected_apps() -> (App, App) {
+        let (server_transport, client_transport) = MemoryTransport::pair();
+        let mut server = app();
+        server
+            .world_mut()
+            .resource_mut::<Network>()
+            .set_transport(server_transport);
+        let mut client = app();
+        client
+            .world_mut()
+            .resource_mut::<Network>()
+            .set_transport(client_transport);
+        (server, client)
+    }
+
+    fn remote_entity(client: &mut App, entity: Entity) -> Option<Entity> {
+        client
+            .world_mut()
+            .query::<(Entity, &Remote)>()
+            .iter(client.world())
+            .find(|(_, remote)| remote.entity == entity)
+            .map(|(local, _)| local)
+    }
+
+    #[test]
+    fn send_message() {
+        let (mut server, mut client) = connected_apps();
+        client.init_resource::<Received>().add_systems(
+            Update,
+            |mut messages: MessageReader<NetMessage>, mut received: ResMut<Received>| {
+                received.0.extend(messages.read().cloned());
+            },
+        );
+
+        server.update();
+        client.update();
+        assert!(client
+            .world()
+            .resource::<Network>()
+            .is_connected(ConnectionId::SERVER));
+
+        server
+            .world_mut()
+            .resource_mut::<Network>()
+            .broadcast(ChannelId::DEFAULT, b"hello");
+        client.update();
+
+        assert_eq!(
+            client.world().resource::<Received>().0,
+            vec![NetMessage {
+                connection: ConnectionId::SERVER,
+                channel: ChannelId::DEFAULT,
+                payload: b"hello".to_vec(),
+            }]
+        );
+    }
+
+    #[test]
+    fn replicate_components() {
+        let (mut server, mut client) = connected_apps();
+        let target = server.world_mut().spawn((Replicated, Health(10))).id();
+        let entity = server
+            .world_mut()
+            .spawn((Replicated, Health(100), Target(target)))
+            .id();
+
+        server.update();
+        client.update();
+        server.update();
+        client.update();
+
+        let local_target = remote_entity(&mut client, target).unwrap();
+        let local_entity = remote_entity(&mut client, entity).unwrap();
+        assert_eq!(
+            client.world().get::<Health>(local_entity),
+            Some(&Health(100))
+        );
+        assert_eq!(
+            client.world().get::<Target>(local_entity),
+            Some(&Target(local_target))
+        );
+
+        server.world_mut().get_mut::<Health>(entity).unwrap().0 = 50;
+        server.update();
+        client.update();
+        assert_eq!(
+            client.world().get::<Health>(local_entity),
+            Some(&Health(50))
+        );
+
+        server.world_mut().despawn(entity);
+        server.update();
+        client.update();
+        assert!(client.world().get_entity(local_entity).is_err());
+        assert!(client.world().get_entity(local_target).is_ok());
+    }
+
+    #[test]
+    fn despawn_on_disconnect() {
+        let (mut server, mut client) = connected_apps();
+        let entity = server.world_mut().spawn((Replicated, Health(1))).id();
+
+        server.update();
+        client.update();
+        server.update();
+        client.update();
+        let local_entity = remote_entity(&mut client, entity).unwrap();
+
+        server.world_mut().resource_mut::<Network>().close();
+        client.update();
+        client.update();
+        assert!(client.world().get_entity(local_entity).is_err());
+    }
+
+    #[test]
+    fn reject_components_not_replicated() {
+        let (mut server, mut client) = connected_apps();
+        server.replicate::<Secret>();
+        // Reflected, but not replicated by the client.
+        client.register_type::<Secret>();
+        let entity = server
+            .world_mut()
+            .spawn((Replicated, Health(1), Secret(7)))
+            .id();
+
+        server.update();
+        client.update();
+        server.update();
+        client.update();
+
+        let local_entity = remote_entity(&mut client, entity).unwrap();
+        assert_eq!(client.world().get::<Health>(local_entity), Some(&Health(1)));
+        assert!(client.world().get::<Secret>(local_entity).is_none());
+    }
+
+    #[test]
+    fn close_writes_disconnected() {
+        let (mut server, _client) = connected_apps();
+        server.update();
+        assert!(server
+            .world()
+            .resource::<Network>()
+            .is_connected(ConnectionId(1)));
+
+        server.world_mut().resource_mut::<Network>().close();
+        server.update();
+        assert_eq!(
+            server
+                .world()
+                .resource::<Messages<Disconnected>>()
+                .iter_current_update_messages()
+                .collect::<Vec<_>>(),
+            vec![&Disconnected {
+                connection: ConnectionId(1)
+            }]
+        );
+    }
+}
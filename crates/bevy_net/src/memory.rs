@@ -0,0 +1,113 @@
+use alloc::collections::VecDeque;
+
+use bevy_platform::sync::{Arc, Mutex, PoisonError};
+
+use crate::{ChannelId, ConnectionId, Reliability, Transport, TransportError, TransportEvent};
+
+/// The state shared by both ends of a [`MemoryTransport`] pair.
+#[derive(Default)]
+struct Link {
+    /// Messages in flight, indexed by the side receiving them.
+    queues: [VecDeque<(ChannelId, Vec<u8>)>; 2],
+    /// Whether each side has been connected yet.
+    connected: [bool; 2],
+    closed: bool,
+}
+
+/// A [`Transport`] that exchanges messages with another [`MemoryTransport`] in the same process.
+///
+/// This is useful for tests, and for running a local server and client in the same app without
+/// going through the network. Messages are always delivered reliably and in order.
+///
+/// ```
+/// # use bevy_net::MemoryTransport;
+/// let (server, client) = MemoryTransport::pair();
+/// ```
+pub struct MemoryTransport {
+    link: Arc<Mutex<Link>>,
+    side: usize,
+    /// The id the other side is known as.
+    remote: ConnectionId,
+    disconnected: bool,
+}
+
+impl MemoryTransport {
+    /// Creates a pair of connected transports, a server and a client.
+    ///
+    /// The server sees the client as `ConnectionId(1)`, and the client sees the server as
+    /// [`ConnectionId::SERVER`].
+    pub fn pair() -> (Self, Self) {
+        let link = Arc::new(Mutex::new(Link::default()));
+        let server = Self {
+            link: Arc::clone(&link),
+            side: 0,
+            remote: ConnectionId(1),
+            disconnected: false,
+        };
+        let client = Self {
+            link,
+            side: 1,
+            remote: ConnectionId::SERVER,
+            disconnected: false,
+        };
+        (server, client)
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn poll(&mut self, events: &mut Vec<TransportEvent>) {
+        if self.disconnected {
+            return;
+        }
+        let mut link = self.link.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if !link.connected[self.side] {
+            link.connected[self.side] = true;
+            events.push(TransportEvent::Connected(self.remote));
+        }
+
+        let side = self.side;
+        events.extend(link.queues[side].drain(..).map(|(channel, payload)| {
+            TransportEvent::Received {
+                connection: self.remote,
+                channel,
+                payload,
+            }
+        }));
+
+        if link.closed {
+            self.disconnected = true;
+            events.push(TransportEvent::Disconnected(self.remote));
+        }
+    }
+
+    fn send(
+        &mut self,
+        connection: ConnectionId,
+        channel: ChannelId,
+        _reliability: Reliability,
+        payload: &[u8],
+    ) -> Result<(), TransportError> {
+        let mut link = self.link.lock().unwrap_or_else(PoisonError::into_inner);
+        if connection != self.remote || link.closed {
+            return Err(TransportError::NotConnected(connection));
+        }
+        link.queues[1 - self.side].push_back((channel, payload.to_vec()));
+        Ok(())
+    }
+
+    fn disconnect(&mut self, connection: ConnectionId) {
+        if connection == self.remote {
+            self.link
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .closed = true;
+        }
+    }
+}
+
+impl Drop for MemoryTransport {
+    fn drop(&mut self) {
+        self.disconnect(self.remote);
+    }
+}
@@ -0,0 +1,245 @@
+use bevy_ecs::{
+    message::{Message, MessageWriter},
+    resource::Resource,
+    schedule::SystemSet,
+    system::ResMut,
+};
+use bevy_log::warn;
+use bevy_platform::collections::{HashMap, HashSet};
+
+use crate::{ChannelId, ConnectionId, Reliability, Transport, TransportError, TransportEvent};
+
+/// The connections of the app and the [`Transport`] they go through.
+///
+/// Insert a transport with [`Network::set_transport`] to start a server or connect to one. The
+/// transport is polled every update by the [`NetPlugin`](crate::NetPlugin), which reports what
+/// happened through the [`Connected`], [`Disconnected`] and [`NetMessage`] messages.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_net::{ChannelId, NetMessage, Network};
+/// fn echo(mut network: ResMut<Network>, mut messages: MessageReader<NetMessage>) {
+///     for message in messages.read() {
+///         let _ = network.send(message.connection, message.channel, &message.payload);
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(echo);
+/// ```
+#[derive(Resource)]
+pub struct Network {
+    transport: Option<Box<dyn Transport>>,
+    channels: HashMap<ChannelId, Reliability>,
+    connections: HashSet<ConnectionId>,
+    /// Connections closed by [`Network::close`], reported on the next update.
+    closed: Vec<ConnectionId>,
+    /// Connections that have not received the full replicated state yet.
+    pub(crate) new_connections: Vec<ConnectionId>,
+    /// Messages received on [`ChannelId::REPLICATION`], consumed by the replication systems.
+    pub(crate) replication_messages: Vec<(ConnectionId, Vec<u8>)>,
+    events: Vec<TransportEvent>,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        let mut channels = HashMap::default();
+        channels.insert(ChannelId::DEFAULT, Reliability::Reliable);
+        channels.insert(ChannelId::REPLICATION, Reliability::ReliableOrdered);
+        Self {
+            transport: None,
+            channels,
+            connections: HashSet::default(),
+            closed: Vec::new(),
+            new_connections: Vec::new(),
+            replication_messages: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+impl Network {
+    /// Starts using `transport`, closing all connections of the previous one.
+    pub fn set_transport(&mut self, transport: impl Transport) {
+        self.close();
+        self.transport = Some(Box::new(transport));
+    }
+
+    /// Closes all connections and removes the transport.
+    ///
+    /// A [`Disconnected`] message is written for each connection on the next update.
+    pub fn close(&mut self) {
+        if let Some(mut transport) = self.transport.take() {
+            for &connection in &self.connections {
+                transport.disconnect(connection);
+            }
+        }
+        self.closed.extend(self.connections.drain());
+        self.new_connections.clear();
+        self.replication_messages.clear();
+    }
+
+    /// Returns `true` if a transport has been set.
+    pub fn is_active(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    /// Returns the transport, if one has been set.
+    pub fn transport(&self) -> Option<&dyn Transport> {
+        self.transport.as_deref()
+    }
+
+    /// Returns the transport mutably, if one has been set.
+    pub fn transport_mut(&mut self) -> Option<&mut dyn Transport> {
+        self.transport.as_deref_mut()
+    }
+
+    /// Sets the [`Reliability`] of the messages sent on `channel`.
+    ///
+    /// Channels that have not been added are reliable. [`ChannelId::REPLICATION`] is reserved for
+    /// the replication layer.
+    pub fn add_channel(&mut self, channel: ChannelId, reliability: Reliability) -> &mut Self {
+        self.channels.insert(channel, reliability);
+        self
+    }
+
+    /// Returns the [`Reliability`] of the messages sent on `channel`.
+    pub fn channel_reliability(&self, channel: ChannelId) -> Reliability {
+        self.channels.get(&channel).copied().unwrap_or_default()
+    }
+
+    /// Returns the open connections.
+    pub fn connections(&self) -> impl Iterator<Item = ConnectionId> + '_ {
+        self.connections.iter().copied()
+    }
+
+    /// Returns `true` if `connection` is open.
+    pub fn is_connected(&self, connection: ConnectionId) -> bool {
+        self.connections.contains(&connection)
+    }
+
+    /// Sends `payload` on `channel` to `connection`, with the reliability of the channel.
+    pub fn send(
+        &mut self,
+        connection: ConnectionId,
+        channel: ChannelId,
+        payload: &[u8],
+    ) -> Result<(), TransportError> {
+        let reliability = self.channel_reliability(channel);
+        let Some(transport) = self.transport.as_mut() else {
+            return Err(TransportError::NotConnected(connection));
+        };
+        transport.send(connection, channel, reliability, payload)
+    }
+
+    /// Sends `payload` on `channel` to every open connection.
+    ///
+    /// Connections the message could not be sent to are logged and skipped.
+    pub fn broadcast(&mut self, channel: ChannelId, payload: &[u8]) {
+        let reliability = self.channel_reliability(channel);
+        let Some(transport) = self.transport.as_mut() else {
+            return;
+        };
+        for &connection in &self.connections {
+            if let Err(error) = transport.send(connection, channel, reliability, payload) {
+                warn!("Failed to send message to {connection}: {error}");
+            }
+        }
+    }
+
+    /// Closes `connection`.
+    ///
+    /// A [`Disconnected`] message is written for it on the next update.
+    pub fn disconnect(&mut self, connection: ConnectionId) {
+        if let Some(transport) = self.transport.as_mut() {
+            transport.disconnect(connection);
+        }
+    }
+}
+
+/// A connection to a remote peer has been established.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Connected {
+    /// The new connection.
+    pub connection: ConnectionId,
+}
+
+/// A connection has been closed.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Disconnected {
+    /// The closed connection.
+    pub connection: ConnectionId,
+}
+
+/// A message has been received from a remote peer.
+///
+/// Messages sent on [`ChannelId::REPLICATION`] are consumed by the replication layer and are not
+/// reported.
+#[derive(Message, Clone, Debug, PartialEq, Eq)]
+pub struct NetMessage {
+    /// The connection the message was received from.
+    pub connection: ConnectionId,
+    /// The channel the message was sent on.
+    pub channel: ChannelId,
+    /// The message.
+    pub payload: Vec<u8>,
+}
+
+/// The systems of the [`NetPlugin`](crate::NetPlugin).
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NetSystems {
+    /// Polls the transport and applies what was received, in [`PreUpdate`](bevy_app::PreUpdate).
+    Receive,
+    /// Sends the replicated state, in [`PostUpdate`](bevy_app::PostUpdate).
+    Send,
+}
+
+/// Polls the [`Network`] transport and writes the messages for what happened.
+pub fn receive_network_events(
+    mut network: ResMut<Network>,
+    mut connected: MessageWriter<Connected>,
+    mut disconnected: MessageWriter<Disconnected>,
+    mut messages: MessageWriter<NetMessage>,
+) {
+    let network = &mut *network;
+    for connection in network.closed.drain(..) {
+        disconnected.write(Disconnected { connection });
+    }
+    let Some(transport) = network.transport.as_mut() else {
+        return;
+    };
+    transport.poll(&mut network.events);
+
+    for event in network.events.drain(..) {
+        match event {
+            TransportEvent::Connected(connection) => {
+                if network.connections.insert(connection) {
+                    network.new_connections.push(connection);
+                    connected.write(Connected { connection });
+                }
+            }
+            TransportEvent::Disconnected(connection) => {
+                if network.connections.remove(&connection) {
+                    network.new_connections.retain(|&new| new != connection);
+                    disconnected.write(Disconnected { connection });
+                }
+            }
+            TransportEvent::Received {
+                connection,
+                channel: ChannelId::REPLICATION,
+                payload,
+            } => {
+                network.replication_messages.push((connection, payload));
+            }
+            TransportEvent::Received {
+                connection,
+                channel,
+                payload,
+            } => {
+                messages.write(NetMessage {
+                    connection,
+                    channel,
+                    payload,
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use core::any::TypeId;
+
+use bevy_app::App;
+use bevy_ecs::{
+    change_detection::Tick,
+    component::{Component, ComponentId},
+    entity::{Entity, EntityHashMap, EntityHashSet, SceneEntityMapper},
+    message::MessageReader,
+    query::{QueryState, With},
+    reflect::{AppTypeRegistry, ReflectComponent},
+    relationship::RelationshipHookMode,
+    resource::Resource,
+    system::{Commands, Local, Query, ResMut},
+    world::{EntityRef, EntityWorldMut, Mut, World},
+};
+use bevy_log::warn;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    std_traits::ReflectDefault,
+    GetTypeRegistration, Reflect, TypePath, TypeRegistry,
+};
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+
+use crate::{ChannelId, ConnectionId, Disconnected, Network};
+
+/// Marks an entity whose replicated components are sent to every connection.
+///
+/// Only the components registered with [`AppReplicationExt::replicate`] are sent, and the
+/// receiving peer only accepts those it registered as well. They are sent in full whenever they
+/// change, and all of them are sent to new connections, on the ordered
+/// [`ChannelId::REPLICATION`] so that changes are applied in the order they were made. Despawning the
+/// entity, or removing this component, despawns it on the remote peers. Removing a replicated
+/// component from the entity is not replicated.
+///
+/// Replication usually goes from the server to its clients, but it works in both directions:
+/// every peer sends the entities it marked with this component.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, Default, PartialEq, Clone)]
+pub struct Replicated;
+
+/// Added to the entities spawned by the replication of a [`Replicated`] entity of a remote peer.
+///
+/// These entities are despawned when their connection is closed.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+pub struct Remote {
+    /// The connection the entity is replicated from.
+    pub connection: ConnectionId,
+    /// The entity on the remote peer.
+    pub entity: Entity,
+}
+
+/// A component registered with [`AppReplicationExt::replicate`].
+#[derive(Clone, Copy, Debug)]
+struct ReplicatedComponent {
+    type_id: TypeId,
+    component_id: ComponentId,
+}
+
+/// The components that are replicated, see [`AppReplicationExt::replicate`].
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ReplicationRegistry {
+    components: Vec<ReplicatedComponent>,
+}
+
+impl ReplicationRegistry {
+    /// Returns `true` if the component of type `type_id` is replicated.
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.components
+            .iter()
+            .any(|component| component.type_id == type_id)
+    }
+
+    /// Returns the ids of the replicated components.
+    pub fn component_ids(&self) -> impl Iterator<Item = ComponentId> + '_ {
+        self.components
+            .iter()
+            .map(|component| component.component_id)
+    }
+}
+
+/// Maps the entities of every remote peer to the local entities replicating them.
+#[derive(Resource, Debug, Default)]
+pub struct ReplicatedEntities {
+    entities: HashMap<ConnectionId, EntityHashMap<Entity>>,
+}
+
+impl ReplicatedEntities {
+    /// Returns the local entity replicating `remote_entity` of `connection`, if any.
+    pub fn get(&self, connection: ConnectionId, remote_entity: Entity) -> Option<Entity> {
+        self.entities.get(&connection)?.get(&remote_entity).copied()
+    }
+}
+
+/// Extension trait for [`App`] to register replicated components.
+pub trait AppReplicationExt {
+    /// Replicates the component `C` of [`Replicated`] entities.
+    ///
+    /// The component is sent using reflection, so it is registered in the
+    /// [`AppTypeRegistry`] along with its [`ReflectComponent`] data. The entities it references
+    /// in fields marked with `#[entities]` are mapped to the local entities replicating them on
+    /// the receiving peer.
+    fn replicate<C>(&mut self) -> &mut Self
+    where
+        C: Component + Reflect + TypePath + GetTypeRegistration;
+}
+
+impl AppReplicationExt for App {
+    fn replicate<C>(&mut self) -> &mut Self
+    where
+        C: Component + Reflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<C>()
+            .register_type_data::<C, ReflectComponent>();
+
+        let component_id = self.world_mut().register_component::<C>();
+        let mut registry = self
+            .world_mut()
+            .get_resource_or_init::<ReplicationRegistry>();
+        if !registry.contains(TypeId::of::<C>()) {
+            registry.components.push(ReplicatedComponent {
+                type_id: TypeId::of::<C>(),
+                component_id,
+            });
+        }
+        self
+    }
+}
+
+/// A message sent on [`ChannelId::REPLICATION`].
+#[derive(Serialize, Deserialize)]
+enum ReplicationMessage {
+    /// Inserts or updates components of an entity.
+    Update {
+        entity: u64,
+        /// The type paths of the components, with their reflected value.
+        components: Vec<(String, Vec<u8>)>,
+    },
+    /// Despawns an entity.
+    Despawn { entity: u64 },
+}
+
+/// Serializes the replicated components of `entity`, or only the changed ones unless `full`.
+fn serialize_components(
+    entity: EntityRef,
+    replication: &ReplicationRegistry,
+    registry: &TypeRegistry,
+    full: bool,
+    last_run: Tick,
+    this_run: Tick,
+) -> Vec<(String, Vec<u8>)> {
+    let mut components = Vec::new();
+    for component in &replication.components {
+        let Some(ticks) = entity.get_change_ticks_by_id(component.component_id) else {
+            continue;
+        };
+        if !full && !ticks.is_changed(last_run, this_run) {
+            continue;
+        }
+        let Some(registration) = registry.get(component.type_id) else {
+            continue;
+        };
+        let Some(value) = registration
+            .data::<ReflectComponent>()
+            .and_then(|reflect_component| reflect_component.reflect(entity))
+        else {
+            continue;
+        };
+        let serializer = TypedReflectSerializer::new(value.as_partial_reflect(), registry);
+        match postcard::to_allocvec(&serializer) {
+            Ok(bytes) => components.push((registration.type_info().type_path().to_owned(), bytes)),
+            Err(error) => warn!(
+                "Failed to serialize replicated component {}: {error}",
+                registration.type_info().type_path()
+            ),
+        }
+    }
+    components
+}
+
+/// Sends the replicated components of [`Replicated`] entities that changed since the last run,
+/// and all of them to new connections.
+pub fn send_replication(
+    world: &mut World,
+    query: &mut QueryState<EntityRef<'static>, With<Replicated>>,
+    mut replicated: Local<EntityHashSet>,
+) {
+    if !world
+        .get_resource::<Network>()
+        .is_some_and(|network| network.is_active() && network.connections().next().is_some())
+    {
+        replicated.clear();
+        return;
+    }
+    let Some(replication) = world.get_resource::<ReplicationRegistry>().cloned() else {
+        return;
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let last_run = world.last_change_tick();
+    let this_run = world.read_change_tick();
+
+    let mut changes = Vec::new();
+    let mut snapshot = Vec::new();
+    let mut despawned = core::mem::take(&mut *replicated);
+    let has_new_connections = !world.resource::<Network>().new_connections.is_empty();
+
+    for entity in query.iter(world) {
+        let is_new = !despawned.remove(&entity.id());
+        replicated.insert(entity.id());
+
+        let components =
+            serialize_components(entity, &replication, &registry, is_new, last_run, this_run);
+        if !components.is_empty() {
+            changes.push(ReplicationMessage::Update {
+                entity: entity.id().to_bits(),
+                components,
+            });
+        }
+        if has_new_connections {
+            snapshot.push(ReplicationMessage::Update {
+                entity: entity.id().to_bits(),
+                components: serialize_components(
+                    entity,
+                    &replication,
+                    &registry,
+                    true,
+                    last_run,
+                    this_run,
+                ),
+            });
+        }
+    }
+    changes.extend(despawned.iter().map(|entity| ReplicationMessage::Despawn {
+        entity: entity.to_bits(),
+    }));
+
+    let mut network = world.resource_mut::<Network>();
+    let new_connections = core::mem::take(&mut network.new_connections);
+    let connections: Vec<_> = network
+        .connections()
+        .filter(|connection| !new_connections.contains(connection))
+        .collect();
+    let mut send = |message: &ReplicationMessage, connections: &[ConnectionId]| {
+        let bytes = match postcard::to_allocvec(message) {
+            Ok(bytes) => bytes,
+            Err(error) => {
+                warn!("Failed to serialize replication message: {error}");
+                return;
+            }
+        };
+        for &connection in connections {
+            if let Err(error) = network.send(connection, ChannelId::REPLICATION, &bytes) {
+                warn!("Failed to send replication message to {connection}: {error}");
+            }
+        }
+    };
+    for message in &changes {
+        send(message, &connections);
+    }
+    for message in &snapshot {
+        send(message, &new_connections);
+    }
+}
+
+/// Applies the replication messages received from remote peers.
+pub fn apply_replication(world: &mut World) {
+    let messages = match world.get_resource_mut::<Network>() {
+        Some(mut network) => core::mem::take(&mut network.replication_messages),
+        None => return,
+    };
+    if messages.is_empty() {
+        return;
+    }
+    let messages: Vec<_> = messages
+        .into_iter()
+        .filter_map(|(connection, bytes)| {
+            match postcard::from_bytes::<ReplicationMessage>(&bytes) {
+                Ok(message) => Some((connection, message)),
+                Err(error) => {
+                    warn!("Received an invalid replication message from {connection}: {error}");
+                    None
+                }
+            }
+        })
+        .collect();
+    let Some(replication) = world.get_resource::<ReplicationRegistry>().cloned() else {
+        return;
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    world.resource_scope(|world, mut replicated: Mut<ReplicatedEntities>| {
+        // Spawn the updated entities first, so that components referencing other entities of the
+        // same batch are mapped to them.
+        for (connection, message) in &messages {
+            let ReplicationMessage::Update { entity, .. } = message else {
+                continue;
+            };
+            let Some(remote_entity) = Entity::try_from_bits(*entity) else {
+                continue;
+            };
+            let entities = replicated.entities.entry(*connection).or_default();
+            // References to entities that were not replicated yet are mapped to dead entities.
+            if entities
+                .get(&remote_entity)
+                .is_none_or(|&local_entity| world.get_entity(local_entity).is_err())
+            {
+                let local_entity = world
+                    .spawn(Remote {
+                        connection: *connection,
+                        entity: remote_entity,
+                    })
+                    .id();
+                entities.insert(remote_entity, local_entity);
+            }
+        }
+
+        for (connection, message) in messages {
+            let entities = replicated.entities.entry(connection).or_default();
+            match message {
+                ReplicationMessage::Update { entity, components } => {
+                    let Some(local_entity) = Entity::try_from_bits(entity)
+                        .and_then(|remote_entity| entities.get(&remote_entity).copied())
+                    else {
+                        continue;
+                    };
+                    SceneEntityMapper::world_scope(entities, world, |world, mapper| {
+                        if let Ok(mut entity) = world.get_entity_mut(local_entity) {
+                            apply_components(
+                                &mut entity,
+                                components,
+                                &replication,
+                                &registry,
+                                mapper,
+                            );
+                        }
+                    });
+                }
+                ReplicationMessage::Despawn { entity } => {
+                    if let Some(local_entity) = Entity::try_from_bits(entity)
+                        .and_then(|remote_entity| entities.remove(&remote_entity))
+                    {
+                        world.try_despawn(local_entity).ok();
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Deserializes the replicated `components` and inserts them into `entity`.
+///
+/// Components that are not replicated by this app are rejected, so that remote peers cannot
+/// insert any reflected component.
+fn apply_components(
+    entity: &mut EntityWorldMut,
+    components: Vec<(String, Vec<u8>)>,
+    replication: &ReplicationRegistry,
+    registry: &TypeRegistry,
+    mapper: &mut SceneEntityMapper,
+) {
+    for (type_path, bytes) in components {
+        let Some((registration, reflect_component)) = registry
+            .get_with_type_path(&type_path)
+            .filter(|registration| replication.contains(registration.type_id()))
+            .and_then(|registration| {
+                Some((registration, registration.data::<ReflectComponent>()?))
+            })
+        else {
+            warn!("Received unregistered replicated component {type_path}");
+            continue;
+        };
+        let value = match TypedReflectDeserializer::new(registration, registry)
+            .deserialize(&mut postcard::Deserializer::from_bytes(&bytes))
+        {
+            Ok(value) => value,
+            Err(error) => {
+                warn!("Failed to deserialize replicated component {type_path}: {error}");
+                continue;
+            }
+        };
+        reflect_component.apply_or_insert_mapped(
+            entity,
+            value.as_ref(),
+            registry,
+            mapper,
+            RelationshipHookMode::Run,
+        );
+    }
+}
+
+/// Despawns the entities replicated from connections that have been closed.
+pub fn despawn_disconnected_entities(
+    mut disconnected: MessageReader<Disconnected>,
+    mut replicated: ResMut<ReplicatedEntities>,
+    remotes: Query<(), With<Remote>>,
+    mut commands: Commands,
+) {
+    for &Disconnected { connection } in disconnected.read() {
+        let Some(entities) = replicated.entities.remove(&connection) else {
+            continue;
+        };
+        for entity in entities.values().copied() {
+            if remotes.contains(entity) {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
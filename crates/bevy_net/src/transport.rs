@@ -0,0 +1,132 @@
+use core::fmt;
+
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Identifies a connection to a remote peer.
+///
+/// Connection ids are assigned by the [`Transport`] and are only unique within it. Clients
+/// usually have a single connection to the server, [`ConnectionId::SERVER`].
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Reflect,
+)]
+#[reflect(Debug, PartialEq, Hash, Clone)]
+pub struct ConnectionId(pub u64);
+
+impl ConnectionId {
+    /// The connection a client has to its server.
+    pub const SERVER: Self = Self(0);
+}
+
+impl fmt::Display for ConnectionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "connection {}", self.0)
+    }
+}
+
+/// Identifies one of the channels messages are sent on, see [`Network::add_channel`].
+///
+/// [`Network::add_channel`]: crate::Network::add_channel
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Reflect,
+)]
+#[reflect(Debug, PartialEq, Hash, Clone)]
+pub struct ChannelId(pub u8);
+
+impl ChannelId {
+    /// The reliable channel used by default, see [`Network::send`](crate::Network::send).
+    pub const DEFAULT: Self = Self(0);
+
+    /// The reliable and ordered channel used by the replication layer.
+    pub const REPLICATION: Self = Self(u8::MAX);
+}
+
+/// The delivery guarantees of a channel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash, Clone)]
+pub enum Reliability {
+    /// Messages arrive at most once, and may be dropped or arrive out of order.
+    ///
+    /// Use this for data that is quickly outdated, like positions that are sent every tick.
+    Unreliable,
+    /// Messages arrive exactly once as long as the connection stays open, but may arrive out
+    /// of order.
+    #[default]
+    Reliable,
+    /// Messages arrive exactly once as long as the connection stays open, in the order they
+    /// were sent on the channel.
+    ///
+    /// Use this for messages that depend on the previous ones, like changes to a state. A lost
+    /// message delays those sent after it on the same channel until it has been sent again.
+    ReliableOrdered,
+}
+
+/// Something that happened on a [`Transport`] since it was last polled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransportEvent {
+    /// A connection to a remote peer has been established.
+    Connected(ConnectionId),
+    /// A connection has been closed, either locally, by the remote peer, or because it timed out.
+    Disconnected(ConnectionId),
+    /// A message has been received.
+    Received {
+        /// The connection the message was received from.
+        connection: ConnectionId,
+        /// The channel the message was sent on.
+        channel: ChannelId,
+        /// The message.
+        payload: Vec<u8>,
+    },
+}
+
+/// An error returned by a [`Transport`].
+#[derive(Debug, Error)]
+pub enum TransportError {
+    /// The connection does not exist or has been closed.
+    #[error("{0} is not connected")]
+    NotConnected(ConnectionId),
+    /// The message is larger than the transport can send in one piece.
+    #[error("the message of {size} bytes exceeds the maximum size of {max} bytes")]
+    MessageTooLarge {
+        /// The size of the message.
+        size: usize,
+        /// The maximum message size supported by the transport.
+        max: usize,
+    },
+    /// The underlying IO failed.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// A way of exchanging messages with remote peers, such as a UDP socket.
+///
+/// Transports only move bytes around: they establish and track connections, and deliver
+/// messages with the [`Reliability`] requested by the caller. Everything else, like serializing
+/// messages or replicating components, is built on top of them by [`Network`](crate::Network).
+///
+/// Bevy provides a `UdpTransport` for native platforms, a `WebSocketTransport` for web clients,
+/// and an in-process [`MemoryTransport`](crate::MemoryTransport) that is useful for tests and
+/// local play. Other transports, for example WebTransport based ones or WebSocket servers, can
+/// be provided by implementing this trait.
+pub trait Transport: Send + Sync + 'static {
+    /// Processes incoming data and timers, and pushes everything that happened since the last
+    /// call to `events`.
+    ///
+    /// This is called once per update by the [`NetPlugin`](crate::NetPlugin).
+    fn poll(&mut self, events: &mut Vec<TransportEvent>);
+
+    /// Sends `payload` on `channel` to the remote peer of `connection`.
+    fn send(
+        &mut self,
+        connection: ConnectionId,
+        channel: ChannelId,
+        reliability: Reliability,
+        payload: &[u8],
+    ) -> Result<(), TransportError>;
+
+    /// Closes `connection`.
+    ///
+    /// A [`TransportEvent::Disconnected`] is reported for the connection on the next poll.
+    fn disconnect(&mut self, connection: ConnectionId);
+}
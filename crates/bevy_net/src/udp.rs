@@ -0,0 +1,831 @@
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+use std::{
+    collections::HashSet,
+    io::{self, ErrorKind},
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+};
+
+use bevy_log::warn;
+use bevy_platform::{collections::HashMap, time::Instant};
+
+use crate::{ChannelId, ConnectionId, Reliability, Transport, TransportError, TransportEvent};
+
+/// The largest packet sent by the [`UdpTransport`], chosen to stay below the MTU of common
+/// networks so that packets are not fragmented.
+const MAX_PACKET_SIZE: usize = 1200;
+
+/// The size of the header of unreliable messages.
+const UNRELIABLE_HEADER_SIZE: usize = 2;
+
+/// The size of the header of reliable messages, see [`ReliableHeader`].
+const RELIABLE_HEADER_SIZE: usize = 14;
+
+/// The largest fragment of a reliable message sent in one packet.
+const MAX_FRAGMENT_SIZE: usize = MAX_PACKET_SIZE - RELIABLE_HEADER_SIZE;
+
+/// The largest number of fragments a reliable message is split into.
+const MAX_FRAGMENTS: usize = 1024;
+
+/// The number of received reliable sequence numbers remembered to discard duplicates.
+const RECEIVED_WINDOW: u32 = 4096;
+
+mod packet {
+    pub const CONNECT: u8 = 0;
+    pub const ACCEPT: u8 = 1;
+    pub const DISCONNECT: u8 = 2;
+    pub const UNRELIABLE: u8 = 3;
+    pub const RELIABLE: u8 = 4;
+    pub const ACK: u8 = 5;
+    pub const HEARTBEAT: u8 = 6;
+    pub const RELIABLE_ORDERED: u8 = 7;
+}
+
+/// The header of the packets of reliable messages, following their kind.
+///
+/// Messages larger than a packet are split into fragments with consecutive sequence numbers, so
+/// the first sequence number of a message is the one of any fragment minus its index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ReliableHeader {
+    channel: ChannelId,
+    sequence: u32,
+    /// The index of the message among those sent on an ordered channel, `0` otherwise.
+    order: u32,
+    fragment: u16,
+    fragment_count: u16,
+}
+
+impl ReliableHeader {
+    fn write(&self, kind: u8, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&[kind, self.channel.0]);
+        bytes.extend_from_slice(&self.sequence.to_le_bytes());
+        bytes.extend_from_slice(&self.order.to_le_bytes());
+        bytes.extend_from_slice(&self.fragment.to_le_bytes());
+        bytes.extend_from_slice(&self.fragment_count.to_le_bytes());
+    }
+
+    /// Reads the header of a packet, returning `None` if it is malformed.
+    fn read(packet: &[u8]) -> Option<Self> {
+        let &[_, channel, s0, s1, s2, s3, o0, o1, o2, o3, f0, f1, c0, c1] =
+            packet.get(..RELIABLE_HEADER_SIZE)?
+        else {
+            return None;
+        };
+        let header = Self {
+            channel: ChannelId(channel),
+            sequence: u32::from_le_bytes([s0, s1, s2, s3]),
+            order: u32::from_le_bytes([o0, o1, o2, o3]),
+            fragment: u16::from_le_bytes([f0, f1]),
+            fragment_count: u16::from_le_bytes([c0, c1]),
+        };
+        (header.fragment < header.fragment_count
+            && usize::from(header.fragment_count) <= MAX_FRAGMENTS)
+            .then_some(header)
+    }
+
+    /// Returns the sequence number of the first fragment of the message.
+    fn first_sequence(&self) -> u32 {
+        self.sequence.wrapping_sub(u32::from(self.fragment))
+    }
+}
+
+/// Timing configuration of a [`UdpTransport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UdpTransportConfig {
+    /// The time after which a connection that has not received anything is closed.
+    ///
+    /// This is also how long a client waits for the server to accept its connection.
+    pub timeout: Duration,
+    /// The time after which an empty packet is sent on an idle connection, to keep it from
+    /// timing out.
+    pub heartbeat_interval: Duration,
+    /// The time after which a reliable message, or a connection request, that has not been
+    /// acknowledged yet is sent again.
+    pub resend_interval: Duration,
+    /// The largest number of clients a server accepts at once.
+    ///
+    /// The connection requests of other clients are rejected, which closes their connection.
+    pub max_connections: usize,
+}
+
+impl Default for UdpTransportConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            heartbeat_interval: Duration::from_secs(1),
+            resend_interval: Duration::from_millis(200),
+            max_connections: 64,
+        }
+    }
+}
+
+enum Role {
+    Server { next_id: u64 },
+    Client,
+}
+
+enum ConnectionState {
+    /// The client is waiting for the server to accept its connection.
+    Connecting {
+        started: Instant,
+        last_attempt: Instant,
+    },
+    Connected,
+}
+
+struct UdpConnection {
+    address: SocketAddr,
+    state: ConnectionState,
+    last_received: Instant,
+    last_sent: Instant,
+    next_sequence: u32,
+    /// Reliable packets that have not been acknowledged yet, with the time they were last sent.
+    unacked: BTreeMap<u32, (Instant, Vec<u8>)>,
+    /// The highest reliable sequence number received so far.
+    highest_received: Option<u32>,
+    /// The reliable sequence numbers received within [`RECEIVED_WINDOW`] of the highest one.
+    received: HashSet<u32>,
+    /// The fragments received of the reliable messages that are not complete yet, by the
+    /// sequence number of their first fragment.
+    fragments: HashMap<u32, FragmentedMessage>,
+    /// The index of the next message sent on each ordered channel.
+    next_order: HashMap<ChannelId, u32>,
+    /// The messages received on each ordered channel.
+    ordered: HashMap<ChannelId, OrderedChannel>,
+}
+
+/// A reliable message split into fragments, some of which have not been received yet.
+struct FragmentedMessage {
+    fragments: Vec<Option<Vec<u8>>>,
+    missing: usize,
+}
+
+/// The messages received on an ordered channel.
+#[derive(Default)]
+struct OrderedChannel {
+    /// The index of the next message to deliver.
+    next: u32,
+    /// The messages received before one that precedes them, by index.
+    pending: HashMap<u32, Vec<u8>>,
+}
+
+impl UdpConnection {
+    fn new(address: SocketAddr, state: ConnectionState, now: Instant) -> Self {
+        Self {
+            address,
+            state,
+            last_received: now,
+            last_sent: now,
+            next_sequence: 0,
+            unacked: BTreeMap::new(),
+            highest_received: None,
+            received: HashSet::new(),
+            fragments: HashMap::default(),
+            next_order: HashMap::default(),
+            ordered: HashMap::default(),
+        }
+    }
+
+    /// Records a received reliable sequence number, returning `false` if it is a duplicate.
+    fn receive_sequence(&mut self, sequence: u32) -> bool {
+        let highest = self.highest_received.get_or_insert(sequence);
+        if sequence.wrapping_sub(*highest) < u32::MAX / 2 {
+            *highest = sequence;
+        }
+        let highest = *highest;
+        if highest.wrapping_sub(sequence) >= RECEIVED_WINDOW {
+            // Too old to tell whether it is a duplicate, so it must have been seen already.
+            return false;
+        }
+        if !self.received.insert(sequence) {
+            return false;
+        }
+        if self.received.len() > RECEIVED_WINDOW as usize {
+            self.received
+                .retain(|&received| highest.wrapping_sub(received) < RECEIVED_WINDOW);
+        }
+        true
+    }
+
+    /// Handles a new fragment of a reliable message, pushing the messages it completes to
+    /// `events`. Messages on ordered channels are held back until the ones sent before them have
+    /// been delivered.
+    fn receive_reliable(
+        &mut self,
+        id: ConnectionId,
+        header: ReliableHeader,
+        ordered: bool,
+        payload: &[u8],
+        events: &mut Vec<TransportEvent>,
+    ) {
+        let Some(payload) = self.reassemble(header, payload) else {
+            return;
+        };
+        if !ordered {
+            events.push(TransportEvent::Received {
+                connection: id,
+                channel: header.channel,
+                payload,
+            });
+            return;
+        }
+
+        let channel = self.ordered.entry(header.channel).or_default();
+        if header.order.wrapping_sub(channel.next) >= RECEIVED_WINDOW {
+            // Already delivered, or too far ahead to be legitimate.
+            return;
+        }
+        channel.pending.insert(header.order, payload);
+        while let Some(payload) = channel.pending.remove(&channel.next) {
+            channel.next = channel.next.wrapping_add(1);
+            events.push(TransportEvent::Received {
+                connection: id,
+                channel: header.channel,
+                payload,
+            });
+        }
+    }
+
+    /// Stores a fragment, returning the whole message once all its fragments have been received.
+    fn reassemble(&mut self, header: ReliableHeader, payload: &[u8]) -> Option<Vec<u8>> {
+        if header.fragment_count == 1 {
+            return Some(payload.to_vec());
+        }
+        let first = header.first_sequence();
+        if let Some(highest) = self.highest_received {
+            // The messages whose first fragment left the window can no longer be completed.
+            self.fragments
+                .retain(|&first, _| highest.wrapping_sub(first) < RECEIVED_WINDOW);
+        }
+
+        let count = usize::from(header.fragment_count);
+        let message = self
+            .fragments
+            .entry(first)
+            .or_insert_with(|| FragmentedMessage {
+                fragments: vec![None; count],
+                missing: count,
+            });
+        if message.fragments.len() != count {
+            // The fragments disagree on the size of the message, so it cannot be reassembled.
+            return None;
+        }
+        let fragment = message.fragments.get_mut(usize::from(header.fragment))?;
+        if fragment.is_none() {
+            *fragment = Some(payload.to_vec());
+            message.missing -= 1;
+        }
+        if message.missing > 0 {
+            return None;
+        }
+        let message = self.fragments.remove(&first)?;
+        Some(message.fragments.into_iter().flatten().flatten().collect())
+    }
+}
+
+/// A [`Transport`] sending messages over UDP.
+///
+/// A server is created with [`UdpTransport::bind`] and accepts up to
+/// [`max_connections`](UdpTransportConfig::max_connections) clients, which connect with
+/// [`UdpTransport::connect`]. Reliable messages are acknowledged by the receiver and sent again
+/// until they are, and idle connections are kept alive with heartbeats.
+///
+/// Reliable messages larger than a packet are split into fragments, which are reassembled by the
+/// receiver, up to [`UdpTransport::MAX_MESSAGE_SIZE`] bytes. Unreliable messages are not
+/// fragmented, so they must fit in a single packet of at most
+/// [`UdpTransport::MAX_UNRELIABLE_MESSAGE_SIZE`] bytes. The protocol is neither encrypted nor
+/// authenticated.
+///
+/// ```no_run
+/// # use bevy_net::UdpTransport;
+/// let server = UdpTransport::bind("0.0.0.0:5000").unwrap();
+/// let client = UdpTransport::connect("127.0.0.1:5000").unwrap();
+/// ```
+pub struct UdpTransport {
+    socket: UdpSocket,
+    role: Role,
+    config: UdpTransportConfig,
+    connections: HashMap<ConnectionId, UdpConnection>,
+    addresses: HashMap<SocketAddr, ConnectionId>,
+    /// Events that happened outside of [`Transport::poll`], reported on the next poll.
+    pending_events: Vec<TransportEvent>,
+    buffer: Vec<u8>,
+}
+
+impl UdpTransport {
+    /// The largest reliable message that can be sent.
+    pub const MAX_MESSAGE_SIZE: usize = MAX_FRAGMENTS * MAX_FRAGMENT_SIZE;
+
+    /// The largest unreliable message that can be sent.
+    pub const MAX_UNRELIABLE_MESSAGE_SIZE: usize = MAX_PACKET_SIZE - UNRELIABLE_HEADER_SIZE;
+
+    /// Creates a server listening for clients on `address`.
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        Self::new(UdpSocket::bind(address)?, Role::Server { next_id: 1 })
+    }
+
+    /// Creates a client connecting to the server at `server_address`.
+    ///
+    /// The connection is established asynchronously: a [`TransportEvent::Connected`] for
+    /// [`ConnectionId::SERVER`] is reported once the server has accepted it, or a
+    /// [`TransportEvent::Disconnected`] if it did not respond within the
+    /// [timeout](UdpTransportConfig::timeout).
+    pub fn connect(server_address: impl ToSocketAddrs) -> io::Result<Self> {
+        let server_address = server_address
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "no address to connect to"))?;
+        let local_address: SocketAddr = if server_address.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        }
+        .parse()
+        .expect("the address is valid");
+
+        let mut transport = Self::new(UdpSocket::bind(local_address)?, Role::Client)?;
+        let now = Instant::now();
+        transport.add_connection(
+            ConnectionId::SERVER,
+            UdpConnection::new(
+                server_address,
+                ConnectionState::Connecting {
+                    started: now,
+                    last_attempt: now,
+                },
+                now,
+            ),
+        );
+        transport.send_packet(server_address, &[packet::CONNECT]);
+        Ok(transport)
+    }
+
+    fn new(socket: UdpSocket, role: Role) -> io::Result<Self> {
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket,
+            role,
+            config: UdpTransportConfig::default(),
+            connections: HashMap::default(),
+            addresses: HashMap::default(),
+            pending_events: Vec::new(),
+            buffer: vec![0; MAX_PACKET_SIZE],
+        })
+    }
+
+    /// Sets the timing configuration of the transport.
+    pub fn with_config(mut self, config: UdpTransportConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns the timing configuration of the transport.
+    pub fn config(&self) -> &UdpTransportConfig {
+        &self.config
+    }
+
+    /// Returns the local address of the socket.
+    ///
+    /// This is useful to find out the port picked by the operating system when binding to
+    /// port `0`.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Returns the remote address of `connection`, if it exists.
+    pub fn remote_addr(&self, connection: ConnectionId) -> Option<SocketAddr> {
+        self.connections
+            .get(&connection)
+            .map(|connection| connection.address)
+    }
+
+    fn add_connection(&mut self, id: ConnectionId, connection: UdpConnection) {
+        self.addresses.insert(connection.address, id);
+        self.connections.insert(id, connection);
+    }
+
+    fn remove_connection(&mut self, id: ConnectionId) -> Option<UdpConnection> {
+        let connection = self.connections.remove(&id)?;
+        self.addresses.remove(&connection.address);
+        Some(connection)
+    }
+
+    fn send_packet(&self, address: SocketAddr, packet: &[u8]) {
+        match self.socket.send_to(packet, address) {
+            Ok(_) => {}
+            // The packet is lost, which the protocol has to deal with anyway.
+            Err(error) if error.kind() == ErrorKind::WouldBlock => {}
+            Err(error) => warn!("Failed to send UDP packet to {address}: {error}"),
+        }
+    }
+
+    fn send_to_connection(&mut self, id: ConnectionId, packet: &[u8]) {
+        if let Some(connection) = self.connections.get_mut(&id) {
+            connection.last_sent = Instant::now();
+            let address = connection.address;
+            self.send_packet(address, packet);
+        }
+    }
+
+    fn handle_packet(&mut self, address: SocketAddr, len: usize, events: &mut Vec<TransportEvent>) {
+        if len == 0 {
+            return;
+        }
+        let kind = self.buffer[0];
+        let now = Instant::now();
+        let id = self.addresses.get(&address).copied();
+
+        if kind == packet::CONNECT {
+            let Role::Server { next_id } = &mut self.role else {
+                return;
+            };
+            let id = match id {
+                Some(id) => id,
+                None if self.connections.len() >= self.config.max_connections => {
+                    self.send_packet(address, &[packet::DISCONNECT]);
+                    return;
+                }
+                None => {
+                    let id = ConnectionId(*next_id);
+                    *next_id += 1;
+                    self.add_connection(
+                        id,
+                        UdpConnection::new(address, ConnectionState::Connected, now),
+                    );
+                    events.push(TransportEvent::Connected(id));
+                    id
+                }
+            };
+            // The previous `ACCEPT` may have been lost, so always respond.
+            self.send_to_connection(id, &[packet::ACCEPT]);
+            return;
+        }
+
+        let Some(id) = id else {
+            return;
+        };
+        let Some(connection) = self.connections.get_mut(&id) else {
+            return;
+        };
+        connection.last_received = now;
+
+        if kind == packet::DISCONNECT {
+            // Also sent by servers rejecting a connection request.
+            self.remove_connection(id);
+            events.push(TransportEvent::Disconnected(id));
+            return;
+        }
+        if kind == packet::ACCEPT {
+            if matches!(connection.state, ConnectionState::Connecting { .. }) {
+                connection.state = ConnectionState::Connected;
+                events.push(TransportEvent::Connected(id));
+            }
+            return;
+        }
+        if matches!(connection.state, ConnectionState::Connecting { .. }) {
+            return;
+        }
+
+        let data = &self.buffer[..len];
+        match kind {
+            packet::UNRELIABLE if len >= UNRELIABLE_HEADER_SIZE => {
+                events.push(TransportEvent::Received {
+                    connection: id,
+                    channel: ChannelId(data[1]),
+                    payload: data[UNRELIABLE_HEADER_SIZE..].to_vec(),
+                });
+            }
+            packet::RELIABLE | packet::RELIABLE_ORDERED => {
+                let Some(header) = ReliableHeader::read(data) else {
+                    return;
+                };
+                if connection.receive_sequence(header.sequence) {
+                    connection.receive_reliable(
+                        id,
+                        header,
+                        kind == packet::RELIABLE_ORDERED,
+                        &data[RELIABLE_HEADER_SIZE..],
+                        events,
+                    );
+                }
+
+                // Acknowledge duplicates as well, as the previous acknowledgement may have been
+                // lost.
+                let mut ack = [packet::ACK, 0, 0, 0, 0];
+                ack[1..].copy_from_slice(&header.sequence.to_le_bytes());
+                self.send_to_connection(id, &ack);
+            }
+            packet::ACK if len >= 5 => {
+                let sequence = u32::from_le_bytes([data[1], data[2], data[3], data[4]]);
+                connection.unacked.remove(&sequence);
+            }
+            // Heartbeats only keep the connection alive, and malformed packets are ignored.
+            _ => {}
+        }
+    }
+
+    fn update_connections(&mut self, events: &mut Vec<TransportEvent>) {
+        let now = Instant::now();
+        let config = self.config;
+        let mut timed_out = Vec::new();
+        let mut outgoing = Vec::new();
+
+        for (&id, connection) in &mut self.connections {
+            match &mut connection.state {
+                ConnectionState::Connecting {
+                    started,
+                    last_attempt,
+                } => {
+                    if now - *started > config.timeout {
+                        timed_out.push(id);
+                    } else if now - *last_attempt > config.resend_interval {
+                        *last_attempt = now;
+                        outgoing.push((connection.address, vec![packet::CONNECT]));
+                    }
+                }
+                ConnectionState::Connected => {
+                    if now - connection.last_received > config.timeout {
+                        timed_out.push(id);
+                        continue;
+                    }
+                    for (last_sent, bytes) in connection.unacked.values_mut() {
+                        if now - *last_sent > config.resend_interval {
+                            *last_sent = now;
+                            connection.last_sent = now;
+                            outgoing.push((connection.address, bytes.clone()));
+                        }
+                    }
+                    if now - connection.last_sent > config.heartbeat_interval {
+                        connection.last_sent = now;
+                        outgoing.push((connection.address, vec![packet::HEARTBEAT]));
+                    }
+                }
+            }
+        }
+
+        for (address, bytes) in outgoing {
+            self.send_packet(address, &bytes);
+        }
+        for id in timed_out {
+            self.remove_connection(id);
+            events.push(TransportEvent::Disconnected(id));
+        }
+    }
+}
+
+impl Transport for UdpTransport {
+    fn poll(&mut self, events: &mut Vec<TransportEvent>) {
+        events.append(&mut self.pending_events);
+
+        loop {
+            match self.socket.recv_from(&mut self.buffer) {
+                Ok((len, address)) => self.handle_packet(address, len, events),
+                Err(error) if error.kind() == ErrorKind::WouldBlock => break,
+                // Reported on some platforms when a previous packet could not be delivered.
+                Err(error) if error.kind() == ErrorKind::ConnectionReset => continue,
+                Err(error) => {
+                    warn!("Failed to receive UDP packet: {error}");
+                    break;
+                }
+            }
+        }
+
+        self.update_connections(events);
+    }
+
+    fn send(
+        &mut self,
+        connection: ConnectionId,
+        channel: ChannelId,
+        reliability: Reliability,
+        payload: &[u8],
+    ) -> Result<(), TransportError> {
+        let max = match reliability {
+            Reliability::Unreliable => Self::MAX_UNRELIABLE_MESSAGE_SIZE,
+            Reliability::Reliable | Reliability::ReliableOrdered => Self::MAX_MESSAGE_SIZE,
+        };
+        if payload.len() > max {
+            return Err(TransportError::MessageTooLarge {
+                size: payload.len(),
+                max,
+            });
+        }
+        let Some(udp_connection) = self
+            .connections
+            .get_mut(&connection)
+            .filter(|connection| matches!(connection.state, ConnectionState::Connected))
+        else {
+            return Err(TransportError::NotConnected(connection));
+        };
+
+        let (kind, order) = match reliability {
+            Reliability::Unreliable => {
+                let mut bytes = Vec::with_capacity(UNRELIABLE_HEADER_SIZE + payload.len());
+                bytes.extend_from_slice(&[packet::UNRELIABLE, channel.0]);
+                bytes.extend_from_slice(payload);
+                self.send_to_connection(connection, &bytes);
+                return Ok(());
+            }
+            Reliability::Reliable => (packet::RELIABLE, 0),
+            Reliability::ReliableOrdered => {
+                let next_order = udp_connection.next_order.entry(channel).or_default();
+                let order = *next_order;
+                *next_order = order.wrapping_add(1);
+                (packet::RELIABLE_ORDERED, order)
+            }
+        };
+
+        // Empty messages are still sent as a single empty fragment.
+        let fragments: Vec<&[u8]> = if payload.is_empty() {
+            vec![payload]
+        } else {
+            payload.chunks(MAX_FRAGMENT_SIZE).collect()
+        };
+        let fragment_count = fragments.len() as u16;
+        let now = Instant::now();
+        let mut packets = Vec::with_capacity(fragments.len());
+        for (fragment, chunk) in fragments.into_iter().enumerate() {
+            let sequence = udp_connection.next_sequence;
+            udp_connection.next_sequence = sequence.wrapping_add(1);
+            let mut bytes = Vec::with_capacity(RELIABLE_HEADER_SIZE + chunk.len());
+            ReliableHeader {
+                channel,
+                sequence,
+                order,
+                fragment: fragment as u16,
+                fragment_count,
+            }
+            .write(kind, &mut bytes);
+            bytes.extend_from_slice(chunk);
+            udp_connection
+                .unacked
+                .insert(sequence, (now, bytes.clone()));
+            packets.push(bytes);
+        }
+
+        for bytes in packets {
+            self.send_to_connection(connection, &bytes);
+        }
+        Ok(())
+    }
+
+    fn disconnect(&mut self, connection: ConnectionId) {
+        if let Some(udp_connection) = self.remove_connection(connection) {
+            self.send_packet(udp_connection.address, &[packet::DISCONNECT]);
+            self.pending_events
+                .push(TransportEvent::Disconnected(connection));
+        }
+    }
+}
+
+impl Drop for UdpTransport {
+    fn drop(&mut self) {
+        for connection in self.connections.values() {
+            self.send_packet(connection.address, &[packet::DISCONNECT]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_platform::time::Instant;
+
+    use super::{ConnectionState, ReliableHeader, UdpConnection, MAX_FRAGMENT_SIZE};
+    use crate::{
+        ChannelId, ConnectionId, Reliability, Transport, TransportEvent, UdpTransport,
+        UdpTransportConfig,
+    };
+
+    /// Polls the transports until `done` returns `true` for the events they reported.
+    fn poll_until(
+        transports: &mut [&mut UdpTransport],
+        mut done: impl FnMut(&[Vec<TransportEvent>]) -> bool,
+    ) -> Vec<Vec<TransportEvent>> {
+        let mut events = vec![Vec::new(); transports.len()];
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !done(&events) {
+            assert!(Instant::now() < deadline, "timed out, received {events:?}");
+            for (transport, events) in transports.iter_mut().zip(&mut events) {
+                transport.poll(events);
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        events
+    }
+
+    fn received(events: &[TransportEvent]) -> Vec<(ChannelId, &[u8])> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                TransportEvent::Received {
+                    channel, payload, ..
+                } => Some((*channel, payload.as_slice())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn connect_send_disconnect() {
+        let mut server = UdpTransport::bind("127.0.0.1:0").unwrap();
+        let mut client = UdpTransport::connect(server.local_addr().unwrap()).unwrap();
+        let events = poll_until(&mut [&mut server, &mut client], |events| {
+            events[0].contains(&TransportEvent::Connected(ConnectionId(1)))
+                && events[1].contains(&TransportEvent::Connected(ConnectionId::SERVER))
+        });
+        assert_eq!(events[0], vec![TransportEvent::Connected(ConnectionId(1))]);
+
+        let large: Vec<u8> = (0..3 * MAX_FRAGMENT_SIZE + 10).map(|i| i as u8).collect();
+        let send = |client: &mut UdpTransport, channel, reliability, payload: &[u8]| {
+            client
+                .send(
+                    ConnectionId::SERVER,
+                    ChannelId(channel),
+                    reliability,
+                    payload,
+                )
+                .unwrap();
+        };
+        send(&mut client, 1, Reliability::Reliable, b"reliable");
+        send(&mut client, 2, Reliability::ReliableOrdered, &large);
+        send(&mut client, 2, Reliability::ReliableOrdered, b"ordered");
+        send(&mut client, 3, Reliability::Unreliable, b"unreliable");
+        let events = poll_until(&mut [&mut server, &mut client], |events| {
+            received(&events[0]).len() == 4
+        });
+        let messages = received(&events[0]);
+        assert!(messages.contains(&(ChannelId(1), b"reliable".as_slice())));
+        assert!(messages.contains(&(ChannelId(3), b"unreliable".as_slice())));
+        let ordered: Vec<_> = messages
+            .iter()
+            .filter(|(channel, _)| *channel == ChannelId(2))
+            .map(|(_, payload)| *payload)
+            .collect();
+        assert_eq!(ordered, vec![large.as_slice(), b"ordered".as_slice()]);
+
+        client.disconnect(ConnectionId::SERVER);
+        poll_until(&mut [&mut server, &mut client], |events| {
+            events[0].contains(&TransportEvent::Disconnected(ConnectionId(1)))
+                && events[1].contains(&TransportEvent::Disconnected(ConnectionId::SERVER))
+        });
+        assert!(server.remote_addr(ConnectionId(1)).is_none());
+    }
+
+    #[test]
+    fn reject_connections_over_limit() {
+        let mut server =
+            UdpTransport::bind("127.0.0.1:0")
+                .unwrap()
+                .with_config(UdpTransportConfig {
+                    max_connections: 1,
+                    ..Default::default()
+                });
+        let address = server.local_addr().unwrap();
+        let mut first = UdpTransport::connect(address).unwrap();
+        let mut second = UdpTransport::connect(address).unwrap();
+
+        let events = poll_until(&mut [&mut server, &mut first, &mut second], |events| {
+            events[1].contains(&TransportEvent::Connected(ConnectionId::SERVER))
+                && events[2].contains(&TransportEvent::Disconnected(ConnectionId::SERVER))
+        });
+        assert_eq!(events[0], vec![TransportEvent::Connected(ConnectionId(1))]);
+        assert!(!events[2].contains(&TransportEvent::Connected(ConnectionId::SERVER)));
+    }
+
+    #[test]
+    fn reassemble_and_order_reliable_messages() {
+        let mut connection = UdpConnection::new(
+            "127.0.0.1:5000".parse().unwrap(),
+            ConnectionState::Connected,
+            Instant::now(),
+        );
+        let header = |sequence, order, fragment, fragment_count| ReliableHeader {
+            channel: ChannelId(1),
+            sequence,
+            order,
+            fragment,
+            fragment_count,
+        };
+        let mut events = Vec::new();
+        let mut receive = |header: ReliableHeader, payload: &[u8]| {
+            assert!(connection.receive_sequence(header.sequence));
+            connection.receive_reliable(ConnectionId(1), header, true, payload, &mut events);
+        };
+
+        // The second message arrives first, then the fragments of the first one out of order.
+        receive(header(2, 1, 0, 1), b"second");
+        receive(header(1, 0, 1, 2), b"world");
+        receive(header(0, 0, 0, 2), b"hello ");
+
+        let payloads: Vec<_> = received(&events)
+            .into_iter()
+            .map(|(_, payload)| payload)
+            .collect();
+        assert_eq!(
+            payloads,
+            vec![b"hello world".as_slice(), b"second".as_slice()]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+use core::cell::{Cell, RefCell};
+use std::io;
+
+use bevy_platform::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+use js_sys::{ArrayBuffer, Uint8Array};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+
+use crate::{ChannelId, ConnectionId, Reliability, Transport, TransportError, TransportEvent};
+
+/// A browser WebSocket, along with the callbacks it calls, which must live as long as it does.
+struct Socket {
+    socket: WebSocket,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+}
+
+impl Socket {
+    /// Closes the socket, without calling the callbacks that are about to be dropped.
+    fn close(self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        // Closing an already closed socket does nothing.
+        let _ = self.socket.close();
+    }
+}
+
+std::thread_local! {
+    /// The sockets of the [`WebSocketTransport`]s. JavaScript objects cannot be sent to other
+    /// threads, so they are kept on the thread that created them.
+    static SOCKETS: RefCell<HashMap<u64, Socket>> = RefCell::new(HashMap::default());
+    static NEXT_SOCKET: Cell<u64> = const { Cell::new(0) };
+}
+
+/// A [`Transport`] connecting to a server through a browser WebSocket, for the web.
+///
+/// Browsers do not allow UDP sockets, so web clients connect with
+/// [`WebSocketTransport::connect`] to a WebSocket server instead of a
+/// [`UdpTransport`](crate::UdpTransport). Each message is sent as a binary WebSocket message,
+/// whose first byte is the [`ChannelId`] and the rest the payload: the server is expected to
+/// exchange messages in the same way. WebSockets are reliable and ordered, so every message is
+/// delivered with [`Reliability::ReliableOrdered`], whatever the reliability of its channel.
+///
+/// The socket belongs to the thread that created the transport. On multithreaded wasm builds,
+/// the transport must be polled and used on that thread, like the main thread of the app.
+///
+/// ```no_run
+/// # use bevy_net::WebSocketTransport;
+/// let client = WebSocketTransport::connect("wss://example.com/game").unwrap();
+/// ```
+pub struct WebSocketTransport {
+    socket: u64,
+    /// The events pushed by the callbacks of the socket since the last poll.
+    events: Arc<Mutex<Vec<TransportEvent>>>,
+}
+
+impl WebSocketTransport {
+    /// Creates a client connecting to the WebSocket server at `url`, like `wss://example.com`.
+    ///
+    /// The connection is established asynchronously: a [`TransportEvent::Connected`] for
+    /// [`ConnectionId::SERVER`] is reported once the socket is open, or a
+    /// [`TransportEvent::Disconnected`] if it could not be opened.
+    pub fn connect(url: &str) -> Result<Self, TransportError> {
+        let socket = WebSocket::new(url).map_err(js_error)?;
+        socket.set_binary_type(BinaryType::Arraybuffer);
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let on_open = {
+            let events = Arc::clone(&events);
+            Closure::<dyn FnMut(Event)>::new(move |_: Event| {
+                push_event(&events, TransportEvent::Connected(ConnectionId::SERVER));
+            })
+        };
+        let on_message = {
+            let events = Arc::clone(&events);
+            Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+                let Ok(buffer) = event.data().dyn_into::<ArrayBuffer>() else {
+                    return;
+                };
+                let mut payload = Uint8Array::new(&buffer).to_vec();
+                if payload.is_empty() {
+                    return;
+                }
+                let channel = ChannelId(payload.remove(0));
+                push_event(
+                    &events,
+                    TransportEvent::Received {
+                        connection: ConnectionId::SERVER,
+                        channel,
+                        payload,
+                    },
+                );
+            })
+        };
+        // Also called when the socket could not be opened.
+        let on_close = {
+            let events = Arc::clone(&events);
+            Closure::<dyn FnMut(CloseEvent)>::new(move |_: CloseEvent| {
+                push_event(&events, TransportEvent::Disconnected(ConnectionId::SERVER));
+            })
+        };
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+        let id = NEXT_SOCKET.replace(NEXT_SOCKET.get() + 1);
+        SOCKETS.with_borrow_mut(|sockets| {
+            sockets.insert(
+                id,
+                Socket {
+                    socket,
+                    _on_open: on_open,
+                    _on_message: on_message,
+                    _on_close: on_close,
+                },
+            );
+        });
+        Ok(Self { socket: id, events })
+    }
+
+    fn close(&mut self) -> bool {
+        let Some(socket) = SOCKETS.with_borrow_mut(|sockets| sockets.remove(&self.socket)) else {
+            return false;
+        };
+        socket.close();
+        true
+    }
+}
+
+fn push_event(events: &Mutex<Vec<TransportEvent>>, event: TransportEvent) {
+    events
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(event);
+}
+
+fn js_error(error: JsValue) -> TransportError {
+    io::Error::other(format!("{error:?}")).into()
+}
+
+impl Transport for WebSocketTransport {
+    fn poll(&mut self, events: &mut Vec<TransportEvent>) {
+        events.append(&mut self.events.lock().unwrap_or_else(PoisonError::into_inner));
+    }
+
+    fn send(
+        &mut self,
+        connection: ConnectionId,
+        channel: ChannelId,
+        _reliability: Reliability,
+        payload: &[u8],
+    ) -> Result<(), TransportError> {
+        if connection != ConnectionId::SERVER {
+            return Err(TransportError::NotConnected(connection));
+        }
+        let mut message = Vec::with_capacity(1 + payload.len());
+        message.push(channel.0);
+        message.extend_from_slice(payload);
+
+        SOCKETS.with_borrow(|sockets| {
+            let socket = sockets
+                .get(&self.socket)
+                .filter(|socket| socket.socket.ready_state() == WebSocket::OPEN)
+                .ok_or(TransportError::NotConnected(connection))?;
+            socket.socket.send_with_u8_array(&message).map_err(js_error)
+        })
+    }
+
+    fn disconnect(&mut self, connection: ConnectionId) {
+        if connection == ConnectionId::SERVER && self.close() {
+            push_event(&self.events, TransportEvent::Disconnected(connection));
+        }
+    }
+}
+
+impl Drop for WebSocketTransport {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
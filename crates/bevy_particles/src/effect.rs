@@ -0,0 +1,275 @@
+use alloc::vec::Vec;
+
+use bevy_asset::Asset;
+#[cfg(any(feature = "bevy_sprite", feature = "bevy_pbr"))]
+use bevy_asset::Handle;
+use bevy_color::LinearRgba;
+#[cfg(feature = "bevy_sprite")]
+use bevy_image::Image;
+use bevy_math::{Vec3, VectorSpace};
+#[cfg(feature = "bevy_pbr")]
+use bevy_mesh::Mesh;
+#[cfg(feature = "bevy_pbr")]
+use bevy_pbr::StandardMaterial;
+use bevy_reflect::TypePath;
+use serde::{Deserialize, Serialize};
+
+/// A visual effect: one or more [`ParticleEmitter`]s, played together by a
+/// [`ParticleEffectPlayer`](crate::ParticleEffectPlayer).
+///
+/// Effects are usually authored in `.particles.ron` files, loaded by the
+/// [`ParticleEffectLoader`](crate::ParticleEffectLoader). With the `file_watcher` feature of
+/// `bevy_asset`, the effects are reloaded when their file is saved, and their players restart, so
+/// that they can be tweaked while the app is running:
+///
+/// ```ron
+/// (
+///     emitters: [
+///         (
+///             rate: 40.0,
+///             bursts: [(time: 0.0, count: 20)],
+///             lifetime: (0.5, 1.0),
+///             shape: Sphere(radius: 0.2),
+///             direction: (0.0, 1.0, 0.0),
+///             spread: 0.4,
+///             speed: (2.0, 3.0),
+///             acceleration: (0.0, -9.8, 0.0),
+///             size: [(0.0, 0.1), (1.0, 0.0)],
+///             color: [
+///                 (0.0, (red: 4.0, green: 2.0, blue: 0.5, alpha: 1.0)),
+///                 (1.0, (red: 1.0, green: 0.0, blue: 0.0, alpha: 0.0)),
+///             ],
+///             renderer: Sprite(image: "textures/spark.png"),
+///         ),
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath, Clone, Debug, Default)]
+pub struct ParticleEffect {
+    /// The emitters of the effect.
+    pub emitters: Vec<ParticleEmitter>,
+}
+
+impl ParticleEffect {
+    /// Adds an emitter to the effect.
+    pub fn with_emitter(mut self, emitter: ParticleEmitter) -> Self {
+        self.emitters.push(emitter);
+        self
+    }
+}
+
+/// Spawns the particles of a [`ParticleEffect`], and defines how they move and look.
+///
+/// The ranges, like [`ParticleEmitter::lifetime`], are the minimum and the maximum of a value
+/// drawn for each particle from the [`EntityRng`](bevy_rand::EntityRng) of the player.
+///
+/// `R` is the renderer of the particles, which is a [`ParticleRenderer`] once loaded, and the
+/// paths of its assets in the effect files.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParticleEmitter<R = ParticleRenderer> {
+    /// How many particles are spawned per second.
+    pub rate: f32,
+    /// The particles spawned at once at given times.
+    pub bursts: Vec<Burst>,
+    /// How long the emitter spawns particles, in seconds, or `None` to never stop.
+    pub duration: Option<f32>,
+    /// Whether the emitter restarts, with its bursts, once its duration is over.
+    pub looping: bool,
+    /// The maximum number of particles of the emitter alive at once.
+    pub max_particles: usize,
+    /// How long each particle lives, in seconds.
+    pub lifetime: (f32, f32),
+    /// Where the particles are spawned, around the player.
+    pub shape: EmitterShape,
+    /// The direction in which the particles are emitted.
+    pub direction: Vec3,
+    /// The half-angle of the cone around [`ParticleEmitter::direction`] in which the particles
+    /// are emitted, in radians.
+    pub spread: f32,
+    /// The initial speed of the particles, in units per second.
+    pub speed: (f32, f32),
+    /// The constant acceleration of the particles, like gravity.
+    pub acceleration: Vec3,
+    /// How fast the particles slow down, as a fraction of their velocity lost per second.
+    pub drag: f32,
+    /// The factor of the velocity of the particles over their lifetime, for example to burst out
+    /// and then linger.
+    pub speed_scale: ParticleCurve<f32>,
+    /// The speed at which the particles spin around their forward axis, in radians per second.
+    pub rotation_speed: (f32, f32),
+    /// The scale of the particles over their lifetime.
+    pub size: ParticleCurve<f32>,
+    /// The color of the particles over their lifetime.
+    pub color: ParticleCurve<LinearRgba>,
+    /// Whether the particles follow the player once spawned.
+    pub space: SimulationSpace,
+    /// How the particles are rendered.
+    pub renderer: R,
+}
+
+impl<R: Default> Default for ParticleEmitter<R> {
+    fn default() -> Self {
+        Self {
+            rate: 10.0,
+            bursts: Vec::new(),
+            duration: None,
+            looping: false,
+            max_particles: 1000,
+            lifetime: (1.0, 1.0),
+            shape: EmitterShape::Point,
+            direction: Vec3::Y,
+            spread: 0.0,
+            speed: (1.0, 1.0),
+            acceleration: Vec3::ZERO,
+            drag: 0.0,
+            speed_scale: ParticleCurve::constant(1.0),
+            rotation_speed: (0.0, 0.0),
+            size: ParticleCurve::constant(1.0),
+            color: ParticleCurve::constant(LinearRgba::WHITE),
+            space: SimulationSpace::World,
+            renderer: R::default(),
+        }
+    }
+}
+
+impl<R> ParticleEmitter<R> {
+    /// Replaces the renderer of the emitter.
+    pub fn map_renderer<S>(self, f: impl FnOnce(R) -> S) -> ParticleEmitter<S> {
+        ParticleEmitter {
+            rate: self.rate,
+            bursts: self.bursts,
+            duration: self.duration,
+            looping: self.looping,
+            max_particles: self.max_particles,
+            lifetime: self.lifetime,
+            shape: self.shape,
+            direction: self.direction,
+            spread: self.spread,
+            speed: self.speed,
+            acceleration: self.acceleration,
+            drag: self.drag,
+            speed_scale: self.speed_scale,
+            rotation_speed: self.rotation_speed,
+            size: self.size,
+            color: self.color,
+            space: self.space,
+            renderer: f(self.renderer),
+        }
+    }
+}
+
+/// Particles spawned at once by a [`ParticleEmitter`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Burst {
+    /// When the particles are spawned, in seconds since the emitter started.
+    pub time: f32,
+    /// How many particles are spawned.
+    pub count: u32,
+}
+
+/// Where a [`ParticleEmitter`] spawns its particles, in the space of the player.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum EmitterShape {
+    /// At the origin of the player.
+    #[default]
+    Point,
+    /// Inside of a sphere.
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32,
+    },
+    /// Inside of a box.
+    Cuboid {
+        /// Half of the size of the box along each axis.
+        half_size: Vec3,
+    },
+    /// Inside of a disk, in the XZ plane.
+    Circle {
+        /// The radius of the disk.
+        radius: f32,
+    },
+}
+
+/// Whether the particles of a [`ParticleEmitter`] follow the player once spawned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulationSpace {
+    /// The particles stay where they are spawned, leaving a trail behind a moving player.
+    #[default]
+    World,
+    /// The particles are children of the player, and move with it.
+    Local,
+}
+
+/// A value over the lifetime of a particle, as keyframes from `0.0`, when it is spawned, to
+/// `1.0`, when it dies.
+///
+/// The value is interpolated linearly between the keyframes, and held before the first and after
+/// the last.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ParticleCurve<T>(pub Vec<(f32, T)>);
+
+impl<T> Default for ParticleCurve<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: VectorSpace<Scalar = f32>> ParticleCurve<T> {
+    /// Creates a curve holding `value`.
+    pub fn constant(value: T) -> Self {
+        Self(Vec::from([(0.0, value)]))
+    }
+
+    /// Returns the value of the curve at `t`, or the default value of `T` if it has no keyframe.
+    pub fn sample(&self, t: f32) -> T {
+        let index = self.0.partition_point(|(at, _)| *at <= t);
+        match (index.checked_sub(1).map(|i| self.0[i]), self.0.get(index)) {
+            (Some((from_t, from)), Some(&(to_t, to))) if to_t > from_t => {
+                from.lerp(to, (t - from_t) / (to_t - from_t))
+            }
+            (Some((_, value)), _) | (None, Some(&(_, value))) => value,
+            (None, None) => T::default(),
+        }
+    }
+}
+
+/// How the particles of a [`ParticleEmitter`] are rendered.
+///
+/// The particles of the sprite and mesh renderers are entities rendered by the regular sprite and
+/// mesh pipelines, which draw the particles sharing an image, or a mesh and a material, as
+/// instanced batches. The particles of the GPU renderer never leave the GPU, which scales to tens
+/// of thousands of particles per emitter.
+#[derive(Clone, Debug, Default)]
+pub enum ParticleRenderer {
+    /// The particles are not rendered, for example to drive custom visuals from the
+    /// [`Particle`](crate::Particle)s.
+    #[default]
+    None,
+    /// Each particle is a [`Sprite`](bevy_sprite::Sprite), tinted by
+    /// [`ParticleEmitter::color`].
+    #[cfg(feature = "bevy_sprite")]
+    Sprite {
+        /// The image of the sprites.
+        image: Handle<Image>,
+    },
+    /// Each particle is a [`Mesh3d`](bevy_mesh::Mesh3d).
+    ///
+    /// The material is shared by all the particles, so [`ParticleEmitter::color`] is ignored.
+    #[cfg(feature = "bevy_pbr")]
+    Mesh {
+        /// The mesh of the particles.
+        mesh: Handle<Mesh>,
+        /// The material of the particles.
+        material: Handle<StandardMaterial>,
+    },
+    /// The particles are simulated in a compute shader and drawn as round billboards facing the
+    /// 3D cameras, colored by [`ParticleEmitter::color`].
+    ///
+    /// No [`Particle`](crate::Particle) entity is spawned. The particles are kept in a ring buffer
+    /// of [`ParticleEmitter::max_particles`], so the oldest particles are replaced when more are
+    /// alive.
+    #[cfg(feature = "bevy_render")]
+    Gpu,
+}
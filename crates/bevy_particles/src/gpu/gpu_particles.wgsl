@@ -0,0 +1,76 @@
+#define_import_path bevy_particles::gpu_particles
+
+// The number of samples of the curves of an emitter.
+const CURVE_SAMPLES: u32 = 16u;
+
+const SHAPE_POINT: u32 = 0u;
+const SHAPE_SPHERE: u32 = 1u;
+const SHAPE_CUBOID: u32 = 2u;
+const SHAPE_CIRCLE: u32 = 3u;
+
+struct Emitter {
+    world_from_emitter: mat4x4<f32>,
+    color: array<vec4<f32>, 16>,
+    // The samples of the curves, packed four per vector.
+    size: array<vec4<f32>, 4>,
+    speed_scale: array<vec4<f32>, 4>,
+    shape: vec4<f32>,
+    direction: vec3<f32>,
+    spread: f32,
+    acceleration: vec3<f32>,
+    drag: f32,
+    lifetime: vec2<f32>,
+    speed: vec2<f32>,
+    rotation_speed: vec2<f32>,
+    delta: f32,
+    shape_kind: u32,
+    local_space: u32,
+    capacity: u32,
+    spawn_start: u32,
+    spawn_count: u32,
+    seed: u32,
+}
+
+struct Particle {
+    position: vec3<f32>,
+    age: f32,
+    velocity: vec3<f32>,
+    lifetime: f32,
+    rotation: f32,
+    angular_velocity: f32,
+    _padding: vec2<f32>,
+}
+
+fn progress(particle: Particle) -> f32 {
+    if particle.lifetime <= 0.0 {
+        return 1.0;
+    }
+    return clamp(particle.age / particle.lifetime, 0.0, 1.0);
+}
+
+fn sample_index(t: f32) -> vec2<f32> {
+    let x = clamp(t, 0.0, 1.0) * f32(CURVE_SAMPLES - 1u);
+    return vec2(floor(x), fract(x));
+}
+
+fn packed_sample(samples: array<vec4<f32>, 4>, index: u32) -> f32 {
+    // Arrays are only indexed dynamically through variables.
+    var packed = samples;
+    let i = min(index, CURVE_SAMPLES - 1u);
+    return packed[i / 4u][i % 4u];
+}
+
+fn sample_packed(samples: array<vec4<f32>, 4>, t: f32) -> f32 {
+    let index = sample_index(t);
+    let from = packed_sample(samples, u32(index.x));
+    let to = packed_sample(samples, u32(index.x) + 1u);
+    return mix(from, to, index.y);
+}
+
+fn sample_color(samples: array<vec4<f32>, 16>, t: f32) -> vec4<f32> {
+    var colors = samples;
+    let index = sample_index(t);
+    let from = colors[u32(index.x)];
+    let to = colors[min(u32(index.x) + 1u, CURVE_SAMPLES - 1u)];
+    return mix(from, to, index.y);
+}
@@ -0,0 +1,550 @@
+//! The GPU renderer of the particles, simulating them in a compute shader and drawing them after
+//! the main 3D pass.
+
+use alloc::{vec, vec::Vec};
+use core::array;
+
+use bevy_app::{App, Plugin};
+use bevy_asset::{embedded_asset, load_embedded_asset, AssetServer, Assets, Handle};
+use bevy_camera::visibility::InheritedVisibility;
+use bevy_color::ColorToComponents;
+use bevy_core_pipeline::{
+    core_3d::CORE_3D_DEPTH_FORMAT,
+    schedule::{camera_driver, Core3d, Core3dSystems},
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_math::{Dir3, Mat4, Vec2, Vec3, Vec4, VectorSpace};
+use bevy_platform::collections::{HashMap, HashSet};
+use bevy_render::{
+    camera::ExtractedCamera,
+    render_resource::{
+        binding_types::{storage_buffer_read_only_sized, storage_buffer_sized, uniform_buffer},
+        *,
+    },
+    renderer::{RenderContext, RenderDevice, RenderGraph, RenderQueue, ViewQuery},
+    view::{
+        ExtractedView, Msaa, ViewDepthStencilTexture, ViewTarget, ViewUniform, ViewUniformOffset,
+        ViewUniforms,
+    },
+    Extract, ExtractSchedule, GpuResourceAppExt, Render, RenderApp, RenderStartup, RenderSystems,
+};
+use bevy_shader::{load_shader_library, Shader};
+use bevy_time::Time;
+use bevy_transform::components::GlobalTransform;
+
+use crate::{
+    player::EmitterStates, EmitterShape, ParticleCurve, ParticleEffect, ParticleEffectPlayer,
+    ParticleEmitter, ParticleRenderer, SimulationSpace,
+};
+
+/// The number of samples of the curves of an emitter, interpolated linearly by the shaders.
+const CURVE_SAMPLES: usize = 16;
+
+/// The size of a particle in the storage buffer of its emitter, in bytes.
+const PARTICLE_SIZE: u64 = 48;
+
+/// The number of particles simulated by each workgroup of the compute shader.
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Simulates and draws the particles of the emitters with a [`ParticleRenderer::Gpu`].
+pub(crate) struct GpuParticlePlugin;
+
+impl Plugin for GpuParticlePlugin {
+    fn build(&self, app: &mut App) {
+        load_shader_library!(app, "gpu_particles.wgsl");
+        embedded_asset!(app, "simulate_particles.wgsl");
+        embedded_asset!(app, "render_particles.wgsl");
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .init_resource::<ExtractedGpuEmitters>()
+            .init_gpu_resource::<GpuEmitters>()
+            .init_gpu_resource::<SpecializedRenderPipelines<GpuParticlePipelines>>()
+            .add_systems(RenderStartup, init_gpu_particle_pipelines)
+            .add_systems(ExtractSchedule, extract_gpu_emitters)
+            .add_systems(
+                Render,
+                (
+                    prepare_gpu_emitters.in_set(RenderSystems::PrepareResources),
+                    prepare_gpu_particle_views.in_set(RenderSystems::PrepareBindGroups),
+                ),
+            )
+            .add_systems(RenderGraph, simulate_gpu_particles.before(camera_driver))
+            .add_systems(
+                Core3d,
+                draw_gpu_particles
+                    .after(Core3dSystems::MainPass)
+                    .before(Core3dSystems::EarlyPostProcess),
+            );
+    }
+}
+
+/// The parameters of an emitter, as read by the shaders.
+#[derive(ShaderType, Clone, Debug)]
+struct GpuEmitterUniform {
+    world_from_emitter: Mat4,
+    color: [Vec4; CURVE_SAMPLES],
+    size: [Vec4; CURVE_SAMPLES / 4],
+    speed_scale: [Vec4; CURVE_SAMPLES / 4],
+    shape: Vec4,
+    direction: Vec3,
+    spread: f32,
+    acceleration: Vec3,
+    drag: f32,
+    lifetime: Vec2,
+    speed: Vec2,
+    rotation_speed: Vec2,
+    delta: f32,
+    shape_kind: u32,
+    local_space: u32,
+    capacity: u32,
+    spawn_start: u32,
+    spawn_count: u32,
+    seed: u32,
+}
+
+impl GpuEmitterUniform {
+    fn new(emitter: &ParticleEmitter, transform: &GlobalTransform, delta: f32) -> Self {
+        let (shape_kind, shape) = match emitter.shape {
+            EmitterShape::Point => (0, Vec4::ZERO),
+            EmitterShape::Sphere { radius } => (1, Vec4::splat(radius)),
+            EmitterShape::Cuboid { half_size } => (2, half_size.extend(0.0)),
+            EmitterShape::Circle { radius } => (3, Vec4::splat(radius)),
+        };
+        let color = sample_curve(&emitter.color);
+        Self {
+            world_from_emitter: transform.to_matrix(),
+            color: color.map(|color| color.to_vec4()),
+            size: pack_curve(&emitter.size),
+            speed_scale: pack_curve(&emitter.speed_scale),
+            shape,
+            direction: *Dir3::new(emitter.direction).unwrap_or(Dir3::Y),
+            spread: emitter.spread,
+            acceleration: emitter.acceleration,
+            drag: emitter.drag,
+            lifetime: emitter.lifetime.into(),
+            speed: emitter.speed.into(),
+            rotation_speed: emitter.rotation_speed.into(),
+            delta,
+            shape_kind,
+            local_space: u32::from(emitter.space == SimulationSpace::Local),
+            capacity: 0,
+            spawn_start: 0,
+            spawn_count: 0,
+            seed: 0,
+        }
+    }
+}
+
+/// Samples a curve at evenly spaced points.
+fn sample_curve<T: VectorSpace<Scalar = f32>>(curve: &ParticleCurve<T>) -> [T; CURVE_SAMPLES] {
+    array::from_fn(|index| curve.sample(index as f32 / (CURVE_SAMPLES - 1) as f32))
+}
+
+/// Samples a curve of scalars, packed four per vector to fit the layout of uniform buffers.
+fn pack_curve(curve: &ParticleCurve<f32>) -> [Vec4; CURVE_SAMPLES / 4] {
+    let samples = sample_curve(curve);
+    array::from_fn(|index| Vec4::from_slice(&samples[index * 4..]))
+}
+
+/// An emitter with a [`ParticleRenderer::Gpu`], extracted from the main world.
+struct ExtractedGpuEmitter {
+    /// The player of the emitter, and the index of the emitter in its effect.
+    key: (Entity, usize),
+    restarts: u32,
+    capacity: u32,
+    spawn_count: u32,
+    visible: bool,
+    uniform: GpuEmitterUniform,
+}
+
+#[derive(Resource, Default)]
+struct ExtractedGpuEmitters(Vec<ExtractedGpuEmitter>);
+
+fn extract_gpu_emitters(
+    mut extracted: ResMut<ExtractedGpuEmitters>,
+    time: Extract<Res<Time>>,
+    effects: Extract<Res<Assets<ParticleEffect>>>,
+    players: Extract<
+        Query<(
+            Entity,
+            &ParticleEffectPlayer,
+            &EmitterStates,
+            &GlobalTransform,
+            &InheritedVisibility,
+        )>,
+    >,
+) {
+    extracted.0.clear();
+    for (entity, player, states, transform, visibility) in &players {
+        let Some(effect) = effects.get(&player.effect) else {
+            continue;
+        };
+        let delta = if player.paused {
+            0.0
+        } else {
+            time.delta_secs()
+        };
+        for (index, (emitter, state)) in effect.emitters.iter().zip(&states.emitters).enumerate() {
+            if !matches!(emitter.renderer, ParticleRenderer::Gpu) {
+                continue;
+            }
+            extracted.0.push(ExtractedGpuEmitter {
+                key: (entity, index),
+                restarts: states.restarts,
+                capacity: emitter.max_particles.clamp(1, u32::MAX as usize) as u32,
+                spawn_count: state.spawned,
+                visible: visibility.get(),
+                uniform: GpuEmitterUniform::new(emitter, transform, delta),
+            });
+        }
+    }
+}
+
+/// The buffers and bind groups of an emitter with a [`ParticleRenderer::Gpu`].
+struct GpuEmitter {
+    restarts: u32,
+    capacity: u32,
+    /// The index at which the next particles are spawned in the ring buffer.
+    cursor: u32,
+    frame: u32,
+    visible: bool,
+    uniform: UniformBuffer<GpuEmitterUniform>,
+    particles: Buffer,
+    bind_groups: Option<(BindGroup, BindGroup)>,
+}
+
+/// The emitters simulated on the GPU, by player and index in the effect of the player.
+#[derive(Resource, Default)]
+struct GpuEmitters(HashMap<(Entity, usize), GpuEmitter>);
+
+fn prepare_gpu_emitters(
+    extracted: Res<ExtractedGpuEmitters>,
+    mut emitters: ResMut<GpuEmitters>,
+    pipelines: Res<GpuParticlePipelines>,
+    pipeline_cache: Res<PipelineCache>,
+    render_device: Res<RenderDevice>,
+    render_queue: Res<RenderQueue>,
+) {
+    let keys: HashSet<_> = extracted.0.iter().map(|emitter| emitter.key).collect();
+    emitters.0.retain(|key, _| keys.contains(key));
+
+    for extracted in &extracted.0 {
+        let emitter = emitters
+            .0
+            .entry(extracted.key)
+            .and_modify(|emitter| {
+                // The particles are cleared when the effect restarts.
+                if emitter.capacity != extracted.capacity || emitter.restarts != extracted.restarts
+                {
+                    *emitter = GpuEmitter::new(extracted, &render_device);
+                }
+            })
+            .or_insert_with(|| GpuEmitter::new(extracted, &render_device));
+
+        let spawn_count = extracted.spawn_count.min(emitter.capacity);
+        let (entity, index) = extracted.key;
+        emitter.uniform.set(GpuEmitterUniform {
+            capacity: emitter.capacity,
+            spawn_start: emitter.cursor,
+            spawn_count,
+            seed: entity.index_u32()
+                ^ (index as u32).rotate_left(16)
+                ^ emitter.frame.wrapping_mul(0x9e37_79b9),
+            ..extracted.uniform.clone()
+        });
+        emitter.uniform.write_buffer(&render_device, &render_queue);
+        emitter.cursor = (emitter.cursor + spawn_count) % emitter.capacity;
+        emitter.frame = emitter.frame.wrapping_add(1);
+        emitter.visible = extracted.visible;
+
+        if emitter.bind_groups.is_none()
+            && let Some(uniform) = emitter.uniform.binding()
+        {
+            let simulate = render_device.create_bind_group(
+                "gpu_particles_simulate_bind_group",
+                &pipeline_cache.get_bind_group_layout(&pipelines.simulate_layout),
+                &BindGroupEntries::sequential((
+                    uniform.clone(),
+                    emitter.particles.as_entire_binding(),
+                )),
+            );
+            let render = render_device.create_bind_group(
+                "gpu_particles_emitter_bind_group",
+                &pipeline_cache.get_bind_group_layout(&pipelines.emitter_layout),
+                &BindGroupEntries::sequential((uniform, emitter.particles.as_entire_binding())),
+            );
+            emitter.bind_groups = Some((simulate, render));
+        }
+    }
+}
+
+impl GpuEmitter {
+    fn new(extracted: &ExtractedGpuEmitter, render_device: &RenderDevice) -> Self {
+        // The buffer is zeroed, so all the particles start dead.
+        let particles = render_device.create_buffer(&BufferDescriptor {
+            label: Some("gpu_particles_buffer"),
+            size: u64::from(extracted.capacity) * PARTICLE_SIZE,
+            usage: BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        Self {
+            restarts: extracted.restarts,
+            capacity: extracted.capacity,
+            cursor: 0,
+            frame: 0,
+            visible: extracted.visible,
+            uniform: UniformBuffer::default(),
+            particles,
+            bind_groups: None,
+        }
+    }
+}
+
+/// The pipelines simulating and drawing the particles.
+#[derive(Resource)]
+struct GpuParticlePipelines {
+    simulate_layout: BindGroupLayoutDescriptor,
+    view_layout: BindGroupLayoutDescriptor,
+    emitter_layout: BindGroupLayoutDescriptor,
+    simulate_pipeline: CachedComputePipelineId,
+    render_shader: Handle<Shader>,
+}
+
+fn init_gpu_particle_pipelines(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    let simulate_layout = BindGroupLayoutDescriptor::new(
+        "gpu_particles_simulate_bind_group_layout",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::COMPUTE,
+            (
+                uniform_buffer::<GpuEmitterUniform>(false),
+                storage_buffer_sized(false, None),
+            ),
+        ),
+    );
+    let view_layout = BindGroupLayoutDescriptor::new(
+        "gpu_particles_view_bind_group_layout",
+        &BindGroupLayoutEntries::single(
+            ShaderStages::VERTEX_FRAGMENT,
+            uniform_buffer::<ViewUniform>(true),
+        ),
+    );
+    let emitter_layout = BindGroupLayoutDescriptor::new(
+        "gpu_particles_emitter_bind_group_layout",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::VERTEX,
+            (
+                uniform_buffer::<GpuEmitterUniform>(false),
+                storage_buffer_read_only_sized(false, None),
+            ),
+        ),
+    );
+
+    let simulate_pipeline = pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
+        label: Some("gpu_particles_simulate_pipeline".into()),
+        layout: vec![simulate_layout.clone()],
+        shader: load_embedded_asset!(asset_server.as_ref(), "simulate_particles.wgsl"),
+        entry_point: Some("simulate".into()),
+        ..Default::default()
+    });
+
+    commands.insert_resource(GpuParticlePipelines {
+        simulate_layout,
+        view_layout,
+        emitter_layout,
+        simulate_pipeline,
+        render_shader: load_embedded_asset!(asset_server.as_ref(), "render_particles.wgsl"),
+    });
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct GpuParticlePipelineKey {
+    target_format: TextureFormat,
+    samples: u32,
+}
+
+impl SpecializedRenderPipeline for GpuParticlePipelines {
+    type Key = GpuParticlePipelineKey;
+
+    fn specialize(&self, key: Self::Key) -> RenderPipelineDescriptor {
+        RenderPipelineDescriptor {
+            label: Some("gpu_particles_render_pipeline".into()),
+            layout: vec![self.view_layout.clone(), self.emitter_layout.clone()],
+            vertex: VertexState {
+                shader: self.render_shader.clone(),
+                entry_point: Some("vertex".into()),
+                ..Default::default()
+            },
+            // The particles are tested against the depth of the main pass, without writing it.
+            depth_stencil: Some(DepthStencilState {
+                format: CORE_3D_DEPTH_FORMAT,
+                depth_write_enabled: Some(false),
+                depth_compare: Some(CompareFunction::GreaterEqual),
+                stencil: StencilState::default(),
+                bias: DepthBiasState::default(),
+            }),
+            multisample: MultisampleState {
+                count: key.samples,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            fragment: Some(FragmentState {
+                shader: self.render_shader.clone(),
+                entry_point: Some("fragment".into()),
+                targets: vec![Some(ColorTargetState {
+                    format: key.target_format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// The pipeline and bind group drawing the particles in a view.
+#[derive(Component)]
+struct GpuParticleView {
+    pipeline: CachedRenderPipelineId,
+    bind_group: BindGroup,
+}
+
+fn prepare_gpu_particle_views(
+    mut commands: Commands,
+    emitters: Res<GpuEmitters>,
+    pipelines: Res<GpuParticlePipelines>,
+    mut specialized_pipelines: ResMut<SpecializedRenderPipelines<GpuParticlePipelines>>,
+    pipeline_cache: Res<PipelineCache>,
+    view_uniforms: Res<ViewUniforms>,
+    render_device: Res<RenderDevice>,
+    views: Query<(Entity, &ExtractedView, &Msaa)>,
+) {
+    if emitters.0.is_empty() {
+        return;
+    }
+    let Some(view_binding) = view_uniforms.uniforms.binding() else {
+        return;
+    };
+    let bind_group = render_device.create_bind_group(
+        "gpu_particles_view_bind_group",
+        &pipeline_cache.get_bind_group_layout(&pipelines.view_layout),
+        &BindGroupEntries::single(view_binding),
+    );
+
+    for (entity, view, msaa) in &views {
+        let pipeline = specialized_pipelines.specialize(
+            &pipeline_cache,
+            &pipelines,
+            GpuParticlePipelineKey {
+                target_format: view.target_format,
+                samples: msaa.samples(),
+            },
+        );
+        commands.entity(entity).insert(GpuParticleView {
+            pipeline,
+            bind_group: bind_group.clone(),
+        });
+    }
+}
+
+/// Simulates the particles of all the emitters, before the cameras are rendered.
+fn simulate_gpu_particles(
+    mut render_context: RenderContext,
+    emitters: Res<GpuEmitters>,
+    pipelines: Res<GpuParticlePipelines>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    if emitters.0.is_empty() {
+        return;
+    }
+    let Some(pipeline) = pipeline_cache.get_compute_pipeline(pipelines.simulate_pipeline) else {
+        return;
+    };
+
+    let mut pass = render_context
+        .command_encoder()
+        .begin_compute_pass(&ComputePassDescriptor {
+            label: Some("gpu_particles_simulate"),
+            timestamp_writes: None,
+        });
+    pass.set_pipeline(pipeline);
+    for emitter in emitters.0.values() {
+        let Some((bind_group, _)) = &emitter.bind_groups else {
+            continue;
+        };
+        pass.set_bind_group(0, bind_group, &[]);
+        pass.dispatch_workgroups(emitter.capacity.div_ceil(WORKGROUP_SIZE), 1, 1);
+    }
+}
+
+/// Draws the particles of the visible emitters, after the main 3D pass.
+fn draw_gpu_particles(
+    view: ViewQuery<(
+        &ExtractedCamera,
+        &ViewTarget,
+        &ViewDepthStencilTexture,
+        &ViewUniformOffset,
+        &GpuParticleView,
+    )>,
+    emitters: Res<GpuEmitters>,
+    pipeline_cache: Res<PipelineCache>,
+    mut ctx: RenderContext,
+) {
+    let (camera, target, depth, view_uniform_offset, particle_view) = view.into_inner();
+    if !emitters.0.values().any(|emitter| emitter.visible) {
+        return;
+    }
+    let Some(pipeline) = pipeline_cache.get_render_pipeline(particle_view.pipeline) else {
+        return;
+    };
+
+    let mut render_pass = ctx.begin_tracked_render_pass(RenderPassDescriptor {
+        label: Some("gpu_particles"),
+        color_attachments: &[Some(target.get_color_attachment())],
+        depth_stencil_attachment: Some(depth.get_attachment(StoreOp::Store)),
+        timestamp_writes: None,
+        occlusion_query_set: None,
+        multiview_mask: None,
+    });
+    if let Some(viewport) = camera.viewport.as_ref() {
+        render_pass.set_camera_viewport(viewport);
+    }
+
+    render_pass.set_render_pipeline(pipeline);
+    render_pass.set_bind_group(0, &particle_view.bind_group, &[view_uniform_offset.offset]);
+    for emitter in emitters.0.values().filter(|emitter| emitter.visible) {
+        let Some((_, bind_group)) = &emitter.bind_groups else {
+            continue;
+        };
+        render_pass.set_bind_group(1, bind_group, &[]);
+        render_pass.draw(0..6, 0..emitter.capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::Vec4;
+
+    use super::pack_curve;
+    use crate::ParticleCurve;
+
+    #[test]
+    fn curves_are_packed_for_the_shaders() {
+        let packed = pack_curve(&ParticleCurve(vec![(0.0, 0.0), (1.0, 15.0)]));
+        assert_eq!(packed[0], Vec4::new(0.0, 1.0, 2.0, 3.0));
+        assert_eq!(packed[3], Vec4::new(12.0, 13.0, 14.0, 15.0));
+    }
+}
@@ -0,0 +1,63 @@
+#import bevy_render::view::View
+#import bevy_particles::gpu_particles::{Emitter, Particle, progress, sample_color, sample_packed}
+
+@group(0) @binding(0) var<uniform> view: View;
+@group(1) @binding(0) var<uniform> emitter: Emitter;
+@group(1) @binding(1) var<storage, read> particles: array<Particle>;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) color: vec4<f32>,
+}
+
+@vertex
+fn vertex(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32,
+) -> VertexOutput {
+    var out: VertexOutput;
+    let particle = particles[instance_index];
+    if particle.age >= particle.lifetime {
+        // Dead particles are culled as degenerate triangles.
+        out.position = vec4(0.0, 0.0, 0.0, 0.0);
+        return out;
+    }
+
+    // Two triangles of a quad centered on the particle.
+    var corners = array(
+        vec2(-1.0, -1.0), vec2(1.0, -1.0), vec2(1.0, 1.0),
+        vec2(-1.0, -1.0), vec2(1.0, 1.0), vec2(-1.0, 1.0),
+    );
+    let corner = corners[vertex_index % 6u];
+    let t = progress(particle);
+    let size = sample_packed(emitter.size, t) * 0.5;
+    let rotated = vec2(
+        corner.x * cos(particle.rotation) - corner.y * sin(particle.rotation),
+        corner.x * sin(particle.rotation) + corner.y * cos(particle.rotation),
+    );
+
+    var world_position = particle.position;
+    if emitter.local_space != 0u {
+        world_position = (emitter.world_from_emitter * vec4(world_position, 1.0)).xyz;
+    }
+    // The quad faces the camera.
+    let right = view.world_from_view[0].xyz;
+    let up = view.world_from_view[1].xyz;
+    world_position += (right * rotated.x + up * rotated.y) * size;
+
+    out.position = view.clip_from_world * vec4(world_position, 1.0);
+    out.uv = corner;
+    out.color = sample_color(emitter.color, t);
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    // A round particle, faded towards its edge.
+    let falloff = 1.0 - smoothstep(0.5, 1.0, length(in.uv));
+    if falloff <= 0.0 {
+        discard;
+    }
+    return vec4(in.color.rgb, in.color.a * falloff);
+}
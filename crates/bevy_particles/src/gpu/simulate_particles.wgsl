@@ -0,0 +1,120 @@
+#import bevy_particles::gpu_particles::{
+    Emitter, Particle, progress, sample_packed,
+    SHAPE_SPHERE, SHAPE_CUBOID, SHAPE_CIRCLE,
+}
+
+const TAU: f32 = 6.28318530718;
+
+@group(0) @binding(0) var<uniform> emitter: Emitter;
+@group(0) @binding(1) var<storage, read_write> particles: array<Particle>;
+
+// A PCG hash, see https://www.jcgt.org/published/0009/03/02/.
+fn hash(value: u32) -> u32 {
+    let state = value * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+struct Rng {
+    state: u32,
+}
+
+fn random(rng: ptr<function, Rng>) -> f32 {
+    (*rng).state = hash((*rng).state);
+    return f32((*rng).state >> 8u) / 16777216.0;
+}
+
+fn random_range(rng: ptr<function, Rng>, range: vec2<f32>) -> f32 {
+    return mix(range.x, range.y, random(rng));
+}
+
+fn random_in_sphere(rng: ptr<function, Rng>) -> vec3<f32> {
+    let cos_theta = random(rng) * 2.0 - 1.0;
+    let sin_theta = sqrt(max(1.0 - cos_theta * cos_theta, 0.0));
+    let phi = random(rng) * TAU;
+    let radius = pow(random(rng), 1.0 / 3.0);
+    return vec3(sin_theta * cos(phi), sin_theta * sin(phi), cos_theta) * radius;
+}
+
+fn spawn_position(rng: ptr<function, Rng>) -> vec3<f32> {
+    switch emitter.shape_kind {
+        case SHAPE_SPHERE: {
+            return random_in_sphere(rng) * emitter.shape.x;
+        }
+        case SHAPE_CUBOID: {
+            let unit = vec3(random(rng), random(rng), random(rng)) * 2.0 - 1.0;
+            return unit * emitter.shape.xyz;
+        }
+        case SHAPE_CIRCLE: {
+            let radius = sqrt(random(rng)) * emitter.shape.x;
+            let phi = random(rng) * TAU;
+            return vec3(cos(phi), 0.0, sin(phi)) * radius;
+        }
+        default: {
+            return vec3(0.0);
+        }
+    }
+}
+
+// Returns a random direction in the cone of half-angle `emitter.spread` around `emitter.direction`.
+fn spawn_direction(rng: ptr<function, Rng>) -> vec3<f32> {
+    let cos_theta = 1.0 - random(rng) * (1.0 - cos(emitter.spread));
+    let sin_theta = sqrt(max(1.0 - cos_theta * cos_theta, 0.0));
+    let phi = random(rng) * TAU;
+    let local = vec3(sin_theta * cos(phi), sin_theta * sin(phi), cos_theta);
+
+    // Rotates the cone around Z to the direction of the emitter.
+    let forward = emitter.direction;
+    var up = vec3(0.0, 1.0, 0.0);
+    if abs(forward.y) > 0.999 {
+        up = vec3(1.0, 0.0, 0.0);
+    }
+    let right = normalize(cross(up, forward));
+    let true_up = cross(forward, right);
+    return right * local.x + true_up * local.y + forward * local.z;
+}
+
+fn spawn(index: u32) -> Particle {
+    var rng = Rng(hash(index ^ hash(emitter.seed)));
+    var particle: Particle;
+    var position = spawn_position(&rng);
+    var velocity = spawn_direction(&rng) * random_range(&rng, emitter.speed);
+    if emitter.local_space == 0u {
+        position = (emitter.world_from_emitter * vec4(position, 1.0)).xyz;
+        velocity = (emitter.world_from_emitter * vec4(velocity, 0.0)).xyz;
+    }
+    particle.position = position;
+    particle.velocity = velocity;
+    particle.age = 0.0;
+    particle.lifetime = random_range(&rng, emitter.lifetime);
+    particle.rotation = 0.0;
+    particle.angular_velocity = random_range(&rng, emitter.rotation_speed);
+    return particle;
+}
+
+@compute @workgroup_size(64)
+fn simulate(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if index >= emitter.capacity {
+        return;
+    }
+
+    // The particles are spawned in a ring buffer, replacing the oldest ones.
+    let offset = (index + emitter.capacity - emitter.spawn_start) % emitter.capacity;
+    if offset < emitter.spawn_count {
+        particles[index] = spawn(index);
+        return;
+    }
+
+    var particle = particles[index];
+    if particle.age >= particle.lifetime {
+        return;
+    }
+    let delta = emitter.delta;
+    particle.age += delta;
+    particle.velocity += emitter.acceleration * delta;
+    particle.velocity *= max(1.0 - emitter.drag * delta, 0.0);
+    particle.position += particle.velocity * sample_packed(emitter.speed_scale, progress(particle)) * delta;
+    particle.rotation += particle.angular_velocity * delta;
+    particles[index] = particle;
+}
@@ -0,0 +1,263 @@
+//! CPU and GPU particle effects for Bevy.
+//!
+//! A [`ParticleEffect`] asset describes emitters: how many particles they spawn, in bursts or
+//! continuously, where and how fast, and how the particles look over their lifetime. A
+//! [`ParticleEffectPlayer`] plays an effect at the position of its entity, simulating the
+//! particles on the CPU and rendering them as sprites or meshes. With the `bevy_render` feature,
+//! [`ParticleRenderer::Gpu`] simulates and draws the particles in shaders instead, for effects
+//! with far more particles. With the `bevy_pbr` feature, a [`Trail`] renders the path of its
+//! entity as a ribbon.
+//!
+//! Effects are authored in `.particles.ron` files, which are reloaded while the app is running
+//! when the `file_watcher` feature of `bevy_asset` is enabled, so that artists can iterate on
+//! them without recompiling.
+//!
+//! Refer to [`ParticlePlugin`] for usage information.
+
+extern crate alloc;
+
+mod effect;
+#[cfg(feature = "bevy_render")]
+mod gpu;
+mod loader;
+mod player;
+mod simulate;
+#[cfg(feature = "bevy_pbr")]
+mod trail;
+
+pub use effect::*;
+pub use loader::*;
+pub use player::{Particle, ParticleEffectPlayer, ParticleOf, Particles};
+#[cfg(feature = "bevy_pbr")]
+pub use trail::{Trail, TrailFacing, TrailTextureMode};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::AssetApp;
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_transform::TransformSystems;
+
+/// The particles prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{
+        Particle, ParticleEffect, ParticleEffectPlayer, ParticleEmitter, ParticlePlugin,
+        ParticleRenderer,
+    };
+
+    #[cfg(feature = "bevy_pbr")]
+    #[doc(hidden)]
+    pub use crate::Trail;
+}
+
+/// Loads and plays [`ParticleEffect`]s.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::{AssetPlugin, AssetServer};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_particles::prelude::*;
+/// App::new()
+///     .add_plugins((AssetPlugin::default(), ParticlePlugin))
+///     .add_systems(Startup, |mut commands: Commands, asset_server: Res<AssetServer>| {
+///         commands.spawn(ParticleEffectPlayer::new(
+///             asset_server.load("effects/sparks.particles.ron"),
+///         ));
+///     });
+/// ```
+///
+/// The particles are simulated in [`PostUpdate`], before the transforms are propagated, so that
+/// they are rendered where they are during the same frame. Add the
+/// [`RngPlugin`](bevy_rand::RngPlugin) with a seed to play the effects the same way from one run
+/// to the next.
+#[derive(Default)]
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ParticleEffect>()
+            .init_asset_loader::<ParticleEffectLoader>()
+            .register_type::<ParticleEffectPlayer>()
+            .register_type::<Particle>()
+            .register_type::<ParticleOf>()
+            .register_type::<Particles>()
+            .add_systems(
+                PostUpdate,
+                (
+                    simulate::restart_changed_effects,
+                    simulate::update_particles,
+                    #[cfg(feature = "bevy_sprite")]
+                    simulate::update_particle_sprites,
+                    simulate::spawn_particles,
+                )
+                    .chain()
+                    .in_set(ParticleSystems)
+                    .before(TransformSystems::Propagate),
+            );
+
+        #[cfg(feature = "bevy_render")]
+        app.add_plugins(gpu::GpuParticlePlugin);
+
+        #[cfg(feature = "bevy_pbr")]
+        app.init_resource::<trail::TrailBatches>().add_systems(
+            PostUpdate,
+            (trail::record_trails, trail::build_trail_meshes)
+                .chain()
+                .in_set(TrailSystems)
+                .after(TransformSystems::Propagate),
+        );
+    }
+}
+
+/// The system set in which the particles are spawned and simulated, in [`PostUpdate`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParticleSystems;
+
+/// The system set in which the [`Trail`]s are recorded and their meshes rebuilt, in
+/// [`PostUpdate`], after the transforms are propagated.
+#[cfg(feature = "bevy_pbr")]
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TrailSystems;
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_asset::{AssetEvent, Assets, Handle};
+    use bevy_color::{Gray, LinearRgba};
+    use bevy_ecs::{message::Messages, prelude::*};
+    use bevy_math::Vec3;
+    use bevy_rand::EntityRng;
+    use bevy_time::Time;
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use crate::{
+        simulate, Burst, Particle, ParticleCurve, ParticleEffect, ParticleEffectPlayer,
+        ParticleEmitter, Particles,
+    };
+
+    fn setup(effect: ParticleEffect) -> (World, Schedule, Handle<ParticleEffect>) {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Messages<AssetEvent<ParticleEffect>>>();
+        let handle = world
+            .get_resource_or_init::<Assets<ParticleEffect>>()
+            .add(effect);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                simulate::restart_changed_effects,
+                simulate::update_particles,
+                simulate::spawn_particles,
+            )
+                .chain(),
+        );
+        (world, schedule, handle)
+    }
+
+    fn step(world: &mut World, schedule: &mut Schedule, seconds: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(seconds));
+        schedule.run(world);
+    }
+
+    fn particle_count(world: &World, player: Entity) -> usize {
+        world
+            .get::<Particles>(player)
+            .map_or(0, RelationshipTarget::len)
+    }
+
+    #[test]
+    fn bursts_rate_and_lifetime() {
+        let effect = ParticleEffect::default().with_emitter(ParticleEmitter {
+            rate: 8.0,
+            bursts: vec![Burst {
+                time: 0.0,
+                count: 5,
+            }],
+            duration: Some(1.0),
+            lifetime: (0.75, 0.75),
+            speed: (2.0, 2.0),
+            ..Default::default()
+        });
+        let (mut world, mut schedule, handle) = setup(effect);
+        let player = world
+            .spawn((
+                ParticleEffectPlayer::new(handle),
+                EntityRng::new(0),
+                GlobalTransform::from_xyz(1.0, 0.0, 0.0),
+            ))
+            .id();
+
+        // The burst, and a quarter of a second of the rate.
+        step(&mut world, &mut schedule, 0.25);
+        assert_eq!(particle_count(&world, player), 7);
+
+        step(&mut world, &mut schedule, 0.5);
+        assert_eq!(particle_count(&world, player), 11);
+        let mut particles = world.query::<(&Particle, &Transform)>();
+        let (particle, transform) = particles
+            .iter(&world)
+            .find(|(particle, _)| particle.age > 0.0)
+            .unwrap();
+        assert_eq!(particle.velocity, Vec3::new(0.0, 2.0, 0.0));
+        assert_eq!(transform.translation, Vec3::new(1.0, 1.0, 0.0));
+
+        // The first particles die, and the emitter stops after its duration.
+        step(&mut world, &mut schedule, 0.5);
+        assert_eq!(particle_count(&world, player), 6);
+        step(&mut world, &mut schedule, 1.0);
+        assert_eq!(particle_count(&world, player), 0);
+    }
+
+    #[test]
+    fn looping_and_max_particles() {
+        let effect = ParticleEffect::default().with_emitter(ParticleEmitter {
+            rate: 0.0,
+            bursts: vec![Burst {
+                time: 0.0,
+                count: 4,
+            }],
+            duration: Some(0.5),
+            looping: true,
+            max_particles: 6,
+            lifetime: (10.0, 10.0),
+            ..Default::default()
+        });
+        let (mut world, mut schedule, handle) = setup(effect);
+        let player = world
+            .spawn((ParticleEffectPlayer::new(handle), EntityRng::new(0)))
+            .id();
+
+        step(&mut world, &mut schedule, 0.25);
+        assert_eq!(particle_count(&world, player), 4);
+        step(&mut world, &mut schedule, 0.5);
+        assert_eq!(particle_count(&world, player), 6);
+
+        // Pausing freezes the emitter and the particles.
+        world
+            .get_mut::<ParticleEffectPlayer>(player)
+            .unwrap()
+            .paused = true;
+        step(&mut world, &mut schedule, 5.0);
+        let mut particles = world.query::<&Particle>();
+        assert!(particles.iter(&world).all(|particle| particle.age < 1.0));
+    }
+
+    #[test]
+    fn curve_sampling() {
+        let curve = ParticleCurve(vec![
+            (0.0, LinearRgba::BLACK),
+            (0.5, LinearRgba::WHITE),
+            (1.0, LinearRgba::NONE),
+        ]);
+        assert_eq!(curve.sample(-1.0), LinearRgba::BLACK);
+        assert_eq!(curve.sample(0.25), LinearRgba::gray(0.5));
+        assert_eq!(curve.sample(0.75), LinearRgba::new(0.5, 0.5, 0.5, 0.5));
+        assert_eq!(curve.sample(2.0), LinearRgba::NONE);
+        assert_eq!(ParticleCurve::<f32>::default().sample(0.5), 0.0);
+    }
+}
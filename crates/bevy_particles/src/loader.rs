@@ -0,0 +1,137 @@
+use alloc::vec::Vec;
+use std::io;
+
+use bevy_asset::{io::Reader, AssetLoader, AssetPath, LoadContext};
+use bevy_reflect::TypePath;
+use ron::de::SpannedError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{ParticleEffect, ParticleEmitter, ParticleRenderer};
+
+/// Loads [`ParticleEffect`]s from `.particles.ron` files.
+///
+/// The files describe a [`SerializedParticleEffect`], whose renderers reference their assets by
+/// path.
+#[derive(Clone, Copy, Debug, Default, TypePath)]
+pub struct ParticleEffectLoader;
+
+/// The serialized form of a [`ParticleEffect`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SerializedParticleEffect {
+    /// The emitters of the effect.
+    pub emitters: Vec<ParticleEmitter<SerializedParticleRenderer>>,
+}
+
+/// The serialized form of a [`ParticleRenderer`], with the paths of its assets.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub enum SerializedParticleRenderer {
+    /// See [`ParticleRenderer::None`].
+    #[default]
+    None,
+    /// See `ParticleRenderer::Sprite`.
+    Sprite {
+        /// The path of the image of the sprites.
+        image: AssetPath<'static>,
+    },
+    /// See `ParticleRenderer::Mesh`.
+    Mesh {
+        /// The path of the mesh of the particles, like `models/debris.glb#Mesh0/Primitive0`.
+        mesh: AssetPath<'static>,
+        /// The path of the material of the particles, like `models/debris.glb#Material0`.
+        material: AssetPath<'static>,
+    },
+    /// See `ParticleRenderer::Gpu`.
+    Gpu,
+}
+
+/// Errors that can occur when loading particle effects from RON.
+#[derive(Error, Debug)]
+pub enum ParticleEffectLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An error occurred in RON deserialization.
+    #[error(transparent)]
+    Ron(#[from] ron::Error),
+    /// An error occurred in RON deserialization, and the location of the error
+    /// is supplied.
+    #[error(transparent)]
+    SpannedRon(#[from] SpannedError),
+    /// A renderer of the effect requires a feature of `bevy_particles` that is disabled.
+    #[error("The particle renderer {0} requires the `{1}` feature of bevy_particles")]
+    UnsupportedRenderer(&'static str, &'static str),
+}
+
+impl AssetLoader for ParticleEffectLoader {
+    type Asset = ParticleEffect;
+
+    type Settings = ();
+
+    type Error = ParticleEffectLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        let serialized_effect = SerializedParticleEffect::deserialize(&mut deserializer)
+            .map_err(|err| deserializer.span_error(err))?;
+
+        let mut emitters = Vec::with_capacity(serialized_effect.emitters.len());
+        for emitter in serialized_effect.emitters {
+            let renderer = load_renderer(&emitter.renderer, load_context)?;
+            emitters.push(emitter.map_renderer(|_| renderer));
+        }
+        Ok(ParticleEffect { emitters })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["particles.ron"]
+    }
+}
+
+#[cfg_attr(
+    not(any(feature = "bevy_sprite", feature = "bevy_pbr")),
+    expect(
+        unused_variables,
+        reason = "only the sprite and mesh renderers load assets"
+    )
+)]
+fn load_renderer(
+    renderer: &SerializedParticleRenderer,
+    load_context: &mut LoadContext<'_>,
+) -> Result<ParticleRenderer, ParticleEffectLoadError> {
+    match renderer {
+        SerializedParticleRenderer::None => Ok(ParticleRenderer::None),
+        #[cfg(feature = "bevy_sprite")]
+        SerializedParticleRenderer::Sprite { image } => Ok(ParticleRenderer::Sprite {
+            image: load_context.load(image.clone()),
+        }),
+        #[cfg(not(feature = "bevy_sprite"))]
+        SerializedParticleRenderer::Sprite { .. } => Err(
+            ParticleEffectLoadError::UnsupportedRenderer("Sprite", "bevy_sprite"),
+        ),
+        #[cfg(feature = "bevy_pbr")]
+        SerializedParticleRenderer::Mesh { mesh, material } => Ok(ParticleRenderer::Mesh {
+            mesh: load_context.load(mesh.clone()),
+            material: load_context.load(material.clone()),
+        }),
+        #[cfg(not(feature = "bevy_pbr"))]
+        SerializedParticleRenderer::Mesh { .. } => Err(
+            ParticleEffectLoadError::UnsupportedRenderer("Mesh", "bevy_pbr"),
+        ),
+        #[cfg(feature = "bevy_render")]
+        SerializedParticleRenderer::Gpu => Ok(ParticleRenderer::Gpu),
+        #[cfg(not(feature = "bevy_render"))]
+        SerializedParticleRenderer::Gpu => Err(ParticleEffectLoadError::UnsupportedRenderer(
+            "Gpu",
+            "bevy_render",
+        )),
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use bevy_asset::{AssetId, Handle};
+use bevy_camera::visibility::Visibility;
+use bevy_ecs::{component::Component, entity::Entity, reflect::ReflectComponent};
+use bevy_math::{ops, Vec3};
+use bevy_rand::ForkRng;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::Transform;
+
+use crate::{ParticleEffect, ParticleEmitter};
+
+/// Plays a [`ParticleEffect`] at the position of the entity.
+///
+/// ```
+/// # use bevy_asset::AssetServer;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_particles::ParticleEffectPlayer;
+/// # use bevy_transform::components::Transform;
+/// fn spawn_campfire(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         ParticleEffectPlayer::new(asset_server.load("effects/fire.particles.ron")),
+///         Transform::from_xyz(0.0, 0.5, 0.0),
+///     ));
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_campfire);
+/// ```
+///
+/// The particles are drawn from the [`EntityRng`](bevy_rand::EntityRng) of the entity, forked
+/// from the [`GlobalRng`](bevy_rand::GlobalRng) when the player is spawned, so that effects play
+/// the same way from one run to the next when the app is seeded. The particles are despawned
+/// with the player, and the effect restarts when it is changed or reloaded.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Debug, Clone)]
+#[require(Transform, Visibility, ForkRng, EmitterStates)]
+pub struct ParticleEffectPlayer {
+    /// The effect to play.
+    pub effect: Handle<ParticleEffect>,
+    /// Whether the particles are frozen: no particle is spawned, and the particles alive neither
+    /// move nor age.
+    pub paused: bool,
+}
+
+impl ParticleEffectPlayer {
+    /// Plays `effect`.
+    pub fn new(effect: Handle<ParticleEffect>) -> Self {
+        Self {
+            effect,
+            paused: false,
+        }
+    }
+}
+
+/// A particle spawned by a [`ParticleEffectPlayer`].
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Debug, Clone)]
+pub struct Particle {
+    /// The index of the emitter of the particle in its [`ParticleEffect`].
+    pub emitter: usize,
+    /// How long the particle has been alive, in seconds.
+    pub age: f32,
+    /// How long the particle lives, in seconds.
+    pub lifetime: f32,
+    /// The velocity of the particle, in the space of its
+    /// [`SimulationSpace`](crate::SimulationSpace).
+    pub velocity: Vec3,
+    /// The speed at which the particle spins around its forward axis, in radians per second.
+    pub angular_velocity: f32,
+}
+
+impl Particle {
+    /// Returns how far the particle is in its life, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        if self.lifetime > 0.0 {
+            (self.age / self.lifetime).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The [`ParticleEffectPlayer`] that spawned a [`Particle`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+#[relationship(relationship_target = Particles)]
+pub struct ParticleOf(pub Entity);
+
+/// The [`Particle`]s alive of a [`ParticleEffectPlayer`], despawned with it.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[relationship_target(relationship = ParticleOf, linked_spawn)]
+pub struct Particles(Vec<Entity>);
+
+/// The progress of the emitters of a [`ParticleEffectPlayer`].
+#[derive(Component, Clone, Debug, Default)]
+pub(crate) struct EmitterStates {
+    /// The effect the emitters were started for, to restart them when it changes.
+    pub(crate) effect: Option<AssetId<ParticleEffect>>,
+    /// How many times the emitters restarted, to clear the particles simulated on the GPU.
+    pub(crate) restarts: u32,
+    pub(crate) emitters: Vec<EmitterState>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct EmitterState {
+    /// The time since the emitter started, or last looped, in seconds.
+    time: f32,
+    /// The fraction of a particle left to spawn at the rate of the emitter.
+    pending: f32,
+    finished: bool,
+    /// How many particles the emitter spawned this frame, for the particles simulated on the GPU.
+    pub(crate) spawned: u32,
+}
+
+impl EmitterState {
+    /// Advances the emitter by `delta` seconds, and returns how many particles to spawn.
+    pub(crate) fn advance<R>(&mut self, emitter: &ParticleEmitter<R>, delta: f32) -> u32 {
+        let mut spawned = 0;
+        let mut to = self.time + delta;
+        while !self.finished {
+            let end = emitter.duration.map_or(to, |duration| to.min(duration));
+            self.pending += emitter.rate * (end - self.time).max(0.0);
+            spawned += emitter
+                .bursts
+                .iter()
+                .filter(|burst| self.time <= burst.time && burst.time < end)
+                .map(|burst| burst.count)
+                .sum::<u32>();
+            self.time = end;
+
+            match emitter.duration {
+                Some(duration) if self.time >= duration => {
+                    if emitter.looping && duration > 0.0 {
+                        to -= duration;
+                        self.time = 0.0;
+                    } else {
+                        self.finished = true;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        let pending = ops::floor(self.pending);
+        self.pending -= pending;
+        spawned + pending as u32
+    }
+}
@@ -0,0 +1,293 @@
+use core::f32::consts::TAU;
+
+use bevy_asset::{AssetEvent, Assets};
+#[cfg(feature = "bevy_sprite")]
+use bevy_color::Color;
+use bevy_ecs::{
+    entity::Entity,
+    hierarchy::ChildOf,
+    message::MessageReader,
+    relationship::RelationshipTarget,
+    system::{Commands, Query, Res},
+};
+use bevy_math::{
+    ops,
+    primitives::{Circle, Cuboid, Sphere},
+    Dir3, Quat, ShapeSample, Vec3,
+};
+#[cfg(feature = "bevy_pbr")]
+use bevy_mesh::Mesh3d;
+#[cfg(feature = "bevy_pbr")]
+use bevy_pbr::MeshMaterial3d;
+use bevy_platform::collections::HashSet;
+use bevy_rand::{ChaCha8Rng, EntityRng};
+#[cfg(feature = "bevy_sprite")]
+use bevy_sprite::Sprite;
+use bevy_time::Time;
+use bevy_transform::components::{GlobalTransform, Transform};
+use rand::RngExt;
+
+use crate::{
+    player::EmitterStates, EmitterShape, Particle, ParticleEffect, ParticleEffectPlayer,
+    ParticleEmitter, ParticleOf, ParticleRenderer, Particles, SimulationSpace,
+};
+
+/// Restarts the [`ParticleEffectPlayer`]s whose effect changed, was loaded or was reloaded.
+pub(crate) fn restart_changed_effects(
+    mut events: MessageReader<AssetEvent<ParticleEffect>>,
+    mut players: Query<(
+        &ParticleEffectPlayer,
+        &mut EmitterStates,
+        Option<&Particles>,
+    )>,
+    mut commands: Commands,
+) {
+    let reloaded: HashSet<_> = events
+        .read()
+        .filter_map(|event| match *event {
+            AssetEvent::Modified { id } | AssetEvent::LoadedWithDependencies { id } => Some(id),
+            _ => None,
+        })
+        .collect();
+
+    for (player, mut states, particles) in &mut players {
+        let id = player.effect.id();
+        if states.effect == Some(id) && !reloaded.contains(&id) {
+            continue;
+        }
+        states.effect = Some(id);
+        states.restarts = states.restarts.wrapping_add(1);
+        states.emitters.clear();
+        for particle in particles.into_iter().flat_map(RelationshipTarget::iter) {
+            commands.entity(particle).despawn();
+        }
+    }
+}
+
+/// Ages and moves the [`Particle`]s, and despawns the dead ones.
+pub(crate) fn update_particles(
+    time: Res<Time>,
+    effects: Res<Assets<ParticleEffect>>,
+    players: Query<&ParticleEffectPlayer>,
+    mut particles: Query<(Entity, &mut Particle, &ParticleOf, &mut Transform)>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut particle, particle_of, mut transform) in &mut particles {
+        let Ok(player) = players.get(particle_of.0) else {
+            continue;
+        };
+        if player.paused {
+            continue;
+        }
+        let Some(emitter) = effects
+            .get(&player.effect)
+            .and_then(|effect| effect.emitters.get(particle.emitter))
+        else {
+            continue;
+        };
+
+        particle.age += delta;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        particle.velocity += emitter.acceleration * delta;
+        particle.velocity *= (1.0 - emitter.drag * delta).max(0.0);
+        transform.translation +=
+            particle.velocity * emitter.speed_scale.sample(particle.progress()) * delta;
+        transform.rotate_local_z(particle.angular_velocity * delta);
+        transform.scale = Vec3::splat(emitter.size.sample(particle.progress()));
+    }
+}
+
+/// Tints the sprites of the [`Particle`]s with the color of their emitter.
+#[cfg(feature = "bevy_sprite")]
+pub(crate) fn update_particle_sprites(
+    effects: Res<Assets<ParticleEffect>>,
+    players: Query<&ParticleEffectPlayer>,
+    mut particles: Query<(&Particle, &ParticleOf, &mut Sprite)>,
+) {
+    for (particle, particle_of, mut sprite) in &mut particles {
+        let Some(emitter) = players
+            .get(particle_of.0)
+            .ok()
+            .and_then(|player| effects.get(&player.effect))
+            .and_then(|effect| effect.emitters.get(particle.emitter))
+        else {
+            continue;
+        };
+        let color = Color::from(emitter.color.sample(particle.progress()));
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
+
+/// Advances the emitters of the [`ParticleEffectPlayer`]s, and spawns their new particles.
+pub(crate) fn spawn_particles(
+    time: Res<Time>,
+    effects: Res<Assets<ParticleEffect>>,
+    mut players: Query<(
+        Entity,
+        &ParticleEffectPlayer,
+        &mut EmitterStates,
+        Option<&mut EntityRng>,
+        &GlobalTransform,
+        Option<&Particles>,
+    )>,
+    particles: Query<&Particle>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+    for (entity, player, mut states, rng, global_transform, alive) in &mut players {
+        for state in &mut states.emitters {
+            state.spawned = 0;
+        }
+        if player.paused {
+            continue;
+        }
+        let Some(effect) = effects.get(&player.effect) else {
+            continue;
+        };
+        let Some(mut rng) = rng else {
+            // Without a `GlobalRng`, the player is given a generator of its own.
+            commands
+                .entity(entity)
+                .insert(EntityRng::new(entity.to_bits()));
+            continue;
+        };
+
+        let mut alive_counts = alloc::vec![0; effect.emitters.len()];
+        for particle in particles.iter_many(alive.into_iter().flat_map(RelationshipTarget::iter)) {
+            if let Some(count) = alive_counts.get_mut(particle.emitter) {
+                *count += 1;
+            }
+        }
+
+        states
+            .emitters
+            .resize_with(effect.emitters.len(), Default::default);
+        for (index, (emitter, state)) in
+            effect.emitters.iter().zip(&mut states.emitters).enumerate()
+        {
+            #[cfg(feature = "bevy_render")]
+            if matches!(emitter.renderer, ParticleRenderer::Gpu) {
+                // The particles are spawned by the compute shader.
+                state.spawned = state
+                    .advance(emitter, delta)
+                    .min(emitter.max_particles.try_into().unwrap_or(u32::MAX));
+                continue;
+            }
+            let count = (state.advance(emitter, delta) as usize)
+                .min(emitter.max_particles.saturating_sub(alive_counts[index]));
+            for _ in 0..count {
+                spawn_particle(
+                    &mut commands,
+                    entity,
+                    index,
+                    emitter,
+                    global_transform,
+                    &mut rng,
+                );
+            }
+        }
+    }
+}
+
+fn spawn_particle(
+    commands: &mut Commands,
+    player: Entity,
+    index: usize,
+    emitter: &ParticleEmitter,
+    global_transform: &GlobalTransform,
+    rng: &mut ChaCha8Rng,
+) {
+    let position = match emitter.shape {
+        EmitterShape::Point => Vec3::ZERO,
+        EmitterShape::Sphere { radius } => Sphere::new(radius).sample_interior(rng),
+        EmitterShape::Cuboid { half_size } => Cuboid { half_size }.sample_interior(rng),
+        EmitterShape::Circle { radius } => {
+            let position = Circle::new(radius).sample_interior(rng);
+            Vec3::new(position.x, 0.0, position.y)
+        }
+    };
+    let velocity =
+        emitted_direction(emitter.direction, emitter.spread, rng) * random(emitter.speed, rng);
+    let particle = Particle {
+        emitter: index,
+        age: 0.0,
+        lifetime: random(emitter.lifetime, rng),
+        velocity,
+        angular_velocity: random(emitter.rotation_speed, rng),
+    };
+    let scale = Vec3::splat(emitter.size.sample(0.0));
+
+    #[cfg_attr(
+        not(any(feature = "bevy_sprite", feature = "bevy_pbr")),
+        expect(
+            unused_mut,
+            unused_variables,
+            reason = "only the sprite and mesh renderers add components"
+        )
+    )]
+    let mut entity = match emitter.space {
+        SimulationSpace::World => commands.spawn((
+            Particle {
+                velocity: global_transform.rotation() * particle.velocity,
+                ..particle
+            },
+            Transform::from_translation(global_transform.transform_point(position))
+                .with_scale(scale),
+            ParticleOf(player),
+        )),
+        SimulationSpace::Local => commands.spawn((
+            particle,
+            Transform::from_translation(position).with_scale(scale),
+            ParticleOf(player),
+            ChildOf(player),
+        )),
+    };
+
+    match &emitter.renderer {
+        ParticleRenderer::None => {}
+        #[cfg(feature = "bevy_sprite")]
+        ParticleRenderer::Sprite { image } => {
+            entity.insert(Sprite {
+                image: image.clone(),
+                color: emitter.color.sample(0.0).into(),
+                ..Default::default()
+            });
+        }
+        #[cfg(feature = "bevy_pbr")]
+        ParticleRenderer::Mesh { mesh, material } => {
+            entity.insert((Mesh3d(mesh.clone()), MeshMaterial3d(material.clone())));
+        }
+        #[cfg(feature = "bevy_render")]
+        ParticleRenderer::Gpu => {}
+    }
+}
+
+/// Returns a random value between the bounds of `range`.
+fn random((min, max): (f32, f32), rng: &mut ChaCha8Rng) -> f32 {
+    min + (max - min) * rng.random::<f32>()
+}
+
+/// Returns a random direction in the cone of half-angle `spread` around `direction`, uniformly
+/// distributed over the solid angle of the cone.
+fn emitted_direction(direction: Vec3, spread: f32, rng: &mut ChaCha8Rng) -> Vec3 {
+    let direction = Dir3::new(direction).unwrap_or(Dir3::Y);
+    if spread <= 0.0 {
+        return *direction;
+    }
+    let cos_theta = 1.0 - rng.random::<f32>() * (1.0 - ops::cos(spread));
+    let sin_theta = ops::sqrt((1.0 - cos_theta * cos_theta).max(0.0));
+    let phi = rng.random::<f32>() * TAU;
+    let local = Vec3::new(
+        sin_theta * ops::cos(phi),
+        sin_theta * ops::sin(phi),
+        cos_theta,
+    );
+    Quat::from_rotation_arc(Vec3::Z, *direction) * local
+}
@@ -0,0 +1,381 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use bevy_asset::{AssetId, Assets, Handle, RenderAssetUsages};
+use bevy_camera::{visibility::NoFrustumCulling, Camera};
+use bevy_color::{ColorToComponents, LinearRgba};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_math::Vec3;
+use bevy_mesh::{Indices, Mesh, Mesh3d, PrimitiveTopology};
+use bevy_pbr::{MeshMaterial3d, StandardMaterial};
+use bevy_platform::collections::HashMap;
+use bevy_time::Time;
+use bevy_transform::components::{GlobalTransform, Transform};
+
+use crate::ParticleCurve;
+
+/// Records the past positions of the entity, and renders them as a ribbon, for sword slashes,
+/// projectiles or skid marks.
+///
+/// ```
+/// # use bevy_asset::Assets;
+/// # use bevy_color::LinearRgba;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_particles::{ParticleCurve, Trail};
+/// # use bevy_pbr::StandardMaterial;
+/// fn spawn_projectile(mut commands: Commands, mut materials: ResMut<Assets<StandardMaterial>>) {
+///     let material = materials.add(StandardMaterial {
+///         unlit: true,
+///         ..Default::default()
+///     });
+///     commands.spawn(Trail {
+///         lifetime: 0.3,
+///         width: ParticleCurve(vec![(0.0, 0.2), (1.0, 0.0)]),
+///         color: ParticleCurve(vec![(0.0, LinearRgba::WHITE), (1.0, LinearRgba::NONE)]),
+///         ..Trail::new(material)
+///     });
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_projectile);
+/// ```
+///
+/// The curves are sampled with the age of each point, from `0.0` at the head of the trail to
+/// `1.0` once the point is [`Trail::lifetime`] old, and the vertex colors multiply the color of
+/// the material. The trails sharing a material are merged into a single mesh every frame, so
+/// that they are drawn together.
+#[derive(Component, Clone, Debug)]
+#[require(Transform)]
+pub struct Trail {
+    /// The material of the ribbon.
+    pub material: Handle<StandardMaterial>,
+    /// How long each recorded position stays in the trail, in seconds.
+    pub lifetime: f32,
+    /// The distance the entity must travel before a new position is recorded.
+    pub min_distance: f32,
+    /// The maximum number of positions recorded.
+    pub max_points: usize,
+    /// The width of the ribbon over the age of its points.
+    pub width: ParticleCurve<f32>,
+    /// The color of the ribbon over the age of its points.
+    pub color: ParticleCurve<LinearRgba>,
+    /// How the texture of the material is laid along the ribbon.
+    pub texture_mode: TrailTextureMode,
+    /// How fast the texture scrolls along the ribbon, in texture lengths per second.
+    pub scroll_speed: f32,
+    /// Which way the ribbon faces.
+    pub facing: TrailFacing,
+    /// Whether new positions are recorded. The trail fades out while it is not emitting.
+    pub emitting: bool,
+    /// The recorded positions, from the newest to the oldest.
+    points: VecDeque<TrailPoint>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TrailPoint {
+    position: Vec3,
+    age: f32,
+}
+
+/// How the texture of a [`Trail`] is laid along its ribbon.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TrailTextureMode {
+    /// The texture is stretched over the whole ribbon.
+    #[default]
+    Stretch,
+    /// The texture repeats every `length` units along the ribbon.
+    Tile {
+        /// The length of the ribbon covered by the texture once.
+        length: f32,
+    },
+}
+
+/// Which way the ribbon of a [`Trail`] faces.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TrailFacing {
+    /// The ribbon turns around its path to face the active [`Camera`] with the highest
+    /// [`Camera::order`].
+    #[default]
+    Camera,
+    /// The ribbon faces the given world-space direction, like [`Vec3::Y`] for skid marks lying
+    /// on the ground.
+    Axis(Vec3),
+}
+
+impl Trail {
+    /// Creates a trail drawn with `material`, half a second long and tapering to a point.
+    pub fn new(material: Handle<StandardMaterial>) -> Self {
+        Self {
+            material,
+            lifetime: 0.5,
+            min_distance: 0.1,
+            max_points: 64,
+            width: ParticleCurve(Vec::from([(0.0, 0.2), (1.0, 0.0)])),
+            color: ParticleCurve::constant(LinearRgba::WHITE),
+            texture_mode: TrailTextureMode::Stretch,
+            scroll_speed: 0.0,
+            facing: TrailFacing::Camera,
+            emitting: true,
+            points: VecDeque::new(),
+        }
+    }
+
+    /// Returns the recorded positions, from the newest to the oldest.
+    pub fn points(&self) -> impl ExactSizeIterator<Item = Vec3> + '_ {
+        self.points.iter().map(|point| point.position)
+    }
+
+    /// Forgets the recorded positions, for example when the entity is teleported.
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    /// Ages the points by `delta` seconds, and records `position` if the trail is emitting.
+    fn record(&mut self, position: Vec3, delta: f32) {
+        for point in &mut self.points {
+            point.age += delta;
+        }
+        while self
+            .points
+            .back()
+            .is_some_and(|point| point.age >= self.lifetime)
+        {
+            self.points.pop_back();
+        }
+        if !self.emitting {
+            return;
+        }
+
+        // The head follows the entity until it is far enough from the previous point to be
+        // recorded, so that the ribbon stays attached to the entity.
+        let head = TrailPoint { position, age: 0.0 };
+        match self.points.get(1) {
+            Some(previous) if previous.position.distance(position) < self.min_distance => {
+                self.points[0] = head;
+            }
+            _ => self.points.push_front(head),
+        }
+        self.points.truncate(self.max_points.max(2));
+    }
+}
+
+/// Records the positions of the [`Trail`]s.
+pub(crate) fn record_trails(time: Res<Time>, mut trails: Query<(&mut Trail, &GlobalTransform)>) {
+    let delta = time.delta_secs();
+    for (mut trail, transform) in &mut trails {
+        trail.record(transform.translation(), delta);
+    }
+}
+
+/// The entities drawing the [`Trail`]s of each material.
+#[derive(Resource, Default)]
+pub(crate) struct TrailBatches(HashMap<AssetId<StandardMaterial>, TrailBatch>);
+
+struct TrailBatch {
+    entity: Entity,
+    mesh: Handle<Mesh>,
+}
+
+/// The vertices of the ribbons of the [`Trail`]s sharing a material.
+#[derive(Default)]
+struct RibbonBuffers {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    colors: Vec<[f32; 4]>,
+    indices: Vec<u32>,
+}
+
+impl RibbonBuffers {
+    /// Adds the ribbon of `trail`, facing `view` for [`TrailFacing::Camera`], with its texture
+    /// shifted by `scroll`.
+    fn add(&mut self, trail: &Trail, view: Option<Vec3>, scroll: f32) {
+        let points = &trail.points;
+        if points.len() < 2 {
+            return;
+        }
+        let length: f32 = points
+            .iter()
+            .zip(points.iter().skip(1))
+            .map(|(a, b)| a.position.distance(b.position))
+            .sum();
+
+        let start = self.positions.len() as u32;
+        let mut distance = 0.0;
+        for (index, point) in points.iter().enumerate() {
+            let previous = points[index.saturating_sub(1)].position;
+            let next = points[(index + 1).min(points.len() - 1)].position;
+            if index > 0 {
+                distance += previous.distance(point.position);
+            }
+
+            let facing = match trail.facing {
+                TrailFacing::Camera => view.map_or(Vec3::Z, |view| view - point.position),
+                TrailFacing::Axis(axis) => axis,
+            }
+            .normalize_or_zero();
+            let side = (previous - next).cross(facing).normalize_or_zero();
+            let t = if trail.lifetime > 0.0 {
+                (point.age / trail.lifetime).clamp(0.0, 1.0)
+            } else {
+                1.0
+            };
+            let half_width = side * trail.width.sample(t) * 0.5;
+            let u = match trail.texture_mode {
+                TrailTextureMode::Stretch if length > 0.0 => distance / length,
+                TrailTextureMode::Stretch => 0.0,
+                TrailTextureMode::Tile { length } => distance / length,
+            } - scroll;
+            let color = trail.color.sample(t).to_f32_array();
+
+            self.positions
+                .push((point.position + half_width).to_array());
+            self.positions
+                .push((point.position - half_width).to_array());
+            self.normals.extend([facing.to_array(); 2]);
+            self.uvs.push([u, 0.0]);
+            self.uvs.push([u, 1.0]);
+            self.colors.extend([color; 2]);
+        }
+        for segment in 0..points.len() as u32 - 1 {
+            let corner = start + segment * 2;
+            self.indices.extend([
+                corner,
+                corner + 1,
+                corner + 2,
+                corner + 2,
+                corner + 1,
+                corner + 3,
+            ]);
+        }
+    }
+
+    fn write(self, mesh: &mut Mesh) {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, self.positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, self.colors);
+        mesh.insert_indices(Indices::U32(self.indices));
+    }
+}
+
+/// Rebuilds the meshes of the ribbons of the [`Trail`]s, one per material.
+pub(crate) fn build_trail_meshes(
+    time: Res<Time>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    trails: Query<&Trail>,
+    mut batches: ResMut<TrailBatches>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut commands: Commands,
+) {
+    let view = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .max_by_key(|(camera, _)| camera.order)
+        .map(|(_, transform)| transform.translation());
+
+    let mut ribbons: HashMap<
+        AssetId<StandardMaterial>,
+        (&Handle<StandardMaterial>, RibbonBuffers),
+    > = HashMap::default();
+    for trail in &trails {
+        if trail.points.len() < 2 {
+            continue;
+        }
+        let scroll = trail.scroll_speed * time.elapsed_secs_wrapped();
+        ribbons
+            .entry(trail.material.id())
+            .or_insert_with(|| (&trail.material, RibbonBuffers::default()))
+            .1
+            .add(trail, view, scroll);
+    }
+
+    batches.0.retain(|material, batch| {
+        let used = ribbons.contains_key(material);
+        if !used {
+            commands.entity(batch.entity).despawn();
+            meshes.remove(&batch.mesh);
+        }
+        used
+    });
+    for (material, (handle, buffers)) in ribbons {
+        if let Some(mut mesh) = batches
+            .0
+            .get(&material)
+            .and_then(|batch| meshes.get_mut(&batch.mesh))
+        {
+            buffers.write(&mut mesh);
+            continue;
+        }
+
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        );
+        buffers.write(&mut mesh);
+        let mesh = meshes.add(mesh);
+        // The ribbons are in world space, and their bounds change every frame.
+        let entity = commands
+            .spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(handle.clone()),
+                Transform::IDENTITY,
+                NoFrustumCulling,
+            ))
+            .id();
+        batches.0.insert(material, TrailBatch { entity, mesh });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_math::Vec3;
+
+    use super::{RibbonBuffers, Trail, TrailFacing, TrailTextureMode};
+
+    #[test]
+    fn record_and_build_ribbon() {
+        let mut trail = Trail {
+            lifetime: 1.0,
+            min_distance: 1.0,
+            facing: TrailFacing::Axis(Vec3::Y),
+            texture_mode: TrailTextureMode::Tile { length: 2.0 },
+            ..Trail::new(Handle::default())
+        };
+        trail.record(Vec3::ZERO, 0.0);
+        trail.record(Vec3::new(0.5, 0.0, 0.0), 0.25);
+        assert_eq!(trail.points().len(), 2);
+
+        // The head follows the entity until it is a unit away from the previous point.
+        trail.record(Vec3::new(0.75, 0.0, 0.0), 0.25);
+        assert_eq!(trail.points().len(), 2);
+        trail.record(Vec3::new(2.0, 0.0, 0.0), 0.25);
+        assert_eq!(
+            trail.points().collect::<Vec<_>>(),
+            [
+                Vec3::new(2.0, 0.0, 0.0),
+                Vec3::new(0.75, 0.0, 0.0),
+                Vec3::ZERO
+            ]
+        );
+
+        let mut buffers = RibbonBuffers::default();
+        buffers.add(&trail, None, 0.0);
+        assert_eq!(buffers.positions.len(), 6);
+        assert_eq!(buffers.indices.len(), 12);
+        // The head is 0.2 wide across the path, and the texture repeats every two units.
+        assert_eq!(buffers.positions[0], [2.0, 0.0, 0.1]);
+        assert_eq!(buffers.positions[1], [2.0, 0.0, -0.1]);
+        assert_eq!(buffers.uvs[4], [1.0, 0.0]);
+
+        // Once it stops emitting, the trail fades out.
+        trail.emitting = false;
+        trail.record(Vec3::new(3.0, 0.0, 0.0), 0.5);
+        assert_eq!(trail.points().len(), 2);
+        trail.record(Vec3::new(3.0, 0.0, 0.0), 0.5);
+        assert_eq!(trail.points().len(), 0);
+    }
+}
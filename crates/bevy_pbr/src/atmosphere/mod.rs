@@ -58,8 +58,9 @@ use bevy_light::{atmosphere::ScatteringMedium, Atmosphere};
 use bevy_math::{Mat4, Quat, UVec2, UVec3, Vec3};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
+    capabilities::{CapabilityRequirements, CapabilitySupport, RenderCapabilityReport},
     extract_component::{ExtractComponentPlugin, UniformComponentPlugin},
-    render_resource::{DownlevelFlags, ShaderType, SpecializedRenderPipelines},
+    render_resource::{ShaderType, SpecializedRenderPipelines},
     renderer::RenderDevice,
     sync_component::{SyncComponent, SyncComponentPlugin},
     sync_world::RenderEntity,
@@ -83,7 +84,6 @@ use resources::{
     prepare_atmosphere_transforms, prepare_atmosphere_uniforms, queue_render_sky_pipelines,
     AtmosphereTransforms, GpuAtmosphere, RenderSkyBindGroupLayouts,
 };
-use tracing::warn;
 
 use crate::resources::prepare_atmosphere_buffers;
 
@@ -128,14 +128,27 @@ impl Plugin for AtmospherePlugin {
             return;
         };
 
-        let render_adapter = render_app.world().resource::<RenderAdapter>();
-
-        if !render_adapter
-            .get_downlevel_capabilities()
-            .flags
-            .contains(DownlevelFlags::COMPUTE_SHADERS)
-        {
-            warn!("AtmospherePlugin not loaded. GPU lacks support for compute shaders.");
+        let world = render_app.world();
+        let capability_report = world.resource::<RenderCapabilityReport>();
+        let render_adapter = world.resource::<RenderAdapter>();
+
+        // The limits are checked against the `RenderDevice`, which takes the
+        // `WGPU_SETTINGS_PRIO` environment variable into account, unlike the
+        // `RenderAdapter`.
+        let requirements = CapabilityRequirements::new()
+            .with_compute_shaders()
+            .with_min_limit(
+                "max_storage_textures_per_shader_stage",
+                |limits| limits.max_storage_textures_per_shader_stage.into(),
+                1,
+            );
+        if let Err(reason) = requirements.check(world.resource::<RenderDevice>(), render_adapter) {
+            capability_report.record(
+                "atmosphere",
+                CapabilitySupport::Unsupported {
+                    reason: reason.into(),
+                },
+            );
             return;
         }
 
@@ -144,18 +157,15 @@ impl Plugin for AtmospherePlugin {
             .allowed_usages
             .contains(TextureUsages::STORAGE_BINDING)
         {
-            warn!("AtmospherePlugin not loaded. GPU lacks support: TextureFormat::Rgba16Float does not support TextureUsages::STORAGE_BINDING.");
-            return;
-        }
-
-        // Check the `RenderDevice` in addition to the `RenderAdapter`. The
-        // former takes the `WGPU_SETTINGS_PRIO` environment variable into
-        // account, and the latter doesn't.
-        let render_device = render_app.world().resource::<RenderDevice>();
-        if render_device.limits().max_storage_textures_per_shader_stage == 0 {
-            warn!("AtmospherePlugin not loaded. GPU lacks support: `max_storage_textures_per_shader_stage` is 0");
+            capability_report.record(
+                "atmosphere",
+                CapabilitySupport::Unsupported {
+                    reason: "`TextureFormat::Rgba16Float` does not support `TextureUsages::STORAGE_BINDING`".into(),
+                },
+            );
             return;
         }
+        capability_report.record("atmosphere", CapabilitySupport::Supported);
 
         render_app
             .insert_resource(AtmosphereBindGroupLayouts::new())
@@ -0,0 +1,208 @@
+use crate::{
+    shader_ref, ExtendedMaterial, MaterialExtension, MaterialExtensionKey,
+    MaterialExtensionPipeline, MaterialPlugin, StandardMaterial,
+};
+use bevy_app::{App, Plugin};
+use bevy_asset::{embedded_asset, Asset};
+use bevy_color::{Color, LinearRgba};
+use bevy_material::AlphaMode;
+use bevy_math::{Dir3, Vec3, Vec4};
+use bevy_mesh::MeshVertexBufferLayoutRef;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    render_asset::RenderAssets,
+    render_resource::{
+        AsBindGroup, AsBindGroupShaderType, RenderPipelineDescriptor, ShaderType,
+        SpecializedMeshPipelineError,
+    },
+    texture::GpuImage,
+};
+use bevy_shader::ShaderRef;
+
+/// The maximum number of planes of a [`ClipPlanes`] material extension.
+///
+/// The planes beyond this number are ignored.
+pub const MAX_CLIP_PLANES: usize = 8;
+
+const CLIP_PLANES_FLAGS_CAPS_BIT: u32 = 1;
+
+/// Plugin to render [`ClippedMaterial`]s.
+pub struct ClipPlanesPlugin;
+
+impl Plugin for ClipPlanesPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "clip_planes.wgsl");
+
+        app.add_plugins(MaterialPlugin::<ClippedMaterial>::default());
+    }
+}
+
+/// A [`StandardMaterial`] cut by a set of planes, for cutaway views, scanning effects and
+/// cross sections.
+///
+/// The clipped fragments are discarded in the main pass as well as in the prepasses and the
+/// shadow maps, so the cut parts of the mesh neither hide nor shadow what they used to cover.
+/// Each entity that needs its own planes needs its own material.
+///
+/// ```
+/// # use bevy_asset::Assets;
+/// # use bevy_color::Color;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::{Dir3, Vec3};
+/// # use bevy_pbr::{ClipPlanes, ClippedMaterial, ExtendedMaterial, MeshMaterial3d, StandardMaterial};
+/// fn spawn_cutaway(mut commands: Commands, mut materials: ResMut<Assets<ClippedMaterial>>) {
+///     // Cut away everything above one unit, and fill the cut with a flat red.
+///     let material = materials.add(ExtendedMaterial {
+///         base: StandardMaterial::default(),
+///         extension: ClipPlanes::default()
+///             .with_plane(Vec3::new(0.0, 1.0, 0.0), Dir3::Y)
+///             .with_cap_color(Color::srgb(1.0, 0.0, 0.0)),
+///     });
+///     commands.spawn(MeshMaterial3d(material));
+/// }
+/// ```
+pub type ClippedMaterial = ExtendedMaterial<StandardMaterial, ClipPlanes>;
+
+/// Material extension discarding the fragments of a mesh on the outer side of a set of planes.
+///
+/// A fragment is clipped if it is on the side of any of the planes that their normal points to.
+/// The faces of the mesh are rendered on both sides, so that the inside of the mesh can be seen
+/// through the cut, and the material is alpha masked with a cutoff of `0.5`, so that the clipped
+/// fragments are also discarded from the prepasses and the shadow maps.
+///
+/// With a [`cap_color`](ClipPlanes::cap_color), the back faces seen through the cut are drawn with
+/// that flat color, which makes closed meshes look solid, like the cross sections of CAD tools.
+/// Without one, they are lit like the front faces, which looks best with
+/// [`StandardMaterial::double_sided`].
+///
+/// The normal prepass ignores the normal maps of clipped materials.
+#[derive(Asset, AsBindGroup, Reflect, Clone, Debug, Default)]
+#[reflect(Default, Debug, Clone)]
+#[uniform(100, ClipPlanesUniform)]
+pub struct ClipPlanes {
+    /// The planes, in world space, with their normal in `xyz` and their signed distance from the
+    /// origin, against the normal, in `w`. A fragment at `position` is clipped if
+    /// `plane.xyz.dot(position) + plane.w > 0.0`.
+    ///
+    /// Only the first [`MAX_CLIP_PLANES`] planes are used.
+    pub planes: Vec<Vec4>,
+    /// The color of the back faces seen through the cut, or `None` to light them like the front
+    /// faces.
+    ///
+    /// Defaults to `None`.
+    pub cap_color: Option<Color>,
+}
+
+impl ClipPlanes {
+    /// Adds a plane going through `point`, clipping the side `normal` points to.
+    pub fn with_plane(mut self, point: Vec3, normal: Dir3) -> Self {
+        self.planes.push(plane_from_point_normal(point, normal));
+        self
+    }
+
+    /// Draws the back faces seen through the cut with the flat `cap_color`.
+    pub fn with_cap_color(mut self, cap_color: impl Into<Color>) -> Self {
+        self.cap_color = Some(cap_color.into());
+        self
+    }
+}
+
+fn plane_from_point_normal(point: Vec3, normal: Dir3) -> Vec4 {
+    normal.extend(-normal.dot(point))
+}
+
+/// The GPU representation of a [`ClipPlanes`].
+#[derive(Clone, Default, ShaderType)]
+pub struct ClipPlanesUniform {
+    pub planes: [Vec4; MAX_CLIP_PLANES],
+    pub cap_color: LinearRgba,
+    pub plane_count: u32,
+    pub flags: u32,
+}
+
+impl AsBindGroupShaderType<ClipPlanesUniform> for ClipPlanes {
+    fn as_bind_group_shader_type(&self, _images: &RenderAssets<GpuImage>) -> ClipPlanesUniform {
+        let mut planes = [Vec4::ZERO; MAX_CLIP_PLANES];
+        let plane_count = self.planes.len().min(MAX_CLIP_PLANES);
+        planes[..plane_count].copy_from_slice(&self.planes[..plane_count]);
+
+        ClipPlanesUniform {
+            planes,
+            cap_color: self.cap_color.unwrap_or(Color::NONE).into(),
+            plane_count: plane_count as u32,
+            flags: if self.cap_color.is_some() {
+                CLIP_PLANES_FLAGS_CAPS_BIT
+            } else {
+                0
+            },
+        }
+    }
+}
+
+impl MaterialExtension for ClipPlanes {
+    fn fragment_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("clip_planes.wgsl"))
+    }
+
+    fn prepass_fragment_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("clip_planes.wgsl"))
+    }
+
+    fn deferred_fragment_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("clip_planes.wgsl"))
+    }
+
+    fn alpha_mode() -> Option<AlphaMode> {
+        // Masking makes the prepasses and the shadow maps run the fragment shader, which discards
+        // the clipped fragments.
+        Some(AlphaMode::Mask(0.5))
+    }
+
+    fn specialize(
+        _pipeline: &MaterialExtensionPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialExtensionKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // The inside of the mesh can be seen through the cut.
+        descriptor.primitive.cull_mode = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        plane_from_point_normal, ClipPlanes, ClipPlanesUniform, CLIP_PLANES_FLAGS_CAPS_BIT,
+        MAX_CLIP_PLANES,
+    };
+    use bevy_color::Color;
+    use bevy_math::{Dir3, Vec3, Vec4};
+    use bevy_render::{render_asset::RenderAssets, render_resource::AsBindGroupShaderType};
+
+    #[test]
+    fn planes_clip_the_side_of_their_normal() {
+        let plane = plane_from_point_normal(Vec3::new(0.0, 2.0, 5.0), Dir3::Y);
+        assert_eq!(plane, Vec4::new(0.0, 1.0, 0.0, -2.0));
+
+        let clipped = |position: Vec3| plane.truncate().dot(position) + plane.w > 0.0;
+        assert!(clipped(Vec3::new(3.0, 2.5, 0.0)));
+        assert!(!clipped(Vec3::new(3.0, 1.5, 0.0)));
+    }
+
+    #[test]
+    fn extra_planes_are_ignored() {
+        let mut clip_planes = ClipPlanes::default().with_cap_color(Color::WHITE);
+        for i in 0..MAX_CLIP_PLANES + 2 {
+            clip_planes = clip_planes.with_plane(Vec3::splat(i as f32), Dir3::X);
+        }
+
+        let uniform = AsBindGroupShaderType::<ClipPlanesUniform>::as_bind_group_shader_type(
+            &clip_planes,
+            &RenderAssets::default(),
+        );
+        assert_eq!(uniform.plane_count, MAX_CLIP_PLANES as u32);
+        assert_eq!(uniform.planes[1], Vec4::new(1.0, 0.0, 0.0, -1.0));
+        assert_eq!(uniform.flags, CLIP_PLANES_FLAGS_CAPS_BIT);
+    }
+}
@@ -0,0 +1,129 @@
+// Discards the fragments of a `ClippedMaterial` on the clipped side of its planes, in the main
+// pass as well as in the depth, normal, motion vector and deferred prepasses and in the shadow
+// maps, and draws the back faces seen through the cut with the flat cap color.
+
+#import bevy_pbr::{
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::alpha_discard,
+    pbr_types::STANDARD_MATERIAL_FLAGS_UNLIT_BIT,
+}
+
+#ifdef PREPASS_PIPELINE
+#import bevy_pbr::{
+    prepass_io::{VertexOutput, FragmentOutput},
+    pbr_deferred_functions::deferred_output,
+    pbr_prepass_functions,
+}
+#else
+#import bevy_pbr::{
+    forward_io::{VertexOutput, FragmentOutput},
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing},
+}
+#endif
+
+const MAX_CLIP_PLANES: u32 = 8u;
+const CLIP_PLANES_FLAGS_CAPS_BIT: u32 = 1u;
+
+struct ClipPlanes {
+    planes: array<vec4<f32>, MAX_CLIP_PLANES>,
+    cap_color: vec4<f32>,
+    plane_count: u32,
+    flags: u32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> clip_planes: ClipPlanes;
+
+// Discards the fragment if it is on the positive side of any of the planes.
+fn clip(world_position: vec3<f32>) {
+    for (var i = 0u; i < min(clip_planes.plane_count, MAX_CLIP_PLANES); i += 1u) {
+        let plane = clip_planes.planes[i];
+        if dot(plane.xyz, world_position) + plane.w > 0.0 {
+            discard;
+        }
+    }
+}
+
+fn is_cap(is_front: bool) -> bool {
+    return !is_front && (clip_planes.flags & CLIP_PLANES_FLAGS_CAPS_BIT) != 0u;
+}
+
+#ifdef PREPASS_PIPELINE
+#ifdef PREPASS_FRAGMENT
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    clip(in.world_position.xyz);
+
+#ifdef DEFERRED_PREPASS
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+    pbr_input.material.base_color = alpha_discard(
+        pbr_input.material.flags,
+        pbr_input.material.alpha_cutoff,
+        pbr_input.material.base_color
+    );
+    if is_cap(is_front) {
+        pbr_input.material.base_color = clip_planes.cap_color;
+        pbr_input.material.flags |= STANDARD_MATERIAL_FLAGS_UNLIT_BIT;
+    }
+    var out = deferred_output(in, pbr_input);
+#else // DEFERRED_PREPASS
+    pbr_prepass_functions::prepass_sample_color_and_alpha_discard(in);
+    var out: FragmentOutput;
+
+#ifdef NORMAL_PREPASS
+    // The normal maps are not applied, and the back faces point toward the camera.
+    var world_normal = normalize(in.world_normal);
+    if !is_front {
+        world_normal = -world_normal;
+    }
+    out.normal = vec4(world_normal * 0.5 + vec3(0.5), 1.0);
+#endif // NORMAL_PREPASS
+
+#ifdef MOTION_VECTOR_PREPASS
+    out.motion_vector = pbr_prepass_functions::calculate_motion_vector(in.world_position, in.previous_world_position);
+#endif // MOTION_VECTOR_PREPASS
+#endif // DEFERRED_PREPASS
+
+#ifdef UNCLIPPED_DEPTH_ORTHO_EMULATION
+    out.frag_depth = in.unclipped_depth;
+#endif // UNCLIPPED_DEPTH_ORTHO_EMULATION
+
+    return out;
+}
+#else // PREPASS_FRAGMENT
+@fragment
+fn fragment(in: VertexOutput) {
+    clip(in.world_position.xyz);
+    pbr_prepass_functions::prepass_sample_color_and_alpha_discard(in);
+}
+#endif // PREPASS_FRAGMENT
+#else // PREPASS_PIPELINE
+@fragment
+fn fragment(
+    in: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> FragmentOutput {
+    clip(in.world_position.xyz);
+
+    var pbr_input = pbr_input_from_standard_material(in, is_front);
+    pbr_input.material.base_color = alpha_discard(
+        pbr_input.material.flags,
+        pbr_input.material.alpha_cutoff,
+        pbr_input.material.base_color
+    );
+
+    var out: FragmentOutput;
+    if is_cap(is_front) {
+        out.color = clip_planes.cap_color;
+    } else if (pbr_input.material.flags & STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
+        out.color = apply_pbr_lighting(pbr_input);
+    } else {
+        out.color = pbr_input.material.base_color;
+    }
+    out.color = main_pass_post_lighting_processing(pbr_input, out.color);
+
+    return out;
+}
+#endif // PREPASS_PIPELINE
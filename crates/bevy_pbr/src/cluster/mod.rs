@@ -11,6 +11,7 @@ use bevy_light::{
 };
 use bevy_math::{uvec4, UVec3, UVec4, Vec4};
 use bevy_render::{
+    capabilities::{CapabilitySupport, RenderCapabilityReport},
     render_resource::{
         BindingResource, BufferBindingType, BufferUsages, DownlevelFlags, RawBufferVec, ShaderSize,
         ShaderType, StorageBuffer, UniformBuffer,
@@ -20,7 +21,7 @@ use bevy_render::{
     Extract,
 };
 use bytemuck::{Pod, Zeroable};
-use tracing::{error, info, trace, warn};
+use tracing::{error, trace, warn};
 
 use crate::{MeshPipeline, RenderViewLightProbes};
 
@@ -84,16 +85,47 @@ pub(crate) fn make_global_cluster_settings(world: &World) -> GlobalClusterSettin
         && device.limits().max_storage_buffers_per_shader_stage > 0;
 
     let gpu_clustering = if gpu_clustering_supported {
-        info!("GPU clustering is supported on this device.");
         Some(GlobalClusterGpuSettings {
             initial_z_slice_list_capacity: GPU_CLUSTERING_INITIAL_Z_SLICE_LIST_CAPACITY,
             initial_index_list_capacity: GPU_CLUSTERING_INITIAL_INDEX_LIST_CAPACITY,
         })
     } else {
-        info!("GPU clustering isn't supported on this device; falling back to CPU clustering.");
         None
     };
 
+    if let Some(capability_report) = world.get_resource::<RenderCapabilityReport>() {
+        capability_report.record(
+            "gpu_clustering",
+            if gpu_clustering_supported {
+                CapabilitySupport::Supported
+            } else {
+                CapabilitySupport::Degraded {
+                    fallback: "CPU clustering".into(),
+                    reason: "compute shaders or storage buffers are unavailable".into(),
+                }
+            },
+        );
+        capability_report.record(
+            "clustered_forward_storage_buffers",
+            if supports_storage_buffers {
+                CapabilitySupport::Supported
+            } else {
+                CapabilitySupport::Degraded {
+                    fallback: format!(
+                        "uniform buffers, limited to {MAX_UNIFORM_BUFFER_CLUSTERABLE_OBJECTS} \
+                         clusterable objects"
+                    )
+                    .into(),
+                    reason: format!(
+                        "fewer than {CLUSTERED_FORWARD_STORAGE_BUFFER_COUNT} storage buffers \
+                         per shader stage"
+                    )
+                    .into(),
+                }
+            },
+        );
+    }
+
     GlobalClusterSettings {
         supports_storage_buffers,
         clustered_decals_are_usable,
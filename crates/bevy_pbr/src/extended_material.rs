@@ -2,7 +2,7 @@ use alloc::borrow::Cow;
 
 use bevy_asset::Asset;
 use bevy_ecs::system::SystemParamItem;
-use bevy_material::{AlphaMode, OpaqueRendererMethod};
+use bevy_material::{AlphaMode, OpaqueRendererMethod, RenderPhaseType};
 use bevy_mesh::MeshVertexBufferLayoutRef;
 use bevy_platform::{collections::HashSet, hash::FixedHasher};
 use bevy_reflect::{impl_type_path, Reflect};
@@ -351,6 +351,14 @@ impl<B: Material, E: MaterialExtension> Material for ExtendedMaterial<B, E> {
         B::depth_bias(&self.base)
     }
 
+    fn sort_bias(&self) -> f32 {
+        B::sort_bias(&self.base)
+    }
+
+    fn render_phase(&self) -> Option<RenderPhaseType> {
+        B::render_phase(&self.base)
+    }
+
     fn reads_view_transmission_texture(&self) -> bool {
         B::reads_view_transmission_texture(&self.base)
     }
@@ -25,6 +25,7 @@ pub mod experimental {
 }
 
 mod atmosphere;
+mod clip_planes;
 mod cluster;
 pub mod contact_shadows;
 #[cfg(feature = "bevy_gltf")]
@@ -42,9 +43,11 @@ mod extended_material;
 mod fog;
 mod light_probe;
 mod lightmap;
+mod mask_layer;
 mod material;
 mod medium;
 mod mesh_material;
+mod outline;
 mod parallax;
 mod pbr_material;
 mod prepass;
@@ -53,23 +56,28 @@ mod ssao;
 mod ssr;
 mod transmission;
 mod volumetric_fog;
+mod wind;
 
 use bevy_color::{Color, LinearRgba};
 
 pub use atmosphere::*;
 use bevy_light::{
-    AmbientLight, DirectionalLight, PointLight, RectLight, ShadowFilteringMethod, SpotLight,
+    AmbientLight, DirectionalLight, PointLight, RectLight, ShadowCascadesDebug,
+    ShadowFilteringMethod, SpotLight,
 };
 use bevy_shader::{load_shader_library, ShaderRef};
+pub use clip_planes::*;
 pub use cluster::*;
 pub use decal::clustered::ClusteredDecalPlugin;
 pub use extended_material::*;
 pub use fog::*;
 pub use light_probe::*;
 pub use lightmap::*;
+pub use mask_layer::*;
 pub use material::*;
 pub use medium::*;
 pub use mesh_material::*;
+pub use outline::*;
 pub use parallax::*;
 pub use pbr_material::*;
 pub use prepass::*;
@@ -78,6 +86,7 @@ pub use ssao::*;
 pub use ssr::*;
 pub use transmission::*;
 pub use volumetric_fog::VolumetricFogPlugin;
+pub use wind::*;
 
 /// The PBR prelude.
 ///
@@ -85,13 +94,16 @@ pub use volumetric_fog::VolumetricFogPlugin;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
+        clip_planes::{ClipPlanes, ClippedMaterial},
         contact_shadows::ContactShadowsPlugin,
         fog::{DistanceFog, FogFalloff},
         material::{Material, MaterialPlugin},
         mesh_material::MeshMaterial3d,
+        outline::Outlined,
         parallax::ParallaxMappingMethod,
         pbr_material::StandardMaterial,
         ssao::ScreenSpaceAmbientOcclusionPlugin,
+        wind::{WindMaterial, WindSway},
     };
 }
 
@@ -233,11 +245,16 @@ impl Plugin for PbrPlugin {
             ))
             .add_plugins((
                 decal::ForwardDecalPlugin,
+                WindPlugin,
+                ClipPlanesPlugin,
+                MaskLayerPlugin,
+                OutlinePlugin,
                 SyncComponentPlugin::<DirectionalLight, Self>::default(),
                 SyncComponentPlugin::<PointLight, Self>::default(),
                 SyncComponentPlugin::<SpotLight, Self>::default(),
                 SyncComponentPlugin::<RectLight, Self>::default(),
                 SyncComponentPlugin::<AmbientLight, Self>::default(),
+                SyncComponentPlugin::<ShadowCascadesDebug, Self>::default(),
             ))
             .add_plugins((
                 ScatteringMediumPlugin,
@@ -377,6 +394,7 @@ impl Plugin for PbrPlugin {
                     extract_ambient_light_resource,
                     extract_ambient_light,
                     extract_shadow_filtering_method,
+                    extract_shadow_cascades_debug,
                     extract_shadow_lod_origin,
                     late_sweep_material_instances,
                 ),
@@ -515,3 +533,6 @@ impl SyncComponent<RenderApp, PbrPlugin> for AmbientLight {
 impl SyncComponent<RenderApp, PbrPlugin> for ShadowFilteringMethod {
     type Target = Self;
 }
+impl SyncComponent<RenderApp, PbrPlugin> for ShadowCascadesDebug {
+    type Target = Self;
+}
@@ -0,0 +1,318 @@
+use crate::{shader_ref, Material, MaterialPlugin, MeshMaterial3d};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{embedded_asset, Asset, Assets, Handle, RenderAssetUsages};
+use bevy_camera::{
+    visibility::RenderLayers, Camera, Camera3d, ClearColorConfig, Hdr, RenderTarget,
+};
+use bevy_color::Color;
+use bevy_core_pipeline::tonemapping::{DebandDither, Tonemapping};
+use bevy_ecs::prelude::*;
+use bevy_image::Image;
+use bevy_light::{NotShadowCaster, NotShadowReceiver};
+use bevy_math::UVec2;
+use bevy_mesh::{Mesh3d, MeshTag};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    gpu_readback::{Readback, ReadbackComplete},
+    render_resource::{AsBindGroup, TextureFormat, TextureUsages},
+    view::Msaa,
+};
+use bevy_shader::ShaderRef;
+
+/// The render layer used by default for the [`MaskLayer`]s and the [`MaskId`]s.
+pub const DEFAULT_MASK_RENDER_LAYER: usize = 31;
+
+/// Plugin rendering the [`MaskId`]s of the entities to the [`MaskLayer`]s.
+pub struct MaskLayerPlugin;
+
+impl Plugin for MaskLayerPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "mask_layer.wgsl");
+
+        app.add_plugins(MaterialPlugin::<MaskMaterial>::default())
+            .init_resource::<MaskMaterialHandle>()
+            .add_systems(PostUpdate, (setup_mask_layers, sync_mask_proxies))
+            .add_observer(update_mask_layer_readback);
+    }
+}
+
+/// Turns a camera into a mask layer, rendering the [`MaskId`] of the visible entities, with no
+/// lighting, to a small texture.
+///
+/// Mask layers are meant for mini-maps, fog of war, or any effect that needs to know which
+/// entity covers which part of an area, without a trip through picking. The camera renders to
+/// a [`MaskLayerImage`], which shaders can sample, and, if [`MaskLayer::readback`] is set, the
+/// texture is read back to the CPU every frame into a [`MaskLayerReadback`].
+///
+/// The camera is configured by this component to render without tonemapping, dithering or
+/// MSAA, so the IDs come out exactly as they were written, and to render before the other cameras
+/// unless it was given an order. Its transform and projection are up to the app, for example an
+/// orthographic projection looking down on the map.
+///
+/// ```
+/// # use bevy_camera::{OrthographicProjection, Projection};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::UVec2;
+/// # use bevy_pbr::{MaskId, MaskLayer};
+/// # use bevy_transform::components::Transform;
+/// # use bevy_math::Vec3;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         MaskLayer {
+///             size: UVec2::splat(128),
+///             readback: true,
+///             ..Default::default()
+///         },
+///         Projection::Orthographic(OrthographicProjection::default_3d()),
+///         Transform::from_xyz(0.0, 50.0, 0.0).looking_at(Vec3::ZERO, Vec3::NEG_Z),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default, Clone, Debug)]
+#[require(Camera3d)]
+pub struct MaskLayer {
+    /// The size of the mask texture, in pixels.
+    ///
+    /// Defaults to 256×256.
+    pub size: UVec2,
+    /// The render layer of the [`MaskId`]s rendered by this mask layer.
+    ///
+    /// Defaults to [`DEFAULT_MASK_RENDER_LAYER`].
+    pub render_layer: usize,
+    /// Whether to read the mask texture back to the CPU every frame, into a
+    /// [`MaskLayerReadback`].
+    ///
+    /// Defaults to `false`.
+    pub readback: bool,
+}
+
+impl Default for MaskLayer {
+    fn default() -> Self {
+        Self {
+            size: UVec2::splat(256),
+            render_layer: DEFAULT_MASK_RENDER_LAYER,
+            readback: false,
+        }
+    }
+}
+
+/// The texture a [`MaskLayer`] renders to, inserted on the camera.
+///
+/// The texture is [`TextureFormat::Rgba8Unorm`], with each texel holding the little endian bytes
+/// of the [`MaskId`] covering it, or zero where no entity is. In WGSL, the ID of a texel is
+/// `pack4x8unorm(textureLoad(mask, position, 0))`.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Clone, Debug)]
+pub struct MaskLayerImage(pub Handle<Image>);
+
+/// The contents of a [`MaskLayer`] texture, read back to the CPU.
+///
+/// This is updated every frame on the cameras whose [`MaskLayer::readback`] is set, a few frames
+/// behind the rendering.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default, Clone, Debug)]
+pub struct MaskLayerReadback {
+    /// The size of the mask texture, in pixels.
+    pub size: UVec2,
+    /// The raw bytes of the texture, whose rows may be padded.
+    pub data: Vec<u8>,
+}
+
+impl MaskLayerReadback {
+    /// Returns the [`MaskId`] at the pixel `position`, zero if no entity covers it, or `None` if
+    /// the position is out of the texture.
+    pub fn id_at(&self, position: UVec2) -> Option<u32> {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return None;
+        }
+        let bytes_per_row = self.data.len() / self.size.y as usize;
+        let offset = position.y as usize * bytes_per_row + position.x as usize * 4;
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    }
+}
+
+/// Renders this entity's mesh to the [`MaskLayer`]s sharing its render layers, with a flat ID.
+///
+/// An ID of zero is indistinguishable from the background, so the IDs should start from one.
+/// The entity needs a [`Mesh3d`]; it is rendered by a child entity, which follows its transform
+/// and visibility.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Clone, Debug, PartialEq)]
+pub struct MaskId {
+    /// The ID written to the mask.
+    pub id: u32,
+    /// The render layers of the [`MaskLayer`]s rendering this entity.
+    ///
+    /// Defaults to [`DEFAULT_MASK_RENDER_LAYER`].
+    pub render_layers: RenderLayers,
+}
+
+impl MaskId {
+    /// Renders the entity with the `id` to the [`MaskLayer`]s on the default render layer.
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            render_layers: RenderLayers::layer(DEFAULT_MASK_RENDER_LAYER),
+        }
+    }
+}
+
+/// The child entity rendering the [`MaskId`] of its parent.
+#[derive(Component, Clone, Copy, Debug)]
+struct MaskProxy(Entity);
+
+/// The material writing the [`MeshTag`] of the mesh as a flat color, used by the [`MaskId`]s.
+#[derive(Asset, AsBindGroup, Reflect, Clone, Debug, Default)]
+#[reflect(Default, Debug, Clone)]
+pub struct MaskMaterial {}
+
+impl Material for MaskMaterial {
+    fn fragment_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("mask_layer.wgsl"))
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+}
+
+/// The [`MaskMaterial`] shared by all the [`MaskId`]s, which only differ by their [`MeshTag`].
+#[derive(Resource)]
+struct MaskMaterialHandle(Handle<MaskMaterial>);
+
+impl FromWorld for MaskMaterialHandle {
+    fn from_world(world: &mut World) -> Self {
+        Self(
+            world
+                .resource_mut::<Assets<MaskMaterial>>()
+                .add(MaskMaterial::default()),
+        )
+    }
+}
+
+fn setup_mask_layers(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut mask_layers: Query<(Entity, &MaskLayer, &mut Camera), Changed<MaskLayer>>,
+) {
+    for (entity, mask_layer, mut camera) in &mut mask_layers {
+        let mut image = Image::new_target_texture(
+            mask_layer.size.x.max(1),
+            mask_layer.size.y.max(1),
+            TextureFormat::Rgba8Unorm,
+            None,
+        );
+        image.texture_descriptor.usage |= TextureUsages::COPY_SRC;
+        image.asset_usage = RenderAssetUsages::RENDER_WORLD;
+        let image = images.add(image);
+
+        camera.clear_color = ClearColorConfig::Custom(Color::NONE);
+        if camera.order == 0 {
+            camera.order = -1;
+        }
+        let mut entity = commands.entity(entity);
+        entity.insert((
+            MaskLayerImage(image.clone()),
+            RenderTarget::from(image.clone()),
+            RenderLayers::layer(mask_layer.render_layer),
+            Hdr,
+            Msaa::Off,
+            Tonemapping::None,
+            DebandDither::Disabled,
+        ));
+        if mask_layer.readback {
+            entity.insert(Readback::texture(image));
+        } else {
+            entity.remove::<(Readback, MaskLayerReadback)>();
+        }
+    }
+}
+
+fn sync_mask_proxies(
+    mut commands: Commands,
+    material: Res<MaskMaterialHandle>,
+    masked: Query<
+        (Entity, &MaskId, &Mesh3d, Option<&MaskProxy>),
+        Or<(Changed<MaskId>, Changed<Mesh3d>)>,
+    >,
+    proxies: Query<&MaskProxy>,
+    mut removed: RemovedComponents<MaskId>,
+) {
+    for entity in removed.read() {
+        let Ok(proxy) = proxies.get(entity) else {
+            continue;
+        };
+        commands.entity(proxy.0).despawn();
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<MaskProxy>();
+        }
+    }
+
+    for (entity, mask_id, mesh, proxy) in &masked {
+        let components = (
+            mesh.clone(),
+            MeshTag(mask_id.id),
+            mask_id.render_layers.clone(),
+        );
+        match proxy {
+            Some(proxy) => {
+                commands.entity(proxy.0).insert(components);
+            }
+            None => {
+                let proxy = commands
+                    .spawn((
+                        components,
+                        MeshMaterial3d(material.0.clone()),
+                        NotShadowCaster,
+                        NotShadowReceiver,
+                        ChildOf(entity),
+                    ))
+                    .id();
+                commands.entity(entity).insert(MaskProxy(proxy));
+            }
+        }
+    }
+}
+
+fn update_mask_layer_readback(
+    readback: On<ReadbackComplete>,
+    mut commands: Commands,
+    mask_layers: Query<&MaskLayer>,
+) {
+    let Ok(mask_layer) = mask_layers.get(readback.entity) else {
+        return;
+    };
+    commands.entity(readback.entity).insert(MaskLayerReadback {
+        size: mask_layer.size.max(UVec2::ONE),
+        data: readback.data.clone(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaskLayerReadback;
+    use bevy_math::UVec2;
+
+    #[test]
+    fn reads_ids_from_padded_rows() {
+        // Two rows of two texels, each row padded to 12 bytes.
+        let mut data = vec![0; 24];
+        data[4..8].copy_from_slice(&7u32.to_le_bytes());
+        data[12..16].copy_from_slice(&0x0102_0304u32.to_le_bytes());
+        let readback = MaskLayerReadback {
+            size: UVec2::new(2, 2),
+            data,
+        };
+
+        assert_eq!(readback.id_at(UVec2::new(0, 0)), Some(0));
+        assert_eq!(readback.id_at(UVec2::new(1, 0)), Some(7));
+        assert_eq!(readback.id_at(UVec2::new(0, 1)), Some(0x0102_0304));
+        assert_eq!(readback.id_at(UVec2::new(2, 0)), None);
+    }
+}
@@ -0,0 +1,8 @@
+// Writes the `MeshTag` of a `MaskId` proxy as a flat color, one byte of the ID per channel.
+
+#import bevy_pbr::{forward_io::VertexOutput, mesh_functions}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return unpack4x8unorm(mesh_functions::get_tag(in.instance_index));
+}
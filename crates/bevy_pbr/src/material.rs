@@ -179,6 +179,28 @@ pub trait Material: Asset + AsBindGroup + Clone + Sized {
         0.0
     }
 
+    #[inline]
+    /// Add a bias to the view depth of the mesh when sorting it with other transparent or
+    /// transmissive meshes, without affecting the depth test.
+    ///
+    /// This can be used to draw overlays like decals over the meshes they lie on. See also
+    /// [`MeshSortBias`] to bias a single mesh.
+    fn sort_bias(&self) -> f32 {
+        0.0
+    }
+
+    #[inline]
+    /// Returns the render phase this material is drawn in, or `None` to choose it from the
+    /// [`alpha_mode`](Material::alpha_mode) and
+    /// [`reads_view_transmission_texture`](Material::reads_view_transmission_texture).
+    ///
+    /// This can for example be used to draw an opaque material in the sorted
+    /// [`Transparent3d`] phase, after all other opaque meshes. The phase doesn't change the
+    /// blending, which still depends on the alpha mode.
+    fn render_phase(&self) -> Option<RenderPhaseType> {
+        None
+    }
+
     #[inline]
     /// Returns whether the material would like to read from [`ViewTransmissionTexture`].
     ///
@@ -380,6 +402,7 @@ impl Plugin for MaterialsPlugin {
                 .init_resource::<DrawFunctions<Shadow>>()
                 .init_resource::<RenderMaterialInstances>()
                 .allow_ambiguous_resource::<RenderMaterialInstances>()
+                .init_resource::<RenderMeshSortBiases>()
                 .init_gpu_resource::<PendingMeshMaterialQueues>()
                 .allow_ambiguous_resource::<PendingMeshMaterialQueues>()
                 .init_gpu_resource::<PendingShadowQueues>()
@@ -393,6 +416,7 @@ impl Plugin for MaterialsPlugin {
                     RenderStartup,
                     init_material_pipeline.after(MeshPipelineSystems),
                 )
+                .add_systems(ExtractSchedule, extract_mesh_sort_biases)
                 .add_systems(
                     Render,
                     (
@@ -835,6 +859,34 @@ pub fn late_sweep_material_instances(
         .set(last_change_tick.get() + 1);
 }
 
+/// Biases the view depth of this mesh when sorting it with other transparent or transmissive
+/// meshes, on top of the [`Material::sort_bias`] of its material.
+///
+/// This doesn't affect the depth test, only the order in which meshes of the same sorted phase are
+/// drawn. Positive values sort the mesh as if it was closer to the camera, so it's drawn over
+/// the other meshes it overlaps.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct MeshSortBias(pub f32);
+
+/// The [`MeshSortBias`] of each mesh entity that has one, in the render world.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct RenderMeshSortBiases(pub MainEntityHashMap<f32>);
+
+/// Extracts [`MeshSortBias`] components into [`RenderMeshSortBiases`].
+pub fn extract_mesh_sort_biases(
+    mut render_mesh_sort_biases: ResMut<RenderMeshSortBiases>,
+    changed_sort_biases: Extract<Query<(Entity, &MeshSortBias), Changed<MeshSortBias>>>,
+    mut removed_sort_biases: Extract<RemovedComponents<MeshSortBias>>,
+) {
+    for entity in removed_sort_biases.read() {
+        render_mesh_sort_biases.remove(&MainEntity::from(entity));
+    }
+    for (entity, sort_bias) in &changed_sort_biases {
+        render_mesh_sort_biases.insert(MainEntity::from(entity), sort_bias.0);
+    }
+}
+
 pub fn extract_entities_needs_specialization<M>(
     entities_needing_specialization: Extract<Res<EntitiesNeedingSpecialization<M>>>,
     mut dirty_specializations: ResMut<DirtySpecializations>,
@@ -918,14 +970,17 @@ pub fn check_entities_needing_specialization<M>(
                 AssetChanged<Mesh3d>,
                 Changed<MeshMaterial3d<M>>,
                 AssetChanged<MeshMaterial3d<M>>,
+                Changed<MeshSortBias>,
             )>,
             With<MeshMaterial3d<M>>,
         ),
     >,
+    materials: Query<(), With<MeshMaterial3d<M>>>,
     mut par_local: Local<Parallel<Vec<Entity>>>,
     mut entities_needing_specialization: ResMut<EntitiesNeedingSpecialization<M>>,
     mut removed_mesh_3d_components: RemovedComponents<Mesh3d>,
     mut removed_mesh_material_3d_components: RemovedComponents<MeshMaterial3d<M>>,
+    mut removed_mesh_sort_biases: RemovedComponents<MeshSortBias>,
 ) where
     M: Material,
 {
@@ -938,6 +993,13 @@ pub fn check_entities_needing_specialization<M>(
         .for_each(|entity| par_local.borrow_local_mut().push(entity));
     par_local.drain_into(&mut entities_needing_specialization.changed);
 
+    // Meshes that lost their sort bias must be queued again to be sorted without it.
+    entities_needing_specialization.changed.extend(
+        removed_mesh_sort_biases
+            .read()
+            .filter(|entity| materials.contains(*entity)),
+    );
+
     // All entities that removed their `Mesh3d` or `MeshMaterial3d` components
     // need to have their specializations removed as well.
     //
@@ -1188,7 +1250,10 @@ pub(crate) fn specialize_material_meshes(
 /// them to [`BinnedRenderPhase`]s or [`SortedRenderPhase`]s as appropriate.
 pub fn queue_material_meshes(
     render_materials: Res<ErasedRenderAssets<PreparedMaterial>>,
-    render_mesh_instances: Res<RenderMeshInstances>,
+    (render_mesh_instances, render_mesh_sort_biases): (
+        Res<RenderMeshInstances>,
+        Res<RenderMeshSortBiases>,
+    ),
     render_material_instances: Res<RenderMaterialInstances>,
     mesh_assets: Res<RenderAssets<RenderMesh>>,
     mesh_allocator: Res<MeshAllocator>,
@@ -1316,7 +1381,12 @@ pub fn queue_material_meshes(
                                     .unwrap()
                                     .aabb_center,
                             ),
-                            depth_bias: material.properties.depth_bias,
+                            depth_bias: material.properties.depth_bias
+                                + material.properties.sort_bias
+                                + render_mesh_sort_biases
+                                    .get(visible_entity)
+                                    .copied()
+                                    .unwrap_or_default(),
                         },
                         entity: (Entity::PLACEHOLDER, *visible_entity),
                         draw_function,
@@ -1416,7 +1486,12 @@ pub fn queue_material_meshes(
                                     .unwrap()
                                     .aabb_center,
                             ),
-                            depth_bias: material.properties.depth_bias,
+                            depth_bias: material.properties.depth_bias
+                                + material.properties.sort_bias
+                                + render_mesh_sort_biases
+                                    .get(visible_entity)
+                                    .copied()
+                                    .unwrap_or_default(),
                         },
                         entity: (Entity::PLACEHOLDER, *visible_entity),
                         draw_function,
@@ -1716,14 +1791,17 @@ where
 
         let mesh_pipeline_key_bits = ErasedMeshPipelineKey::new(mesh_pipeline_key_bits);
 
-        let render_phase_type = match material.alpha_mode() {
-            AlphaMode::Blend | AlphaMode::Premultiplied | AlphaMode::Add | AlphaMode::Multiply => {
-                RenderPhaseType::Transparent
-            }
-            _ if reads_view_transmission_texture => RenderPhaseType::Transmissive,
-            AlphaMode::Opaque | AlphaMode::AlphaToCoverage => RenderPhaseType::Opaque,
-            AlphaMode::Mask(_) => RenderPhaseType::AlphaMask,
-        };
+        let render_phase_type = material
+            .render_phase()
+            .unwrap_or(match material.alpha_mode() {
+                AlphaMode::Blend
+                | AlphaMode::Premultiplied
+                | AlphaMode::Add
+                | AlphaMode::Multiply => RenderPhaseType::Transparent,
+                _ if reads_view_transmission_texture => RenderPhaseType::Transmissive,
+                AlphaMode::Opaque | AlphaMode::AlphaToCoverage => RenderPhaseType::Opaque,
+                AlphaMode::Mask(_) => RenderPhaseType::AlphaMask,
+            });
 
         let shaders = material_shaders.shaders.clone();
 
@@ -1736,6 +1814,7 @@ where
             properties: Arc::new(MaterialProperties {
                 alpha_mode: material.alpha_mode(),
                 depth_bias: material.depth_bias(),
+                sort_bias: material.sort_bias(),
                 reads_view_transmission_texture,
                 render_phase_type,
                 render_method,
@@ -0,0 +1,237 @@
+use crate::{
+    shader_ref, Material, MaterialPipeline, MaterialPipelineKey, MaterialPlugin, MeshMaterial3d,
+};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{embedded_asset, Asset, Assets};
+use bevy_color::{Color, LinearRgba};
+use bevy_ecs::prelude::*;
+use bevy_light::{NotShadowCaster, NotShadowReceiver};
+use bevy_mesh::{Mesh3d, MeshVertexBufferLayoutRef};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    render_asset::RenderAssets,
+    render_resource::{
+        AsBindGroup, AsBindGroupShaderType, Face, RenderPipelineDescriptor, ShaderType,
+        SpecializedMeshPipelineError,
+    },
+    texture::GpuImage,
+};
+use bevy_shader::ShaderRef;
+
+/// Plugin drawing the outlines of the [`Outlined`] entities.
+pub struct OutlinePlugin;
+
+impl Plugin for OutlinePlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "outline.wgsl");
+
+        app.add_plugins(MaterialPlugin::<OutlineMaterial>::default())
+            .add_systems(PostUpdate, sync_outline_proxies);
+    }
+}
+
+/// Draws an outline of a solid color around the silhouette of this entity's mesh, for example to
+/// highlight the selected or hovered objects of an editor or a game.
+///
+/// The outline is drawn by a child entity rendering the back faces of the mesh pushed outward
+/// along their normals, so that they only show where they stick out of the mesh. The width is in
+/// pixels, whatever the distance to the camera, and the outline is hidden by the objects in front
+/// of the entity, like the entity itself.
+///
+/// The entity needs a [`Mesh3d`] with normals. The outline follows the transform and visibility of
+/// the entity, but not its skinning or morph targets. The edges of meshes with hard normals, like
+/// cubes, leave gaps at the corners of wide outlines.
+///
+/// ```
+/// # use bevy_color::palettes::css::ORANGE;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_pbr::Outlined;
+/// fn select(add: On<Add, Selected>, mut commands: Commands) {
+///     commands.entity(add.entity).insert(Outlined {
+///         color: ORANGE.into(),
+///         width: 3.0,
+///     });
+/// }
+/// # #[derive(Component)]
+/// # struct Selected;
+/// ```
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+pub struct Outlined {
+    /// The color of the outline.
+    ///
+    /// Defaults to [`Color::WHITE`].
+    pub color: Color,
+    /// The width of the outline, in physical pixels.
+    ///
+    /// Defaults to `2.0`.
+    pub width: f32,
+}
+
+impl Default for Outlined {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            width: 2.0,
+        }
+    }
+}
+
+/// The child entity drawing the outline of its [`Outlined`] parent.
+#[derive(Component, Clone, Copy, Debug)]
+struct OutlineProxy(Entity);
+
+/// The material drawing the outline of an [`Outlined`] entity, as the back faces of its mesh
+/// pushed outward by [`OutlineMaterial::width`] pixels.
+#[derive(Asset, AsBindGroup, Reflect, Clone, Debug)]
+#[reflect(Default, Debug, Clone)]
+#[uniform(0, OutlineMaterialUniform)]
+pub struct OutlineMaterial {
+    /// The color of the outline.
+    pub color: Color,
+    /// The width of the outline, in physical pixels.
+    pub width: f32,
+}
+
+impl Default for OutlineMaterial {
+    fn default() -> Self {
+        let Outlined { color, width } = Outlined::default();
+        Self { color, width }
+    }
+}
+
+/// The GPU representation of an [`OutlineMaterial`].
+#[derive(Clone, Default, ShaderType)]
+pub struct OutlineMaterialUniform {
+    pub color: LinearRgba,
+    pub width: f32,
+}
+
+impl AsBindGroupShaderType<OutlineMaterialUniform> for OutlineMaterial {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> OutlineMaterialUniform {
+        OutlineMaterialUniform {
+            color: self.color.into(),
+            width: self.width.max(0.0),
+        }
+    }
+}
+
+impl Material for OutlineMaterial {
+    fn vertex_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("outline.wgsl"))
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("outline.wgsl"))
+    }
+
+    fn enable_prepass() -> bool {
+        false
+    }
+
+    fn enable_shadows() -> bool {
+        false
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        _key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        // Only the back faces stick out of the mesh, the front faces would cover it.
+        descriptor.primitive.cull_mode = Some(Face::Front);
+        Ok(())
+    }
+}
+
+fn sync_outline_proxies(
+    mut commands: Commands,
+    mut materials: ResMut<Assets<OutlineMaterial>>,
+    outlined: Query<
+        (Entity, &Outlined, &Mesh3d, Option<&OutlineProxy>),
+        Or<(Changed<Outlined>, Changed<Mesh3d>)>,
+    >,
+    proxies: Query<&OutlineProxy>,
+    proxy_materials: Query<&MeshMaterial3d<OutlineMaterial>>,
+    mut removed: RemovedComponents<Outlined>,
+) {
+    for entity in removed.read() {
+        let Ok(proxy) = proxies.get(entity) else {
+            continue;
+        };
+        commands.entity(proxy.0).despawn();
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.remove::<OutlineProxy>();
+        }
+    }
+
+    for (entity, outlined, mesh, proxy) in &outlined {
+        let material = OutlineMaterial {
+            color: outlined.color,
+            width: outlined.width,
+        };
+        // Each outline has its own material, which is updated in place when the outline changes.
+        if let Some(proxy) = proxy
+            && let Ok(proxy_material) = proxy_materials.get(proxy.0)
+        {
+            if let Some(mut existing) = materials.get_mut(&proxy_material.0) {
+                *existing = material;
+            }
+            commands.entity(proxy.0).insert(mesh.clone());
+            continue;
+        }
+
+        let proxy = commands
+            .spawn((
+                mesh.clone(),
+                MeshMaterial3d(materials.add(material)),
+                NotShadowCaster,
+                NotShadowReceiver,
+                ChildOf(entity),
+            ))
+            .id();
+        commands.entity(entity).insert(OutlineProxy(proxy));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sync_outline_proxies, OutlineMaterial, OutlineProxy, Outlined};
+    use bevy_asset::{Assets, Handle};
+    use bevy_color::Color;
+    use bevy_ecs::prelude::*;
+    use bevy_mesh::Mesh3d;
+
+    use crate::MeshMaterial3d;
+
+    #[test]
+    fn outline_proxies_follow_outlined_entities() {
+        let mut world = World::new();
+        world.init_resource::<Assets<OutlineMaterial>>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(sync_outline_proxies);
+
+        let entity = world
+            .spawn((Mesh3d(Handle::default()), Outlined::default()))
+            .id();
+        schedule.run(&mut world);
+        let proxy = world.get::<OutlineProxy>(entity).unwrap().0;
+        assert_eq!(world.get::<ChildOf>(proxy).unwrap().parent(), entity);
+
+        world.get_mut::<Outlined>(entity).unwrap().color = Color::BLACK;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<OutlineProxy>(entity).unwrap().0, proxy);
+        let material = world.get::<MeshMaterial3d<OutlineMaterial>>(proxy).unwrap();
+        let materials = world.resource::<Assets<OutlineMaterial>>();
+        assert_eq!(materials.get(&material.0).unwrap().color, Color::BLACK);
+
+        world.entity_mut(entity).remove::<Outlined>();
+        schedule.run(&mut world);
+        assert!(world.get_entity(proxy).is_err());
+        assert!(world.get::<OutlineProxy>(entity).is_none());
+    }
+}
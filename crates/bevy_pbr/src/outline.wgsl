@@ -0,0 +1,56 @@
+// Draws the back faces of an `Outlined` mesh pushed outward, in screen space, by the width of the
+// outline, so that they only show around the silhouette of the mesh.
+
+#import bevy_pbr::{
+    forward_io::{Vertex, VertexOutput, decompress_vertex},
+    mesh_functions,
+    mesh_view_bindings::view,
+    view_transformations::position_world_to_clip,
+}
+
+struct OutlineMaterial {
+    color: vec4<f32>,
+    width: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<uniform> outline: OutlineMaterial;
+
+@vertex
+fn vertex(vertex_in: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    let vertex = decompress_vertex(vertex_in, vertex_in.instance_index);
+    let world_from_local = mesh_functions::get_world_from_local(vertex_in.instance_index);
+
+    out.world_position = mesh_functions::mesh_position_local_to_world(
+        world_from_local,
+        vec4<f32>(vertex.position, 1.0)
+    );
+    out.position = position_world_to_clip(out.world_position.xyz);
+
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vertex.normal, vertex_in.instance_index);
+    // Push the vertex along its normal projected on the screen. Scaling the offset by `w` keeps
+    // the width constant in pixels whatever the distance to the camera.
+    let screen_normal = (view.clip_from_world * vec4(out.world_normal, 0.0)).xy * view.viewport.zw;
+    if dot(screen_normal, screen_normal) > 0.0 {
+        let offset = normalize(screen_normal) * outline.width * 2.0 / view.viewport.zw;
+        out.position = vec4(out.position.xy + offset * out.position.w, out.position.zw);
+    }
+#endif
+
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex_in.instance_index;
+#endif
+
+#ifdef VISIBILITY_RANGE_DITHER
+    out.visibility_range_dither = mesh_functions::get_visibility_range_dither_level(
+        vertex_in.instance_index, world_from_local[3]);
+#endif
+
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    return outline.color;
+}
@@ -689,6 +689,13 @@ pub struct StandardMaterial {
     /// [z-fighting]: https://en.wikipedia.org/wiki/Z-fighting
     pub depth_bias: f32,
 
+    /// Adjust the render order of transparent meshes, without affecting depth.
+    ///
+    /// A material with a positive sort bias is sorted as if it was closer to
+    /// the camera, so it renders over other transparent meshes it overlaps.
+    /// See also [`MeshSortBias`](crate::MeshSortBias).
+    pub sort_bias: f32,
+
     /// The depth map used for [parallax mapping].
     ///
     /// It is a grayscale image where white represents bottom and black the top.
@@ -928,6 +935,7 @@ impl Default for StandardMaterial {
             fog_enabled: true,
             alpha_mode: AlphaMode::Opaque,
             depth_bias: 0.0,
+            sort_bias: 0.0,
             depth_map: None,
             parallax_depth_scale: 0.1,
             max_parallax_layer_count: 16.0,
@@ -1412,6 +1420,11 @@ impl Material for StandardMaterial {
         self.depth_bias
     }
 
+    #[inline]
+    fn sort_bias(&self) -> f32 {
+        self.sort_bias
+    }
+
     #[inline]
     fn reads_view_transmission_texture(&self) -> bool {
         self.specular_transmission > 0.0
@@ -24,7 +24,8 @@ use bevy_light::SunDisk;
 use bevy_light::{
     spot_light_clip_from_view, spot_light_world_from_view, AmbientLight, CascadeShadowConfig,
     Cascades, DirectionalLight, DirectionalLightShadowMap, GlobalAmbientLight, PointLight,
-    PointLightShadowMap, RectLight, ShadowFilteringMethod, SpotLight, VolumetricLight,
+    PointLightShadowMap, RectLight, ShadowCascadesDebug, ShadowFilteringMethod, SpotLight,
+    VolumetricLight,
 };
 use bevy_log::warn_once;
 use bevy_material::{
@@ -161,6 +162,7 @@ pub struct GpuDirectionalLight {
     shadow_normal_bias: f32,
     num_cascades: u32,
     cascades_overlap_proportion: f32,
+    cascades_fade_proportion: f32,
     depth_texture_base_index: u32,
     decal_index: u32,
     sun_disk_angular_size: f32,
@@ -175,6 +177,7 @@ bitflags::bitflags! {
         const VOLUMETRIC                        = 1 << 1;
         const AFFECTS_LIGHTMAPPED_MESH_DIFFUSE  = 1 << 2;
         const CONTACT_SHADOWS_ENABLED           = 1 << 3;
+        const DEBUG_CASCADES                    = 1 << 4;
         const NONE                              = 0;
         const UNINITIALIZED                     = 0xFFFF;
     }
@@ -292,6 +295,21 @@ pub fn extract_shadow_filtering_method(
     commands.try_insert_batch(values);
 }
 
+// This is needed because of the orphan rule not allowing implementing
+// foreign trait ExtractComponent on foreign type ShadowCascadesDebug
+pub fn extract_shadow_cascades_debug(
+    mut commands: Commands,
+    mut previous_len: Local<usize>,
+    query: Extract<Query<(RenderEntity, &ShadowCascadesDebug)>>,
+) {
+    let mut values = Vec::with_capacity(*previous_len);
+    for (entity, query_item) in &query {
+        values.push((entity, *query_item));
+    }
+    *previous_len = values.len();
+    commands.try_insert_batch(values);
+}
+
 // This is needed because of the orphan rule not allowing implementing
 // foreign trait ExtractResource on foreign type AmbientLight
 pub fn extract_ambient_light_resource(
@@ -1067,6 +1085,7 @@ pub fn prepare_lights(
             Option<&RenderLayers>,
             Has<NoIndirectDrawing>,
             Option<&AmbientLight>,
+            Has<ShadowCascadesDebug>,
         ),
         With<Camera3d>,
     >,
@@ -1410,6 +1429,7 @@ pub fn prepare_lights(
         maybe_layers,
         _no_indirect_drawing,
         _maybe_ambient_override,
+        _debug_cascades,
     ) in sorted_cameras
         .0
         .iter()
@@ -1687,6 +1707,7 @@ pub fn prepare_lights(
         maybe_layers,
         no_indirect_drawing,
         maybe_ambient_override,
+        debug_cascades,
     ) in sorted_cameras
         .0
         .iter()
@@ -1774,6 +1795,10 @@ pub fn prepare_lights(
                 flags |= DirectionalLightFlags::AFFECTS_LIGHTMAPPED_MESH_DIFFUSE;
             }
 
+            if debug_cascades {
+                flags |= DirectionalLightFlags::DEBUG_CASCADES;
+            }
+
             gpu_directional_lights[index] = GpuDirectionalLight {
                 // Filled in later.
                 cascades: [GpuDirectionalCascade::default(); MAX_CASCADES_PER_LIGHT],
@@ -1788,6 +1813,7 @@ pub fn prepare_lights(
                 shadow_normal_bias: light.shadow_normal_bias,
                 num_cascades: num_cascades as u32,
                 cascades_overlap_proportion: light.cascade_shadow_config.overlap_proportion,
+                cascades_fade_proportion: light.cascade_shadow_config.fade_proportion,
                 depth_texture_base_index: num_directional_cascades_enabled_for_this_view as u32,
                 sun_disk_angular_size: light.sun_disk_angular_size,
                 sun_disk_intensity: light.sun_disk_intensity,
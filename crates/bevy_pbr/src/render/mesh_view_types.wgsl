@@ -41,6 +41,7 @@ struct DirectionalLight {
     shadow_normal_bias: f32,
     num_cascades: u32,
     cascades_overlap_proportion: f32,
+    cascades_fade_proportion: f32,
     depth_texture_base_index: u32,
     decal_index: u32,
     sun_disk_angular_size: f32,
@@ -51,6 +52,7 @@ const DIRECTIONAL_LIGHT_FLAGS_SHADOWS_ENABLED_BIT: u32                  = 1u <<
 const DIRECTIONAL_LIGHT_FLAGS_VOLUMETRIC_BIT: u32                       = 1u << 1u;
 const DIRECTIONAL_LIGHT_FLAGS_AFFECTS_LIGHTMAPPED_MESH_DIFFUSE_BIT: u32 = 1u << 2u;
 const DIRECTIONAL_LIGHT_FLAGS_CONTACT_SHADOWS_ENABLED_BIT: u32           = 1u << 3u;
+const DIRECTIONAL_LIGHT_FLAGS_DEBUG_CASCADES_BIT: u32                   = 1u << 4u;
 
 struct RectLight {
     color: vec4<f32>,
@@ -19,7 +19,7 @@
 #endif
 
 #ifdef VISIBILITY_RANGE_DITHER
-#import bevy_pbr::pbr_functions::visibility_range_dither;
+#import bevy_pbr::pbr_functions::visibility_range_fade;
 #endif
 
 #ifdef MESHLET_MESH_MATERIAL_PASS
@@ -50,12 +50,6 @@ fn fragment(
 
     var in = vertex_output;
 
-    // If we're in the crossfade section of a visibility range, conditionally
-    // discard the fragment according to the visibility pattern.
-#ifdef VISIBILITY_RANGE_DITHER
-    visibility_range_dither(in.position, in.visibility_range_dither);
-#endif
-
 #ifdef FORWARD_DECAL
     let forward_decal_info = get_forward_decal_info(in);
     in.world_position = forward_decal_info.world_position;
@@ -65,6 +59,17 @@ fn fragment(
     // generate a PbrInput struct from the StandardMaterial bindings
     var pbr_input = pbr_input_from_standard_material(in, is_front);
 
+    // If we're in the crossfade section of a visibility range, conditionally
+    // discard the fragment according to the visibility pattern, or fade it out
+    // if the material is alpha blended.
+#ifdef VISIBILITY_RANGE_DITHER
+    pbr_input.material.base_color.a *= visibility_range_fade(
+        pbr_input.material.flags,
+        in.position,
+        in.visibility_range_dither,
+    );
+#endif
+
     // alpha discard
     pbr_input.material.base_color = alpha_discard(
         pbr_input.material.flags,
@@ -106,6 +106,26 @@ fn visibility_range_dither(frag_coord: vec4<f32>, dither: i32) {
         discard;
     }
 }
+
+// Processes a visibility range dither value for a material, and returns the
+// factor its alpha should be multiplied by.
+//
+// Alpha blended materials fade smoothly by lowering their alpha, while all
+// other materials use the dither pattern of `visibility_range_dither`.
+fn visibility_range_fade(material_flags: u32, frag_coord: vec4<f32>, dither: i32) -> f32 {
+    let alpha_mode = material_flags & pbr_types::STANDARD_MATERIAL_FLAGS_ALPHA_MODE_RESERVED_BITS;
+    if alpha_mode == pbr_types::STANDARD_MATERIAL_FLAGS_ALPHA_MODE_BLEND
+        || alpha_mode == pbr_types::STANDARD_MATERIAL_FLAGS_ALPHA_MODE_PREMULTIPLIED
+        || alpha_mode == pbr_types::STANDARD_MATERIAL_FLAGS_ALPHA_MODE_ADD {
+        if (dither <= -16 || dither >= 16) {
+            discard;
+        }
+        return 1.0 - f32(abs(dither)) / 16.0;
+    }
+
+    visibility_range_dither(frag_coord, dither);
+    return 1.0;
+}
 #endif
 
 fn alpha_discard(material_flags: u32, alpha_cutoff: f32, output_color: vec4<f32>) -> vec4<f32> {
@@ -643,6 +663,12 @@ fn apply_pbr_lighting(
 
 #ifdef DIRECTIONAL_LIGHT_SHADOW_MAP_DEBUG_CASCADES
         light_contrib = shadows::cascade_debug_visualization(light_contrib, i, view_z);
+#else
+        // The `ShadowCascadesDebug` component on the camera enables the visualization at runtime.
+        if (view_bindings::lights.directional_lights[i].flags &
+                mesh_view_types::DIRECTIONAL_LIGHT_FLAGS_DEBUG_CASCADES_BIT) != 0u {
+            light_contrib = shadows::cascade_debug_visualization(light_contrib, i, view_z);
+        }
 #endif
         direct_light += light_contrib * shadow;
 
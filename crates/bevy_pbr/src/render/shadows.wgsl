@@ -245,10 +245,9 @@ fn fetch_directional_shadow(
 
     // Blend with the next cascade, if there is one.
     let next_cascade_index = cascade_index + 1u;
+    let next_cascade_blend = cascade_blend_factor(light_id, cascade_index, view_z);
     if (next_cascade_index < (*light).num_cascades) {
-        let this_far_bound = (*light).cascades[cascade_index].far_bound;
-        let next_near_bound = (1.0 - (*light).cascades_overlap_proportion) * this_far_bound;
-        if (-view_z >= next_near_bound) {
+        if (next_cascade_blend > 0.0) {
             let next_shadow = sample_directional_cascade(
                 light_id,
                 next_cascade_index,
@@ -256,25 +255,61 @@ fn fetch_directional_shadow(
                 surface_normal,
                 frag_coord_xy,
             );
-            shadow = mix(shadow, next_shadow, (-view_z - next_near_bound) / (this_far_bound - next_near_bound));
+            shadow = mix(shadow, next_shadow, next_cascade_blend);
         }
+    } else {
+        // Fade the last cascade out towards the maximum shadow distance.
+        shadow = mix(shadow, 1.0, next_cascade_blend);
     }
     return shadow;
 }
 
+// Returns how far `view_z` is into the band at the far end of the cascade, where it blends with
+// the next cascade, or fades out if it is the last one. 0.0 outside of the band, 1.0 at the far
+// bound.
+fn cascade_blend_factor(light_id: u32, cascade_index: u32, view_z: f32) -> f32 {
+    let light = &view_bindings::lights.directional_lights[light_id];
+    var proportion = (*light).cascades_overlap_proportion;
+    if (cascade_index + 1u >= (*light).num_cascades) {
+        proportion = (*light).cascades_fade_proportion;
+    }
+    if (proportion <= 0.0) {
+        return 0.0;
+    }
+    let this_far_bound = (*light).cascades[cascade_index].far_bound;
+    let band_start = (1.0 - proportion) * this_far_bound;
+    return saturate((-view_z - band_start) / (this_far_bound - band_start));
+}
+
+fn cascade_debug_color(cascade_index: u32) -> vec3<f32> {
+    let cascade_color_hsv = vec3(
+        f32(cascade_index) / f32(#{MAX_CASCADES_PER_LIGHT}u + 1u) * PI_2,
+        1.0,
+        0.5
+    );
+    return hsv_to_rgb(cascade_color_hsv);
+}
+
+// Tints each cascade with its own color. Where cascades overlap, the color blends into the color
+// of the next cascade, and it fades to black where the last cascade fades out.
 fn cascade_debug_visualization(
     output_color: vec3<f32>,
     light_id: u32,
     view_z: f32,
 ) -> vec3<f32> {
+    let light = &view_bindings::lights.directional_lights[light_id];
     let overlay_alpha = 0.95;
     let cascade_index = get_cascade_index(light_id, view_z);
-    let cascade_color_hsv = vec3(
-        f32(cascade_index) / f32(#{MAX_CASCADES_PER_LIGHT}u + 1u) * PI_2,
-        1.0,
-        0.5
-    );
-    let cascade_color = hsv_to_rgb(cascade_color_hsv);
+    if (cascade_index >= (*light).num_cascades) {
+        return output_color;
+    }
+
+    let blend = cascade_blend_factor(light_id, cascade_index, view_z);
+    var next_color = vec3(0.0);
+    if (cascade_index + 1u < (*light).num_cascades) {
+        next_color = cascade_debug_color(cascade_index + 1u);
+    }
+    let cascade_color = mix(cascade_debug_color(cascade_index), next_color, blend);
     return vec3<f32>(
         (1.0 - overlay_alpha) * output_color.rgb + overlay_alpha * cascade_color
     );
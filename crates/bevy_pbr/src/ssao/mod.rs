@@ -18,6 +18,7 @@ use bevy_image::ToExtents;
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{
     camera::{ExtractedCamera, TemporalJitter},
+    capabilities::{CapabilityRequirements, RenderCapabilityReport},
     diagnostic::RecordDiagnostics,
     extract_component::ExtractComponent,
     globals::{GlobalsBuffer, GlobalsUniform},
@@ -37,7 +38,7 @@ use bevy_render::{
 use bevy_shader::{load_shader_library, Shader, ShaderDefVal};
 use bevy_utils::prelude::default;
 use core::mem;
-use tracing::{error, warn};
+use tracing::error;
 
 /// Plugin for screen space ambient occlusion.
 pub struct ScreenSpaceAmbientOcclusionPlugin;
@@ -58,14 +59,19 @@ impl Plugin for ScreenSpaceAmbientOcclusionPlugin {
             return;
         };
 
-        if render_app
-            .world()
-            .resource::<RenderDevice>()
-            .limits()
-            .max_storage_textures_per_shader_stage
-            < 5
-        {
-            warn!("ScreenSpaceAmbientOcclusionPlugin not loaded. GPU lacks support: Limits::max_storage_textures_per_shader_stage is less than 5.");
+        let world = render_app.world();
+        let support = world.resource::<RenderCapabilityReport>().negotiate(
+            "ssao",
+            &CapabilityRequirements::new().with_min_limit(
+                "max_storage_textures_per_shader_stage",
+                |limits| limits.max_storage_textures_per_shader_stage.into(),
+                5,
+            ),
+            world.resource::<RenderDevice>(),
+            world.resource::<RenderAdapter>(),
+            None,
+        );
+        if !support.is_available() {
             return;
         }
 
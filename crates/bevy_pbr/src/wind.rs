@@ -0,0 +1,136 @@
+use crate::{shader_ref, ExtendedMaterial, MaterialExtension, MaterialPlugin, StandardMaterial};
+use bevy_app::{App, Plugin};
+use bevy_asset::{embedded_asset, Asset};
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    render_asset::RenderAssets,
+    render_resource::{AsBindGroup, AsBindGroupShaderType, ShaderType},
+    texture::GpuImage,
+};
+use bevy_shader::ShaderRef;
+
+/// Plugin to render [`WindMaterial`]s.
+pub struct WindPlugin;
+
+impl Plugin for WindPlugin {
+    fn build(&self, app: &mut App) {
+        embedded_asset!(app, "wind.wgsl");
+
+        app.add_plugins(MaterialPlugin::<WindMaterial>::default());
+    }
+}
+
+/// A [`StandardMaterial`] whose vertices sway in the wind, for grass, foliage and other
+/// vegetation.
+///
+/// The vertices are displaced in the vertex shader, in the main pass as well as in the shadow,
+/// depth, motion vector and deferred prepasses, so animating thousands of plants costs no CPU
+/// time.
+///
+/// ```
+/// # use bevy_asset::Assets;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::Vec3;
+/// # use bevy_pbr::{ExtendedMaterial, MeshMaterial3d, StandardMaterial, WindMaterial, WindSway};
+/// fn spawn_grass(mut commands: Commands, mut materials: ResMut<Assets<WindMaterial>>) {
+///     let material = materials.add(ExtendedMaterial {
+///         base: StandardMaterial::default(),
+///         extension: WindSway {
+///             direction: Vec3::X,
+///             strength: 0.2,
+///             ..Default::default()
+///         },
+///     });
+///     commands.spawn(MeshMaterial3d(material));
+/// }
+/// ```
+pub type WindMaterial = ExtendedMaterial<StandardMaterial, WindSway>;
+
+/// Material extension swaying the vertices of a mesh in the wind.
+///
+/// The vertices are bent along [`WindSway::direction`] in proportion to the square of their
+/// height above the origin of the mesh, so the base of a plant stays anchored to the ground while
+/// its top moves the most. Every instance sways with its own phase, derived from its position, so
+/// neighboring plants don't move in unison. The phase can be offset further by giving the entities
+/// a [`MeshTag`](bevy_mesh::MeshTag), in thousandths of a radian.
+///
+/// Only the positions of the vertices are displaced: the normals are left as they are, which is
+/// not noticeable for the small displacements of vegetation. Skinned and morphed meshes are not
+/// supported.
+#[derive(Asset, AsBindGroup, Reflect, Clone, Debug)]
+#[reflect(Default, Debug, Clone)]
+#[uniform(100, WindSwayUniform)]
+pub struct WindSway {
+    /// The direction in which the wind blows, in world space.
+    ///
+    /// Defaults to [`Vec3::X`].
+    pub direction: Vec3,
+    /// How far the vertices at [`WindSway::height`] are displaced at the peak of a gust, in world
+    /// units.
+    ///
+    /// Defaults to `0.1`.
+    pub strength: f32,
+    /// How many gusts blow per second.
+    ///
+    /// Defaults to `0.5`.
+    pub frequency: f32,
+    /// The height above the origin of the mesh, in local units, from which the vertices are
+    /// displaced the most. This is usually the height of the plant.
+    ///
+    /// Defaults to `1.0`.
+    pub height: f32,
+    /// How far the vertices quiver, independently of each other, in world units, for the leaves
+    /// fluttering in the wind.
+    ///
+    /// Defaults to `0.0`.
+    pub flutter: f32,
+}
+
+impl Default for WindSway {
+    fn default() -> Self {
+        Self {
+            direction: Vec3::X,
+            strength: 0.1,
+            frequency: 0.5,
+            height: 1.0,
+            flutter: 0.0,
+        }
+    }
+}
+
+/// The GPU representation of a [`WindSway`].
+#[derive(Clone, Default, ShaderType)]
+pub struct WindSwayUniform {
+    pub direction: Vec3,
+    pub strength: f32,
+    pub frequency: f32,
+    pub height: f32,
+    pub flutter: f32,
+}
+
+impl AsBindGroupShaderType<WindSwayUniform> for WindSway {
+    fn as_bind_group_shader_type(&self, _images: &RenderAssets<GpuImage>) -> WindSwayUniform {
+        WindSwayUniform {
+            direction: self.direction.normalize_or_zero(),
+            strength: self.strength,
+            frequency: self.frequency,
+            height: self.height,
+            flutter: self.flutter,
+        }
+    }
+}
+
+impl MaterialExtension for WindSway {
+    fn vertex_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("wind.wgsl"))
+    }
+
+    fn prepass_vertex_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("wind.wgsl"))
+    }
+
+    fn deferred_vertex_shader() -> ShaderRef {
+        shader_ref(bevy_asset::embedded_path!("wind.wgsl"))
+    }
+}
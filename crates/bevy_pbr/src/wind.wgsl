@@ -0,0 +1,142 @@
+// Sways the vertices of a `WindMaterial` in the wind, in the main pass and in the prepasses, so
+// that the shadows and the motion vectors follow.
+
+#import bevy_pbr::{
+    mesh_functions,
+    view_transformations::position_world_to_clip,
+}
+#import bevy_render::globals::Globals
+
+#ifdef PREPASS_PIPELINE
+#import bevy_pbr::prepass_io::{Vertex, VertexOutput, decompress_vertex}
+
+@group(0) @binding(1) var<uniform> globals: Globals;
+#else
+#import bevy_pbr::{
+    forward_io::{Vertex, VertexOutput, decompress_vertex},
+    mesh_view_bindings::globals,
+}
+#endif
+
+struct WindSway {
+    direction: vec3<f32>,
+    strength: f32,
+    frequency: f32,
+    height: f32,
+    flutter: f32,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> wind: WindSway;
+
+const TAU: f32 = 6.28318530718;
+
+// Returns the world space displacement of the vertex at `local_position` at `time`.
+//
+// The phase of each instance is derived from its position, so that neighboring plants do not
+// sway in unison, and offset by its `MeshTag`, in thousandths of a radian.
+fn sway(
+    local_position: vec3<f32>,
+    world_from_local: mat4x4<f32>,
+    instance_index: u32,
+    time: f32,
+) -> vec3<f32> {
+    let origin = world_from_local[3].xyz;
+    let phase = dot(origin.xz, vec2(0.37, 0.61))
+        + f32(mesh_functions::get_tag(instance_index)) * 0.001;
+    // The vertices bend more the higher they are, and not at all below the origin.
+    let bend = clamp(local_position.y / max(wind.height, 0.0001), 0.0, 1.0);
+    let angle = time * wind.frequency * TAU + phase;
+    let gust = 0.5 + 0.5 * sin(angle);
+    let flutter = sin(angle * 5.3 + dot(local_position, vec3(7.1, 3.7, 5.3))) * wind.flutter;
+    return wind.direction * (bend * bend * wind.strength * gust + bend * flutter);
+}
+
+@vertex
+fn vertex(vertex_in: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    let vertex = decompress_vertex(vertex_in, vertex_in.instance_index);
+    let world_from_local = mesh_functions::get_world_from_local(vertex_in.instance_index);
+
+    let world_position = mesh_functions::mesh_position_local_to_world(
+        world_from_local,
+        vec4<f32>(vertex.position, 1.0)
+    );
+    out.world_position = vec4(
+        world_position.xyz + sway(vertex.position, world_from_local, vertex_in.instance_index, globals.time),
+        world_position.w
+    );
+    out.position = position_world_to_clip(out.world_position.xyz);
+
+#ifdef PREPASS_PIPELINE
+#ifdef UNCLIPPED_DEPTH_ORTHO_EMULATION
+    out.unclipped_depth = out.position.z;
+    out.position.z = min(out.position.z, 1.0); // Clamp depth to avoid clipping
+#endif // UNCLIPPED_DEPTH_ORTHO_EMULATION
+#endif // PREPASS_PIPELINE
+
+#ifdef VERTEX_UVS_A
+    out.uv = vertex.uv;
+#endif
+#ifdef VERTEX_UVS_B
+    out.uv_b = vertex.uv_b;
+#endif
+
+    // The normals are not bent, which is not noticeable for small displacements.
+#ifdef PREPASS_PIPELINE
+#ifdef NORMAL_PREPASS_OR_DEFERRED_PREPASS
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vertex.normal, vertex_in.instance_index);
+#endif
+#ifdef VERTEX_TANGENTS
+    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(
+        world_from_local,
+        vertex.tangent,
+        vertex_in.instance_index
+    );
+#endif
+#endif // NORMAL_PREPASS_OR_DEFERRED_PREPASS
+#else // PREPASS_PIPELINE
+#ifdef VERTEX_NORMALS
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vertex.normal, vertex_in.instance_index);
+#endif
+#ifdef VERTEX_TANGENTS
+    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(
+        world_from_local,
+        vertex.tangent,
+        vertex_in.instance_index
+    );
+#endif
+#endif // PREPASS_PIPELINE
+
+#ifdef VERTEX_COLORS
+    out.color = vertex.color;
+#endif
+
+#ifdef MOTION_VECTOR_PREPASS
+    let previous_world_from_local = mesh_functions::get_previous_world_from_local(vertex_in.instance_index);
+    let previous_world_position = mesh_functions::mesh_position_local_to_world(
+        previous_world_from_local,
+        vec4<f32>(vertex.position, 1.0)
+    );
+    out.previous_world_position = vec4(
+        previous_world_position.xyz + sway(
+            vertex.position,
+            previous_world_from_local,
+            vertex_in.instance_index,
+            globals.time - globals.delta_time
+        ),
+        previous_world_position.w
+    );
+#endif // MOTION_VECTOR_PREPASS
+
+#ifdef VERTEX_OUTPUT_INSTANCE_INDEX
+    out.instance_index = vertex_in.instance_index;
+#endif
+
+#ifdef VISIBILITY_RANGE_DITHER
+    out.visibility_range_dither = mesh_functions::get_visibility_range_dither_level(
+        vertex_in.instance_index, world_from_local[3]);
+#endif
+
+    return out;
+}
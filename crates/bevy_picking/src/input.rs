@@ -25,6 +25,12 @@ use bevy_platform::collections::{HashMap, HashSet};
 use bevy_reflect::prelude::*;
 use bevy_window::{PrimaryWindow, WindowEvent, WindowRef};
 use tracing::debug;
+#[cfg(feature = "gamepad")]
+use {
+    bevy_time::{Real, Time},
+    bevy_window::Window,
+    uuid::Uuid,
+};
 
 use crate::pointer::{
     Location, PointerAction, PointerButton, PointerId, PointerInput, PointerLocation,
@@ -37,6 +43,9 @@ use crate::PickingSystems;
 /// This includes the most common types in this module, re-exported for your convenience.
 pub mod prelude {
     pub use crate::input::PointerInputPlugin;
+
+    #[cfg(feature = "gamepad")]
+    pub use crate::input::GamepadPointer;
 }
 
 #[derive(Copy, Clone, Resource, Debug, Reflect)]
@@ -109,6 +118,9 @@ impl Plugin for PointerInputPlugin {
                 Last,
                 deactivate_touch_pointers.run_if(PointerInputSettings::is_touch_enabled),
             );
+
+        #[cfg(feature = "gamepad")]
+        app.add_systems(First, gamepad_pick_events.in_set(PickingSystems::Input));
     }
 }
 
@@ -298,3 +310,149 @@ pub fn deactivate_touch_pointers(
         commands.entity(entity).despawn();
     }
 }
+
+/// A virtual cursor that is moved around the primary window by a gamepad's analog stick.
+///
+/// Spawning an entity with this component creates a new picking pointer, which means the usual
+/// [`Pointer`](crate::events::Pointer) events (hover, click, drag, ...) are emitted for it and UI
+/// nodes react to it just like they would to the mouse. This is useful on consoles and handhelds,
+/// where no mouse is available.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::gamepad::Gamepad;
+/// # use bevy_picking::input::GamepadPointer;
+/// fn spawn_virtual_cursors(mut commands: Commands, gamepads: Query<Entity, Added<Gamepad>>) {
+///     for gamepad in &gamepads {
+///         commands.spawn(GamepadPointer::new(gamepad));
+///     }
+/// }
+/// ```
+///
+/// Rendering the cursor itself is left to the user: read the pointer's
+/// [`PointerLocation`](crate::pointer::PointerLocation) to position a sprite or UI node.
+#[cfg(feature = "gamepad")]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Debug, Clone)]
+#[require(PointerId = PointerId::Custom(Uuid::new_v4()))]
+pub struct GamepadPointer {
+    /// The [`Gamepad`] entity driving this pointer.
+    pub gamepad: Entity,
+    /// Which analog stick moves the pointer.
+    pub stick: GamepadStick,
+    /// The maximum speed of the pointer, in logical pixels per second.
+    pub speed: f32,
+    /// The button emulating [`PointerButton::Primary`].
+    pub primary: GamepadButton,
+    /// The button emulating [`PointerButton::Secondary`].
+    pub secondary: GamepadButton,
+    /// The current position of the pointer in logical pixels, relative to the top-left corner of
+    /// the primary window.
+    ///
+    /// When `None`, the pointer is placed at the center of the window on the next update.
+    pub position: Option<Vec2>,
+}
+
+#[cfg(feature = "gamepad")]
+impl GamepadPointer {
+    /// Creates a virtual pointer driven by the left stick of `gamepad`, with the south button
+    /// acting as the primary button and the east button as the secondary one.
+    pub fn new(gamepad: Entity) -> Self {
+        Self {
+            gamepad,
+            stick: GamepadStick::Left,
+            speed: 800.0,
+            primary: GamepadButton::South,
+            secondary: GamepadButton::East,
+            position: None,
+        }
+    }
+}
+
+/// Selects one of the two analog sticks of a [`Gamepad`].
+#[cfg(feature = "gamepad")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash, Clone)]
+pub enum GamepadStick {
+    /// The left analog stick.
+    #[default]
+    Left,
+    /// The right analog stick.
+    Right,
+}
+
+/// Sends pointer events for every [`GamepadPointer`], based on the state of its gamepad.
+#[cfg(feature = "gamepad")]
+pub fn gamepad_pick_events(
+    // Input
+    gamepads: Query<&Gamepad>,
+    primary_window: Query<(Entity, &Window), With<PrimaryWindow>>,
+    time: Res<Time<Real>>,
+    // Output
+    mut pointers: Query<(&PointerId, &mut GamepadPointer)>,
+    mut pointer_inputs: MessageWriter<PointerInput>,
+) {
+    let Ok((window_entity, window)) = primary_window.single() else {
+        return;
+    };
+    let Some(target) = RenderTarget::Window(WindowRef::Primary).normalize(Some(window_entity))
+    else {
+        return;
+    };
+    let window_size = window.size();
+
+    for (pointer_id, mut pointer) in &mut pointers {
+        let Ok(gamepad) = gamepads.get(pointer.gamepad) else {
+            continue;
+        };
+
+        let stick = match pointer.stick {
+            GamepadStick::Left => gamepad.left_stick(),
+            GamepadStick::Right => gamepad.right_stick(),
+        };
+        // Stick Y points up, window Y points down.
+        let velocity = Vec2::new(stick.x, -stick.y) * pointer.speed;
+
+        let (last_position, spawned) = match pointer.position {
+            Some(position) => (position, false),
+            None => (window_size / 2.0, true),
+        };
+        let position =
+            (last_position + velocity * time.delta_secs()).clamp(Vec2::ZERO, window_size);
+
+        if spawned || position != last_position {
+            pointer.position = Some(position);
+            pointer_inputs.write(PointerInput::new(
+                *pointer_id,
+                Location {
+                    target: target.clone(),
+                    position,
+                },
+                PointerAction::Move {
+                    delta: position - last_position,
+                },
+            ));
+        }
+
+        for (button, pointer_button) in [
+            (pointer.primary, PointerButton::Primary),
+            (pointer.secondary, PointerButton::Secondary),
+        ] {
+            let action = if gamepad.just_pressed(button) {
+                PointerAction::Press(pointer_button)
+            } else if gamepad.just_released(button) {
+                PointerAction::Release(pointer_button)
+            } else {
+                continue;
+            };
+            pointer_inputs.write(PointerInput::new(
+                *pointer_id,
+                Location {
+                    target: target.clone(),
+                    position,
+                },
+                action,
+            ));
+        }
+    }
+}
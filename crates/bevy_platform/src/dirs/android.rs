@@ -0,0 +1,57 @@
+use alloc::string::ToString;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Android assigns a range of 100000 uids to each user of the device.
+const PER_USER_RANGE: u32 = 100_000;
+
+/// Returns the internal storage of the app, like `/data/user/0/com.example.mygame`.
+///
+/// The package name is the name of the process, and the user is derived from its uid, since the
+/// [`Context`](https://developer.android.com/reference/android/content/Context) of the app is not
+/// available here.
+fn app_dir() -> Option<PathBuf> {
+    let cmdline = fs::read("/proc/self/cmdline").ok()?;
+    let process = cmdline.split(|&byte| byte == 0).next()?;
+    // The other processes of the app are named like `com.example.mygame:service`.
+    let package = core::str::from_utf8(process).ok()?.split(':').next()?;
+    if package.is_empty() {
+        return None;
+    }
+
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let uid: u32 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("Uid:"))?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(
+        Path::new("/data/user")
+            .join((uid / PER_USER_RANGE).to_string())
+            .join(package),
+    )
+}
+
+/// Returns the path to the directory used for application settings.
+pub fn preferences_dir() -> Option<PathBuf> {
+    data_dir()
+}
+
+/// Returns the path to the directory used for application data, like save files.
+pub fn data_dir() -> Option<PathBuf> {
+    app_dir().map(|app| app.join("files"))
+}
+
+/// Returns the path to the directory used for cached application data, which can be deleted.
+pub fn cache_dir() -> Option<PathBuf> {
+    app_dir().map(|app| app.join("cache"))
+}
+
+/// Returns the path to the directory used for application logs.
+pub fn log_dir() -> Option<PathBuf> {
+    data_dir()
+}
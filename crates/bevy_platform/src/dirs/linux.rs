@@ -25,6 +25,31 @@ pub fn preferences_dir() -> Option<PathBuf> {
         .or_else(|| home_dir().map(|home| home.join(".config")))
 }
 
+/// Returns the path to the directory used for application data, like save files.
+pub fn data_dir() -> Option<PathBuf> {
+    // default value for XDG_DATA_HOME when unset, empty, or invalid is ~/.local/share/
+    env::var_os("XDG_DATA_HOME")
+        .and_then(is_absolute_path)
+        .or_else(|| home_dir().map(|home| home.join(".local/share")))
+}
+
+/// Returns the path to the directory used for cached application data, which can be deleted.
+pub fn cache_dir() -> Option<PathBuf> {
+    // default value for XDG_CACHE_HOME when unset, empty, or invalid is ~/.cache/
+    env::var_os("XDG_CACHE_HOME")
+        .and_then(is_absolute_path)
+        .or_else(|| home_dir().map(|home| home.join(".cache")))
+}
+
+/// Returns the path to the directory used for application logs.
+pub fn log_dir() -> Option<PathBuf> {
+    // logs belong to the state directory, ~/.local/state/ when XDG_STATE_HOME is unset, empty,
+    // or invalid
+    env::var_os("XDG_STATE_HOME")
+        .and_then(is_absolute_path)
+        .or_else(|| home_dir().map(|home| home.join(".local/state")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
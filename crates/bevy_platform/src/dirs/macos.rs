@@ -4,3 +4,18 @@ use std::{env::home_dir, path::PathBuf};
 pub fn preferences_dir() -> Option<PathBuf> {
     home_dir().map(|home| home.join("Library/Preferences"))
 }
+
+/// Returns the path to the directory used for application data, like save files.
+pub fn data_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join("Library/Application Support"))
+}
+
+/// Returns the path to the directory used for cached application data, which can be deleted.
+pub fn cache_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join("Library/Caches"))
+}
+
+/// Returns the path to the directory used for application logs.
+pub fn log_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join("Library/Logs"))
+}
@@ -2,27 +2,84 @@
 
 // Modeled after https://github.com/dirs-dev/dirs-sys-rs/
 
-#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "android"
+)))]
 use std::path::PathBuf;
 
 #[cfg(target_os = "windows")]
 mod windows;
 #[cfg(target_os = "windows")]
-pub use windows::preferences_dir;
+pub use windows::{cache_dir, data_dir, log_dir, preferences_dir};
 
-#[cfg(target_os = "macos")]
+// iOS apps have the same `Library` directories, in their sandbox.
+#[cfg(any(target_os = "macos", target_os = "ios"))]
 mod macos;
-#[cfg(target_os = "macos")]
-pub use macos::preferences_dir;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+pub use macos::{cache_dir, data_dir, log_dir, preferences_dir};
 
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "linux")]
-pub use linux::preferences_dir;
+pub use linux::{cache_dir, data_dir, log_dir, preferences_dir};
+
+#[cfg(target_os = "android")]
+mod android;
+#[cfg(target_os = "android")]
+pub use android::{cache_dir, data_dir, log_dir, preferences_dir};
 
 /// Returns the path to the directory used for application settings. This version
 /// always returns `None`.
-#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "android"
+)))]
 pub fn preferences_dir() -> Option<PathBuf> {
     None
 }
+
+/// Returns the path to the directory used for application data, like save files. This version
+/// always returns `None`.
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "android"
+)))]
+pub fn data_dir() -> Option<PathBuf> {
+    None
+}
+
+/// Returns the path to the directory used for cached application data, which can be deleted.
+/// This version always returns `None`.
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "android"
+)))]
+pub fn cache_dir() -> Option<PathBuf> {
+    None
+}
+
+/// Returns the path to the directory used for application logs. This version always returns
+/// `None`.
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "android"
+)))]
+pub fn log_dir() -> Option<PathBuf> {
+    None
+}
@@ -6,7 +6,7 @@ use std::os::windows::ffi::OsStringExt;
 use std::path::PathBuf;
 use windows::Win32::UI::Shell;
 
-/// Returns the path to the directory used for application settings.
+/// Returns the path to a known folder.
 // From https://github.com/dirs-dev/dirs-sys-rs/blob/main/src/lib.rs
 #[expect(unsafe_code, reason = "Uses unsafe Windows API functions")]
 fn known_folder(folder_id: windows::core::GUID) -> Option<PathBuf> {
@@ -32,3 +32,18 @@ fn known_folder(folder_id: windows::core::GUID) -> Option<PathBuf> {
 pub fn preferences_dir() -> Option<PathBuf> {
     known_folder(Shell::FOLDERID_LocalAppData)
 }
+
+/// Returns the path to the directory used for application data, like save files.
+pub fn data_dir() -> Option<PathBuf> {
+    known_folder(Shell::FOLDERID_RoamingAppData)
+}
+
+/// Returns the path to the directory used for cached application data, which can be deleted.
+pub fn cache_dir() -> Option<PathBuf> {
+    known_folder(Shell::FOLDERID_LocalAppData)
+}
+
+/// Returns the path to the directory used for application logs.
+pub fn log_dir() -> Option<PathBuf> {
+    known_folder(Shell::FOLDERID_LocalAppData)
+}
@@ -11,6 +11,7 @@ struct BloomUniforms {
     viewport: vec4<f32>,
     scale: vec2<f32>,
     aspect: f32,
+    lens_dirt_intensity: f32,
 };
 
 @group(0) @binding(0) var input_texture: texture_2d<f32>;
@@ -18,6 +19,11 @@ struct BloomUniforms {
 
 @group(0) @binding(2) var<uniform> uniforms: BloomUniforms;
 
+#ifdef LENS_DIRT
+@group(1) @binding(0) var lens_dirt_texture: texture_2d<f32>;
+@group(1) @binding(1) var lens_dirt_sampler: sampler;
+#endif
+
 #ifdef FIRST_DOWNSAMPLE
 // https://catlikecoding.com/unity/tutorials/advanced-rendering/bloom/#3.4
 fn soft_threshold(color: vec3<f32>) -> vec3<f32> {
@@ -179,5 +185,11 @@ fn downsample(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
 
 @fragment
 fn upsample(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
-    return vec4<f32>(sample_input_3x3_tent(uv), 1.0);
+    var sample = sample_input_3x3_tent(uv);
+#ifdef LENS_DIRT
+    // Only the final upsample, which covers the viewport, reads the dirt texture.
+    let dirt = textureSample(lens_dirt_texture, lens_dirt_sampler, uv).rgb;
+    sample += sample * dirt * uniforms.lens_dirt_intensity;
+#endif
+    return vec4<f32>(sample, 1.0);
 }
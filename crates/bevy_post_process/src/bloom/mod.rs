@@ -3,7 +3,7 @@ mod settings;
 mod upsampling_pipeline;
 
 use bevy_image::ToExtents;
-pub use settings::{Bloom, BloomCompositeMode, BloomPrefilter};
+pub use settings::{Bloom, BloomCompositeMode, BloomLensDirt, BloomPrefilter};
 
 use crate::bloom::{
     downsampling_pipeline::init_bloom_downsampling_pipeline,
@@ -24,9 +24,10 @@ use bevy_render::{
     extract_component::{
         ComponentUniforms, DynamicUniformIndex, ExtractComponentPlugin, UniformComponentPlugin,
     },
+    render_asset::RenderAssets,
     render_resource::*,
     renderer::{RenderContext, RenderDevice, ViewQuery},
-    texture::{CachedTexture, TextureCache},
+    texture::{CachedTexture, GpuImage, TextureCache},
     view::ViewTarget,
     GpuResourceAppExt, Render, RenderApp, RenderStartup, RenderSystems,
 };
@@ -49,6 +50,7 @@ impl Plugin for BloomPlugin {
 
         app.add_plugins((
             ExtractComponentPlugin::<Bloom>::default(),
+            ExtractComponentPlugin::<BloomLensDirt>::default(),
             UniformComponentPlugin::<BloomUniforms>::default(),
         ));
 
@@ -95,8 +97,11 @@ pub fn bloom(
         &Bloom,
         &UpsamplingPipelineIds,
         &BloomDownsamplingPipelineIds,
+        Option<&BloomLensDirt>,
     )>,
     downsampling_pipeline_res: Res<BloomDownsamplingPipeline>,
+    upsampling_pipeline_res: Res<BloomUpsamplingPipeline>,
+    images: Res<RenderAssets<GpuImage>>,
     pipeline_cache: Res<PipelineCache>,
     uniforms: Res<ComponentUniforms<BloomUniforms>>,
     mut ctx: RenderContext,
@@ -110,6 +115,7 @@ pub fn bloom(
         bloom_settings,
         upsampling_pipeline_ids,
         downsampling_pipeline_ids,
+        lens_dirt,
     ) = view.into_inner();
 
     if bloom_settings.intensity == 0.0 || !camera.hdr {
@@ -147,6 +153,21 @@ pub fn bloom(
         )),
     );
 
+    // The final upsampling pipeline reads the lens dirt once its texture is loaded.
+    let lens_dirt_bind_group = lens_dirt
+        .and_then(|lens_dirt| images.get(&lens_dirt.texture))
+        .map(|lens_dirt_image| {
+            ctx.render_device().create_bind_group(
+                "bloom_lens_dirt_bind_group",
+                &pipeline_cache
+                    .get_bind_group_layout(&upsampling_pipeline_res.lens_dirt_bind_group_layout),
+                &BindGroupEntries::sequential((
+                    &lens_dirt_image.texture_view,
+                    &bind_groups.sampler,
+                )),
+            )
+        });
+
     let diagnostics = ctx.diagnostic_recorder();
     let diagnostics = diagnostics.as_deref();
     let time_span = diagnostics.time_span(ctx.command_encoder(), "bloom");
@@ -255,6 +276,9 @@ pub fn bloom(
             &bind_groups.upsampling_bind_groups[(bloom_texture.mip_count - 1) as usize],
             &[uniform_index.index()],
         );
+        if let Some(lens_dirt_bind_group) = &lens_dirt_bind_group {
+            upsampling_final_pass.set_bind_group(1, lens_dirt_bind_group, &[]);
+        }
         if let Some(viewport) = camera.viewport.as_ref() {
             upsampling_final_pass.set_viewport(
                 viewport.physical_position.x as f32,
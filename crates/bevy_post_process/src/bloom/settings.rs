@@ -1,10 +1,12 @@
 use super::downsampling_pipeline::BloomUniforms;
+use bevy_asset::Handle;
 use bevy_camera::{Camera, Hdr};
 use bevy_ecs::{
     prelude::Component,
     query::{QueryItem, With},
     reflect::ReflectComponent,
 };
+use bevy_image::Image;
 use bevy_math::{AspectRatio, URect, UVec4, Vec2, Vec4};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_render::{extract_component::ExtractComponent, sync_component::SyncComponent, RenderApp};
@@ -27,6 +29,11 @@ use bevy_render::{extract_component::ExtractComponent, sync_component::SyncCompo
 /// blurred (lower frequency) images generated from the camera's view.
 /// See <https://starlederer.github.io/bloom/> for a visualization of the parametric curve
 /// used in Bevy as well as a visualization of the curve's respective scattering profile.
+///
+/// Add a [`BloomLensDirt`] to the camera to modulate the bloom with a dirt texture.
+///
+/// All the parameters can be animated, for example to make the bloom stronger while the camera
+/// is dazzled.
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component, Default, Clone)]
 #[require(Hdr)]
@@ -151,6 +158,24 @@ impl Bloom {
         ..Self::NATURAL
     };
 
+    /// A faint, energy-conserving preset, that only shows around very bright lights.
+    ///
+    /// Unlike [`Bloom::OLD_SCHOOL`], this doesn't add light to the image: it scatters a small
+    /// part of it, so the exposure of the scene stays the same.
+    pub const SUBTLE: Self = Self {
+        intensity: 0.05,
+        low_frequency_boost: 0.3,
+        ..Self::NATURAL
+    };
+
+    /// A strong, energy-conserving preset with wide halos, like the bloom of film cameras.
+    pub const CINEMATIC: Self = Self {
+        intensity: 0.3,
+        low_frequency_boost: 0.8,
+        low_frequency_boost_curvature: 0.9,
+        ..Self::NATURAL
+    };
+
     /// A preset that's similar to how older games did bloom.
     pub const OLD_SCHOOL: Self = Self {
         intensity: 0.05,
@@ -213,6 +238,56 @@ pub struct BloomPrefilter {
     pub threshold_softness: f32,
 }
 
+impl BloomPrefilter {
+    /// Creates a prefilter from a threshold and an absolute knee width.
+    ///
+    /// Colors start contributing to the bloom `knee` below the `threshold`, fading in along a
+    /// quadratic curve until they reach it.
+    pub fn from_knee(threshold: f32, knee: f32) -> Self {
+        Self {
+            threshold,
+            threshold_softness: if threshold > 0.0 {
+                (knee / threshold).clamp(0.0, 1.0)
+            } else {
+                0.0
+            },
+        }
+    }
+
+    /// The width of the soft knee below the threshold, in the same units as the threshold.
+    pub fn knee(&self) -> f32 {
+        self.threshold * self.threshold_softness.clamp(0.0, 1.0)
+    }
+}
+
+/// Modulates the [`Bloom`] of a camera with a dirt texture, emulating the smudges and scratches
+/// of a lens that light up around bright lights.
+///
+/// The texture is stretched over the viewport. Its color is multiplied with the bloom and added on
+/// top of it, so black areas of the texture are left clean.
+#[derive(Component, Clone, Reflect, ExtractComponent)]
+#[reflect(Component, Default, Clone)]
+#[extract_app(RenderApp)]
+pub struct BloomLensDirt {
+    /// The dirt texture, usually mostly black with bright smudges.
+    pub texture: Handle<Image>,
+
+    /// How much the dirt brightens the bloom (default: 1.0).
+    ///
+    /// * 0.0 means the dirt is invisible
+    /// * 1.0 means the bloom is doubled where the texture is white
+    pub intensity: f32,
+}
+
+impl Default for BloomLensDirt {
+    fn default() -> Self {
+        Self {
+            texture: Handle::default(),
+            intensity: 1.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Reflect, PartialEq, Eq, Hash, Copy)]
 #[reflect(Clone, Hash, PartialEq)]
 pub enum BloomCompositeMode {
@@ -225,11 +300,17 @@ impl SyncComponent<RenderApp> for Bloom {
 }
 
 impl ExtractComponent<RenderApp> for Bloom {
-    type QueryData = (&'static Self, &'static Camera);
+    type QueryData = (
+        &'static Self,
+        &'static Camera,
+        Option<&'static BloomLensDirt>,
+    );
     type QueryFilter = With<Hdr>;
     type Out = (Self, BloomUniforms);
 
-    fn extract_component((bloom, camera): QueryItem<'_, '_, Self::QueryData>) -> Option<Self::Out> {
+    fn extract_component(
+        (bloom, camera, lens_dirt): QueryItem<'_, '_, Self::QueryData>,
+    ) -> Option<Self::Out> {
         match (
             camera.physical_viewport_rect(),
             camera.physical_viewport_size(),
@@ -257,6 +338,7 @@ impl ExtractComponent<RenderApp> for Bloom {
                         .expect("Valid screen size values for Bloom settings")
                         .ratio(),
                     scale: bloom.scale,
+                    lens_dirt_intensity: lens_dirt.map_or(0.0, |lens_dirt| lens_dirt.intensity),
                 };
 
                 Some((bloom.clone(), uniform))
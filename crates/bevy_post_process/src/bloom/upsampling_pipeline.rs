@@ -1,7 +1,8 @@
 use bevy_core_pipeline::FullscreenShader;
 
 use super::{
-    downsampling_pipeline::BloomUniforms, Bloom, BloomCompositeMode, BLOOM_TEXTURE_FORMAT,
+    downsampling_pipeline::BloomUniforms, Bloom, BloomCompositeMode, BloomLensDirt,
+    BLOOM_TEXTURE_FORMAT,
 };
 use bevy_asset::{load_embedded_asset, AssetServer, Handle};
 use bevy_ecs::{
@@ -10,10 +11,12 @@ use bevy_ecs::{
     system::{Commands, Query, Res, ResMut},
 };
 use bevy_render::{
+    render_asset::RenderAssets,
     render_resource::{
         binding_types::{sampler, texture_2d, uniform_buffer},
         *,
     },
+    texture::GpuImage,
     view::ExtractedView,
 };
 use bevy_shader::Shader;
@@ -28,6 +31,8 @@ pub struct UpsamplingPipelineIds {
 #[derive(Resource)]
 pub struct BloomUpsamplingPipeline {
     pub bind_group_layout: BindGroupLayoutDescriptor,
+    /// Layout with the [`BloomLensDirt`] texture and sampler, bound in the final pass only.
+    pub lens_dirt_bind_group_layout: BindGroupLayoutDescriptor,
     /// The asset handle for the fullscreen vertex shader.
     pub fullscreen_shader: FullscreenShader,
     /// The fragment shader asset handle.
@@ -38,6 +43,7 @@ pub struct BloomUpsamplingPipeline {
 pub struct BloomUpsamplingPipelineKeys {
     composite_mode: BloomCompositeMode,
     target_format: TextureFormat,
+    lens_dirt: bool,
 }
 
 pub fn init_bloom_upscaling_pipeline(
@@ -60,8 +66,22 @@ pub fn init_bloom_upscaling_pipeline(
         ),
     );
 
+    let lens_dirt_bind_group_layout = BindGroupLayoutDescriptor::new(
+        "bloom_lens_dirt_bind_group_layout",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::FRAGMENT,
+            (
+                // Lens dirt texture
+                texture_2d(TextureSampleType::Float { filterable: true }),
+                // Sampler
+                sampler(SamplerBindingType::Filtering),
+            ),
+        ),
+    );
+
     commands.insert_resource(BloomUpsamplingPipeline {
         bind_group_layout,
+        lens_dirt_bind_group_layout,
         fullscreen_shader: fullscreen_shader.clone(),
         fragment_shader: load_embedded_asset!(asset_server.as_ref(), "bloom.wgsl"),
     });
@@ -103,12 +123,21 @@ impl SpecializedRenderPipeline for BloomUpsamplingPipeline {
             },
         };
 
+        let mut layout = vec![self.bind_group_layout.clone()];
+        let mut shader_defs = vec![];
+
+        if key.lens_dirt {
+            layout.push(self.lens_dirt_bind_group_layout.clone());
+            shader_defs.push("LENS_DIRT".into());
+        }
+
         RenderPipelineDescriptor {
             label: Some("bloom_upsampling_pipeline".into()),
-            layout: vec![self.bind_group_layout.clone()],
+            layout,
             vertex: self.fullscreen_shader.to_vertex_state(),
             fragment: Some(FragmentState {
                 shader: self.fragment_shader.clone(),
+                shader_defs,
                 entry_point: Some("upsample".into()),
                 targets: vec![Some(ColorTargetState {
                     format: key.target_format,
@@ -134,15 +163,17 @@ pub fn prepare_upsampling_pipeline(
     pipeline_cache: Res<PipelineCache>,
     mut pipelines: ResMut<SpecializedRenderPipelines<BloomUpsamplingPipeline>>,
     pipeline: Res<BloomUpsamplingPipeline>,
-    views: Query<(&ExtractedView, Entity, &Bloom)>,
+    images: Res<RenderAssets<GpuImage>>,
+    views: Query<(&ExtractedView, Entity, &Bloom, Option<&BloomLensDirt>)>,
 ) {
-    for (view, entity, bloom) in &views {
+    for (view, entity, bloom, lens_dirt) in &views {
         let pipeline_id = pipelines.specialize(
             &pipeline_cache,
             &pipeline,
             BloomUpsamplingPipelineKeys {
                 composite_mode: bloom.composite_mode,
                 target_format: BLOOM_TEXTURE_FORMAT,
+                lens_dirt: false,
             },
         );
 
@@ -152,6 +183,9 @@ pub fn prepare_upsampling_pipeline(
             BloomUpsamplingPipelineKeys {
                 composite_mode: bloom.composite_mode,
                 target_format: view.target_format,
+                // The dirt is only applied once it is loaded.
+                lens_dirt: lens_dirt
+                    .is_some_and(|lens_dirt| images.get(&lens_dirt.texture).is_some()),
             },
         );
 
@@ -0,0 +1,97 @@
+use bevy_camera::Camera;
+use bevy_ecs::{
+    component::Component,
+    query::{QueryItem, With},
+    reflect::ReflectComponent,
+    system::lifetimeless::Read,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    extract_component::ExtractComponent, render_resource::ShaderType,
+    sync_component::SyncComponent, RenderApp,
+};
+
+/// Adds noise to the image, emulating the grain of photographic film.
+///
+/// [Film grain] comes from the silver particles of film, which give footage a
+/// subtle texture. In games, it's often used to give a cinematic or gritty
+/// look to the image, and it can also hide color banding in dark gradients.
+///
+/// Bevy's implementation modulates the brightness of the image with a random
+/// pattern that changes every frame by default. The grain is strongest in the
+/// shadows and the midtones, like in real film, which can be adjusted with
+/// [`FilmGrain::luminance_response`].
+///
+/// [Film grain]: https://en.wikipedia.org/wiki/Film_grain
+#[derive(Reflect, Component, Clone)]
+#[reflect(Component, Default, Clone)]
+pub struct FilmGrain {
+    /// The strength of the grain.
+    ///
+    /// Range: `0.0` (No effect) to `1.0` (Very noisy image)
+    ///
+    /// The default value is 0.05.
+    pub intensity: f32,
+    /// The size of a grain, in physical pixels.
+    ///
+    /// Values greater than 1.0 produce a coarser, blotchier grain, and values
+    /// below 1.0 are treated as 1.0.
+    ///
+    /// The default value is 1.5.
+    pub grain_size: f32,
+    /// How much the grain fades in the bright areas of the image.
+    ///
+    /// Range: `0.0` (Uniform grain) to `1.0` (No grain in the highlights)
+    ///
+    /// The default value is 0.5.
+    pub luminance_response: f32,
+    /// Whether the grain pattern changes every frame.
+    ///
+    /// A static pattern looks like a dirty screen rather than film, but can
+    /// be useful for screenshots and tests.
+    ///
+    /// The default value is true.
+    pub animated: bool,
+}
+
+impl Default for FilmGrain {
+    fn default() -> Self {
+        Self {
+            intensity: 0.05,
+            grain_size: 1.5,
+            luminance_response: 0.5,
+            animated: true,
+        }
+    }
+}
+
+impl SyncComponent<RenderApp> for FilmGrain {
+    type Target = Self;
+}
+
+impl ExtractComponent<RenderApp> for FilmGrain {
+    type QueryData = Read<FilmGrain>;
+    type QueryFilter = With<Camera>;
+    type Out = Self;
+
+    fn extract_component(film_grain: QueryItem<'_, '_, Self::QueryData>) -> Option<Self::Out> {
+        // Skip the postprocessing phase entirely if the intensity is negligible.
+        if film_grain.intensity > 1e-4 {
+            Some(film_grain.clone())
+        } else {
+            None
+        }
+    }
+}
+
+/// The on-GPU version of the [`FilmGrain`] settings.
+///
+/// See the documentation for [`FilmGrain`] for more information on each of
+/// these fields.
+#[derive(ShaderType, Default)]
+pub struct FilmGrainUniform {
+    pub(super) intensity: f32,
+    pub(super) grain_size: f32,
+    pub(super) luminance_response: f32,
+    pub(super) animated: u32,
+}
@@ -0,0 +1,68 @@
+// The film grain postprocessing effect.
+
+#define_import_path bevy_post_process::effect_stack::film_grain
+
+#import bevy_render::globals::Globals
+#import bevy_post_process::effect_stack::chromatic_aberration::source_texture
+
+// See `bevy_post_process::effect_stack::FilmGrain` for more information on
+// these fields.
+struct FilmGrainSettings {
+    intensity: f32,
+    grain_size: f32,
+    luminance_response: f32,
+    animated: u32,
+}
+
+const VISUAL_THRESHOLD: f32 = 1e-4;
+
+// The settings supplied by the developer.
+@group(0) @binding(6) var<uniform> film_grain_settings: FilmGrainSettings;
+// Provides the frame count that seeds the animated grain.
+@group(0) @binding(7) var<uniform> globals: Globals;
+
+// https://www.pcg-random.org/
+fn pcg_hash(input: u32) -> u32 {
+    let state = input * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+// Returns a random value between -1.0 and 1.0 for a grain cell.
+fn grain_noise(cell: vec2<i32>, seed: u32) -> f32 {
+    let hash = pcg_hash(bitcast<u32>(cell.x) ^ pcg_hash(bitcast<u32>(cell.y) ^ pcg_hash(seed)));
+    return f32(hash) / 2147483647.5 - 1.0;
+}
+
+fn film_grain(uv: vec2<f32>, color: vec3<f32>) -> vec3<f32> {
+    let intensity = film_grain_settings.intensity;
+    if (intensity < VISUAL_THRESHOLD) {
+        return color;
+    }
+
+    var seed = 0u;
+    if (film_grain_settings.animated != 0u) {
+        seed = globals.frame_count;
+    }
+
+    // Interpolate the noise of the four closest grain cells, so that large
+    // grains are blotchy instead of blocky.
+    let pixel = uv * vec2<f32>(textureDimensions(source_texture));
+    let grain_position = pixel / max(film_grain_settings.grain_size, 1.0) - 0.5;
+    let cell = vec2<i32>(floor(grain_position));
+    let t = smoothstep(vec2(0.0), vec2(1.0), fract(grain_position));
+    let noise = mix(
+        mix(grain_noise(cell, seed), grain_noise(cell + vec2(1, 0), seed), t.x),
+        mix(grain_noise(cell + vec2(0, 1), seed), grain_noise(cell + vec2(1, 1), seed), t.x),
+        t.y,
+    );
+
+    // Fade the grain in the highlights. The color is HDR, so the luminance is
+    // remapped to [0, 1) first.
+    let luminance = dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let response = 1.0 - film_grain_settings.luminance_response * luminance / (1.0 + luminance);
+
+    // Modulate the color rather than adding to it, so the grain looks the same
+    // regardless of exposure.
+    return color * max(1.0 + noise * intensity * response, 0.0);
+}
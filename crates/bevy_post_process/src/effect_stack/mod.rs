@@ -3,15 +3,18 @@
 //! Includes:
 //!
 //! - Chromatic Aberration
+//! - Film Grain
 //! - Lens Distortion
 //! - Vignette
 
 mod chromatic_aberration;
+mod film_grain;
 mod lens_distortion;
 mod vignette;
 
 use bevy_color::ColorToComponents;
 pub use chromatic_aberration::{ChromaticAberration, ChromaticAberrationUniform};
+pub use film_grain::{FilmGrain, FilmGrainUniform};
 pub use lens_distortion::{LensDistortion, LensDistortionUniform};
 pub use vignette::{Vignette, VignetteUniform};
 
@@ -37,6 +40,7 @@ use bevy_render::{
     camera::ExtractedCamera,
     diagnostic::RecordDiagnostics,
     extract_component::ExtractComponentPlugin,
+    globals::{GlobalsBuffer, GlobalsUniform},
     render_asset::RenderAssets,
     render_resource::{
         binding_types::{sampler, texture_2d, uniform_buffer},
@@ -68,6 +72,7 @@ use bevy_core_pipeline::{
 /// Includes:
 ///
 /// - Chromatic Aberration
+/// - Film Grain
 /// - Lens Distortion
 /// - Vignette
 #[derive(Default)]
@@ -103,13 +108,15 @@ pub struct PostProcessingPipelineId(CachedRenderPipelineId);
 /// A resource, part of the render world, that stores the uniform buffers for
 /// post-processing effects.
 ///
-/// This currently holds buffers for [`ChromaticAberrationUniform`] and
-/// [`VignetteUniform`], allowing them to be uploaded to the GPU efficiently.
+/// This currently holds buffers for [`ChromaticAberrationUniform`],
+/// [`VignetteUniform`], [`LensDistortionUniform`] and [`FilmGrainUniform`],
+/// allowing them to be uploaded to the GPU efficiently.
 #[derive(Resource, Default)]
 pub struct PostProcessingUniformBuffers {
     chromatic_aberration: DynamicUniformBuffer<ChromaticAberrationUniform>,
     vignette: DynamicUniformBuffer<VignetteUniform>,
     lens_distortion: DynamicUniformBuffer<LensDistortionUniform>,
+    film_grain: DynamicUniformBuffer<FilmGrainUniform>,
 }
 
 /// A component, part of the render world, that stores the appropriate byte
@@ -120,6 +127,7 @@ pub struct PostProcessingUniformBufferOffsets {
     chromatic_aberration: u32,
     vignette: u32,
     lens_distortion: u32,
+    film_grain: u32,
 }
 
 impl Plugin for EffectStackPlugin {
@@ -127,6 +135,7 @@ impl Plugin for EffectStackPlugin {
         load_shader_library!(app, "chromatic_aberration.wgsl");
         load_shader_library!(app, "lens_distortion.wgsl");
         load_shader_library!(app, "vignette.wgsl");
+        load_shader_library!(app, "film_grain.wgsl");
 
         embedded_asset!(app, "post_process.wgsl");
 
@@ -146,7 +155,8 @@ impl Plugin for EffectStackPlugin {
 
         app.add_plugins(ExtractComponentPlugin::<ChromaticAberration>::default())
             .add_plugins(ExtractComponentPlugin::<LensDistortion>::default())
-            .add_plugins(ExtractComponentPlugin::<Vignette>::default());
+            .add_plugins(ExtractComponentPlugin::<Vignette>::default())
+            .add_plugins(ExtractComponentPlugin::<FilmGrain>::default());
 
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
@@ -197,6 +207,10 @@ pub(crate) fn init_post_processing_pipeline(
                 uniform_buffer::<VignetteUniform>(true),
                 // Lens Distortion settings:
                 uniform_buffer::<LensDistortionUniform>(true),
+                // Film grain settings:
+                uniform_buffer::<FilmGrainUniform>(true),
+                // Globals, for the film grain seed:
+                uniform_buffer::<GlobalsUniform>(false),
             ),
         ),
     );
@@ -244,7 +258,7 @@ pub(crate) fn post_processing(
     view: ViewQuery<(
         &ViewTarget,
         &PostProcessingPipelineId,
-        AnyOf<(&ChromaticAberration, &Vignette, &LensDistortion)>,
+        AnyOf<(&ChromaticAberration, &Vignette, &LensDistortion, &FilmGrain)>,
         &PostProcessingUniformBufferOffsets,
     )>,
     pipeline_cache: Res<PipelineCache>,
@@ -252,16 +266,19 @@ pub(crate) fn post_processing(
     post_processing_uniform_buffers: Res<PostProcessingUniformBuffers>,
     gpu_image_assets: Res<RenderAssets<GpuImage>>,
     default_lut: Res<DefaultChromaticAberrationLut>,
+    globals_buffer: Res<GlobalsBuffer>,
     mut ctx: RenderContext,
 ) {
     let (view_target, pipeline_id, post_effects, post_processing_uniform_buffer_offsets) =
         view.into_inner();
 
-    let (maybe_chromatic_aberration, maybe_vignette, maybe_lens_distortion) = post_effects;
+    let (maybe_chromatic_aberration, maybe_vignette, maybe_lens_distortion, maybe_film_grain) =
+        post_effects;
 
     if maybe_chromatic_aberration.is_none()
         && maybe_vignette.is_none()
         && maybe_lens_distortion.is_none()
+        && maybe_film_grain.is_none()
     {
         return;
     }
@@ -299,6 +316,16 @@ pub(crate) fn post_processing(
         return;
     };
 
+    let Some(film_grain_uniform_buffer_binding) =
+        post_processing_uniform_buffers.film_grain.binding()
+    else {
+        return;
+    };
+
+    let Some(globals_uniform_buffer_binding) = globals_buffer.buffer.binding() else {
+        return;
+    };
+
     // Use the [`PostProcessWrite`] infrastructure, since this is a full-screen pass.
     let post_process = view_target.post_process_write();
 
@@ -326,6 +353,8 @@ pub(crate) fn post_processing(
             chromatic_aberration_uniform_buffer_binding,
             vignette_uniform_buffer_binding,
             lens_distortion_uniform_buffer_binding,
+            film_grain_uniform_buffer_binding,
+            globals_uniform_buffer_binding,
         )),
     );
 
@@ -343,6 +372,7 @@ pub(crate) fn post_processing(
             post_processing_uniform_buffer_offsets.chromatic_aberration,
             post_processing_uniform_buffer_offsets.vignette,
             post_processing_uniform_buffer_offsets.lens_distortion,
+            post_processing_uniform_buffer_offsets.film_grain,
         ],
     );
     render_pass.draw(0..3, 0..1);
@@ -362,6 +392,7 @@ pub(crate) fn prepare_post_processing_pipelines(
             With<ChromaticAberration>,
             With<Vignette>,
             With<LensDistortion>,
+            With<FilmGrain>,
             With<ExtractedCamera>,
         )>,
     >,
@@ -394,21 +425,29 @@ pub(crate) fn prepare_post_processing_uniforms(
             Option<&ChromaticAberration>,
             Option<&Vignette>,
             Option<&LensDistortion>,
+            Option<&FilmGrain>,
         ),
         Or<(
             With<ChromaticAberration>,
             With<Vignette>,
             With<LensDistortion>,
+            With<FilmGrain>,
         )>,
     >,
 ) {
     post_processing_uniform_buffers.chromatic_aberration.clear();
     post_processing_uniform_buffers.vignette.clear();
     post_processing_uniform_buffers.lens_distortion.clear();
+    post_processing_uniform_buffers.film_grain.clear();
 
     // Gather up all the postprocessing settings.
-    for (view_entity, maybe_chromatic_aberration, maybe_vignette, maybe_lens_distortion) in
-        views.iter_mut()
+    for (
+        view_entity,
+        maybe_chromatic_aberration,
+        maybe_vignette,
+        maybe_lens_distortion,
+        maybe_film_grain,
+    ) in views.iter_mut()
     {
         let chromatic_aberration_uniform_buffer_offset =
             if let Some(chromatic_aberration) = maybe_chromatic_aberration {
@@ -463,12 +502,28 @@ pub(crate) fn prepare_post_processing_uniforms(
                     .push(&LensDistortionUniform::default())
             };
 
+        let film_grain_uniform_buffer_offset = if let Some(film_grain) = maybe_film_grain {
+            post_processing_uniform_buffers
+                .film_grain
+                .push(&FilmGrainUniform {
+                    intensity: film_grain.intensity.max(0.0),
+                    grain_size: film_grain.grain_size,
+                    luminance_response: film_grain.luminance_response.clamp(0.0, 1.0),
+                    animated: film_grain.animated as u32,
+                })
+        } else {
+            post_processing_uniform_buffers
+                .film_grain
+                .push(&FilmGrainUniform::default())
+        };
+
         commands
             .entity(view_entity)
             .insert(PostProcessingUniformBufferOffsets {
                 chromatic_aberration: chromatic_aberration_uniform_buffer_offset,
                 vignette: vignette_uniform_buffer_offset,
                 lens_distortion: lens_distortion_uniform_buffer_offset,
+                film_grain: film_grain_uniform_buffer_offset,
             });
     }
 
@@ -482,4 +537,7 @@ pub(crate) fn prepare_post_processing_uniforms(
     post_processing_uniform_buffers
         .lens_distortion
         .write_buffer(&render_device, &render_queue);
+    post_processing_uniform_buffers
+        .film_grain
+        .write_buffer(&render_device, &render_queue);
 }
@@ -0,0 +1,254 @@
+#![no_std]
+
+//! Deterministic, seedable random number generation for Bevy apps.
+//!
+//! The [`RngPlugin`] inserts a [`GlobalRng`] resource, seeded either with a fixed seed or with
+//! entropy. Entities that need their own stream of random numbers, such as particle emitters,
+//! use an [`EntityRng`] forked from it. As long as the seed and the order in which the
+//! generators are used stay the same, every random number drawn is the same from one run to the
+//! next, which makes simulations reproducible.
+//!
+//! The generators dereference to a [`ChaCha8Rng`], which can be passed to every API taking an
+//! [`rand::Rng`], such as the shape sampling of `bevy_math`. Since the system parameters wrap the
+//! generators in turn, dereference them twice to reach it:
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_math::{primitives::Sphere, ShapeSample, Vec3};
+//! # use bevy_rand::GlobalRng;
+//! fn spawn_points(mut commands: Commands, mut rng: ResMut<GlobalRng>) {
+//!     let point: Vec3 = Sphere::new(5.0).sample_interior(&mut **rng);
+//! }
+//! # bevy_ecs::system::assert_is_system(spawn_points);
+//! ```
+
+use core::{
+    fmt,
+    hash::BuildHasher,
+    ops::{Deref, DerefMut},
+};
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    component::Component, lifecycle::HookContext, resource::Resource, world::DeferredWorld,
+};
+use bevy_platform::hash::RandomState;
+pub use chacha20::ChaCha8Rng;
+use rand::{RngExt, SeedableRng};
+
+/// The random number generation prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{EntityRng, ForkRng, GlobalRng, RngPlugin};
+}
+
+/// Adds a [`GlobalRng`] to the app.
+///
+/// By default, the generator is seeded with entropy, so every run is different. Set
+/// [`RngPlugin::seed`] to reproduce a run:
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_rand::RngPlugin;
+/// App::new().add_plugins(RngPlugin { seed: Some(42) });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RngPlugin {
+    /// The seed of the [`GlobalRng`], or `None` to seed it with entropy.
+    pub seed: Option<u64>,
+}
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = self.seed.unwrap_or_else(entropy_seed);
+        app.insert_resource(GlobalRng::new(seed));
+    }
+}
+
+/// Returns a seed that differs from one run to the next.
+fn entropy_seed() -> u64 {
+    RandomState::default().hash_one(0_u64)
+}
+
+/// The random number generator shared by the whole app, added by the [`RngPlugin`].
+///
+/// Engine features that need randomness draw from this generator, or from an [`EntityRng`]
+/// forked from it, so that they are reproduced when the app is started with the same seed.
+#[derive(Resource)]
+pub struct GlobalRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+impl GlobalRng {
+    /// Creates a generator from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the seed the generator was created or last reseeded with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restarts the generator from `seed`.
+    ///
+    /// The [`EntityRng`]s that were already forked are not affected.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// Creates an independent generator seeded from this one, advancing it.
+    pub fn fork(&mut self) -> EntityRng {
+        EntityRng::new(self.rng.random())
+    }
+}
+
+impl fmt::Debug for GlobalRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GlobalRng")
+            .field("seed", &self.seed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Deref for GlobalRng {
+    type Target = ChaCha8Rng;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rng
+    }
+}
+
+impl DerefMut for GlobalRng {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.rng
+    }
+}
+
+/// A random number generator owned by an entity.
+///
+/// Giving each entity its own generator keeps the numbers it draws independent from the other
+/// users of the [`GlobalRng`], so adding an unrelated random effect does not change them. Create
+/// one with [`GlobalRng::fork`], [`EntityRng::fork`], or by adding [`ForkRng`] to the entity.
+#[derive(Component)]
+pub struct EntityRng(ChaCha8Rng);
+
+impl EntityRng {
+    /// Creates a generator from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self(ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Creates an independent generator seeded from this one, advancing it.
+    ///
+    /// This is useful to give the entities spawned by another entity, such as the particles of
+    /// an emitter, their own generator.
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.0.random())
+    }
+}
+
+impl fmt::Debug for EntityRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EntityRng").finish_non_exhaustive()
+    }
+}
+
+impl Deref for EntityRng {
+    type Target = ChaCha8Rng;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for EntityRng {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Adds an [`EntityRng`] forked from the [`GlobalRng`] to the entity.
+///
+/// The generator is forked when this component is added, so entities spawned in the same order
+/// receive the same generators. Entities that already have an [`EntityRng`] keep it.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_rand::ForkRng;
+/// fn spawn_emitter(mut commands: Commands) {
+///     commands.spawn(ForkRng);
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_emitter);
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[component(on_add = fork_global_rng)]
+pub struct ForkRng;
+
+fn fork_global_rng(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if world.get::<EntityRng>(entity).is_some() {
+        return;
+    }
+    let Some(mut global) = world.get_resource_mut::<GlobalRng>() else {
+        return;
+    };
+    let rng = global.fork();
+    world.commands().entity(entity).insert_if_new(rng);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use rand::RngExt;
+
+    use crate::{EntityRng, ForkRng, GlobalRng, RngPlugin};
+
+    fn draw(app: &mut App) -> [u64; 2] {
+        let mut rng = app.world_mut().resource_mut::<GlobalRng>();
+        [rng.random(), rng.random()]
+    }
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut first = App::new();
+        first.add_plugins(RngPlugin { seed: Some(7) });
+        let mut second = App::new();
+        second.add_plugins(RngPlugin { seed: Some(7) });
+        let mut other = App::new();
+        other.add_plugins(RngPlugin { seed: Some(8) });
+
+        assert_eq!(draw(&mut first), draw(&mut second));
+        assert_ne!(draw(&mut first), draw(&mut other));
+
+        first.world_mut().resource_mut::<GlobalRng>().reseed(7);
+        second.world_mut().resource_mut::<GlobalRng>().reseed(7);
+        assert_eq!(draw(&mut first), draw(&mut second));
+    }
+
+    #[test]
+    fn fork_entity_rng() {
+        let spawn = || {
+            let mut app = App::new();
+            app.add_plugins(RngPlugin { seed: Some(1) });
+            let first = app.world_mut().spawn(ForkRng).id();
+            let second = app.world_mut().spawn(ForkRng).id();
+            let mut draw = |entity| {
+                app.world_mut()
+                    .get_mut::<EntityRng>(entity)
+                    .unwrap()
+                    .random::<u64>()
+            };
+            [draw(first), draw(second)]
+        };
+
+        let numbers = spawn();
+        assert_eq!(numbers, spawn());
+        assert_ne!(numbers[0], numbers[1]);
+    }
+}
@@ -6,6 +6,7 @@ use std::sync::Mutex;
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_dev_tools::schedule_data::serde::ScheduleData;
+use bevy_diagnostic::{Diagnostic, DiagnosticsStore};
 use bevy_ecs::{
     component::ComponentId,
     entity::Entity,
@@ -35,7 +36,7 @@ use crate::{
         json_schema::{export_type, JsonSchemaBevyType},
         open_rpc::OpenRpcDocument,
     },
-    BrpError, BrpResult, PreviousScheduleBuildMetadata,
+    BrpError, BrpResult, PreviousScheduleBuildMetadata, RemoteSystemRegistry,
 };
 
 #[cfg(all(feature = "http", not(target_family = "wasm")))]
@@ -107,6 +108,18 @@ pub const BRP_OBSERVE_METHOD: &str = "world.observe+watch";
 /// The method path for a `schedule.graph` request.
 pub const BRP_SCHEDULE_GRAPH: &str = "schedule.graph";
 
+/// The method path for a `world.run_system` request.
+pub const BRP_RUN_SYSTEM_METHOD: &str = "world.run_system";
+
+/// The method path for a `world.list_systems` request.
+pub const BRP_LIST_SYSTEMS_METHOD: &str = "world.list_systems";
+
+/// The method path for a `diagnostics.list` request.
+pub const BRP_LIST_DIAGNOSTICS_METHOD: &str = "diagnostics.list";
+
+/// The method path for a `diagnostics.get+watch` request.
+pub const BRP_GET_DIAGNOSTICS_AND_WATCH_METHOD: &str = "diagnostics.get+watch";
+
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
@@ -366,6 +379,26 @@ pub struct BrpObserveParams {
     pub entity: Option<Entity>,
 }
 
+/// `world.run_system`: Runs a one-shot system registered in the
+/// [`RemoteSystemRegistry`](crate::RemoteSystemRegistry).
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRunSystemParams {
+    /// The name the system was registered with.
+    pub system: String,
+}
+
+/// `diagnostics.get+watch`: Sends the measurements of diagnostics every frame.
+///
+/// The server responds with a [`BrpListDiagnosticsResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpGetDiagnosticsParams {
+    /// The paths of the diagnostics to watch, or all the diagnostics if empty.
+    #[serde(default)]
+    pub paths: Vec<String>,
+}
+
 /// `schedule.graph`:
 ///
 /// The server responds with [`BrpScheduleGraphResponse`] if the schedule is found,
@@ -524,6 +557,43 @@ pub struct BrpListComponentsWatchingResponse {
     removed: Vec<String>,
 }
 
+/// The response to a `world.list_systems` request.
+pub type BrpListSystemsResponse = Vec<String>;
+
+/// A diagnostic and its latest measurements.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpDiagnostic {
+    /// The path of the diagnostic.
+    pub path: String,
+    /// The unit of the measurements.
+    pub suffix: String,
+    /// Whether the diagnostic is being measured.
+    pub enabled: bool,
+    /// The latest measurement.
+    pub value: Option<f64>,
+    /// The exponentially smoothed measurement.
+    pub smoothed: Option<f64>,
+    /// The average of the measurements in the history.
+    pub average: Option<f64>,
+}
+
+impl From<&Diagnostic> for BrpDiagnostic {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        Self {
+            path: diagnostic.path().as_str().to_owned(),
+            suffix: diagnostic.suffix.to_string(),
+            enabled: diagnostic.is_enabled,
+            value: diagnostic.value(),
+            smoothed: diagnostic.smoothed(),
+            average: diagnostic.average(),
+        }
+    }
+}
+
+/// The response to a `diagnostics.list` request, and a single response from a
+/// `diagnostics.get+watch` request.
+pub type BrpListDiagnosticsResponse = Vec<BrpDiagnostic>;
+
 /// The response to a `world.query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
@@ -1553,6 +1623,95 @@ pub fn process_remote_write_message_request(
     })
 }
 
+/// Handles a `world.run_system` request coming from a client.
+pub fn process_remote_run_system_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRunSystemParams { system } = parse_some(params)?;
+
+    let Some(system_id) = world
+        .get_resource::<RemoteSystemRegistry>()
+        .and_then(|registry| registry.get(&system))
+    else {
+        return Err(BrpError {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("Unknown system: `{system}`"),
+            data: None,
+        });
+    };
+
+    world.run_system(system_id).map_err(|err| BrpError {
+        code: error_codes::SYSTEM_ERROR,
+        message: format!("System `{system}` failed to run: {err}"),
+        data: None,
+    })?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `world.list_systems` request coming from a client.
+pub fn process_remote_list_systems_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let mut response: BrpListSystemsResponse = world
+        .get_resource::<RemoteSystemRegistry>()
+        .map(RemoteSystemRegistry::systems)
+        .unwrap_or_default();
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `diagnostics.list` request coming from a client.
+pub fn process_remote_list_diagnostics_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let response = diagnostics(world, &[]);
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `diagnostics.get+watch` request coming from a client.
+pub fn process_remote_get_diagnostics_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult<Option<Value>> {
+    let BrpGetDiagnosticsParams { paths } = match params {
+        None => Default::default(),
+        Some(params) => parse(params)?,
+    };
+
+    let response = diagnostics(world, &paths);
+    if response.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::to_value(response)
+        .map(Some)
+        .map_err(BrpError::internal)
+}
+
+/// Collects the diagnostics with the given `paths`, or all of them if `paths` is empty, sorted by
+/// path.
+fn diagnostics(world: &World, paths: &[String]) -> BrpListDiagnosticsResponse {
+    let Some(store) = world.get_resource::<DiagnosticsStore>() else {
+        return Vec::new();
+    };
+
+    let mut response: BrpListDiagnosticsResponse = store
+        .iter()
+        .filter(|diagnostic| {
+            paths.is_empty() || paths.iter().any(|path| path == diagnostic.path().as_str())
+        })
+        .map(BrpDiagnostic::from)
+        .collect();
+    response.sort_by(|a, b| a.path.cmp(&b.path));
+    response
+}
+
 /// Stores observer state for `world.observe+watch` requests.
 #[derive(Resource, Default)]
 pub struct BrpEventObservers {
@@ -2119,6 +2278,70 @@ mod tests {
         assert!(world.resource::<TestResult>().0);
     }
 
+    #[test]
+    fn run_registered_system() {
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let system = world.register_system(|mut counter: ResMut<Counter>| counter.0 += 1);
+        let mut registry = RemoteSystemRegistry::new();
+        registry.insert("increment", system);
+        world.insert_resource(registry);
+
+        let params = serde_json::to_value(&BrpRunSystemParams {
+            system: "increment".to_owned(),
+        })
+        .expect("FAIL");
+        assert_eq!(
+            process_remote_run_system_request(In(Some(params)), &mut world),
+            Ok(Null)
+        );
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        let params = serde_json::to_value(&BrpRunSystemParams {
+            system: "decrement".to_owned(),
+        })
+        .expect("FAIL");
+        assert_eq!(
+            process_remote_run_system_request(In(Some(params)), &mut world).map_err(|err| err.code),
+            Err(error_codes::SYSTEM_NOT_FOUND)
+        );
+    }
+
+    #[test]
+    fn watch_selected_diagnostics() {
+        use bevy_diagnostic::DiagnosticPath;
+
+        let mut store = DiagnosticsStore::default();
+        store.add(Diagnostic::new(DiagnosticPath::const_new("fps")));
+        store.add(Diagnostic::new(DiagnosticPath::const_new("frame_time")).with_suffix("ms"));
+        let mut world = World::new();
+        world.insert_resource(store);
+
+        let list = process_remote_list_diagnostics_request(In(None), &world).expect("FAIL");
+        let list: BrpListDiagnosticsResponse = serde_json::from_value(list).expect("FAIL");
+        assert_eq!(
+            list.iter()
+                .map(|diagnostic| diagnostic.path.as_str())
+                .collect::<Vec<_>>(),
+            ["fps", "frame_time"]
+        );
+
+        let params = serde_json::to_value(&BrpGetDiagnosticsParams {
+            paths: vec!["frame_time".to_owned()],
+        })
+        .expect("FAIL");
+        let watched = process_remote_get_diagnostics_watching_request(In(Some(params)), &world)
+            .expect("FAIL")
+            .expect("diagnostic is watched");
+        let watched: BrpListDiagnosticsResponse = serde_json::from_value(watched).expect("FAIL");
+        assert_eq!(watched.len(), 1);
+        assert_eq!(watched[0].suffix, "ms");
+        assert_eq!(watched[0].value, None);
+    }
+
     #[test]
     fn observe_watching_captures_triggered_events() {
         #[derive(Event, Reflect)]
@@ -463,6 +463,47 @@
 //!
 //! `result`: null.
 //!
+//! ### `world.run_system`
+//!
+//! Runs a one-shot system registered with [`RemotePlugin::with_system`] or in the
+//! [`RemoteSystemRegistry`].
+//!
+//! `params`:
+//! - `system`: The name the system was registered with.
+//!
+//! `result`: null.
+//!
+//! ### `world.list_systems`
+//!
+//! List the names of the one-shot systems that can be run with `world.run_system`. This method
+//! has no parameters.
+//!
+//! `result`: An array of system names.
+//!
+//! ### `diagnostics.list`
+//!
+//! List all diagnostics of the [`DiagnosticsStore`](bevy_diagnostic::DiagnosticsStore) with their
+//! latest measurements. This method has no parameters.
+//!
+//! `result`: An array of objects, each containing:
+//! - `path`: The path of the diagnostic, e.g. `fps`.
+//! - `suffix`: The unit of the measurements, e.g. `ms`.
+//! - `enabled`: Whether the diagnostic is being measured.
+//! - `value`: The latest measurement, if any.
+//! - `smoothed`: The exponentially smoothed measurement, if any.
+//! - `average`: The average of the measurements in the history, if any.
+//!
+//! ### `diagnostics.get+watch`
+//!
+//! Watch diagnostics, sending their measurements every frame. This is useful for dashboards
+//! plotting the performance of the app.
+//!
+//! `params` (optional):
+//! - `paths`: The paths of the diagnostics to watch. When empty or omitted, all diagnostics are
+//!   watched.
+//!
+//! `result`: An array of objects like those of `diagnostics.list`.
+//!
 //! ### `registry.schema`
 //!
 //! Retrieve schema information about registered types in the Bevy app's type registry.
@@ -574,6 +615,8 @@ pub struct RemotePlugin {
     methods: RwLock<Vec<(String, RemoteMethodHandler)>>,
     /// The verbs that the server will recognize and respond to for the render subapp.
     render_methods: RwLock<Vec<(String, RemoteMethodHandler)>>,
+    /// The one-shot systems that clients can run with `world.run_system`.
+    systems: RwLock<Vec<(String, Box<dyn System<In = (), Out = ()>>)>>,
 }
 
 impl RemotePlugin {
@@ -583,6 +626,7 @@ impl RemotePlugin {
         Self {
             methods: RwLock::new(vec![]),
             render_methods: RwLock::new(vec![]),
+            systems: RwLock::new(vec![]),
         }
     }
 
@@ -668,6 +712,20 @@ impl RemotePlugin {
         self
     }
 
+    /// Add a one-shot system that clients can run using the given `name` with `world.run_system`.
+    #[must_use]
+    pub fn with_system<M>(
+        mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M>,
+    ) -> Self {
+        self.systems
+            .get_mut()
+            .unwrap()
+            .push((name.into(), Box::new(IntoSystem::into_system(system))));
+        self
+    }
+
     /// Create the default list of BRP methods
     fn add_default_methods(self, to_main: bool) -> Self {
         self.with_method(
@@ -785,6 +843,26 @@ impl RemotePlugin {
             builtin_methods::schedule_graph,
             to_main,
         )
+        .with_method(
+            builtin_methods::BRP_RUN_SYSTEM_METHOD,
+            builtin_methods::process_remote_run_system_request,
+            to_main,
+        )
+        .with_method(
+            builtin_methods::BRP_LIST_SYSTEMS_METHOD,
+            builtin_methods::process_remote_list_systems_request,
+            to_main,
+        )
+        .with_method(
+            builtin_methods::BRP_LIST_DIAGNOSTICS_METHOD,
+            builtin_methods::process_remote_list_diagnostics_request,
+            to_main,
+        )
+        .with_watching_method(
+            builtin_methods::BRP_GET_DIAGNOSTICS_AND_WATCH_METHOD,
+            builtin_methods::process_remote_get_diagnostics_watching_request,
+            to_main,
+        )
     }
 }
 
@@ -821,6 +899,14 @@ impl Plugin for RemotePlugin {
             );
         }
 
+        let mut remote_systems = RemoteSystemRegistry::new();
+        for (name, system) in self.systems.write().unwrap().drain(..) {
+            remote_systems.insert(
+                name,
+                app.main_mut().world_mut().register_boxed_system(system),
+            );
+        }
+
         if remote_methods
             .0
             .contains_key(builtin_methods::BRP_SCHEDULE_GRAPH)
@@ -835,6 +921,7 @@ impl Plugin for RemotePlugin {
             .insert_after(Last, RemoteLast);
 
         app.insert_resource(remote_methods)
+            .insert_resource(remote_systems)
             .init_resource::<schemas::SchemaTypesMetadata>()
             .init_resource::<RemoteWatchingRequests>()
             .init_resource::<builtin_methods::BrpEventObservers>()
@@ -992,6 +1079,37 @@ impl RemoteMethods {
     }
 }
 
+/// Holds the one-shot systems that clients can run by name with `world.run_system`.
+///
+/// Systems can be added to this list using [`RemotePlugin::with_system`] or at runtime using
+/// [`RemoteSystemRegistry::insert`].
+#[derive(Debug, Resource, Default)]
+pub struct RemoteSystemRegistry(HashMap<String, SystemId>);
+
+impl RemoteSystemRegistry {
+    /// Creates a new [`RemoteSystemRegistry`] resource with no systems registered in it.
+    pub fn new() -> Self {
+        default()
+    }
+
+    /// Adds a new system, replacing any existing system with that name.
+    ///
+    /// If there was an existing system with that name, returns its [`SystemId`].
+    pub fn insert(&mut self, name: impl Into<String>, system: SystemId) -> Option<SystemId> {
+        self.0.insert(name.into(), system)
+    }
+
+    /// Get the [`SystemId`] of a system with its name.
+    pub fn get(&self, name: &str) -> Option<SystemId> {
+        self.0.get(name).copied()
+    }
+
+    /// Get a [`Vec<String>`] with system names.
+    pub fn systems(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+}
+
 /// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers.
 #[derive(Debug, Resource, Default)]
 pub struct RemoteWatchingRequests(Vec<(BrpMessage, RemoteWatchingMethodSystemId)>);
@@ -1422,6 +1540,12 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find a system registered with that name.
+    pub const SYSTEM_NOT_FOUND: i16 = -23601;
+
+    /// The system failed to run.
+    pub const SYSTEM_ERROR: i16 = -23602;
 }
 
 /// The result of a request.
@@ -20,7 +20,7 @@ use bevy_ecs::{
     world::{FromWorld, World},
 };
 use bevy_encase_derive::ShaderType;
-use bevy_log::{error, info_once};
+use bevy_log::error;
 use bevy_math::UVec4;
 use bevy_platform::collections::{hash_map::Entry, HashMap, HashSet};
 use bevy_tasks::ComputeTaskPool;
@@ -31,6 +31,7 @@ use nonmax::NonMaxU32;
 use wgpu::{BindingResource, BufferUsages, DownlevelFlags, Features};
 
 use crate::{
+    capabilities::{CapabilitySupport, RenderCapabilityReport},
     occlusion_culling::OcclusionCulling,
     render_phase::{
         BinnedPhaseItem, BinnedRenderPhaseBatch, BinnedRenderPhaseBatchSet,
@@ -1455,25 +1456,35 @@ impl FromWorld for GpuPreprocessingSupport {
 
         let adapter_info = RenderAdapterInfo(WgpuWrapper::new(adapter.get_info()));
 
-        let max_supported_mode = if device.limits().max_compute_workgroup_size_x == 0
+        let (max_supported_mode, support) = if device.limits().max_compute_workgroup_size_x == 0
             || is_non_supported_android_device(&adapter_info)
             || adapter_info.backend == wgpu::Backend::Gl
         {
-            info_once!(
-                "GPU preprocessing is not supported on this device. \
-                Falling back to CPU preprocessing.",
-            );
-            GpuPreprocessingMode::None
+            (
+                GpuPreprocessingMode::None,
+                CapabilitySupport::Degraded {
+                    fallback: "CPU preprocessing".into(),
+                    reason: "compute shaders are unavailable or unreliable".into(),
+                },
+            )
         } else if !(culling_feature_support && limit_support && downlevel_support)
             || is_preprocessing_only_android_device(&adapter_info)
         {
-            info_once!("Some GPU preprocessing are limited on this device.");
-            GpuPreprocessingMode::PreprocessingOnly
+            (
+                GpuPreprocessingMode::PreprocessingOnly,
+                CapabilitySupport::Degraded {
+                    fallback: "GPU preprocessing without occlusion culling".into(),
+                    reason: "missing the features or limits required by GPU culling".into(),
+                },
+            )
         } else {
-            info_once!("GPU preprocessing is fully supported on this device.");
-            GpuPreprocessingMode::Culling
+            (GpuPreprocessingMode::Culling, CapabilitySupport::Supported)
         };
 
+        if let Some(capability_report) = world.get_resource::<RenderCapabilityReport>() {
+            capability_report.record("gpu_preprocessing", support);
+        }
+
         GpuPreprocessingSupport { max_supported_mode }
     }
 }
@@ -0,0 +1,275 @@
+//! Negotiation of the optional engine features with the capabilities of the adapter in use.
+//!
+//! Many rendering features need more than what the weakest targets (WebGL2, older mobile GPUs)
+//! offer, like compute shaders, storage buffers or a number of storage textures. Instead of failing
+//! on these targets, the features check their [`CapabilityRequirements`] when the renderer starts,
+//! fall back to a simpler path or disable themselves, and record the outcome in the
+//! [`RenderCapabilityReport`], which the app can read to adjust its settings menus or to log the
+//! capabilities of the players' machines.
+
+use alloc::{borrow::Cow, collections::BTreeMap, sync::Arc};
+use std::sync::{PoisonError, RwLock};
+
+use bevy_ecs::resource::Resource;
+use bevy_log::{debug, info, warn};
+use wgpu::DownlevelFlags;
+
+use crate::{
+    renderer::{RenderAdapter, RenderDevice},
+    settings::{WgpuFeatures, WgpuLimits},
+};
+
+/// How well an engine feature is supported by the adapter in use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CapabilitySupport {
+    /// The feature is fully supported.
+    Supported,
+    /// The feature works, through a fallback which is slower or more limited.
+    Degraded {
+        /// The fallback in use.
+        fallback: Cow<'static, str>,
+        /// Why the full feature isn't supported.
+        reason: Cow<'static, str>,
+    },
+    /// The feature is disabled.
+    Unsupported {
+        /// Why the feature isn't supported.
+        reason: Cow<'static, str>,
+    },
+}
+
+impl CapabilitySupport {
+    /// Returns `true` if the feature works, possibly through a fallback.
+    pub fn is_available(&self) -> bool {
+        !matches!(self, CapabilitySupport::Unsupported { .. })
+    }
+}
+
+/// The outcome of the capability negotiation of the engine features, keyed by feature name.
+///
+/// This resource is available in both the main and the render world, and shared between them.
+/// Engine features record their support when the renderer starts, usually in
+/// [`Plugin::finish`](bevy_app::Plugin::finish), and third party plugins are encouraged to do the
+/// same.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct RenderCapabilityReport(Arc<RwLock<BTreeMap<Cow<'static, str>, CapabilitySupport>>>);
+
+impl RenderCapabilityReport {
+    /// Records the support of the `feature`, replacing any previous record, and logs it.
+    pub fn record(&self, feature: impl Into<Cow<'static, str>>, support: CapabilitySupport) {
+        let feature = feature.into();
+        match &support {
+            CapabilitySupport::Supported => debug!("{feature} is supported on this device."),
+            CapabilitySupport::Degraded { fallback, reason } => {
+                info!(
+                    "{feature} is limited on this device ({reason}); falling back to {fallback}."
+                );
+            }
+            CapabilitySupport::Unsupported { reason } => {
+                warn!("{feature} is not supported on this device ({reason}); it is disabled.");
+            }
+        }
+        self.0
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(feature, support);
+    }
+
+    /// Checks the `requirements` of the `feature`, records the outcome and returns it.
+    ///
+    /// When the requirements aren't met, the feature is recorded as
+    /// [`CapabilitySupport::Degraded`] if it has a `fallback`, [`CapabilitySupport::Unsupported`]
+    /// otherwise.
+    pub fn negotiate(
+        &self,
+        feature: impl Into<Cow<'static, str>>,
+        requirements: &CapabilityRequirements,
+        device: &RenderDevice,
+        adapter: &RenderAdapter,
+        fallback: Option<&'static str>,
+    ) -> CapabilitySupport {
+        let support = match (requirements.check(device, adapter), fallback) {
+            (Ok(()), _) => CapabilitySupport::Supported,
+            (Err(reason), Some(fallback)) => CapabilitySupport::Degraded {
+                fallback: fallback.into(),
+                reason: reason.into(),
+            },
+            (Err(reason), None) => CapabilitySupport::Unsupported {
+                reason: reason.into(),
+            },
+        };
+        self.record(feature, support.clone());
+        support
+    }
+
+    /// Returns the recorded support of the `feature`, if any.
+    pub fn get(&self, feature: &str) -> Option<CapabilitySupport> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(feature)
+            .cloned()
+    }
+
+    /// Returns all the recorded features and their support, sorted by name.
+    pub fn entries(&self) -> Vec<(Cow<'static, str>, CapabilitySupport)> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(feature, support)| (feature.clone(), support.clone()))
+            .collect()
+    }
+}
+
+/// The capabilities an engine feature needs from the adapter and the device.
+///
+/// The limits are checked against the [`RenderDevice`], which honors the
+/// [`WgpuSettings`](crate::settings::WgpuSettings) constraints, such as
+/// `WGPU_SETTINGS_PRIO=webgl2`, while the downlevel flags come from the [`RenderAdapter`].
+#[derive(Clone)]
+pub struct CapabilityRequirements {
+    features: WgpuFeatures,
+    downlevel_flags: DownlevelFlags,
+    limits: Vec<(&'static str, fn(&WgpuLimits) -> u64, u64)>,
+}
+
+impl Default for CapabilityRequirements {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CapabilityRequirements {
+    /// Requires nothing.
+    pub fn new() -> Self {
+        Self {
+            features: WgpuFeatures::empty(),
+            downlevel_flags: DownlevelFlags::empty(),
+            limits: Vec::new(),
+        }
+    }
+
+    /// Requires the `features` to be enabled on the device.
+    pub fn with_features(mut self, features: WgpuFeatures) -> Self {
+        self.features |= features;
+        self
+    }
+
+    /// Requires the adapter to support the downlevel `flags`.
+    pub fn with_downlevel_flags(mut self, flags: DownlevelFlags) -> Self {
+        self.downlevel_flags |= flags;
+        self
+    }
+
+    /// Requires the limit `name`, read with `limit`, to be at least `min`.
+    pub fn with_min_limit(
+        mut self,
+        name: &'static str,
+        limit: fn(&WgpuLimits) -> u64,
+        min: u64,
+    ) -> Self {
+        self.limits.push((name, limit, min));
+        self
+    }
+
+    /// Requires compute shaders, which WebGL2 lacks.
+    ///
+    /// Besides the downlevel flag, this also requires a compute workgroup size, since the
+    /// `max_compute_*` limits are zero when compute is simulated away through the device limits.
+    pub fn with_compute_shaders(self) -> Self {
+        self.with_downlevel_flags(DownlevelFlags::COMPUTE_SHADERS)
+            .with_min_limit(
+                "max_compute_workgroup_size_x",
+                |limits| limits.max_compute_workgroup_size_x.into(),
+                1,
+            )
+    }
+
+    /// Checks the requirements against the `device` and the `adapter`, returning the reason of the
+    /// failure, if any.
+    pub fn check(&self, device: &RenderDevice, adapter: &RenderAdapter) -> Result<(), String> {
+        let missing_features = self.features.difference(device.features());
+        if !missing_features.is_empty() {
+            return Err(format!("missing features {missing_features:?}"));
+        }
+
+        let missing_flags = self
+            .downlevel_flags
+            .difference(adapter.get_downlevel_capabilities().flags);
+        if !missing_flags.is_empty() {
+            return Err(format!("missing downlevel flags {missing_flags:?}"));
+        }
+
+        self.check_limits(&device.limits())
+    }
+
+    /// Checks the limit requirements against `limits`, returning the reason of the failure, if
+    /// any.
+    pub fn check_limits(&self, limits: &WgpuLimits) -> Result<(), String> {
+        for (name, limit, min) in &self.limits {
+            let value = limit(limits);
+            if value < *min {
+                return Err(format!("`{name}` is {value}, at least {min} is required"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CapabilityRequirements, CapabilitySupport, RenderCapabilityReport};
+    use crate::settings::WgpuLimits;
+
+    #[test]
+    fn checks_limits() {
+        let requirements = CapabilityRequirements::new().with_min_limit(
+            "max_storage_textures_per_shader_stage",
+            |limits| limits.max_storage_textures_per_shader_stage.into(),
+            4,
+        );
+
+        assert!(requirements.check_limits(&WgpuLimits::default()).is_ok());
+        assert_eq!(
+            requirements.check_limits(&WgpuLimits::downlevel_webgl2_defaults()),
+            Err("`max_storage_textures_per_shader_stage` is 0, at least 4 is required".into())
+        );
+        assert!(CapabilityRequirements::new()
+            .with_compute_shaders()
+            .check_limits(&WgpuLimits::downlevel_webgl2_defaults())
+            .is_err());
+    }
+
+    #[test]
+    fn report_is_shared() {
+        let report = RenderCapabilityReport::default();
+        let main_world_report = report.clone();
+
+        report.record("ssao", CapabilitySupport::Supported);
+        report.record(
+            "gpu_clustering",
+            CapabilitySupport::Degraded {
+                fallback: "CPU clustering".into(),
+                reason: "no compute shaders".into(),
+            },
+        );
+
+        assert_eq!(
+            main_world_report.get("ssao"),
+            Some(CapabilitySupport::Supported)
+        );
+        assert!(main_world_report
+            .get("gpu_clustering")
+            .is_some_and(|support| support.is_available()));
+        assert_eq!(main_world_report.get("atmosphere"), None);
+        assert_eq!(
+            main_world_report
+                .entries()
+                .iter()
+                .map(|(feature, _)| feature.as_ref())
+                .collect::<Vec<_>>(),
+            ["gpu_clustering", "ssao"]
+        );
+    }
+}
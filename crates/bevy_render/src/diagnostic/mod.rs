@@ -6,6 +6,7 @@ mod erased_render_asset_diagnostic_plugin;
 pub(crate) mod internal;
 mod mesh_allocator_diagnostic_plugin;
 mod render_asset_diagnostic_plugin;
+mod render_world_diagnostic_plugin;
 #[cfg(feature = "tracing-tracy")]
 mod tracy_gpu;
 
@@ -26,10 +27,12 @@ use crate::{
 };
 
 use self::internal::{sync_diagnostics, Pass, RenderDiagnosticsMutex, WriteTimestamp};
+pub(crate) use self::render_world_diagnostic_plugin::RenderPhaseDrawCalls;
 pub use self::{
     erased_render_asset_diagnostic_plugin::ErasedRenderAssetDiagnosticPlugin,
     internal::DiagnosticsRecorder, mesh_allocator_diagnostic_plugin::MeshAllocatorDiagnosticPlugin,
     render_asset_diagnostic_plugin::RenderAssetDiagnosticPlugin,
+    render_world_diagnostic_plugin::RenderWorldDiagnosticPlugin,
 };
 
 use crate::renderer::RenderDevice;
@@ -0,0 +1,173 @@
+use core::any::TypeId;
+use std::sync::Mutex;
+
+use bevy_app::{Plugin, PreUpdate};
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+use bevy_ecs::{
+    query::With,
+    resource::Resource,
+    system::{Local, Query, Res, ResMut},
+    world::World,
+};
+use bevy_platform::{collections::HashMap, time::Instant};
+use bevy_utils::prelude::ShortName;
+
+use crate::{
+    render_resource::BindGroupId, sync_world::MainEntity, Extract, ExtractSchedule, RenderApp,
+};
+
+/// Number of render world entities extracted from the main world
+static EXTRACTED_ENTITIES: DiagnosticPath = DiagnosticPath::const_new("render/extracted_entities");
+
+/// Number of bind groups created in the frame
+static BIND_GROUPS: DiagnosticPath = DiagnosticPath::const_new("render/bind_groups");
+
+/// Number of draw calls of all render phases in the frame
+static DRAW_CALLS: DiagnosticPath = DiagnosticPath::const_new("render/draw_calls");
+
+/// Records the number of entities extracted to the render world, of bind groups prepared, and of
+/// draw calls per render phase every frame.
+///
+/// The number of draw calls of each render phase is recorded under the
+/// [`draw_calls_diagnostic_path`](Self::draw_calls_diagnostic_path), followed by the short type
+/// name of the phase items, such as `render/draw_calls/Opaque3d`.
+///
+/// This complements [`RenderDiagnosticsPlugin`](super::RenderDiagnosticsPlugin), which records
+/// the CPU and GPU time of the render passes.
+pub struct RenderWorldDiagnosticPlugin;
+
+impl RenderWorldDiagnosticPlugin {
+    /// Get the [`DiagnosticPath`] for the number of extracted entities
+    pub fn extracted_entities_diagnostic_path() -> &'static DiagnosticPath {
+        &EXTRACTED_ENTITIES
+    }
+    /// Get the [`DiagnosticPath`] for the number of bind groups created
+    pub fn bind_groups_diagnostic_path() -> &'static DiagnosticPath {
+        &BIND_GROUPS
+    }
+    /// Get the [`DiagnosticPath`] for the number of draw calls of all render phases
+    pub fn draw_calls_diagnostic_path() -> &'static DiagnosticPath {
+        &DRAW_CALLS
+    }
+}
+
+impl Plugin for RenderWorldDiagnosticPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.register_diagnostic(
+            Diagnostic::new(EXTRACTED_ENTITIES.clone()).with_suffix(" entities"),
+        )
+        .register_diagnostic(Diagnostic::new(BIND_GROUPS.clone()).with_suffix(" bind groups"))
+        .register_diagnostic(Diagnostic::new(DRAW_CALLS.clone()).with_suffix(" draw calls"))
+        .init_resource::<RenderWorldMeasurements>()
+        .add_systems(PreUpdate, add_render_world_measurements);
+
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .init_resource::<RenderPhaseDrawCalls>()
+                .add_systems(ExtractSchedule, measure_render_world);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RenderWorldMeasurement {
+    extracted_entities: usize,
+    bind_groups: u32,
+    draw_calls: Vec<(DiagnosticPath, u32)>,
+}
+
+#[derive(Debug, Default, Resource)]
+struct RenderWorldMeasurements(Mutex<Option<RenderWorldMeasurement>>);
+
+/// The number of draw calls of each render phase in the frame, recorded by the render phases.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct RenderPhaseDrawCalls(Mutex<HashMap<TypeId, (DiagnosticPath, u32)>>);
+
+impl RenderPhaseDrawCalls {
+    /// Adds `draw_calls` to the draw calls of the render phase of the `I` phase items, if the
+    /// [`RenderWorldDiagnosticPlugin`] is present.
+    pub(crate) fn record<I: 'static>(world: &World, draw_calls: u32) {
+        let Some(phases) = world.get_resource::<Self>() else {
+            return;
+        };
+        let mut phases = phases.0.lock().unwrap();
+        phases
+            .entry(TypeId::of::<I>())
+            .or_insert_with(|| {
+                let name = ShortName::of::<I>().to_string();
+                (
+                    DiagnosticPath::from_components(["render", "draw_calls", &name]),
+                    0,
+                )
+            })
+            .1 += draw_calls;
+    }
+}
+
+fn add_render_world_measurements(
+    measurements: Res<RenderWorldMeasurements>,
+    mut store: ResMut<DiagnosticsStore>,
+) {
+    let Some(measurement) = measurements.0.lock().unwrap().take() else {
+        return;
+    };
+
+    let time = Instant::now();
+    let total_draw_calls = measurement
+        .draw_calls
+        .iter()
+        .map(|(_, draw_calls)| *draw_calls as f64)
+        .sum();
+    let values = [
+        (&EXTRACTED_ENTITIES, measurement.extracted_entities as f64),
+        (&BIND_GROUPS, measurement.bind_groups as f64),
+        (&DRAW_CALLS, total_draw_calls),
+    ];
+    let phases = measurement
+        .draw_calls
+        .iter()
+        .map(|(path, draw_calls)| (path, *draw_calls as f64));
+
+    for (path, value) in values.into_iter().chain(phases) {
+        // The diagnostics of the render phases are added the first time the phases are drawn.
+        if store.get(path).is_none() {
+            store.add(Diagnostic::new(path.clone()).with_suffix(" draw calls"));
+        }
+        store
+            .get_mut(path)
+            .unwrap()
+            .add_measurement(DiagnosticMeasurement { time, value });
+    }
+}
+
+/// Measures the previous frame of the render world.
+fn measure_render_world(
+    measurements: Extract<Res<RenderWorldMeasurements>>,
+    extracted_entities: Query<(), With<MainEntity>>,
+    mut draw_calls: ResMut<RenderPhaseDrawCalls>,
+    mut last_bind_group_id: Local<Option<u32>>,
+) {
+    // Bind group ids are allocated sequentially, so the bind groups created since the last frame
+    // are counted by allocating an id every frame.
+    let bind_group_id = u32::from(core::num::NonZero::<u32>::from(BindGroupId::new()));
+    let bind_groups = last_bind_group_id
+        .replace(bind_group_id)
+        .map(|last| bind_group_id.saturating_sub(last + 1))
+        .unwrap_or_default();
+
+    let draw_calls = draw_calls
+        .0
+        .get_mut()
+        .unwrap()
+        .values_mut()
+        .map(|(path, draw_calls)| (path.clone(), core::mem::take(draw_calls)))
+        .collect();
+
+    *measurements.0.lock().unwrap() = Some(RenderWorldMeasurement {
+        extracted_entities: extracted_entities.iter().count(),
+        bind_groups,
+        draw_calls,
+    });
+}
@@ -0,0 +1,239 @@
+//! Per-instance user data for meshes, gathered into a storage buffer that
+//! custom materials can read from.
+//!
+//! Add an [`InstanceDataPlugin<T>`] for your data type, put an
+//! [`InstanceData<T>`] on each mesh entity, and bind
+//! [`InstanceDataBuffer::buffer`] in your material with a
+//! `#[storage(N, read_only)]` attribute. In the shader, the data of an instance
+//! is then found at the index given by `mesh_functions::get_tag`:
+//!
+//! ```wgsl
+//! @group(#{MATERIAL_BIND_GROUP}) @binding(0) var<storage, read> instances: array<MyInstanceData>;
+//!
+//! let data = instances[mesh_functions::get_tag(vertex.instance_index)];
+//! ```
+//!
+//! Meshes with the same mesh and material are still automatically instanced
+//! into a single draw call, however different their data is.
+
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{Assets, Handle, RenderAssetUsages};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::{Entity, EntityHashMap},
+    lifecycle::RemovedComponents,
+    query::Changed,
+    resource::Resource,
+    system::{Query, ResMut},
+    world::{FromWorld, World},
+};
+use bevy_log::error;
+use bevy_mesh::MeshTag;
+
+use crate::{render_resource::GpuArrayBufferable, storage::ShaderBuffer};
+
+/// Gathers the [`InstanceData<T>`] of all entities into the storage buffer of
+/// the [`InstanceDataBuffer<T>`] resource.
+///
+/// Add one of these plugins for each type of instance data.
+pub struct InstanceDataPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for InstanceDataPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: GpuArrayBufferable + Send + Sync + 'static> Plugin for InstanceDataPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InstanceDataBuffer<T>>()
+            .add_systems(PostUpdate, write_instance_data::<T>);
+    }
+}
+
+/// Custom data for this mesh instance, such as a color, a random seed or a
+/// matrix, which is made available to shaders by the [`InstanceDataPlugin<T>`].
+///
+/// The data is written to the [`InstanceDataBuffer<T>`] at the index stored in
+/// this entity's [`MeshTag`], which is managed by the plugin and must not be
+/// changed while this component is present.
+///
+/// `T` is laid out in the buffer following the WGSL rules for storage buffers,
+/// so it usually derives [`ShaderType`](crate::render_resource::ShaderType).
+#[derive(Component, Clone, Debug, Default)]
+#[require(MeshTag)]
+pub struct InstanceData<T: GpuArrayBufferable + Send + Sync + 'static>(pub T);
+
+/// The storage buffer holding the [`InstanceData<T>`] of all entities, indexed
+/// by their [`MeshTag`].
+///
+/// Indices are reused when an [`InstanceData<T>`] is removed, so the buffer
+/// only grows as large as the most instances alive at once.
+#[derive(Resource)]
+pub struct InstanceDataBuffer<T: GpuArrayBufferable + Send + Sync + 'static> {
+    buffer: Handle<ShaderBuffer>,
+    values: Vec<T>,
+    indices: EntityHashMap<u32>,
+    free_indices: Vec<u32>,
+}
+
+impl<T: GpuArrayBufferable + Send + Sync + 'static> FromWorld for InstanceDataBuffer<T> {
+    fn from_world(world: &mut World) -> Self {
+        let buffer = ShaderBuffer {
+            label: "instance data buffer".into(),
+            ..ShaderBuffer::with_size(T::SHADER_SIZE.get(), RenderAssetUsages::default())
+        };
+        Self {
+            buffer: world.resource_mut::<Assets<ShaderBuffer>>().add(buffer),
+            values: Vec::new(),
+            indices: EntityHashMap::default(),
+            free_indices: Vec::new(),
+        }
+    }
+}
+
+impl<T: GpuArrayBufferable + Send + Sync + 'static> InstanceDataBuffer<T> {
+    /// The storage buffer to bind in materials, as an array of `T`.
+    #[inline]
+    pub fn buffer(&self) -> &Handle<ShaderBuffer> {
+        &self.buffer
+    }
+
+    /// Returns the index of the data of `entity` in the buffer, if it has an
+    /// [`InstanceData<T>`].
+    #[inline]
+    pub fn index(&self, entity: Entity) -> Option<u32> {
+        self.indices.get(&entity).copied()
+    }
+
+    /// Returns the number of entities with an [`InstanceData<T>`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if no entity has an [`InstanceData<T>`].
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+}
+
+/// Assigns buffer indices to new [`InstanceData<T>`]s, frees the ones of
+/// removed ones, and rewrites the storage buffer if anything changed.
+pub fn write_instance_data<T: GpuArrayBufferable + Send + Sync + 'static>(
+    mut instance_data_buffer: ResMut<InstanceDataBuffer<T>>,
+    mut buffers: ResMut<Assets<ShaderBuffer>>,
+    mut instances: Query<(Entity, &InstanceData<T>, &mut MeshTag), Changed<InstanceData<T>>>,
+    mut removed: RemovedComponents<InstanceData<T>>,
+) {
+    let instance_data_buffer = &mut *instance_data_buffer;
+    let mut dirty = false;
+
+    for entity in removed.read() {
+        if let Some(index) = instance_data_buffer.indices.remove(&entity) {
+            instance_data_buffer.free_indices.push(index);
+        }
+    }
+
+    for (entity, instance_data, mut mesh_tag) in &mut instances {
+        let index = match instance_data_buffer.indices.get(&entity) {
+            Some(index) => *index,
+            None => {
+                let index = match instance_data_buffer.free_indices.pop() {
+                    Some(index) => index,
+                    None => {
+                        instance_data_buffer.values.push(instance_data.0.clone());
+                        instance_data_buffer.values.len() as u32 - 1
+                    }
+                };
+                instance_data_buffer.indices.insert(entity, index);
+                index
+            }
+        };
+        instance_data_buffer.values[index as usize] = instance_data.0.clone();
+        mesh_tag.set_if_neq(MeshTag(index));
+        dirty = true;
+    }
+
+    if !dirty || instance_data_buffer.values.is_empty() {
+        return;
+    }
+
+    let mut bytes = encase::StorageBuffer::new(Vec::<u8>::new());
+    if let Err(err) = bytes.write(&instance_data_buffer.values) {
+        error!("Failed to write instance data: {err}");
+        return;
+    }
+    if let Some(mut buffer) = buffers.get_mut(&instance_data_buffer.buffer) {
+        buffer.clear();
+        buffer.extend_from_slice(&bytes.into_inner());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{AssetApp, AssetPlugin, Assets};
+    use bevy_ecs::{entity::Entity, world::World};
+    use bevy_math::Vec4;
+    use bevy_mesh::MeshTag;
+
+    use super::{InstanceData, InstanceDataBuffer, InstanceDataPlugin};
+    use crate::storage::ShaderBuffer;
+
+    fn mesh_tag(world: &World, entity: Entity) -> u32 {
+        world.get::<MeshTag>(entity).unwrap().0
+    }
+
+    fn buffer_contents(world: &World) -> Vec<u8> {
+        let handle = world.resource::<InstanceDataBuffer<Vec4>>().buffer();
+        let buffer = world
+            .resource::<Assets<ShaderBuffer>>()
+            .get(handle)
+            .unwrap();
+        buffer.as_slice::<u8>().unwrap().to_vec()
+    }
+
+    fn bytes(values: &[Vec4]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(Vec4::to_array)
+            .flat_map(f32::to_le_bytes)
+            .collect()
+    }
+
+    #[test]
+    fn instance_data_indices_are_reused() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .init_asset::<ShaderBuffer>()
+            .add_plugins(InstanceDataPlugin::<Vec4>::default());
+
+        let a = app.world_mut().spawn(InstanceData(Vec4::ONE)).id();
+        let b = app.world_mut().spawn(InstanceData(Vec4::ZERO)).id();
+        app.update();
+        assert_eq!(mesh_tag(app.world(), a), 0);
+        assert_eq!(mesh_tag(app.world(), b), 1);
+        assert_eq!(
+            buffer_contents(app.world()),
+            bytes(&[Vec4::ONE, Vec4::ZERO])
+        );
+
+        // The index of a despawned instance goes to the next new one.
+        app.world_mut().despawn(a);
+        let c = app.world_mut().spawn(InstanceData(Vec4::X)).id();
+        app.update();
+        assert_eq!(mesh_tag(app.world(), c), 0);
+        assert_eq!(app.world().resource::<InstanceDataBuffer<Vec4>>().len(), 2);
+        assert_eq!(buffer_contents(app.world()), bytes(&[Vec4::X, Vec4::ZERO]));
+
+        app.world_mut().entity_mut(b).insert(InstanceData(Vec4::Y));
+        app.update();
+        assert_eq!(mesh_tag(app.world(), b), 1);
+        assert_eq!(buffer_contents(app.world()), bytes(&[Vec4::X, Vec4::Y]));
+    }
+}
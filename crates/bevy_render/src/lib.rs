@@ -38,6 +38,7 @@ extern crate self as bevy_render;
 
 pub mod batching;
 pub mod camera;
+pub mod capabilities;
 pub mod diagnostic;
 pub mod erased_render_asset;
 pub mod error_handler;
@@ -49,6 +50,7 @@ pub mod extract_resource;
 pub mod globals;
 pub mod gpu_component_array_buffer;
 pub mod gpu_readback;
+pub mod instance_data;
 pub mod material_bind_groups;
 pub mod mesh;
 pub mod occlusion_culling;
@@ -66,6 +68,7 @@ pub mod sync_world;
 pub mod texture;
 pub mod uniform;
 pub mod view;
+pub mod xr;
 
 /// The render prelude.
 ///
@@ -83,13 +86,14 @@ pub use extract_plugin::{ExtractSchedule, MainWorld};
 
 use crate::{
     camera::CameraPlugin,
+    capabilities::RenderCapabilityReport,
     error_handler::{RenderErrorHandler, RenderState},
     extract_plugin::ExtractPlugin,
     gpu_readback::GpuReadbackPlugin,
     material_bind_groups::MaterialBindGroupPlugin,
     mesh::{MeshRenderAssetPlugin, RenderMesh},
     render_asset::prepare_assets,
-    render_resource::{PipelineCache, SparseBufferPlugin},
+    render_resource::{PipelineCache, PipelineCompilationError, SparseBufferPlugin},
     renderer::{render_system, RenderAdapterInfo, RenderGraph},
     settings::{RenderCreation, WgpuLimits},
     storage::StoragePlugin,
@@ -389,14 +393,24 @@ impl Plugin for RenderPlugin {
         let (sender, receiver) = bevy_time::create_time_channels();
         app.insert_resource(receiver);
 
+        let (error_sender, error_receiver) =
+            render_resource::create_pipeline_compilation_error_channel();
+        app.add_message::<PipelineCompilationError>()
+            .insert_resource(error_receiver)
+            .add_systems(First, render_resource::receive_pipeline_compilation_errors);
+
         let asset_server = app.world().resource::<AssetServer>().clone();
+        let capability_report = RenderCapabilityReport::default();
         app.init_resource::<RenderAssetBytesPerFrame>()
-            .init_resource::<RenderErrorHandler>();
+            .init_resource::<RenderErrorHandler>()
+            .insert_resource(capability_report.clone());
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.insert_resource(capability_report);
             render_app.init_resource::<RenderScheduleOrder>();
             render_app.init_resource::<RenderAssetBytesPerFrameLimiter>();
             render_app.init_gpu_resource::<renderer::PendingCommandBuffers>();
             render_app.insert_resource(sender);
+            render_app.insert_resource(error_sender);
             render_app.insert_resource(asset_server);
             render_app.insert_resource(RenderState::Initializing);
             render_app.add_systems(
@@ -431,7 +445,11 @@ impl Plugin for RenderPlugin {
             render_app.add_systems(
                 Render,
                 (
-                    (PipelineCache::process_pipeline_queue_system, render_system)
+                    (
+                        PipelineCache::process_pipeline_queue_system,
+                        PipelineCache::send_compilation_errors_system,
+                        render_system,
+                    )
                         .chain()
                         .in_set(RenderSystems::Render),
                     reset_render_asset_bytes_per_frame.in_set(RenderSystems::Cleanup),
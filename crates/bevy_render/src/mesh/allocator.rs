@@ -13,6 +13,7 @@ use bevy_ecs::{
 use bevy_log::warn;
 use bevy_math::bounding::{Aabb2d, BoundingVolume};
 use bevy_mesh::Indices;
+use bevy_platform::collections::HashSet;
 use glam::Vec4;
 use wgpu::{BufferUsages, DownlevelFlags, COPY_BUFFER_ALIGNMENT};
 
@@ -254,13 +255,17 @@ pub fn allocate_and_free_meshes(
     render_device: Res<RenderDevice>,
     render_queue: Res<RenderQueue>,
 ) {
+    // Process modified meshes of which only some vertices changed, in place.
+    let updated_meshes = mesh_allocator.update_meshes_in_place(&extracted_meshes, &render_queue);
+
     // Process removed or modified meshes.
-    mesh_allocator.free_meshes(&extracted_meshes);
+    mesh_allocator.free_meshes(&extracted_meshes, &updated_meshes);
 
     // Process newly-added or modified meshes.
     mesh_allocator.allocate_meshes(
         &mesh_allocator_settings,
         &extracted_meshes,
+        &updated_meshes,
         &mut mesh_vertex_buffer_layouts,
         &render_device,
         &render_queue,
@@ -398,6 +403,7 @@ impl MeshAllocator {
         &mut self,
         mesh_allocator_settings: &MeshAllocatorSettings,
         extracted_meshes: &ExtractedAssets<RenderMesh>,
+        updated_meshes: &HashSet<AssetId<Mesh>>,
         mesh_vertex_buffer_layouts: &mut MeshVertexBufferLayouts,
         render_device: &RenderDevice,
         render_queue: &RenderQueue,
@@ -406,6 +412,9 @@ impl MeshAllocator {
 
         // Loop over each mesh that was extracted this frame.
         for (mesh_id, mesh) in &extracted_meshes.extracted {
+            if updated_meshes.contains(mesh_id) {
+                continue;
+            }
             let vertex_buffer_size = mesh.get_vertex_buffer_size() as u64;
             if vertex_buffer_size == 0 {
                 warn!("Mesh {:?} contains no vertices.", mesh_id);
@@ -476,6 +485,9 @@ impl MeshAllocator {
 
         // Copy new mesh data in.
         for (mesh_id, mesh) in &extracted_meshes.extracted {
+            if updated_meshes.contains(mesh_id) {
+                continue;
+            }
             let vertex_buffer_size = mesh.get_vertex_buffer_size() as u64;
             if vertex_buffer_size == 0 {
                 continue;
@@ -488,18 +500,77 @@ impl MeshAllocator {
         }
     }
 
-    /// Copies vertex array data from a mesh into the appropriate spot in the
-    /// slab.
-    fn copy_mesh_metadata(
-        &mut self,
-        mesh_id: &AssetId<Mesh>,
-        mesh: &Mesh,
-        render_device: &RenderDevice,
+    /// Overwrites the vertices listed by [`Mesh::changed_vertex_ranges`] of the
+    /// meshes modified this frame in their existing allocations, so that only
+    /// these vertices are uploaded again.
+    ///
+    /// Returns the IDs of the meshes updated this way, which must not be freed
+    /// and allocated again. The other modified meshes, such as those whose
+    /// vertex count or indices changed, are reallocated as usual.
+    fn update_meshes_in_place(
+        &self,
+        extracted_meshes: &ExtractedAssets<RenderMesh>,
         render_queue: &RenderQueue,
-    ) {
+    ) -> HashSet<AssetId<Mesh>> {
+        let mut updated_meshes = HashSet::default();
+
+        for (mesh_id, mesh) in &extracted_meshes.extracted {
+            if !extracted_meshes.modified.contains(mesh_id) {
+                continue;
+            }
+            let Some(vertex_ranges) = mesh
+                .changed_vertex_ranges()
+                .filter(|vertex_ranges| !vertex_ranges.is_empty())
+            else {
+                continue;
+            };
+
+            // The mesh must still fit in its allocations exactly.
+            let Some(vertex_slice) = self.mesh_vertex_slice(mesh_id) else {
+                continue;
+            };
+            let metadata = Self::mesh_metadata(mesh);
+            if (vertex_slice.range.end - vertex_slice.range.start) as usize != mesh.count_vertices()
+                || metadata.is_some() != self.mesh_id_to_metadata_slab(mesh_id).is_some()
+            {
+                continue;
+            }
+
+            let vertex_key = MeshAllocationKey::new(*mesh_id, ElementClass::Vertex);
+            for vertex_range in vertex_ranges {
+                self.update_element_data(
+                    &vertex_key,
+                    vertex_range.clone(),
+                    |vertices, slice| {
+                        mesh.write_packed_vertex_buffer_range(
+                            vertices.start as usize..vertices.end as usize,
+                            slice,
+                        );
+                    },
+                    render_queue,
+                );
+            }
+            if let Some(metadata) = metadata {
+                self.update_element_data(
+                    &MeshAllocationKey::new(*mesh_id, ElementClass::Metadata),
+                    0..1,
+                    |_, mut slice| slice.copy_from_slice(bytemuck::cast_slice(&[metadata])),
+                    render_queue,
+                );
+            }
+
+            updated_meshes.insert(*mesh_id);
+        }
+
+        updated_meshes
+    }
+
+    /// Returns the metadata of a mesh, if it has compressed vertex attributes
+    /// or a precomputed AABB.
+    fn mesh_metadata(mesh: &Mesh) -> Option<MeshMetadata> {
         const UV_RANGES_NONE: [Option<Aabb2d>; 2] = [None; 2];
-        let metadata = match (mesh.final_aabb, mesh.final_uv_ranges) {
-            (None, UV_RANGES_NONE) => return,
+        match (mesh.final_aabb, mesh.final_uv_ranges) {
+            (None, UV_RANGES_NONE) => None,
             _ => {
                 let (aabb_center, aabb_half_extents) = mesh
                     .final_aabb
@@ -517,13 +588,27 @@ impl MeshAllocator {
                         })
                         .unwrap_or(Vec4::new(0.0, 0.0, 1.0, 1.0))
                 });
-                MeshMetadata {
+                Some(MeshMetadata {
                     aabb_center,
                     aabb_half_extents,
                     uv_channels_min_and_extents,
                     ..Default::default()
-                }
+                })
             }
+        }
+    }
+
+    /// Copies vertex array data from a mesh into the appropriate spot in the
+    /// slab.
+    fn copy_mesh_metadata(
+        &mut self,
+        mesh_id: &AssetId<Mesh>,
+        mesh: &Mesh,
+        render_device: &RenderDevice,
+        render_queue: &RenderQueue,
+    ) {
+        let Some(metadata) = Self::mesh_metadata(mesh) else {
+            return;
         };
         // Call the generic function.
         self.copy_element_data(
@@ -601,16 +686,23 @@ impl MeshAllocator {
         );
     }
 
-    /// Frees allocations for meshes that were removed or modified this frame.
-    fn free_meshes(&mut self, extracted_meshes: &ExtractedAssets<RenderMesh>) {
+    /// Frees allocations for meshes that were removed or modified this frame,
+    /// except for the meshes that were updated in place.
+    fn free_meshes(
+        &mut self,
+        extracted_meshes: &ExtractedAssets<RenderMesh>,
+        updated_meshes: &HashSet<AssetId<Mesh>>,
+    ) {
         let mut deallocation_stage = self.slab_allocator.stage_deallocation();
 
         // TODO: Consider explicitly reusing allocations for changed meshes of
         // the same size
-        let meshes_to_free = extracted_meshes
-            .removed
-            .iter()
-            .chain(extracted_meshes.modified.iter());
+        let meshes_to_free = extracted_meshes.removed.iter().chain(
+            extracted_meshes
+                .modified
+                .iter()
+                .filter(|mesh_id| !updated_meshes.contains(*mesh_id)),
+        );
 
         for mesh_id in meshes_to_free {
             deallocation_stage.free(&MeshAllocationKey::new(*mesh_id, ElementClass::Metadata));
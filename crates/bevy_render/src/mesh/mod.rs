@@ -219,6 +219,13 @@ impl RenderAsset for RenderMesh {
             .map_err(|_| AssetExtractionError::AlreadyExtracted)
     }
 
+    fn clone_for_render_world(source: &mut Self::SourceAsset) -> Self::SourceAsset {
+        let mesh = source.clone();
+        // The next changes are relative to the mesh extracted now.
+        source.clear_changed_vertex_ranges();
+        mesh
+    }
+
     fn byte_len(mesh: &Self::SourceAsset) -> Option<usize> {
         let mut vertex_size = 0;
         for attribute_data in mesh.attributes() {
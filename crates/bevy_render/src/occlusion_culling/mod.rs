@@ -2,6 +2,15 @@
 //!
 //! See [`OcclusionCulling`] for a detailed description of occlusion culling in
 //! Bevy.
+//!
+//! Occlusion culling runs on top of the GPU frustum culling that the mesh
+//! preprocessing compute pass performs by default, see
+//! [`GpuPreprocessingMode::Culling`]. Both write the indirect draw parameters
+//! on the GPU, so the CPU frustum culling can be skipped with
+//! [`NoCpuCulling`](bevy_camera::visibility::NoCpuCulling) for scenes with
+//! many meshes.
+//!
+//! [`GpuPreprocessingMode::Culling`]: crate::batching::gpu_preprocessing::GpuPreprocessingMode::Culling
 
 use bevy_app::{App, Plugin};
 use bevy_ecs::{component::Component, entity::Entity, prelude::ReflectComponent};
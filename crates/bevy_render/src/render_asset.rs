@@ -103,6 +103,14 @@ pub trait RenderAsset: Send + Sync + 'static + Sized {
     ) -> Result<Self::SourceAsset, AssetExtractionError> {
         Err(AssetExtractionError::NoExtractionImplementation)
     }
+
+    /// Make a copy of the asset to be moved to the `RenderWorld` / gpu, for assets that are kept
+    /// in the main world too, with [`RenderAssetUsages::MAIN_WORLD`].
+    /// The default implementation clones the asset. Assets tracking what changed since they were
+    /// last extracted, in order to only upload these changes, can reset their tracking here.
+    fn clone_for_render_world(source: &mut Self::SourceAsset) -> Self::SourceAsset {
+        source.clone()
+    }
 }
 
 /// This plugin extracts the changed assets from the "app world" into the "render world"
@@ -347,8 +355,8 @@ pub(crate) fn extract_render_asset<A: RenderAsset>(
                                     }
                                 };
                             }
-                        } else {
-                            extracted_assets.extracted.push((id, asset.clone()));
+                        } else if let Some(asset) = assets.get_mut_untracked(id) {
+                            extracted_assets.extracted.push((id, A::clone_for_render_world(asset)));
                             extracted_assets.added.insert(id);
                         }
                     }
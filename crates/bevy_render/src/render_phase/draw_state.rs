@@ -132,6 +132,7 @@ impl DrawState {
 pub struct TrackedRenderPass<'a> {
     pass: RenderPass<'a>,
     state: DrawState,
+    draw_calls: u32,
 }
 
 impl<'a> TrackedRenderPass<'a> {
@@ -147,9 +148,17 @@ impl<'a> TrackedRenderPass<'a> {
                 ..default()
             },
             pass,
+            draw_calls: 0,
         }
     }
 
+    /// Returns the number of draw commands issued on this pass so far.
+    ///
+    /// Each multi-draw command counts as a single draw command.
+    pub fn draw_calls(&self) -> u32 {
+        self.draw_calls
+    }
+
     /// Returns the wgpu [`RenderPass`].
     ///
     /// Function invalidates internal tracking state,
@@ -275,6 +284,7 @@ impl<'a> TrackedRenderPass<'a> {
     pub fn draw(&mut self, vertices: Range<u32>, instances: Range<u32>) {
         #[cfg(feature = "detailed_trace")]
         trace!("draw: {:?} {:?}", vertices, instances);
+        self.draw_calls += 1;
         self.pass.draw(vertices, instances);
     }
 
@@ -290,6 +300,7 @@ impl<'a> TrackedRenderPass<'a> {
             base_vertex,
             instances
         );
+        self.draw_calls += 1;
         self.pass.draw_indexed(indices, base_vertex, instances);
     }
 
@@ -313,6 +324,7 @@ impl<'a> TrackedRenderPass<'a> {
     pub fn draw_indirect(&mut self, indirect_buffer: &'a Buffer, indirect_offset: u64) {
         #[cfg(feature = "detailed_trace")]
         trace!("draw indirect: {:?} {}", indirect_buffer, indirect_offset);
+        self.draw_calls += 1;
         self.pass.draw_indirect(indirect_buffer, indirect_offset);
     }
 
@@ -342,6 +354,7 @@ impl<'a> TrackedRenderPass<'a> {
             indirect_buffer,
             indirect_offset
         );
+        self.draw_calls += 1;
         self.pass
             .draw_indexed_indirect(indirect_buffer, indirect_offset);
     }
@@ -376,6 +389,7 @@ impl<'a> TrackedRenderPass<'a> {
             indirect_offset,
             count
         );
+        self.draw_calls += 1;
         self.pass
             .multi_draw_indirect(indirect_buffer, indirect_offset, count);
     }
@@ -419,6 +433,7 @@ impl<'a> TrackedRenderPass<'a> {
             count_offset,
             max_count
         );
+        self.draw_calls += 1;
         self.pass.multi_draw_indirect_count(
             indirect_buffer,
             indirect_offset,
@@ -460,6 +475,7 @@ impl<'a> TrackedRenderPass<'a> {
             indirect_offset,
             count
         );
+        self.draw_calls += 1;
         self.pass
             .multi_draw_indexed_indirect(indirect_buffer, indirect_offset, count);
     }
@@ -505,6 +521,7 @@ impl<'a> TrackedRenderPass<'a> {
             count_offset,
             max_count
         );
+        self.draw_calls += 1;
         self.pass.multi_draw_indexed_indirect_count(
             indirect_buffer,
             indirect_offset,
@@ -27,6 +27,7 @@
 mod draw;
 mod draw_state;
 mod rangefinder;
+mod view_phases;
 
 use bevy_app::{App, Plugin};
 use bevy_derive::{Deref, DerefMut};
@@ -42,6 +43,7 @@ use indexmap::IndexMap;
 use nonmax::NonMaxU32;
 pub use rangefinder::*;
 use tracing::error;
+pub use view_phases::*;
 use wgpu::{BufferUsages, Features};
 
 use crate::batching::gpu_preprocessing::{
@@ -52,7 +54,7 @@ use crate::render_resource::{RawBufferVec, UninitBufferVec};
 use crate::renderer::RenderDevice;
 use crate::sync_world::{MainEntity, MainEntityHashMap};
 use crate::view::{ExtractedView, RetainedViewEntity};
-use crate::RenderDebugFlags;
+use crate::{diagnostic::RenderPhaseDrawCalls, RenderDebugFlags};
 use bevy_material::descriptor::CachedRenderPipelineId;
 
 use crate::{
@@ -1181,9 +1183,11 @@ where
             // locks.
         }
 
+        let draw_calls = render_pass.draw_calls();
         self.render_batchable_meshes(render_pass, world, view)?;
         self.render_unbatchable_meshes(render_pass, world, view)?;
         self.render_non_meshes(render_pass, world, view)?;
+        RenderPhaseDrawCalls::record::<BPI>(world, render_pass.draw_calls() - draw_calls);
 
         Ok(())
     }
@@ -1994,6 +1998,7 @@ where
         let mut draw_functions = draw_functions.write();
         draw_functions.prepare(world);
 
+        let draw_calls = render_pass.draw_calls();
         let mut index = 0;
         while index < items.len() {
             let item = &items[index];
@@ -2006,6 +2011,7 @@ where
                 index += batch_range.len();
             }
         }
+        RenderPhaseDrawCalls::record::<I>(world, render_pass.draw_calls() - draw_calls);
         Ok(())
     }
 }
@@ -0,0 +1,141 @@
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin};
+use bevy_camera::Camera;
+use bevy_ecs::{component::Mutable, prelude::*, query::QueryFilter};
+use bevy_platform::collections::HashSet;
+
+use super::{BinnedPhaseItem, SortedPhaseItem, ViewBinnedRenderPhases, ViewSortedRenderPhases};
+use crate::{
+    batching::gpu_preprocessing::{GpuPreprocessingMode, GpuPreprocessingSupport},
+    view::{NoIndirectDrawing, RetainedViewEntity},
+    Extract, ExtractSchedule, RenderApp,
+};
+
+/// The phases of a render phase item for each view, which are prepared for the views that render
+/// them at the start of each frame.
+///
+/// This is implemented by [`ViewBinnedRenderPhases`] and [`ViewSortedRenderPhases`], so that
+/// [`CameraRenderPhasePlugin`] can prepare either kind of phase.
+pub trait ViewRenderPhases: Resource<Mutability = Mutable> {
+    /// Ensures that the phase of the view `retained_view_entity` exists, and clears it for the new
+    /// frame.
+    ///
+    /// The `gpu_preprocessing_mode` is only used by binned phases, when they are created.
+    fn prepare_view(
+        &mut self,
+        retained_view_entity: RetainedViewEntity,
+        gpu_preprocessing_mode: GpuPreprocessingMode,
+    );
+
+    /// Removes the phases of the views for which `f` returns `false`.
+    fn retain_views(&mut self, f: impl FnMut(&RetainedViewEntity) -> bool);
+}
+
+impl<BPI> ViewRenderPhases for ViewBinnedRenderPhases<BPI>
+where
+    BPI: BinnedPhaseItem,
+{
+    fn prepare_view(
+        &mut self,
+        retained_view_entity: RetainedViewEntity,
+        gpu_preprocessing_mode: GpuPreprocessingMode,
+    ) {
+        self.prepare_for_new_frame(retained_view_entity, gpu_preprocessing_mode);
+    }
+
+    fn retain_views(&mut self, mut f: impl FnMut(&RetainedViewEntity) -> bool) {
+        self.retain(|view_entity, _| f(view_entity));
+    }
+}
+
+impl<SPI> ViewRenderPhases for ViewSortedRenderPhases<SPI>
+where
+    SPI: SortedPhaseItem,
+{
+    fn prepare_view(&mut self, retained_view_entity: RetainedViewEntity, _: GpuPreprocessingMode) {
+        self.prepare_for_new_frame(retained_view_entity);
+    }
+
+    fn retain_views(&mut self, mut f: impl FnMut(&RetainedViewEntity) -> bool) {
+        self.retain(|view_entity, _| f(view_entity));
+    }
+}
+
+/// A plugin that prepares the phases stored in `P` for every active camera matching the query
+/// filter `F`, so that plugins can add their own render phases without extracting the phases of
+/// each view themselves.
+///
+/// The phase item must also be registered with a [`BinnedRenderPhasePlugin`] or a
+/// [`SortedRenderPhasePlugin`], which store and batch the phases. For example, an outline phase
+/// drawn by 3D cameras can be added with:
+///
+/// ```ignore
+/// app.add_plugins((
+///     SortedRenderPhasePlugin::<Outline3d, MeshPipeline>::new(debug_flags),
+///     CameraRenderPhasePlugin::<ViewSortedRenderPhases<Outline3d>, With<Camera3d>>::default(),
+/// ));
+/// ```
+///
+/// The phases are then queued by the plugin, and rendered by a system added to the render
+/// schedule of the cameras, ordered relative to the built-in passes. For example, a system added
+/// to the `Core3d` schedule `.after(main_opaque_pass_3d)` draws a phase after the opaque phase.
+///
+/// The phases belong to the main view of each camera, with a subview index of 0. Binned phases use
+/// GPU culling when it's supported, unless the camera has the [`NoIndirectDrawing`] component.
+///
+/// [`BinnedRenderPhasePlugin`]: super::BinnedRenderPhasePlugin
+/// [`SortedRenderPhasePlugin`]: super::SortedRenderPhasePlugin
+pub struct CameraRenderPhasePlugin<P, F = ()>(PhantomData<fn() -> (P, F)>);
+
+impl<P, F> Default for CameraRenderPhasePlugin<P, F> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P, F> Plugin for CameraRenderPhasePlugin<P, F>
+where
+    P: ViewRenderPhases,
+    F: QueryFilter + 'static,
+{
+    fn build(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.add_systems(ExtractSchedule, extract_camera_render_phases::<P, F>);
+    }
+}
+
+/// Prepares the phases stored in `P` for the active cameras matching `F`, and removes the phases
+/// of the other views.
+pub fn extract_camera_render_phases<P, F>(
+    mut phases: ResMut<P>,
+    cameras: Extract<Query<(Entity, &Camera, Has<NoIndirectDrawing>), F>>,
+    mut live_entities: Local<HashSet<RetainedViewEntity>>,
+    gpu_preprocessing_support: Res<GpuPreprocessingSupport>,
+) where
+    P: ViewRenderPhases,
+    F: QueryFilter + 'static,
+{
+    live_entities.clear();
+
+    for (main_entity, camera, no_indirect_drawing) in &cameras {
+        if !camera.is_active {
+            continue;
+        }
+
+        let gpu_preprocessing_mode = gpu_preprocessing_support.min(if !no_indirect_drawing {
+            GpuPreprocessingMode::Culling
+        } else {
+            GpuPreprocessingMode::PreprocessingOnly
+        });
+
+        let retained_view_entity = RetainedViewEntity::new(main_entity.into(), None, 0);
+        phases.prepare_view(retained_view_entity, gpu_preprocessing_mode);
+        live_entities.insert(retained_view_entity);
+    }
+
+    phases.retain_views(|view_entity| live_entities.contains(view_entity));
+}
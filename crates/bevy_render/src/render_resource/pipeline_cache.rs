@@ -12,7 +12,7 @@ use crate::{
 use alloc::{borrow::Cow, sync::Arc};
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::{
-    message::MessageReader,
+    message::{Message, MessageReader, MessageWriter},
     resource::Resource,
     system::{Res, ResMut},
 };
@@ -224,6 +224,8 @@ pub struct PipelineCache {
     pub(crate) synchronous_pipeline_compilation: bool,
     /// If `true`, the shader cache needs to be repopulated from the main world's `Assets<Shader>`.
     needs_shader_reload: bool,
+    /// The compilation errors not yet sent to the main world.
+    compilation_errors: Vec<PipelineCompilationError>,
 }
 
 impl PipelineCache {
@@ -276,6 +278,7 @@ impl PipelineCache {
             global_shader_defs,
             synchronous_pipeline_compilation,
             needs_shader_reload: true,
+            compilation_errors: Vec::new(),
         }
     }
 
@@ -589,9 +592,9 @@ impl PipelineCache {
                     cache: None,
                 };
 
-                Ok(Pipeline::RenderPipeline(
-                    device.create_render_pipeline(&descriptor),
-                ))
+                create_in_validation_scope(&device, || {
+                    Pipeline::RenderPipeline(device.create_render_pipeline(&descriptor))
+                })
             },
             self.synchronous_pipeline_compilation,
         )
@@ -651,9 +654,9 @@ impl PipelineCache {
                     cache: None,
                 };
 
-                Ok(Pipeline::ComputePipeline(
-                    device.create_compute_pipeline(&descriptor),
-                ))
+                create_in_validation_scope(&device, || {
+                    Pipeline::ComputePipeline(device.create_compute_pipeline(&descriptor))
+                })
             },
             self.synchronous_pipeline_compilation,
         )
@@ -727,10 +730,45 @@ impl PipelineCache {
                         error!("{}", pipeline_error_context(cached_pipeline));
                     }
                     error!("failed to process shader error:\n{}", error_detail);
+                    self.compilation_errors.push(PipelineCompilationError::new(
+                        id,
+                        cached_pipeline,
+                        PipelineCompilationErrorKind::ProcessShader,
+                        error_detail,
+                    ));
+                    return;
+                }
+                ShaderCacheError::ProcessShaderSource(description) => {
+                    error!("failed to process shader: {}", description);
+                    let description = description.clone();
+                    self.compilation_errors.push(PipelineCompilationError::new(
+                        id,
+                        cached_pipeline,
+                        PipelineCompilationErrorKind::ProcessShader,
+                        description,
+                    ));
                     return;
                 }
                 ShaderCacheError::CreateShaderModule(description) => {
                     error!("failed to create shader module: {}", description);
+                    let description = description.clone();
+                    self.compilation_errors.push(PipelineCompilationError::new(
+                        id,
+                        cached_pipeline,
+                        PipelineCompilationErrorKind::CreateShaderModule,
+                        description,
+                    ));
+                    return;
+                }
+                ShaderCacheError::CreatePipeline(description) => {
+                    error!("failed to create pipeline: {}", description);
+                    let description = description.clone();
+                    self.compilation_errors.push(PipelineCompilationError::new(
+                        id,
+                        cached_pipeline,
+                        PipelineCompilationErrorKind::CreatePipeline,
+                        description,
+                    ));
                     return;
                 }
             },
@@ -746,6 +784,25 @@ impl PipelineCache {
         cache.process_queue();
     }
 
+    /// Returns the compilation errors of the pipelines since the last call, which the
+    /// [`RenderPlugin`](crate::RenderPlugin) sends as [`PipelineCompilationError`] messages in the
+    /// main world.
+    pub fn drain_compilation_errors(
+        &mut self,
+    ) -> impl Iterator<Item = PipelineCompilationError> + '_ {
+        self.compilation_errors.drain(..)
+    }
+
+    pub(crate) fn send_compilation_errors_system(
+        mut cache: ResMut<Self>,
+        sender: Res<PipelineCompilationErrorSender>,
+    ) {
+        for error in cache.drain_compilation_errors() {
+            // The receiver lives in the main world, which outlives the render world.
+            let _ = sender.0.try_send(error);
+        }
+    }
+
     pub(crate) fn extract_shaders(
         mut cache: ResMut<Self>,
         shaders: Extract<Res<Assets<Shader>>>,
@@ -788,6 +845,128 @@ impl PipelineCache {
     }
 }
 
+/// Sent when a pipeline of the [`PipelineCache`] fails to compile, for example because of an error
+/// in one of its shaders or in a shader they import.
+///
+/// The errors are logged too, these messages let tools such as editors show them. When one of the
+/// shaders of the pipeline, or one of the shaders they import, is modified, for example by hot
+/// reloading, the pipeline is compiled again, so the error can be fixed while the app runs.
+///
+/// This is sent in the main world, in [`First`](bevy_app::First), a frame or two after the
+/// compilation failed in the render world.
+#[derive(Message, Clone, Debug)]
+pub struct PipelineCompilationError {
+    /// The id of the pipeline in the [`PipelineCache`].
+    pub pipeline: CachedPipelineId,
+    /// The label of the pipeline.
+    pub label: Option<Cow<'static, str>>,
+    /// The shaders of the pipeline, one of which, or one of their imports, caused the error.
+    pub shaders: Vec<Handle<Shader>>,
+    /// The step of the compilation that failed.
+    pub kind: PipelineCompilationErrorKind,
+    /// The error, with the location of the error in the shader source when it is known.
+    pub message: String,
+}
+
+/// The step of the compilation of a pipeline that failed, see [`PipelineCompilationError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PipelineCompilationErrorKind {
+    /// The shader source could not be parsed, preprocessed or composed with its imports.
+    ProcessShader,
+    /// The shader module could not be created by the GPU backend, which usually means the shader
+    /// failed validation.
+    CreateShaderModule,
+    /// The pipeline could not be created by the GPU backend, for example because the shaders don't
+    /// match the bind group or vertex layouts of the pipeline.
+    CreatePipeline,
+}
+
+impl PipelineCompilationError {
+    fn new(
+        pipeline: CachedPipelineId,
+        cached_pipeline: &CachedPipeline,
+        kind: PipelineCompilationErrorKind,
+        message: String,
+    ) -> Self {
+        let (label, shaders) = match &cached_pipeline.descriptor {
+            PipelineDescriptor::RenderPipelineDescriptor(descriptor) => (
+                descriptor.label.clone(),
+                core::iter::once(descriptor.vertex.shader.clone())
+                    .chain(
+                        descriptor
+                            .fragment
+                            .as_ref()
+                            .map(|fragment| fragment.shader.clone()),
+                    )
+                    .collect(),
+            ),
+            PipelineDescriptor::ComputePipelineDescriptor(descriptor) => {
+                (descriptor.label.clone(), vec![descriptor.shader.clone()])
+            }
+        };
+        Self {
+            pipeline,
+            label,
+            shaders,
+            kind,
+            message,
+        }
+    }
+}
+
+/// Sends the [`PipelineCompilationError`]s of the render world to the main world.
+#[derive(Resource)]
+pub(crate) struct PipelineCompilationErrorSender(
+    pub(crate) async_channel::Sender<PipelineCompilationError>,
+);
+
+/// Receives the [`PipelineCompilationError`]s of the render world in the main world.
+#[derive(Resource)]
+pub(crate) struct PipelineCompilationErrorReceiver(
+    pub(crate) async_channel::Receiver<PipelineCompilationError>,
+);
+
+pub(crate) fn create_pipeline_compilation_error_channel() -> (
+    PipelineCompilationErrorSender,
+    PipelineCompilationErrorReceiver,
+) {
+    let (sender, receiver) = async_channel::unbounded();
+    (
+        PipelineCompilationErrorSender(sender),
+        PipelineCompilationErrorReceiver(receiver),
+    )
+}
+
+/// Writes the [`PipelineCompilationError`]s received from the render world.
+pub(crate) fn receive_pipeline_compilation_errors(
+    receiver: Res<PipelineCompilationErrorReceiver>,
+    mut errors: MessageWriter<PipelineCompilationError>,
+) {
+    while let Ok(error) = receiver.0.try_recv() {
+        errors.write(error);
+    }
+}
+
+/// Runs `create`, returning the validation errors of the GPU object it creates instead of sending
+/// them to the uncaptured error handler of the device, which panics by default.
+///
+/// Like in `load_module`, the errors are only caught on native platforms.
+fn create_in_validation_scope<T>(
+    device: &RenderDevice,
+    create: impl FnOnce() -> T,
+) -> Result<T, ShaderCacheError> {
+    let scope = device
+        .wgpu_device()
+        .push_error_scope(wgpu::ErrorFilter::Validation);
+    let created = create();
+    if let Some(Some(wgpu::Error::Validation { description, .. })) =
+        bevy_tasks::futures::now_or_never(scope.pop())
+    {
+        return Err(ShaderCacheError::CreatePipeline(description));
+    }
+    Ok(created)
+}
+
 fn pipeline_error_context(cached_pipeline: &CachedPipeline) -> String {
     fn format(
         shader: &Handle<Shader>,
@@ -11,7 +11,10 @@ pub use render_device::*;
 pub use wgpu_wrapper::WgpuWrapper;
 
 use crate::{
-    settings::{RenderResources, WgpuSettings, WgpuSettingsPriority},
+    settings::{
+        AdapterSelection, RenderResources, RendererFallback, WgpuFeatures, WgpuLimits,
+        WgpuSettings, WgpuSettingsPriority,
+    },
     sync_world::MainEntity,
     view::{screenshot::SubmitScreenshotCommandsState, ExtractedWindow, ViewTarget},
 };
@@ -155,19 +158,90 @@ pub struct RenderInstance(pub Arc<WgpuWrapper<Instance>>);
 #[derive(Resource, Clone, Deref, DerefMut)]
 pub struct RenderAdapterInfo(pub WgpuWrapper<AdapterInfo>);
 
+/// A report of the adapter chosen by the renderer and of the adapters available on the system.
+///
+/// This is available in both the main and the render world once the renderer is initialized, e.g.
+/// to show a "choose GPU" setting, whose choice can be passed as
+/// [`WgpuSettings::adapter_name`] on the next launch, or to log the capabilities of the players'
+/// machines.
+#[derive(Resource, Clone, Debug)]
+pub struct RenderAdapterReport {
+    /// The information of the adapter in use.
+    pub info: AdapterInfo,
+    /// The features supported by the adapter in use.
+    pub adapter_features: WgpuFeatures,
+    /// The limits supported by the adapter in use.
+    pub adapter_limits: WgpuLimits,
+    /// The features enabled on the [`RenderDevice`], a subset of the adapter's features.
+    pub device_features: WgpuFeatures,
+    /// The limits of the [`RenderDevice`], which may be lower than the adapter's limits.
+    pub device_limits: WgpuLimits,
+    /// The information of all the adapters of the system for the enabled backends, including the
+    /// one in use.
+    ///
+    /// On wasm, this only contains the adapter in use.
+    pub available_adapters: Vec<AdapterInfo>,
+    /// The fallback the adapter in use was found with, if no adapter was found for the
+    /// [`WgpuSettings::backends`]. See [`WgpuSettings::fallbacks`].
+    pub fallback: Option<RendererFallback>,
+}
+
+impl RenderAdapterReport {
+    /// Creates the report of the `adapter` used to create the `device`, found with the given
+    /// `fallback`, if any. The available adapters are those of the `instance` for the given
+    /// `backends`.
+    pub fn new(
+        adapter: &RenderAdapter,
+        device: &RenderDevice,
+        instance: &RenderInstance,
+        backends: Backends,
+        fallback: Option<RendererFallback>,
+    ) -> Self {
+        let info = adapter.get_info();
+
+        #[cfg(not(target_family = "wasm"))]
+        let available_adapters = bevy_tasks::block_on(instance.enumerate_adapters(backends))
+            .iter()
+            .map(Adapter::get_info)
+            .collect();
+        #[cfg(target_family = "wasm")]
+        let available_adapters = {
+            let _ = (instance, backends);
+            vec![info.clone()]
+        };
+
+        Self {
+            info,
+            adapter_features: adapter.features(),
+            adapter_limits: adapter.limits(),
+            device_features: device.features(),
+            device_limits: device.limits(),
+            available_adapters,
+            fallback,
+        }
+    }
+}
+
 const GPU_NOT_FOUND_ERROR_MESSAGE: &str = if cfg!(target_os = "linux") {
     "Unable to find a GPU! Make sure you have installed required drivers! For extra information, see: https://github.com/bevyengine/bevy/blob/latest/docs/linux_dependencies.md"
 } else {
     "Unable to find a GPU! Make sure you have installed required drivers!"
 };
 
+/// Selects an adapter by name or with the [`WgpuSettings::adapter_selection`], returning `None`
+/// to let `wgpu` choose.
 #[cfg(not(target_family = "wasm"))]
-async fn find_adapter_by_name(
+async fn select_adapter(
     instance: &Instance,
     options: &WgpuSettings,
     compatible_surface: Option<&wgpu::Surface<'_>>,
-    adapter_name: &str,
+    adapter_name: Option<&str>,
 ) -> Option<Adapter> {
+    if adapter_name.is_none() && matches!(options.adapter_selection, AdapterSelection::Automatic) {
+        return None;
+    }
+
+    let mut adapters = Vec::new();
     for adapter in instance
         .enumerate_adapters(options.backends.expect(
             "The `backends` field of `WgpuSettings` must be set to use a specific adapter.",
@@ -175,22 +249,47 @@ async fn find_adapter_by_name(
         .await
     {
         bevy_log::trace!("Checking adapter: {:?}", adapter.get_info());
-        let info = adapter.get_info();
         if let Some(surface) = compatible_surface
             && !adapter.is_surface_supported(surface)
         {
             continue;
         }
+        adapters.push(adapter);
+    }
 
-        if info
-            .name
-            .to_lowercase()
-            .contains(&adapter_name.to_lowercase())
-        {
-            return Some(adapter);
+    if let Some(adapter_name) = adapter_name {
+        let adapter_name = adapter_name.to_lowercase();
+        if let Some(index) = adapters.iter().position(|adapter| {
+            adapter
+                .get_info()
+                .name
+                .to_lowercase()
+                .contains(&adapter_name)
+        }) {
+            return Some(adapters.swap_remove(index));
         }
+        warn!(
+            "No adapter named {adapter_name:?} was found, falling back to the adapter selection."
+        );
+    }
+
+    let infos = adapters.iter().map(Adapter::get_info).collect::<Vec<_>>();
+    let index = options.adapter_selection.select(&infos)?;
+    Some(adapters.swap_remove(index))
+}
+
+/// Returns the options to request an adapter compatible with `compatible_surface`.
+fn adapter_options<'a, 'b>(
+    options: &WgpuSettings,
+    compatible_surface: Option<&'a wgpu::Surface<'b>>,
+    force_fallback_adapter: bool,
+) -> RequestAdapterOptions<'a, 'b> {
+    RequestAdapterOptions {
+        power_preference: options.power_preference,
+        compatible_surface,
+        force_fallback_adapter,
+        apply_limit_buckets: false,
     }
-    None
 }
 
 /// Initializes the renderer by retrieving and preparing the GPU instance, device and queue
@@ -202,7 +301,7 @@ pub async fn initialize_renderer(
     #[cfg(feature = "raw_vulkan_init")]
     raw_vulkan_init_settings: raw_vulkan_init::RawVulkanInitSettings,
 ) -> RenderResources {
-    let instance_descriptor = wgpu::InstanceDescriptor {
+    let instance_descriptor = |backends| wgpu::InstanceDescriptor {
         backends,
         flags: options.instance_flags,
         memory_budget_thresholds: options.instance_memory_budget_thresholds,
@@ -229,33 +328,40 @@ pub async fn initialize_renderer(
     };
 
     #[cfg(not(feature = "raw_vulkan_init"))]
-    let instance = Instance::new(instance_descriptor);
+    #[cfg_attr(
+        target_family = "wasm",
+        expect(unused_mut, reason = "The GL fallback is not supported on wasm")
+    )]
+    let mut instance = Instance::new(instance_descriptor(backends));
     #[cfg(feature = "raw_vulkan_init")]
     let mut additional_vulkan_features = raw_vulkan_init::AdditionalVulkanFeatures::default();
     #[cfg(feature = "raw_vulkan_init")]
     let instance = raw_vulkan_init::create_raw_vulkan_instance(
-        instance_descriptor,
+        instance_descriptor(backends),
         &raw_vulkan_init_settings,
         &mut additional_vulkan_features,
     );
 
-    let surface = primary_window.and_then(|wrapper| {
-        let maybe_handle = wrapper
-            .0
-            .lock()
-            .expect("Couldn't get the window handle in time for renderer initialization");
-        if let Some(wrapper) = maybe_handle.as_ref() {
-            // SAFETY: Plugins should be set up on the main thread.
-            let handle = unsafe { wrapper.get_handle() };
-            Some(
-                instance
-                    .create_surface(handle)
-                    .expect("Failed to create wgpu surface"),
-            )
-        } else {
-            None
-        }
-    });
+    let create_surface = |instance: &Instance| {
+        primary_window.as_ref().and_then(|wrapper| {
+            let maybe_handle = wrapper
+                .0
+                .lock()
+                .expect("Couldn't get the window handle in time for renderer initialization");
+            if let Some(wrapper) = maybe_handle.as_ref() {
+                // SAFETY: Plugins should be set up on the main thread.
+                let handle = unsafe { wrapper.get_handle() };
+                Some(
+                    instance
+                        .create_surface(handle)
+                        .expect("Failed to create wgpu surface"),
+                )
+            } else {
+                None
+            }
+        })
+    };
+    let surface = create_surface(&instance);
 
     let force_fallback_adapter = std::env::var("WGPU_FORCE_FALLBACK_ADAPTER")
         .map_or(options.force_fallback_adapter, |v| {
@@ -266,30 +372,24 @@ pub async fn initialize_renderer(
         .as_deref()
         .map_or(options.adapter_name.clone(), |x| Some(x.to_lowercase()));
 
-    let request_adapter_options = RequestAdapterOptions {
-        power_preference: options.power_preference,
-        compatible_surface: surface.as_ref(),
-        force_fallback_adapter,
-        apply_limit_buckets: false,
-    };
+    let request_adapter_options =
+        adapter_options(options, surface.as_ref(), force_fallback_adapter);
 
     #[cfg(not(target_family = "wasm"))]
-    let mut selected_adapter = if let Some(adapter_name) = desired_adapter_name {
-        find_adapter_by_name(
-            &instance,
-            options,
-            request_adapter_options.compatible_surface,
-            &adapter_name,
-        )
-        .await
-    } else {
-        None
-    };
+    let mut selected_adapter = select_adapter(
+        &instance,
+        options,
+        request_adapter_options.compatible_surface,
+        desired_adapter_name.as_deref(),
+    )
+    .await;
     #[cfg(target_family = "wasm")]
     let mut selected_adapter = None;
 
     #[cfg(target_family = "wasm")]
-    if desired_adapter_name.is_some() {
+    if desired_adapter_name.is_some()
+        || !matches!(options.adapter_selection, AdapterSelection::Automatic)
+    {
         warn!("Choosing an adapter is not supported on wasm.");
     }
 
@@ -304,10 +404,89 @@ pub async fn initialize_renderer(
             .ok();
     }
 
-    let adapter = selected_adapter.expect(GPU_NOT_FOUND_ERROR_MESSAGE);
+    #[cfg_attr(
+        any(target_family = "wasm", feature = "raw_vulkan_init"),
+        expect(
+            unused_mut,
+            reason = "The GL fallback is not supported on wasm nor with `raw_vulkan_init`"
+        )
+    )]
+    let mut instance_backends = backends;
+    let mut fallback = None;
+    for &candidate in &options.fallbacks {
+        if selected_adapter.is_some() {
+            break;
+        }
+        match candidate {
+            RendererFallback::SoftwareAdapter => {
+                if force_fallback_adapter {
+                    continue;
+                }
+                warn!(
+                    "No adapter found for the {backends:?} backends, requesting a software adapter"
+                );
+                selected_adapter = instance
+                    .request_adapter(&adapter_options(options, surface.as_ref(), true))
+                    .await
+                    .ok();
+            }
+            RendererFallback::GlBackend =>
+            {
+                #[cfg(not(any(target_family = "wasm", feature = "raw_vulkan_init")))]
+                if !backends.contains(Backends::GL) {
+                    warn!("No adapter found for the {backends:?} backends, trying the GL backend");
+                    let gl_instance = Instance::new(instance_descriptor(Backends::GL));
+                    let gl_surface = create_surface(&gl_instance);
+                    let mut gl_adapter = gl_instance
+                        .request_adapter(&adapter_options(
+                            options,
+                            gl_surface.as_ref(),
+                            force_fallback_adapter,
+                        ))
+                        .await
+                        .ok();
+                    if gl_adapter.is_none() && !force_fallback_adapter {
+                        gl_adapter = gl_instance
+                            .request_adapter(&adapter_options(options, gl_surface.as_ref(), true))
+                            .await
+                            .ok();
+                    }
+                    if gl_adapter.is_some() {
+                        instance = gl_instance;
+                        instance_backends = Backends::GL;
+                        selected_adapter = gl_adapter;
+                    }
+                }
+            }
+        }
+        if selected_adapter.is_some() {
+            fallback = Some(candidate);
+        }
+    }
+
+    let adapter = selected_adapter.unwrap_or_else(|| {
+        if options.fallbacks.is_empty() {
+            panic!("{GPU_NOT_FOUND_ERROR_MESSAGE}")
+        } else {
+            panic!(
+                "{GPU_NOT_FOUND_ERROR_MESSAGE} No adapter was found for the {backends:?} backends, \
+                 nor with the {:?} fallbacks.",
+                options.fallbacks
+            )
+        }
+    });
     let adapter_info = adapter.get_info();
     info!("{:?}", adapter_info);
 
+    if let Some(fallback) = fallback {
+        warn!(
+            "No adapter was found for the {backends:?} backends, rendering with the {fallback:?} \
+             fallback on the {:?} backend instead. Rendering might be slow or lack features. \
+             See https://bevy.org/learn/errors/b0006/",
+            adapter_info.backend
+        );
+    }
+
     if adapter_info.device_type == DeviceType::Cpu {
         warn!(
             "The selected adapter is using a driver that only supports software rendering. \
@@ -381,6 +560,8 @@ pub async fn initialize_renderer(
         RenderAdapterInfo(WgpuWrapper::new(adapter_info)),
         RenderAdapter(Arc::new(WgpuWrapper::new(adapter))),
         RenderInstance(Arc::new(WgpuWrapper::new(instance))),
+        instance_backends,
+        fallback,
         #[cfg(feature = "raw_vulkan_init")]
         additional_vulkan_features,
     )
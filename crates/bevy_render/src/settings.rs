@@ -1,17 +1,20 @@
 use crate::{
     error_handler::DeviceErrorHandler,
     render_resource::PipelineCache,
-    renderer::{self, RenderAdapter, RenderAdapterInfo, RenderDevice, RenderInstance, RenderQueue},
+    renderer::{
+        self, RenderAdapter, RenderAdapterInfo, RenderAdapterReport, RenderDevice, RenderInstance,
+        RenderQueue,
+    },
     FutureRenderResources,
 };
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, sync::Arc};
 use bevy_ecs::world::World;
 use bevy_image::{CompressedImageFormatSupport, CompressedImageFormats};
 use bevy_window::RawHandleWrapperHolder;
 
-use wgpu::MemoryBudgetThresholds;
+use wgpu::{AdapterInfo, MemoryBudgetThresholds};
 pub use wgpu::{
-    Backends, Dx12Compiler, Features as WgpuFeatures, Gles3MinorVersion, InstanceFlags,
+    Backends, DeviceType, Dx12Compiler, Features as WgpuFeatures, Gles3MinorVersion, InstanceFlags,
     Limits as WgpuLimits, MemoryHints, PowerPreference,
 };
 
@@ -26,6 +29,84 @@ pub enum WgpuSettingsPriority {
     WebGL2,
 }
 
+/// Returns the index in `available` of the first of the `preferred` device types found.
+fn first_of_device_types(preferred: &[DeviceType], available: &[DeviceType]) -> Option<usize> {
+    preferred.iter().find_map(|device_type| {
+        available
+            .iter()
+            .position(|available| available == device_type)
+    })
+}
+
+/// Configures how the renderer chooses between the adapters (GPUs) of the system.
+///
+/// An adapter requested by name, through [`WgpuSettings::adapter_name`] or the
+/// `WGPU_ADAPTER_NAME` environment variable, always takes precedence. When no adapter matches the
+/// selection, the renderer falls back to the adapter picked by `wgpu` for the
+/// [`WgpuSettings::power_preference`].
+///
+/// The adapters of the system and the adapter in use can be read at runtime from the
+/// [`RenderAdapterReport`] resource, for example to offer a "choose GPU" setting that is passed
+/// back as [`WgpuSettings::adapter_name`] on the next launch.
+///
+/// Choosing an adapter is not supported on wasm, where the browser decides.
+#[derive(Clone, Default)]
+pub enum AdapterSelection {
+    /// Lets `wgpu` choose the adapter according to the [`WgpuSettings::power_preference`].
+    #[default]
+    Automatic,
+    /// Picks the first adapter of the first of these device types available on the system.
+    PreferDeviceTypes(Vec<DeviceType>),
+    /// Picks the adapter at the returned index in the list of the adapters able to present to
+    /// the primary window, if any.
+    Custom(Arc<dyn Fn(&[AdapterInfo]) -> Option<usize> + Send + Sync>),
+}
+
+impl AdapterSelection {
+    /// Prefers discrete GPUs, then integrated ones.
+    pub fn prefer_discrete() -> Self {
+        Self::PreferDeviceTypes(vec![DeviceType::DiscreteGpu, DeviceType::IntegratedGpu])
+    }
+
+    /// Prefers integrated GPUs, then discrete ones, e.g. to save battery.
+    pub fn prefer_integrated() -> Self {
+        Self::PreferDeviceTypes(vec![DeviceType::IntegratedGpu, DeviceType::DiscreteGpu])
+    }
+
+    /// Returns the index of the selected adapter in `adapters`, or `None` to let `wgpu` choose.
+    pub fn select(&self, adapters: &[AdapterInfo]) -> Option<usize> {
+        match self {
+            AdapterSelection::Automatic => None,
+            AdapterSelection::PreferDeviceTypes(device_types) => first_of_device_types(
+                device_types,
+                &adapters
+                    .iter()
+                    .map(|adapter| adapter.device_type)
+                    .collect::<Vec<_>>(),
+            ),
+            AdapterSelection::Custom(select) => {
+                select(adapters).filter(|index| *index < adapters.len())
+            }
+        }
+    }
+}
+
+/// A fallback tried by the renderer when no adapter is found for the [`WgpuSettings::backends`],
+/// for example on a machine without GPU drivers, in a virtual machine or in CI.
+///
+/// The fallback in use, if any, is reported in the [`RenderAdapterReport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RendererFallback {
+    /// Requests a software adapter for the enabled backends, such as WARP on DX12 or lavapipe on
+    /// Vulkan. Skipped if [`WgpuSettings::force_fallback_adapter`] is set.
+    SoftwareAdapter,
+    /// Requests an adapter for the GL backend, then a software one such as llvmpipe.
+    ///
+    /// Skipped if the GL backend is already enabled, on wasm, and with the `raw_vulkan_init`
+    /// feature.
+    GlBackend,
+}
+
 /// Provides configuration for renderer initialization. Use [`RenderDevice::features`](RenderDevice::features),
 /// [`RenderDevice::limits`](RenderDevice::limits), and the [`RenderAdapterInfo`]
 /// resource to get runtime information about the actual adapter, backend, features, and limits.
@@ -39,6 +120,10 @@ pub enum WgpuSettingsPriority {
 pub struct WgpuSettings {
     pub device_label: Option<Cow<'static, str>>,
     pub backends: Option<Backends>,
+    /// The power preference passed to `wgpu` when it chooses the adapter.
+    ///
+    /// Defaults to [`PowerPreference::HighPerformance`]. Can be overridden with the
+    /// `WGPU_POWER_PREF` environment variable.
     pub power_preference: PowerPreference,
     pub priority: WgpuSettingsPriority,
     /// The features to ensure are enabled regardless of what the adapter/backend supports.
@@ -65,6 +150,13 @@ pub struct WgpuSettings {
     pub force_fallback_adapter: bool,
     /// The name of the adapter to use.
     pub adapter_name: Option<String>,
+    /// How to choose the adapter when [`WgpuSettings::adapter_name`] is not set.
+    pub adapter_selection: AdapterSelection,
+    /// The fallbacks tried in order when no adapter is found for the [`WgpuSettings::backends`].
+    ///
+    /// Defaults to a [`RendererFallback::SoftwareAdapter`], then the
+    /// [`RendererFallback::GlBackend`]. When empty, the renderer fails to initialize instead.
+    pub fallbacks: Vec<RendererFallback>,
 }
 
 impl Default for WgpuSettings {
@@ -159,6 +251,11 @@ impl Default for WgpuSettings {
             instance_memory_budget_thresholds: MemoryBudgetThresholds::default(),
             force_fallback_adapter: false,
             adapter_name: None,
+            adapter_selection: AdapterSelection::Automatic,
+            fallbacks: vec![
+                RendererFallback::SoftwareAdapter,
+                RendererFallback::GlBackend,
+            ],
         }
     }
 }
@@ -170,6 +267,10 @@ pub struct RenderResources(
     pub RenderAdapterInfo,
     pub RenderAdapter,
     pub RenderInstance,
+    /// The backends the instance was created with.
+    pub Backends,
+    /// The fallback the adapter was found with, if any.
+    pub Option<RendererFallback>,
     #[cfg(feature = "raw_vulkan_init")] pub renderer::raw_vulkan_init::AdditionalVulkanFeatures,
 );
 
@@ -187,13 +288,26 @@ impl RenderResources {
         render_world: &mut World,
         synchronous_pipeline_compilation: bool,
     ) {
-        let RenderResources(device, queue, adapter_info, render_adapter, instance, ..) = self;
+        let RenderResources(
+            device,
+            queue,
+            adapter_info,
+            render_adapter,
+            instance,
+            backends,
+            fallback,
+            ..,
+        ) = self;
 
         let compressed_image_format_support =
             CompressedImageFormatSupport(CompressedImageFormats::from_features(device.features()));
 
+        let adapter_report =
+            RenderAdapterReport::new(&render_adapter, &device, &instance, backends, fallback);
+
         main_world.insert_resource(device.clone());
         main_world.insert_resource(queue.clone());
+        main_world.insert_resource(adapter_report.clone());
         main_world.insert_resource(adapter_info.clone());
         main_world.insert_resource(render_adapter.clone());
         main_world.insert_resource(compressed_image_format_support);
@@ -201,7 +315,7 @@ impl RenderResources {
         #[cfg(feature = "raw_vulkan_init")]
         {
             let additional_vulkan_features: renderer::raw_vulkan_init::AdditionalVulkanFeatures =
-                self.5;
+                self.7;
             render_world.insert_resource(additional_vulkan_features);
         }
 
@@ -216,6 +330,7 @@ impl RenderResources {
         render_world.insert_resource(queue);
         render_world.insert_resource(render_adapter);
         render_world.insert_resource(adapter_info);
+        render_world.insert_resource(adapter_report);
     }
 }
 
@@ -229,6 +344,8 @@ pub enum RenderCreation {
 
 impl RenderCreation {
     /// Function to create a [`RenderCreation::Manual`] variant.
+    ///
+    /// The [`RenderAdapterReport`] only lists the adapters of the backend of `adapter_info`.
     pub fn manual(
         device: RenderDevice,
         queue: RenderQueue,
@@ -238,12 +355,15 @@ impl RenderCreation {
         #[cfg(feature = "raw_vulkan_init")]
         additional_vulkan_features: renderer::raw_vulkan_init::AdditionalVulkanFeatures,
     ) -> Self {
+        let backends = Backends::from(adapter_info.backend);
         RenderResources(
             device,
             queue,
             adapter_info,
             adapter,
             instance,
+            backends,
+            None,
             #[cfg(feature = "raw_vulkan_init")]
             additional_vulkan_features,
         )
@@ -333,3 +453,42 @@ pub fn settings_priority_from_env() -> Option<WgpuSettingsPriority> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use wgpu::DeviceType;
+
+    use super::{first_of_device_types, AdapterSelection};
+
+    #[test]
+    fn selects_preferred_device_types() {
+        let available = [
+            DeviceType::Cpu,
+            DeviceType::IntegratedGpu,
+            DeviceType::DiscreteGpu,
+        ];
+
+        assert_eq!(
+            first_of_device_types(&[DeviceType::DiscreteGpu, DeviceType::Cpu], &available),
+            Some(2)
+        );
+        assert_eq!(
+            first_of_device_types(&[DeviceType::VirtualGpu, DeviceType::Cpu], &available),
+            Some(0)
+        );
+        assert_eq!(
+            first_of_device_types(&[DeviceType::DiscreteGpu], &available[..2]),
+            None
+        );
+    }
+
+    #[test]
+    fn falls_back_to_automatic_selection() {
+        assert_eq!(AdapterSelection::Automatic.select(&[]), None);
+        assert_eq!(AdapterSelection::prefer_discrete().select(&[]), None);
+        let out_of_range = AdapterSelection::Custom(Arc::new(|_| Some(0)));
+        assert_eq!(out_of_range.select(&[]), None);
+    }
+}
@@ -1003,6 +1003,74 @@ where
             }
         }
     }
+
+    /// Overwrites part of the data of an allocation that is already on the GPU.
+    ///
+    /// `elements` is the range of the elements to overwrite, from the start of
+    /// the allocation. It's widened to whole slots to satisfy the alignment
+    /// requirements of wgpu, and truncated to the allocation. The given
+    /// `fill_data` callback is passed the widened range and is expected to
+    /// write the data of its elements into the given slice.
+    ///
+    /// Returns false if the key isn't resident on the GPU, in which case nothing
+    /// is written.
+    pub fn update_element_data(
+        &self,
+        key: &I::Key,
+        elements: Range<u32>,
+        fill_data: impl FnOnce(Range<u32>, WriteOnly<[u8]>),
+        render_queue: &RenderQueue,
+    ) -> bool {
+        let Some(slab_id) = self.key_to_slab.get(key) else {
+            return false;
+        };
+        let Some(slice) = self.slab_allocation_slice(key, *slab_id) else {
+            return false;
+        };
+        let element_layout = self.slabs[slab_id].element_layout();
+        let element_size = element_layout.size();
+        let elements_per_slot = element_layout.elements_per_slot();
+
+        let element_count = slice.range.end - slice.range.start;
+        let Range { start, end } =
+            slot_aligned_elements(elements, element_count, elements_per_slot);
+        if start == end {
+            return true;
+        }
+
+        let offset = (slice.range.start + start) as u64 * element_size;
+        let len = (end - start) as u64 * element_size;
+        // Only the last slot of the allocation may be partially filled, in
+        // which case the rest of it is padding. Round up to a whole number of
+        // slots, without going past the end of the buffer.
+        let size = ((end - start).next_multiple_of(elements_per_slot) as u64 * element_size)
+            .min(slice.buffer.size() - offset);
+        if let Some(size) = BufferSize::new(size)
+            && let Some(mut buffer) = render_queue.write_buffer_with(slice.buffer, offset, size)
+        {
+            fill_data(start..end, buffer.slice(..len as usize));
+        }
+        true
+    }
+}
+
+/// Widens the range of `elements` to whole slots of `elements_per_slot`
+/// elements, truncated to the `element_count` elements of an allocation.
+///
+/// Both ends are widened, so that the other elements of the first and last
+/// slots are written with their data too rather than overwritten.
+fn slot_aligned_elements(
+    elements: Range<u32>,
+    element_count: u32,
+    elements_per_slot: u32,
+) -> Range<u32> {
+    let end = elements.end.min(element_count);
+    if elements.start >= end {
+        return end..end;
+    }
+    let start = elements.start / elements_per_slot * elements_per_slot;
+    let end = end.next_multiple_of(elements_per_slot).min(element_count);
+    start..end
 }
 
 /// The results of [`GeneralSlab::grow_if_necessary`].
@@ -1115,3 +1183,27 @@ fn buffer_usages_to_str(buffer_usages: BufferUsages) -> &'static str {
         ""
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::slot_aligned_elements;
+
+    #[test]
+    fn slot_aligned_elements_of_6_byte_vertices() {
+        // Two 6-byte vertices make up a 12-byte slot, so that slots are 4-byte aligned.
+        let elements_per_slot = 2;
+        let element_count = 5;
+        let aligned = |elements| slot_aligned_elements(elements, element_count, elements_per_slot);
+
+        assert_eq!(aligned(0..2), 0..2);
+        // The other vertex of the slot is written with its data.
+        assert_eq!(aligned(1..2), 0..2);
+        assert_eq!(aligned(2..3), 2..4);
+        assert_eq!(aligned(1..4), 0..4);
+        // The last slot is only half used, so the write can't go past the last vertex.
+        assert_eq!(aligned(3..5), 2..5);
+        assert_eq!(aligned(4..9), 4..5);
+        assert!(aligned(3..3).is_empty());
+        assert!(aligned(6..8).is_empty());
+    }
+}
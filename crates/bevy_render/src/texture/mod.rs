@@ -1,14 +1,21 @@
 mod fallback_image;
 mod gpu_image;
 mod manual_texture_view;
+#[cfg(feature = "texture_streaming")]
+mod streaming;
 mod texture_attachment;
 mod texture_cache;
 
 pub use crate::render_resource::DefaultImageSampler;
-use bevy_image::{CompressedImageFormatSupport, CompressedImageFormats, ImageLoader, ImagePlugin};
+use bevy_image::{
+    CompressedImageFormatSupport, CompressedImageFormats, CompressedImageTranscodeTargets,
+    ImageLoader, ImagePlugin,
+};
 pub use fallback_image::*;
 pub use gpu_image::*;
 pub use manual_texture_view::*;
+#[cfg(feature = "texture_streaming")]
+pub use streaming::*;
 pub use texture_attachment::*;
 pub use texture_cache::*;
 
@@ -32,6 +39,8 @@ impl Plugin for TexturePlugin {
             ExtractResourcePlugin::<ManualTextureViews>::default(),
         ))
         .init_resource::<ManualTextureViews>();
+        #[cfg(feature = "texture_streaming")]
+        app.add_plugins(TextureStreamingPlugin);
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
                 .init_resource::<ManualTextureViews>()
@@ -56,7 +65,16 @@ impl Plugin for TexturePlugin {
                 CompressedImageFormats::NONE
             };
 
-            app.register_asset_loader(ImageLoader::new(supported_compressed_formats));
+            let transcode_targets = app
+                .world()
+                .get_resource::<CompressedImageTranscodeTargets>()
+                .cloned()
+                .unwrap_or_default();
+
+            app.register_asset_loader(
+                ImageLoader::new(supported_compressed_formats)
+                    .with_transcode_targets(transcode_targets),
+            );
         }
         let default_sampler = app.get_added_plugins::<ImagePlugin>()[0]
             .default_sampler
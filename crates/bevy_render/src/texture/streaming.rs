@@ -0,0 +1,577 @@
+//! Streaming of the mips of large textures, to render scenes with more textures than fit in
+//! GPU memory.
+//!
+//! Streamed textures are stored in the `.stex` format, with one chunk per mip, by the
+//! [`StreamedTextureSaver`] processor. Once loaded as a [`StreamedTexture`], only the mips
+//! needed to render the entities using the texture, as marked with [`StreamedTextures`], are
+//! uploaded to the GPU, within the [`TextureStreamingBudget`].
+
+use core::ops::Range;
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{
+    io::{Reader, Writer},
+    processor::{AssetProcessor, LoadTransformAndSave},
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+    Asset, AssetApp, AssetId, AssetLoader, AssetPath, Assets, AsyncWriteExt, Handle, LoadContext,
+    RenderAssetUsages,
+};
+use bevy_camera::Camera;
+use bevy_ecs::prelude::*;
+use bevy_image::{Image, ImageLoader, SerializedImage};
+use bevy_math::ops;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::TypePath;
+use bevy_transform::{components::GlobalTransform, TransformSystems};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use wgpu_types::TextureDimension;
+
+/// The first bytes of a `.stex` file.
+const MAGIC: &[u8; 4] = b"BSTX";
+
+/// Adds support for loading and streaming [`StreamedTexture`]s.
+#[derive(Default)]
+pub struct TextureStreamingPlugin;
+
+impl Plugin for TextureStreamingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<StreamedTexture>()
+            .register_asset_loader(StreamedTextureLoader)
+            .init_resource::<TextureStreamingBudget>()
+            .add_systems(
+                PostUpdate,
+                update_texture_residency.after(TransformSystems::Propagate),
+            );
+
+        if let Some(processor) = app.world().get_resource::<AssetProcessor>() {
+            processor.register_processor::<LoadTransformAndSave<
+                ImageLoader,
+                IdentityAssetTransformer<Image>,
+                StreamedTextureSaver,
+            >>(StreamedTextureSaver.into());
+        }
+    }
+}
+
+/// A texture whose mips are uploaded to the GPU on demand.
+///
+/// All the mips are kept in CPU memory, and the [`image`](Self::image) is replaced with the mips
+/// needed by the entities using the texture, from the least detailed mip up to the most detailed
+/// one needed. Use the [`image`](Self::image) in materials, and add [`StreamedTextures`] to the
+/// entities using it.
+#[derive(Asset, TypePath)]
+pub struct StreamedTexture {
+    /// The image holding the resident mips of the texture.
+    ///
+    /// Its size changes as mips are streamed in and out, which doesn't change how it is sampled.
+    pub image: Handle<Image>,
+    /// The image with all the mips, without data.
+    template: Image,
+    /// The data of each mip, from the most detailed one.
+    mips: Vec<Vec<u8>>,
+    /// The number of the least detailed mips which are always resident.
+    min_resident_mips: u32,
+    /// The most detailed mip uploaded to the GPU.
+    resident_mip: u32,
+}
+
+impl StreamedTexture {
+    /// Returns the number of mips of the texture.
+    pub fn mip_level_count(&self) -> u32 {
+        self.mips.len() as u32
+    }
+
+    /// Returns the most detailed mip currently uploaded to the GPU, `0` being the full
+    /// resolution.
+    pub fn resident_mip(&self) -> u32 {
+        self.resident_mip
+    }
+
+    /// Returns the least detailed mip which can be the most detailed resident mip.
+    fn max_resident_mip(&self) -> u32 {
+        self.mip_level_count()
+            .saturating_sub(self.min_resident_mips.max(1))
+    }
+
+    /// Returns the GPU memory used when `mip` is the most detailed resident mip, in bytes.
+    fn resident_bytes(&self, mip: u32) -> u64 {
+        self.mips[mip as usize..]
+            .iter()
+            .map(|mip| mip.len() as u64)
+            .sum()
+    }
+
+    /// Creates the image with the mips from `mip` to the least detailed one.
+    fn resident_image(&self, mip: u32) -> Image {
+        let mut image = self.template.clone();
+        let descriptor = &mut image.texture_descriptor;
+        descriptor.size = self
+            .template
+            .texture_descriptor
+            .mip_level_size(mip)
+            .unwrap();
+        descriptor.mip_level_count = self.mip_level_count() - mip;
+        image.data = Some(self.mips[mip as usize..].concat());
+        image.asset_usage = RenderAssetUsages::RENDER_WORLD;
+        image
+    }
+}
+
+/// The [`StreamedTexture`]s used by an entity, typically by its material.
+///
+/// The mips needed for each texture are chosen from the distance between the entity and the
+/// closest active [`Camera`]: the full resolution mip is needed closer than the
+/// [`full_resolution_distance`](Self::full_resolution_distance), and one less detailed mip each
+/// time the distance doubles.
+#[derive(Component, Clone, Debug)]
+#[require(GlobalTransform)]
+pub struct StreamedTextures {
+    /// The textures used by the entity.
+    pub textures: Vec<Handle<StreamedTexture>>,
+    /// The distance to the cameras under which the full resolution of the textures is needed.
+    pub full_resolution_distance: f32,
+}
+
+impl StreamedTextures {
+    /// Creates the streamed textures of an entity, needing their full resolution closer than
+    /// `full_resolution_distance`.
+    pub fn new(
+        textures: impl IntoIterator<Item = Handle<StreamedTexture>>,
+        full_resolution_distance: f32,
+    ) -> Self {
+        Self {
+            textures: textures.into_iter().collect(),
+            full_resolution_distance,
+        }
+    }
+}
+
+/// The GPU memory the [`StreamedTexture`]s can use.
+///
+/// When the mips needed by all the textures don't fit in the budget, the textures used by the
+/// entities closest to the cameras are streamed in first. The least detailed mips of each
+/// texture are always resident, even over budget.
+#[derive(Resource, Clone, Debug)]
+pub struct TextureStreamingBudget {
+    /// The maximum size of the resident mips, in bytes. Defaults to 512 MiB.
+    pub max_bytes: u64,
+    resident_bytes: u64,
+}
+
+impl Default for TextureStreamingBudget {
+    fn default() -> Self {
+        Self {
+            max_bytes: 512 * 1024 * 1024,
+            resident_bytes: 0,
+        }
+    }
+}
+
+impl TextureStreamingBudget {
+    /// Creates a budget of `max_bytes`.
+    pub fn new(max_bytes: u64) -> Self {
+        Self {
+            max_bytes,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the size of the mips currently resident, in bytes.
+    pub fn resident_bytes(&self) -> u64 {
+        self.resident_bytes
+    }
+}
+
+/// The mips wanted for a texture.
+struct ResidencyRequest {
+    /// The most detailed mip wanted.
+    wanted_mip: u32,
+    /// The least detailed mip which can be the most detailed resident mip.
+    max_mip: u32,
+    /// The lower, the sooner the request is fulfilled.
+    priority: f32,
+}
+
+/// Chooses the most detailed resident mip of each texture, fulfilling the requests by priority
+/// while the resident mips fit in `max_bytes`.
+fn assign_resident_mips(
+    requests: &[ResidencyRequest],
+    resident_bytes: impl Fn(usize, u32) -> u64,
+    max_bytes: u64,
+) -> (Vec<u32>, u64) {
+    let mut mips: Vec<u32> = requests.iter().map(|request| request.max_mip).collect();
+    let mut used: u64 = mips
+        .iter()
+        .enumerate()
+        .map(|(index, mip)| resident_bytes(index, *mip))
+        .sum();
+
+    let mut order: Vec<usize> = (0..requests.len()).collect();
+    order.sort_by(|a, b| requests[*a].priority.total_cmp(&requests[*b].priority));
+    for index in order {
+        let request = &requests[index];
+        let minimum = resident_bytes(index, request.max_mip);
+        // Use the most detailed wanted mip that fits, or keep the minimum.
+        if let Some(mip) = (request.wanted_mip..request.max_mip)
+            .find(|mip| used - minimum + resident_bytes(index, *mip) <= max_bytes)
+        {
+            used = used - minimum + resident_bytes(index, mip);
+            mips[index] = mip;
+        }
+    }
+    (mips, used)
+}
+
+/// Streams the mips of the [`StreamedTexture`]s in and out, from the distance between the
+/// cameras and the entities using them.
+pub fn update_texture_residency(
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    users: Query<(&StreamedTextures, &GlobalTransform)>,
+    mut textures: ResMut<Assets<StreamedTexture>>,
+    mut images: ResMut<Assets<Image>>,
+    mut budget: ResMut<TextureStreamingBudget>,
+) {
+    let camera_positions: Vec<_> = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .map(|(_, transform)| transform.translation())
+        .collect();
+
+    // The distance, relative to the full resolution distance, at which each texture is used.
+    let mut relative_distances = HashMap::<AssetId<StreamedTexture>, f32>::default();
+    for (user, transform) in &users {
+        let position = transform.translation();
+        let Some(distance) = camera_positions
+            .iter()
+            .map(|camera| camera.distance(position))
+            .min_by(f32::total_cmp)
+        else {
+            continue;
+        };
+        let relative_distance = distance / user.full_resolution_distance.max(f32::EPSILON);
+        for texture in &user.textures {
+            relative_distances
+                .entry(texture.id())
+                .and_modify(|closest| *closest = closest.min(relative_distance))
+                .or_insert(relative_distance);
+        }
+    }
+
+    let ids: Vec<_> = textures.ids().collect();
+    let requests: Vec<_> = ids
+        .iter()
+        .map(|id| {
+            let texture = textures.get(*id).unwrap();
+            let max_mip = texture.max_resident_mip();
+            match relative_distances.get(id) {
+                Some(&relative_distance) => ResidencyRequest {
+                    wanted_mip: (ops::log2(relative_distance.max(1.0)) as u32).min(max_mip),
+                    max_mip,
+                    priority: relative_distance,
+                },
+                // Unused textures keep only their least detailed mips.
+                None => ResidencyRequest {
+                    wanted_mip: max_mip,
+                    max_mip,
+                    priority: f32::INFINITY,
+                },
+            }
+        })
+        .collect();
+    let (mips, resident_bytes) = assign_resident_mips(
+        &requests,
+        |index, mip| textures.get(ids[index]).unwrap().resident_bytes(mip),
+        budget.max_bytes,
+    );
+    budget.resident_bytes = resident_bytes;
+
+    for (id, mip) in ids.into_iter().zip(mips) {
+        if textures.get(id).unwrap().resident_mip == mip {
+            continue;
+        }
+        let mut texture = textures.get_mut(id).unwrap();
+        texture.resident_mip = mip;
+        if let Err(err) = images.insert(&texture.image, texture.resident_image(mip)) {
+            bevy_log::warn!("Failed to stream the mips of a texture: {err}");
+        }
+    }
+}
+
+/// Errors that can occur when encoding or decoding a `.stex` file.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum StreamedTextureError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The header of the file couldn't be serialized or deserialized.
+    #[error("invalid streamed texture header: {0}")]
+    Header(String),
+    /// The file isn't a streamed texture, or is truncated.
+    #[error("invalid streamed texture data")]
+    InvalidData,
+    /// The image has no data to stream.
+    #[error("the image has no data")]
+    MissingData,
+    /// Only single layer 2D images with a color format can be streamed.
+    #[error("only single layer 2D images with a color format can be streamed")]
+    UnsupportedImage,
+}
+
+/// The header of a `.stex` file, followed by the data of the mips.
+#[derive(Serialize, Deserialize)]
+struct StreamedTextureHeader {
+    /// The image with all the mips, without data.
+    image: SerializedImage,
+    /// The range of the data of each mip, after the header, from the most detailed mip.
+    mips: Vec<Range<u64>>,
+}
+
+/// Returns the size of the data of each mip of `image`.
+fn mip_sizes(image: &Image) -> Result<Vec<usize>, StreamedTextureError> {
+    let descriptor = &image.texture_descriptor;
+    if descriptor.dimension != TextureDimension::D2 || descriptor.size.depth_or_array_layers != 1 {
+        return Err(StreamedTextureError::UnsupportedImage);
+    }
+    let format = descriptor.format;
+    let block_size = format
+        .block_copy_size(None)
+        .ok_or(StreamedTextureError::UnsupportedImage)? as usize;
+    let (block_width, block_height) = format.block_dimensions();
+    Ok((0..descriptor.mip_level_count)
+        .map(|mip| {
+            let size = descriptor.mip_level_size(mip).unwrap();
+            size.width.div_ceil(block_width) as usize
+                * size.height.div_ceil(block_height) as usize
+                * block_size
+        })
+        .collect())
+}
+
+/// Encodes `image` in the `.stex` format.
+fn encode_streamed_texture(image: &Image) -> Result<Vec<u8>, StreamedTextureError> {
+    let data = image
+        .data
+        .as_deref()
+        .ok_or(StreamedTextureError::MissingData)?;
+    let sizes = mip_sizes(image)?;
+    if sizes.iter().sum::<usize>() != data.len() {
+        return Err(StreamedTextureError::InvalidData);
+    }
+
+    let mut mips = Vec::with_capacity(sizes.len());
+    let mut offset = 0;
+    for size in sizes {
+        mips.push(offset..offset + size as u64);
+        offset += size as u64;
+    }
+    let mut template = image.clone();
+    template.data = None;
+    let header = ron::ser::to_string(&StreamedTextureHeader {
+        image: SerializedImage::from_image(template),
+        mips,
+    })
+    .map_err(|err| StreamedTextureError::Header(err.to_string()))?;
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + data.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    Ok(bytes)
+}
+
+/// Decodes a `.stex` file into the image without data and the data of each mip.
+fn decode_streamed_texture(bytes: &[u8]) -> Result<(Image, Vec<Vec<u8>>), StreamedTextureError> {
+    let rest = bytes
+        .strip_prefix(MAGIC)
+        .ok_or(StreamedTextureError::InvalidData)?;
+    let (header_len, rest) = rest
+        .split_first_chunk::<4>()
+        .ok_or(StreamedTextureError::InvalidData)?;
+    let (header, data) = rest
+        .split_at_checked(u32::from_le_bytes(*header_len) as usize)
+        .ok_or(StreamedTextureError::InvalidData)?;
+    let header: StreamedTextureHeader =
+        ron::de::from_bytes(header).map_err(|err| StreamedTextureError::Header(err.to_string()))?;
+
+    let template = header.image.into_image();
+    let sizes = mip_sizes(&template)?;
+    if sizes.len() != header.mips.len() {
+        return Err(StreamedTextureError::InvalidData);
+    }
+    let mips = header
+        .mips
+        .into_iter()
+        .zip(sizes)
+        .map(|(range, size)| {
+            data.get(range.start as usize..range.end as usize)
+                .filter(|mip| mip.len() == size)
+                .map(<[u8]>::to_vec)
+                .ok_or(StreamedTextureError::InvalidData)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((template, mips))
+}
+
+/// Saves [`Image`]s in the `.stex` format of the [`StreamedTextureLoader`].
+///
+/// The image must be a single layer 2D image, usually with its mips already generated, for
+/// example loaded from a KTX2 or DDS file.
+#[derive(Clone, Default, TypePath)]
+pub struct StreamedTextureSaver;
+
+impl AssetSaver for StreamedTextureSaver {
+    type Asset = Image;
+    type Settings = ();
+    type OutputLoader = StreamedTextureLoader;
+    type Error = StreamedTextureError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, '_, Self::Asset>,
+        _settings: &Self::Settings,
+        _asset_path: AssetPath<'_>,
+    ) -> Result<StreamedTextureLoaderSettings, Self::Error> {
+        let bytes = encode_streamed_texture(&asset)?;
+        writer.write_all(&bytes).await?;
+        Ok(StreamedTextureLoaderSettings::default())
+    }
+}
+
+/// Settings of the [`StreamedTextureLoader`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StreamedTextureLoaderSettings {
+    /// The number of the least detailed mips which are always resident. Defaults to 4, which for
+    /// a square texture is its 8 by 8 pixels mip and smaller ones.
+    pub min_resident_mips: u32,
+}
+
+impl Default for StreamedTextureLoaderSettings {
+    fn default() -> Self {
+        Self {
+            min_resident_mips: 4,
+        }
+    }
+}
+
+/// Loads [`StreamedTexture`]s from `.stex` files, written by the [`StreamedTextureSaver`].
+///
+/// The [`image`](StreamedTexture::image) of the texture is the `image` labeled asset, such as
+/// `textures/terrain.stex#image`, initially holding its least detailed mips.
+#[derive(Clone, Default, TypePath)]
+pub struct StreamedTextureLoader;
+
+impl AssetLoader for StreamedTextureLoader {
+    type Asset = StreamedTexture;
+    type Settings = StreamedTextureLoaderSettings;
+    type Error = StreamedTextureError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<StreamedTexture, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let (template, mips) = decode_streamed_texture(&bytes)?;
+
+        let mut texture = StreamedTexture {
+            image: Handle::default(),
+            template,
+            mips,
+            min_resident_mips: settings.min_resident_mips,
+            resident_mip: 0,
+        };
+        texture.resident_mip = texture.max_resident_mip();
+        texture.image =
+            load_context.add_labeled_asset("image", texture.resident_image(texture.resident_mip));
+        Ok(texture)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["stex"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_image::TextureFormatPixelInfo;
+    use wgpu_types::{Extent3d, TextureFormat};
+
+    fn test_image() -> Image {
+        let mut image = Image::new_fill(
+            Extent3d {
+                width: 8,
+                height: 4,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8Unorm,
+            RenderAssetUsages::default(),
+        );
+        // Mips of 8x4, 4x2, 2x1 and 1x1 pixels.
+        image.texture_descriptor.mip_level_count = 4;
+        let pixel_size = TextureFormat::Rgba8Unorm.pixel_size().unwrap();
+        image.data = Some(
+            [32, 8, 2, 1]
+                .into_iter()
+                .enumerate()
+                .flat_map(|(mip, pixels)| core::iter::repeat_n(mip as u8, pixels * pixel_size))
+                .collect(),
+        );
+        image
+    }
+
+    #[test]
+    fn streamed_textures_are_split_in_mips() {
+        let (template, mips) =
+            decode_streamed_texture(&encode_streamed_texture(&test_image()).unwrap()).unwrap();
+        assert!(template.data.is_none());
+        assert_eq!(
+            mips.iter().map(Vec::len).collect::<Vec<_>>(),
+            [128, 32, 8, 4]
+        );
+        assert!(mips[2].iter().all(|byte| *byte == 2));
+
+        let texture = StreamedTexture {
+            image: Handle::default(),
+            template,
+            mips,
+            min_resident_mips: 2,
+            resident_mip: 0,
+        };
+        let image = texture.resident_image(texture.max_resident_mip());
+        assert_eq!(image.texture_descriptor.size.width, 2);
+        assert_eq!(image.texture_descriptor.mip_level_count, 2);
+        assert_eq!(image.data.unwrap().len(), 12);
+    }
+
+    #[test]
+    fn resident_mips_fit_in_the_budget() {
+        // Each mip is 4 times smaller than the previous one.
+        let resident_bytes = |_, mip: u32| (mip..4).map(|mip| 64 >> (2 * mip)).sum();
+        let request = |wanted_mip, priority| ResidencyRequest {
+            wanted_mip,
+            max_mip: 3,
+            priority,
+        };
+        let requests = [request(0, 2.0), request(0, 1.0), request(3, f32::INFINITY)];
+
+        // The closest texture is streamed in first, the other one as much as it fits.
+        let (mips, used) = assign_resident_mips(&requests, resident_bytes, 100);
+        assert_eq!(mips, [2, 0, 3]);
+        assert_eq!(used, 5 + 85 + 1);
+
+        // The least detailed mips are always resident.
+        let (mips, used) = assign_resident_mips(&requests, resident_bytes, 0);
+        assert_eq!(mips, [3, 3, 3]);
+        assert_eq!(used, 3);
+    }
+}
@@ -6,15 +6,20 @@ use crate::{
     renderer::{RenderAdapter, RenderDevice, RenderInstance},
     Extract, ExtractSchedule, Render, RenderApp, RenderSystems,
 };
-use bevy_app::{App, Plugin};
+use bevy_app::{App, First, Plugin};
 use bevy_ecs::prelude::*;
 use bevy_ecs::system::RunSystemOnce;
 use bevy_log::{debug, info, warn};
 use bevy_utils::default;
 use bevy_window::{
     CompositeAlphaMode, PresentMode, PrimaryWindow, RawHandleWrapper, Window, WindowClosing,
+    WindowPresentModeChanged,
 };
 use core::num::NonZero;
+use std::sync::{
+    mpsc::{Receiver, Sender},
+    Mutex,
+};
 use wgpu::{
     SurfaceConfiguration, SurfaceTargetUnsafe, TextureFormat, TextureUsages, TextureViewDescriptor,
 };
@@ -29,6 +34,11 @@ impl Plugin for WindowRenderPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ScreenshotPlugin);
 
+        let (tx, rx) = std::sync::mpsc::channel();
+        app.add_message::<WindowPresentModeChanged>()
+            .insert_resource(PresentModeChanges(Mutex::new(rx)))
+            .add_systems(First, send_present_mode_changes);
+
         // We need to sync the window entity in the render world
         // We can't use [`SyncComponentPlugin`] because it would introduce `bevy_render` as
         // a dependency to `bevy_window`
@@ -49,6 +59,7 @@ impl Plugin for WindowRenderPlugin {
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app
+                .insert_resource(RenderPresentModeChangesSender(tx))
                 .add_systems(ExtractSchedule, extract_windows.before(extract_cameras))
                 .add_systems(
                     Render,
@@ -78,6 +89,7 @@ pub struct ExtractedWindow {
     pub swap_chain_texture_view_format: Option<TextureFormat>,
     pub size_changed: bool,
     pub present_mode_changed: bool,
+    pub frame_latency_changed: bool,
     pub alpha_mode: CompositeAlphaMode,
     /// Whether this window needs an initial buffer commit.
     ///
@@ -99,6 +111,18 @@ impl ExtractedWindow {
         self.swap_chain_texture = Some(SurfaceTexture::from(frame));
     }
 
+    /// Whether the surface of the window must be reconfigured.
+    fn needs_reconfiguration(&self) -> bool {
+        self.size_changed || self.present_mode_changed || self.frame_latency_changed
+    }
+
+    /// The desired maximum frame latency the surface is configured with.
+    fn frame_latency(&self) -> u32 {
+        self.desired_maximum_frame_latency
+            .map(NonZero::<u32>::get)
+            .unwrap_or(DEFAULT_DESIRED_MAXIMUM_FRAME_LATENCY)
+    }
+
     fn has_swapchain_texture(&self) -> bool {
         self.swap_chain_texture_view.is_some() && self.swap_chain_texture.is_some()
     }
@@ -146,6 +170,7 @@ fn extract_windows(
                     swap_chain_texture_format: None,
                     swap_chain_texture_view_format: None,
                     present_mode_changed: false,
+                    frame_latency_changed: false,
                     alpha_mode: window.composite_alpha_mode,
                     needs_initial_present: true,
                 },
@@ -166,6 +191,8 @@ fn extract_windows(
             || new_height != extracted_window.physical_height;
         extracted_window.present_mode_changed =
             window.present_mode != extracted_window.present_mode;
+        extracted_window.frame_latency_changed =
+            window.desired_maximum_frame_latency != extracted_window.desired_maximum_frame_latency;
 
         if extracted_window.size_changed {
             debug!(
@@ -186,6 +213,15 @@ fn extract_windows(
             );
             extracted_window.present_mode = window.present_mode;
         }
+
+        if extracted_window.frame_latency_changed {
+            debug!(
+                "Window desired maximum frame latency changed from {:?} to {:?}",
+                extracted_window.desired_maximum_frame_latency,
+                window.desired_maximum_frame_latency
+            );
+            extracted_window.desired_maximum_frame_latency = window.desired_maximum_frame_latency;
+        }
     }
 
     for closing_window in closing.read() {
@@ -263,7 +299,7 @@ pub fn prepare_windows(
         };
 
         // We didn't present the previous frame, so we can keep using our existing swapchain texture.
-        if window.has_swapchain_texture() && !window.size_changed && !window.present_mode_changed {
+        if window.has_swapchain_texture() && !window.needs_reconfiguration() {
             continue;
         }
 
@@ -332,7 +368,7 @@ pub fn prepare_windows(
 
 pub fn need_surface_configuration(windows: Query<(&ExtractedWindow, Has<SurfaceData>)>) -> bool {
     for (window, has_surface_data) in &windows {
-        if !has_surface_data || window.size_changed || window.present_mode_changed {
+        if !has_surface_data || window.needs_reconfiguration() {
             return true;
         }
     }
@@ -353,6 +389,7 @@ pub fn create_surfaces(
     #[cfg(any(target_os = "macos", target_os = "ios"))] _marker: bevy_ecs::system::NonSendMarker,
     mut windows: Query<(
         Entity,
+        MainEntity,
         &mut ExtractedWindow,
         &RawHandleWrapper,
         Option<&mut SurfaceData>,
@@ -360,8 +397,9 @@ pub fn create_surfaces(
     render_instance: Res<RenderInstance>,
     render_adapter: Res<RenderAdapter>,
     render_device: Res<RenderDevice>,
+    present_mode_changes: Res<RenderPresentModeChangesSender>,
 ) {
-    for (entity, mut window, handle, mut maybe_surface_data) in &mut windows {
+    for (entity, main_entity, mut window, handle, mut maybe_surface_data) in &mut windows {
         let Some(data) = maybe_surface_data.as_mut() else {
             let surface_target = SurfaceTargetUnsafe::RawHandle {
                 raw_display_handle: Some(handle.get_display_handle()),
@@ -403,10 +441,7 @@ pub fn create_surfaces(
                 height: window.physical_height,
                 usage: TextureUsages::RENDER_ATTACHMENT,
                 present_mode,
-                desired_maximum_frame_latency: window
-                    .desired_maximum_frame_latency
-                    .map(NonZero::<u32>::get)
-                    .unwrap_or(DEFAULT_DESIRED_MAXIMUM_FRAME_LATENCY),
+                desired_maximum_frame_latency: window.frame_latency(),
                 alpha_mode: match window.alpha_mode {
                     CompositeAlphaMode::Auto => wgpu::CompositeAlphaMode::Auto,
                     CompositeAlphaMode::Opaque => wgpu::CompositeAlphaMode::Opaque,
@@ -422,6 +457,7 @@ pub fn create_surfaces(
             };
 
             render_device.configure_surface(&surface, &configuration);
+            present_mode_changes.send(main_entity, &window, &configuration);
 
             commands.entity(entity).insert(SurfaceData {
                 surface: WgpuWrapper::new(surface),
@@ -431,7 +467,7 @@ pub fn create_surfaces(
             continue;
         };
 
-        if window.size_changed || window.present_mode_changed {
+        if window.needs_reconfiguration() {
             // normally this is dropped on present but we double check here to be safe as failure to
             // drop it will cause validation errors in wgpu
             drop(window.swap_chain_texture.take());
@@ -445,11 +481,60 @@ pub fn create_surfaces(
             data.configuration.height = window.physical_height;
             let caps = data.surface.get_capabilities(&render_adapter);
             data.configuration.present_mode = present_mode(&window, &caps);
+            data.configuration.desired_maximum_frame_latency = window.frame_latency();
             render_device.configure_surface(&data.surface, &data.configuration);
+            if window.present_mode_changed || window.frame_latency_changed {
+                present_mode_changes.send(main_entity, &window, &data.configuration);
+            }
         }
     }
 }
 
+/// Receives the [`WindowPresentModeChanged`] messages sent by the render world.
+#[derive(Resource)]
+struct PresentModeChanges(Mutex<Receiver<WindowPresentModeChanged>>);
+
+/// Sends a [`WindowPresentModeChanged`] message to the main world whenever [`create_surfaces`]
+/// configures a surface with a new present mode or frame latency.
+#[derive(Resource)]
+pub struct RenderPresentModeChangesSender(Sender<WindowPresentModeChanged>);
+
+impl RenderPresentModeChangesSender {
+    fn send(
+        &self,
+        window: Entity,
+        extracted: &ExtractedWindow,
+        configuration: &SurfaceConfiguration,
+    ) {
+        let present_mode = match configuration.present_mode {
+            wgpu::PresentMode::Fifo => PresentMode::Fifo,
+            wgpu::PresentMode::FifoRelaxed => PresentMode::FifoRelaxed,
+            wgpu::PresentMode::Mailbox => PresentMode::Mailbox,
+            wgpu::PresentMode::Immediate => PresentMode::Immediate,
+            wgpu::PresentMode::AutoVsync => PresentMode::AutoVsync,
+            wgpu::PresentMode::AutoNoVsync => PresentMode::AutoNoVsync,
+        };
+        // The receiver is only dropped when the app exits.
+        let _ = self.0.send(WindowPresentModeChanged {
+            window,
+            requested_present_mode: extracted.present_mode,
+            present_mode,
+            desired_maximum_frame_latency: configuration.desired_maximum_frame_latency,
+        });
+    }
+}
+
+/// Writes the [`WindowPresentModeChanged`] messages sent by the render world.
+fn send_present_mode_changes(
+    changes: Res<PresentModeChanges>,
+    mut messages: MessageWriter<WindowPresentModeChanged>,
+) {
+    let Ok(receiver) = changes.0.lock() else {
+        return;
+    };
+    messages.write_batch(receiver.try_iter());
+}
+
 fn present_mode(window: &ExtractedWindow, caps: &wgpu::SurfaceCapabilities) -> wgpu::PresentMode {
     let present_mode = match window.present_mode {
         PresentMode::Fifo => wgpu::PresentMode::Fifo,
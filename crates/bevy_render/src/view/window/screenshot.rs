@@ -134,6 +134,12 @@ struct RenderScreenshotsPrepared(EntityHashMap<ScreenshotPreparedState>);
 struct RenderScreenshotsSender(Sender<(Entity, Image)>);
 
 /// Saves the captured screenshot to disk at the provided path.
+///
+/// The format of the file is chosen from the extension of the path. Screenshots saved as `.exr` or
+/// `.hdr` files keep the full range of float render targets, such as an [`Image`] created with
+/// [`TextureFormat::Rgba16Float`] and rendered to by a camera with the `Hdr` component and no
+/// tonemapping, which is useful for baking tools and lookdev. Saving them requires the `exr` or
+/// `hdr` cargo feature.
 pub fn save_to_disk(path: impl AsRef<Path>) -> impl FnMut(On<ScreenshotCaptured>) {
     let path = path.as_ref().to_owned();
     move |screenshot_captured| {
@@ -143,7 +149,14 @@ pub fn save_to_disk(path: impl AsRef<Path>) -> impl FnMut(On<ScreenshotCaptured>
                 Ok(format) => {
                     // discard the alpha channel which stores brightness values when HDR is enabled to make sure
                     // the screenshot looks right
-                    let img = dyn_img.to_rgb8();
+                    let img = match format {
+                        // EXR and HDR files store float colors, so the colors of float render
+                        // targets outside of the [0, 1] range are kept.
+                        image::ImageFormat::OpenExr | image::ImageFormat::Hdr => {
+                            image::DynamicImage::ImageRgb32F(dyn_img.to_rgb32f())
+                        }
+                        _ => image::DynamicImage::ImageRgb8(dyn_img.to_rgb8()),
+                    };
                     #[cfg(not(target_arch = "wasm32"))]
                     match img.save_with_format(&path, format) {
                         Ok(_) => info!("Screenshot saved to {}", path.display()),
@@ -0,0 +1,640 @@
+//! Rendering-side foundations for XR headsets.
+//!
+//! Bevy doesn't talk to any XR runtime itself. Instead, integration plugins (for OpenXR, `WebXR`,
+//! ...) implement [`XrSessionBackend`] and insert an [`XrSession`], and the [`XrPlugin`] takes
+//! care of the rest of the frame loop:
+//!
+//! - each frame, the session provides the predicted pose and field of view of every view, along
+//!   with the swapchain image to render them to,
+//! - the cameras marked with [`XrView`] are moved to the pose of their view and given an
+//!   [`XrProjection`] matching its field of view, so the regular view uniforms are used as is,
+//! - the swapchain image is exposed to the cameras through [`ManualTextureViews`], either one
+//!   array layer per view or side by side, depending on the [`XrTargetLayout`],
+//! - once the frame has been rendered, the session is asked to submit it.
+//!
+//! Each view is rendered by its own camera, one after the other. This is not multiview
+//! rendering, where a single render pass draws every view.
+//!
+//! The frames are begun in the main world and ended in the render world, with the
+//! [`XrFrameState`] extracted along with the cameras. With pipelined rendering, the main world
+//! waits for the previous frame to be ended before beginning the next one, so the backend always
+//! sees the frames begun and ended in pairs.
+//!
+//! The eye cameras are usually children of an entity representing the origin of the tracking
+//! space, since the poses are expressed relative to it.
+
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use bevy_app::{App, Last, Plugin, PostUpdate};
+use bevy_camera::{
+    Camera, CameraProjection, CameraUpdateSystems, ManualTextureViewHandle, Projection,
+    RenderTarget, SubCameraView, Viewport,
+};
+use bevy_ecs::prelude::*;
+use bevy_math::{ops, Isometry3d, Mat4, UVec2, Vec3A, Vec4};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render_macros::ExtractResource;
+use bevy_transform::{components::Transform, TransformSystems};
+use wgpu::{TextureFormat, TextureViewDescriptor, TextureViewDimension};
+
+use crate::{
+    extract_resource::ExtractResourcePlugin,
+    render_resource::Texture,
+    texture::{ManualTextureView, ManualTextureViews},
+    Render, RenderApp, RenderSystems,
+};
+
+/// The first [`ManualTextureViewHandle`] used for the views of the [`XrSession`].
+///
+/// The handles from this value to this value plus the number of views are reserved.
+pub const XR_TEXTURE_VIEW_HANDLE_BASE: u32 = 0x5852_0000;
+
+/// Drives an [`XrSession`] and moves the [`XrView`] cameras to the views it provides.
+#[derive(Default)]
+pub struct XrPlugin;
+
+impl Plugin for XrPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ExtractResourcePlugin::<XrSession>::default(),
+            ExtractResourcePlugin::<XrFrameState>::default(),
+        ))
+        .init_resource::<XrFrameState>()
+        .configure_sets(
+            PostUpdate,
+            XrSystems
+                .before(TransformSystems::Propagate)
+                .before(CameraUpdateSystems),
+        )
+        .add_systems(
+            PostUpdate,
+            begin_xr_frame
+                .in_set(XrSystems)
+                .run_if(resource_exists::<XrSession>),
+        );
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            // Without rendering, the frames are ended right away so the next ones can begin.
+            app.add_systems(Last, end_xr_frame.run_if(resource_exists::<XrSession>));
+            return;
+        };
+        render_app.add_systems(
+            Render,
+            end_xr_frame
+                .in_set(RenderSystems::Cleanup)
+                .run_if(resource_exists::<XrSession>),
+        );
+    }
+}
+
+/// The system set in which the XR frame begins and the [`XrView`] cameras are updated, in
+/// [`PostUpdate`], before the transforms are propagated.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XrSystems;
+
+/// The pose and field of view of a view, as provided by the XR runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub struct XrViewState {
+    /// The pose of the view, relative to the origin of the tracking space.
+    pub pose: Isometry3d,
+    /// The field of view of the view.
+    pub fov: XrFov,
+}
+
+/// An asymmetric field of view, as the angles in radians between the forward direction and each
+/// side of the view.
+///
+/// Like in OpenXR, `angle_left` and `angle_down` are usually negative.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub struct XrFov {
+    /// The angle of the left side of the view.
+    pub angle_left: f32,
+    /// The angle of the right side of the view.
+    pub angle_right: f32,
+    /// The angle of the top side of the view.
+    pub angle_up: f32,
+    /// The angle of the bottom side of the view.
+    pub angle_down: f32,
+}
+
+impl XrFov {
+    /// A symmetric field of view, with the vertical angle `fov_y` and the horizontal angle derived
+    /// from `aspect_ratio`.
+    pub fn symmetric(fov_y: f32, aspect_ratio: f32) -> Self {
+        let half_height = ops::tan(fov_y / 2.0);
+        let half_width = half_height * aspect_ratio;
+        Self {
+            angle_left: -ops::atan(half_width),
+            angle_right: ops::atan(half_width),
+            angle_up: fov_y / 2.0,
+            angle_down: -fov_y / 2.0,
+        }
+    }
+
+    /// Returns the tangents of the left, right, bottom and top angles.
+    fn tangents(&self) -> [f32; 4] {
+        [
+            ops::tan(self.angle_left),
+            ops::tan(self.angle_right),
+            ops::tan(self.angle_down),
+            ops::tan(self.angle_up),
+        ]
+    }
+}
+
+impl Default for XrFov {
+    fn default() -> Self {
+        Self::symmetric(core::f32::consts::FRAC_PI_2, 1.0)
+    }
+}
+
+/// A perspective projection with the asymmetric field of view of an XR view.
+///
+/// Like [`PerspectiveProjection`](bevy_camera::PerspectiveProjection), this is an infinite
+/// reverse-z projection; the `far` plane is only used for culling and shadow cascades.
+#[derive(Clone, Copy, Debug, Reflect)]
+#[reflect(Clone, Default, Debug)]
+pub struct XrProjection {
+    /// The field of view, updated each frame from the [`XrSession`].
+    pub fov: XrFov,
+    /// The distance from the camera to the near clipping plane.
+    pub near: f32,
+    /// The distance from the camera to the far plane.
+    pub far: f32,
+}
+
+impl Default for XrProjection {
+    fn default() -> Self {
+        Self {
+            fov: XrFov::default(),
+            near: 0.1,
+            far: 1000.0,
+        }
+    }
+}
+
+impl XrProjection {
+    fn clip_from_view_from_tangents(&self, left: f32, right: f32, bottom: f32, top: f32) -> Mat4 {
+        Mat4::from_cols(
+            Vec4::new(2.0 / (right - left), 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 2.0 / (top - bottom), 0.0, 0.0),
+            Vec4::new(
+                (right + left) / (right - left),
+                (top + bottom) / (top - bottom),
+                0.0,
+                -1.0,
+            ),
+            Vec4::new(0.0, 0.0, self.near, 0.0),
+        )
+    }
+}
+
+impl CameraProjection for XrProjection {
+    fn get_clip_from_view(&self) -> Mat4 {
+        let [left, right, bottom, top] = self.fov.tangents();
+        self.clip_from_view_from_tangents(left, right, bottom, top)
+    }
+
+    fn get_clip_from_view_for_sub(&self, sub_view: &SubCameraView) -> Mat4 {
+        let [left, right, bottom, top] = self.fov.tangents();
+        let full_size = sub_view.full_size.as_vec2();
+        let size = sub_view.size.as_vec2();
+        // Y-axis increases from top to bottom
+        let offset_y = full_size.y - (sub_view.offset.y + size.y);
+
+        let width = right - left;
+        let height = top - bottom;
+        self.clip_from_view_from_tangents(
+            left + width * sub_view.offset.x / full_size.x,
+            left + width * (sub_view.offset.x + size.x) / full_size.x,
+            bottom + height * offset_y / full_size.y,
+            bottom + height * (offset_y + size.y) / full_size.y,
+        )
+    }
+
+    fn update(&mut self, _width: f32, _height: f32) {
+        // The field of view comes from the XR runtime and doesn't depend on the target size.
+    }
+
+    fn far(&self) -> f32 {
+        self.far
+    }
+
+    fn get_frustum_corners(&self, z_near: f32, z_far: f32) -> [Vec3A; 8] {
+        let [left, right, bottom, top] = self.fov.tangents();
+        let (a, b) = (z_near.abs(), z_far.abs());
+        // NOTE: These vertices are in the specific order required by [`calculate_cascade`].
+        [
+            Vec3A::new(right * a, bottom * a, z_near), // bottom right
+            Vec3A::new(right * a, top * a, z_near),    // top right
+            Vec3A::new(left * a, top * a, z_near),     // top left
+            Vec3A::new(left * a, bottom * a, z_near),  // bottom left
+            Vec3A::new(right * b, bottom * b, z_far),  // bottom right
+            Vec3A::new(right * b, top * b, z_far),     // top right
+            Vec3A::new(left * b, top * b, z_far),      // top left
+            Vec3A::new(left * b, bottom * b, z_far),   // bottom left
+        ]
+    }
+}
+
+/// How the views are laid out in the swapchain image of an [`XrFrame`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub enum XrTargetLayout {
+    /// Each view is rendered by its own camera to its own array layer, like the array swapchains
+    /// of OpenXR.
+    #[default]
+    LayerPerView,
+    /// The views are laid out from left to right in a single layer, like the `WebXR` framebuffer.
+    SideBySide,
+}
+
+/// The image the views of an [`XrFrame`] render to.
+#[derive(Clone, Debug)]
+pub struct XrSwapchainImage {
+    /// The texture, usually a swapchain image acquired from the XR runtime.
+    pub texture: Texture,
+    /// The size of a single view, in physical pixels.
+    pub view_size: UVec2,
+    /// The format of the texture.
+    pub format: TextureFormat,
+    /// How the views are laid out in the texture.
+    pub layout: XrTargetLayout,
+}
+
+/// A frame begun by an [`XrSessionBackend`].
+#[derive(Clone, Debug)]
+pub struct XrFrame {
+    /// The predicted pose and field of view of each view for this frame.
+    pub views: Vec<XrViewState>,
+    /// The image to render the views to, or `None` if the runtime asked not to render this frame.
+    pub swapchain_image: Option<XrSwapchainImage>,
+}
+
+/// The interface between Bevy and an XR runtime, implemented by the integration plugins.
+pub trait XrSessionBackend: Send + 'static {
+    /// Waits for the runtime, then begins a new frame.
+    ///
+    /// Returns `None` if no frame could be begun, for example because the session isn't running,
+    /// in which case [`XrSessionBackend::end_frame`] won't be called for this frame.
+    fn begin_frame(&mut self) -> Option<XrFrame>;
+
+    /// Submits the frame last begun once it has been rendered, given the views it was rendered
+    /// with, which are empty if nothing was rendered.
+    fn end_frame(&mut self, views: &[XrViewState]);
+}
+
+/// The XR session, shared between the main world and the render world.
+///
+/// Insert it with [`XrSession::new`] to start rendering to the headset, and remove it to stop.
+#[derive(Resource, Clone, ExtractResource)]
+#[extract_app(RenderApp)]
+pub struct XrSession(Arc<XrSessionShared>);
+
+struct XrSessionShared {
+    backend: Mutex<Box<dyn XrSessionBackend>>,
+    /// Whether a frame was begun and is not ended yet.
+    frame_in_flight: Mutex<bool>,
+    frame_ended: Condvar,
+}
+
+impl XrSession {
+    /// Creates a session driven by the `backend`.
+    pub fn new(backend: impl XrSessionBackend) -> Self {
+        Self(Arc::new(XrSessionShared {
+            backend: Mutex::new(Box::new(backend)),
+            frame_in_flight: Mutex::new(false),
+            frame_ended: Condvar::new(),
+        }))
+    }
+
+    /// Locks the backend of the session.
+    pub fn lock(&self) -> MutexGuard<'_, Box<dyn XrSessionBackend>> {
+        self.0
+            .backend
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Begins a new frame, once the previous one has been ended.
+    fn begin_frame(&self) -> Option<XrFrame> {
+        let mut frame_in_flight = self
+            .0
+            .frame_ended
+            .wait_while(
+                self.0
+                    .frame_in_flight
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner),
+                |frame_in_flight| *frame_in_flight,
+            )
+            .unwrap_or_else(PoisonError::into_inner);
+        let frame = self.lock().begin_frame();
+        *frame_in_flight = frame.is_some();
+        frame
+    }
+
+    /// Ends the frame in flight, allowing the next one to begin.
+    fn end_frame(&self, views: &[XrViewState]) {
+        let mut frame_in_flight = self
+            .0
+            .frame_in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if *frame_in_flight {
+            self.lock().end_frame(views);
+            *frame_in_flight = false;
+            self.0.frame_ended.notify_all();
+        }
+    }
+}
+
+/// The state of the XR frame in progress, extracted to the render world to end the frame begun
+/// in the main world.
+#[derive(Resource, Clone, Default, Debug, ExtractResource)]
+#[extract_app(RenderApp)]
+pub struct XrFrameState {
+    /// Whether a frame was begun, and must be ended once rendered.
+    pub begun: bool,
+    /// The views rendered this frame, empty if the runtime asked not to render.
+    pub views: Vec<XrViewState>,
+}
+
+/// Marks a camera as rendering one of the views of the [`XrSession`].
+///
+/// The [`Transform`], [`Projection`], [`RenderTarget`] and viewport of the camera are managed by
+/// the [`XrPlugin`], and the camera is only active while the session renders.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Clone, Debug)]
+#[require(Camera, Projection)]
+pub struct XrView {
+    /// The index of the view in the [`XrFrame`], usually 0 for the left eye and 1 for the right.
+    pub index: u32,
+}
+
+impl XrView {
+    /// Returns the [`ManualTextureViewHandle`] of the texture view the view with the `index`
+    /// renders to, for the given `layout`.
+    pub const fn texture_view_handle(
+        index: u32,
+        layout: XrTargetLayout,
+    ) -> ManualTextureViewHandle {
+        match layout {
+            XrTargetLayout::LayerPerView => {
+                ManualTextureViewHandle(XR_TEXTURE_VIEW_HANDLE_BASE + index)
+            }
+            XrTargetLayout::SideBySide => ManualTextureViewHandle(XR_TEXTURE_VIEW_HANDLE_BASE),
+        }
+    }
+}
+
+/// Begins the XR frame, exposes its swapchain image as [`ManualTextureViews`] and updates the
+/// [`XrView`] cameras.
+pub fn begin_xr_frame(
+    session: Res<XrSession>,
+    mut frame_state: ResMut<XrFrameState>,
+    mut manual_texture_views: ResMut<ManualTextureViews>,
+    mut views: Query<(
+        &XrView,
+        &mut Camera,
+        &mut RenderTarget,
+        &mut Transform,
+        &mut Projection,
+    )>,
+) {
+    let frame = session.begin_frame();
+    frame_state.begun = frame.is_some();
+    frame_state.views.clear();
+
+    let image = frame
+        .as_ref()
+        .and_then(|frame| frame.swapchain_image.as_ref());
+    if let (Some(frame), Some(image)) = (&frame, image) {
+        frame_state.views.clone_from(&frame.views);
+        match image.layout {
+            XrTargetLayout::LayerPerView => {
+                for index in 0..frame.views.len() as u32 {
+                    let texture_view = image.texture.create_view(&TextureViewDescriptor {
+                        label: Some("xr_view_texture_view"),
+                        dimension: Some(TextureViewDimension::D2),
+                        base_array_layer: index,
+                        array_layer_count: Some(1),
+                        ..Default::default()
+                    });
+                    manual_texture_views.insert(
+                        XrView::texture_view_handle(index, image.layout),
+                        ManualTextureView {
+                            texture_view,
+                            size: image.view_size,
+                            view_format: image.format,
+                        },
+                    );
+                }
+            }
+            XrTargetLayout::SideBySide => {
+                let texture_view = image.texture.create_view(&TextureViewDescriptor {
+                    label: Some("xr_views_texture_view"),
+                    ..Default::default()
+                });
+                manual_texture_views.insert(
+                    XrView::texture_view_handle(0, image.layout),
+                    ManualTextureView {
+                        texture_view,
+                        size: image.view_size * UVec2::new(frame.views.len() as u32, 1),
+                        view_format: image.format,
+                    },
+                );
+            }
+        }
+    }
+
+    for (xr_view, mut camera, mut target, mut transform, mut projection) in &mut views {
+        let Some(state) = frame
+            .as_ref()
+            .and_then(|frame| frame.views.get(xr_view.index as usize))
+        else {
+            if camera.is_active {
+                camera.is_active = false;
+            }
+            continue;
+        };
+
+        // The poses are tracked even when not rendering, for the sake of gameplay.
+        *transform = Transform::from_isometry(state.pose);
+        match &mut *projection {
+            Projection::Custom(custom) if custom.get::<XrProjection>().is_some() => {
+                if let Some(xr_projection) = custom.get_mut::<XrProjection>() {
+                    xr_projection.fov = state.fov;
+                }
+            }
+            _ => {
+                *projection = Projection::custom(XrProjection {
+                    fov: state.fov,
+                    ..Default::default()
+                });
+            }
+        }
+
+        if camera.is_active != image.is_some() {
+            camera.is_active = image.is_some();
+        }
+        let Some(image) = image else {
+            continue;
+        };
+
+        let handle = XrView::texture_view_handle(xr_view.index, image.layout);
+        if !matches!(*target, RenderTarget::TextureView(current) if current == handle) {
+            *target = RenderTarget::TextureView(handle);
+        }
+
+        let viewport = match image.layout {
+            XrTargetLayout::LayerPerView => None,
+            XrTargetLayout::SideBySide => Some(Viewport {
+                physical_position: UVec2::new(xr_view.index * image.view_size.x, 0),
+                physical_size: image.view_size,
+                ..Default::default()
+            }),
+        };
+        let viewport_changed = match (&camera.viewport, &viewport) {
+            (None, None) => false,
+            (Some(current), Some(viewport)) => {
+                current.physical_position != viewport.physical_position
+                    || current.physical_size != viewport.physical_size
+            }
+            _ => true,
+        };
+        if viewport_changed {
+            camera.viewport = viewport;
+        }
+    }
+}
+
+/// Submits the XR frame once it has been rendered.
+pub fn end_xr_frame(session: Res<XrSession>, frame_state: Option<Res<XrFrameState>>) {
+    let Some(frame_state) = frame_state else {
+        return;
+    };
+    if frame_state.begun {
+        session.end_frame(&frame_state.views);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use bevy_camera::{Camera, CameraProjection, PerspectiveProjection, Projection};
+    use bevy_ecs::{system::RunSystemOnce, world::World};
+    use bevy_math::{Isometry3d, Quat, Vec3};
+    use bevy_transform::components::Transform;
+
+    use super::{
+        begin_xr_frame, XrFov, XrFrame, XrFrameState, XrProjection, XrSession, XrSessionBackend,
+        XrView, XrViewState,
+    };
+    use crate::texture::ManualTextureViews;
+
+    #[test]
+    fn symmetric_projection_matches_perspective() {
+        let fov = core::f32::consts::FRAC_PI_3;
+        let xr_projection = XrProjection {
+            fov: XrFov::symmetric(fov, 1.5),
+            ..Default::default()
+        };
+        let perspective = PerspectiveProjection {
+            fov,
+            aspect_ratio: 1.5,
+            near: xr_projection.near,
+            ..Default::default()
+        };
+
+        assert!(xr_projection
+            .get_clip_from_view()
+            .abs_diff_eq(perspective.get_clip_from_view(), 1e-5));
+    }
+
+    struct TrackingOnlyBackend;
+
+    impl XrSessionBackend for TrackingOnlyBackend {
+        fn begin_frame(&mut self) -> Option<XrFrame> {
+            Some(XrFrame {
+                views: vec![XrViewState {
+                    pose: Isometry3d::new(Vec3::new(-0.03, 1.6, 0.0), Quat::IDENTITY),
+                    fov: XrFov::default(),
+                }],
+                swapchain_image: None,
+            })
+        }
+
+        fn end_frame(&mut self, _views: &[XrViewState]) {}
+    }
+
+    #[test]
+    fn views_track_poses_without_rendering() {
+        let mut world = World::new();
+        world.insert_resource(XrSession::new(TrackingOnlyBackend));
+        world.init_resource::<XrFrameState>();
+        world.init_resource::<ManualTextureViews>();
+        let left = world.spawn(XrView { index: 0 }).id();
+        let right = world.spawn(XrView { index: 1 }).id();
+
+        world.run_system_once(begin_xr_frame).unwrap();
+
+        let frame_state = world.resource::<XrFrameState>();
+        assert!(frame_state.begun);
+        assert!(frame_state.views.is_empty());
+        assert_eq!(
+            world.get::<Transform>(left).unwrap().translation,
+            Vec3::new(-0.03, 1.6, 0.0)
+        );
+        assert!(matches!(
+            world.get::<Projection>(left),
+            Some(Projection::Custom(custom)) if custom.get::<XrProjection>().is_some()
+        ));
+        assert!(!world.get::<Camera>(left).unwrap().is_active);
+        assert!(!world.get::<Camera>(right).unwrap().is_active);
+    }
+
+    #[derive(Default)]
+    struct CountingBackend {
+        begun: usize,
+        ended: usize,
+    }
+
+    impl XrSessionBackend for CountingBackend {
+        fn begin_frame(&mut self) -> Option<XrFrame> {
+            assert_eq!(
+                self.begun, self.ended,
+                "a frame was begun before the previous ended"
+            );
+            self.begun += 1;
+            Some(XrFrame {
+                views: Vec::new(),
+                swapchain_image: None,
+            })
+        }
+
+        fn end_frame(&mut self, _views: &[XrViewState]) {
+            self.ended += 1;
+        }
+    }
+
+    #[test]
+    fn next_frame_begins_after_previous_ends() {
+        let session = XrSession::new(CountingBackend::default());
+        assert!(session.begin_frame().is_some());
+
+        let render_session = session.clone();
+        let render_thread = std::thread::spawn(move || {
+            std::thread::sleep(core::time::Duration::from_millis(10));
+            render_session.end_frame(&[]);
+        });
+        // Blocks until the render thread ends the first frame.
+        assert!(session.begin_frame().is_some());
+        render_thread.join().unwrap();
+        session.end_frame(&[]);
+    }
+}
@@ -24,12 +24,15 @@ use bevy_reflect::{
     TypeRegistry,
 };
 
+mod persistent;
 #[cfg(not(target_arch = "wasm32"))]
 mod store_fs;
 
 #[cfg(target_arch = "wasm32")]
 mod store_wasm;
 
+pub use persistent::*;
+
 use bevy_time::{Time, Timer, TimerMode};
 use serde::de::DeserializeSeed;
 #[cfg(not(target_arch = "wasm32"))]
@@ -107,6 +110,10 @@ use store_wasm::SettingsStore;
 /// Saving is crash-resistant: if the app crashes in the middle of a save, the settings file
 /// will not be corrupted (it writes to a temporary file first, then uses atomic operations to
 /// replace the previous file).
+///
+/// Settings which aren't reflected, or should be saved to their own RON or TOML file with
+/// automatic saves and schema migrations, can instead implement [`PersistentSettings`] and be
+/// added with a [`PersistentSettingsPlugin`], after this plugin.
 pub struct SettingsPlugin {
     /// The unique name of the application.
     pub app_name: String,
@@ -0,0 +1,484 @@
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+use bevy_app::{App, AppExit, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::{DetectChanges, Tick},
+    message::MessageReader,
+    resource::Resource,
+    system::{Res, ResMut, SystemChangeTick},
+};
+use bevy_log::{error, warn};
+use bevy_time::{Time, Timer, TimerMode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{SettingsFileRegistry, SettingsStore};
+
+/// The format of the file a [`Persistent`] resource is saved to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PersistentFormat {
+    /// [TOML](https://toml.io), saved with the `toml` extension. The settings must serialize to
+    /// a table, like structs do.
+    #[default]
+    Toml,
+    /// [RON](https://github.com/ron-rs/ron), saved with the `ron` extension.
+    Ron,
+}
+
+impl PersistentFormat {
+    /// Returns the extension of the files in this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            PersistentFormat::Toml => "toml",
+            PersistentFormat::Ron => "ron",
+        }
+    }
+}
+
+/// Settings saved to their own file, through serde rather than reflection, by a [`Persistent`]
+/// resource.
+///
+/// The file is written to the settings directory of the [`SettingsPlugin`](crate::SettingsPlugin),
+/// or to local storage on `wasm32`, along with the [`VERSION`](Self::VERSION) of the settings.
+/// When loading a file saved with an older version, the settings are converted by
+/// [`migrate`](Self::migrate).
+///
+/// ```
+/// # use bevy_settings::{PersistentSettings, PersistentFormat};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct GraphicsSettings {
+///     resolution: (u32, u32),
+///     vsync: bool,
+/// }
+///
+/// impl PersistentSettings for GraphicsSettings {
+///     const FILE: &'static str = "graphics";
+///     const FORMAT: PersistentFormat = PersistentFormat::Ron;
+/// }
+/// ```
+pub trait PersistentSettings:
+    Serialize + DeserializeOwned + Default + Send + Sync + 'static
+{
+    /// The name of the file the settings are saved to, without the extension.
+    ///
+    /// This must be different from the files of the other settings, including those of the
+    /// [`SettingsGroup`](crate::SettingsGroup)s.
+    const FILE: &'static str;
+
+    /// The format of the file.
+    const FORMAT: PersistentFormat = PersistentFormat::Toml;
+
+    /// The version of the schema of the settings, saved alongside them.
+    ///
+    /// Increment it when a change to the type prevents the settings saved by previous versions
+    /// of the app from being deserialized, and convert them in [`migrate`](Self::migrate).
+    const VERSION: u32 = 0;
+
+    /// Converts the settings saved with an older `version` of the schema.
+    ///
+    /// The stored settings are usually deserialized with [`StoredSettings::deserialize`] into
+    /// a copy of the type as it was at that version, then converted. By default, no version is
+    /// supported and the default settings are used instead.
+    fn migrate(version: u32, stored: StoredSettings) -> Result<Self, PersistentSettingsError> {
+        let _ = stored;
+        Err(PersistentSettingsError::UnsupportedVersion(version))
+    }
+}
+
+/// The settings saved with an older version of a [`PersistentSettings`] schema, passed to
+/// [`PersistentSettings::migrate`].
+#[derive(Debug, Clone)]
+pub enum StoredSettings {
+    /// Settings read from a [`PersistentFormat::Toml`] file.
+    Toml(toml::Value),
+    /// Settings read from a [`PersistentFormat::Ron`] file.
+    Ron(ron::Value),
+}
+
+impl StoredSettings {
+    /// Deserializes the stored settings into `T`.
+    pub fn deserialize<T: DeserializeOwned>(self) -> Result<T, PersistentSettingsError> {
+        match self {
+            StoredSettings::Toml(value) => Ok(T::deserialize(value)?),
+            StoredSettings::Ron(value) => Ok(value.into_rust()?),
+        }
+    }
+}
+
+/// An error when loading or saving [`PersistentSettings`].
+#[derive(Error, Debug)]
+pub enum PersistentSettingsError {
+    /// The TOML file could not be parsed.
+    #[error("could not parse the settings: {0}")]
+    TomlDeserialize(#[from] toml::de::Error),
+    /// The settings could not be serialized to TOML.
+    #[error("could not serialize the settings: {0}")]
+    TomlSerialize(#[from] toml::ser::Error),
+    /// The RON file could not be parsed.
+    #[error("could not parse the settings: {0}")]
+    RonDeserialize(#[from] ron::error::SpannedError),
+    /// The settings could not be serialized or deserialized with RON.
+    #[error("could not (de)serialize the settings: {0}")]
+    Ron(#[from] ron::Error),
+    /// The settings were saved with a version of the schema [`PersistentSettings::migrate`]
+    /// doesn't support.
+    #[error("settings saved with version {0} of the schema are not supported")]
+    UnsupportedVersion(u32),
+}
+
+/// The contents of the file of [`PersistentSettings`].
+#[derive(Serialize, Deserialize)]
+struct PersistentFile<T> {
+    #[serde(default)]
+    version: u32,
+    settings: T,
+}
+
+/// The version of [`PersistentFile`], read before the settings to choose how to deserialize them.
+#[derive(Deserialize)]
+struct PersistentFileVersion {
+    #[serde(default)]
+    version: u32,
+}
+
+/// Serializes the `settings` with their version.
+fn encode<T: PersistentSettings>(settings: &T) -> Result<String, PersistentSettingsError> {
+    let file = PersistentFile {
+        version: T::VERSION,
+        settings,
+    };
+    Ok(match T::FORMAT {
+        PersistentFormat::Toml => toml::to_string_pretty(&file)?,
+        PersistentFormat::Ron => ron::ser::to_string_pretty(&file, Default::default())?,
+    })
+}
+
+/// Deserializes settings, migrating them if they were saved with an older version. Returns
+/// whether they were migrated.
+fn decode<T: PersistentSettings>(contents: &str) -> Result<(T, bool), PersistentSettingsError> {
+    fn parse<U: DeserializeOwned>(
+        format: PersistentFormat,
+        contents: &str,
+    ) -> Result<U, PersistentSettingsError> {
+        Ok(match format {
+            PersistentFormat::Toml => toml::from_str(contents)?,
+            PersistentFormat::Ron => ron::from_str(contents)?,
+        })
+    }
+
+    let version = parse::<PersistentFileVersion>(T::FORMAT, contents)?.version;
+    if version == T::VERSION {
+        return Ok((
+            parse::<PersistentFile<T>>(T::FORMAT, contents)?.settings,
+            false,
+        ));
+    }
+    if version > T::VERSION {
+        return Err(PersistentSettingsError::UnsupportedVersion(version));
+    }
+    let stored = match T::FORMAT {
+        PersistentFormat::Toml => StoredSettings::Toml(
+            parse::<PersistentFile<toml::Value>>(T::FORMAT, contents)?.settings,
+        ),
+        PersistentFormat::Ron => {
+            StoredSettings::Ron(parse::<PersistentFile<ron::Value>>(T::FORMAT, contents)?.settings)
+        }
+    };
+    Ok((T::migrate(version, stored)?, true))
+}
+
+/// A resource holding [`PersistentSettings`], loaded when adding the
+/// [`PersistentSettingsPlugin`] and saved whenever they change.
+///
+/// Dereferences to the settings: changing them through [`ResMut<Persistent<T>>`](ResMut)
+/// schedules a save.
+#[derive(Resource, Debug, Default)]
+pub struct Persistent<T: PersistentSettings>(T);
+
+impl<T: PersistentSettings> Persistent<T> {
+    /// Wraps `settings`.
+    pub fn new(settings: T) -> Self {
+        Self(settings)
+    }
+
+    /// Returns the settings.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: PersistentSettings> Deref for Persistent<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: PersistentSettings> DerefMut for Persistent<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Tracks when a [`Persistent`] resource was last loaded or saved.
+#[derive(Resource)]
+struct PersistentState<T> {
+    /// App name (from the settings plugin)
+    app_name: String,
+    last_save: Tick,
+    /// Debounces the saves while the settings change.
+    save_timer: Timer,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: PersistentSettings> PersistentState<T> {
+    fn save(&mut self, settings: &T, this_run: Tick, use_async: bool) {
+        self.last_save = this_run;
+        self.save_timer.pause();
+        let contents = match encode(settings) {
+            Ok(contents) => contents,
+            Err(err) => {
+                error!("Could not save the {} settings: {err}", T::FILE);
+                return;
+            }
+        };
+        let store = SettingsStore::new(&self.app_name);
+        if use_async {
+            store.write_async(T::FILE, T::FORMAT.extension(), contents);
+        } else {
+            store.write(T::FILE, T::FORMAT.extension(), contents);
+        }
+    }
+}
+
+/// Plugin loading the [`Persistent<T>`] resource, and saving it when it changes and when the app
+/// exits.
+///
+/// The [`SettingsPlugin`](crate::SettingsPlugin) must be added first, to know where to save the
+/// settings. If the settings can't be loaded, for example because the file is corrupted, the
+/// default settings are used and an error is logged.
+pub struct PersistentSettingsPlugin<T> {
+    /// How long to wait after a change to the settings before saving them, so that settings
+    /// changing every frame, for example while dragging a volume slider, are saved once. `None`
+    /// disables the autosave, leaving only the save on exit.
+    ///
+    /// Defaults to one second.
+    pub autosave_delay: Option<Duration>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for PersistentSettingsPlugin<T> {
+    fn default() -> Self {
+        Self {
+            autosave_delay: Some(Duration::from_secs(1)),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> PersistentSettingsPlugin<T> {
+    /// Sets the [`autosave_delay`](Self::autosave_delay).
+    pub fn with_autosave_delay(mut self, autosave_delay: Option<Duration>) -> Self {
+        self.autosave_delay = autosave_delay;
+        self
+    }
+}
+
+impl<T: PersistentSettings> Plugin for PersistentSettingsPlugin<T> {
+    fn build(&self, app: &mut App) {
+        let Some(registry) = app.world().get_resource::<SettingsFileRegistry>() else {
+            warn!(
+                "Settings registry not found - add the SettingsPlugin before the \
+                 PersistentSettingsPlugin, the {} settings won't be saved",
+                T::FILE
+            );
+            app.init_resource::<Persistent<T>>();
+            return;
+        };
+        let app_name = registry.app_name.clone();
+        let this_run = app.world().read_change_tick();
+
+        let mut save_timer = Timer::new(
+            self.autosave_delay.unwrap_or(Duration::ZERO),
+            TimerMode::Once,
+        );
+        save_timer.pause();
+        let mut state = PersistentState::<T> {
+            app_name,
+            last_save: this_run,
+            save_timer,
+            marker: PhantomData,
+        };
+
+        let store = SettingsStore::new(&state.app_name);
+        let settings = match store
+            .read(T::FILE, T::FORMAT.extension())
+            .map(|contents| decode::<T>(&contents))
+        {
+            Some(Ok((settings, migrated))) => {
+                if migrated {
+                    // Save the migrated settings right away, so that the file is only migrated
+                    // once.
+                    state.save(&settings, this_run, false);
+                }
+                settings
+            }
+            Some(Err(err)) => {
+                error!(
+                    "Could not load the {} settings, using the defaults: {err}",
+                    T::FILE
+                );
+                T::default()
+            }
+            None => T::default(),
+        };
+
+        app.insert_resource(Persistent(settings))
+            .insert_resource(state)
+            .add_systems(PostUpdate, save_persistent_on_exit::<T>);
+        if self.autosave_delay.is_none() {
+            return;
+        }
+        app.add_systems(PostUpdate, autosave_persistent::<T>);
+    }
+}
+
+/// Schedules a save of the settings when they change, and saves them once the delay is over.
+fn autosave_persistent<T: PersistentSettings>(
+    settings: Res<Persistent<T>>,
+    mut state: ResMut<PersistentState<T>>,
+    time: Res<Time>,
+    ticks: SystemChangeTick,
+) {
+    if settings.is_changed()
+        && settings
+            .last_changed()
+            .is_newer_than(state.last_save, ticks.this_run())
+    {
+        state.save_timer.reset();
+        state.save_timer.unpause();
+    }
+    state.save_timer.tick(time.delta());
+    if state.save_timer.just_finished() {
+        state.save(&settings, ticks.this_run(), true);
+    }
+}
+
+/// Saves the settings when the app exits, if they changed since they were last saved.
+fn save_persistent_on_exit<T: PersistentSettings>(
+    mut exit: MessageReader<AppExit>,
+    settings: Res<Persistent<T>>,
+    mut state: ResMut<PersistentState<T>>,
+    ticks: SystemChangeTick,
+) {
+    if exit.read().last().is_none() {
+        return;
+    }
+    if settings
+        .last_changed()
+        .is_newer_than(state.last_save, ticks.this_run())
+    {
+        state.save(&settings, ticks.this_run(), false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+    struct AudioSettings {
+        volume: f32,
+        muted: bool,
+    }
+
+    impl PersistentSettings for AudioSettings {
+        const FILE: &'static str = "audio";
+    }
+
+    #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+    struct Bindings {
+        jump: String,
+        crouch: String,
+    }
+
+    /// The bindings before `crouch` was added, at version 0.
+    #[derive(Deserialize)]
+    struct BindingsV0 {
+        jump: String,
+    }
+
+    impl PersistentSettings for Bindings {
+        const FILE: &'static str = "bindings";
+        const FORMAT: PersistentFormat = PersistentFormat::Ron;
+        const VERSION: u32 = 1;
+
+        fn migrate(version: u32, stored: StoredSettings) -> Result<Self, PersistentSettingsError> {
+            match version {
+                0 => {
+                    let old: BindingsV0 = stored.deserialize()?;
+                    Ok(Bindings {
+                        jump: old.jump,
+                        crouch: "C".into(),
+                    })
+                }
+                _ => Err(PersistentSettingsError::UnsupportedVersion(version)),
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let audio = AudioSettings {
+            volume: 0.5,
+            muted: true,
+        };
+        let contents = encode(&audio).unwrap();
+        assert!(contents.starts_with("version = 0"));
+        assert_eq!(decode::<AudioSettings>(&contents).unwrap(), (audio, false));
+
+        let bindings = Bindings {
+            jump: "Space".into(),
+            crouch: "LControl".into(),
+        };
+        let contents = encode(&bindings).unwrap();
+        assert_eq!(decode::<Bindings>(&contents).unwrap(), (bindings, false));
+    }
+
+    #[test]
+    fn migrate_older_versions() {
+        let (bindings, migrated) =
+            decode::<Bindings>(r#"(version: 0, settings: (jump: "Space"))"#).unwrap();
+        assert!(migrated);
+        assert_eq!(
+            bindings,
+            Bindings {
+                jump: "Space".into(),
+                crouch: "C".into(),
+            }
+        );
+
+        // Files saved by a newer version of the app can't be read.
+        assert!(matches!(
+            decode::<Bindings>(r#"(version: 2, settings: (jump: "Space"))"#),
+            Err(PersistentSettingsError::UnsupportedVersion(2))
+        ));
+        // Files without a version were saved with version 0.
+        assert_eq!(
+            decode::<AudioSettings>("[settings]\nvolume = 1.0\nmuted = false\n").unwrap(),
+            (
+                AudioSettings {
+                    volume: 1.0,
+                    muted: false,
+                },
+                false
+            )
+        );
+    }
+}
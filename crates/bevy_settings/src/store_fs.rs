@@ -1,7 +1,10 @@
 use bevy_log::{debug, error, warn};
 use bevy_platform::dirs::preferences_dir;
 use bevy_tasks::IoTaskPool;
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 /// Persistent storage which uses the local filesystem. Settings will be located in the
 /// OS-specific directory for user settings.
@@ -33,27 +36,7 @@ impl SettingsStore {
     /// * `filename` - the name of the file to be saved
     /// * `contents` - the contents of the file
     pub(crate) fn save(&self, filename: &str, contents: toml::Table) {
-        if let Some(base_path) = &self.base_path {
-            // Recursively create the settings directory if it doesn't exist.
-            let mut dir_builder = fs::DirBuilder::new();
-            dir_builder.recursive(true);
-            if let Err(e) = dir_builder.create(base_path.clone()) {
-                warn!("Could not create settings directory: {:?}", e);
-                return;
-            }
-
-            // Save settings to temp file
-            let temp_path = base_path.join(format!("{filename}.toml.new"));
-            if let Err(e) = fs::write(&temp_path, contents.to_string()) {
-                error!("Error saving settings file: {}", e);
-            }
-
-            // Replace old settings file with new one.
-            let file_path = base_path.join(format!("{filename}.toml"));
-            if let Err(e) = fs::rename(&temp_path, file_path) {
-                warn!("Could not save settings file: {:?}", e);
-            }
-        }
+        self.write(filename, "toml", contents.to_string());
     }
 
     /// Save the contents of a [`toml::Table`] to disk in another thread.
@@ -62,33 +45,60 @@ impl SettingsStore {
     /// * `filename` - the name of the file to be saved
     /// * `contents` - the contents of the file
     pub(crate) fn save_async(&self, filename: &str, contents: toml::Table) {
+        self.write_async(filename, "toml", contents.to_string());
+    }
+
+    /// Save a string to disk, replacing the previous file atomically.
+    ///
+    /// # Arguments
+    /// * `filename` - the name of the file to be saved, without the file extension
+    /// * `extension` - the extension of the file
+    /// * `contents` - the contents of the file
+    pub(crate) fn write(&self, filename: &str, extension: &str, contents: String) {
+        if let Some(base_path) = &self.base_path {
+            write_file(base_path, filename, extension, &contents);
+        }
+    }
+
+    /// Save a string to disk in another thread, replacing the previous file atomically.
+    ///
+    /// # Arguments
+    /// * `filename` - the name of the file to be saved, without the file extension
+    /// * `extension` - the extension of the file
+    /// * `contents` - the contents of the file
+    pub(crate) fn write_async(&self, filename: &str, extension: &str, contents: String) {
         if let Some(base_path) = &self.base_path {
             IoTaskPool::get().scope(|scope| {
                 scope.spawn(async {
-                    // Recursively create the settings directory if it doesn't exist.
-                    let mut dir_builder = fs::DirBuilder::new();
-                    dir_builder.recursive(true);
-                    if let Err(e) = dir_builder.create(base_path.clone()) {
-                        warn!("Could not create settings directory: {:?}", e);
-                        return;
-                    }
-
-                    // Save settings to temp file
-                    let temp_path = base_path.join(format!("{filename}.toml.new"));
-                    if let Err(e) = fs::write(&temp_path, contents.to_string()) {
-                        error!("Error saving settings file: {}", e);
-                    }
-
-                    // Replace old settings file with new one.
-                    let file_path = base_path.join(format!("{filename}.toml"));
-                    if let Err(e) = fs::rename(&temp_path, file_path) {
-                        warn!("Could not save settings file: {:?}", e);
-                    }
+                    write_file(base_path, filename, extension, &contents);
                 });
             });
         }
     }
 
+    /// Read a string from disk. If the file does not exist, `None` will be returned.
+    ///
+    /// # Arguments
+    /// * `filename` - The name of the settings file, without the file extension.
+    /// * `extension` - The extension of the file.
+    pub(crate) fn read(&self, filename: &str, extension: &str) -> Option<String> {
+        let file_path = self
+            .base_path
+            .as_ref()?
+            .join(format!("{filename}.{extension}"));
+        if !file_path.is_file() {
+            // Settings file does not exist yet.
+            return None;
+        }
+        match fs::read_to_string(file_path) {
+            Ok(contents) => Some(contents),
+            Err(e) => {
+                error!("Error reading settings file: {}", e);
+                None
+            }
+        }
+    }
+
     /// Deserialize a [`toml::Table`] from disk. If the file does not exist, `None` will
     /// be returned.
     ///
@@ -104,6 +114,29 @@ impl SettingsStore {
     }
 }
 
+/// Write a settings file to a temporary file, then replace the previous file with it.
+fn write_file(base_path: &Path, filename: &str, extension: &str, contents: &str) {
+    // Recursively create the settings directory if it doesn't exist.
+    let mut dir_builder = fs::DirBuilder::new();
+    dir_builder.recursive(true);
+    if let Err(e) = dir_builder.create(base_path) {
+        warn!("Could not create settings directory: {:?}", e);
+        return;
+    }
+
+    // Save settings to temp file
+    let temp_path = base_path.join(format!("{filename}.{extension}.new"));
+    if let Err(e) = fs::write(&temp_path, contents) {
+        error!("Error saving settings file: {}", e);
+    }
+
+    // Replace old settings file with new one.
+    let file_path = base_path.join(format!("{filename}.{extension}"));
+    if let Err(e) = fs::rename(&temp_path, file_path) {
+        warn!("Could not save settings file: {:?}", e);
+    }
+}
+
 /// Load a settings file from disk in TOML format.
 pub(crate) fn decode_toml_file(file: &PathBuf) -> Option<toml::Table> {
     if file.exists() && file.is_file() {
@@ -30,61 +30,76 @@ impl SettingsStore {
     /// * `filename` - the name of the file to be saved
     /// * `contents` - the contents of the file
     pub(crate) fn save(&self, filename: &str, contents: toml::Table) {
+        self.write(filename, "toml", contents.to_string());
+    }
+
+    /// Save the content of a [`toml::Table`] to local storage, in another thread.
+    ///
+    /// # Arguments
+    /// * `filename` - the name of the file to be saved
+    /// * `contents` - the contents of the file
+    pub(crate) fn save_async(&self, filename: &str, contents: toml::Table) {
+        self.write_async(filename, "toml", contents.to_string());
+    }
+
+    /// Save a string to browser storage, synchronously. The extension is ignored, since local
+    /// storage has no files.
+    ///
+    /// # Arguments
+    /// * `filename` - the name of the file to be saved
+    /// * `contents` - the contents of the file
+    pub(crate) fn write(&self, filename: &str, _extension: &str, contents: String) {
         if let Ok(Some(storage)) = window().unwrap().local_storage() {
-            let toml_str = contents.to_string();
             storage
-                .set_item(self.storage_key(filename).as_str(), &toml_str)
+                .set_item(self.storage_key(filename).as_str(), &contents)
                 .unwrap();
         }
     }
 
-    /// Save the content of a [`toml::Table`] to local storage, in another thread.
+    /// Save a string to browser storage, in another thread.
     ///
     /// # Arguments
     /// * `filename` - the name of the file to be saved
     /// * `contents` - the contents of the file
-    pub(crate) fn save_async(&self, filename: &str, contents: toml::Table) {
+    pub(crate) fn write_async(&self, filename: &str, extension: &str, contents: String) {
         IoTaskPool::get().scope(|scope| {
             scope.spawn(async {
-                if let Ok(Some(storage)) = window().unwrap().local_storage() {
-                    let toml_str = contents.to_string();
-                    storage
-                        .set_item(self.storage_key(filename).as_str(), &toml_str)
-                        .unwrap();
-                }
+                self.write(filename, extension, contents);
             });
         });
     }
 
+    /// Read a string from browser storage. If there is no such key, `None` will be returned.
+    ///
+    /// # Arguments
+    /// * `filename` - The name of the settings file, without the file extension.
+    pub(crate) fn read(&self, filename: &str, _extension: &str) -> Option<String> {
+        let storage = window().unwrap().local_storage().ok()??;
+        storage.get_item(&self.storage_key(filename)).ok()?
+    }
+
     /// Deserialize a [`toml::Table`]. If the file does not exist, `None` will
     /// be returned.
     ///
     /// # Arguments
     /// * `filename` - The name of the settings file, without the file extension.
     pub(crate) fn load(&self, filename: &str) -> Option<toml::Table> {
-        if let Ok(Some(storage)) = window().unwrap().local_storage() {
-            let storage_key = self.storage_key(filename);
-            let Ok(Some(toml_str)) = storage.get_item(&storage_key) else {
-                return None;
-            };
+        let toml_str = self.read(filename, "toml")?;
 
-            let table_value = match toml::from_str::<toml::Value>(&toml_str) {
-                Ok(table_value) => table_value,
-                Err(e) => {
-                    error!("Error parsing settings file: {}", e);
-                    return None;
-                }
-            };
+        let table_value = match toml::from_str::<toml::Value>(&toml_str) {
+            Ok(table_value) => table_value,
+            Err(e) => {
+                error!("Error parsing settings file: {}", e);
+                return None;
+            }
+        };
 
-            match table_value {
-                toml::Value::Table(table) => Some(table),
-                _ => {
-                    error!("Settings file must be a table");
-                    None
-                }
+        match table_value {
+            toml::Value::Table(table) => Some(table),
+            _ => {
+                error!("Settings file must be a table");
+                None
             }
-        } else {
-            None
         }
     }
 }
@@ -257,11 +257,15 @@ impl<ShaderModule, RenderDevice> ShaderCache<ShaderModule, RenderDevice> {
                                 &wesl::EscapeMangler,
                                 &compiler_options,
                             )
-                            .unwrap();
+                            .map_err(|err| {
+                                ShaderCacheError::ProcessShaderSource(err.to_string())
+                            })?;
 
                             ShaderCacheSource::Wgsl(compiled.to_string())
                         } else {
-                            panic!("Wesl shaders must be imported from a file");
+                            return Err(ShaderCacheError::ProcessShaderSource(
+                                "Wesl shaders must be imported from a file".into(),
+                            ));
                         }
                     }
                     _ => {
@@ -309,7 +313,9 @@ impl<ShaderModule, RenderDevice> ShaderCache<ShaderModule, RenderDevice> {
                                 naga::valid::ValidationFlags::all(),
                                 self.composer.capabilities,
                             );
-                            let module_info = validator.validate(&naga).unwrap();
+                            let module_info = validator.validate(&naga).map_err(|err| {
+                                ShaderCacheError::ProcessShaderSource(err.to_string())
+                            })?;
                             let wgsl = naga::back::wgsl::write_string(
                                 &naga,
                                 &module_info,
@@ -331,6 +337,42 @@ impl<ShaderModule, RenderDevice> ShaderCache<ShaderModule, RenderDevice> {
         Ok(module.clone())
     }
 
+    /// Returns the shaders importing the shader with the given asset id, directly or indirectly
+    /// through other imports.
+    ///
+    /// These are the shaders processed again when the shader is replaced or removed, for example
+    /// when it is hot reloaded.
+    pub fn dependents(&self, id: AssetId<Shader>) -> HashSet<AssetId<Shader>> {
+        let mut dependents = HashSet::default();
+        let mut shaders_to_visit = vec![id];
+        while let Some(shader) = shaders_to_visit.pop() {
+            let Some(data) = self.data.get(&shader) else {
+                continue;
+            };
+            for dependent in &data.dependents {
+                if dependents.insert(*dependent) {
+                    shaders_to_visit.push(*dependent);
+                }
+            }
+        }
+        dependents
+    }
+
+    /// Removes the shader with the given asset id from the dependents of its imports, and from the
+    /// shaders waiting on an import.
+    fn remove_imports(&mut self, id: AssetId<Shader>) {
+        if let Some(data) = self.data.get_mut(&id) {
+            for import_id in core::mem::take(&mut data.resolved_imports).into_values() {
+                if let Some(import_data) = self.data.get_mut(&import_id) {
+                    import_data.dependents.remove(&id);
+                }
+            }
+        }
+        for waiting_shaders in self.waiting_on_import.values_mut() {
+            waiting_shaders.retain(|waiting_shader| *waiting_shader != id);
+        }
+    }
+
     fn clear(&mut self, id: AssetId<Shader>) -> Vec<CachedPipelineId> {
         let mut shaders_to_clear = vec![id];
         let mut pipelines_to_queue = Vec::new();
@@ -356,6 +398,8 @@ impl<ShaderModule, RenderDevice> ShaderCache<ShaderModule, RenderDevice> {
     /// (directly or indirectly via a shader import) and thus must be recompiled.
     pub fn set_shader(&mut self, id: AssetId<Shader>, shader: Shader) -> Vec<CachedPipelineId> {
         let pipelines_to_queue = self.clear(id);
+        // The new version of the shader may import other shaders.
+        self.remove_imports(id);
         let path = &shader.import_path;
         self.import_path_shaders.insert(path.clone(), id);
         if let Some(waiting_shaders) = self.waiting_on_import.get_mut(path) {
@@ -400,6 +444,7 @@ impl<ShaderModule, RenderDevice> ShaderCache<ShaderModule, RenderDevice> {
     /// (directly or indirectly via a shader import) and thus must be recompiled.
     pub fn remove(&mut self, id: AssetId<Shader>) -> Vec<CachedPipelineId> {
         let pipelines_to_queue = self.clear(id);
+        self.remove_imports(id);
         if let Some(shader) = self.shaders.remove(&id) {
             self.import_path_shaders.remove(&shader.import_path);
         }
@@ -466,4 +511,83 @@ pub enum ShaderCacheError {
     ShaderImportNotYetAvailable,
     #[error("Could not create shader module: {0}")]
     CreateShaderModule(String),
+    #[error("Shader could not be processed: {0}")]
+    ProcessShaderSource(String),
+    #[error("Could not create pipeline: {0}")]
+    CreatePipeline(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShaderCache;
+    use crate::Shader;
+    use bevy_asset::{uuid::Uuid, AssetId};
+    use bevy_platform::collections::HashSet;
+    use wgpu_types::{DownlevelFlags, Features};
+
+    fn shader_id(id: u128) -> AssetId<Shader> {
+        AssetId::Uuid {
+            uuid: Uuid::from_u128(id),
+        }
+    }
+
+    #[test]
+    fn dependents_follow_the_imports_of_replaced_shaders() {
+        let mut cache = ShaderCache::<(), ()>::new(
+            (),
+            Features::empty(),
+            DownlevelFlags::empty(),
+            |_, _, _| Ok(()),
+        );
+        let (a, b, c, main) = (shader_id(1), shader_id(2), shader_id(3), shader_id(4));
+        let set = |ids: &[AssetId<Shader>]| ids.iter().copied().collect::<HashSet<_>>();
+
+        cache.set_shader(
+            a,
+            Shader::from_wgsl("#define_import_path test::a\nfn a() {}", "a.wgsl"),
+        );
+        cache.set_shader(
+            b,
+            Shader::from_wgsl("#define_import_path test::b\nfn b() {}", "b.wgsl"),
+        );
+        cache.set_shader(
+            main,
+            Shader::from_wgsl(
+                "#import test::a\n#import test::c\nfn main() {}",
+                "main.wgsl",
+            ),
+        );
+        assert_eq!(cache.dependents(a), set(&[main]));
+        assert!(cache.dependents(b).is_empty());
+
+        // Imports loaded after the shader importing them are tracked too.
+        cache.set_shader(
+            c,
+            Shader::from_wgsl("#define_import_path test::c\nfn c() {}", "c.wgsl"),
+        );
+        assert_eq!(cache.dependents(c), set(&[main]));
+
+        // Editing the imports of a shader replaces its dependencies.
+        cache.set_shader(
+            main,
+            Shader::from_wgsl("#import test::b\nfn main() {}", "main.wgsl"),
+        );
+        assert!(cache.dependents(a).is_empty());
+        assert!(cache.dependents(c).is_empty());
+        assert_eq!(cache.dependents(b), set(&[main]));
+
+        // Shaders importing a shader indirectly depend on its imports.
+        cache.set_shader(
+            a,
+            Shader::from_wgsl(
+                "#define_import_path test::a\n#import test::b\nfn a() {}",
+                "a.wgsl",
+            ),
+        );
+        cache.set_shader(
+            main,
+            Shader::from_wgsl("#import test::a\nfn main() {}", "main.wgsl"),
+        );
+        assert_eq!(cache.dependents(b), set(&[a, main]));
+    }
 }
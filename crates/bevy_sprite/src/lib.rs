@@ -13,6 +13,7 @@ extern crate alloc;
 mod picking_backend;
 mod sprite;
 mod sprite_mesh;
+mod sprite_sheet;
 #[cfg(feature = "bevy_text")]
 mod text2d;
 mod texture_slice;
@@ -33,12 +34,13 @@ pub mod prelude {
     pub use crate::{
         sprite::{Sprite, SpriteImageMode},
         sprite_mesh::SpriteMesh,
+        sprite_sheet::SpriteSheet,
         texture_slice::{BorderRect, SliceScaleMode, TextureSlice, TextureSlicer},
         SpriteScalingMode,
     };
 }
 
-use bevy_asset::Assets;
+use bevy_asset::{AssetApp, Assets};
 use bevy_camera::{
     primitives::{Aabb, MeshAabb},
     visibility::NoFrustumCulling,
@@ -51,6 +53,7 @@ use bevy_text::detect_text_needs_rerender;
 pub use picking_backend::*;
 pub use sprite::*;
 pub use sprite_mesh::*;
+pub use sprite_sheet::*;
 #[cfg(feature = "bevy_text")]
 pub use text2d::*;
 pub use texture_slice::*;
@@ -71,6 +74,8 @@ impl Plugin for SpritePlugin {
         if !app.is_plugin_added::<TextureAtlasPlugin>() {
             app.add_plugins(TextureAtlasPlugin);
         }
+        app.init_asset::<SpriteSheet>()
+            .register_asset_loader(SpriteSheetLoader);
         app.add_systems(
             PostUpdate,
             (calculate_bounds_2d, calculate_bounds_2d_sprite_mesh)
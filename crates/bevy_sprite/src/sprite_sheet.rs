@@ -0,0 +1,245 @@
+use alloc::{string::String, vec::Vec};
+use std::io;
+
+use bevy_asset::{
+    io::Reader, Asset, AssetLoader, Handle, LoadContext, LoadDirectError, ParseAssetPathError,
+    ReadAssetBytesError,
+};
+use bevy_image::{
+    Image, ImageLoader, TextureAtlas, TextureAtlasBuilder, TextureAtlasBuilderError,
+    TextureAtlasLayout,
+};
+use bevy_math::UVec2;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::TypePath;
+use ron::de::SpannedError;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Sprite;
+
+/// A texture atlas packed at load time from a folder of images, whose frames are referenced by
+/// name.
+///
+/// Sprite sheets are loaded by the [`SpriteSheetLoader`] from `.sprites.ron` files describing a
+/// [`SpriteSheetManifest`]. The packed image and layout are the `image` and `layout` subassets of
+/// the file.
+///
+/// ```
+/// # use bevy_asset::{AssetServer, Assets, Handle};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_sprite::SpriteSheet;
+/// #[derive(Resource)]
+/// struct HeroSheet(Handle<SpriteSheet>);
+///
+/// fn load_hero(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     // `hero.sprites.ron` contains `(folder: "hero")`, packing the images in `hero/`.
+///     commands.insert_resource(HeroSheet(asset_server.load("hero.sprites.ron")));
+/// }
+///
+/// fn spawn_hero(mut commands: Commands, hero: Res<HeroSheet>, sheets: Res<Assets<SpriteSheet>>) {
+///     // Loaded from `hero/idle_0.png`.
+///     if let Some(sprite) = sheets.get(&hero.0).and_then(|sheet| sheet.sprite("idle_0")) {
+///         commands.spawn(sprite);
+///     }
+/// }
+/// ```
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct SpriteSheet {
+    /// The packed image.
+    pub image: Handle<Image>,
+    /// The layout of the frames in the packed image.
+    pub layout: Handle<TextureAtlasLayout>,
+    /// The indices of the frames in the layout, by name. The name of a frame is the file name of
+    /// its image, without the extension.
+    pub frames: HashMap<String, usize>,
+}
+
+impl SpriteSheet {
+    /// Returns the index in the layout of the frame named `name`.
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.frames.get(name).copied()
+    }
+
+    /// Returns the [`TextureAtlas`] of the frame named `name`.
+    pub fn texture_atlas(&self, name: &str) -> Option<TextureAtlas> {
+        self.index(name).map(|index| TextureAtlas {
+            layout: self.layout.clone(),
+            index,
+        })
+    }
+
+    /// Returns a [`Sprite`] showing the frame named `name`.
+    pub fn sprite(&self, name: &str) -> Option<Sprite> {
+        self.texture_atlas(name)
+            .map(|atlas| Sprite::from_atlas_image(self.image.clone(), atlas))
+    }
+}
+
+/// The serialized description of a [`SpriteSheet`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpriteSheetManifest {
+    /// The folder of the images to pack, relative to the manifest file. The images directly in the
+    /// folder are packed, in the order of their paths, and sub-folders are ignored.
+    pub folder: String,
+    /// The padding between the frames, see [`TextureAtlasBuilder::padding`].
+    ///
+    /// Defaults to no padding.
+    pub padding: UVec2,
+    /// The number of pixels by which the edges of the frames are extruded, see
+    /// [`TextureAtlasBuilder::extrusion`].
+    ///
+    /// Defaults to 0.
+    pub extrusion: u32,
+    /// The maximum size of the packed image. Loading fails if the images don't fit.
+    ///
+    /// Defaults to 2048 by 2048 pixels.
+    pub max_size: UVec2,
+}
+
+impl Default for SpriteSheetManifest {
+    fn default() -> Self {
+        Self {
+            folder: String::new(),
+            padding: UVec2::ZERO,
+            extrusion: 0,
+            max_size: UVec2::splat(2048),
+        }
+    }
+}
+
+/// Loads [`SpriteSheet`]s from `.sprites.ron` files describing a [`SpriteSheetManifest`].
+///
+/// Changing an image of the folder reloads the sprite sheet, but adding or removing images
+/// doesn't.
+#[derive(Clone, Copy, Debug, Default, TypePath)]
+pub struct SpriteSheetLoader;
+
+/// Errors that can occur when loading a [`SpriteSheet`].
+#[derive(Error, Debug)]
+pub enum SpriteSheetLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An error occurred in RON deserialization.
+    #[error(transparent)]
+    Ron(#[from] ron::Error),
+    /// An error occurred in RON deserialization, and the location of the error
+    /// is supplied.
+    #[error(transparent)]
+    SpannedRon(#[from] SpannedError),
+    /// The folder of the manifest isn't a valid asset path.
+    #[error("Invalid folder path: {0}")]
+    InvalidFolder(#[from] ParseAssetPathError),
+    /// The folder of the manifest could not be read.
+    #[error("Could not read the folder: {0}")]
+    ReadFolder(#[from] ReadAssetBytesError),
+    /// An image of the folder could not be loaded.
+    #[error(transparent)]
+    LoadImage(#[from] LoadDirectError),
+    /// The images could not be packed.
+    #[error("Could not pack the images: {0}")]
+    Pack(#[from] TextureAtlasBuilderError),
+}
+
+impl AssetLoader for SpriteSheetLoader {
+    type Asset = SpriteSheet;
+
+    type Settings = ();
+
+    type Error = SpriteSheetLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        let manifest = SpriteSheetManifest::deserialize(&mut deserializer)
+            .map_err(|err| deserializer.span_error(err))?;
+
+        let folder = load_context.path().resolve_embed_str(&manifest.folder)?;
+        let mut names = Vec::new();
+        let mut images = Vec::new();
+        for path in load_context.read_directory(folder).await? {
+            let Some(name) = path
+                .path()
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| frame_name(file_name, ImageLoader::SUPPORTED_FILE_EXTENSIONS))
+                .map(String::from)
+            else {
+                continue;
+            };
+            let image = load_context
+                .load_builder()
+                .load_value::<Image>(path)
+                .await?
+                .take();
+            names.push(name);
+            images.push(image);
+        }
+
+        let mut builder = TextureAtlasBuilder::default();
+        builder
+            .padding(manifest.padding)
+            .extrusion(manifest.extrusion)
+            .max_size(manifest.max_size);
+        for image in &images {
+            builder.add_texture(None, image);
+        }
+        let (layout, _, image) = builder.build()?;
+
+        // The frames are indexed in insertion order.
+        let frames = names.into_iter().zip(0..).collect();
+        Ok(SpriteSheet {
+            image: load_context.add_labeled_asset("image", image),
+            layout: load_context.add_labeled_asset("layout", layout),
+            frames,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["sprites.ron"]
+    }
+}
+
+/// Returns the name of the frame of the file `file_name`, or `None` if the file doesn't have one of
+/// the image `extensions`.
+fn frame_name<'a>(file_name: &'a str, extensions: &[&str]) -> Option<&'a str> {
+    let (name, extension) = file_name.rsplit_once('.')?;
+    extensions
+        .iter()
+        .any(|supported| supported.eq_ignore_ascii_case(extension))
+        .then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{frame_name, SpriteSheetManifest};
+    use bevy_math::UVec2;
+
+    #[test]
+    fn frames_are_named_after_their_images() {
+        let extensions = ["png", "jpg"];
+        assert_eq!(frame_name("idle_0.png", &extensions), Some("idle_0"));
+        assert_eq!(frame_name("run.1.PNG", &extensions), Some("run.1"));
+        assert_eq!(frame_name("notes.txt", &extensions), None);
+        assert_eq!(frame_name("frames", &extensions), None);
+    }
+
+    #[test]
+    fn manifests_default_missing_fields() {
+        let manifest: SpriteSheetManifest =
+            ron::de::from_str(r#"(folder: "hero", padding: (2, 2))"#).unwrap();
+        assert_eq!(manifest.folder, "hero");
+        assert_eq!(manifest.padding, UVec2::splat(2));
+        assert_eq!(manifest.extrusion, 0);
+        assert_eq!(manifest.max_size, UVec2::splat(2048));
+    }
+}
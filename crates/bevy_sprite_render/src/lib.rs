@@ -11,6 +11,7 @@
 extern crate alloc;
 
 mod mesh2d;
+mod pixel_art;
 mod render;
 mod sprite_mesh;
 #[cfg(feature = "bevy_text")]
@@ -23,11 +24,12 @@ mod tilemap_chunk;
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{ColorMaterial, MeshMaterial2d, SpriteMaterial};
+    pub use crate::{ColorMaterial, MeshMaterial2d, PixelArtCamera, PixelSnap, SpriteMaterial};
 }
 
 use bevy_shader::load_shader_library;
 pub use mesh2d::*;
+pub use pixel_art::*;
 pub use render::*;
 pub use sprite_mesh::*;
 pub(crate) use texture_slice::*;
@@ -74,6 +76,7 @@ impl Plugin for SpriteRenderPlugin {
             Mesh2dRenderPlugin,
             Materials2dPlugin,
             ColorMaterialPlugin,
+            PixelArtPlugin,
             SpriteMeshPlugin,
             TilemapChunkPlugin,
             TilemapChunkMaterialPlugin,
@@ -1255,6 +1255,7 @@ where
             binding: material_binding_id,
             properties: Arc::new(MaterialProperties {
                 depth_bias: material.depth_bias(),
+                sort_bias: 0.0,
                 alpha_mode: material.alpha_mode().into(),
                 material_layout: Some(material_layout),
                 bindless,
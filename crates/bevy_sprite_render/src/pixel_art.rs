@@ -0,0 +1,332 @@
+//! Renders 2D cameras at a low resolution, for crisp pixel art at any window size.
+//!
+//! A camera with a [`PixelArtCamera`] renders the world to a texture of its
+//! [`resolution`](PixelArtCamera::resolution), which is then drawn to the camera's original
+//! [`RenderTarget`] by an upscaling camera, scaled up to the largest integer multiple that fits,
+//! with nearest filtering.
+//!
+//! The upscaling camera renders after the pixel art camera and targets the window, so it is the one
+//! UI is drawn with by default: UI and text keep the native resolution of the window, while the
+//! world is pixelated.
+//!
+//! Entities with a [`PixelSnap`] are snapped to the pixel grid, so they don't shimmer as they move
+//! by fractions of a pixel.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{Assets, Handle};
+use bevy_camera::{
+    visibility::{RenderLayers, VisibilitySystems},
+    Camera, Camera2d, CameraUpdateSystems, Projection, RenderTarget,
+};
+use bevy_ecs::prelude::*;
+use bevy_image::{Image, ImageSampler};
+use bevy_math::{UVec2, Vec2};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_render::{
+    render_resource::{Extent3d, TextureFormat},
+    view::Msaa,
+};
+use bevy_sprite::Sprite;
+use bevy_transform::{components::GlobalTransform, TransformSystems};
+
+/// Adds support for [`PixelArtCamera`] and [`PixelSnap`].
+pub struct PixelArtPlugin;
+
+impl Plugin for PixelArtPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_add_pixel_art_camera)
+            .add_observer(on_remove_pixel_art_camera)
+            .add_systems(
+                PostUpdate,
+                (
+                    (update_pixel_art_outputs, fit_pixel_art_upscalers)
+                        .chain()
+                        .before(CameraUpdateSystems),
+                    snap_to_pixel_grid
+                        .after(TransformSystems::Propagate)
+                        .before(VisibilitySystems::UpdateFrusta),
+                ),
+            );
+    }
+}
+
+/// Renders this 2D camera at a low resolution, then upscales it to its [`RenderTarget`] by the
+/// largest integer factor that fits, with nearest filtering.
+///
+/// When this is added, the camera is made to render to an [`Image`] of the given `resolution`, and
+/// two entities are spawned: a [`Sprite`] showing that image on the `canvas_layers`, and a
+/// [`Camera2d`] rendering these layers to the original target of the camera, with an `order` one
+/// above the camera's. They are despawned and the original target restored when this is removed.
+///
+/// The canvas is centered in the target, with the space around it cleared by the upscaling camera.
+/// The pixel art camera renders the [`RenderLayers`] it has, by default layer 0, which must not
+/// include the `canvas_layers`.
+///
+/// UI is drawn by the upscaling camera, at the native resolution of the target, unless a UI node
+/// targets the pixel art camera itself.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+#[require(Camera2d)]
+pub struct PixelArtCamera {
+    /// The resolution the world is rendered at, in pixels.
+    ///
+    /// Defaults to 320x180.
+    pub resolution: UVec2,
+    /// The layers of the canvas displaying the low resolution image, rendered by the upscaling
+    /// camera.
+    ///
+    /// Defaults to layer 1.
+    pub canvas_layers: RenderLayers,
+}
+
+impl PixelArtCamera {
+    /// Creates a pixel art camera rendering at the given resolution.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            resolution: UVec2::new(width, height),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for PixelArtCamera {
+    fn default() -> Self {
+        Self {
+            resolution: UVec2::new(320, 180),
+            canvas_layers: RenderLayers::layer(1),
+        }
+    }
+}
+
+/// The entities displaying the image of a [`PixelArtCamera`], managed by the [`PixelArtPlugin`].
+#[derive(Component, Clone, Debug)]
+pub struct PixelArtOutput {
+    /// The image the pixel art camera renders to.
+    pub image: Handle<Image>,
+    /// The sprite showing the image.
+    pub canvas: Entity,
+    /// The camera rendering the canvas to the original target.
+    pub upscaler: Entity,
+    /// The target the pixel art camera had before the [`PixelArtCamera`] was added.
+    original_target: RenderTarget,
+}
+
+/// Marks the cameras drawing the canvas of a [`PixelArtCamera`] to its original target.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct PixelArtUpscaler {
+    /// The pixel art camera whose canvas is drawn.
+    pub camera: Entity,
+}
+
+/// Snaps the rendered position of this entity to the pixel grid.
+///
+/// Only the [`GlobalTransform`] is rounded, after transform propagation, so the [`Transform`]
+/// keeps its fractional position and movement stays smooth over time. Children are propagated
+/// from the unsnapped position, so they need a [`PixelSnap`] of their own.
+///
+/// Add it to the [`PixelArtCamera`] as well to keep scrolling on the grid.
+///
+/// [`Transform`]: bevy_transform::components::Transform
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+pub struct PixelSnap {
+    /// The size of a cell of the grid, in world units.
+    ///
+    /// Defaults to `1.0`, which is one pixel for a [`PixelArtCamera`] with the default projection.
+    pub grid: f32,
+}
+
+impl Default for PixelSnap {
+    fn default() -> Self {
+        Self { grid: 1.0 }
+    }
+}
+
+fn pixel_art_image(resolution: UVec2) -> Image {
+    let mut image = Image::new_target_texture(
+        resolution.x.max(1),
+        resolution.y.max(1),
+        TextureFormat::Rgba8UnormSrgb,
+        None,
+    );
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+/// Redirects a new [`PixelArtCamera`] to a low resolution image, and spawns its canvas and
+/// upscaling camera.
+fn on_add_pixel_art_camera(
+    add: On<Add, PixelArtCamera>,
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut cameras: Query<(&PixelArtCamera, &Camera, &mut RenderTarget)>,
+) {
+    let Ok((pixel_art_camera, camera, mut target)) = cameras.get_mut(add.entity) else {
+        return;
+    };
+
+    let image = images.add(pixel_art_image(pixel_art_camera.resolution));
+    let original_target =
+        core::mem::replace(&mut *target, RenderTarget::Image(image.clone().into()));
+
+    let canvas = commands
+        .spawn((
+            Sprite::from_image(image.clone()),
+            pixel_art_camera.canvas_layers.clone(),
+        ))
+        .id();
+    let upscaler = commands
+        .spawn((
+            Camera2d,
+            Camera {
+                order: camera.order + 1,
+                ..Default::default()
+            },
+            original_target.clone(),
+            Msaa::Off,
+            pixel_art_camera.canvas_layers.clone(),
+            PixelArtUpscaler { camera: add.entity },
+        ))
+        .id();
+
+    // Multisampling would blur the edges of the pixels.
+    commands.entity(add.entity).insert((
+        Msaa::Off,
+        PixelArtOutput {
+            image,
+            canvas,
+            upscaler,
+            original_target,
+        },
+    ));
+}
+
+/// Despawns the canvas and upscaling camera of a removed [`PixelArtCamera`], and restores its
+/// original target.
+fn on_remove_pixel_art_camera(
+    remove: On<Remove, PixelArtCamera>,
+    mut commands: Commands,
+    outputs: Query<&PixelArtOutput>,
+) {
+    let Ok(output) = outputs.get(remove.entity) else {
+        return;
+    };
+    commands.entity(output.canvas).try_despawn();
+    commands.entity(output.upscaler).try_despawn();
+    commands
+        .entity(remove.entity)
+        .try_remove::<PixelArtOutput>()
+        .try_insert(output.original_target.clone());
+}
+
+/// Resizes the image and moves the canvas of the [`PixelArtCamera`]s that changed.
+fn update_pixel_art_outputs(
+    mut images: ResMut<Assets<Image>>,
+    cameras: Query<(&PixelArtCamera, &PixelArtOutput), Changed<PixelArtCamera>>,
+    mut layers: Query<&mut RenderLayers>,
+) {
+    for (pixel_art_camera, output) in &cameras {
+        let size = Extent3d {
+            width: pixel_art_camera.resolution.x.max(1),
+            height: pixel_art_camera.resolution.y.max(1),
+            depth_or_array_layers: 1,
+        };
+        if images
+            .get(&output.image)
+            .is_some_and(|image| image.texture_descriptor.size != size)
+            && let Some(mut image) = images.get_mut(&output.image)
+        {
+            image.resize(size);
+        }
+
+        for entity in [output.canvas, output.upscaler] {
+            if let Ok(mut layers) = layers.get_mut(entity) {
+                layers.set_if_neq(pixel_art_camera.canvas_layers.clone());
+            }
+        }
+    }
+}
+
+/// Scales the projection of the [`PixelArtUpscaler`]s so that each pixel of the canvas covers an
+/// integer number of physical pixels of the target.
+fn fit_pixel_art_upscalers(
+    pixel_art_cameras: Query<&PixelArtCamera>,
+    mut upscalers: Query<(&PixelArtUpscaler, &Camera, &mut Projection)>,
+) {
+    for (upscaler, camera, mut projection) in &mut upscalers {
+        let Ok(pixel_art_camera) = pixel_art_cameras.get(upscaler.camera) else {
+            continue;
+        };
+        let (Some(logical_size), Some(physical_size)) =
+            (camera.logical_target_size(), camera.physical_target_size())
+        else {
+            continue;
+        };
+        if logical_size.x <= 0.0 {
+            continue;
+        }
+
+        let resolution = pixel_art_camera.resolution.max(UVec2::ONE).as_vec2();
+        let factor = (physical_size.as_vec2() / resolution)
+            .min_element()
+            .floor()
+            .max(1.0);
+        // The projection of a 2D camera maps one world unit to one logical pixel at scale 1.
+        let scale = physical_size.x as f32 / (factor * logical_size.x);
+        if let Projection::Orthographic(orthographic) = &mut *projection
+            && orthographic.scale != scale
+        {
+            orthographic.scale = scale;
+        }
+    }
+}
+
+/// Rounds the translation of the [`GlobalTransform`] of the entities with a [`PixelSnap`] to
+/// their grid.
+pub fn snap_to_pixel_grid(mut snapped: Query<(&PixelSnap, &mut GlobalTransform)>) {
+    for (snap, mut global_transform) in &mut snapped {
+        if snap.grid <= 0.0 {
+            continue;
+        }
+        let mut affine = global_transform.affine();
+        let translation = Vec2::new(affine.translation.x, affine.translation.y);
+        let snapped_translation = (translation / snap.grid).round() * snap.grid;
+        if snapped_translation != translation {
+            affine.translation.x = snapped_translation.x;
+            affine.translation.y = snapped_translation.y;
+            *global_transform = GlobalTransform::from(affine);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, PostUpdate};
+    use bevy_math::Vec3;
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use super::{snap_to_pixel_grid, PixelSnap};
+
+    #[test]
+    fn pixel_snap_rounds_global_translation() {
+        let mut app = App::new();
+        app.add_systems(PostUpdate, snap_to_pixel_grid);
+
+        let entity = app
+            .world_mut()
+            .spawn((
+                PixelSnap { grid: 2.0 },
+                GlobalTransform::from(Transform::from_xyz(3.2, -4.9, 1.5)),
+            ))
+            .id();
+        app.update();
+
+        let translation = app
+            .world()
+            .get::<GlobalTransform>(entity)
+            .unwrap()
+            .translation();
+        // Depth is left untouched, as it only orders the sprites.
+        assert_eq!(translation, Vec3::new(4.0, -4.0, 1.5));
+    }
+}
@@ -0,0 +1,413 @@
+use alloc::borrow::Cow;
+use core::{hash::Hash, marker::PhantomData};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{Asset, Assets};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    query::{Added, Changed, Or},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut, SystemParamItem},
+};
+use bevy_image::TextureAtlasLayout;
+use bevy_mesh::{Mesh2d, MeshVertexBufferLayoutRef};
+use bevy_platform::{collections::HashSet, hash::FixedHasher};
+use bevy_reflect::{impl_type_path, Reflect};
+use bevy_render::{
+    render_resource::{
+        AsBindGroup, AsBindGroupError, BindGroupLayout, BindGroupLayoutEntry, BindlessDescriptor,
+        BindlessResourceType, BindlessSlabResourceLimit, RenderPipelineDescriptor,
+        SpecializedMeshPipelineError, UnpreparedBindGroup,
+    },
+    renderer::RenderDevice,
+};
+use bevy_shader::ShaderRef;
+use bevy_sprite::{prelude::SpriteMesh, Anchor};
+
+use super::{add_material, add_mesh, sprite_material_for, SpriteMaterial};
+use crate::{
+    check_entities_needing_specialization, AlphaMode2d, Material2d, Material2dKey,
+    Material2dPipeline, Material2dPlugin, Mesh2dPipelineKey, MeshMaterial2d,
+};
+
+/// Adds support for [`SpriteMesh`]es with a [`SpriteMeshExtension<E>`], rendered with an
+/// [`ExtendedSpriteMaterial<E>`].
+pub struct SpriteMaterialExtensionPlugin<E: SpriteMaterialExtension>(PhantomData<E>);
+
+impl<E: SpriteMaterialExtension> Default for SpriteMaterialExtensionPlugin<E> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<E: SpriteMaterialExtension> Plugin for SpriteMaterialExtensionPlugin<E>
+where
+    E::Data: PartialEq + Eq + Hash + Clone,
+{
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<ExtendedSpriteMaterial<E>>::default())
+            .add_systems(
+                PostUpdate,
+                (remove_extended_material::<E>, add_extended_material::<E>)
+                    .chain()
+                    .after(add_mesh)
+                    .before(add_material)
+                    .before(check_entities_needing_specialization::<ExtendedSpriteMaterial<E>>),
+            );
+    }
+}
+
+pub struct SpriteMaterialExtensionKey<E: SpriteMaterialExtension> {
+    pub mesh_key: Mesh2dPipelineKey,
+    pub bind_group_data: E::Data,
+}
+
+/// Custom shaders and data layered on top of a [`SpriteMaterial`], for effects on individual
+/// sprites (dissolves, outlines, flashes...) that still support the whole feature set of
+/// [`SpriteMesh`].
+///
+/// A user type implementing the trait is added to a [`SpriteMesh`] entity through the
+/// [`SpriteMeshExtension`] component, and requires the [`SpriteMaterialExtensionPlugin`].
+///
+/// The bindings of the extension are added to the ones of the [`SpriteMaterial`], which use
+/// the bindings `0` to `10` of the material bind group, so the extension should start at
+/// binding `100`. The extension's fragment shader can import `bevy_sprite::sprite_functions`
+/// to sample the sprite the same way the default shader does:
+///
+/// ```wgsl
+/// #import bevy_sprite::{
+///     mesh2d_vertex_output::VertexOutput,
+///     sprite_functions::sample_final_color,
+/// }
+///
+/// @group(#{MATERIAL_BIND_GROUP}) @binding(100) var<uniform> progress: f32;
+///
+/// @fragment
+/// fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
+///     var color = sample_final_color(mesh.uv, mesh.instance_index);
+///     color.a *= step(mesh.uv.x, progress);
+///     return color;
+/// }
+/// ```
+pub trait SpriteMaterialExtension: Asset + AsBindGroup + Clone + Sized {
+    /// Returns this material's vertex shader. If [`ShaderRef::Default`] is returned, the
+    /// [`SpriteMaterial`] vertex shader will be used.
+    fn vertex_shader() -> ShaderRef {
+        ShaderRef::Default
+    }
+
+    /// Returns this material's fragment shader. If [`ShaderRef::Default`] is returned, the
+    /// [`SpriteMaterial`] fragment shader will be used.
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Default
+    }
+
+    /// Returns this material's [`AlphaMode2d`]. If `None` is returned, the alpha mode of the
+    /// [`SpriteMesh`] will be used.
+    fn alpha_mode() -> Option<AlphaMode2d> {
+        None
+    }
+
+    /// Customizes the default [`RenderPipelineDescriptor`].
+    /// Specialization for the [`SpriteMaterial`] is applied before this function is called.
+    #[expect(
+        unused_variables,
+        reason = "The parameters here are intentionally unused by the default implementation; however, putting underscores here will result in the underscores being copied by rust-analyzer's tab completion."
+    )]
+    #[inline]
+    fn specialize(
+        pipeline: &Material2dPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayoutRef,
+        key: SpriteMaterialExtensionKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        Ok(())
+    }
+}
+
+/// Adds a [`SpriteMaterialExtension`] to a [`SpriteMesh`], rendering it with an
+/// [`ExtendedSpriteMaterial<E>`] instead of the default [`SpriteMaterial`].
+///
+/// Each entity gets its own material, updated in place whenever the extension, the
+/// [`SpriteMesh`] or the [`Anchor`] changes, so the extension can be animated without
+/// allocating new assets.
+#[derive(Component, Clone, Debug, Default)]
+#[require(ExtendedSpriteMesh)]
+pub struct SpriteMeshExtension<E: SpriteMaterialExtension>(pub E);
+
+/// Marks the [`SpriteMesh`]es rendered with an [`ExtendedSpriteMaterial`], so that they don't
+/// get a default [`SpriteMaterial`].
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ExtendedSpriteMesh;
+
+/// A [`SpriteMaterial`] extended with the shaders and data of a [`SpriteMaterialExtension`].
+///
+/// This is the 2D counterpart of the 3D `ExtendedMaterial`, built from the [`SpriteMesh`] and
+/// the [`SpriteMeshExtension`] of an entity.
+#[derive(Asset, Clone, Debug, Reflect)]
+#[reflect(type_path = false)]
+#[reflect(Clone)]
+pub struct ExtendedSpriteMaterial<E: SpriteMaterialExtension> {
+    pub base: SpriteMaterial,
+    pub extension: E,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SpriteMaterialExtensionBindGroupData<B, E> {
+    pub base: B,
+    pub extension: E,
+}
+
+// We don't use the `TypePath` derive here due to a bug where `#[reflect(type_path = false)]`
+// causes the `TypePath` derive to not generate an implementation.
+impl_type_path!((in bevy_sprite_render::sprite_mesh) ExtendedSpriteMaterial<E: SpriteMaterialExtension>);
+
+impl<E: SpriteMaterialExtension> AsBindGroup for ExtendedSpriteMaterial<E> {
+    type Data = SpriteMaterialExtensionBindGroupData<
+        <SpriteMaterial as AsBindGroup>::Data,
+        <E as AsBindGroup>::Data,
+    >;
+    type Param = (
+        <SpriteMaterial as AsBindGroup>::Param,
+        <E as AsBindGroup>::Param,
+    );
+
+    fn bindless_slot_count() -> Option<BindlessSlabResourceLimit> {
+        // Bindless is only enabled if the extension is bindless too, with the smaller of the two
+        // slab size limits.
+        match (
+            SpriteMaterial::bindless_slot_count()?,
+            E::bindless_slot_count()?,
+        ) {
+            (BindlessSlabResourceLimit::Auto, BindlessSlabResourceLimit::Auto) => {
+                Some(BindlessSlabResourceLimit::Auto)
+            }
+            (BindlessSlabResourceLimit::Auto, BindlessSlabResourceLimit::Custom(limit))
+            | (BindlessSlabResourceLimit::Custom(limit), BindlessSlabResourceLimit::Auto) => {
+                Some(BindlessSlabResourceLimit::Custom(limit))
+            }
+            (
+                BindlessSlabResourceLimit::Custom(base_limit),
+                BindlessSlabResourceLimit::Custom(extended_limit),
+            ) => Some(BindlessSlabResourceLimit::Custom(
+                base_limit.min(extended_limit),
+            )),
+        }
+    }
+
+    fn bindless_supported(render_device: &RenderDevice) -> bool {
+        SpriteMaterial::bindless_supported(render_device) && E::bindless_supported(render_device)
+    }
+
+    fn label() -> &'static str {
+        E::label()
+    }
+
+    fn bind_group_data(&self) -> Self::Data {
+        SpriteMaterialExtensionBindGroupData {
+            base: self.base.bind_group_data(),
+            extension: self.extension.bind_group_data(),
+        }
+    }
+
+    fn unprepared_bind_group(
+        &self,
+        layout: &BindGroupLayout,
+        render_device: &RenderDevice,
+        (base_param, extended_param): &mut SystemParamItem<'_, '_, Self::Param>,
+        mut force_non_bindless: bool,
+    ) -> Result<UnpreparedBindGroup, AsBindGroupError> {
+        force_non_bindless = force_non_bindless || Self::bindless_slot_count().is_none();
+
+        let UnpreparedBindGroup { mut bindings } = SpriteMaterial::unprepared_bind_group(
+            &self.base,
+            layout,
+            render_device,
+            base_param,
+            force_non_bindless,
+        )?;
+        let UnpreparedBindGroup {
+            bindings: extension_bindings,
+        } = E::unprepared_bind_group(
+            &self.extension,
+            layout,
+            render_device,
+            extended_param,
+            force_non_bindless,
+        )?;
+
+        bindings.extend(extension_bindings.0);
+
+        Ok(UnpreparedBindGroup { bindings })
+    }
+
+    fn bind_group_layout_entries(
+        render_device: &RenderDevice,
+        mut force_non_bindless: bool,
+    ) -> Vec<BindGroupLayoutEntry>
+    where
+        Self: Sized,
+    {
+        force_non_bindless = force_non_bindless || Self::bindless_slot_count().is_none();
+
+        // The common bindless resource arrays are declared by both materials in bindless mode,
+        // skip the duplicates or `wgpu` will complain.
+        let base_entries =
+            SpriteMaterial::bind_group_layout_entries(render_device, force_non_bindless);
+        let extension_entries = E::bind_group_layout_entries(render_device, force_non_bindless);
+
+        let mut seen_bindings = HashSet::<u32>::with_hasher(FixedHasher);
+
+        base_entries
+            .into_iter()
+            .chain(extension_entries)
+            .filter(|entry| seen_bindings.insert(entry.binding))
+            .collect()
+    }
+
+    fn bindless_descriptor() -> Option<BindlessDescriptor> {
+        let base_bindless_descriptor = SpriteMaterial::bindless_descriptor()?;
+        let extended_bindless_descriptor = E::bindless_descriptor()?;
+
+        let mut buffers = base_bindless_descriptor.buffers.to_vec();
+        let mut index_tables = base_bindless_descriptor.index_tables.to_vec();
+
+        buffers.extend(extended_bindless_descriptor.buffers.iter().cloned());
+        index_tables.extend(extended_bindless_descriptor.index_tables.iter().cloned());
+
+        // The resource arrays are indexed by bindless index, so they are merged, the sprite
+        // material winning any conflict.
+        let max_bindless_index = base_bindless_descriptor
+            .resources
+            .len()
+            .max(extended_bindless_descriptor.resources.len());
+        let mut resources = Vec::with_capacity(max_bindless_index);
+        for bindless_index in 0..max_bindless_index {
+            match base_bindless_descriptor.resources.get(bindless_index) {
+                None | Some(&BindlessResourceType::None) => resources.push(
+                    extended_bindless_descriptor
+                        .resources
+                        .get(bindless_index)
+                        .copied()
+                        .unwrap_or(BindlessResourceType::None),
+                ),
+                Some(&resource_type) => resources.push(resource_type),
+            }
+        }
+
+        Some(BindlessDescriptor {
+            resources: Cow::Owned(resources),
+            buffers: Cow::Owned(buffers),
+            index_tables: Cow::Owned(index_tables),
+        })
+    }
+}
+
+impl<E: SpriteMaterialExtension> Material2d for ExtendedSpriteMaterial<E> {
+    fn vertex_shader() -> ShaderRef {
+        match E::vertex_shader() {
+            ShaderRef::Default => SpriteMaterial::vertex_shader(),
+            specified => specified,
+        }
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        match E::fragment_shader() {
+            ShaderRef::Default => SpriteMaterial::fragment_shader(),
+            specified => specified,
+        }
+    }
+
+    fn depth_bias(&self) -> f32 {
+        self.base.depth_bias()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        E::alpha_mode().unwrap_or_else(|| self.base.alpha_mode())
+    }
+
+    fn specialize(
+        pipeline: &Material2dPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayoutRef,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        SpriteMaterial::specialize(
+            pipeline,
+            descriptor,
+            layout,
+            Material2dKey {
+                mesh_key: key.mesh_key,
+                bind_group_data: key.bind_group_data.base,
+            },
+        )?;
+
+        E::specialize(
+            pipeline,
+            descriptor,
+            layout,
+            SpriteMaterialExtensionKey {
+                mesh_key: key.mesh_key,
+                bind_group_data: key.bind_group_data.extension,
+            },
+        )
+    }
+}
+
+// Build or update the extended material when the SpriteMesh or its extension changes.
+fn add_extended_material<E: SpriteMaterialExtension>(
+    sprites: Query<
+        (
+            Entity,
+            &SpriteMesh,
+            &Anchor,
+            &SpriteMeshExtension<E>,
+            Option<&MeshMaterial2d<ExtendedSpriteMaterial<E>>>,
+        ),
+        Or<(
+            Changed<SpriteMesh>,
+            Changed<Anchor>,
+            Changed<SpriteMeshExtension<E>>,
+            Added<Mesh2d>,
+        )>,
+    >,
+    texture_atlas_layouts: Res<Assets<TextureAtlasLayout>>,
+    mut materials: ResMut<Assets<ExtendedSpriteMaterial<E>>>,
+    mut commands: Commands,
+) {
+    for (entity, sprite, anchor, extension, existing) in sprites {
+        let material = ExtendedSpriteMaterial {
+            base: sprite_material_for(sprite, anchor, &texture_atlas_layouts),
+            extension: extension.0.clone(),
+        };
+
+        if let Some(existing) = existing
+            && let Some(mut existing) = materials.get_mut(existing.id())
+        {
+            *existing = material;
+            continue;
+        }
+
+        commands
+            .entity(entity)
+            .remove::<MeshMaterial2d<SpriteMaterial>>()
+            .insert(MeshMaterial2d(materials.add(material)));
+    }
+}
+
+// Hand the SpriteMesh back to the default SpriteMaterial when its extension is removed.
+fn remove_extended_material<E: SpriteMaterialExtension>(
+    mut removed: RemovedComponents<SpriteMeshExtension<E>>,
+    mut commands: Commands,
+) {
+    for entity in removed.read() {
+        if let Ok(mut entity) = commands.get_entity(entity) {
+            entity.try_remove::<(
+                ExtendedSpriteMesh,
+                MeshMaterial2d<ExtendedSpriteMaterial<E>>,
+            )>();
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use bevy_app::{Plugin, PostUpdate};
 use bevy_ecs::{
     entity::Entity,
-    query::{Added, Changed, Or},
+    query::{Added, Changed, Or, Without},
     schedule::IntoScheduleConfigs,
     system::{Commands, Local, Query, Res, ResMut},
 };
@@ -16,7 +16,9 @@ use bevy_platform::collections::HashMap;
 use bevy_shader::load_shader_library;
 use bevy_sprite::{prelude::SpriteMesh, Anchor};
 
+mod extended_sprite_material;
 mod sprite_material;
+pub use extended_sprite_material::*;
 pub use sprite_material::*;
 
 use crate::{check_entities_needing_specialization, MeshMaterial2d};
@@ -68,13 +70,20 @@ fn add_mesh(
 
 // Change the material when SpriteMesh is added / changed.
 //
-// NOTE: This also adds the SpriteAtlasLayout into the SpriteMaterial,
-// but this should instead be read later, similar to the images, allowing
-// for hot reload.
+// SpriteMeshes rendered with an `ExtendedSpriteMaterial` are skipped, and the
+// ones without a material (e.g. after their extension got removed) are picked up.
 fn add_material(
     sprites: Query<
         (Entity, &SpriteMesh, &Anchor),
-        Or<(Changed<SpriteMesh>, Changed<Anchor>, Added<Mesh2d>)>,
+        (
+            Or<(
+                Changed<SpriteMesh>,
+                Changed<Anchor>,
+                Added<Mesh2d>,
+                Without<MeshMaterial2d<SpriteMaterial>>,
+            )>,
+            Without<ExtendedSpriteMesh>,
+        ),
     >,
     texture_atlas_layouts: Res<Assets<TextureAtlasLayout>>,
     mut cached_materials: Local<HashMap<(SpriteMesh, Anchor), Handle<SpriteMaterial>>>,
@@ -87,16 +96,7 @@ fn add_material(
                 .entity(entity)
                 .insert(MeshMaterial2d(handle.clone()));
         } else {
-            let mut material = SpriteMaterial::from_sprite_mesh(sprite.clone());
-            material.anchor = **anchor;
-
-            if let Some(texture_atlas) = &sprite.texture_atlas
-                && let Some(texture_atlas_layout) =
-                    texture_atlas_layouts.get(texture_atlas.layout.id())
-            {
-                material.texture_atlas_layout = Some(texture_atlas_layout.clone());
-                material.texture_atlas_index = texture_atlas.index;
-            }
+            let material = sprite_material_for(sprite, anchor, &texture_atlas_layouts);
 
             let handle = materials.add(material);
             cached_materials.insert((sprite.clone(), *anchor), handle.clone());
@@ -107,3 +107,26 @@ fn add_material(
         }
     }
 }
+
+// Build the SpriteMaterial of a SpriteMesh.
+//
+// NOTE: This also adds the SpriteAtlasLayout into the SpriteMaterial,
+// but this should instead be read later, similar to the images, allowing
+// for hot reload.
+fn sprite_material_for(
+    sprite: &SpriteMesh,
+    anchor: &Anchor,
+    texture_atlas_layouts: &Assets<TextureAtlasLayout>,
+) -> SpriteMaterial {
+    let mut material = SpriteMaterial::from_sprite_mesh(sprite.clone());
+    material.anchor = **anchor;
+
+    if let Some(texture_atlas) = &sprite.texture_atlas
+        && let Some(texture_atlas_layout) = texture_atlas_layouts.get(texture_atlas.layout.id())
+    {
+        material.texture_atlas_layout = Some(texture_atlas_layout.clone());
+        material.texture_atlas_index = texture_atlas.index;
+    }
+
+    material
+}
@@ -66,7 +66,9 @@ pub type BoxedFuture<'a, T> = core::pin::Pin<Box<dyn ConditionalSendFuture<Outpu
 mod executor;
 pub mod futures;
 mod iter;
+mod priority;
 mod slice;
+mod stats;
 mod usages;
 
 cfg::async_executor! {
@@ -78,7 +80,9 @@ cfg::async_executor! {
 // Exports
 pub use async_task::Task;
 pub use iter::ParallelIterator;
+pub use priority::TaskPriority;
 pub use slice::{ParallelSlice, ParallelSliceMut};
+pub use stats::TaskPoolStats;
 pub use usages::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool};
 
 pub use futures_lite;
@@ -0,0 +1,18 @@
+/// The priority of a task spawned with
+/// [`TaskPool::spawn_with_priority`](crate::TaskPool::spawn_with_priority).
+///
+/// Priorities are not preemptive: a running task is never interrupted. Instead, the threads of a
+/// pool check for higher priority tasks between short batches of tasks, so a high priority task
+/// starts ahead of the normal and low priority tasks that are waiting to run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// Runs ahead of the normal and low priority tasks of the pool, for example latency-sensitive
+    /// work that the next frame depends on.
+    High,
+    /// The priority of tasks spawned with [`TaskPool::spawn`](crate::TaskPool::spawn).
+    #[default]
+    Normal,
+    /// Only runs when the threads of the pool are not busy with normal priority tasks, for
+    /// example background work like prefetching assets.
+    Low,
+}
@@ -3,7 +3,8 @@ use bevy_platform::sync::Arc;
 use core::{cell::{RefCell, Cell}, future::Future, marker::PhantomData, mem};
 
 use crate::executor::LocalExecutor;
-use crate::{block_on, Task};
+use crate::stats::{TaskPoolCounters, TaskPoolStats};
+use crate::{block_on, Task, TaskPriority};
 
 crate::cfg::std! {
     if {
@@ -81,7 +82,9 @@ impl TaskPoolBuilder {
 /// A thread pool for executing tasks. Tasks are futures that are being automatically driven by
 /// the pool on threads owned by the pool. In this case - main thread only.
 #[derive(Debug, Default, Clone)]
-pub struct TaskPool {}
+pub struct TaskPool {
+    counters: Arc<TaskPoolCounters>,
+}
 
 impl TaskPool {
     /// Just create a new `ThreadExecutor` for wasm
@@ -95,7 +98,7 @@ impl TaskPool {
     }
 
     fn new_internal() -> Self {
-        Self {}
+        Self::default()
     }
 
     /// Return the number of threads owned by the task pool
@@ -103,6 +106,14 @@ impl TaskPool {
         1
     }
 
+    /// Returns a snapshot of the tasks currently running on the pool.
+    pub fn stats(&self) -> TaskPoolStats {
+        self.counters.stats(self.thread_num())
+    }
+
+    /// No op on the single threaded task pool, which always runs on a single thread.
+    pub fn resize(&self, _num_threads: usize) {}
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -192,6 +203,12 @@ impl TaskPool {
     where
         T: 'static + MaybeSend + MaybeSync,
     {
+        let tracked = TaskPoolCounters::track_task(&self.counters);
+        let future = async move {
+            let _tracked = tracked;
+            future.await
+        };
+
         crate::cfg::switch! {{
             crate::cfg::web => {
                 web_task::spawn_local(future)
@@ -213,6 +230,35 @@ impl TaskPool {
         }}
     }
 
+    /// Spawns a static future with the given [`TaskPriority`].
+    ///
+    /// The single threaded task pool runs its tasks in the order they are spawned, so this is
+    /// exactly the same as [`TaskPool::spawn`].
+    pub fn spawn_with_priority<T>(
+        &self,
+        _priority: TaskPriority,
+        future: impl Future<Output = T> + 'static + MaybeSend + MaybeSync,
+    ) -> Task<T>
+    where
+        T: 'static + MaybeSend + MaybeSync,
+    {
+        self.spawn(future)
+    }
+
+    /// Spawns a static future that is expected to run for a long time.
+    ///
+    /// There are no other threads to dedicate to the task on the single threaded task pool, so
+    /// this is exactly the same as [`TaskPool::spawn`].
+    pub fn spawn_dedicated<T>(
+        &self,
+        future: impl Future<Output = T> + 'static + MaybeSend + MaybeSync,
+    ) -> Task<T>
+    where
+        T: 'static + MaybeSend + MaybeSync,
+    {
+        self.spawn(future)
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskPool::spawn`].
     pub fn spawn_local<T>(
         &self,
@@ -355,7 +401,7 @@ mod test {
     #[test]
     fn scoped_spawn() {
         let (sender, receiver) = async_channel::unbounded();
-        let task_pool = TaskPool {};
+        let task_pool = TaskPool::new();
         let thread = thread::spawn(move || {
             let duration = time::Duration::from_millis(50);
             thread::sleep(duration);
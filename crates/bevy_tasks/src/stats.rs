@@ -0,0 +1,96 @@
+use bevy_platform::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// A snapshot of the work performed by a [`TaskPool`](crate::TaskPool).
+///
+/// Returned by [`TaskPool::stats`](crate::TaskPool::stats). Only tasks spawned with
+/// [`TaskPool::spawn`](crate::TaskPool::spawn),
+/// [`TaskPool::spawn_with_priority`](crate::TaskPool::spawn_with_priority) and
+/// [`TaskPool::spawn_dedicated`](crate::TaskPool::spawn_dedicated) are tracked, scoped tasks
+/// are not.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TaskPoolStats {
+    /// The number of threads owned by the pool, see [`TaskPool::thread_num`](crate::TaskPool::thread_num).
+    pub threads: usize,
+    /// The number of tasks that have been spawned but are not finished or canceled yet.
+    pub active_tasks: usize,
+    /// The total number of tasks spawned on the pool since it was created.
+    pub spawned_tasks: usize,
+    /// The number of dedicated threads currently running a task spawned with
+    /// [`TaskPool::spawn_dedicated`](crate::TaskPool::spawn_dedicated).
+    pub dedicated_threads: usize,
+}
+
+impl TaskPoolStats {
+    /// Returns the number of active tasks per pool thread.
+    ///
+    /// A value above `1.0` means that tasks are waiting for a thread to become available, or are
+    /// waiting on IO.
+    pub fn load(&self) -> f64 {
+        self.active_tasks as f64 / self.threads.max(1) as f64
+    }
+}
+
+/// The counters backing [`TaskPoolStats`], shared between a pool and its tracked tasks.
+#[derive(Debug, Default)]
+pub(crate) struct TaskPoolCounters {
+    active_tasks: AtomicUsize,
+    spawned_tasks: AtomicUsize,
+    dedicated_threads: AtomicUsize,
+}
+
+impl TaskPoolCounters {
+    /// Records a newly spawned task. The task counts as active until the returned guard is dropped.
+    pub(crate) fn track_task(counters: &Arc<Self>) -> TrackedTask {
+        counters.spawned_tasks.fetch_add(1, Ordering::Relaxed);
+        counters.active_tasks.fetch_add(1, Ordering::Relaxed);
+        TrackedTask {
+            counters: Arc::clone(counters),
+            dedicated: false,
+        }
+    }
+
+    /// Records a newly spawned task running on its own thread.
+    #[cfg_attr(
+        not(all(not(target_arch = "wasm32"), feature = "multi_threaded")),
+        expect(
+            dead_code,
+            reason = "only dedicated threads in the multi-threaded pool"
+        )
+    )]
+    pub(crate) fn track_dedicated_task(counters: &Arc<Self>) -> TrackedTask {
+        counters.dedicated_threads.fetch_add(1, Ordering::Relaxed);
+        let mut tracked = Self::track_task(counters);
+        tracked.dedicated = true;
+        tracked
+    }
+
+    pub(crate) fn stats(&self, threads: usize) -> TaskPoolStats {
+        TaskPoolStats {
+            threads,
+            active_tasks: self.active_tasks.load(Ordering::Relaxed),
+            spawned_tasks: self.spawned_tasks.load(Ordering::Relaxed),
+            dedicated_threads: self.dedicated_threads.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Marks a task as finished once dropped, which happens when its future completes or the task is
+/// canceled.
+pub(crate) struct TrackedTask {
+    counters: Arc<TaskPoolCounters>,
+    dedicated: bool,
+}
+
+impl Drop for TrackedTask {
+    fn drop(&mut self) {
+        self.counters.active_tasks.fetch_sub(1, Ordering::Relaxed);
+        if self.dedicated {
+            self.counters
+                .dedicated_threads
+                .fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
@@ -1,19 +1,35 @@
-use alloc::{boxed::Box, format, string::String, vec::Vec};
-use core::{future::Future, marker::PhantomData, mem, panic::AssertUnwindSafe};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    mem,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll},
+};
 use std::{
     thread::{self, JoinHandle},
     thread_local,
 };
 
 use crate::executor::FallibleTask;
-use bevy_platform::sync::Arc;
+use bevy_platform::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex, PoisonError,
+};
 use concurrent_queue::ConcurrentQueue;
 use futures_lite::FutureExt;
 
 use crate::{
     block_on,
+    stats::{TaskPoolCounters, TaskPoolStats},
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
-    Task,
+    Task, TaskPriority,
 };
 
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
@@ -26,6 +42,15 @@ impl Drop for CallOnDrop {
     }
 }
 
+/// Closes the channel of a dedicated thread once dropped, which stops the thread.
+struct CloseOnDrop(async_channel::Sender<async_task::Runnable>);
+
+impl Drop for CloseOnDrop {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
 /// Used to create a [`TaskPool`]
 #[derive(Default)]
 #[must_use]
@@ -131,16 +156,142 @@ impl TaskPoolBuilder {
 ///
 /// If the result is not required, one may also use [`Task::detach`] and the pool
 /// will still execute a task, even if it is dropped.
+///
+/// Long-running tasks, such as pathfinding or world generation, can occupy pool threads for
+/// many frames and starve other work spawned on the same pool. Such tasks should be spawned
+/// with [`TaskPool::spawn_dedicated`], which runs them on their own thread instead.
+///
+/// Tasks can be given a [`TaskPriority`] with [`TaskPool::spawn_with_priority`], and the number
+/// of threads of a pool can be changed after it is built with [`TaskPool::resize`].
 #[derive(Debug)]
 pub struct TaskPool {
     /// The executor for the pool.
     executor: Arc<crate::executor::Executor<'static>>,
+    /// The executor for the tasks spawned with [`TaskPriority::High`].
+    high_priority_executor: Arc<crate::executor::Executor<'static>>,
+    /// The executor for the tasks spawned with [`TaskPriority::Low`].
+    low_priority_executor: Arc<crate::executor::Executor<'static>>,
 
     // The inner state of the pool.
-    threads: Vec<JoinHandle<()>>,
+    threads: Mutex<PoolThreads>,
+    thread_num: AtomicUsize,
+    counters: Arc<TaskPoolCounters>,
+    thread_config: ThreadConfig,
+}
+
+/// The threads of a [`TaskPool`].
+#[derive(Debug, Default)]
+struct PoolThreads {
+    /// The threads running the tasks of the pool.
+    running: Vec<PoolThread>,
+    /// The threads removed by [`TaskPool::resize`], which exit once they are done with their
+    /// current task.
+    stopped: Vec<JoinHandle<()>>,
+}
+
+/// A thread running the tasks of a [`TaskPool`].
+#[derive(Debug)]
+struct PoolThread {
+    handle: JoinHandle<()>,
+    /// Closing this channel stops the thread.
     shutdown_tx: async_channel::Sender<()>,
 }
 
+/// The configuration used to spawn the threads of a [`TaskPool`].
+struct ThreadConfig {
+    thread_name: String,
+    stack_size: Option<usize>,
+    on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+}
+
+impl ThreadConfig {
+    /// Spawns a thread named `<thread_name> (<suffix>)` running `f`, between the
+    /// `on_thread_spawn` and `on_thread_destroy` callbacks.
+    fn spawn(&self, suffix: &str, f: impl FnOnce() + Send + 'static) -> JoinHandle<()> {
+        let mut thread_builder =
+            thread::Builder::new().name(format!("{} ({suffix})", self.thread_name));
+        if let Some(stack_size) = self.stack_size {
+            thread_builder = thread_builder.stack_size(stack_size);
+        }
+        let on_thread_spawn = self.on_thread_spawn.clone();
+        let on_thread_destroy = self.on_thread_destroy.clone();
+
+        thread_builder
+            .spawn(move || {
+                if let Some(on_thread_spawn) = on_thread_spawn {
+                    on_thread_spawn();
+                    drop(on_thread_spawn);
+                }
+                let _destructor = CallOnDrop(on_thread_destroy);
+                f();
+            })
+            .expect("Failed to spawn thread.")
+    }
+}
+
+impl core::fmt::Debug for ThreadConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ThreadConfig")
+            .field("thread_name", &self.thread_name)
+            .field("stack_size", &self.stack_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs the tasks of a pool thread.
+///
+/// High priority tasks run before anything else. Normal priority tasks run in batches, and a
+/// low priority task only runs between two batches, or when no normal priority task is ready.
+struct PriorityRunner<'a, F> {
+    high_priority_executor: &'a crate::executor::Executor<'static>,
+    low_priority_executor: &'a crate::executor::Executor<'static>,
+    /// Runs the normal priority tasks until the thread is stopped.
+    run: Pin<Box<F>>,
+    high_priority_tick: Pin<Box<dyn Future<Output = ()> + 'a>>,
+    low_priority_tick: Pin<Box<dyn Future<Output = ()> + 'a>>,
+}
+
+impl<'a, F: Future> PriorityRunner<'a, F> {
+    fn new(
+        high_priority_executor: &'a crate::executor::Executor<'static>,
+        low_priority_executor: &'a crate::executor::Executor<'static>,
+        run: F,
+    ) -> Self {
+        Self {
+            high_priority_executor,
+            low_priority_executor,
+            run: Box::pin(run),
+            high_priority_tick: Box::pin(high_priority_executor.tick()),
+            low_priority_tick: Box::pin(low_priority_executor.tick()),
+        }
+    }
+}
+
+impl<F: Future> Future for PriorityRunner<'_, F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let this = self.get_mut();
+        loop {
+            // Each tick runs a single task, so the higher priorities are checked again after it.
+            if this.high_priority_tick.as_mut().poll(cx).is_ready() {
+                this.high_priority_tick = Box::pin(this.high_priority_executor.tick());
+                continue;
+            }
+            // This yields after a batch of tasks, even if more normal priority tasks are ready.
+            if let Poll::Ready(output) = this.run.as_mut().poll(cx) {
+                return Poll::Ready(output);
+            }
+            if this.low_priority_tick.as_mut().poll(cx).is_ready() {
+                this.low_priority_tick = Box::pin(this.low_priority_executor.tick());
+                continue;
+            }
+            return Poll::Pending;
+        }
+    }
+}
+
 impl TaskPool {
     thread_local! {
         static LOCAL_EXECUTOR: crate::executor::LocalExecutor<'static> = const { crate::executor::LocalExecutor::new() };
@@ -158,72 +309,110 @@ impl TaskPool {
     }
 
     fn new_internal(builder: TaskPoolBuilder) -> Self {
-        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
-
-        let executor = Arc::new(crate::executor::Executor::new());
-
         let num_threads = builder
             .num_threads
             .unwrap_or_else(crate::available_parallelism);
 
-        let threads = (0..num_threads)
-            .map(|i| {
-                let ex = Arc::clone(&executor);
-                let shutdown_rx = shutdown_rx.clone();
-
-                let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
-                    format!("{thread_name} ({i})")
-                } else {
-                    format!("TaskPool ({i})")
-                };
-                let mut thread_builder = thread::Builder::new().name(thread_name);
+        let pool = Self {
+            executor: Arc::new(crate::executor::Executor::new()),
+            high_priority_executor: Arc::new(crate::executor::Executor::new()),
+            low_priority_executor: Arc::new(crate::executor::Executor::new()),
+            threads: Mutex::new(PoolThreads::default()),
+            thread_num: AtomicUsize::new(0),
+            counters: Arc::new(TaskPoolCounters::default()),
+            thread_config: ThreadConfig {
+                thread_name: builder
+                    .thread_name
+                    .unwrap_or_else(|| String::from("TaskPool")),
+                stack_size: builder.stack_size,
+                on_thread_spawn: builder.on_thread_spawn,
+                on_thread_destroy: builder.on_thread_destroy,
+            },
+        };
+        pool.resize(num_threads);
+        pool
+    }
 
-                if let Some(stack_size) = builder.stack_size {
-                    thread_builder = thread_builder.stack_size(stack_size);
+    /// Spawns a thread running the tasks of the pool, which stops once `shutdown_tx` is closed.
+    fn spawn_pool_thread(&self, index: usize) -> PoolThread {
+        let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
+        let ex = Arc::clone(&self.executor);
+        let high_priority_ex = Arc::clone(&self.high_priority_executor);
+        let low_priority_ex = Arc::clone(&self.low_priority_executor);
+
+        let handle = self.thread_config.spawn(&index.to_string(), move || {
+            TaskPool::LOCAL_EXECUTOR.with(|local_executor| loop {
+                let res = std::panic::catch_unwind(|| {
+                    let tick_forever = async move {
+                        loop {
+                            local_executor.tick().await;
+                        }
+                    };
+                    block_on(PriorityRunner::new(
+                        &high_priority_ex,
+                        &low_priority_ex,
+                        ex.run(tick_forever.or(shutdown_rx.recv())),
+                    ))
+                });
+                if let Ok(value) = res {
+                    // Use unwrap_err because we expect a Closed error
+                    value.unwrap_err();
+                    break;
                 }
+            });
+        });
 
-                let on_thread_spawn = builder.on_thread_spawn.clone();
-                let on_thread_destroy = builder.on_thread_destroy.clone();
-
-                thread_builder
-                    .spawn(move || {
-                        TaskPool::LOCAL_EXECUTOR.with(|local_executor| {
-                            if let Some(on_thread_spawn) = on_thread_spawn {
-                                on_thread_spawn();
-                                drop(on_thread_spawn);
-                            }
-                            let _destructor = CallOnDrop(on_thread_destroy);
-                            loop {
-                                let res = std::panic::catch_unwind(|| {
-                                    let tick_forever = async move {
-                                        loop {
-                                            local_executor.tick().await;
-                                        }
-                                    };
-                                    block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
-                                });
-                                if let Ok(value) = res {
-                                    // Use unwrap_err because we expect a Closed error
-                                    value.unwrap_err();
-                                    break;
-                                }
-                            }
-                        });
-                    })
-                    .expect("Failed to spawn thread.")
-            })
-            .collect();
-
-        Self {
-            executor,
-            threads,
+        PoolThread {
+            handle,
             shutdown_tx,
         }
     }
 
+    /// Changes the number of threads owned by the task pool.
+    ///
+    /// New threads are spawned right away. Removed threads stop once they are done with their
+    /// current task, and the tasks they have not started yet are left to the other threads of the
+    /// pool. Tasks spawned with [`TaskPool::spawn_local`] on a removed thread never complete.
+    ///
+    /// ```
+    /// use bevy_tasks::{block_on, TaskPoolBuilder};
+    ///
+    /// let pool = TaskPoolBuilder::new().num_threads(2).build();
+    /// pool.resize(4);
+    /// assert_eq!(pool.thread_num(), 4);
+    /// assert_eq!(block_on(pool.spawn(async { 1 + 1 })), 2);
+    /// ```
+    pub fn resize(&self, num_threads: usize) {
+        let mut threads = self.threads.lock().unwrap_or_else(PoisonError::into_inner);
+        // Stopped threads that have exited don't need to be joined when the pool is dropped.
+        threads.stopped.retain(|handle| !handle.is_finished());
+
+        while threads.running.len() > num_threads {
+            let Some(thread) = threads.running.pop() else {
+                break;
+            };
+            thread.shutdown_tx.close();
+            threads.stopped.push(thread.handle);
+        }
+        while threads.running.len() < num_threads {
+            let thread = self.spawn_pool_thread(threads.running.len());
+            threads.running.push(thread);
+        }
+
+        self.thread_num.store(num_threads, Ordering::Relaxed);
+    }
+
     /// Return the number of threads owned by the task pool
     pub fn thread_num(&self) -> usize {
-        self.threads.len()
+        self.thread_num.load(Ordering::Relaxed)
+    }
+
+    /// Returns a snapshot of the tasks currently running on the pool.
+    ///
+    /// This can be used to monitor the utilization of a pool, for example with
+    /// `bevy_diagnostic`'s `TaskPoolDiagnosticsPlugin`.
+    pub fn stats(&self) -> TaskPoolStats {
+        self.counters.stats(self.thread_num())
     }
 
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
@@ -426,7 +615,7 @@ impl TaskPool {
                     results
                 };
 
-                let tick_task_pool_executor = tick_task_pool_executor || self.threads.is_empty();
+                let tick_task_pool_executor = tick_task_pool_executor || self.thread_num() == 0;
 
                 // we get this from a thread local so we should always be on the scope executors thread.
                 // note: it is possible `scope_executor` and `external_executor` is the same executor,
@@ -560,7 +749,96 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        self.executor.spawn(future)
+        self.spawn_with_priority(TaskPriority::Normal, future)
+    }
+
+    /// Spawns a static future onto the thread pool with the given [`TaskPriority`].
+    ///
+    /// The threads of the pool run high priority tasks ahead of the other tasks, and only run
+    /// low priority tasks when they are not busy with normal priority ones. A pool without any
+    /// threads runs all its tasks with [`TaskPriority::Normal`], as they are only run by
+    /// [`TaskPool::scope`].
+    ///
+    /// Like with [`TaskPool::spawn`], the returned [`Task`] can be polled for the result,
+    /// canceled, or detached.
+    ///
+    /// ```
+    /// use bevy_tasks::{block_on, TaskPool, TaskPriority};
+    ///
+    /// let pool = TaskPool::new();
+    /// let task = pool.spawn_with_priority(TaskPriority::Low, async { 1 + 1 });
+    /// assert_eq!(block_on(task), 2);
+    /// ```
+    pub fn spawn_with_priority<T>(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let executor = match priority {
+            // Scopes only tick the normal priority executor, and they are the only ones running the
+            // tasks of a pool without threads.
+            _ if self.thread_num() == 0 => &self.executor,
+            TaskPriority::High => &self.high_priority_executor,
+            TaskPriority::Normal => &self.executor,
+            TaskPriority::Low => &self.low_priority_executor,
+        };
+        let tracked = TaskPoolCounters::track_task(&self.counters);
+        executor.spawn(async move {
+            let _tracked = tracked;
+            future.await
+        })
+    }
+
+    /// Spawns a static future onto a new thread dedicated to running it, instead of onto the
+    /// threads of the pool.
+    ///
+    /// This should be preferred over [`TaskPool::spawn`] for long-running, CPU-heavy tasks that
+    /// would otherwise occupy a pool thread for a long time and delay other tasks spawned on the
+    /// pool. The thread is named after the pool and exits once the task completes or is
+    /// canceled.
+    ///
+    /// Like with [`TaskPool::spawn`], the returned [`Task`] can be polled for the result,
+    /// canceled, or detached.
+    ///
+    /// ```
+    /// use bevy_tasks::{block_on, TaskPool};
+    ///
+    /// let pool = TaskPool::new();
+    /// let task = pool.spawn_dedicated(async { (0..1000u64).sum::<u64>() });
+    /// assert_eq!(block_on(task), 499500);
+    /// ```
+    pub fn spawn_dedicated<T>(&self, future: impl Future<Output = T> + Send + 'static) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        // The thread runs the task whenever it is woken, and exits once the task completes or is
+        // canceled, as dropping the future closes the channel.
+        let (runnable_tx, runnable_rx) = async_channel::unbounded::<async_task::Runnable>();
+        let tracked = TaskPoolCounters::track_dedicated_task(&self.counters);
+        let finished = CloseOnDrop(runnable_tx.clone());
+        let future = async move {
+            let _tracked = tracked;
+            let _finished = finished;
+            future.await
+        };
+
+        let (runnable, task) = async_task::spawn(future, move |runnable| {
+            // The receiver is only dropped once the thread exits, at which point the task is
+            // already finished.
+            let _ = runnable_tx.try_send(runnable);
+        });
+
+        self.thread_config.spawn("dedicated", move || {
+            while let Ok(runnable) = runnable_rx.recv_blocking() {
+                runnable.run();
+            }
+        });
+
+        runnable.schedule();
+        task
     }
 
     /// Spawns a static future on the thread-local async executor for the
@@ -608,10 +886,18 @@ impl Default for TaskPool {
 
 impl Drop for TaskPool {
     fn drop(&mut self) {
-        self.shutdown_tx.close();
+        let threads = mem::take(
+            self.threads
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        for thread in &threads.running {
+            thread.shutdown_tx.close();
+        }
 
         let panicking = thread::panicking();
-        for join_handle in self.threads.drain(..) {
+        let join_handles = threads.running.into_iter().map(|thread| thread.handle);
+        for join_handle in join_handles.chain(threads.stopped) {
             let res = join_handle.join();
             if !panicking {
                 res.expect("Task thread panicked while executing.");
@@ -964,4 +1250,128 @@ mod tests {
 
         assert_eq!(count.load(Ordering::Acquire), 1);
     }
+
+    #[test]
+    fn test_spawn_stats() {
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+        let (tx, rx) = async_channel::unbounded::<()>();
+
+        let task = pool.spawn(async move { rx.recv().await.unwrap() });
+        let stats = pool.stats();
+        assert_eq!(stats.threads, 2);
+        assert_eq!(stats.active_tasks, 1);
+        assert_eq!(stats.spawned_tasks, 1);
+
+        tx.send_blocking(()).unwrap();
+        block_on(task);
+        assert_eq!(pool.stats().active_tasks, 0);
+        assert_eq!(pool.stats().spawned_tasks, 1);
+    }
+
+    #[test]
+    fn test_spawn_dedicated() {
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .thread_name("Pathfinding".into())
+            .build();
+        let (tx, rx) = async_channel::unbounded::<()>();
+
+        let task = pool.spawn_dedicated(async move {
+            rx.recv().await.unwrap();
+            thread::current().name().map(String::from)
+        });
+        assert_eq!(pool.stats().dedicated_threads, 1);
+
+        // The pool thread is not blocked by the dedicated task.
+        assert_eq!(block_on(pool.spawn(async { 1 })), 1);
+
+        tx.send_blocking(()).unwrap();
+        assert_eq!(block_on(task).as_deref(), Some("Pathfinding (dedicated)"));
+        assert_eq!(pool.stats().dedicated_threads, 0);
+        assert_eq!(pool.stats().active_tasks, 0);
+    }
+
+    #[test]
+    fn test_spawn_dedicated_thread_exits_once_finished() {
+        let destroyed = Arc::new(AtomicI32::new(0));
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .on_thread_destroy({
+                let destroyed = destroyed.clone();
+                move || {
+                    destroyed.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+            .build();
+
+        let task = pool.spawn_dedicated(async {});
+        // The thread exits even though the task is still held.
+        while destroyed.load(Ordering::Relaxed) == 0 {
+            thread::yield_now();
+        }
+        assert!(task.is_finished());
+    }
+
+    #[test]
+    fn test_spawn_with_priority() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let (started_tx, started_rx) = std::sync::mpsc::channel::<()>();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+
+        // Keep the only thread of the pool busy while the other tasks are spawned.
+        let blocker = pool.spawn_with_priority(TaskPriority::High, async move {
+            started_tx.send(()).unwrap();
+            release_rx.recv().unwrap();
+        });
+        started_rx.recv().unwrap();
+
+        let tasks = [TaskPriority::Low, TaskPriority::Normal, TaskPriority::High].map(|priority| {
+            let order = order.clone();
+            pool.spawn_with_priority(priority, async move {
+                order.lock().unwrap().push(priority);
+            })
+        });
+
+        release_tx.send(()).unwrap();
+        block_on(blocker);
+        for task in tasks {
+            block_on(task);
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            [TaskPriority::High, TaskPriority::Normal, TaskPriority::Low]
+        );
+    }
+
+    #[test]
+    fn test_resize() {
+        let pool = TaskPoolBuilder::new().num_threads(2).build();
+        assert_eq!(pool.thread_num(), 2);
+
+        pool.resize(4);
+        assert_eq!(pool.thread_num(), 4);
+        assert_eq!(pool.stats().threads, 4);
+        let tasks: Vec<_> = (0..16).map(|i| pool.spawn(async move { i })).collect();
+        assert_eq!(tasks.into_iter().map(block_on).sum::<i32>(), 120);
+
+        pool.resize(1);
+        assert_eq!(pool.thread_num(), 1);
+        assert_eq!(pool.stats().threads, 1);
+        assert_eq!(block_on(pool.spawn(async { 1 })), 1);
+        assert_eq!(
+            block_on(pool.spawn_with_priority(TaskPriority::Low, async { 2 })),
+            2
+        );
+
+        // Without threads, the tasks are run by the scopes.
+        pool.resize(0);
+        assert_eq!(pool.thread_num(), 0);
+        let task = pool.spawn_with_priority(TaskPriority::High, async { 3 });
+        let results = pool.scope(|s| {
+            s.spawn(async { 1 });
+            s.spawn(task);
+        });
+        assert_eq!(results, [1, 3]);
+    }
 }
@@ -0,0 +1,335 @@
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{message::message_update_system, prelude::*};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect, TypePath};
+use core::{fmt, marker::PhantomData, time::Duration};
+
+use crate::{real::Real, time::Time, time_system, virt::Virtual, TimeSystems};
+
+/// A pausable and scalable clock dedicated to one "channel" of app logic.
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Channel<C>>#impl-Time<Channel<C>>`].**
+///
+/// While [`Time<Virtual>`](Virtual) affects every system reading the default [`Time`],
+/// channel clocks let different parts of an app run at different speeds. For example,
+/// gameplay can be slowed down for a bullet-time effect while UI animations keep running
+/// at normal speed, without touching how individual systems use their delta time.
+///
+/// Channels are identified by a marker type `C` and added with [`TimeChannelPlugin`].
+/// Systems then read the clock by type:
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{Channel, ChannelSource, TimeChannelPlugin, prelude::*};
+/// struct Gameplay;
+/// struct Ui;
+///
+/// let mut app = App::new();
+/// app.add_plugins((
+///     TimeChannelPlugin::<Gameplay>::default(),
+///     // UI keeps animating while the virtual clock is paused.
+///     TimeChannelPlugin::<Ui>::new(ChannelSource::Real),
+/// ));
+///
+/// fn bullet_time(mut gameplay: ResMut<Time<Channel<Gameplay>>>) {
+///     gameplay.set_relative_speed(0.2);
+/// }
+///
+/// fn move_enemies(time: Res<Time<Channel<Gameplay>>>) {
+///     let _distance = 10.0 * time.delta_secs();
+/// }
+/// ```
+///
+/// Each update, the channel advances by the delta of its [`ChannelSource`] multiplied by
+/// its [`relative_speed()`](Time::relative_speed). A paused channel does not advance.
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, Default),
+    reflect(where C: TypePath)
+)]
+pub struct Channel<C> {
+    source: ChannelSource,
+    paused: bool,
+    relative_speed: f64,
+    effective_speed: f64,
+    #[cfg_attr(feature = "bevy_reflect", reflect(ignore))]
+    marker: PhantomData<fn() -> C>,
+}
+
+/// The clock a [`Channel`] derives its time from.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Default, Clone, PartialEq, Hash)
+)]
+pub enum ChannelSource {
+    /// Advance with [`Time<Virtual>`](Virtual).
+    ///
+    /// Pausing or scaling the virtual clock also pauses or scales the channel.
+    #[default]
+    Virtual,
+    /// Advance with [`Time<Real>`](Real).
+    ///
+    /// The channel keeps running while the virtual clock is paused, which is usually what
+    /// menus and UI want.
+    Real,
+}
+
+impl<C> Channel<C> {
+    /// Creates a new channel context that advances with the provided `source` clock.
+    pub fn new(source: ChannelSource) -> Self {
+        Self {
+            source,
+            paused: false,
+            relative_speed: 1.0,
+            effective_speed: 1.0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for Channel<C> {
+    fn default() -> Self {
+        Self::new(ChannelSource::default())
+    }
+}
+
+impl<C> Clone for Channel<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Channel<C> {}
+
+impl<C> fmt::Debug for Channel<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Channel")
+            .field("source", &self.source)
+            .field("paused", &self.paused)
+            .field("relative_speed", &self.relative_speed)
+            .field("effective_speed", &self.effective_speed)
+            .finish()
+    }
+}
+
+impl<C> Time<Channel<C>> {
+    /// Returns the clock this channel derives its time from.
+    #[inline]
+    pub fn source(&self) -> ChannelSource {
+        self.context().source
+    }
+
+    /// Sets the clock this channel derives its time from.
+    ///
+    /// This takes effect on the next update.
+    #[inline]
+    pub fn set_source(&mut self, source: ChannelSource) {
+        self.context_mut().source = source;
+    }
+
+    /// Returns the speed the channel advances relative to its source clock, as [`f32`].
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed_f64() as f32
+    }
+
+    /// Returns the speed the channel advances relative to its source clock, as [`f64`].
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    /// Returns the speed the channel advanced relative to its source clock in this update,
+    /// as [`f32`].
+    ///
+    /// Returns `0.0` if the channel was paused, otherwise the same as
+    /// [`relative_speed()`](Self::relative_speed) at the start of the update.
+    #[inline]
+    pub fn effective_speed(&self) -> f32 {
+        self.context().effective_speed as f32
+    }
+
+    /// Returns the speed the channel advanced relative to its source clock in this update,
+    /// as [`f64`].
+    ///
+    /// Returns `0.0` if the channel was paused, otherwise the same as
+    /// [`relative_speed_f64()`](Self::relative_speed_f64) at the start of the update.
+    #[inline]
+    pub fn effective_speed_f64(&self) -> f64 {
+        self.context().effective_speed
+    }
+
+    /// Sets the speed the channel advances relative to its source clock, given as an [`f32`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        self.set_relative_speed_f64(ratio as f64);
+    }
+
+    /// Sets the speed the channel advances relative to its source clock, given as an [`f64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed_f64(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.context_mut().relative_speed = ratio;
+    }
+
+    /// Stops the channel if it is running, otherwise resumes it.
+    #[inline]
+    pub fn toggle(&mut self) {
+        self.context_mut().paused ^= true;
+    }
+
+    /// Stops the channel, preventing it from advancing until resumed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    /// Resumes the channel.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    /// Returns `true` if the channel is currently paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    /// Returns `true` if the channel was paused at the start of this update.
+    #[inline]
+    pub fn was_paused(&self) -> bool {
+        self.context().effective_speed == 0.0
+    }
+
+    /// Advances the channel by `source_delta` * `relative_speed`, unless paused.
+    fn advance_with_source_delta(&mut self, source_delta: Duration) {
+        let speed = if self.context().paused {
+            0.0
+        } else {
+            self.context().relative_speed
+        };
+        let delta = if speed != 1.0 {
+            source_delta.mul_f64(speed)
+        } else {
+            // avoid rounding when at normal speed
+            source_delta
+        };
+        self.context_mut().effective_speed = speed;
+        self.advance_by(delta);
+    }
+}
+
+/// Adds a [`Time<Channel<C>>`](Channel) clock to the app and advances it every update.
+///
+/// The clock is updated in [`First`], right after [`Time<Real>`](Real) and
+/// [`Time<Virtual>`](Virtual), as part of [`TimeSystems`]. Requires
+/// [`TimePlugin`](crate::TimePlugin).
+pub struct TimeChannelPlugin<C> {
+    /// The clock the channel derives its time from.
+    pub source: ChannelSource,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C> TimeChannelPlugin<C> {
+    /// Creates a plugin for a channel advancing with the provided `source` clock.
+    pub fn new(source: ChannelSource) -> Self {
+        Self {
+            source,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for TimeChannelPlugin<C> {
+    fn default() -> Self {
+        Self::new(ChannelSource::default())
+    }
+}
+
+impl<C: 'static> Plugin for TimeChannelPlugin<C> {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Time<Channel<C>>>() {
+            app.insert_resource(Time::new_with(Channel::<C>::new(self.source)));
+        }
+
+        app.add_systems(
+            First,
+            update_channel_time::<C>
+                .in_set(TimeSystems)
+                .after(time_system)
+                .ambiguous_with(message_update_system),
+        );
+    }
+}
+
+/// Advances [`Time<Channel<C>>`](Channel) based on the delta of its [`ChannelSource`].
+pub fn update_channel_time<C: 'static>(
+    mut channel: ResMut<Time<Channel<C>>>,
+    real: Res<Time<Real>>,
+    virt: Res<Time<Virtual>>,
+) {
+    let source_delta = match channel.source() {
+        ChannelSource::Virtual => virt.delta(),
+        ChannelSource::Real => real.delta(),
+    };
+    channel.advance_with_source_delta(source_delta);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Gameplay;
+
+    #[test]
+    fn test_relative_speed() {
+        let mut time = Time::<Channel<Gameplay>>::default();
+
+        time.advance_with_source_delta(Duration::from_millis(250));
+
+        assert_eq!(time.delta(), Duration::from_millis(250));
+        assert_eq!(time.elapsed(), Duration::from_millis(250));
+
+        time.set_relative_speed_f64(0.5);
+        time.advance_with_source_delta(Duration::from_millis(250));
+
+        assert_eq!(time.effective_speed(), 0.5);
+        assert_eq!(time.delta(), Duration::from_millis(125));
+        assert_eq!(time.elapsed(), Duration::from_millis(375));
+    }
+
+    #[test]
+    fn test_pause() {
+        let mut time = Time::<Channel<Gameplay>>::default();
+
+        time.pause();
+        time.advance_with_source_delta(Duration::from_millis(250));
+
+        assert!(time.is_paused());
+        assert!(time.was_paused());
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.elapsed(), Duration::ZERO);
+
+        time.toggle();
+        time.advance_with_source_delta(Duration::from_millis(250));
+
+        assert!(!time.is_paused());
+        assert!(!time.was_paused());
+        assert_eq!(time.delta(), Duration::from_millis(250));
+    }
+}
@@ -0,0 +1,375 @@
+use bevy_ecs::prelude::*;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use core::time::Duration;
+
+use crate::{Time, Timer, TimerMode};
+
+/// A [`Timer`] attached to an entity, ticked automatically by [`TimePlugin`].
+///
+/// Whenever the timer finishes, a [`TimerFinished`] event is triggered for the entity.
+/// What happens to the entity afterwards is controlled by [`OnTimerFinish`].
+/// This removes the need for a bespoke system every time something should happen
+/// "after 3 seconds".
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{EntityTimer, TimerFinished};
+/// #[derive(Component)]
+/// struct Explosion;
+///
+/// fn spawn_explosion(mut commands: Commands) {
+///     // The explosion is despawned after 3 seconds.
+///     commands.spawn((Explosion, EntityTimer::from_seconds(3.0).despawn_on_finish()));
+///
+///     // Spawn a fuse that reacts when it burned down.
+///     commands
+///         .spawn(EntityTimer::from_seconds(2.0))
+///         .observe(|finished: On<TimerFinished>, mut commands: Commands| {
+///             commands.entity(finished.entity).insert(Explosion);
+///         });
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_explosion);
+/// ```
+///
+/// The timer is ticked in [`PreUpdate`] using the default [`Time`] clock.
+///
+/// [`TimePlugin`]: crate::TimePlugin
+/// [`PreUpdate`]: bevy_app::PreUpdate
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Clone, PartialEq)
+)]
+pub struct EntityTimer {
+    /// The timer that is ticked every update.
+    pub timer: Timer,
+    /// What to do with the entity when the timer finishes.
+    pub on_finish: OnTimerFinish,
+}
+
+impl EntityTimer {
+    /// Creates a new [`EntityTimer`] from a [`Timer`].
+    pub fn new(timer: Timer) -> Self {
+        Self {
+            timer,
+            on_finish: OnTimerFinish::default(),
+        }
+    }
+
+    /// Creates a new non-repeating [`EntityTimer`] that finishes after `duration` seconds.
+    pub fn from_seconds(duration: f32) -> Self {
+        Self::new(Timer::from_seconds(duration, TimerMode::Once))
+    }
+
+    /// Creates a new repeating [`EntityTimer`] that finishes every `duration` seconds.
+    pub fn repeating(duration: f32) -> Self {
+        Self::new(Timer::from_seconds(duration, TimerMode::Repeating))
+    }
+
+    /// Despawns the entity once the timer finishes.
+    pub fn despawn_on_finish(mut self) -> Self {
+        self.on_finish = OnTimerFinish::Despawn;
+        self
+    }
+
+    /// Removes the [`EntityTimer`] component once the timer finishes.
+    pub fn remove_on_finish(mut self) -> Self {
+        self.on_finish = OnTimerFinish::Remove;
+        self
+    }
+}
+
+/// Determines what happens to an entity with an [`EntityTimer`] when its timer finishes.
+///
+/// The action is applied after [`TimerFinished`] has been triggered, so observers can still
+/// access the entity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Default, Clone, PartialEq, Hash)
+)]
+pub enum OnTimerFinish {
+    /// Keep the entity and the timer as-is.
+    ///
+    /// Repeating timers keep running, and finished non-repeating timers have to be reset
+    /// manually.
+    #[default]
+    Nothing,
+    /// Despawn the entity.
+    Despawn,
+    /// Remove the [`EntityTimer`] component from the entity.
+    Remove,
+}
+
+/// Triggered for an entity when its [`EntityTimer`] finishes.
+#[derive(EntityEvent, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Event))]
+pub struct TimerFinished {
+    /// The entity whose timer finished.
+    pub entity: Entity,
+    /// How many times the timer finished during this tick.
+    ///
+    /// This is always `1` for non-repeating timers, but repeating timers can finish multiple
+    /// times if their duration is shorter than the frame time.
+    pub times_finished: u32,
+}
+
+/// A cooldown attached to an entity, ticked automatically by [`TimePlugin`].
+///
+/// A cooldown starts out ready. Calling [`Cooldown::trigger`] consumes it, and it becomes
+/// ready again once its duration has elapsed, at which point a [`CooldownReady`] event is
+/// triggered for the entity.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::Cooldown;
+/// #[derive(Component)]
+/// struct Dash;
+///
+/// fn dash(mut query: Query<&mut Cooldown, With<Dash>>) {
+///     for mut cooldown in &mut query {
+///         if cooldown.trigger() {
+///             // Perform the dash, which can't be used again for a while.
+///         }
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(dash);
+/// ```
+///
+/// [`TimePlugin`]: crate::TimePlugin
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Default, Clone, PartialEq)
+)]
+pub struct Cooldown {
+    timer: Timer,
+}
+
+impl Cooldown {
+    /// Creates a new, ready [`Cooldown`] with the given `duration`.
+    pub fn new(duration: Duration) -> Self {
+        let mut timer = Timer::new(duration, TimerMode::Once);
+        timer.set_finished();
+        Self { timer }
+    }
+
+    /// Creates a new, ready [`Cooldown`] with a duration of `duration` seconds.
+    pub fn from_seconds(duration: f32) -> Self {
+        Self::new(Duration::from_secs_f32(duration))
+    }
+
+    /// Returns `true` if the cooldown can be triggered.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.timer.is_finished()
+    }
+
+    /// Returns `true` if the cooldown became ready during the last tick.
+    #[inline]
+    pub fn just_became_ready(&self) -> bool {
+        self.timer.just_finished()
+    }
+
+    /// Triggers the cooldown if it is ready, returning whether it was.
+    ///
+    /// Once triggered, the cooldown stays unavailable until its duration has elapsed.
+    pub fn trigger(&mut self) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+        self.timer.reset();
+        true
+    }
+
+    /// Makes the cooldown ready immediately.
+    pub fn reset(&mut self) {
+        self.timer.set_finished();
+    }
+
+    /// Returns the time left until the cooldown becomes ready.
+    #[inline]
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    /// Returns the fraction of the cooldown that has elapsed, from `0.0` (just triggered)
+    /// to `1.0` (ready).
+    #[inline]
+    pub fn fraction(&self) -> f32 {
+        self.timer.fraction()
+    }
+
+    /// Returns the total duration of the cooldown.
+    #[inline]
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+
+    /// Sets the total duration of the cooldown.
+    #[inline]
+    pub fn set_duration(&mut self, duration: Duration) {
+        self.timer.set_duration(duration);
+    }
+
+    /// Advances the cooldown by `delta`.
+    pub fn tick(&mut self, delta: Duration) -> &Self {
+        self.timer.tick(delta);
+        self
+    }
+}
+
+/// Triggered for an entity when its [`Cooldown`] becomes ready again.
+#[derive(EntityEvent, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Event))]
+pub struct CooldownReady {
+    /// The entity whose cooldown became ready.
+    pub entity: Entity,
+}
+
+/// Ticks all [`EntityTimer`]s, triggers [`TimerFinished`] and applies their [`OnTimerFinish`].
+pub fn tick_entity_timers(
+    mut timers: Query<(Entity, &mut EntityTimer)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let delta = time.delta();
+    for (entity, mut entity_timer) in &mut timers {
+        if !entity_timer.timer.tick(delta).just_finished() {
+            continue;
+        }
+
+        commands.trigger(TimerFinished {
+            entity,
+            times_finished: entity_timer.timer.times_finished_this_tick(),
+        });
+
+        match entity_timer.on_finish {
+            OnTimerFinish::Nothing => {}
+            OnTimerFinish::Despawn => {
+                commands.entity(entity).despawn();
+            }
+            OnTimerFinish::Remove => {
+                commands.entity(entity).remove::<EntityTimer>();
+            }
+        }
+    }
+}
+
+/// Ticks all [`Cooldown`]s and triggers [`CooldownReady`] for those that became ready.
+pub fn tick_cooldowns(
+    mut cooldowns: Query<(Entity, &mut Cooldown)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let delta = time.delta();
+    for (entity, mut cooldown) in &mut cooldowns {
+        // Ready cooldowns are ticked too, so `just_became_ready` is cleared after one tick.
+        if cooldown.tick(delta).just_became_ready() {
+            commands.trigger(CooldownReady { entity });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+
+    use crate::{
+        Cooldown, CooldownReady, EntityTimer, TimePlugin, TimeUpdateStrategy, TimerFinished,
+    };
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    #[test]
+    fn entity_timer_despawns_on_finish() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<Counter>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+
+        let entity = app
+            .world_mut()
+            .spawn(EntityTimer::from_seconds(0.25).despawn_on_finish())
+            .observe(|_: On<TimerFinished>, mut counter: ResMut<Counter>| {
+                counter.0 += 1;
+            })
+            .id();
+
+        // The first update has a zero delta.
+        for _ in 0..3 {
+            app.update();
+            assert!(app.world().get_entity(entity).is_ok());
+        }
+
+        app.update();
+        assert!(app.world().get_entity(entity).is_err());
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn cooldown_becomes_ready() {
+        let mut cooldown = Cooldown::from_seconds(1.0);
+        assert!(cooldown.is_ready());
+        assert!(!cooldown.just_became_ready());
+        assert!(cooldown.trigger());
+        assert!(!cooldown.trigger());
+
+        cooldown.tick(Duration::from_millis(500));
+        assert!(!cooldown.is_ready());
+        assert_eq!(cooldown.fraction(), 0.5);
+
+        cooldown.tick(Duration::from_millis(500));
+        assert!(cooldown.is_ready());
+        assert!(cooldown.just_became_ready());
+
+        cooldown.tick(Duration::from_millis(500));
+        assert!(cooldown.is_ready());
+        assert!(!cooldown.just_became_ready());
+
+        cooldown.trigger();
+        cooldown.reset();
+        assert!(cooldown.is_ready());
+        assert!(!cooldown.just_became_ready());
+    }
+
+    #[test]
+    fn cooldown_triggers_ready_event() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<Counter>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .add_observer(|_: On<CooldownReady>, mut counter: ResMut<Counter>| {
+                counter.0 += 1;
+            });
+
+        let mut cooldown = Cooldown::from_seconds(0.15);
+        cooldown.trigger();
+        let entity = app.world_mut().spawn(cooldown).id();
+
+        // The first update has a zero delta.
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+        assert!(app.world().get::<Cooldown>(entity).unwrap().just_became_ready());
+
+        app.update();
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+        let cooldown = app.world().get::<Cooldown>(entity).unwrap();
+        assert!(cooldown.is_ready());
+        assert!(!cooldown.just_became_ready());
+    }
+}
@@ -12,9 +12,11 @@ extern crate std;
 
 extern crate alloc;
 
+mod channel;
 /// Common run conditions
 pub mod common_conditions;
 mod delayed_commands;
+mod entity_timer;
 mod fixed;
 mod real;
 mod stopwatch;
@@ -22,7 +24,9 @@ mod time;
 mod timer;
 mod virt;
 
+pub use channel::*;
 pub use delayed_commands::*;
+pub use entity_timer::*;
 pub use fixed::*;
 pub use real::*;
 pub use stopwatch::*;
@@ -35,7 +39,9 @@ pub use virt::*;
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{DelayedCommandsExt, Fixed, Real, Time, Timer, TimerMode, Virtual};
+    pub use crate::{
+        Cooldown, DelayedCommandsExt, EntityTimer, Fixed, Real, Time, Timer, TimerMode, Virtual,
+    };
 }
 
 use bevy_app::{prelude::*, OnAppExitSystems, RunFixedMainLoop};
@@ -76,7 +82,9 @@ impl Plugin for TimePlugin {
             app.register_type::<Time>()
                 .register_type::<Time<Real>>()
                 .register_type::<Time<Virtual>>()
-                .register_type::<Time<Fixed>>();
+                .register_type::<Time<Fixed>>()
+                .register_type::<EntityTimer>()
+                .register_type::<Cooldown>();
         }
 
         app.add_systems(
@@ -85,7 +93,14 @@ impl Plugin for TimePlugin {
                 .in_set(TimeSystems)
                 .ambiguous_with(message_update_system),
         )
-        .add_systems(PreUpdate, check_delayed_command_queues)
+        .add_systems(
+            PreUpdate,
+            (
+                check_delayed_command_queues,
+                tick_entity_timers,
+                tick_cooldowns,
+            ),
+        )
         .add_systems(
             RunFixedMainLoop,
             run_fixed_main_schedule.in_set(RunFixedMainLoopSystems::FixedMainLoop),
@@ -206,6 +206,14 @@ impl Timer {
         self.tick(remaining);
     }
 
+    /// Puts the timer in its finished state without it counting as
+    /// [just finished](Timer::just_finished).
+    pub(crate) fn set_finished(&mut self) {
+        self.stopwatch.set_elapsed(self.duration);
+        self.finished = true;
+        self.times_finished_this_tick = 0;
+    }
+
     /// Almost finishes the timer leaving 1 ns of remaining time.
     /// This can be useful when needing an immediate action without having
     /// to wait for the set duration of the timer in the first tick.
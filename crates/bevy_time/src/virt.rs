@@ -70,6 +70,12 @@ use crate::{real::Real, time::Time};
 /// time. You should also consider how stable your FPS is, as the limit will
 /// also dictate how big of an FPS drop you can accept without losing time and
 /// falling behind real time.
+///
+/// Short hitches, where a single frame takes noticeably longer than its
+/// neighbors, can also be smoothed out by calling
+/// [`set_delta_smoothing()`](Time::set_delta_smoothing). This averages the
+/// raw delta over recent updates before it is scaled, so a one-off spike is
+/// spread over several frames instead of causing a visible jump.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Clone))]
 pub struct Virtual {
@@ -77,6 +83,8 @@ pub struct Virtual {
     paused: bool,
     relative_speed: f64,
     effective_speed: f64,
+    delta_smoothing: f64,
+    smoothed_delta: Option<Duration>,
 }
 
 impl Time<Virtual> {
@@ -238,8 +246,70 @@ impl Time<Virtual> {
         self.context().effective_speed == 0.0
     }
 
+    /// Returns the amount of smoothing applied to the raw delta before it is
+    /// scaled, as [`f32`].
+    ///
+    /// See [`set_delta_smoothing()`](Self::set_delta_smoothing) for details.
+    #[inline]
+    pub fn delta_smoothing(&self) -> f32 {
+        self.context().delta_smoothing as f32
+    }
+
+    /// Sets the amount of smoothing applied to the raw delta before it is
+    /// scaled, in the range `[0.0, 1.0)`.
+    ///
+    /// Each update, the raw delta is blended with the previous smoothed delta
+    /// using an exponential moving average, where `smoothing` is the weight of
+    /// the previous value. `0.0` disables smoothing, while values closer to
+    /// `1.0` hide hitches better at the cost of reacting more slowly to
+    /// lasting changes in frame rate.
+    ///
+    /// The default value is `0.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `smoothing` is not in the range `[0.0, 1.0)`.
+    #[inline]
+    pub fn set_delta_smoothing(&mut self, smoothing: f32) {
+        assert!(
+            (0.0..1.0).contains(&smoothing),
+            "tried to set delta smoothing outside of [0.0, 1.0)"
+        );
+        let context = self.context_mut();
+        context.delta_smoothing = smoothing as f64;
+        if smoothing == 0.0 {
+            context.smoothed_delta = None;
+        }
+    }
+
+    /// Blends `raw_delta` with the previously smoothed delta according to the `delta_smoothing`.
+    ///
+    /// The raw delta is first clamped to the one reaching `max_delta` once scaled, so that a
+    /// single long frame doesn't inflate the deltas of the following frames.
+    fn smooth_raw_delta(&mut self, raw_delta: Duration) -> Duration {
+        let context = self.context();
+        let smoothing = context.delta_smoothing;
+        if smoothing == 0.0 {
+            return raw_delta;
+        }
+        let max_raw_delta = if context.relative_speed > 0.0 {
+            Duration::try_from_secs_f64(context.max_delta.as_secs_f64() / context.relative_speed)
+                .unwrap_or(Duration::MAX)
+        } else {
+            context.max_delta
+        };
+        let raw_delta = raw_delta.min(max_raw_delta);
+        let smoothed = match self.context().smoothed_delta {
+            Some(previous) => previous.mul_f64(smoothing) + raw_delta.mul_f64(1.0 - smoothing),
+            None => raw_delta,
+        };
+        self.context_mut().smoothed_delta = Some(smoothed);
+        smoothed
+    }
+
     /// Updates the elapsed duration of `self` by `raw_delta` * `relative_speed`, up to the `max_delta`.
     fn advance_with_raw_delta(&mut self, raw_delta: Duration) {
+        let raw_delta = self.smooth_raw_delta(raw_delta);
         let max_delta = self.context().max_delta;
         let speed = if self.context().paused {
             0.0
@@ -274,6 +344,8 @@ impl Default for Virtual {
             paused: false,
             relative_speed: 1.0,
             effective_speed: 1.0,
+            delta_smoothing: 0.0,
+            smoothed_delta: None,
         }
     }
 }
@@ -479,4 +551,48 @@ mod test {
 
         assert_eq!(time.delta(), delta / 100);
     }
+
+    #[test]
+    fn test_delta_smoothing() {
+        let mut time = Time::<Virtual>::default();
+        time.set_max_delta(Duration::from_secs(10));
+        time.set_delta_smoothing(0.5);
+
+        time.advance_with_raw_delta(Duration::from_millis(250));
+
+        assert_eq!(time.delta(), Duration::from_millis(250));
+
+        time.advance_with_raw_delta(Duration::from_millis(1250));
+
+        assert_eq!(time.delta(), Duration::from_millis(750));
+
+        time.advance_with_raw_delta(Duration::from_millis(250));
+
+        assert_eq!(time.delta(), Duration::from_millis(500));
+        assert_eq!(time.elapsed(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_delta_smoothing_recovers_after_spike() {
+        let mut time = Time::<Virtual>::default();
+        time.set_max_delta(Duration::from_millis(100));
+        time.set_delta_smoothing(0.5);
+        let frame = Duration::from_millis(16);
+
+        time.advance_with_raw_delta(frame);
+        // A long stall is clamped before it is averaged.
+        time.advance_with_raw_delta(Duration::from_secs(5));
+        assert!(time.delta() < time.max_delta(), "{:?}", time.delta());
+
+        let deltas: [Duration; 6] = core::array::from_fn(|_| {
+            time.advance_with_raw_delta(frame);
+            time.delta()
+        });
+        assert!(
+            deltas.windows(2).all(|pair| pair[1] < pair[0]),
+            "{deltas:?}"
+        );
+        assert!(deltas[2] < Duration::from_millis(30), "{deltas:?}");
+        assert!(deltas[5] < Duration::from_millis(17), "{deltas:?}");
+    }
 }
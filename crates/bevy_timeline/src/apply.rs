@@ -0,0 +1,249 @@
+use bevy_animation::AnimationPlayer;
+use bevy_asset::Assets;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    query::Without,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    resource::IsResource,
+    system::{Query, Res},
+    world::EntityMut,
+};
+use bevy_math::Vec3;
+use bevy_reflect::{GetPath, PartialReflect};
+use bevy_transform::components::{GlobalTransform, Transform};
+use bevy_utils::once;
+use tracing::warn;
+
+use crate::{player::SavedValue, PlaybackState, PropertyValue, Timeline, TimelinePlayer, Track};
+
+/// Plays the segments of the [`AnimationTrack`](crate::AnimationTrack)s at the time of their
+/// [`TimelinePlayer`].
+pub(crate) fn apply_animation_tracks(
+    timelines: Res<Assets<Timeline>>,
+    mut players: Query<&mut TimelinePlayer>,
+    mut animation_players: Query<&mut AnimationPlayer>,
+) {
+    for mut player in &mut players {
+        let Some(timeline) = timelines.get(&player.timeline) else {
+            continue;
+        };
+        let player = &mut *player;
+
+        if player.state() == PlaybackState::Stopped {
+            for (entity, node) in player.animations.drain(..) {
+                if let Ok(mut animation_player) = animation_players.get_mut(entity) {
+                    animation_player.stop(node);
+                }
+            }
+            continue;
+        }
+
+        let weight = player.weight(timeline);
+        let time = player.time();
+        for track in &timeline.tracks {
+            let Track::Animation(track) = track else {
+                continue;
+            };
+            let Some(entity) = player.binding(&track.binding) else {
+                continue;
+            };
+            let Ok(mut animation_player) = animation_players.get_mut(entity) else {
+                continue;
+            };
+
+            for segment in &track.segments {
+                let key = (entity, segment.node);
+                let segment_weight = segment.weight(time) * weight;
+                if segment_weight <= 0.0 {
+                    if let Some(index) =
+                        player.animations.iter().position(|started| *started == key)
+                    {
+                        player.animations.swap_remove(index);
+                        animation_player.stop(segment.node);
+                    }
+                    continue;
+                }
+
+                // The animation is paused so that it only follows the time of the timeline.
+                let seek_time = segment.clip_start + time - segment.start;
+                let animation = animation_player.play(segment.node);
+                animation.pause().set_weight(segment_weight);
+                if player.state() == PlaybackState::Playing {
+                    animation.seek_to(seek_time);
+                } else {
+                    // Scrubbing does not trigger the animation events.
+                    animation.set_seek_time(seek_time);
+                }
+                if !player.animations.contains(&key) {
+                    player.animations.push(key);
+                }
+            }
+        }
+    }
+}
+
+/// Moves the cameras of the [`CameraTrack`](crate::CameraTrack)s to their shots, blended with
+/// their gameplay pose.
+pub(crate) fn apply_camera_tracks(
+    timelines: Res<Assets<Timeline>>,
+    mut players: Query<&mut TimelinePlayer>,
+    shots: Query<&GlobalTransform>,
+    mut cameras: Query<&mut Transform>,
+) {
+    for mut player in &mut players {
+        let Some(timeline) = timelines.get(&player.timeline) else {
+            continue;
+        };
+        let player = &mut *player;
+        let weight = player.weight(timeline);
+
+        for (index, track) in timeline.tracks.iter().enumerate() {
+            let Track::Camera(track) = track else {
+                continue;
+            };
+            let Some(mut transform) = player
+                .binding(&track.camera)
+                .and_then(|camera| cameras.get_mut(camera).ok())
+            else {
+                continue;
+            };
+
+            if player.state() == PlaybackState::Stopped {
+                if let Some(SavedValue::Transform(saved)) = player.saved.remove(&index) {
+                    *transform = saved;
+                }
+                continue;
+            }
+
+            let Some(shot) = track
+                .cut_at(player.time())
+                .and_then(|cut| player.binding(&cut.shot))
+                .and_then(|shot| shots.get(shot).ok())
+            else {
+                continue;
+            };
+            let SavedValue::Transform(saved) = *player
+                .saved
+                .entry(index)
+                .or_insert(SavedValue::Transform(*transform))
+            else {
+                continue;
+            };
+
+            let shot = shot.compute_transform();
+            *transform = Transform {
+                translation: saved.translation.lerp(shot.translation, weight),
+                rotation: saved.rotation.slerp(shot.rotation, weight),
+                scale: saved.scale,
+            };
+        }
+    }
+}
+
+/// Sets the fields driven by the [`PropertyTrack`](crate::PropertyTrack)s, blended with their
+/// gameplay value.
+pub(crate) fn apply_property_tracks(
+    timelines: Res<Assets<Timeline>>,
+    type_registry: Res<AppTypeRegistry>,
+    mut players: Query<&mut TimelinePlayer>,
+    mut entities: Query<EntityMut, (Without<TimelinePlayer>, Without<IsResource>)>,
+) {
+    let type_registry = type_registry.read();
+
+    for mut player in &mut players {
+        let Some(timeline) = timelines.get(&player.timeline) else {
+            continue;
+        };
+        let player = &mut *player;
+        let weight = player.weight(timeline);
+
+        for (index, track) in timeline.tracks.iter().enumerate() {
+            let Track::Property(track) = track else {
+                continue;
+            };
+            let Some(reflect_component) = type_registry
+                .get_with_type_path(&track.component)
+                .or_else(|| type_registry.get_with_short_type_path(&track.component))
+                .and_then(|registration| registration.data::<ReflectComponent>())
+            else {
+                once!(warn!(
+                    "The component {} of a property track is not registered, or does not reflect `Component`",
+                    track.component
+                ));
+                continue;
+            };
+            let Some(mut component) = player
+                .binding(&track.binding)
+                .and_then(|entity| entities.get_mut(entity).ok())
+                .and_then(|entity| reflect_component.reflect_mut(entity))
+            else {
+                continue;
+            };
+            let Some(field) = component
+                .bypass_change_detection()
+                .reflect_path_mut(track.field.as_str())
+                .ok()
+            else {
+                once!(warn!(
+                    "The field {} of a property track was not found on {}",
+                    track.field, track.component
+                ));
+                continue;
+            };
+
+            if player.state() == PlaybackState::Stopped {
+                if let Some(SavedValue::Property(saved)) = player.saved.remove(&index) {
+                    set_property(field, saved);
+                    component.set_changed();
+                }
+                continue;
+            }
+
+            let Some(current) = get_property(field) else {
+                once!(warn!(
+                    "The field {} of {} driven by a property track is not an `f32` or a `Vec3`",
+                    track.field, track.component
+                ));
+                continue;
+            };
+            let SavedValue::Property(saved) = *player
+                .saved
+                .entry(index)
+                .or_insert(SavedValue::Property(current))
+            else {
+                continue;
+            };
+            let value = saved.lerp(track.curve.sample(player.time()), weight);
+            if value != current {
+                set_property(field, value);
+                component.set_changed();
+            }
+        }
+    }
+}
+
+fn get_property(field: &dyn PartialReflect) -> Option<PropertyValue> {
+    field
+        .try_downcast_ref::<f32>()
+        .map(|value| PropertyValue::F32(*value))
+        .or_else(|| {
+            field
+                .try_downcast_ref::<Vec3>()
+                .map(|value| PropertyValue::Vec3(*value))
+        })
+}
+
+fn set_property(field: &mut dyn PartialReflect, value: PropertyValue) {
+    match value {
+        PropertyValue::F32(value) => {
+            if let Some(field) = field.try_downcast_mut::<f32>() {
+                *field = value;
+            }
+        }
+        PropertyValue::Vec3(value) => {
+            if let Some(field) = field.try_downcast_mut::<Vec3>() {
+                *field = value;
+            }
+        }
+    }
+}
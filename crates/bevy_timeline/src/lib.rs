@@ -0,0 +1,252 @@
+//! Cutscene sequencing for Bevy.
+//!
+//! A [`Timeline`] asset arranges animations, camera cuts, sounds, markers and property curves on
+//! tracks, and a [`TimelinePlayer`] plays it on the entities of the scene, blending in from and
+//! out to the gameplay. Timelines can be scrubbed, so that cutscenes can be authored and previewed
+//! in the app itself, without an external tool.
+//!
+//! Refer to [`TimelinePlugin`] for usage information.
+
+extern crate alloc;
+
+mod apply;
+mod loader;
+mod player;
+mod timeline;
+
+pub use loader::{TimelineLoadError, TimelineLoader};
+pub use player::{PlaybackState, TimelineMarker, TimelinePlayer};
+pub use timeline::*;
+
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_asset::AssetApp;
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+
+/// The timeline prelude.
+///
+/// This includes the most common types in this crate, re-exported for your convenience.
+pub mod prelude {
+    #[doc(hidden)]
+    pub use crate::{
+        PlaybackState, Timeline, TimelineMarker, TimelinePlayer, TimelinePlugin, Track,
+    };
+}
+
+/// Plays the [`Timeline`]s of the [`TimelinePlayer`]s.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_asset::AssetPlugin;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_timeline::prelude::*;
+/// App::new()
+///     .add_plugins((AssetPlugin::default(), TimelinePlugin))
+///     .add_observer(|marker: On<TimelineMarker>| {
+///         if marker.name == "door_opens" {
+///             println!("The timeline {} reached the door", marker.entity);
+///         }
+///     });
+/// ```
+///
+/// The players are advanced in [`PostUpdate`], before the animations are applied, so that the
+/// bound entities follow the time of the players during the same frame.
+#[derive(Default)]
+pub struct TimelinePlugin;
+
+impl Plugin for TimelinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Timeline>()
+            .register_asset_reflect::<Timeline>()
+            .init_asset_loader::<TimelineLoader>()
+            .register_type::<PlaybackState>()
+            .register_type::<TimelineMarker>()
+            .add_systems(
+                PostUpdate,
+                (
+                    player::advance_timelines,
+                    apply::apply_animation_tracks,
+                    apply::apply_camera_tracks,
+                    apply::apply_property_tracks,
+                )
+                    .chain()
+                    .in_set(TimelineSystems)
+                    .before(AnimationSystems),
+            );
+    }
+}
+
+/// The system set in which the [`TimelinePlayer`]s are advanced and applied, in
+/// [`PostUpdate`].
+#[derive(SystemSet, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimelineSystems;
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+    use core::time::Duration;
+
+    use bevy_animation::graph::AnimationNodeIndex;
+    use bevy_asset::{Assets, Handle};
+    use bevy_ecs::{prelude::*, reflect::AppTypeRegistry};
+    use bevy_math::{curve::UnevenSampleAutoCurve, Vec3};
+    use bevy_time::Time;
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use crate::{
+        apply, player, AnimationSegment, AnimationTrack, CameraTrack, MarkerTrack, PlaybackState,
+        PropertyTrack, PropertyValue, Timeline, TimelineMarker, TimelinePlayer, Track,
+    };
+
+    #[derive(Resource, Default)]
+    struct Markers(Vec<String>);
+
+    fn setup(timeline: Timeline) -> (World, Schedule, Handle<Timeline>) {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<Markers>();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Transform>();
+        let handle = world
+            .get_resource_or_init::<Assets<Timeline>>()
+            .add(timeline);
+        world.add_observer(|marker: On<TimelineMarker>, mut markers: ResMut<Markers>| {
+            markers.0.push(marker.name.clone());
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                player::advance_timelines,
+                apply::apply_camera_tracks,
+                apply::apply_property_tracks,
+            )
+                .chain(),
+        );
+        (world, schedule, handle)
+    }
+
+    fn step(world: &mut World, schedule: &mut Schedule, seconds: f32) {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(seconds));
+        schedule.run(world);
+    }
+
+    #[test]
+    fn markers_and_properties() {
+        let timeline = Timeline::new(2.0)
+            .with_track(
+                MarkerTrack::default()
+                    .with_marker(0.5, "first")
+                    .with_marker(1.5, "second"),
+            )
+            .with_track(PropertyTrack::new(
+                "door",
+                "Transform",
+                "translation.y",
+                UnevenSampleAutoCurve::new([(0.0, 0.0), (2.0, 4.0)]).unwrap(),
+            ));
+        let (mut world, mut schedule, handle) = setup(timeline);
+        let door = world.spawn(Transform::from_xyz(0.0, -1.0, 0.0)).id();
+        let player = world
+            .spawn(TimelinePlayer::new(handle).bind("door", door))
+            .id();
+
+        step(&mut world, &mut schedule, 1.0);
+        assert_eq!(world.resource::<Markers>().0, ["first"]);
+        assert_eq!(world.get::<Transform>(door).unwrap().translation.y, 2.0);
+
+        // Scrubbing moves the door without triggering the markers.
+        world.get_mut::<TimelinePlayer>(player).unwrap().pause();
+        world.get_mut::<TimelinePlayer>(player).unwrap().seek(1.75);
+        step(&mut world, &mut schedule, 0.1);
+        assert_eq!(world.resource::<Markers>().0, ["first"]);
+        assert_eq!(world.get::<Transform>(door).unwrap().translation.y, 3.5);
+
+        // Once finished, the door is given back to the gameplay.
+        world.get_mut::<TimelinePlayer>(player).unwrap().play();
+        step(&mut world, &mut schedule, 1.0);
+        assert_eq!(world.resource::<Markers>().0, ["first"]);
+        assert_eq!(
+            world.get::<TimelinePlayer>(player).unwrap().state(),
+            PlaybackState::Stopped
+        );
+        assert_eq!(world.get::<Transform>(door).unwrap().translation.y, -1.0);
+    }
+
+    #[test]
+    fn camera_blending() {
+        let timeline =
+            Timeline::new(4.0).with_track(CameraTrack::new("camera").with_cut(0.0, "shot"));
+        let (mut world, mut schedule, handle) = setup(timeline);
+        let camera = world.spawn(Transform::default()).id();
+        let shot = world.spawn(GlobalTransform::from_xyz(10.0, 0.0, 0.0)).id();
+        world.spawn(
+            TimelinePlayer::new(handle)
+                .with_blend(2.0, 1.0)
+                .bind("camera", camera)
+                .bind("shot", shot),
+        );
+
+        step(&mut world, &mut schedule, 1.0);
+        assert_eq!(
+            world.get::<Transform>(camera).unwrap().translation,
+            Vec3::new(5.0, 0.0, 0.0)
+        );
+        step(&mut world, &mut schedule, 1.5);
+        assert_eq!(
+            world.get::<Transform>(camera).unwrap().translation,
+            Vec3::new(10.0, 0.0, 0.0)
+        );
+        step(&mut world, &mut schedule, 1.0);
+        assert_eq!(
+            world.get::<Transform>(camera).unwrap().translation,
+            Vec3::new(5.0, 0.0, 0.0)
+        );
+        step(&mut world, &mut schedule, 1.0);
+        assert_eq!(
+            world.get::<Transform>(camera).unwrap().translation,
+            Vec3::ZERO
+        );
+    }
+
+    #[test]
+    fn ron_round_trip() {
+        let timeline = Timeline::new(6.0)
+            .with_track(
+                AnimationTrack::new("hero").with_segment(AnimationSegment::new(
+                    AnimationNodeIndex::new(1),
+                    0.0,
+                    4.0,
+                )),
+            )
+            .with_track(CameraTrack::new("camera").with_cut(3.0, "close_up"))
+            .with_track(MarkerTrack::default().with_marker(4.0, "door_opens"))
+            .with_track(PropertyTrack::new(
+                "door",
+                "Transform",
+                "translation",
+                UnevenSampleAutoCurve::new([(4.0, Vec3::ZERO), (6.0, Vec3::Y)]).unwrap(),
+            ));
+
+        let mut saved = String::new();
+        timeline.save(&mut saved).unwrap();
+        let loaded: Timeline = ron::de::from_str(&saved).unwrap();
+
+        assert_eq!(loaded.duration, 6.0);
+        assert_eq!(loaded.tracks.len(), 4);
+        let Track::Property(property) = &loaded.tracks[3] else {
+            panic!("expected a property track");
+        };
+        assert_eq!(
+            property.curve.sample(5.0),
+            PropertyValue::Vec3(Vec3::Y * 0.5)
+        );
+        let mut resaved = String::new();
+        loaded.save(&mut resaved).unwrap();
+        assert_eq!(saved, resaved);
+    }
+}
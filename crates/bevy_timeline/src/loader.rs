@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+use std::io;
+
+use bevy_asset::{io::Reader, AssetLoader, LoadContext};
+use bevy_reflect::TypePath;
+use ron::de::SpannedError;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::Timeline;
+
+/// An [`AssetLoader`] that can load [`Timeline`]s as assets.
+///
+/// The canonical extension for [`Timeline`]s is `.timeline.ron`. Plain `.timeline` is supported
+/// as well.
+#[derive(Default, TypePath)]
+pub struct TimelineLoader;
+
+/// Errors that can occur when deserializing timelines from RON.
+#[derive(Error, Debug)]
+pub enum TimelineLoadError {
+    /// An I/O error occurred.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An error occurred in RON deserialization.
+    #[error(transparent)]
+    Ron(#[from] ron::Error),
+    /// An error occurred in RON deserialization, and the location of the error
+    /// is supplied.
+    #[error(transparent)]
+    SpannedRon(#[from] SpannedError),
+}
+
+impl AssetLoader for TimelineLoader {
+    type Asset = Timeline;
+
+    type Settings = ();
+
+    type Error = TimelineLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _: &Self::Settings,
+        _: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        Timeline::deserialize(&mut deserializer).map_err(|err| deserializer.span_error(err).into())
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["timeline", "timeline.ron"]
+    }
+}
@@ -0,0 +1,264 @@
+use alloc::{string::String, vec::Vec};
+
+use bevy_animation::graph::AnimationNodeIndex;
+use bevy_asset::{Assets, Handle};
+#[cfg(feature = "bevy_audio")]
+use bevy_audio::{AudioPlayer, PlaybackSettings};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    reflect::ReflectEvent,
+    system::{Commands, Query, Res},
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::Time;
+use bevy_transform::components::Transform;
+
+use crate::{PropertyValue, Timeline, Track};
+
+/// Plays a [`Timeline`] on the entities bound to its tracks.
+///
+/// ```
+/// # use bevy_asset::Handle;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_timeline::*;
+/// fn start_cutscene(
+///     mut commands: Commands,
+///     cutscene: Res<Cutscene>,
+///     camera: Single<Entity, With<MainCamera>>,
+/// ) {
+///     commands.spawn(
+///         TimelinePlayer::new(cutscene.timeline.clone())
+///             .with_blend(1.0, 1.0)
+///             .bind("camera", *camera)
+///             .bind("hero", cutscene.hero)
+///             .bind("wide_shot", cutscene.wide_shot),
+///     );
+/// }
+/// # #[derive(Resource)]
+/// # struct Cutscene { timeline: Handle<Timeline>, hero: Entity, wide_shot: Entity }
+/// # #[derive(Component)]
+/// # struct MainCamera;
+/// # bevy_ecs::system::assert_is_system(start_cutscene);
+/// ```
+///
+/// The timeline takes control of the bound entities when it starts playing: the transforms of
+/// its cameras and the fields driven by its property tracks are saved, and restored once it is
+/// stopped or finished. During [`TimelinePlayer::blend_in`] and [`TimelinePlayer::blend_out`],
+/// at the start and the end of the timeline, the values of the timeline are blended with the
+/// saved gameplay values, for smooth transitions to and from the cutscene.
+///
+/// The timeline can be scrubbed by seeking while paused, for example from an editor or a debug
+/// UI: the bound entities follow the time of the player.
+#[derive(Component, Clone, Debug)]
+pub struct TimelinePlayer {
+    /// The timeline to play.
+    pub timeline: Handle<Timeline>,
+    /// The playback speed. Negative speeds play the timeline backwards.
+    pub speed: f32,
+    /// How long the timeline takes to blend in from the gameplay values, in seconds.
+    pub blend_in: f32,
+    /// How long the timeline takes to blend out to the gameplay values, in seconds.
+    pub blend_out: f32,
+    bindings: HashMap<String, Entity>,
+    state: PlaybackState,
+    time: f32,
+    /// The time before the last update, to find the markers and cues played past.
+    previous_time: f32,
+    /// Whether the time jumped since the last update, so that no marker or cue is triggered.
+    seeked: bool,
+    /// The gameplay values of the entities driven by each track, restored once stopped.
+    pub(crate) saved: HashMap<usize, SavedValue>,
+    /// The animations started by each track, stopped once the player is stopped.
+    pub(crate) animations: Vec<(Entity, AnimationNodeIndex)>,
+}
+
+/// The state of a [`TimelinePlayer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq, Clone, Default)]
+pub enum PlaybackState {
+    /// The timeline advances.
+    #[default]
+    Playing,
+    /// The timeline keeps control of the bound entities, but does not advance.
+    Paused,
+    /// The timeline has finished or has been stopped, and released the bound entities.
+    Stopped,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum SavedValue {
+    Transform(Transform),
+    Property(PropertyValue),
+}
+
+impl TimelinePlayer {
+    /// Plays `timeline` from its start, without blending.
+    pub fn new(timeline: Handle<Timeline>) -> Self {
+        Self {
+            timeline,
+            speed: 1.0,
+            blend_in: 0.0,
+            blend_out: 0.0,
+            bindings: HashMap::default(),
+            state: PlaybackState::Playing,
+            time: 0.0,
+            previous_time: 0.0,
+            seeked: false,
+            saved: HashMap::default(),
+            animations: Vec::new(),
+        }
+    }
+
+    /// Sets how long the timeline takes to blend in and out.
+    pub fn with_blend(mut self, blend_in: f32, blend_out: f32) -> Self {
+        self.blend_in = blend_in;
+        self.blend_out = blend_out;
+        self
+    }
+
+    /// Binds `name` to `entity`.
+    pub fn bind(mut self, name: impl Into<String>, entity: Entity) -> Self {
+        self.set_binding(name, entity);
+        self
+    }
+
+    /// Binds `name` to `entity`, replacing the previous binding.
+    pub fn set_binding(&mut self, name: impl Into<String>, entity: Entity) {
+        self.bindings.insert(name.into(), entity);
+    }
+
+    /// Returns the entity bound to `name`.
+    pub fn binding(&self, name: &str) -> Option<Entity> {
+        self.bindings.get(name).copied()
+    }
+
+    /// Returns the state of the player.
+    pub fn state(&self) -> PlaybackState {
+        self.state
+    }
+
+    /// Returns the current time, in seconds of the timeline.
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Resumes the timeline, or restarts it from its beginning if it was stopped.
+    pub fn play(&mut self) {
+        if self.state == PlaybackState::Stopped {
+            self.seek(0.0);
+        }
+        self.state = PlaybackState::Playing;
+    }
+
+    /// Pauses the timeline, keeping the bound entities where the timeline put them.
+    pub fn pause(&mut self) {
+        if self.state == PlaybackState::Playing {
+            self.state = PlaybackState::Paused;
+        }
+    }
+
+    /// Stops the timeline, giving the bound entities back to the gameplay.
+    pub fn stop(&mut self) {
+        self.state = PlaybackState::Stopped;
+    }
+
+    /// Jumps to `time`, without triggering the markers and the sounds in between.
+    pub fn seek(&mut self, time: f32) {
+        self.time = time;
+        self.seeked = true;
+    }
+
+    /// Returns how much the timeline overrides the gameplay values at the current time, as
+    /// defined by [`TimelinePlayer::blend_in`] and [`TimelinePlayer::blend_out`].
+    pub fn weight(&self, timeline: &Timeline) -> f32 {
+        if self.state == PlaybackState::Stopped {
+            return 0.0;
+        }
+        let blend = |elapsed: f32, duration: f32| {
+            if duration > 0.0 {
+                (elapsed / duration).clamp(0.0, 1.0)
+            } else {
+                1.0
+            }
+        };
+        blend(self.time, self.blend_in).min(blend(timeline.duration - self.time, self.blend_out))
+    }
+}
+
+/// Triggered on the entity with the [`TimelinePlayer`] when its timeline plays past a marker of
+/// a [`MarkerTrack`](crate::MarkerTrack).
+#[derive(EntityEvent, Clone, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Event, Debug, PartialEq, Clone)]
+pub struct TimelineMarker {
+    /// The entity with the [`TimelinePlayer`].
+    pub entity: Entity,
+    /// The name of the marker.
+    pub name: String,
+}
+
+/// Advances the [`TimelinePlayer`]s, and triggers the markers and the sounds played past.
+pub(crate) fn advance_timelines(
+    time: Res<Time>,
+    timelines: Res<Assets<Timeline>>,
+    mut players: Query<(Entity, &mut TimelinePlayer)>,
+    mut commands: Commands,
+) {
+    for (entity, mut player) in &mut players {
+        let Some(timeline) = timelines.get(&player.timeline) else {
+            continue;
+        };
+
+        let player = &mut *player;
+        player.previous_time = player.time;
+        if player.state == PlaybackState::Playing {
+            player.time += time.delta_secs() * player.speed;
+        }
+        let end = if player.speed < 0.0 {
+            0.0
+        } else {
+            timeline.duration
+        };
+        player.time = player.time.clamp(0.0, timeline.duration);
+
+        if !player.seeked && player.state == PlaybackState::Playing {
+            let (from, to) = (player.previous_time, player.time);
+            let played_past = |at: f32| {
+                if from <= to {
+                    from < at && at <= to || from == 0.0 && at == 0.0 && to > 0.0
+                } else {
+                    to <= at && at < from
+                }
+            };
+            for track in &timeline.tracks {
+                match track {
+                    Track::Marker(track) => {
+                        for (_, name) in track.markers.iter().filter(|(at, _)| played_past(*at)) {
+                            commands.trigger(TimelineMarker {
+                                entity,
+                                name: name.clone(),
+                            });
+                        }
+                    }
+                    #[cfg(feature = "bevy_audio")]
+                    Track::Audio(track) => {
+                        for cue in track.cues.iter().filter(|cue| played_past(cue.start)) {
+                            commands.spawn((
+                                AudioPlayer(cue.source.clone()),
+                                PlaybackSettings::DESPAWN.with_volume(cue.volume),
+                            ));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        player.seeked = false;
+
+        if player.state == PlaybackState::Playing && player.time == end {
+            player.state = PlaybackState::Stopped;
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use bevy_animation::graph::AnimationNodeIndex;
+use bevy_asset::Asset;
+#[cfg(feature = "bevy_audio")]
+use bevy_asset::Handle;
+#[cfg(feature = "bevy_audio")]
+use bevy_audio::{AudioSource, Volume};
+use bevy_math::{
+    curve::{Curve, UnevenSampleAutoCurve},
+    Vec3,
+};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+/// A cutscene: tracks of animations, camera cuts, sounds, markers and property curves, played
+/// together by a [`TimelinePlayer`](crate::TimelinePlayer).
+///
+/// The tracks do not reference entities directly, but binding names, which each player maps to
+/// the entities of the scene, see [`TimelinePlayer::bind`](crate::TimelinePlayer::bind). The
+/// same timeline can so be played with different actors.
+///
+/// ```
+/// # use bevy_animation::graph::AnimationNodeIndex;
+/// # use bevy_math::curve::UnevenSampleAutoCurve;
+/// # use bevy_timeline::*;
+/// # let wave = AnimationNodeIndex::new(1);
+/// let timeline = Timeline::new(6.0)
+///     .with_track(AnimationTrack::new("hero").with_segment(AnimationSegment::new(wave, 0.0, 4.0)))
+///     .with_track(
+///         CameraTrack::new("camera")
+///             .with_cut(0.0, "wide_shot")
+///             .with_cut(3.0, "close_up"),
+///     )
+///     .with_track(MarkerTrack::default().with_marker(4.0, "door_opens"))
+///     .with_track(PropertyTrack::new(
+///         "door",
+///         "Transform",
+///         "translation.y",
+///         UnevenSampleAutoCurve::new([(4.0, 0.0), (6.0, 3.0)]).unwrap(),
+///     ));
+/// ```
+///
+/// Timelines can be saved to `.timeline.ron` files with [`Timeline::save`], and loaded with the
+/// [`TimelineLoader`](crate::TimelineLoader).
+#[derive(Asset, Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Clone, Debug, Default)]
+pub struct Timeline {
+    /// The length of the timeline, in seconds.
+    pub duration: f32,
+    /// The tracks played by the timeline.
+    pub tracks: Vec<Track>,
+}
+
+impl Timeline {
+    /// Creates an empty timeline lasting `duration` seconds.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            tracks: Vec::new(),
+        }
+    }
+
+    /// Adds a track to the timeline.
+    pub fn with_track(mut self, track: impl Into<Track>) -> Self {
+        self.tracks.push(track.into());
+        self
+    }
+
+    /// Serializes the timeline to the given [`Write`]r in RON format.
+    ///
+    /// If writing to a file, it can later be loaded with the
+    /// [`TimelineLoader`](crate::TimelineLoader). Audio tracks reference their sounds by
+    /// handle, so they cannot be saved.
+    pub fn save<W>(&self, writer: &mut W) -> Result<(), ron::Error>
+    where
+        W: Write,
+    {
+        let mut ron_serializer = ron::ser::Serializer::new(writer, Some(PrettyConfig::default()))?;
+        self.serialize(&mut ron_serializer)
+    }
+}
+
+/// A track of a [`Timeline`].
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub enum Track {
+    /// Plays animations on an entity.
+    Animation(AnimationTrack),
+    /// Moves a camera between shots.
+    Camera(CameraTrack),
+    /// Triggers [`TimelineMarker`](crate::TimelineMarker)s.
+    Marker(MarkerTrack),
+    /// Drives a field of a component.
+    Property(PropertyTrack),
+    /// Plays sounds.
+    ///
+    /// Audio tracks are not serialized, since their sounds are handles.
+    #[cfg(feature = "bevy_audio")]
+    #[serde(skip)]
+    Audio(AudioTrack),
+}
+
+impl From<AnimationTrack> for Track {
+    fn from(track: AnimationTrack) -> Self {
+        Track::Animation(track)
+    }
+}
+
+impl From<CameraTrack> for Track {
+    fn from(track: CameraTrack) -> Self {
+        Track::Camera(track)
+    }
+}
+
+impl From<MarkerTrack> for Track {
+    fn from(track: MarkerTrack) -> Self {
+        Track::Marker(track)
+    }
+}
+
+impl From<PropertyTrack> for Track {
+    fn from(track: PropertyTrack) -> Self {
+        Track::Property(track)
+    }
+}
+
+#[cfg(feature = "bevy_audio")]
+impl From<AudioTrack> for Track {
+    fn from(track: AudioTrack) -> Self {
+        Track::Audio(track)
+    }
+}
+
+/// Plays nodes of the [`AnimationGraph`](bevy_animation::graph::AnimationGraph) of the bound
+/// entity's [`AnimationPlayer`](bevy_animation::AnimationPlayer), in sync with the timeline.
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct AnimationTrack {
+    /// The binding of the entity with the animation player.
+    pub binding: String,
+    /// The animations played on the track. Overlapping segments crossfade.
+    pub segments: Vec<AnimationSegment>,
+}
+
+impl AnimationTrack {
+    /// Creates an empty track for the entity bound to `binding`.
+    pub fn new(binding: impl Into<String>) -> Self {
+        Self {
+            binding: binding.into(),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Adds a segment to the track.
+    pub fn with_segment(mut self, segment: AnimationSegment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+}
+
+/// An animation played during a part of an [`AnimationTrack`].
+#[derive(Reflect, Clone, Copy, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct AnimationSegment {
+    /// The animation graph node to play.
+    pub node: AnimationNodeIndex,
+    /// When the segment starts, in seconds of the timeline.
+    pub start: f32,
+    /// When the segment ends, in seconds of the timeline.
+    pub end: f32,
+    /// The time in the animation played at the start of the segment.
+    pub clip_start: f32,
+    /// How long the segment fades in and out, to crossfade with the overlapping segments.
+    pub blend: f32,
+}
+
+impl AnimationSegment {
+    /// Plays `node` from its beginning between `start` and `end`, without fading.
+    pub fn new(node: AnimationNodeIndex, start: f32, end: f32) -> Self {
+        Self {
+            node,
+            start,
+            end,
+            clip_start: 0.0,
+            blend: 0.0,
+        }
+    }
+
+    /// Returns the weight of the segment at `time`, from zero outside of it to one once it has
+    /// faded in.
+    pub fn weight(&self, time: f32) -> f32 {
+        if time < self.start || time > self.end {
+            return 0.0;
+        }
+        if self.blend <= 0.0 {
+            return 1.0;
+        }
+        ((time - self.start) / self.blend)
+            .min((self.end - time) / self.blend)
+            .clamp(0.0, 1.0)
+    }
+}
+
+/// Moves the bound camera to the pose of the shot of the latest cut.
+///
+/// The shots are entities, usually without a camera, whose
+/// [`GlobalTransform`](bevy_transform::components::GlobalTransform) gives the pose of the
+/// camera.
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct CameraTrack {
+    /// The binding of the camera.
+    pub camera: String,
+    /// The cuts, sorted by their start time.
+    pub cuts: Vec<CameraCut>,
+}
+
+impl CameraTrack {
+    /// Creates an empty track moving the camera bound to `camera`.
+    pub fn new(camera: impl Into<String>) -> Self {
+        Self {
+            camera: camera.into(),
+            cuts: Vec::new(),
+        }
+    }
+
+    /// Cuts to the shot bound to `shot` at `start`.
+    pub fn with_cut(mut self, start: f32, shot: impl Into<String>) -> Self {
+        self.cuts.push(CameraCut {
+            start,
+            shot: shot.into(),
+        });
+        self.cuts.sort_by(|a, b| a.start.total_cmp(&b.start));
+        self
+    }
+
+    /// Returns the cut active at `time`.
+    pub fn cut_at(&self, time: f32) -> Option<&CameraCut> {
+        self.cuts.iter().rev().find(|cut| cut.start <= time)
+    }
+}
+
+/// A cut of a [`CameraTrack`].
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct CameraCut {
+    /// When the camera cuts to the shot, in seconds of the timeline.
+    pub start: f32,
+    /// The binding of the shot.
+    pub shot: String,
+}
+
+/// Triggers a [`TimelineMarker`](crate::TimelineMarker) when the timeline plays past each
+/// marker.
+///
+/// Markers are skipped when seeking, see [`TimelinePlayer::seek`](crate::TimelinePlayer::seek).
+#[derive(Reflect, Clone, Debug, Default, Serialize, Deserialize)]
+#[reflect(Clone, Debug, Default)]
+pub struct MarkerTrack {
+    /// The markers, as their time and their name.
+    pub markers: Vec<(f32, String)>,
+}
+
+impl MarkerTrack {
+    /// Adds a marker named `name` at `time`.
+    pub fn with_marker(mut self, time: f32, name: impl Into<String>) -> Self {
+        self.markers.push((time, name.into()));
+        self
+    }
+}
+
+/// Drives a field of a component of the bound entity with a curve, by reflection.
+///
+/// The component must be registered, and reflect [`Component`](bevy_ecs::component::Component).
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub struct PropertyTrack {
+    /// The binding of the entity with the component.
+    pub binding: String,
+    /// The type path of the component, either in full or short, like `Transform`.
+    pub component: String,
+    /// The path of the field in the component, see
+    /// [`GetPath`](bevy_reflect::GetPath).
+    pub field: String,
+    /// The values of the field over time.
+    pub curve: PropertyCurve,
+}
+
+impl PropertyTrack {
+    /// Drives `field` of `component` on the entity bound to `binding` with `curve`.
+    pub fn new(
+        binding: impl Into<String>,
+        component: impl Into<String>,
+        field: impl Into<String>,
+        curve: impl Into<PropertyCurve>,
+    ) -> Self {
+        Self {
+            binding: binding.into(),
+            component: component.into(),
+            field: field.into(),
+            curve: curve.into(),
+        }
+    }
+}
+
+/// The values of the field driven by a [`PropertyTrack`], interpolated between keyframes.
+///
+/// The values before the first keyframe and after the last are held.
+#[derive(Reflect, Clone, Debug, Serialize, Deserialize)]
+#[reflect(Clone, Debug)]
+pub enum PropertyCurve {
+    /// Drives an [`f32`] field.
+    F32(UnevenSampleAutoCurve<f32>),
+    /// Drives a [`Vec3`] field.
+    Vec3(UnevenSampleAutoCurve<Vec3>),
+}
+
+impl From<UnevenSampleAutoCurve<f32>> for PropertyCurve {
+    fn from(curve: UnevenSampleAutoCurve<f32>) -> Self {
+        PropertyCurve::F32(curve)
+    }
+}
+
+impl From<UnevenSampleAutoCurve<Vec3>> for PropertyCurve {
+    fn from(curve: UnevenSampleAutoCurve<Vec3>) -> Self {
+        PropertyCurve::Vec3(curve)
+    }
+}
+
+impl PropertyCurve {
+    /// Returns the value of the curve at `time`.
+    pub fn sample(&self, time: f32) -> PropertyValue {
+        match self {
+            PropertyCurve::F32(curve) => PropertyValue::F32(curve.sample_clamped(time)),
+            PropertyCurve::Vec3(curve) => PropertyValue::Vec3(curve.sample_clamped(time)),
+        }
+    }
+}
+
+/// A value of a [`PropertyCurve`].
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[reflect(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// An [`f32`] value.
+    F32(f32),
+    /// A [`Vec3`] value.
+    Vec3(Vec3),
+}
+
+impl PropertyValue {
+    /// Interpolates from `self` to `other`.
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        match (self, other) {
+            (PropertyValue::F32(a), PropertyValue::F32(b)) => PropertyValue::F32(a + (b - a) * t),
+            (PropertyValue::Vec3(a), PropertyValue::Vec3(b)) => PropertyValue::Vec3(a.lerp(b, t)),
+            _ => other,
+        }
+    }
+}
+
+/// Plays sounds at points of the timeline.
+///
+/// Sounds are only started when the timeline plays past their start, not when seeking, and are
+/// not stopped when the timeline is paused or stopped.
+#[cfg(feature = "bevy_audio")]
+#[derive(Reflect, Clone, Debug, Default)]
+#[reflect(Clone, Debug, Default)]
+pub struct AudioTrack {
+    /// The sounds played by the track.
+    pub cues: Vec<AudioCue>,
+}
+
+#[cfg(feature = "bevy_audio")]
+impl AudioTrack {
+    /// Plays `source` at `start`.
+    pub fn with_cue(mut self, start: f32, source: Handle<AudioSource>) -> Self {
+        self.cues.push(AudioCue {
+            start,
+            source,
+            volume: Volume::Linear(1.0),
+        });
+        self
+    }
+}
+
+/// A sound of an [`AudioTrack`].
+#[cfg(feature = "bevy_audio")]
+#[derive(Reflect, Clone, Debug)]
+#[reflect(Clone, Debug)]
+pub struct AudioCue {
+    /// When the sound starts, in seconds of the timeline.
+    pub start: f32,
+    /// The sound to play.
+    pub source: Handle<AudioSource>,
+    /// The volume of the sound.
+    pub volume: Volume,
+}
@@ -46,12 +46,13 @@ pub mod prelude {
         commands::BuildChildrenTransformExt,
         helper::TransformHelper,
         plugins::{TransformPlugin, TransformSystems},
-        systems::StaticTransformOptimizations,
+        systems::{StaticTransformOptimizations, TransformPropagation},
         traits::TransformPoint,
     };
 }
 
 #[cfg(feature = "bevy-support")]
 pub use prelude::{
-    StaticTransformOptimizations, TransformPlugin, TransformPoint, TransformSystems,
+    StaticTransformOptimizations, TransformPlugin, TransformPoint, TransformPropagation,
+    TransformSystems,
 };
@@ -1,12 +1,15 @@
 use crate::{
     prelude::GlobalTransform,
     systems::{
-        mark_dirty_trees, propagate_parent_transforms, sync_simple_transforms,
-        StaticTransformOptimizations,
+        mark_dirty_trees, propagate_parent_transforms, propagate_transforms_deterministic,
+        sync_simple_transforms, StaticTransformOptimizations, TransformPropagation,
     },
 };
 use bevy_app::{App, Plugin, PostStartup, PostUpdate, ValidateParentHasComponentPlugin};
-use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_ecs::{
+    schedule::{common_conditions::not, IntoScheduleConfigs, SystemSet},
+    system::Res,
+};
 
 /// Set enum for the systems relating to transform propagation
 #[derive(Debug, Hash, PartialEq, Eq, Clone, SystemSet)]
@@ -16,6 +19,10 @@ pub enum TransformSystems {
 }
 
 /// The base plugin for handling [`Transform`](crate::components::Transform) components
+///
+/// How transforms are propagated is configured by the [`TransformPropagation`] resource. Insert
+/// [`TransformPropagation::DeterministicF64`] for lockstep multiplayer games, which need identical
+/// results across platforms.
 #[derive(Default)]
 pub struct TransformPlugin;
 
@@ -23,19 +30,26 @@ impl Plugin for TransformPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ValidateParentHasComponentPlugin::<GlobalTransform>::default())
             .init_resource::<StaticTransformOptimizations>()
-            // add transform systems to startup so the first update is "correct"
-            .add_systems(
-                PostStartup,
+            .init_resource::<TransformPropagation>();
+
+        // add transform systems to startup so the first update is "correct"
+        app.add_systems(
+            PostStartup,
+            (
                 (
                     mark_dirty_trees,
                     propagate_parent_transforms,
                     sync_simple_transforms,
                 )
                     .chain()
-                    .in_set(TransformSystems::Propagate),
+                    .run_if(not(is_deterministic)),
+                propagate_transforms_deterministic.run_if(is_deterministic),
             )
-            .add_systems(
-                PostUpdate,
+                .in_set(TransformSystems::Propagate),
+        )
+        .add_systems(
+            PostUpdate,
+            (
                 (
                     mark_dirty_trees,
                     propagate_parent_transforms,
@@ -43,7 +57,14 @@ impl Plugin for TransformPlugin {
                     sync_simple_transforms,
                 )
                     .chain()
-                    .in_set(TransformSystems::Propagate),
-            );
+                    .run_if(not(is_deterministic)),
+                propagate_transforms_deterministic.run_if(is_deterministic),
+            )
+                .in_set(TransformSystems::Propagate),
+        );
     }
 }
+
+fn is_deterministic(propagation: Res<TransformPropagation>) -> bool {
+    propagation.is_deterministic()
+}
@@ -3,7 +3,9 @@ use crate::{
     helper::TransformHelper,
 };
 
+use alloc::vec::Vec;
 use bevy_ecs::{prelude::*, query::QueryFilter};
+use bevy_math::{Affine3A, DAffine3};
 
 /// Generic system that propagates transforms,
 /// using [`TransformHelper`] for any entity matching the filter `F`.
@@ -102,6 +104,45 @@ impl StaticTransformOptimizations {
     }
 }
 
+/// Configure how [`Transform`]s are propagated to [`GlobalTransform`]s by the
+/// [`TransformPlugin`](crate::TransformPlugin).
+///
+/// Insert this resource when building the app, like `app.insert_resource(TransformPropagation::DeterministicF64)`.
+///
+/// By default, transform propagation runs in parallel and skips the unchanged parts of the
+/// hierarchy. The deterministic modes propagate the whole hierarchy on a single thread, in a fixed
+/// order, every frame, so that the results only depend on the current [`Transform`]s. This is
+/// slower, and should only be used when needed.
+///
+/// Only [`TransformPropagation::DeterministicF64`] gives identical [`GlobalTransform`]s across
+/// machines, as lockstep multiplayer games and cross-platform replays need. The `f32` math of
+/// [`TransformPropagation::Deterministic`] uses SIMD code paths and fused multiply-adds that
+/// depend on the target, so it is only reproducible with the same build on the same kind of CPU.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum TransformPropagation {
+    /// Propagate transforms in parallel, with static scene optimizations.
+    #[default]
+    Parallel,
+    /// Propagate transforms in a fixed order, with `f32` math. The results are reproducible with
+    /// the same build on the same kind of CPU, but may differ across platforms.
+    Deterministic,
+    /// Propagate transforms deterministically, with `f64` math. The hierarchy is composed in
+    /// `f64`, and only the resulting [`GlobalTransform`]s are rounded to `f32`. This avoids the
+    /// platform specific SIMD and fused multiply-add code paths of the `f32` math, and the
+    /// accumulation of rounding errors in deep hierarchies, so this is the only mode giving the
+    /// same results across platforms.
+    DeterministicF64,
+}
+
+impl TransformPropagation {
+    /// Returns `true` if transforms are propagated deterministically.
+    #[inline]
+    pub fn is_deterministic(&self) -> bool {
+        *self != TransformPropagation::Parallel
+    }
+}
+
 /// Optimization for static scenes.
 ///
 /// Propagates a "dirty bit" up the hierarchy towards ancestors. Transform propagation can ignore
@@ -115,12 +156,8 @@ pub fn mark_dirty_trees(
     parents: Query<&ChildOf>,
     static_optimizations: Res<StaticTransformOptimizations>,
     // Cached allocations for multi-threaded parallel implementation
-    #[cfg(feature = "multi_threaded")] mut shared_bitset: Local<
-        alloc::vec::Vec<core::sync::atomic::AtomicU64>,
-    >,
-    #[cfg(feature = "multi_threaded")] mut local_bitset: Local<
-        bevy_utils::Parallel<alloc::vec::Vec<u64>>,
-    >,
+    #[cfg(feature = "multi_threaded")] mut shared_bitset: Local<Vec<core::sync::atomic::AtomicU64>>,
+    #[cfg(feature = "multi_threaded")] mut local_bitset: Local<bevy_utils::Parallel<Vec<u64>>>,
     #[cfg(feature = "multi_threaded")] mut consumer_channels: Local<
         bevy_utils::BufferedChannel<Entity>,
     >,
@@ -812,15 +849,97 @@ mod parallel {
     }
 }
 
+/// The global transform of a parent, in the precision of the [`TransformPropagation`] mode.
+#[derive(Clone, Copy)]
+enum PropagatedTransform {
+    F32(GlobalTransform),
+    F64(DAffine3),
+}
+
+impl PropagatedTransform {
+    fn new(transform: &Transform, propagation: TransformPropagation) -> Self {
+        match propagation {
+            TransformPropagation::DeterministicF64 => Self::F64(Self::daffine(transform)),
+            _ => Self::F32(GlobalTransform::from(*transform)),
+        }
+    }
+
+    fn daffine(transform: &Transform) -> DAffine3 {
+        DAffine3::from_scale_rotation_translation(
+            transform.scale.as_dvec3(),
+            transform.rotation.as_dquat(),
+            transform.translation.as_dvec3(),
+        )
+    }
+
+    fn mul_transform(&self, transform: &Transform) -> Self {
+        match self {
+            Self::F32(global_transform) => Self::F32(global_transform.mul_transform(*transform)),
+            Self::F64(affine) => Self::F64(*affine * Self::daffine(transform)),
+        }
+    }
+
+    fn global_transform(&self) -> GlobalTransform {
+        match self {
+            Self::F32(global_transform) => *global_transform,
+            Self::F64(affine) => GlobalTransform::from(Affine3A::from_mat3_translation(
+                affine.matrix3.as_mat3(),
+                affine.translation.as_vec3(),
+            )),
+        }
+    }
+}
+
+/// Update the [`GlobalTransform`] of all entities deterministically, for the deterministic
+/// [`TransformPropagation`] modes.
+///
+/// The roots of the hierarchy are visited in the order of their [`Entity`], and the children in
+/// the order of their [`Children`]. Every [`GlobalTransform`] is recomputed every frame, but only
+/// changed when its value differs.
+///
+/// This replaces [`mark_dirty_trees`], [`propagate_parent_transforms`] and
+/// [`sync_simple_transforms`].
+pub fn propagate_transforms_deterministic(
+    roots: Query<Entity, (With<Transform>, With<GlobalTransform>, Without<ChildOf>)>,
+    mut transforms: Query<(&Transform, &mut GlobalTransform)>,
+    children: Query<&Children>,
+    propagation: Res<TransformPropagation>,
+    mut sorted_roots: Local<Vec<Entity>>,
+) {
+    let mut stack: Vec<(Entity, Option<PropagatedTransform>)> = Vec::new();
+    sorted_roots.clear();
+    sorted_roots.extend(&roots);
+    sorted_roots.sort_unstable();
+
+    for &root in sorted_roots.iter() {
+        stack.push((root, None));
+        while let Some((entity, parent)) = stack.pop() {
+            let Ok((transform, mut global_transform)) = transforms.get_mut(entity) else {
+                continue;
+            };
+            let propagated = match parent {
+                Some(parent) => parent.mul_transform(transform),
+                None => PropagatedTransform::new(transform, *propagation),
+            };
+            global_transform.set_if_neq(propagated.global_transform());
+
+            if let Ok(children) = children.get(entity) {
+                // Pushed in reverse, to pop the children in order.
+                stack.extend(children.iter().rev().map(|child| (child, Some(propagated))));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use alloc::{vec, vec::Vec};
     use bevy_app::prelude::*;
     use bevy_ecs::world::CommandQueue;
-    use bevy_math::{vec3, Vec3};
+    use bevy_math::{vec3, Quat, Vec3};
     use bevy_tasks::{ComputeTaskPool, TaskPool};
 
-    use crate::systems::*;
+    use crate::{plugins::TransformPlugin, systems::*};
 
     #[test]
     fn correct_parent_removed() {
@@ -1218,4 +1337,55 @@ mod test {
             *world.entity(child).get::<GlobalTransform>().unwrap()
         );
     }
+
+    #[test]
+    fn deterministic_propagation_matches_parallel_propagation() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        let propagate = |propagation: TransformPropagation| {
+            let mut app = App::new();
+            app.insert_resource(propagation)
+                .add_plugins(TransformPlugin);
+            let root = app
+                .world_mut()
+                .spawn(Transform::from_xyz(1.0, 2.0, 3.0).with_scale(Vec3::splat(2.0)))
+                .id();
+            let child = app
+                .world_mut()
+                .spawn((
+                    Transform::from_xyz(0.5, 0.0, 0.0).with_rotation(Quat::from_rotation_y(1.0)),
+                    ChildOf(root),
+                ))
+                .id();
+            let grandchild = app
+                .world_mut()
+                .spawn((Transform::from_xyz(0.0, 0.0, 4.0), ChildOf(child)))
+                .id();
+            let simple = app
+                .world_mut()
+                .spawn(Transform::from_xyz(7.0, 0.0, 0.0))
+                .id();
+            app.update();
+
+            // Moving the root updates its descendants.
+            app.world_mut()
+                .get_mut::<Transform>(root)
+                .unwrap()
+                .translation
+                .x = -1.0;
+            app.update();
+
+            [root, child, grandchild, simple]
+                .map(|entity| *app.world().get::<GlobalTransform>(entity).unwrap())
+        };
+
+        let parallel = propagate(TransformPropagation::Parallel);
+        assert_eq!(propagate(TransformPropagation::Deterministic), parallel);
+        for (f64, f32) in propagate(TransformPropagation::DeterministicF64)
+            .iter()
+            .zip(parallel)
+        {
+            assert!(f64.affine().abs_diff_eq(f32.affine(), 1e-5));
+        }
+    }
 }
@@ -3,8 +3,8 @@ use crate::experimental::GhostNode;
 use crate::{
     experimental::{UiChildren, UiRootNodes},
     ui_transform::{UiGlobalTransform, UiTransform},
-    ComputedNode, ComputedUiRenderTargetInfo, ContentSize, Display, FixedNode, IgnoreScroll,
-    LayoutConfig, Node, Outline, OverflowAxis, ScrollPosition,
+    ComputedNode, ComputedUiRenderTargetInfo, ContentSize, DefaultLayoutConfig, Display, FixedNode,
+    IgnoreScroll, LayoutConfig, Node, Outline, OverflowAxis, ScrollPosition,
 };
 use bevy_ecs::{
     change_detection::{DetectChanges, DetectChangesMut},
@@ -12,11 +12,11 @@ use bevy_ecs::{
     hierarchy::{ChildOf, Children},
     lifecycle::RemovedComponents,
     query::{Added, Has, With},
-    system::{Query, ResMut},
+    system::{Query, Res, ResMut},
     world::Ref,
 };
 
-use bevy_math::{Affine2, Vec2};
+use bevy_math::{Affine2, Mat2, Vec2};
 use bevy_sprite::BorderRect;
 use ui_surface::UiSurface;
 
@@ -67,6 +67,7 @@ impl Default for LayoutContext {
 /// Updates the UI's layout tree, computes the new layout geometry and then updates the sizes and transforms of all the UI nodes.
 pub fn ui_layout_system(
     mut ui_surface: ResMut<UiSurface>,
+    default_layout_config: Res<DefaultLayoutConfig>,
     ui_root_node_query: UiRootNodes,
     fixed_nodes_query: Query<Entity, (With<FixedNode>, With<ChildOf>)>,
     ui_children: UiChildren,
@@ -226,9 +227,9 @@ pub fn ui_layout_system(
             ui_root_entity,
             ui_root_entity,
             &mut ui_surface,
-            true,
+            default_layout_config.0,
             computed_target.physical_size().as_vec2(),
-            Affine2::IDENTITY,
+            Affine2::from_translation(computed_target.offset()),
             &mut node_update_query,
             &ui_children,
             computed_target.scale_factor.recip(),
@@ -242,7 +243,7 @@ pub fn ui_layout_system(
         root: Entity,
         entity: Entity,
         ui_surface: &mut UiSurface,
-        inherited_use_rounding: bool,
+        inherited_layout_config: LayoutConfig,
         target_size: Vec2,
         mut inherited_transform: Affine2,
         node_update_query: &mut Query<(
@@ -277,11 +278,13 @@ pub fn ui_layout_system(
                 return;
             }
 
-            let use_rounding = maybe_layout_config
-                .map(|layout_config| layout_config.use_rounding)
-                .unwrap_or(inherited_use_rounding);
+            let layout_config = maybe_layout_config
+                .copied()
+                .unwrap_or(inherited_layout_config);
 
-            let Ok((layout, unrounded_size)) = ui_surface.get_layout(entity, use_rounding) else {
+            let Ok((layout, unrounded_size)) =
+                ui_surface.get_layout(entity, layout_config.use_rounding)
+            else {
                 return;
             };
 
@@ -337,6 +340,14 @@ pub fn ui_layout_system(
             local_transform.translation += local_center;
             inherited_transform *= local_transform;
 
+            // Snap the top-left corner of the node to a physical pixel, which also snaps its
+            // descendants as their layout offsets are whole pixels.
+            if layout_config.snap_to_pixels && inherited_transform.matrix2 == Mat2::IDENTITY {
+                let half_size = 0.5 * layout_size;
+                inherited_transform.translation =
+                    (inherited_transform.translation - half_size).round() + half_size;
+            }
+
             if inherited_transform != **global_transform {
                 *global_transform = inherited_transform.into();
             }
@@ -426,7 +437,7 @@ pub fn ui_layout_system(
                     root,
                     child_uinode,
                     ui_surface,
-                    use_rounding,
+                    layout_config,
                     target_size,
                     inherited_transform,
                     node_update_query,
@@ -471,6 +482,7 @@ mod tests {
         ));
         app.init_resource::<UiScale>();
         app.init_resource::<UiSurface>();
+        app.init_resource::<DefaultLayoutConfig>();
         app.init_resource::<bevy_text::TextPipeline>();
         app.init_resource::<bevy_text::FontCx>();
         app.init_resource::<bevy_text::ScaleCx>();
@@ -1194,6 +1206,51 @@ mod tests {
         assert_eq!(layout.size.height, 0.);
     }
 
+    #[test]
+    fn auto_fill_grid_responds_to_width() {
+        let mut app = setup_ui_test_app();
+        let world = app.world_mut();
+
+        let grid = world
+            .spawn(Node {
+                display: Display::Grid,
+                width: Val::Px(350.),
+                grid_template_columns: RepeatedGridTrack::minmax(
+                    GridTrackRepetition::AutoFill,
+                    MinTrackSizingFunction::Px(100.),
+                    MaxTrackSizingFunction::Fraction(1.),
+                ),
+                ..default()
+            })
+            .with_children(|commands| {
+                for _ in 0..8 {
+                    commands.spawn(Node {
+                        height: Val::Px(10.),
+                        ..default()
+                    });
+                }
+            })
+            .id();
+
+        let children = world
+            .entity(grid)
+            .get::<Children>()
+            .unwrap()
+            .iter()
+            .collect::<Vec<Entity>>();
+
+        for (width, columns) in [(350., 3.), (450., 4.), (150., 1.)] {
+            app.world_mut().get_mut::<Node>(grid).unwrap().width = Val::Px(width);
+            app.update();
+            let world = app.world();
+            let item_width = world.get::<ComputedNode>(children[0]).unwrap().size.x;
+            assert!((item_width - width / columns).abs() <= 1.);
+            let rows = (8. / columns).ceil();
+            let grid_height = world.get::<ComputedNode>(grid).unwrap().size.y;
+            assert_eq!(grid_height, 10. * rows);
+        }
+    }
+
     #[test]
     fn ui_rounding_test() {
         let mut app = setup_ui_test_app();
@@ -1244,6 +1301,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn snap_to_pixels_rounds_node_positions() {
+        let mut app = setup_ui_test_app();
+        let world = app.world_mut();
+        let node = Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(10.),
+            height: Val::Px(10.),
+            ..default()
+        };
+        let translation = UiTransform::from_translation(Val2::px(0.3, 0.6));
+        let blurry = world.spawn((node.clone(), translation)).id();
+        let snapped = world
+            .spawn((
+                node,
+                translation,
+                LayoutConfig {
+                    snap_to_pixels: true,
+                    ..default()
+                },
+            ))
+            .id();
+
+        app.update();
+
+        let world = app.world();
+        let blurry_translation = world.get::<UiGlobalTransform>(blurry).unwrap().translation;
+        assert!(blurry_translation.abs_diff_eq(Vec2::new(5.3, 5.6), 0.001));
+        let snapped_translation = world.get::<UiGlobalTransform>(snapped).unwrap().translation;
+        assert_eq!(snapped_translation, Vec2::new(5., 6.));
+    }
+
     #[test]
     fn no_camera_ui() {
         let mut app = App::new();
@@ -1267,6 +1356,7 @@ mod tests {
         let world = app.world_mut();
         world.init_resource::<UiScale>();
         world.init_resource::<UiSurface>();
+        world.init_resource::<DefaultLayoutConfig>();
 
         world.init_resource::<bevy_text::TextPipeline>();
 
@@ -15,6 +15,8 @@ extern crate alloc;
 pub mod auto_directional_navigation;
 pub mod interaction_states;
 pub mod measurement;
+pub mod minimap;
+pub mod split_screen;
 pub mod update;
 pub mod widget;
 
@@ -83,7 +85,7 @@ use bevy_input::InputSystems;
 use bevy_transform::TransformSystems;
 use layout::ui_surface::UiSurface;
 use stack::ui_stack_system;
-pub use stack::{ComputedStackIndex, UiStack};
+pub use stack::{ComputedStackIndex, UiNodesAtPoint, UiStack};
 use update::{propagate_ui_target_cameras, update_clipping_system};
 
 /// The basic plugin for Bevy UI
@@ -143,6 +145,7 @@ impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<UiSurface>()
             .init_resource::<UiScale>()
+            .init_resource::<DefaultLayoutConfig>()
             .init_resource::<UiStack>()
             .configure_sets(
                 PostUpdate,
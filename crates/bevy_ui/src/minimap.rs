@@ -0,0 +1,509 @@
+//! A minimap, showing the world from above in a UI node, with icons in place of the entities.
+//!
+//! Add the [`MinimapPlugin`] to your app, then spawn:
+//!
+//! - a camera with the [`MinimapCamera`] component, which looks down on the world and renders to
+//!   a texture, optionally following a [target](MinimapCamera::target);
+//! - a UI node with the [`Minimap`] component, which displays the texture of the camera and pans
+//!   and zooms it with the mouse;
+//! - a [`MinimapIcon`] on the entities to show as icons, which always face the viewer. Their meshes
+//!   and those of their descendants are hidden from the minimap cameras only.
+//!
+//! ```
+//! # use bevy_asset::Handle;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_image::Image;
+//! # use bevy_ui::{minimap::{Minimap, MinimapCamera, MinimapIcon}, Node, Val};
+//! # fn system(mut commands: Commands, player_icon: Handle<Image>) {
+//! let player = commands.spawn(MinimapIcon::new(player_icon)).id();
+//! let camera = commands
+//!     .spawn(MinimapCamera {
+//!         target: Some(player),
+//!         ..Default::default()
+//!     })
+//!     .id();
+//! commands.spawn((
+//!     Minimap::new(camera),
+//!     Node {
+//!         width: Val::Px(200.0),
+//!         height: Val::Px(200.0),
+//!         ..Default::default()
+//!     },
+//! ));
+//! # }
+//! ```
+
+use crate::{
+    widget::{ImageNode, ViewportNode},
+    ComputedNode, Display, Node, PositionType, RelativeCursorPosition, UiRect, UiSystems, Val,
+};
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_asset::{Assets, Handle};
+use bevy_camera::{
+    visibility::{InheritedVisibility, VisibilitySystems, VisibleEntities},
+    Camera, Camera3d, CameraUpdateSystems, OrthographicProjection, Projection, RenderTarget,
+    ScalingMode,
+};
+use bevy_color::Color;
+use bevy_ecs::{entity::EntityHashMap, entity::EntityHashSet, prelude::*};
+use bevy_image::Image;
+use bevy_input::{
+    mouse::{AccumulatedMouseScroll, MouseButton, MouseScrollUnit},
+    ButtonInput,
+};
+use bevy_math::{ops, Vec2, Vec3};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::{components::GlobalTransform, components::Transform, TransformSystems};
+use wgpu_types::TextureFormat;
+
+/// A plugin managing the [`MinimapCamera`]s, the [`Minimap`] widgets and the [`MinimapIcon`]s.
+///
+/// See the [module docs](self) for an overview.
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_add_minimap_camera)
+            .add_observer(on_add_minimap)
+            .add_systems(PreUpdate, pan_and_zoom_minimaps.after(UiSystems::Focus))
+            .add_systems(
+                PostUpdate,
+                (
+                    update_minimap_cameras
+                        .before(CameraUpdateSystems)
+                        .before(TransformSystems::Propagate),
+                    hide_minimap_icon_meshes
+                        .after(VisibilitySystems::CheckVisibility)
+                        .before(VisibilitySystems::MarkNewlyHiddenEntitiesInvisible),
+                    update_minimap_icons.in_set(UiSystems::Prepare),
+                ),
+            );
+    }
+}
+
+/// A camera looking down on the world for a [`Minimap`], north (`-Z`) up.
+///
+/// It renders to a texture, which is created when the component is added if the camera doesn't
+/// target an image yet, and resized to the size of the [`Minimap`] displaying it. The camera should
+/// be given a lower [`Camera::order`] than the camera rendering the UI, so the texture is rendered
+/// first.
+///
+/// The [`Transform`] and the [`Projection`] of the camera are set from this component. The minimap
+/// doesn't hide anything from cameras using GPU culling, as it relies on their
+/// [`VisibleEntities`].
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+#[require(Camera3d, Projection = Projection::Orthographic(OrthographicProjection::default_3d()))]
+pub struct MinimapCamera {
+    /// The entity the camera is centered on, or `None` to be centered on the origin.
+    pub target: Option<Entity>,
+    /// The offset of the center of the minimap from the target, on the `XZ` plane.
+    ///
+    /// Changed by dragging the [`Minimap`].
+    pub pan: Vec2,
+    /// The height above the world the camera looks down from.
+    ///
+    /// The camera can't see above it. Defaults to `100.0`.
+    pub height: f32,
+    /// The distance in world units covered by the height of the minimap.
+    ///
+    /// Changed by scrolling over the [`Minimap`], between [`MinimapCamera::min_extent`] and
+    /// [`MinimapCamera::max_extent`]. Defaults to `50.0`.
+    pub extent: f32,
+    /// The smallest [`MinimapCamera::extent`], when the minimap is zoomed in the most.
+    ///
+    /// Defaults to `10.0`.
+    pub min_extent: f32,
+    /// The largest [`MinimapCamera::extent`], when the minimap is zoomed out the most.
+    ///
+    /// Defaults to `500.0`.
+    pub max_extent: f32,
+}
+
+impl Default for MinimapCamera {
+    fn default() -> Self {
+        Self {
+            target: None,
+            pan: Vec2::ZERO,
+            height: 100.0,
+            extent: 50.0,
+            min_extent: 10.0,
+            max_extent: 500.0,
+        }
+    }
+}
+
+/// A UI node displaying the view of a [`MinimapCamera`], with the [`MinimapIcon`]s over it.
+///
+/// Scrolling over it zooms the minimap, and dragging it with the
+/// [`pan_button`](Minimap::pan_button) pans it.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Clone, Debug, PartialEq)]
+#[require(Node, RelativeCursorPosition)]
+pub struct Minimap {
+    /// The [`MinimapCamera`] displayed by this node.
+    pub camera: Entity,
+    /// How much one line of scrolling zooms the minimap, as a fraction of its extent.
+    ///
+    /// Defaults to `0.1`. Set to `0.0` to disable zooming.
+    pub zoom_sensitivity: f32,
+    /// The mouse button panning the minimap while held, or `None` to disable panning.
+    ///
+    /// Defaults to [`MouseButton::Left`].
+    pub pan_button: Option<MouseButton>,
+}
+
+impl Minimap {
+    /// Creates a minimap displaying the view of the `camera`.
+    pub fn new(camera: Entity) -> Self {
+        Self {
+            camera,
+            zoom_sensitivity: 0.1,
+            pan_button: Some(MouseButton::Left),
+        }
+    }
+}
+
+/// Shows an entity as an icon on the [`Minimap`]s, instead of its meshes.
+#[derive(Component, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+pub struct MinimapIcon {
+    /// The image of the icon.
+    pub image: Handle<Image>,
+    /// The tint of the icon.
+    ///
+    /// Defaults to [`Color::WHITE`].
+    pub color: Color,
+    /// The size of the icon, in logical pixels. It doesn't change with the zoom of the minimap.
+    ///
+    /// Defaults to `16.0`.
+    pub size: f32,
+    /// Whether the meshes of the entity and of its descendants are hidden from the
+    /// [`MinimapCamera`]s.
+    ///
+    /// Defaults to `true`.
+    pub hide_meshes: bool,
+}
+
+impl MinimapIcon {
+    /// Creates an icon showing the `image`.
+    pub fn new(image: Handle<Image>) -> Self {
+        Self {
+            image,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for MinimapIcon {
+    fn default() -> Self {
+        Self {
+            image: Handle::default(),
+            color: Color::WHITE,
+            size: 16.0,
+            hide_meshes: true,
+        }
+    }
+}
+
+/// The node of a [`MinimapIcon`] on a [`Minimap`], spawned as a child of the minimap.
+#[derive(Component)]
+pub struct MinimapIconNode(pub Entity);
+
+/// Makes a new [`MinimapCamera`] render to a texture.
+fn on_add_minimap_camera(
+    add: On<Add, MinimapCamera>,
+    mut cameras: Query<&mut RenderTarget>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Ok(mut target) = cameras.get_mut(add.entity) else {
+        return;
+    };
+    if target.as_image().is_none() {
+        // The texture is resized to the size of the minimap by `update_viewport_render_target_size`.
+        let image = Image::new_target_texture(1, 1, TextureFormat::Rgba8UnormSrgb, None);
+        *target = RenderTarget::Image(images.add(image).into());
+    }
+}
+
+/// Displays the view of the camera of a new [`Minimap`].
+fn on_add_minimap(add: On<Add, Minimap>, mut commands: Commands, minimaps: Query<&Minimap>) {
+    if let Ok(minimap) = minimaps.get(add.entity) {
+        commands
+            .entity(add.entity)
+            .insert(ViewportNode::new(minimap.camera));
+    }
+}
+
+/// Pans and zooms the [`MinimapCamera`]s of the hovered [`Minimap`]s.
+pub fn pan_and_zoom_minimaps(
+    mut last_cursors: Local<EntityHashMap<Vec2>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    minimaps: Query<(Entity, &Minimap, &RelativeCursorPosition, &ComputedNode)>,
+    mut cameras: Query<&mut MinimapCamera>,
+) {
+    let mut dragged = EntityHashMap::default();
+    for (entity, minimap, cursor, node) in &minimaps {
+        let Ok(mut camera) = cameras.get_mut(minimap.camera) else {
+            continue;
+        };
+
+        if cursor.cursor_over() && minimap.zoom_sensitivity > 0.0 {
+            let lines = match mouse_scroll.unit {
+                MouseScrollUnit::Line => mouse_scroll.delta.y,
+                // Roughly the number of pixels of a line.
+                MouseScrollUnit::Pixel => mouse_scroll.delta.y / 16.0,
+            };
+            if lines != 0.0 {
+                let extent = camera.extent * ops::powf(1.0 - minimap.zoom_sensitivity, lines);
+                camera.extent = extent.clamp(camera.min_extent, camera.max_extent);
+            }
+        }
+
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+        let Some(button) = minimap.pan_button else {
+            continue;
+        };
+        let holding = mouse_buttons.pressed(button);
+        let last = last_cursors.get(&entity).copied();
+        // A drag starts over the minimap, and keeps panning it when leaving it.
+        if holding
+            && (last.is_some() || (cursor.cursor_over() && mouse_buttons.just_pressed(button)))
+        {
+            if let Some(last) = last {
+                let size = node.size();
+                if size.y > 0.0 {
+                    // The world follows the cursor, so the camera moves against it. The normalized
+                    // cursor position goes down the node, toward +Z.
+                    let aspect = size.x / size.y;
+                    let extent = camera.extent;
+                    camera.pan -= (normalized - last) * Vec2::new(aspect, 1.0) * extent;
+                }
+            }
+            dragged.insert(entity, normalized);
+        }
+    }
+    *last_cursors = dragged;
+}
+
+/// Places the [`MinimapCamera`]s above their target, and sets their [`Projection`] from their
+/// extent.
+pub fn update_minimap_cameras(
+    mut cameras: Query<(&MinimapCamera, &mut Transform, &mut Projection)>,
+    targets: Query<&GlobalTransform>,
+) {
+    for (minimap_camera, mut transform, mut projection) in &mut cameras {
+        let target = minimap_camera
+            .target
+            .and_then(|target| targets.get(target).ok())
+            .map_or(Vec3::ZERO, GlobalTransform::translation);
+        let center = Vec2::new(target.x, target.z) + minimap_camera.pan;
+        let new_transform =
+            Transform::from_xyz(center.x, target.y + minimap_camera.height, center.y)
+                .looking_to(Vec3::NEG_Y, Vec3::NEG_Z);
+        transform.set_if_neq(new_transform);
+
+        let scaling_mode = ScalingMode::FixedVertical {
+            viewport_height: minimap_camera.extent,
+        };
+        let up_to_date = matches!(
+            &*projection,
+            Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical { viewport_height },
+                ..
+            }) if *viewport_height == minimap_camera.extent
+        );
+        if !up_to_date {
+            if let Projection::Orthographic(orthographic) = &mut *projection {
+                orthographic.scaling_mode = scaling_mode;
+            } else {
+                *projection = Projection::Orthographic(OrthographicProjection {
+                    scaling_mode,
+                    ..OrthographicProjection::default_3d()
+                });
+            }
+        }
+    }
+}
+
+/// Removes the meshes of the [`MinimapIcon`]s from the [`VisibleEntities`] of the
+/// [`MinimapCamera`]s.
+pub fn hide_minimap_icon_meshes(
+    icons: Query<(Entity, &MinimapIcon)>,
+    children: Query<&Children>,
+    mut cameras: Query<&mut VisibleEntities, With<MinimapCamera>>,
+) {
+    let mut hidden = EntityHashSet::default();
+    for (entity, icon) in &icons {
+        if icon.hide_meshes {
+            hidden.insert(entity);
+            hidden.extend(children.iter_descendants(entity));
+        }
+    }
+    if hidden.is_empty() {
+        return;
+    }
+
+    for mut visible_entities in &mut cameras {
+        for entities in visible_entities.entities.values_mut() {
+            entities.retain(|entity| !hidden.contains(entity));
+        }
+    }
+}
+
+/// Spawns, places and despawns the nodes of the [`MinimapIcon`]s on the [`Minimap`]s.
+pub fn update_minimap_icons(
+    mut commands: Commands,
+    minimaps: Query<(Entity, &Minimap, Option<&Children>)>,
+    cameras: Query<(&Camera, &GlobalTransform), With<MinimapCamera>>,
+    icons: Query<(Entity, &MinimapIcon, &GlobalTransform, &InheritedVisibility)>,
+    mut icon_nodes: Query<(&MinimapIconNode, &mut Node, &mut ImageNode)>,
+) {
+    for (minimap_entity, minimap, children) in &minimaps {
+        let Ok((camera, camera_transform)) = cameras.get(minimap.camera) else {
+            continue;
+        };
+
+        let mut existing: EntityHashMap<Entity> = children
+            .into_iter()
+            .flatten()
+            .filter_map(|child| {
+                let (icon_node, ..) = icon_nodes.get(*child).ok()?;
+                Some((icon_node.0, *child))
+            })
+            .collect();
+
+        for (icon_entity, icon, transform, visibility) in &icons {
+            // The position of the icon in the view, from (0, 0) at the top left to (1, 1).
+            let position = camera
+                .world_to_ndc(camera_transform, transform.translation())
+                .map(|ndc| Vec2::new(ndc.x + 1.0, 1.0 - ndc.y) / 2.0)
+                .filter(|position| {
+                    visibility.get()
+                        && position.cmpge(Vec2::ZERO).all()
+                        && position.cmple(Vec2::ONE).all()
+                });
+            let new_node = Node {
+                display: if position.is_some() {
+                    Display::Flex
+                } else {
+                    Display::None
+                },
+                position_type: PositionType::Absolute,
+                left: Val::Percent(position.unwrap_or_default().x * 100.0),
+                top: Val::Percent(position.unwrap_or_default().y * 100.0),
+                width: Val::Px(icon.size),
+                height: Val::Px(icon.size),
+                // Centers the icon on the entity.
+                margin: UiRect {
+                    left: Val::Px(-icon.size / 2.0),
+                    top: Val::Px(-icon.size / 2.0),
+                    ..UiRect::DEFAULT
+                },
+                ..Node::DEFAULT
+            };
+
+            match existing
+                .remove(&icon_entity)
+                .and_then(|node_entity| icon_nodes.get_mut(node_entity).ok())
+            {
+                Some((_, mut node, mut image_node)) => {
+                    node.set_if_neq(new_node);
+                    if image_node.image != icon.image {
+                        image_node.image = icon.image.clone();
+                    }
+                    if image_node.color != icon.color {
+                        image_node.color = icon.color;
+                    }
+                }
+                None => {
+                    commands.spawn((
+                        MinimapIconNode(icon_entity),
+                        new_node,
+                        ImageNode::new(icon.image.clone()).with_color(icon.color),
+                        ChildOf(minimap_entity),
+                    ));
+                }
+            }
+        }
+
+        // The icons left were removed.
+        for &node_entity in existing.values() {
+            commands.entity(node_entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_camera::visibility::Visibility;
+    use bevy_ecs::system::RunSystemOnce;
+    use core::any::TypeId;
+
+    #[test]
+    fn icon_meshes_are_hidden_from_minimap_cameras() {
+        let mut world = World::new();
+        let icon = world.spawn(MinimapIcon::default()).id();
+        let icon_child = world.spawn(ChildOf(icon)).id();
+        let other = world.spawn(Visibility::default()).id();
+        let shown_icon = world
+            .spawn(MinimapIcon {
+                hide_meshes: false,
+                ..Default::default()
+            })
+            .id();
+
+        let mut visible_entities = VisibleEntities::default();
+        visible_entities
+            .get_mut(TypeId::of::<Visibility>())
+            .extend([icon, icon_child, other, shown_icon]);
+        let minimap_camera = world
+            .spawn((MinimapCamera::default(), visible_entities.clone()))
+            .id();
+        let main_camera = world.spawn((Camera3d::default(), visible_entities)).id();
+
+        world.run_system_once(hide_minimap_icon_meshes).unwrap();
+        let visible = |camera| {
+            world
+                .get::<VisibleEntities>(camera)
+                .unwrap()
+                .get(TypeId::of::<Visibility>())
+                .to_vec()
+        };
+        assert_eq!(visible(minimap_camera), [other, shown_icon]);
+        assert_eq!(visible(main_camera), [icon, icon_child, other, shown_icon]);
+    }
+
+    #[test]
+    fn minimap_camera_follows_target() {
+        let mut world = World::new();
+        let target = world.spawn(GlobalTransform::from_xyz(10.0, 2.0, -4.0)).id();
+        let camera = world
+            .spawn(MinimapCamera {
+                target: Some(target),
+                pan: Vec2::new(1.0, 1.0),
+                extent: 20.0,
+                ..Default::default()
+            })
+            .id();
+
+        world.run_system_once(update_minimap_cameras).unwrap();
+        let transform = world.get::<Transform>(camera).unwrap();
+        assert_eq!(transform.translation, Vec3::new(11.0, 102.0, -3.0));
+        assert!(transform.forward().abs_diff_eq(Vec3::NEG_Y, 1e-6));
+        assert!(transform.up().abs_diff_eq(Vec3::NEG_Z, 1e-6));
+        assert!(matches!(
+            world.get::<Projection>(camera),
+            Some(Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical {
+                    viewport_height: 20.0
+                },
+                ..
+            }))
+        ));
+    }
+}
@@ -0,0 +1,464 @@
+//! A split-screen helper, dividing the primary window between the cameras of several local
+//! players.
+//!
+//! Add the [`SplitScreenPlugin`] to your app, and a [`SplitScreenPlayer`] component to the camera
+//! of each player. The plugin then:
+//!
+//! - computes the [`Viewport`] of every player from the [`SplitScreenLayout`], and recomputes
+//!   them when the window is resized or when players join or leave;
+//! - spawns a full-size UI root for every player, targeting their camera, which is despawned with
+//!   it (see [`SplitScreenUiRoots`]);
+//! - adds the render layer of the player to their camera, for the entities only they should see
+//!   (see [`SplitScreenPlayer::render_layer`]);
+//! - assigns a free [`Gamepad`] to each player waiting for one, and sends a
+//!   [`SplitScreenJoinRequest`] when a gamepad without a player presses the join button.
+//!
+//! ```
+//! # use bevy_camera::Camera3d;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ui::split_screen::{PlayerInput, SplitScreenJoinRequest, SplitScreenPlayer};
+//! fn join(
+//!     mut commands: Commands,
+//!     mut requests: MessageReader<SplitScreenJoinRequest>,
+//!     players: Query<&SplitScreenPlayer>,
+//! ) {
+//!     for request in requests.read() {
+//!         commands.spawn((
+//!             Camera3d::default(),
+//!             SplitScreenPlayer {
+//!                 index: players.iter().count(),
+//!                 input: PlayerInput::Gamepad(request.gamepad),
+//!             },
+//!         ));
+//!     }
+//! }
+//! ```
+
+use crate::{Node, UiTargetCamera, Val};
+use alloc::vec::Vec;
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_camera::{
+    visibility::{Layer, RenderLayers},
+    Camera, CameraUpdateSystems, Viewport,
+};
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    gamepad::{Gamepad, GamepadButton},
+    InputSystems,
+};
+use bevy_math::{URect, UVec2};
+use bevy_platform::collections::HashSet;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_window::{PrimaryWindow, Window};
+
+/// The render layer of the first player, see [`SplitScreenPlayer::render_layer`].
+///
+/// The layers below it are left to the game, so the players can share the layers of the world.
+pub const SPLIT_SCREEN_FIRST_PLAYER_LAYER: Layer = 16;
+
+/// A plugin dividing the primary window between the cameras of the [`SplitScreenPlayer`]s.
+///
+/// See the [module docs](self) for what it manages.
+pub struct SplitScreenPlugin;
+
+impl Plugin for SplitScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SplitScreen>()
+            .add_message::<SplitScreenJoinRequest>()
+            .add_observer(on_add_split_screen_player)
+            .add_systems(PreUpdate, assign_player_gamepads.after(InputSystems))
+            .add_systems(
+                PostUpdate,
+                update_split_screen_viewports.before(CameraUpdateSystems),
+            );
+    }
+}
+
+/// The configuration of the split-screen.
+#[derive(Resource, Reflect, Clone, Debug, PartialEq)]
+#[reflect(Resource, Default, Clone, Debug, PartialEq)]
+pub struct SplitScreen {
+    /// How the window is divided between the players.
+    pub layout: SplitScreenLayout,
+    /// The space between the views of the players, in physical pixels.
+    ///
+    /// Defaults to `0`.
+    pub gap: u32,
+    /// The button a gamepad without a player presses to send a [`SplitScreenJoinRequest`], or
+    /// `None` to not send any.
+    ///
+    /// Defaults to [`GamepadButton::Start`].
+    pub join_button: Option<GamepadButton>,
+}
+
+impl Default for SplitScreen {
+    fn default() -> Self {
+        Self {
+            layout: SplitScreenLayout::default(),
+            gap: 0,
+            join_button: Some(GamepadButton::Start),
+        }
+    }
+}
+
+/// How the window is divided between the players of a [`SplitScreen`], in the order of their
+/// [`SplitScreenPlayer::index`].
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Default, Clone, Debug, PartialEq)]
+pub enum SplitScreenLayout {
+    /// The players are placed in a grid as square as possible, from left to right then top to
+    /// bottom, with the players of the last row sharing its whole width.
+    ///
+    /// The grid has more columns than rows when the window is wider than it is tall, so two
+    /// players are side by side in a landscape window and on top of each other in a portrait one.
+    #[default]
+    Grid,
+    /// The players are side by side, from left to right.
+    Horizontal,
+    /// The players are on top of each other, from top to bottom.
+    Vertical,
+}
+
+impl SplitScreenLayout {
+    /// Computes the views of `count` players in a window of `size` physical pixels, with `gap`
+    /// pixels between them.
+    pub fn views(self, count: usize, size: UVec2, gap: u32) -> Vec<URect> {
+        if count == 0 || size.x == 0 || size.y == 0 {
+            return Vec::new();
+        }
+        let count = count as u32;
+        let (columns, rows) = match self {
+            SplitScreenLayout::Grid => {
+                let long = (count as f32).sqrt().ceil() as u32;
+                let short = count.div_ceil(long);
+                if size.x >= size.y {
+                    (long, short)
+                } else {
+                    (short, long)
+                }
+            }
+            SplitScreenLayout::Horizontal => (count, 1),
+            SplitScreenLayout::Vertical => (1, count),
+        };
+
+        let mut views = Vec::with_capacity(count as usize);
+        for row in 0..rows {
+            let in_row = columns.min(count - row * columns);
+            let (top, bottom) = span(row, rows, size.y, gap);
+            for column in 0..in_row {
+                let (left, right) = span(column, in_row, size.x, gap);
+                views.push(URect::new(left, top, right, bottom));
+            }
+        }
+        views
+    }
+}
+
+/// The start and end of the cell `index` of `count` cells dividing `length` pixels, with `gap`
+/// pixels between them.
+fn span(index: u32, count: u32, length: u32, gap: u32) -> (u32, u32) {
+    // The cells are placed from their boundaries, so the rounding never leaves pixels at the end.
+    let total = length + gap;
+    let start = index * total / count;
+    let end = ((index + 1) * total / count).saturating_sub(gap);
+    // A viewport can't be empty.
+    (start.min(length - 1), end.clamp(start + 1, length))
+}
+
+/// A local player of a [`SplitScreen`], on the camera they see the game through.
+///
+/// The camera renders the entities on its [`RenderLayers`]. If it has none when the player is
+/// added, it renders those of layer `0` and of the [render layer](Self::render_layer) of the
+/// player.
+///
+/// The [`Camera::order`] is left as is, so the cameras of the players should be given different
+/// orders.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Component, Default, Clone, Debug, PartialEq)]
+#[require(Camera)]
+pub struct SplitScreenPlayer {
+    /// The position of the player in the [`SplitScreenLayout`]. The indices don't need to be
+    /// contiguous, so the players after a player leaving keep their index.
+    pub index: usize,
+    /// The input device of the player.
+    pub input: PlayerInput,
+}
+
+impl SplitScreenPlayer {
+    /// Creates the player at `index`, who is assigned the next free gamepad.
+    pub fn new(index: usize) -> Self {
+        Self {
+            index,
+            input: PlayerInput::Unassigned,
+        }
+    }
+
+    /// The render layer of the entities only this player sees, such as their crosshair or the
+    /// interior of their vehicle.
+    pub fn render_layer(&self) -> Layer {
+        SPLIT_SCREEN_FIRST_PLAYER_LAYER + self.index
+    }
+}
+
+/// The input device of a [`SplitScreenPlayer`].
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[reflect(Default, Clone, Debug, PartialEq)]
+pub enum PlayerInput {
+    /// The player is waiting for a gamepad, and is assigned the next connected gamepad no other
+    /// player uses.
+    #[default]
+    Unassigned,
+    /// The player uses the keyboard and the mouse.
+    KeyboardMouse,
+    /// The player uses this [`Gamepad`] entity. The player is unassigned when it disconnects.
+    Gamepad(Entity),
+}
+
+impl PlayerInput {
+    /// Returns the [`Gamepad`] entity of the player, if they use one.
+    pub fn gamepad(&self) -> Option<Entity> {
+        match self {
+            PlayerInput::Gamepad(gamepad) => Some(*gamepad),
+            _ => None,
+        }
+    }
+}
+
+/// Sent when a [`Gamepad`] without a [`SplitScreenPlayer`] presses the
+/// [`SplitScreen::join_button`] and no player is waiting for a gamepad.
+///
+/// The game decides whether to spawn a new player using this gamepad.
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitScreenJoinRequest {
+    /// The gamepad asking to join.
+    pub gamepad: Entity,
+}
+
+/// The UI root of the [`SplitScreenPlayer`] it is spawned for, which covers the view of the player.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+#[relationship(relationship_target = SplitScreenUiRoots)]
+pub struct SplitScreenUiRootOf(pub Entity);
+
+/// The UI roots of a [`SplitScreenPlayer`], despawned with it.
+///
+/// One is spawned when the player is added; the UI of the player should be spawned as its
+/// children.
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component, Debug, Default)]
+#[relationship_target(relationship = SplitScreenUiRootOf, linked_spawn)]
+pub struct SplitScreenUiRoots(Vec<Entity>);
+
+/// Spawns the UI root of a new [`SplitScreenPlayer`], and adds their render layer to their camera.
+fn on_add_split_screen_player(
+    add: On<Add, SplitScreenPlayer>,
+    mut commands: Commands,
+    players: Query<(&SplitScreenPlayer, Has<RenderLayers>)>,
+) {
+    let Ok((player, has_render_layers)) = players.get(add.entity) else {
+        return;
+    };
+    if !has_render_layers {
+        commands
+            .entity(add.entity)
+            .insert(RenderLayers::layer(0).with(player.render_layer()));
+    }
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..Default::default()
+        },
+        UiTargetCamera(add.entity),
+        SplitScreenUiRootOf(add.entity),
+    ));
+}
+
+/// Unassigns the disconnected gamepads, and assigns the free ones to the players waiting for one
+/// or sends a [`SplitScreenJoinRequest`] when they press the [`SplitScreen::join_button`].
+pub fn assign_player_gamepads(
+    split_screen: Res<SplitScreen>,
+    mut players: Query<&mut SplitScreenPlayer>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut join_requests: MessageWriter<SplitScreenJoinRequest>,
+) {
+    let mut assigned = HashSet::<Entity>::default();
+    for mut player in &mut players {
+        if let Some(gamepad) = player.input.gamepad() {
+            if gamepads.contains(gamepad) {
+                assigned.insert(gamepad);
+            } else {
+                player.input = PlayerInput::Unassigned;
+            }
+        }
+    }
+
+    let mut waiting: Vec<_> = players
+        .iter_mut()
+        .filter(|player| player.input == PlayerInput::Unassigned)
+        .collect();
+    waiting.sort_by_key(|player| player.index);
+    let mut waiting = waiting.into_iter();
+
+    let mut free: Vec<_> = gamepads
+        .iter()
+        .filter(|(entity, _)| !assigned.contains(entity))
+        .collect();
+    free.sort_by_key(|(entity, _)| *entity);
+    for (entity, gamepad) in free {
+        if let Some(mut player) = waiting.next() {
+            player.input = PlayerInput::Gamepad(entity);
+        } else if let Some(button) = split_screen.join_button
+            && gamepad.just_pressed(button)
+        {
+            join_requests.write(SplitScreenJoinRequest { gamepad: entity });
+        }
+    }
+}
+
+/// Sets the [`Viewport`] of the cameras of the [`SplitScreenPlayer`]s from the size of the
+/// primary window.
+pub fn update_split_screen_viewports(
+    split_screen: Res<SplitScreen>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut players: Query<(&SplitScreenPlayer, &mut Camera)>,
+) {
+    let Ok(window) = window.single() else {
+        return;
+    };
+    let size = window.physical_size();
+    let mut players: Vec<_> = players.iter_mut().collect();
+    players.sort_by_key(|(player, _)| player.index);
+    let views = split_screen
+        .layout
+        .views(players.len(), size, split_screen.gap);
+    for ((_, mut camera), view) in players.into_iter().zip(views) {
+        let up_to_date = camera.viewport.as_ref().is_some_and(|viewport| {
+            viewport.physical_position == view.min && viewport.physical_size == view.size()
+        });
+        if !up_to_date {
+            let depth = camera
+                .viewport
+                .as_ref()
+                .map(|viewport| viewport.depth.clone())
+                .unwrap_or(0.0..1.0);
+            camera.viewport = Some(Viewport {
+                physical_position: view.min,
+                physical_size: view.size(),
+                depth,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_layout() {
+        let size = UVec2::new(1920, 1080);
+        assert_eq!(
+            SplitScreenLayout::Grid.views(2, size, 0),
+            [URect::new(0, 0, 960, 1080), URect::new(960, 0, 1920, 1080)]
+        );
+        assert_eq!(
+            SplitScreenLayout::Grid.views(3, size, 0),
+            [
+                URect::new(0, 0, 960, 540),
+                URect::new(960, 0, 1920, 540),
+                URect::new(0, 540, 1920, 1080),
+            ]
+        );
+        assert_eq!(
+            SplitScreenLayout::Grid.views(2, UVec2::new(1080, 1920), 10),
+            [URect::new(0, 0, 1080, 955), URect::new(0, 965, 1080, 1920)]
+        );
+        assert_eq!(
+            SplitScreenLayout::Vertical.views(3, UVec2::new(100, 100), 0),
+            [
+                URect::new(0, 0, 100, 33),
+                URect::new(0, 33, 100, 66),
+                URect::new(0, 66, 100, 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn players_join_and_leave() {
+        let mut app = App::new();
+        app.add_plugins(SplitScreenPlugin);
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        let size = Window::default().physical_size();
+
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        let first = app.world_mut().spawn(SplitScreenPlayer::new(0)).id();
+        let second = app
+            .world_mut()
+            .spawn((
+                SplitScreenPlayer {
+                    index: 1,
+                    input: PlayerInput::KeyboardMouse,
+                },
+                Camera {
+                    order: 1,
+                    ..Default::default()
+                },
+            ))
+            .id();
+        app.update();
+
+        let viewport = |app: &App, entity| {
+            let viewport = app
+                .world()
+                .get::<Camera>(entity)
+                .unwrap()
+                .viewport
+                .clone()
+                .unwrap();
+            (viewport.physical_position, viewport.physical_size)
+        };
+        assert_eq!(
+            viewport(&app, first),
+            (UVec2::ZERO, UVec2::new(size.x / 2, size.y))
+        );
+        assert_eq!(
+            viewport(&app, second),
+            (UVec2::new(size.x / 2, 0), UVec2::new(size.x / 2, size.y))
+        );
+        assert_eq!(
+            app.world().get::<SplitScreenPlayer>(first).unwrap().input,
+            PlayerInput::Gamepad(gamepad)
+        );
+        assert_eq!(
+            app.world().get::<RenderLayers>(second),
+            Some(&RenderLayers::from_layers(&[
+                0,
+                SPLIT_SCREEN_FIRST_PLAYER_LAYER + 1
+            ]))
+        );
+        let ui_root = app.world().get::<SplitScreenUiRoots>(second).unwrap().0[0];
+        assert_eq!(
+            app.world().get::<UiTargetCamera>(ui_root),
+            Some(&UiTargetCamera(second))
+        );
+
+        // A second gamepad without a player asks to join.
+        let mut other_gamepad = Gamepad::default();
+        other_gamepad.digital_mut().press(GamepadButton::Start);
+        let other_gamepad = app.world_mut().spawn(other_gamepad).id();
+        app.update();
+        let requests = app.world().resource::<Messages<SplitScreenJoinRequest>>();
+        assert_eq!(
+            requests.iter_current_update_messages().collect::<Vec<_>>(),
+            [&SplitScreenJoinRequest {
+                gamepad: other_gamepad
+            }]
+        );
+
+        app.world_mut().despawn(second);
+        app.update();
+        assert_eq!(viewport(&app, first), (UVec2::ZERO, size));
+        assert!(app.world().get_entity(ui_root).is_err());
+    }
+}
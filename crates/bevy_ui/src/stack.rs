@@ -1,11 +1,15 @@
 //! This module contains the systems that update the stored UI nodes stack
 
 use crate::{
+    clip_check_recursive,
     experimental::{UiChildren, UiRootNodes},
-    GlobalZIndex, ZIndex,
+    ComputedNode, ComputedUiTargetCamera, GlobalZIndex, Node, OverrideClip, UiGlobalTransform,
+    ZIndex,
 };
+use bevy_camera::visibility::InheritedVisibility;
 use bevy_derive::{Deref, DerefMut};
-use bevy_ecs::{entity::EntityHashSet, prelude::*};
+use bevy_ecs::{entity::EntityHashSet, prelude::*, system::SystemParam};
+use bevy_math::Vec2;
 use bevy_reflect::std_traits::ReflectDefault;
 use bevy_reflect::Reflect;
 use core::ops::Range;
@@ -22,6 +26,17 @@ pub struct ComputedStackIndex(pub u32);
 ///
 /// The first entry is the furthest node from the camera and is the first one to get rendered
 /// while the last entry is the first node to receive interactions.
+///
+/// The paint order is deterministic:
+/// - The UI roots and the nodes with a [`GlobalZIndex`] each start a stacking context, which holds
+///   the node and its descendants, except those starting their own stacking context.
+/// - The stacking contexts are ordered by their [`GlobalZIndex`], `0` for roots without one, then
+///   by the [`ZIndex`] of their node. On ties, the UI roots are drawn below the nodes with a
+///   [`GlobalZIndex`] nested in the hierarchy, and the nodes are then ordered by their [`Entity`].
+/// - In a stacking context, each node is drawn before its children, which are ordered by their
+///   [`ZIndex`], then by their order among the children of their parent.
+///
+/// Use [`UiNodesAtPoint`] to find the nodes under a point in this order.
 #[derive(Debug, Resource, Default, Reflect)]
 #[reflect(Resource, Default)]
 pub struct UiStack {
@@ -53,7 +68,7 @@ impl ChildBufferCache {
 /// filtering branches by `Without<GlobalZIndex>`so that we don't revisit nodes.
 pub fn ui_stack_system(
     mut cache: Local<ChildBufferCache>,
-    mut root_nodes: Local<Vec<(Entity, (i32, i32))>>,
+    mut root_nodes: Local<Vec<(Entity, (i32, i32, bool))>>,
     mut visited_root_nodes: Local<EntityHashSet>,
     mut ui_stack: ResMut<UiStack>,
     ui_root_nodes: UiRootNodes,
@@ -76,6 +91,7 @@ pub fn ui_stack_system(
             (
                 maybe_global_zindex.map(|zindex| zindex.0).unwrap_or(0),
                 maybe_zindex.map(|zindex| zindex.0).unwrap_or(0),
+                false,
             ),
         ));
         visited_root_nodes.insert(id);
@@ -91,11 +107,14 @@ pub fn ui_stack_system(
             (
                 global_zindex.0,
                 maybe_zindex.map(|zindex| zindex.0).unwrap_or(0),
+                true,
             ),
         ));
     }
 
-    root_nodes.sort_by_key(|(_, z)| *z);
+    // Break the remaining ties by entity, so that the order doesn't depend on the order of the
+    // queries.
+    root_nodes.sort_by_key(|(entity, z)| (*z, *entity));
 
     for (root_entity, _) in root_nodes.drain(..) {
         let start = ui_stack.uinodes.len();
@@ -144,16 +163,98 @@ fn update_uistack_recursive(
     cache.push(child_buffer);
 }
 
+/// A [`SystemParam`] to find the UI nodes under a point, for example to know whether the cursor
+/// is over the UI, or which node a dragged item is dropped on.
+///
+/// The nodes are searched in the order of the [`UiStack`], from the topmost node to the bottom
+/// one, which is the order they receive interactions in. Hidden nodes and the parts of the nodes
+/// clipped by their ancestors are skipped, but `Pickable` is ignored.
+///
+/// The results are as of the last time the [`UiStack`] and the layout were updated, in
+/// [`UiSystems::Stack`](crate::UiSystems::Stack) and
+/// [`UiSystems::Layout`](crate::UiSystems::Layout).
+#[derive(SystemParam)]
+pub struct UiNodesAtPoint<'w, 's> {
+    ui_stack: Res<'w, UiStack>,
+    node_query: Query<
+        'w,
+        's,
+        (
+            &'static ComputedNode,
+            &'static UiGlobalTransform,
+            &'static ComputedUiTargetCamera,
+            Option<&'static InheritedVisibility>,
+        ),
+    >,
+    clipping_query: Query<
+        'w,
+        's,
+        (
+            &'static ComputedNode,
+            &'static UiGlobalTransform,
+            &'static Node,
+        ),
+    >,
+    child_of_query: Query<'w, 's, &'static ChildOf, Without<OverrideClip>>,
+}
+
+impl<'w, 's> UiNodesAtPoint<'w, 's> {
+    /// Returns the nodes rendered by `camera` under `point`, from the topmost node to the bottom
+    /// one.
+    ///
+    /// `point` is in physical pixels, relative to the top left corner of the viewport of the
+    /// camera.
+    pub fn iter(&self, camera: Entity, point: Vec2) -> impl Iterator<Item = Entity> + '_ {
+        self.ui_stack
+            .uinodes
+            .iter()
+            .rev()
+            .copied()
+            .filter(move |&entity| {
+                let Ok((node, transform, target_camera, inherited_visibility)) =
+                    self.node_query.get(entity)
+                else {
+                    return false;
+                };
+                target_camera.get() == Some(camera)
+                    && inherited_visibility.is_some_and(|visibility| visibility.get())
+                    && node.size() != Vec2::ZERO
+                    && node.contains_point(*transform, point)
+                    && clip_check_recursive(
+                        point,
+                        entity,
+                        &self.clipping_query,
+                        &self.child_of_query,
+                    )
+            })
+    }
+
+    /// Returns the topmost node rendered by `camera` under `point`.
+    ///
+    /// `point` is in physical pixels, relative to the top left corner of the viewport of the
+    /// camera.
+    pub fn topmost(&self, camera: Entity, point: Vec2) -> Option<Entity> {
+        self.iter(camera, point).next()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use bevy_camera::visibility::InheritedVisibility;
     use bevy_ecs::{
         component::Component,
+        entity::Entity,
+        hierarchy::ChildOf,
         schedule::Schedule,
-        system::Commands,
+        system::{Commands, RunSystemOnce},
         world::{CommandQueue, World},
     };
+    use bevy_math::Vec2;
 
-    use crate::{GlobalZIndex, Node, UiStack, ZIndex};
+    use crate::{
+        ComputedNode, ComputedUiTargetCamera, GlobalZIndex, Node, UiGlobalTransform,
+        UiNodesAtPoint, UiStack, ZIndex,
+    };
 
     use super::ui_stack_system;
 
@@ -352,4 +453,95 @@ mod tests {
             assert_eq!(*part, i..i + 1);
         }
     }
+
+    #[test]
+    fn roots_with_equal_zindex_are_ordered_by_entity() {
+        let mut world = World::default();
+        world.init_resource::<UiStack>();
+
+        let parent = world.spawn(Node::default()).id();
+        let nested = world
+            .spawn((Node::default(), GlobalZIndex(0), ChildOf(parent)))
+            .id();
+        let mut roots = (0..4)
+            .map(|_| world.spawn(Node::default()).id())
+            .collect::<Vec<_>>();
+        roots.push(parent);
+        roots.sort();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_stack_system);
+        schedule.run(&mut world);
+
+        // The parent is followed by its children, unless they start their own stacking context.
+        let mut expected_result = roots;
+        expected_result.push(nested);
+        assert_eq!(world.resource::<UiStack>().uinodes, expected_result);
+    }
+
+    #[test]
+    fn nodes_at_point_are_ordered_top_to_bottom() {
+        let mut world = World::default();
+        world.init_resource::<UiStack>();
+        let camera = world.spawn_empty().id();
+        let other_camera = world.spawn_empty().id();
+
+        let ui_node = |center: Vec2, size: Vec2| {
+            (
+                Node::default(),
+                ComputedNode {
+                    size,
+                    ..ComputedNode::DEFAULT
+                },
+                UiGlobalTransform::from_translation(center),
+                ComputedUiTargetCamera {
+                    camera: Some(camera),
+                },
+                InheritedVisibility::VISIBLE,
+            )
+        };
+        let back = world
+            .spawn(ui_node(Vec2::splat(50.), Vec2::splat(100.)))
+            .id();
+        let tooltip = world
+            .spawn((
+                ui_node(Vec2::splat(50.), Vec2::splat(20.)),
+                GlobalZIndex(1),
+                ChildOf(back),
+            ))
+            .id();
+        let front = world
+            .spawn((ui_node(Vec2::splat(90.), Vec2::splat(40.)), ZIndex(1)))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ui_stack_system);
+        schedule.run(&mut world);
+
+        let nodes_at = |world: &mut World, camera: Entity, point: Vec2| {
+            world
+                .run_system_once(move |nodes_at_point: UiNodesAtPoint| {
+                    nodes_at_point.iter(camera, point).collect::<Vec<_>>()
+                })
+                .unwrap()
+        };
+        assert_eq!(
+            nodes_at(&mut world, camera, Vec2::splat(50.)),
+            vec![tooltip, back]
+        );
+        assert_eq!(
+            nodes_at(&mut world, camera, Vec2::splat(85.)),
+            vec![front, back]
+        );
+        assert_eq!(nodes_at(&mut world, camera, Vec2::splat(105.)), vec![front]);
+        assert!(nodes_at(&mut world, camera, Vec2::splat(150.)).is_empty());
+        assert!(nodes_at(&mut world, other_camera, Vec2::splat(50.)).is_empty());
+
+        let topmost = world
+            .run_system_once(move |nodes_at_point: UiNodesAtPoint| {
+                nodes_at_point.topmost(camera, Vec2::splat(50.))
+            })
+            .unwrap();
+        assert_eq!(topmost, Some(tooltip));
+    }
 }
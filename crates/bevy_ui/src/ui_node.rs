@@ -1813,8 +1813,26 @@ impl From<usize> for GridTrackRepetition {
 /// to create a `RepeatedGridTrack`. i.e. `GridTrack::px(10.0)` is equivalent to `RepeatedGridTrack::px(1, 10.0)`.
 ///
 /// You may only use one auto-repetition per track list. And if your track list contains an auto repetition
-/// then all tracks (in and outside of the repetition) must be fixed size (px or percent). Integer repetitions are just shorthand for writing out
+/// then all tracks (in and outside of the repetition) must have a fixed size (px or percent) as their minimum or maximum
+/// sizing function, like `minmax(100px, 1fr)`. Integer repetitions are just shorthand for writing out
 /// N tracks longhand and are not subject to the same limitations.
+///
+/// ```
+/// # use bevy_ui::{
+/// #     Display, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction, Node,
+/// #     RepeatedGridTrack,
+/// # };
+/// // As many columns of at least 100 logical pixels as fit in the node, sharing its whole width.
+/// let inventory = Node {
+///     display: Display::Grid,
+///     grid_template_columns: RepeatedGridTrack::minmax(
+///         GridTrackRepetition::AutoFill,
+///         MinTrackSizingFunction::Px(100.),
+///         MaxTrackSizingFunction::Fraction(1.),
+///     ),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Clone, PartialEq, Debug, Reflect)]
 #[reflect(Default, PartialEq, Clone)]
 #[cfg_attr(
@@ -2440,6 +2458,13 @@ pub struct ZIndex(pub i32);
 /// Nodes with a `GlobalZIndex` of less than 0 will be drawn below nodes without a `GlobalZIndex` or nodes with a greater `GlobalZIndex`.
 ///
 /// If two Nodes have the same `GlobalZIndex`, the node with the greater [`ZIndex`] will be drawn on top.
+///
+/// A node with a `GlobalZIndex` starts its own stacking context: it is drawn with its descendants
+/// as if it was a UI root, so that tooltips, dropdowns and modals spawned deep in the hierarchy
+/// can be drawn above the rest of the UI, and receive interactions before it. The node is still
+/// clipped by the overflow of its ancestors; add [`OverrideClip`] to escape it too.
+///
+/// See [`UiStack`](crate::UiStack) for the full paint order.
 #[derive(Component, Copy, Clone, Debug, Default, PartialEq, Eq, Reflect)]
 #[reflect(Component, Default, Debug, PartialEq, Clone)]
 pub struct GlobalZIndex(pub i32);
@@ -2953,21 +2978,55 @@ impl Default for ShadowStyle {
     derive(serde::Serialize, serde::Deserialize),
     reflect(Serialize, Deserialize)
 )]
-/// This component can be added to any UI node to modify its layout behavior.
+/// This component can be added to any UI node to modify its layout behavior, and the behavior of
+/// its descendants without a `LayoutConfig` of their own.
+///
+/// The nodes without a `LayoutConfig` on themselves or their ancestors use the
+/// [`DefaultLayoutConfig`].
 pub struct LayoutConfig {
     /// If set to true the coordinates for this node and its descendents will be rounded to the nearest physical pixel.
     /// This can help prevent visual artifacts like blurry images or semi-transparent edges that can occur with sub-pixel positioning.
     ///
     /// Defaults to true.
     pub use_rounding: bool,
+    /// If set to true, this node and its descendants are moved to the nearest physical pixel after
+    /// applying their [`UiTransform`](crate::UiTransform)s, when they aren't rotated or scaled.
+    ///
+    /// Rounding only applies to the layout, so fractional [`UiTransform`](crate::UiTransform)
+    /// translations, or scale factors like 1.25 applied to them, still place the edges of the nodes
+    /// between physical pixels, blurring thin borders and text. Snapping fixes this, at the cost
+    /// of nodes animated by their transform moving a whole pixel at a time.
+    ///
+    /// Defaults to false.
+    pub snap_to_pixels: bool,
 }
 
 impl Default for LayoutConfig {
     fn default() -> Self {
-        Self { use_rounding: true }
+        Self {
+            use_rounding: true,
+            snap_to_pixels: false,
+        }
     }
 }
 
+/// The [`LayoutConfig`] of the UI nodes without a `LayoutConfig` on themselves or their
+/// ancestors.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ui::{DefaultLayoutConfig, LayoutConfig};
+/// fn snap_ui_to_pixels(mut default_layout_config: ResMut<DefaultLayoutConfig>) {
+///     default_layout_config.0 = LayoutConfig {
+///         use_rounding: true,
+///         snap_to_pixels: true,
+///     };
+/// }
+/// ```
+#[derive(Resource, Copy, Clone, Debug, Default, PartialEq, Deref, DerefMut, Reflect)]
+#[reflect(Resource, Debug, PartialEq, Default, Clone)]
+pub struct DefaultLayoutConfig(pub LayoutConfig);
+
 /// Indicates that this root [`Node`] entity should be rendered to a specific camera.
 ///
 /// UI then will be laid out respecting the camera's viewport and scale factor, and
@@ -3025,6 +3084,86 @@ impl UiTargetCamera {
 #[reflect(Component, Default)]
 pub struct IsDefaultUiCamera;
 
+/// How the UI rendered by this camera is scaled to fit its viewport.
+///
+/// Without this component, the UI follows the scale factor of the render target, like
+/// [`UiScaleMode::ConstantPixelSize`]. The scale computed from the mode is multiplied by
+/// [`UiScale`](crate::UiScale), which can still be used for user preferences.
+///
+/// ```
+/// # use bevy_ui::prelude::*;
+/// # use bevy_ecs::prelude::Commands;
+/// # use bevy_camera::Camera2d;
+/// fn spawn_camera(mut commands: Commands) {
+///     commands.spawn((
+///         Camera2d,
+///         // Lay out the UI as if the viewport were 1920x1080, scaling it to fit.
+///         UiScaleMode::FitDesignResolution {
+///             width: 1920.,
+///             height: 1080.,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub enum UiScaleMode {
+    /// [`Val::Px`] values are the same number of logical pixels whatever the size of the
+    /// viewport.
+    #[default]
+    ConstantPixelSize,
+    /// The UI is scaled so that the height of the viewport is always `reference_height` logical
+    /// pixels.
+    ScaleWithHeight { reference_height: f32 },
+    /// The UI is scaled so that the shortest side of the viewport is always `reference_size`
+    /// logical pixels, which keeps layouts readable on screens in portrait orientation.
+    ScaleWithShortestSide { reference_size: f32 },
+    /// The UI is laid out in a `width` by `height` area of logical pixels, scaled to fit the
+    /// viewport and centered in it.
+    ///
+    /// When the aspect ratio of the viewport differs from the design resolution, the UI is
+    /// letterboxed: it does not cover the bars left on the sides of the viewport.
+    FitDesignResolution { width: f32, height: f32 },
+}
+
+impl UiScaleMode {
+    /// Returns the render target information for the UI rendered by a camera with a viewport of
+    /// `physical_size` pixels, on a render target with the given scale factor.
+    pub fn target_info(
+        &self,
+        physical_size: UVec2,
+        target_scale_factor: f32,
+        ui_scale: f32,
+    ) -> ComputedUiRenderTargetInfo {
+        let size = physical_size.as_vec2();
+        let (scale_factor, layout_size) = match *self {
+            UiScaleMode::ConstantPixelSize => (target_scale_factor, size),
+            UiScaleMode::ScaleWithHeight { reference_height } => (size.y / reference_height, size),
+            UiScaleMode::ScaleWithShortestSide { reference_size } => {
+                (size.min_element() / reference_size, size)
+            }
+            UiScaleMode::FitDesignResolution { width, height } => {
+                let fit = (size.x / width).min(size.y / height);
+                (fit, (Vec2::new(width, height) * fit).round().min(size))
+            }
+        };
+        // Degenerate viewports and reference sizes fall back to the render target's scale factor.
+        if !scale_factor.is_finite() || scale_factor <= 0. {
+            return UiScaleMode::ConstantPixelSize.target_info(
+                physical_size,
+                target_scale_factor,
+                ui_scale,
+            );
+        }
+        let layout_size = layout_size.as_uvec2();
+        ComputedUiRenderTargetInfo {
+            scale_factor: scale_factor * ui_scale,
+            physical_size: layout_size,
+            offset: ((physical_size - layout_size) / 2).as_vec2(),
+        }
+    }
+}
+
 #[derive(SystemParam)]
 pub struct DefaultUiCamera<'w, 's> {
     cameras: Query<'w, 's, (Entity, &'static Camera, &'static RenderTarget)>,
@@ -3076,8 +3215,11 @@ impl ComputedUiTargetCamera {
 pub struct ComputedUiRenderTargetInfo {
     /// The scale factor of the target camera's render target.
     pub(crate) scale_factor: f32,
-    /// The size of the target camera's viewport in physical pixels.
+    /// The size of the area of the target camera's viewport the UI is laid out in, in physical
+    /// pixels.
     pub(crate) physical_size: UVec2,
+    /// The offset of that area from the top left corner of the viewport, in physical pixels.
+    pub(crate) offset: Vec2,
 }
 
 impl Default for ComputedUiRenderTargetInfo {
@@ -3085,6 +3227,7 @@ impl Default for ComputedUiRenderTargetInfo {
         Self {
             scale_factor: 1.,
             physical_size: UVec2::ZERO,
+            offset: Vec2::ZERO,
         }
     }
 }
@@ -3094,15 +3237,24 @@ impl ComputedUiRenderTargetInfo {
         self.scale_factor
     }
 
-    /// Returns the size of the target camera's viewport in physical pixels.
+    /// Returns the size of the area the UI is laid out in, in physical pixels.
+    ///
+    /// This is the size of the target camera's viewport, unless the UI is letterboxed by
+    /// [`UiScaleMode::FitDesignResolution`].
     pub const fn physical_size(&self) -> UVec2 {
         self.physical_size
     }
 
-    /// Returns the size of the target camera's viewport in logical pixels.
+    /// Returns the size of the area the UI is laid out in, in logical pixels.
     pub fn logical_size(&self) -> Vec2 {
         self.physical_size.as_vec2() / self.scale_factor
     }
+
+    /// Returns the offset of the area the UI is laid out in from the top left corner of the
+    /// target camera's viewport, in physical pixels.
+    pub const fn offset(&self) -> Vec2 {
+        self.offset
+    }
 }
 
 /// A UI entity with a `FixedNode` component is treated as a root node, even when it has a UI node parent.
@@ -4,7 +4,7 @@ use crate::{
     experimental::{UiChildren, UiRootNodes},
     ui_transform::UiGlobalTransform,
     CalculatedClip, ComputedUiRenderTargetInfo, ComputedUiTargetCamera, DefaultUiCamera, Display,
-    Node, OverrideClip, UiScale, UiTargetCamera,
+    Node, OverrideClip, UiScale, UiScaleMode, UiTargetCamera,
 };
 
 use super::ComputedNode;
@@ -116,7 +116,7 @@ pub fn propagate_ui_target_cameras(
     mut commands: Commands,
     default_ui_camera: DefaultUiCamera,
     ui_scale: Res<UiScale>,
-    camera_query: Query<&Camera>,
+    camera_query: Query<(&Camera, Option<&UiScaleMode>)>,
     target_camera_query: Query<&UiTargetCamera>,
     ui_root_nodes: UiRootNodes,
     ui_children: UiChildren,
@@ -150,22 +150,20 @@ pub fn propagate_ui_target_cameras(
             .entity(root_entity)
             .try_insert(Propagate(ComputedUiTargetCamera { camera }));
 
-        let (scale_factor, physical_size) = camera
+        let target_info = camera
             .and_then(|camera| camera_query.get(camera).ok())
-            .map(|camera| {
-                (
-                    camera.target_scaling_factor().unwrap_or(1.) * ui_scale.0,
+            .map(|(camera, scale_mode)| {
+                scale_mode.copied().unwrap_or_default().target_info(
                     camera.physical_viewport_size().unwrap_or(UVec2::ZERO),
+                    camera.target_scaling_factor().unwrap_or(1.),
+                    ui_scale.0,
                 )
             })
-            .unwrap_or((1., UVec2::ZERO));
+            .unwrap_or_default();
 
         commands
             .entity(root_entity)
-            .try_insert(Propagate(ComputedUiRenderTargetInfo {
-                scale_factor,
-                physical_size,
-            }));
+            .try_insert(Propagate(target_info));
     }
 }
 
@@ -177,6 +175,7 @@ mod tests {
     use crate::IsDefaultUiCamera;
     use crate::Node;
     use crate::UiScale;
+    use crate::UiScaleMode;
     use crate::UiTargetCamera;
     use bevy_app::App;
     use bevy_app::HierarchyPropagatePlugin;
@@ -187,7 +186,7 @@ mod tests {
     use bevy_camera::ComputedCameraValues;
     use bevy_camera::RenderTargetInfo;
     use bevy_ecs::hierarchy::ChildOf;
-    use bevy_math::UVec2;
+    use bevy_math::{UVec2, Vec2};
     use bevy_utils::default;
 
     fn setup_test_app() -> App {
@@ -257,6 +256,7 @@ mod tests {
             ComputedUiRenderTargetInfo {
                 physical_size,
                 scale_factor,
+                ..Default::default()
             }
         );
     }
@@ -331,6 +331,7 @@ mod tests {
                 ComputedUiRenderTargetInfo {
                     physical_size,
                     scale_factor,
+                    ..Default::default()
                 }
             );
         }
@@ -667,4 +668,60 @@ mod tests {
             2.
         );
     }
+
+    #[test]
+    fn update_context_with_scale_mode() {
+        let mut app = setup_test_app();
+        let world = app.world_mut();
+
+        let physical_size = UVec2::new(1000, 400);
+
+        let camera = world
+            .spawn((
+                Camera2d,
+                Camera {
+                    computed: ComputedCameraValues {
+                        target_info: Some(RenderTargetInfo {
+                            physical_size,
+                            scale_factor: 1.,
+                        }),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                UiScaleMode::ScaleWithHeight {
+                    reference_height: 200.,
+                },
+            ))
+            .id();
+
+        let uinode = world.spawn(Node::default()).id();
+
+        app.update();
+        let world = app.world_mut();
+
+        assert_eq!(
+            *world.get::<ComputedUiRenderTargetInfo>(uinode).unwrap(),
+            ComputedUiRenderTargetInfo {
+                physical_size,
+                scale_factor: 2.,
+                ..Default::default()
+            }
+        );
+
+        // A 4:3 design resolution is letterboxed in the wide viewport.
+        *world.get_mut::<UiScaleMode>(camera).unwrap() = UiScaleMode::FitDesignResolution {
+            width: 800.,
+            height: 600.,
+        };
+
+        app.update();
+        let world = app.world_mut();
+
+        let target_info = *world.get::<ComputedUiRenderTargetInfo>(uinode).unwrap();
+        assert_eq!(target_info.scale_factor(), 400. / 600.);
+        assert_eq!(target_info.physical_size(), UVec2::new(533, 400));
+        assert_eq!(target_info.offset(), Vec2::new(233., 0.));
+        assert!(target_info.logical_size().abs_diff_eq(Vec2::new(800., 600.), 1.));
+    }
 }
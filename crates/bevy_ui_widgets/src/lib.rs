@@ -47,6 +47,8 @@
 //!
 //! For more guidance on this, see the documentation for [`EntityEvent`].
 
+extern crate alloc;
+
 mod button;
 mod checkbox;
 mod dialog;
@@ -60,6 +62,9 @@ mod scrollarea;
 mod scrollbar;
 mod slider;
 mod text_input;
+mod tooltip;
+mod virtual_list;
+mod window_chrome;
 
 use bevy_input_focus::pointer_focus::PointerFocusPlugin;
 pub use button::*;
@@ -74,6 +79,9 @@ pub use scrollarea::*;
 pub use scrollbar::*;
 pub use slider::*;
 pub use text_input::*;
+pub use tooltip::*;
+pub use virtual_list::*;
+pub use window_chrome::*;
 
 use bevy_app::{PluginGroup, PluginGroupBuilder};
 use bevy_ecs::{entity::Entity, event::EntityEvent, reflect::ReflectEvent};
@@ -101,6 +109,9 @@ impl PluginGroup for UiWidgetsPlugins {
             .add(ScrollAreaPlugin)
             .add(ScrollbarPlugin)
             .add(SliderPlugin)
+            .add(TooltipPlugin)
+            .add(VirtualListPlugin)
+            .add(WindowChromePlugin)
             .add(PointerFocusPlugin)
     }
 }
@@ -10,7 +10,7 @@ use bevy_ecs::{
     schedule::IntoScheduleConfigs,
     system::{ParamSet, Query},
 };
-use bevy_math::{Affine2, Rect, Vec2};
+use bevy_math::{Affine2, Mat2, Rect, Vec2};
 use bevy_reflect::Reflect;
 use bevy_ui::{
     ui_layout_system, ComputedNode, ComputedUiRenderTargetInfo, Node, PositionType,
@@ -79,6 +79,21 @@ pub struct PopoverPlacement {
     pub gap: f32,
 }
 
+/// What a popover element is positioned relative to. The "parent" of the [`PopoverPlacement`]s is
+/// the anchor.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub enum PopoverAnchor {
+    /// The popover element is positioned relative to its parent element.
+    #[default]
+    Parent,
+    /// The popover element is positioned relative to another UI node, which should be rendered to
+    /// the same target and can't be a popover element itself.
+    Entity(Entity),
+    /// The popover element is positioned relative to a point of the render target, in logical
+    /// pixels, such as the position of the pointer.
+    Point(Vec2),
+}
+
 /// Component which is inserted into a popover element to make it dynamically position relative to
 /// an parent element.
 #[derive(Component, PartialEq, Default, Reflect)]
@@ -89,6 +104,13 @@ pub struct Popover {
 
     /// Indicates how close to the window edge the popup is allowed to go.
     pub window_margin: f32,
+
+    /// What the popover element is positioned relative to. Defaults to the parent element.
+    pub anchor: PopoverAnchor,
+
+    /// If true, and none of the positions have sufficient room, the popover element is moved inside
+    /// the window margin instead of being clipped by the window edge.
+    pub clamp_to_window: bool,
 }
 
 impl Clone for Popover {
@@ -96,6 +118,8 @@ impl Clone for Popover {
         Self {
             positions: self.positions.clone(),
             window_margin: self.window_margin,
+            anchor: self.anchor,
+            clamp_to_window: self.clamp_to_window,
         }
     }
 }
@@ -109,7 +133,7 @@ pub(crate) fn position_popover(
         &ComputedNode,
         &ComputedUiRenderTargetInfo,
         &Popover,
-        &ChildOf,
+        Option<&ChildOf>,
     )>,
     mut qs_transform: ParamSet<(
         Query<(&ComputedNode, &UiGlobalTransform), Without<Popover>>,
@@ -135,21 +159,40 @@ pub(crate) fn position_popover(
         }
         .inflate(-popover.window_margin);
 
-        // Compute the parent rectangle.
-        let q_parent = qs_transform.p0();
-        let Ok((parent_node, parent_transform)) = q_parent.get(parent.parent()) else {
-            continue;
+        // The translation of the popover element is relative to its parent element.
+        let q_transform = qs_transform.p0();
+        let parent_matrix = match parent {
+            Some(parent) => match q_transform.get(parent.parent()) {
+                Ok((_, parent_transform)) => parent_transform.affine().matrix2,
+                Err(_) => continue,
+            },
+            None => Mat2::IDENTITY,
         };
 
-        // Computed node size includes the border, but since absolute positioning doesn't include
-        // border we need to remove it from the calculations.
-        let parent_size =
-            parent_node.size() - parent_node.border.min_inset - parent_node.border.max_inset;
-        let parent_rect = scale_rect(
-            Rect::from_center_size(parent_transform.translation, parent_size),
-            parent_node.inverse_scale_factor,
-        );
-        let parent_matrix = parent_transform.affine().matrix2;
+        // Compute the anchor rectangle.
+        let anchor_entity = match popover.anchor {
+            PopoverAnchor::Parent => parent.map(ChildOf::parent),
+            PopoverAnchor::Entity(anchor) => Some(anchor),
+            PopoverAnchor::Point(_) => None,
+        };
+        let anchor_rect = match (popover.anchor, anchor_entity) {
+            (PopoverAnchor::Point(point), _) => Rect::from_center_size(point, Vec2::ZERO),
+            (_, Some(anchor)) => {
+                let Ok((anchor_node, anchor_transform)) = q_transform.get(anchor) else {
+                    continue;
+                };
+                // Computed node size includes the border, but since absolute positioning doesn't
+                // include border we need to remove it from the calculations.
+                let anchor_size = anchor_node.size()
+                    - anchor_node.border.min_inset
+                    - anchor_node.border.max_inset;
+                scale_rect(
+                    Rect::from_center_size(anchor_transform.translation, anchor_size),
+                    anchor_node.inverse_scale_factor,
+                )
+            }
+            (_, None) => continue,
+        };
 
         let mut best_occluded = f32::MAX;
         let mut best_rect = Rect::default();
@@ -165,22 +208,22 @@ pub(crate) fn position_popover(
             // Position along main axis.
             match position.side {
                 PopoverSide::Top => {
-                    rect.max.y = parent_rect.min.y - position.gap;
+                    rect.max.y = anchor_rect.min.y - position.gap;
                     rect.min.y = rect.max.y - popover_size.y;
                 }
 
                 PopoverSide::Bottom => {
-                    rect.min.y = parent_rect.max.y + position.gap;
+                    rect.min.y = anchor_rect.max.y + position.gap;
                     rect.max.y = rect.min.y + popover_size.y;
                 }
 
                 PopoverSide::Left => {
-                    rect.max.x = parent_rect.min.x - position.gap;
+                    rect.max.x = anchor_rect.min.x - position.gap;
                     rect.min.x = rect.max.x - popover_size.x;
                 }
 
                 PopoverSide::Right => {
-                    rect.min.x = parent_rect.max.x + position.gap;
+                    rect.min.x = anchor_rect.max.x + position.gap;
                     rect.max.x = rect.min.x + popover_size.x;
                 }
             }
@@ -189,37 +232,37 @@ pub(crate) fn position_popover(
             match position.align {
                 PopoverAlign::Start => match position.side {
                     PopoverSide::Top | PopoverSide::Bottom => {
-                        rect.min.x = parent_rect.min.x;
+                        rect.min.x = anchor_rect.min.x;
                         rect.max.x = rect.min.x + target_width;
                     }
 
                     PopoverSide::Left | PopoverSide::Right => {
-                        rect.min.y = parent_rect.min.y;
+                        rect.min.y = anchor_rect.min.y;
                         rect.max.y = rect.min.y + target_height;
                     }
                 },
 
                 PopoverAlign::End => match position.side {
                     PopoverSide::Top | PopoverSide::Bottom => {
-                        rect.max.x = parent_rect.max.x;
+                        rect.max.x = anchor_rect.max.x;
                         rect.min.x = rect.max.x - target_width;
                     }
 
                     PopoverSide::Left | PopoverSide::Right => {
-                        rect.max.y = parent_rect.max.y;
+                        rect.max.y = anchor_rect.max.y;
                         rect.min.y = rect.max.y - target_height;
                     }
                 },
 
                 PopoverAlign::Center => match position.side {
                     PopoverSide::Top | PopoverSide::Bottom => {
-                        rect.min.x = parent_rect.min.x + (parent_rect.width() - target_width) * 0.5;
+                        rect.min.x = anchor_rect.min.x + (anchor_rect.width() - target_width) * 0.5;
                         rect.max.x = rect.min.x + target_width;
                     }
 
                     PopoverSide::Left | PopoverSide::Right => {
                         rect.min.y =
-                            parent_rect.min.y + (parent_rect.height() - target_height) * 0.5;
+                            anchor_rect.min.y + (anchor_rect.height() - target_height) * 0.5;
                         rect.max.y = rect.min.y + target_height;
                     }
                 },
@@ -238,6 +281,10 @@ pub(crate) fn position_popover(
             }
         }
 
+        if popover.clamp_to_window && best_occluded > 0.0 && best_occluded < f32::MAX {
+            best_rect = clamp_rect(best_rect, window_rect);
+        }
+
         // Update node properties, but only if they are different from before (to avoid setting
         // change detection bit).
         if best_occluded < f32::MAX {
@@ -327,3 +374,39 @@ fn scale_rect(rect: Rect, factor: f32) -> Rect {
         max: rect.max * factor,
     }
 }
+
+/// Moves `rect` inside `bounds`. If `rect` is larger than `bounds`, its starting edges are kept
+/// inside.
+fn clamp_rect(rect: Rect, bounds: Rect) -> Rect {
+    let offset = (bounds.max - rect.max)
+        .min(Vec2::ZERO)
+        .max(bounds.min - rect.min);
+    Rect {
+        min: rect.min + offset,
+        max: rect.max + offset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp_rect;
+    use bevy_math::{Rect, Vec2};
+
+    #[test]
+    fn clamped_popovers_stay_inside_the_window() {
+        let window = Rect::new(0.0, 0.0, 100.0, 100.0);
+
+        let overflowing = Rect::new(80.0, -10.0, 120.0, 20.0);
+        assert_eq!(
+            clamp_rect(overflowing, window),
+            Rect::new(60.0, 0.0, 100.0, 30.0)
+        );
+
+        let inside = Rect::new(10.0, 10.0, 20.0, 20.0);
+        assert_eq!(clamp_rect(inside, window), inside);
+
+        // Too wide to fit, so the left edge is kept in the window.
+        let too_wide = Rect::new(-20.0, 10.0, 130.0, 20.0);
+        assert_eq!(clamp_rect(too_wide, window).min, Vec2::new(0.0, 10.0));
+    }
+}
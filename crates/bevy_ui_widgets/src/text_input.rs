@@ -20,7 +20,7 @@ use bevy_picking::pointer::PointerButton;
 use bevy_reflect::Reflect;
 use bevy_text::{
     scrollable_text_layout_width, EditableText, EditableTextSystems, PreeditCursor, TextEdit,
-    TextLayout, TextLayoutInfo,
+    TextEditChange, TextLayout, TextLayoutInfo,
 };
 use bevy_time::{Real, Time};
 use bevy_ui::widget::{sync_editable_text_viewports, update_editable_text_layout};
@@ -31,6 +31,8 @@ use bevy_ui::{
 };
 use bevy_window::{Ime, PrimaryWindow, Window};
 
+use crate::ValueChange;
+
 const NONE: u8 = 0;
 const SUPER: u8 = 1;
 const CTRL: u8 = 2;
@@ -93,6 +95,7 @@ fn on_focused_keyboard_input(
     mut keyboard_input: On<FocusedInput<KeyboardInput>>,
     mut query: Query<&mut EditableText, Without<InteractionDisabled>>,
     keys: Res<ButtonInput<Key>>,
+    mut commands: Commands,
 ) {
     let Ok(mut editable_text) = query.get_mut(keyboard_input.focused_entity) else {
         return; // Focused entity is not an EditableText, nothing to do
@@ -199,6 +202,18 @@ fn on_focused_keyboard_input(
         }
     }
 
+    // Submitting still propagates the input, so that the ancestors can handle `Enter` too.
+    let submit_modifiers = if allow_newlines { COMMAND } else { NONE };
+    if mod_flags == submit_modifiers
+        && keyboard_input.input.logical_key == Key::Enter
+        && keyboard_input.input.state.is_pressed()
+        && !keyboard_input.input.repeat
+    {
+        commands
+            .entity(keyboard_input.focused_entity)
+            .insert(PendingSubmit);
+    }
+
     keyboard_input.propagate(should_propagate);
 }
 
@@ -523,6 +538,7 @@ fn update_ime_position(
 /// is an [`EditableText`].
 ///
 /// IME is enabled when an `EditableText` gains focus and disabled when focus moves elsewhere.
+/// On Android, enabling IME also shows the on-screen keyboard, and disabling it hides the keyboard.
 fn listen_for_ime_input_when_text_input_focused(
     input_focus: Res<InputFocus>,
     editable_text_query: Query<(), With<EditableText>>,
@@ -626,6 +642,79 @@ fn apply_queued_select_all(
     }
 }
 
+/// Notification sent by an [`EditableText`] widget when the user submits its value, by pressing
+/// `Enter` in a single line input, or `Ctrl` + `Enter` (`Cmd` + `Enter` on macOS) in an input
+/// which [allows newlines](EditableText::allow_newlines).
+///
+/// The value of the input is also sent as a [`ValueChange<String>`] each time it changes, with
+/// `is_final` set to `false`, and along with each [`TextInputSubmit`], with `is_final` set to
+/// `true`.
+#[derive(Clone, Debug, PartialEq, EntityEvent, Reflect)]
+#[reflect(Event)]
+pub struct TextInputSubmit {
+    /// The submitted [`EditableText`] widget.
+    pub entity: Entity,
+    /// The value of the input when it was submitted.
+    pub value: String,
+}
+
+/// Marks an [`EditableText`] to submit once its pending edits are applied.
+#[derive(Component)]
+struct PendingSubmit;
+
+/// The value of an [`EditableText`] when its last [`ValueChange`] was sent.
+#[derive(Component)]
+struct LastTextInputValue(String);
+
+/// Sends a [`ValueChange<String>`] when the value of an [`EditableText`] changes, ignoring the
+/// edits which only move the cursor or the selection.
+fn on_text_edit_change(
+    change: On<TextEditChange>,
+    mut q_text_input: Query<(&EditableText, Option<&mut LastTextInputValue>)>,
+    mut commands: Commands,
+) {
+    let entity = change.event_target();
+    let Ok((editable_text, last_value)) = q_text_input.get_mut(entity) else {
+        return;
+    };
+    let Some(mut last_value) = last_value else {
+        // The initial value of the input isn't a change.
+        commands
+            .entity(entity)
+            .insert(LastTextInputValue(editable_text.value().to_string()));
+        return;
+    };
+    if editable_text.value() != &last_value.0 {
+        last_value.0 = editable_text.value().to_string();
+        commands.trigger(ValueChange {
+            source: entity,
+            value: last_value.0.clone(),
+            is_final: false,
+        });
+    }
+}
+
+/// Triggers the [`TextInputSubmit`] and the final [`ValueChange`] of the [`EditableText`] widgets
+/// submitted this frame, once the edits queued before the submission are applied.
+fn submit_text_inputs(
+    q_text_input: Query<(Entity, &EditableText), With<PendingSubmit>>,
+    mut commands: Commands,
+) {
+    for (entity, editable_text) in &q_text_input {
+        let value = editable_text.value().to_string();
+        commands.entity(entity).remove::<PendingSubmit>();
+        commands.trigger(TextInputSubmit {
+            entity,
+            value: value.clone(),
+        });
+        commands.trigger(ValueChange {
+            source: entity,
+            value,
+            is_final: true,
+        });
+    }
+}
+
 /// System sets for IME-related systems used by [`EditableTextInputPlugin`].
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ImeSystems {
@@ -662,6 +751,7 @@ impl Plugin for EditableTextInputPlugin {
             .add_observer(on_pointer_press)
             .add_observer(on_focus_lost)
             .add_observer(on_focus_select_all)
+            .add_observer(on_text_edit_change)
             .add_systems(
                 PreUpdate,
                 (
@@ -690,6 +780,7 @@ impl Plugin for EditableTextInputPlugin {
                     .after(sync_editable_text_viewports)
                     .before(EditableTextSystems),
             )
+            .add_systems(PostUpdate, submit_text_inputs.after(EditableTextSystems))
             .add_systems(
                 PostUpdate,
                 apply_queued_select_all
@@ -754,6 +845,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enter_submits_single_line_inputs() {
+        #[derive(Resource, Default)]
+        struct Submitted(Vec<String>);
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, InputDispatchPlugin))
+            .init_resource::<InputFocus>()
+            .init_resource::<Submitted>()
+            .add_observer(on_focused_keyboard_input)
+            .add_observer(
+                |submit: On<TextInputSubmit>, mut submitted: ResMut<Submitted>| {
+                    submitted.0.push(submit.value.clone());
+                },
+            )
+            .add_systems(Update, submit_text_inputs);
+
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let editable_text = app.world_mut().spawn(EditableText::new("hello")).id();
+        app.insert_resource(InputFocus::from_entity(editable_text));
+
+        for repeat in [false, true] {
+            app.world_mut().write_message(KeyboardInput {
+                key_code: KeyCode::Enter,
+                logical_key: Key::Enter,
+                state: ButtonState::Pressed,
+                text: None,
+                repeat,
+                window,
+            });
+        }
+        app.update();
+
+        // Repeated key presses don't submit again.
+        assert_eq!(app.world().resource::<Submitted>().0, ["hello"]);
+        assert!(!app
+            .world()
+            .entity(editable_text)
+            .contains::<PendingSubmit>());
+    }
+
+    #[test]
+    fn submit_sends_final_value_change() {
+        #[derive(Resource, Default)]
+        struct ValueChanges(Vec<(String, bool)>);
+
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, InputDispatchPlugin))
+            .init_resource::<InputFocus>()
+            .init_resource::<ValueChanges>()
+            .add_observer(on_focused_keyboard_input)
+            .add_observer(
+                |change: On<ValueChange<String>>, mut changes: ResMut<ValueChanges>| {
+                    changes.0.push((change.value.clone(), change.is_final));
+                },
+            )
+            .add_systems(Update, submit_text_inputs);
+
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let editable_text = app.world_mut().spawn(EditableText::new("hello")).id();
+        app.insert_resource(InputFocus::from_entity(editable_text));
+
+        app.world_mut().write_message(KeyboardInput {
+            key_code: KeyCode::Enter,
+            logical_key: Key::Enter,
+            state: ButtonState::Pressed,
+            text: None,
+            repeat: false,
+            window,
+        });
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<ValueChanges>().0,
+            [("hello".to_string(), true)]
+        );
+
+        // Nothing is sent until the input is submitted again.
+        app.update();
+        assert_eq!(app.world().resource::<ValueChanges>().0.len(), 1);
+    }
+
     #[test]
     fn autoscroll_speed_is_zero_inside_then_ramps_and_caps() {
         let visible_size = 100.0;
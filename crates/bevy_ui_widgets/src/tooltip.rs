@@ -0,0 +1,345 @@
+//! A text tooltip, positioned with the [`Popover`] framework.
+
+use core::time::Duration;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    reflect::{ReflectComponent, ReflectResource},
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
+    world::Ref,
+};
+use bevy_input::{mouse::MouseButton, ButtonInput};
+use bevy_picking::{hover::Hovered, Pickable};
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_time::{Real, Time};
+use bevy_ui::{widget::Text, GlobalZIndex, Node, OverrideClip, PositionType};
+
+use crate::popover::{Popover, PopoverAlign, PopoverAnchor, PopoverPlacement, PopoverSide};
+
+/// Component which shows a text tooltip next to its entity while the entity is hovered.
+///
+/// The tooltip is shown once the entity has been hovered for [`TooltipSettings::show_delay`], and
+/// hidden once the pointer has left the entity for [`TooltipSettings::hide_delay`], or as soon as
+/// a mouse button is pressed. It isn't shown again until the pointer leaves the entity. Only one
+/// tooltip is shown at a time, the one of the innermost hovered entity, and moving from one
+/// tooltip to another shows the new one without waiting for the delay.
+///
+/// The tooltip element is spawned as a child of the entity, with a [`TooltipPopup`] component, the
+/// text and a [`Popover`] placed according to the [`TooltipSettings`]. It has no styling, which
+/// can be added by an observer of the insertion of [`TooltipPopup`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Clone, Debug, Default, PartialEq)]
+#[require(Hovered)]
+pub struct Tooltip(pub Text);
+
+impl Tooltip {
+    /// Makes a new tooltip showing `text`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self(Text::new(text))
+    }
+}
+
+/// Component of the tooltip element shown for a [`Tooltip`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Clone, Debug, PartialEq)]
+pub struct TooltipPopup {
+    /// The entity with the [`Tooltip`].
+    pub owner: Entity,
+}
+
+/// Settings of the tooltips shown for [`Tooltip`]s.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource, Clone, Debug, Default)]
+pub struct TooltipSettings {
+    /// How long an entity must be hovered before its tooltip is shown.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub show_delay: Duration,
+    /// How long the pointer must have left an entity before its tooltip is hidden.
+    ///
+    /// Defaults to 100 milliseconds.
+    pub hide_delay: Duration,
+    /// The potential positions of the tooltip element relative to its entity, see
+    /// [`Popover::positions`].
+    ///
+    /// Defaults to centered below the entity, or above it if there isn't enough room below.
+    pub positions: Vec<PopoverPlacement>,
+    /// How close to the window edge the tooltip element is allowed to go.
+    ///
+    /// Defaults to 4 logical pixels.
+    pub window_margin: f32,
+    /// The [`GlobalZIndex`] of the tooltip element.
+    ///
+    /// Defaults to 1000.
+    pub z_index: i32,
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            show_delay: Duration::from_millis(500),
+            hide_delay: Duration::from_millis(100),
+            positions: vec![
+                PopoverPlacement {
+                    side: PopoverSide::Bottom,
+                    align: PopoverAlign::Center,
+                    gap: 4.0,
+                },
+                PopoverPlacement {
+                    side: PopoverSide::Top,
+                    align: PopoverAlign::Center,
+                    gap: 4.0,
+                },
+            ],
+            window_margin: 4.0,
+            z_index: 1000,
+        }
+    }
+}
+
+/// The tooltip element currently shown.
+struct ShownTooltip {
+    popup: Entity,
+    owner: Entity,
+    /// How long the pointer has left the owner.
+    unhovered: Duration,
+}
+
+#[derive(Resource, Default)]
+struct TooltipState {
+    /// The hovered entity whose tooltip isn't shown yet, and how long it has been hovered.
+    hovered: Option<(Entity, Duration)>,
+    shown: Option<ShownTooltip>,
+    /// The entity hovered when a mouse button was pressed, whose tooltip isn't shown until the
+    /// pointer leaves it.
+    suppressed: Option<Entity>,
+}
+
+fn update_tooltips(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    settings: Res<TooltipSettings>,
+    mut state: ResMut<TooltipState>,
+    q_tooltips: Query<(Entity, Ref<Tooltip>, &Hovered)>,
+    q_parents: Query<&ChildOf>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+) {
+    let delta = time.delta();
+
+    // Hovering an entity also hovers its ancestors, so pick the innermost hovered tooltip.
+    let is_hovered_ancestor = |entity: Entity| {
+        q_tooltips.iter().any(|(other, _, hovered)| {
+            hovered.get()
+                && q_parents
+                    .iter_ancestors(other)
+                    .any(|parent| parent == entity)
+        })
+    };
+    let mut hovered = q_tooltips
+        .iter()
+        .filter(|(_, _, hovered)| hovered.get())
+        .map(|(entity, _, _)| entity)
+        .find(|entity| !is_hovered_ancestor(*entity));
+
+    if mouse.is_some_and(|mouse| mouse.get_just_pressed().next().is_some()) {
+        state.suppressed = hovered;
+    } else if state.suppressed.is_some() && state.suppressed != hovered {
+        state.suppressed = None;
+    }
+    let suppressed = state.suppressed.is_some();
+    if suppressed {
+        hovered = None;
+    }
+
+    // Hide the shown tooltip.
+    let mut switching = false;
+    if let Some(shown) = &mut state.shown {
+        if Some(shown.owner) == hovered {
+            shown.unhovered = Duration::ZERO;
+            if let Ok((_, tooltip, _)) = q_tooltips.get(shown.owner)
+                && tooltip.is_changed()
+            {
+                commands.entity(shown.popup).insert(tooltip.0.clone());
+            }
+        } else {
+            shown.unhovered += delta;
+            switching = hovered.is_some();
+            if switching
+                || shown.unhovered >= settings.hide_delay
+                || suppressed
+                || !q_tooltips.contains(shown.owner)
+            {
+                commands.entity(shown.popup).try_despawn();
+                state.shown = None;
+            }
+        }
+    }
+
+    // Show the tooltip of the hovered entity.
+    let Some(owner) = hovered else {
+        state.hovered = None;
+        return;
+    };
+    if state.shown.is_some() {
+        return;
+    }
+    let elapsed = match state.hovered {
+        Some((entity, elapsed)) if entity == owner => elapsed + delta,
+        _ if switching => settings.show_delay,
+        _ => Duration::ZERO,
+    };
+    if elapsed < settings.show_delay {
+        state.hovered = Some((owner, elapsed));
+        return;
+    }
+
+    let Ok((_, tooltip, _)) = q_tooltips.get(owner) else {
+        return;
+    };
+    let popup = commands
+        .spawn((
+            TooltipPopup { owner },
+            Node {
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            tooltip.0.clone(),
+            Popover {
+                positions: settings.positions.clone(),
+                window_margin: settings.window_margin,
+                anchor: PopoverAnchor::Parent,
+                clamp_to_window: true,
+            },
+            GlobalZIndex(settings.z_index),
+            OverrideClip,
+            // The tooltip element must not keep its owner hovered.
+            Pickable::IGNORE,
+            ChildOf(owner),
+        ))
+        .id();
+    state.hovered = None;
+    state.shown = Some(ShownTooltip {
+        popup,
+        owner,
+        unhovered: Duration::ZERO,
+    });
+}
+
+/// Plugin that adds the systems showing the tooltips of [`Tooltip`] components.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipSettings>()
+            .init_resource::<TooltipState>()
+            .add_systems(Update, update_tooltips);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tooltip_app() -> App {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .add_plugins(TooltipPlugin);
+        app
+    }
+
+    fn advance(app: &mut App, millis: u64) {
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(millis));
+        app.update();
+    }
+
+    fn popups(app: &mut App) -> Vec<TooltipPopup> {
+        app.world_mut()
+            .query::<&TooltipPopup>()
+            .iter(app.world())
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    fn tooltips_are_shown_after_the_delay_and_hidden_on_leave() {
+        let mut app = tooltip_app();
+        let button = app
+            .world_mut()
+            .spawn((Tooltip::new("Save"), Hovered(true)))
+            .id();
+
+        // The hover starts in the first frame.
+        advance(&mut app, 10);
+        advance(&mut app, 300);
+        assert!(popups(&mut app).is_empty());
+        advance(&mut app, 300);
+        assert_eq!(popups(&mut app), vec![TooltipPopup { owner: button }]);
+
+        app.world_mut().entity_mut(button).insert(Hovered(false));
+        advance(&mut app, 50);
+        assert_eq!(popups(&mut app).len(), 1);
+        advance(&mut app, 60);
+        assert!(popups(&mut app).is_empty());
+    }
+
+    #[test]
+    fn pressing_hides_tooltips_until_the_pointer_leaves() {
+        let mut app = tooltip_app();
+        let button = app
+            .world_mut()
+            .spawn((Tooltip::new("Save"), Hovered(true)))
+            .id();
+        advance(&mut app, 10);
+        advance(&mut app, 600);
+        assert_eq!(popups(&mut app).len(), 1);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Left);
+        advance(&mut app, 10);
+        assert!(popups(&mut app).is_empty());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear();
+        advance(&mut app, 600);
+        assert!(popups(&mut app).is_empty());
+
+        app.world_mut().entity_mut(button).insert(Hovered(false));
+        advance(&mut app, 10);
+        app.world_mut().entity_mut(button).insert(Hovered(true));
+        advance(&mut app, 10);
+        advance(&mut app, 600);
+        assert_eq!(popups(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn innermost_tooltips_are_shown() {
+        let mut app = tooltip_app();
+        let panel = app
+            .world_mut()
+            .spawn((Tooltip::new("Panel"), Hovered(true)))
+            .id();
+        let button = app
+            .world_mut()
+            .spawn((Tooltip::new("Save"), Hovered(true), ChildOf(panel)))
+            .id();
+
+        advance(&mut app, 10);
+        advance(&mut app, 600);
+        assert_eq!(popups(&mut app), vec![TooltipPopup { owner: button }]);
+
+        // Moving to the panel shows its tooltip right away.
+        app.world_mut().entity_mut(button).insert(Hovered(false));
+        advance(&mut app, 10);
+        assert_eq!(popups(&mut app), vec![TooltipPopup { owner: panel }]);
+    }
+}
@@ -0,0 +1,323 @@
+//! Virtualized lists, instantiating only the visible items of long lists and grids.
+
+use alloc::sync::Arc;
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    query::Without,
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, EntityCommands, Query},
+    world::Ref,
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::Reflect;
+use bevy_ui::{percent, px, ComputedNode, Display, Node, PositionType, ScrollPosition, UiSystems};
+
+/// The data shown by a [`VirtualList`].
+///
+/// A virtual list only has entities for its visible items, which are recycled as the list scrolls:
+/// an item entity is [spawned](Self::spawn_item) once, and then [bound](Self::bind_item) to a new
+/// index every time it moves to another position of the list.
+pub trait VirtualListProvider: Send + Sync + 'static {
+    /// Returns the number of items of the list.
+    fn len(&self) -> usize;
+
+    /// Returns true if the list has no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the components and children of a new item entity, which are kept when the entity is
+    /// recycled.
+    ///
+    /// The [`Node`] of the item positions it in the list, and is replaced by the list.
+    fn spawn_item(&self, _item: &mut EntityCommands) {}
+
+    /// Updates an item entity to show the item at `index`, for instance by inserting a component
+    /// with the index, which the systems of the app use to update the children of the item.
+    fn bind_item(&self, index: usize, item: &mut EntityCommands);
+}
+
+/// Component of a scrolling node showing a long list or grid of [`VirtualListProvider`] items,
+/// which only has entities for the visible items.
+///
+/// The items are laid out in rows of [`columns`](Self::columns) items of the same width and of
+/// [`item_height`](Self::item_height) logical pixels, from top to bottom. The node should scroll
+/// vertically, with [`Overflow::scroll_y`](bevy_ui::Overflow::scroll_y) and a [`ScrollArea`] for
+/// instance.
+///
+/// After the data changes, [`set_changed`](bevy_ecs::change_detection::DetectChangesMut::set_changed)
+/// the component to bind the visible items again.
+///
+/// [`ScrollArea`]: crate::ScrollArea
+#[derive(Component, Clone)]
+#[require(Node, ScrollPosition, VirtualListState)]
+pub struct VirtualList {
+    /// The items of the list.
+    pub provider: Arc<dyn VirtualListProvider>,
+    /// The height of the items, in logical pixels.
+    pub item_height: f32,
+    /// The number of items per row, 1 for lists.
+    pub columns: usize,
+    /// The number of rows kept instantiated above and below the visible rows, so that they are
+    /// already laid out when they scroll into view.
+    pub overscan: usize,
+}
+
+impl VirtualList {
+    /// Creates a list of the items of `provider`, with one item per row and 2 rows of overscan.
+    pub fn new(provider: impl VirtualListProvider, item_height: f32) -> Self {
+        Self {
+            provider: Arc::new(provider),
+            item_height,
+            columns: 1,
+            overscan: 2,
+        }
+    }
+
+    /// Lays out the items in rows of `columns` items.
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Sets the number of rows kept instantiated above and below the visible rows.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Returns the range of the indices of the items to instantiate, for a viewport of
+    /// `viewport_height` logical pixels scrolled by `scroll` logical pixels.
+    pub fn visible_range(&self, scroll: f32, viewport_height: f32) -> core::ops::Range<usize> {
+        let len = self.provider.len();
+        if len == 0 || self.item_height <= 0.0 {
+            return 0..0;
+        }
+        let columns = self.columns.max(1);
+        let first_row = (scroll.max(0.0) / self.item_height) as usize;
+        let end_row = ((scroll.max(0.0) + viewport_height.max(0.0)) / self.item_height).ceil();
+        let start = first_row.saturating_sub(self.overscan) * columns;
+        let end = (end_row as usize + self.overscan).saturating_mul(columns);
+        start.min(len)..end.min(len)
+    }
+}
+
+/// Component of the item entities of a [`VirtualList`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Clone, Debug, PartialEq)]
+pub struct VirtualListItem {
+    /// The index of the item shown by the entity.
+    pub index: usize,
+}
+
+/// The node sizing the content of a [`VirtualList`] to the height of all its rows.
+#[derive(Component)]
+struct VirtualListSpacer;
+
+#[derive(Component, Default)]
+struct VirtualListState {
+    spacer: Option<Entity>,
+    /// The item entities, by index.
+    items: HashMap<usize, Entity>,
+    /// The hidden item entities, waiting to be recycled.
+    pool: Vec<Entity>,
+}
+
+fn update_virtual_lists(
+    mut commands: Commands,
+    mut q_lists: Query<(
+        Entity,
+        Ref<VirtualList>,
+        &ComputedNode,
+        &ScrollPosition,
+        &mut VirtualListState,
+    )>,
+    mut q_nodes: Query<&mut Node, Without<VirtualList>>,
+) {
+    for (list_entity, list, computed_node, scroll_position, mut state) in &mut q_lists {
+        let viewport_height = computed_node.size().y * computed_node.inverse_scale_factor;
+        let range = list.visible_range(scroll_position.y, viewport_height);
+        let columns = list.columns.max(1);
+        let rows = list.provider.len().div_ceil(columns);
+        let state = &mut *state;
+
+        let spacer_node = Node {
+            width: percent(100),
+            height: px(rows as f32 * list.item_height),
+            ..Default::default()
+        };
+        match state.spacer {
+            Some(spacer) => {
+                if let Ok(mut node) = q_nodes.get_mut(spacer)
+                    && node.height != spacer_node.height
+                {
+                    node.height = spacer_node.height;
+                }
+            }
+            None => {
+                state.spacer = Some(
+                    commands
+                        .spawn((VirtualListSpacer, spacer_node, ChildOf(list_entity)))
+                        .id(),
+                );
+            }
+        }
+
+        // Recycle the items which are out of view.
+        let pool = &mut state.pool;
+        state.items.retain(|index, item| {
+            if range.contains(index) {
+                return true;
+            }
+            if let Ok(mut node) = q_nodes.get_mut(*item) {
+                node.display = Display::None;
+            }
+            pool.push(*item);
+            false
+        });
+
+        for index in range {
+            let item_node = Node {
+                position_type: PositionType::Absolute,
+                left: percent((index % columns) as f32 * 100.0 / columns as f32),
+                top: px((index / columns) as f32 * list.item_height),
+                width: percent(100.0 / columns as f32),
+                height: px(list.item_height),
+                ..Default::default()
+            };
+
+            if let Some(&item) = state.items.get(&index) {
+                if list.is_changed() {
+                    if let Ok(mut node) = q_nodes.get_mut(item) {
+                        *node = item_node;
+                    }
+                    list.provider.bind_item(index, &mut commands.entity(item));
+                }
+                continue;
+            }
+
+            let item = match state.pool.pop() {
+                Some(item) => {
+                    if let Ok(mut node) = q_nodes.get_mut(item) {
+                        *node = item_node;
+                    }
+                    item
+                }
+                None => {
+                    let mut item = commands.spawn((item_node, ChildOf(list_entity)));
+                    list.provider.spawn_item(&mut item);
+                    item.id()
+                }
+            };
+            let mut item_commands = commands.entity(item);
+            item_commands.insert(VirtualListItem { index });
+            list.provider.bind_item(index, &mut item_commands);
+            state.items.insert(index, item);
+        }
+    }
+}
+
+/// Plugin that adds the systems updating the items of [`VirtualList`]s.
+pub struct VirtualListPlugin;
+
+impl Plugin for VirtualListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, update_virtual_lists.before(UiSystems::Prepare));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{query::With, world::World};
+    use bevy_math::Vec2;
+
+    struct Rows(usize);
+
+    impl VirtualListProvider for Rows {
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        fn bind_item(&self, _index: usize, _item: &mut EntityCommands) {}
+    }
+
+    #[test]
+    fn visible_ranges_include_the_overscan() {
+        let list = VirtualList::new(Rows(10_000), 20.0);
+        assert_eq!(list.visible_range(0.0, 100.0), 0..7);
+        assert_eq!(list.visible_range(1000.0, 100.0), 48..57);
+        assert_eq!(list.visible_range(1_000_000.0, 100.0), 10_000..10_000);
+
+        let grid = VirtualList::new(Rows(10), 20.0)
+            .with_columns(4)
+            .with_overscan(0);
+        assert_eq!(grid.visible_range(20.0, 30.0), 4..10);
+    }
+
+    fn items(world: &mut World) -> Vec<(Entity, usize, Display)> {
+        let mut items: Vec<_> = world
+            .query::<(Entity, &VirtualListItem, &Node)>()
+            .iter(world)
+            .map(|(entity, item, node)| (entity, item.index, node.display))
+            .collect();
+        items.sort_by_key(|(_, index, _)| *index);
+        items
+    }
+
+    #[test]
+    fn items_are_recycled_when_scrolling() {
+        let mut app = App::new();
+        app.add_plugins(VirtualListPlugin);
+        let list = app
+            .world_mut()
+            .spawn((
+                VirtualList::new(Rows(10_000), 20.0).with_overscan(0),
+                ComputedNode {
+                    size: Vec2::new(200.0, 100.0),
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        app.update();
+        let before = items(app.world_mut());
+        assert_eq!(
+            before
+                .iter()
+                .map(|(_, index, _)| *index)
+                .collect::<Vec<_>>(),
+            (0..5).collect::<Vec<_>>()
+        );
+
+        app.world_mut()
+            .entity_mut(list)
+            .insert(ScrollPosition(Vec2::new(0.0, 40.0)));
+        app.update();
+        let after = items(app.world_mut());
+        // Items 0 and 1 scrolled out of view, and their entities show items 5 and 6.
+        assert_eq!(after.len(), 5);
+        assert_eq!(
+            after.iter().map(|(_, index, _)| *index).collect::<Vec<_>>(),
+            (2..7).collect::<Vec<_>>()
+        );
+        let mut before_entities: Vec<_> = before.iter().map(|(entity, ..)| *entity).collect();
+        let mut after_entities: Vec<_> = after.iter().map(|(entity, ..)| *entity).collect();
+        before_entities.sort();
+        after_entities.sort();
+        assert_eq!(before_entities, after_entities);
+
+        let spacer = app
+            .world_mut()
+            .query_filtered::<&Node, With<VirtualListSpacer>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(spacer.height, px(200_000.0));
+    }
+}
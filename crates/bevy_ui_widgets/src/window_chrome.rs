@@ -0,0 +1,261 @@
+use bevy_app::{App, Plugin};
+use bevy_camera::NormalizedRenderTarget;
+use bevy_ecs::{
+    component::Component,
+    entity::{ContainsEntity, Entity},
+    message::MessageWriter,
+    observer::On,
+    query::Has,
+    reflect::ReflectComponent,
+    system::Query,
+};
+use bevy_math::CompassOctant;
+use bevy_picking::{
+    events::{Click, Pointer, Press},
+    pointer::{Location, PointerButton},
+};
+use bevy_reflect::Reflect;
+use bevy_ui::InteractionDisabled;
+use bevy_window::{Window, WindowCloseRequested};
+
+/// Headless widget making a UI node act as a part of the frame of the window, for windows without
+/// [`decorations`](Window::decorations) drawing their own title bar and borders.
+///
+/// Pressing the primary pointer button over a node with this component moves or resizes the
+/// window natively, and clicking it minimizes, maximizes or closes the window, in the window the
+/// node is displayed in.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_math::CompassOctant;
+/// # use bevy_ui::{percent, px, Node, PositionType};
+/// # use bevy_ui_widgets::WindowChrome;
+/// fn spawn_title_bar(mut commands: Commands) {
+///     commands.spawn((
+///         WindowChrome::TitleBar,
+///         Node {
+///             width: percent(100),
+///             height: px(32),
+///             ..Default::default()
+///         },
+///         children![(
+///             WindowChrome::Close,
+///             Node {
+///                 width: px(32),
+///                 height: px(32),
+///                 ..Default::default()
+///             },
+///         )],
+///     ));
+///     // A 4 pixels border on the right edge of the window, to resize it.
+///     commands.spawn((
+///         WindowChrome::Resize(CompassOctant::East),
+///         Node {
+///             position_type: PositionType::Absolute,
+///             right: px(0),
+///             width: px(4),
+///             height: percent(100),
+///             ..Default::default()
+///         },
+///     ));
+/// }
+/// ```
+///
+/// Nodes with [`InteractionDisabled`] are ignored.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq, Clone)]
+pub enum WindowChrome {
+    /// Dragging the node moves the window, and double-clicking it maximizes or un-maximizes the
+    /// window.
+    TitleBar,
+    /// Dragging the node moves the window.
+    DragRegion,
+    /// Dragging the node resizes the window from the given edge or corner.
+    Resize(CompassOctant),
+    /// Clicking the node minimizes the window.
+    Minimize,
+    /// Clicking the node maximizes the window, or un-maximizes it if it is maximized.
+    Maximize,
+    /// Clicking the node requests to close the window, sending a [`WindowCloseRequested`]
+    /// message.
+    Close,
+}
+
+/// Returns the window entity a pointer is over, if any.
+fn pointer_window(location: &Location) -> Option<Entity> {
+    match &location.target {
+        NormalizedRenderTarget::Window(window) => Some(window.entity()),
+        _ => None,
+    }
+}
+
+fn window_chrome_on_pointer_down(
+    mut press: On<Pointer<Press>>,
+    q_chrome: Query<(&WindowChrome, Has<InteractionDisabled>)>,
+    mut q_windows: Query<&mut Window>,
+) {
+    let Ok((chrome, disabled)) = q_chrome.get(press.entity) else {
+        return;
+    };
+    if disabled || press.button != PointerButton::Primary {
+        return;
+    }
+    let Some(mut window) =
+        pointer_window(&press.pointer_location).and_then(|window| q_windows.get_mut(window).ok())
+    else {
+        return;
+    };
+    match *chrome {
+        WindowChrome::TitleBar | WindowChrome::DragRegion => {
+            press.propagate(false);
+            // The second press of a double-click maximizes instead of moving the window.
+            if *chrome == WindowChrome::TitleBar && press.count == 2 {
+                window.toggle_maximized();
+            } else {
+                window.start_drag_move();
+            }
+        }
+        WindowChrome::Resize(direction) => {
+            press.propagate(false);
+            window.start_drag_resize(direction);
+        }
+        // The buttons act on click, but stop the press so it doesn't drag the title bar.
+        WindowChrome::Minimize | WindowChrome::Maximize | WindowChrome::Close => {
+            press.propagate(false);
+        }
+    }
+}
+
+fn window_chrome_on_pointer_click(
+    mut click: On<Pointer<Click>>,
+    q_chrome: Query<(&WindowChrome, Has<InteractionDisabled>)>,
+    mut q_windows: Query<&mut Window>,
+    mut close_requested: MessageWriter<WindowCloseRequested>,
+) {
+    let Ok((chrome, disabled)) = q_chrome.get(click.entity) else {
+        return;
+    };
+    if disabled || click.button != PointerButton::Primary {
+        return;
+    }
+    let Some(window_entity) = pointer_window(&click.pointer_location) else {
+        return;
+    };
+    let Ok(mut window) = q_windows.get_mut(window_entity) else {
+        return;
+    };
+    match *chrome {
+        WindowChrome::Minimize => window.set_minimized(true),
+        WindowChrome::Maximize => window.toggle_maximized(),
+        WindowChrome::Close => {
+            close_requested.write(WindowCloseRequested {
+                window: window_entity,
+            });
+        }
+        WindowChrome::TitleBar | WindowChrome::DragRegion | WindowChrome::Resize(_) => return,
+    }
+    click.propagate(false);
+}
+
+/// Plugin that adds the observers for the [`WindowChrome`] widget.
+pub struct WindowChromePlugin;
+
+impl Plugin for WindowChromePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<WindowCloseRequested>()
+            .add_observer(window_chrome_on_pointer_down)
+            .add_observer(window_chrome_on_pointer_click);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{hierarchy::ChildOf, message::Messages};
+    use bevy_math::Vec2;
+    use bevy_picking::{backend::HitData, pointer::PointerId};
+    use bevy_window::{PrimaryWindow, WindowRef};
+
+    fn window_location(window: Entity) -> Location {
+        Location {
+            target: NormalizedRenderTarget::Window(
+                WindowRef::Entity(window).normalize(Some(window)).unwrap(),
+            ),
+            position: Vec2::ZERO,
+        }
+    }
+
+    fn press(app: &mut App, target: Entity, window: Entity, count: u8) {
+        app.world_mut().trigger(Pointer::new(
+            PointerId::Mouse,
+            window_location(window),
+            Press {
+                button: PointerButton::Primary,
+                hit: HitData::new(window, 0.0, None, None),
+                count,
+            },
+            target,
+        ));
+    }
+
+    fn click(app: &mut App, target: Entity, window: Entity) {
+        app.world_mut().trigger(Pointer::new(
+            PointerId::Mouse,
+            window_location(window),
+            Click {
+                button: PointerButton::Primary,
+                hit: HitData::new(window, 0.0, None, None),
+                duration: core::time::Duration::from_millis(10),
+                count: 1,
+            },
+            target,
+        ));
+    }
+
+    #[test]
+    fn window_chrome_moves_resizes_and_closes_the_window() {
+        let mut app = App::new();
+        app.add_plugins(WindowChromePlugin);
+        let window = app
+            .world_mut()
+            .spawn((Window::default(), PrimaryWindow))
+            .id();
+        let title_bar = app
+            .world_mut()
+            .spawn((WindowChrome::TitleBar, ChildOf(window)))
+            .id();
+        // Pressing a child of the title bar drags the window too.
+        let title = app.world_mut().spawn(ChildOf(title_bar)).id();
+        let border = app
+            .world_mut()
+            .spawn((WindowChrome::Resize(CompassOctant::East), ChildOf(window)))
+            .id();
+        let close = app
+            .world_mut()
+            .spawn((WindowChrome::Close, ChildOf(title_bar)))
+            .id();
+
+        press(&mut app, title, window, 1);
+        press(&mut app, border, window, 1);
+        let mut window_component = app.world_mut().get_mut::<Window>(window).unwrap();
+        assert!(window_component.internal.take_move_request());
+        assert_eq!(
+            window_component.internal.take_resize_request(),
+            Some(CompassOctant::East)
+        );
+
+        // Double-clicking the title bar maximizes the window instead of moving it.
+        press(&mut app, title_bar, window, 2);
+        let mut window_component = app.world_mut().get_mut::<Window>(window).unwrap();
+        assert!(!window_component.internal.take_move_request());
+        assert!(window_component.internal.take_toggle_maximize_request());
+
+        // Pressing the close button doesn't move the window, clicking it closes the window.
+        press(&mut app, close, window, 1);
+        click(&mut app, close, window);
+        let mut window_component = app.world_mut().get_mut::<Window>(window).unwrap();
+        assert!(!window_component.internal.take_move_request());
+        let close_requests = app.world().resource::<Messages<WindowCloseRequested>>();
+        assert_eq!(close_requests.len(), 1);
+    }
+}
@@ -4,7 +4,7 @@ use bevy_ecs::prelude::ReflectMessage;
 use bevy_ecs::{entity::Entity, message::Message};
 use bevy_input::{
     gestures::*,
-    keyboard::{KeyboardFocusLost, KeyboardInput},
+    keyboard::{KeyRepeat, KeyboardFocusLost, KeyboardInput, TextInputEvent},
     mouse::{MouseButtonInput, MouseMotion, MouseWheel},
     touch::TouchInput,
 };
@@ -22,7 +22,7 @@ use bevy_reflect::Reflect;
 #[cfg(feature = "serialize")]
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 
-use crate::WindowTheme;
+use crate::{PresentMode, WindowTheme};
 
 /// A window event that is sent whenever a window's logical size has changed.
 #[derive(Message, Debug, Clone, PartialEq)]
@@ -446,6 +446,35 @@ pub struct WindowThemeChanged {
     pub theme: WindowTheme,
 }
 
+/// An event sent by the renderer when the surface of a window is configured, with the present
+/// mode and frame latency actually used.
+///
+/// The [`present_mode`](Self::present_mode) differs from the
+/// [`requested_present_mode`](Self::requested_present_mode) when the requested mode is one of the
+/// `Auto` modes, or isn't supported by the platform and fell back to another mode.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone, Message)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct WindowPresentModeChanged {
+    /// Window whose surface was configured.
+    pub window: Entity,
+    /// The [`Window::present_mode`](crate::Window::present_mode) requested for the window.
+    pub requested_present_mode: PresentMode,
+    /// The present mode used by the surface of the window.
+    pub present_mode: PresentMode,
+    /// The maximum number of frames queued on the GPU used by the surface of the window, from
+    /// [`Window::desired_maximum_frame_latency`](crate::Window::desired_maximum_frame_latency).
+    pub desired_maximum_frame_latency: u32,
+}
+
 /// Application lifetime events
 #[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(
@@ -484,6 +513,24 @@ impl AppLifecycle {
     }
 }
 
+/// The operating system has signaled that the application is running low on memory.
+///
+/// This is currently only sent on Android and iOS. Apps that hold onto large caches
+/// (e.g. GPU-heavy textures or meshes that can be reloaded from disk) should react to
+/// this message by releasing them, as the OS may otherwise terminate the application.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone, Message)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MemoryWarning;
+
 /// Wraps all `bevy_window` and `bevy_input` events in a common enum.
 ///
 /// Read these events with `MessageReader<WindowEvent>` if you need to
@@ -536,6 +583,8 @@ pub enum WindowEvent {
     WindowScaleFactorChanged(WindowScaleFactorChanged),
     /// Sent for windows that are using the system theme when the system theme changes.
     WindowThemeChanged(WindowThemeChanged),
+    /// The operating system has signaled that the application is running low on memory.
+    MemoryWarning(MemoryWarning),
 
     /// The state of a mouse button has changed.
     MouseButtonInput(MouseButtonInput),
@@ -558,6 +607,10 @@ pub enum WindowEvent {
 
     /// A keyboard input.
     KeyboardInput(KeyboardInput),
+    /// A key repeated by the operating system.
+    KeyRepeat(KeyRepeat),
+    /// Text typed in a window.
+    TextInputEvent(TextInputEvent),
     /// Sent when focus has been lost for all Bevy windows.
     ///
     /// Used to clear pressed key state.
@@ -570,6 +623,12 @@ impl From<AppLifecycle> for WindowEvent {
     }
 }
 
+impl From<MemoryWarning> for WindowEvent {
+    fn from(e: MemoryWarning) -> Self {
+        Self::MemoryWarning(e)
+    }
+}
+
 impl From<CursorEntered> for WindowEvent {
     fn from(e: CursorEntered) -> Self {
         Self::CursorEntered(e)
@@ -720,6 +779,18 @@ impl From<KeyboardInput> for WindowEvent {
     }
 }
 
+impl From<KeyRepeat> for WindowEvent {
+    fn from(e: KeyRepeat) -> Self {
+        Self::KeyRepeat(e)
+    }
+}
+
+impl From<TextInputEvent> for WindowEvent {
+    fn from(e: TextInputEvent) -> Self {
+        Self::TextInputEvent(e)
+    }
+}
+
 impl From<KeyboardFocusLost> for WindowEvent {
     fn from(e: KeyboardFocusLost) -> Self {
         Self::KeyboardFocusLost(e)
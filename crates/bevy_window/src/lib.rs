@@ -22,6 +22,7 @@ mod event;
 mod monitor;
 mod raw_handle;
 mod system;
+mod taskbar;
 mod window;
 
 pub use crate::raw_handle::*;
@@ -30,6 +31,7 @@ pub use cursor::*;
 pub use event::*;
 pub use monitor::*;
 pub use system::*;
+pub use taskbar::*;
 pub use window::*;
 
 /// The windowing prelude.
@@ -123,7 +125,9 @@ impl Plugin for WindowPlugin {
             .add_message::<FileDragAndDrop>()
             .add_message::<WindowMoved>()
             .add_message::<WindowThemeChanged>()
-            .add_message::<AppLifecycle>();
+            .add_message::<WindowPresentModeChanged>()
+            .add_message::<AppLifecycle>()
+            .add_message::<MemoryWarning>();
 
         if let Some(primary_window) = &self.primary_window {
             let mut entity_commands = app.world_mut().spawn(primary_window.clone());
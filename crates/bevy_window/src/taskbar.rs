@@ -0,0 +1,90 @@
+//! Components to customize how a window appears in the taskbar or dock.
+
+#[cfg(feature = "window_icon")]
+use bevy_asset::Handle;
+use bevy_ecs::component::Component;
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+#[cfg(feature = "window_icon")]
+use bevy_image::Image;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// Insert into a window entity to set the icon of that window, shown in its title bar and in the
+/// taskbar.
+///
+/// The image must be in 8 bit int or 32 bit float rgba, and square images of 32 to 256 pixels
+/// work best. The icon is set once the image is loaded, and can be changed at runtime by
+/// replacing this component. Removing it restores the default icon.
+///
+/// On macOS, windows don't have icons: set the icon of the application bundle instead.
+#[cfg(feature = "window_icon")]
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, Default, PartialEq, Clone)
+)]
+pub struct WindowIcon(pub Handle<Image>);
+
+#[cfg(feature = "window_icon")]
+impl From<Handle<Image>> for WindowIcon {
+    fn from(handle: Handle<Image>) -> Self {
+        Self(handle)
+    }
+}
+
+/// Insert into a window entity to request the attention of the user, for example when a match is
+/// found while the game is in the background.
+///
+/// The window flashes in the taskbar, or its icon bounces in the dock, until the window is
+/// focused, at which point this component is removed. Removing it earlier cancels the request.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, Default, PartialEq, Hash, Clone)
+)]
+pub enum WindowAttention {
+    /// Flashes the window once, or until it is focused, depending on the platform.
+    #[default]
+    Informational,
+    /// Flashes the window until it is focused.
+    Critical,
+}
+
+/// Insert into a window entity to show a progress bar over its icon in the taskbar or dock, for
+/// example while a level or an update is downloading. Removing it hides the progress bar.
+///
+/// The progress goes from `0.0` to `1.0`.
+///
+/// Not every platform or windowing backend supports progress bars, in which case this component
+/// is ignored.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, PartialEq, Clone)
+)]
+pub enum TaskbarProgress {
+    /// The progress is unknown, shown as an animated bar.
+    Indeterminate,
+    /// The task is progressing.
+    Normal(f32),
+    /// The task is paused, shown as a yellow bar on Windows.
+    Paused(f32),
+    /// The task failed, shown as a red bar on Windows.
+    Error(f32),
+}
+
+impl TaskbarProgress {
+    /// Returns the progress of the task, or `None` if it is [`Indeterminate`](Self::Indeterminate).
+    pub fn progress(&self) -> Option<f32> {
+        match *self {
+            Self::Indeterminate => None,
+            Self::Normal(progress) | Self::Paused(progress) | Self::Error(progress) => {
+                Some(progress.clamp(0.0, 1.0))
+            }
+        }
+    }
+}
@@ -163,6 +163,10 @@ impl ContainsEntity for NormalizedWindowRef {
 #[require(CursorOptions)]
 pub struct Window {
     /// What presentation mode to give the window.
+    ///
+    /// It can be changed at runtime, for example to toggle vsync. The renderer sends a
+    /// [`WindowPresentModeChanged`](crate::WindowPresentModeChanged) message with the mode
+    /// actually used once the window is reconfigured.
     pub present_mode: PresentMode,
     /// Which fullscreen or windowing mode should be used.
     pub mode: WindowMode,
@@ -275,7 +279,9 @@ pub struct Window {
     ///
     ///  ## Platform-specific
     ///
-    /// - iOS / Android / Web: Unsupported.
+    /// - Android: Shows the on-screen keyboard when enabled, and hides it when disabled.
+    ///   [`Ime`](crate::Ime) events are not sent.
+    /// - iOS / Web: Unsupported.
     pub ime_enabled: bool,
     /// Sets location of IME candidate box in client area coordinates relative to the top left.
     ///
@@ -324,6 +330,8 @@ pub struct Window {
     /// Optional hint given to the rendering API regarding the maximum number of queued frames admissible on the GPU.
     ///
     /// Given values are usually within the 1-3 range. If not provided, this will default to 2.
+    /// Lower values reduce the input latency, at the cost of the framerate when the CPU or the
+    /// GPU can't keep up. It can be changed at runtime.
     ///
     /// See [`wgpu::SurfaceConfiguration::desired_maximum_frame_latency`].
     ///
@@ -532,6 +540,12 @@ impl Window {
         self.internal.maximize_request = Some(maximized);
     }
 
+    /// Calling this will attempt to maximize the window if it isn't maximized, or to un-maximize
+    /// it otherwise.
+    pub fn toggle_maximized(&mut self) {
+        self.internal.toggle_maximize_request = true;
+    }
+
     /// Setting to true will attempt to minimize the window.
     ///
     /// Setting to false will attempt to un-minimize the window.
@@ -1108,6 +1122,8 @@ pub struct InternalWindowState {
     minimize_request: Option<bool>,
     /// If this is true then next frame we will ask to maximize/un-maximize the window depending on `maximized`.
     maximize_request: Option<bool>,
+    /// If this is true then next frame we will ask to maximize the window if it isn't maximized, or to un-maximize it otherwise.
+    toggle_maximize_request: bool,
     /// If this is true then next frame we will ask to drag-move the window.
     drag_move_request: bool,
     /// If this is `Some` then the next frame we will ask to drag-resize the window.
@@ -1122,6 +1138,11 @@ impl InternalWindowState {
         self.maximize_request.take()
     }
 
+    /// Consumes the current maximize toggle request, if it exists. This should only be called by window backends.
+    pub fn take_toggle_maximize_request(&mut self) -> bool {
+        core::mem::take(&mut self.toggle_maximize_request)
+    }
+
     /// Consumes the current minimize request, if it exists. This should only be called by window backends.
     pub fn take_minimize_request(&mut self) -> Option<bool> {
         self.minimize_request.take()
@@ -8,7 +8,7 @@ use bevy_input::{
 };
 use bevy_math::{CompassOctant, Vec2};
 use bevy_window::SystemCursorIcon;
-use bevy_window::{EnabledButtons, WindowLevel, WindowTheme};
+use bevy_window::{EnabledButtons, WindowAttention, WindowLevel, WindowTheme};
 use winit::keyboard::{Key, NamedKey, NativeKey};
 
 #[cfg(target_os = "ios")]
@@ -741,6 +741,14 @@ pub fn convert_resize_direction(resize_direction: CompassOctant) -> winit::windo
     }
 }
 
+/// Converts a Bevy [`WindowAttention`] to a [`winit::window::UserAttentionType`]
+pub fn convert_window_attention(attention: WindowAttention) -> winit::window::UserAttentionType {
+    match attention {
+        WindowAttention::Informational => winit::window::UserAttentionType::Informational,
+        WindowAttention::Critical => winit::window::UserAttentionType::Critical,
+    }
+}
+
 #[cfg(target_os = "ios")]
 /// Converts a [`bevy_window::ScreenEdge`] to a [`winit::platform::ios::ScreenEdge`].
 pub(crate) fn convert_screen_edge(edge: ScreenEdge) -> winit::platform::ios::ScreenEdge {
@@ -46,6 +46,7 @@ pub mod converters;
 mod cursor;
 mod state;
 mod system;
+mod taskbar;
 mod winit_config;
 mod winit_monitors;
 mod winit_windows;
@@ -167,6 +168,7 @@ impl Plugin for WinitPlugin {
 
         app.add_plugins(AccessKitPlugin);
         app.add_plugins(cursor::WinitCursorPlugin);
+        app.add_plugins(taskbar::WinitTaskbarPlugin);
 
         app.add_observer(
             |_window: On<Add, Window>, event_loop_proxy: Res<EventLoopProxyWrapper>| -> Result {
@@ -10,6 +10,7 @@ use bevy_ecs::{
 };
 use bevy_input::{
     gestures::*,
+    keyboard::{KeyRepeat, TextInputEvent},
     mouse::{MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
 };
 use bevy_log::{trace, warn};
@@ -29,8 +30,8 @@ use winit::{
 };
 
 use bevy_window::{
-    AppLifecycle, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, Ime, RequestRedraw,
-    Window, WindowBackendScaleFactorChanged, WindowCloseRequested, WindowDestroyed,
+    AppLifecycle, CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, Ime, MemoryWarning,
+    RequestRedraw, Window, WindowBackendScaleFactorChanged, WindowCloseRequested, WindowDestroyed,
     WindowEvent as BevyWindowEvent, WindowFocused, WindowMoved, WindowOccluded, WindowResized,
     WindowScaleFactorChanged, WindowThemeChanged,
 };
@@ -279,7 +280,15 @@ impl ApplicationHandler<WinitUserEvent> for WinitAppRunnerState {
                         } else {
                             pressed_keys.0.remove(&keyboard_input.key_code);
                         }
+                        let key_repeat = KeyRepeat::from_keyboard_input(&keyboard_input);
+                        let text_input = TextInputEvent::from_keyboard_input(&keyboard_input);
                         self.bevy_window_events.send(keyboard_input);
+                        if let Some(key_repeat) = key_repeat {
+                            self.bevy_window_events.send(key_repeat);
+                        }
+                        if let Some(text_input) = text_input {
+                            self.bevy_window_events.send(text_input);
+                        }
                     }
                     WindowEvent::CursorMoved { position, .. } => {
                         let physical_position = DVec2::new(position.x, position.y);
@@ -393,6 +402,13 @@ impl ApplicationHandler<WinitUserEvent> for WinitAppRunnerState {
                             });
                         }
                         event::Ime::Commit(value) => {
+                            if !value.is_empty() {
+                                self.bevy_window_events.send(TextInputEvent {
+                                    text: value.as_str().into(),
+                                    repeat: false,
+                                    window,
+                                });
+                            }
                             self.bevy_window_events.send(Ime::Commit { window, value });
                         }
                         event::Ime::Enabled => {
@@ -499,6 +515,13 @@ impl ApplicationHandler<WinitUserEvent> for WinitAppRunnerState {
         self.lifecycle = AppLifecycle::WillSuspend;
     }
 
+    fn memory_warning(&mut self, _event_loop: &ActiveEventLoop) {
+        // Forward the warning on the next update so that the app gets a chance to free
+        // caches before the OS decides to kill it.
+        self.bevy_window_events.send(MemoryWarning);
+        self.redraw_requested = true;
+    }
+
     fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
         // Drop windows while event loop is still active, before TLS destruction.
         // Prevents panic on macOS when exiting from exclusive fullscreen.
@@ -837,6 +860,9 @@ impl WinitAppRunnerState {
                 BevyWindowEvent::WindowThemeChanged(e) => {
                     world.write_message(e);
                 }
+                BevyWindowEvent::MemoryWarning(e) => {
+                    world.write_message(e);
+                }
                 BevyWindowEvent::MouseButtonInput(e) => {
                     world.write_message(e);
                 }
@@ -864,6 +890,12 @@ impl WinitAppRunnerState {
                 BevyWindowEvent::KeyboardInput(e) => {
                     world.write_message(e);
                 }
+                BevyWindowEvent::KeyRepeat(e) => {
+                    world.write_message(e);
+                }
+                BevyWindowEvent::TextInputEvent(e) => {
+                    world.write_message(e);
+                }
                 BevyWindowEvent::KeyboardFocusLost(e) => {
                     world.write_message(e);
                 }
@@ -484,6 +484,10 @@ pub(crate) fn changed_windows(
                 winit_window.set_maximized(maximized);
             }
 
+            if window.internal.take_toggle_maximize_request() {
+                winit_window.set_maximized(!winit_window.is_maximized());
+            }
+
             if let Some(minimized) = window.internal.take_minimize_request() {
                 winit_window.set_minimized(minimized);
             }
@@ -524,6 +528,16 @@ pub(crate) fn changed_windows(
 
             if window.ime_enabled != cache.ime_enabled {
                 winit_window.set_ime_allowed(window.ime_enabled);
+
+                // Winit doesn't show the on-screen keyboard on Android when IME is allowed.
+                #[cfg(target_os = "android")]
+                if let Some(android_app) = bevy_android::ANDROID_APP.get() {
+                    if window.ime_enabled {
+                        android_app.show_soft_input(true);
+                    } else {
+                        android_app.hide_soft_input(false);
+                    }
+                }
             }
 
             if window.ime_position != cache.ime_position {
@@ -0,0 +1,117 @@
+//! Applies the icon, attention requests and progress of the windows in the taskbar or dock.
+
+use bevy_app::{App, Last, Plugin};
+#[cfg(feature = "window_icon")]
+use bevy_asset::Assets;
+use bevy_ecs::{entity::EntityHashSet, prelude::*, system::NonSendMarker};
+#[cfg(feature = "window_icon")]
+use bevy_image::Image;
+#[cfg(feature = "window_icon")]
+use bevy_window::WindowIcon;
+use bevy_window::{TaskbarProgress, Window, WindowAttention, WindowFocused};
+#[cfg(feature = "window_icon")]
+use wgpu_types::TextureFormat;
+
+use crate::{converters::convert_window_attention, WINIT_WINDOWS};
+
+/// Adds support for window icons, attention requests and taskbar progress.
+pub(crate) struct WinitTaskbarPlugin;
+
+impl Plugin for WinitTaskbarPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "window_icon")]
+        app.add_systems(Last, update_window_icons);
+
+        app.add_systems(Last, (update_window_attention, warn_unsupported_progress));
+    }
+}
+
+#[cfg(feature = "window_icon")]
+fn update_window_icons(
+    windows: Query<(Entity, Ref<WindowIcon>), With<Window>>,
+    mut removed_icons: RemovedComponents<WindowIcon>,
+    images: Res<Assets<Image>>,
+    mut queue: Local<EntityHashSet>,
+    _non_send_marker: NonSendMarker,
+) {
+    WINIT_WINDOWS.with_borrow(|winit_windows| {
+        for entity in removed_icons.read() {
+            if let Some(winit_window) = winit_windows.get_window(entity) {
+                winit_window.set_window_icon(None);
+            }
+        }
+
+        for (entity, icon) in &windows {
+            if !(queue.remove(&entity) || icon.is_changed()) {
+                continue;
+            }
+            // The window may not be created yet, and the image not loaded yet.
+            let (Some(winit_window), Some(image)) =
+                (winit_windows.get_window(entity), images.get(&icon.0))
+            else {
+                queue.insert(entity);
+                continue;
+            };
+
+            let rgba = match image.texture_descriptor.format {
+                TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => image.data.clone(),
+                _ => image
+                    .convert(TextureFormat::Rgba8UnormSrgb)
+                    .and_then(|image| image.data),
+            };
+            let Some(rgba) = rgba else {
+                tracing::warn!(
+                    "Window icon {:?} not accepted because it can't be converted to rgba8",
+                    icon.0
+                );
+                continue;
+            };
+            let size = image.size();
+            match winit::window::Icon::from_rgba(rgba, size.x, size.y) {
+                Ok(winit_icon) => winit_window.set_window_icon(Some(winit_icon)),
+                Err(err) => tracing::warn!("Window icon {:?} is invalid: {err}", icon.0),
+            }
+        }
+    });
+}
+
+fn update_window_attention(
+    mut commands: Commands,
+    windows: Query<(Entity, Ref<WindowAttention>), With<Window>>,
+    mut removed_attention: RemovedComponents<WindowAttention>,
+    mut focused: MessageReader<WindowFocused>,
+    mut queue: Local<EntityHashSet>,
+    _non_send_marker: NonSendMarker,
+) {
+    // The platforms stop requesting attention once the window is focused.
+    for event in focused.read().filter(|event| event.focused) {
+        commands
+            .entity(event.window)
+            .try_remove::<WindowAttention>();
+    }
+
+    WINIT_WINDOWS.with_borrow(|winit_windows| {
+        for entity in removed_attention.read() {
+            if let Some(winit_window) = winit_windows.get_window(entity) {
+                winit_window.request_user_attention(None);
+            }
+        }
+
+        for (entity, attention) in &windows {
+            if !(queue.remove(&entity) || attention.is_changed()) {
+                continue;
+            }
+            let Some(winit_window) = winit_windows.get_window(entity) else {
+                queue.insert(entity);
+                continue;
+            };
+            winit_window.request_user_attention(Some(convert_window_attention(*attention)));
+        }
+    });
+}
+
+fn warn_unsupported_progress(progress: Query<(), (Added<TaskbarProgress>, With<Window>)>) {
+    if !progress.is_empty() {
+        bevy_log::warn_once!("TaskbarProgress is not supported by winit and is ignored");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{DynamicWorldBuilder, WorldAsset, WorldInstanceSpawnError};
-use bevy_asset::Asset;
+use bevy_asset::{Asset, AssetId, Assets, Handle};
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
     entity::{Entity, EntityHashMap, SceneEntityMapper},
@@ -21,12 +21,44 @@ use {crate::serde::DynamicWorldSerializer, serde::Serialize};
 /// * [`WorldInstanceSpawner::spawn_dynamic`](crate::WorldInstanceSpawner::spawn_dynamic)
 /// * adding the [`DynamicWorldRoot`](crate::components::DynamicWorldRoot) component to an entity.
 /// * using the [`DynamicWorldBuilder`] to construct a `DynamicWorld` from `World`.
+///
+/// # Inheritance
+///
+/// A dynamic world can inherit from a [`base`](Self::base) dynamic world, for example to make
+/// variants of a prefab without duplicating it. When spawned, the base is written first, then the
+/// entities and resources of the variant are applied on top of it as overrides:
+/// * the entities of the variant with the identifier of an entity of the base override the
+///   components of that entity, and the other entities are added,
+/// * the [`removed_entities`](Self::removed_entities) and
+///   [`removed_components`](Self::removed_components) of the base are removed.
+///
+/// Overriding components which implement [`FromReflect`](bevy_reflect::FromReflect) may only
+/// declare the fields which change. Use [`DynamicWorld::diff`] to author the overrides from a base
+/// and an edited copy of it.
 #[derive(Asset, TypePath, Default)]
 pub struct DynamicWorld {
     /// Resources stored in the dynamic world.
     pub resources: Vec<Box<dyn PartialReflect>>,
     /// Entities contained in the dynamic world.
     pub entities: Vec<DynamicEntity>,
+    /// The dynamic world this world inherits from, if any.
+    #[dependency]
+    pub base: Option<Handle<DynamicWorld>>,
+    /// The entities of the base removed from this world.
+    pub removed_entities: Vec<Entity>,
+    /// The components of the entities of the base removed from this world.
+    pub removed_components: Vec<RemovedComponents>,
+}
+
+/// The components of an entity of the [`base`](DynamicWorld::base) of a [`DynamicWorld`] removed
+/// from the dynamic world.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemovedComponents {
+    /// The identifier of the entity in the base.
+    pub entity: Entity,
+    /// The type paths of the removed components.
+    pub type_paths: Vec<String>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -84,8 +116,64 @@ impl DynamicWorld {
             .build()
     }
 
+    /// Returns whether this dynamic world inherits from a base, or removes entities or components.
+    pub fn has_inheritance(&self) -> bool {
+        self.base.is_some()
+            || !self.removed_entities.is_empty()
+            || !self.removed_components.is_empty()
+    }
+
+    /// Returns the chain of dynamic worlds this world inherits from, starting from the root base and
+    /// ending with this world.
+    ///
+    /// Returns a [`WorldInstanceSpawnError::NonExistentDynamicWorld`] if a base isn't loaded yet,
+    /// and a [`WorldInstanceSpawnError::CyclicBase`] if a world inherits from itself.
+    pub fn inheritance_chain<'a>(
+        &'a self,
+        dynamic_worlds: &'a Assets<DynamicWorld>,
+    ) -> Result<Vec<&'a DynamicWorld>, WorldInstanceSpawnError> {
+        let mut chain = vec![self];
+        let mut visited: Vec<AssetId<DynamicWorld>> = Vec::new();
+        let mut current = self;
+        while let Some(base) = &current.base {
+            let id = base.id();
+            if visited.contains(&id) {
+                return Err(WorldInstanceSpawnError::CyclicBase { id });
+            }
+            visited.push(id);
+            current = dynamic_worlds
+                .get(id)
+                .ok_or(WorldInstanceSpawnError::NonExistentDynamicWorld { id })?;
+            chain.push(current);
+        }
+        chain.reverse();
+        Ok(chain)
+    }
+
+    /// Write this dynamic world and the worlds it inherits from to the given world.
+    ///
+    /// See [`Self::inheritance_chain`] and [`Self::write_to_world_with`] for the errors this method
+    /// may return.
+    pub fn write_inherited_to_world_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+        dynamic_worlds: &Assets<DynamicWorld>,
+    ) -> Result<(), WorldInstanceSpawnError> {
+        for dynamic_world in self.inheritance_chain(dynamic_worlds)? {
+            dynamic_world.write_to_world_with(world, entity_map, type_registry)?;
+        }
+        Ok(())
+    }
+
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
+    /// The [`removed_entities`](Self::removed_entities) and
+    /// [`removed_components`](Self::removed_components) are then removed from the entities of the
+    /// `entity_map`. The [`base`](Self::base) isn't written, use
+    /// [`Self::write_inherited_to_world_with`] to write it.
+    ///
     /// This method will return a [`WorldInstanceSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
@@ -200,6 +288,48 @@ impl DynamicWorld {
             });
         }
 
+        self.remove_from_world(world, entity_map, type_registry)
+    }
+
+    /// Removes the [`removed_components`](Self::removed_components) and
+    /// [`removed_entities`](Self::removed_entities) of the base from the world.
+    fn remove_from_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), WorldInstanceSpawnError> {
+        for removed in &self.removed_components {
+            let Some(&entity) = entity_map.get(&removed.entity) else {
+                continue;
+            };
+            for type_path in &removed.type_paths {
+                let registration =
+                    type_registry.get_with_type_path(type_path).ok_or_else(|| {
+                        WorldInstanceSpawnError::UnregisteredButReflectedType {
+                            type_path: type_path.clone(),
+                        }
+                    })?;
+                let reflect_component =
+                    registration.data::<ReflectComponent>().ok_or_else(|| {
+                        WorldInstanceSpawnError::UnregisteredComponent {
+                            type_path: type_path.clone(),
+                        }
+                    })?;
+                if let Ok(mut entity) = world.get_entity_mut(entity) {
+                    reflect_component.remove(&mut entity);
+                }
+            }
+        }
+
+        for removed in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(removed)
+                && let Ok(entity) = world.get_entity_mut(entity)
+            {
+                entity.despawn();
+            }
+        }
+
         Ok(())
     }
 
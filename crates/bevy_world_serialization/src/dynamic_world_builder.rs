@@ -233,6 +233,7 @@ impl<'w> DynamicWorldBuilder<'w> {
         DynamicWorld {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_entities.into_values().collect(),
+            ..default()
         }
     }
 
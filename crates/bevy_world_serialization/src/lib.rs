@@ -19,6 +19,7 @@ mod reflect_utils;
 mod world_asset;
 mod world_asset_loader;
 mod world_asset_spawner;
+mod world_diff;
 mod world_filter;
 
 #[cfg(feature = "serialize")]
@@ -1,8 +1,9 @@
 //! `serde` serialization and deserialization implementation for Bevy worlds.
 
-use crate::{DynamicEntity, DynamicWorld};
+use crate::{DynamicEntity, DynamicWorld, RemovedComponents};
 use bevy_asset::{
-    EphemeralHandleBehavior, HandleDeserializeProcessor, HandleSerializeProcessor, LoadFromPath,
+    EphemeralHandleBehavior, Handle, HandleDeserializeProcessor, HandleReference,
+    HandleSerializeProcessor, LoadFromPath, UntypedHandle,
 };
 use bevy_ecs::entity::Entity;
 use bevy_platform::collections::HashSet;
@@ -13,10 +14,10 @@ use bevy_reflect::{
     },
     PartialReflect, ReflectFromReflect, TypeRegistry,
 };
-use core::fmt::Formatter;
+use core::{any::TypeId, fmt::Formatter};
 use serde::{
     de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
-    ser::{SerializeMap, SerializeStruct},
+    ser::{Error as _, SerializeMap, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
@@ -26,6 +27,12 @@ pub const WORLD_STRUCT: &str = "World";
 pub const WORLD_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a world struct.
 pub const WORLD_ENTITIES: &str = "entities";
+/// Name of the serialized base field in a world struct.
+pub const WORLD_BASE: &str = "base";
+/// Name of the serialized removed entities field in a world struct.
+pub const WORLD_REMOVED_ENTITIES: &str = "removed_entities";
+/// Name of the serialized removed components field in a world struct.
+pub const WORLD_REMOVED_COMPONENTS: &str = "removed_components";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
@@ -57,6 +64,10 @@ pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 /// // Serialize through any serde-compatible Serializer
 /// let ron_string = ron::ser::to_string(&serializer);
 /// ```
+///
+/// The [`base`](DynamicWorld::base), [`removed_entities`](DynamicWorld::removed_entities) and
+/// [`removed_components`](DynamicWorld::removed_components) of the dynamic world are only
+/// serialized when they are set, which is only supported by human-readable formats such as RON.
 pub struct DynamicWorldSerializer<'a> {
     /// The dynamic world to serialize.
     pub world: &'a DynamicWorld,
@@ -81,7 +92,32 @@ impl<'a> Serialize for DynamicWorldSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(WORLD_STRUCT, 2)?;
+        let world = self.world;
+        // The inheritance fields are optional, which formats deserializing structs as sequences
+        // don't support.
+        if world.has_inheritance() && !serializer.is_human_readable() {
+            return Err(S::Error::custom(
+                "dynamic world inheritance can only be serialized to human-readable formats",
+            ));
+        }
+        let base = world
+            .base
+            .as_ref()
+            .map(|base| {
+                base_reference(base).ok_or_else(|| {
+                    S::Error::custom("the base of a dynamic world must have an asset path or uuid")
+                })
+            })
+            .transpose()?;
+        let len = 2
+            + usize::from(base.is_some())
+            + usize::from(!world.removed_entities.is_empty())
+            + usize::from(!world.removed_components.is_empty());
+
+        let mut state = serializer.serialize_struct(WORLD_STRUCT, len)?;
+        if let Some(base) = &base {
+            state.serialize_field(WORLD_BASE, base)?;
+        }
         state.serialize_field(
             WORLD_RESOURCES,
             &WorldMapSerializer {
@@ -96,10 +132,27 @@ impl<'a> Serialize for DynamicWorldSerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if !world.removed_entities.is_empty() {
+            state.serialize_field(WORLD_REMOVED_ENTITIES, &world.removed_entities)?;
+        }
+        if !world.removed_components.is_empty() {
+            state.serialize_field(WORLD_REMOVED_COMPONENTS, &world.removed_components)?;
+        }
         state.end()
     }
 }
 
+/// Returns the stable reference to the base of a dynamic world, if it has one.
+fn base_reference(base: &Handle<DynamicWorld>) -> Option<HandleReference> {
+    if let Some(path) = base.path() {
+        return Some(HandleReference::Path(path.clone_owned()));
+    }
+    match base {
+        Handle::Uuid(uuid, _) => Some(HandleReference::Uuid(*uuid)),
+        Handle::Strong(_) => None,
+    }
+}
+
 /// Handles serialization of multiple entities as a map of entity id to serialized entity.
 pub struct EntitiesSerializer<'a> {
     /// The entities to serialize.
@@ -209,6 +262,11 @@ impl<'a> Serialize for WorldMapSerializer<'a> {
 enum WorldField {
     Resources,
     Entities,
+    Base,
+    #[serde(rename = "removed_entities")]
+    RemovedEntities,
+    #[serde(rename = "removed_components")]
+    RemovedComponents,
 }
 
 #[derive(Deserialize)]
@@ -276,6 +334,7 @@ impl<'a, 'de> Visitor<'de> for WorldVisitor<'a> {
         Ok(DynamicWorld {
             resources,
             entities,
+            ..Default::default()
         })
     }
 
@@ -285,6 +344,9 @@ impl<'a, 'de> Visitor<'de> for WorldVisitor<'a> {
     {
         let mut resources = None;
         let mut entities = None;
+        let mut base = None;
+        let mut removed_entities = None;
+        let mut removed_components = None;
         while let Some(key) = map.next_key()? {
             match key {
                 WorldField::Resources => {
@@ -305,6 +367,31 @@ impl<'a, 'de> Visitor<'de> for WorldVisitor<'a> {
                         load_from_path: self.load_from_path,
                     })?);
                 }
+                WorldField::Base => {
+                    if base.is_some() {
+                        return Err(Error::duplicate_field(WORLD_BASE));
+                    }
+                    let type_id = TypeId::of::<DynamicWorld>();
+                    let handle = match map.next_value::<HandleReference>()? {
+                        HandleReference::Path(path) => {
+                            self.load_from_path.load_from_path_erased(type_id, path)
+                        }
+                        HandleReference::Uuid(uuid) => UntypedHandle::Uuid { type_id, uuid },
+                    };
+                    base = Some(handle.typed::<DynamicWorld>());
+                }
+                WorldField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(WORLD_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value::<Vec<Entity>>()?);
+                }
+                WorldField::RemovedComponents => {
+                    if removed_components.is_some() {
+                        return Err(Error::duplicate_field(WORLD_REMOVED_COMPONENTS));
+                    }
+                    removed_components = Some(map.next_value::<Vec<RemovedComponents>>()?);
+                }
             }
         }
 
@@ -314,6 +401,9 @@ impl<'a, 'de> Visitor<'de> for WorldVisitor<'a> {
         Ok(DynamicWorld {
             resources,
             entities,
+            base,
+            removed_entities: removed_entities.unwrap_or_default(),
+            removed_components: removed_components.unwrap_or_default(),
         })
     }
 }
@@ -900,6 +990,93 @@ mod tests {
         );
     }
 
+    /// A handle creator loading dynamic worlds by path, for the purposes of testing inheritance.
+    #[derive(Default)]
+    struct BaseHandleCreator(Vec<AssetPath<'static>>);
+
+    impl LoadFromPath for BaseHandleCreator {
+        fn load_from_path_erased(
+            &mut self,
+            type_id: TypeId,
+            path: AssetPath<'static>,
+        ) -> UntypedHandle {
+            assert_eq!(type_id, TypeId::of::<DynamicWorld>());
+            self.0.push(path);
+            Handle::<DynamicWorld>::from(Uuid::from_u128(2)).untyped()
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_with_base() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let input = r#"(
+  base: Path("prefabs/knight.scn.ron"),
+  resources: {},
+  entities: {
+    4294967293: (
+      components: {
+        "bevy_world_serialization::serde::tests::Foo": (123),
+      },
+    ),
+  },
+  removed_entities: [4294967292],
+  removed_components: [
+    (
+      entity: 4294967293,
+      type_paths: ["bevy_world_serialization::serde::tests::Bar"],
+    ),
+  ],
+)"#;
+        let mut load_from_path = BaseHandleCreator::default();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_world = WorldDeserializer {
+            type_registry: &registry,
+            load_from_path: &mut load_from_path,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        assert_eq!(
+            load_from_path.0,
+            [AssetPath::from("prefabs/knight.scn.ron")]
+        );
+        assert_eq!(
+            dynamic_world.base,
+            Some(Handle::<DynamicWorld>::from(Uuid::from_u128(2)))
+        );
+        assert_eq!(dynamic_world.entities.len(), 1);
+        assert_eq!(dynamic_world.removed_entities.len(), 1);
+        assert_eq!(dynamic_world.removed_components[0].type_paths.len(), 1);
+
+        let serialized = dynamic_world.serialize(&registry).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized_world = WorldDeserializer {
+            type_registry: &registry,
+            load_from_path: &mut FakeHandleCreator,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        assert_eq!(deserialized_world.base, dynamic_world.base);
+        assert_eq!(
+            deserialized_world.removed_entities,
+            dynamic_world.removed_entities
+        );
+        assert_eq!(
+            deserialized_world.removed_components,
+            dynamic_world.removed_components
+        );
+        assert_world_eq(&dynamic_world, &deserialized_world);
+
+        let mut buf = Vec::new();
+        let mut serializer = rmp_serde::Serializer::new(&mut buf);
+        assert!(DynamicWorldSerializer::new(&dynamic_world, &registry)
+            .serialize(&mut serializer)
+            .is_err());
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
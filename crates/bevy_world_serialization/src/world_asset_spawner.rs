@@ -157,6 +157,12 @@ pub enum WorldInstanceSpawnError {
         /// Id of the non-existent world asset.
         id: AssetId<WorldAsset>,
     },
+    /// Dynamic world with the given id inherits from itself.
+    #[error("Dynamic world inherits from itself")]
+    CyclicBase {
+        /// Id of the dynamic world inheriting from itself.
+        id: AssetId<DynamicWorld>,
+    },
 }
 
 impl WorldInstanceSpawner {
@@ -304,7 +310,14 @@ impl WorldInstanceSpawner {
                 .get(id)
                 .ok_or(WorldInstanceSpawnError::NonExistentDynamicWorld { id })?;
 
-            dynamic_world.write_to_world(world, entity_map)
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let type_registry = type_registry.read();
+            dynamic_world.write_inherited_to_world_with(
+                world,
+                entity_map,
+                &type_registry,
+                &dynamic_worlds,
+            )
         })
     }
 
@@ -0,0 +1,252 @@
+use core::any::TypeId;
+
+use crate::reflect_utils::clone_reflect_value;
+use crate::{DynamicEntity, DynamicWorld, RemovedComponents};
+use bevy_ecs::world::World;
+use bevy_reflect::{PartialReflect, ReflectCloneError, TypeInfo, TypeRegistry};
+
+impl DynamicWorld {
+    /// Computes the overrides turning the `base` dynamic world into the `variant` dynamic world.
+    ///
+    /// Entities are matched by their identifier, so the `variant` is usually an edited copy of the
+    /// `base`. The returned dynamic world contains:
+    /// * the components of the `variant` which are missing from or differ from the `base`, and the
+    ///   entities missing from the `base`,
+    /// * the resources of the `variant` which are missing from or differ from the `base`,
+    /// * the components and entities of the `base` missing from the `variant`, as
+    ///   [`removed_components`](Self::removed_components) and
+    ///   [`removed_entities`](Self::removed_entities).
+    ///
+    /// Values are compared with [`PartialReflect::reflect_partial_eq`], and values which can't be
+    /// compared are considered different.
+    ///
+    /// The [`base`](Self::base) of the returned dynamic world isn't set: set it to the handle of
+    /// the `base` before saving or spawning the overrides.
+    ///
+    /// Returns an error if a value of the `variant` of a type missing from the `type_registry`
+    /// can't be cloned.
+    pub fn diff(
+        base: &DynamicWorld,
+        variant: &DynamicWorld,
+        type_registry: &TypeRegistry,
+    ) -> Result<Self, ReflectCloneError> {
+        let mut overrides = DynamicWorld::default();
+
+        for variant_entity in &variant.entities {
+            let Some(base_entity) = base
+                .entities
+                .iter()
+                .find(|base_entity| base_entity.entity == variant_entity.entity)
+            else {
+                overrides.entities.push(DynamicEntity {
+                    entity: variant_entity.entity,
+                    components: variant_entity
+                        .components
+                        .iter()
+                        .map(|component| clone_value(component.as_ref(), type_registry))
+                        .collect::<Result<_, _>>()?,
+                });
+                continue;
+            };
+
+            let components: Vec<_> =
+                changed_values(&base_entity.components, &variant_entity.components)
+                    .map(|component| clone_value(component, type_registry))
+                    .collect::<Result<_, _>>()?;
+            if !components.is_empty() {
+                overrides.entities.push(DynamicEntity {
+                    entity: variant_entity.entity,
+                    components,
+                });
+            }
+
+            let type_paths: Vec<_> = base_entity
+                .components
+                .iter()
+                .filter(|component| {
+                    find_value(&variant_entity.components, component.as_ref()).is_none()
+                })
+                .map(|component| type_path(component.as_ref()))
+                .collect();
+            if !type_paths.is_empty() {
+                overrides.removed_components.push(RemovedComponents {
+                    entity: variant_entity.entity,
+                    type_paths,
+                });
+            }
+        }
+
+        overrides.removed_entities = base
+            .entities
+            .iter()
+            .map(|base_entity| base_entity.entity)
+            .filter(|entity| {
+                !variant
+                    .entities
+                    .iter()
+                    .any(|variant_entity| variant_entity.entity == *entity)
+            })
+            .collect();
+
+        overrides.resources = changed_values(&base.resources, &variant.resources)
+            .map(|resource| clone_value(resource, type_registry))
+            .collect::<Result<_, _>>()?;
+
+        Ok(overrides)
+    }
+
+    /// Computes the overrides turning the `base` world into the `variant` world.
+    ///
+    /// This extracts both worlds with [`DynamicWorld::from_world_with`], then calls
+    /// [`DynamicWorld::diff`].
+    pub fn diff_worlds(
+        base: &World,
+        variant: &World,
+        type_registry: &TypeRegistry,
+    ) -> Result<Self, ReflectCloneError> {
+        Self::diff(
+            &DynamicWorld::from_world_with(base, type_registry),
+            &DynamicWorld::from_world_with(variant, type_registry),
+            type_registry,
+        )
+    }
+}
+
+/// Returns the type identifier of the type represented by a value, if any.
+fn represented_type_id(value: &dyn PartialReflect) -> Option<TypeId> {
+    value.get_represented_type_info().map(TypeInfo::type_id)
+}
+
+/// Returns the type path of the type represented by a value.
+fn type_path(value: &dyn PartialReflect) -> String {
+    value
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| value.reflect_type_path())
+        .to_string()
+}
+
+/// Finds the value representing the same type as `value` in `values`.
+fn find_value<'a>(
+    values: &'a [Box<dyn PartialReflect>],
+    value: &dyn PartialReflect,
+) -> Option<&'a dyn PartialReflect> {
+    let type_id = represented_type_id(value);
+    let type_path = type_path(value);
+    values
+        .iter()
+        .map(AsRef::as_ref)
+        .find(|other| match (type_id, represented_type_id(*other)) {
+            (Some(type_id), Some(other_type_id)) => type_id == other_type_id,
+            _ => type_path == self::type_path(*other),
+        })
+}
+
+/// Returns the values of `variant` which are missing from or differ from `base`.
+fn changed_values<'a>(
+    base: &'a [Box<dyn PartialReflect>],
+    variant: &'a [Box<dyn PartialReflect>],
+) -> impl Iterator<Item = &'a dyn PartialReflect> {
+    variant
+        .iter()
+        .map(AsRef::as_ref)
+        .filter(|value| match find_value(base, *value) {
+            Some(base_value) => base_value.reflect_partial_eq(*value) != Some(true),
+            None => true,
+        })
+}
+
+fn clone_value(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, ReflectCloneError> {
+    match represented_type_id(value).and_then(|type_id| type_registry.get(type_id)) {
+        Some(registration) => Ok(clone_reflect_value(value, registration)),
+        None => value.to_dynamic(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_asset::Assets;
+    use bevy_ecs::{
+        component::Component,
+        entity::EntityHashMap,
+        reflect::{AppTypeRegistry, ReflectComponent},
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    use crate::DynamicWorld;
+
+    #[derive(Component, Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Component, PartialEq)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Component, PartialEq)]
+    struct Armored;
+
+    #[test]
+    fn diff_spawns_variant_over_base() {
+        let app_type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = app_type_registry.write();
+            registry.register::<Health>();
+            registry.register::<Armored>();
+        }
+        let type_registry = app_type_registry.read();
+
+        let mut world = World::new();
+        let knight = world.spawn((Health(10), Armored)).id();
+        let squire = world.spawn(Health(5)).id();
+        let base = DynamicWorld::from_world_with(&world, &type_registry);
+
+        // The variant has a stronger knight without armor, no squire, and a horse.
+        world
+            .entity_mut(knight)
+            .insert(Health(20))
+            .remove::<Armored>();
+        world.despawn(squire);
+        let horse = world.spawn(Health(15)).id();
+        let variant = DynamicWorld::from_world_with(&world, &type_registry);
+
+        let mut overrides = DynamicWorld::diff(&base, &variant, &type_registry).unwrap();
+        assert_eq!(overrides.entities.len(), 2);
+        let knight_overrides = overrides
+            .entities
+            .iter()
+            .find(|entity| entity.entity == knight)
+            .unwrap();
+        assert_eq!(knight_overrides.components.len(), 1);
+        assert!(overrides
+            .entities
+            .iter()
+            .any(|entity| entity.entity == horse));
+        assert_eq!(overrides.removed_entities, [squire]);
+        assert_eq!(overrides.removed_components.len(), 1);
+        assert_eq!(overrides.removed_components[0].entity, knight);
+        assert!(overrides.removed_components[0].type_paths[0].ends_with("Armored"));
+
+        let mut dynamic_worlds = Assets::<DynamicWorld>::default();
+        overrides.base = Some(dynamic_worlds.add(base));
+
+        let mut spawned = World::new();
+        let mut entity_map = EntityHashMap::default();
+        overrides
+            .write_inherited_to_world_with(
+                &mut spawned,
+                &mut entity_map,
+                &type_registry,
+                &dynamic_worlds,
+            )
+            .unwrap();
+
+        assert_eq!(entity_map.len(), 2);
+        let spawned_knight = entity_map[&knight];
+        assert_eq!(spawned.get::<Health>(spawned_knight), Some(&Health(20)));
+        assert!(spawned.get::<Armored>(spawned_knight).is_none());
+        assert_eq!(spawned.get::<Health>(entity_map[&horse]), Some(&Health(15)));
+        assert_eq!(spawned.query::<&Health>().iter(&spawned).count(), 2);
+    }
+}
@@ -263,8 +263,12 @@ Bevy has a helper to build its examples:
 - Build for WebGL2: `cargo run -p build-wasm-example -- --api webgl2 load_gltf`
 - Build for WebGPU: `cargo run -p build-wasm-example -- --api webgpu load_gltf`
 - Debug: `cargo run -p build-wasm-example -- --debug --api webgl2 load_gltf`
+- Serve on <http://localhost:8000/>, rebuilding and reloading the page when the sources change: `cargo run -p build-wasm-example -- --api webgl2 --serve load_gltf`
+- Multithreaded, with a nightly toolchain and its `rust-src` component: `cargo run -p build-wasm-example -- --threads --serve load_gltf`. The build also writes an HTML shell and the cross-origin isolation headers it must be served with to `examples/wasm/target`
+- Compare screenshots taken at frames 100 and 200 against the references in `.github/start-wasm-example/references`, with the example served on <http://localhost:8000/>: `cargo run -p build-wasm-example -- --api webgl2 --test --screenshot-frames 100,200 load_gltf`. The screenshots, the diff images and a JUnit report are written to `wasm-screenshots`, and `--update-references` records new references. The frames are rendered a fixed `--screenshot-frame-time` apart, 1/60th of a second by default
+- Write an HTML shell with a loading progress bar to `examples/wasm/target/index.html`, and gzip and brotli compressed copies of the files to serve: `cargo run -p build-wasm-example -- --api webgl2 --loading-shell --compress load_gltf`
 
-This helper will log the command used to build the examples.
+This helper will log the command used to build the examples, and print the size of the wasm file, its bindings and the assets the example refers to. With `--analyze-size`, it also lists the functions taking the most space, using [twiggy](https://github.com/rustwasm/twiggy).
 
 #### Audio in the browsers
 
@@ -0,0 +1,75 @@
+//! Renders the world at a low resolution with a `PixelArtCamera`, upscaled by an integer factor to
+//! fit the window, while the UI keeps the native resolution of the window.
+//!
+//! The left sprite has a `PixelSnap`, so it moves from pixel to pixel, while the right one moves
+//! smoothly across pixels.
+
+use bevy::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_sprites, change_resolution))
+        .run();
+}
+
+#[derive(Component)]
+struct Bob;
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    commands.spawn(PixelArtCamera::new(160, 90));
+
+    let image = asset_server.load("pixel/bevy_pixel_dark.png");
+    commands.spawn((
+        Sprite::from_image(image.clone()),
+        Transform::from_xyz(-40.0, 0.0, 0.0),
+        PixelSnap::default(),
+        Bob,
+    ));
+    commands.spawn((
+        Sprite::from_image(image),
+        Transform::from_xyz(40.0, 0.0, 0.0),
+        Bob,
+    ));
+    commands.spawn((
+        Mesh2d(meshes.add(Circle::new(12.0))),
+        MeshMaterial2d(materials.add(Color::BLACK)),
+        Transform::from_xyz(0.0, -25.0, -1.0),
+    ));
+
+    commands.spawn((
+        Text::new("Space: change the resolution"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+}
+
+/// Moves the sprites up and down slowly, by fractions of a pixel each frame.
+fn move_sprites(time: Res<Time>, mut sprites: Query<&mut Transform, With<Bob>>) {
+    for mut transform in &mut sprites {
+        transform.translation.y = 10.0 * ops::sin(time.elapsed_secs());
+    }
+}
+
+fn change_resolution(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut pixel_art_camera: Single<&mut PixelArtCamera>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        pixel_art_camera.resolution = if pixel_art_camera.resolution.x == 160 {
+            UVec2::new(320, 180)
+        } else {
+            UVec2::new(160, 90)
+        };
+    }
+}
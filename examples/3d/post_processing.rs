@@ -5,13 +5,14 @@
 //! - Chromatic Aberration
 //! - Vignette
 //! - Lens Distortion
+//! - Film Grain
 
 use std::f32::consts::PI;
 
 use bevy::{
     camera::Hdr,
     light::CascadeShadowConfigBuilder,
-    post_process::effect_stack::{ChromaticAberration, LensDistortion, Vignette},
+    post_process::effect_stack::{ChromaticAberration, FilmGrain, LensDistortion, Vignette},
     prelude::*,
 };
 
@@ -45,6 +46,8 @@ struct AppSettings {
     lens_distortion_multiplier_x: f32,
     /// Distortion strength multiplier for the vertical direction.
     lens_distortion_multiplier_y: f32,
+    /// The intensity of the film grain effect.
+    film_grain_intensity: f32,
 }
 
 /// The entry point.
@@ -101,6 +104,8 @@ fn spawn_camera(commands: &mut Commands, asset_server: &AssetServer) {
         Vignette::default(),
         // Include the `LensDistortion` component.
         LensDistortion::default(),
+        // Include the `FilmGrain` component.
+        FilmGrain::default(),
     ));
 }
 
@@ -168,6 +173,7 @@ impl Default for AppSettings {
             lens_distortion_intensity: lens_distortion.intensity,
             lens_distortion_multiplier_x: lens_distortion.multiplier.x,
             lens_distortion_multiplier_y: lens_distortion.multiplier.y,
+            film_grain_intensity: FilmGrain::default().intensity,
         }
     }
 }
@@ -176,7 +182,7 @@ impl Default for AppSettings {
 fn handle_keyboard_input(mut app_settings: ResMut<AppSettings>, input: Res<ButtonInput<KeyCode>>) {
     if input.just_pressed(KeyCode::ArrowUp) && app_settings.selected > 0 {
         app_settings.selected -= 1;
-    } else if input.just_pressed(KeyCode::ArrowDown) && app_settings.selected < 8 {
+    } else if input.just_pressed(KeyCode::ArrowDown) && app_settings.selected < 9 {
         app_settings.selected += 1;
     }
 
@@ -223,6 +229,10 @@ fn handle_keyboard_input(mut app_settings: ResMut<AppSettings>, input: Res<Butto
             app_settings.lens_distortion_multiplier_y =
                 (app_settings.lens_distortion_multiplier_y + delta).clamp(0.0, 1.0);
         }
+        9 => {
+            app_settings.film_grain_intensity =
+                (app_settings.film_grain_intensity + delta).clamp(0.0, 1.0);
+        }
         _ => {}
     }
 }
@@ -232,6 +242,7 @@ fn update_chromatic_aberration_settings(
     mut chromatic_aberration: Query<&mut ChromaticAberration>,
     mut vignette: Query<&mut Vignette>,
     mut lens_distortion: Query<&mut LensDistortion>,
+    mut film_grain: Query<&mut FilmGrain>,
     app_settings: Res<AppSettings>,
 ) {
     let intensity = app_settings.chromatic_aberration_intensity;
@@ -263,6 +274,10 @@ fn update_chromatic_aberration_settings(
         lens_distortion.multiplier.x = app_settings.lens_distortion_multiplier_x;
         lens_distortion.multiplier.y = app_settings.lens_distortion_multiplier_y;
     }
+
+    for mut film_grain in &mut film_grain {
+        film_grain.intensity = app_settings.film_grain_intensity;
+    }
 }
 
 /// Updates the help text at the bottom of the screen to reflect the current
@@ -303,6 +318,10 @@ fn update_help_text(mut text: Single<&mut Text>, app_settings: Res<AppSettings>)
             "Lens Distortion multiplier y: {:.2}\n",
             app_settings.lens_distortion_multiplier_y
         ),
+        format!(
+            "Film grain intensity: {:.2}\n",
+            app_settings.film_grain_intensity
+        ),
     ];
     for (i, val) in text_list.iter().enumerate() {
         if i == app_settings.selected {
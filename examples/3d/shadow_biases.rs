@@ -2,7 +2,7 @@
 
 use bevy::{
     camera_controller::free_camera::{FreeCamera, FreeCameraPlugin},
-    light::ShadowFilteringMethod,
+    light::{ShadowCascadesDebug, ShadowFilteringMethod},
     prelude::*,
 };
 
@@ -19,6 +19,7 @@ fn main() {
                 adjust_point_light_biases,
                 toggle_light,
                 adjust_directional_light_biases,
+                toggle_cascades_debug,
             ),
         )
         .run();
@@ -133,6 +134,9 @@ fn setup(
                 (TextSpan(format!(" {:.1},", light_transform.translation.y))),
                 (TextSpan(format!(" {:.1}", light_transform.translation.z))),
                 (TextSpan::new("]\n")),
+                (TextSpan::new("C     - toggle shadow cascade debug colors [")),
+                (TextSpan::new("off")),
+                (TextSpan::new("]\n")),
             ]
         )],
     ));
@@ -299,3 +303,22 @@ fn adjust_directional_light_biases(
         *writer.text(*example_text, 19) = format!("{:.1}", light.shadow_normal_bias);
     }
 }
+
+fn toggle_cascades_debug(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    camera: Single<(Entity, Has<ShadowCascadesDebug>), With<Camera3d>>,
+    example_text: Single<Entity, With<Text>>,
+    mut writer: TextUiWriter,
+) {
+    if input.just_pressed(KeyCode::KeyC) {
+        let (camera, debug) = *camera;
+        if debug {
+            commands.entity(camera).remove::<ShadowCascadesDebug>();
+            *writer.text(*example_text, 27) = "off".to_string();
+        } else {
+            commands.entity(camera).insert(ShadowCascadesDebug);
+            *writer.text(*example_text, 27) = "on".to_string();
+        }
+    }
+}
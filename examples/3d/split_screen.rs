@@ -1,16 +1,36 @@
-//! Renders four cameras to the same window to accomplish "split screen".
+//! Renders several cameras to the same window to accomplish "split screen".
+//!
+//! Press `Space` to add a player and `Backspace` to remove the last one. Gamepads without a player
+//! join by pressing Start.
 
 use std::f32::consts::PI;
 
 use bevy::{
-    camera::Viewport, light::CascadeShadowConfigBuilder, prelude::*, window::WindowResized,
+    light::CascadeShadowConfigBuilder,
+    prelude::*,
+    ui::split_screen::{
+        PlayerInput, SplitScreenJoinRequest, SplitScreenPlayer, SplitScreenPlugin,
+        SplitScreenUiRootOf,
+    },
 };
 
+const CAMERA_POSITIONS: [Vec3; 4] = [
+    Vec3::new(0.0, 200.0, -150.0),
+    Vec3::new(150.0, 150., 50.0),
+    Vec3::new(100.0, 150., -150.0),
+    Vec3::new(-100.0, 80., 150.0),
+];
+
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
+        // Computes the viewports of the players, and spawns their UI roots
+        .add_plugins(SplitScreenPlugin)
         .add_systems(Startup, setup)
-        .add_systems(Update, (set_camera_viewports, button_system))
+        .add_systems(
+            Update,
+            (join_or_leave, setup_player_ui, button_system).chain(),
+        )
         .run();
 }
 
@@ -56,96 +76,115 @@ fn setup(
         .build(),
     ));
 
-    // Cameras and their dedicated UI
-    for (index, (camera_name, camera_pos)) in [
-        ("Player 1", Vec3::new(0.0, 200.0, -150.0)),
-        ("Player 2", Vec3::new(150.0, 150., 50.0)),
-        ("Player 3", Vec3::new(100.0, 150., -150.0)),
-        ("Player 4", Vec3::new(-100.0, 80., 150.0)),
-    ]
-    .iter()
-    .enumerate()
-    {
-        let camera = commands
-            .spawn((
-                Camera3d::default(),
-                Transform::from_translation(*camera_pos).looking_at(Vec3::ZERO, Vec3::Y),
-                Camera {
-                    // Renders cameras with different priorities to prevent ambiguities
-                    order: index as isize,
-                    ..default()
-                },
-                CameraPosition {
-                    pos: UVec2::new((index % 2) as u32, (index / 2) as u32),
-                },
-            ))
-            .id();
-
-        // Set up UI
-        commands.spawn((
-            UiTargetCamera(camera),
-            Node {
-                width: percent(100),
-                height: percent(100),
-                ..default()
-            },
-            children![
-                (
-                    Text::new(*camera_name),
-                    Node {
-                        position_type: PositionType::Absolute,
-                        top: px(12),
-                        left: px(12),
-                        ..default()
-                    },
-                ),
-                buttons_panel(),
-            ],
-        ));
+    // The first two players: the viewports are updated as players join and leave
+    commands.spawn(player(0, PlayerInput::KeyboardMouse));
+    commands.spawn(player(1, PlayerInput::Unassigned));
+}
+
+fn player(index: usize, input: PlayerInput) -> impl Bundle {
+    (
+        Camera3d::default(),
+        Transform::from_translation(CAMERA_POSITIONS[index]).looking_at(Vec3::ZERO, Vec3::Y),
+        Camera {
+            // Renders cameras with different priorities to prevent ambiguities
+            order: index as isize,
+            ..default()
+        },
+        SplitScreenPlayer { index, input },
+    )
+}
+
+fn join_or_leave(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut join_requests: MessageReader<SplitScreenJoinRequest>,
+    players: Query<(Entity, &SplitScreenPlayer)>,
+) {
+    let mut count = players.iter().count();
+    let mut join = |input| {
+        if count < CAMERA_POSITIONS.len() {
+            commands.spawn(player(count, input));
+            count += 1;
+        }
+    };
+    for request in join_requests.read() {
+        join(PlayerInput::Gamepad(request.gamepad));
+    }
+    if keys.just_pressed(KeyCode::Space) {
+        join(PlayerInput::KeyboardMouse);
     }
 
-    fn buttons_panel() -> impl Bundle {
-        (
-            Node {
-                position_type: PositionType::Absolute,
-                width: percent(100),
-                height: percent(100),
-                display: Display::Flex,
-                flex_direction: FlexDirection::Row,
-                justify_content: JustifyContent::SpaceBetween,
-                align_items: AlignItems::Center,
-                padding: UiRect::all(px(20)),
-                ..default()
-            },
-            children![
-                rotate_button("<", Direction::Left),
-                rotate_button(">", Direction::Right),
-            ],
-        )
+    if keys.just_pressed(KeyCode::Backspace) {
+        // Its UI root is despawned with it
+        if let Some((last, _)) = players.iter().max_by_key(|(_, player)| player.index) {
+            if count > 1 {
+                commands.entity(last).despawn();
+            }
+        }
     }
+}
 
-    fn rotate_button(caption: &str, direction: Direction) -> impl Bundle {
-        (
-            RotateCamera(direction),
-            Button,
-            Node {
-                width: px(40),
-                height: px(40),
-                border: UiRect::all(px(2)),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BorderColor::all(Color::WHITE),
-            BackgroundColor(Color::srgb(0.25, 0.25, 0.25)),
-            children![Text::new(caption)],
-        )
+/// Fills the UI roots spawned for the new players
+fn setup_player_ui(
+    mut commands: Commands,
+    ui_roots: Query<(Entity, &SplitScreenUiRootOf), Added<SplitScreenUiRootOf>>,
+    players: Query<&SplitScreenPlayer>,
+) {
+    for (ui_root, root_of) in &ui_roots {
+        let Ok(player) = players.get(root_of.0) else {
+            continue;
+        };
+        commands.entity(ui_root).insert(children![
+            (
+                Text::new(format!("Player {}", player.index + 1)),
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: px(12),
+                    left: px(12),
+                    ..default()
+                },
+            ),
+            buttons_panel(),
+        ]);
     }
 }
 
-#[derive(Component)]
-struct CameraPosition {
-    pos: UVec2,
+fn buttons_panel() -> impl Bundle {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(px(20)),
+            ..default()
+        },
+        children![
+            rotate_button("<", Direction::Left),
+            rotate_button(">", Direction::Right),
+        ],
+    )
+}
+
+fn rotate_button(caption: &str, direction: Direction) -> impl Bundle {
+    (
+        RotateCamera(direction),
+        Button,
+        Node {
+            width: px(40),
+            height: px(40),
+            border: UiRect::all(px(2)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BorderColor::all(Color::WHITE),
+        BackgroundColor(Color::srgb(0.25, 0.25, 0.25)),
+        children![Text::new(caption)],
+    )
 }
 
 #[derive(Component)]
@@ -156,28 +195,6 @@ enum Direction {
     Right,
 }
 
-fn set_camera_viewports(
-    windows: Query<&Window>,
-    mut window_resized_reader: MessageReader<WindowResized>,
-    mut query: Query<(&CameraPosition, &mut Camera)>,
-) {
-    // We need to dynamically resize the camera's viewports whenever the window size changes
-    // so then each camera always takes up half the screen.
-    // A resize_event is sent when the window is first created, allowing us to reuse this system for initial setup.
-    for window_resized in window_resized_reader.read() {
-        let window = windows.get(window_resized.window).unwrap();
-        let size = window.physical_size() / 2;
-
-        for (camera_position, mut camera) in &mut query {
-            camera.viewport = Some(Viewport {
-                physical_position: camera_position.pos * size,
-                physical_size: size,
-                ..default()
-            });
-        }
-    }
-}
-
 fn button_system(
     interaction_query: Query<
         (&Interaction, &ComputedUiTargetCamera, &RotateCamera),
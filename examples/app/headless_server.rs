@@ -0,0 +1,42 @@
+//! This example shows how to build a dedicated server with the `ServerPlugins` group.
+//!
+//! `ServerPlugins` never opens a window or initializes a renderer, and runs the main schedule at
+//! a fixed tick rate. Pressing `Ctrl+C`, or stopping the process with `SIGTERM`, shuts the
+//! server down gracefully.
+//!
+//! To strip rendering and windowing code from the binary entirely, disable bevy's default
+//! features and only enable the ones the server needs:
+//!
+//! ```toml
+//! [dependencies]
+//! bevy = { version = "*", default-features = false, features = ["bevy_log", "bevy_state"] }
+//! # replace "*" with the most recent version of bevy
+//! ```
+use bevy::{app::ScheduleRunnerPlugin, prelude::*};
+
+/// The number of server ticks per second.
+const TICK_RATE: f64 = 30.0;
+
+fn main() {
+    App::new()
+        .add_plugins(ServerPlugins.set(ScheduleRunnerPlugin::run_at_tick_rate(TICK_RATE)))
+        .add_systems(Startup, || info!("Server started, press Ctrl+C to stop it"))
+        .add_systems(Update, report_ticks)
+        .add_systems(Last, on_shutdown.run_if(on_message::<AppExit>))
+        .run();
+}
+
+fn report_ticks(mut ticks: Local<u32>, time: Res<Time>) {
+    *ticks += 1;
+    if (*ticks).is_multiple_of(TICK_RATE as u32) {
+        info!(
+            "Tick {} after {:.1} seconds",
+            *ticks,
+            time.elapsed_secs_f64()
+        );
+    }
+}
+
+fn on_shutdown() {
+    info!("Shutting down, saving the world state would happen here");
+}
@@ -2,10 +2,24 @@
 //!
 //! See also: `first_person_view_model` example, which does something similar but as a first-person
 //! camera view.
+//!
+//! See also: `orbit_camera_controller` example, which uses the premade `OrbitCamera` controller to
+//! orbit around and follow a moving target.
 
 use std::{f32::consts::FRAC_PI_2, ops::Range};
 
-use bevy::{input::mouse::AccumulatedMouseMotion, prelude::*};
+use bevy::{
+    input::action::{InputBinding, MouseAxis},
+    prelude::*,
+};
+
+/// The actions controlling the camera, bound to inputs by an [`InputMap`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum CameraAction {
+    Pitch,
+    Yaw,
+    Roll,
+}
 
 #[derive(Debug, Resource)]
 struct CameraSettings {
@@ -35,7 +49,17 @@ impl Default for CameraSettings {
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, InputMapPlugin::<CameraAction>::default()))
+        .insert_resource(
+            InputMap::default()
+                .with(CameraAction::Pitch, InputBinding::MouseMotion(MouseAxis::Y))
+                .with(CameraAction::Yaw, InputBinding::MouseMotion(MouseAxis::X))
+                .with(
+                    CameraAction::Roll,
+                    ActionBinding::new(MouseButton::Left).scaled(-1.0),
+                )
+                .with(CameraAction::Roll, MouseButton::Right),
+        )
         .init_resource::<CameraSettings>()
         .add_systems(Startup, (setup, instructions))
         .add_systems(Update, orbit)
@@ -99,28 +123,18 @@ fn instructions(mut commands: Commands) {
 fn orbit(
     mut camera: Single<&mut Transform, With<Camera>>,
     camera_settings: Res<CameraSettings>,
-    mouse_buttons: Res<ButtonInput<MouseButton>>,
-    mouse_motion: Res<AccumulatedMouseMotion>,
+    actions: Res<ActionState<CameraAction>>,
     time: Res<Time>,
 ) {
-    let delta = mouse_motion.delta;
-    let mut delta_roll = 0.0;
-
-    if mouse_buttons.pressed(MouseButton::Left) {
-        delta_roll -= 1.0;
-    }
-    if mouse_buttons.pressed(MouseButton::Right) {
-        delta_roll += 1.0;
-    }
-
     // Mouse motion is one of the few inputs that should not be multiplied by delta time,
     // as we are already receiving the full movement since the last frame was rendered. Multiplying
     // by delta time here would make the movement slower that it should be.
-    let delta_pitch = delta.y * camera_settings.pitch_speed;
-    let delta_yaw = delta.x * camera_settings.yaw_speed;
+    let delta_pitch = actions.value(&CameraAction::Pitch) * camera_settings.pitch_speed;
+    let delta_yaw = actions.value(&CameraAction::Yaw) * camera_settings.yaw_speed;
 
     // Conversely, we DO need to factor in delta time for mouse button inputs.
-    delta_roll *= camera_settings.roll_speed * time.delta_secs();
+    let delta_roll =
+        actions.value(&CameraAction::Roll) * camera_settings.roll_speed * time.delta_secs();
 
     // Obtain the existing pitch, yaw, and roll values from the transform.
     let (yaw, pitch, roll) = camera.rotation.to_euler(EulerRot::YXZ);
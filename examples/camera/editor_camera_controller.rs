@@ -0,0 +1,121 @@
+//! This example showcases the `EditorCamera` camera controller.
+//!
+//! The `EditorCamera` controller orbits around a focus point, like the cameras of 3D editors. To use it,
+//! simply add the [`EditorCameraPlugin`] to your [`App`] and attach the [`EditorCamera`] component to the camera
+//! entity you wish to control.
+//!
+//! ## Default Controls
+//!
+//! All keybinds can be changed by editing the [`EditorCamera`] component, as well as the sensitivities,
+//! the smoothing and the inertia.
+//!
+//! | Default Key Binding             | Action                            |
+//! |:--------------------------------|:----------------------------------|
+//! | Middle click, or `Alt` + click  | Orbit around the focus point      |
+//! | `Shift` + middle click          | Pan                               |
+//! | Scroll wheel                    | Zoom toward the cursor            |
+//! | Right click                     | Look around (hold)                |
+//! | WASD, while right clicking      | Horizontal movement               |
+//! | QE, while right clicking        | Vertical movement                 |
+//! | Left shift, while right clicking| Fly faster                        |
+//!
+//! ## Example controls
+//!
+//! | Key Binding | Action                              |
+//! |:------------|:------------------------------------|
+//! | F           | Focus on the cube                   |
+//! | I           | Enable/Disable smoothing & inertia  |
+
+use bevy::{
+    camera_controller::editor_camera::{EditorCamera, EditorCameraPlugin, EditorCameraState},
+    color::palettes::tailwind,
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Plugin that enables EditorCamera functionality
+        .add_plugins(EditorCameraPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (focus_on_cube, toggle_smoothing))
+        .run();
+}
+
+#[derive(Component)]
+struct FocusTarget;
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(6.0, 4.0, 8.0),
+        // The camera turns toward the initial focus point.
+        EditorCamera::default(),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(20.0, 20.0))),
+        MeshMaterial3d(materials.add(Color::from(tailwind::GRAY_300))),
+    ));
+    for (i, color) in [tailwind::RED_400, tailwind::GREEN_400, tailwind::BLUE_400]
+        .into_iter()
+        .enumerate()
+    {
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere::new(0.5))),
+            MeshMaterial3d(materials.add(Color::from(color))),
+            Transform::from_xyz(i as f32 * 2.0 - 2.0, 0.5, -3.0),
+        ));
+    }
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::default())),
+        MeshMaterial3d(materials.add(Color::from(tailwind::AMBER_400))),
+        Transform::from_xyz(4.0, 0.5, 2.0),
+        FocusTarget,
+    ));
+
+    commands.spawn((
+        DirectionalLight {
+            shadow_maps_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(3.0, 8.0, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.spawn((
+        Text::new("Press F to focus on the cube\nPress I to toggle smoothing and inertia"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+}
+
+fn focus_on_cube(
+    keys: Res<ButtonInput<KeyCode>>,
+    target: Single<&Transform, With<FocusTarget>>,
+    mut camera: Single<&mut EditorCameraState>,
+) {
+    if keys.just_pressed(KeyCode::KeyF) {
+        camera.focus_on(target.translation, 4.0);
+    }
+}
+
+fn toggle_smoothing(keys: Res<ButtonInput<KeyCode>>, mut camera: Single<&mut EditorCamera>) {
+    if keys.just_pressed(KeyCode::KeyI) {
+        let default = EditorCamera::default();
+        if camera.smoothing > 0.0 {
+            camera.smoothing = 0.0;
+            camera.damping = 0.0;
+        } else {
+            camera.smoothing = default.smoothing;
+            camera.damping = default.damping;
+        }
+    }
+}
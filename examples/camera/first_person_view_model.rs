@@ -21,16 +21,16 @@
 //!
 //! ## Implementation
 //!
-//! The `Player` is an entity holding two cameras, one for each model. The view model camera has a fixed
-//! FOV of 70 degrees, while the world model camera has a variable FOV that can be changed by the player.
+//! The `Player` is an entity holding the world model camera, which has a variable FOV that can be changed
+//! by the player. The world model camera has a `ViewModelCamera` child, which renders the view model over
+//! it with a fixed FOV of 70 degrees and its own depth buffer, so the arm never clips into the walls.
 //!
 //! We use different `RenderLayers` to select what to render.
 //!
 //! - The world model camera has no explicit `RenderLayers` component, so it uses the layer 0.
 //!   All static objects in the scene are also on layer 0 for the same reason.
-//! - The view model camera has a `RenderLayers` component with layer 1, so it only renders objects
-//!   explicitly assigned to layer 1. The arm of the player is one such object.
-//!   The order of the view model camera is additionally bumped to 1 to ensure it renders on top of the world model.
+//! - The view model camera is on the `VIEW_MODEL_RENDER_LAYER` layer, 1, so it only renders objects
+//!   explicitly assigned to that layer. The arm of the player is one such object.
 //! - The light source in the scene must illuminate both the view model and the world model, so it is
 //!   assigned to both layers 0 and 1.
 //!
@@ -45,8 +45,11 @@
 use std::f32::consts::FRAC_PI_2;
 
 use bevy::{
-    camera::visibility::RenderLayers, color::palettes::tailwind,
-    input::mouse::AccumulatedMouseMotion, light::NotShadowCaster, prelude::*,
+    camera::{visibility::RenderLayers, ViewModelCamera, VIEW_MODEL_RENDER_LAYER},
+    color::palettes::tailwind,
+    input::mouse::AccumulatedMouseMotion,
+    light::NotShadowCaster,
+    prelude::*,
 };
 
 fn main() {
@@ -89,13 +92,9 @@ struct WorldModelCamera;
 
 /// Used implicitly by all entities without a `RenderLayers` component.
 /// Our world model camera and all objects other than the player are on this layer.
-/// The light source belongs to both layers.
+/// The light source belongs to both this layer and `VIEW_MODEL_RENDER_LAYER`.
 const DEFAULT_RENDER_LAYER: usize = 0;
 
-/// Used by the view model camera and the player's arm.
-/// The light source belongs to both layers.
-const VIEW_MODEL_RENDER_LAYER: usize = 1;
-
 fn spawn_view_model(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -117,21 +116,12 @@ fn spawn_view_model(
                     fov: 90.0_f32.to_radians(),
                     ..default()
                 }),
-            ),
-            // Spawn view model camera.
-            (
-                Camera3d::default(),
-                Camera {
-                    // Bump the order to render on top of the world model.
-                    order: 1,
-                    ..default()
-                },
-                Projection::from(PerspectiveProjection {
+                // Spawn the view model camera, which renders the objects on `VIEW_MODEL_RENDER_LAYER`
+                // on top of the world model.
+                children![ViewModelCamera {
                     fov: 70.0_f32.to_radians(),
                     ..default()
-                }),
-                // Only render objects belonging to the view model.
-                RenderLayers::layer(VIEW_MODEL_RENDER_LAYER),
+                }],
             ),
             // Spawn the player's right arm.
             (
@@ -0,0 +1,142 @@
+//! Shows a minimap following the player, using the [`MinimapPlugin`].
+//!
+//! The minimap is rendered by a second camera looking down on the scene. The player and the pillars
+//! have a [`MinimapIcon`], so they show as icons instead of meshes on the minimap only.
+//!
+//! | Key Binding           | Action                   |
+//! |:----------------------|:-------------------------|
+//! | Arrow keys            | Move the player          |
+//! | Scroll over the map   | Zoom the minimap         |
+//! | Drag the map          | Pan the minimap          |
+//! | R                     | Recenter the minimap     |
+
+use bevy::{
+    color::palettes::tailwind,
+    prelude::*,
+    ui::minimap::{Minimap, MinimapCamera, MinimapIcon, MinimapPlugin},
+};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, MinimapPlugin))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_player, recenter_minimap))
+        .run();
+}
+
+#[derive(Component)]
+struct Player;
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let player = commands
+        .spawn((
+            Player,
+            Mesh3d(meshes.add(Capsule3d::new(0.5, 1.0))),
+            MeshMaterial3d(materials.add(Color::from(tailwind::AMBER_400))),
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            MinimapIcon {
+                size: 24.0,
+                ..MinimapIcon::new(asset_server.load("branding/icon.png"))
+            },
+        ))
+        .id();
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(100.0, 100.0))),
+        MeshMaterial3d(materials.add(Color::from(tailwind::GREEN_700))),
+    ));
+    let pillar = meshes.add(Cylinder::new(0.5, 4.0));
+    let pillar_material = materials.add(Color::from(tailwind::STONE_400));
+    for x in -4..=4 {
+        for z in -4..=4 {
+            if (x + z) % 3 == 0 && (x, z) != (0, 0) {
+                commands.spawn((
+                    Mesh3d(pillar.clone()),
+                    MeshMaterial3d(pillar_material.clone()),
+                    Transform::from_xyz(x as f32 * 8.0, 2.0, z as f32 * 8.0),
+                    // The default image is plain white, tinted by the color.
+                    MinimapIcon {
+                        color: tailwind::STONE_300.into(),
+                        size: 8.0,
+                        ..default()
+                    },
+                ));
+            }
+        }
+    }
+
+    commands.spawn((
+        DirectionalLight {
+            shadow_maps_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 10.0, 2.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    // The main camera, which also renders the UI.
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 12.0, 16.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    // The minimap camera renders to a texture before the main camera.
+    let minimap_camera = commands
+        .spawn((
+            MinimapCamera {
+                target: Some(player),
+                ..default()
+            },
+            Camera {
+                order: -1,
+                ..default()
+            },
+        ))
+        .id();
+
+    commands.spawn((
+        Minimap::new(minimap_camera),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            right: px(12),
+            width: px(240),
+            height: px(240),
+            border: UiRect::all(px(3)),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BorderColor::all(Color::WHITE),
+    ));
+}
+
+fn move_player(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut player: Single<&mut Transform, With<Player>>,
+) {
+    let mut direction = Vec3::ZERO;
+    if keys.pressed(KeyCode::ArrowUp) {
+        direction.z -= 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowDown) {
+        direction.z += 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1.0;
+    }
+    if keys.pressed(KeyCode::ArrowRight) {
+        direction.x += 1.0;
+    }
+    player.translation += direction.normalize_or_zero() * 10.0 * time.delta_secs();
+}
+
+fn recenter_minimap(keys: Res<ButtonInput<KeyCode>>, mut minimap: Single<&mut MinimapCamera>) {
+    if keys.just_pressed(KeyCode::KeyR) {
+        minimap.pan = Vec2::ZERO;
+    }
+}
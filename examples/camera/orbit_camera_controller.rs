@@ -0,0 +1,153 @@
+//! This example showcases the `OrbitCamera` camera controller.
+//!
+//! The `OrbitCamera` controller orbits around a target entity and follows it as it moves, like
+//! third-person cameras. To use it, simply add the [`OrbitCameraPlugin`] to your [`App`] and attach
+//! the [`OrbitCamera`] component to the camera entity you wish to control.
+//!
+//! ## Default Controls
+//!
+//! The distance, the angular limits, the sensitivities, the smoothing and the inertia can be changed
+//! by editing the [`OrbitCamera`] component.
+//!
+//! | Default Key Binding | Action                     |
+//! |:--------------------|:---------------------------|
+//! | Left click + drag   | Orbit around the target    |
+//! | Scroll wheel        | Zoom                       |
+//!
+//! ## Example controls
+//!
+//! | Key Binding | Action                              |
+//! |:------------|:------------------------------------|
+//! | Space       | Switch the target                   |
+//! | I           | Enable/Disable smoothing & inertia  |
+
+use bevy::{
+    camera_controller::orbit_camera::{OrbitCamera, OrbitCameraPlugin},
+    color::palettes::tailwind,
+    prelude::*,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        // Plugin that enables OrbitCamera functionality
+        .add_plugins(OrbitCameraPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_targets, switch_target, toggle_smoothing))
+        .run();
+}
+
+/// A target moving in circles.
+#[derive(Component)]
+struct Target {
+    radius: f32,
+    speed: f32,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let capsule = commands
+        .spawn((
+            Target {
+                radius: 4.0,
+                speed: 0.5,
+            },
+            Mesh3d(meshes.add(Capsule3d::new(0.5, 1.0))),
+            MeshMaterial3d(materials.add(Color::from(tailwind::AMBER_400))),
+            Transform::from_xyz(4.0, 1.0, 0.0),
+        ))
+        .id();
+    commands.spawn((
+        Target {
+            radius: 8.0,
+            speed: -0.3,
+        },
+        Mesh3d(meshes.add(Cuboid::from_length(1.0))),
+        MeshMaterial3d(materials.add(Color::from(tailwind::SKY_400))),
+        Transform::from_xyz(8.0, 0.5, 0.0),
+    ));
+
+    commands.spawn((
+        Camera3d::default(),
+        OrbitCamera {
+            // Look at the top of the capsule rather than at its center.
+            offset: Vec3::new(0.0, 0.5, 0.0),
+            distance_range: 2.0..30.0,
+            ..OrbitCamera::new(capsule, 8.0)
+        },
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(30.0, 30.0))),
+        MeshMaterial3d(materials.add(Color::from(tailwind::GREEN_700))),
+    ));
+    let pillar = meshes.add(Cylinder::new(0.3, 3.0));
+    let pillar_material = materials.add(Color::from(tailwind::STONE_400));
+    for i in 0..12 {
+        let angle = i as f32 * std::f32::consts::TAU / 12.0;
+        commands.spawn((
+            Mesh3d(pillar.clone()),
+            MeshMaterial3d(pillar_material.clone()),
+            Transform::from_xyz(6.0 * ops::cos(angle), 1.5, 6.0 * ops::sin(angle)),
+        ));
+    }
+    commands.spawn((
+        DirectionalLight {
+            shadow_maps_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(4.0, 10.0, 2.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.spawn((
+        Text::new(
+            "Left click + drag: orbit\n\
+            Scroll: zoom\n\
+            Space: switch the target\n\
+            I: enable/disable smoothing & inertia",
+        ),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+}
+
+fn move_targets(time: Res<Time>, mut targets: Query<(&mut Transform, &Target)>) {
+    for (mut transform, target) in &mut targets {
+        let angle = time.elapsed_secs() * target.speed;
+        transform.translation.x = target.radius * ops::cos(angle);
+        transform.translation.z = target.radius * ops::sin(angle);
+    }
+}
+
+fn switch_target(
+    keys: Res<ButtonInput<KeyCode>>,
+    targets: Query<Entity, With<Target>>,
+    mut orbit_camera: Single<&mut OrbitCamera>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        // The camera moves to the new target smoothly.
+        orbit_camera.target = targets
+            .iter()
+            .find(|target| Some(*target) != orbit_camera.target);
+    }
+}
+
+fn toggle_smoothing(keys: Res<ButtonInput<KeyCode>>, mut orbit_camera: Single<&mut OrbitCamera>) {
+    if keys.just_pressed(KeyCode::KeyI) {
+        let defaults = OrbitCamera::default();
+        if orbit_camera.smoothing > 0.0 {
+            orbit_camera.smoothing = 0.0;
+            orbit_camera.damping = 0.0;
+        } else {
+            orbit_camera.smoothing = defaults.smoothing;
+            orbit_camera.damping = defaults.damping;
+        }
+    }
+}
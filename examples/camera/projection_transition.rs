@@ -0,0 +1,96 @@
+//! Shows how to smoothly switch a camera between a perspective and an orthographic projection,
+//! like the map view of a strategy game.
+//!
+//! Press `Space` to switch the projection.
+
+use bevy::{
+    camera::{BlendedProjection, ProjectionTransition, ScalingMode},
+    prelude::*,
+};
+
+/// The distance from the camera to the center of the scene, which keeps its size on the screen.
+const FOCUS_DISTANCE: f32 = 14.0;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_systems(Startup, setup)
+        .add_systems(Update, switch_projection)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let perspective = PerspectiveProjection::default();
+    // The orthographic view covers the same height as the perspective one at the focus distance.
+    let orthographic = OrthographicProjection {
+        scaling_mode: ScalingMode::FixedVertical {
+            viewport_height: 2.0 * FOCUS_DISTANCE * ops::tan(perspective.fov / 2.0),
+        },
+        ..OrthographicProjection::default_3d()
+    };
+    commands.spawn((
+        Camera3d::default(),
+        Projection::custom(BlendedProjection {
+            perspective,
+            orthographic,
+            focus_distance: FOCUS_DISTANCE,
+            blend: 0.0,
+        }),
+        Transform::from_xyz(0.0, 10.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(20.0, 20.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.3, 0.5, 0.3))),
+    ));
+    let cube = meshes.add(Cuboid::new(1.0, 2.0, 1.0));
+    let material = materials.add(Color::srgb(0.8, 0.7, 0.6));
+    for x in -3..=3 {
+        for z in -3..=3 {
+            commands.spawn((
+                Mesh3d(cube.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_xyz(x as f32 * 2.5, 1.0, z as f32 * 2.5),
+            ));
+        }
+    }
+    commands.spawn((
+        DirectionalLight {
+            shadow_maps_enabled: true,
+            ..default()
+        },
+        Transform::from_xyz(3.0, 8.0, 4.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+
+    commands.spawn((
+        Text::new("Press Space to switch the projection"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+}
+
+fn switch_projection(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    camera: Single<(Entity, &Projection), With<Camera3d>>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        let (entity, projection) = *camera;
+        let orthographic =
+            BlendedProjection::get(projection).is_some_and(|blended| blended.blend > 0.5);
+        // Inserting a new transition starts it from the current blend.
+        commands.entity(entity).insert(if orthographic {
+            ProjectionTransition::to_perspective(0.8)
+        } else {
+            ProjectionTransition::to_orthographic(0.8)
+        });
+    }
+}
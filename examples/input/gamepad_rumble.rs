@@ -2,7 +2,10 @@
 //! pressed.
 
 use bevy::{
-    input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest},
+    input::gamepad::{
+        Gamepad, GamepadHaptics, GamepadRumble, GamepadRumbleEnvelope, GamepadRumbleIntensity,
+        GamepadRumbleRequest,
+    },
     prelude::*,
 };
 use core::time::Duration;
@@ -15,10 +18,14 @@ fn main() {
 }
 
 fn gamepad_system(
-    gamepads: Query<(Entity, &Gamepad)>,
+    gamepads: Query<(Entity, &Gamepad, Option<Ref<GamepadHaptics>>)>,
     mut rumble_requests: MessageWriter<GamepadRumbleRequest>,
 ) {
-    for (entity, gamepad) in &gamepads {
+    for (entity, gamepad, haptics) in &gamepads {
+        if let Some(haptics) = haptics.filter(|haptics| haptics.is_added() && !haptics.rumble) {
+            info!("{entity} doesn't support rumble: {haptics:?}");
+        }
+
         if gamepad.just_pressed(GamepadButton::North) {
             info!(
                 "North face button: strong (low-frequency) with low intensity for rumble for 5 seconds. Press multiple times to increase intensity."
@@ -62,6 +69,28 @@ fn gamepad_system(
             });
         }
 
+        if gamepad.just_pressed(GamepadButton::RightTrigger) {
+            info!(
+                "Right trigger: heartbeat, the weak motor kicks and fades while the strong motor swells for 1 second"
+            );
+            let rumble = GamepadRumble::new(Duration::from_secs(1), GamepadRumbleIntensity::MAX)
+                .with_weak_motor_envelope(GamepadRumbleEnvelope::new(
+                    Duration::ZERO,
+                    Duration::from_secs(1),
+                ))
+                .with_strong_motor_envelope(
+                    GamepadRumbleEnvelope::new(
+                        Duration::from_millis(400),
+                        Duration::from_millis(600),
+                    )
+                    .with_easing(EaseFunction::SineInOut),
+                );
+            rumble_requests.write(GamepadRumbleRequest::AddEnvelope {
+                gamepad: entity,
+                rumble,
+            });
+        }
+
         if gamepad.just_pressed(GamepadButton::Start) {
             info!("Start button: Interrupt the current rumble");
             rumble_requests.write(GamepadRumbleRequest::Stop { gamepad: entity });
@@ -252,6 +252,7 @@ pub fn setup(mut commands: Commands, asset_server: Res<AssetServer>, args: Res<A
                 maximum_distance: 100.0,
                 first_cascade_far_bound: 10.0,
                 overlap_proportion: 0.2,
+                ..default()
             }
             .build(),
         ))
@@ -194,6 +194,7 @@ pub fn setup(
                 maximum_distance: 80.0,
                 first_cascade_far_bound: 5.0,
                 overlap_proportion: 0.2,
+                ..default()
             }),
         ))
         .insert_if(OcclusionCulling, || !args.no_shadow_occlusion_culling);
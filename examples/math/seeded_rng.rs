@@ -0,0 +1,97 @@
+//! This example shows how to draw reproducible random numbers from the [`GlobalRng`] and from
+//! per-entity [`EntityRng`]s.
+//!
+//! Press `R` to restart the generator from its seed: the same points are scattered again.
+
+use bevy::{prelude::*, rand::RngPlugin};
+use rand::RngExt;
+
+fn main() {
+    App::new()
+        // Use a fixed seed for the global rng; this makes the random output reproducible.
+        .add_plugins(DefaultPlugins.set(RngPlugin {
+            seed: Some(19878367467712),
+        }))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (scatter, restart))
+        .run();
+}
+
+/// An entity scattering points around itself, drawing from its own [`EntityRng`].
+#[derive(Component)]
+struct Emitter {
+    color: Color,
+}
+
+/// A point placed by an [`Emitter`].
+#[derive(Component)]
+struct Point;
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // `ForkRng` gives each emitter its own generator, forked from the global one in spawn order.
+    for (x, color) in [
+        (-300.0, Color::srgb(0.9, 0.3, 0.3)),
+        (0.0, Color::srgb(0.3, 0.9, 0.3)),
+        (300.0, Color::srgb(0.3, 0.3, 0.9)),
+    ] {
+        commands.spawn((Emitter { color }, ForkRng, Transform::from_xyz(x, 0.0, 0.0)));
+    }
+
+    commands.spawn((
+        Text::new("Press R to restart the generators from the seed"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            left: px(12),
+            ..default()
+        },
+    ));
+}
+
+fn scatter(
+    mut commands: Commands,
+    mut emitters: Query<(&Emitter, &Transform, &mut EntityRng)>,
+    points: Query<(), With<Point>>,
+) {
+    if points.iter().count() >= 300 {
+        return;
+    }
+
+    for (emitter, transform, mut rng) in &mut emitters {
+        let offset = Vec2::new(
+            rng.random_range(-120.0..120.0),
+            rng.random_range(-200.0..200.0),
+        );
+        commands.spawn((
+            Point,
+            Sprite::from_color(emitter.color, Vec2::splat(6.0)),
+            Transform::from_translation(transform.translation + offset.extend(0.0)),
+        ));
+    }
+}
+
+fn restart(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut global_rng: ResMut<GlobalRng>,
+    emitters: Query<Entity, With<Emitter>>,
+    points: Query<Entity, With<Point>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+
+    for point in &points {
+        commands.entity(point).despawn();
+    }
+
+    // Reseeding the global generator and forking it again in the same order reproduces the
+    // generators of the emitters.
+    let seed = global_rng.seed();
+    global_rng.reseed(seed);
+    for emitter in &emitters {
+        commands.entity(emitter).insert(global_rng.fork());
+    }
+}
@@ -15,7 +15,8 @@ use serde::{Deserialize, Serialize};
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(RemotePlugin::default())
+        // Clients can run the `toggle_bar` system with the `world.run_system` method.
+        .add_plugins(RemotePlugin::default().with_system("toggle_bar", toggle_bar))
         .add_plugins(RemoteHttpPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, remove.run_if(input_just_pressed(KeyCode::Space)))
@@ -82,6 +83,10 @@ fn move_cube(mut query: Query<&mut Transform, With<Cube>>, time: Res<Time>) {
     }
 }
 
+fn toggle_bar(mut test_resource: ResMut<TestResource>) {
+    test_resource.bar = !test_resource.bar;
+}
+
 fn remove(mut commands: Commands, cube_entity: Single<Entity, With<Cube>>) {
     commands.entity(*cube_entity).remove::<Cube>();
 }
@@ -0,0 +1,54 @@
+//! Demonstrates using a custom extension to the `SpriteMaterial` to dissolve a sprite with a custom shader.
+
+use bevy::{
+    prelude::*,
+    render::render_resource::*,
+    shader::ShaderRef,
+    sprite_render::{SpriteMaterialExtension, SpriteMaterialExtensionPlugin, SpriteMeshExtension},
+};
+
+/// This example uses a shader source file from the assets subdirectory
+const SHADER_ASSET_PATH: &str = "shaders/extended_sprite_material.wgsl";
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(SpriteMaterialExtensionPlugin::<Dissolve>::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, animate_dissolve)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    commands.spawn((
+        SpriteMesh::from_image(asset_server.load("branding/bevy_bird_dark.png")),
+        SpriteMeshExtension(Dissolve {
+            edge_color: LinearRgba::rgb(1.0, 0.4, 0.0),
+            ..default()
+        }),
+    ));
+}
+
+fn animate_dissolve(time: Res<Time>, mut sprites: Query<&mut SpriteMeshExtension<Dissolve>>) {
+    for mut dissolve in &mut sprites {
+        dissolve.0.progress = ops::sin(time.elapsed_secs()) * 0.5 + 0.5;
+    }
+}
+
+#[derive(Asset, AsBindGroup, Reflect, Debug, Clone, Default)]
+struct Dissolve {
+    // Start at a high binding number to ensure bindings don't conflict
+    // with the sprite material.
+    #[uniform(100)]
+    progress: f32,
+    #[uniform(100)]
+    edge_color: LinearRgba,
+}
+
+impl SpriteMaterialExtension for Dissolve {
+    fn fragment_shader() -> ShaderRef {
+        SHADER_ASSET_PATH.into()
+    }
+}
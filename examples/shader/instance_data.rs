@@ -0,0 +1,107 @@
+//! Shows how to give each instance of an automatically instanced mesh its own data, using
+//! `InstanceData` and the `InstanceDataPlugin`.
+//!
+//! All cubes share the same mesh and material, so they are drawn in a single draw call, yet each
+//! has its own color and animation phase. Press `Space` to recolor them.
+
+use bevy::{
+    color::ColorToComponents,
+    prelude::*,
+    reflect::TypePath,
+    render::{
+        instance_data::{InstanceData, InstanceDataBuffer, InstanceDataPlugin},
+        render_resource::{AsBindGroup, ShaderType},
+        storage::ShaderBuffer,
+    },
+    shader::ShaderRef,
+};
+
+const SHADER_ASSET_PATH: &str = "shaders/instance_data.wgsl";
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            MaterialPlugin::<CustomMaterial>::default(),
+            InstanceDataPlugin::<CubeInstance>::default(),
+        ))
+        .add_systems(Startup, setup)
+        .add_systems(Update, recolor)
+        .run();
+}
+
+/// The data of each cube, laid out in the storage buffer as described in the shader.
+#[derive(ShaderType, Clone, Default)]
+struct CubeInstance {
+    color: Vec4,
+    phase: f32,
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<CustomMaterial>>,
+    instance_data: Res<InstanceDataBuffer<CubeInstance>>,
+) {
+    let mesh = meshes.add(Cuboid::from_size(Vec3::splat(0.3)));
+    // The material binds the buffer holding the data of all instances.
+    let material = materials.add(CustomMaterial {
+        instances: instance_data.buffer().clone(),
+    });
+
+    for x in -10..=10 {
+        for z in -10..=10 {
+            commands.spawn((
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                InstanceData(CubeInstance {
+                    color: cube_color(x, z, 0.0),
+                    phase: (x + z) as f32 * 0.4,
+                }),
+                Transform::from_xyz(x as f32 * 0.5, 0.0, z as f32 * 0.5),
+            ));
+        }
+    }
+
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 6.0, 9.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+}
+
+fn cube_color(x: i32, z: i32, offset: f32) -> Vec4 {
+    let hue = ((x * 7 + z * 13) as f32 * 5.0 + offset).rem_euclid(360.0);
+    LinearRgba::from(Color::hsl(hue, 0.8, 0.6)).to_vec4()
+}
+
+fn recolor(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut offset: Local<f32>,
+    mut cubes: Query<(&Transform, &mut InstanceData<CubeInstance>)>,
+) {
+    if !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+    *offset += 90.0;
+    for (transform, mut instance) in &mut cubes {
+        let x = (transform.translation.x * 2.0).round() as i32;
+        let z = (transform.translation.z * 2.0).round() as i32;
+        instance.0.color = cube_color(x, z, *offset);
+    }
+}
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Clone)]
+struct CustomMaterial {
+    #[storage(0, read_only)]
+    instances: Handle<ShaderBuffer>,
+}
+
+impl Material for CustomMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SHADER_ASSET_PATH.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        SHADER_ASSET_PATH.into()
+    }
+}
@@ -29,7 +29,6 @@ use bevy::{
         DrawMesh, MeshInputUniform, MeshPipeline, MeshPipelineKey, MeshPipelineViewLayoutKey,
         MeshUniform, RenderMeshInstances, SetMeshBindGroup, SetMeshViewBindGroup,
     },
-    platform::collections::HashSet,
     prelude::*,
     render::{
         batching::{
@@ -44,9 +43,9 @@ use bevy::{
         mesh::{allocator::MeshAllocator, RenderMesh},
         render_asset::RenderAssets,
         render_phase::{
-            sort_phase_system, AddRenderCommand, CachedRenderPipelinePhaseItem, DrawFunctionId,
-            DrawFunctions, PhaseItem, PhaseItemExtraIndex, SetItemPipeline, SortedPhaseItem,
-            SortedRenderPhasePlugin, ViewSortedRenderPhases,
+            sort_phase_system, AddRenderCommand, CachedRenderPipelinePhaseItem,
+            CameraRenderPhasePlugin, DrawFunctionId, DrawFunctions, PhaseItem, PhaseItemExtraIndex,
+            SetItemPipeline, SortedPhaseItem, SortedRenderPhasePlugin, ViewSortedRenderPhases,
         },
         render_resource::{
             CachedRenderPipelineId, ColorTargetState, ColorWrites, Face, FragmentState,
@@ -56,8 +55,8 @@ use bevy::{
         },
         renderer::{RenderContext, ViewQuery},
         sync_world::{MainEntity, MainEntityHashSet},
-        view::{ExtractedView, RenderVisibleEntities, RetainedViewEntity, ViewTarget},
-        Extract, Render, RenderApp, RenderDebugFlags, RenderStartup, RenderSystems,
+        view::{ExtractedView, RenderVisibleEntities, ViewTarget},
+        Render, RenderApp, RenderDebugFlags, RenderStartup, RenderSystems,
     },
 };
 use indexmap::IndexMap;
@@ -121,6 +120,9 @@ impl Plugin for MeshStencilPhasePlugin {
         app.add_plugins((
             ExtractComponentPlugin::<DrawStencil>::default(),
             SortedRenderPhasePlugin::<Stencil3d, MeshPipeline>::new(RenderDebugFlags::default()),
+            // When defining a phase, we need a phase for each view that will use it. This plugin
+            // prepares the phase of every active 3D camera at the start of each frame.
+            CameraRenderPhasePlugin::<ViewSortedRenderPhases<Stencil3d>, With<Camera3d>>::default(),
         ));
         // We need to get the render app from the main app
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
@@ -136,7 +138,6 @@ impl Plugin for MeshStencilPhasePlugin {
                 RenderStartup,
                 init_stencil_pipeline.after(MeshPipelineSystems),
             )
-            .add_systems(ExtractSchedule, extract_camera_phases)
             .add_systems(
                 Render,
                 (
@@ -506,30 +507,6 @@ impl GetFullBatchData for StencilPipeline {
     }
 }
 
-// When defining a phase, we need to extract it from the main world and add it to a resource
-// that will be used by the render world. We need to give that resource all views that will use
-// that phase
-fn extract_camera_phases(
-    mut stencil_phases: ResMut<ViewSortedRenderPhases<Stencil3d>>,
-    cameras: Extract<Query<(Entity, &Camera), With<Camera3d>>>,
-    mut live_entities: Local<HashSet<RetainedViewEntity>>,
-) {
-    live_entities.clear();
-    for (main_entity, camera) in &cameras {
-        if !camera.is_active {
-            continue;
-        }
-        // This is the main camera, so we use the first subview index (0)
-        let retained_view_entity = RetainedViewEntity::new(main_entity.into(), None, 0);
-
-        stencil_phases.prepare_for_new_frame(retained_view_entity);
-        live_entities.insert(retained_view_entity);
-    }
-
-    // Clear out all dead views.
-    stencil_phases.retain(|camera_entity, _| live_entities.contains(camera_entity));
-}
-
 /// A resource that stores meshes that couldn't be specialized yet because their
 /// materials hadn't loaded.
 ///
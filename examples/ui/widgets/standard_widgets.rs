@@ -853,6 +853,7 @@ fn spawn_menu(anchor: Entity, assets: Res<AssetServer>, mut commands: Commands)
                     },
                 ],
                 window_margin: 10.0,
+                ..default()
             },
             OverrideClip,
             children![
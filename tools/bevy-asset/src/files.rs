@@ -0,0 +1,86 @@
+//! Listing of the files of an asset folder.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// A file of an asset folder.
+pub struct AssetFile {
+    /// The path of the file, relative to the folder, with `/` separators.
+    pub path: String,
+    /// The path of the file on disk.
+    pub full_path: PathBuf,
+    /// The size in bytes.
+    pub size: u64,
+}
+
+/// Lists the files of `root` recursively, sorted by path, skipping the hidden files and folders.
+///
+/// The `.meta` files are skipped unless `include_meta` is set, as they describe the other assets
+/// rather than being assets of their own.
+pub fn list(root: &Path, include_meta: bool) -> io::Result<Vec<AssetFile>> {
+    fn visit(
+        root: &Path,
+        folder: &Path,
+        include_meta: bool,
+        files: &mut Vec<AssetFile>,
+    ) -> io::Result<()> {
+        for entry in fs::read_dir(folder)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let full_path = entry.path();
+            let metadata = fs::metadata(&full_path)?;
+            if metadata.is_dir() {
+                visit(root, &full_path, include_meta, files)?;
+                continue;
+            }
+            if !include_meta
+                && full_path
+                    .extension()
+                    .is_some_and(|extension| extension == "meta")
+            {
+                continue;
+            }
+            let path = full_path
+                .strip_prefix(root)
+                .unwrap_or(&full_path)
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            files.push(AssetFile {
+                path,
+                full_path,
+                size: metadata.len(),
+            });
+        }
+        Ok(())
+    }
+
+    let mut files = vec![];
+    visit(root, root, include_meta, &mut files)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {err}", root.display())))?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+/// Returns the absolute path of `folder`, which is where the [`AssetPlugin`] looks for it.
+///
+/// [`AssetPlugin`]: bevy::asset::AssetPlugin
+pub fn absolute(folder: &Path) -> Result<String, String> {
+    fs::canonicalize(folder)
+        .map(|path| path.to_string_lossy().into_owned())
+        .map_err(|err| format!("Can't find {}: {err}", folder.display()))
+}
+
+/// Formats a size in bytes for humans.
+pub fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.2} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    }
+}
@@ -0,0 +1,35 @@
+//! The windowless, GPU-less app used to load and process the assets.
+
+use core::time::Duration;
+
+use bevy::{
+    app::ScheduleRunnerPlugin,
+    prelude::*,
+    render::{settings::WgpuSettings, RenderPlugin},
+    window::ExitCondition,
+};
+
+/// Returns an app with all the asset loaders and processors of the engine, but without a window
+/// or a renderer, configured with `asset_plugin`.
+pub fn app(asset_plugin: AssetPlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        DefaultPlugins
+            .set(asset_plugin)
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            }),
+        ScheduleRunnerPlugin::run_loop(Duration::from_millis(10)),
+    ));
+    app
+}
@@ -0,0 +1,89 @@
+//! Tool used to process, validate and publish asset folders without running the game.
+
+#![expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "Allowed in tools."
+)]
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand};
+
+mod files;
+mod headless;
+mod manifest;
+mod process;
+mod validate;
+
+#[derive(Parser)]
+#[command(about = "Process, validate and publish a Bevy asset folder")]
+struct Args {
+    #[command(subcommand)]
+    action: Action,
+}
+
+#[derive(Subcommand)]
+enum Action {
+    /// Run the asset processor over the folder, then exit
+    Process {
+        /// The folder of the unprocessed assets
+        #[arg(default_value = "assets")]
+        source: PathBuf,
+
+        /// The folder the processed assets are written to
+        #[arg(long, default_value = "imported_assets/Default")]
+        destination: PathBuf,
+    },
+    /// Load every asset of the folder and their dependencies, and report the assets that fail to
+    /// load, the references that don't resolve and the files that are too large
+    Validate {
+        /// The folder of the assets
+        #[arg(default_value = "assets")]
+        source: PathBuf,
+
+        /// The size in bytes above which a file is reported as oversized
+        #[arg(long, default_value_t = 16 * 1024 * 1024)]
+        max_size: u64,
+
+        /// The number of seconds to wait for the assets to load
+        #[arg(long, default_value_t = 120)]
+        timeout: u64,
+    },
+    /// Write a manifest with the size and the hash of every file of the folder, to upload or patch
+    /// only the files that changed
+    Manifest {
+        /// The folder of the assets
+        #[arg(default_value = "assets")]
+        source: PathBuf,
+
+        /// The file the manifest is written to
+        #[arg(long, default_value = "asset-manifest.json")]
+        output: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let result = match args.action {
+        Action::Process {
+            source,
+            destination,
+        } => process::run(&source, &destination),
+        Action::Validate {
+            source,
+            max_size,
+            timeout,
+        } => validate::run(&source, max_size, timeout),
+        Action::Manifest { source, output } => manifest::run(&source, &output),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
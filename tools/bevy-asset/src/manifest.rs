@@ -0,0 +1,59 @@
+//! Manifest of the files of an asset folder, to upload them to a CDN or to build patches.
+
+use std::{fs, path::Path};
+
+use serde::Serialize;
+
+use crate::files;
+
+#[derive(Serialize)]
+struct Manifest {
+    /// The hash algorithm used, so the consumers can check it.
+    algorithm: &'static str,
+    /// The sum of the sizes of the files.
+    total_size: u64,
+    files: Vec<ManifestEntry>,
+}
+
+#[derive(Serialize)]
+struct ManifestEntry {
+    /// The path of the file, relative to the asset folder, with `/` separators.
+    path: String,
+    size: u64,
+    /// The hexadecimal blake3 hash of the content of the file.
+    hash: String,
+}
+
+/// Writes a manifest of the files of `source`, meta files included, to `output`.
+///
+/// The entries are sorted by path, so that the manifests of two versions of the assets can be
+/// diffed to find the files to upload.
+pub fn run(source: &Path, output: &Path) -> Result<(), String> {
+    let asset_files = files::list(source, true).map_err(|err| err.to_string())?;
+
+    let mut entries = Vec::with_capacity(asset_files.len());
+    for file in asset_files {
+        let content = fs::read(&file.full_path)
+            .map_err(|err| format!("Can't read {}: {err}", file.full_path.display()))?;
+        entries.push(ManifestEntry {
+            path: file.path,
+            size: file.size,
+            hash: blake3::hash(&content).to_hex().to_string(),
+        });
+    }
+
+    let manifest = Manifest {
+        algorithm: "blake3",
+        total_size: entries.iter().map(|entry| entry.size).sum(),
+        files: entries,
+    };
+    let json = serde_json::to_string_pretty(&manifest).map_err(|err| err.to_string())?;
+    fs::write(output, json).map_err(|err| format!("Can't write {}: {err}", output.display()))?;
+    println!(
+        "Wrote the manifest of {} files ({}) to {}",
+        manifest.files.len(),
+        files::format_size(manifest.total_size),
+        output.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+//! Headless run of the asset processor.
+
+use std::path::Path;
+
+use bevy::{
+    asset::{
+        processor::{AssetProcessor, ProcessorState},
+        AssetMode,
+    },
+    prelude::*,
+    tasks::block_on,
+};
+
+use crate::{files, headless};
+
+/// Processes the assets of `source` into `destination`, and exits once they are all processed.
+pub fn run(source: &Path, destination: &Path) -> Result<(), String> {
+    let file_path = files::absolute(source)?;
+    std::fs::create_dir_all(destination)
+        .map_err(|err| format!("Can't create {}: {err}", destination.display()))?;
+    let processed_file_path = files::absolute(destination)?;
+
+    println!("Processing {file_path} into {processed_file_path}...");
+    let exit = headless::app(AssetPlugin {
+        file_path,
+        processed_file_path,
+        mode: AssetMode::Processed,
+        use_asset_processor_override: Some(true),
+        ..default()
+    })
+    .add_systems(Update, exit_when_processed)
+    .run();
+
+    match exit {
+        AppExit::Success => {
+            println!("Done. Check the log above for the assets that failed to process.");
+            Ok(())
+        }
+        AppExit::Error(code) => Err(format!("The processor exited with code {code}")),
+    }
+}
+
+fn exit_when_processed(processor: Res<AssetProcessor>, mut exit: MessageWriter<AppExit>) {
+    if block_on(processor.get_state()) == ProcessorState::Finished {
+        exit.write(AppExit::Success);
+    }
+}
@@ -0,0 +1,161 @@
+//! Validation of an asset folder: every asset must load, with all of its dependencies.
+
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use bevy::{
+    asset::{
+        io::AssetReaderError, AssetLoadError, AssetMode, LoadedUntypedAsset,
+        RecursiveDependencyLoadState,
+    },
+    prelude::*,
+};
+
+use crate::{files, headless};
+
+/// The outcome of the validation of an asset.
+enum Problem {
+    /// The asset, or one of its dependencies, references a file that doesn't exist.
+    Missing { referenced: String },
+    /// The asset, or one of its dependencies, failed to load.
+    Failed { error: String },
+    /// The asset didn't finish loading before the timeout.
+    TimedOut,
+}
+
+#[derive(Resource)]
+struct Validation {
+    pending: Vec<(String, Handle<LoadedUntypedAsset>)>,
+    loaded: usize,
+    skipped: Vec<String>,
+    problems: Arc<Mutex<Vec<(String, Problem)>>>,
+    start: Instant,
+    timeout: u64,
+}
+
+/// Loads all the assets of `source` and reports those that fail to load, with `max_size` the size
+/// above which a file is reported as oversized.
+pub fn run(source: &Path, max_size: u64, timeout: u64) -> Result<(), String> {
+    let file_path = files::absolute(source)?;
+    let assets = files::list(source, false).map_err(|err| err.to_string())?;
+
+    let oversized: Vec<_> = assets.iter().filter(|file| file.size > max_size).collect();
+    let paths: Vec<String> = assets.iter().map(|file| file.path.clone()).collect();
+    let problems = Arc::new(Mutex::new(Vec::new()));
+
+    println!("Loading the {} assets of {file_path}...", paths.len());
+    let mut app = headless::app(AssetPlugin {
+        file_path,
+        mode: AssetMode::Unprocessed,
+        use_asset_processor_override: Some(false),
+        ..default()
+    });
+    let timeout_problems = problems.clone();
+    app.add_systems(
+        Startup,
+        move |mut commands: Commands, asset_server: Res<AssetServer>| {
+            commands.insert_resource(Validation {
+                pending: paths
+                    .iter()
+                    .map(|path| (path.clone(), asset_server.load_untyped(path.as_str())))
+                    .collect(),
+                loaded: 0,
+                skipped: vec![],
+                problems: timeout_problems.clone(),
+                start: Instant::now(),
+                timeout,
+            });
+        },
+    )
+    .add_systems(Update, check_load_states);
+    app.run();
+
+    let problems = core::mem::take(&mut *problems.lock().unwrap());
+    for file in &oversized {
+        println!(
+            "OVERSIZED {} is {}, more than {}",
+            file.path,
+            files::format_size(file.size),
+            files::format_size(max_size)
+        );
+    }
+    for (path, problem) in &problems {
+        match problem {
+            Problem::Missing { referenced } => {
+                println!("MISSING   {path} references {referenced}, which doesn't exist");
+            }
+            Problem::Failed { error } => println!("FAILED    {path}: {error}"),
+            Problem::TimedOut => println!("TIMEOUT   {path} didn't load in {timeout}s"),
+        }
+    }
+
+    if problems.is_empty() && oversized.is_empty() {
+        println!("All the assets are valid.");
+        Ok(())
+    } else {
+        Err(format!(
+            "{} assets failed to load and {} files are oversized.",
+            problems.len(),
+            oversized.len()
+        ))
+    }
+}
+
+fn check_load_states(
+    asset_server: Res<AssetServer>,
+    mut validation: ResMut<Validation>,
+    mut exit: MessageWriter<AppExit>,
+) {
+    let validation = &mut *validation;
+    let mut problems = validation.problems.lock().unwrap();
+    validation.pending.retain(|(path, handle)| {
+        match asset_server.get_recursive_dependency_load_state(handle) {
+            Some(RecursiveDependencyLoadState::Loaded) => {
+                validation.loaded += 1;
+                false
+            }
+            Some(RecursiveDependencyLoadState::Failed(error)) => {
+                match &*error {
+                    // Files that aren't assets, like licenses or readmes.
+                    AssetLoadError::MissingAssetLoaderForExtension(_) => {
+                        validation.skipped.push(path.clone());
+                    }
+                    AssetLoadError::AssetReaderError(AssetReaderError::NotFound(referenced)) => {
+                        problems.push((
+                            path.clone(),
+                            Problem::Missing {
+                                referenced: referenced.display().to_string(),
+                            },
+                        ));
+                    }
+                    error => problems.push((
+                        path.clone(),
+                        Problem::Failed {
+                            error: error.to_string(),
+                        },
+                    )),
+                }
+                false
+            }
+            _ => true,
+        }
+    });
+
+    let timed_out = validation.start.elapsed().as_secs() >= validation.timeout;
+    if timed_out {
+        for (path, _) in validation.pending.drain(..) {
+            problems.push((path, Problem::TimedOut));
+        }
+    }
+    if validation.pending.is_empty() {
+        println!(
+            "{} assets loaded, {} files skipped as they have no loader.",
+            validation.loaded,
+            validation.skipped.len()
+        );
+        exit.write(AppExit::Success);
+    }
+}
@@ -0,0 +1,241 @@
+//! Comparison of the screenshots taken in the browsers against stored references.
+//!
+//! The screenshots are taken by the example itself, through the `bevy_ci_testing` feature, at
+//! fixed frames and with a fixed frame time, so that two runs of the same build render the same
+//! images. Browsers and GPUs still disagree on a few pixels, which the tolerance accounts for.
+
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use image::{Rgba, RgbaImage};
+
+/// The difference on any channel above which two pixels are considered different.
+const CHANNEL_THRESHOLD: u8 = 8;
+
+/// The result of the comparison of a screenshot against its reference.
+#[derive(Debug)]
+pub enum Outcome {
+    /// The screenshot matches the reference, with this fraction of pixels differing.
+    Matched { difference: f32 },
+    /// More pixels than the tolerance differ, and a diff image was written.
+    Differs { difference: f32, diff: PathBuf },
+    /// The screenshot has a different size than the reference.
+    SizeMismatch {
+        screenshot: (u32, u32),
+        reference: (u32, u32),
+    },
+    /// There is no reference for this screenshot.
+    MissingReference,
+    /// The example didn't take this screenshot.
+    MissingScreenshot,
+    /// The reference was replaced by the screenshot.
+    Updated,
+}
+
+impl Outcome {
+    /// Returns `true` if this outcome fails the test.
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Matched { .. } | Outcome::Updated)
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Outcome::Matched { difference } => {
+                format!("matched, {:.3}% of the pixels differ", difference * 100.0)
+            }
+            Outcome::Differs { difference, diff } => format!(
+                "{:.3}% of the pixels differ, see {}",
+                difference * 100.0,
+                diff.display()
+            ),
+            Outcome::SizeMismatch {
+                screenshot,
+                reference,
+            } => format!(
+                "the screenshot is {}x{}, the reference is {}x{}",
+                screenshot.0, screenshot.1, reference.0, reference.1
+            ),
+            Outcome::MissingReference => "no reference, run with --update-references".to_string(),
+            Outcome::MissingScreenshot => "the example didn't take this screenshot".to_string(),
+            Outcome::Updated => "reference updated".to_string(),
+        }
+    }
+}
+
+/// A screenshot and the result of its comparison.
+pub struct Comparison {
+    /// The name of the example.
+    pub example: String,
+    /// The name of the browser, as a playwright project.
+    pub browser: String,
+    /// The frame the screenshot was taken at.
+    pub frame: u32,
+    /// The result of the comparison.
+    pub outcome: Outcome,
+}
+
+/// Compares the `screenshot` against the `reference`, writing a `diff` image if they differ by more
+/// than `tolerance`, the fraction of pixels allowed to differ.
+///
+/// If `update` is set, the reference is replaced by the screenshot instead.
+pub fn compare(
+    screenshot: &Path,
+    reference: &Path,
+    diff: &Path,
+    tolerance: f32,
+    update: bool,
+) -> Result<Outcome, String> {
+    if !screenshot.exists() {
+        return Ok(Outcome::MissingScreenshot);
+    }
+    if update {
+        if let Some(parent) = reference.parent() {
+            fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+        }
+        fs::copy(screenshot, reference).map_err(|err| err.to_string())?;
+        return Ok(Outcome::Updated);
+    }
+    if !reference.exists() {
+        return Ok(Outcome::MissingReference);
+    }
+
+    let open = |path: &Path| {
+        image::open(path)
+            .map(|image| image.to_rgba8())
+            .map_err(|err| format!("Failed to read {}: {err}", path.display()))
+    };
+    let screenshot = open(screenshot)?;
+    let reference = open(reference)?;
+    if screenshot.dimensions() != reference.dimensions() {
+        return Ok(Outcome::SizeMismatch {
+            screenshot: screenshot.dimensions(),
+            reference: reference.dimensions(),
+        });
+    }
+
+    let (difference, diff_image) = difference(&screenshot, &reference);
+    if difference <= tolerance {
+        return Ok(Outcome::Matched { difference });
+    }
+    diff_image
+        .save(diff)
+        .map_err(|err| format!("Failed to write {}: {err}", diff.display()))?;
+    Ok(Outcome::Differs {
+        difference,
+        diff: diff.to_path_buf(),
+    })
+}
+
+/// Returns the fraction of pixels that differ between the two images of the same size, and an
+/// image showing the reference faded, with the differing pixels in red.
+fn difference(screenshot: &RgbaImage, reference: &RgbaImage) -> (f32, RgbaImage) {
+    let mut differing = 0;
+    let diff = RgbaImage::from_fn(reference.width(), reference.height(), |x, y| {
+        let a = screenshot.get_pixel(x, y);
+        let b = reference.get_pixel(x, y);
+        if a.0
+            .iter()
+            .zip(b.0)
+            .any(|(a, b)| a.abs_diff(b) > CHANNEL_THRESHOLD)
+        {
+            differing += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let luminance = (u16::from(b[0]) + u16::from(b[1]) + u16::from(b[2])) / 3;
+            let faded = 192 + (luminance / 4) as u8;
+            Rgba([faded, faded, faded, 255])
+        }
+    });
+    let pixels = (reference.width() * reference.height()).max(1);
+    (differing as f32 / pixels as f32, diff)
+}
+
+/// Writes the `comparisons` as a JUnit report to `path`, with a test suite per example.
+pub fn write_junit(path: &Path, comparisons: &[Comparison]) -> std::io::Result<()> {
+    let failures = comparisons
+        .iter()
+        .filter(|comparison| comparison.outcome.is_failure())
+        .count();
+    let mut report = String::new();
+    writeln!(report, r#"<?xml version="1.0" encoding="UTF-8"?>"#).unwrap();
+    writeln!(
+        report,
+        r#"<testsuites name="wasm screenshots" tests="{}" failures="{failures}">"#,
+        comparisons.len()
+    )
+    .unwrap();
+
+    let mut examples: Vec<&str> = comparisons
+        .iter()
+        .map(|comparison| comparison.example.as_str())
+        .collect();
+    examples.dedup();
+    for example in examples {
+        let cases: Vec<_> = comparisons
+            .iter()
+            .filter(|comparison| comparison.example == example)
+            .collect();
+        writeln!(
+            report,
+            r#"  <testsuite name="{}" tests="{}" failures="{}">"#,
+            escape(example),
+            cases.len(),
+            cases
+                .iter()
+                .filter(|comparison| comparison.outcome.is_failure())
+                .count()
+        )
+        .unwrap();
+        for case in cases {
+            let name = escape(&format!("{} frame {}", case.browser, case.frame));
+            let message = escape(&case.outcome.message());
+            if case.outcome.is_failure() {
+                writeln!(
+                    report,
+                    r#"    <testcase name="{name}" classname="{}"><failure message="{message}"/></testcase>"#,
+                    escape(example)
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    report,
+                    r#"    <testcase name="{name}" classname="{}"><system-out>{message}</system-out></testcase>"#,
+                    escape(example)
+                )
+                .unwrap();
+            }
+        }
+        writeln!(report, "  </testsuite>").unwrap();
+    }
+    writeln!(report, "</testsuites>").unwrap();
+
+    fs::write(path, report)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Prints a summary of the `comparisons`.
+pub fn print_summary(comparisons: &[Comparison]) {
+    for comparison in comparisons {
+        println!(
+            "{} {} on {} at frame {}: {}",
+            if comparison.outcome.is_failure() {
+                "FAIL"
+            } else {
+                "ok  "
+            },
+            comparison.example,
+            comparison.browser,
+            comparison.frame,
+            comparison.outcome.message()
+        );
+    }
+}
@@ -1,16 +1,32 @@
 //! Tool used to build Bevy examples for wasm.
 
-use std::{fs::File, io::Write};
+#![expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "Allowed in tools."
+)]
 
+use std::{fs, path::PathBuf};
+
+use bevy_dev_tools::ci_testing::{CiTestingConfig, CiTestingEvent, CiTestingEventOnFrame};
 use clap::{Parser, ValueEnum};
 use xshell::{cmd, Shell};
 
+mod compare;
+mod serve;
+mod shell;
+mod size;
+mod threads;
+
 #[derive(Debug, Copy, Clone, ValueEnum)]
 enum WebApi {
     Webgl2,
     Webgpu,
 }
 
+/// The playwright projects run when no browser is given.
+const DEFAULT_BROWSERS: &[&str] = &["chromium", "firefox", "webkit"];
+
 #[derive(Parser, Debug)]
 struct Args {
     /// Examples to build
@@ -24,9 +40,35 @@ struct Args {
     /// Run on the given browsers. By default, chromium, firefox, webkit
     browsers: Vec<String>,
 
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "screenshot_frames")]
     /// Stop after this number of frames
-    frames: Option<usize>,
+    frames: Option<u32>,
+
+    #[arg(long, value_delimiter = ',', requires = "test")]
+    /// Take screenshots at these frames, with a fixed frame time, and compare them against the
+    /// references. The example exits after the last screenshot
+    screenshot_frames: Vec<u32>,
+
+    #[arg(long, default_value_t = 1.0 / 60.0)]
+    /// Time in seconds between frames while taking screenshots, so that they don't depend on the
+    /// frame rate of the browser
+    screenshot_frame_time: f32,
+
+    #[arg(long, default_value = ".github/start-wasm-example/references")]
+    /// Folder of the reference screenshots, by example and browser
+    references: PathBuf,
+
+    #[arg(long, default_value = "wasm-screenshots")]
+    /// Folder where the screenshots, the diff images and the JUnit report are written
+    screenshots: PathBuf,
+
+    #[arg(long, default_value_t = 0.001)]
+    /// Fraction of the pixels allowed to differ from the reference
+    tolerance: f32,
+
+    #[arg(long)]
+    /// Replace the references with the screenshots taken instead of comparing them
+    update_references: bool,
 
     #[arg(value_enum, short, long, default_value_t = WebApi::Webgl2)]
     /// Browser API to use for rendering
@@ -43,6 +85,33 @@ struct Args {
     #[arg(long)]
     /// Build the example in debug mode instead of release
     debug: bool,
+
+    #[arg(long)]
+    /// Serve the example on a local HTTP server with cross-origin isolation, rebuilding it and
+    /// reloading the page when the sources change
+    serve: bool,
+
+    #[arg(long)]
+    /// Build a multithreaded example, with the atomics and bulk memory target features. This
+    /// requires a nightly toolchain with the `rust-src` component
+    threads: bool,
+
+    #[arg(long)]
+    /// Write an HTML shell showing the download progress of the example to
+    /// `examples/wasm/target/index.html`
+    loading_shell: bool,
+
+    #[arg(long)]
+    /// Write gzip and brotli compressed copies of the wasm file and its bindings
+    compress: bool,
+
+    #[arg(long)]
+    /// Print the functions taking the most space in the wasm file, with twiggy
+    analyze_size: bool,
+
+    #[arg(long, default_value_t = 8000)]
+    /// Port of the local HTTP server
+    port: u16,
 }
 
 fn main() {
@@ -50,12 +119,34 @@ fn main() {
 
     assert!(!cli.examples.is_empty(), "must have at least one example");
 
-    let default_features = true;
     let mut features: Vec<&str> = cli.features.iter().map(String::as_str).collect();
+    let mut ci_testing_config = CiTestingConfig::default();
     if let Some(frames) = cli.frames {
-        let mut file = File::create("ci_testing_config.ron").unwrap();
-        file.write_fmt(format_args!("(events: [({frames}, AppExit)])"))
-            .unwrap();
+        ci_testing_config
+            .events
+            .push(CiTestingEventOnFrame(frames, CiTestingEvent::AppExit));
+    }
+    let mut screenshot_frames = cli.screenshot_frames.clone();
+    screenshot_frames.sort_unstable();
+    screenshot_frames.dedup();
+    if let Some((&last, others)) = screenshot_frames.split_last() {
+        ci_testing_config.setup.fixed_frame_time = Some(cli.screenshot_frame_time);
+        ci_testing_config.events.extend(
+            others
+                .iter()
+                .map(|&frame| CiTestingEventOnFrame(frame, CiTestingEvent::Screenshot)),
+        );
+        ci_testing_config.events.push(CiTestingEventOnFrame(
+            last,
+            CiTestingEvent::ScreenshotAndExit,
+        ));
+    }
+    if !ci_testing_config.events.is_empty() {
+        fs::write(
+            "ci_testing_config.ron",
+            ron::to_string(&ci_testing_config).unwrap(),
+        )
+        .unwrap();
         features.push("bevy_ci_testing");
     }
 
@@ -66,56 +157,163 @@ fn main() {
         }
     }
 
-    for example in cli.examples {
-        let sh = Shell::new().unwrap();
-        let features_string = features.join(",");
-        let mut parameters = vec![];
-        if !default_features {
-            parameters.push("--no-default-features");
-        }
-        if !features.is_empty() {
-            parameters.push("--features");
-            parameters.push(&features_string);
-        }
-
-        let (profile, out_dir) = if cli.debug {
-            ("dev", "debug")
-        } else if cli.optimize_size {
-            ("wasm-release", "wasm-release")
-        } else {
-            ("release", "release")
-        };
+    if cli.threads {
+        features.push("multi_threaded");
+    }
 
-        let cmd = cmd!(
-            sh,
-            "cargo build {parameters...} --profile {profile} --target wasm32-unknown-unknown --example {example}"
+    if cli.serve {
+        assert!(
+            cli.examples.len() == 1,
+            "can only serve one example at a time"
         );
-        cmd.env("RUSTFLAGS", "--cfg getrandom_backend=\"wasm_js\"")
-            .run()
-            .expect("Error building example");
-
-        cmd!(
-            sh,
-            "wasm-bindgen --out-dir examples/wasm/target --out-name wasm_example --target web target/wasm32-unknown-unknown/{out_dir}/examples/{example}.wasm"
-        )
-        .run()
-        .expect("Error creating wasm binding");
-
-        if cli.optimize_size {
-            cmd!(sh, "wasm-opt -Oz --output examples/wasm/target/wasm_example_bg.wasm.optimized examples/wasm/target/wasm_example_bg.wasm")
-                .run().expect("Failed to optimize for size. Do you have wasm-opt correctly set up?");
+        let example = &cli.examples[0];
+        let build = || {
+            let sh = Shell::new().unwrap();
+            build_example(&sh, example, &features, &cli)
+        };
+        if let Err(err) = build() {
+            eprintln!("{err}");
         }
+        serve::serve(cli.port, build);
+        return;
+    }
+
+    let mut comparisons = vec![];
+    for example in &cli.examples {
+        let sh = Shell::new().unwrap();
+        build_example(&sh, example, &features, &cli).unwrap();
 
         if cli.test {
-            let _dir = sh.push_dir(".github/start-wasm-example");
+            let example_screenshots = std::env::current_dir()
+                .unwrap()
+                .join(&cli.screenshots)
+                .join(example);
+            let dir = sh.push_dir(".github/start-wasm-example");
             let mut browsers = cli.browsers.clone();
             if !browsers.is_empty() {
                 browsers.insert(0, "--project".to_string());
             }
-            cmd!(sh, "npx playwright test --headed {browsers...}")
-                .env("SCREENSHOT_PREFIX", format!("screenshot-{example}"))
-                .run()
-                .expect("Error running playwright test");
+            let mut playwright = cmd!(sh, "npx playwright test --headed {browsers...}")
+                .env("SCREENSHOT_PREFIX", format!("screenshot-{example}"));
+            if !screenshot_frames.is_empty() {
+                playwright = playwright.env("SCREENSHOT_DIR", &example_screenshots);
+            }
+            playwright.run().expect("Error running playwright test");
+            drop(dir);
+
+            let browsers = if cli.browsers.is_empty() {
+                DEFAULT_BROWSERS.iter().map(ToString::to_string).collect()
+            } else {
+                cli.browsers.clone()
+            };
+            for browser in browsers {
+                for &frame in &screenshot_frames {
+                    let file = format!("screenshot-{frame}.png");
+                    let outcome = compare::compare(
+                        &example_screenshots.join(&browser).join(&file),
+                        &cli.references.join(example).join(&browser).join(&file),
+                        &example_screenshots
+                            .join(&browser)
+                            .join(format!("diff-{frame}.png")),
+                        cli.tolerance,
+                        cli.update_references,
+                    )
+                    .unwrap();
+                    comparisons.push(compare::Comparison {
+                        example: example.clone(),
+                        browser: browser.clone(),
+                        frame,
+                        outcome,
+                    });
+                }
+            }
         }
     }
+
+    if !comparisons.is_empty() {
+        compare::print_summary(&comparisons);
+        let report = cli.screenshots.join("junit.xml");
+        compare::write_junit(&report, &comparisons).unwrap();
+        println!("JUnit report written to {}", report.display());
+        if comparisons
+            .iter()
+            .any(|comparison| comparison.outcome.is_failure())
+        {
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Builds the `example` and its bindings into `examples/wasm/target`.
+fn build_example(sh: &Shell, example: &str, features: &[&str], cli: &Args) -> Result<(), String> {
+    let default_features = true;
+    let features_string = features.join(",");
+    let mut parameters = vec![];
+    if !default_features {
+        parameters.push("--no-default-features");
+    }
+    if !features.is_empty() {
+        parameters.push("--features");
+        parameters.push(&features_string);
+    }
+
+    let (profile, out_dir) = if cli.debug {
+        ("dev", "debug")
+    } else if cli.optimize_size {
+        ("wasm-release", "wasm-release")
+    } else {
+        ("release", "release")
+    };
+
+    let mut toolchain = vec![];
+    let mut rustflags = String::from("--cfg getrandom_backend=\"wasm_js\"");
+    if cli.threads {
+        toolchain.push("+nightly");
+        parameters.extend_from_slice(threads::CARGO_PARAMETERS);
+        rustflags.push(' ');
+        rustflags.push_str(threads::RUSTFLAGS);
+    }
+
+    let cmd = cmd!(
+        sh,
+        "cargo {toolchain...} build {parameters...} --profile {profile} --target wasm32-unknown-unknown --example {example}"
+    );
+    cmd.env("RUSTFLAGS", rustflags)
+        .run()
+        .map_err(|err| format!("Error building example: {err}"))?;
+
+    cmd!(
+        sh,
+        "wasm-bindgen --out-dir examples/wasm/target --out-name wasm_example --target web target/wasm32-unknown-unknown/{out_dir}/examples/{example}.wasm"
+    )
+    .run()
+    .map_err(|err| format!("Error creating wasm binding: {err}"))?;
+
+    if cli.optimize_size {
+        let wasm_opt_features: &[&str] = if cli.threads {
+            threads::WASM_OPT_FEATURES
+        } else {
+            &[]
+        };
+        cmd!(sh, "wasm-opt -Oz {wasm_opt_features...} --output examples/wasm/target/wasm_example_bg.wasm.optimized examples/wasm/target/wasm_example_bg.wasm")
+            .run()
+            .map_err(|err| format!("Failed to optimize for size. Do you have wasm-opt correctly set up? {err}"))?;
+    }
+
+    if cli.threads || cli.loading_shell {
+        shell::write(cli.threads, cli.loading_shell)
+            .map_err(|err| format!("Error writing the HTML shell: {err}"))?;
+    }
+
+    if cli.compress {
+        size::artifacts()
+            .and_then(|artifacts| size::compress(&artifacts))
+            .map_err(|err| format!("Error compressing the example: {err}"))?;
+    }
+    size::report(example).map_err(|err| format!("Error reporting the size: {err}"))?;
+    if cli.analyze_size {
+        size::analyze(sh)?;
+    }
+
+    Ok(())
 }
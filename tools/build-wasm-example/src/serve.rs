@@ -0,0 +1,207 @@
+//! Local development server for the wasm examples.
+//!
+//! The server sends the cross-origin isolation headers required by `SharedArrayBuffer`, and so by
+//! multithreaded wasm, watches the sources to rebuild the example when they change, and injects a
+//! small script in the page which reloads it after every successful rebuild.
+
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// The folder served, containing the page and the built example.
+const ROOT: &str = "examples/wasm";
+
+/// The path polled by the page to know when to reload.
+const RELOAD_PATH: &str = "/__bevy_reload";
+
+/// The folders watched for changes, relative to the root of the repository.
+const WATCHED: &[&str] = &["examples", "crates", "assets", "Cargo.toml"];
+
+const RELOAD_SCRIPT: &str = r#"<script>
+  (async () => {
+    let version = null;
+    while (true) {
+      try {
+        const current = await (await fetch("/__bevy_reload", { cache: "no-store" })).text();
+        if (version !== null && current !== version) {
+          location.reload();
+        }
+        version = current;
+      } catch (_) {}
+      await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+  })();
+</script>"#;
+
+/// Serves the example on `port`, calling `build` whenever the watched sources change.
+///
+/// This never returns.
+pub fn serve(port: u16, mut build: impl FnMut() -> Result<(), String>) {
+    let version = Arc::new(AtomicU64::new(0));
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .unwrap_or_else(|err| panic!("Failed to listen on port {port}: {err}"));
+    println!("Serving the example on http://localhost:{port}/");
+    {
+        let version = version.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let version = version.clone();
+                thread::spawn(move || {
+                    if let Err(err) = handle_connection(stream, &version) {
+                        eprintln!("Failed to answer a request: {err}");
+                    }
+                });
+            }
+        });
+    }
+
+    let mut last_change = latest_modification();
+    loop {
+        thread::sleep(Duration::from_millis(500));
+        let change = latest_modification();
+        if change == last_change {
+            continue;
+        }
+        last_change = change;
+
+        println!("Sources changed, rebuilding...");
+        match build() {
+            Ok(()) => {
+                version.fetch_add(1, Ordering::Relaxed);
+                println!("Rebuilt, reloading the page.");
+            }
+            Err(err) => eprintln!("{err}"),
+        }
+        // Ignore the changes made while building, like generated files.
+        last_change = latest_modification();
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, version: &AtomicU64) -> std::io::Result<()> {
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("/")
+        .split(['?', '#'])
+        .next()
+        .unwrap_or("/");
+
+    if path == RELOAD_PATH {
+        let body = version.load(Ordering::Relaxed).to_string();
+        return respond(&mut stream, "200 OK", "text/plain", body.as_bytes());
+    }
+
+    let Some(file) = resolve(path) else {
+        return respond(&mut stream, "404 Not Found", "text/plain", b"Not found");
+    };
+    match fs::read(&file) {
+        Ok(mut body) => {
+            let content_type = content_type(&file);
+            if content_type == "text/html" {
+                body = inject_reload_script(&String::from_utf8_lossy(&body)).into_bytes();
+            }
+            respond(&mut stream, "200 OK", content_type, &body)
+        }
+        Err(_) => respond(&mut stream, "404 Not Found", "text/plain", b"Not found"),
+    }
+}
+
+/// Maps a request path to a file in [`ROOT`], refusing to leave it.
+fn resolve(path: &str) -> Option<PathBuf> {
+    let mut file = PathBuf::from(ROOT);
+    for segment in path.split('/').filter(|segment| !segment.is_empty()) {
+        if segment == ".." || segment.contains('\\') {
+            return None;
+        }
+        file.push(segment);
+    }
+    if file.is_dir() {
+        file.push("index.html");
+    }
+    Some(file)
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: {content_type}\r\n\
+         Content-Length: {}\r\n\
+         Cross-Origin-Opener-Policy: same-origin\r\n\
+         Cross-Origin-Embedder-Policy: require-corp\r\n\
+         Cache-Control: no-cache\r\n\
+         Connection: close\r\n\r\n",
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+fn content_type(file: &Path) -> &'static str {
+    match file.extension().and_then(|extension| extension.to_str()) {
+        Some("html") => "text/html",
+        Some("js") => "text/javascript",
+        Some("wasm") => "application/wasm",
+        Some("json") => "application/json",
+        Some("css") => "text/css",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("ico") => "image/x-icon",
+        Some("ogg") => "audio/ogg",
+        Some("wav") => "audio/wav",
+        _ => "application/octet-stream",
+    }
+}
+
+fn inject_reload_script(html: &str) -> String {
+    match html.rfind("</html>") {
+        Some(end) => format!("{}{RELOAD_SCRIPT}\n{}", &html[..end], &html[end..]),
+        None => format!("{html}\n{RELOAD_SCRIPT}"),
+    }
+}
+
+/// The latest modification time of the watched files, skipping the build outputs.
+fn latest_modification() -> Option<SystemTime> {
+    fn visit(path: &Path, latest: &mut Option<SystemTime>) {
+        let Ok(metadata) = fs::symlink_metadata(path) else {
+            return;
+        };
+        if metadata.is_dir() {
+            let Ok(entries) = fs::read_dir(path) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                if name == "target" || name.to_string_lossy().starts_with('.') {
+                    continue;
+                }
+                visit(&entry.path(), latest);
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            *latest = (*latest).max(Some(modified));
+        }
+    }
+
+    let mut latest = None;
+    for path in WATCHED {
+        visit(Path::new(path), &mut latest);
+    }
+    latest
+}
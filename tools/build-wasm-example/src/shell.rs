@@ -0,0 +1,125 @@
+//! The HTML shells written next to the built example, in `examples/wasm/target`.
+//!
+//! `examples/wasm/index.html` is enough to run an example, but it shows nothing while the wasm
+//! file downloads, and can't run a multithreaded example outside of a cross-origin isolated page.
+//! The generated shell can show the download progress, and checks the isolation first when the
+//! example uses threads.
+
+use std::{fs, io};
+
+/// The headers to serve the pages with, in the `_headers` format understood by most static hosts.
+const HEADERS: &str = "/*
+  Cross-Origin-Opener-Policy: same-origin
+  Cross-Origin-Embedder-Policy: require-corp
+";
+
+const TEMPLATE: &str = r#"<html lang="en">
+  <head>
+    <meta charset="UTF-8" />
+    <title>Wasm Example</title>
+    <style>
+      #loading {
+        font-family: sans-serif;
+        margin: 20vh auto;
+        width: 50%;
+      }
+      #loading progress {
+        width: 100%;
+      }
+    </style>
+  </head>
+  <body>
+    <div id="loading">
+      <progress id="progress"></progress>
+      <p id="status">Loading...</p>
+    </div>
+  </body>
+  <script type="module">
+    const status = document.getElementById("status");
+    const progress = document.getElementById("progress");
+
+    // Set when the example uses threads, which need a cross-origin isolated page.
+    const threads = {threads};
+    // The size of the uncompressed wasm file, as the `Content-Length` of a compressed response
+    // doesn't match the number of bytes read from it.
+    const wasmSize = {wasm_size};
+    const showProgress = {progress};
+
+    async function fetchWasm() {
+      const response = await fetch("./wasm_example_bg.wasm");
+      if (!response.ok) {
+        throw new Error(`failed to fetch the wasm file: ${response.status}`);
+      }
+      if (!showProgress || response.body === null) {
+        return await response.arrayBuffer();
+      }
+      progress.max = wasmSize;
+      const reader = response.body.getReader();
+      const chunks = [];
+      let loaded = 0;
+      while (true) {
+        const { done, value } = await reader.read();
+        if (done) {
+          break;
+        }
+        chunks.push(value);
+        loaded += value.length;
+        progress.value = loaded;
+        status.textContent = `Loading... ${Math.round(loaded / 1024)} / ${Math.round(wasmSize / 1024)} KiB`;
+      }
+      const bytes = new Uint8Array(loaded);
+      let offset = 0;
+      for (const chunk of chunks) {
+        bytes.set(chunk, offset);
+        offset += chunk.length;
+      }
+      return bytes;
+    }
+
+    if (threads && !self.crossOriginIsolated) {
+      progress.hidden = true;
+      status.innerHTML = `This page is not cross-origin isolated, so it can't use threads. Serve it with the
+        <code>Cross-Origin-Opener-Policy: same-origin</code> and
+        <code>Cross-Origin-Embedder-Policy: require-corp</code> headers, for example with
+        <code>cargo run -p build-wasm-example -- --threads --serve &lt;example&gt;</code>.`;
+    } else {
+      try {
+        const { default: init } = await import("./wasm_example.js");
+        const module = await fetchWasm();
+        status.textContent = "Starting...";
+        progress.removeAttribute("value");
+        await init({ module_or_path: module }).catch((error) => {
+          // Winit stops the event loop by throwing an exception, which isn't an error.
+          if (!error.message.startsWith("Using exceptions for control flow")) {
+            throw error;
+          }
+        });
+        document.getElementById("loading").remove();
+      } catch (error) {
+        progress.hidden = true;
+        status.textContent = `Failed to load the example: ${error}`;
+        throw error;
+      }
+    }
+  </script>
+</html>
+"#;
+
+/// Writes `examples/wasm/target/index.html`, showing the download progress of the wasm file if
+/// `progress` is set, and the cross-origin isolation headers it should be served with if the
+/// example uses `threads`.
+pub fn write(threads: bool, progress: bool) -> io::Result<()> {
+    let wasm_size = fs::metadata("examples/wasm/target/wasm_example_bg.wasm")?.len();
+    let shell = TEMPLATE
+        .replace("{threads}", &threads.to_string())
+        .replace("{progress}", &progress.to_string())
+        .replace("{wasm_size}", &wasm_size.to_string());
+    fs::write("examples/wasm/target/index.html", shell)?;
+    if threads {
+        fs::write("examples/wasm/target/_headers", HEADERS)?;
+        println!(
+            "The multithreaded example is in examples/wasm/target, with the headers it must be served with in examples/wasm/target/_headers."
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+//! Compression of the built example and report of its download size.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use xshell::{cmd, Shell};
+
+const TARGET: &str = "examples/wasm/target";
+
+/// Writes a gzip and a brotli compressed copy of each `file`, next to it, for static hosts that
+/// serve precompressed files.
+pub fn compress(files: &[PathBuf]) -> io::Result<()> {
+    for file in files {
+        let content = fs::read(file)?;
+
+        let mut gzip = flate2::write::GzEncoder::new(
+            fs::File::create(with_extension(file, "gz"))?,
+            flate2::Compression::best(),
+        );
+        gzip.write_all(&content)?;
+        gzip.finish()?;
+
+        let mut brotli = brotli::CompressorWriter::new(
+            fs::File::create(with_extension(file, "br"))?,
+            4096,
+            11,
+            22,
+        );
+        brotli.write_all(&content)?;
+        // Finishes the brotli stream.
+        brotli.into_inner();
+    }
+    Ok(())
+}
+
+/// Returns the files of the built example that are downloaded by the browser, the wasm file, the
+/// bindings and their snippets.
+pub fn artifacts() -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![
+        Path::new(TARGET).join("wasm_example_bg.wasm"),
+        Path::new(TARGET).join("wasm_example.js"),
+    ];
+    files.extend(snippets()?);
+    Ok(files)
+}
+
+fn snippets() -> io::Result<Vec<PathBuf>> {
+    fn visit(path: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+        for entry in fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                visit(&path, files)?;
+            } else if path.extension().is_some_and(|extension| extension == "js") {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = vec![];
+    let snippets = Path::new(TARGET).join("snippets");
+    if snippets.is_dir() {
+        visit(&snippets, &mut files)?;
+    }
+    Ok(files)
+}
+
+/// Prints the download size of the `example`: its wasm file, its bindings and snippets, and the
+/// assets its source refers to, with their compressed sizes when they were compressed.
+pub fn report(example: &str) -> io::Result<()> {
+    let wasm = Path::new(TARGET).join("wasm_example_bg.wasm");
+    let bindings = Path::new(TARGET).join("wasm_example.js");
+    let snippets = snippets()?;
+    let assets = referenced_assets(example);
+
+    println!("Size of {example}:");
+    print_row("wasm", &[wasm]);
+    print_row("bindings", &[bindings]);
+    print_row(&format!("snippets ({})", snippets.len()), &snippets);
+    print_row(&format!("assets ({})", assets.len()), &assets);
+    Ok(())
+}
+
+fn print_row(name: &str, files: &[PathBuf]) {
+    let size = |extension: Option<&str>| -> Option<u64> {
+        files
+            .iter()
+            .map(|file| {
+                let file = match extension {
+                    Some(extension) => with_extension(file, extension),
+                    None => file.clone(),
+                };
+                fs::metadata(file).ok().map(|metadata| metadata.len())
+            })
+            .sum()
+    };
+    let mut row = format!("  {name:<16} {:>12}", format_size(size(None).unwrap_or(0)));
+    if let Some(gzip) = size(Some("gz")) {
+        row.push_str(&format!("  gzip {:>12}", format_size(gzip)));
+    }
+    if let Some(brotli) = size(Some("br")) {
+        row.push_str(&format!("  brotli {:>12}", format_size(brotli)));
+    }
+    println!("{row}");
+}
+
+/// The files of the `assets` folder whose path appears in a string literal of the example source.
+///
+/// This is a heuristic: assets loaded from paths built at runtime, or by other assets like the
+/// textures of a glTF file, are missed.
+fn referenced_assets(example: &str) -> Vec<PathBuf> {
+    let Some(source) = example_path(example).and_then(|path| fs::read_to_string(path).ok()) else {
+        return vec![];
+    };
+    let mut assets: Vec<PathBuf> = source
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(|literal| literal.split('#').next().unwrap_or(literal))
+        .map(|literal| Path::new("assets").join(literal))
+        .filter(|path| path.is_file())
+        .collect();
+    assets.sort();
+    assets.dedup();
+    assets
+}
+
+/// The path of the source of the `example`, read from the manifest of the repository.
+fn example_path(example: &str) -> Option<String> {
+    let manifest = fs::read_to_string("Cargo.toml").ok()?;
+    let mut lines = manifest
+        .lines()
+        .skip_while(|line| line.trim() != format!("name = \"{example}\""));
+    lines.next()?;
+    lines
+        .take_while(|line| !line.starts_with('['))
+        .find_map(|line| line.trim().strip_prefix("path = \""))
+        .map(|path| path.trim_end_matches('"').to_string())
+}
+
+/// Prints the functions taking the most space in the wasm file of the example, with `twiggy`.
+pub fn analyze(sh: &Shell) -> Result<(), String> {
+    cmd!(
+        sh,
+        "twiggy top -n 25 examples/wasm/target/wasm_example_bg.wasm"
+    )
+    .run()
+    .map_err(|err| {
+        format!("Failed to analyze the size. Is twiggy installed (`cargo install twiggy`)? {err}")
+    })
+}
+
+fn with_extension(file: &Path, extension: &str) -> PathBuf {
+    let mut file = file.as_os_str().to_owned();
+    file.push(".");
+    file.push(extension);
+    file.into()
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.2} MiB", bytes as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    }
+}
@@ -0,0 +1,19 @@
+//! Build settings of the multithreaded wasm examples.
+//!
+//! Threads on the web are web workers sharing the memory of the main thread through a
+//! `SharedArrayBuffer`. The standard library must be rebuilt with the atomics and bulk memory
+//! target features for that, which needs a nightly toolchain, and browsers only expose
+//! `SharedArrayBuffer` to pages served with cross-origin isolation headers.
+
+/// The additional parameters of `cargo build`, rebuilding the standard library.
+pub const CARGO_PARAMETERS: &[&str] = &["-Zbuild-std=std,panic_abort"];
+
+/// The additional `RUSTFLAGS`, enabling the target features needed by shared memory.
+pub const RUSTFLAGS: &str = "-C target-feature=+atomics,+bulk-memory,+mutable-globals";
+
+/// The features `wasm-opt` must accept to optimize a multithreaded module.
+pub const WASM_OPT_FEATURES: &[&str] = &[
+    "--enable-threads",
+    "--enable-bulk-memory",
+    "--enable-mutable-globals",
+];
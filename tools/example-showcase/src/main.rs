@@ -1,4 +1,4 @@
-//! Tool to run all examples or generate a showcase page for the Bevy website.
+//! Tool to browse and run the examples, or generate a showcase page for the Bevy website.
 
 #![expect(clippy::print_stdout, reason = "Allowed in tools.")]
 
@@ -92,6 +92,31 @@ enum Action {
         #[arg(long)]
         /// Only run examples that don't need extra features
         only_default_features: bool,
+
+        #[command(flatten)]
+        filter: ExampleFilter,
+    },
+    /// List the examples, optionally filtered by category or search terms
+    List {
+        #[command(flatten)]
+        filter: ExampleFilter,
+    },
+    /// Browse the examples interactively, then build and launch the chosen ones
+    Browse {
+        #[command(flatten)]
+        filter: ExampleFilter,
+
+        #[arg(long)]
+        /// WGPU backend to use, like `vulkan`, `metal`, `dx12` or `gl`
+        wgpu_backend: Option<String>,
+
+        #[arg(value_enum, long)]
+        /// Build the examples for the web with this API instead of running them natively
+        api: Option<WebApi>,
+
+        #[arg(long, value_delimiter = ',')]
+        /// Extra features to enable, in addition to the ones required by the example
+        features: Vec<String>,
     },
     /// Build the markdown files for the website
     BuildWebsiteList {
@@ -123,6 +148,40 @@ enum Action {
     },
 }
 
+/// Filters selecting a subset of the examples.
+#[derive(clap::Args, Debug, Default)]
+struct ExampleFilter {
+    #[arg(long)]
+    /// Only keep the examples of this category, case insensitive
+    category: Option<String>,
+
+    #[arg(long)]
+    /// Only keep the examples whose name, category or description contain this text, case
+    /// insensitive
+    search: Option<String>,
+}
+
+impl ExampleFilter {
+    fn matches(&self, example: &Example) -> bool {
+        let category_matches = self
+            .category
+            .as_ref()
+            .is_none_or(|category| example.category.eq_ignore_ascii_case(category));
+        let search_matches = self.search.as_ref().is_none_or(|search| {
+            let search = search.to_lowercase();
+            [
+                &example.name,
+                &example.technical_name,
+                &example.category,
+                &example.description,
+            ]
+            .iter()
+            .any(|text| text.to_lowercase().contains(&search))
+        });
+        category_matches && search_matches
+    }
+}
+
 #[derive(Debug, Copy, Clone, ValueEnum)]
 enum WebApi {
     Webgl2,
@@ -165,6 +224,7 @@ fn main() {
             show_logs,
             example_list,
             only_default_features,
+            filter,
         } => {
             if example_list.is_some() && cli.page.is_some() {
                 let mut cmd = Args::command();
@@ -309,6 +369,7 @@ fn main() {
                     .filter(|example| {
                         !only_default_features || example.required_features.is_empty()
                     })
+                    .filter(|example| filter.matches(example))
                     .skip(cli.page.unwrap_or(0) * cli.per_page.unwrap_or(0))
                     .take(cli.per_page.unwrap_or(usize::MAX))
             };
@@ -482,6 +543,63 @@ fn main() {
                 exit(1);
             }
         }
+        Action::List { filter } => {
+            let mut examples = parse_examples();
+            examples.retain(|example| {
+                example.example_type == ExampleType::Bin && filter.matches(example)
+            });
+            print_examples(&examples);
+        }
+        Action::Browse {
+            mut filter,
+            wgpu_backend,
+            api,
+            features,
+        } => {
+            let mut all_examples = parse_examples();
+            all_examples.retain(|example| example.example_type == ExampleType::Bin);
+
+            let stdin = std::io::stdin();
+            loop {
+                let examples = all_examples
+                    .iter()
+                    .filter(|example| filter.matches(example))
+                    .filter(|example| api.is_none() || example.wasm)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                print_examples(&examples);
+                println!();
+                println!(
+                    "Enter a number to launch an example, `/text` to search, `#category` to filter \
+                     by category, `*` to clear the filters, or nothing to quit."
+                );
+                print!("> ");
+                let _ = std::io::stdout().flush();
+
+                let mut input = String::new();
+                if stdin.read_line(&mut input).unwrap_or(0) == 0 {
+                    break;
+                }
+                let input = input.trim();
+                if input.is_empty() {
+                    break;
+                } else if input == "*" {
+                    filter = ExampleFilter::default();
+                } else if let Some(search) = input.strip_prefix('/') {
+                    filter.search = (!search.is_empty()).then(|| search.to_string());
+                } else if let Some(category) = input.strip_prefix('#') {
+                    filter.category = (!category.is_empty()).then(|| category.to_string());
+                } else if let Some(example) = input
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| examples.get(index.wrapping_sub(1)))
+                {
+                    launch_example(example, &profile, wgpu_backend.as_deref(), api, &features);
+                } else {
+                    println!("Unknown command `{input}`.");
+                }
+            }
+        }
         Action::BuildWebsiteList {
             content_folder,
             api,
@@ -761,6 +879,78 @@ required_features = {:?}
     }
 }
 
+/// Prints the examples as a numbered list, grouped by category.
+fn print_examples(examples: &[Example]) {
+    let mut current_category = None;
+    for (index, example) in examples.iter().enumerate() {
+        if current_category != Some(&example.category) {
+            println!("{}", example.category);
+            current_category = Some(&example.category);
+        }
+        let features = if example.required_features.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", example.required_features.join(", "))
+        };
+        println!(
+            "  {:>4}. {}{features} - {}",
+            index + 1,
+            example.technical_name,
+            example.description
+        );
+    }
+    if examples.is_empty() {
+        println!("No example matches the filters.");
+    }
+}
+
+/// Builds and launches the `example`, natively or for the web with the given `api`.
+fn launch_example(
+    example: &Example,
+    profile: &str,
+    wgpu_backend: Option<&str>,
+    api: Option<WebApi>,
+    extra_features: &[String],
+) {
+    let sh = Shell::new().unwrap();
+    let name = &example.technical_name;
+    let features = example
+        .required_features
+        .iter()
+        .chain(extra_features)
+        .cloned()
+        .collect::<Vec<_>>();
+    let features = if features.is_empty() {
+        vec![]
+    } else {
+        vec!["--features".to_string(), features.join(",")]
+    };
+
+    let result = match api {
+        Some(api) => {
+            let api = api.to_string();
+            cmd!(
+                sh,
+                "cargo run -p build-wasm-example -- --api {api} {name} {features...}"
+            )
+            .run()
+        }
+        None => {
+            let mut cmd = cmd!(
+                sh,
+                "cargo run --profile {profile} --example {name} {features...}"
+            );
+            if let Some(backend) = wgpu_backend {
+                cmd = cmd.env("WGPU_BACKEND", backend);
+            }
+            cmd.run()
+        }
+    };
+    if let Err(err) = result {
+        println!("Failed to run {name}: {err}");
+    }
+}
+
 fn split_docblock_and_code(code: &str) -> (String, &str) {
     let mut docblock_lines = Vec::new();
     let mut code_byte_start = 0;